@@ -26,6 +26,7 @@ mod private {
 }
 pub use iterator::BinaryViewValueIter;
 pub use mutable::MutableBinaryViewArray;
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
 use polars_utils::slice::GetSaferUnchecked;
 use private::Sealed;
 
@@ -372,6 +373,39 @@ impl<T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
         mutable.freeze().with_validity(self.validity)
     }
 
+    /// Rebuild the array such that every distinct value that is not inlined in its view is
+    /// stored only once in the data buffers.
+    pub fn deduplicate(&self) -> Self {
+        let mut mutable = MutableBinaryViewArray::<T>::with_capacity(self.len());
+        let mut seen: PlHashMap<&[u8], View> = PlHashMap::new();
+
+        for (i, view) in self.views.iter().enumerate() {
+            if self.is_null(i) {
+                mutable.views.push(View::default());
+                continue;
+            }
+            if view.length <= INLINE_VIEW_SIZE {
+                mutable.total_bytes_len += view.length as usize;
+                mutable.views.push(*view);
+                continue;
+            }
+            // SAFETY: the index is in bounds.
+            let bytes = unsafe { self.value_unchecked(i) }.to_bytes();
+            match seen.get(bytes) {
+                Some(view) => {
+                    mutable.total_bytes_len += view.length as usize;
+                    mutable.views.push(*view);
+                },
+                None => {
+                    // SAFETY: the bytes were valid in `self`.
+                    mutable.push_value_ignore_validity(unsafe { T::from_bytes_unchecked(bytes) });
+                    seen.insert(bytes, *mutable.views.last().unwrap());
+                },
+            }
+        }
+        mutable.freeze().with_validity(self.validity.clone())
+    }
+
     pub fn is_sliced(&self) -> bool {
         self.views.as_ptr() != self.views.storage_ptr()
     }
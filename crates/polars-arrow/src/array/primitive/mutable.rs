@@ -245,6 +245,45 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
         self.update_all_valid();
     }
 
+    /// Extends the [`MutablePrimitiveArray`] from an iterator of values, which don't need to be
+    /// of trusted len.
+    #[inline]
+    pub fn extend_values<I>(&mut self, iterator: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.values.extend(iterator);
+        self.update_all_valid();
+    }
+
+    /// Extends the [`MutablePrimitiveArray`] from a slice and the runs of its validity, given as
+    /// `(is_valid, length)` pairs. The values at the null slots are kept but are not observable.
+    ///
+    /// # Panics
+    /// Panics if the lengths of the runs don't sum to the length of `items`.
+    pub fn extend_from_slice_with_validity_runs<I>(&mut self, items: &[T], runs: I)
+    where
+        I: IntoIterator<Item = (bool, usize)>,
+    {
+        let offset = self.len();
+        self.values.extend_from_slice(items);
+        let mut len = 0;
+        for (is_valid, run_len) in runs {
+            match self.validity.as_mut() {
+                Some(validity) => validity.extend_constant(run_len, is_valid),
+                None if is_valid || run_len == 0 => {},
+                None => {
+                    let mut validity = MutableBitmap::with_capacity(self.values.capacity());
+                    validity.extend_constant(offset + len, true);
+                    validity.extend_constant(run_len, false);
+                    self.validity = Some(validity);
+                },
+            }
+            len += run_len;
+        }
+        assert_eq!(len, items.len(), "the validity runs must cover all values");
+    }
+
     fn update_all_valid(&mut self) {
         // get len before mutable borrow
         let len = self.len();
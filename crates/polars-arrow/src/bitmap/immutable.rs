@@ -139,6 +139,13 @@ impl Bitmap {
         self.len() == 0
     }
 
+    /// Returns the number of bytes in the underlying storage.
+    /// This is larger than needed for [`Bitmap::len`] bits if the bitmap is sliced.
+    #[inline]
+    pub fn storage_len(&self) -> usize {
+        self.bytes.len()
+    }
+
     /// Returns a new iterator of `bool` over this bitmap
     pub fn iter(&self) -> BitmapIter {
         BitmapIter::new(&self.bytes, self.offset, self.length)
@@ -101,6 +101,13 @@ impl<T> Buffer<T> {
         self.length == 0
     }
 
+    /// Returns the number of elements in the underlying storage.
+    /// This is larger than [`Buffer::len`] if the buffer is sliced.
+    #[inline]
+    pub fn storage_len(&self) -> usize {
+        self.storage.len()
+    }
+
     /// Returns whether underlying data is sliced.
     /// If sliced the [`Buffer`] is backed by
     /// more data than the length of `Self`.
@@ -129,3 +129,122 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         },
     }
 }
+
+fn allocated_validity_size(validity: Option<&Bitmap>) -> usize {
+    validity.map(|b| b.storage_len()).unwrap_or(0)
+}
+
+macro_rules! dyn_binary_allocated {
+    ($array:expr, $ty:ty, $o:ty) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        array.offsets().buffer().storage_len() * std::mem::size_of::<$o>()
+            + array.values().storage_len()
+            + allocated_validity_size(array.validity())
+    }};
+}
+
+fn binview_allocated_size<T: ViewType + ?Sized>(array: &BinaryViewArrayGeneric<T>) -> usize {
+    array.views().storage_len() * std::mem::size_of::<View>()
+        + array
+            .data_buffers()
+            .iter()
+            .map(|b| b.storage_len())
+            .sum::<usize>()
+        + allocated_validity_size(array.validity())
+}
+
+/// Returns the size in bytes of all the buffers the array keeps alive.
+/// # Implementation
+/// Unlike [`estimated_bytes_size`], this includes the parts of the buffers that are not visible
+/// because the array is sliced. Buffers shared by multiple arrays are counted for every array.
+pub fn allocated_bytes_size(array: &dyn Array) -> usize {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => 0,
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array.values().storage_len() + allocated_validity_size(array.validity())
+        },
+        Primitive(PrimitiveType::DaysMs) => {
+            let array = array.as_any().downcast_ref::<DaysMsArray>().unwrap();
+            array.values().storage_len() * std::mem::size_of::<i32>() * 2
+                + allocated_validity_size(array.validity())
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap();
+
+            array.values().storage_len() * std::mem::size_of::<$T>()
+                + allocated_validity_size(array.validity())
+        }),
+        Binary => dyn_binary_allocated!(array, BinaryArray<i32>, i32),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array.values().storage_len() + allocated_validity_size(array.validity())
+        },
+        LargeBinary => dyn_binary_allocated!(array, BinaryArray<i64>, i64),
+        Utf8 => dyn_binary_allocated!(array, Utf8Array<i32>, i32),
+        LargeUtf8 => dyn_binary_allocated!(array, Utf8Array<i64>, i64),
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            allocated_bytes_size(array.values().as_ref())
+                + array.offsets().buffer().storage_len() * std::mem::size_of::<i32>()
+                + allocated_validity_size(array.validity())
+        },
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            allocated_bytes_size(array.values().as_ref())
+                + allocated_validity_size(array.validity())
+        },
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            allocated_bytes_size(array.values().as_ref())
+                + array.offsets().buffer().storage_len() * std::mem::size_of::<i64>()
+                + allocated_validity_size(array.validity())
+        },
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|x| allocated_bytes_size(x.as_ref()))
+                .sum::<usize>()
+                + allocated_validity_size(array.validity())
+        },
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let types = array.types().storage_len() * std::mem::size_of::<i8>();
+            let offsets = array
+                .offsets()
+                .map(|x| x.storage_len() * std::mem::size_of::<i32>())
+                .unwrap_or_default();
+            let fields = array
+                .fields()
+                .iter()
+                .map(|x| allocated_bytes_size(x.as_ref()))
+                .sum::<usize>();
+            types + offsets + fields
+        },
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            allocated_bytes_size(array.keys()) + allocated_bytes_size(array.values().as_ref())
+        }),
+        Utf8View => binview_allocated_size::<str>(array.as_any().downcast_ref().unwrap()),
+        BinaryView => binview_allocated_size::<[u8]>(array.as_any().downcast_ref().unwrap()),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = array.offsets().buffer().storage_len() * std::mem::size_of::<i32>();
+            offsets
+                + allocated_bytes_size(array.field().as_ref())
+                + allocated_validity_size(array.validity())
+        },
+    }
+}
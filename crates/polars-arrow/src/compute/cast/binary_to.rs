@@ -29,6 +29,8 @@ impl_parse!(u8);
 impl_parse!(u16);
 impl_parse!(u32);
 impl_parse!(u64);
+impl_parse!(i128);
+impl_parse!(u128);
 
 impl Parse for f32 {
     fn parse(val: &[u8]) -> Option<Self>
@@ -42,6 +42,8 @@ pub enum ArrowDataType {
     Int32,
     /// An [`i64`]
     Int64,
+    /// An [`i128`]
+    Int128,
     /// An [`u8`]
     UInt8,
     /// An [`u16`]
@@ -50,6 +52,8 @@ pub enum ArrowDataType {
     UInt32,
     /// An [`u64`]
     UInt64,
+    /// An [`u128`]
+    UInt128,
     /// An 16-bit float
     Float16,
     /// A [`f32`]
@@ -186,10 +190,14 @@ impl From<ArrowDataType> for arrow_schema::DataType {
             ArrowDataType::Int16 => Self::Int16,
             ArrowDataType::Int32 => Self::Int32,
             ArrowDataType::Int64 => Self::Int64,
+            // arrow-rs has no 128-bit integers, they have the layout of a decimal and a fixed
+            // size binary of 16 bytes respectively.
+            ArrowDataType::Int128 => Self::Decimal128(38, 0),
             ArrowDataType::UInt8 => Self::UInt8,
             ArrowDataType::UInt16 => Self::UInt16,
             ArrowDataType::UInt32 => Self::UInt32,
             ArrowDataType::UInt64 => Self::UInt64,
+            ArrowDataType::UInt128 => Self::FixedSizeBinary(16),
             ArrowDataType::Float16 => Self::Float16,
             ArrowDataType::Float32 => Self::Float32,
             ArrowDataType::Float64 => Self::Float64,
@@ -449,12 +457,14 @@ impl ArrowDataType {
             Int64 | Date64 | Timestamp(_, _) | Time64(_) | Duration(_) => {
                 PhysicalType::Primitive(PrimitiveType::Int64)
             },
+            Int128 => PhysicalType::Primitive(PrimitiveType::Int128),
             Decimal(_, _) => PhysicalType::Primitive(PrimitiveType::Int128),
             Decimal256(_, _) => PhysicalType::Primitive(PrimitiveType::Int256),
             UInt8 => PhysicalType::Primitive(PrimitiveType::UInt8),
             UInt16 => PhysicalType::Primitive(PrimitiveType::UInt16),
             UInt32 => PhysicalType::Primitive(PrimitiveType::UInt32),
             UInt64 => PhysicalType::Primitive(PrimitiveType::UInt64),
+            UInt128 => PhysicalType::Primitive(PrimitiveType::UInt128),
             Float16 => PhysicalType::Primitive(PrimitiveType::Float16),
             Float32 => PhysicalType::Primitive(PrimitiveType::Float32),
             Float64 => PhysicalType::Primitive(PrimitiveType::Float64),
@@ -576,14 +586,14 @@ impl From<PrimitiveType> for ArrowDataType {
             PrimitiveType::UInt16 => ArrowDataType::UInt16,
             PrimitiveType::UInt32 => ArrowDataType::UInt32,
             PrimitiveType::UInt64 => ArrowDataType::UInt64,
-            PrimitiveType::Int128 => ArrowDataType::Decimal(32, 32),
+            PrimitiveType::Int128 => ArrowDataType::Int128,
             PrimitiveType::Int256 => ArrowDataType::Decimal256(32, 32),
             PrimitiveType::Float16 => ArrowDataType::Float16,
             PrimitiveType::Float32 => ArrowDataType::Float32,
             PrimitiveType::Float64 => ArrowDataType::Float64,
             PrimitiveType::DaysMs => ArrowDataType::Interval(IntervalUnit::DayTime),
             PrimitiveType::MonthDayNano => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
-            PrimitiveType::UInt128 => unimplemented!(),
+            PrimitiveType::UInt128 => ArrowDataType::UInt128,
         }
     }
 }
@@ -251,6 +251,8 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> PolarsResult<ArrowDataType> {
         "I" => ArrowDataType::UInt32,
         "l" => ArrowDataType::Int64,
         "L" => ArrowDataType::UInt64,
+        "_pli128" => ArrowDataType::Int128,
+        "_plu128" => ArrowDataType::UInt128,
         "e" => ArrowDataType::Float16,
         "f" => ArrowDataType::Float32,
         "g" => ArrowDataType::Float64,
@@ -413,6 +415,9 @@ fn to_format(data_type: &ArrowDataType) -> String {
         ArrowDataType::UInt32 => "I".to_string(),
         ArrowDataType::Int64 => "l".to_string(),
         ArrowDataType::UInt64 => "L".to_string(),
+        // The C data interface has no 128-bit integers, these are only understood by polars.
+        ArrowDataType::Int128 => "_pli128".to_string(),
+        ArrowDataType::UInt128 => "_plu128".to_string(),
         ArrowDataType::Float16 => "e".to_string(),
         ArrowDataType::Float32 => "f".to_string(),
         ArrowDataType::Float64 => "g".to_string(),
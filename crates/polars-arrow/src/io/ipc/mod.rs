@@ -32,6 +32,8 @@ pub mod append;
 pub mod read;
 pub mod write;
 
+use crate::datatypes::IntegerType;
+
 const ARROW_MAGIC_V1: [u8; 4] = [b'F', b'E', b'A', b'1'];
 const ARROW_MAGIC_V2: [u8; 6] = [b'A', b'R', b'R', b'O', b'W', b'1'];
 pub(crate) const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
@@ -44,6 +46,10 @@ pub struct IpcField {
     pub fields: Vec<IpcField>,
     /// dictionary id
     pub dictionary_id: Option<i64>,
+    /// integer type of the run ends if the field is run-end encoded. Run-end encoded
+    /// fields are decoded on read, so the field's data type is the type of its values
+    /// and `fields` holds the [`IpcField`] of the values.
+    pub run_ends: Option<IntegerType>,
 }
 
 /// Struct containing fields and whether the file is written in little or big endian.
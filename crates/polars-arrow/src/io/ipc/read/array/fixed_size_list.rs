@@ -65,6 +65,7 @@ pub fn read_fixed_size_list<R: Read + Seek>(
 pub fn skip_fixed_size_list(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
     variadic_buffer_counts: &mut VecDeque<usize>,
 ) -> PolarsResult<()> {
@@ -83,6 +84,7 @@ pub fn skip_fixed_size_list(
     skip(
         field_nodes,
         field.data_type(),
+        &ipc_field.fields[0],
         buffers,
         variadic_buffer_counts,
     )
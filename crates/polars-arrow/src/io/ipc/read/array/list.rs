@@ -84,6 +84,7 @@ where
 pub fn skip_list<O: Offset>(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
     variadic_buffer_counts: &mut VecDeque<usize>,
 ) -> PolarsResult<()> {
@@ -102,5 +103,11 @@ pub fn skip_list<O: Offset>(
 
     let data_type = ListArray::<O>::get_child_type(data_type);
 
-    skip(field_nodes, data_type, buffers, variadic_buffer_counts)
+    skip(
+        field_nodes,
+        data_type,
+        &ipc_field.fields[0],
+        buffers,
+        variadic_buffer_counts,
+    )
 }
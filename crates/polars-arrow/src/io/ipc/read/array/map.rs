@@ -80,6 +80,7 @@ pub fn read_map<R: Read + Seek>(
 pub fn skip_map(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
     variadic_buffer_counts: &mut VecDeque<usize>,
 ) -> PolarsResult<()> {
@@ -98,5 +99,11 @@ pub fn skip_map(
 
     let data_type = MapArray::get_field(data_type).data_type();
 
-    skip(field_nodes, data_type, buffers, variadic_buffer_counts)
+    skip(
+        field_nodes,
+        data_type,
+        &ipc_field.fields[0],
+        buffers,
+        variadic_buffer_counts,
+    )
 }
@@ -27,7 +27,9 @@ mod binview;
 mod map;
 pub use binview::*;
 pub use map::*;
+mod run_end_encoded;
 use polars_error::{PolarsResult, *};
+pub use run_end_encoded::*;
 
 use super::{Compression, IpcBuffer, Node, OutOfSpecKind};
 use crate::datatypes::ArrowDataType;
@@ -0,0 +1,178 @@
+use std::collections::VecDeque;
+use std::io::{Read, Seek};
+
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsResult};
+
+use super::super::super::IpcField;
+use super::super::deserialize::{read, skip};
+use super::super::{Compression, Dictionaries, IpcBuffer, Node, Version};
+use super::{read_primitive, skip_primitive};
+use crate::array::growable::make_growable;
+use crate::array::Array;
+use crate::datatypes::{ArrowDataType, Field, IntegerType, PhysicalType};
+use crate::io::ipc::read::array::{try_get_array_length, try_get_field_node};
+use crate::types::NativeType;
+
+#[allow(clippy::too_many_arguments)]
+fn read_run_ends<T: NativeType + Into<i64>, R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    scratch: &mut Vec<u8>,
+) -> PolarsResult<Vec<usize>>
+where
+    Vec<u8>: TryInto<T::Bytes>,
+{
+    let run_ends = read_primitive::<T, _>(
+        field_nodes,
+        T::PRIMITIVE.into(),
+        buffers,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+        None,
+        scratch,
+    )?;
+    run_ends
+        .values()
+        .iter()
+        .map(|&end| {
+            usize::try_from(end.into())
+                .map_err(|_| polars_err!(oos = "IPC: RunEndEncoded run ends must be positive"))
+        })
+        .collect()
+}
+
+/// Reads a run-end encoded array and decodes it into an array of its values type.
+///
+/// Only the runs that are needed to fill `limit` are read from the values.
+#[allow(clippy::too_many_arguments)]
+pub fn read_run_end_encoded<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
+    field: &Field,
+    ipc_field: &IpcField,
+    run_ends: IntegerType,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    limit: Option<usize>,
+    version: Version,
+    scratch: &mut Vec<u8>,
+) -> PolarsResult<Box<dyn Array>> {
+    let field_node = try_get_field_node(field_nodes, &field.data_type)?;
+    let length = try_get_array_length(field_node, limit)?;
+
+    let run_ends = match run_ends {
+        IntegerType::Int16 => read_run_ends::<i16, _>(
+            field_nodes,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            scratch,
+        )?,
+        IntegerType::Int32 => read_run_ends::<i32, _>(
+            field_nodes,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            scratch,
+        )?,
+        IntegerType::Int64 => read_run_ends::<i64, _>(
+            field_nodes,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            scratch,
+        )?,
+        _ => polars_bail!(oos = "IPC: RunEndEncoded run ends must be Int16, Int32 or Int64"),
+    };
+
+    // Only the runs that start before `length` are needed.
+    let n_runs = if length == 0 {
+        0
+    } else {
+        (run_ends.partition_point(|&end| end < length) + 1).min(run_ends.len())
+    };
+
+    let values = read(
+        field_nodes,
+        variadic_buffer_counts,
+        field,
+        &ipc_field.fields[0],
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        Some(n_runs),
+        version,
+        scratch,
+    )?;
+    polars_ensure!(
+        values.len() == n_runs,
+        oos = "IPC: RunEndEncoded must have as many values as run ends"
+    );
+    if matches!(
+        values.data_type().to_physical_type(),
+        PhysicalType::Union | PhysicalType::Map
+    ) {
+        polars_bail!(nyi = "Reading run-end encoded arrays of unions or maps");
+    }
+
+    let mut growable = make_growable(&[values.as_ref()], values.null_count() > 0, length);
+    let mut start = 0;
+    for (i, &end) in run_ends[..n_runs].iter().enumerate() {
+        let end = end.min(length);
+        polars_ensure!(
+            end > start,
+            oos = "IPC: RunEndEncoded run ends must be strictly increasing"
+        );
+        // SAFETY: `i < n_runs == values.len()`.
+        unsafe { growable.extend_copies(0, i, 1, end - start) };
+        start = end;
+    }
+    polars_ensure!(
+        start == length,
+        oos = "IPC: RunEndEncoded run ends must cover the length of the array"
+    );
+
+    Ok(growable.as_box())
+}
+
+pub fn skip_run_end_encoded(
+    field_nodes: &mut VecDeque<Node>,
+    data_type: &ArrowDataType,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
+) -> PolarsResult<()> {
+    let _ = field_nodes.pop_front().ok_or_else(|| {
+        polars_err!(
+            oos = "IPC: unable to fetch the field for run-end encoded. The file or stream is corrupted."
+        )
+    })?;
+
+    skip_primitive(field_nodes, buffers)?;
+    skip(
+        field_nodes,
+        data_type,
+        &ipc_field.fields[0],
+        buffers,
+        variadic_buffer_counts,
+    )
+}
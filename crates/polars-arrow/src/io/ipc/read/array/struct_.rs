@@ -70,6 +70,7 @@ pub fn read_struct<R: Read + Seek>(
 pub fn skip_struct(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
     variadic_buffer_counts: &mut VecDeque<usize>,
 ) -> PolarsResult<()> {
@@ -85,12 +86,16 @@ pub fn skip_struct(
 
     let fields = StructArray::get_fields(data_type);
 
-    fields.iter().try_for_each(|field| {
-        skip(
-            field_nodes,
-            field.data_type(),
-            buffers,
-            variadic_buffer_counts,
-        )
-    })
+    fields
+        .iter()
+        .zip(ipc_field.fields.iter())
+        .try_for_each(|(field, ipc_field)| {
+            skip(
+                field_nodes,
+                field.data_type(),
+                ipc_field,
+                buffers,
+                variadic_buffer_counts,
+            )
+        })
 }
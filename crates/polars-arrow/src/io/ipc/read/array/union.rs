@@ -96,6 +96,7 @@ pub fn read_union<R: Read + Seek>(
 pub fn skip_union(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
     variadic_buffer_counts: &mut VecDeque<usize>,
 ) -> PolarsResult<()> {
@@ -118,12 +119,16 @@ pub fn skip_union(
 
     let fields = UnionArray::get_fields(data_type);
 
-    fields.iter().try_for_each(|field| {
-        skip(
-            field_nodes,
-            field.data_type(),
-            buffers,
-            variadic_buffer_counts,
-        )
-    })
+    fields
+        .iter()
+        .zip(ipc_field.fields.iter())
+        .try_for_each(|(field, ipc_field)| {
+            skip(
+                field_nodes,
+                field.data_type(),
+                ipc_field,
+                buffers,
+                variadic_buffer_counts,
+            )
+        })
 }
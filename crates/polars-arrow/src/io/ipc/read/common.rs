@@ -148,10 +148,11 @@ pub fn read_record_batch<R: Read + Seek>(
                     version,
                     scratch,
                 )?)),
-                ProjectionResult::NotSelected((field, _)) => {
+                ProjectionResult::NotSelected((field, ipc_field)) => {
                     skip(
                         &mut field_nodes,
                         &field.data_type,
+                        ipc_field,
                         &mut buffers,
                         &mut variadic_buffer_counts,
                     )?;
@@ -221,6 +222,10 @@ fn find_first_dict_field<'a>(
             return Some((field, ipc_field));
         }
     }
+    if ipc_field.run_ends.is_some() {
+        // The field of run-end encoded values has the same data type as the field itself.
+        return find_first_dict_field(id, field, &ipc_field.fields[0]);
+    }
     find_first_dict_field_d(id, &field.data_type, ipc_field)
 }
 
@@ -28,6 +28,24 @@ pub fn read<R: Read + Seek>(
     scratch: &mut Vec<u8>,
 ) -> PolarsResult<Box<dyn Array>> {
     use PhysicalType::*;
+    if let Some(run_ends) = ipc_field.run_ends {
+        return read_run_end_encoded(
+            field_nodes,
+            variadic_buffer_counts,
+            field,
+            ipc_field,
+            run_ends,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            limit,
+            version,
+            scratch,
+        );
+    }
     let data_type = field.data_type.clone();
 
     match data_type.to_physical_type() {
@@ -262,10 +280,20 @@ pub fn read<R: Read + Seek>(
 pub fn skip(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
     variadic_buffer_counts: &mut VecDeque<usize>,
 ) -> PolarsResult<()> {
     use PhysicalType::*;
+    if ipc_field.run_ends.is_some() {
+        return skip_run_end_encoded(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            variadic_buffer_counts,
+        );
+    }
     match data_type.to_physical_type() {
         Null => skip_null(field_nodes),
         Boolean => skip_boolean(field_nodes, buffers),
@@ -273,15 +301,49 @@ pub fn skip(
         LargeBinary | Binary => skip_binary(field_nodes, buffers),
         LargeUtf8 | Utf8 => skip_utf8(field_nodes, buffers),
         FixedSizeBinary => skip_fixed_size_binary(field_nodes, buffers),
-        List => skip_list::<i32>(field_nodes, data_type, buffers, variadic_buffer_counts),
-        LargeList => skip_list::<i64>(field_nodes, data_type, buffers, variadic_buffer_counts),
-        FixedSizeList => {
-            skip_fixed_size_list(field_nodes, data_type, buffers, variadic_buffer_counts)
-        },
-        Struct => skip_struct(field_nodes, data_type, buffers, variadic_buffer_counts),
+        List => skip_list::<i32>(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            variadic_buffer_counts,
+        ),
+        LargeList => skip_list::<i64>(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            variadic_buffer_counts,
+        ),
+        FixedSizeList => skip_fixed_size_list(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            variadic_buffer_counts,
+        ),
+        Struct => skip_struct(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            variadic_buffer_counts,
+        ),
         Dictionary(_) => skip_dictionary(field_nodes, buffers),
-        Union => skip_union(field_nodes, data_type, buffers, variadic_buffer_counts),
-        Map => skip_map(field_nodes, data_type, buffers, variadic_buffer_counts),
+        Union => skip_union(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            variadic_buffer_counts,
+        ),
+        Map => skip_map(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            variadic_buffer_counts,
+        ),
         BinaryView | Utf8View => skip_binview(field_nodes, buffers, variadic_buffer_counts),
     }
 }
@@ -11,7 +11,7 @@ use super::common::*;
 use super::schema::fb_to_schema;
 use super::{Dictionaries, OutOfSpecKind};
 use crate::array::Array;
-use crate::datatypes::ArrowSchemaRef;
+use crate::datatypes::{ArrowSchemaRef, Metadata};
 use crate::io::ipc::IpcSchema;
 use crate::record_batch::RecordBatchT;
 
@@ -34,6 +34,9 @@ pub struct FileMetadata {
 
     /// The total size of the file in bytes
     pub size: u64,
+
+    /// The custom metadata in the file footer
+    pub custom_metadata: Option<Metadata>,
 }
 
 /// Read the row count by summing the length of the of the record batches
@@ -211,12 +214,36 @@ pub fn deserialize_footer(footer_data: &[u8], size: u64) -> PolarsResult<FileMet
         })
         .transpose()?;
 
+    let custom_metadata = footer
+        .custom_metadata()
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferFooter(err)))?
+        .map(|kv_list| {
+            let mut metadata = Metadata::default();
+            for kv in kv_list {
+                let kv = kv.map_err(|err| {
+                    polars_err!(oos = OutOfSpecKind::InvalidFlatbufferFooter(err))
+                })?;
+                let key = kv.key().map_err(|err| {
+                    polars_err!(oos = OutOfSpecKind::InvalidFlatbufferFooter(err))
+                })?;
+                let value = kv.value().map_err(|err| {
+                    polars_err!(oos = OutOfSpecKind::InvalidFlatbufferFooter(err))
+                })?;
+                if let (Some(key), Some(value)) = (key, value) {
+                    metadata.insert(key.to_string(), value.to_string());
+                }
+            }
+            PolarsResult::Ok(metadata)
+        })
+        .transpose()?;
+
     Ok(FileMetadata {
         schema: Arc::new(schema),
         ipc_schema,
         blocks,
         dictionaries,
         size,
+        custom_metadata,
     })
 }
 
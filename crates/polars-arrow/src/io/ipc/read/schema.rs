@@ -126,6 +126,7 @@ fn deserialize_union(union_: UnionRef, field: FieldRef) -> PolarsResult<(ArrowDa
     let ipc_field = IpcField {
         fields: ipc_fields,
         dictionary_id: None,
+        run_ends: None,
     };
     Ok((ArrowDataType::Union(fields, ids, mode), ipc_field))
 }
@@ -147,6 +148,7 @@ fn deserialize_map(map: MapRef, field: FieldRef) -> PolarsResult<(ArrowDataType,
         IpcField {
             fields: vec![ipc_field],
             dictionary_id: None,
+            run_ends: None,
         },
     ))
 }
@@ -165,6 +167,7 @@ fn deserialize_struct(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcField)
     let ipc_field = IpcField {
         fields: ipc_fields,
         dictionary_id: None,
+        run_ends: None,
     };
     Ok((ArrowDataType::Struct(fields), ipc_field))
 }
@@ -183,6 +186,7 @@ fn deserialize_list(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcField)>
         IpcField {
             fields: vec![ipc_field],
             dictionary_id: None,
+            run_ends: None,
         },
     ))
 }
@@ -201,6 +205,7 @@ fn deserialize_large_list(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcFi
         IpcField {
             fields: vec![ipc_field],
             dictionary_id: None,
+            run_ends: None,
         },
     ))
 }
@@ -227,6 +232,41 @@ fn deserialize_fixed_size_list(
         IpcField {
             fields: vec![ipc_field],
             dictionary_id: None,
+            run_ends: None,
+        },
+    ))
+}
+
+/// Run-end encoded fields are decoded on read. The returned data type is the one of the
+/// values and the run-end type is recorded in the [`IpcField`].
+fn deserialize_run_end_encoded(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcField)> {
+    let children = field
+        .children()?
+        .ok_or_else(|| polars_err!(oos = "IPC: RunEndEncoded must contain children"))?;
+    let run_ends = children
+        .get(0)
+        .ok_or_else(|| polars_err!(oos = "IPC: RunEndEncoded must contain run ends"))??;
+    let values = children
+        .get(1)
+        .ok_or_else(|| polars_err!(oos = "IPC: RunEndEncoded must contain values"))??;
+
+    let (run_ends, _) = deserialize_field(run_ends)?;
+    let run_ends = match run_ends.data_type {
+        ArrowDataType::Int16 => IntegerType::Int16,
+        ArrowDataType::Int32 => IntegerType::Int32,
+        ArrowDataType::Int64 => IntegerType::Int64,
+        dt => polars_bail!(
+            oos = format!("IPC: RunEndEncoded run ends must be Int16, Int32 or Int64, got {dt:?}")
+        ),
+    };
+    let (values, ipc_field) = deserialize_field(values)?;
+
+    Ok((
+        values.data_type,
+        IpcField {
+            fields: vec![ipc_field],
+            dictionary_id: None,
+            run_ends: Some(run_ends),
         },
     ))
 }
@@ -270,7 +310,12 @@ fn get_data_type(
         Null(_) => (ArrowDataType::Null, IpcField::default()),
         Bool(_) => (ArrowDataType::Boolean, IpcField::default()),
         Int(int) => {
-            let data_type = deserialize_integer(int)?.into();
+            // 128-bit integers are not part of the IPC format, but are written by polars.
+            let data_type = match (int.bit_width()?, int.is_signed()?) {
+                (128, true) => ArrowDataType::Int128,
+                (128, false) => ArrowDataType::UInt128,
+                _ => deserialize_integer(int)?.into(),
+            };
             (data_type, IpcField::default())
         },
         Binary(_) => (ArrowDataType::Binary, IpcField::default()),
@@ -351,7 +396,7 @@ fn get_data_type(
         Struct(_) => deserialize_struct(field)?,
         Union(union_) => deserialize_union(union_, field)?,
         Map(map) => deserialize_map(map, field)?,
-        RunEndEncoded(_) => todo!(),
+        RunEndEncoded(_) => deserialize_run_end_encoded(field)?,
         LargeListView(_) | ListView(_) => todo!(),
     })
 }
@@ -157,6 +157,10 @@ fn serialize_type(data_type: &ArrowDataType) -> arrow_format::ipc::Type {
             bit_width: 64,
             is_signed: false,
         })),
+        UInt128 => ipc::Type::Int(Box::new(ipc::Int {
+            bit_width: 128,
+            is_signed: false,
+        })),
         Int8 => ipc::Type::Int(Box::new(ipc::Int {
             bit_width: 8,
             is_signed: true,
@@ -173,6 +177,10 @@ fn serialize_type(data_type: &ArrowDataType) -> arrow_format::ipc::Type {
             bit_width: 64,
             is_signed: true,
         })),
+        Int128 => ipc::Type::Int(Box::new(ipc::Int {
+            bit_width: 128,
+            is_signed: true,
+        })),
         Float16 => ipc::Type::FloatingPoint(Box::new(ipc::FloatingPoint {
             precision: ipc::Precision::Half,
         })),
@@ -263,10 +271,12 @@ fn serialize_children(
         | Int16
         | Int32
         | Int64
+        | Int128
         | UInt8
         | UInt16
         | UInt32
         | UInt64
+        | UInt128
         | Float16
         | Float32
         | Float64
@@ -40,6 +40,8 @@ pub struct FileWriter<W: Write> {
     pub(crate) dictionary_tracker: DictionaryTracker,
     /// Buffer/scratch that is reused between writes
     pub(crate) encoded_message: EncodedData,
+    /// Custom metadata written as part of the IPC footer
+    pub(crate) custom_metadata: Option<Metadata>,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -83,6 +85,7 @@ impl<W: Write> FileWriter<W> {
                 cannot_replace: true,
             },
             encoded_message: Default::default(),
+            custom_metadata: None,
         }
     }
 
@@ -102,6 +105,12 @@ impl<W: Write> FileWriter<W> {
         self.encoded_message = scratches;
     }
 
+    /// Set the custom metadata that is written in the footer of the file. Unlike the metadata of
+    /// the schema, this can be set after the record batches have been written.
+    pub fn set_custom_metadata(&mut self, custom_metadata: Metadata) {
+        self.custom_metadata = Some(custom_metadata);
+    }
+
     /// Writes the header and first (schema) message to the file.
     /// # Errors
     /// Errors if the file has been started or has finished.
@@ -194,7 +203,15 @@ impl<W: Write> FileWriter<W> {
             schema: Some(Box::new(schema)),
             dictionaries: Some(std::mem::take(&mut self.dictionary_blocks)),
             record_batches: Some(std::mem::take(&mut self.record_blocks)),
-            custom_metadata: None,
+            custom_metadata: self.custom_metadata.as_ref().map(|metadata| {
+                metadata
+                    .iter()
+                    .map(|(key, value)| arrow_format::ipc::KeyValue {
+                        key: Some(key.clone()),
+                        value: Some(value.clone()),
+                    })
+                    .collect()
+            }),
         };
         let mut builder = Builder::new();
         let footer_data = builder.finish(&root, None);
@@ -10,6 +10,8 @@ use polars_error::{polars_bail, PolarsError};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Ambiguous {
     Earliest,
     Latest,
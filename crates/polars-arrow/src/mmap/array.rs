@@ -522,6 +522,9 @@ fn get_array<T: AsRef<[u8]>>(
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> PolarsResult<ArrowArray> {
     use crate::datatypes::PhysicalType::*;
+    if ipc_field.run_ends.is_some() {
+        polars_bail!(nyi = "Memory mapping run-end encoded arrays");
+    }
     let node = field_nodes.pop_front().ok_or_else(
         || polars_err!(ComputeError: "out-of-spec: {:?}", OutOfSpecKind::ExpectedBuffer),
     )?;
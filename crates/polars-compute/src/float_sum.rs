@@ -102,24 +102,31 @@ where
     }
 }
 
-#[cfg(feature = "simd")]
-impl<F> SumBlock<F> for [i128; PAIRWISE_RECURSION_LIMIT]
-where
-    i128: AsPrimitive<F>,
-    F: Float + std::iter::Sum + 'static,
-{
-    fn sum_block_vectorized(&self) -> F {
-        self.iter().map(|x| x.as_()).sum()
-    }
+macro_rules! impl_sum_block_scalar {
+    ($T:ty) => {
+        #[cfg(feature = "simd")]
+        impl<F> SumBlock<F> for [$T; PAIRWISE_RECURSION_LIMIT]
+        where
+            $T: AsPrimitive<F>,
+            F: Float + std::iter::Sum + 'static,
+        {
+            fn sum_block_vectorized(&self) -> F {
+                self.iter().map(|x| x.as_()).sum()
+            }
 
-    fn sum_block_vectorized_with_mask(&self, mask: BitMask<'_>) -> F {
-        self.iter()
-            .enumerate()
-            .map(|(idx, x)| if mask.get(idx) { x.as_() } else { F::zero() })
-            .sum()
-    }
+            fn sum_block_vectorized_with_mask(&self, mask: BitMask<'_>) -> F {
+                self.iter()
+                    .enumerate()
+                    .map(|(idx, x)| if mask.get(idx) { x.as_() } else { F::zero() })
+                    .sum()
+            }
+        }
+    };
 }
 
+impl_sum_block_scalar!(i128);
+impl_sum_block_scalar!(u128);
+
 #[cfg(not(feature = "simd"))]
 impl<T, F> SumBlock<F> for [T; PAIRWISE_RECURSION_LIMIT]
 where
@@ -1,13 +1,73 @@
+//! Arithmetic on decimals and the precision and scale of their results.
 use super::*;
 
+/// The maximum precision a decimal, backed by an `i128`, can represent.
+const MAX_PRECISION: usize = 38;
+
+fn cap_precision(precision: usize) -> Option<usize> {
+    Some(precision.min(MAX_PRECISION))
+}
+
+/// The precision and scale of the sum or difference of decimals with the given precision and
+/// scale. A precision of `None` means the maximum precision.
+pub fn add_dtype(
+    (lhs_precision, lhs_scale): (Option<usize>, usize),
+    (rhs_precision, rhs_scale): (Option<usize>, usize),
+) -> (Option<usize>, usize) {
+    let scale = lhs_scale.max(rhs_scale);
+    let precision = match (lhs_precision, rhs_precision) {
+        (Some(lp), Some(rp)) => {
+            // One extra integer digit for the carry.
+            let int_digits = lp
+                .saturating_sub(lhs_scale)
+                .max(rp.saturating_sub(rhs_scale));
+            cap_precision(int_digits + scale + 1)
+        },
+        _ => None,
+    };
+    (precision, scale)
+}
+
+/// The precision and scale of the product of decimals with the given precision and scale.
+pub fn mul_dtype(
+    (lhs_precision, lhs_scale): (Option<usize>, usize),
+    (rhs_precision, rhs_scale): (Option<usize>, usize),
+) -> (Option<usize>, usize) {
+    let scale = lhs_scale + rhs_scale;
+    let precision = match (lhs_precision, rhs_precision) {
+        (Some(lp), Some(rp)) => cap_precision(lp + rp),
+        _ => None,
+    };
+    (precision, scale)
+}
+
+/// The precision and scale of the quotient of decimals with the given precision and scale.
+///
+/// Like postgres and MySQL, the scale of the result is the scale of the dividend plus 4.
+pub fn div_dtype(
+    (lhs_precision, lhs_scale): (Option<usize>, usize),
+    (_, rhs_scale): (Option<usize>, usize),
+) -> (Option<usize>, usize) {
+    let scale = lhs_scale + 4;
+    // Dividing by the smallest non-zero divisor, `10^-rhs_scale`, adds `rhs_scale` integer
+    // digits to the dividend.
+    let precision = lhs_precision
+        .and_then(|lp| cap_precision(lp.saturating_sub(lhs_scale) + rhs_scale + scale));
+    (precision, scale)
+}
+
+fn precision_scale(ca: &DecimalChunked) -> (Option<usize>, usize) {
+    (ca.precision(), ca.scale())
+}
+
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let scale = self.scale().max(rhs.scale());
+        let (precision, scale) = add_dtype(precision_scale(self), precision_scale(rhs));
         let lhs = self.to_scale(scale)?;
         let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 + &rhs.0).into_decimal_unchecked(None, scale))
+        Ok((&lhs.0 + &rhs.0).into_decimal_unchecked(precision, scale))
     }
 }
 
@@ -15,10 +75,10 @@ impl Sub for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let scale = self.scale().max(rhs.scale());
+        let (precision, scale) = add_dtype(precision_scale(self), precision_scale(rhs));
         let lhs = self.to_scale(scale)?;
         let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 - &rhs.0).into_decimal_unchecked(None, scale))
+        Ok((&lhs.0 - &rhs.0).into_decimal_unchecked(precision, scale))
     }
 }
 
@@ -26,8 +86,8 @@ impl Mul for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let scale = self.scale() + rhs.scale();
-        Ok((&self.0 * &rhs.0).into_decimal_unchecked(None, scale))
+        let (precision, scale) = mul_dtype(precision_scale(self), precision_scale(rhs));
+        Ok((&self.0 * &rhs.0).into_decimal_unchecked(precision, scale))
     }
 }
 
@@ -35,9 +95,8 @@ impl Div for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn div(self, rhs: Self) -> Self::Output {
-        // Follow postgres and MySQL adding a fixed scale increment of 4
-        let scale = self.scale() + 4;
+        let (precision, scale) = div_dtype(precision_scale(self), precision_scale(rhs));
         let lhs = self.to_scale(scale + rhs.scale())?;
-        Ok((&lhs.0 / &rhs.0).into_decimal_unchecked(None, scale))
+        Ok((&lhs.0 / &rhs.0).into_decimal_unchecked(precision, scale))
     }
 }
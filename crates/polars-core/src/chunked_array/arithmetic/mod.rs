@@ -1,6 +1,6 @@
 //! Implementations of arithmetic operations on ChunkedArray's.
 #[cfg(feature = "dtype-decimal")]
-mod decimal;
+pub mod decimal;
 mod numeric;
 
 use std::ops::{Add, Div, Mul, Rem, Sub};
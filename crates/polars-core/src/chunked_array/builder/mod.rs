@@ -185,6 +185,40 @@ mod test {
         assert_eq!(Vec::from(&ca), values);
     }
 
+    #[test]
+    fn test_primitive_builder_extend() {
+        let mut builder = PrimitiveChunkedBuilder::<Int32Type>::new("foo", 0);
+        builder.extend_values([1, 2]);
+        builder.extend_with_validity_runs(&[3, 0, 0, 4], [(true, 1), (false, 2), (true, 1)]);
+        builder.extend_constant(None, 1);
+        builder.extend_options([Some(5), None]);
+        builder.extend_constant(Some(6), 2);
+        let ca = builder.finish();
+        assert_eq!(
+            Vec::from(&ca),
+            &[
+                Some(1),
+                Some(2),
+                Some(3),
+                None,
+                None,
+                Some(4),
+                None,
+                Some(5),
+                None,
+                Some(6),
+                Some(6)
+            ]
+        );
+        assert_eq!(ca.null_count(), 4);
+
+        // Only valid runs don't allocate a validity.
+        let mut builder = PrimitiveChunkedBuilder::<Int32Type>::new("foo", 0);
+        builder.extend_with_validity_runs(&[1, 2, 3], [(true, 2), (true, 1)]);
+        let ca = builder.finish();
+        assert!(ca.downcast_iter().next().unwrap().validity().is_none());
+    }
+
     #[test]
     fn test_list_builder() {
         let mut builder =
@@ -48,4 +48,41 @@ where
             field: Field::new(name, T::get_dtype()),
         }
     }
+
+    /// Appends all values of an iterator, reserving by its size hint up front.
+    pub fn extend_values<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = T::Native>,
+    {
+        self.array_builder.extend_values(values)
+    }
+
+    /// Appends all optional values of an iterator, reserving by its size hint up front.
+    pub fn extend_options<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = Option<T::Native>>,
+    {
+        let values = values.into_iter();
+        self.array_builder.reserve(values.size_hint().0);
+        values.for_each(|opt| self.array_builder.push(opt))
+    }
+
+    /// Appends `value` `n` times.
+    pub fn extend_constant(&mut self, value: Option<T::Native>, n: usize) {
+        self.array_builder.extend_constant(n, value)
+    }
+
+    /// Appends a slice of values of which the validity is given as runs of `(is_valid, length)`
+    /// pairs, like parsers that produce stretches of valid and missing values. This doesn't
+    /// branch per value. The values at the null slots are ignored.
+    ///
+    /// # Panics
+    /// Panics if the lengths of the runs don't sum to the length of `values`.
+    pub fn extend_with_validity_runs<I>(&mut self, values: &[T::Native], runs: I)
+    where
+        I: IntoIterator<Item = (bool, usize)>,
+    {
+        self.array_builder
+            .extend_from_slice_with_validity_runs(values, runs)
+    }
 }
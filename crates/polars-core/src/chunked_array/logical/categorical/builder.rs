@@ -192,6 +192,11 @@ impl CategoricalChunkedBuilder {
         }
     }
 
+    /// The categories that were registered so far, in order of their index.
+    pub(super) fn into_categories(self) -> Utf8ViewArray {
+        self.categories.into()
+    }
+
     pub fn finish(self) -> CategoricalChunked {
         // SAFETY: keys and values are in bounds
         unsafe {
@@ -87,6 +87,121 @@ impl GlobalRevMapMerger {
     }
 }
 
+/// Merges the rev-maps of many local categoricals into a single rev-map, e.g. when the results
+/// of a scan over many files are concatenated.
+///
+/// Appending local categoricals one by one rebuilds a hashmap of all categories seen so far for
+/// every append. This merger interns every category only once and re-encodes the physicals of
+/// each input with a lookup table.
+pub struct LocalRevMapMerger {
+    builder: CategoricalChunkedBuilder,
+    // The hash of the first merged rev-map. Its categories get the same index in the merged
+    // rev-map, so categoricals with the same rev-map don't need to be re-encoded.
+    first_hash: Option<u128>,
+    remapped: bool,
+}
+
+impl Default for LocalRevMapMerger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalRevMapMerger {
+    pub fn new() -> Self {
+        LocalRevMapMerger {
+            builder: CategoricalChunkedBuilder::new("", 0, Default::default()),
+            first_hash: None,
+            remapped: false,
+        }
+    }
+
+    /// Add the categories of `ca` to the merged rev-map and return the physicals of `ca` encoded
+    /// for the merged rev-map.
+    pub fn merge(&mut self, ca: &CategoricalChunked) -> PolarsResult<UInt32Chunked> {
+        polars_ensure!(
+            !ca.is_enum(),
+            ComputeError: "can not merge incompatible Enum types"
+        );
+        let RevMapping::Local(categories, hash) = &**ca.get_rev_map() else {
+            polars_bail!(string_cache_mismatch)
+        };
+        if self.first_hash == Some(*hash) {
+            return Ok(ca.physical().clone());
+        }
+        self.first_hash.get_or_insert(*hash);
+
+        let mut is_identity = true;
+        let idx_mapping = categories
+            .values_iter()
+            .enumerate()
+            .map(|(idx, s)| {
+                let new_idx = self.builder.register_value(s).0;
+                is_identity &= new_idx == idx as u32;
+                new_idx
+            })
+            .collect::<Vec<_>>();
+        if is_identity {
+            return Ok(ca.physical().clone());
+        }
+
+        if !self.remapped {
+            self.remapped = true;
+            polars_warn!(
+                CategoricalRemappingWarning,
+                "Local categoricals have different encodings, re-encoding is done \
+            to perform this merge operation. Consider using a StringCache or an Enum type \
+            if the categories are known in advance"
+            );
+        }
+        // Null slots may hold any value, so they can be out of bounds.
+        Ok(ca
+            .physical()
+            .apply_values(|v| idx_mapping.get(v as usize).copied().unwrap_or_default()))
+    }
+
+    pub fn finish(self) -> Arc<RevMapping> {
+        Arc::new(RevMapping::build_local(self.builder.into_categories()))
+    }
+}
+
+/// Give the local categoricals in `cas` the same rev-map, so that they can be appended without
+/// re-encoding.
+///
+/// Returns `None` if not all categoricals are non-enum local categoricals.
+pub fn make_local_categoricals_compatible(
+    cas: &[&CategoricalChunked],
+) -> PolarsResult<Option<Vec<CategoricalChunked>>> {
+    if !cas
+        .iter()
+        .all(|ca| ca.get_rev_map().is_local() && !ca.is_enum())
+    {
+        return Ok(None);
+    }
+    let mut merger = LocalRevMapMerger::new();
+    let physicals = cas
+        .iter()
+        .map(|ca| merger.merge(ca))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let rev_map = merger.finish();
+    let out = cas
+        .iter()
+        .zip(physicals)
+        .map(|(ca, physical)| {
+            // SAFETY: the physicals were encoded for the merged rev-map.
+            unsafe {
+                CategoricalChunked::from_cats_and_rev_map_unchecked(
+                    physical,
+                    rev_map.clone(),
+                    false,
+                    ca.get_ordering(),
+                )
+            }
+        })
+        .collect();
+    Ok(Some(out))
+}
+
 fn merge_local_rhs_categorical<'a>(
     categories: &'a Utf8ViewArray,
     ca_right: &'a CategoricalChunked,
@@ -112,21 +227,23 @@ fn merge_local_rhs_categorical<'a>(
             .map(|(k, v)| (v, k as u32)),
     );
     let mut new_categories = slots_to_mut(categories);
-    let mut idx_mapping = PlHashMap::with_capacity(cats_right.len());
-
-    for (idx, s) in cats_right.values_iter().enumerate() {
-        if let Some(v) = cats_left_hashmap.get(&s) {
-            idx_mapping.insert(idx as u32, *v);
-        } else {
-            idx_mapping.insert(idx as u32, new_categories.len() as u32);
-            new_categories.push(Some(s));
-        }
-    }
+    // The physicals are dense, so they directly index the mapping.
+    let idx_mapping = cats_right
+        .values_iter()
+        .map(|s| match cats_left_hashmap.get(&s) {
+            Some(v) => *v,
+            None => {
+                new_categories.push(Some(s));
+                new_categories.len() as u32 - 1
+            },
+        })
+        .collect::<Vec<_>>();
     let new_rev_map = Arc::new(RevMapping::build_local(new_categories.into()));
     Ok((
+        // Null slots may hold any value, so they can be out of bounds.
         ca_right
             .physical
-            .apply(|opt_v| opt_v.map(|v| *idx_mapping.get(&v).unwrap())),
+            .apply_values(|v| idx_mapping.get(v as usize).copied().unwrap_or_default()),
         new_rev_map,
     ))
 }
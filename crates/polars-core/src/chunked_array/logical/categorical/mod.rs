@@ -147,14 +147,50 @@ impl CategoricalChunked {
             },
             _ => (),
         };
-        // Make a mapping from old idx to new idx
         let old_rev_map = self.get_rev_map();
+        // If the categories are only extended, the physicals stay the same.
+        if let RevMapping::Local(old_categories, _) = old_rev_map.as_ref() {
+            if old_categories.len() <= categories.len()
+                && old_categories
+                    .values_iter()
+                    .zip(categories.values_iter())
+                    .all(|(l, r)| l == r)
+            {
+                // SAFETY: the old categories have the same index in the new categories.
+                return unsafe {
+                    CategoricalChunked::from_cats_and_rev_map_unchecked(
+                        self.physical().clone(),
+                        Arc::new(RevMapping::Local(categories.clone(), hash)),
+                        true,
+                        self.get_ordering(),
+                    )
+                };
+            }
+        }
+
+        // Make a mapping from old idx to new idx
         #[allow(clippy::unnecessary_cast)]
-        let idx_map: PlHashMap<u32, u32> = categories
+        let new_idx_of: PlHashMap<&str, u32> = categories
             .values_iter()
             .enumerate_idx()
-            .filter_map(|(new_idx, s)| old_rev_map.find(s).map(|old_idx| (old_idx, new_idx as u32)))
+            .map(|(new_idx, s)| (s, new_idx as u32))
             .collect();
+        let idx_map: PlHashMap<u32, u32> = match old_rev_map.as_ref() {
+            RevMapping::Global(map, old_categories, _) => map
+                .iter()
+                .filter_map(|(old_idx, cat_idx)| {
+                    let s = old_categories.value(*cat_idx as usize);
+                    new_idx_of.get(s).map(|new_idx| (*old_idx, *new_idx))
+                })
+                .collect(),
+            RevMapping::Local(old_categories, _) => old_categories
+                .values_iter()
+                .enumerate()
+                .filter_map(|(old_idx, s)| {
+                    new_idx_of.get(s).map(|new_idx| (old_idx as u32, *new_idx))
+                })
+                .collect(),
+        };
 
         // Loop over the physicals and try get new idx
         let new_phys: UInt32Chunked = self
@@ -542,4 +578,44 @@ mod test {
         assert_eq!(vals, &["a", "b", "c"]);
         Ok(())
     }
+
+    #[test]
+    fn test_concat_local_categoricals() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+
+        let dfs = [
+            vec![Some("a"), Some("b")],
+            vec![Some("a"), Some("b")],
+            vec![Some("c"), None, Some("a")],
+            vec![None, None],
+        ]
+        .into_iter()
+        .map(|values| {
+            let s =
+                Series::new("a", values).cast(&DataType::Categorical(None, Default::default()))?;
+            DataFrame::new(vec![s])
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+        let out = crate::utils::concat_df(&dfs)?;
+        let ca = out.column("a")?.categorical()?;
+        assert_eq!(ca.get_rev_map().len(), 3);
+        let vals = ca.iter_str().collect::<Vec<_>>();
+        assert_eq!(
+            vals,
+            &[
+                Some("a"),
+                Some("b"),
+                Some("a"),
+                Some("b"),
+                Some("c"),
+                None,
+                Some("a"),
+                None,
+                None
+            ]
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,127 @@
+use super::*;
+
+fn local_categories<'a>(rev_map: &'a RevMapping, op: &str) -> PolarsResult<&'a Utf8ViewArray> {
+    match rev_map {
+        RevMapping::Local(categories, _) => Ok(categories),
+        RevMapping::Global(_, _, _) => polars_bail!(
+            InvalidOperation: "'{}' is not supported for categoricals of the global string cache", op
+        ),
+    }
+}
+
+fn category_index(categories: &Utf8ViewArray) -> PlHashMap<&str, u32> {
+    categories
+        .values_iter()
+        .enumerate()
+        .map(|(idx, s)| (s, idx as u32))
+        .collect()
+}
+
+impl RevMapping {
+    /// A local [`RevMapping`] with `categories` appended. Categories that are present already
+    /// keep their position.
+    pub fn add_categories(&self, categories: &[String]) -> PolarsResult<RevMapping> {
+        let current = local_categories(self, "add_categories")?;
+        let mut index = category_index(current);
+        let mut new_categories = current.clone().make_mut();
+        for s in categories {
+            if !index.contains_key(s.as_str()) {
+                index.insert(s.as_str(), new_categories.len() as u32);
+                new_categories.push(Some(s.as_str()));
+            }
+        }
+        Ok(RevMapping::build_local(new_categories.into()))
+    }
+
+    /// A local [`RevMapping`] with the categories in the order of `categories`, together with the
+    /// new index of every current category. `categories` must contain every current category
+    /// exactly once.
+    pub fn reorder_categories(
+        &self,
+        categories: &[String],
+    ) -> PolarsResult<(RevMapping, Vec<u32>)> {
+        let current = local_categories(self, "reorder")?;
+        polars_ensure!(
+            categories.len() == current.len(),
+            InvalidOperation: "'reorder' expects all {} categories, got {}", current.len(), categories.len()
+        );
+        let index = category_index(current);
+        let mut idx_mapping = vec![u32::MAX; current.len()];
+        for (new_idx, s) in categories.iter().enumerate() {
+            let Some(&old_idx) = index.get(s.as_str()) else {
+                polars_bail!(InvalidOperation: "'reorder' got unknown category '{}'", s)
+            };
+            polars_ensure!(
+                idx_mapping[old_idx as usize] == u32::MAX,
+                InvalidOperation: "'reorder' got duplicate category '{}'", s
+            );
+            idx_mapping[old_idx as usize] = new_idx as u32;
+        }
+        let new_categories = Utf8ViewArray::from_slice_values(categories);
+        Ok((RevMapping::build_local(new_categories), idx_mapping))
+    }
+}
+
+impl CategoricalChunked {
+    fn with_local_rev_map(&self, physical: UInt32Chunked, rev_map: RevMapping) -> Self {
+        // SAFETY: the callers only create rev-maps that are valid for the physicals.
+        unsafe {
+            CategoricalChunked::from_cats_and_rev_map_unchecked(
+                physical,
+                Arc::new(rev_map),
+                self.is_enum(),
+                self.get_ordering(),
+            )
+        }
+    }
+
+    /// Append `categories` to the categories, without changing the values.
+    pub fn add_categories(&self, categories: &[String]) -> PolarsResult<Self> {
+        let rev_map = self.get_rev_map().add_categories(categories)?;
+        Ok(self.with_local_rev_map(self.physical().clone(), rev_map))
+    }
+
+    /// Remove the categories that don't occur in the values. The remaining categories keep their
+    /// order.
+    pub fn remove_unused_categories(&self) -> PolarsResult<Self> {
+        let current = local_categories(self.get_rev_map(), "remove_unused")?;
+        let mut used = vec![false; current.len()];
+        for arr in self.physical().downcast_iter() {
+            for idx in arr.iter().flatten() {
+                used[*idx as usize] = true;
+            }
+        }
+        if used.iter().all(|used| *used) {
+            return Ok(self.clone());
+        }
+
+        let mut new_categories = MutablePlString::with_capacity(current.len());
+        let idx_mapping = current
+            .values_iter()
+            .zip(&used)
+            .map(|(s, used)| {
+                let new_idx = new_categories.len() as u32;
+                if *used {
+                    new_categories.push(Some(s));
+                }
+                new_idx
+            })
+            .collect::<Vec<_>>();
+        // Null slots may hold any value, so they can be out of bounds.
+        let physical = self
+            .physical()
+            .apply_values(|v| idx_mapping.get(v as usize).copied().unwrap_or_default());
+        Ok(self.with_local_rev_map(physical, RevMapping::build_local(new_categories.into())))
+    }
+
+    /// Change the order of the categories to the order of `categories`, which must contain every
+    /// category exactly once. This changes the order in which the values are sorted with the
+    /// physical ordering.
+    pub fn reorder_categories(&self, categories: &[String]) -> PolarsResult<Self> {
+        let (rev_map, idx_mapping) = self.get_rev_map().reorder_categories(categories)?;
+        let physical = self
+            .physical()
+            .apply_values(|v| idx_mapping.get(v as usize).copied().unwrap_or_default());
+        Ok(self.with_local_rev_map(physical, rev_map))
+    }
+}
@@ -1,4 +1,5 @@
 mod append;
+mod categories;
 mod full;
 #[cfg(feature = "algorithm_group_by")]
 mod unique;
@@ -8,8 +8,7 @@ pub type DecimalChunked = Logical<DecimalType, Int128Type>;
 
 impl Int128Chunked {
     fn update_chunks_dtype(&mut self, precision: Option<usize>, scale: usize) {
-        // physical i128 type doesn't exist
-        // so we update the decimal dtype
+        // the arrow arrays of a decimal carry its precision and scale
         for arr in self.chunks.iter_mut() {
             let mut default = PrimitiveArray::new_empty(arr.data_type().clone());
             let arr = arr
@@ -61,6 +60,17 @@ impl Int128Chunked {
     }
 }
 
+/// Divide by a positive `divisor`, rounding half away from zero.
+pub(crate) fn div_round_half_away(dividend: i128, divisor: i128) -> i128 {
+    let quotient = dividend / divisor;
+    let remainder = dividend % divisor;
+    if 2 * remainder.abs() >= divisor {
+        quotient + dividend.signum()
+    } else {
+        quotient
+    }
+}
+
 impl LogicalType for DecimalChunked {
     fn dtype(&self) -> &DataType {
         self.2.as_ref().unwrap()
@@ -127,6 +137,13 @@ impl DecimalChunked {
         }
     }
 
+    /// The mean of the non-null values at the scale of the array, rounded half away from zero.
+    pub fn mean_decimal(&self) -> Option<i128> {
+        let count = (self.len() - self.null_count()) as i128;
+        let sum = self.0.sum()?;
+        (count > 0).then(|| div_round_half_away(sum, count))
+    }
+
     pub(crate) fn to_scale(&self, scale: usize) -> PolarsResult<Cow<'_, Self>> {
         if self.scale() == scale {
             return Ok(Cow::Borrowed(self));
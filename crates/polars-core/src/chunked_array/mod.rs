@@ -36,6 +36,8 @@ pub(crate) mod logical;
 pub mod object;
 #[cfg(feature = "random")]
 mod random;
+#[cfg(feature = "random")]
+pub use random::StratumSize;
 #[cfg(any(
     feature = "temporal",
     feature = "dtype-datetime",
@@ -619,7 +621,19 @@ impl<T: PolarsDataType> ChunkedArray<T> {
 
     /// Rename this [`ChunkedArray`].
     pub fn rename(&mut self, name: &str) {
-        self.field = Arc::new(Field::new(name, self.field.data_type().clone()))
+        Arc::make_mut(&mut self.field).set_name(name.into())
+    }
+
+    /// The [`ExtensionType`] the values are tagged with.
+    pub fn extension(&self) -> Option<&ExtensionTypeRef> {
+        self.field.extension.as_ref()
+    }
+
+    /// Tag the values with an [`ExtensionType`], or remove the tag with `None`.
+    ///
+    /// The storage type and the values are not checked, see [`Series::with_extension`].
+    pub fn set_extension(&mut self, extension: Option<ExtensionTypeRef>) {
+        Arc::make_mut(&mut self.field).extension = extension;
     }
 
     /// Return this [`ChunkedArray`] with a new name.
@@ -43,6 +43,10 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
         DataType::Int16 => downcast_and_pack!(Int16Array, Int16),
         DataType::Int32 => downcast_and_pack!(Int32Array, Int32),
         DataType::Int64 => downcast_and_pack!(Int64Array, Int64),
+        #[cfg(feature = "dtype-i128")]
+        DataType::Int128 => downcast_and_pack!(Int128Array, Int128),
+        #[cfg(feature = "dtype-u128")]
+        DataType::UInt128 => downcast_and_pack!(UInt128Array, UInt128),
         DataType::Float32 => downcast_and_pack!(Float32Array, Float32),
         DataType::Float64 => downcast_and_pack!(Float64Array, Float64),
         DataType::List(dt) => {
@@ -1,3 +1,5 @@
+use polars_utils::interrupt::try_raise_interrupt;
+
 use super::*;
 
 // Reduce monomorphisation.
@@ -34,6 +36,7 @@ where
     let mut count: IdxSize = 0;
 
     for arr_iter in iters {
+        try_raise_interrupt();
         let iter = arr_iter.into_iter().filter_map(|v| {
             let i = count;
             count += 1;
@@ -50,6 +53,7 @@ where
     }
 
     sort_impl(vals.as_mut_slice(), options);
+    try_raise_interrupt();
 
     let iter = vals.into_iter().map(|(idx, _v)| idx);
     let idx = if nulls_last {
@@ -83,6 +87,7 @@ where
 
     let mut count: IdxSize = 0;
     for arr_iter in iters {
+        try_raise_interrupt();
         vals.extend(arr_iter.into_iter().map(|v| {
             let idx = count;
             count += 1;
@@ -91,6 +96,7 @@ where
     }
 
     sort_impl(vals.as_mut_slice(), options);
+    try_raise_interrupt();
 
     let iter = vals.into_iter().map(|(idx, _v)| idx);
     let idx: Vec<_> = iter.collect_trusted();
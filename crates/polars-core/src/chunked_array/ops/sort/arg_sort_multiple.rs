@@ -1,6 +1,7 @@
 use arrow::compute::utils::combine_validities_and_many;
 use compare_inner::NullOrderCmp;
 use polars_row::{convert_columns, EncodingField, RowsEncoded};
+use polars_utils::interrupt::try_raise_interrupt;
 use polars_utils::iter::EnumerateIdxTrait;
 
 use super::*;
@@ -69,22 +70,51 @@ pub(crate) fn arg_sort_multiple_impl<T: NullOrderCmp + Send + Copy>(
         }
     };
 
-    match (options.multithreaded, options.maintain_order) {
-        (true, true) => POOL.install(|| {
-            vals.par_sort_by(compare);
-        }),
-        (true, false) => POOL.install(|| {
-            vals.par_sort_unstable_by(compare);
-        }),
-        (false, true) => vals.sort_by(compare),
-        (false, false) => vals.sort_unstable_by(compare),
+    match options.limit {
+        // Partial sort: only order the first `limit` elements.
+        Some(limit) if (limit as usize) < vals.len() => {
+            if options.maintain_order {
+                // Break ties on the index, so that equal elements keep their order.
+                partial_sort_by(&mut vals, limit as usize, |a, b| {
+                    compare(a, b).then(a.0.cmp(&b.0))
+                })
+            } else {
+                partial_sort_by(&mut vals, limit as usize, compare)
+            }
+        },
+        _ => match (options.multithreaded, options.maintain_order) {
+            (true, true) => POOL.install(|| {
+                vals.par_sort_by(compare);
+            }),
+            (true, false) => POOL.install(|| {
+                vals.par_sort_unstable_by(compare);
+            }),
+            (false, true) => vals.sort_by(compare),
+            (false, false) => vals.sort_unstable_by(compare),
+        },
     }
+    try_raise_interrupt();
 
     let ca: NoNull<IdxCa> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
     // Don't set to sorted. Argsort indices are not sorted.
     Ok(ca.into_inner())
 }
 
+/// Only keep the `limit` smallest elements of `vals`, in order.
+fn partial_sort_by<T, F: Fn(&T, &T) -> Ordering + Copy>(
+    vals: &mut Vec<T>,
+    limit: usize,
+    compare: F,
+) {
+    if limit == 0 {
+        vals.clear();
+        return;
+    }
+    vals.select_nth_unstable_by(limit - 1, compare);
+    vals.truncate(limit);
+    vals.sort_unstable_by(compare);
+}
+
 pub fn _get_rows_encoded_compat_array(by: &Series) -> PolarsResult<ArrayRef> {
     let by = convert_sort_column_multi_sort(by)?;
     let by = by.rechunk();
@@ -251,12 +281,14 @@ pub(crate) fn argsort_multiple_row_fmt(
 
     let rows_encoded = _get_rows_encoded(by, &descending, &nulls_last)?;
     let mut items: Vec<_> = rows_encoded.iter().enumerate_idx().collect();
+    try_raise_interrupt();
 
     if parallel {
         POOL.install(|| items.par_sort_by(|a, b| a.1.cmp(b.1)));
     } else {
         items.sort_by(|a, b| a.1.cmp(b.1));
     }
+    try_raise_interrupt();
 
     let ca: NoNull<IdxCa> = items.into_iter().map(|tpl| tpl.0).collect();
     Ok(ca.into_inner())
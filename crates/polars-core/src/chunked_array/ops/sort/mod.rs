@@ -66,11 +66,47 @@ fn partition_nulls<T: Copy>(
     (partitioned, validity)
 }
 
+/// Detect input that is already sorted in the requested order, or in exactly the opposite order,
+/// and finish it in `O(n)` instead of doing a full sort. This is the common case for time-series
+/// data that is (nearly) sorted by time.
+///
+/// A reverse sorted run may only be reversed for a `stable` sort if it has no ties, as reversing
+/// would otherwise swap the order of equal elements. Returns `true` if `slice` is sorted afterwards.
+fn sort_presorted<T, C>(slice: &mut [T], descending: bool, stable: bool, cmp: &C) -> bool
+where
+    C: Fn(&T, &T) -> Ordering,
+{
+    let order = |a: &T, b: &T| match descending {
+        true => cmp(b, a),
+        false => cmp(a, b),
+    };
+    if slice.len() < 2 {
+        return true;
+    }
+    // The first pair decides in which direction the input may already be sorted.
+    let reverse = order(&slice[0], &slice[1]) == Ordering::Greater;
+    let in_run = |w: &[T]| match (reverse, order(&w[0], &w[1])) {
+        (false, ord) => ord != Ordering::Greater,
+        (true, Ordering::Equal) => !stable,
+        (true, ord) => ord == Ordering::Greater,
+    };
+    if !slice.windows(2).all(in_run) {
+        return false;
+    }
+    if reverse {
+        slice.reverse();
+    }
+    true
+}
+
 pub(crate) fn sort_by_branch<T, C>(slice: &mut [T], descending: bool, cmp: C, parallel: bool)
 where
     T: Send,
     C: Send + Sync + Fn(&T, &T) -> Ordering,
 {
+    if sort_presorted(slice, descending, true, &cmp) {
+        return;
+    }
     if parallel {
         POOL.install(|| match descending {
             true => slice.par_sort_by(|a, b| cmp(b, a)),
@@ -89,6 +125,9 @@ where
     T: Send,
     C: Send + Sync + Fn(&T, &T) -> Ordering,
 {
+    if sort_presorted(slice, options.descending, false, &cmp) {
+        return;
+    }
     if options.multithreaded {
         POOL.install(|| match options.descending {
             true => slice.par_sort_unstable_by(|a, b| cmp(b, a)),
@@ -220,6 +259,15 @@ fn arg_sort_numeric<T>(ca: &ChunkedArray<T>, options: SortOptions) -> IdxCa
 where
     T: PolarsNumericType,
 {
+    // The data is already in the requested order, e.g. because the scan knew it was sorted.
+    if ca.null_count() == 0
+        && matches!(
+            (ca.is_sorted_flag(), options.descending),
+            (IsSorted::Ascending, false) | (IsSorted::Descending, true)
+        )
+    {
+        return IdxCa::new_vec(ca.name(), (0..ca.len() as IdxSize).collect());
+    }
     if ca.null_count() == 0 {
         let iter = ca
             .downcast_iter()
@@ -897,6 +945,55 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_arg_sort_multiple_limit() -> PolarsResult<()> {
+        let a = Int32Chunked::new("a", &[1, 2, 1, 1, 3, 4, 3, 3]);
+        let b = Int64Chunked::new("b", &[0, 1, 2, 3, 4, 5, 6, 1]).into_series();
+
+        let options = SortMultipleOptions::default()
+            .with_order_descending_multi([false, false])
+            .with_nulls_last_multi([false, false]);
+        let out = a.arg_sort_multiple(&[b.clone()], &options.clone().with_limit(Some(3)))?;
+        assert_eq!(Vec::from(&out), &[Some(0), Some(2), Some(3)]);
+
+        let options = options.with_order_descending_multi([false, true]);
+        let out = a.arg_sort_multiple(&[b.clone()], &options.clone().with_limit(Some(4)))?;
+        assert_eq!(Vec::from(&out), &[Some(3), Some(2), Some(0), Some(1)]);
+
+        // A limit larger than the length is a full sort.
+        let out = a.arg_sort_multiple(&[b.clone()], &options.clone().with_limit(Some(10)))?;
+        let expected = a.arg_sort_multiple(&[b.clone()], &options)?;
+        assert_eq!(Vec::from(&out), Vec::from(&expected));
+
+        let out = a.arg_sort_multiple(&[b], &options.with_limit(Some(0)))?;
+        assert!(out.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_presorted() {
+        let a = Int32Chunked::new("a", &[5, 4, 3, 3, 1]);
+        let out = a.sort(false);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(3), Some(3), Some(4), Some(5)]
+        );
+        let out = a.sort(true);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(5), Some(4), Some(3), Some(3), Some(1)]
+        );
+
+        // A reverse run with ties must not be reversed by a stable sort.
+        let idx = a.arg_sort(SortOptions::default());
+        assert_eq!(idx.cont_slice().unwrap(), &[4, 2, 3, 1, 0]);
+        let idx = a.arg_sort(SortOptions {
+            descending: true,
+            ..Default::default()
+        });
+        assert_eq!(idx.cont_slice().unwrap(), &[0, 1, 2, 3, 4]);
+    }
+
     #[test]
     fn test_sort_string() {
         let ca = StringChunked::new("a", &[Some("a"), None, Some("c"), None, Some("b")]);
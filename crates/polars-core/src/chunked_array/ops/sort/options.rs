@@ -96,6 +96,11 @@ pub struct SortMultipleOptions {
     pub multithreaded: bool,
     /// Whether maintain the order of equal elements. Default `false`.
     pub maintain_order: bool,
+    /// Only compute the first `limit` sort indices. Default `None`.
+    ///
+    /// This is a partial sort, so it is cheaper than a full sort followed by a slice. It is
+    /// respected by `arg_sort_multiple`, but not when sorting a [`DataFrame`].
+    pub limit: Option<IdxSize>,
 }
 
 impl Default for SortOptions {
@@ -116,6 +121,7 @@ impl Default for SortMultipleOptions {
             nulls_last: vec![false],
             multithreaded: true,
             maintain_order: false,
+            limit: None,
         }
     }
 }
@@ -173,6 +179,12 @@ impl SortMultipleOptions {
         self
     }
 
+    /// Only compute the first `limit` sort indices. Default `None`.
+    pub fn with_limit(mut self, limit: Option<IdxSize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
     /// Reverse the order of sorting for each column.
     pub fn with_order_reversed(mut self) -> Self {
         self.descending.iter_mut().for_each(|x| *x = !*x);
@@ -224,6 +236,7 @@ impl From<&SortOptions> for SortMultipleOptions {
             nulls_last: vec![value.nulls_last],
             multithreaded: value.multithreaded,
             maintain_order: value.maintain_order,
+            limit: None,
         }
     }
 }
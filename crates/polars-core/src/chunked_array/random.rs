@@ -2,22 +2,24 @@ use num_traits::{Float, NumCast};
 use polars_error::to_compute_err;
 use rand::distributions::Bernoulli;
 use rand::prelude::*;
-use rand::seq::index::IndexVec;
 use rand_distr::{Normal, Standard, StandardNormal, Uniform};
 
 use crate::prelude::DataType::Float64;
 use crate::prelude::*;
-use crate::random::get_global_random_u64;
+use crate::random::{counter_random_index, counter_random_u64, get_global_random_u64};
 use crate::utils::NoNull;
 
+// The random indices below are drawn with a counter based generator keyed by the row index
+// (or draw number), so the same seed gives the same sample no matter how the rows are
+// partitioned over chunks, morsels or threads.
+
 fn create_rand_index_with_replacement(n: usize, len: usize, seed: Option<u64>) -> IdxCa {
     if len == 0 {
         return IdxCa::new_vec("", vec![]);
     }
-    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
-    let dist = Uniform::new(0, len as IdxSize);
-    (0..n as IdxSize)
-        .map(move |_| dist.sample(&mut rng))
+    let seed = seed.unwrap_or_else(get_global_random_u64);
+    (0..n as u64)
+        .map(|i| counter_random_index(seed, i, len as u64) as IdxSize)
         .collect_trusted::<NoNull<IdxCa>>()
         .into_inner()
 }
@@ -28,24 +30,101 @@ fn create_rand_index_no_replacement(
     seed: Option<u64>,
     shuffle: bool,
 ) -> IdxCa {
-    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
-    let mut buf: Vec<IdxSize>;
-    if n == len {
-        buf = (0..len as IdxSize).collect();
-        if shuffle {
-            buf.shuffle(&mut rng)
+    if n == len && !shuffle {
+        return IdxCa::new_vec("", (0..len as IdxSize).collect());
+    }
+    let seed = seed.unwrap_or_else(get_global_random_u64);
+    // Every row gets a random key, the sample consists of the `n` rows with the smallest keys
+    // in the order of their keys.
+    let mut keyed: Vec<(u64, IdxSize)> = (0..len as IdxSize)
+        .map(|i| (counter_random_u64(seed, i as u64), i))
+        .collect();
+    if n < len {
+        if n > 0 {
+            keyed.select_nth_unstable(n - 1);
         }
-    } else {
-        // TODO: avoid extra potential copy by vendoring rand::seq::index::sample,
-        // or genericize take over slices over any unsigned type. The optimizer
-        // should get rid of the extra copy already if IdxSize matches the IndexVec
-        // size returned.
-        buf = match rand::seq::index::sample(&mut rng, len, n) {
-            IndexVec::U32(v) => v.into_iter().map(|x| x as IdxSize).collect(),
-            IndexVec::USize(v) => v.into_iter().map(|x| x as IdxSize).collect(),
-        };
+        keyed.truncate(n);
     }
-    IdxCa::new_vec("", buf)
+    keyed.sort_unstable();
+    IdxCa::new_vec("", keyed.into_iter().map(|(_, i)| i).collect())
+}
+
+/// A uniform random number in `(0, 1]` for draw `idx`.
+#[inline]
+fn counter_random_unit(seed: u64, idx: u64) -> f64 {
+    ((counter_random_u64(seed, idx) >> 11) + 1) as f64 / (1u64 << 53) as f64
+}
+
+/// Sample `n` indices where the probability of every index is proportional to its weight. Null
+/// weights count as zero.
+fn create_rand_index_weighted(
+    n: usize,
+    weights: &Float64Chunked,
+    with_replacement: bool,
+    seed: Option<u64>,
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        weights.into_iter().flatten().all(|w| w.is_finite() && w >= 0.0),
+        ComputeError: "sample weights must be finite and non-negative"
+    );
+    let seed = seed.unwrap_or_else(get_global_random_u64);
+    let weights = weights.into_iter().map(|w| w.unwrap_or(0.0));
+
+    if with_replacement {
+        let cum_weights = weights
+            .scan(0.0, |total, w| {
+                *total += w;
+                Some(*total)
+            })
+            .collect::<Vec<_>>();
+        let total = cum_weights.last().copied().unwrap_or(0.0);
+        polars_ensure!(
+            n == 0 || total > 0.0,
+            ShapeMismatch: "cannot take a weighted sample if all weights are zero"
+        );
+        let last = cum_weights.len().saturating_sub(1);
+        return Ok((0..n as u64)
+            .map(|i| {
+                let target = counter_random_unit(seed, i) * total;
+                cum_weights.partition_point(|c| *c < target).min(last) as IdxSize
+            })
+            .collect_trusted::<NoNull<IdxCa>>()
+            .into_inner());
+    }
+
+    // Efraimidis-Spirakis: the sample consists of the `n` rows with the largest keys
+    // `u^(1/w)`, in the order of their keys.
+    let mut keyed: Vec<(f64, IdxSize)> = weights
+        .enumerate()
+        .filter(|(_, w)| *w > 0.0)
+        .map(|(i, w)| (counter_random_unit(seed, i as u64).ln() / w, i as IdxSize))
+        .collect();
+    polars_ensure!(
+        n <= keyed.len(),
+        ShapeMismatch:
+        "cannot take a larger sample than the number of rows with a positive weight when `with_replacement=false`"
+    );
+    let by_key_desc = |a: &(f64, IdxSize), b: &(f64, IdxSize)| b.0.total_cmp(&a.0);
+    if n < keyed.len() {
+        if n > 0 {
+            keyed.select_nth_unstable_by(n - 1, by_key_desc);
+        }
+        keyed.truncate(n);
+    }
+    keyed.sort_unstable_by(by_key_desc);
+    Ok(IdxCa::new_vec(
+        "",
+        keyed.into_iter().map(|(_, i)| i).collect(),
+    ))
+}
+
+/// The number of rows to take from every stratum in [`DataFrame::sample_stratified`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StratumSize {
+    /// Take `n` rows, or all rows of the strata that have fewer.
+    N(usize),
+    /// Take a fraction between 0.0-1.0 of the rows.
+    Fraction(f64),
 }
 
 impl<T> ChunkedArray<T>
@@ -118,6 +197,39 @@ impl Series {
         self.sample_n(n, with_replacement, shuffle, seed)
     }
 
+    /// Sample `n` values, where the probability of a value to be sampled is proportional to its
+    /// weight in `weights`.
+    pub fn sample_n_weighted(
+        &self,
+        n: usize,
+        weights: &Series,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            weights.len() == self.len(),
+            ShapeMismatch: "sample weights must have the same length as the values, got {} and {}",
+            weights.len(), self.len()
+        );
+        let weights = weights.cast(&Float64)?;
+        let idx = create_rand_index_weighted(n, weights.f64()?, with_replacement, seed)?;
+        // SAFETY: we know that we never go out of bounds.
+        unsafe { Ok(self.take_unchecked(&idx)) }
+    }
+
+    /// Sample a fraction between 0.0-1.0 of the values, where the probability of a value to be
+    /// sampled is proportional to its weight in `weights`.
+    pub fn sample_frac_weighted(
+        &self,
+        frac: f64,
+        weights: &Series,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        let n = (self.len() as f64 * frac) as usize;
+        self.sample_n_weighted(n, weights, with_replacement, seed)
+    }
+
     pub fn shuffle(&self, seed: Option<u64>) -> Self {
         let len = self.len();
         let n = len;
@@ -245,6 +357,59 @@ impl DataFrame {
     }
 }
 
+impl DataFrame {
+    /// Sample the rows of every group of the `by` columns separately, so that every group is
+    /// represented in the sample. The sampled rows keep their order.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn sample_stratified<I, S>(
+        &self,
+        by: I,
+        size: StratumSize,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        if let StratumSize::Fraction(frac) = size {
+            polars_ensure!(
+                (0.0..=1.0).contains(&frac),
+                ComputeError: "stratum fraction must be between 0.0 and 1.0, got {}", frac
+            );
+        }
+        let seed = seed.unwrap_or_else(get_global_random_u64);
+        let groups = self.group_by(by)?.take_groups().into_idx();
+
+        let mut idx = Vec::with_capacity(match size {
+            StratumSize::N(n) => n.saturating_mul(groups.len()).min(self.height()),
+            StratumSize::Fraction(frac) => (self.height() as f64 * frac) as usize,
+        });
+        for (_, group) in groups.iter() {
+            let n = match size {
+                StratumSize::N(n) => n.min(group.len()),
+                StratumSize::Fraction(frac) => (group.len() as f64 * frac) as usize,
+            };
+            // The random keys are keyed by the row index, so that the sample doesn't depend on
+            // the order of the groups.
+            let mut keyed = group
+                .iter()
+                .map(|&i| (counter_random_u64(seed, i as u64), i))
+                .collect::<Vec<_>>();
+            if n < keyed.len() {
+                if n > 0 {
+                    keyed.select_nth_unstable(n - 1);
+                }
+                keyed.truncate(n);
+            }
+            idx.extend(keyed.into_iter().map(|(_, i)| i));
+        }
+        idx.sort_unstable();
+        let idx = IdxCa::new_vec("", idx);
+        // SAFETY: the indices are within bounds.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -343,4 +508,77 @@ mod test {
             .sample_frac(&Series::new("frac", &[2.0]), true, false, Some(0))
             .is_ok());
     }
+    #[test]
+    fn test_sample_weighted() {
+        let s = Series::new("a", 0..6i32);
+        let weights = Series::new("w", [0.0, 1.0, 0.0, 2.0, 0.0, 3.0]);
+
+        // Rows with a zero weight are never sampled.
+        let out = s.sample_n_weighted(3, &weights, false, Some(0)).unwrap();
+        let mut sampled = out.i32().unwrap().into_no_null_iter().collect::<Vec<_>>();
+        sampled.sort_unstable();
+        assert_eq!(sampled, [1, 3, 5]);
+        let out = s.sample_n_weighted(20, &weights, true, Some(0)).unwrap();
+        assert!(out.i32().unwrap().into_no_null_iter().all(|v| v % 2 == 1));
+
+        // There are only 3 rows with a positive weight.
+        assert!(s.sample_n_weighted(4, &weights, false, Some(0)).is_err());
+        let negative = Series::new("w", [1.0, -1.0, 1.0, 1.0, 1.0, 1.0]);
+        assert!(s.sample_n_weighted(1, &negative, false, Some(0)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "algorithm_group_by")]
+    fn test_sample_stratified() {
+        let df = df![
+            "label" => ["a", "a", "a", "a", "b", "b", "c"],
+            "value" => [0, 1, 2, 3, 4, 5, 6],
+        ]
+        .unwrap();
+
+        let out = df
+            .sample_stratified(["label"], StratumSize::N(2), Some(0))
+            .unwrap();
+        let labels = out.column("label").unwrap();
+        let labels = labels
+            .str()
+            .unwrap()
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(labels, ["a", "a", "b", "b", "c"]);
+        // The rows keep their order.
+        let values = out.column("value").unwrap().i32().unwrap();
+        let values = values.into_no_null_iter().collect::<Vec<_>>();
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+
+        let out = df
+            .sample_stratified(["label"], StratumSize::Fraction(0.5), Some(0))
+            .unwrap();
+        assert_eq!(out.height(), 3);
+        assert!(df
+            .sample_stratified(["label"], StratumSize::Fraction(1.5), Some(0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_sample_partition_independent() {
+        let mut s = Series::new("a", 0..50i32);
+        s.append(&Series::new("a", 50..100i32)).unwrap();
+        let rechunked = s.rechunk();
+        assert!(s.shuffle(Some(7)).equals(&rechunked.shuffle(Some(7))));
+
+        // A sample is a prefix of the shuffle with the same seed.
+        let shuffled = s.shuffle(Some(7));
+        let sampled = s.sample_n(10, false, true, Some(7)).unwrap();
+        assert!(sampled.equals(&shuffled.head(Some(10))));
+
+        // The random keys only depend on the seed and the row index.
+        let keys = (0..100u64)
+            .map(|i| counter_random_u64(7, i))
+            .collect::<Vec<_>>();
+        let offset_keys = (40..100u64)
+            .map(|i| counter_random_u64(7, i))
+            .collect::<Vec<_>>();
+        assert_eq!(keys[40..], offset_keys);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::export::chrono::Duration as ChronoDuration;
+use crate::fmt::{fmt_duration_string, iso_duration_string};
 use crate::prelude::DataType::Duration;
 use crate::prelude::*;
 
@@ -55,6 +56,39 @@ impl DurationChunked {
         }
     }
 
+    /// Convert from Duration into String with the given format: `"iso"` for ISO 8601 durations
+    /// (e.g. `P1DT2H3M`) or `"humanized"` for the form used to display durations (e.g. `1d 2h 3m`).
+    pub fn to_string(&self, format: &str) -> PolarsResult<StringChunked> {
+        let fmt_fn = match format {
+            "iso" => iso_duration_string::<String>,
+            "humanized" => fmt_duration_string::<String>,
+            _ => polars_bail!(
+                InvalidOperation: "invalid duration format '{}', expected 'iso' or 'humanized'", format
+            ),
+        };
+        let tu = self.time_unit();
+        let mut ca: StringChunked = self.apply_kernel_cast(&|arr| {
+            let mut buf = String::new();
+            let mut mutarr = MutablePlString::with_capacity(arr.len());
+
+            for opt in arr.into_iter() {
+                match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        fmt_fn(&mut buf, *v, tu).unwrap();
+                        mutarr.push_value(&buf)
+                    },
+                }
+            }
+
+            mutarr.freeze().boxed()
+        });
+
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
     /// Change the underlying [`TimeUnit`]. This does not modify the data.
     pub fn set_time_unit(&mut self, tu: TimeUnit) {
         self.2 = Some(Duration(tu))
@@ -1,3 +1,9 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use polars_utils::aliases::PlHashMap;
+
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -29,8 +35,253 @@ pub(crate) const FMT_TABLE_INLINE_COLUMN_DATA_TYPE: &str =
 pub(crate) const FMT_TABLE_ROUNDED_CORNERS: &str = "POLARS_FMT_TABLE_ROUNDED_CORNERS";
 pub(crate) const FMT_TABLE_CELL_LIST_LEN: &str = "POLARS_FMT_TABLE_CELL_LIST_LEN";
 
+pub(crate) const VERBOSE: &str = "POLARS_VERBOSE";
+pub(crate) const TABLE_WIDTH: &str = "POLARS_TABLE_WIDTH";
+pub const STREAMING_CHUNK_SIZE: &str = "POLARS_STREAMING_CHUNK_SIZE";
+pub const TEMP_DIR: &str = "POLARS_TEMP_DIR";
+pub const FILE_CACHE_TTL: &str = "POLARS_FILE_CACHE_TTL";
+pub const CLOUD_MAX_RETRIES: &str = "POLARS_CLOUD_MAX_RETRIES";
+pub const MAX_SPILL_BYTES: &str = "POLARS_MAX_SPILL_BYTES";
+
+thread_local! {
+    static SCOPED_CONFIGS: RefCell<Vec<Arc<Config>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// In-process configuration of polars.
+///
+/// Every setting corresponds to one of the `POLARS_*` environment variables. Instead of
+/// mutating the process environment, a [`Config`] is activated for a scope with
+/// [`Config::scoped`] and is reverted when the returned [`ConfigGuard`] is dropped. This makes
+/// it safe to use different settings in different parts of a (multi-tenant) service.
+///
+/// Settings that are not set fall back to the enclosing scope and finally to the environment
+/// variables. A scope applies to the thread it was activated on; use [`Config::current`] to
+/// carry the settings over to other threads. The streaming engine does so for its workers.
+///
+/// ```
+/// use polars_core::config::Config;
+///
+/// let _guard = Config::new()
+///     .with_tbl_rows(Some(20))
+///     .with_verbose(true)
+///     .scoped();
+/// assert!(polars_core::config::verbose());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    vars: PlHashMap<String, Option<String>>,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `POLARS_*` variable `key` to `value`.
+    pub fn with_var(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.vars.insert(key.to_string(), Some(value.into()));
+        self
+    }
+
+    /// Unset the `POLARS_*` variable `key`, hiding a value set by an enclosing scope or in
+    /// the environment.
+    pub fn without_var(mut self, key: &str) -> Self {
+        self.vars.insert(key.to_string(), None);
+        self
+    }
+
+    fn with_flag(self, key: &str, active: bool) -> Self {
+        self.with_var(key, if active { "1" } else { "0" })
+    }
+
+    fn with_limit(self, key: &str, limit: Option<usize>) -> Self {
+        let limit = limit.map_or_else(|| "-1".to_string(), |n| n.to_string());
+        self.with_var(key, limit)
+    }
+
+    /// Print logging info to stderr.
+    pub fn with_verbose(self, active: bool) -> Self {
+        self.with_flag(VERBOSE, active)
+    }
+
+    /// Maximum number of rows shown when formatting a `DataFrame`; `None` shows all rows.
+    pub fn with_tbl_rows(self, n: Option<usize>) -> Self {
+        self.with_limit(FMT_MAX_ROWS, n)
+    }
+
+    /// Maximum number of columns shown when formatting a `DataFrame`; `None` shows all
+    /// columns.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_cols(self, n: Option<usize>) -> Self {
+        self.with_limit(FMT_MAX_COLS, n)
+    }
+
+    /// Maximum number of characters printed per string value.
+    pub fn with_fmt_str_lengths(self, n: Option<usize>) -> Self {
+        self.with_limit(FMT_STR_LEN, n)
+    }
+
+    /// Maximum number of list elements printed per value.
+    pub fn with_fmt_table_cell_list_len(self, n: Option<usize>) -> Self {
+        self.with_limit(FMT_TABLE_CELL_LIST_LEN, n)
+    }
+
+    /// Width of the tables used when formatting a `DataFrame`.
+    pub fn with_tbl_width_chars(self, width: usize) -> Self {
+        self.with_var(TABLE_WIDTH, width.to_string())
+    }
+
+    /// Styling of tables, e.g. `"ASCII_MARKDOWN"` or `"UTF8_FULL_CONDENSED"`.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_formatting(self, format: &str) -> Self {
+        self.with_var(FMT_TABLE_FORMATTING, format)
+    }
+
+    /// Cell alignment of tables: `"LEFT"`, `"CENTER"` or `"RIGHT"`.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_cell_alignment(self, alignment: &str) -> Self {
+        self.with_var(FMT_TABLE_CELL_ALIGNMENT, alignment)
+    }
+
+    /// Cell alignment of numeric columns: `"LEFT"`, `"CENTER"` or `"RIGHT"`.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_cell_numeric_alignment(self, alignment: &str) -> Self {
+        self.with_var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT, alignment)
+    }
+
+    /// Print the shape information below the table.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_dataframe_shape_below(self, active: bool) -> Self {
+        self.with_flag(FMT_TABLE_DATAFRAME_SHAPE_BELOW, active)
+    }
+
+    /// Hide the data types of the columns.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_hide_column_data_types(self, active: bool) -> Self {
+        self.with_flag(FMT_TABLE_HIDE_COLUMN_DATA_TYPES, active)
+    }
+
+    /// Hide the column names.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_hide_column_names(self, active: bool) -> Self {
+        self.with_flag(FMT_TABLE_HIDE_COLUMN_NAMES, active)
+    }
+
+    /// Hide the separator between the column names and the rows.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_hide_dtype_separator(self, active: bool) -> Self {
+        self.with_flag(FMT_TABLE_HIDE_COLUMN_SEPARATOR, active)
+    }
+
+    /// Omit the shape information of the table.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_hide_dataframe_shape(self, active: bool) -> Self {
+        self.with_flag(FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION, active)
+    }
+
+    /// Put the data type of a column on the same line as its name.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_column_data_type_inline(self, active: bool) -> Self {
+        self.with_flag(FMT_TABLE_INLINE_COLUMN_DATA_TYPE, active)
+    }
+
+    /// Apply rounded corners to UTF8-styled tables.
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn with_tbl_rounded_corners(self, active: bool) -> Self {
+        self.with_flag(FMT_TABLE_ROUNDED_CORNERS, active)
+    }
+
+    /// Number of rows per chunk in the streaming engine. By default this is determined from
+    /// the number of columns and threads.
+    pub fn with_streaming_chunk_size(self, size: usize) -> Self {
+        self.with_var(STREAMING_CHUNK_SIZE, size.to_string())
+    }
+
+    /// Directory the streaming engine spills to.
+    pub fn with_temp_dir(self, path: impl Into<String>) -> Self {
+        self.with_var(TEMP_DIR, path)
+    }
+
+    /// Maximum number of bytes a query may spill to disk. A query that spills more fails with a
+    /// `SpillLimitExceeded` error.
+    pub fn with_max_spill_bytes(self, limit: usize) -> Self {
+        self.with_var(MAX_SPILL_BYTES, limit.to_string())
+    }
+
+    /// Default time to live in seconds of files in the cloud file cache.
+    pub fn with_file_cache_ttl(self, ttl: u64) -> Self {
+        self.with_var(FILE_CACHE_TTL, ttl.to_string())
+    }
+
+    /// Default number of retries of cloud requests.
+    pub fn with_cloud_max_retries(self, max_retries: usize) -> Self {
+        self.with_var(CLOUD_MAX_RETRIES, max_retries.to_string())
+    }
+
+    /// The settings of all [`Config`]s active on the current thread, merged into one.
+    pub fn current() -> Self {
+        SCOPED_CONFIGS.with(|configs| {
+            let mut vars = PlHashMap::default();
+            for config in configs.borrow().iter() {
+                vars.extend(config.vars.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+            Self { vars }
+        })
+    }
+
+    /// Activate this configuration on the current thread until the guard is dropped.
+    #[must_use = "the configuration is reverted when the guard is dropped"]
+    pub fn scoped(self) -> ConfigGuard {
+        let depth = SCOPED_CONFIGS.with(|configs| {
+            let mut configs = configs.borrow_mut();
+            configs.push(Arc::new(self));
+            configs.len() - 1
+        });
+        ConfigGuard {
+            depth,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Reverts a [`Config`] activated with [`Config::scoped`] when dropped.
+pub struct ConfigGuard {
+    depth: usize,
+    // The scope lives in thread-local storage, so the guard must be dropped on the same thread.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ConfigGuard {
+    fn drop(&mut self) {
+        SCOPED_CONFIGS.with(|configs| configs.borrow_mut().truncate(self.depth));
+    }
+}
+
+/// Get the value of the `POLARS_*` variable `key`.
+///
+/// Checks the [`Config`]s active on this thread, innermost first, before falling back to the
+/// environment variable.
+pub fn get_var(key: &str) -> Option<String> {
+    let scoped = SCOPED_CONFIGS.with(|configs| {
+        configs
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|config| config.vars.get(key).cloned())
+    });
+    match scoped {
+        Some(value) => value,
+        None => std::env::var(key).ok(),
+    }
+}
+
 pub fn verbose() -> bool {
-    std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
+    get_var(VERBOSE).as_deref().unwrap_or("") == "1"
+}
+
+/// The maximum number of bytes a query may spill to disk, if limited.
+pub fn get_max_spill_bytes() -> Option<usize> {
+    get_var(MAX_SPILL_BYTES).map(|s| s.parse::<usize>().expect("integer"))
 }
 
 pub fn get_file_prefetch_size() -> usize {
@@ -51,3 +302,35 @@ pub fn force_async() -> bool {
         .map(|value| value == "1")
         .unwrap_or_default()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scoped_config() {
+        let key = "POLARS_TEST_SCOPED_CONFIG";
+        assert_eq!(get_var(key), None);
+        {
+            let _outer = Config::new().with_var(key, "outer").scoped();
+            assert_eq!(get_var(key).as_deref(), Some("outer"));
+            {
+                let _inner = Config::new().without_var(key).scoped();
+                assert_eq!(get_var(key), None);
+                assert_eq!(Config::current().vars.get(key), Some(&None));
+            }
+            assert_eq!(get_var(key).as_deref(), Some("outer"));
+
+            // Other threads don't see the scope unless it is carried over.
+            let config = Config::current();
+            std::thread::spawn(move || {
+                assert_eq!(get_var(key), None);
+                let _guard = config.scoped();
+                assert_eq!(get_var(key).as_deref(), Some("outer"));
+            })
+            .join()
+            .unwrap();
+        }
+        assert_eq!(get_var(key), None);
+    }
+}
@@ -114,6 +114,8 @@ enum SerializableDataType {
     Enum(Option<Wrap<Utf8ViewArray>>, CategoricalOrdering),
     #[cfg(feature = "object")]
     Object(String),
+    Int128,
+    UInt128,
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -125,10 +127,12 @@ impl From<&DataType> for SerializableDataType {
             UInt16 => Self::UInt16,
             UInt32 => Self::UInt32,
             UInt64 => Self::UInt64,
+            UInt128 => Self::UInt128,
             Int8 => Self::Int8,
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
+            Int128 => Self::Int128,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -169,10 +173,12 @@ impl From<SerializableDataType> for DataType {
             UInt16 => Self::UInt16,
             UInt32 => Self::UInt32,
             UInt64 => Self::UInt64,
+            UInt128 => Self::UInt128,
             Int8 => Self::Int8,
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
+            Int128 => Self::Int128,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
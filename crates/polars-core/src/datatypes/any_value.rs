@@ -82,6 +82,12 @@ pub enum AnyValue<'a> {
     Int32(i32),
     /// A 64-bit integer number.
     Int64(i64),
+    /// A 128-bit integer number.
+    #[cfg(feature = "dtype-i128")]
+    Int128(i128),
+    /// An unsigned 128-bit integer number.
+    #[cfg(feature = "dtype-u128")]
+    UInt128(u128),
     /// A 32-bit floating point number.
     Float32(f32),
     /// A 64-bit floating point number.
@@ -407,6 +413,10 @@ impl<'a> AnyValue<'a> {
             UInt16(_) => DataType::UInt16,
             UInt32(_) => DataType::UInt32,
             UInt64(_) => DataType::UInt64,
+            #[cfg(feature = "dtype-i128")]
+            Int128(_) => DataType::Int128,
+            #[cfg(feature = "dtype-u128")]
+            UInt128(_) => DataType::UInt128,
             Float32(_) => DataType::Float32,
             Float64(_) => DataType::Float64,
             String(_) | StringOwned(_) => DataType::String,
@@ -453,6 +463,10 @@ impl<'a> AnyValue<'a> {
             UInt16(v) => NumCast::from(*v),
             UInt32(v) => NumCast::from(*v),
             UInt64(v) => NumCast::from(*v),
+            #[cfg(feature = "dtype-i128")]
+            Int128(v) => NumCast::from(*v),
+            #[cfg(feature = "dtype-u128")]
+            UInt128(v) => NumCast::from(*v),
             Float32(v) => NumCast::from(*v),
             Float64(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-date")]
@@ -512,17 +526,26 @@ impl<'a> AnyValue<'a> {
     }
 
     pub fn is_signed_integer(&self) -> bool {
-        matches!(
-            self,
-            AnyValue::Int8(_) | AnyValue::Int16(_) | AnyValue::Int32(_) | AnyValue::Int64(_)
-        )
+        match self {
+            AnyValue::Int8(_) | AnyValue::Int16(_) | AnyValue::Int32(_) | AnyValue::Int64(_) => {
+                true
+            },
+            #[cfg(feature = "dtype-i128")]
+            AnyValue::Int128(_) => true,
+            _ => false,
+        }
     }
 
     pub fn is_unsigned_integer(&self) -> bool {
-        matches!(
-            self,
-            AnyValue::UInt8(_) | AnyValue::UInt16(_) | AnyValue::UInt32(_) | AnyValue::UInt64(_)
-        )
+        match self {
+            AnyValue::UInt8(_)
+            | AnyValue::UInt16(_)
+            | AnyValue::UInt32(_)
+            | AnyValue::UInt64(_) => true,
+            #[cfg(feature = "dtype-u128")]
+            AnyValue::UInt128(_) => true,
+            _ => false,
+        }
     }
 
     pub fn is_null(&self) -> bool {
@@ -552,6 +575,10 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int16) => AnyValue::Int16(av.extract::<i16>()?),
             (av, DataType::Int32) => AnyValue::Int32(av.extract::<i32>()?),
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
+            #[cfg(feature = "dtype-i128")]
+            (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
+            #[cfg(feature = "dtype-u128")]
+            (av, DataType::UInt128) => AnyValue::UInt128(av.extract::<u128>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
 
@@ -564,6 +591,10 @@ impl<'a> AnyValue<'a> {
             (AnyValue::Int16(v), DataType::Boolean) => AnyValue::Boolean(*v != i16::default()),
             (AnyValue::Int32(v), DataType::Boolean) => AnyValue::Boolean(*v != i32::default()),
             (AnyValue::Int64(v), DataType::Boolean) => AnyValue::Boolean(*v != i64::default()),
+            #[cfg(feature = "dtype-i128")]
+            (AnyValue::Int128(v), DataType::Boolean) => AnyValue::Boolean(*v != i128::default()),
+            #[cfg(feature = "dtype-u128")]
+            (AnyValue::UInt128(v), DataType::Boolean) => AnyValue::Boolean(*v != u128::default()),
             (AnyValue::Float32(v), DataType::Boolean) => AnyValue::Boolean(*v != f32::default()),
             (AnyValue::Float64(v), DataType::Boolean) => AnyValue::Boolean(*v != f64::default()),
 
@@ -732,6 +763,10 @@ impl AnyValue<'_> {
             UInt16(v) => v.hash(state),
             UInt32(v) => v.hash(state),
             UInt64(v) => v.hash(state),
+            #[cfg(feature = "dtype-i128")]
+            Int128(v) => v.hash(state),
+            #[cfg(feature = "dtype-u128")]
+            UInt128(v) => v.hash(state),
             String(v) => v.hash(state),
             StringOwned(v) => v.hash(state),
             Float32(v) => v.to_ne_bytes().hash(state),
@@ -890,6 +925,10 @@ impl<'a> AnyValue<'a> {
             UInt16(v) => UInt16(v),
             UInt32(v) => UInt32(v),
             UInt64(v) => UInt64(v),
+            #[cfg(feature = "dtype-i128")]
+            Int128(v) => Int128(v),
+            #[cfg(feature = "dtype-u128")]
+            UInt128(v) => UInt128(v),
             Boolean(v) => Boolean(v),
             Float32(v) => Float32(v),
             Float64(v) => Float64(v),
@@ -974,6 +1013,10 @@ impl AnyValue<'_> {
             (Int16(l), Int16(r)) => *l == *r,
             (Int32(l), Int32(r)) => *l == *r,
             (Int64(l), Int64(r)) => *l == *r,
+            #[cfg(feature = "dtype-i128")]
+            (Int128(l), Int128(r)) => *l == *r,
+            #[cfg(feature = "dtype-u128")]
+            (UInt128(l), UInt128(r)) => *l == *r,
             (Float32(l), Float32(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float64(l), Float64(r)) => l.to_total_ord() == r.to_total_ord(),
             (String(l), String(r)) => l == r,
@@ -1061,6 +1104,10 @@ impl PartialOrd for AnyValue<'_> {
             (Int16(l), Int16(r)) => l.partial_cmp(r),
             (Int32(l), Int32(r)) => l.partial_cmp(r),
             (Int64(l), Int64(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-i128")]
+            (Int128(l), Int128(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-u128")]
+            (UInt128(l), UInt128(r)) => l.partial_cmp(r),
             (Float32(l), Float32(r)) => l.to_total_ord().partial_cmp(&r.to_total_ord()),
             (Float64(l), Float64(r)) => l.to_total_ord().partial_cmp(&r.to_total_ord()),
             _ => match (self.as_borrowed(), other.as_borrowed()) {
@@ -1259,6 +1306,14 @@ impl<K: NumericNative> From<K> for AnyValue<'static> {
                 PrimitiveType::UInt64 => {
                     AnyValue::UInt64(NumCast::from(value).unwrap_unchecked_release())
                 },
+                #[cfg(feature = "dtype-i128")]
+                PrimitiveType::Int128 => {
+                    AnyValue::Int128(NumCast::from(value).unwrap_unchecked_release())
+                },
+                #[cfg(feature = "dtype-u128")]
+                PrimitiveType::UInt128 => {
+                    AnyValue::UInt128(NumCast::from(value).unwrap_unchecked_release())
+                },
                 PrimitiveType::Float32 => {
                     AnyValue::Float32(NumCast::from(value).unwrap_unchecked_release())
                 },
@@ -0,0 +1,267 @@
+//! User-defined logical types that are stored as one of the existing physical types.
+//!
+//! A column is tagged with an [`ExtensionType`] with [`Series::with_extension`]. The tag is written
+//! as Arrow extension metadata (`ARROW:extension:name` and `ARROW:extension:metadata`) to IPC and
+//! Parquet files, and restored when such a file is read and an extension type with that name is
+//! registered with [`register_extension_type`]. Unknown extension types are read as their storage
+//! type.
+//!
+//! The tag is kept by operations that keep the values as they are, e.g. slicing, renaming, sorting
+//! and concatenating. Operations that compute new values return the storage type.
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::{Arc, RwLock};
+
+use arrow::array::*;
+use arrow::datatypes::PhysicalType;
+use once_cell::sync::Lazy;
+
+use crate::prelude::*;
+
+/// A logical type that is stored as an existing physical [`DataType`].
+pub trait ExtensionType: Send + Sync {
+    /// The name that identifies the type, e.g. `arrow.uuid`. This is the name that is stored as
+    /// `ARROW:extension:name`.
+    fn name(&self) -> &str;
+
+    /// The [`DataType`] the values are stored as.
+    fn storage_dtype(&self) -> DataType;
+
+    /// Metadata that is stored as `ARROW:extension:metadata`.
+    fn metadata(&self) -> Option<String> {
+        None
+    }
+
+    /// Check that the values of `storage` are valid for this type.
+    fn validate(&self, _storage: &Series) -> PolarsResult<()> {
+        Ok(())
+    }
+
+    /// Format a non-null value for display, or `None` to display the storage value.
+    fn format_value(&self, _value: &AnyValue) -> Option<String> {
+        None
+    }
+
+    /// A Series of the same length as `storage` whose order defines the order of the values, or
+    /// `None` to order by the storage values. The returned Series must not be tagged with this
+    /// extension type.
+    fn sort_key(&self, _storage: &Series) -> Option<Series> {
+        None
+    }
+}
+
+/// A shared reference to a registered [`ExtensionType`]. Two references are equal if the types
+/// have the same name.
+#[derive(Clone)]
+pub struct ExtensionTypeRef(pub Arc<dyn ExtensionType>);
+
+impl Deref for ExtensionTypeRef {
+    type Target = dyn ExtensionType;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl Debug for ExtensionTypeRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ExtensionType({})", self.name())
+    }
+}
+
+impl PartialEq for ExtensionTypeRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for ExtensionTypeRef {}
+
+impl Hash for ExtensionTypeRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state)
+    }
+}
+
+static EXTENSION_REGISTRY: Lazy<RwLock<PlHashMap<String, ExtensionTypeRef>>> = Lazy::new(|| {
+    let mut registry = PlHashMap::default();
+    for extension in [
+        ExtensionTypeRef(Arc::new(UuidType)),
+        ExtensionTypeRef(Arc::new(JsonType)),
+    ] {
+        registry.insert(extension.name().to_string(), extension);
+    }
+    RwLock::new(registry)
+});
+
+/// Register an extension type, so that columns of that type are restored when reading IPC and
+/// Parquet files. This replaces an earlier registration with the same name.
+pub fn register_extension_type<E: ExtensionType + 'static>(extension: E) -> ExtensionTypeRef {
+    let extension = ExtensionTypeRef(Arc::new(extension));
+    let mut registry = EXTENSION_REGISTRY.write().unwrap();
+    registry.insert(extension.name().to_string(), extension.clone());
+    extension
+}
+
+/// Remove the registration of an extension type, returning it if it was registered.
+pub fn unregister_extension_type(name: &str) -> Option<ExtensionTypeRef> {
+    let mut registry = EXTENSION_REGISTRY.write().unwrap();
+    registry.remove(name)
+}
+
+/// Get a registered extension type by name.
+pub fn get_extension_type(name: &str) -> Option<ExtensionTypeRef> {
+    let registry = EXTENSION_REGISTRY.read().unwrap();
+    registry.get(name).cloned()
+}
+
+/// UUIDs stored as 16 bytes, displayed in their hyphenated form.
+///
+/// This is the canonical `arrow.uuid` extension type, which is stored as `FixedSizeBinary(16)` by
+/// other Arrow implementations.
+pub struct UuidType;
+
+impl ExtensionType for UuidType {
+    fn name(&self) -> &str {
+        "arrow.uuid"
+    }
+
+    fn storage_dtype(&self) -> DataType {
+        DataType::Binary
+    }
+
+    fn validate(&self, storage: &Series) -> PolarsResult<()> {
+        for value in storage.binary()?.into_iter().flatten() {
+            polars_ensure!(
+                value.len() == 16,
+                ComputeError: "invalid UUID: expected 16 bytes, got {}", value.len()
+            );
+        }
+        Ok(())
+    }
+
+    fn format_value(&self, value: &AnyValue) -> Option<String> {
+        let bytes = match value {
+            AnyValue::Binary(bytes) => *bytes,
+            AnyValue::BinaryOwned(bytes) => bytes.as_slice(),
+            _ => return None,
+        };
+        let mut out = String::with_capacity(36);
+        for (i, byte) in bytes.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                out.push('-');
+            }
+            out.push_str(&format!("{byte:02x}"));
+        }
+        Some(out)
+    }
+}
+
+/// JSON documents stored as strings. This is the canonical `arrow.json` extension type.
+pub struct JsonType;
+
+impl ExtensionType for JsonType {
+    fn name(&self) -> &str {
+        "arrow.json"
+    }
+
+    fn storage_dtype(&self) -> DataType {
+        DataType::String
+    }
+}
+
+/// Replace the [`ArrowDataType::Extension`] of an array by its storage type.
+pub(crate) fn extension_storage_array(arr: &dyn Array) -> PolarsResult<ArrayRef> {
+    let data_type = arr.data_type().to_logical_type().clone();
+    let out = match arr.data_type().to_physical_type() {
+        PhysicalType::Boolean => {
+            let arr = arr.as_any().downcast_ref::<BooleanArray>().unwrap();
+            BooleanArray::new(data_type, arr.values().clone(), arr.validity().cloned()).boxed()
+        },
+        PhysicalType::Primitive(primitive) => {
+            arrow::with_match_primitive_type_full!(primitive, |$T| {
+                let arr = arr.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+                arr.clone().to(data_type).boxed()
+            })
+        },
+        PhysicalType::Utf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Utf8Array::new(
+                data_type,
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        PhysicalType::LargeUtf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Utf8Array::new(
+                data_type,
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        PhysicalType::Binary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            BinaryArray::new(
+                data_type,
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        PhysicalType::LargeBinary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            BinaryArray::new(
+                data_type,
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        PhysicalType::FixedSizeBinary => {
+            let arr = arr.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            arr.clone().to(data_type).boxed()
+        },
+        PhysicalType::Utf8View => {
+            let arr = arr.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            // SAFETY: only the data type changes.
+            unsafe {
+                Utf8ViewArray::new_unchecked(
+                    data_type,
+                    arr.views().clone(),
+                    arr.data_buffers().clone(),
+                    arr.validity().cloned(),
+                    arr.total_bytes_len(),
+                    arr.total_buffer_len(),
+                )
+            }
+            .boxed()
+        },
+        PhysicalType::BinaryView => {
+            let arr = arr.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            // SAFETY: only the data type changes.
+            unsafe {
+                BinaryViewArray::new_unchecked(
+                    data_type,
+                    arr.views().clone(),
+                    arr.data_buffers().clone(),
+                    arr.validity().cloned(),
+                    arr.total_bytes_len(),
+                    arr.total_buffer_len(),
+                )
+            }
+            .boxed()
+        },
+        dt => polars_bail!(
+            ComputeError: "extension types stored as {:?} are not supported", dt
+        ),
+    };
+    Ok(out)
+}
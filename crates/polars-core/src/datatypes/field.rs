@@ -11,6 +11,9 @@ use super::*;
 pub struct Field {
     pub name: SmartString,
     pub dtype: DataType,
+    /// The [`ExtensionType`] the values are tagged with.
+    #[cfg_attr(any(feature = "serde", feature = "serde-lazy"), serde(skip))]
+    pub extension: Option<ExtensionTypeRef>,
 }
 
 pub type FieldRef = Arc<Field>;
@@ -31,11 +34,22 @@ impl Field {
         Field {
             name: name.into(),
             dtype,
+            extension: None,
         }
     }
 
     pub fn from_owned(name: SmartString, dtype: DataType) -> Self {
-        Field { name, dtype }
+        Field {
+            name,
+            dtype,
+            extension: None,
+        }
+    }
+
+    /// Tag the `Field` with an [`ExtensionType`].
+    pub fn with_extension(mut self, extension: Option<ExtensionTypeRef>) -> Self {
+        self.extension = extension;
+        self
     }
 
     /// Returns a reference to the `Field` name.
@@ -110,7 +124,19 @@ impl Field {
     /// assert_eq!(f.to_arrow(true), af);
     /// ```
     pub fn to_arrow(&self, pl_flavor: bool) -> ArrowField {
-        self.dtype.to_arrow_field(self.name.as_str(), pl_flavor)
+        let field = self.dtype.to_arrow_field(self.name.as_str(), pl_flavor);
+        match &self.extension {
+            Some(extension) => {
+                let data_type = ArrowDataType::Extension(
+                    extension.name().to_string(),
+                    Box::new(field.data_type),
+                    extension.metadata(),
+                );
+                ArrowField::new(field.name, data_type, field.is_nullable)
+                    .with_metadata(field.metadata)
+            },
+            None => field,
+        }
     }
 }
 
@@ -138,12 +164,15 @@ impl DataType {
             ArrowDataType::UInt16 => DataType::UInt16,
             ArrowDataType::UInt32 => DataType::UInt32,
             ArrowDataType::UInt64 => DataType::UInt64,
+            ArrowDataType::UInt128 => DataType::UInt128,
             ArrowDataType::Int8 => DataType::Int8,
             ArrowDataType::Int16 => DataType::Int16,
             ArrowDataType::Int32 => DataType::Int32,
             ArrowDataType::Int64 => DataType::Int64,
+            ArrowDataType::Int128 => DataType::Int128,
             ArrowDataType::Boolean => DataType::Boolean,
-            ArrowDataType::Float32 => DataType::Float32,
+            // Half floats are upcast when the arrays are converted to a Series.
+            ArrowDataType::Float16 | ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
             #[cfg(feature = "dtype-array")]
             ArrowDataType::FixedSizeList(f, size) => DataType::Array(DataType::from_arrow(f.data_type(), bin_to_view).boxed(), *size),
@@ -173,6 +202,7 @@ impl DataType {
                     panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
                 }
             }
+            ArrowDataType::Extension(_, storage, _) => DataType::from_arrow(storage, bin_to_view),
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => DataType::Decimal(Some(*precision), Some(*scale)),
             ArrowDataType::Utf8View |ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => DataType::String,
@@ -199,6 +229,14 @@ impl From<&ArrowDataType> for DataType {
 
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
-        Field::new(&f.name, f.data_type().into())
+        let field = Field::new(&f.name, f.data_type().into());
+        match f.data_type() {
+            ArrowDataType::Extension(name, _, _) => {
+                let extension = get_extension_type(name)
+                    .filter(|extension| extension.storage_dtype() == field.dtype);
+                field.with_extension(extension)
+            },
+            _ => field,
+        }
     }
 }
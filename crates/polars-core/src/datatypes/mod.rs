@@ -11,6 +11,7 @@ mod _serde;
 mod aliases;
 mod any_value;
 mod dtype;
+mod extension;
 mod field;
 mod into_scalar;
 #[cfg(feature = "object")]
@@ -32,6 +33,7 @@ use arrow::types::simd::Simd;
 use arrow::types::NativeType;
 use bytemuck::Zeroable;
 pub use dtype::*;
+pub use extension::*;
 pub use field::*;
 pub use into_scalar::*;
 use num_traits::{Bounded, FromPrimitive, Num, NumCast, One, Zero};
@@ -181,6 +183,10 @@ impl_polars_num_datatype!(PolarsIntegerType, Int8Type, Int8, i8, i8);
 impl_polars_num_datatype!(PolarsIntegerType, Int16Type, Int16, i16, i16);
 impl_polars_num_datatype!(PolarsIntegerType, Int32Type, Int32, i32, i32);
 impl_polars_num_datatype!(PolarsIntegerType, Int64Type, Int64, i64, i64);
+#[cfg(feature = "dtype-i128")]
+impl_polars_num_datatype!(PolarsIntegerType, Int128Type, Int128, i128, i128);
+#[cfg(feature = "dtype-u128")]
+impl_polars_num_datatype!(PolarsIntegerType, UInt128Type, UInt128, u128, u128);
 impl_polars_num_datatype!(PolarsFloatType, Float32Type, Float32, f32, f32);
 impl_polars_num_datatype!(PolarsFloatType, Float64Type, Float64, f64, f64);
 impl_polars_datatype!(DateType, Date, PrimitiveArray<i32>, 'a, i32, i32, i32);
@@ -228,28 +234,6 @@ unsafe impl PolarsDataType for FixedSizeListType {
         DataType::Array(Box::new(DataType::Null), 0)
     }
 }
-#[cfg(feature = "dtype-decimal")]
-pub struct Int128Type {}
-#[cfg(feature = "dtype-decimal")]
-unsafe impl PolarsDataType for Int128Type {
-    type Physical<'a> = i128;
-    type OwnedPhysical = i128;
-    type ZeroablePhysical<'a> = i128;
-    type Array = PrimitiveArray<i128>;
-    type IsNested = FalseT;
-    type HasViews = FalseT;
-
-    fn get_dtype() -> DataType {
-        // Scale is not None to allow for get_any_value() to work.
-        DataType::Decimal(None, Some(0))
-    }
-}
-#[cfg(feature = "dtype-decimal")]
-impl PolarsNumericType for Int128Type {
-    type Native = i128;
-}
-#[cfg(feature = "dtype-decimal")]
-impl PolarsIntegerType for Int128Type {}
 #[cfg(feature = "object")]
 pub struct ObjectType<T>(T);
 #[cfg(feature = "object")]
@@ -278,8 +262,10 @@ pub type Int8Chunked = ChunkedArray<Int8Type>;
 pub type Int16Chunked = ChunkedArray<Int16Type>;
 pub type Int32Chunked = ChunkedArray<Int32Type>;
 pub type Int64Chunked = ChunkedArray<Int64Type>;
-#[cfg(feature = "dtype-decimal")]
+#[cfg(feature = "dtype-i128")]
 pub type Int128Chunked = ChunkedArray<Int128Type>;
+#[cfg(feature = "dtype-u128")]
+pub type UInt128Chunked = ChunkedArray<UInt128Type>;
 pub type Float32Chunked = ChunkedArray<Float32Type>;
 pub type Float64Chunked = ChunkedArray<Float64Type>;
 pub type StringChunked = ChunkedArray<StringType>;
@@ -351,11 +337,16 @@ impl NumericNative for u64 {
     type PolarsType = UInt64Type;
     type TrueDivPolarsType = Float64Type;
 }
-#[cfg(feature = "dtype-decimal")]
+#[cfg(feature = "dtype-i128")]
 impl NumericNative for i128 {
     type PolarsType = Int128Type;
     type TrueDivPolarsType = Float64Type;
 }
+#[cfg(feature = "dtype-u128")]
+impl NumericNative for u128 {
+    type PolarsType = UInt128Type;
+    type TrueDivPolarsType = Float64Type;
+}
 impl NumericNative for f32 {
     type PolarsType = Float32Type;
     type TrueDivPolarsType = Float32Type;
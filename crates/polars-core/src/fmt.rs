@@ -94,7 +94,7 @@ pub fn set_trim_decimal_zeros(trim: Option<bool>) {
 
 /// Parses an environment variable value.
 fn parse_env_var<T: FromStr>(name: &str) -> Option<T> {
-    std::env::var(name).ok().and_then(|v| v.parse().ok())
+    get_var(name).and_then(|v| v.parse().ok())
 }
 /// Parses an environment variable value as a limit or set a default.
 ///
@@ -335,6 +335,14 @@ impl Debug for Series {
             DataType::Int64 => {
                 format_array!(f, self.i64().unwrap(), "i64", self.name(), "Series")
             },
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => {
+                format_array!(f, self.i128().unwrap(), "i128", self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                format_array!(f, self.u128().unwrap(), "u128", self.name(), "Series")
+            },
             DataType::Float32 => {
                 format_array!(f, self.f32().unwrap(), "f32", self.name(), "Series")
             },
@@ -446,14 +454,19 @@ fn field_to_str(f: &Field, str_truncate: usize) -> (String, usize) {
     if env_is_true(FMT_TABLE_HIDE_COLUMN_NAMES) {
         column_name = "".to_string();
     }
+    // Columns that are tagged with an extension type show the name of that type.
+    let data_type = match &f.extension {
+        Some(extension) => extension.name().to_string(),
+        None => f.data_type().to_string(),
+    };
     let column_data_type = if env_is_true(FMT_TABLE_HIDE_COLUMN_DATA_TYPES) {
         "".to_string()
     } else if env_is_true(FMT_TABLE_INLINE_COLUMN_DATA_TYPE)
         | env_is_true(FMT_TABLE_HIDE_COLUMN_NAMES)
     {
-        format!("{}", f.data_type())
+        data_type
     } else {
-        format!("\n{}", f.data_type())
+        format!("\n{data_type}")
     };
     let mut dtype_length = column_data_type.trim_start().len();
     let mut separator = "\n---";
@@ -519,7 +532,7 @@ fn prepare_row(
 
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn env_is_true(varname: &str) -> bool {
-    std::env::var(varname).as_deref().unwrap_or("0") == "1"
+    get_var(varname).as_deref().unwrap_or("0") == "1"
 }
 
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
@@ -572,7 +585,7 @@ impl Display for DataFrame {
                 names.push(s);
                 name_lengths.push(l);
             }
-            let (preset, is_utf8) = match std::env::var(FMT_TABLE_FORMATTING)
+            let (preset, is_utf8) = match get_var(FMT_TABLE_FORMATTING)
                 .as_deref()
                 .unwrap_or("DEFAULT")
             {
@@ -668,14 +681,10 @@ impl Display for DataFrame {
             }
 
             let tbl_fallback_width = 100;
-            let tbl_width = std::env::var("POLARS_TABLE_WIDTH")
-                .map(|s| {
-                    Some(
-                        s.parse::<u16>()
-                            .expect("could not parse table width argument"),
-                    )
-                })
-                .unwrap_or(None);
+            let tbl_width = get_var(TABLE_WIDTH).map(|s| {
+                s.parse::<u16>()
+                    .expect("could not parse table width argument")
+            });
 
             // column width constraints
             let col_width_exact =
@@ -721,13 +730,11 @@ impl Display for DataFrame {
             }
 
             // set alignment of cells, if defined
-            if std::env::var(FMT_TABLE_CELL_ALIGNMENT).is_ok()
-                | std::env::var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT).is_ok()
-            {
-                let str_preset = std::env::var(FMT_TABLE_CELL_ALIGNMENT)
-                    .unwrap_or_else(|_| "DEFAULT".to_string());
-                let num_preset = std::env::var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT)
-                    .unwrap_or_else(|_| str_preset.to_string());
+            let str_alignment = get_var(FMT_TABLE_CELL_ALIGNMENT);
+            let num_alignment = get_var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT);
+            if str_alignment.is_some() | num_alignment.is_some() {
+                let str_preset = str_alignment.unwrap_or_else(|| "DEFAULT".to_string());
+                let num_preset = num_alignment.unwrap_or_else(|| str_preset.to_string());
                 for (column_index, column) in table.column_iter_mut().enumerate() {
                     let dtype = fields[column_index].data_type();
                     let mut preset = str_preset.as_str();
@@ -923,7 +930,7 @@ const SIZES_US: [i64; 4] = [86_400_000_000, 3_600_000_000, 60_000_000, 1_000_000
 const SIZES_MS: [i64; 4] = [86_400_000, 3_600_000, 60_000, 1_000];
 
 #[cfg(feature = "dtype-duration")]
-fn fmt_duration_ns(f: &mut Formatter<'_>, v: i64) -> fmt::Result {
+fn fmt_duration_ns<W: fmt::Write>(f: &mut W, v: i64) -> fmt::Result {
     if v == 0 {
         return write!(f, "0ns");
     }
@@ -939,7 +946,7 @@ fn fmt_duration_ns(f: &mut Formatter<'_>, v: i64) -> fmt::Result {
 }
 
 #[cfg(feature = "dtype-duration")]
-fn fmt_duration_us(f: &mut Formatter<'_>, v: i64) -> fmt::Result {
+fn fmt_duration_us<W: fmt::Write>(f: &mut W, v: i64) -> fmt::Result {
     if v == 0 {
         return write!(f, "0µs");
     }
@@ -953,7 +960,7 @@ fn fmt_duration_us(f: &mut Formatter<'_>, v: i64) -> fmt::Result {
 }
 
 #[cfg(feature = "dtype-duration")]
-fn fmt_duration_ms(f: &mut Formatter<'_>, v: i64) -> fmt::Result {
+fn fmt_duration_ms<W: fmt::Write>(f: &mut W, v: i64) -> fmt::Result {
     if v == 0 {
         return write!(f, "0ms");
     }
@@ -965,7 +972,7 @@ fn fmt_duration_ms(f: &mut Formatter<'_>, v: i64) -> fmt::Result {
 }
 
 #[cfg(feature = "dtype-duration")]
-fn format_duration(f: &mut Formatter, v: i64, sizes: &[i64], names: &[&str]) -> fmt::Result {
+fn format_duration<W: fmt::Write>(f: &mut W, v: i64, sizes: &[i64], names: &[&str]) -> fmt::Result {
     for i in 0..4 {
         let whole_num = if i == 0 {
             v / sizes[i]
@@ -982,6 +989,65 @@ fn format_duration(f: &mut Formatter, v: i64, sizes: &[i64], names: &[&str]) ->
     Ok(())
 }
 
+/// Write a duration in the humanized form that is used to display it, e.g. `1d 2h 3m`.
+#[cfg(feature = "dtype-duration")]
+pub fn fmt_duration_string<W: fmt::Write>(f: &mut W, v: i64, tu: TimeUnit) -> fmt::Result {
+    match tu {
+        TimeUnit::Nanoseconds => fmt_duration_ns(f, v),
+        TimeUnit::Microseconds => fmt_duration_us(f, v),
+        TimeUnit::Milliseconds => fmt_duration_ms(f, v),
+    }
+}
+
+/// Write a duration as an ISO 8601 duration, e.g. `P1DT2H3M4.5S`.
+///
+/// Days are assumed to be 24 hours long, and negative durations are prefixed with a `-`.
+#[cfg(feature = "dtype-duration")]
+pub fn iso_duration_string<W: fmt::Write>(f: &mut W, v: i64, tu: TimeUnit) -> fmt::Result {
+    if v == 0 {
+        return write!(f, "PT0S");
+    }
+    let (per_second, frac_digits) = match tu {
+        TimeUnit::Nanoseconds => (1_000_000_000, 9),
+        TimeUnit::Microseconds => (1_000_000, 6),
+        TimeUnit::Milliseconds => (1_000, 3),
+    };
+    if v < 0 {
+        write!(f, "-")?;
+    }
+    let v = v.unsigned_abs();
+    let seconds_total = v / per_second;
+    let frac = v % per_second;
+    let days = seconds_total / 86_400;
+    let hours = (seconds_total % 86_400) / 3_600;
+    let minutes = (seconds_total % 3_600) / 60;
+    let seconds = seconds_total % 60;
+
+    write!(f, "P")?;
+    if days > 0 {
+        write!(f, "{days}D")?;
+    }
+    if hours == 0 && minutes == 0 && seconds == 0 && frac == 0 {
+        return Ok(());
+    }
+    write!(f, "T")?;
+    if hours > 0 {
+        write!(f, "{hours}H")?;
+    }
+    if minutes > 0 {
+        write!(f, "{minutes}M")?;
+    }
+    if seconds > 0 || frac > 0 {
+        write!(f, "{seconds}")?;
+        if frac > 0 {
+            let frac = format!("{frac:0frac_digits$}");
+            write!(f, ".{}", frac.trim_end_matches('0'))?;
+        }
+        write!(f, "S")?;
+    }
+    Ok(())
+}
+
 fn format_blob(f: &mut Formatter<'_>, bytes: &[u8]) -> fmt::Result {
     let width = get_str_len_limit() * 2;
     write!(f, "b\"")?;
@@ -1014,6 +1080,10 @@ impl Display for AnyValue<'_> {
             AnyValue::Int16(v) => fmt_integer(f, width, *v),
             AnyValue::Int32(v) => fmt_integer(f, width, *v),
             AnyValue::Int64(v) => fmt_integer(f, width, *v),
+            #[cfg(feature = "dtype-i128")]
+            AnyValue::Int128(v) => fmt_integer(f, width, *v),
+            #[cfg(feature = "dtype-u128")]
+            AnyValue::UInt128(v) => fmt_integer(f, width, *v),
             AnyValue::Float32(v) => fmt_float(f, width, *v),
             AnyValue::Float64(v) => fmt_float(f, width, *v),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
@@ -1038,11 +1108,7 @@ impl Display for AnyValue<'_> {
                 }
             },
             #[cfg(feature = "dtype-duration")]
-            AnyValue::Duration(v, tu) => match tu {
-                TimeUnit::Nanoseconds => fmt_duration_ns(f, *v),
-                TimeUnit::Microseconds => fmt_duration_us(f, *v),
-                TimeUnit::Milliseconds => fmt_duration_ms(f, *v),
-            },
+            AnyValue::Duration(v, tu) => fmt_duration_string(f, *v, *tu),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
@@ -34,8 +34,51 @@ pub struct UnpivotArgs {
     pub streamable: bool,
 }
 
+/// Options for [`DataFrame::explode_with_options`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct ExplodeOptions {
+    /// If the lists of the exploded columns have a different number of elements in a row, pad the
+    /// shorter lists with nulls instead of raising an error.
+    pub pad_with_nulls: bool,
+}
+
+/// The number of rows a list explodes into. Empty and null lists explode into a single null.
+#[inline]
+fn exploded_len(offsets: &[i64], row: usize) -> usize {
+    ((offsets[row + 1] - offsets[row]) as usize).max(1)
+}
+
+/// The first row in which the exploded columns have a different number of elements, together
+/// with the index of the column that differs from the first column.
+fn first_length_mismatch(
+    exploded_columns: &[(Series, OffsetsBuffer<i64>)],
+) -> Option<(usize, usize)> {
+    let first = exploded_columns[0].1.as_slice();
+    exploded_columns
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, (_, offsets))| offsets.as_slice() != first)
+        .filter_map(|(col, (_, offsets))| {
+            let offsets = offsets.as_slice();
+            (0..first.len() - 1)
+                .find(|&row| exploded_len(first, row) != exploded_len(offsets, row))
+                .map(|row| (row, col))
+        })
+        .min()
+}
+
 impl DataFrame {
-    pub fn explode_impl(&self, mut columns: Vec<Series>) -> PolarsResult<DataFrame> {
+    pub fn explode_impl(&self, columns: Vec<Series>) -> PolarsResult<DataFrame> {
+        self.explode_impl_with_options(columns, ExplodeOptions::default())
+    }
+
+    pub fn explode_impl_with_options(
+        &self,
+        mut columns: Vec<Series>,
+        options: ExplodeOptions,
+    ) -> PolarsResult<DataFrame> {
         polars_ensure!(!columns.is_empty(), InvalidOperation: "no columns provided in explode");
         let mut df = self.clone();
         if self.is_empty() {
@@ -63,55 +106,107 @@ impl DataFrame {
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
 
-        fn process_column(
-            original_df: &DataFrame,
-            df: &mut DataFrame,
-            exploded: Series,
-        ) -> PolarsResult<()> {
-            if exploded.len() == df.height() || df.width() == 0 {
-                let col_idx = original_df.check_name_to_idx(exploded.name())?;
-                df.columns.insert(col_idx, exploded);
-            } else {
-                polars_bail!(
-                    ShapeMismatch: "exploded column(s) {:?} doesn't have the same length: {} \
-                    as the dataframe: {}", exploded.name(), exploded.name(), df.height(),
-                );
+        // The columns are exploded in lockstep, so every row must explode into the same number
+        // of rows in all columns.
+        if let Some((row, col)) = first_length_mismatch(&exploded_columns) {
+            if options.pad_with_nulls {
+                return self.explode_padded(df, exploded_columns);
             }
-            Ok(())
+            polars_bail!(
+                ShapeMismatch: "exploded columns must have matching element counts, got {} \
+                elements in column {:?} and {} elements in column {:?} in row {}",
+                exploded_len(exploded_columns[0].1.as_slice(), row), columns[0].name(),
+                exploded_len(exploded_columns[col].1.as_slice(), row), columns[col].name(), row,
+            );
         }
 
-        let check_offsets = || {
-            let first_offsets = exploded_columns[0].1.as_slice();
-            for (_, offsets) in &exploded_columns[1..] {
-                polars_ensure!(first_offsets == offsets.as_slice(),
-                    ShapeMismatch: "exploded columns must have matching element counts"
-                )
-            }
-            Ok(())
-        };
-        let process_first = || {
-            let (exploded, offsets) = &exploded_columns[0];
-
-            let row_idx = offsets_to_indexes(offsets.as_slice(), exploded.len());
-            let mut row_idx = IdxCa::from_vec("", row_idx);
-            row_idx.set_sorted_flag(IsSorted::Ascending);
-
-            // SAFETY:
-            // We just created indices that are in bounds.
-            let mut df = unsafe { df.take_unchecked(&row_idx) };
-            process_column(self, &mut df, exploded.clone())?;
-            PolarsResult::Ok(df)
-        };
-        let (df, result) = POOL.join(process_first, check_offsets);
-        let mut df = df?;
-        result?;
+        let (exploded, offsets) = &exploded_columns[0];
+        let row_idx = offsets_to_indexes(offsets.as_slice(), exploded.len());
+        let mut row_idx = IdxCa::from_vec("", row_idx);
+        row_idx.set_sorted_flag(IsSorted::Ascending);
 
-        for (exploded, _) in exploded_columns.into_iter().skip(1) {
-            process_column(self, &mut df, exploded)?
+        // SAFETY:
+        // We just created indices that are in bounds.
+        let mut df = unsafe { df.take_unchecked(&row_idx) };
+        for (exploded, _) in exploded_columns {
+            self.insert_exploded(&mut df, exploded)?
         }
 
         Ok(df)
     }
+
+    /// Explode the columns in lockstep, where every row explodes into the largest number of
+    /// elements of the columns in that row and the shorter lists are padded with nulls.
+    fn explode_padded(
+        &self,
+        df: DataFrame,
+        exploded_columns: Vec<(Series, OffsetsBuffer<i64>)>,
+    ) -> PolarsResult<DataFrame> {
+        let offsets = exploded_columns
+            .iter()
+            .map(|(_, offsets)| offsets.as_slice())
+            .collect::<Vec<_>>();
+        let capacity = exploded_columns
+            .iter()
+            .map(|(exploded, _)| exploded.len())
+            .max()
+            .unwrap_or(0);
+
+        let mut row_idx = Vec::with_capacity(capacity);
+        let mut gather_idx = vec![Vec::<Option<IdxSize>>::with_capacity(capacity); offsets.len()];
+        // The position in the exploded column at which the elements of the current row start.
+        let mut positions = vec![0 as IdxSize; offsets.len()];
+        for row in 0..self.height() {
+            let n_rows = offsets
+                .iter()
+                .map(|offsets| exploded_len(offsets, row))
+                .max()
+                .unwrap();
+            for ((offsets, gather_idx), position) in
+                offsets.iter().zip(&mut gather_idx).zip(&mut positions)
+            {
+                let len = exploded_len(offsets, row);
+                gather_idx
+                    .extend((0..n_rows).map(|i| (i < len).then_some(*position + i as IdxSize)));
+                *position += len as IdxSize;
+            }
+            row_idx.extend(std::iter::repeat(row as IdxSize).take(n_rows));
+        }
+
+        let mut row_idx = IdxCa::from_vec("", row_idx);
+        row_idx.set_sorted_flag(IsSorted::Ascending);
+        // SAFETY:
+        // The row indices are in bounds.
+        let mut df = unsafe { df.take_unchecked(&row_idx) };
+
+        let exploded_columns = POOL.install(|| {
+            exploded_columns
+                .into_par_iter()
+                .zip(gather_idx)
+                .map(|((exploded, _), gather_idx)| {
+                    let gather_idx = IdxCa::from_iter_options("", gather_idx.into_iter());
+                    exploded.take(&gather_idx)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        for exploded in exploded_columns {
+            self.insert_exploded(&mut df, exploded)?
+        }
+        Ok(df)
+    }
+
+    /// Insert an exploded column in `df` at the position the column has in `self`.
+    fn insert_exploded(&self, df: &mut DataFrame, exploded: Series) -> PolarsResult<()> {
+        polars_ensure!(
+            exploded.len() == df.height() || df.width() == 0,
+            ShapeMismatch: "exploded column(s) {:?} doesn't have the same length: {} \
+            as the dataframe: {}", exploded.name(), exploded.len(), df.height(),
+        );
+        let col_idx = self.check_name_to_idx(exploded.name())?;
+        df.columns.insert(col_idx, exploded);
+        Ok(())
+    }
+
     /// Explode `DataFrame` to long format by exploding a column with Lists.
     ///
     /// # Example
@@ -182,6 +277,23 @@ impl DataFrame {
         self.explode_impl(columns)
     }
 
+    /// Explode `DataFrame` to long format by exploding columns with Lists, see [`ExplodeOptions`].
+    ///
+    /// Multiple columns are exploded in lockstep: the `n`-th element of every list ends up in the
+    /// same row.
+    pub fn explode_with_options<I, S>(
+        &self,
+        columns: I,
+        options: ExplodeOptions,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let columns = self.select_series(columns)?;
+        self.explode_impl_with_options(columns, options)
+    }
+
     ///
     /// Unpivot a `DataFrame` from wide to long format.
     ///
@@ -431,6 +543,58 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_lockstep() -> PolarsResult<()> {
+        let a = Series::new(
+            "a",
+            [
+                Series::new("", [1i32, 2]),
+                Series::new("", [3i32]),
+                Series::new("", [4i32, 5, 6]),
+            ],
+        );
+        let b = Series::new(
+            "b",
+            [
+                Series::new("", ["x", "y"]),
+                Series::new("", ["z", "w"]),
+                Series::new("", ["v"]),
+            ],
+        );
+        let c = Series::new("c", [1i32, 2, 3]);
+        let df = DataFrame::new(vec![a, c, b])?;
+
+        let err = df.explode(["a", "b"]).unwrap_err();
+        assert!(err.to_string().contains("in row 1"));
+
+        let out = df.explode_with_options(
+            ["b", "a"],
+            ExplodeOptions {
+                pad_with_nulls: true,
+            },
+        )?;
+        let expected = df![
+            "a" => [Some(1), Some(2), Some(3), None, Some(4), Some(5), Some(6)],
+            "c" => [1, 1, 2, 2, 3, 3, 3],
+            "b" => [Some("x"), Some("y"), Some("z"), Some("w"), Some("v"), None, None],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        // Sliced lists with the same lengths don't have the same offsets.
+        let df = DataFrame::new(vec![
+            df.column("a")?.slice(0, 1),
+            df.column("b")?.slice(1, 1),
+        ])?;
+        let out = df.explode(["a", "b"])?;
+        let expected = df![
+            "a" => [1, 2],
+            "b" => ["z", "w"],
+        ]?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_unpivot() -> PolarsResult<()> {
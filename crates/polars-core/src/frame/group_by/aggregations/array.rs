@@ -0,0 +1,72 @@
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+
+use super::*;
+
+impl ArrayChunked {
+    /// Element-wise mean of the arrays in every group.
+    ///
+    /// Null arrays and null elements are ignored. An element is null if it has no valid
+    /// values in the group.
+    pub(crate) unsafe fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        let width = self.width();
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let values = ca.get_inner().cast(&DataType::Float64).unwrap();
+        let values = values.f64().unwrap().rechunk();
+        let values = values.downcast_iter().next().unwrap();
+
+        let mut sums = vec![0.0f64; width];
+        let mut counts = vec![0usize; width];
+        let mut out_values = Vec::with_capacity(groups.len() * width);
+        let mut out_inner_validity = MutableBitmap::with_capacity(groups.len() * width);
+        let mut out_validity = MutableBitmap::with_capacity(groups.len());
+
+        let mut agg_group = |rows: &mut dyn Iterator<Item = usize>| {
+            sums.iter_mut().for_each(|v| *v = 0.0);
+            counts.iter_mut().for_each(|v| *v = 0);
+            let mut any_valid = false;
+            for row in rows.filter(|row| arr.is_valid(*row)) {
+                any_valid = true;
+                for (j, (sum, count)) in sums.iter_mut().zip(counts.iter_mut()).enumerate() {
+                    let idx = row * width + j;
+                    if values.is_valid(idx) {
+                        *sum += values.value(idx);
+                        *count += 1;
+                    }
+                }
+            }
+            out_validity.push(any_valid);
+            for (sum, count) in sums.iter().zip(counts.iter()) {
+                out_values.push(*sum / *count as f64);
+                out_inner_validity.push(*count > 0);
+            }
+        };
+
+        match groups {
+            GroupsProxy::Idx(groups) => {
+                for idx in groups.all() {
+                    agg_group(&mut idx2usize(idx));
+                }
+            },
+            GroupsProxy::Slice { groups, .. } => {
+                for &[first, len] in groups {
+                    agg_group(&mut (first as usize..(first + len) as usize));
+                }
+            },
+        }
+
+        let values = PrimitiveArray::from_vec(out_values).with_validity(out_inner_validity.into());
+        let dtype = DataType::Array(Box::new(DataType::Float64), width);
+        let out =
+            FixedSizeListArray::new(dtype.to_arrow(true), values.boxed(), out_validity.into());
+        // SAFETY: the values are of type Float64.
+        let out =
+            unsafe { ArrayChunked::from_chunks_and_dtype(self.name(), vec![out.boxed()], dtype) };
+        match self.inner_dtype() {
+            DataType::Float32 => out
+                .cast(&DataType::Array(Box::new(DataType::Float32), width))
+                .unwrap(),
+            _ => out.into_series(),
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "dtype-decimal")]
+use crate::chunked_array::ops::arity::binary_elementwise;
 
 // implemented on the series because we don't need types
 impl Series {
@@ -175,6 +177,29 @@ impl Series {
                 * (MS_IN_DAY as f64))
                 .cast(&Datetime(TimeUnit::Milliseconds, None))
                 .unwrap(),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => {
+                // Stay exact: divide the decimal sums by the counts, rounding at the scale of
+                // the input.
+                let ca = s.decimal().unwrap();
+                let sum = s.agg_sum(groups);
+                let count = s.agg_valid_count(groups);
+                let count = count.idx().unwrap();
+                let mean: Int128Chunked =
+                    binary_elementwise(&sum.decimal().unwrap().0, count, |sum, count| {
+                        match (sum, count) {
+                            (Some(sum), Some(count)) if count > 0 => {
+                                Some(div_round_half_away(sum, count as i128))
+                            },
+                            _ => None,
+                        }
+                    });
+                mean.with_name(s.name())
+                    .into_decimal_unchecked(ca.precision(), ca.scale())
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-array")]
+            Array(inner, _) if inner.is_numeric() => s.array().unwrap().agg_mean(groups),
             _ => Series::full_null("", groups.len(), s.dtype()),
         }
     }
@@ -1,4 +1,6 @@
 mod agg_list;
+#[cfg(feature = "dtype-array")]
+mod array;
 mod boolean;
 mod dispatch;
 mod string;
@@ -3,6 +3,7 @@ use std::hash::{BuildHasher, Hash, Hasher};
 use hashbrown::hash_map::RawEntryMut;
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
 use polars_utils::idx_vec::IdxVec;
+use polars_utils::interrupt::InterruptCheck;
 use polars_utils::sync::SyncPtr;
 use polars_utils::total_ord::{ToTotalOrd, TotalHash};
 use polars_utils::unitvec;
@@ -10,7 +11,7 @@ use rayon::prelude::*;
 
 use crate::hashing::*;
 use crate::prelude::*;
-use crate::utils::flatten;
+use crate::utils::{_split_offsets, flatten};
 use crate::POOL;
 
 fn get_init_size() -> usize {
@@ -119,6 +120,135 @@ where
     }
 }
 
+/// The number of keys that is sampled to estimate their cardinality.
+const RADIX_SAMPLE_SIZE: usize = 4096;
+/// The estimated number of groups above which the keys are radix partitioned.
+const RADIX_MIN_GROUPS: usize = 1 << 18;
+/// The number of keys per radix partition, such that its hash table fits in cache.
+const RADIX_PARTITION_SIZE: usize = 1 << 15;
+const RADIX_MAX_PARTITIONS: usize = 1 << 10;
+
+/// Whether the keys likely have so many distinct values that the hash tables of the threaded
+/// group by outgrow the caches.
+fn use_radix_partitioning<T>(keys: &[&[T]]) -> bool
+where
+    T: ToTotalOrd,
+    <T as ToTotalOrd>::TotalOrdItem: Hash + Eq,
+{
+    if std::env::var("POLARS_NO_RADIX_GROUP_BY").is_ok() {
+        return false;
+    }
+    if std::env::var("POLARS_FORCE_RADIX_GROUP_BY").is_ok() {
+        return true;
+    }
+    let len = keys.iter().map(|k| k.len()).sum::<usize>();
+    if len < RADIX_MIN_GROUPS {
+        return false;
+    }
+    let stride = len / RADIX_SAMPLE_SIZE;
+    let mut counts = PlHashMap::with_capacity(RADIX_SAMPLE_SIZE);
+    for k in keys.iter().flat_map(|keys| keys.iter().step_by(stride)) {
+        *counts.entry(k.to_total_ord()).or_insert(0u32) += 1;
+    }
+    // Chao1 estimator of the number of distinct keys, based on the keys that are sampled once
+    // and twice.
+    let f1 = counts.values().filter(|c| **c == 1).count() as f64;
+    let f2 = counts.values().filter(|c| **c == 2).count() as f64;
+    let estimated_groups = counts.len() as f64 + f1 * (f1 - 1.0).max(0.0) / (2.0 * (f2 + 1.0));
+    estimated_groups >= RADIX_MIN_GROUPS as f64
+}
+
+/// Group by for keys of high cardinality.
+///
+/// The threaded group by builds a hash table per thread and every thread traverses all keys to
+/// pick those of its partition. With many distinct keys those tables don't fit in cache. Here
+/// the keys are first scattered to many small partitions in parallel, after which every
+/// partition builds its own hash table.
+pub(crate) fn group_by_radix_partitioned<T>(keys: &[&[T]], sorted: bool) -> GroupsProxy
+where
+    T: ToTotalOrd + Sync,
+    <T as ToTotalOrd>::TotalOrdItem: Send + Hash + Eq + Sync + Copy + DirtyHash,
+{
+    let n_threads = POOL.current_num_threads();
+    let len = keys.iter().map(|k| k.len()).sum::<usize>();
+    let n_partitions = (len / RADIX_PARTITION_SIZE)
+        .clamp(n_threads, RADIX_MAX_PARTITIONS.max(n_threads))
+        .next_power_of_two();
+
+    // Split the keys so that the scatter runs on all threads, even for a single chunk.
+    let mut splits = Vec::with_capacity(keys.len() * n_threads);
+    let mut offset = 0;
+    for keys in keys {
+        for (split_offset, split_len) in _split_offsets(keys.len(), n_threads) {
+            splits.push((
+                offset + split_offset as IdxSize,
+                &keys[split_offset..split_offset + split_len],
+            ));
+        }
+        offset += keys.len() as IdxSize;
+    }
+
+    // First pass: scatter the keys with their row index to the partitions.
+    let interrupt = InterruptCheck::current();
+    let scattered = POOL.install(|| {
+        splits
+            .into_par_iter()
+            .map(|(offset, keys)| {
+                interrupt.try_raise();
+                let mut partitions = (0..n_partitions)
+                    .map(|_| Vec::with_capacity(keys.len() / n_partitions))
+                    .collect::<Vec<_>>();
+                for (i, k) in keys.iter().enumerate() {
+                    let k = k.to_total_ord();
+                    let partition = hash_to_partition(k.dirty_hash(), n_partitions);
+                    // SAFETY: `hash_to_partition` is in bounds.
+                    unsafe { partitions.get_unchecked_mut(partition) }
+                        .push((k, offset + i as IdxSize));
+                }
+                partitions
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // Second pass: build a small hash table per partition. The splits are visited in order, so
+    // the indices of every group stay sorted.
+    let out = POOL.install(|| {
+        (0..n_partitions)
+            .into_par_iter()
+            .map(|partition| {
+                interrupt.try_raise();
+                let size = scattered.iter().map(|s| s[partition].len()).sum::<usize>();
+                let mut hash_tbl: PlHashMap<T::TotalOrdItem, (IdxSize, IdxVec)> =
+                    PlHashMap::with_capacity(size);
+                let hasher = hash_tbl.hasher().clone();
+                for split in &scattered {
+                    for &(k, idx) in &split[partition] {
+                        let hash = hasher.hash_one(k);
+                        let entry = hash_tbl.raw_entry_mut().from_key_hashed_nocheck(hash, &k);
+                        match entry {
+                            RawEntryMut::Vacant(entry) => {
+                                let tuples = unitvec![idx];
+                                entry.insert_with_hasher(hash, k, (idx, tuples), |k| {
+                                    hasher.hash_one(*k)
+                                });
+                            },
+                            RawEntryMut::Occupied(mut entry) => {
+                                let v = entry.get_mut();
+                                v.1.push(idx);
+                            },
+                        }
+                    }
+                }
+                hash_tbl
+                    .into_iter()
+                    .map(|(_k, v)| v)
+                    .collect_trusted::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    });
+    finish_group_order(out, sorted)
+}
+
 // giving the slice info to the compiler is much
 // faster than the using an iterator, that's why we
 // have the code duplication
@@ -128,15 +258,20 @@ pub(crate) fn group_by_threaded_slice<T, IntoSlice>(
     sorted: bool,
 ) -> GroupsProxy
 where
-    T: TotalHash + TotalEq + ToTotalOrd,
+    T: TotalHash + TotalEq + ToTotalOrd + Sync,
     <T as ToTotalOrd>::TotalOrdItem: Send + Hash + Eq + Sync + Copy + DirtyHash,
     IntoSlice: AsRef<[T]> + Send + Sync,
 {
+    let keys = keys.iter().map(|k| k.as_ref()).collect::<Vec<_>>();
+    if use_radix_partitioning(&keys) {
+        return group_by_radix_partitioned(&keys, sorted);
+    }
     let init_size = get_init_size();
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
     // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
+    let interrupt = InterruptCheck::current();
     let out = POOL.install(|| {
         (0..n_partitions)
             .into_par_iter()
@@ -146,7 +281,7 @@ where
 
                 let mut offset = 0;
                 for keys in &keys {
-                    let keys = keys.as_ref();
+                    interrupt.try_raise();
                     let len = keys.len() as IdxSize;
                     let hasher = hash_tbl.hasher().clone();
 
@@ -202,6 +337,7 @@ where
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
     // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
+    let interrupt = InterruptCheck::current();
     let out = POOL.install(|| {
         (0..n_partitions)
             .into_par_iter()
@@ -211,6 +347,7 @@ where
 
                 let mut offset = 0;
                 for keys in keys {
+                    interrupt.try_raise();
                     let keys = keys.clone().into_iter();
                     let len = keys.len() as IdxSize;
                     let hasher = hash_tbl.hasher().clone();
@@ -1185,4 +1185,29 @@ mod test {
         let _ = df.group_by(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_radix_partitioned() {
+        let a = (0..100_000u32).map(|i| i % 30_000).collect::<Vec<_>>();
+        let b = (0..50_000u32).map(|i| i * 7 % 40_000).collect::<Vec<_>>();
+
+        let GroupsProxy::Idx(expected) =
+            super::hashing::group_by(a.iter().chain(&b).copied(), true)
+        else {
+            unreachable!()
+        };
+        let GroupsProxy::Idx(out) =
+            super::hashing::group_by_radix_partitioned(&[a.as_slice(), b.as_slice()], true)
+        else {
+            unreachable!()
+        };
+        assert_eq!(out.first(), expected.first());
+        assert_eq!(out.all().len(), expected.all().len());
+        assert!(out
+            .all()
+            .iter()
+            .zip(expected.all())
+            .all(|(l, r)| l.as_slice() == r.as_slice()));
+    }
 }
@@ -23,9 +23,12 @@ pub mod group_by;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
+#[cfg(feature = "algorithm_group_by")]
+mod unique;
 mod upstream_traits;
 
 use arrow::record_batch::RecordBatch;
+use polars_utils::format_smartstring;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
@@ -45,6 +48,62 @@ pub enum NullStrategy {
     Propagate,
 }
 
+/// Options for [`DataFrame::unnest_with_options`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnnestOptions {
+    /// The number of levels of nested structs that are unnested. `None` unnests all levels.
+    pub max_depth: Option<usize>,
+    /// Join the names of the fields to the name of their parent with this separator. If `None`,
+    /// the fields keep their own names.
+    pub separator: Option<SmartString>,
+}
+
+impl Default for UnnestOptions {
+    fn default() -> Self {
+        UnnestOptions {
+            max_depth: Some(1),
+            separator: None,
+        }
+    }
+}
+
+impl UnnestOptions {
+    /// The name of `field` once it is unnested out of the struct called `parent`.
+    pub fn field_name(&self, parent: &str, field: &str) -> SmartString {
+        match &self.separator {
+            Some(separator) => format_smartstring!("{}{}{}", parent, separator, field),
+            None => field.into(),
+        }
+    }
+
+    /// Whether the structs at `depth` are unnested, where the unnested columns are at depth 1.
+    pub fn unnests_depth(&self, depth: usize) -> bool {
+        self.max_depth.map_or(true, |max_depth| depth <= max_depth)
+    }
+
+    /// The fields `field` is unnested into, in order.
+    pub fn unnest_field(&self, field: &Field) -> Vec<Field> {
+        let mut out = vec![];
+        self.unnest_field_impl(field, 1, &mut out);
+        out
+    }
+
+    fn unnest_field_impl(&self, field: &Field, depth: usize, out: &mut Vec<Field>) {
+        match field.data_type() {
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) if self.unnests_depth(depth) => {
+                for fld in fields {
+                    let mut fld = fld.clone();
+                    fld.name = self.field_name(field.name(), fld.name());
+                    self.unnest_field_impl(&fld, depth + 1, out);
+                }
+            },
+            _ => out.push(field.clone()),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UniqueKeepStrategy {
@@ -462,6 +521,28 @@ impl DataFrame {
         }
     }
 
+    /// Reallocate the columns whose buffers are much larger than the data they expose, e.g.
+    /// a small filtered subset of a large scan, to their minimal footprint.
+    ///
+    /// Returns the number of bytes this [`DataFrame`] no longer keeps alive. See
+    /// [`Series::compact`].
+    pub fn compact(&mut self) -> usize {
+        // Don't parallelize this. Memory overhead
+        self.columns.iter_mut().map(|s| s.compact()).sum()
+    }
+
+    /// Store repeated values of the `String` and `Binary` columns only once per chunk.
+    ///
+    /// See [`Series::deduplicate_strings`].
+    pub fn deduplicate_strings(&mut self) -> &mut Self {
+        self.columns = self
+            .columns
+            .iter()
+            .map(|s| s.deduplicate_strings())
+            .collect();
+        self
+    }
+
     /// Aggregate all the chunks in the DataFrame to a single chunk.
     pub fn as_single_chunk(&mut self) -> &mut Self {
         // Don't parallelize this. Memory overhead
@@ -1774,6 +1855,9 @@ impl DataFrame {
 
         // therefore when we try to set the first columns as sorted, we ignore the error
         // as expressions are not present (they are renamed to _POLARS_SORT_COLUMN_i.
+
+        // The limit only applies to sort indices, frames are sliced instead.
+        sort_options.limit = None;
         let first_descending = sort_options.descending[0];
         let first_by_column = by_column[0].name().to_string();
 
@@ -2725,6 +2809,12 @@ impl DataFrame {
         // take on multiple chunks is terrible
         df.as_single_chunk_par();
 
+        // Building the groups of multiple columns only to take the first or last row is wasteful.
+        if names.len() > 1 {
+            let idx = self.unique_idx(maintain_order, subset, keep, slice)?;
+            return Ok(unsafe { df.take_unchecked(&idx) });
+        }
+
         let columns = match (keep, maintain_order) {
             (UniqueKeepStrategy::First | UniqueKeepStrategy::Any, true) => {
                 let gb = df.group_by_stable(names)?;
@@ -2980,18 +3070,87 @@ impl DataFrame {
     /// inserted as columns.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest<I: IntoVec<String>>(&self, cols: I) -> PolarsResult<DataFrame> {
+        self.unnest_with_options(cols, &UnnestOptions::default())
+    }
+
+    /// Unnest the given `Struct` columns, and the `Struct` fields of those columns up to
+    /// [`UnnestOptions::max_depth`] levels deep.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// # use polars_core::prelude::*;
+    /// let options = UnnestOptions {
+    ///     max_depth: None,
+    ///     separator: Some(".".into()),
+    /// };
+    /// // A column "a" of struct {b: struct {c: i32}} becomes the column "a.b.c".
+    /// let out = df.unnest_with_options(["a"], &options)?;
+    /// ```
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with_options<I: IntoVec<String>>(
+        &self,
+        cols: I,
+        options: &UnnestOptions,
+    ) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            options.max_depth != Some(0),
+            InvalidOperation: "'max_depth' of unnest must be positive"
+        );
         let cols = cols.into_vec();
-        self.unnest_impl(cols.into_iter().collect())
+        self.unnest_impl(cols.into_iter().collect(), options)
+    }
+
+    /// Unnest the given `Struct` columns and their nested `Struct` fields, up to `max_depth`
+    /// levels deep or all levels if `None`. If a `separator` is given, the names of the new
+    /// columns are the names of their parents and their own name joined by the separator.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_recursive<I: IntoVec<String>>(
+        &self,
+        cols: I,
+        max_depth: Option<usize>,
+        separator: Option<&str>,
+    ) -> PolarsResult<DataFrame> {
+        let options = UnnestOptions {
+            max_depth,
+            separator: separator.map(SmartString::from),
+        };
+        self.unnest_with_options(cols, &options)
     }
 
     #[cfg(feature = "dtype-struct")]
-    fn unnest_impl(&self, cols: PlHashSet<String>) -> PolarsResult<DataFrame> {
+    fn unnest_impl(
+        &self,
+        cols: PlHashSet<String>,
+        options: &UnnestOptions,
+    ) -> PolarsResult<DataFrame> {
+        fn unnest_series(
+            s: &Series,
+            depth: usize,
+            options: &UnnestOptions,
+            new_cols: &mut Vec<Series>,
+        ) -> PolarsResult<()> {
+            match s.dtype() {
+                DataType::Struct(_) if options.unnests_depth(depth) => {
+                    for fld in s.struct_()?.fields() {
+                        let name = options.field_name(s.name(), fld.name());
+                        let mut fld = fld.clone();
+                        fld.rename(&name);
+                        unnest_series(&fld, depth + 1, options, new_cols)?;
+                    }
+                },
+                _ => new_cols.push(s.clone()),
+            }
+            Ok(())
+        }
+
         let mut new_cols = Vec::with_capacity(std::cmp::min(self.width() * 2, self.width() + 128));
         let mut count = 0;
         for s in &self.columns {
             if cols.contains(s.name()) {
-                let ca = s.struct_()?;
-                new_cols.extend_from_slice(ca.fields());
+                // Check that the column is a struct.
+                s.struct_()?;
+                unnest_series(s, 1, options, &mut new_cols)?;
                 count += 1;
             } else {
                 new_cols.push(s.clone())
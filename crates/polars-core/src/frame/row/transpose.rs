@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use arrow::array::Array;
+use arrow::bitmap::MutableBitmap;
 use either::Either;
 
 use super::*;
@@ -23,8 +25,21 @@ impl DataFrame {
             },
         };
 
-        let cols = &self.columns;
-        match dtype {
+        // Temporal columns are transposed as their physical integers. The transposed buffers are
+        // reinterpreted as the temporal dtype afterwards.
+        let physical_cols;
+        let cols = if dtype.is_temporal() {
+            physical_cols = self
+                .columns
+                .iter()
+                .map(|s| Ok(s.cast(dtype)?.to_physical_repr().into_owned()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            &physical_cols
+        } else {
+            &self.columns
+        };
+        let n_cols_t = cols_t.len();
+        match dtype.to_physical() {
             #[cfg(feature = "dtype-i8")]
             DataType::Int8 => numeric_transpose::<Int8Type>(cols, names_out, &mut cols_t),
             #[cfg(feature = "dtype-i16")]
@@ -35,7 +50,9 @@ impl DataFrame {
             DataType::UInt8 => numeric_transpose::<UInt8Type>(cols, names_out, &mut cols_t),
             #[cfg(feature = "dtype-u16")]
             DataType::UInt16 => numeric_transpose::<UInt16Type>(cols, names_out, &mut cols_t),
-            DataType::UInt32 => numeric_transpose::<UInt32Type>(cols, names_out, &mut cols_t),
+            DataType::UInt32 if !dtype.is_categorical() && !dtype.is_enum() => {
+                numeric_transpose::<UInt32Type>(cols, names_out, &mut cols_t)
+            },
             DataType::UInt64 => numeric_transpose::<UInt64Type>(cols, names_out, &mut cols_t),
             DataType::Float32 => numeric_transpose::<Float32Type>(cols, names_out, &mut cols_t),
             DataType::Float64 => numeric_transpose::<Float64Type>(cols, names_out, &mut cols_t),
@@ -44,41 +61,70 @@ impl DataFrame {
                 // this requires to support `Object` in Series::iter which we don't yet
                 polars_bail!(InvalidOperation: "Object dtype not supported in 'transpose'")
             },
-            _ => {
-                let phys_dtype = dtype.to_physical();
-                let mut buffers = (0..new_width)
-                    .map(|_| {
-                        let buf: AnyValueBufferTrusted = (&phys_dtype, new_height).into();
-                        buf
-                    })
-                    .collect::<Vec<_>>();
-
+            phys_dtype => {
                 let columns = self
                     .columns
                     .iter()
                     // first cast to supertype before casting to physical to ensure units are correct
-                    .map(|s| s.cast(dtype).unwrap().cast(&phys_dtype).unwrap())
-                    .collect::<Vec<_>>();
+                    .map(|s| {
+                        let s = s.cast(dtype)?.cast(&phys_dtype)?;
+                        polars_ensure!(
+                            s.dtype() == &phys_dtype,
+                            ComputeError: "cannot transpose with supertype: {}", dtype
+                        );
+                        Ok(s)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
 
-                // this is very expensive. A lot of cache misses here.
-                // This is the part that is performance critical.
-                for s in columns {
-                    polars_ensure!(s.dtype() == &phys_dtype, ComputeError: "cannot transpose with supertype: {}", dtype);
-                    s.iter().zip(buffers.iter_mut()).for_each(|(av, buf)| {
-                        // SAFETY: we checked the type and we borrow
-                        unsafe {
-                            buf.add_unchecked_borrowed_physical(&av);
-                        }
-                    });
-                }
-                cols_t.extend(buffers.into_iter().zip(names_out).map(|(buf, name)| {
-                    // SAFETY: we are casting back to the supertype
-                    let mut s = unsafe { buf.into_series().cast_unchecked(dtype).unwrap() };
-                    s.rename(name);
-                    s
-                }));
+                // Every task fills the buffers of a block of output columns, so that the tasks
+                // don't share buffers. Iterating a column as `AnyValue`s is expensive, so the
+                // input columns are sliced to the rows of the block.
+                let block_size = new_width.div_ceil(POOL.current_num_threads()).max(1);
+                let blocks = POOL.install(|| {
+                    (0..new_width)
+                        .into_par_iter()
+                        .step_by(block_size)
+                        .map(|offset| {
+                            let len = block_size.min(new_width - offset);
+                            let mut buffers = (0..len)
+                                .map(|_| {
+                                    let buf: AnyValueBufferTrusted =
+                                        (&phys_dtype, new_height).into();
+                                    buf
+                                })
+                                .collect::<Vec<_>>();
+                            for s in &columns {
+                                let s = s.slice(offset as i64, len);
+                                s.iter().zip(buffers.iter_mut()).for_each(|(av, buf)| {
+                                    // SAFETY: we checked the type and we borrow
+                                    unsafe {
+                                        buf.add_unchecked_borrowed_physical(&av);
+                                    }
+                                });
+                            }
+                            buffers
+                                .into_iter()
+                                .zip(&names_out[offset..offset + len])
+                                .map(|(buf, name)| {
+                                    // SAFETY: we are casting back to the supertype
+                                    let mut s =
+                                        unsafe { buf.into_series().cast_unchecked(dtype).unwrap() };
+                                    s.rename(name);
+                                    s
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>()
+                });
+                cols_t.extend(blocks.into_iter().flatten());
             },
         };
+        if dtype.is_temporal() {
+            for s in &mut cols_t[n_cols_t..] {
+                // SAFETY: the physical values were taken from columns of this dtype.
+                *s = unsafe { s.cast_unchecked(dtype)? };
+            }
+        }
         Ok(unsafe { DataFrame::new_no_checks(cols_t) })
     }
 
@@ -145,113 +191,77 @@ impl DataFrame {
     }
 }
 
-#[inline]
-unsafe fn add_value<T: NumericNative>(
-    values_buf_ptr: usize,
-    col_idx: usize,
-    row_idx: usize,
-    value: T,
-) {
-    let column = (*(values_buf_ptr as *mut Vec<Vec<T>>)).get_unchecked_mut(col_idx);
-    let el_ptr = column.as_mut_ptr();
-    *el_ptr.add(row_idx) = value;
-}
+/// The number of columns and rows of the tiles in which [`numeric_transpose`] copies values.
+/// A tile of the input columns fits in the cache, so every cache line is read only once.
+const TILE_SIZE: usize = 64;
 
 // This just fills a pre-allocated mutable series vector, which may have a name column.
 // Nothing is returned and the actual DataFrame is constructed above.
 pub(super) fn numeric_transpose<T>(cols: &[Series], names_out: &[String], cols_t: &mut Vec<Series>)
 where
     T: PolarsNumericType,
-    //S: AsRef<str>,
     ChunkedArray<T>: IntoSeries,
 {
     let new_width = cols[0].len();
     let new_height = cols.len();
 
-    let has_nulls = cols.iter().any(|s| s.null_count() > 0);
-
-    let mut values_buf: Vec<Vec<T::Native>> = (0..new_width)
-        .map(|_| Vec::with_capacity(new_height))
-        .collect();
-    let mut validity_buf: Vec<_> = if has_nulls {
-        // we first use bools instead of bits, because we can access these in parallel without aliasing
-        (0..new_width).map(|_| vec![true; new_height]).collect()
-    } else {
-        (0..new_width).map(|_| vec![]).collect()
-    };
-
-    // work with *mut pointers because we it is UB write to &refs.
-    let values_buf_ptr = &mut values_buf as *mut Vec<Vec<T::Native>> as usize;
-    let validity_buf_ptr = &mut validity_buf as *mut Vec<Vec<bool>> as usize;
-
-    POOL.install(|| {
-        cols.iter().enumerate().for_each(|(row_idx, s)| {
-            let s = s.cast(&T::get_dtype()).unwrap();
-            let ca = s.unpack::<T>().unwrap();
-
-            // SAFETY:
-            // we access in parallel, but every access is unique, so we don't break aliasing rules
-            // we also ensured we allocated enough memory, so we never reallocate and thus
-            // the pointers remain valid.
-            if has_nulls {
-                for (col_idx, opt_v) in ca.iter().enumerate() {
-                    match opt_v {
-                        None => unsafe {
-                            let column = (*(validity_buf_ptr as *mut Vec<Vec<bool>>))
-                                .get_unchecked_mut(col_idx);
-                            let el_ptr = column.as_mut_ptr();
-                            *el_ptr.add(row_idx) = false;
-                            // we must initialize this memory otherwise downstream code
-                            // might access uninitialized memory when the masked out values
-                            // are changed.
-                            add_value(values_buf_ptr, col_idx, row_idx, T::Native::default());
-                        },
-                        Some(v) => unsafe {
-                            add_value(values_buf_ptr, col_idx, row_idx, v);
-                        },
-                    }
-                }
-            } else {
-                for (col_idx, v) in ca.into_no_null_iter().enumerate() {
-                    unsafe {
-                        let column = (*(values_buf_ptr as *mut Vec<Vec<T::Native>>))
-                            .get_unchecked_mut(col_idx);
-                        let el_ptr = column.as_mut_ptr();
-                        *el_ptr.add(row_idx) = v;
-                    }
-                }
-            }
-        })
+    let cols = POOL.install(|| {
+        cols.par_iter()
+            .map(|s| {
+                let s = s.cast(&T::get_dtype()).unwrap();
+                s.unpack::<T>().unwrap().rechunk()
+            })
+            .collect::<Vec<_>>()
     });
+    let arrays = cols
+        .iter()
+        .map(|ca| ca.downcast_iter().next().unwrap())
+        .collect::<Vec<_>>();
+    let has_nulls = arrays.iter().any(|arr| arr.null_count() > 0);
 
-    let par_iter = values_buf
+    // Every task writes a block of output columns, reading the input columns tile by tile.
+    let block_size = TILE_SIZE.max(new_width.div_ceil(POOL.current_num_threads()));
+    let par_iter = (0..new_width)
         .into_par_iter()
-        .zip(validity_buf)
-        .zip(names_out)
-        .map(|((mut values, validity), name)| {
-            // SAFETY:
-            // all values are written we can now set len
-            unsafe {
-                values.set_len(new_height);
-            }
+        .step_by(block_size)
+        .flat_map_iter(|offset| {
+            let len = block_size.min(new_width - offset);
+            let mut values = (0..len)
+                .map(|_| Vec::with_capacity(new_height))
+                .collect::<Vec<Vec<T::Native>>>();
+            let mut validities = (0..len)
+                .map(|_| MutableBitmap::with_capacity(if has_nulls { new_height } else { 0 }))
+                .collect::<Vec<_>>();
 
-            let validity = if has_nulls {
-                let validity = Bitmap::from_trusted_len_iter(validity.iter().copied());
-                if validity.unset_bits() > 0 {
-                    Some(validity)
-                } else {
-                    None
+            for tile in arrays.chunks(TILE_SIZE) {
+                for (i, (values, validity)) in values.iter_mut().zip(&mut validities).enumerate() {
+                    let row = offset + i;
+                    // SAFETY: all input columns have `new_width` rows.
+                    values.extend(tile.iter().map(|arr| unsafe { arr.value_unchecked(row) }));
+                    if has_nulls {
+                        validity.extend(
+                            tile.iter()
+                                .map(|arr| unsafe { !arr.is_null_unchecked(row) }),
+                        );
+                    }
                 }
-            } else {
-                None
-            };
+            }
 
-            let arr = PrimitiveArray::<T::Native>::new(
-                T::get_dtype().to_arrow(true),
-                values.into(),
-                validity,
-            );
-            ChunkedArray::with_chunk(name.as_str(), arr).into_series()
+            values
+                .into_iter()
+                .zip(validities)
+                .zip(&names_out[offset..offset + len])
+                .map(|((values, validity), name)| {
+                    let validity: Bitmap = validity.into();
+                    let validity = (validity.unset_bits() > 0).then_some(validity);
+                    let arr = PrimitiveArray::<T::Native>::new(
+                        T::get_dtype().to_arrow(true),
+                        values.into(),
+                        validity,
+                    );
+                    ChunkedArray::with_chunk(name.as_str(), arr).into_series()
+                })
+                .collect::<Vec<_>>()
         });
     POOL.install(|| cols_t.par_extend(par_iter));
 }
@@ -303,4 +313,48 @@ mod test {
         assert!(out.equals_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_transpose_wide() -> PolarsResult<()> {
+        // More columns and rows than fit in a tile.
+        let columns = (0..150)
+            .map(|i| {
+                Series::new(
+                    &format!("c{i}"),
+                    (0..100)
+                        .map(|j| (j % 7 != i % 5).then_some(i * 1000 + j))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut df = DataFrame::new(columns)?;
+        let out = df.transpose(None, None)?;
+        assert_eq!(out.shape(), (150, 100));
+        for j in [0, 63, 64, 99] {
+            let s = out.column(&format!("column_{j}"))?;
+            for i in [0, 63, 64, 149] {
+                let expected = (j % 7 != i % 5).then_some(i * 1000 + j);
+                assert_eq!(s.i32()?.get(i as usize), expected);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_transpose_temporal() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [Some(1), None],
+            "b" => [Some(10), Some(20)],
+        ]?;
+        df.apply("a", |s| s.cast(&DataType::Date).unwrap())?;
+        df.apply("b", |s| s.cast(&DataType::Date).unwrap())?;
+        let out = df.transpose(None, None)?;
+        assert_eq!(out.dtypes(), &[DataType::Date, DataType::Date]);
+        assert_eq!(
+            out.column("column_1")?.to_physical_repr().i32()?.to_vec(),
+            &[None, Some(20)]
+        );
+        Ok(())
+    }
 }
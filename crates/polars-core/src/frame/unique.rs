@@ -0,0 +1,199 @@
+use ahash::RandomState;
+use arrow::array::BinaryArray;
+use hashbrown::hash_map::RawEntryMut;
+use polars_utils::hashing::hash_to_partition;
+use rayon::prelude::*;
+
+use crate::prelude::sort::arg_sort_multiple::encode_rows_vertical_par_unordered;
+use crate::prelude::*;
+use crate::utils::flatten::flatten_par;
+use crate::utils::slice_offsets;
+use crate::POOL;
+
+struct Occurrences {
+    first: IdxSize,
+    last: IdxSize,
+    count: IdxSize,
+}
+
+/// Get the indices of the rows to keep of the row-encoded keys in `rows`.
+///
+/// Every chunk is hashed in parallel and its rows are scattered to a partition by hash. The
+/// partitions are then deduplicated in parallel, each in its own hash table, so no table is
+/// shared between threads. Within a partition the rows are visited in order, so the first and
+/// last occurrences are exact.
+fn unique_idx_rows(rows: &BinaryOffsetChunked, keep: UniqueKeepStrategy) -> Vec<IdxSize> {
+    let n_partitions = POOL.current_num_threads();
+    let random_state = RandomState::new();
+    let arrays = rows.downcast_iter().collect::<Vec<_>>();
+    let mut offset = 0 as IdxSize;
+    let offsets = arrays
+        .iter()
+        .map(|arr| {
+            let chunk_offset = offset;
+            offset += arr.len() as IdxSize;
+            chunk_offset
+        })
+        .collect::<Vec<_>>();
+
+    // Per chunk, per partition the local indices and hashes of the rows.
+    let partitioned = POOL.install(|| {
+        arrays
+            .par_iter()
+            .map(|arr| {
+                let capacity = arr.len() / n_partitions + 1;
+                let mut partitions = (0..n_partitions)
+                    .map(|_| Vec::with_capacity(capacity))
+                    .collect::<Vec<_>>();
+                for (i, key) in arr.values_iter().enumerate() {
+                    let h = random_state.hash_one(key);
+                    partitions[hash_to_partition(h, n_partitions)].push((i as IdxSize, h));
+                }
+                partitions
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let kept = POOL.install(|| {
+        (0..n_partitions)
+            .into_par_iter()
+            .map(|partition| {
+                let mut table: PlHashMap<&[u8], Occurrences> =
+                    PlHashMap::with_hasher(random_state.clone());
+                for ((arr, partitions), &offset) in arrays.iter().zip(&partitioned).zip(&offsets) {
+                    let arr: &BinaryArray<i64> = arr;
+                    for &(i, h) in &partitions[partition] {
+                        // SAFETY: the index was taken from this array.
+                        let key = unsafe { arr.value_unchecked(i as usize) };
+                        let idx = offset + i;
+                        match table.raw_entry_mut().from_key_hashed_nocheck(h, key) {
+                            RawEntryMut::Vacant(entry) => {
+                                let occurrences = Occurrences {
+                                    first: idx,
+                                    last: idx,
+                                    count: 1,
+                                };
+                                entry.insert_hashed_nocheck(h, key, occurrences);
+                            },
+                            RawEntryMut::Occupied(mut entry) => {
+                                let occurrences = entry.get_mut();
+                                occurrences.last = idx;
+                                occurrences.count += 1;
+                            },
+                        }
+                    }
+                }
+                table
+                    .into_values()
+                    .filter_map(|o| match keep {
+                        UniqueKeepStrategy::First | UniqueKeepStrategy::Any => Some(o.first),
+                        UniqueKeepStrategy::Last => Some(o.last),
+                        UniqueKeepStrategy::None => (o.count == 1).then_some(o.first),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    });
+    flatten_par(&kept)
+}
+
+/// Get the first or last index of every group.
+fn groups_idx(groups: &GroupsProxy, keep_last: bool) -> Vec<IdxSize> {
+    groups
+        .iter()
+        .map(|g| match (g, keep_last) {
+            (GroupsIndicator::Idx((first, _)), false) => first,
+            (GroupsIndicator::Idx((_, idx)), true) => idx[idx.len() - 1],
+            (GroupsIndicator::Slice([first, _]), false) => first,
+            (GroupsIndicator::Slice([first, len]), true) => first + len - 1,
+        })
+        .collect()
+}
+
+impl DataFrame {
+    /// Get the indices of the rows that [`DataFrame::unique_impl`] keeps, in the order in which
+    /// they are returned.
+    ///
+    /// If `subset` consists of multiple columns, the rows are row-encoded and deduplicated in
+    /// hash partitions in parallel, see `unique_idx_rows`.
+    pub fn unique_idx(
+        &self,
+        maintain_order: bool,
+        subset: Option<&[String]>,
+        keep: UniqueKeepStrategy,
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<IdxCa> {
+        let keys = match subset {
+            Some(names) => self.select_series(names)?,
+            None => self.get_columns().to_vec(),
+        };
+        polars_ensure!(
+            !keys.is_empty(),
+            ComputeError: "at least one column is required in 'unique'"
+        );
+        // Columns of dtype `Null` are equal in every row.
+        let keys = keys
+            .into_iter()
+            .filter(|s| !s.dtype().is_null())
+            .collect::<Vec<_>>();
+
+        let mut idx = if keys.is_empty() {
+            match (self.height(), keep) {
+                (0, _) => vec![],
+                (1, _) => vec![0],
+                (_, UniqueKeepStrategy::None) => vec![],
+                (n, UniqueKeepStrategy::Last) => vec![n as IdxSize - 1],
+                (_, _) => vec![0],
+            }
+        } else if keys.len() == 1 || keys.iter().any(|s| s.dtype().is_object()) {
+            let df = unsafe { DataFrame::new_no_checks(keys) };
+            let gb = df.group_by_with_series(df.get_columns().to_vec(), true, false)?;
+            let groups = gb.get_groups();
+            match keep {
+                UniqueKeepStrategy::First | UniqueKeepStrategy::Any => groups_idx(groups, false),
+                UniqueKeepStrategy::Last => groups_idx(groups, true),
+                UniqueKeepStrategy::None => groups
+                    .iter()
+                    .filter(|g| g.len() == 1)
+                    .map(|g| g.first())
+                    .collect(),
+            }
+        } else {
+            let rows = encode_rows_vertical_par_unordered(&keys)?;
+            unique_idx_rows(&rows, keep)
+        };
+        if maintain_order {
+            POOL.install(|| idx.par_sort_unstable());
+        }
+        if let Some((offset, len)) = slice {
+            let (offset, len) = slice_offsets(offset, len, idx.len());
+            idx = idx[offset..offset + len].to_vec();
+        }
+        Ok(IdxCa::from_vec("index", idx))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_unique_idx_multiple_columns() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 1, 1, 2],
+            "b" => ["x", "x", "x", "y", "x"],
+        ]?;
+        let subset = ["a".to_string(), "b".to_string()];
+        let unique_idx = |keep| -> PolarsResult<Vec<_>> {
+            let idx = df.unique_idx(true, Some(&subset), keep, None)?;
+            Ok(idx.into_no_null_iter().collect())
+        };
+        assert_eq!(unique_idx(UniqueKeepStrategy::First)?, &[0, 1, 3]);
+        assert_eq!(unique_idx(UniqueKeepStrategy::Last)?, &[2, 3, 4]);
+        assert_eq!(unique_idx(UniqueKeepStrategy::None)?, &[3]);
+
+        let out = df.unique_stable(None, UniqueKeepStrategy::Last, Some((1, 5)))?;
+        assert_eq!(out.column("a")?.i32()?.to_vec(), &[Some(1), Some(2)]);
+        Ok(())
+    }
+}
@@ -13,3 +13,29 @@ pub(crate) fn get_global_random_u64() -> u64 {
 pub fn set_global_random_seed(seed: u64) {
     *POLARS_GLOBAL_RNG_STATE.lock().unwrap() = SmallRng::seed_from_u64(seed);
 }
+
+#[inline]
+fn splitmix64_mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Counter based random number for the row at global index `idx`.
+///
+/// Unlike a seeded generator this has no state, so the value of a row only depends on `seed`
+/// and its index. Partitions of the data (chunks, morsels, threads) can draw the numbers of
+/// their rows independently and still agree with a single pass over all rows.
+#[inline]
+pub fn counter_random_u64(seed: u64, idx: u64) -> u64 {
+    // SplitMix64 at position `idx` of the stream of the (scrambled) seed.
+    const GAMMA: u64 = 0x9E3779B97F4A7C15;
+    splitmix64_mix(splitmix64_mix(seed).wrapping_add(idx.wrapping_add(1).wrapping_mul(GAMMA)))
+}
+
+/// Counter based random index in `0..len` for draw `idx`.
+#[inline]
+pub fn counter_random_index(seed: u64, idx: u64, len: u64) -> u64 {
+    // Multiply-shift instead of a modulo to avoid the bias towards small indices.
+    ((counter_random_u64(seed, idx) as u128 * len as u128) >> 64) as u64
+}
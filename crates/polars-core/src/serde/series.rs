@@ -179,6 +179,16 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<u64>> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     },
+                    #[cfg(feature = "dtype-i128")]
+                    DataType::Int128 => {
+                        let values: Vec<Option<i128>> = map.next_value()?;
+                        Ok(Series::new(&name, values))
+                    },
+                    #[cfg(feature = "dtype-u128")]
+                    DataType::UInt128 => {
+                        let values: Vec<Option<u128>> = map.next_value()?;
+                        Ok(Series::new(&name, values))
+                    },
                     #[cfg(feature = "dtype-date")]
                     DataType::Date => {
                         let values: Vec<Option<i32>> = map.next_value()?;
@@ -120,6 +120,14 @@ impl Series {
             DataType::UInt64 => any_values_to_integer::<UInt64Type>(values, strict)?
                 .with_cheap_metadata()
                 .into_series(),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => any_values_to_integer::<Int128Type>(values, strict)?
+                .with_cheap_metadata()
+                .into_series(),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => any_values_to_integer::<UInt128Type>(values, strict)?
+                .with_cheap_metadata()
+                .into_series(),
             DataType::Float32 => any_values_to_f32(values, strict)?
                 .with_cheap_metadata()
                 .into_series(),
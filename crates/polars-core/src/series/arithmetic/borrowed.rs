@@ -1,3 +1,6 @@
+#[cfg(feature = "dtype-array")]
+use arrow::compute::utils::combine_validities_and;
+
 use super::*;
 
 pub trait NumOpsDispatchInner: PolarsDataType + Sized {
@@ -129,44 +132,94 @@ fn array_shape(dt: &DataType, infer: bool) -> Vec<i64> {
     buf
 }
 
+/// Apply `func` to the leaf values of array columns.
+///
+/// Both sides may be arrays of the same shape, or one side may be a numeric column whose
+/// values are broadcast over the elements of the array in the same row. Columns of length 1
+/// are broadcast over all rows.
 #[cfg(feature = "dtype-array")]
-impl ArrayChunked {
-    fn arithm_helper(
-        &self,
-        rhs: &Series,
-        op: &dyn Fn(Series, Series) -> PolarsResult<Series>,
-    ) -> PolarsResult<Series> {
-        let l_leaf_array = self.clone().into_series().get_leaf_array();
-        let shape = array_shape(self.dtype(), true);
-
-        let r_leaf_array = if rhs.dtype().is_numeric() && rhs.len() == 1 {
-            rhs.clone()
+pub fn _array_arithmetic<F: Fn(&Series, &Series) -> PolarsResult<Series>>(
+    lhs: &Series,
+    rhs: &Series,
+    func: F,
+) -> PolarsResult<Series> {
+    let len = if lhs.len() == 1 { rhs.len() } else { lhs.len() };
+    let broadcast = |s: &Series| {
+        if s.len() == len {
+            s.rechunk()
         } else {
-            polars_ensure!(self.dtype() == rhs.dtype(), InvalidOperation: "can only do arithmetic of array's of the same type and shape; got {} and {}", self.dtype(), rhs.dtype());
-            rhs.get_leaf_array()
-        };
+            s.new_from_index(0, len)
+        }
+    };
+    // Repeat every value of a numeric column for all leaf elements of a row.
+    let repeat = |s: &Series, n_leaves: usize| -> PolarsResult<Series> {
+        if s.len() == 1 {
+            return Ok(s.clone());
+        }
+        let idx = (0..len * n_leaves)
+            .map(|i| (i / n_leaves) as IdxSize)
+            .collect::<Vec<_>>();
+        s.take_slice(&idx)
+    };
 
-        let out = op(l_leaf_array, r_leaf_array)?;
-        out.reshape_array(&shape)
+    let (lhs, rhs) = (broadcast(lhs), broadcast(rhs));
+    let (shape, out, validity) = match (lhs.dtype(), rhs.dtype()) {
+        (DataType::Array(_, _), DataType::Array(_, _)) => {
+            let shape = array_shape(lhs.dtype(), true);
+            polars_ensure!(
+                shape == array_shape(rhs.dtype(), true),
+                InvalidOperation: "can only do arithmetic on arrays of the same shape; got {} and {}",
+                lhs.dtype(), rhs.dtype()
+            );
+            let validity =
+                combine_validities_and(lhs.chunks()[0].validity(), rhs.chunks()[0].validity());
+            let out = func(&lhs.get_leaf_array(), &rhs.get_leaf_array())?;
+            (shape, out, validity)
+        },
+        (DataType::Array(_, _), dt) if dt.is_numeric() => {
+            let shape = array_shape(lhs.dtype(), true);
+            let n_leaves = shape[1..].iter().product::<i64>() as usize;
+            let out = func(&lhs.get_leaf_array(), &repeat(&rhs, n_leaves)?)?;
+            (shape, out, lhs.chunks()[0].validity().cloned())
+        },
+        (dt, DataType::Array(_, _)) if dt.is_numeric() => {
+            let shape = array_shape(rhs.dtype(), true);
+            let n_leaves = shape[1..].iter().product::<i64>() as usize;
+            let out = func(&repeat(&lhs, n_leaves)?, &rhs.get_leaf_array())?;
+            (shape, out, rhs.chunks()[0].validity().cloned())
+        },
+        (l, r) => polars_bail!(InvalidOperation: "arithmetic not supported on {} and {}", l, r),
+    };
+
+    let out = out.reshape_array(&shape)?.rechunk();
+    if validity.is_none() {
+        return Ok(out.with_name(lhs.name()));
     }
+    let arr = out.array()?.downcast_iter().next().unwrap();
+    let arr = arr.clone().with_validity(validity);
+    // SAFETY: only the outer validity changed.
+    let ca = unsafe {
+        ArrayChunked::from_chunks_and_dtype(lhs.name(), vec![arr.boxed()], out.dtype().clone())
+    };
+    Ok(ca.into_series())
 }
 
 #[cfg(feature = "dtype-array")]
 impl NumOpsDispatchInner for FixedSizeListType {
     fn add_to(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.add_to(&r))
+        _array_arithmetic(&lhs.clone().into_series(), rhs, |l, r| l + r)
     }
     fn subtract(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.subtract(&r))
+        _array_arithmetic(&lhs.clone().into_series(), rhs, |l, r| l - r)
     }
     fn multiply(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.multiply(&r))
+        _array_arithmetic(&lhs.clone().into_series(), rhs, |l, r| l * r)
     }
     fn divide(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.divide(&r))
+        _array_arithmetic(&lhs.clone().into_series(), rhs, |l, r| l / r)
     }
     fn remainder(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.remainder(&r))
+        _array_arithmetic(&lhs.clone().into_series(), rhs, |l, r| l % r)
     }
 }
 
@@ -492,6 +545,10 @@ impl Add for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.add(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(..), _) | (_, DataType::Array(..)) => {
+                _array_arithmetic(self, rhs, |a, b| a.add(b))
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
                 lhs.add_to(rhs.as_ref())
@@ -510,6 +567,10 @@ impl Sub for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.sub(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(..), _) | (_, DataType::Array(..)) => {
+                _array_arithmetic(self, rhs, |a, b| a.sub(b))
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
                 lhs.subtract(rhs.as_ref())
@@ -533,6 +594,8 @@ impl Mul for &Series {
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (Struct(_), Struct(_)) => _struct_arithmetic(self, rhs, |a, b| a.mul(b)),
+            #[cfg(feature = "dtype-array")]
+            (Array(..), _) | (_, Array(..)) => _array_arithmetic(self, rhs, |a, b| a.mul(b)),
             // temporal lh
             (Duration(_), _) | (Date, _) | (Datetime(_, _), _) | (Time, _) => self.multiply(rhs),
             // temporal rhs
@@ -568,6 +631,8 @@ impl Div for &Series {
             (Struct(_), Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.div(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (Array(..), _) | (_, Array(..)) => _array_arithmetic(self, rhs, |a, b| a.div(b)),
             (Duration(_), _) => self.divide(rhs),
             | (Date, _)
             | (Datetime(_, _), _)
@@ -601,6 +666,10 @@ impl Rem for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.rem(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(..), _) | (_, DataType::Array(..)) => {
+                _array_arithmetic(self, rhs, |a, b| a.rem(b))
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
                 lhs.remainder(rhs.as_ref())
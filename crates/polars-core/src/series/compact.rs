@@ -0,0 +1,211 @@
+use arrow::array::growable::make_growable;
+use arrow::array::{Array, BinaryViewArrayGeneric, View, ViewType, INLINE_VIEW_SIZE};
+use arrow::compute::aggregate::{allocated_bytes_size, estimated_bytes_size};
+use arrow::datatypes::PhysicalType;
+
+use crate::prelude::*;
+
+/// Chunks are only reallocated if that frees at least this many bytes.
+const COMPACT_MIN_SAVINGS: usize = 16 * 1024;
+
+/// Lower bound of the bytes needed to store the visible data of a view array.
+fn binview_needed_size<T: ViewType + ?Sized>(array: &BinaryViewArrayGeneric<T>) -> usize {
+    // Strings up to 12 bytes are inlined in the views.
+    let len = array.len();
+    len * std::mem::size_of::<View>()
+        + array
+            .total_bytes_len()
+            .saturating_sub(len * INLINE_VIEW_SIZE as usize)
+}
+
+fn needed_bytes_size(array: &dyn Array) -> usize {
+    match array.data_type().to_physical_type() {
+        PhysicalType::BinaryView => {
+            binview_needed_size::<[u8]>(array.as_any().downcast_ref().unwrap())
+        },
+        PhysicalType::Utf8View => {
+            binview_needed_size::<str>(array.as_any().downcast_ref().unwrap())
+        },
+        _ => estimated_bytes_size(array),
+    }
+}
+
+fn is_oversized(array: &dyn Array) -> bool {
+    let allocated = allocated_bytes_size(array);
+    let needed = needed_bytes_size(array);
+    allocated.saturating_sub(needed) >= COMPACT_MIN_SAVINGS && allocated >= 2 * needed
+}
+
+fn copy_array(array: &dyn Array) -> ArrayRef {
+    let mut growable = make_growable(&[array], array.null_count() > 0, array.len());
+    // SAFETY: we extend with the whole array.
+    unsafe { growable.extend(0, 0, array.len()) };
+    growable.as_box()
+}
+
+fn compact_binview<T: ViewType + ?Sized>(array: &BinaryViewArrayGeneric<T>) -> ArrayRef {
+    let array = array.clone().gc();
+    // Arrays without data buffers are returned as is by `gc`.
+    if array.is_sliced() {
+        copy_array(&array)
+    } else {
+        Box::new(array)
+    }
+}
+
+fn compact_array(array: &dyn Array) -> ArrayRef {
+    match array.data_type().to_physical_type() {
+        PhysicalType::BinaryView => compact_binview::<[u8]>(array.as_any().downcast_ref().unwrap()),
+        PhysicalType::Utf8View => compact_binview::<str>(array.as_any().downcast_ref().unwrap()),
+        _ => copy_array(array),
+    }
+}
+
+impl Series {
+    /// Returns the size in bytes of all the buffers this [`Series`] keeps alive.
+    ///
+    /// Unlike [`Series::estimated_size`], this includes the parts of the buffers that are
+    /// not visible because the chunks are sliced.
+    pub fn allocated_size(&self) -> usize {
+        self.chunks()
+            .iter()
+            .map(|arr| allocated_bytes_size(arr.as_ref()))
+            .sum()
+    }
+
+    /// Reallocate the chunks whose buffers are much larger than the data they expose, e.g.
+    /// small slices or filters of a large scan, to their minimal footprint.
+    ///
+    /// Returns the number of bytes this [`Series`] no longer keeps alive. Note that buffers
+    /// shared with other arrays are only freed once those arrays are dropped as well.
+    pub fn compact(&mut self) -> usize {
+        match self.dtype() {
+            #[cfg(feature = "object")]
+            DataType::Object(_, _) => 0,
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => {
+                // The chunks of a struct are built from its fields.
+                let mut fields = self.struct_().unwrap().fields().to_vec();
+                let reclaimed = fields.iter_mut().map(|s| s.compact()).sum();
+                if reclaimed > 0 {
+                    *self = StructChunked::new(self.name(), &fields)
+                        .unwrap()
+                        .into_series();
+                }
+                reclaimed
+            },
+            _ => {
+                if !self.chunks().iter().any(|arr| is_oversized(arr.as_ref())) {
+                    return 0;
+                }
+                let before = self.allocated_size();
+                // SAFETY: compacting doesn't change the length, data type or null count.
+                let chunks = unsafe { self.chunks_mut() };
+                for arr in chunks.iter_mut() {
+                    if is_oversized(arr.as_ref()) {
+                        *arr = compact_array(arr.as_ref());
+                    }
+                }
+                before.saturating_sub(self.allocated_size())
+            },
+        }
+    }
+
+    /// Rebuild the buffers of `String` and `Binary` data such that repeated values are stored
+    /// only once per chunk. This reduces the memory of columns with many repeated values that
+    /// shouldn't be `Categorical`. Other data types are returned as is.
+    pub fn deduplicate_strings(&self) -> Series {
+        match self.dtype() {
+            DataType::String | DataType::Binary => {
+                let mut out = self.clone();
+                // SAFETY: deduplicating doesn't change the length, data type or null count.
+                let chunks = unsafe { out.chunks_mut() };
+                for arr in chunks.iter_mut() {
+                    *arr = match arr.data_type().to_physical_type() {
+                        PhysicalType::Utf8View => {
+                            let arr: &BinaryViewArrayGeneric<str> =
+                                arr.as_any().downcast_ref().unwrap();
+                            Box::new(arr.deduplicate())
+                        },
+                        _ => {
+                            let arr: &BinaryViewArrayGeneric<[u8]> =
+                                arr.as_any().downcast_ref().unwrap();
+                            Box::new(arr.deduplicate())
+                        },
+                    };
+                }
+                out
+            },
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => {
+                let fields = self
+                    .struct_()
+                    .unwrap()
+                    .fields()
+                    .iter()
+                    .map(|s| s.deduplicate_strings())
+                    .collect::<Vec<_>>();
+                StructChunked::new(self.name(), &fields)
+                    .unwrap()
+                    .into_series()
+            },
+            _ => self.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_compact_sliced() {
+        let s = Series::new("a", (0..100_000).collect::<Vec<i64>>());
+        let mut sliced = s.slice(10, 5);
+        assert_eq!(sliced.allocated_size(), s.allocated_size());
+
+        let reclaimed = sliced.compact();
+        assert!(reclaimed > 0);
+        assert_eq!(sliced.allocated_size(), 5 * std::mem::size_of::<i64>());
+        assert!(sliced.equals(&s.slice(10, 5)));
+
+        // Already compact.
+        assert_eq!(sliced.compact(), 0);
+    }
+
+    #[test]
+    fn test_compact_strings() {
+        let values = (0..10_000)
+            .map(|i| format!("a fairly long string value {i}"))
+            .collect::<Vec<_>>();
+        let s = Series::new("a", values);
+        let mut filtered = s.slice(100, 10);
+        assert!(filtered.compact() > 0);
+        assert!(filtered.allocated_size() < 1024);
+        assert!(filtered.equals(&s.slice(100, 10)));
+    }
+
+    #[test]
+    fn test_deduplicate_strings() {
+        let values = (0..10_000)
+            .map(|i| match i % 3 {
+                0 => Some(format!("a fairly long string value {}", i % 4)),
+                1 => None,
+                _ => Some("short".to_string()),
+            })
+            .collect::<Vec<_>>();
+        let s = Series::new("a", values);
+        let out = s.deduplicate_strings();
+        assert!(out.equals_missing(&s));
+        assert!(out.allocated_size() < s.allocated_size());
+
+        let out_buffer_len = out
+            .str()
+            .unwrap()
+            .downcast_iter()
+            .next()
+            .unwrap()
+            .total_buffer_len();
+        assert_eq!(out_buffer_len, 4 * "a fairly long string value 0".len());
+    }
+}
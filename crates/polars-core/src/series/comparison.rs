@@ -56,6 +56,10 @@ macro_rules! impl_compare {
             Int16 => lhs.i16().unwrap().$method(rhs.i16().unwrap()),
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
             Int64 => lhs.i64().unwrap().$method(rhs.i64().unwrap()),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => lhs.i128().unwrap().$method(rhs.i128().unwrap()),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => lhs.u128().unwrap().$method(rhs.u128().unwrap()),
             Float32 => lhs.f32().unwrap().$method(rhs.f32().unwrap()),
             Float64 => lhs.f64().unwrap().$method(rhs.f64().unwrap()),
             List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
@@ -102,6 +106,19 @@ fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     Ok(())
 }
 
+/// Turn a numeric scalar into a series that can be compared with a decimal series, as the
+/// physical values of a decimal series are scaled by its scale.
+#[cfg(feature = "dtype-decimal")]
+fn decimal_scalar_rhs<Rhs: NumericNative>(rhs: Rhs) -> Series {
+    if Rhs::is_float() {
+        Float64Chunked::from_slice("", &[rhs.to_f64().unwrap()]).into_series()
+    } else {
+        Int128Chunked::from_slice("", &[rhs.to_i128().unwrap()])
+            .into_decimal_unchecked(None, 0)
+            .into_series()
+    }
+}
+
 impl ChunkCompare<&Series> for Series {
     type Item = PolarsResult<BooleanChunked>;
 
@@ -154,48 +171,80 @@ where
 
     fn equal(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
+        #[cfg(feature = "dtype-decimal")]
+        if self.dtype().is_decimal() {
+            return ChunkCompare::<&Series>::equal(self, &decimal_scalar_rhs(rhs));
+        }
         let s = self.to_physical_repr();
         Ok(apply_method_physical_numeric!(&s, equal, rhs))
     }
 
     fn equal_missing(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
+        #[cfg(feature = "dtype-decimal")]
+        if self.dtype().is_decimal() {
+            return ChunkCompare::<&Series>::equal_missing(self, &decimal_scalar_rhs(rhs));
+        }
         let s = self.to_physical_repr();
         Ok(apply_method_physical_numeric!(&s, equal_missing, rhs))
     }
 
     fn not_equal(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
+        #[cfg(feature = "dtype-decimal")]
+        if self.dtype().is_decimal() {
+            return ChunkCompare::<&Series>::not_equal(self, &decimal_scalar_rhs(rhs));
+        }
         let s = self.to_physical_repr();
         Ok(apply_method_physical_numeric!(&s, not_equal, rhs))
     }
 
     fn not_equal_missing(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
+        #[cfg(feature = "dtype-decimal")]
+        if self.dtype().is_decimal() {
+            return ChunkCompare::<&Series>::not_equal_missing(self, &decimal_scalar_rhs(rhs));
+        }
         let s = self.to_physical_repr();
         Ok(apply_method_physical_numeric!(&s, not_equal_missing, rhs))
     }
 
     fn gt(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
+        #[cfg(feature = "dtype-decimal")]
+        if self.dtype().is_decimal() {
+            return ChunkCompare::<&Series>::gt(self, &decimal_scalar_rhs(rhs));
+        }
         let s = self.to_physical_repr();
         Ok(apply_method_physical_numeric!(&s, gt, rhs))
     }
 
     fn gt_eq(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
+        #[cfg(feature = "dtype-decimal")]
+        if self.dtype().is_decimal() {
+            return ChunkCompare::<&Series>::gt_eq(self, &decimal_scalar_rhs(rhs));
+        }
         let s = self.to_physical_repr();
         Ok(apply_method_physical_numeric!(&s, gt_eq, rhs))
     }
 
     fn lt(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
+        #[cfg(feature = "dtype-decimal")]
+        if self.dtype().is_decimal() {
+            return ChunkCompare::<&Series>::lt(self, &decimal_scalar_rhs(rhs));
+        }
         let s = self.to_physical_repr();
         Ok(apply_method_physical_numeric!(&s, lt, rhs))
     }
 
     fn lt_eq(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
+        #[cfg(feature = "dtype-decimal")]
+        if self.dtype().is_decimal() {
+            return ChunkCompare::<&Series>::lt_eq(self, &decimal_scalar_rhs(rhs));
+        }
         let s = self.to_physical_repr();
         Ok(apply_method_physical_numeric!(&s, lt_eq, rhs))
     }
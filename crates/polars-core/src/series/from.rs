@@ -43,12 +43,16 @@ impl Series {
             Int16 => Int16Chunked::from_chunks(name, chunks).into_series(),
             Int32 => Int32Chunked::from_chunks(name, chunks).into_series(),
             Int64 => Int64Chunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => Int128Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-u8")]
             UInt8 => UInt8Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-u16")]
             UInt16 => UInt16Chunked::from_chunks(name, chunks).into_series(),
             UInt32 => UInt32Chunked::from_chunks(name, chunks).into_series(),
             UInt64 => UInt64Chunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => UInt128Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-date")]
             Date => Int32Chunked::from_chunks(name, chunks)
                 .into_date()
@@ -201,12 +205,16 @@ impl Series {
             ArrowDataType::UInt16 => Ok(UInt16Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::UInt32 => Ok(UInt32Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::UInt64 => Ok(UInt64Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(feature = "dtype-u128")]
+            ArrowDataType::UInt128 => Ok(UInt128Chunked::from_chunks(name, chunks).into_series()),
             #[cfg(feature = "dtype-i8")]
             ArrowDataType::Int8 => Ok(Int8Chunked::from_chunks(name, chunks).into_series()),
             #[cfg(feature = "dtype-i16")]
             ArrowDataType::Int16 => Ok(Int16Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int32 => Ok(Int32Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int64 => Ok(Int64Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(feature = "dtype-i128")]
+            ArrowDataType::Int128 => Ok(Int128Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -396,6 +404,20 @@ impl Series {
                 };
                 Ok(s)
             },
+            ArrowDataType::Extension(extension_name, storage, _) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| extension_storage_array(arr.as_ref()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let s = Self::_try_from_arrow_unchecked_with_md(name, chunks, storage, md)?;
+                // Unknown extension types are read as their storage type.
+                match get_extension_type(extension_name) {
+                    Some(extension) if s.dtype() == &extension.storage_dtype() => {
+                        s.with_extension(extension)
+                    },
+                    _ => Ok(s),
+                }
+            },
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(logical_fields) => {
                 // We don't have to convert inner types, as that already
@@ -24,17 +24,20 @@ impl SeriesWrap<DecimalChunked> {
         f(&self.0)
     }
 
+    fn to_float(&self) -> Series {
+        self.0.cast(&DataType::Float64).unwrap()
+    }
+
     fn agg_helper<F: Fn(&Int128Chunked) -> Series>(&self, f: F) -> Series {
         let agg_s = f(&self.0);
         match agg_s.dtype() {
-            DataType::Decimal(_, _) => {
-                let ca = agg_s.decimal().unwrap();
-                let ca = ca.as_ref().clone();
+            DataType::Int128 => {
+                let ca = agg_s.i128().unwrap().clone();
                 let precision = self.0.precision();
                 let scale = self.0.scale();
                 ca.into_decimal_unchecked(precision, scale).into_series()
             },
-            DataType::List(dtype) if dtype.is_decimal() => {
+            DataType::List(dtype) if matches!(dtype.as_ref(), DataType::Int128) => {
                 let dtype = self.0.dtype();
                 let ca = agg_s.list().unwrap();
                 let arr = ca.downcast_iter().next().unwrap();
@@ -64,19 +67,6 @@ impl SeriesWrap<DecimalChunked> {
     }
 }
 
-unsafe impl IntoSeries for Int128Chunked {
-    fn into_series(self) -> Series
-    where
-        Self: Sized,
-    {
-        // this is incorrect as it ignores the datatype
-        // the caller must correct this.
-        let mut ca = DecimalChunked::new_logical(self);
-        ca.2 = Some(DataType::Decimal(None, None));
-        ca.into_series()
-    }
-}
-
 impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
     fn compute_len(&mut self) {
         self.0.compute_len()
@@ -136,6 +126,16 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         self.agg_helper(|ca| ca.agg_max(groups))
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.to_float().agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.to_float().agg_var(groups, ddof)
+    }
+
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
         self.agg_helper(|ca| ca.agg_list(groups))
@@ -165,9 +165,8 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
     fn explode_by_offsets(&self, offsets: &[i64]) -> Series {
         self.0
             .explode_by_offsets(offsets)
-            .decimal()
+            .i128()
             .unwrap()
-            .as_ref()
             .clone()
             .into_decimal_unchecked(self.0.precision(), self.0.scale())
             .into_series()
@@ -369,6 +368,21 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
             Scalar::new(self.dtype().clone(), av)
         }))
     }
+    fn mean(&self) -> Option<f64> {
+        self.to_float().mean()
+    }
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.to_float().std(ddof)
+    }
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.to_float().var(ddof)
+    }
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float().std_reduce(ddof)
+    }
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float().var_reduce(ddof)
+    }
     fn as_any(&self) -> &dyn Any {
         &self.0
     }
@@ -279,6 +279,11 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn set_extension(&mut self, extension: Option<ExtensionTypeRef>) -> PolarsResult<()> {
+                self.0.set_extension(extension);
+                Ok(())
+            }
+
             fn chunk_lengths(&self) -> ChunkLenIter {
                 self.0.chunk_lengths()
             }
@@ -379,10 +384,20 @@ macro_rules! impl_dyn_series {
             }
 
             fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+                if self.0.extension().is_some() {
+                    return self.0.clone().into_series().sort_with_extension(options);
+                }
                 Ok(ChunkSort::sort_with(&self.0, options).into_series())
             }
 
             fn arg_sort(&self, options: SortOptions) -> IdxCa {
+                if self.0.extension().is_some() {
+                    return self
+                        .0
+                        .clone()
+                        .into_series()
+                        .arg_sort_with_extension(options);
+                }
                 ChunkSort::arg_sort(&self.0, options)
             }
 
@@ -483,9 +498,17 @@ impl_dyn_series!(Int8Chunked, Int8Type);
 impl_dyn_series!(Int16Chunked, Int16Type);
 impl_dyn_series!(Int32Chunked, Int32Type);
 impl_dyn_series!(Int64Chunked, Int64Type);
+#[cfg(feature = "dtype-i128")]
+impl_dyn_series!(Int128Chunked, Int128Type);
+#[cfg(feature = "dtype-u128")]
+impl_dyn_series!(UInt128Chunked, UInt128Type);
 
 impl<T: PolarsNumericType> private::PrivateSeriesNumeric for SeriesWrap<ChunkedArray<T>> {
     fn bit_repr(&self) -> Option<BitRepr> {
+        // 128-bit integers don't fit in any bit representation.
+        if std::mem::size_of::<T::Native>() == 16 {
+            return None;
+        }
         Some(self.0.to_bit_repr())
     }
 }
@@ -99,6 +99,11 @@ impl SeriesTrait for SeriesWrap<StringChunked> {
         self.0.rename(name);
     }
 
+    fn set_extension(&mut self, extension: Option<ExtensionTypeRef>) -> PolarsResult<()> {
+        self.0.set_extension(extension);
+        Ok(())
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter {
         self.0.chunk_lengths()
     }
@@ -189,10 +194,20 @@ impl SeriesTrait for SeriesWrap<StringChunked> {
     }
 
     fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        if self.0.extension().is_some() {
+            return self.0.clone().into_series().sort_with_extension(options);
+        }
         Ok(ChunkSort::sort_with(&self.0, options).into_series())
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        if self.0.extension().is_some() {
+            return self
+                .0
+                .clone()
+                .into_series()
+                .arg_sort_with_extension(options);
+        }
         ChunkSort::arg_sort(&self.0, options)
     }
 
@@ -5,6 +5,7 @@ use crate::prelude::*;
 pub mod amortized_iter;
 mod any_value;
 pub mod arithmetic;
+mod compact;
 mod comparison;
 mod from;
 pub mod implementations;
@@ -402,20 +403,14 @@ impl Series {
                         if let Some(dtype) = cast_dtype(&field.dtype) {
                             let mut new_fields = Vec::with_capacity(fields.len());
                             new_fields.extend(fields.iter().take(i).cloned());
-                            new_fields.push(Field {
-                                name: field.name.clone(),
-                                dtype,
-                            });
+                            new_fields.push(Field::from_owned(field.name.clone(), dtype));
                             break new_fields;
                         }
                     };
 
                     new_fields.extend(fields.iter().skip(new_fields.len()).cloned().map(|field| {
                         let dtype = cast_dtype(&field.dtype).unwrap_or(field.dtype);
-                        Field {
-                            name: field.name.clone(),
-                            dtype,
-                        }
+                        Field::from_owned(field.name.clone(), dtype)
                     }));
 
                     Some(D::Struct(new_fields))
@@ -775,6 +770,14 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<str>> {
+        if let Some(extension) = self.extension() {
+            let av = self.0.get(index)?;
+            if !av.is_null() {
+                if let Some(out) = extension.format_value(&av) {
+                    return Ok(Cow::Owned(out));
+                }
+            }
+        }
         let out = match self.0.get(index)? {
             AnyValue::String(s) => Cow::Borrowed(s),
             AnyValue::Null => Cow::Borrowed("null"),
@@ -841,6 +844,15 @@ impl Series {
                 let av: AnyValue = val.into();
                 Scalar::new(dt.clone(), av)
             },
+            #[cfg(feature = "dtype-decimal")]
+            dt @ DataType::Decimal(_, _) => {
+                let ca = self.decimal().unwrap();
+                let av = match ca.mean_decimal() {
+                    Some(mean) => AnyValue::Decimal(mean, ca.scale()),
+                    None => AnyValue::Null,
+                };
+                Scalar::new(dt.clone(), av)
+            },
             dt => Scalar::new(dt.clone(), AnyValue::Null),
         }
     }
@@ -0,0 +1,62 @@
+use crate::prelude::*;
+
+impl Series {
+    /// The [`ExtensionType`] the values are tagged with.
+    pub fn extension(&self) -> Option<ExtensionTypeRef> {
+        self.field().extension.clone()
+    }
+
+    /// Tag the values with an [`ExtensionType`].
+    ///
+    /// The Series must have the storage type of the extension type and valid values.
+    pub fn with_extension(mut self, extension: ExtensionTypeRef) -> PolarsResult<Series> {
+        polars_ensure!(
+            self.dtype() == &extension.storage_dtype(),
+            SchemaMismatch: "extension type '{}' is stored as {}, got {}",
+            extension.name(), extension.storage_dtype(), self.dtype()
+        );
+        extension.validate(&self)?;
+        self._get_inner_mut().set_extension(Some(extension))?;
+        Ok(self)
+    }
+
+    /// Tag the values with the registered [`ExtensionType`] called `name`.
+    pub fn into_extension(self, name: &str) -> PolarsResult<Series> {
+        let extension = get_extension_type(name).ok_or_else(
+            || polars_err!(ComputeError: "extension type '{}' is not registered", name),
+        )?;
+        self.with_extension(extension)
+    }
+
+    /// Remove the [`ExtensionType`] tag, returning the storage values.
+    pub fn into_storage(mut self) -> Series {
+        if self.extension().is_some() {
+            self._get_inner_mut().set_extension(None).unwrap();
+        }
+        self
+    }
+
+    pub(crate) fn arg_sort_with_extension(&self, options: SortOptions) -> IdxCa {
+        let extension = self.extension().unwrap();
+        let storage = self.clone().into_storage();
+        match extension.sort_key(&storage) {
+            Some(key) => key.arg_sort(options),
+            None => storage.arg_sort(options),
+        }
+    }
+
+    pub(crate) fn sort_with_extension(&self, options: SortOptions) -> PolarsResult<Series> {
+        let extension = self.extension().unwrap();
+        let storage = self.clone().into_storage();
+        let mut out = match extension.sort_key(&storage) {
+            Some(key) => {
+                let idx = key.arg_sort(options);
+                // SAFETY: the sort key has the same length as the storage.
+                unsafe { storage.take_unchecked(&idx) }
+            },
+            None => storage.sort_with(options)?,
+        };
+        out._get_inner_mut().set_extension(Some(extension))?;
+        Ok(out)
+    }
+}
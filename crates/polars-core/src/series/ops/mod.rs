@@ -1,5 +1,6 @@
 mod downcast;
 mod extend;
+mod extension;
 mod null;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -183,6 +183,13 @@ pub trait SeriesTrait:
     /// Rename the Series.
     fn rename(&mut self, name: &str);
 
+    /// Tag the values with an [`ExtensionType`], or remove the tag with `None`.
+    ///
+    /// The storage type and the values are not checked, see [`Series::with_extension`].
+    fn set_extension(&mut self, _extension: Option<ExtensionTypeRef>) -> PolarsResult<()> {
+        polars_bail!(opq = set_extension, self._dtype());
+    }
+
     fn bitand(&self, _other: &Series) -> PolarsResult<Series> {
         polars_bail!(opq = bitand, self._dtype());
     }
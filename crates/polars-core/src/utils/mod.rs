@@ -384,6 +384,10 @@ macro_rules! match_dtype_to_logical_apply_macro {
             DataType::Int16 => $macro!(Int16Type $(, $opt_args)*),
             DataType::Int32 => $macro!(Int32Type $(, $opt_args)*),
             DataType::Int64 => $macro!(Int64Type $(, $opt_args)*),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => $macro!(Int128Type $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!(UInt128Type $(, $opt_args)*),
             DataType::Float32 => $macro!(Float32Type $(, $opt_args)*),
             DataType::Float64 => $macro!(Float64Type $(, $opt_args)*),
             dt => panic!("not implemented for dtype {:?}", dt),
@@ -410,6 +414,10 @@ macro_rules! match_arrow_data_type_apply_macro_ca {
             DataType::Int16 => $macro!($self.i16().unwrap() $(, $opt_args)*),
             DataType::Int32 => $macro!($self.i32().unwrap() $(, $opt_args)*),
             DataType::Int64 => $macro!($self.i64().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => $macro!($self.i128().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!($self.u128().unwrap() $(, $opt_args)*),
             DataType::Float32 => $macro!($self.f32().unwrap() $(, $opt_args)*),
             DataType::Float64 => $macro!($self.f64().unwrap() $(, $opt_args)*),
             dt => panic!("not implemented for dtype {:?}", dt),
@@ -457,6 +465,10 @@ macro_rules! with_match_physical_integer_type {(
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        #[cfg(feature = "dtype-i128")]
+        Int128 => __with_ty__! { i128 },
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { u128 },
         dt => panic!("not implemented for dtype {:?}", dt),
     }
 })}
@@ -506,6 +518,10 @@ macro_rules! with_match_physical_numeric_polars_type {(
         UInt16 => __with_ty__! { UInt16Type },
         UInt32 => __with_ty__! { UInt32Type },
         UInt64 => __with_ty__! { UInt64Type },
+            #[cfg(feature = "dtype-i128")]
+        Int128 => __with_ty__! { Int128Type },
+            #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { UInt128Type },
         Float32 => __with_ty__! { Float32Type },
         Float64 => __with_ty__! { Float64Type },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -532,6 +548,10 @@ macro_rules! with_match_physical_integer_polars_type {(
         UInt16 => __with_ty__! { UInt16Type },
         UInt32 => __with_ty__! { UInt32Type },
         UInt64 => __with_ty__! { UInt64Type },
+            #[cfg(feature = "dtype-i128")]
+        Int128 => __with_ty__! { Int128Type },
+            #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { UInt128Type },
         dt => panic!("not implemented for dtype {:?}", dt),
     }
 })}
@@ -554,6 +574,10 @@ macro_rules! downcast_as_macro_arg_physical {
             DataType::Int16 => $macro!($self.i16().unwrap() $(, $opt_args)*),
             DataType::Int32 => $macro!($self.i32().unwrap() $(, $opt_args)*),
             DataType::Int64 => $macro!($self.i64().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => $macro!($self.i128().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!($self.u128().unwrap() $(, $opt_args)*),
             DataType::Float32 => $macro!($self.f32().unwrap() $(, $opt_args)*),
             DataType::Float64 => $macro!($self.f64().unwrap() $(, $opt_args)*),
             dt => panic!("not implemented for {:?}", dt),
@@ -730,11 +754,56 @@ where
     acc_df
 }
 
+/// Give the local categorical columns of the DataFrames a shared rev-map, so that stacking them
+/// doesn't re-encode all categories seen so far for every DataFrame.
+#[cfg(feature = "dtype-categorical")]
+fn make_local_categorical_columns_compatible(dfs: &mut [DataFrame]) -> PolarsResult<()> {
+    // Stacking two DataFrames is a single merge already.
+    if dfs.len() < 3 {
+        return Ok(());
+    }
+    let width = dfs[0].width();
+    if dfs.iter().any(|df| df.width() != width) {
+        return Ok(());
+    }
+    for i in 0..width {
+        if !matches!(
+            dfs[0].get_columns()[i].dtype(),
+            DataType::Categorical(Some(rev_map), _) if rev_map.is_local()
+        ) {
+            continue;
+        }
+        // Mismatching dtypes are reported by `vstack`.
+        let Ok(cas) = dfs
+            .iter()
+            .map(|df| df.get_columns()[i].categorical())
+            .collect::<PolarsResult<Vec<_>>>()
+        else {
+            continue;
+        };
+        let Some(cas) = make_local_categoricals_compatible(&cas)? else {
+            continue;
+        };
+        for (df, ca) in dfs.iter_mut().zip(cas) {
+            // SAFETY: the length and name of the column don't change.
+            let s = unsafe { &mut df.get_columns_mut()[i] };
+            *s = ca.into_series().with_name(s.name());
+        }
+    }
+    Ok(())
+}
+
 /// This takes ownership of the DataFrame so that drop is called earlier.
 pub fn accumulate_dataframes_vertical<I>(dfs: I) -> PolarsResult<DataFrame>
 where
     I: IntoIterator<Item = DataFrame>,
 {
+    #[cfg(feature = "dtype-categorical")]
+    let dfs = {
+        let mut dfs = dfs.into_iter().collect::<Vec<_>>();
+        make_local_categorical_columns_compatible(&mut dfs)?;
+        dfs
+    };
     let mut iter = dfs.into_iter();
     let additional = iter.size_hint().0;
     let mut acc_df = iter.next().unwrap();
@@ -750,14 +819,7 @@ pub fn concat_df<'a, I>(dfs: I) -> PolarsResult<DataFrame>
 where
     I: IntoIterator<Item = &'a DataFrame>,
 {
-    let mut iter = dfs.into_iter();
-    let additional = iter.size_hint().0;
-    let mut acc_df = iter.next().unwrap().clone();
-    acc_df.reserve_chunks(additional);
-    for df in iter {
-        acc_df.vstack_mut(df)?;
-    }
-    Ok(acc_df)
+    accumulate_dataframes_vertical(dfs.into_iter().cloned())
 }
 
 /// Concat the DataFrames to a single DataFrame.
@@ -153,6 +153,17 @@ pub fn get_supertype_with_options(
 
             (Float64, Float32) => Some(Float64),
 
+            #[cfg(all(feature = "dtype-i128", feature = "dtype-u128"))]
+            (Int128, UInt128) => Some(Float64),
+            #[cfg(feature = "dtype-i128")]
+            (Int128, dt) if dt.is_signed_integer() || dt.is_unsigned_integer() || dt.is_bool() => Some(Int128),
+            #[cfg(feature = "dtype-i128")]
+            (Int128, Float32 | Float64) => Some(Float64),
+            #[cfg(feature = "dtype-u128")]
+            (UInt128, dt) if dt.is_unsigned_integer() || dt.is_bool() => Some(UInt128),
+            #[cfg(feature = "dtype-u128")]
+            (UInt128, dt) if dt.is_signed_integer() || dt.is_float() => Some(Float64), // Follow numpy
+
             // Time related dtypes
             #[cfg(feature = "dtype-date")]
             (Date, UInt32) => Some(Int64),
@@ -316,7 +327,14 @@ pub fn get_supertype_with_options(
                             }
                         }
                     }
-                    UnknownKind::Int(_) if dt.is_decimal() => Some(dt.clone()),
+                    // The literal may not fit the precision of the decimal, so only keep its scale.
+                    #[cfg(feature = "dtype-decimal")]
+                    UnknownKind::Int(_) => match dt {
+                        Decimal(_, scale) => Some(Decimal(None, *scale)),
+                        _ => Some(Unknown(UnknownKind::Any)),
+                    },
+                    #[cfg(feature = "dtype-decimal")]
+                    UnknownKind::Float if dt.is_decimal() => Some(Float64),
                     _ => Some(Unknown(UnknownKind::Any))
                 }
             },
@@ -335,12 +353,19 @@ pub fn get_supertype_with_options(
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(p1, s1), Decimal(p2, s2)) => {
-                Some(Decimal((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
+                // Keep enough integer digits for both sides at the larger scale.
+                let scale = (*s1).max(*s2);
+                let int_digits = |p: usize, s: Option<usize>| p.saturating_sub(s.unwrap_or(0));
+                let precision = (*p1).zip(*p2).map(|(p1, p2)| {
+                    (int_digits(p1, *s1).max(int_digits(p2, *s2)) + scale.unwrap_or(0)).min(38)
+                });
+                Some(Decimal(precision, scale))
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
             #[cfg(feature = "dtype-decimal")]
-            (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
+            // Integers may not fit the precision of the decimal, so only keep its scale.
+            (Decimal(_, scale), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(Decimal(None, *scale)),
             _ => None,
         }
     }
@@ -2,10 +2,11 @@ use std::borrow::Cow;
 
 use polars_core::prelude::*;
 use polars_core::POOL;
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 use polars_io::predicates::{BatchStats, StatsEvaluator};
 #[cfg(feature = "is_between")]
-use polars_ops::prelude::ClosedInterval;
+use polars_ops::prelude::{ClosedInterval, WeightedAggregation};
+use polars_utils::idx_vec::IdxVec;
 use rayon::prelude::*;
 
 use super::*;
@@ -135,6 +136,22 @@ impl ApplyExpr {
         &self,
         mut ac: AggregationContext<'a>,
     ) -> PolarsResult<AggregationContext<'a>> {
+        if let Expr::Function {
+            function: FunctionExpr::FirstLastN { n, last },
+            ..
+        } = &self.expr
+        {
+            if matches!(ac.agg_state(), AggState::NotAggregated(_)) {
+                // Slice the groups instead of applying the function to every group.
+                let groups = first_last_n_groups(ac.groups(), *n, *last);
+                // SAFETY: the sliced groups are in bounds of the input.
+                let out = unsafe { ac.series().agg_list(&groups) };
+                ac.with_agg_state(AggState::AggregatedScalar(out));
+                ac.with_update_groups(UpdateGroups::No);
+                return Ok(ac);
+            }
+        }
+
         let s = ac.series();
 
         polars_ensure!(
@@ -144,6 +161,55 @@ impl ApplyExpr {
         );
 
         let name = s.name().to_string();
+
+        #[cfg(feature = "approx_unique")]
+        if matches!(
+            self.expr,
+            Expr::Function {
+                function: FunctionExpr::ApproxNUnique,
+                ..
+            }
+        ) && matches!(ac.agg_state(), AggState::NotAggregated(_))
+        {
+            // Estimate every group with a sketch over the hashes of the flat column.
+            let ca = polars_ops::prelude::approx_n_unique_groups(s, ac.groups())?;
+            ac.with_agg_state(AggState::AggregatedScalar(ca.into_series()));
+            ac.with_update_groups(UpdateGroups::No);
+            return Ok(ac);
+        }
+
+        #[cfg(feature = "approx_quantile")]
+        if let Expr::Function {
+            function: FunctionExpr::ApproxQuantile { quantile, accuracy },
+            ..
+        } = &self.expr
+        {
+            if matches!(ac.agg_state(), AggState::NotAggregated(_)) {
+                // Build a sketch per group instead of materializing the groups as lists.
+                let ca = polars_ops::prelude::approx_quantile_groups(
+                    s,
+                    ac.groups(),
+                    *quantile,
+                    *accuracy,
+                )?;
+                ac.with_agg_state(AggState::AggregatedScalar(ca.into_series()));
+                ac.with_update_groups(UpdateGroups::No);
+                return Ok(ac);
+            }
+        }
+
+        // Slice groups (e.g. from `rolling` and `group_by_dynamic`) over a flat column are
+        // handed to the function as zero-copy slices instead of materializing a list first.
+        if matches!(ac.agg_state(), AggState::NotAggregated(_)) {
+            let s = s.clone();
+            if let GroupsProxy::Slice { groups, .. } = ac.groups().as_ref() {
+                if !groups.is_empty() {
+                    let ca = self.apply_on_slice_groups(&s, groups, &name)?;
+                    return self.finish_apply_groups(ac, ca);
+                }
+            }
+        }
+
         let agg = ac.aggregated();
         // Collection of empty list leads to a null dtype. See: #3687.
         if agg.len() == 0 {
@@ -200,6 +266,27 @@ impl ApplyExpr {
         self.finish_apply_groups(ac, ca.with_name(&name))
     }
 
+    /// Apply the function to every `[first, len]` slice of `s` without copying the data.
+    fn apply_on_slice_groups(
+        &self,
+        s: &Series,
+        groups: &[[IdxSize; 2]],
+        name: &str,
+    ) -> PolarsResult<ListChunked> {
+        // The slices keep the name of `s`, so there is no need to rename them.
+        let f = |&[first, len]: &[IdxSize; 2]| {
+            self.function
+                .call_udf(&mut [s.slice(first as i64, len as usize)])
+        };
+
+        let ca: ListChunked = if self.allow_threading {
+            POOL.install(|| groups.par_iter().map(f).collect::<PolarsResult<_>>())?
+        } else {
+            groups.iter().map(f).collect::<PolarsResult<_>>()?
+        };
+        Ok(ca.with_name(name))
+    }
+
     /// Apply elementwise e.g. ignore the group/list indices.
     fn apply_single_elementwise<'a>(
         &self,
@@ -225,6 +312,111 @@ impl ApplyExpr {
         ac.with_series_and_args(s, aggregated, Some(&self.expr), true)?;
         Ok(ac)
     }
+    /// Find the row of the minimum or maximum of `by` in every group and gather the values at
+    /// those rows, without materializing or sorting the groups.
+    fn apply_min_max_by<'a>(
+        &self,
+        mut acs: Vec<AggregationContext<'a>>,
+        max: bool,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let by = acs[1].flat_naive().into_owned();
+        let idx = polars_ops::prelude::arg_min_max_groups(&by, acs[1].groups(), max);
+
+        let mut ac = acs.swap_remove(0);
+        let values = ac.flat_naive().into_owned();
+        // SAFETY: the indices come from the groups, so they are in bounds.
+        let out = unsafe { values.take_unchecked(&idx) };
+        ac.with_agg_state(AggState::AggregatedScalar(out));
+        ac.with_update_groups(UpdateGroups::No);
+        Ok(ac)
+    }
+
+    /// Compute a weighted aggregation of every group without materializing the groups.
+    fn apply_weighted_agg<'a>(
+        &self,
+        mut acs: Vec<AggregationContext<'a>>,
+        agg: WeightedAggregation,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let weights = acs[1].flat_naive().into_owned();
+        let mut ac = acs.swap_remove(0);
+        let values = ac.flat_naive().into_owned();
+        let out = polars_ops::prelude::weighted_agg_groups(&values, &weights, ac.groups(), agg)?;
+        ac.with_agg_state(AggState::AggregatedScalar(out));
+        ac.with_update_groups(UpdateGroups::No);
+        Ok(ac)
+    }
+
+    /// Count the distinct rows of the inputs in every group without materializing the groups.
+    fn apply_n_unique_rows<'a>(
+        &self,
+        mut acs: Vec<AggregationContext<'a>>,
+        filtered: bool,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut columns = acs
+            .iter()
+            .map(|ac| ac.flat_naive().into_owned())
+            .collect::<Vec<_>>();
+        let predicate = if filtered { columns.pop() } else { None };
+        let predicate = predicate.as_ref().map(|s| s.bool()).transpose()?;
+        let out = polars_ops::prelude::n_unique_rows_groups(&columns, predicate, acs[0].groups())?;
+
+        let mut ac = acs.swap_remove(0);
+        ac.with_agg_state(AggState::AggregatedScalar(out.into_series()));
+        ac.with_update_groups(UpdateGroups::No);
+        Ok(ac)
+    }
+
+    /// The groups of the `k` largest, or smallest if `descending`, rows of every group of a column
+    /// of which the groups aren't aggregated yet, instead of aggregating the groups to lists.
+    /// Returns `None` if that isn't possible.
+    #[cfg(feature = "top_k")]
+    fn top_k_groups(
+        &self,
+        acs: &mut [AggregationContext],
+        descending: bool,
+    ) -> PolarsResult<Option<GroupsProxy>> {
+        let (AggState::NotAggregated(s), AggState::Literal(k)) =
+            (acs[0].agg_state(), acs[1].agg_state())
+        else {
+            return Ok(None);
+        };
+        polars_ensure!(
+            k.len() == 1,
+            ComputeError: "`k` must be a single value for `top_k`."
+        );
+        let Some(k) = k.cast(&IDX_DTYPE)?.idx()?.get(0) else {
+            polars_bail!(ComputeError: "`k` must be set for `top_k`")
+        };
+        let s = s.clone();
+        // `top_k` puts the nulls last in either direction.
+        let options = SortOptions {
+            descending: !descending,
+            nulls_last: true,
+            ..Default::default()
+        };
+        Ok(polars_ops::prelude::arg_top_k_groups(
+            &s,
+            acs[0].groups().as_ref(),
+            k as usize,
+            options,
+        ))
+    }
+
+    /// Sum the rows that match the predicate in every group, instead of filtering the groups.
+    fn apply_count_if<'a>(
+        &self,
+        mut acs: Vec<AggregationContext<'a>>,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let predicate = acs[1].flat_naive().into_owned();
+        let mut ac = acs.swap_remove(0);
+        let mask = polars_ops::prelude::count_if_mask(&ac.flat_naive(), predicate.bool()?)?;
+        // SAFETY: the groups are in bounds of the input.
+        let out = unsafe { mask.into_series().agg_sum(ac.groups()) };
+        ac.with_agg_state(AggState::AggregatedScalar(out));
+        ac.with_update_groups(UpdateGroups::No);
+        Ok(ac)
+    }
+
     fn apply_multiple_group_aware<'a>(
         &self,
         mut acs: Vec<AggregationContext<'a>>,
@@ -310,6 +502,44 @@ fn check_map_output_len(input_len: usize, output_len: usize, expr: &Expr) -> Pol
     Ok(())
 }
 
+/// The first or the `last` `n` rows of every group.
+fn first_last_n_groups(groups: &GroupsProxy, n: usize, last: bool) -> GroupsProxy {
+    match groups {
+        GroupsProxy::Idx(groups) => {
+            let (first, all) = groups
+                .iter()
+                .map(|(first, idx)| {
+                    let idx = if last {
+                        &idx[idx.len().saturating_sub(n)..]
+                    } else {
+                        &idx[..idx.len().min(n)]
+                    };
+                    (idx.first().copied().unwrap_or(first), IdxVec::from(idx))
+                })
+                .unzip();
+            GroupsProxy::Idx(GroupsIdx::new(first, all, groups.is_sorted_flag()))
+        },
+        GroupsProxy::Slice { groups, .. } => {
+            let n = n as IdxSize;
+            let groups = groups
+                .iter()
+                .map(|&[first, len]| {
+                    let new_len = len.min(n);
+                    if last {
+                        [first + len - new_len, new_len]
+                    } else {
+                        [first, new_len]
+                    }
+                })
+                .collect();
+            GroupsProxy::Slice {
+                groups,
+                rolling: false,
+            }
+        },
+    }
+}
+
 impl PhysicalExpr for ApplyExpr {
     fn as_expression(&self) -> Option<&Expr> {
         Some(&self.expr)
@@ -363,6 +593,44 @@ impl PhysicalExpr for ApplyExpr {
         } else {
             let mut acs = self.prepare_multiple_inputs(df, groups, state)?;
 
+            #[cfg(feature = "top_k")]
+            if let Expr::Function {
+                function: FunctionExpr::TopK { descending },
+                ..
+            } = &self.expr
+            {
+                if let Some(groups) = self.top_k_groups(&mut acs, *descending)? {
+                    let mut ac = acs.swap_remove(0);
+                    ac.with_groups(groups);
+                    return Ok(ac);
+                }
+            }
+
+            if let Expr::Function {
+                function:
+                    function @ (FunctionExpr::MinMaxBy { .. }
+                    | FunctionExpr::NUniqueRows { .. }
+                    | FunctionExpr::CountIf
+                    | FunctionExpr::WeightedAgg(_)),
+                ..
+            } = &self.expr
+            {
+                let len = acs[0].flat_naive().len();
+                if acs.iter().all(|ac| {
+                    matches!(ac.agg_state(), AggState::NotAggregated(_))
+                        && ac.flat_naive().len() == len
+                }) {
+                    return match function {
+                        FunctionExpr::MinMaxBy { max } => self.apply_min_max_by(acs, *max),
+                        FunctionExpr::NUniqueRows { filtered } => {
+                            self.apply_n_unique_rows(acs, *filtered)
+                        },
+                        FunctionExpr::WeightedAgg(agg) => self.apply_weighted_agg(acs, *agg),
+                        _ => self.apply_count_if(acs),
+                    };
+                }
+            }
+
             match self.collect_groups {
                 ApplyOptions::ApplyList => {
                     let mut s = acs.iter_mut().map(|ac| ac.aggregated()).collect::<Vec<_>>();
@@ -403,7 +671,7 @@ impl PhysicalExpr for ApplyExpr {
     fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
         self.expr.to_field(input_schema, Context::Default)
     }
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         let function = match &self.expr {
             Expr::Function { function, .. } => function,
@@ -488,7 +756,7 @@ fn apply_multiple_elementwise<'a>(
     }
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 impl StatsEvaluator for ApplyExpr {
     fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
         let read = self.should_read_impl(stats)?;
@@ -504,7 +772,7 @@ impl StatsEvaluator for ApplyExpr {
     }
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 impl ApplyExpr {
     fn should_read_impl(&self, stats: &BatchStats) -> PolarsResult<bool> {
         let (function, input) = match &self.expr {
@@ -558,6 +826,14 @@ impl ApplyExpr {
                     #[allow(clippy::explicit_auto_deref)]
                     let input: &Series = &**input;
                     let st = stats.get_stats(&root).ok()?;
+
+                    // All values of a fully dictionary-encoded column are known.
+                    if let Some(dictionary) = st.get_dictionary_state() {
+                        if !polars_ops::prelude::is_in(dictionary, input).ok()?.any() {
+                            return Some(false);
+                        }
+                    }
+
                     let min = st.to_min()?;
                     let max = st.to_max()?;
 
@@ -2,6 +2,8 @@ use polars_core::prelude::*;
 use polars_core::POOL;
 #[cfg(feature = "round_series")]
 use polars_ops::prelude::floor_div_series;
+#[cfg(feature = "list_elementwise")]
+use polars_ops::prelude::{list_elementwise, ListBinaryOp};
 
 use super::*;
 use crate::expressions::{
@@ -34,8 +36,41 @@ impl BinaryExpr {
     }
 }
 
+/// Arithmetic between two list columns is applied to the elements at the same position.
+#[cfg(feature = "list_elementwise")]
+fn apply_list_operator(
+    left: &Series,
+    right: &Series,
+    op: Operator,
+) -> Option<PolarsResult<Series>> {
+    if !matches!(
+        (left.dtype(), right.dtype()),
+        (DataType::List(_), DataType::List(_))
+    ) {
+        return None;
+    }
+    let op = match op {
+        Operator::Plus => ListBinaryOp::Add,
+        Operator::Minus => ListBinaryOp::Sub,
+        Operator::Multiply => ListBinaryOp::Mul,
+        Operator::Divide => ListBinaryOp::Div,
+        Operator::TrueDivide => ListBinaryOp::TrueDiv,
+        Operator::FloorDivide => ListBinaryOp::FloorDiv,
+        Operator::Modulus => ListBinaryOp::Rem,
+        _ => return None,
+    };
+    Some(
+        list_elementwise(left.list().unwrap(), right.list().unwrap(), op)
+            .map(|ca| ca.into_series()),
+    )
+}
+
 /// Can partially do operations in place.
 fn apply_operator_owned(left: Series, right: Series, op: Operator) -> PolarsResult<Series> {
+    #[cfg(feature = "list_elementwise")]
+    if let Some(out) = apply_list_operator(&left, &right, op) {
+        return out;
+    }
     match op {
         Operator::Plus => left.try_add_owned(right),
         Operator::Minus => left.try_sub_owned(right),
@@ -48,6 +83,10 @@ fn apply_operator_owned(left: Series, right: Series, op: Operator) -> PolarsResu
 
 pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResult<Series> {
     use DataType::*;
+    #[cfg(feature = "list_elementwise")]
+    if let Some(out) = apply_list_operator(left, right, op) {
+        return out;
+    }
     match op {
         Operator::Gt => ChunkCompare::gt(left, right).map(|ca| ca.into_series()),
         Operator::GtEq => ChunkCompare::gt_eq(left, right).map(|ca| ca.into_series()),
@@ -73,7 +112,7 @@ pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResu
                 if right.dtype().is_temporal() {
                     return left / right;
                 }
-                left.cast(&Float64)? / right.cast(&Float64)?
+                left.cast(&Float64)? / right.cast(&right.dtype().cast_leaf(Float64))?
             },
         },
         Operator::FloorDivide => {
@@ -265,13 +304,13 @@ impl PhysicalExpr for BinaryExpr {
         Some(self)
     }
 
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         Some(self)
     }
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 mod stats {
     use polars_io::predicates::{BatchStats, StatsEvaluator};
 
@@ -376,6 +415,24 @@ mod stats {
     }
 
     impl BinaryExpr {
+        /// All values of a fully dictionary-encoded column are known, so `col == lit` only
+        /// needs to read the batch if the literal is one of them.
+        fn dictionary_should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
+            let (column, literal) = match (self.left.as_expression(), self.right.as_expression()) {
+                (Some(Expr::Column(name)), _) if self.right.is_literal() => (name, &self.right),
+                (_, Some(Expr::Column(name))) if self.left.is_literal() => (name, &self.left),
+                _ => return Ok(true),
+            };
+            let Some(dictionary) = stats.get_stats(column)?.get_dictionary_state() else {
+                return Ok(true);
+            };
+
+            let lit_s = literal.evaluate(&DataFrame::empty(), &ExecutionState::new())?;
+            Ok(ChunkCompare::equal(dictionary, &lit_s)
+                .map(|ca| ca.any())
+                .unwrap_or(true))
+        }
+
         fn impl_should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
             // See: #5864 for the rationale behind this.
             use Expr::*;
@@ -440,6 +497,10 @@ mod stats {
                 // Default: read the file
                 _ => Ok(true),
             };
+            let out = match out {
+                Ok(true) if self.op == Eq => self.dictionary_should_read(stats),
+                out => out,
+            };
             out.map(|read| {
                 if state.verbose() && read {
                     eprintln!("parquet file must be read, statistics not sufficient for predicate.")
@@ -12,6 +12,9 @@ pub struct SortExpr {
     pub(crate) physical_expr: Arc<dyn PhysicalExpr>,
     pub(crate) options: SortOptions,
     expr: Expr,
+    /// Only the first rows of every group are needed, see [`SortExpr::with_limit`].
+    #[cfg(feature = "top_k")]
+    limit: Option<IdxSize>,
 }
 
 impl SortExpr {
@@ -20,8 +23,18 @@ impl SortExpr {
             physical_expr,
             options,
             expr,
+            #[cfg(feature = "top_k")]
+            limit: None,
         }
     }
+
+    /// Only sort the first `limit` rows of every group, because the sort is followed by a head.
+    /// The groups can then be sorted with a bounded heap instead of a full sort.
+    #[cfg(feature = "top_k")]
+    pub(crate) fn with_limit(mut self, limit: IdxSize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 /// Map arg_sort result back to the indices on the `GroupIdx`
@@ -69,6 +82,20 @@ impl PhysicalExpr for SortExpr {
             _ => {
                 let series = ac.flat_naive().into_owned();
 
+                #[cfg(feature = "top_k")]
+                if let Some(limit) = self.limit {
+                    let groups = polars_ops::prelude::arg_top_k_groups(
+                        &series,
+                        ac.groups().as_ref(),
+                        limit as usize,
+                        self.options,
+                    );
+                    if let Some(groups) = groups {
+                        ac.with_groups(groups);
+                        return Ok(ac);
+                    }
+                }
+
                 let mut sort_options = self.options;
                 sort_options.multithreaded = false;
                 let groups = POOL.install(|| {
@@ -57,10 +57,11 @@ pub(super) fn update_groups_sort_by(
     groups: &GroupsProxy,
     sort_by_s: &Series,
     options: &SortOptions,
+    limit: Option<IdxSize>,
 ) -> PolarsResult<GroupsProxy> {
     let groups = groups
         .par_iter()
-        .map(|indicator| sort_by_groups_single_by(indicator, sort_by_s, options))
+        .map(|indicator| sort_by_groups_single_by(indicator, sort_by_s, options, limit))
         .collect::<PolarsResult<_>>()?;
 
     Ok(GroupsProxy::Idx(groups))
@@ -70,6 +71,7 @@ fn sort_by_groups_single_by(
     indicator: GroupsIndicator,
     sort_by_s: &Series,
     options: &SortOptions,
+    limit: Option<IdxSize>,
 ) -> PolarsResult<(IdxSize, IdxVec)> {
     let options = SortOptions {
         descending: options.descending,
@@ -83,26 +85,44 @@ fn sort_by_groups_single_by(
             // SAFETY: group tuples are always in bounds.
             let group = unsafe { sort_by_s.take_slice_unchecked(idx) };
 
-            let sorted_idx = group.arg_sort(options);
+            let sorted_idx = apply_limit(group.arg_sort(options), limit);
             map_sorted_indices_to_group_idx(&sorted_idx, idx)
         },
         GroupsIndicator::Slice([first, len]) => {
             let group = sort_by_s.slice(first as i64, len as usize);
-            let sorted_idx = group.arg_sort(options);
+            let sorted_idx = apply_limit(group.arg_sort(options), limit);
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
     };
-    let first = new_idx
-        .first()
-        .ok_or_else(|| polars_err!(ComputeError: "{}", ERR_MSG))?;
+    group_first(indicator, new_idx, limit)
+}
 
-    Ok((*first, new_idx))
+fn apply_limit(sorted_idx: IdxCa, limit: Option<IdxSize>) -> IdxCa {
+    match limit {
+        Some(limit) => sorted_idx.slice(0, limit as usize),
+        None => sorted_idx,
+    }
+}
+
+fn group_first(
+    indicator: GroupsIndicator,
+    new_idx: IdxVec,
+    limit: Option<IdxSize>,
+) -> PolarsResult<(IdxSize, IdxVec)> {
+    let first = match new_idx.first() {
+        Some(first) => *first,
+        // A limit of zero gives empty groups.
+        None if limit == Some(0) => indicator.first(),
+        None => polars_bail!(ComputeError: "{}", ERR_MSG),
+    };
+    Ok((first, new_idx))
 }
 
 fn sort_by_groups_no_match_single<'a>(
     mut ac_in: AggregationContext<'a>,
     mut ac_by: AggregationContext<'a>,
     descending: bool,
+    limit: Option<IdxSize>,
     expr: &Expr,
 ) -> PolarsResult<AggregationContext<'a>> {
     let s_in = ac_in.aggregated();
@@ -123,6 +143,7 @@ fn sort_by_groups_no_match_single<'a>(
                         multithreaded: false,
                         ..Default::default()
                     });
+                    let idx = apply_limit(idx, limit);
                     Ok(Some(unsafe { s.take_unchecked(&idx) }))
                 },
                 _ => Ok(None),
@@ -141,6 +162,7 @@ fn sort_by_groups_multiple_by(
     nulls_last: &[bool],
     multithreaded: bool,
     maintain_order: bool,
+    limit: Option<IdxSize>,
 ) -> PolarsResult<(IdxSize, IdxVec)> {
     let new_idx = match indicator {
         GroupsIndicator::Idx((_first, idx)) => {
@@ -155,6 +177,7 @@ fn sort_by_groups_multiple_by(
                 nulls_last: nulls_last.to_owned(),
                 multithreaded,
                 maintain_order,
+                limit,
             };
 
             let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], &options).unwrap();
@@ -171,16 +194,13 @@ fn sort_by_groups_multiple_by(
                 nulls_last: nulls_last.to_owned(),
                 multithreaded,
                 maintain_order,
+                limit,
             };
             let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], &options).unwrap();
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
     };
-    let first = new_idx
-        .first()
-        .ok_or_else(|| polars_err!(ComputeError: "{}", ERR_MSG))?;
-
-    Ok((*first, new_idx))
+    group_first(indicator, new_idx, limit)
 }
 
 impl PhysicalExpr for SortByExpr {
@@ -192,7 +212,8 @@ impl PhysicalExpr for SortByExpr {
         let (series, sorted_idx) = if self.by.len() == 1 {
             let sorted_idx_f = || {
                 let s_sort_by = self.by[0].evaluate(df, state)?;
-                Ok(s_sort_by.arg_sort(SortOptions::from(&self.sort_options)))
+                let sorted_idx = s_sort_by.arg_sort(SortOptions::from(&self.sort_options));
+                Ok(apply_limit(sorted_idx, self.sort_options.limit))
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         } else {
@@ -232,8 +253,12 @@ impl PhysicalExpr for SortByExpr {
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         };
         let (sorted_idx, series) = (sorted_idx?, series?);
+        let expected_len = match self.sort_options.limit {
+            Some(limit) => series.len().min(limit as usize),
+            None => series.len(),
+        };
         polars_ensure!(
-            sorted_idx.len() == series.len(),
+            sorted_idx.len() == expected_len,
             expr = self.expr, ComputeError:
             "`sort_by` produced different length ({}) than the Series that has to be sorted ({})",
             sorted_idx.len(), series.len()
@@ -294,6 +319,7 @@ impl PhysicalExpr for SortByExpr {
                     ac_in,
                     ac_sort_by,
                     self.sort_options.descending[0],
+                    self.sort_options.limit,
                     &self.expr,
                 );
             };
@@ -312,6 +338,7 @@ impl PhysicalExpr for SortByExpr {
                             nulls_last: nulls_last[0],
                             ..Default::default()
                         },
+                        self.sort_options.limit,
                     )
                 },
             );
@@ -332,6 +359,7 @@ impl PhysicalExpr for SortByExpr {
                             &nulls_last,
                             self.sort_options.multithreaded,
                             self.sort_options.maintain_order,
+                            self.sort_options.limit,
                         )
                     })
                     .collect::<PolarsResult<_>>()
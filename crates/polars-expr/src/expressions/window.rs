@@ -450,7 +450,7 @@ impl PhysicalExpr for WindowExpr {
             if let Some((order_by, options)) = &self.order_by {
                 let order_by = order_by.evaluate(df, state)?;
                 polars_ensure!(order_by.len() == df.height(), ShapeMismatch: "the order by expression evaluated to a length: {} that doesn't match the input DataFrame: {}", order_by.len(), df.height());
-                groups = update_groups_sort_by(&groups, &order_by, options)?
+                groups = update_groups_sort_by(&groups, &order_by, options, None)?
             }
 
             let out: PolarsResult<GroupsProxy> = Ok(groups);
@@ -201,6 +201,19 @@ pub fn create_physical_expr(
     }
 }
 
+/// The length of a slice that takes the head, if its `offset` and `length` are literals.
+#[cfg(feature = "top_k")]
+fn literal_head_length(offset: Node, length: Node, expr_arena: &Arena<AExpr>) -> Option<IdxSize> {
+    let extract = |node: Node| match expr_arena.get(node) {
+        AExpr::Literal(lv) => lv.to_any_value()?.extract::<i64>(),
+        _ => None,
+    };
+    match (extract(offset)?, extract(length)?) {
+        (0, length) => IdxSize::try_from(length).ok(),
+        _ => None,
+    }
+}
+
 fn create_physical_expr_inner(
     expression: Node,
     ctxt: Context,
@@ -564,7 +577,27 @@ fn create_physical_expr_inner(
             offset,
             length,
         } => {
-            let input = create_physical_expr_inner(*input, ctxt, expr_arena, schema, state)?;
+            // A sort followed by a head only needs the first rows of every group.
+            #[cfg(feature = "top_k")]
+            let sort_head = match (
+                expr_arena.get(*input),
+                literal_head_length(*offset, *length, expr_arena),
+            ) {
+                (Sort { expr, options }, Some(limit)) => {
+                    let phys_expr =
+                        create_physical_expr_inner(*expr, ctxt, expr_arena, schema, state)?;
+                    let sort = SortExpr::new(phys_expr, *options, node_to_expr(*input, expr_arena))
+                        .with_limit(limit);
+                    Some(Arc::new(sort) as Arc<dyn PhysicalExpr>)
+                },
+                _ => None,
+            };
+            #[cfg(not(feature = "top_k"))]
+            let sort_head = None;
+            let input = match sort_head {
+                Some(input) => input,
+                None => create_physical_expr_inner(*input, ctxt, expr_arena, schema, state)?,
+            };
             let offset = create_physical_expr_inner(*offset, ctxt, expr_arena, schema, state)?;
             let length = create_physical_expr_inner(*length, ctxt, expr_arena, schema, state)?;
             polars_ensure!(!(state.has_implode() && matches!(ctxt, Context::Aggregation)), InvalidOperation: "'implode' followed by a slice during aggregation is not allowed");
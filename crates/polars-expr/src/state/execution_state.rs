@@ -4,11 +4,13 @@ use std::sync::{Mutex, RwLock};
 
 use bitflags::bitflags;
 use once_cell::sync::OnceCell;
-use polars_core::config::verbose;
+use polars_core::config::{get_max_spill_bytes, verbose};
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
+use polars_utils::memory_budget::MemoryBudget;
+use polars_utils::spill::SpillTracker;
 
-use super::NodeTimer;
+use super::{execution_hooks, ExecutionHooksRef, NodeTimer, OperatorEvent};
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 pub type GroupsProxyCache = Arc<RwLock<PlHashMap<String, GroupsProxy>>>;
@@ -71,7 +73,10 @@ pub struct ExecutionState {
     pub flags: AtomicU8,
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
+    hooks: Option<ExecutionHooksRef>,
     stop: Arc<AtomicBool>,
+    memory_budget: Option<MemoryBudget>,
+    spill_tracker: SpillTracker,
 }
 
 impl ExecutionState {
@@ -89,7 +94,10 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            hooks: execution_hooks(),
             stop: Arc::new(AtomicBool::new(false)),
+            memory_budget: None,
+            spill_tracker: SpillTracker::new(get_max_spill_bytes()),
         }
     }
 
@@ -97,8 +105,14 @@ impl ExecutionState {
     pub fn time_nodes(&mut self) {
         self.node_timer = Some(NodeTimer::new())
     }
+    /// Whether the operators are [`record`](Self::record)ed, to time them or to call the
+    /// registered execution hooks.
     pub fn has_node_timer(&self) -> bool {
-        self.node_timer.is_some()
+        self.node_timer.is_some() || self.hooks.is_some()
+    }
+
+    pub fn hooks(&self) -> Option<&ExecutionHooksRef> {
+        self.hooks.as_ref()
     }
 
     pub fn finish_timer(self) -> PolarsResult<DataFrame> {
@@ -107,7 +121,10 @@ impl ExecutionState {
 
     // This is wrong when the U64 overflows which will never happen.
     pub fn should_stop(&self) -> PolarsResult<()> {
-        polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
+        polars_ensure!(
+            !self.stop.load(Ordering::Relaxed) && !polars_utils::interrupt::is_interrupted(),
+            Cancelled: "query interrupted"
+        );
         Ok(())
     }
 
@@ -115,18 +132,55 @@ impl ExecutionState {
         self.stop.clone()
     }
 
+    /// Stop when `token` is set, e.g. to share the cancel token of the query that runs this
+    /// state.
+    pub fn set_cancel_token(&mut self, token: Arc<AtomicBool>) {
+        self.stop = token;
+    }
+
+    /// Limit the memory of the query, see [`MemoryBudget`].
+    pub fn set_memory_budget(&mut self, budget: MemoryBudget) {
+        self.memory_budget = Some(budget);
+    }
+
+    pub fn memory_budget(&self) -> Option<&MemoryBudget> {
+        self.memory_budget.as_ref()
+    }
+
+    /// The bytes the query spilled to disk. The limit is taken from the
+    /// [`Config`](polars_core::config::Config) the state is created in.
+    pub fn spill_tracker(&self) -> &SpillTracker {
+        &self.spill_tracker
+    }
+
     pub fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
-        match &self.node_timer {
-            None => func(),
-            Some(timer) => {
-                let start = std::time::Instant::now();
-                let out = func();
-                let end = std::time::Instant::now();
-
-                timer.store(start, end, name.as_ref().to_string());
-                out
-            },
+        if self.node_timer.is_none() && self.hooks.is_none() {
+            return func();
+        }
+        let hooks = self.hooks.as_deref().unwrap_or_default();
+        let event = OperatorEvent {
+            name: name.as_ref(),
+            elapsed: None,
+        };
+        for hook in hooks {
+            hook.on_operator_start(&event);
+        }
+
+        let start = std::time::Instant::now();
+        let out = func();
+        let end = std::time::Instant::now();
+
+        let event = OperatorEvent {
+            elapsed: Some(end - start),
+            ..event
+        };
+        for hook in hooks {
+            hook.on_operator_finish(&event);
+        }
+        if let Some(timer) = &self.node_timer {
+            timer.store(start, end, name.as_ref().to_string());
         }
+        out
     }
 
     /// Partially clones and partially clears state
@@ -141,7 +195,10 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            hooks: self.hooks.clone(),
             stop: self.stop.clone(),
+            memory_budget: self.memory_budget.clone(),
+            spill_tracker: self.spill_tracker.clone(),
         }
     }
 
@@ -263,7 +320,10 @@ impl Clone for ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            hooks: self.hooks.clone(),
             stop: self.stop.clone(),
+            memory_budget: self.memory_budget.clone(),
+            spill_tracker: self.spill_tracker.clone(),
         }
     }
 }
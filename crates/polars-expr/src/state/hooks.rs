@@ -0,0 +1,62 @@
+use std::sync::RwLock;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+
+/// The optimized plan of a query that is about to be executed.
+pub struct PlanOptimizedEvent<'a> {
+    /// The plan as printed by `LazyFrame::explain`.
+    pub plan: &'a str,
+}
+
+/// An operator of the physical plan that started or finished.
+pub struct OperatorEvent<'a> {
+    /// The name of the operator, as shown by `LazyFrame::profile`.
+    pub name: &'a str,
+    /// The time the operator took. `None` when the operator starts.
+    pub elapsed: Option<Duration>,
+}
+
+/// A sink that has written all of its output.
+pub struct SinkCompleteEvent<'a> {
+    /// The path or uri that was written to.
+    pub target: &'a str,
+    /// The time the query took, including optimization.
+    pub elapsed: Duration,
+}
+
+/// Callbacks around the execution of queries, for auditing, caching or metrics.
+///
+/// The hooks are called from the threads that execute the query, so they should return quickly.
+pub trait ExecutionHooks: Send + Sync {
+    fn on_plan_optimized(&self, _event: &PlanOptimizedEvent) {}
+
+    fn on_operator_start(&self, _event: &OperatorEvent) {}
+
+    fn on_operator_finish(&self, _event: &OperatorEvent) {}
+
+    fn on_sink_complete(&self, _event: &SinkCompleteEvent) {}
+}
+
+pub type ExecutionHooksRef = Arc<[Arc<dyn ExecutionHooks>]>;
+
+static EXECUTION_HOOKS: Lazy<RwLock<Option<ExecutionHooksRef>>> = Lazy::new(|| RwLock::new(None));
+
+/// Register `hooks` to be called by all queries that start executing afterwards.
+pub fn register_execution_hooks(hooks: Arc<dyn ExecutionHooks>) {
+    let mut registered = EXECUTION_HOOKS.write().unwrap();
+    let mut all = registered.as_deref().unwrap_or_default().to_vec();
+    all.push(hooks);
+    *registered = Some(all.into());
+}
+
+/// Remove all registered [`ExecutionHooks`].
+pub fn clear_execution_hooks() {
+    *EXECUTION_HOOKS.write().unwrap() = None;
+}
+
+/// The hooks that are registered, if any.
+pub fn execution_hooks() -> Option<ExecutionHooksRef> {
+    EXECUTION_HOOKS.read().unwrap().clone()
+}
@@ -1,5 +1,7 @@
 mod execution_state;
+mod hooks;
 mod node_timer;
 
 pub use execution_state::*;
+pub use hooks::*;
 use node_timer::*;
@@ -1,3 +1,4 @@
+pub mod stream;
 pub mod version_0;
 
 use std::mem::ManuallyDrop;
@@ -0,0 +1,102 @@
+//! Import and export of chunked data through the Arrow
+//! [C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html), to
+//! interchange data with e.g. DuckDB, DataFusion or ADBC drivers.
+//!
+//! A stream carries a struct array per record batch, of which the fields are the columns. The
+//! buffers of the arrays are shared, not copied.
+use arrow::array::{Array, ArrayRef, StructArray};
+use arrow::ffi::{export_iterator, ArrowArrayStream, ArrowArrayStreamReader};
+use arrow::record_batch::RecordBatch;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+/// Export `df` as a stream with a record batch per chunk.
+///
+/// If `pl_flavor` is false, string and binary views are converted to the (large) string and
+/// binary arrays that consumers without view support expect, which copies them.
+pub fn export_dataframe(df: DataFrame, pl_flavor: bool) -> ArrowArrayStream {
+    let schema = df.schema();
+    export_dataframes(&schema, std::iter::once(Ok(df)), pl_flavor)
+}
+
+/// Export `dfs`, e.g. the batches of a streaming query, as a stream of their chunks. The
+/// iterator is advanced as the consumer reads the stream, so the batches don't have to be in
+/// memory at once.
+///
+/// Every [`DataFrame`] must have `schema`.
+pub fn export_dataframes<I>(schema: &Schema, dfs: I, pl_flavor: bool) -> ArrowArrayStream
+where
+    I: Iterator<Item = PolarsResult<DataFrame>> + 'static,
+{
+    let dtype = ArrowDataType::Struct(schema.to_arrow(pl_flavor).fields);
+    let field = ArrowField::new("", dtype.clone(), false);
+    let arrays = dfs.flat_map(move |df| match df {
+        Ok(df) => struct_arrays(df, &dtype, pl_flavor),
+        Err(err) => vec![Err(err)],
+    });
+    export_iterator(Box::new(arrays), field)
+}
+
+/// The chunks of `df` as struct arrays of `dtype`.
+fn struct_arrays(
+    mut df: DataFrame,
+    dtype: &ArrowDataType,
+    pl_flavor: bool,
+) -> Vec<PolarsResult<ArrayRef>> {
+    df.align_chunks();
+    df.iter_chunks(pl_flavor, false)
+        .map(|batch| Ok(StructArray::new(dtype.clone(), batch.into_arrays(), None).boxed()))
+        .collect()
+}
+
+/// Import all record batches of `stream` into a [`DataFrame`] with a chunk per batch.
+///
+/// # Safety
+/// `stream` must fulfill the C stream interface, and the arrays it produces the C data interface.
+pub unsafe fn import_stream(mut stream: ArrowArrayStream) -> PolarsResult<DataFrame> {
+    let mut reader = ArrowArrayStreamReader::try_new(&mut stream)?;
+    let ArrowDataType::Struct(fields) = reader.field().data_type().clone() else {
+        polars_bail!(
+            ComputeError: "expected a stream of struct arrays, got {:?}",
+            reader.field().data_type()
+        )
+    };
+
+    let mut dfs = vec![];
+    while let Some(array) = reader.next() {
+        let array = array?;
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        let batch = RecordBatch::new(array.values().to_vec());
+        dfs.push(DataFrame::try_from((batch, fields.as_slice()))?);
+    }
+    if dfs.is_empty() {
+        let schema = ArrowSchema::from(fields);
+        return Ok(DataFrame::empty_with_arrow_schema(&schema));
+    }
+    Ok(accumulate_dataframes_vertical_unchecked(dfs))
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_stream_roundtrip() {
+        let mut df = df!["a" => [1, 2], "b" => ["x", "y"]].unwrap();
+        df.vstack_mut(&df.clone()).unwrap();
+
+        for pl_flavor in [true, false] {
+            let out = unsafe { import_stream(export_dataframe(df.clone(), pl_flavor)) }.unwrap();
+            assert!(out.equals(&df));
+            assert_eq!(out.n_chunks(), 2);
+        }
+
+        let schema = df.schema();
+        let stream = export_dataframes(&schema, std::iter::empty(), true);
+        let out = unsafe { import_stream(stream) }.unwrap();
+        assert_eq!(out.schema(), schema);
+        assert_eq!(out.height(), 0);
+    }
+}
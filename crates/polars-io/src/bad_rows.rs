@@ -0,0 +1,52 @@
+//! Rows that failed to parse, collected by the error tolerant readers instead of raising, see
+//! [`CsvReader::finish_with_bad_rows`](crate::csv::read::CsvReader::finish_with_bad_rows).
+use polars_core::prelude::*;
+
+/// The rows of a read that failed to parse.
+///
+/// These are returned as a DataFrame with the 1-based line number of the row in the file
+/// (`line`), the raw bytes of the row (`raw`) and why it failed to parse (`error`), so that
+/// the rows can be quarantined and repaired.
+#[derive(Default)]
+pub(crate) struct BadRows {
+    lines: Vec<u64>,
+    raw: Vec<Vec<u8>>,
+    errors: Vec<String>,
+}
+
+impl BadRows {
+    pub(crate) fn push(&mut self, line: usize, raw: &[u8], err: &PolarsError) {
+        // Only keep the message itself, not the hints that follow it.
+        let err = err.to_string();
+        let reason = err.lines().next().unwrap_or_default();
+
+        self.lines.push(line as u64);
+        self.raw.push(raw.to_vec());
+        self.errors.push(reason.to_string());
+    }
+
+    /// Append the bad rows of a block of which the first row is at line `line_offset + 1`.
+    #[cfg(feature = "json")]
+    pub(crate) fn append(&mut self, other: BadRows, line_offset: usize) {
+        self.lines.extend(
+            other
+                .lines
+                .into_iter()
+                .map(|line| line + line_offset as u64),
+        );
+        self.raw.extend(other.raw);
+        self.errors.extend(other.errors);
+    }
+
+    pub(crate) fn into_df(self) -> DataFrame {
+        let lines = UInt64Chunked::from_vec("line", self.lines);
+        let raw = BinaryChunked::from_iter_values("raw", self.raw.iter().map(|raw| raw.as_slice()));
+        let errors = StringChunked::from_iter_values("error", self.errors.iter());
+        DataFrame::new(vec![
+            lines.into_series(),
+            raw.into_series(),
+            errors.into_series(),
+        ])
+        .unwrap()
+    }
+}
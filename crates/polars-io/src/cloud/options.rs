@@ -72,7 +72,7 @@ pub struct CloudOptions {
 impl Default for CloudOptions {
     fn default() -> Self {
         Self {
-            max_retries: 2,
+            max_retries: get_default_max_retries(),
             #[cfg(feature = "file_cache")]
             file_cache_ttl: get_env_file_cache_ttl(),
             #[cfg(feature = "aws")]
@@ -160,6 +160,13 @@ impl FromStr for CloudType {
         polars_bail!(ComputeError: "at least one of the cloud features must be enabled");
     }
 }
+
+fn get_default_max_retries() -> usize {
+    polars_core::config::get_var(polars_core::config::CLOUD_MAX_RETRIES)
+        .map(|x| x.parse::<usize>().expect("integer"))
+        .unwrap_or(2)
+}
+
 #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
 fn get_retry_config(max_retries: usize) -> RetryConfig {
     RetryConfig {
@@ -7,7 +7,7 @@ use polars_error::PolarsResult;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::RowIndex;
+use crate::{DtypeOverrides, RowIndex};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -26,6 +26,7 @@ pub struct CsvReadOptions {
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
     pub dtype_overwrite: Option<Arc<Vec<DataType>>>,
+    pub dtype_overrides: Option<Arc<DtypeOverrides>>,
     // CSV-specific options
     pub parse_options: Arc<CsvParseOptions>,
     pub has_header: bool,
@@ -70,6 +71,7 @@ impl Default for CsvReadOptions {
             schema: None,
             schema_overwrite: None,
             dtype_overwrite: None,
+            dtype_overrides: None,
 
             parse_options: Default::default(),
             has_header: true,
@@ -178,6 +180,13 @@ impl CsvReadOptions {
         self
     }
 
+    /// Overrides the inferred data types and the number of rows to infer them from for the
+    /// columns of which the name matches a pattern, see [`DtypeOverrides`].
+    pub fn with_dtype_overrides(mut self, dtype_overrides: Option<Arc<DtypeOverrides>>) -> Self {
+        self.dtype_overrides = dtype_overrides;
+        self
+    }
+
     /// Sets the CSV parsing options. See [map_parse_options][Self::map_parse_options]
     /// for an easier way to mutate them in-place.
     pub fn with_parse_options(mut self, parse_options: CsvParseOptions) -> Self {
@@ -26,7 +26,7 @@ use super::utils::is_compressed;
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::update_row_counts;
-use crate::RowIndex;
+use crate::{DtypeOverrides, RowIndex};
 
 pub(crate) fn cast_columns(
     df: &mut DataFrame,
@@ -149,6 +149,7 @@ impl<'a> CoreReader<'a> {
         mut n_threads: Option<usize>,
         schema_overwrite: Option<SchemaRef>,
         dtype_overwrite: Option<Arc<Vec<DataType>>>,
+        dtype_overrides: Option<Arc<DtypeOverrides>>,
         sample_size: usize,
         chunk_size: usize,
         low_memory: bool,
@@ -202,6 +203,7 @@ impl<'a> CoreReader<'a> {
                     max_records,
                     has_header,
                     schema_overwrite.as_deref(),
+                    dtype_overrides.as_deref(),
                     skip_rows,
                     skip_rows_after_header,
                     comment_prefix.as_ref(),
@@ -324,6 +326,20 @@ impl<'a> CoreReader<'a> {
         Ok((bytes, starting_point_offset))
     }
 
+    pub(super) fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// The bytes of the data rows, after the skipped rows and the header, and the 1-based line
+    /// number of the first data row.
+    pub(super) fn data_rows(&self) -> PolarsResult<(&[u8], usize)> {
+        let bytes = self.reader_bytes.as_deref().unwrap();
+        let (data, offset) = self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
+        let offset = offset.unwrap_or(bytes.len());
+        let line = 1 + memchr::memchr_iter(self.eol_char, &bytes[..offset]).count();
+        Ok((data, line))
+    }
+
     /// Estimates number of rows and optionally ensure we don't read more than `n_rows`
     /// by slicing `bytes` to the upper bound.
     fn estimate_rows_and_set_upper_bound<'b>(
@@ -481,10 +497,7 @@ impl<'a> CoreReader<'a> {
                     &projection
                         .iter()
                         .map(|&i| self.schema.get_at_index(i).unwrap())
-                        .map(|(name, dtype)| Field {
-                            name: name.clone(),
-                            dtype: dtype.clone(),
-                        })
+                        .map(|(name, dtype)| Field::from_owned(name.clone(), dtype.clone()))
                         .collect::<Schema>(),
                 )
             };
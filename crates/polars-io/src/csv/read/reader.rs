@@ -1,16 +1,21 @@
 use std::fs::File;
+use std::io::Cursor;
+use std::ops::Range;
 use std::path::PathBuf;
 
 use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 #[cfg(feature = "temporal")]
 use polars_time::prelude::*;
 #[cfg(feature = "temporal")]
 use rayon::prelude::*;
 
 use super::options::CsvReadOptions;
+use super::parser::SplitLines;
 use super::read_impl::batched::to_batched_owned;
 use super::read_impl::CoreReader;
 use super::{infer_file_schema, BatchedCsvReader, OwnedBatchedCsvReader};
+use crate::bad_rows::BadRows;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::shared::SerReader;
@@ -137,6 +142,7 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.n_threads,
             schema,
             self.options.dtype_overwrite.clone(),
+            self.options.dtype_overrides.clone(),
             self.options.sample_size,
             self.options.chunk_size,
             self.options.low_memory,
@@ -227,6 +233,121 @@ impl<R: MmapBytesReader> CsvReader<R> {
     }
 }
 
+impl<R: MmapBytesReader> CsvReader<R> {
+    /// Read the file and return the rows that fail to parse in a separate DataFrame, instead of
+    /// raising an error on the first of them.
+    ///
+    /// The bad rows have the line number of the row in the file (`line`), the raw bytes of the
+    /// row (`raw`) and why it failed to parse (`error`). Rows are checked by the regular parser:
+    /// a block of rows that fails to parse is split until the rows that fail are found, so a
+    /// file with few bad rows reads about as fast as with [`finish`](SerReader::finish). Set a
+    /// schema to quarantine values that don't match it, otherwise the schema is inferred from
+    /// all rows, including the bad ones.
+    pub fn finish_with_bad_rows(mut self) -> PolarsResult<(DataFrame, DataFrame)> {
+        polars_ensure!(
+            self.options.n_rows.is_none() && self.options.row_index.is_none(),
+            InvalidOperation: "bad rows cannot be collected when reading with 'n_rows' or 'row_index'"
+        );
+        let options = self.options.clone();
+        let parse_options = options.get_parse_options();
+        let core_reader = self.core_reader(options.schema_overwrite.clone(), vec![])?;
+        let schema = core_reader.schema();
+        let (data, first_line) = core_reader.data_rows()?;
+
+        // The byte range and the line number of every row.
+        let mut rows = vec![];
+        let mut line = first_line;
+        let quote_char = parse_options.quote_char.unwrap_or(b'"');
+        for row in SplitLines::new(data, quote_char, parse_options.eol_char) {
+            let start = row.as_ptr() as usize - data.as_ptr() as usize;
+            rows.push((start..start + row.len(), line));
+            line += 1 + memchr::memchr_iter(parse_options.eol_char, row).count();
+        }
+
+        let row_options = CsvReadOptions {
+            rechunk: false,
+            n_threads: options.n_threads,
+            low_memory: options.low_memory,
+            schema: Some(schema),
+            parse_options: parse_options.clone(),
+            has_header: false,
+            sample_size: options.sample_size,
+            chunk_size: options.chunk_size,
+            raise_if_empty: false,
+            ..Default::default()
+        };
+        let parse = |rows: &[(Range<usize>, usize)]| {
+            let bytes = &data[rows[0].0.start..rows[rows.len() - 1].0.end];
+            row_options
+                .clone()
+                .into_reader_with_file_handle(Cursor::new(bytes))
+                .finish()
+        };
+
+        // The blocks are parsed separately, their categories must be comparable.
+        #[cfg(feature = "dtype-categorical")]
+        let _cat_lock = core_reader
+            .schema()
+            .iter_dtypes()
+            .any(|dtype| matches!(dtype, DataType::Categorical(_, _)))
+            .then(polars_core::StringCacheHolder::hold);
+
+        let mut dfs = vec![];
+        let mut bad_rows = BadRows::default();
+        parse_or_split(&rows, &parse, data, &mut dfs, &mut bad_rows)?;
+
+        let mut df = if dfs.is_empty() {
+            DataFrame::empty_with_schema(&core_reader.schema())
+        } else {
+            accumulate_dataframes_vertical_unchecked(dfs)
+        };
+        if let Some(columns) = &options.columns {
+            df = df.select(columns.iter())?;
+        } else if let Some(projection) = &options.projection {
+            let mut projection = projection.as_ref().clone();
+            projection.sort_unstable();
+            let names = df.get_column_names_owned();
+            df = df.select(projection.iter().map(|&i| names[i].as_str()))?;
+        }
+        if options.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok((df, bad_rows.into_df()))
+    }
+}
+
+/// Parse `rows`, or split them in halves if they fail to parse until the rows that fail are
+/// found.
+fn parse_or_split<F>(
+    rows: &[(Range<usize>, usize)],
+    parse: &F,
+    data: &[u8],
+    dfs: &mut Vec<DataFrame>,
+    bad_rows: &mut BadRows,
+) -> PolarsResult<()>
+where
+    F: Fn(&[(Range<usize>, usize)]) -> PolarsResult<DataFrame>,
+{
+    if rows.is_empty() {
+        return Ok(());
+    }
+    match parse(rows) {
+        Ok(df) => dfs.push(df),
+        // Don't hide that the read was interrupted.
+        Err(err @ PolarsError::Cancelled(_)) => return Err(err),
+        Err(err) if rows.len() == 1 => {
+            let (range, line) = &rows[0];
+            bad_rows.push(*line, &data[range.clone()], &err)
+        },
+        Err(_) => {
+            let (left, right) = rows.split_at(rows.len() / 2);
+            parse_or_split(left, parse, data, dfs, bad_rows)?;
+            parse_or_split(right, parse, data, dfs, bad_rows)?;
+        },
+    }
+    Ok(())
+}
+
 impl CsvReader<Box<dyn MmapBytesReader>> {
     pub fn batched(mut self, schema: Option<SchemaRef>) -> PolarsResult<OwnedBatchedCsvReader> {
         match schema {
@@ -241,6 +362,7 @@ impl CsvReader<Box<dyn MmapBytesReader>> {
                     self.options.infer_schema_length,
                     self.options.has_header,
                     None,
+                    self.options.dtype_overrides.as_deref(),
                     self.options.skip_rows,
                     self.options.skip_rows_after_header,
                     parse_options.comment_prefix.as_ref(),
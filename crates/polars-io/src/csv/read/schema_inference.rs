@@ -13,6 +13,7 @@ use super::parser::{is_comment_line, skip_bom, skip_line_ending, SplitLines};
 use super::splitfields::SplitFields;
 use super::CsvReadOptions;
 use crate::mmap::ReaderBytes;
+use crate::options::DtypeOverrides;
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
 #[derive(Clone, Debug, Default)]
@@ -36,6 +37,7 @@ impl SchemaInferenceResult {
         let has_header = options.has_header;
         let schema_overwrite_arc = options.schema_overwrite.clone();
         let schema_overwrite = schema_overwrite_arc.as_ref().map(|x| x.as_ref());
+        let dtype_overrides = options.dtype_overrides.as_deref();
         let skip_rows = options.skip_rows;
         let skip_rows_after_header = options.skip_rows_after_header;
         let comment_prefix = parse_options.comment_prefix.as_ref();
@@ -55,6 +57,7 @@ impl SchemaInferenceResult {
             infer_schema_length,
             has_header,
             schema_overwrite,
+            dtype_overrides,
             skip_rows,
             skip_rows_after_header,
             comment_prefix,
@@ -195,6 +198,7 @@ fn infer_file_schema_inner(
     max_read_rows: Option<usize>,
     has_header: bool,
     schema_overwrite: Option<&Schema>,
+    dtype_overrides: Option<&DtypeOverrides>,
     // we take &mut because we maybe need to skip more rows dependent
     // on the schema inference
     mut skip_rows: usize,
@@ -298,6 +302,7 @@ fn infer_file_schema_inner(
             max_read_rows,
             has_header,
             schema_overwrite,
+            dtype_overrides,
             skip_rows,
             skip_rows_after_header,
             comment_prefix,
@@ -321,6 +326,23 @@ fn infer_file_schema_inner(
     }
 
     let header_length = headers.len();
+    let overrides = dtype_overrides
+        .map(|overrides| overrides.resolve(headers.iter().map(|name| name.as_str())))
+        .transpose()?;
+    // The number of rows to infer every column from, if it is overridden.
+    let column_lengths = overrides
+        .iter()
+        .flatten()
+        .map(|o| o.infer_schema_length(max_read_rows))
+        .collect::<Vec<_>>();
+    // Read enough rows for the column that is inferred from the most rows.
+    let read_rows = if column_lengths.is_empty() {
+        max_read_rows
+    } else {
+        column_lengths
+            .iter()
+            .try_fold(0, |acc: usize, len| len.map(|len| acc.max(len)))
+    };
     // keep track of inferred field types
     let mut column_types: Vec<PlHashSet<DataType>> =
         vec![PlHashSet::with_capacity(4); header_length];
@@ -335,7 +357,7 @@ fn infer_file_schema_inner(
 
     let mut end_ptr = start_ptr;
     for mut line in records_ref
-        .take(match max_read_rows {
+        .take(match read_rows {
             Some(max_read_rows) => {
                 if max_read_rows <= (usize::MAX - skip_rows_after_header) {
                     // read skip_rows_after_header more rows for inferring
@@ -376,6 +398,12 @@ fn infer_file_schema_inner(
 
         for i in 0..header_length {
             if let Some((slice, needs_escaping)) = record.next() {
+                if column_lengths
+                    .get(i)
+                    .is_some_and(|len| len.is_some_and(|len| rows_count > len))
+                {
+                    continue;
+                }
                 if slice.is_empty() {
                     unsafe { *nulls.get_unchecked_release_mut(i) = true };
                 } else {
@@ -462,6 +490,11 @@ fn infer_file_schema_inner(
             }
         }
 
+        if let Some(dtype) = overrides.as_ref().and_then(|o| o[i].dtype.clone()) {
+            fields.push(Field::new(field_name, dtype));
+            continue;
+        }
+
         let possibilities = &column_types[i];
         let dtype = finish_infer_field_schema(possibilities);
         fields.push(Field::new(field_name, dtype));
@@ -483,6 +516,7 @@ fn infer_file_schema_inner(
             max_read_rows,
             has_header,
             schema_overwrite,
+            dtype_overrides,
             skip_rows,
             skip_rows_after_header,
             comment_prefix,
@@ -523,6 +557,7 @@ pub fn infer_file_schema(
     max_read_rows: Option<usize>,
     has_header: bool,
     schema_overwrite: Option<&Schema>,
+    dtype_overrides: Option<&DtypeOverrides>,
     // we take &mut because we maybe need to skip more rows dependent
     // on the schema inference
     skip_rows: usize,
@@ -543,6 +578,7 @@ pub fn infer_file_schema(
         max_read_rows,
         has_header,
         schema_overwrite,
+        dtype_overrides,
         skip_rows,
         skip_rows_after_header,
         comment_prefix,
@@ -21,5 +21,5 @@ mod options;
 mod write_impl;
 mod writer;
 
-pub use options::{CsvWriterOptions, QuoteStyle, SerializeOptions};
+pub use options::{CsvWriterOptions, NestedSerialization, QuoteStyle, SerializeOptions};
 pub use writer::{BatchedWriter, CsvWriter};
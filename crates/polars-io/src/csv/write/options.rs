@@ -52,6 +52,9 @@ pub struct SerializeOptions {
     pub line_terminator: String,
     /// When to insert quotes.
     pub quote_style: QuoteStyle,
+    /// How to write [`DataType::List`](polars_core::datatypes::DataType::List) and
+    /// [`DataType::Struct`](polars_core::datatypes::DataType::Struct) columns.
+    pub nested: NestedSerialization,
 }
 
 impl Default for SerializeOptions {
@@ -67,6 +70,7 @@ impl Default for SerializeOptions {
             null: String::new(),
             line_terminator: "\n".into(),
             quote_style: Default::default(),
+            nested: Default::default(),
         }
     }
 }
@@ -93,3 +97,24 @@ pub enum QuoteStyle {
     /// Never quote any fields, even if it would produce invalid CSV data.
     Never,
 }
+
+/// How to write nested values, i.e. lists and structs, that CSV has no representation for.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NestedSerialization {
+    /// Raise an error if a column is nested.
+    /// This is the default.
+    #[default]
+    Error,
+    /// Write nested values as JSON, e.g. `[1,2]` or `{"a":1,"b":"x"}`.
+    ///
+    /// Requires the `json` feature.
+    Json,
+    /// Write the elements of a list, or the fields of a struct, joined by the given string,
+    /// e.g. `1;2`. Missing elements are written as the null value representation, elements that
+    /// are nested themselves as JSON.
+    Join(String),
+    /// Write a row per element of the list columns, the other columns are repeated. The lists in
+    /// a row must all have the same length. Structs are written as JSON.
+    Explode,
+}
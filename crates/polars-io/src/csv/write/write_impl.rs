@@ -1,9 +1,11 @@
+mod nested;
 mod serializer;
 
 use std::io::Write;
 
 use arrow::array::NullArray;
 use arrow::legacy::time_zone::Tz;
+use nested::serialize_nested;
 use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_error::polars_ensure;
@@ -20,24 +22,14 @@ pub(crate) fn write<W: Write>(
     options: &SerializeOptions,
     n_threads: usize,
 ) -> PolarsResult<()> {
+    #[cfg(feature = "object")]
     for s in df.get_columns() {
-        let nested = match s.dtype() {
-            DataType::List(_) => true,
-            #[cfg(feature = "dtype-struct")]
-            DataType::Struct(_) => true,
-            #[cfg(feature = "object")]
-            DataType::Object(_, _) => {
-                return Err(PolarsError::ComputeError(
-                    "csv writer does not support object dtype".into(),
-                ))
-            },
-            _ => false,
-        };
         polars_ensure!(
-            !nested,
-            ComputeError: "CSV format does not support nested data",
+            !matches!(s.dtype(), DataType::Object(_, _)),
+            ComputeError: "csv writer does not support object dtype",
         );
     }
+    let df = &*serialize_nested(df, options)?;
 
     // Check that the double quote is valid UTF-8.
     polars_ensure!(
@@ -118,97 +110,121 @@ pub(crate) fn write<W: Write>(
     let serializer_pool = LowContentionPool::<Vec<_>>::new(n_threads);
     let write_buffer_pool = LowContentionPool::<Vec<_>>::new(n_threads);
 
-    let mut n_rows_finished = 0;
-
-    // holds the buffers that will be written
-    let mut result_buf: Vec<PolarsResult<Vec<u8>>> = Vec::with_capacity(n_threads);
-
-    while n_rows_finished < len {
-        let buf_writer = |thread_no| {
-            let thread_offset = thread_no * chunk_size;
-            let total_offset = n_rows_finished + thread_offset;
-            let mut df = df.slice(total_offset as i64, chunk_size);
-            // the `series.iter` needs rechunked series.
-            // we don't do this on the whole as this probably needs much less rechunking
-            // so will be faster.
-            // and allows writing `pl.concat([df] * 100, rechunk=False).write_csv()` as the rechunk
-            // would go OOM
-            df.as_single_chunk();
-            let cols = df.get_columns();
-
-            // SAFETY:
-            // the bck thinks the lifetime is bounded to write_buffer_pool, but at the time we return
-            // the vectors the buffer pool, the series have already been removed from the buffers
-            // in other words, the lifetime does not leave this scope
-            let cols = unsafe { std::mem::transmute::<&[Series], &[Series]>(cols) };
-            let mut write_buffer = write_buffer_pool.get();
-
-            if df.is_empty() {
-                return Ok(write_buffer);
-            }
+    let buf_writer = |n_rows_finished: usize, thread_no: usize| {
+        let thread_offset = thread_no * chunk_size;
+        let total_offset = n_rows_finished + thread_offset;
+        let mut df = df.slice(total_offset as i64, chunk_size);
+        // the `series.iter` needs rechunked series.
+        // we don't do this on the whole as this probably needs much less rechunking
+        // so will be faster.
+        // and allows writing `pl.concat([df] * 100, rechunk=False).write_csv()` as the rechunk
+        // would go OOM
+        df.as_single_chunk();
+        let cols = df.get_columns();
 
-            let mut serializers_vec = serializer_pool.get();
-            if serializers_vec.is_empty() {
-                serializers_vec = cols
-                    .iter()
-                    .enumerate()
-                    .map(|(i, col)| {
-                        serializer_for(
-                            &*col.chunks()[0],
-                            options,
-                            col.dtype(),
-                            datetime_formats[i],
-                            time_zones[i],
-                        )
-                    })
-                    .collect::<Result<_, _>>()?;
-            } else {
-                debug_assert_eq!(serializers_vec.len(), cols.len());
-                for (col_iter, col) in std::iter::zip(&mut serializers_vec, cols) {
-                    col_iter.update_array(&*col.chunks()[0]);
-                }
-            }
+        // SAFETY:
+        // the bck thinks the lifetime is bounded to write_buffer_pool, but at the time we return
+        // the vectors the buffer pool, the series have already been removed from the buffers
+        // in other words, the lifetime does not leave this scope
+        let cols = unsafe { std::mem::transmute::<&[Series], &[Series]>(cols) };
+        let mut write_buffer = write_buffer_pool.get();
 
-            let serializers = serializers_vec.as_mut_slice();
+        if df.is_empty() {
+            return Ok(write_buffer);
+        }
 
-            let len = std::cmp::min(cols[0].len(), chunk_size);
+        let mut serializers_vec = serializer_pool.get();
+        if serializers_vec.is_empty() {
+            serializers_vec = cols
+                .iter()
+                .enumerate()
+                .map(|(i, col)| {
+                    serializer_for(
+                        &*col.chunks()[0],
+                        options,
+                        col.dtype(),
+                        datetime_formats[i],
+                        time_zones[i],
+                    )
+                })
+                .collect::<Result<_, _>>()?;
+        } else {
+            debug_assert_eq!(serializers_vec.len(), cols.len());
+            for (col_iter, col) in std::iter::zip(&mut serializers_vec, cols) {
+                col_iter.update_array(&*col.chunks()[0]);
+            }
+        }
 
-            for _ in 0..len {
-                serializers[0].serialize(&mut write_buffer, options);
-                for serializer in &mut serializers[1..] {
-                    write_buffer.push(options.separator);
-                    serializer.serialize(&mut write_buffer, options);
-                }
+        let serializers = serializers_vec.as_mut_slice();
 
-                write_buffer.extend_from_slice(options.line_terminator.as_bytes());
+        let len = std::cmp::min(cols[0].len(), chunk_size);
+
+        for _ in 0..len {
+            serializers[0].serialize(&mut write_buffer, options);
+            for serializer in &mut serializers[1..] {
+                write_buffer.push(options.separator);
+                serializer.serialize(&mut write_buffer, options);
             }
 
-            serializer_pool.set(serializers_vec);
+            write_buffer.extend_from_slice(options.line_terminator.as_bytes());
+        }
+
+        serializer_pool.set(serializers_vec);
 
-            Ok(write_buffer)
-        };
+        Ok(write_buffer)
+    };
 
+    // Serialize the rows of a round in parallel, `chunk_size` rows per thread.
+    let serialize_round = |n_rows_finished: usize| -> PolarsResult<Vec<Vec<u8>>> {
         if n_threads > 1 {
-            let par_iter = (0..n_threads).into_par_iter().map(buf_writer);
             // rayon will ensure the right order
-            POOL.install(|| result_buf.par_extend(par_iter));
+            POOL.install(|| {
+                (0..n_threads)
+                    .into_par_iter()
+                    .map(|thread_no| buf_writer(n_rows_finished, thread_no))
+                    .collect()
+            })
         } else {
-            result_buf.push(buf_writer(0));
+            Ok(vec![buf_writer(n_rows_finished, 0)?])
         }
-
-        for buf in result_buf.drain(..) {
-            let mut buf = buf?;
+    };
+    let mut write_round = |round: Vec<Vec<u8>>| -> PolarsResult<()> {
+        for mut buf in round {
             writer.write_all(&buf)?;
             buf.clear();
             write_buffer_pool.set(buf);
         }
+        Ok(())
+    };
 
-        n_rows_finished += total_rows_per_pool_iter;
+    if len <= total_rows_per_pool_iter {
+        return write_round(serialize_round(0)?);
     }
-    Ok(())
+
+    // The writer doesn't have to be `Send`, so it stays on this thread. The next rounds are
+    // serialized on another thread in the meantime, so that the pool doesn't idle while the
+    // previous round is written.
+    std::thread::scope(|scope| {
+        let (tx, rx) = std::sync::mpsc::sync_channel(1);
+        scope.spawn(move || {
+            let mut n_rows_finished = 0;
+            while n_rows_finished < len {
+                let round = serialize_round(n_rows_finished);
+                let is_err = round.is_err();
+                // The receiver hangs up if writing failed.
+                if tx.send(round).is_err() || is_err {
+                    break;
+                }
+                n_rows_finished += total_rows_per_pool_iter;
+            }
+        });
+        for round in rx {
+            write_round(round?)?;
+        }
+        Ok(())
+    })
 }
 
-/// Writes a CSV header to `writer`.
 pub(crate) fn write_header<W: Write>(
     writer: &mut W,
     names: &[&str],
@@ -0,0 +1,133 @@
+//! Conversion of nested columns to columns that can be written to CSV.
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+
+use crate::csv::write::{NestedSerialization, SerializeOptions};
+
+fn is_nested(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::List(_) => true,
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => true,
+        _ => false,
+    }
+}
+
+/// Replace the nested columns of `df` as configured by `options.nested`.
+pub(super) fn serialize_nested<'a>(
+    df: &'a DataFrame,
+    options: &SerializeOptions,
+) -> PolarsResult<Cow<'a, DataFrame>> {
+    if !df.iter().any(|s| is_nested(s.dtype())) {
+        return Ok(Cow::Borrowed(df));
+    }
+
+    let mut df = match &options.nested {
+        NestedSerialization::Error => {
+            polars_bail!(ComputeError: "CSV format does not support nested data")
+        },
+        NestedSerialization::Explode if df.iter().any(|s| s.dtype().is_list()) => {
+            let lists = df
+                .iter()
+                .filter(|s| matches!(s.dtype(), DataType::List(_)))
+                .map(|s| s.name().to_string())
+                .collect::<Vec<_>>();
+            df.explode(lists)?
+        },
+        _ => df.clone(),
+    };
+
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| match &options.nested {
+            NestedSerialization::Join(separator) => join_nested(s, separator, &options.null),
+            _ => to_strings(s),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    // SAFETY: the names and lengths of the columns don't change.
+    unsafe { *df.get_columns_mut() = columns };
+    Ok(Cow::Owned(df))
+}
+
+/// The values of `s` as strings if they are nested, otherwise `s` itself.
+fn to_strings(s: &Series) -> PolarsResult<Series> {
+    if is_nested(s.dtype()) {
+        to_json(s)
+    } else {
+        Ok(s.clone())
+    }
+}
+
+#[cfg(feature = "json")]
+fn to_json(s: &Series) -> PolarsResult<Series> {
+    let iter = (0..s.n_chunks()).map(|i| {
+        let arr = s.to_arrow(i, true);
+        // Null values are written as the null value representation, not as JSON `null`.
+        polars_json::json::write::serialize_to_utf8(arr.as_ref())
+            .with_validity(arr.validity().cloned())
+    });
+    Ok(StringChunked::from_chunk_iter(s.name(), iter).into_series())
+}
+
+#[cfg(not(feature = "json"))]
+fn to_json(_s: &Series) -> PolarsResult<Series> {
+    polars_bail!(ComputeError: "writing nested data as JSON requires the 'json' feature")
+}
+
+/// The elements of the values of `s` as strings, joined by `separator`.
+fn join_nested(s: &Series, separator: &str, null: &str) -> PolarsResult<Series> {
+    let elements_to_string = |s: &Series| -> PolarsResult<StringChunked> {
+        let s = if is_nested(s.dtype()) {
+            to_json(s)?
+        } else {
+            s.cast(&DataType::String)?
+        };
+        Ok(s.str()?.rechunk())
+    };
+    let join = |values: &mut dyn Iterator<Item = Option<&str>>| {
+        let mut out = String::new();
+        for (i, value) in values.enumerate() {
+            if i > 0 {
+                out.push_str(separator);
+            }
+            out.push_str(value.unwrap_or(null));
+        }
+        out
+    };
+
+    let out: StringChunked = match s.dtype() {
+        DataType::List(_) => {
+            let ca = s.list()?.rechunk();
+            let arr = ca.downcast_iter().next().unwrap();
+            let values = elements_to_string(&ca.get_inner())?;
+            (0..arr.len())
+                .map(|i| {
+                    arr.is_valid(i).then(|| {
+                        let (start, end) = arr.offsets().start_end(i);
+                        join(&mut (start..end).map(|j| values.get(j)))
+                    })
+                })
+                .collect()
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => {
+            let ca = s.struct_()?;
+            let fields = ca
+                .fields()
+                .iter()
+                .map(elements_to_string)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let is_null = s.is_null();
+            (0..s.len())
+                .map(|i| {
+                    (!is_null.get(i).unwrap_or(false))
+                        .then(|| join(&mut fields.iter().map(|field| field.get(i))))
+                })
+                .collect()
+        },
+        _ => return Ok(s.clone()),
+    };
+    Ok(out.with_name(s.name()).into_series())
+}
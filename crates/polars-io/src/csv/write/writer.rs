@@ -7,7 +7,7 @@ use polars_core::POOL;
 use polars_error::PolarsResult;
 
 use super::write_impl::{write, write_bom, write_header};
-use super::{QuoteStyle, SerializeOptions};
+use super::{NestedSerialization, QuoteStyle, SerializeOptions};
 use crate::shared::SerWriter;
 
 /// Write a DataFrame to csv.
@@ -156,6 +156,13 @@ where
         self
     }
 
+    /// Set how list and struct columns are written.
+    /// See more on [`NestedSerialization`].
+    pub fn with_nested_serialization(mut self, nested: NestedSerialization) -> Self {
+        self.options.nested = nested;
+        self
+    }
+
     pub fn n_threads(mut self, n_threads: usize) -> Self {
         self.n_threads = n_threads;
         self
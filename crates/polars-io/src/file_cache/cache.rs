@@ -184,7 +184,7 @@ impl FileCache {
 }
 
 pub fn get_env_file_cache_ttl() -> u64 {
-    std::env::var("POLARS_FILE_CACHE_TTL")
+    config::get_var(config::FILE_CACHE_TTL)
         .map(|x| x.parse::<u64>().expect("integer"))
         .unwrap_or(60 * 60)
 }
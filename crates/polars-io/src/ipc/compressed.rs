@@ -0,0 +1,174 @@
+//! DataFrames of which some columns are kept in memory as compressed blocks.
+//!
+//! Every chunk of a compressed column is stored as a ZSTD compressed IPC buffer. The chunks are
+//! only decompressed when the column is selected, and only the chunks that hold the selected rows.
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+struct CompressedChunk {
+    len: usize,
+    bytes: Vec<u8>,
+}
+
+enum StoredColumn {
+    Plain(Series),
+    Compressed(Vec<CompressedChunk>),
+}
+
+impl StoredColumn {
+    fn compress(s: &Series) -> PolarsResult<Self> {
+        let chunks = POOL.install(|| {
+            (0..s.n_chunks())
+                .into_par_iter()
+                .map(|i| {
+                    let chunk = s.select_chunk(i);
+                    let mut bytes = vec![];
+                    IpcWriter::new(&mut bytes)
+                        .with_compression(Some(IpcCompression::ZSTD))
+                        .with_pl_flavor(true)
+                        .finish(&mut DataFrame::new(vec![chunk.clone()])?)?;
+                    bytes.shrink_to_fit();
+                    Ok(CompressedChunk {
+                        len: chunk.len(),
+                        bytes,
+                    })
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        Ok(Self::Compressed(chunks))
+    }
+
+    /// The first `n_rows` of the column.
+    fn materialize(&self, name: &str, dtype: &DataType, n_rows: usize) -> PolarsResult<Series> {
+        let chunks = match self {
+            Self::Plain(s) => return Ok(s.slice(0, n_rows)),
+            Self::Compressed(chunks) => chunks,
+        };
+
+        let mut remaining = n_rows;
+        let needed = chunks
+            .iter()
+            .take_while(|chunk| {
+                let take = remaining > 0;
+                remaining = remaining.saturating_sub(chunk.len);
+                take
+            })
+            .collect::<Vec<_>>();
+        let parts = POOL.install(|| {
+            needed
+                .into_par_iter()
+                .map(|chunk| {
+                    let df = IpcReader::new(Cursor::new(chunk.bytes.as_slice())).finish()?;
+                    Ok(df.get_columns()[0].clone())
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut out = Series::full_null(name, 0, dtype);
+        for part in &parts {
+            out.append(part)?;
+        }
+        Ok(out.slice(0, n_rows))
+    }
+
+    fn estimated_size(&self) -> usize {
+        match self {
+            Self::Plain(s) => s.estimated_size(),
+            Self::Compressed(chunks) => chunks.iter().map(|chunk| chunk.bytes.len()).sum(),
+        }
+    }
+}
+
+/// A [`DataFrame`] of which rarely accessed String and Binary columns are kept in memory as
+/// compressed blocks, trading CPU for memory.
+///
+/// Scan it with `LazyFrame::scan_compressed` to only decompress the columns that a query
+/// projects.
+pub struct CompressedDataFrame {
+    schema: SchemaRef,
+    height: usize,
+    columns: Vec<StoredColumn>,
+}
+
+impl CompressedDataFrame {
+    /// Compress the `columns` of `df`, or all of its String and Binary columns if `columns` is
+    /// `None`. The other columns are kept as they are.
+    pub fn new(df: &DataFrame, columns: Option<&[String]>) -> PolarsResult<Self> {
+        if let Some(columns) = columns {
+            for name in columns {
+                let dtype = df.column(name)?.dtype();
+                polars_ensure!(
+                    matches!(dtype, DataType::String | DataType::Binary),
+                    InvalidOperation: "only String and Binary columns can be compressed, got '{}' of type {}",
+                    name, dtype
+                );
+            }
+        }
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| {
+                let compress = match columns {
+                    Some(columns) => columns.iter().any(|name| name == s.name()),
+                    None => matches!(s.dtype(), DataType::String | DataType::Binary),
+                };
+                if compress {
+                    StoredColumn::compress(s)
+                } else {
+                    Ok(StoredColumn::Plain(s.clone()))
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Self {
+            schema: Arc::new(df.schema()),
+            height: df.height(),
+            columns,
+        })
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of bytes that the columns take in memory.
+    pub fn estimated_size(&self) -> usize {
+        self.columns.iter().map(|c| c.estimated_size()).sum()
+    }
+
+    /// Decompress the first `n_rows` of the `columns`, or of all columns if `columns` is `None`.
+    pub fn select(
+        &self,
+        columns: Option<&[String]>,
+        n_rows: Option<usize>,
+    ) -> PolarsResult<DataFrame> {
+        let n_rows = n_rows.map_or(self.height, |n| n.min(self.height));
+        let materialize = |idx: usize| {
+            let (name, dtype) = self.schema.get_at_index(idx).unwrap();
+            self.columns[idx].materialize(name, dtype, n_rows)
+        };
+        let columns = match columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| materialize(self.schema.try_get_full(name)?.0))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            None => (0..self.columns.len())
+                .map(materialize)
+                .collect::<PolarsResult<Vec<_>>>()?,
+        };
+        DataFrame::new(columns)
+    }
+
+    /// Decompress all columns.
+    pub fn decompress(&self) -> PolarsResult<DataFrame> {
+        self.select(None, None)
+    }
+}
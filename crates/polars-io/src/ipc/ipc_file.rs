@@ -42,7 +42,8 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::mmap::MmapBytesReader;
+use super::statistics::prune_record_batches;
+use crate::mmap::{MmapBytesReader, MMAP_SUPPORTED};
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use crate::shared::{finish_reader, ArrowReader};
@@ -52,6 +53,10 @@ use crate::RowIndex;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcScanOptions {
     pub memory_map: bool,
+    /// Copy the memory mapped data into memory and release the mapping once a file is read, see
+    /// [`IpcReader::with_release_resources`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub release_resources: bool,
 }
 
 /// Read Arrows IPC format into a DataFrame
@@ -82,6 +87,7 @@ pub struct IpcReader<R: MmapBytesReader> {
     pub(super) row_index: Option<RowIndex>,
     // Stores the as key semaphore to make sure we don't write to the memory mapped file.
     pub(super) memory_map: Option<PathBuf>,
+    pub(super) release_resources: bool,
     metadata: Option<read::FileMetadata>,
     schema: Option<ArrowSchemaRef>,
 }
@@ -141,11 +147,38 @@ impl<R: MmapBytesReader> IpcReader<R> {
 
     /// Set if the file is to be memory_mapped. Only works with uncompressed files.
     /// The file name must be passed to register the memory mapped file.
+    /// This is ignored on wasm, where files can't be memory mapped.
     pub fn memory_mapped(mut self, path_buf: Option<PathBuf>) -> Self {
         self.memory_map = path_buf;
         self
     }
 
+    /// Copy the data of a memory mapped file into memory record batch by record batch and
+    /// release the mapping once the file is read. Otherwise the file stays mapped for as long as
+    /// the returned [`DataFrame`] is alive, which exhausts the mappings of long running processes
+    /// that read many files.
+    pub fn with_release_resources(mut self, toggle: bool) -> Self {
+        self.release_resources = toggle;
+        self
+    }
+
+    /// Skip the record batches that cannot contain rows matching `predicate`, if the file was
+    /// written with [`IpcWriter::with_batch_statistics`]. Skipping batches would shift the rows
+    /// counted by a row limit or row index, so it is only done without them.
+    pub(super) fn prune_record_batches(
+        &self,
+        metadata: &mut read::FileMetadata,
+        predicate: Option<&dyn PhysicalIoExpr>,
+        verbose: bool,
+    ) -> PolarsResult<()> {
+        match predicate {
+            Some(predicate) if self.n_rows.is_none() && self.row_index.is_none() => {
+                prune_record_batches(metadata, predicate, verbose)
+            },
+            _ => Ok(()),
+        }
+    }
+
     // todo! hoist to lazy crate
     #[cfg(feature = "lazy")]
     pub fn finish_with_scan_ops(
@@ -153,17 +186,18 @@ impl<R: MmapBytesReader> IpcReader<R> {
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         verbose: bool,
     ) -> PolarsResult<DataFrame> {
-        if self.memory_map.is_some() && self.reader.to_file().is_some() {
+        if MMAP_SUPPORTED && self.memory_map.is_some() && self.reader.to_file().is_some() {
             if verbose {
                 eprintln!("memory map ipc file")
             }
-            match self.finish_memmapped(predicate.clone()) {
+            match self.finish_memmapped(predicate.clone(), verbose) {
                 Ok(df) => return Ok(df),
                 Err(err) => check_mmap_err(err)?,
             }
         }
         let rechunk = self.rechunk;
-        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let mut metadata = read::read_file_metadata(&mut self.reader)?;
+        self.prune_record_batches(&mut metadata, predicate.as_deref(), verbose)?;
 
         // NOTE: For some code paths this already happened. See
         // https://github.com/pola-rs/polars/pull/14984#discussion_r1520125000
@@ -203,6 +237,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             projection: None,
             row_index: None,
             memory_map: None,
+            release_resources: false,
             metadata: None,
             schema: None,
         }
@@ -214,8 +249,8 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
     }
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
-        if self.memory_map.is_some() && self.reader.to_file().is_some() {
-            match self.finish_memmapped(None) {
+        if MMAP_SUPPORTED && self.memory_map.is_some() && self.reader.to_file().is_some() {
+            match self.finish_memmapped(None, false) {
                 Ok(df) => return Ok(df),
                 Err(err) => check_mmap_err(err)?,
             }
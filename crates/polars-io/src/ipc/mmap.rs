@@ -1,3 +1,4 @@
+use arrow::compute::concatenate::concatenate;
 use arrow::io::ipc::read;
 use arrow::io::ipc::read::{Dictionaries, FileMetadata};
 use arrow::mmap::{mmap_dictionaries_unchecked, mmap_unchecked};
@@ -14,14 +15,16 @@ impl<R: MmapBytesReader> IpcReader<R> {
     pub(super) fn finish_memmapped(
         &mut self,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        verbose: bool,
     ) -> PolarsResult<DataFrame> {
         match self.reader.to_file() {
             Some(file) => {
                 let mmap = unsafe { memmap::Mmap::map(file).unwrap() };
                 let mmap_key = self.memory_map.take().unwrap();
                 let semaphore = MMapSemaphore::new(mmap_key, mmap);
-                let metadata =
+                let mut metadata =
                     read::read_file_metadata(&mut std::io::Cursor::new(semaphore.as_ref()))?;
+                self.prune_record_batches(&mut metadata, predicate.as_deref(), verbose)?;
 
                 if let Some(columns) = &self.columns {
                     let schema = &metadata.schema;
@@ -35,7 +38,12 @@ impl<R: MmapBytesReader> IpcReader<R> {
                     metadata.schema.clone()
                 };
 
-                let reader = MMapChunkIter::new(Arc::new(semaphore), metadata, &self.projection)?;
+                let reader = MMapChunkIter::new(
+                    Arc::new(semaphore),
+                    metadata,
+                    &self.projection,
+                    self.release_resources,
+                )?;
 
                 finish_reader(
                     reader,
@@ -59,6 +67,8 @@ struct MMapChunkIter<'a> {
     idx: usize,
     end: usize,
     projection: &'a Option<Vec<usize>>,
+    /// Copy every record batch, so that it doesn't reference the mapping.
+    copy: bool,
 }
 
 impl<'a> MMapChunkIter<'a> {
@@ -66,6 +76,7 @@ impl<'a> MMapChunkIter<'a> {
         mmap: Arc<MMapSemaphore>,
         metadata: FileMetadata,
         projection: &'a Option<Vec<usize>>,
+        copy: bool,
     ) -> PolarsResult<Self> {
         let end = metadata.blocks.len();
         // mmap the dictionaries
@@ -78,6 +89,7 @@ impl<'a> MMapChunkIter<'a> {
             idx: 0,
             end,
             projection,
+            copy,
         })
     }
 }
@@ -102,6 +114,16 @@ impl ArrowReader for MMapChunkIter<'_> {
                     RecordBatch::new(arrays)
                 },
             };
+            let chunk = if self.copy {
+                let arrays = chunk
+                    .arrays()
+                    .iter()
+                    .map(|arr| concatenate(&[arr.as_ref()]))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                RecordBatch::new(arrays)
+            } else {
+                chunk
+            };
             Ok(Some(chunk))
         } else {
             Ok(None)
@@ -1,4 +1,6 @@
 #[cfg(feature = "ipc")]
+mod compressed;
+#[cfg(feature = "ipc")]
 mod ipc_file;
 #[cfg(feature = "cloud")]
 mod ipc_reader_async;
@@ -6,14 +8,21 @@ mod ipc_reader_async;
 mod ipc_stream;
 #[cfg(feature = "ipc")]
 mod mmap;
+#[cfg(all(feature = "ipc", target_os = "linux"))]
+mod shared_memory;
+mod statistics;
 mod write;
 #[cfg(all(feature = "async", feature = "ipc"))]
 mod write_async;
 
+#[cfg(feature = "ipc")]
+pub use compressed::CompressedDataFrame;
 #[cfg(feature = "ipc")]
 pub use ipc_file::{IpcReader, IpcScanOptions};
 #[cfg(feature = "cloud")]
 pub use ipc_reader_async::*;
 #[cfg(feature = "ipc_streaming")]
 pub use ipc_stream::*;
+#[cfg(all(feature = "ipc", target_os = "linux"))]
+pub use shared_memory::SharedMemorySegment;
 pub use write::{BatchedWriter, IpcCompression, IpcWriter, IpcWriterOption, IpcWriterOptions};
@@ -0,0 +1,115 @@
+//! DataFrames in named shared memory segments, to share them between the processes of a host.
+//!
+//! A segment is a file in `/dev/shm`, the tmpfs that backs POSIX shared memory, that holds a
+//! [`DataFrame`] in the uncompressed Arrow IPC file format. Opening a segment memory maps it, so
+//! the buffers of the opened frame are the shared pages of the segment instead of copies.
+//!
+//! A segment is never modified after it is created: it is written under a temporary name and
+//! linked to its name once complete, so a process never opens a partially written segment.
+use std::fs::File;
+use std::io::{BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+
+use super::{IpcReader, IpcWriter};
+use crate::shared::{SerReader, SerWriter};
+
+const SHM_DIR: &str = "/dev/shm";
+
+fn segment_path(name: &str) -> PolarsResult<PathBuf> {
+    polars_ensure!(
+        !name.is_empty() && name.len() <= 255 && !name.starts_with('.') && !name.contains('/'),
+        InvalidOperation: "invalid shared memory segment name '{}', expected at most 255 \
+        characters without '/' that don't start with '.'", name
+    );
+    Ok(Path::new(SHM_DIR).join(name))
+}
+
+/// A named shared memory segment that holds a [`DataFrame`], created by
+/// [`SharedMemorySegment::create`] and opened by other processes with
+/// [`SharedMemorySegment::open`].
+///
+/// The segment is removed when this handle is dropped, unless it is [persisted]. Frames that
+/// are opened from it stay valid after it is removed, their mappings keep the pages alive.
+///
+/// [persisted]: SharedMemorySegment::persist
+#[derive(Debug)]
+pub struct SharedMemorySegment {
+    name: String,
+    path: PathBuf,
+    size: usize,
+    remove_on_drop: bool,
+}
+
+impl SharedMemorySegment {
+    /// Write `df` to a new segment `name`. Errors if the segment already exists.
+    pub fn create(name: &str, df: &mut DataFrame) -> PolarsResult<Self> {
+        let path = segment_path(name)?;
+        let tmp_path = Path::new(SHM_DIR).join(format!(".{}.{}.tmp", name, std::process::id()));
+
+        let mut write = || -> PolarsResult<usize> {
+            let mut writer = BufWriter::new(File::create(&tmp_path)?);
+            IpcWriter::new(&mut writer).finish(df)?;
+            writer.flush()?;
+            let size = writer.get_ref().metadata()?.len() as usize;
+            // Linking fails if the name exists, unlike renaming.
+            std::fs::hard_link(&tmp_path, &path).map_err(|err| match err.kind() {
+                ErrorKind::AlreadyExists => {
+                    polars_err!(Duplicate: "shared memory segment '{}' already exists", name)
+                },
+                _ => err.into(),
+            })?;
+            Ok(size)
+        };
+        let size = write();
+        let _ = std::fs::remove_file(&tmp_path);
+
+        Ok(Self {
+            name: name.to_string(),
+            path,
+            size: size?,
+            remove_on_drop: true,
+        })
+    }
+
+    /// Open the [`DataFrame`] of the segment `name` without copying its buffers.
+    pub fn open(name: &str) -> PolarsResult<DataFrame> {
+        let path = segment_path(name)?;
+        let file = File::open(&path).map_err(|err| match err.kind() {
+            ErrorKind::NotFound => {
+                polars_err!(ComputeError: "shared memory segment '{}' doesn't exist", name)
+            },
+            _ => err.into(),
+        })?;
+        IpcReader::new(file).memory_mapped(Some(path)).finish()
+    }
+
+    /// Remove the segment `name`, e.g. one that was [persisted](SharedMemorySegment::persist).
+    pub fn remove(name: &str) -> PolarsResult<()> {
+        Ok(std::fs::remove_file(segment_path(name)?)?)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The size of the segment in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Keep the segment after this handle is dropped, until it is removed with
+    /// [`SharedMemorySegment::remove`] or the host restarts.
+    pub fn persist(mut self) {
+        self.remove_on_drop = false;
+    }
+}
+
+impl Drop for SharedMemorySegment {
+    fn drop(&mut self) {
+        if self.remove_on_drop {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Statistics of the record batches of an IPC file.
+//!
+//! The minimum, maximum and null count of every column of every record batch are collected in a
+//! DataFrame with a row per record batch. That DataFrame is itself serialized to IPC and stored,
+//! hex-encoded, in the custom metadata of the file footer. Readers that don't know about the
+//! statistics ignore it.
+use arrow::datatypes::Metadata;
+#[cfg(feature = "ipc")]
+use arrow::io::ipc::read::FileMetadata;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked_optional;
+
+use crate::predicates::use_min_max;
+#[cfg(feature = "ipc")]
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::prelude::*;
+
+/// The key of the statistics in the custom metadata of the footer.
+const BATCH_STATISTICS_KEY: &str = "polars:batch_statistics";
+const NUM_ROWS: &str = "num_rows";
+
+fn min_name(column: &str) -> String {
+    format!("{column}_min")
+}
+
+fn max_name(column: &str) -> String {
+    format!("{column}_max")
+}
+
+fn null_count_name(column: &str) -> String {
+    format!("{column}_null_count")
+}
+
+/// Collects the statistics of the record batches while they are written.
+#[derive(Default)]
+pub(super) struct BatchStatisticsBuilder {
+    batches: Vec<DataFrame>,
+}
+
+impl BatchStatisticsBuilder {
+    /// Add the statistics of the record batch that holds the rows of `df`.
+    pub(super) fn push(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let mut columns = vec![Series::new(NUM_ROWS, [df.height() as IdxSize])];
+        for s in df.get_columns() {
+            if !use_min_max(s.dtype()) {
+                continue;
+            }
+            let name = s.name();
+            columns.push(s.min_reduce()?.into_series(&min_name(name)));
+            columns.push(s.max_reduce()?.into_series(&max_name(name)));
+            columns.push(Series::new(
+                &null_count_name(name),
+                [s.null_count() as IdxSize],
+            ));
+        }
+        self.batches.push(DataFrame::new(columns)?);
+        Ok(())
+    }
+
+    /// Store the collected statistics in the custom metadata of the footer.
+    pub(super) fn finish(self) -> PolarsResult<Metadata> {
+        let mut metadata = Metadata::default();
+        if let Some(mut df) = accumulate_dataframes_vertical_unchecked_optional(self.batches) {
+            let mut buf = vec![];
+            IpcWriter::new(&mut buf).finish(&mut df)?;
+            metadata.insert(BATCH_STATISTICS_KEY.to_string(), hex_encode(&buf));
+        }
+        Ok(metadata)
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        out.push(DIGITS[(b >> 4) as usize] as char);
+        out.push(DIGITS[(b & 0xf) as usize] as char);
+    }
+    out
+}
+
+#[cfg(feature = "ipc")]
+fn hex_decode(s: &str) -> PolarsResult<Vec<u8>> {
+    polars_ensure!(s.len() % 2 == 0, ComputeError: "invalid ipc batch statistics");
+    s.as_bytes()
+        .chunks_exact(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| polars_err!(ComputeError: "invalid ipc batch statistics"))
+        })
+        .collect()
+}
+
+/// Read the statistics of the record batches, if the file has them.
+#[cfg(feature = "ipc")]
+fn read_batch_statistics(metadata: &FileMetadata) -> PolarsResult<Option<DataFrame>> {
+    let Some(encoded) = metadata
+        .custom_metadata
+        .as_ref()
+        .and_then(|md| md.get(BATCH_STATISTICS_KEY))
+    else {
+        return Ok(None);
+    };
+    let bytes = hex_decode(encoded)?;
+    let df = IpcReader::new(std::io::Cursor::new(bytes)).finish()?;
+    // Batches appended by another writer have no statistics.
+    Ok((df.height() == metadata.blocks.len()).then_some(df))
+}
+
+/// Remove the record batches of which the statistics show that none of their rows match
+/// `predicate` from `metadata`.
+#[cfg(feature = "ipc")]
+pub(super) fn prune_record_batches(
+    metadata: &mut FileMetadata,
+    predicate: &dyn PhysicalIoExpr,
+    verbose: bool,
+) -> PolarsResult<()> {
+    let Some(evaluator) = predicate.as_stats_evaluator() else {
+        return Ok(());
+    };
+    let Some(stats) = read_batch_statistics(metadata)? else {
+        return Ok(());
+    };
+
+    let schema = Arc::new(Schema::from(metadata.schema.as_ref()));
+    let num_rows = stats.column(NUM_ROWS)?.idx()?;
+    let mut keep = Vec::with_capacity(stats.height());
+    for i in 0..stats.height() {
+        let column_stats = schema
+            .iter_fields()
+            .map(|field| {
+                let name = field.name();
+                match (
+                    stats.column(&min_name(name)),
+                    stats.column(&max_name(name)),
+                    stats.column(&null_count_name(name)),
+                ) {
+                    (Ok(min), Ok(max), Ok(null_count)) => ColumnStats::new(
+                        field,
+                        Some(null_count.slice(i as i64, 1)),
+                        Some(min.slice(i as i64, 1)),
+                        Some(max.slice(i as i64, 1)),
+                    ),
+                    _ => ColumnStats::from_field(field),
+                }
+            })
+            .collect();
+        let batch_stats = BatchStats::new(
+            schema.clone(),
+            column_stats,
+            num_rows.get(i).map(|n| n as usize),
+        );
+        keep.push(evaluator.should_read(&batch_stats)?);
+    }
+
+    if verbose {
+        let skipped = keep.iter().filter(|keep| !**keep).count();
+        eprintln!(
+            "ipc: skipping {skipped} of {} record batches based on their statistics",
+            keep.len()
+        );
+    }
+    let mut keep = keep.into_iter();
+    metadata.blocks.retain(|_| keep.next().unwrap());
+    Ok(())
+}
@@ -7,8 +7,9 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics::BatchStatisticsBuilder;
 use crate::prelude::*;
-use crate::shared::{schema_to_arrow_checked, WriterFactory};
+use crate::shared::{df_to_arrow_schema_checked, schema_to_arrow_checked, WriterFactory};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -43,6 +44,7 @@ pub struct IpcWriter<W> {
     pub(super) compression: Option<IpcCompression>,
     /// Polars' flavor of arrow. This might be temporary.
     pub(super) pl_flavor: bool,
+    pub(super) batch_statistics: bool,
 }
 
 impl<W: Write> IpcWriter<W> {
@@ -57,6 +59,14 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Write the minimum, maximum and null count of the columns of every record batch to the
+    /// footer of the file, so that scans with a predicate can skip record batches. Not supported
+    /// when writing asynchronously. Defaults to `false`.
+    pub fn with_batch_statistics(mut self, batch_statistics: bool) -> Self {
+        self.batch_statistics = batch_statistics;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, self.pl_flavor, "ipc")?;
         let mut writer = write::FileWriter::new(
@@ -72,6 +82,7 @@ impl<W: Write> IpcWriter<W> {
         Ok(BatchedWriter {
             writer,
             pl_flavor: self.pl_flavor,
+            statistics: self.batch_statistics.then(BatchStatisticsBuilder::default),
         })
     }
 }
@@ -85,11 +96,12 @@ where
             writer,
             compression: None,
             pl_flavor: true,
+            batch_statistics: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(&df.schema(), self.pl_flavor, "ipc")?;
+        let schema = df_to_arrow_schema_checked(df, self.pl_flavor, "ipc")?;
         let mut ipc_writer = write::FileWriter::try_new(
             &mut self.writer,
             Arc::new(schema),
@@ -99,19 +111,38 @@ where
             },
         )?;
         df.align_chunks();
-        let iter = df.iter_chunks(self.pl_flavor, true);
-
-        for batch in iter {
-            ipc_writer.write(&batch, None)?
+        let mut statistics = self.batch_statistics.then(BatchStatisticsBuilder::default);
+        write_batches(&mut ipc_writer, statistics.as_mut(), df, self.pl_flavor)?;
+        if let Some(statistics) = statistics {
+            ipc_writer.set_custom_metadata(statistics.finish()?);
         }
         ipc_writer.finish()?;
         Ok(())
     }
 }
 
+/// Write the chunks of `df` as record batches and collect their statistics.
+fn write_batches<W: Write>(
+    writer: &mut write::FileWriter<W>,
+    mut statistics: Option<&mut BatchStatisticsBuilder>,
+    df: &DataFrame,
+    pl_flavor: bool,
+) -> PolarsResult<()> {
+    let mut offset = 0;
+    for batch in df.iter_chunks(pl_flavor, true) {
+        if let Some(statistics) = statistics.as_deref_mut() {
+            statistics.push(&df.slice(offset, batch.len()))?;
+        }
+        offset += batch.len() as i64;
+        writer.write(&batch, None)?
+    }
+    Ok(())
+}
+
 pub struct BatchedWriter<W: Write> {
     writer: write::FileWriter<W>,
     pl_flavor: bool,
+    statistics: Option<BatchStatisticsBuilder>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -120,15 +151,19 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
-        let iter = df.iter_chunks(self.pl_flavor, true);
-        for batch in iter {
-            self.writer.write(&batch, None)?
-        }
-        Ok(())
+        write_batches(
+            &mut self.writer,
+            self.statistics.as_mut(),
+            df,
+            self.pl_flavor,
+        )
     }
 
     /// Writes the footer of the IPC file.
     pub fn finish(&mut self) -> PolarsResult<()> {
+        if let Some(statistics) = self.statistics.take() {
+            self.writer.set_custom_metadata(statistics.finish()?);
+        }
         self.writer.finish()?;
         Ok(())
     }
@@ -11,6 +11,7 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
             writer,
             compression: None,
             pl_flavor: false,
+            batch_statistics: false,
         }
     }
 
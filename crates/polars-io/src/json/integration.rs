@@ -0,0 +1,777 @@
+//! (De)serialization of the [Arrow integration JSON format](https://arrow.apache.org/docs/format/Integration.html#json-test-data-format).
+//!
+//! This format describes the schema, the physical buffers and the record batches of a frame
+//! exactly, which makes it useful to exchange reproducible frames in bug reports. See
+//! [`DebugDump`] to write such a reproduction, including the chunk layout of every column.
+use std::io::{Read, Write};
+use std::path::Path;
+
+use arrow::array::*;
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::Metadata;
+use arrow::offset::OffsetsBuffer;
+use arrow::types::NativeType;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_utils::{create_file, open_file};
+use serde_json::{json, Map, Value};
+
+use crate::prelude::*;
+
+/// Field metadata key under which the chunk lengths of a column are stored.
+const CHUNK_LENGTHS_KEY: &str = "polars.chunk_lengths";
+
+/// Writes a DataFrame in the Arrow integration JSON format.
+///
+/// Every aligned chunk of the [`DataFrame`] is written as a separate record batch.
+/// Categorical, Enum and Object columns are not supported; cast them to String first.
+#[must_use]
+pub struct ArrowJsonWriter<W: Write> {
+    buffer: W,
+    pretty: bool,
+    chunk_layout: bool,
+}
+
+impl<W: Write> ArrowJsonWriter<W> {
+    /// Indent the output for readability.
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Record the chunk lengths of every column in the field metadata, so that the
+    /// [`ArrowJsonReader`] can restore the exact chunk layout.
+    pub fn with_chunk_layout(mut self, chunk_layout: bool) -> Self {
+        self.chunk_layout = chunk_layout;
+        self
+    }
+}
+
+impl<W> SerWriter<W> for ArrowJsonWriter<W>
+where
+    W: Write,
+{
+    fn new(buffer: W) -> Self {
+        ArrowJsonWriter {
+            buffer,
+            pretty: false,
+            chunk_layout: false,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut fields = df
+            .iter()
+            .map(|s| {
+                polars_ensure!(
+                    !matches!(s.dtype(), DataType::Categorical(_, _) | DataType::Enum(_, _)),
+                    ComputeError: "cannot write '{}' datatype to arrow json; cast to String first", s.dtype()
+                );
+                #[cfg(feature = "object")]
+                polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to arrow json");
+                Ok(s.field().to_arrow(false))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        if self.chunk_layout {
+            for (field, s) in fields.iter_mut().zip(df.get_columns()) {
+                let lengths = s
+                    .chunk_lengths()
+                    .map(|len| len.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                field
+                    .metadata
+                    .insert(CHUNK_LENGTHS_KEY.to_string(), lengths);
+            }
+        }
+
+        df.align_chunks();
+        let batches = df
+            .iter_chunks(false, false)
+            .map(|chunk| {
+                let columns = fields
+                    .iter()
+                    .zip(chunk.arrays())
+                    .map(|(field, arr)| serialize_array(&field.name, arr.as_ref()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Ok(json!({"count": chunk.len(), "columns": columns}))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let out = json!({
+            "schema": {
+                "fields": fields.iter().map(serialize_field).collect::<PolarsResult<Vec<_>>>()?,
+            },
+            "batches": batches,
+        });
+
+        if self.pretty {
+            serde_json::to_writer_pretty(&mut self.buffer, &out)
+        } else {
+            serde_json::to_writer(&mut self.buffer, &out)
+        }
+        .map_err(to_compute_err)
+    }
+}
+
+/// Reads a DataFrame from the Arrow integration JSON format.
+///
+/// Every record batch becomes a chunk of the resulting [`DataFrame`]. If the file was written with
+/// [`ArrowJsonWriter::with_chunk_layout`], the original chunk layout of every column is restored.
+#[must_use]
+pub struct ArrowJsonReader<R: Read> {
+    reader: R,
+    rechunk: bool,
+}
+
+impl<R> SerReader<R> for ArrowJsonReader<R>
+where
+    R: Read,
+{
+    fn new(reader: R) -> Self {
+        ArrowJsonReader {
+            reader,
+            rechunk: false,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let mut bytes = vec![];
+        self.reader.read_to_end(&mut bytes)?;
+        let value: Value = serde_json::from_slice(&bytes).map_err(to_compute_err)?;
+
+        let fields = get_array(get(get(&value, "schema")?, "fields")?)?
+            .iter()
+            .map(deserialize_field)
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let batches = match value.get("batches") {
+            Some(batches) => get_array(batches)?.as_slice(),
+            None => &[],
+        };
+        let dfs = batches
+            .iter()
+            .map(|batch| {
+                let columns = get_array(get(batch, "columns")?)?;
+                polars_ensure!(
+                    columns.len() == fields.len(),
+                    ComputeError: "arrow json batch has {} columns, but the schema has {} fields", columns.len(), fields.len()
+                );
+                let columns = fields
+                    .iter()
+                    .zip(columns)
+                    .map(|(field, column)| {
+                        let arr = deserialize_array(&field.data_type, column)?;
+                        Series::try_from((field, arr))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                DataFrame::new(columns)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut df = if dfs.is_empty() {
+            DataFrame::empty_with_arrow_schema(&ArrowSchema::from(fields.clone()))
+        } else {
+            accumulate_dataframes_vertical_unchecked(dfs)
+        };
+
+        if self.rechunk {
+            df.as_single_chunk_par();
+        } else {
+            restore_chunk_layout(&mut df, &fields)?;
+        }
+        Ok(df)
+    }
+}
+
+/// Write a [`DataFrame`] to a file that captures its data, schema and chunk layout, so that it can
+/// be attached to a bug report and replayed exactly with [`read_debug_dump`].
+pub trait DebugDump {
+    fn debug_dump<P: AsRef<Path>>(&self, path: P) -> PolarsResult<()>;
+}
+
+impl DebugDump for DataFrame {
+    fn debug_dump<P: AsRef<Path>>(&self, path: P) -> PolarsResult<()> {
+        let file = create_file(path.as_ref())?;
+        ArrowJsonWriter::new(std::io::BufWriter::new(file))
+            .with_pretty(true)
+            .with_chunk_layout(true)
+            .finish(&mut self.clone())
+    }
+}
+
+/// Read a [`DataFrame`] written by [`DebugDump::debug_dump`], restoring its chunk layout.
+pub fn read_debug_dump<P: AsRef<Path>>(path: P) -> PolarsResult<DataFrame> {
+    let file = open_file(path.as_ref())?;
+    ArrowJsonReader::new(std::io::BufReader::new(file)).finish()
+}
+
+fn restore_chunk_layout(df: &mut DataFrame, fields: &[ArrowField]) -> PolarsResult<()> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .zip(fields)
+        .map(|(s, field)| {
+            let Some(lengths) = field.metadata.get(CHUNK_LENGTHS_KEY) else {
+                return Ok(s.clone());
+            };
+            let lengths = lengths
+                .split(',')
+                .filter(|len| !len.is_empty())
+                .map(|len| len.parse::<usize>().map_err(to_compute_err))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            polars_ensure!(
+                lengths.iter().sum::<usize>() == s.len(),
+                ComputeError: "chunk lengths of column '{}' do not add up to its length", s.name()
+            );
+            if lengths.is_empty() || s.chunk_lengths().eq(lengths.iter().copied()) {
+                return Ok(s.clone());
+            }
+
+            let s = s.rechunk();
+            let mut out = s.slice(0, lengths[0]);
+            let mut offset = lengths[0];
+            for &len in &lengths[1..] {
+                out.append(&s.slice(offset as i64, len))?;
+                offset += len;
+            }
+            Ok(out)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    *df = unsafe { DataFrame::new_no_checks(columns) };
+    Ok(())
+}
+
+fn time_unit_to_str(tu: &ArrowTimeUnit) -> &'static str {
+    match tu {
+        ArrowTimeUnit::Second => "SECOND",
+        ArrowTimeUnit::Millisecond => "MILLISECOND",
+        ArrowTimeUnit::Microsecond => "MICROSECOND",
+        ArrowTimeUnit::Nanosecond => "NANOSECOND",
+    }
+}
+
+fn time_unit_from_str(tu: &str) -> PolarsResult<ArrowTimeUnit> {
+    Ok(match tu {
+        "SECOND" => ArrowTimeUnit::Second,
+        "MILLISECOND" => ArrowTimeUnit::Millisecond,
+        "MICROSECOND" => ArrowTimeUnit::Microsecond,
+        "NANOSECOND" => ArrowTimeUnit::Nanosecond,
+        _ => polars_bail!(ComputeError: "invalid time unit '{}' in arrow json", tu),
+    })
+}
+
+fn serialize_metadata(metadata: &Metadata) -> Value {
+    metadata
+        .iter()
+        .map(|(key, value)| json!({"key": key, "value": value}))
+        .collect()
+}
+
+fn serialize_field(field: &ArrowField) -> PolarsResult<Value> {
+    use ArrowDataType as D;
+    let int =
+        |signed: bool, width: usize| json!({"name": "int", "isSigned": signed, "bitWidth": width});
+    let (data_type, children) = match &field.data_type {
+        D::Null => (json!({"name": "null"}), vec![]),
+        D::Boolean => (json!({"name": "bool"}), vec![]),
+        D::Int8 => (int(true, 8), vec![]),
+        D::Int16 => (int(true, 16), vec![]),
+        D::Int32 => (int(true, 32), vec![]),
+        D::Int64 => (int(true, 64), vec![]),
+        D::Int128 => (int(true, 128), vec![]),
+        D::UInt8 => (int(false, 8), vec![]),
+        D::UInt16 => (int(false, 16), vec![]),
+        D::UInt32 => (int(false, 32), vec![]),
+        D::UInt64 => (int(false, 64), vec![]),
+        D::UInt128 => (int(false, 128), vec![]),
+        D::Float32 => (
+            json!({"name": "floatingpoint", "precision": "SINGLE"}),
+            vec![],
+        ),
+        D::Float64 => (
+            json!({"name": "floatingpoint", "precision": "DOUBLE"}),
+            vec![],
+        ),
+        D::Utf8 => (json!({"name": "utf8"}), vec![]),
+        D::LargeUtf8 => (json!({"name": "largeutf8"}), vec![]),
+        D::Binary => (json!({"name": "binary"}), vec![]),
+        D::LargeBinary => (json!({"name": "largebinary"}), vec![]),
+        D::Date32 => (json!({"name": "date", "unit": "DAY"}), vec![]),
+        D::Date64 => (json!({"name": "date", "unit": "MILLISECOND"}), vec![]),
+        D::Time32(tu) => (
+            json!({"name": "time", "unit": time_unit_to_str(tu), "bitWidth": 32}),
+            vec![],
+        ),
+        D::Time64(tu) => (
+            json!({"name": "time", "unit": time_unit_to_str(tu), "bitWidth": 64}),
+            vec![],
+        ),
+        D::Timestamp(tu, tz) => {
+            let mut ty = json!({"name": "timestamp", "unit": time_unit_to_str(tu)});
+            if let Some(tz) = tz {
+                ty["timezone"] = json!(tz);
+            }
+            (ty, vec![])
+        },
+        D::Duration(tu) => (
+            json!({"name": "duration", "unit": time_unit_to_str(tu)}),
+            vec![],
+        ),
+        D::Decimal(precision, scale) => (
+            json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 128}),
+            vec![],
+        ),
+        D::List(inner) => (json!({"name": "list"}), vec![serialize_field(inner)?]),
+        D::LargeList(inner) => (json!({"name": "largelist"}), vec![serialize_field(inner)?]),
+        D::FixedSizeList(inner, size) => (
+            json!({"name": "fixedsizelist", "listSize": size}),
+            vec![serialize_field(inner)?],
+        ),
+        D::Struct(fields) => (
+            json!({"name": "struct"}),
+            fields
+                .iter()
+                .map(serialize_field)
+                .collect::<PolarsResult<Vec<_>>>()?,
+        ),
+        dt => polars_bail!(ComputeError: "cannot write arrow type {:?} to arrow json", dt),
+    };
+
+    let mut out = json!({
+        "name": field.name,
+        "nullable": field.is_nullable,
+        "type": data_type,
+        "children": children,
+    });
+    if !field.metadata.is_empty() {
+        out["metadata"] = serialize_metadata(&field.metadata);
+    }
+    Ok(out)
+}
+
+fn deserialize_field(value: &Value) -> PolarsResult<ArrowField> {
+    use ArrowDataType as D;
+    let name = get_str(get(value, "name")?)?;
+    let nullable = value
+        .get("nullable")
+        .and_then(Value::as_bool)
+        .unwrap_or(true);
+    let ty = get(value, "type")?;
+    let mut children = match value.get("children") {
+        Some(children) => get_array(children)?
+            .iter()
+            .map(deserialize_field)
+            .collect::<PolarsResult<Vec<_>>>()?,
+        None => vec![],
+    };
+    let mut single_child = || {
+        polars_ensure!(children.len() == 1, ComputeError: "arrow json list field '{}' must have exactly one child", name);
+        Ok(Box::new(children.pop().unwrap()))
+    };
+    let time_unit = || time_unit_from_str(get_str(get(ty, "unit")?)?);
+
+    let data_type = match get_str(get(ty, "name")?)? {
+        "null" => D::Null,
+        "bool" => D::Boolean,
+        "int" => {
+            let signed = get(ty, "isSigned")?.as_bool().unwrap_or(true);
+            match (signed, get_u64(get(ty, "bitWidth")?)?) {
+                (true, 8) => D::Int8,
+                (true, 16) => D::Int16,
+                (true, 32) => D::Int32,
+                (true, 64) => D::Int64,
+                (true, 128) => D::Int128,
+                (false, 8) => D::UInt8,
+                (false, 16) => D::UInt16,
+                (false, 32) => D::UInt32,
+                (false, 64) => D::UInt64,
+                (false, 128) => D::UInt128,
+                (_, width) => {
+                    polars_bail!(ComputeError: "invalid integer bit width {} in arrow json", width)
+                },
+            }
+        },
+        "floatingpoint" => match get_str(get(ty, "precision")?)? {
+            "SINGLE" => D::Float32,
+            "DOUBLE" => D::Float64,
+            precision => {
+                polars_bail!(ComputeError: "unsupported float precision '{}' in arrow json", precision)
+            },
+        },
+        "utf8" => D::Utf8,
+        "largeutf8" => D::LargeUtf8,
+        "binary" => D::Binary,
+        "largebinary" => D::LargeBinary,
+        "date" => match get_str(get(ty, "unit")?)? {
+            "DAY" => D::Date32,
+            _ => D::Date64,
+        },
+        "time" => match get_u64(get(ty, "bitWidth")?)? {
+            32 => D::Time32(time_unit()?),
+            _ => D::Time64(time_unit()?),
+        },
+        "timestamp" => D::Timestamp(
+            time_unit()?,
+            ty.get("timezone").and_then(Value::as_str).map(String::from),
+        ),
+        "duration" => D::Duration(time_unit()?),
+        "decimal" => D::Decimal(
+            get_u64(get(ty, "precision")?)? as usize,
+            ty.get("scale").map(get_u64).transpose()?.unwrap_or(0) as usize,
+        ),
+        "list" => D::List(single_child()?),
+        "largelist" => D::LargeList(single_child()?),
+        "fixedsizelist" => {
+            D::FixedSizeList(single_child()?, get_u64(get(ty, "listSize")?)? as usize)
+        },
+        "struct" => D::Struct(children),
+        other => polars_bail!(ComputeError: "unsupported type '{}' in arrow json", other),
+    };
+
+    let mut metadata = Metadata::new();
+    if let Some(entries) = value.get("metadata") {
+        for entry in get_array(entries)? {
+            metadata.insert(
+                get_str(get(entry, "key")?)?.to_string(),
+                get_str(get(entry, "value")?)?.to_string(),
+            );
+        }
+    }
+    Ok(ArrowField::new(name, data_type, nullable).with_metadata(metadata))
+}
+
+/// 64 and 128 bit integers are written as strings, as JSON numbers cannot represent them exactly.
+fn int_to_json<T: ToString>(v: T) -> Value {
+    Value::String(v.to_string())
+}
+
+/// Non-finite floats have no JSON representation and are written as strings.
+fn float_to_json(v: f64) -> Value {
+    serde_json::Number::from_f64(v)
+        .map(Value::Number)
+        .unwrap_or_else(|| Value::String(v.to_string()))
+}
+
+fn serialize_validity(arr: &dyn Array) -> Value {
+    (0..arr.len()).map(|i| arr.is_valid(i) as u8).collect()
+}
+
+fn serialize_primitive<T: NativeType>(arr: &dyn Array, f: impl Fn(T) -> Value) -> Value {
+    let arr = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    arr.values().iter().map(|v| f(*v)).collect()
+}
+
+fn serialize_offsets<O: Offset>(offsets: &OffsetsBuffer<O>) -> Value {
+    let start = offsets.first().to_usize();
+    offsets
+        .iter()
+        .map(|o| {
+            let o = o.to_usize() - start;
+            if O::IS_LARGE {
+                int_to_json(o)
+            } else {
+                json!(o)
+            }
+        })
+        .collect()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02X}")).collect()
+}
+
+fn serialize_array(name: &str, arr: &dyn Array) -> PolarsResult<Value> {
+    use ArrowDataType as D;
+    let mut out = Map::new();
+    out.insert("name".into(), json!(name));
+    out.insert("count".into(), json!(arr.len()));
+    if !matches!(arr.data_type(), D::Null) {
+        out.insert("VALIDITY".into(), serialize_validity(arr));
+    }
+
+    let data = match arr.data_type() {
+        D::Null => None,
+        D::Boolean => {
+            let arr = arr.as_any().downcast_ref::<BooleanArray>().unwrap();
+            Some(arr.values().iter().collect())
+        },
+        D::Int8 => Some(serialize_primitive::<i8>(arr, |v| json!(v))),
+        D::Int16 => Some(serialize_primitive::<i16>(arr, |v| json!(v))),
+        D::Int32 | D::Date32 | D::Time32(_) => Some(serialize_primitive::<i32>(arr, |v| json!(v))),
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            Some(serialize_primitive::<i64>(arr, int_to_json))
+        },
+        D::Int128 | D::Decimal(_, _) => Some(serialize_primitive::<i128>(arr, int_to_json)),
+        D::UInt8 => Some(serialize_primitive::<u8>(arr, |v| json!(v))),
+        D::UInt16 => Some(serialize_primitive::<u16>(arr, |v| json!(v))),
+        D::UInt32 => Some(serialize_primitive::<u32>(arr, |v| json!(v))),
+        D::UInt64 => Some(serialize_primitive::<u64>(arr, int_to_json)),
+        D::UInt128 => Some(serialize_primitive::<u128>(arr, int_to_json)),
+        D::Float32 => Some(serialize_primitive::<f32>(arr, |v| float_to_json(v as f64))),
+        D::Float64 => Some(serialize_primitive::<f64>(arr, float_to_json)),
+        D::Utf8 => Some(serialize_utf8::<i32>(arr, &mut out)),
+        D::LargeUtf8 => Some(serialize_utf8::<i64>(arr, &mut out)),
+        D::Binary => Some(serialize_binary::<i32>(arr, &mut out)),
+        D::LargeBinary => Some(serialize_binary::<i64>(arr, &mut out)),
+        D::List(_) => {
+            serialize_list::<i32>(arr, &mut out)?;
+            None
+        },
+        D::LargeList(_) => {
+            serialize_list::<i64>(arr, &mut out)?;
+            None
+        },
+        D::FixedSizeList(_, _) => {
+            let arr = arr.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            out.insert(
+                "children".into(),
+                json!([serialize_array("item", arr.values().as_ref())?]),
+            );
+            None
+        },
+        D::Struct(fields) => {
+            let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+            let children = fields
+                .iter()
+                .zip(arr.values())
+                .map(|(field, values)| serialize_array(&field.name, values.as_ref()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            out.insert("children".into(), Value::Array(children));
+            None
+        },
+        dt => polars_bail!(ComputeError: "cannot write arrow type {:?} to arrow json", dt),
+    };
+    if let Some(data) = data {
+        out.insert("DATA".into(), data);
+    }
+    Ok(Value::Object(out))
+}
+
+fn serialize_utf8<O: Offset>(arr: &dyn Array, out: &mut Map<String, Value>) -> Value {
+    let arr = arr.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    out.insert("OFFSET".into(), serialize_offsets(arr.offsets()));
+    arr.values_iter().collect()
+}
+
+fn serialize_binary<O: Offset>(arr: &dyn Array, out: &mut Map<String, Value>) -> Value {
+    let arr = arr.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    out.insert("OFFSET".into(), serialize_offsets(arr.offsets()));
+    arr.values_iter().map(|v| Value::String(hex(v))).collect()
+}
+
+fn serialize_list<O: Offset>(arr: &dyn Array, out: &mut Map<String, Value>) -> PolarsResult<()> {
+    let arr = arr.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let start = arr.offsets().first().to_usize();
+    let end = arr.offsets().last().to_usize();
+    let values = arr.values().sliced(start, end - start);
+    out.insert("OFFSET".into(), serialize_offsets(arr.offsets()));
+    out.insert(
+        "children".into(),
+        json!([serialize_array("item", values.as_ref())?]),
+    );
+    Ok(())
+}
+
+fn get<'a>(value: &'a Value, key: &str) -> PolarsResult<&'a Value> {
+    value
+        .get(key)
+        .ok_or_else(|| polars_err!(ComputeError: "missing key '{}' in arrow json", key))
+}
+
+fn get_array(value: &Value) -> PolarsResult<&Vec<Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| polars_err!(ComputeError: "expected an array in arrow json, got {}", value))
+}
+
+fn get_str(value: &Value) -> PolarsResult<&str> {
+    value
+        .as_str()
+        .ok_or_else(|| polars_err!(ComputeError: "expected a string in arrow json, got {}", value))
+}
+
+fn get_u64(value: &Value) -> PolarsResult<u64> {
+    value.as_u64().ok_or_else(
+        || polars_err!(ComputeError: "expected an integer in arrow json, got {}", value),
+    )
+}
+
+/// Parse a JSON number, or a number that was written as a string.
+fn parse_number<T: std::str::FromStr>(value: &Value) -> PolarsResult<T> {
+    let parsed = match value {
+        Value::Number(n) => n.to_string().parse::<T>().ok(),
+        Value::String(s) => s.parse::<T>().ok(),
+        _ => None,
+    };
+    parsed.ok_or_else(|| polars_err!(ComputeError: "could not parse {} in arrow json", value))
+}
+
+fn deserialize_validity(column: &Value, len: usize) -> PolarsResult<Option<Bitmap>> {
+    let Some(validity) = column.get("VALIDITY") else {
+        return Ok(None);
+    };
+    let validity = get_array(validity)?;
+    polars_ensure!(validity.len() == len, ComputeError: "arrow json validity has the wrong length");
+    let validity = validity
+        .iter()
+        .map(|v| Ok(get_u64(v)? != 0))
+        .collect::<PolarsResult<Bitmap>>()?;
+    Ok((validity.unset_bits() > 0).then_some(validity))
+}
+
+fn deserialize_primitive<T: NativeType>(
+    data_type: &ArrowDataType,
+    column: &Value,
+    validity: Option<Bitmap>,
+    f: impl Fn(&Value) -> PolarsResult<T>,
+) -> PolarsResult<ArrayRef> {
+    let values = get_array(get(column, "DATA")?)?
+        .iter()
+        .map(f)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(PrimitiveArray::try_new(data_type.clone(), values.into(), validity)?.boxed())
+}
+
+fn deserialize_offsets<O: Offset>(column: &Value) -> PolarsResult<OffsetsBuffer<O>> {
+    let offsets = get_array(get(column, "OFFSET")?)?
+        .iter()
+        .map(|o| {
+            let o = parse_number::<usize>(o)?;
+            O::from_usize(o)
+                .ok_or_else(|| polars_err!(ComputeError: "offset {} overflows in arrow json", o))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    OffsetsBuffer::try_from(offsets)
+}
+
+fn single_child(column: &Value) -> PolarsResult<&Value> {
+    let children = get_array(get(column, "children")?)?;
+    polars_ensure!(children.len() == 1, ComputeError: "arrow json list column must have exactly one child");
+    Ok(&children[0])
+}
+
+fn unhex(s: &str) -> PolarsResult<Vec<u8>> {
+    polars_ensure!(s.len() % 2 == 0, ComputeError: "invalid hex string '{}' in arrow json", s);
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&s[i..i + 2], 16)
+                .map_err(|_| polars_err!(ComputeError: "invalid hex string '{}' in arrow json", s))
+        })
+        .collect()
+}
+
+fn deserialize_array(data_type: &ArrowDataType, column: &Value) -> PolarsResult<ArrayRef> {
+    use ArrowDataType as D;
+    let len = get_u64(get(column, "count")?)? as usize;
+    let validity = deserialize_validity(column, len)?;
+    let dt = data_type;
+    let number = |v: &Value| parse_number(v);
+
+    let arr = match data_type {
+        D::Null => NullArray::new(D::Null, len).boxed(),
+        D::Boolean => {
+            let values = get_array(get(column, "DATA")?)?
+                .iter()
+                .map(|v| {
+                    v.as_bool().ok_or_else(
+                        || polars_err!(ComputeError: "expected a boolean in arrow json, got {}", v),
+                    )
+                })
+                .collect::<PolarsResult<Bitmap>>()?;
+            BooleanArray::try_new(D::Boolean, values, validity)?.boxed()
+        },
+        D::Int8 => deserialize_primitive::<i8>(dt, column, validity, number)?,
+        D::Int16 => deserialize_primitive::<i16>(dt, column, validity, number)?,
+        D::Int32 | D::Date32 | D::Time32(_) => {
+            deserialize_primitive::<i32>(dt, column, validity, number)?
+        },
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            deserialize_primitive::<i64>(dt, column, validity, number)?
+        },
+        D::Int128 | D::Decimal(_, _) => {
+            deserialize_primitive::<i128>(dt, column, validity, number)?
+        },
+        D::UInt8 => deserialize_primitive::<u8>(dt, column, validity, number)?,
+        D::UInt16 => deserialize_primitive::<u16>(dt, column, validity, number)?,
+        D::UInt32 => deserialize_primitive::<u32>(dt, column, validity, number)?,
+        D::UInt64 => deserialize_primitive::<u64>(dt, column, validity, number)?,
+        D::UInt128 => deserialize_primitive::<u128>(dt, column, validity, number)?,
+        D::Float32 => deserialize_primitive::<f32>(dt, column, validity, number)?,
+        D::Float64 => deserialize_primitive::<f64>(dt, column, validity, number)?,
+        D::Utf8 => deserialize_utf8::<i32>(dt, column, validity)?,
+        D::LargeUtf8 => deserialize_utf8::<i64>(dt, column, validity)?,
+        D::Binary => deserialize_binary::<i32>(dt, column, validity)?,
+        D::LargeBinary => deserialize_binary::<i64>(dt, column, validity)?,
+        D::List(inner) => {
+            let values = deserialize_array(&inner.data_type, single_child(column)?)?;
+            ListArray::<i32>::try_new(dt.clone(), deserialize_offsets(column)?, values, validity)?
+                .boxed()
+        },
+        D::LargeList(inner) => {
+            let values = deserialize_array(&inner.data_type, single_child(column)?)?;
+            ListArray::<i64>::try_new(dt.clone(), deserialize_offsets(column)?, values, validity)?
+                .boxed()
+        },
+        D::FixedSizeList(inner, _) => {
+            let values = deserialize_array(&inner.data_type, single_child(column)?)?;
+            FixedSizeListArray::try_new(dt.clone(), values, validity)?.boxed()
+        },
+        D::Struct(fields) => {
+            let children = get_array(get(column, "children")?)?;
+            polars_ensure!(
+                children.len() == fields.len(),
+                ComputeError: "arrow json struct column has {} children, but its type has {} fields", children.len(), fields.len()
+            );
+            let values = fields
+                .iter()
+                .zip(children)
+                .map(|(field, child)| deserialize_array(&field.data_type, child))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            StructArray::try_new(dt.clone(), values, validity)?.boxed()
+        },
+        dt => polars_bail!(ComputeError: "cannot read arrow type {:?} from arrow json", dt),
+    };
+    polars_ensure!(arr.len() == len, ComputeError: "arrow json column has length {}, but its count is {}", arr.len(), len);
+    Ok(arr)
+}
+
+fn deserialize_utf8<O: Offset>(
+    data_type: &ArrowDataType,
+    column: &Value,
+    validity: Option<Bitmap>,
+) -> PolarsResult<ArrayRef> {
+    let mut values = String::new();
+    for v in get_array(get(column, "DATA")?)? {
+        values.push_str(get_str(v)?);
+    }
+    let offsets = deserialize_offsets::<O>(column)?;
+    Ok(Utf8Array::<O>::try_new(
+        data_type.clone(),
+        offsets,
+        values.into_bytes().into(),
+        validity,
+    )?
+    .boxed())
+}
+
+fn deserialize_binary<O: Offset>(
+    data_type: &ArrowDataType,
+    column: &Value,
+    validity: Option<Bitmap>,
+) -> PolarsResult<ArrayRef> {
+    let mut values = vec![];
+    for v in get_array(get(column, "DATA")?)? {
+        values.extend(unhex(get_str(v)?)?);
+    }
+    let offsets = deserialize_offsets::<O>(column)?;
+    Ok(BinaryArray::<O>::try_new(data_type.clone(), offsets, values.into(), validity)?.boxed())
+}
@@ -63,19 +63,20 @@
 //! ```
 //!
 pub(crate) mod infer;
+mod integration;
 
 use std::io::Write;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
 
-use arrow::legacy::conversion::chunk_to_struct;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
-use polars_json::json::write::FallibleStreamingIterator;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
 
+pub use integration::{read_debug_dump, ArrowJsonReader, ArrowJsonWriter, DebugDump};
+
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
@@ -105,18 +106,128 @@ pub enum JsonFormat {
     JsonLines,
 }
 
+/// How the values of a DataFrame are laid out when written to JSON.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JsonOrient {
+    /// Every row is an object keyed by column name: `[{"a":1,"b":"x"},{"a":2,"b":"y"}]`.
+    #[default]
+    Records,
+    /// A single object holding the values of every column: `{"a":[1,2],"b":["x","y"]}`.
+    ///
+    /// Only supported with [`JsonFormat::Json`].
+    Columns,
+    /// Every row is an array of its values in column order: `[[1,"x"],[2,"y"]]`.
+    Values,
+}
+
+/// How null values are written to JSON.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JsonNullPolicy {
+    /// Write null values as `null`.
+    #[default]
+    Write,
+    /// Leave out the keys of which the value is null. Only applies to the row objects of
+    /// [`JsonOrient::Records`], in the other orientations the position of a value is meaningful.
+    Skip,
+}
+
 /// Writes a DataFrame to JSON.
 ///
 /// Under the hood, this uses [`arrow2::io::json`](https://docs.rs/arrow2/latest/arrow2/io/json/write/fn.write.html).
 /// `arrow2` generally serializes types that are not JSON primitives, such as Date and DateTime, as their
 /// `Display`-formatted versions. For instance, a (naive) DateTime column is formatted as the String `"yyyy-mm-dd
-/// HH:MM:SS"`. To control how non-primitive columns are serialized, convert them to String or another primitive type
-/// before serializing.
+/// HH:MM:SS"`. To control how temporal columns are serialized, set a format with e.g.
+/// [`JsonWriter::with_datetime_format`]. For other non-primitive columns, convert them to String or another primitive
+/// type before serializing.
 #[must_use]
 pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    options: JsonSerializeOptions,
+}
+
+/// The options shared by [`JsonWriter`] and [`BatchedWriter`].
+#[derive(Clone, Debug, Default)]
+struct JsonSerializeOptions {
+    orient: JsonOrient,
+    null_policy: JsonNullPolicy,
+    date_format: Option<String>,
+    datetime_format: Option<String>,
+    time_format: Option<String>,
+}
+
+impl JsonSerializeOptions {
+    /// Check that `df` can be written and format its temporal columns as strings if a format is
+    /// set for them.
+    fn prepare(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| {
+                #[cfg(feature = "object")]
+                polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to json");
+                let out = match s.dtype() {
+                    #[cfg(feature = "dtype-date")]
+                    DataType::Date if self.date_format.is_some() => {
+                        let format = self.date_format.as_deref().unwrap();
+                        s.date()?.to_string(format).into_series()
+                    },
+                    #[cfg(feature = "dtype-datetime")]
+                    DataType::Datetime(_, _) if self.datetime_format.is_some() => {
+                        let format = self.datetime_format.as_deref().unwrap();
+                        s.datetime()?.to_string(format)?.into_series()
+                    },
+                    #[cfg(feature = "dtype-time")]
+                    DataType::Time if self.time_format.is_some() => {
+                        let format = self.time_format.as_deref().unwrap();
+                        s.time()?.to_string(format).into_series()
+                    },
+                    _ => s.clone(),
+                };
+                Ok(out)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(unsafe { DataFrame::new_no_checks(columns) })
+    }
+
+    /// Serialize the rows of `df` to `buffer`, separated by `separator`. `has_written_rows`
+    /// tells whether rows precede the output in `buffer`, which are separated from it as well.
+    /// Returns whether rows have been written after this call. The chunks of `df` must be
+    /// aligned.
+    fn serialize_rows(
+        &self,
+        df: &DataFrame,
+        separator: &[u8],
+        mut has_written_rows: bool,
+        buffer: &mut Vec<u8>,
+    ) -> bool {
+        let names = df.get_column_names();
+        let names = match self.orient {
+            JsonOrient::Values => None,
+            _ => Some(names.as_slice()),
+        };
+        let skip_nulls = self.null_policy == JsonNullPolicy::Skip;
+
+        for chunk in df.iter_chunks(true, false) {
+            if chunk.len() == 0 {
+                continue;
+            }
+            if has_written_rows {
+                buffer.extend_from_slice(separator);
+            }
+            has_written_rows = true;
+            let arrays = chunk
+                .arrays()
+                .iter()
+                .map(|arr| arr.as_ref())
+                .collect::<Vec<_>>();
+            polars_json::json::write::serialize_rows(&arrays, names, skip_nulls, separator, buffer);
+        }
+        has_written_rows
+    }
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -124,6 +235,53 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Set how the values are laid out. Defaults to [`JsonOrient::Records`].
+    pub fn with_orient(mut self, orient: JsonOrient) -> Self {
+        self.options.orient = orient;
+        self
+    }
+
+    /// Set how null values are written. Defaults to [`JsonNullPolicy::Write`].
+    pub fn with_null_policy(mut self, null_policy: JsonNullPolicy) -> Self {
+        self.options.null_policy = null_policy;
+        self
+    }
+
+    /// Set the chrono format string used to write `Date` columns.
+    pub fn with_date_format(mut self, format: Option<String>) -> Self {
+        self.options.date_format = format;
+        self
+    }
+
+    /// Set the chrono format string used to write `Datetime` columns.
+    pub fn with_datetime_format(mut self, format: Option<String>) -> Self {
+        self.options.datetime_format = format;
+        self
+    }
+
+    /// Set the chrono format string used to write `Time` columns.
+    pub fn with_time_format(mut self, format: Option<String>) -> Self {
+        self.options.time_format = format;
+        self
+    }
+
+    /// Write the DataFrames passed to [`BatchedWriter::write_batch`] one after another, as if
+    /// they were a single DataFrame. Only [`JsonOrient::Records`] and [`JsonOrient::Values`]
+    /// can be written in batches.
+    pub fn batched(self, _schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        polars_ensure!(
+            self.options.orient != JsonOrient::Columns,
+            InvalidOperation: "cannot write json with orient 'columns' in batches"
+        );
+        Ok(BatchedWriter {
+            writer: self.buffer,
+            json_lines: matches!(self.json_format, JsonFormat::JsonLines),
+            options: self.options,
+            has_written_rows: false,
+            has_finished: false,
+        })
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -136,33 +294,45 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            options: JsonSerializeOptions::default(),
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
         df.align_chunks();
-        let fields = df
-            .iter()
-            .map(|s| {
-                #[cfg(feature = "object")]
-                polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to json");
-                Ok(s.field().to_arrow(true))
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
-        let batches = df
-            .iter_chunks(true, false)
-            .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
+        let df = self.options.prepare(df)?;
 
-        match self.json_format {
-            JsonFormat::JsonLines => {
-                let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
-                let writer =
-                    polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
-                writer.collect::<PolarsResult<()>>()?;
+        match (&self.json_format, self.options.orient) {
+            (JsonFormat::JsonLines, JsonOrient::Columns) => {
+                polars_bail!(InvalidOperation: "cannot write json lines with orient 'columns'")
             },
-            JsonFormat::Json => {
-                let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
+            (JsonFormat::Json, JsonOrient::Columns) => {
+                let mut buffer = vec![b'{'];
+                for (i, s) in df.get_columns().iter().enumerate() {
+                    if i != 0 {
+                        buffer.push(b',');
+                    }
+                    polars_json::json::write::write_str(&mut buffer, s.name())?;
+                    buffer.extend_from_slice(b":[");
+                    let arr = s.rechunk().to_arrow(0, true);
+                    polars_json::json::write::serialize(arr.as_ref(), &mut buffer);
+                    buffer.push(b']');
+                }
+                buffer.push(b'}');
+                self.buffer.write_all(&buffer)?;
+            },
+            (JsonFormat::JsonLines, _) => {
+                let mut buffer = vec![];
+                if self.options.serialize_rows(&df, b"\n", false, &mut buffer) {
+                    buffer.push(b'\n');
+                }
+                self.buffer.write_all(&buffer)?;
+            },
+            (JsonFormat::Json, _) => {
+                let mut buffer = vec![b'['];
+                self.options.serialize_rows(&df, b",", false, &mut buffer);
+                buffer.push(b']');
+                self.buffer.write_all(&buffer)?;
             },
         }
 
@@ -170,36 +340,69 @@ where
     }
 }
 
+/// Writes DataFrames to JSON one batch at a time, created by [`JsonWriter::batched`].
+///
+/// With [`JsonFormat::Json`], the rows of all batches form a single JSON array, which is closed
+/// by [`BatchedWriter::finish`].
 pub struct BatchedWriter<W: Write> {
     writer: W,
+    json_lines: bool,
+    options: JsonSerializeOptions,
+    has_written_rows: bool,
+    has_finished: bool,
 }
 
 impl<W> BatchedWriter<W>
 where
     W: Write,
 {
+    /// Create a writer of records in the [`JsonFormat::JsonLines`] format.
     pub fn new(writer: W) -> Self {
-        BatchedWriter { writer }
+        BatchedWriter {
+            writer,
+            json_lines: true,
+            options: JsonSerializeOptions::default(),
+            has_written_rows: false,
+            has_finished: false,
+        }
     }
+
     /// Write a batch to the json writer.
     ///
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
-        let fields = df
-            .iter()
-            .map(|s| {
-                #[cfg(feature = "object")]
-                polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to json");
-                Ok(s.field().to_arrow(true))
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
-        let chunks = df.iter_chunks(true, false);
-        let batches =
-            chunks.map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
-        let mut serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
-        while let Some(block) = serializer.next()? {
-            self.writer.write_all(block)?;
+        let df = self.options.prepare(df)?;
+        let mut buffer = vec![];
+        if self.json_lines {
+            if self.options.serialize_rows(&df, b"\n", false, &mut buffer) {
+                buffer.push(b'\n');
+            }
+        } else {
+            if !self.has_written_rows {
+                buffer.push(b'[');
+            }
+            self.has_written_rows =
+                self.options
+                    .serialize_rows(&df, b",", self.has_written_rows, &mut buffer);
+            if !self.has_written_rows {
+                buffer.clear();
+            }
+        }
+        self.writer.write_all(&buffer)?;
+        Ok(())
+    }
+
+    /// Write the end of the output. Must be called once after the last batch.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        if self.json_lines || self.has_finished {
+            return Ok(());
+        }
+        self.has_finished = true;
+        if self.has_written_rows {
+            self.writer.write_all(b"]")?;
+        } else {
+            self.writer.write_all(b"[]")?;
         }
         Ok(())
     }
@@ -322,6 +525,7 @@ where
                     self.schema,
                     self.schema_overwrite,
                     None,
+                    None,
                     1024, // sample size
                     NonZeroUsize::new(1 << 18).unwrap(),
                     false,
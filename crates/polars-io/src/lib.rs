@@ -4,6 +4,8 @@
 
 #[cfg(feature = "avro")]
 pub mod avro;
+#[cfg(feature = "csv")]
+mod bad_rows;
 pub mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
@@ -25,6 +27,8 @@ pub mod partition;
 pub mod pl_async;
 pub mod predicates;
 pub mod prelude;
+#[cfg(feature = "range_reads")]
+pub mod range_reader;
 mod shared;
 pub mod utils;
 
@@ -11,6 +11,20 @@ use polars_core::config::verbose;
 use polars_error::{polars_bail, PolarsResult};
 use polars_utils::create_file;
 
+#[cfg(feature = "range_reads")]
+use crate::range_reader::RangeSource;
+
+/// Whether files can be memory mapped on this platform. On wasm they are always read to a buffer.
+pub(crate) const MMAP_SUPPORTED: bool = cfg!(not(target_family = "wasm"));
+
+/// Memory map `file`, or `None` if that isn't supported on this platform or for this file.
+pub(crate) fn try_mmap(file: &File) -> Option<Mmap> {
+    if !MMAP_SUPPORTED {
+        return None;
+    }
+    unsafe { Mmap::map(file) }.ok()
+}
+
 // Keep track of memory mapped files so we don't write to them while reading
 // Use a btree as it uses less memory than a hashmap and this thing never shrinks.
 static MEMORY_MAPPED_FILES: Lazy<Mutex<BTreeMap<PathBuf, u32>>> =
@@ -24,11 +38,20 @@ pub(crate) struct MMapSemaphore {
 impl MMapSemaphore {
     pub(super) fn new(path: PathBuf, mmap: Mmap) -> Self {
         let mut guard = MEMORY_MAPPED_FILES.lock().unwrap();
-        guard.insert(path.clone(), 1);
+        *guard.entry(path.clone()).or_insert(0) += 1;
         Self { path, mmap }
     }
 }
 
+/// The number of memory mappings of files that are open, e.g. because a [`DataFrame`] still
+/// references the memory mapped data of the IPC file it was read from.
+///
+/// [`DataFrame`]: polars_core::frame::DataFrame
+pub fn open_memory_maps() -> usize {
+    let guard = MEMORY_MAPPED_FILES.lock().unwrap();
+    guard.values().map(|&count| count as usize).sum()
+}
+
 impl AsRef<[u8]> for MMapSemaphore {
     #[inline]
     fn as_ref(&self) -> &[u8] {
@@ -70,6 +93,13 @@ pub trait MmapBytesReader: Read + Seek + Send + Sync {
     fn to_bytes(&self) -> Option<&[u8]> {
         None
     }
+
+    /// The source of a reader that fetches byte ranges on demand, so that readers can fetch only
+    /// the parts of a file they need.
+    #[cfg(feature = "range_reads")]
+    fn to_range_source(&self) -> Option<&dyn RangeSource> {
+        None
+    }
 }
 
 impl MmapBytesReader for File {
@@ -101,6 +131,11 @@ impl<T: MmapBytesReader + ?Sized> MmapBytesReader for Box<T> {
     fn to_bytes(&self) -> Option<&[u8]> {
         T::to_bytes(self)
     }
+
+    #[cfg(feature = "range_reads")]
+    fn to_range_source(&self) -> Option<&dyn RangeSource> {
+        T::to_range_source(self)
+    }
 }
 
 impl<T: MmapBytesReader> MmapBytesReader for &mut T {
@@ -111,6 +146,11 @@ impl<T: MmapBytesReader> MmapBytesReader for &mut T {
     fn to_bytes(&self) -> Option<&[u8]> {
         T::to_bytes(self)
     }
+
+    #[cfg(feature = "range_reads")]
+    fn to_range_source(&self) -> Option<&dyn RangeSource> {
+        T::to_range_source(self)
+    }
 }
 
 // Handle various forms of input bytes
@@ -140,9 +180,12 @@ impl<'a, T: 'a + MmapBytesReader> From<&'a mut T> for ReaderBytes<'a> {
                 ReaderBytes::Borrowed(s)
             },
             None => {
-                if let Some(f) = m.to_file() {
+                // Pipes and character devices, e.g. stdin, can't be memory mapped.
+                let mapped = m.to_file().and_then(|f| {
                     let f = unsafe { std::mem::transmute::<&File, &'a File>(f) };
-                    let mmap = unsafe { memmap::Mmap::map(f).unwrap() };
+                    Some((try_mmap(f)?, f))
+                });
+                if let Some((mmap, f)) = mapped {
                     ReaderBytes::Mapped(mmap, f)
                 } else {
                     if verbose() {
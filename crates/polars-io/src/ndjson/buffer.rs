@@ -124,6 +124,36 @@ impl Buffer<'_> {
             _ => panic!("unexpected dtype when deserializing ndjson"),
         }
     }
+    /// Check that `value` is added as a valid value, rather than read as null because it
+    /// doesn't match the dtype of the buffer.
+    pub(crate) fn check(&self, value: &Value) -> PolarsResult<()> {
+        use AnyValueBuffer::*;
+        if matches!(value, Value::Static(StaticNode::Null)) {
+            return Ok(());
+        }
+        let valid = match &self.buf {
+            Boolean(_) => matches!(value, Value::Static(StaticNode::Bool(_))),
+            Int32(_) => deserialize_number::<i32>(value).is_some(),
+            Int64(_) => deserialize_number::<i64>(value).is_some(),
+            UInt64(_) => deserialize_number::<u64>(value).is_some(),
+            UInt32(_) => deserialize_number::<u32>(value).is_some(),
+            Float32(_) => deserialize_number::<f32>(value).is_some(),
+            Float64(_) => deserialize_number::<f64>(value).is_some(),
+            String(_) => matches!(value, Value::String(_)),
+            #[cfg(feature = "dtype-datetime")]
+            Datetime(_, _, _) => deserialize_datetime::<Int64Type>(value).is_some(),
+            #[cfg(feature = "dtype-date")]
+            Date(_) => deserialize_datetime::<Int32Type>(value).is_some(),
+            All(dtype, _) => return deserialize_all(value, dtype, false).map(|_| ()),
+            _ => true,
+        };
+        polars_ensure!(
+            valid,
+            ComputeError: "could not parse {} as the dtype of column '{}'", value, self.name
+        );
+        Ok(())
+    }
+
     pub fn add_null(&mut self) {
         self.buf.add(AnyValue::Null).expect("should not fail");
     }
@@ -10,11 +10,12 @@ use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::POOL;
 use rayon::prelude::*;
 
+use crate::bad_rows::BadRows;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::ndjson::buffer::*;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
-use crate::RowIndex;
+use crate::{DtypeOverrides, RowIndex};
 const NEWLINE: u8 = b'\n';
 const RETURN: u8 = b'\r';
 const CLOSING_BRACKET: u8 = b'}';
@@ -32,6 +33,7 @@ where
     chunk_size: NonZeroUsize,
     schema: Option<SchemaRef>,
     schema_overwrite: Option<&'a Schema>,
+    dtype_overrides: Option<Arc<DtypeOverrides>>,
     path: Option<PathBuf>,
     low_memory: bool,
     ignore_errors: bool,
@@ -58,6 +60,13 @@ where
         self
     }
 
+    /// Set the dtypes and inference lengths of the columns that match a pattern, see
+    /// [`DtypeOverrides`].
+    pub fn with_dtype_overrides(mut self, dtype_overrides: Option<Arc<DtypeOverrides>>) -> Self {
+        self.dtype_overrides = dtype_overrides;
+        self
+    }
+
     pub fn with_rechunk(mut self, rechunk: bool) -> Self {
         self.rechunk = rechunk;
         self
@@ -119,6 +128,7 @@ where
             self.n_rows,
             self.schema,
             self.schema_overwrite,
+            self.dtype_overrides.as_deref(),
             self.n_threads,
             1024, // sample size
             self.chunk_size,
@@ -136,6 +146,91 @@ where
 
 impl<'a> JsonLineReader<'a, File> {
     /// This is the recommended way to create a json reader as this allows for fastest parsing.
+    /// Read the file and return the lines that fail to parse in a separate DataFrame, instead
+    /// of raising an error on the first of them.
+    ///
+    /// The bad rows have the line number of the row in the file (`line`), the raw bytes of the
+    /// line (`raw`) and why it failed to parse (`error`). Values that don't match the dtype of
+    /// their column make a row bad as well, rather than being read as null. Set a schema to
+    /// quarantine values that don't match it, an inferred schema is inferred from the lines that
+    /// are valid JSON.
+    pub fn finish_with_bad_rows(mut self) -> PolarsResult<(DataFrame, DataFrame)> {
+        polars_ensure!(
+            self.n_rows.is_none() && self.row_index.is_none(),
+            InvalidOperation: "bad rows cannot be collected when reading with 'n_rows' or 'row_index'"
+        );
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let bytes: &[u8] = &reader_bytes;
+
+        let mut schema = match self.schema {
+            Some(schema) => schema,
+            None => Arc::new(infer_schema_of_valid_lines(
+                bytes,
+                self.infer_schema_len,
+                self.dtype_overrides.as_deref(),
+            )?),
+        };
+        if let Some(overwriting_schema) = self.schema_overwrite {
+            let schema = Arc::make_mut(&mut schema);
+            overwrite_schema(schema, overwriting_schema)?;
+        }
+
+        let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
+        let file_chunks = get_file_chunks_json(bytes, n_threads);
+        let chunks = POOL.install(|| {
+            file_chunks
+                .into_par_iter()
+                .map(|(start_pos, stop_at_nbytes)| {
+                    let bytes = &bytes[start_pos..stop_at_nbytes];
+                    let mut buffers = init_buffers(&schema, 0, false)?;
+                    let mut bad_rows = BadRows::default();
+                    let mut scratch = vec![];
+                    let mut n_lines = 0;
+                    for (i, line) in bytes.split(|b| *b == NEWLINE).enumerate() {
+                        n_lines = i;
+                        let line = line.strip_suffix(&[RETURN]).unwrap_or(line);
+                        if line.iter().all(u8::is_ascii_whitespace) {
+                            continue;
+                        }
+                        if let Err(err) = parse_line_strict(line, &mut buffers, &mut scratch) {
+                            bad_rows.push(i + 1, line, &err)
+                        }
+                    }
+
+                    let mut df = DataFrame::new(
+                        buffers
+                            .into_values()
+                            .map(|buf| buf.into_series())
+                            .collect::<_>(),
+                    )?;
+                    if let Some(projection) = &self.projection {
+                        df = df.select(projection.as_ref())?;
+                    }
+                    if let Some(predicate) = &self.predicate {
+                        let s = predicate.evaluate_io(&df)?;
+                        let mask = s.bool()?;
+                        df = df.filter(mask)?;
+                    }
+                    PolarsResult::Ok((df, bad_rows, n_lines))
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut dfs = Vec::with_capacity(chunks.len());
+        let mut bad_rows = BadRows::default();
+        let mut line_offset = 0;
+        for (df, chunk_bad_rows, n_lines) in chunks {
+            dfs.push(df);
+            bad_rows.append(chunk_bad_rows, line_offset);
+            line_offset += n_lines;
+        }
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        if self.rechunk && df.n_chunks() > 1 {
+            df.as_single_chunk_par();
+        }
+        Ok((df, bad_rows.into_df()))
+    }
+
     pub fn from_path<P: Into<PathBuf>>(path: P) -> PolarsResult<Self> {
         let path = resolve_homedir(&path.into());
         let f = polars_utils::open_file(&path)?;
@@ -156,6 +251,7 @@ where
             infer_schema_len: Some(NonZeroUsize::new(100).unwrap()),
             schema: None,
             schema_overwrite: None,
+            dtype_overrides: None,
             path: None,
             chunk_size: NonZeroUsize::new(1 << 18).unwrap(),
             low_memory: false,
@@ -173,6 +269,7 @@ where
             self.n_rows,
             self.schema,
             self.schema_overwrite,
+            self.dtype_overrides.as_deref(),
             self.n_threads,
             1024, // sample size
             self.chunk_size,
@@ -212,6 +309,7 @@ impl<'a> CoreJsonReader<'a> {
         n_rows: Option<usize>,
         schema: Option<SchemaRef>,
         schema_overwrite: Option<&Schema>,
+        dtype_overrides: Option<&DtypeOverrides>,
         n_threads: Option<usize>,
         sample_size: usize,
         chunk_size: NonZeroUsize,
@@ -229,7 +327,11 @@ impl<'a> CoreJsonReader<'a> {
             None => {
                 let bytes: &[u8] = &reader_bytes;
                 let mut cursor = Cursor::new(bytes);
-                Arc::new(crate::ndjson::infer_schema(&mut cursor, infer_schema_len)?)
+                Arc::new(crate::ndjson::infer_schema_with_overrides(
+                    &mut cursor,
+                    infer_schema_len,
+                    dtype_overrides,
+                )?)
             },
         };
         if let Some(overwriting_schema) = schema_overwrite {
@@ -420,6 +522,68 @@ fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> Pol
     Ok(())
 }
 
+/// Parse a line, failing without adding to the buffers if it isn't a JSON object or if a value
+/// doesn't match the dtype of its column.
+fn parse_line_strict(
+    bytes: &[u8],
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    scratch: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    scratch.clear();
+    scratch.extend_from_slice(bytes);
+    let value: simd_json::BorrowedValue = simd_json::to_borrowed_value(scratch)
+        .map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
+    let simd_json::BorrowedValue::Object(value) = value else {
+        polars_bail!(ComputeError: "expected a JSON object, got: {}", value)
+    };
+    // Check all values first, so that the buffers keep the same length.
+    for (key, buffer) in buffers.iter() {
+        if let Some(v) = key.0.map_lookup(&value) {
+            buffer.check(v)?
+        }
+    }
+    for (key, buffer) in buffers.iter_mut() {
+        match key.0.map_lookup(&value) {
+            Some(v) => buffer.add(v)?,
+            None => buffer.add_null(),
+        }
+    }
+    Ok(())
+}
+
+/// Infer the schema from the first `infer_schema_len` lines that are valid JSON objects.
+fn infer_schema_of_valid_lines(
+    bytes: &[u8],
+    infer_schema_len: Option<NonZeroUsize>,
+    dtype_overrides: Option<&DtypeOverrides>,
+) -> PolarsResult<Schema> {
+    let default = infer_schema_len.map(|len| len.get());
+    let max_len = match dtype_overrides {
+        Some(overrides) => overrides.max_infer_schema_length(default),
+        None => default,
+    };
+    let mut valid = vec![];
+    let mut n_valid = 0;
+    for line in bytes.split(|b| *b == NEWLINE) {
+        if max_len.map_or(false, |len| n_valid >= len.max(1)) {
+            break;
+        }
+        if matches!(
+            serde_json::from_slice::<serde_json::Value>(line),
+            Ok(serde_json::Value::Object(_))
+        ) {
+            valid.extend_from_slice(line);
+            valid.push(NEWLINE);
+            n_valid += 1;
+        }
+    }
+    crate::ndjson::infer_schema_with_overrides(
+        &mut Cursor::new(valid.as_slice()),
+        infer_schema_len,
+        dtype_overrides,
+    )
+}
+
 /// Find the nearest next line position.
 /// Does not check for new line characters embedded in String fields.
 /// This just looks for `}\n`
@@ -1,8 +1,11 @@
+use std::io::{BufRead, Seek, SeekFrom};
 use std::num::NonZeroUsize;
 
 use arrow::array::StructArray;
 use polars_core::prelude::*;
 
+use crate::options::DtypeOverrides;
+
 pub(crate) mod buffer;
 pub mod core;
 
@@ -18,3 +21,48 @@ pub fn infer_schema<R: std::io::BufRead>(
         .collect();
     Ok(schema)
 }
+
+/// Infer the schema like [`infer_schema`], but with the dtypes and the number of rows to infer
+/// from of the columns that match `dtype_overrides`.
+pub fn infer_schema_with_overrides<R: BufRead + Seek>(
+    reader: &mut R,
+    infer_schema_len: Option<NonZeroUsize>,
+    dtype_overrides: Option<&DtypeOverrides>,
+) -> PolarsResult<Schema> {
+    let Some(overrides) = dtype_overrides.filter(|overrides| !overrides.is_empty()) else {
+        return infer_schema(reader, infer_schema_len);
+    };
+    let start = reader.stream_position()?;
+    let mut infer = |len: Option<usize>| {
+        reader.seek(SeekFrom::Start(start))?;
+        // A length of 0 still infers from a single row, like `infer_schema_len`.
+        infer_schema(
+            reader,
+            len.map(|len| NonZeroUsize::new(len.max(1)).unwrap()),
+        )
+    };
+
+    // The names are only known after inferring, so first infer from the most rows that any
+    // column needs, then only re-infer for the columns that need fewer.
+    let default = infer_schema_len.map(|len| len.get());
+    let max_len = overrides.max_infer_schema_length(default);
+    let schema = infer(max_len)?;
+    let resolved = overrides.resolve(schema.iter_names().map(|name| name.as_str()))?;
+
+    let mut by_len: PlHashMap<usize, Schema> = PlHashMap::new();
+    let mut out = Schema::with_capacity(schema.len());
+    for ((name, dtype), column) in schema.iter().zip(resolved) {
+        let dtype = match (column.dtype, column.infer_schema_length(default)) {
+            (Some(dtype), _) => dtype,
+            (None, Some(len)) if Some(len) != max_len => {
+                if !by_len.contains_key(&len) {
+                    by_len.insert(len, infer(Some(len))?);
+                }
+                by_len[&len].get(name).unwrap_or(dtype).clone()
+            },
+            (None, _) => dtype.clone(),
+        };
+        out.with_column(name.clone(), dtype);
+    }
+    Ok(out)
+}
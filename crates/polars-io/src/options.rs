@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
+use polars_core::datatypes::DataType;
 use polars_core::schema::SchemaRef;
+use polars_error::{polars_err, PolarsResult};
 use polars_utils::IdxSize;
+use regex::Regex;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -35,3 +38,144 @@ impl Default for HiveOptions {
         }
     }
 }
+
+/// Data types and schema inference lengths for the columns of which the name matches a pattern,
+/// so that the schema of a wide file doesn't have to name every column to avoid misinference.
+///
+/// A pattern that starts with `^` and ends with `$` is a regex, like in `col`. Any other pattern
+/// is a glob in which `*` matches any characters and `?` a single character. If several
+/// patterns match a column, the first one is used. Explicitly given dtypes, like a schema
+/// overwrite, take precedence.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DtypeOverrides {
+    dtypes: Vec<(String, DataType)>,
+    infer_schema_lengths: Vec<(String, Option<usize>)>,
+}
+
+/// The override of a single column.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ColumnOverride {
+    pub dtype: Option<DataType>,
+    pub infer_schema_length: Option<Option<usize>>,
+}
+
+impl ColumnOverride {
+    /// The number of rows to infer the dtype of the column from, `None` is all rows.
+    pub fn infer_schema_length(&self, default: Option<usize>) -> Option<usize> {
+        self.infer_schema_length.unwrap_or(default)
+    }
+}
+
+fn pattern_to_regex(pattern: &str) -> PolarsResult<Regex> {
+    let regex = if pattern.starts_with('^') && pattern.ends_with('$') {
+        pattern.to_string()
+    } else {
+        let glob = regex::escape(pattern)
+            .replace(r"\*", ".*")
+            .replace(r"\?", ".");
+        format!("^{glob}$")
+    };
+    Regex::new(&regex)
+        .map_err(|err| polars_err!(ComputeError: "invalid column pattern '{}': {}", pattern, err))
+}
+
+impl DtypeOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `dtype` for the columns that match `pattern` instead of inferring it.
+    pub fn with_dtype(mut self, pattern: &str, dtype: DataType) -> Self {
+        self.dtypes.push((pattern.to_string(), dtype));
+        self
+    }
+
+    /// Infer the dtype of the columns that match `pattern` from the first
+    /// `infer_schema_length` rows, or from all rows if `None`.
+    pub fn with_infer_schema_length(
+        mut self,
+        pattern: &str,
+        infer_schema_length: Option<usize>,
+    ) -> Self {
+        self.infer_schema_lengths
+            .push((pattern.to_string(), infer_schema_length));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dtypes.is_empty() && self.infer_schema_lengths.is_empty()
+    }
+
+    /// The largest number of rows that any column is inferred from, `None` is all rows.
+    pub(crate) fn max_infer_schema_length(&self, default: Option<usize>) -> Option<usize> {
+        self.infer_schema_lengths
+            .iter()
+            .map(|(_, len)| *len)
+            .try_fold(default?, |acc, len| Some(acc.max(len?)))
+    }
+
+    /// The override of every column in `names`.
+    pub(crate) fn resolve<'a, I>(&self, names: I) -> PolarsResult<Vec<ColumnOverride>>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let dtypes = self
+            .dtypes
+            .iter()
+            .map(|(pattern, dtype)| Ok((pattern_to_regex(pattern)?, dtype)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let lengths = self
+            .infer_schema_lengths
+            .iter()
+            .map(|(pattern, len)| Ok((pattern_to_regex(pattern)?, *len)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(names
+            .into_iter()
+            .map(|name| ColumnOverride {
+                dtype: dtypes
+                    .iter()
+                    .find(|(re, _)| re.is_match(name))
+                    .map(|(_, dtype)| (*dtype).clone()),
+                infer_schema_length: lengths
+                    .iter()
+                    .find(|(re, _)| re.is_match(name))
+                    .map(|(_, len)| *len),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dtype_overrides() -> PolarsResult<()> {
+        let overrides = DtypeOverrides::new()
+            .with_dtype("^.*_id$", DataType::String)
+            .with_dtype("ts_*", DataType::Int64)
+            .with_dtype("*", DataType::Float64)
+            .with_infer_schema_length("a?", None);
+        let resolved = overrides.resolve(["user_id", "ts_start", "ab", "abc"])?;
+        let dtypes = resolved.iter().map(|o| o.dtype.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            dtypes,
+            [
+                Some(DataType::String),
+                Some(DataType::Int64),
+                Some(DataType::Float64),
+                Some(DataType::Float64)
+            ]
+        );
+        assert_eq!(resolved[2].infer_schema_length(Some(100)), None);
+        assert_eq!(resolved[3].infer_schema_length(Some(100)), Some(100));
+
+        assert!(DtypeOverrides::new()
+            .with_dtype("^($", DataType::String)
+            .resolve(["a"])
+            .is_err());
+        Ok(())
+    }
+}
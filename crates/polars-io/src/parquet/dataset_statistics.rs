@@ -0,0 +1,175 @@
+//! A sidecar file with the statistics of every file of a partitioned dataset.
+//!
+//! A scan of a dataset with the sidecar can plan without listing the dataset and without opening
+//! the footer of every file: the files are pruned with their statistics and counted with their
+//! number of rows.
+use std::fmt::{Debug, Formatter};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+use crate::parquet::read::ParquetReader;
+use crate::parquet::write::ParquetWriter;
+use crate::predicates::{use_min_max, BatchStats, ColumnStats};
+use crate::SerReader;
+
+/// The name of the sidecar file in the root directory of a dataset.
+pub const STATISTICS_SIDECAR: &str = "_polars_statistics";
+
+const PATH: &str = "path";
+const NUM_ROWS: &str = "num_rows";
+const MIN: &str = ":min";
+const MAX: &str = ":max";
+const NULL_COUNT: &str = ":null_count";
+
+/// The number of rows and the column statistics of every file of a dataset.
+///
+/// The sidecar is a parquet file with a row per file. It has the path of the file relative to
+/// the root of the dataset, its number of rows and the minimum, maximum and null count of the
+/// columns that support them, as the columns `{name}:min`, `{name}:max` and
+/// `{name}:null_count`.
+#[derive(Clone)]
+pub struct DatasetStatistics {
+    root: PathBuf,
+    df: DataFrame,
+    /// The row of every file, by its path in the root.
+    rows: PlHashMap<PathBuf, usize>,
+    schema: SchemaRef,
+}
+
+impl Debug for DatasetStatistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DatasetStatistics {{ root: {}, files: {} }}",
+            self.root.display(),
+            self.df.height()
+        )
+    }
+}
+
+impl DatasetStatistics {
+    /// The statistics of the file at `path`, relative to the root of the dataset, of which the
+    /// content is `df`, as a row of the sidecar.
+    pub fn file_statistics(path: &str, df: &DataFrame) -> PolarsResult<DataFrame> {
+        let mut columns = vec![
+            Series::new(PATH, &[path]),
+            Series::new(NUM_ROWS, &[df.height() as u64]),
+        ];
+        for s in df.get_columns() {
+            if !use_min_max(s.dtype()) {
+                continue;
+            }
+            let name = s.name();
+            columns.push(s.min_reduce()?.into_series(&format!("{name}{MIN}")));
+            columns.push(s.max_reduce()?.into_series(&format!("{name}{MAX}")));
+            columns.push(Series::new(
+                &format!("{name}{NULL_COUNT}"),
+                &[s.null_count() as u64],
+            ));
+        }
+        DataFrame::new(columns)
+    }
+
+    /// Combine the [`file_statistics`](Self::file_statistics) of the files of the dataset in
+    /// `root`.
+    pub fn new(root: &Path, files: Vec<DataFrame>) -> PolarsResult<Self> {
+        polars_ensure!(!files.is_empty(), ComputeError: "a dataset needs at least one file");
+        Self::from_dataframe(root, accumulate_dataframes_vertical_unchecked(files))
+    }
+
+    fn from_dataframe(root: &Path, df: DataFrame) -> PolarsResult<Self> {
+        let rows = df
+            .column(PATH)?
+            .str()?
+            .into_iter()
+            .enumerate()
+            .map(|(i, path)| {
+                let path = path.ok_or_else(
+                    || polars_err!(ComputeError: "the statistics sidecar has a null path"),
+                )?;
+                Ok((root.join(path), i))
+            })
+            .collect::<PolarsResult<_>>()?;
+        let schema = df
+            .get_columns()
+            .iter()
+            .filter_map(|s| {
+                let name = s.name().strip_suffix(MIN)?;
+                Some(Field::new(name, s.dtype().clone()))
+            })
+            .collect::<Schema>();
+        Ok(Self {
+            root: root.to_path_buf(),
+            df,
+            rows,
+            schema: Arc::new(schema),
+        })
+    }
+
+    /// Read the sidecar of the dataset in `root`, if there is one.
+    pub fn read(root: &Path) -> PolarsResult<Option<Self>> {
+        let file = match File::open(root.join(STATISTICS_SIDECAR)) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let df = ParquetReader::new(file).finish()?;
+        Self::from_dataframe(root, df).map(Some)
+    }
+
+    /// Write the sidecar into the root of the dataset. The sidecar is replaced atomically.
+    pub fn write(&self) -> PolarsResult<()> {
+        let tmp = self.root.join(format!("{STATISTICS_SIDECAR}.tmp"));
+        ParquetWriter::new(File::create(&tmp)?).finish(&mut self.df.clone())?;
+        std::fs::rename(tmp, self.root.join(STATISTICS_SIDECAR))?;
+        Ok(())
+    }
+
+    /// The paths of the files of the dataset.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.df
+            .column(PATH)
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_no_null_iter()
+            .map(|path| self.root.join(path))
+            .collect()
+    }
+
+    /// The number of rows of the file at `path`, if it is a file of the dataset.
+    pub fn num_rows(&self, path: &Path) -> Option<usize> {
+        let row = *self.rows.get(path)?;
+        let num_rows = self.df.column(NUM_ROWS).ok()?.u64().ok()?.get(row)?;
+        Some(num_rows as usize)
+    }
+
+    /// The column statistics of the file at `path`, if it is a file of the dataset.
+    pub fn batch_stats(&self, path: &Path) -> Option<BatchStats> {
+        let row = *self.rows.get(path)? as i64;
+        let get = |name: &str, suffix: &str| {
+            self.df
+                .column(&format!("{name}{suffix}"))
+                .ok()
+                .map(|s| s.slice(row, 1))
+        };
+        let stats = self
+            .schema
+            .iter_fields()
+            .map(|field| {
+                let null_count = get(field.name(), NULL_COUNT);
+                let min = get(field.name(), MIN);
+                let max = get(field.name(), MAX);
+                ColumnStats::new(field, null_count, min, max)
+            })
+            .collect();
+        Some(BatchStats::new(
+            self.schema.clone(),
+            stats,
+            self.num_rows(path),
+        ))
+    }
+}
@@ -1,5 +1,8 @@
 //! Functionality for reading and writing Apache Parquet files.
 
+mod dataset_statistics;
 pub mod metadata;
 pub mod read;
 pub mod write;
+
+pub use dataset_statistics::{DatasetStatistics, STATISTICS_SIDECAR};
@@ -1,7 +1,7 @@
 use arrow::datatypes::Field;
-#[cfg(feature = "async")]
+#[cfg(any(feature = "async", feature = "range_reads"))]
 use bytes::Bytes;
-#[cfg(feature = "async")]
+#[cfg(any(feature = "async", feature = "range_reads"))]
 use polars_core::datatypes::PlHashMap;
 use polars_error::PolarsResult;
 use polars_parquet::read::{
@@ -11,7 +11,7 @@ use polars_parquet::read::{
 
 /// Store columns data in two scenarios:
 /// 1. a local memory mapped file
-/// 2. data fetched from cloud storage on demand, in this case
+/// 2. data fetched from cloud storage or a range source on demand, in this case
 ///     a. the key in the hashmap is the start in the file
 ///     b. the value in the hashmap is the actual data.
 ///
@@ -22,7 +22,7 @@ use polars_parquet::read::{
 ///    d. when all the data is available deserialize on multiple threads, for example using rayon
 pub enum ColumnStore<'a> {
     Local(&'a [u8]),
-    #[cfg(feature = "async")]
+    #[cfg(any(feature = "async", feature = "range_reads"))]
     Fetched(PlHashMap<u64, Bytes>),
 }
 
@@ -46,7 +46,7 @@ fn _mmap_single_column<'a>(
     let (start, len) = meta.byte_range();
     let chunk = match store {
         ColumnStore::Local(file) => &file[start as usize..(start + len) as usize],
-        #[cfg(all(feature = "async", feature = "parquet"))]
+        #[cfg(any(feature = "async", feature = "range_reads"))]
         ColumnStore::Fetched(fetched) => {
             let entry = fetched.get(&start).unwrap_or_else(|| {
                 panic!(
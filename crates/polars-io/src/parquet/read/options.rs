@@ -7,6 +7,8 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Store repeated string values only once per chunk after reading.
+    pub deduplicate_strings: bool,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
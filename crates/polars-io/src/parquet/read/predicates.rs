@@ -1,8 +1,9 @@
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
 use polars_parquet::read::statistics::{deserialize, Statistics};
-use polars_parquet::read::RowGroupMetaData;
+use polars_parquet::read::{read_dictionary_values, RowGroupMetaData};
 
+use super::mmap::{mmap_columns, ColumnStore};
 use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
 
 impl ColumnStats {
@@ -59,3 +60,50 @@ pub(super) fn read_this_row_group(
     }
     Ok(true)
 }
+
+/// Evaluate the predicate against the dictionaries of the fully dictionary-encoded string
+/// columns it depends on, e.g. to skip row groups that don't contain the literal of an
+/// equality or `is_in` predicate.
+///
+/// Contrary to [`read_this_row_group`] this reads the dictionary pages of the row group, so
+/// it should only be called once the statistics were not sufficient to skip it.
+pub(super) fn read_this_row_group_dictionaries(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchemaRef,
+    store: &ColumnStore,
+) -> PolarsResult<bool> {
+    // Fetched stores only hold the projected columns and the data is already downloaded.
+    let (Some(pred), ColumnStore::Local(_)) = (predicate, store) else {
+        return Ok(true);
+    };
+    let (Some(evaluator), Some(live_columns)) = (pred.as_stats_evaluator(), pred.live_variables())
+    else {
+        return Ok(true);
+    };
+
+    let mut stats = Vec::with_capacity(schema.fields.len());
+    let mut has_dictionary = false;
+    for field in schema.fields.iter() {
+        let mut column_stats = ColumnStats::from_arrow_stats(deserialize(field, md)?, field);
+        if live_columns.iter().any(|name| name.as_ref() == field.name) {
+            // Nested columns consist of multiple column chunks and are not considered.
+            if let [(column_md, chunk)] = mmap_columns(store, md.columns(), &field.name)[..] {
+                if let Some(dictionary) =
+                    read_dictionary_values(column_md, chunk, field.data_type.clone())?
+                {
+                    let dictionary = Series::try_from((field.name.as_str(), dictionary))?;
+                    column_stats = column_stats.with_dictionary(dictionary);
+                    has_dictionary = true;
+                }
+            }
+        }
+        stats.push(column_stats);
+    }
+    if !has_dictionary {
+        return Ok(true);
+    }
+
+    let stats = BatchStats::new(Arc::new(schema.into()), stats, Some(md.num_rows()));
+    Ok(!matches!(evaluator.should_read(&stats), Ok(false)))
+}
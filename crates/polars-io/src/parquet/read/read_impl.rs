@@ -5,6 +5,7 @@ use std::ops::{Deref, Range};
 use arrow::array::new_empty_array;
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::POOL;
 use polars_parquet::read::{self, ArrayIter, FileMetaData, PhysicalType, RowGroupMetaData};
@@ -13,13 +14,15 @@ use rayon::prelude::*;
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
-use super::predicates::read_this_row_group;
+use super::predicates::{read_this_row_group, read_this_row_group_dictionaries};
 use super::to_metadata::ToMetadata;
 use super::utils::materialize_empty_df;
 use super::{mmap, ParallelStrategy};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::parquet::metadata::FileMetaDataRef;
 use crate::predicates::{apply_predicate, PhysicalIoExpr};
+#[cfg(feature = "range_reads")]
+use crate::range_reader::RangeSource;
 use crate::utils::get_reader_bytes;
 use crate::RowIndex;
 
@@ -73,6 +76,18 @@ fn column_idx_to_series(
         array_iter_to_series(iter, field, None)
     }?;
 
+    // Reuse the sort order that the writer recorded for this row group, so that we don't have
+    // to sort it again. Only the first sorting column is sorted over the whole row group.
+    if let Some(sorting) = md.sorting_columns().first() {
+        if md.columns()[sorting.column_idx].descriptor().path_in_schema == [&*field.name] {
+            series.set_sorted_flag(if sorting.descending {
+                IsSorted::Descending
+            } else {
+                IsSorted::Ascending
+            });
+        }
+    }
+
     // See if we can find some statistics for this series. If we cannot find anything just return
     // the series as is.
     let Some(Ok(stats)) = md.columns()[column_i].statistics() else {
@@ -262,7 +277,8 @@ fn rg_to_dfs_optionally_par_over_columns(
         let current_row_count = md.num_rows() as IdxSize;
 
         if use_statistics
-            && !read_this_row_group(predicate, &file_metadata.row_groups[rg_idx], schema)?
+            && (!read_this_row_group(predicate, md, schema)?
+                || !read_this_row_group_dictionaries(predicate, md, schema, store)?)
         {
             *previous_row_count += current_row_count;
             continue;
@@ -372,11 +388,11 @@ fn rg_to_dfs_par_over_rg(
             .map(|(rg_idx, md, projection_height, row_count_start)| {
                 if projection_height == 0
                     || use_statistics
-                        && !read_this_row_group(
+                        && (!read_this_row_group(
                             predicate,
                             &file_metadata.row_groups[rg_idx],
                             schema,
-                        )?
+                        )? || !read_this_row_group_dictionaries(predicate, md, schema, store)?)
                 {
                     return Ok(None);
                 }
@@ -483,25 +499,50 @@ pub fn read_parquet<R: MmapBytesReader>(
         parallel = ParallelStrategy::None;
     }
 
-    let reader = ReaderBytes::from(&mut reader);
-    let bytes = reader.deref();
-    let store = mmap::ColumnStore::Local(bytes);
-
-    let dfs = rg_to_dfs(
-        &store,
-        &mut 0,
-        0,
-        n_row_groups,
-        &mut limit,
-        &file_metadata,
-        reader_schema,
-        predicate,
-        row_index.clone(),
-        parallel,
-        &materialized_projection,
-        use_statistics,
-        hive_partition_columns,
-    )?;
+    // Only fetch the projected columns of the row groups within the limit.
+    #[cfg(feature = "range_reads")]
+    let fetch_row_group_end =
+        compute_row_group_range(0, n_row_groups, limit, &file_metadata.row_groups);
+
+    let mut read_row_groups = |store: &ColumnStore, row_group_end: usize| {
+        rg_to_dfs(
+            store,
+            &mut 0,
+            0,
+            row_group_end,
+            &mut limit,
+            &file_metadata,
+            reader_schema,
+            predicate,
+            row_index.clone(),
+            parallel,
+            &materialized_projection,
+            use_statistics,
+            hive_partition_columns,
+        )
+    };
+
+    #[cfg(feature = "range_reads")]
+    let dfs = match reader.to_range_source() {
+        Some(source) => {
+            let store = fetch_column_chunks(
+                source,
+                &file_metadata.row_groups[..fetch_row_group_end],
+                reader_schema,
+                &materialized_projection,
+            )?;
+            read_row_groups(&store, fetch_row_group_end)?
+        },
+        None => {
+            let reader = ReaderBytes::from(&mut reader);
+            read_row_groups(&mmap::ColumnStore::Local(reader.deref()), n_row_groups)?
+        },
+    };
+    #[cfg(not(feature = "range_reads"))]
+    let dfs = {
+        let reader = ReaderBytes::from(&mut reader);
+        read_row_groups(&mmap::ColumnStore::Local(reader.deref()), n_row_groups)?
+    };
 
     if dfs.is_empty() {
         Ok(materialize_empty_df(
@@ -515,6 +556,37 @@ pub fn read_parquet<R: MmapBytesReader>(
     }
 }
 
+/// Fetch the column chunks of the `projection` in `row_groups` from `source`.
+#[cfg(feature = "range_reads")]
+fn fetch_column_chunks(
+    source: &dyn RangeSource,
+    row_groups: &[RowGroupMetaData],
+    schema: &ArrowSchema,
+    projection: &[usize],
+) -> PolarsResult<ColumnStore<'static>> {
+    let ranges = row_groups
+        .iter()
+        .flat_map(|rg| {
+            projection.iter().flat_map(|i| {
+                read::get_field_columns(rg.columns(), &schema.fields[*i].name)
+                    .into_iter()
+                    .map(|meta| {
+                        let (start, len) = meta.byte_range();
+                        start..start + len
+                    })
+            })
+        })
+        .collect::<Vec<_>>();
+    let chunks = source.fetch_ranges(&ranges)?;
+    Ok(ColumnStore::Fetched(
+        ranges
+            .iter()
+            .zip(chunks)
+            .map(|(range, chunk)| (range.start, chunk.into()))
+            .collect(),
+    ))
+}
+
 pub struct FetchRowGroupsFromMmapReader(ReaderBytes<'static>);
 
 impl FetchRowGroupsFromMmapReader {
@@ -779,6 +851,9 @@ impl BatchedParquetReader {
                     self.limit = limit;
                     dfs
                 },
+                // Only the object store fetcher returns fetched column chunks.
+                #[cfg(all(feature = "range_reads", not(feature = "async")))]
+                ColumnStore::Fetched(_) => unreachable!(),
             }?;
 
             self.row_group_offset += n;
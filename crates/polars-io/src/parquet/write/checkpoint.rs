@@ -0,0 +1,65 @@
+use std::path::{Path, PathBuf};
+
+use polars_error::{polars_bail, polars_err, PolarsResult};
+
+const CHECKPOINT_FILE: &str = "_checkpoint";
+
+/// The progress of a query that writes its result as parts to a directory.
+///
+/// The parts are named `part-00000.parquet`, `part-00001.parquet`, ..., so that a glob over the
+/// directory reads them in order. A checkpoint is only stored after the parts it counts are
+/// complete, so a directory with a checkpoint never has to be rewritten.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// Identifies the query that writes the parts.
+    pub fingerprint: u64,
+    /// The number of complete parts.
+    pub parts: usize,
+    /// The number of rows in the complete parts.
+    pub rows: usize,
+    /// Whether all parts are written.
+    pub complete: bool,
+}
+
+impl Checkpoint {
+    pub fn part_path(dir: &Path, part: usize) -> PathBuf {
+        dir.join(format!("part-{part:05}.parquet"))
+    }
+
+    /// Load the checkpoint in `dir`, if there is one.
+    pub fn load(dir: &Path) -> PolarsResult<Option<Self>> {
+        let path = dir.join(CHECKPOINT_FILE);
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut checkpoint = Self::default();
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            let invalid = || polars_err!(ComputeError: "invalid checkpoint line: '{}'", line);
+            let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+            match key {
+                "fingerprint" => checkpoint.fingerprint = value.parse().map_err(|_| invalid())?,
+                "parts" => checkpoint.parts = value.parse().map_err(|_| invalid())?,
+                "rows" => checkpoint.rows = value.parse().map_err(|_| invalid())?,
+                "complete" => checkpoint.complete = value.parse().map_err(|_| invalid())?,
+                _ => polars_bail!(ComputeError: "unknown checkpoint key: '{}'", key),
+            }
+        }
+        Ok(Some(checkpoint))
+    }
+
+    /// Store the checkpoint in `dir`. The checkpoint is replaced atomically, so an interrupted
+    /// store leaves the previous checkpoint.
+    pub fn store(&self, dir: &Path) -> PolarsResult<()> {
+        let text = format!(
+            "fingerprint={}\nparts={}\nrows={}\ncomplete={}\n",
+            self.fingerprint, self.parts, self.rows, self.complete
+        );
+        let tmp = dir.join(format!("{CHECKPOINT_FILE}.tmp"));
+        std::fs::write(&tmp, text)?;
+        std::fs::rename(tmp, dir.join(CHECKPOINT_FILE))?;
+        Ok(())
+    }
+}
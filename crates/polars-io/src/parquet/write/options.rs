@@ -1,3 +1,7 @@
+use std::io::Write;
+use std::path::PathBuf;
+
+use polars_core::frame::DataFrame;
 use polars_error::PolarsResult;
 use polars_parquet::write::{
     BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
@@ -6,6 +10,9 @@ use polars_parquet::write::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::ParquetWriter;
+use crate::{SerWriter, WriterFactory};
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
@@ -21,6 +28,51 @@ pub struct ParquetWriteOptions {
     pub maintain_order: bool,
 }
 
+impl ParquetWriteOptions {
+    /// Create a [`ParquetWriter`] of `writer` with these options.
+    pub fn to_writer<W: Write>(&self, writer: W) -> ParquetWriter<W> {
+        ParquetWriter::new(writer)
+            .with_compression(self.compression)
+            .with_statistics(self.statistics)
+            .with_row_group_size(self.row_group_size)
+            .with_data_page_size(self.data_pagesize_limit)
+    }
+}
+
+/// A [`ParquetWriter`] behind the [`SerWriter`] interface, which writes a single DataFrame.
+struct FactoryParquetWriter<W: Write> {
+    writer: Option<ParquetWriter<W>>,
+}
+
+impl<W: Write> SerWriter<W> for FactoryParquetWriter<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer: Some(ParquetWriter::new(writer)),
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let writer = self
+            .writer
+            .take()
+            .expect("a parquet file is written only once");
+        writer.finish(df)?;
+        Ok(())
+    }
+}
+
+impl WriterFactory for ParquetWriteOptions {
+    fn create_writer<W: Write + 'static>(&self, writer: W) -> Box<dyn SerWriter<W>> {
+        Box::new(FactoryParquetWriter {
+            writer: Some(self.to_writer(writer)),
+        })
+    }
+
+    fn extension(&self) -> PathBuf {
+        PathBuf::from("parquet")
+    }
+}
+
 /// The compression strategy to use for writing Parquet files.
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -11,7 +11,7 @@ use polars_parquet::write::{
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
 use crate::prelude::chunk_df_for_writing;
-use crate::shared::schema_to_arrow_checked;
+use crate::shared::{df_to_arrow_schema_checked, schema_to_arrow_checked};
 
 /// Write a DataFrame to Parquet format.
 #[must_use]
@@ -84,6 +84,10 @@ where
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, true, "parquet")?;
+        self.batched_arrow(schema)
+    }
+
+    fn batched_arrow(self, schema: ArrowSchema) -> PolarsResult<BatchedWriter<W>> {
         let parquet_schema = to_parquet_schema(&schema)?;
         let encodings = get_encodings(&schema);
         let options = self.materialize_options();
@@ -110,7 +114,8 @@ where
     /// Write the given DataFrame in the writer `W`. Returns the total size of the file.
     pub fn finish(self, df: &mut DataFrame) -> PolarsResult<u64> {
         let chunked_df = chunk_df_for_writing(df, self.row_group_size.unwrap_or(512 * 512))?;
-        let mut batched = self.batched(&chunked_df.schema())?;
+        let schema = df_to_arrow_schema_checked(&chunked_df, true, "parquet")?;
+        let mut batched = self.batched_arrow(schema)?;
         batched.write_batch(&chunked_df)?;
         batched.finish()
     }
@@ -9,6 +9,8 @@ use polars_core::series::IsSorted;
 use polars_core::POOL;
 use rayon::prelude::*;
 
+#[cfg(feature = "parquet")]
+use crate::parquet::DatasetStatistics;
 use crate::utils::resolve_homedir;
 use crate::WriterFactory;
 
@@ -32,6 +34,8 @@ pub struct PartitionedWriter<F> {
     rootdir: PathBuf,
     by: Vec<String>,
     parallel: bool,
+    #[cfg(feature = "parquet")]
+    statistics_sidecar: bool,
 }
 
 impl<F> PartitionedWriter<F>
@@ -49,6 +53,8 @@ where
             rootdir: rootdir.into(),
             by: by.into_iter().map(|s| s.as_ref().to_string()).collect(),
             parallel: true,
+            #[cfg(feature = "parquet")]
+            statistics_sidecar: false,
         }
     }
 
@@ -58,7 +64,20 @@ where
         self
     }
 
-    fn write_partition_df(&self, partition_df: &mut DataFrame, i: usize) -> PolarsResult<()> {
+    /// Also write a [`DatasetStatistics`] sidecar with the statistics of every file into the
+    /// root directory, which `scan_parquet` can use to plan without opening every file.
+    #[cfg(feature = "parquet")]
+    pub fn with_statistics_sidecar(mut self, statistics_sidecar: bool) -> Self {
+        self.statistics_sidecar = statistics_sidecar;
+        self
+    }
+
+    /// Write a partition and return the statistics of its file if the sidecar is written.
+    fn write_partition_df(
+        &self,
+        partition_df: &mut DataFrame,
+        i: usize,
+    ) -> PolarsResult<Option<DataFrame>> {
         let mut path = resolve_partition_dir(&self.rootdir, &self.by, partition_df);
         std::fs::create_dir_all(&path)?;
 
@@ -68,12 +87,23 @@ where
             self.option.extension().display()
         ));
 
-        let file = std::fs::File::create(path)?;
+        let file = std::fs::File::create(&path)?;
         let writer = BufWriter::new(file);
 
         self.option
             .create_writer::<BufWriter<File>>(writer)
-            .finish(partition_df)
+            .finish(partition_df)?;
+
+        #[cfg(feature = "parquet")]
+        if self.statistics_sidecar {
+            let root = resolve_homedir(&self.rootdir);
+            let relative =
+                path.strip_prefix(&root).unwrap().to_str().ok_or_else(
+                    || polars_err!(ComputeError: "non-UTF-8 path '{}'", path.display()),
+                )?;
+            return DatasetStatistics::file_statistics(relative, partition_df).map(Some);
+        }
+        Ok(None)
     }
 
     pub fn finish(self, df: &DataFrame) -> PolarsResult<()> {
@@ -82,7 +112,8 @@ where
 
         // don't parallelize this
         // there is a lot of parallelization in take and this may easily SO
-        POOL.install(|| {
+        #[cfg_attr(not(feature = "parquet"), allow(unused_variables))]
+        let statistics = POOL.install(|| {
             match groups {
                 GroupsProxy::Idx(idx) => {
                     idx.par_iter()
@@ -108,6 +139,14 @@ where
             }
         })?;
 
+        #[cfg(feature = "parquet")]
+        if self.statistics_sidecar {
+            let root = resolve_homedir(&self.rootdir);
+            let files = statistics.into_iter().flatten().collect::<Vec<_>>();
+            if !files.is_empty() {
+                DatasetStatistics::new(&root, files)?.write()?;
+            }
+        }
         Ok(())
     }
 }
@@ -13,6 +13,11 @@ pub trait PhysicalIoExpr: Send + Sync {
     fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
         None
     }
+
+    /// Returns the names of the columns the predicate depends on, if known.
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        None
+    }
 }
 
 pub trait StatsEvaluator {
@@ -52,6 +57,9 @@ pub struct ColumnStats {
     null_count: Option<Series>,
     min_value: Option<Series>,
     max_value: Option<Series>,
+    // All distinct values of the column, if known. E.g. the dictionary of a fully
+    // dictionary-encoded parquet column chunk.
+    dictionary: Option<Series>,
 }
 
 impl ColumnStats {
@@ -67,6 +75,7 @@ impl ColumnStats {
             null_count,
             min_value,
             max_value,
+            dictionary: None,
         }
     }
 
@@ -77,6 +86,7 @@ impl ColumnStats {
             null_count: None,
             min_value: None,
             max_value: None,
+            dictionary: None,
         }
     }
 
@@ -88,9 +98,16 @@ impl ColumnStats {
             null_count: None,
             min_value: Some(s.clone()),
             max_value: Some(s),
+            dictionary: None,
         }
     }
 
+    /// Sets the distinct values that can occur in the column.
+    pub fn with_dictionary(mut self, dictionary: Series) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+
     pub fn field_name(&self) -> &SmartString {
         self.field.name()
     }
@@ -115,6 +132,11 @@ impl ColumnStats {
         self.max_value.as_ref()
     }
 
+    /// Returns all distinct values of the column, if known.
+    pub fn get_dictionary_state(&self) -> Option<&Series> {
+        self.dictionary.as_ref()
+    }
+
     /// Returns the null count of the column.
     pub fn null_count(&self) -> Option<usize> {
         match self.dtype() {
@@ -189,7 +211,7 @@ impl ColumnStats {
 }
 
 /// Returns whether the [`DataType`] supports minimum/maximum operations.
-fn use_min_max(dtype: &DataType) -> bool {
+pub fn use_min_max(dtype: &DataType) -> bool {
     dtype.is_numeric()
         || matches!(
             dtype,
@@ -9,5 +9,7 @@ pub use crate::json::*;
 pub use crate::ndjson::core::*;
 #[cfg(feature = "parquet")]
 pub use crate::parquet::{metadata::*, read::*, write::*};
+#[cfg(feature = "range_reads")]
+pub use crate::range_reader::{CallbackSource, RangeReader, RangeSource};
 pub use crate::shared::{SerReader, SerWriter};
 pub use crate::utils::*;
@@ -0,0 +1,165 @@
+//! Readers over files that are fetched in byte ranges on demand, e.g. with HTTP range requests.
+//!
+//! This doesn't need an async runtime, a file system or memory mapping, so it also works under
+//! wasm, where the ranges are typically fetched by a JavaScript callback, see
+//! [`CallbackSource`].
+//!
+//! A [`RangeReader`] can be passed to the Parquet and IPC readers. The Parquet reader only fetches
+//! the column chunks of the projected columns in the row groups it reads, the IPC reader only
+//! fetches the record batches it reads.
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+
+use polars_error::{polars_ensure, PolarsResult};
+
+use crate::mmap::MmapBytesReader;
+
+/// A file of which byte ranges can be fetched.
+pub trait RangeSource: Send + Sync {
+    /// The size of the file in bytes.
+    fn size(&self) -> PolarsResult<u64>;
+
+    /// Fetch the bytes in `range`.
+    fn fetch(&self, range: Range<u64>) -> PolarsResult<Vec<u8>>;
+
+    /// Fetch the bytes in each of `ranges`. Sources that can fetch ranges concurrently or in a
+    /// single request should override this.
+    fn fetch_ranges(&self, ranges: &[Range<u64>]) -> PolarsResult<Vec<Vec<u8>>> {
+        ranges
+            .iter()
+            .map(|range| self.fetch(range.clone()))
+            .collect()
+    }
+}
+
+pub type FetchCallback = Box<dyn Fn(Range<u64>) -> PolarsResult<Vec<u8>> + Send + Sync>;
+
+/// A [`RangeSource`] that fetches the ranges with a callback.
+///
+/// Bindings for the browser pass a JavaScript function here that issues a `fetch` with a `Range`
+/// header, or reads a slice of a `File` or `Blob`.
+pub struct CallbackSource {
+    size: u64,
+    fetch: FetchCallback,
+}
+
+impl CallbackSource {
+    /// A source of `size` bytes of which `fetch` returns the bytes in a range.
+    pub fn new<F>(size: u64, fetch: F) -> Self
+    where
+        F: Fn(Range<u64>) -> PolarsResult<Vec<u8>> + Send + Sync + 'static,
+    {
+        Self {
+            size,
+            fetch: Box::new(fetch),
+        }
+    }
+}
+
+impl RangeSource for CallbackSource {
+    fn size(&self) -> PolarsResult<u64> {
+        Ok(self.size)
+    }
+
+    fn fetch(&self, range: Range<u64>) -> PolarsResult<Vec<u8>> {
+        let expected = range.end - range.start;
+        let bytes = (self.fetch)(range)?;
+        polars_ensure!(
+            bytes.len() as u64 == expected,
+            ComputeError: "range fetch returned {} bytes, expected {}", bytes.len(), expected
+        );
+        Ok(bytes)
+    }
+}
+
+/// The default number of bytes that a [`RangeReader`] fetches at once.
+const DEFAULT_BLOCK_SIZE: usize = 64 * 1024;
+
+/// A [`Read`] and [`Seek`] over a [`RangeSource`], that fetches blocks of bytes as they are read.
+pub struct RangeReader<S: RangeSource> {
+    source: S,
+    size: u64,
+    position: u64,
+    block_size: usize,
+    buffer: Vec<u8>,
+    buffer_start: u64,
+}
+
+impl<S: RangeSource> RangeReader<S> {
+    pub fn new(source: S) -> PolarsResult<Self> {
+        let size = source.size()?;
+        Ok(Self {
+            source,
+            size,
+            position: 0,
+            block_size: DEFAULT_BLOCK_SIZE,
+            buffer: vec![],
+            buffer_start: 0,
+        })
+    }
+
+    /// The minimum number of bytes to fetch at once. Larger blocks need fewer requests for small
+    /// reads, e.g. of the metadata, but may fetch bytes that are never read.
+    pub fn with_block_size(mut self, block_size: usize) -> Self {
+        self.block_size = block_size.max(1);
+        self
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    fn buffered(&self) -> &[u8] {
+        let buffer_end = self.buffer_start + self.buffer.len() as u64;
+        if (self.buffer_start..buffer_end).contains(&self.position) {
+            &self.buffer[(self.position - self.buffer_start) as usize..]
+        } else {
+            &[]
+        }
+    }
+}
+
+impl<S: RangeSource> Read for RangeReader<S> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.position >= self.size {
+            return Ok(0);
+        }
+        if self.buffered().is_empty() {
+            let len = buf.len().max(self.block_size) as u64;
+            let end = (self.position + len).min(self.size);
+            self.buffer = self
+                .source
+                .fetch(self.position..end)
+                .map_err(std::io::Error::other)?;
+            self.buffer_start = self.position;
+        }
+        let buffered = self.buffered();
+        let n = buffered.len().min(buf.len());
+        buf[..n].copy_from_slice(&buffered[..n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl<S: RangeSource> Seek for RangeReader<S> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.size.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        self.position = position.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
+
+impl<S: RangeSource> MmapBytesReader for RangeReader<S> {
+    fn to_range_source(&self) -> Option<&dyn RangeSource> {
+        Some(&self.source)
+    }
+}
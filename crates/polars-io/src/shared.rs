@@ -121,12 +121,34 @@ pub(crate) fn finish_reader<R: ArrowReader>(
 pub(crate) fn schema_to_arrow_checked(
     schema: &Schema,
     pl_flavor: bool,
+    file_name: &str,
+) -> PolarsResult<ArrowSchema> {
+    fields_to_arrow_checked(schema.iter_fields(), pl_flavor, file_name)
+}
+
+/// Like [`schema_to_arrow_checked`], but keeps the extension types the columns are tagged with.
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+pub(crate) fn df_to_arrow_schema_checked(
+    df: &DataFrame,
+    pl_flavor: bool,
+    file_name: &str,
+) -> PolarsResult<ArrowSchema> {
+    fields_to_arrow_checked(
+        df.get_columns().iter().map(|s| s.field().into_owned()),
+        pl_flavor,
+        file_name,
+    )
+}
+
+fn fields_to_arrow_checked(
+    fields: impl Iterator<Item = Field>,
+    pl_flavor: bool,
     _file_name: &str,
 ) -> PolarsResult<ArrowSchema> {
-    let fields = schema.iter_fields().map(|field| {
+    let fields = fields.map(|field| {
         #[cfg(feature = "object")]
         polars_ensure!(!matches!(field.data_type(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to {}", _file_name);
-        Ok(field.data_type().to_arrow_field(field.name().as_str(), pl_flavor))
+        Ok(field.to_arrow(pl_flavor))
     }).collect::<PolarsResult<Vec<_>>>()?;
     Ok(ArrowSchema::from(fields))
 }
@@ -1,6 +1,6 @@
 #[cfg(any(feature = "ipc_streaming", feature = "parquet"))]
 use std::borrow::Cow;
-use std::io::Read;
+use std::io::{Cursor, Read};
 use std::path::{Path, PathBuf};
 
 use once_cell::sync::Lazy;
@@ -9,7 +9,7 @@ use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df_as_ref};
 use regex::{Regex, RegexBuilder};
 
-use crate::mmap::{MmapBytesReader, ReaderBytes};
+use crate::mmap::{try_mmap, MmapBytesReader, ReaderBytes};
 
 pub static POLARS_TEMP_DIR_BASE_PATH: Lazy<Box<Path>> = Lazy::new(|| {
     let path = std::env::var("POLARS_TEMP_DIR")
@@ -47,29 +47,37 @@ pub(crate) fn ensure_directory_init(path: &Path) -> std::io::Result<()> {
 pub fn get_reader_bytes<'a, R: Read + MmapBytesReader + ?Sized>(
     reader: &'a mut R,
 ) -> PolarsResult<ReaderBytes<'a>> {
-    // we have a file so we can mmap
-    if let Some(file) = reader.to_file() {
-        let mmap = unsafe { memmap::Mmap::map(file)? };
-
+    // we have a file so we can mmap, unless it is a pipe or a character device, e.g. stdin
+    let mapped = reader.to_file().and_then(|file| {
         // somehow bck thinks borrows alias
         // this is sound as file was already bound to 'a
         use std::fs::File;
         let file = unsafe { std::mem::transmute::<&File, &'a File>(file) };
+        Some((try_mmap(file)?, file))
+    });
+    if let Some((mmap, file)) = mapped {
         Ok(ReaderBytes::Mapped(mmap, file))
-    } else {
+    } else if reader.to_bytes().is_some() {
         // we can get the bytes for free
-        if reader.to_bytes().is_some() {
-            // duplicate .to_bytes() is necessary to satisfy the borrow checker
-            Ok(ReaderBytes::Borrowed((*reader).to_bytes().unwrap()))
-        } else {
-            // we have to read to an owned buffer to get the bytes.
-            let mut bytes = Vec::with_capacity(1024 * 128);
-            reader.read_to_end(&mut bytes)?;
-            Ok(ReaderBytes::Owned(bytes))
-        }
+        // duplicate .to_bytes() is necessary to satisfy the borrow checker
+        Ok(ReaderBytes::Borrowed((*reader).to_bytes().unwrap()))
+    } else {
+        // we have to read to an owned buffer to get the bytes.
+        let mut bytes = Vec::with_capacity(1024 * 128);
+        reader.read_to_end(&mut bytes)?;
+        Ok(ReaderBytes::Owned(bytes))
     }
 }
 
+/// Read all of `reader` into memory, so that input that can be neither memory mapped nor
+/// seeked, e.g. stdin or a pipe, can be passed to any of the readers that take a
+/// [`MmapBytesReader`].
+pub fn read_to_bytes_reader<R: Read>(mut reader: R) -> PolarsResult<Cursor<Vec<u8>>> {
+    let mut bytes = Vec::with_capacity(1024 * 128);
+    reader.read_to_end(&mut bytes)?;
+    Ok(Cursor::new(bytes))
+}
+
 // used by python polars
 pub fn resolve_homedir(path: &Path) -> PathBuf {
     // replace "~" with home directory
@@ -11,8 +11,8 @@ use arrow::record_batch::RecordBatchT;
 pub use fallible_streaming_iterator::*;
 use polars_error::{PolarsError, PolarsResult};
 pub(crate) use serialize::new_serializer;
-use serialize::serialize;
-pub use utf8::serialize_to_utf8;
+pub use serialize::{serialize, serialize_rows};
+pub use utf8::{serialize_to_utf8, write_str};
 
 /// [`FallibleStreamingIterator`] that serializes an [`Array`] to bytes of valid JSON
 /// # Implementation
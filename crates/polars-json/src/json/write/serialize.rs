@@ -543,7 +543,7 @@ fn serialize_item<'a>(
 /// Serializes `array` to a valid JSON to `buffer`
 /// # Implementation
 /// This operation is CPU-bounded
-pub(crate) fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
+pub fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
     let mut serializer = new_serializer(array, 0, usize::MAX);
 
     (0..array.len()).for_each(|i| {
@@ -553,3 +553,60 @@ pub(crate) fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
         buffer.extend_from_slice(serializer.next().unwrap());
     });
 }
+
+/// Serializes the rows of `arrays`, which must all have the same length, to `buffer`. Rows are
+/// separated by `separator`.
+///
+/// If `names` is given, every row is written as an object with those keys, otherwise as an array
+/// of its values. With `skip_nulls`, keys of which the value is null are left out of the objects.
+/// # Implementation
+/// This operation is CPU-bounded
+pub fn serialize_rows(
+    arrays: &[&dyn Array],
+    names: Option<&[&str]>,
+    skip_nulls: bool,
+    separator: &[u8],
+    buffer: &mut Vec<u8>,
+) {
+    let len = arrays.first().map_or(0, |arr| arr.len());
+    let mut serializers = arrays
+        .iter()
+        .map(|arr| new_serializer(*arr, 0, usize::MAX))
+        .collect::<Vec<_>>();
+
+    for i in 0..len {
+        if i != 0 {
+            buffer.extend_from_slice(separator);
+        }
+        match names {
+            Some(names) => {
+                buffer.push(b'{');
+                let mut first_item = true;
+                for ((arr, serializer), name) in arrays.iter().zip(&mut serializers).zip(names) {
+                    let value = serializer.next().unwrap();
+                    if skip_nulls && arr.is_null(i) {
+                        continue;
+                    }
+                    if !first_item {
+                        buffer.push(b',');
+                    }
+                    first_item = false;
+                    utf8::write_str(buffer, name).unwrap();
+                    buffer.push(b':');
+                    buffer.extend_from_slice(value);
+                }
+                buffer.push(b'}');
+            },
+            None => {
+                buffer.push(b'[');
+                for (j, serializer) in serializers.iter_mut().enumerate() {
+                    if j != 0 {
+                        buffer.push(b',');
+                    }
+                    buffer.extend_from_slice(serializer.next().unwrap());
+                }
+                buffer.push(b']');
+            },
+        }
+    }
+}
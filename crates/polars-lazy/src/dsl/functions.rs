@@ -49,6 +49,31 @@ pub fn concat_lf_diagonal<L: AsRef<[LazyFrame]>>(
     concat_impl(inputs, args)
 }
 
+#[cfg(feature = "diagonal_concat")]
+/// Concat [LazyFrame]s vertically by column name.
+///
+/// The columns of every input are aligned by name to `schema`: they are cast to its dtypes,
+/// missing columns are filled with nulls and columns that are not in `schema` are dropped. On
+/// the streaming engine a union of scans is aligned batch by batch, so that it doesn't have to
+/// be materialized.
+pub fn concat_lf_by_name<L: AsRef<[LazyFrame]>>(
+    inputs: L,
+    schema: SchemaRef,
+    args: UnionArgs,
+) -> PolarsResult<LazyFrame> {
+    let inputs = inputs
+        .as_ref()
+        .iter()
+        .map(|lf| {
+            lf.clone()
+                .map_private(DslFunction::FunctionNode(FunctionNode::AlignByName {
+                    schema: schema.clone(),
+                }))
+        })
+        .collect::<Vec<_>>();
+    concat_impl(inputs, args)
+}
+
 /// Concat [LazyFrame]s horizontally.
 pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
     inputs: L,
@@ -0,0 +1,281 @@
+//! Partial aggregates of a group by that can be stored and combined with the aggregates of new
+//! data, see [`LazyGroupBy::aggregate_state`].
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_io::parquet::write::ParquetWriter;
+
+use crate::prelude::*;
+
+/// How an aggregation is stored, combined and finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StateKind {
+    Sum,
+    Count {
+        include_nulls: bool,
+    },
+    Len,
+    Min {
+        propagate_nans: bool,
+    },
+    Max {
+        propagate_nans: bool,
+    },
+    /// Stored as a sum and a count.
+    Mean,
+    First,
+    Last,
+}
+
+#[derive(Clone, Debug)]
+struct StateAgg {
+    input: Expr,
+    kind: StateKind,
+    name: String,
+}
+
+impl StateAgg {
+    fn try_new(expr: &Expr) -> PolarsResult<Self> {
+        let name = expr_output_name(expr)?.to_string();
+        let agg = match expr {
+            Expr::Alias(input, _) => input.as_ref(),
+            expr => expr,
+        };
+        let (input, kind) = match agg {
+            Expr::Len => (Expr::Len, StateKind::Len),
+            Expr::Agg(agg) => {
+                let kind = match agg {
+                    AggExpr::Sum(_) => StateKind::Sum,
+                    AggExpr::Count(_, include_nulls) => StateKind::Count {
+                        include_nulls: *include_nulls,
+                    },
+                    AggExpr::Min { propagate_nans, .. } => StateKind::Min {
+                        propagate_nans: *propagate_nans,
+                    },
+                    AggExpr::Max { propagate_nans, .. } => StateKind::Max {
+                        propagate_nans: *propagate_nans,
+                    },
+                    AggExpr::Mean(_) => StateKind::Mean,
+                    AggExpr::First(_) => StateKind::First,
+                    AggExpr::Last(_) => StateKind::Last,
+                    _ => polars_bail!(
+                        InvalidOperation: "aggregation '{:?}' cannot be stored as a partial state", expr
+                    ),
+                };
+                (agg.as_ref().clone(), kind)
+            },
+            _ => polars_bail!(
+                InvalidOperation: "expected an aggregation of a single expression, got '{:?}'", expr
+            ),
+        };
+        Ok(Self { input, kind, name })
+    }
+
+    fn sum_name(&self) -> String {
+        format!("{}__sum", self.name)
+    }
+
+    fn count_name(&self) -> String {
+        format!("{}__count", self.name)
+    }
+
+    /// The aggregations of the data to the state columns.
+    fn partial(&self, out: &mut Vec<Expr>) {
+        let input = self.input.clone();
+        let name = self.name.as_str();
+        match self.kind {
+            StateKind::Sum => out.push(input.sum().alias(name)),
+            StateKind::Count { include_nulls } => {
+                out.push(Expr::Agg(AggExpr::Count(Arc::new(input), include_nulls)).alias(name))
+            },
+            StateKind::Len => out.push(len().alias(name)),
+            StateKind::Min { propagate_nans } => out.push(
+                Expr::Agg(AggExpr::Min {
+                    input: Arc::new(input),
+                    propagate_nans,
+                })
+                .alias(name),
+            ),
+            StateKind::Max { propagate_nans } => out.push(
+                Expr::Agg(AggExpr::Max {
+                    input: Arc::new(input),
+                    propagate_nans,
+                })
+                .alias(name),
+            ),
+            StateKind::Mean => {
+                out.push(input.clone().sum().alias(&self.sum_name()));
+                out.push(input.count().alias(&self.count_name()));
+            },
+            StateKind::First => out.push(input.first().alias(name)),
+            StateKind::Last => out.push(input.last().alias(name)),
+        }
+    }
+
+    /// The aggregations of the state columns of several states to a single state.
+    fn combine(&self, out: &mut Vec<Expr>) {
+        let name = self.name.as_str();
+        match self.kind {
+            StateKind::Sum | StateKind::Count { .. } | StateKind::Len => out.push(col(name).sum()),
+            StateKind::Min { .. } => out.push(col(name).min()),
+            StateKind::Max { .. } => out.push(col(name).max()),
+            StateKind::Mean => {
+                out.push(col(&self.sum_name()).sum());
+                out.push(col(&self.count_name()).sum());
+            },
+            StateKind::First => out.push(col(name).first()),
+            StateKind::Last => out.push(col(name).last()),
+        }
+    }
+
+    /// The result of the aggregation from the state columns.
+    fn finish(&self) -> Expr {
+        match self.kind {
+            StateKind::Mean => {
+                let count = col(&self.count_name());
+                when(count.clone().eq(lit(0)))
+                    .then(lit(NULL).cast(DataType::Float64))
+                    .otherwise(
+                        col(&self.sum_name()).cast(DataType::Float64)
+                            / count.cast(DataType::Float64),
+                    )
+                    .alias(&self.name)
+            },
+            _ => col(&self.name),
+        }
+    }
+}
+
+/// The partial aggregates of a group by: a row per group with the keys and the state of every
+/// aggregation.
+///
+/// The state of the aggregates of new data can be combined with a stored state, so that
+/// incremental jobs don't aggregate the data they already aggregated again. Only aggregations
+/// that can be combined are supported: `sum`, `count`, `len`, `min`, `max`, `mean`, `first` and
+/// `last`.
+pub struct AggregateState {
+    state: LazyFrame,
+    keys: Vec<String>,
+    aggs: Vec<StateAgg>,
+}
+
+impl AggregateState {
+    /// The state, with the keys and the state columns of the aggregations.
+    pub fn state(&self) -> LazyFrame {
+        self.state.clone()
+    }
+
+    /// The keys and the results of the aggregations.
+    pub fn finish(self) -> LazyFrame {
+        let exprs = self
+            .keys
+            .iter()
+            .map(|key| col(key))
+            .chain(self.aggs.iter().map(StateAgg::finish))
+            .collect::<Vec<_>>();
+        self.state.select(exprs)
+    }
+
+    /// Compute the state and store it as a parquet file at `path`. The file is replaced
+    /// atomically, so the state can be stored at the path it was resumed from.
+    pub fn sink(self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        let path = path.as_ref();
+        let mut df = self.state.collect()?;
+
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        let file = std::fs::File::create(&tmp)?;
+        ParquetWriter::new(file).finish(&mut df)?;
+        std::fs::rename(tmp, path)?;
+        Ok(())
+    }
+}
+
+impl LazyGroupBy {
+    fn state_keys_and_aggs(&self, aggs: &[Expr]) -> PolarsResult<(Vec<String>, Vec<StateAgg>)> {
+        #[cfg(feature = "dynamic_group_by")]
+        polars_ensure!(
+            self.dynamic_options.is_none() && self.rolling_options.is_none(),
+            InvalidOperation: "the state of a dynamic or rolling group by cannot be stored"
+        );
+        let keys = self
+            .keys
+            .iter()
+            .map(|key| expr_output_name(key).map(|name| name.to_string()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let aggs = aggs
+            .iter()
+            .map(StateAgg::try_new)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok((keys, aggs))
+    }
+
+    /// The partial aggregates of this group by, which can be stored and combined with the
+    /// aggregates of new data, see [`AggregateState`].
+    ///
+    /// The state is computed with the streaming engine if possible.
+    pub fn aggregate_state<E: AsRef<[Expr]>>(self, aggs: E) -> PolarsResult<AggregateState> {
+        let (keys, aggs) = self.state_keys_and_aggs(aggs.as_ref())?;
+        let mut partial = vec![];
+        for agg in &aggs {
+            agg.partial(&mut partial);
+        }
+        let state = self.agg(partial).with_streaming(true);
+        Ok(AggregateState { state, keys, aggs })
+    }
+
+    /// Aggregate and store the partial aggregates at `path`, so that they can be resumed by
+    /// [`resume_from_state`](Self::resume_from_state).
+    pub fn sink_aggregate_state<E: AsRef<[Expr]>>(
+        self,
+        path: impl AsRef<Path>,
+        aggs: E,
+    ) -> PolarsResult<()> {
+        self.aggregate_state(aggs)?.sink(path)
+    }
+
+    /// Aggregate and combine the partial aggregates with the state stored at `path`.
+    ///
+    /// The keys and the aggregations must be the same as those of the stored state. If there is
+    /// no state at `path`, this is the state of this group by only.
+    pub fn resume_from_state<E: AsRef<[Expr]>>(
+        self,
+        path: impl AsRef<Path>,
+        aggs: E,
+    ) -> PolarsResult<AggregateState> {
+        let path = path.as_ref();
+        let new = self.aggregate_state(aggs)?;
+        if !path.exists() {
+            return Ok(new);
+        }
+
+        let mut stored = LazyFrame::scan_parquet(path, Default::default())?;
+        let stored_schema = stored.schema()?;
+        let new_schema = new.state.clone().schema()?;
+        polars_ensure!(
+            stored_schema.iter_names().eq(new_schema.iter_names()),
+            SchemaMismatch: "the state at '{}' was stored with other keys or aggregations",
+            path.display()
+        );
+
+        // The stored state goes first, so that `first` and `last` keep their order.
+        let states = concat(
+            [stored, new.state],
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )?;
+        let mut combined = vec![];
+        for agg in &new.aggs {
+            agg.combine(&mut combined);
+        }
+        let keys = new.keys.iter().map(|key| col(key)).collect::<Vec<_>>();
+        let state = states.group_by_stable(keys).agg(combined);
+        Ok(AggregateState {
+            state,
+            keys: new.keys,
+            aggs: new.aggs,
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use arrow::ffi::ArrowArrayStream;
+use polars_core::prelude::*;
+use polars_ffi::stream::{export_dataframes, import_stream};
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Scan the record batches of an Arrow [C stream](https://arrow.apache.org/docs/format/CStreamInterface.html).
+    ///
+    /// The stream is consumed immediately, but its buffers are shared with the producer instead
+    /// of copied.
+    ///
+    /// # Safety
+    /// `stream` must fulfill the C stream interface, and the arrays it produces the C data interface.
+    pub unsafe fn scan_arrow_stream(stream: ArrowArrayStream) -> PolarsResult<Self> {
+        Ok(import_stream(stream)?.lazy())
+    }
+
+    /// Export the result of this query as an Arrow [C stream](https://arrow.apache.org/docs/format/CStreamInterface.html).
+    ///
+    /// The query runs on the streaming engine when the consumer first reads from the stream, and
+    /// the chunks of the result are passed on without copying them. See
+    /// [`export_dataframe`](polars_ffi::stream::export_dataframe) for `pl_flavor`.
+    pub fn into_arrow_stream(self, pl_flavor: bool) -> PolarsResult<ArrowArrayStream> {
+        let schema = self.clone().schema()?;
+        let result = std::iter::once_with(move || self.with_streaming(true).collect());
+        Ok(export_dataframes(&schema, result, pl_flavor))
+    }
+}
@@ -0,0 +1,225 @@
+//! A versioned binary format for [`LazyFrame`] plans and [`DataFrame`]s, to cache them or ship
+//! them between services.
+//!
+//! # Layout
+//!
+//! | bytes   | content                                                       |
+//! |---------|---------------------------------------------------------------|
+//! | 8       | magic `PLBINFMT`                                              |
+//! | 2       | format version, little endian                                 |
+//! | 1       | payload kind: `0` for a [`DataFrame`], `1` for a plan         |
+//! | 4 + n   | length (little endian) and UTF-8 version of the writing polars |
+//! | rest    | payload, CBOR encoded                                         |
+//!
+//! The payload of a plan is the [`DslPlan`] together with the schema it resolved to when it was
+//! written. When the plan is read, it is resolved again and the schemas are compared, so that a
+//! plan whose meaning changed between versions is rejected instead of silently run.
+//!
+//! # Compatibility
+//!
+//! Readers accept every format version up to [`BINARY_FORMAT_VERSION`]. The format version is
+//! bumped whenever the encoding of a payload changes, and the decoder of every older version is
+//! kept. Version `0` is the headerless CBOR encoding written by earlier releases; data without
+//! the magic is read as version `0`.
+use std::io::{Chain, Cursor, Read, Write};
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The version of the binary format that is written.
+pub const BINARY_FORMAT_VERSION: u16 = 1;
+
+const MAGIC: &[u8; 8] = b"PLBINFMT";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+enum PayloadKind {
+    DataFrame = 0,
+    Plan = 1,
+}
+
+impl PayloadKind {
+    fn name(self) -> &'static str {
+        match self {
+            PayloadKind::DataFrame => "DataFrame",
+            PayloadKind::Plan => "LazyFrame plan",
+        }
+    }
+}
+
+/// The header of data written in the binary format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinaryHeader {
+    /// The format version, see [`BINARY_FORMAT_VERSION`].
+    pub format_version: u16,
+    /// The version of polars that wrote the data, empty for version `0`.
+    pub polars_version: String,
+}
+
+#[derive(Serialize)]
+struct PlanPayloadRef<'a> {
+    plan: &'a DslPlan,
+    schema: &'a Schema,
+}
+
+#[derive(Deserialize)]
+struct PlanPayload {
+    plan: DslPlan,
+    schema: Schema,
+}
+
+fn write_header<W: Write>(writer: &mut W, kind: PayloadKind) -> PolarsResult<()> {
+    let polars_version = env!("CARGO_PKG_VERSION").as_bytes();
+    writer.write_all(MAGIC)?;
+    writer.write_all(&BINARY_FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&[kind as u8])?;
+    writer.write_all(&(polars_version.len() as u32).to_le_bytes())?;
+    writer.write_all(polars_version)?;
+    Ok(())
+}
+
+/// Read the header and the payload kind. If the data has no magic and is thus of version `0`,
+/// the consumed bytes are returned instead.
+fn read_header<R: Read>(reader: &mut R) -> PolarsResult<Result<(BinaryHeader, u8), Vec<u8>>> {
+    let mut magic = Vec::with_capacity(MAGIC.len());
+    reader
+        .by_ref()
+        .take(MAGIC.len() as u64)
+        .read_to_end(&mut magic)?;
+    if magic != MAGIC {
+        return Ok(Err(magic));
+    }
+
+    let mut fixed = [0u8; 7];
+    reader.read_exact(&mut fixed)?;
+    let len = u32::from_le_bytes([fixed[3], fixed[4], fixed[5], fixed[6]]) as usize;
+    let mut polars_version = vec![0u8; len];
+    reader.read_exact(&mut polars_version)?;
+    let header = BinaryHeader {
+        format_version: u16::from_le_bytes([fixed[0], fixed[1]]),
+        polars_version: String::from_utf8(polars_version).map_err(to_compute_err)?,
+    };
+    Ok(Ok((header, fixed[2])))
+}
+
+/// Read the header of `reader` and return the format version and the payload.
+fn read_payload_start<R: Read>(
+    mut reader: R,
+    kind: PayloadKind,
+) -> PolarsResult<(u16, Chain<Cursor<Vec<u8>>, R>)> {
+    let version = match read_header(&mut reader)? {
+        Ok((header, payload_kind)) => {
+            polars_ensure!(
+                header.format_version <= BINARY_FORMAT_VERSION,
+                ComputeError: "data has binary format version {}, but at most {} is supported; \
+                upgrade polars to read it",
+                header.format_version, BINARY_FORMAT_VERSION
+            );
+            polars_ensure!(
+                payload_kind == kind as u8,
+                ComputeError: "expected a serialized {}, got a different payload",
+                kind.name()
+            );
+            header.format_version
+        },
+        Err(consumed) => return Ok((0, Cursor::new(consumed).chain(reader))),
+    };
+    Ok((version, Cursor::new(vec![]).chain(reader)))
+}
+
+fn decode<T: serde::de::DeserializeOwned, R: Read>(
+    reader: R,
+    kind: PayloadKind,
+) -> PolarsResult<T> {
+    ciborium::from_reader(reader)
+        .map_err(|err| polars_err!(ComputeError: "could not deserialize {}: {}", kind.name(), err))
+}
+
+fn encode<T: Serialize, W: Write>(value: &T, writer: W, kind: PayloadKind) -> PolarsResult<()> {
+    ciborium::into_writer(value, writer)
+        .map_err(|err| polars_err!(ComputeError: "could not serialize {}: {}", kind.name(), err))
+}
+
+/// Read the header of data written by [`LazyFrame::serialize_binary`] or
+/// [`serialize_dataframe_binary`], or `None` if it has none and is thus of version `0`.
+pub fn read_binary_header<R: Read>(mut reader: R) -> PolarsResult<Option<BinaryHeader>> {
+    Ok(read_header(&mut reader)?.ok().map(|(header, _)| header))
+}
+
+/// Write `df` in the binary format, see the [module docs](self).
+pub fn serialize_dataframe_binary<W: Write>(df: &DataFrame, mut writer: W) -> PolarsResult<()> {
+    write_header(&mut writer, PayloadKind::DataFrame)?;
+    encode(df, writer, PayloadKind::DataFrame)
+}
+
+/// Read a [`DataFrame`] written by [`serialize_dataframe_binary`] or by an earlier release.
+pub fn deserialize_dataframe_binary<R: Read>(reader: R) -> PolarsResult<DataFrame> {
+    let (version, payload) = read_payload_start(reader, PayloadKind::DataFrame)?;
+    deserialize_dataframe_with_version(payload, version)
+}
+
+/// Read the payload of a [`DataFrame`] of format `version`, without a header.
+pub fn deserialize_dataframe_with_version<R: Read>(
+    reader: R,
+    version: u16,
+) -> PolarsResult<DataFrame> {
+    match version {
+        0 | 1 => decode(reader, PayloadKind::DataFrame),
+        v => polars_bail!(
+            ComputeError: "binary format version {} is not supported, at most {} is",
+            v, BINARY_FORMAT_VERSION
+        ),
+    }
+}
+
+impl LazyFrame {
+    /// Write the plan of this query in the binary format, see
+    /// [`binary`](crate::frame::binary).
+    ///
+    /// The plan is resolved to embed its schema, which fails if e.g. a scanned file is missing.
+    pub fn serialize_binary<W: Write>(&self, mut writer: W) -> PolarsResult<()> {
+        let schema = self.clone().schema()?;
+        write_header(&mut writer, PayloadKind::Plan)?;
+        let payload = PlanPayloadRef {
+            plan: &self.logical_plan,
+            schema: &schema,
+        };
+        encode(&payload, writer, PayloadKind::Plan)
+    }
+
+    /// Read a plan written by [`LazyFrame::serialize_binary`] or by an earlier release.
+    pub fn deserialize_binary<R: Read>(reader: R) -> PolarsResult<Self> {
+        let (version, payload) = read_payload_start(reader, PayloadKind::Plan)?;
+        Self::deserialize_binary_with_version(payload, version)
+    }
+
+    /// Read the payload of a plan of format `version`, without a header.
+    pub fn deserialize_binary_with_version<R: Read>(reader: R, version: u16) -> PolarsResult<Self> {
+        match version {
+            // Earlier releases wrote the bare plan.
+            0 => Ok(LazyFrame::from(decode::<DslPlan, _>(
+                reader,
+                PayloadKind::Plan,
+            )?)),
+            1 => {
+                let payload: PlanPayload = decode(reader, PayloadKind::Plan)?;
+                let mut lf = LazyFrame::from(payload.plan);
+                let schema = lf.schema()?;
+                polars_ensure!(
+                    schema.as_ref() == &payload.schema,
+                    SchemaMismatch: "the deserialized plan resolves to schema {:?}, but it was \
+                    written with schema {:?}",
+                    schema, payload.schema
+                );
+                Ok(lf)
+            },
+            v => polars_bail!(
+                ComputeError: "binary format version {} is not supported, at most {} is",
+                v, BINARY_FORMAT_VERSION
+            ),
+        }
+    }
+}
@@ -0,0 +1,324 @@
+//! Collect many queries at once, reading the files they have in common only once.
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use polars_core::utils::rayon::prelude::*;
+use polars_core::POOL;
+use polars_io::HiveOptions;
+use polars_mem_engine::create_physical_plan;
+
+use super::execute_interruptible;
+use crate::prelude::*;
+
+/// Options for [`collect_all_with_options`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CollectAllOptions {
+    /// Read the files that are scanned by more than one query only once, and share the result
+    /// between the queries.
+    pub share_scans: bool,
+    /// The maximum number of queries that are executed at the same time. If `None`, all queries
+    /// are executed in parallel on the global thread pool.
+    pub max_concurrency: Option<usize>,
+}
+
+/// Collect multiple [`LazyFrame`]s, see [`CollectAllOptions`].
+///
+/// The results are returned in the order of the given queries.
+pub fn collect_all_with_options<I>(
+    lfs: I,
+    options: CollectAllOptions,
+) -> PolarsResult<Vec<DataFrame>>
+where
+    I: IntoIterator<Item = LazyFrame>,
+{
+    let lfs = lfs.into_iter().collect::<Vec<_>>();
+    if let Some(max_concurrency) = options.max_concurrency {
+        polars_ensure!(
+            max_concurrency > 0,
+            InvalidOperation: "'max_concurrency' must be positive, got {}", max_concurrency
+        );
+    }
+    if !options.share_scans {
+        return run_throttled(lfs, options.max_concurrency, LazyFrame::collect);
+    }
+
+    let mut queries = POOL.install(|| {
+        lfs.into_par_iter()
+            .map(|lf| {
+                // The streaming engines build their own pipelines during optimization, so their
+                // scans are not shared.
+                if lf.opt_state.streaming || lf.opt_state.new_streaming {
+                    Ok(Query::Lazy(lf))
+                } else {
+                    lf.to_alp_optimized().map(Query::Optimized)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    share_scans(&mut queries)?;
+
+    run_throttled(queries, options.max_concurrency, |query| match query {
+        Query::Lazy(lf) => lf.collect(),
+        Query::Optimized(mut plan) => {
+            let mut physical_plan =
+                create_physical_plan(plan.lp_top, &mut plan.lp_arena, &mut plan.expr_arena)?;
+            let mut state = ExecutionState::new();
+            execute_interruptible(physical_plan.as_mut(), &mut state)
+        },
+    })
+}
+
+enum Query {
+    Lazy(LazyFrame),
+    Optimized(IRPlan),
+}
+
+/// Run `f` on all items, with at most `max_concurrency` items at the same time.
+fn run_throttled<T, F>(
+    items: Vec<T>,
+    max_concurrency: Option<usize>,
+    f: F,
+) -> PolarsResult<Vec<DataFrame>>
+where
+    T: Send,
+    F: Fn(T) -> PolarsResult<DataFrame> + Sync,
+{
+    let Some(max_concurrency) = max_concurrency else {
+        return POOL.install(|| items.into_par_iter().map(&f).collect());
+    };
+
+    let n_items = items.len();
+    let items = items
+        .into_iter()
+        .map(|item| Mutex::new(Some(item)))
+        .collect::<Vec<_>>();
+    let results = (0..n_items).map(|_| Mutex::new(None)).collect::<Vec<_>>();
+    let next = AtomicUsize::new(0);
+
+    // Every worker takes the next query once it is done with the previous one. The queries
+    // themselves still run their operations on the global thread pool.
+    std::thread::scope(|scope| {
+        for _ in 0..max_concurrency.min(n_items) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= n_items {
+                    break;
+                }
+                let item = items[i].lock().unwrap().take().unwrap();
+                let out = f(item);
+                *results[i].lock().unwrap() = Some(out);
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|out| out.into_inner().unwrap().unwrap())
+        .collect()
+}
+
+/// Scans of the same files with the same options.
+#[derive(PartialEq, Eq, Hash)]
+struct ScanKey {
+    paths: Arc<[PathBuf]>,
+    scan_type: FileScan,
+    hive_options: HiveOptions,
+}
+
+struct SharedScan {
+    /// The index of the query and the node of every scan of the files.
+    nodes: Vec<(usize, Node)>,
+    /// The columns that are needed by any of the scans, `None` if all columns are needed.
+    columns: Option<PlIndexSet<String>>,
+}
+
+/// Read the files that are scanned by more than one query once, and replace those scans by the
+/// result.
+fn share_scans(queries: &mut [Query]) -> PolarsResult<()> {
+    let mut scans: PlHashMap<ScanKey, SharedScan> = PlHashMap::new();
+    for (i, query) in queries.iter().enumerate() {
+        let Query::Optimized(plan) = query else {
+            continue;
+        };
+        let mut seen = PlHashSet::new();
+        for (node, lp) in (&plan.lp_arena).iter(plan.lp_top) {
+            let IR::Scan {
+                paths,
+                scan_type,
+                file_options,
+                predicate,
+                ..
+            } = lp
+            else {
+                continue;
+            };
+            // A plan can reach the same node more than once through a cache. Scans that stop early
+            // or add a row index read something different than a full scan.
+            if !seen.insert(node)
+                || file_options.n_rows.is_some()
+                || file_options.row_index.is_some()
+            {
+                continue;
+            }
+
+            let key = ScanKey {
+                paths: paths.clone(),
+                scan_type: scan_type.clone(),
+                hive_options: file_options.hive_options.clone(),
+            };
+            let shared = scans.entry(key).or_insert_with(|| SharedScan {
+                nodes: vec![],
+                columns: Some(PlIndexSet::default()),
+            });
+            shared.nodes.push((i, node));
+            match (&mut shared.columns, &file_options.with_columns) {
+                (Some(columns), Some(with_columns)) => {
+                    columns.extend(with_columns.iter().cloned());
+                    if let Some(predicate) = predicate {
+                        columns.extend(
+                            aexpr_to_leaf_names_iter(predicate.node(), &plan.expr_arena)
+                                .map(|name| name.to_string()),
+                        );
+                    }
+                },
+                (columns, _) => *columns = None,
+            }
+        }
+    }
+
+    let shared_scans = scans
+        .into_values()
+        .filter(|shared| shared.nodes.len() > 1)
+        .collect::<Vec<_>>();
+    let dfs = POOL.install(|| {
+        shared_scans
+            .par_iter()
+            .map(|shared| {
+                let (i, node) = shared.nodes[0];
+                let Query::Optimized(plan) = &queries[i] else {
+                    unreachable!()
+                };
+                execute_shared_scan(plan, node, shared.columns.as_ref())
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    for (shared, df) in shared_scans.into_iter().zip(dfs) {
+        let df = Arc::new(df);
+        let schema = Arc::new(df.schema());
+        for (i, node) in shared.nodes {
+            let Query::Optimized(plan) = &mut queries[i] else {
+                unreachable!()
+            };
+            replace_scan(plan, node, &df, &schema)?;
+        }
+    }
+    Ok(())
+}
+
+fn project<'a>(
+    schema: &Schema,
+    columns: impl IntoIterator<Item = &'a String>,
+) -> PolarsResult<SchemaRef> {
+    columns
+        .into_iter()
+        .map(|name| schema.try_get_field(name))
+        .collect::<PolarsResult<Schema>>()
+        .map(Arc::new)
+}
+
+fn execute_shared_scan(
+    plan: &IRPlan,
+    node: Node,
+    columns: Option<&PlIndexSet<String>>,
+) -> PolarsResult<DataFrame> {
+    let IR::Scan {
+        paths,
+        file_info,
+        hive_parts,
+        scan_type,
+        mut file_options,
+        ..
+    } = plan.lp_arena.get(node).clone()
+    else {
+        unreachable!()
+    };
+    let output_schema = columns
+        .map(|columns| project(&file_info.schema, columns))
+        .transpose()?;
+    file_options.with_columns = columns.map(|columns| columns.iter().cloned().collect());
+
+    let mut lp_arena = Arena::with_capacity(1);
+    let mut expr_arena = Arena::with_capacity(0);
+    let root = lp_arena.add(IR::Scan {
+        paths,
+        file_info,
+        hive_parts,
+        predicate: None,
+        output_schema,
+        scan_type,
+        file_options,
+    });
+    let mut physical_plan = create_physical_plan(root, &mut lp_arena, &mut expr_arena)?;
+    execute_interruptible(physical_plan.as_mut(), &mut ExecutionState::new())
+}
+
+/// Replace a scan by the shared result, applying the projection and predicate of the scan.
+fn replace_scan(
+    plan: &mut IRPlan,
+    node: Node,
+    df: &Arc<DataFrame>,
+    schema: &SchemaRef,
+) -> PolarsResult<()> {
+    let IR::Scan {
+        predicate,
+        output_schema,
+        file_options,
+        ..
+    } = plan.lp_arena.take(node)
+    else {
+        unreachable!()
+    };
+    let Some(with_columns) = file_options.with_columns else {
+        plan.lp_arena.replace(
+            node,
+            IR::DataFrameScan {
+                df: df.clone(),
+                schema: schema.clone(),
+                output_schema: None,
+                filter: predicate,
+            },
+        );
+        return Ok(());
+    };
+
+    // The predicate is applied after the projection, so it must project the columns of the
+    // predicate as well. These are removed again afterwards.
+    let mut projection = with_columns.iter().cloned().collect::<PlIndexSet<_>>();
+    if let Some(predicate) = &predicate {
+        projection.extend(
+            aexpr_to_leaf_names_iter(predicate.node(), &plan.expr_arena)
+                .map(|name| name.to_string()),
+        );
+    }
+    let columns = match output_schema {
+        Some(output_schema) => output_schema,
+        None => project(schema, with_columns.iter())?,
+    };
+    let scan = plan.lp_arena.add(IR::DataFrameScan {
+        df: df.clone(),
+        schema: schema.clone(),
+        output_schema: Some(project(schema, projection.iter())?),
+        filter: predicate,
+    });
+    plan.lp_arena.replace(
+        node,
+        IR::SimpleProjection {
+            input: scan,
+            columns,
+        },
+    );
+    Ok(())
+}
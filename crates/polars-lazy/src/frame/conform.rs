@@ -0,0 +1,90 @@
+//! Conform a [`LazyFrame`] to a fixed target schema, e.g. before appending it to a warehouse
+//! table.
+use polars_core::prelude::*;
+use polars_core::utils::get_supertype;
+
+use crate::prelude::*;
+
+/// What to do with a column of the target schema that the frame doesn't have.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MissingColumns {
+    /// Add the column filled with nulls.
+    #[default]
+    Null,
+    /// Raise an error.
+    Error,
+}
+
+/// What to do with a column of the frame that is not in the target schema.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ExtraColumns {
+    /// Drop the column.
+    #[default]
+    Drop,
+    /// Raise an error.
+    Error,
+}
+
+/// Which casts are allowed to bring a column to its target dtype.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ConformCast {
+    /// Only casts to a dtype that can hold every value of the source dtype, i.e. the target
+    /// dtype is the supertype of both. Other casts are rejected when the plan is built.
+    #[default]
+    Safe,
+    /// Any cast; the query raises if a value cannot be represented in the target dtype.
+    Strict,
+}
+
+/// Options of [`LazyFrame::conform_to`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConformOptions {
+    pub missing: MissingColumns,
+    pub extra: ExtraColumns,
+    pub cast: ConformCast,
+}
+
+/// The projection that conforms a frame with schema `input` to `target`.
+pub(super) fn conform_exprs(
+    input: &Schema,
+    target: &Schema,
+    options: ConformOptions,
+) -> PolarsResult<Vec<Expr>> {
+    if options.extra == ExtraColumns::Error {
+        let extra = input
+            .iter_names()
+            .filter(|name| !target.contains(name))
+            .collect::<Vec<_>>();
+        polars_ensure!(
+            extra.is_empty(),
+            SchemaMismatch: "columns {:?} are not in the target schema", extra
+        );
+    }
+
+    target
+        .iter()
+        .map(|(name, dtype)| {
+            let Some(input_dtype) = input.get(name) else {
+                polars_ensure!(
+                    options.missing == MissingColumns::Null,
+                    ColumnNotFound: "column '{}' of the target schema is missing", name
+                );
+                return Ok(lit(NULL).cast(dtype.clone()).alias(name));
+            };
+            if input_dtype == dtype {
+                return Ok(col(name));
+            }
+            match options.cast {
+                ConformCast::Safe => {
+                    polars_ensure!(
+                        get_supertype(input_dtype, dtype).as_ref() == Some(dtype),
+                        SchemaMismatch: "cannot safely cast column '{}' from {} to {}",
+                        name, input_dtype, dtype
+                    );
+                    Ok(col(name).strict_cast(dtype.clone()))
+                },
+                ConformCast::Strict => Ok(col(name).strict_cast(dtype.clone())),
+            }
+        })
+        .collect()
+}
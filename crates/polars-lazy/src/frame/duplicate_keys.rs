@@ -0,0 +1,90 @@
+//! Diagnostics of duplicate join keys, see [`LazyFrame::duplicate_keys`] and
+//! [`JoinBuilder::on_duplicate_keys`].
+//!
+//! A key that occurs several times in the right table of a join multiplies the rows of the left
+//! table that match it. If the keys were expected to be unique, that silently results in wrong
+//! aggregates downstream.
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+const COUNT: &str = "count";
+
+/// What [`JoinBuilder::on_duplicate_keys`] does with keys that occur several times in the right
+/// table of a join.
+#[derive(Clone, Debug)]
+pub enum DuplicateKeyPolicy {
+    /// Raise an error that reports the duplicate keys.
+    Error,
+    /// Warn with a report of the duplicate keys and join anyway.
+    Warn,
+    /// Aggregate the rows of every key of the right table with these expressions before joining.
+    /// The columns of the right table that are not aggregated are dropped.
+    Aggregate(Vec<Expr>),
+}
+
+/// Raise an error or warn if a key occurs several times in `df`.
+fn check_duplicate_keys(df: DataFrame, on: &[Expr], error: bool) -> PolarsResult<DataFrame> {
+    let report = df.clone().lazy().duplicate_keys(on, 1).collect()?;
+    if report.height() > 0 {
+        let msg = format!(
+            "{} join keys occur several times in the right table:\n{}",
+            report.height(),
+            report.head(Some(10))
+        );
+        if error {
+            polars_bail!(Duplicate: "{}", msg);
+        }
+        polars_warn!("{}", msg);
+    }
+    Ok(df)
+}
+
+impl DuplicateKeyPolicy {
+    /// Apply the policy to the right table of a join and return it with the expressions to join
+    /// it on.
+    pub(super) fn apply(self, other: LazyFrame, right_on: Vec<Expr>) -> (LazyFrame, Vec<Expr>) {
+        match self {
+            Self::Error | Self::Warn => {
+                let error = matches!(self, Self::Error);
+                let on = right_on.clone();
+                let other = other.map(
+                    move |df| check_duplicate_keys(df, &on, error),
+                    AllowedOptimizations::default(),
+                    None,
+                    Some("check_duplicate_keys"),
+                );
+                (other, right_on)
+            },
+            Self::Aggregate(aggs) => {
+                let other = other.group_by_stable(&right_on).agg(aggs);
+                // The keys are evaluated by the aggregation, so join on their output columns. If
+                // a key has no output name, the aggregation raises the error.
+                let right_on = right_on
+                    .into_iter()
+                    .map(|e| expr_output_name(&e).map_or(e, |name| col(&name)))
+                    .collect();
+                (other, right_on)
+            },
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Report the keys `on` that occur more than `threshold` times, with the number of their rows
+    /// in the column `count`, the most frequent keys first.
+    ///
+    /// With a `threshold` of 1 these are the keys that multiply the matching rows if this frame
+    /// is the right table of a join on `on`.
+    pub fn duplicate_keys<E: AsRef<[Expr]>>(self, on: E, threshold: IdxSize) -> LazyFrame {
+        self.group_by(on)
+            .agg([len().alias(COUNT)])
+            .filter(col(COUNT).gt(lit(threshold)))
+            .sort(
+                [COUNT],
+                SortMultipleOptions::default()
+                    .with_order_descending(true)
+                    .with_maintain_order(true),
+            )
+    }
+}
@@ -0,0 +1,404 @@
+//! A dry-run estimate of the memory that a query needs, see [`LazyFrame::estimate_resources`].
+use polars_core::prelude::*;
+use smartstring::alias::String as SmartString;
+
+use crate::prelude::*;
+
+// Strings and binary values are stored as 16 byte views, plus the bytes that don't fit in the
+// view. Without statistics we assume short values.
+const VARIABLE_WIDTH_BYTES: f64 = 24.0;
+// The number of elements that is assumed for a list without statistics.
+const LIST_LENGTH: f64 = 4.0;
+// The bytes per row of the hash tables and group indices of hashing operators.
+const HASH_BYTES: f64 = (std::mem::size_of::<u64>() + std::mem::size_of::<IdxSize>()) as f64;
+
+/// The estimated resources of one operator of a query.
+#[derive(Clone, Debug)]
+pub struct OperatorEstimate {
+    /// The name of the operator.
+    pub name: &'static str,
+    /// The depth of the operator in the plan, the root of the plan has depth 0.
+    pub depth: usize,
+    /// The estimated number of rows of the output.
+    pub rows: usize,
+    /// The estimated number of bytes of the output.
+    pub output_bytes: usize,
+    /// The estimated number of bytes in memory while the operator runs, including its inputs,
+    /// its intermediate state and its output.
+    pub peak_bytes: usize,
+    /// The estimated number of bytes that the streaming engine spills to disk if the state of the
+    /// operator exceeds the memory budget. Only operators that have to see all their input before
+    /// producing output spill.
+    pub spill_bytes: usize,
+}
+
+/// A rough estimate of the memory of a query, made from the optimized plan and the metadata of
+/// the scanned files without executing the query, see [`LazyFrame::estimate_resources`].
+#[derive(Clone, Debug)]
+pub struct ResourceEstimate {
+    /// The estimates of the operators, every operator is followed by its inputs.
+    pub operators: Vec<OperatorEstimate>,
+    /// The estimated peak memory of the in-memory engine.
+    pub peak_bytes: usize,
+    /// The estimated total number of bytes spilled by the streaming engine.
+    pub spill_bytes: usize,
+    /// The memory that is assumed to be available, if known.
+    pub memory_budget: Option<usize>,
+    /// Whether the number of rows of all sources is known. Sources of which the number of rows is
+    /// unknown are estimated to be empty.
+    pub complete: bool,
+}
+
+impl ResourceEstimate {
+    /// Whether the query is expected to fit in the memory budget. This is `true` if the budget is
+    /// unknown.
+    pub fn fits_in_memory(&self) -> bool {
+        self.memory_budget
+            .map_or(true, |memory_budget| self.peak_bytes <= memory_budget)
+    }
+
+    /// The estimates of the operators as a [`DataFrame`], with the operators indented by their
+    /// depth.
+    pub fn to_df(&self) -> PolarsResult<DataFrame> {
+        let column = |name: &str, f: fn(&OperatorEstimate) -> u64| {
+            Series::new(name, self.operators.iter().map(f).collect::<Vec<_>>())
+        };
+        let names = self
+            .operators
+            .iter()
+            .map(|op| format!("{}{}", "  ".repeat(op.depth), op.name))
+            .collect::<Vec<_>>();
+        DataFrame::new(vec![
+            Series::new("operator", names),
+            column("rows", |op| op.rows as u64),
+            column("output_bytes", |op| op.output_bytes as u64),
+            column("peak_bytes", |op| op.peak_bytes as u64),
+            column("spill_bytes", |op| op.spill_bytes as u64),
+        ])
+    }
+}
+
+/// The estimated bytes per row of every column of the output of a node.
+type Widths = PlIndexMap<SmartString, f64>;
+
+#[derive(Clone)]
+struct NodeEstimate {
+    rows: usize,
+    widths: Widths,
+    peak_bytes: usize,
+}
+
+impl NodeEstimate {
+    fn output_bytes(&self) -> usize {
+        bytes(self.rows, self.widths.values().sum())
+    }
+}
+
+fn bytes(rows: usize, width: f64) -> usize {
+    (rows as f64 * width) as usize
+}
+
+fn dtype_width(dtype: &DataType) -> f64 {
+    use DataType::*;
+    match dtype {
+        Null => 0.0,
+        Boolean | Int8 | UInt8 => 1.0,
+        Int16 | UInt16 => 2.0,
+        Int32 | UInt32 | Float32 | Date => 4.0,
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(_, _) => 4.0,
+        String | Binary | BinaryOffset => VARIABLE_WIDTH_BYTES,
+        List(inner) => std::mem::size_of::<i64>() as f64 + LIST_LENGTH * dtype_width(inner),
+        #[cfg(feature = "dtype-array")]
+        Array(inner, width) => *width as f64 * dtype_width(inner),
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => fields.iter().map(|fld| dtype_width(fld.data_type())).sum(),
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => 16.0,
+        _ => 8.0,
+    }
+}
+
+/// The bytes per row of the columns of `schema`, taken from `known` if present there.
+fn schema_widths(schema: &Schema, known: Option<&Widths>) -> Widths {
+    schema
+        .iter()
+        .map(|(name, dtype)| {
+            let width = known
+                .and_then(|known| known.get(name.as_str()))
+                .copied()
+                .unwrap_or_else(|| dtype_width(dtype));
+            (name.clone(), width)
+        })
+        .collect()
+}
+
+/// The number of rows of a scan, `None` if it is unknown.
+fn scan_rows(file_info: &FileInfo, file_options: &FileScanOptions) -> Option<usize> {
+    let rows = match file_info.row_estimation {
+        (Some(known), _) => known,
+        (None, usize::MAX) => return None,
+        (None, estimated) => estimated,
+    };
+    Some(file_options.n_rows.map_or(rows, |n_rows| n_rows.min(rows)))
+}
+
+/// The uncompressed bytes per row of the columns of a file, if its metadata has them.
+fn file_widths(scan_type: &FileScan) -> Option<PlHashMap<&str, f64>> {
+    match scan_type {
+        #[cfg(feature = "parquet")]
+        FileScan::Parquet {
+            metadata: Some(metadata),
+            ..
+        } if metadata.num_rows > 0 => {
+            let mut widths = PlHashMap::new();
+            for rg in &metadata.row_groups {
+                for column in rg.columns() {
+                    let name = column.descriptor().path_in_schema[0].as_str();
+                    *widths.entry(name).or_insert(0.0) += column.uncompressed_size() as f64;
+                }
+            }
+            for width in widths.values_mut() {
+                *width /= metadata.num_rows as f64;
+            }
+            Some(widths)
+        },
+        _ => None,
+    }
+}
+
+struct Estimator<'a> {
+    lp_arena: &'a Arena<IR>,
+    memory_budget: Option<usize>,
+    complete: bool,
+    operators: Vec<OperatorEstimate>,
+    // Nodes under a cache can be reached more than once, but they only run once.
+    visited: PlHashMap<Node, NodeEstimate>,
+}
+
+impl Estimator<'_> {
+    fn spill(&self, state_bytes: usize) -> usize {
+        self.memory_budget
+            .map_or(0, |memory_budget| state_bytes.saturating_sub(memory_budget))
+    }
+
+    fn estimate(&mut self, node: Node, depth: usize) -> NodeEstimate {
+        if let Some(estimate) = self.visited.get(&node) {
+            return estimate.clone();
+        }
+        // Reserve the position of the operator before its inputs.
+        let position = self.operators.len();
+        let lp_arena = self.lp_arena;
+        let lp = lp_arena.get(node);
+        let inputs = lp
+            .get_inputs()
+            .iter()
+            .map(|input| self.estimate(*input, depth + 1))
+            .collect::<Vec<_>>();
+        let schema = lp.schema(lp_arena);
+
+        // The inputs run one after the other, and the outputs of finished inputs are kept until
+        // this operator runs.
+        let mut inputs_peak = 0;
+        let mut inputs_bytes = 0;
+        for input in &inputs {
+            inputs_peak = inputs_peak.max(inputs_bytes + input.peak_bytes);
+            inputs_bytes += input.output_bytes();
+        }
+        let input_rows = inputs.first().map_or(0, |input| input.rows);
+        let input_widths = inputs.first().map(|input| &input.widths);
+
+        // The number of rows of the output, and the bytes of the state of operators that have
+        // to see all their input before producing output.
+        let (rows, state_bytes) = match lp {
+            IR::DataFrameScan { df, .. } => {
+                let widths = schema
+                    .iter_names()
+                    .map(|name| {
+                        let width = match df.column(name) {
+                            Ok(s) if df.height() > 0 => {
+                                s.estimated_size() as f64 / df.height() as f64
+                            },
+                            _ => 0.0,
+                        };
+                        (name.clone(), width)
+                    })
+                    .collect();
+                return self.finish(node, position, depth, lp, df.height(), widths, 0, 0);
+            },
+            IR::Scan {
+                file_info,
+                file_options,
+                scan_type,
+                ..
+            } => {
+                let rows = scan_rows(file_info, file_options).unwrap_or_else(|| {
+                    self.complete = false;
+                    0
+                });
+                let mut widths = schema_widths(&schema, None);
+                if let Some(file_widths) = file_widths(scan_type) {
+                    for (name, width) in widths.iter_mut() {
+                        if let Some(file_width) = file_widths.get(name.as_str()) {
+                            *width = *file_width;
+                        }
+                    }
+                }
+                return self.finish(node, position, depth, lp, rows, widths, 0, 0);
+            },
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => {
+                self.complete = false;
+                (0, 0)
+            },
+            IR::Slice { len, .. } => ((*len as usize).min(input_rows), 0),
+            IR::Reduce { .. } => (1, 0),
+            IR::Sort {
+                slice, by_column, ..
+            } => {
+                let rows = slice.map_or(input_rows, |(_, len)| len.min(input_rows));
+                // The sort keys and the indices that sort them.
+                let keys_width = by_column
+                    .iter()
+                    .map(|e| {
+                        input_widths
+                            .and_then(|widths| widths.get(e.output_name()))
+                            .copied()
+                            .unwrap_or(8.0)
+                    })
+                    .sum::<f64>();
+                let index_width = std::mem::size_of::<IdxSize>() as f64;
+                (rows, bytes(input_rows, keys_width + index_width))
+            },
+            IR::GroupBy { .. } | IR::Distinct { .. } => (input_rows, bytes(input_rows, HASH_BYTES)),
+            IR::Join { options, .. } => {
+                let left = input_rows;
+                let right = inputs[1].rows;
+                let rows = match options.args.how {
+                    JoinType::Left => left,
+                    JoinType::Cross => left.saturating_mul(right),
+                    JoinType::Full => left + right,
+                    _ => left.max(right),
+                };
+                // The hash table is built on one of the sides and the join produces the indices
+                // of the matching rows of both sides.
+                let index_width = 2.0 * std::mem::size_of::<IdxSize>() as f64;
+                let state = bytes(left.min(right), HASH_BYTES) + bytes(rows, index_width);
+                (rows, state)
+            },
+            IR::Union { .. } => (inputs.iter().map(|input| input.rows).sum(), 0),
+            IR::HConcat { .. } => (inputs.iter().map(|input| input.rows).max().unwrap_or(0), 0),
+            IR::Invalid => unreachable!(),
+            _ => (input_rows, 0),
+        };
+
+        let widths = match lp {
+            IR::Union { .. } => schema_widths(&schema, input_widths),
+            _ => {
+                // Columns can come from any of the inputs.
+                let mut known = Widths::default();
+                for input in &inputs {
+                    known.extend(input.widths.iter().map(|(k, v)| (k.clone(), *v)));
+                }
+                schema_widths(&schema, Some(&known))
+            },
+        };
+        let output_bytes = bytes(rows, widths.values().sum::<f64>());
+        let peak_bytes = inputs_peak.max(inputs_bytes + state_bytes + output_bytes);
+        let spill_bytes = self.spill(state_bytes + inputs_bytes);
+        let spill_bytes = if state_bytes > 0 { spill_bytes } else { 0 };
+        self.finish(
+            node,
+            position,
+            depth,
+            lp,
+            rows,
+            widths,
+            peak_bytes,
+            spill_bytes,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn finish(
+        &mut self,
+        node: Node,
+        position: usize,
+        depth: usize,
+        lp: &IR,
+        rows: usize,
+        widths: Widths,
+        peak_bytes: usize,
+        spill_bytes: usize,
+    ) -> NodeEstimate {
+        let output_bytes = bytes(rows, widths.values().sum());
+        let estimate = NodeEstimate {
+            rows,
+            widths,
+            peak_bytes: peak_bytes.max(output_bytes),
+        };
+        self.operators.insert(
+            position,
+            OperatorEstimate {
+                name: lp.name(),
+                depth,
+                rows,
+                output_bytes,
+                peak_bytes: estimate.peak_bytes,
+                spill_bytes,
+            },
+        );
+        self.visited.insert(node, estimate.clone());
+        estimate
+    }
+}
+
+/// The memory that is available to a query.
+fn available_memory() -> Option<usize> {
+    #[cfg(feature = "streaming")]
+    {
+        Some(polars_utils::sys::MEMINFO.free() as usize)
+    }
+    // Only the streaming engine spills, so without it there is no budget to compare with.
+    #[cfg(not(feature = "streaming"))]
+    {
+        None
+    }
+}
+
+impl LazyFrame {
+    /// Estimate the peak memory of the query, and the bytes that the streaming engine spills,
+    /// without executing it. The estimates are made from the optimized plan and the metadata of
+    /// the scanned files and are rough; they are meant to tell whether a query needs the
+    /// streaming engine or a bigger machine.
+    ///
+    /// The memory budget is the memory that is currently available.
+    pub fn estimate_resources(self) -> PolarsResult<ResourceEstimate> {
+        self.estimate_resources_with_budget(available_memory())
+    }
+
+    /// Estimate the resources of the query, see [`LazyFrame::estimate_resources`], against the
+    /// given memory budget.
+    pub fn estimate_resources_with_budget(
+        self,
+        memory_budget: Option<usize>,
+    ) -> PolarsResult<ResourceEstimate> {
+        let plan = self.to_alp_optimized()?;
+        let mut estimator = Estimator {
+            lp_arena: &plan.lp_arena,
+            memory_budget,
+            complete: true,
+            operators: vec![],
+            visited: Default::default(),
+        };
+        let root = estimator.estimate(plan.lp_top, 0);
+        let spill_bytes = estimator.operators.iter().map(|op| op.spill_bytes).sum();
+        Ok(ResourceEstimate {
+            operators: estimator.operators,
+            peak_bytes: root.peak_bytes,
+            spill_bytes,
+            memory_budget,
+            complete: estimator.complete,
+        })
+    }
+}
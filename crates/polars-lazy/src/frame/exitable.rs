@@ -3,6 +3,10 @@ use std::sync::mpsc::{channel, Receiver};
 use std::sync::Mutex;
 
 use polars_core::POOL;
+use polars_utils::memory_budget::MemoryBudget;
+pub use polars_utils::memory_budget::MemoryUsage;
+use polars_utils::spill::SpillTracker;
+pub use polars_utils::spill::{OperatorSpill, SpillUsage};
 
 use super::*;
 
@@ -13,7 +17,7 @@ impl LazyFrame {
         let (tx, rx) = channel();
         let token = state.cancel_token();
         POOL.spawn_fifo(move || {
-            let result = physical_plan.execute(&mut state);
+            let result = execute_interruptible(physical_plan.as_mut(), &mut state);
             tx.send(result).unwrap();
         });
 
@@ -24,6 +28,90 @@ impl LazyFrame {
     }
 }
 
+impl LazyFrame {
+    /// Prepare the query so that it can be cancelled while it runs.
+    ///
+    /// Get a [`CancelHandle`] from the returned query before running it with
+    /// [`CancelableQuery::collect`]. Once the handle is cancelled, the executors stop at their
+    /// next check and the query returns a `Cancelled` error.
+    pub fn collect_cancelable(self) -> PolarsResult<CancelableQuery> {
+        let (state, physical_plan, _) = self.prepare_collect(false)?;
+        Ok(CancelableQuery {
+            state,
+            physical_plan,
+        })
+    }
+}
+
+/// A query prepared by [`LazyFrame::collect_cancelable`].
+pub struct CancelableQuery {
+    state: ExecutionState,
+    physical_plan: Box<dyn Executor>,
+}
+
+impl CancelableQuery {
+    /// Limit the memory of the query to about `limit` bytes.
+    ///
+    /// The streaming engine reserves the memory its sinks hold against the limit. As the limit
+    /// gets tight it spills to disk earlier, reads smaller morsels and pushes fewer morsels at
+    /// the same time, rather than running out of memory. The limit isn't a hard bound, the query
+    /// doesn't fail if it is exceeded. Set the limit before getting the
+    /// [`cancel_handle`](Self::cancel_handle), to be able to monitor the usage.
+    pub fn with_memory_limit(mut self, limit: usize) -> Self {
+        self.state.set_memory_budget(MemoryBudget::new(limit));
+        self
+    }
+
+    /// A handle to cancel the query, which can be moved to another thread.
+    pub fn cancel_handle(&self) -> CancelHandle {
+        CancelHandle {
+            token: self.state.cancel_token(),
+            memory_budget: self.state.memory_budget().cloned(),
+            spill_tracker: self.state.spill_tracker().clone(),
+        }
+    }
+
+    /// Run the query on the current thread.
+    pub fn collect(mut self) -> PolarsResult<DataFrame> {
+        execute_interruptible(self.physical_plan.as_mut(), &mut self.state)
+    }
+}
+
+/// Cancels the query it was created for and reports its memory usage, see
+/// [`LazyFrame::collect_cancelable`].
+#[derive(Clone)]
+pub struct CancelHandle {
+    token: Arc<AtomicBool>,
+    memory_budget: Option<MemoryBudget>,
+    spill_tracker: SpillTracker,
+}
+
+impl CancelHandle {
+    /// Cancel the query at earliest convenience. Cancelling a query that has finished has no
+    /// effect.
+    pub fn cancel(&self) {
+        self.token.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.load(Ordering::Relaxed)
+    }
+
+    /// The current memory usage of the query, if it has a
+    /// [memory limit](CancelableQuery::with_memory_limit).
+    pub fn memory_usage(&self) -> Option<MemoryUsage> {
+        self.memory_budget.as_ref().map(MemoryBudget::usage)
+    }
+
+    /// The bytes the operators of the query spilled to disk so far. The spills of a query are
+    /// limited with [`Config::with_max_spill_bytes`], set when the query is prepared.
+    ///
+    /// [`Config::with_max_spill_bytes`]: polars_core::config::Config::with_max_spill_bytes
+    pub fn spill_usage(&self) -> SpillUsage {
+        self.spill_tracker.usage()
+    }
+}
+
 #[derive(Clone)]
 pub struct InProcessQuery {
     rx: Arc<Mutex<Receiver<PolarsResult<DataFrame>>>>,
@@ -0,0 +1,115 @@
+//! Join facts to the calendar periods in which they fall, see [`LazyFrame::join_period`].
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+const TS: &str = "__POLARS_PERIOD_TS";
+const START: &str = "__POLARS_PERIOD_START";
+const END: &str = "__POLARS_PERIOD_END";
+const PERIOD: &str = "__POLARS_PERIOD_ID";
+const MIN_PERIOD: &str = "__POLARS_PERIOD_MIN";
+const MAX_PERIOD: &str = "__POLARS_PERIOD_MAX";
+const ROW: &str = "__POLARS_PERIOD_ROW";
+
+/// The calendar periods that the rows of [`LazyFrame::join_period`] are matched in.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PeriodGranularity {
+    Day,
+    #[default]
+    Month,
+    Quarter,
+    Year,
+}
+
+impl PeriodGranularity {
+    /// The id of the period that the date or datetime `expr` falls in. Consecutive periods have
+    /// consecutive ids.
+    fn period_id(self, expr: Expr) -> Expr {
+        let year = || expr.clone().dt().year().cast(DataType::Int64);
+        let id = match self {
+            Self::Day => expr.clone().cast(DataType::Date).cast(DataType::Int32),
+            Self::Month => year() * lit(12i64) + expr.clone().dt().month().cast(DataType::Int64),
+            Self::Quarter => year() * lit(4i64) + expr.clone().dt().quarter().cast(DataType::Int64),
+            Self::Year => year(),
+        };
+        id.cast(DataType::Int64)
+    }
+}
+
+impl LazyFrame {
+    /// Join every row of this frame to the rows of `other` of which the validity range
+    /// `[right_start, right_end)` contains `left_ts`, like facts to the versions of a slowly
+    /// changing dimension.
+    ///
+    /// Instead of a generic interval join, the ranges are split into the calendar periods of
+    /// `granularity` that they overlap, and the rows are equi-joined on the integer id of their
+    /// period before the bounds are checked. This is fast if the ranges span few periods,
+    /// e.g. month or quarter boundaries with a monthly granularity. A null start or end is an
+    /// open bound, ranges are clipped to the periods of this frame.
+    ///
+    /// Only [`JoinType::Inner`] and [`JoinType::Left`] are supported.
+    pub fn join_period(
+        self,
+        other: LazyFrame,
+        left_ts: Expr,
+        right_start: Expr,
+        right_end: Expr,
+        granularity: PeriodGranularity,
+        how: JoinType,
+    ) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            matches!(how, JoinType::Inner | JoinType::Left),
+            InvalidOperation: "join_period only supports inner and left joins, got {}", how
+        );
+
+        let mut left = self;
+        if how == JoinType::Left {
+            left = left.with_row_index(ROW, None);
+        }
+        let left = left
+            .with_column(left_ts.alias(TS))
+            .with_column(granularity.period_id(col(TS)).alias(PERIOD));
+
+        // Clip the ranges to the periods of the left rows, so that open and long ranges don't
+        // explode into periods that can't match.
+        let bounds = left.clone().select([
+            col(PERIOD).min().alias(MIN_PERIOD),
+            col(PERIOD).max().alias(MAX_PERIOD),
+        ]);
+        let first = max_horizontal([
+            granularity.period_id(col(START)).fill_null(col(MIN_PERIOD)),
+            col(MIN_PERIOD),
+        ])?;
+        let last = min_horizontal([
+            granularity.period_id(col(END)).fill_null(col(MAX_PERIOD)),
+            col(MAX_PERIOD),
+        ])?;
+        let right = other
+            .with_columns([right_start.alias(START), right_end.alias(END)])
+            .cross_join(bounds, None)
+            .with_column(int_ranges(first, last + lit(1i64), lit(1i64)).alias(PERIOD))
+            .drop([MIN_PERIOD, MAX_PERIOD])
+            .explode([col(PERIOD)]);
+
+        let in_range = (col(START).is_null().or(col(TS).gt_eq(col(START))))
+            .and(col(END).is_null().or(col(TS).lt(col(END))));
+        let matches = left
+            .clone()
+            .inner_join(right, col(PERIOD), col(PERIOD))
+            .filter(in_range);
+
+        let out = if how == JoinType::Left {
+            let unmatched = left.anti_join(matches.clone().select([col(ROW)]), col(ROW), col(ROW));
+            let args = UnionArgs {
+                diagonal: true,
+                ..Default::default()
+            };
+            concat_impl([matches, unmatched], args)?
+                .sort([ROW], Default::default())
+                .drop([ROW])
+        } else {
+            matches
+        };
+        Ok(out.drop([TS, START, END, PERIOD]))
+    }
+}
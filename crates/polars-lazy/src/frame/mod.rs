@@ -2,12 +2,41 @@
 #[cfg(feature = "python")]
 mod python;
 
+#[cfg(feature = "parquet")]
+mod aggregate_state;
+#[cfg(feature = "arrow_stream")]
+mod arrow_stream;
+#[cfg(feature = "binary_format")]
+pub mod binary;
 mod cached_arenas;
+mod collect_all;
+mod conform;
+mod duplicate_keys;
 mod err;
+mod estimate;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(feature = "period_join")]
+mod join_period;
+#[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+mod paged;
+#[cfg(feature = "partition_by")]
+mod partition_by_iter;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+mod predicate_cache;
+#[cfg(feature = "repro")]
+mod repro;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+mod sink_all;
+#[cfg(feature = "pipeline_spec")]
+mod spec;
+mod table_registry;
 
 #[cfg(any(
     feature = "parquet",
@@ -18,18 +47,35 @@ pub mod pivot;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
+#[cfg(feature = "parquet")]
+pub use aggregate_state::AggregateState;
 pub use anonymous_scan::*;
+#[cfg(feature = "binary_format")]
+pub use binary::{
+    deserialize_dataframe_binary, deserialize_dataframe_with_version, read_binary_header,
+    serialize_dataframe_binary, BinaryHeader, BINARY_FORMAT_VERSION,
+};
+pub use collect_all::{collect_all_with_options, CollectAllOptions};
+pub use conform::{ConformCast, ConformOptions, ExtraColumns, MissingColumns};
 #[cfg(feature = "csv")]
 pub use csv::*;
+pub use duplicate_keys::DuplicateKeyPolicy;
+pub use estimate::{OperatorEstimate, ResourceEstimate};
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+#[cfg(feature = "period_join")]
+pub use join_period::PeriodGranularity;
 #[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+#[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+pub use paged::PagedFrames;
+#[cfg(feature = "partition_by")]
+pub use partition_by_iter::PartitionByIter;
 use polars_core::prelude::*;
 use polars_expr::{create_physical_expr, ExpressionConversionState};
 use polars_io::RowIndex;
@@ -37,7 +83,20 @@ use polars_mem_engine::{create_physical_plan, Executor};
 use polars_ops::frame::JoinCoalesce;
 pub use polars_plan::frame::{AllowedOptimizations, OptState};
 use polars_plan::global::FETCH_ROWS;
+pub use predicate_cache::{PredicateCache, PredicateCacheStats};
+#[cfg(feature = "repro")]
+pub use repro::{ReproBundle, ReproInput, ReproOptions};
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+pub use sink_all::{sink_all, SinkAllOptions, SinkAllResult, SinkOutcome, SinkQuery};
 use smartstring::alias::String as SmartString;
+#[cfg(feature = "pipeline_spec")]
+pub use spec::*;
+pub use table_registry::{TableInfo, TableRegistry, TableRegistryOptions};
 
 use crate::frame::cached_arenas::CachedArena;
 #[cfg(feature = "streaming")]
@@ -60,12 +119,30 @@ impl IntoLazy for DataFrame {
     }
 }
 
+impl From<LazyFrame> for DslPlan {
+    fn from(lf: LazyFrame) -> Self {
+        lf.logical_plan
+    }
+}
+
 impl IntoLazy for LazyFrame {
     fn lazy(self) -> LazyFrame {
         self
     }
 }
 
+/// Execute a physical plan, interrupting its kernels when the query is cancelled.
+pub(crate) fn execute_interruptible(
+    physical_plan: &mut dyn Executor,
+    state: &mut ExecutionState,
+) -> PolarsResult<DataFrame> {
+    let cancel_token = state.cancel_token();
+    let budget = state.memory_budget().cloned();
+    polars_utils::interrupt::catch_interrupt(Some(cancel_token), || {
+        polars_utils::memory_budget::with_memory_budget(budget, || physical_plan.execute(state))
+    })
+}
+
 /// Lazy abstraction over an eager `DataFrame`.
 /// It really is an abstraction over a logical plan. The methods of this struct will incrementally
 /// modify a logical plan until output is requested (via [`collect`](crate::frame::LazyFrame::collect)).
@@ -150,6 +227,7 @@ impl LazyFrame {
             fast_projection: false,
             row_estimate: false,
             new_streaming: false,
+            low_latency: false,
         })
     }
 
@@ -220,6 +298,14 @@ impl LazyFrame {
         self
     }
 
+    /// Skip the optimizations and the parallelism that only pay off on large data if the sources
+    /// of the query have at most `POLARS_LOW_LATENCY_ROW_LIMIT` rows (default `10_000`). This is
+    /// meant for services that run many queries on tiny frames.
+    pub fn with_low_latency(mut self, toggle: bool) -> Self {
+        self.opt_state.low_latency = toggle;
+        self
+    }
+
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.eager = toggle;
@@ -438,6 +524,71 @@ impl LazyFrame {
         })
     }
 
+    /// Rename the columns to normalized names: convert them to `options.case`, optionally
+    /// restricted to ASCII, and resolve the names that clash after normalization.
+    ///
+    /// This resolves the schema of the [`LazyFrame`].
+    #[cfg(feature = "rename_normalized")]
+    pub fn rename_normalized(mut self, options: NormalizeNamesOptions) -> PolarsResult<Self> {
+        let schema = self.schema()?;
+        let existing = schema.iter_names().collect::<Vec<_>>();
+        let new = polars_ops::frame::normalize_names(&existing, &options)?;
+        Ok(self.rename(existing, new))
+    }
+
+    /// Infer the semantic types of the String columns, like e-mail addresses or URLs, see
+    /// [`SemanticTypeInference`].
+    ///
+    /// The first [`sample_size`](SemanticTypeOptions::sample_size) non-null values of every String
+    /// column are collected to infer the types. If the options of the inference ask for it, the
+    /// returned [`LazyFrame`] validates the columns of which the type was inferred.
+    #[cfg(feature = "semantic_types")]
+    pub fn infer_semantic_types(
+        mut self,
+        inference: SemanticTypeInference,
+    ) -> PolarsResult<(Self, SemanticTypes)> {
+        let schema = self.schema()?;
+        let sample_size = inference.options().sample_size;
+        let exprs = schema
+            .iter()
+            .filter(|(_, dtype)| *dtype == &DataType::String)
+            .map(|(name, _)| col(name).drop_nulls().head(Some(sample_size)).implode())
+            .collect::<Vec<_>>();
+        if exprs.is_empty() {
+            return Ok((self, Default::default()));
+        }
+        // The samples have different lengths, so they are collected as a single row of lists.
+        let samples = self.clone().select(exprs).collect()?;
+        let types = samples
+            .get_columns()
+            .iter()
+            .map(|s| {
+                let mut sample = s.list()?.get_as_series(0).unwrap();
+                sample.rename(s.name());
+                inference.infer_column(&sample)
+            })
+            .filter_map(|m| m.transpose())
+            .collect::<PolarsResult<SemanticTypes>>()?;
+
+        if !inference.options().validate || types.is_empty() {
+            return Ok((self, types));
+        }
+        let inference = Arc::new(inference);
+        let exprs = types
+            .iter()
+            .map(|m| {
+                let inference = inference.clone();
+                let semantic_type = m.semantic_type.clone();
+                let dtype = inference.validated_dtype(&semantic_type)?;
+                Ok(col(&m.column).map(
+                    move |s| inference.validate_column(&s, &semantic_type).map(Some),
+                    GetOutput::from_type(dtype),
+                ))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok((self.with_columns(exprs), types))
+    }
+
     /// Removes columns from the DataFrame.
     /// Note that it's better to only select the columns you need
     /// and let the projection pushdown optimize away the unneeded columns.
@@ -556,6 +707,16 @@ impl LazyFrame {
         }])
     }
 
+    /// Reorder, add, drop and cast columns so that the result has exactly the `schema` given,
+    /// in a single projection.
+    ///
+    /// This resolves the schema of the [`LazyFrame`].
+    pub fn conform_to(mut self, schema: &Schema, options: ConformOptions) -> PolarsResult<Self> {
+        let input = self.schema()?;
+        let exprs = conform::conform_exprs(&input, schema, options)?;
+        Ok(self.select(exprs))
+    }
+
     /// Fetch is like a collect operation, but it overwrites the number of rows read by every scan
     /// operation. This is a utility that helps debug a query on a smaller number of rows.
     ///
@@ -677,9 +838,21 @@ impl LazyFrame {
         } else {
             true
         };
+        let state = ExecutionState::new();
+        if let Some(hooks) = state.hooks() {
+            let plan = IRPlanRef {
+                lp_top,
+                lp_arena: &lp_arena,
+                expr_arena: &expr_arena,
+            }
+            .describe();
+            let event = PlanOptimizedEvent { plan: &plan };
+            for hook in hooks.iter() {
+                hook.on_plan_optimized(&event);
+            }
+        }
         let physical_plan = create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
 
-        let state = ExecutionState::new();
         Ok((state, physical_plan, no_file_sink))
     }
 
@@ -689,7 +862,7 @@ impl LazyFrame {
         P: Fn(Node, &mut Arena<IR>, &mut Arena<AExpr>) -> PolarsResult<()>,
     {
         let (mut state, mut physical_plan, _) = self.prepare_collect_post_opt(false, post_opt)?;
-        physical_plan.execute(&mut state)
+        execute_interruptible(physical_plan.as_mut(), &mut state)
     }
 
     #[allow(unused_mut)]
@@ -747,7 +920,7 @@ impl LazyFrame {
     pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
         let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
         state.time_nodes();
-        let out = physical_plan.execute(&mut state)?;
+        let out = execute_interruptible(physical_plan.as_mut(), &mut state)?;
         let timer_df = state.finish_timer()?;
         Ok((out, timer_df))
     }
@@ -770,6 +943,80 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into parquet files of `rows_per_part` rows in the directory `dir`,
+    /// and checkpoint the progress after every file.
+    ///
+    /// If the query is interrupted, running the same query again resumes after the last
+    /// complete file. Its rows are skipped at the scan if the slice can be pushed down,
+    /// otherwise they are computed again but not written. Only the written output is
+    /// checkpointed: the state of a group_by or join is built again. The query must produce its
+    /// rows in a deterministic order for the resumed output to be correct.
+    ///
+    /// The files can be read with a glob, e.g. `scan_parquet("dir/*.parquet")`.
+    #[cfg(feature = "parquet")]
+    pub fn sink_parquet_checkpointed(
+        self,
+        dir: impl AsRef<Path>,
+        options: ParquetWriteOptions,
+        rows_per_part: usize,
+    ) -> PolarsResult<()> {
+        use std::hash::{Hash, Hasher};
+
+        use polars_io::parquet::write::Checkpoint;
+
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.describe_plan()?.hash(&mut hasher);
+        let fingerprint = hasher.finish();
+
+        let rows = match Checkpoint::load(dir)? {
+            Some(checkpoint) => {
+                polars_ensure!(
+                    checkpoint.fingerprint == fingerprint,
+                    ComputeError: "the checkpoint in '{}' was written by a different query",
+                    dir.display()
+                );
+                if checkpoint.complete {
+                    return Ok(());
+                }
+                checkpoint.rows
+            },
+            None => 0,
+        };
+
+        // Only resume from a slice that is pushed down, a slice that remains in the plan would
+        // materialize the rest of the output.
+        let (lf, skip_rows) = if rows > 0 {
+            let resumed = self.clone().slice(rows as i64, IdxSize::MAX);
+            let plan = resumed.clone().to_alp_optimized()?;
+            let has_slice = (&plan.lp_arena)
+                .iter(plan.lp_top)
+                .any(|(_, lp)| matches!(lp, IR::Slice { .. }));
+            if has_slice {
+                (self, rows)
+            } else {
+                (resumed, 0)
+            }
+        } else {
+            (self, 0)
+        };
+
+        lf.sink(
+            SinkType::Checkpointed {
+                path: Arc::new(dir.to_path_buf()),
+                options,
+                checkpoint: CheckpointOptions {
+                    rows_per_part,
+                    fingerprint,
+                    skip_rows,
+                },
+            },
+            "collect().write_parquet()",
+        )
+    }
+
     /// Stream a query result into a parquet file on an ObjectStore-compatible cloud service. This is useful if the final result doesn't fit
     /// into memory, and where you do not want to write to a local file but to a location in the cloud.
     /// This method will return an error if the query cannot be completely done in a
@@ -832,7 +1079,7 @@ impl LazyFrame {
             ComputeError: "cannot run the whole query in a streaming order; \
                            use `collect().write_ipc()` instead"
         );
-        let _ = physical_plan.execute(&mut state)?;
+        let _ = execute_interruptible(physical_plan.as_mut(), &mut state)?;
         Ok(())
     }
 
@@ -864,6 +1111,24 @@ impl LazyFrame {
         )
     }
 
+    /// Materialize the first `n` rows of the query, as soon as they are produced.
+    ///
+    /// The query runs in the streaming engine and stops once `n` rows arrived, so expensive
+    /// pipelines can be previewed without waiting for them to complete. Contrary to
+    /// [`limit`](Self::limit) the rows are not necessarily the first rows of the result, but
+    /// the first ones to be produced. If the query cannot run in a streaming fashion, this is
+    /// equivalent to `limit(n).collect()`.
+    #[cfg(feature = "streaming")]
+    pub fn head_streaming(mut self, n: IdxSize) -> PolarsResult<DataFrame> {
+        self.opt_state.streaming = true;
+        self.logical_plan = DslPlan::Sink {
+            input: Arc::new(self.logical_plan),
+            payload: SinkType::LimitEarly { len: n as usize },
+        };
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        execute_interruptible(physical_plan.as_mut(), &mut state)
+    }
+
     #[cfg(any(
         feature = "ipc",
         feature = "parquet",
@@ -872,6 +1137,15 @@ impl LazyFrame {
         feature = "json",
     ))]
     fn sink(mut self, payload: SinkType, msg_alternative: &str) -> Result<(), PolarsError> {
+        let start = std::time::Instant::now();
+        let target = match &payload {
+            SinkType::Memory | SinkType::LimitEarly { .. } | SinkType::Channel(_) => String::new(),
+            SinkType::File { path, .. } => path.display().to_string(),
+            #[cfg(feature = "cloud")]
+            SinkType::Cloud { uri, .. } => uri.to_string(),
+            #[cfg(feature = "parquet")]
+            SinkType::Checkpointed { path, .. } => path.display().to_string(),
+        };
         self.opt_state.streaming = true;
         self.logical_plan = DslPlan::Sink {
             input: Arc::new(self.logical_plan),
@@ -883,7 +1157,16 @@ impl LazyFrame {
             ComputeError: format!("cannot run the whole query in a streaming order; \
             use `{msg_alternative}` instead", msg_alternative=msg_alternative)
         );
-        let _ = physical_plan.execute(&mut state)?;
+        let _ = execute_interruptible(physical_plan.as_mut(), &mut state)?;
+        if let Some(hooks) = state.hooks() {
+            let event = SinkCompleteEvent {
+                target: &target,
+                elapsed: start.elapsed(),
+            };
+            for hook in hooks.iter() {
+                hook.on_sink_complete(&event);
+            }
+        }
         Ok(())
     }
 
@@ -1500,13 +1783,23 @@ impl LazyFrame {
 
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
     pub fn explode<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, columns: E) -> LazyFrame {
+        self.explode_with_options(columns, ExplodeOptions::default())
+    }
+
+    /// Apply explode operation with [`ExplodeOptions`].
+    /// [See eager explode](polars_core::frame::DataFrame::explode_with_options).
+    pub fn explode_with_options<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        columns: E,
+        options: ExplodeOptions,
+    ) -> LazyFrame {
         let columns = columns
             .as_ref()
             .iter()
             .map(|e| e.clone().into())
             .collect::<Vec<_>>();
         let opt_state = self.get_opt_state();
-        let lp = self.get_plan_builder().explode(columns).build();
+        let lp = self.get_plan_builder().explode(columns, options).build();
         Self::from_logical_plan(lp, opt_state)
     }
 
@@ -1657,6 +1950,27 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Apply a user-defined streaming [`Operator`](crate::operators::Operator) to this query.
+    ///
+    /// On the streaming engine every thread runs its own split of `operator`. On the in-memory
+    /// engine `operator` processes the whole `DataFrame` at once.
+    /// `schema` computes the output schema; if `None` the input schema is kept.
+    #[cfg(feature = "custom_operators")]
+    pub fn map_streaming(
+        self,
+        operator: Box<dyn polars_pipe::operators::Operator>,
+        schema: Option<Arc<dyn UdfSchema>>,
+        name: Option<&'static str>,
+    ) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let function = Arc::new(polars_pipe::operators::OperatorNode::new(operator));
+        let lp = self
+            .get_plan_builder()
+            .map_streaming(function, schema, name.unwrap_or("STREAMING UDF"))
+            .build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
     #[cfg(feature = "python")]
     pub fn map_python(
         self,
@@ -1722,11 +2036,60 @@ impl LazyFrame {
     /// inserted as columns.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest<I: IntoIterator<Item = S>, S: AsRef<str>>(self, cols: I) -> Self {
+        self.unnest_with_options(cols, UnnestOptions::default())
+    }
+
+    /// Unnest the given `Struct` columns and their nested `Struct` fields, see
+    /// [`DataFrame::unnest_recursive`].
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_recursive<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        self,
+        cols: I,
+        max_depth: Option<usize>,
+        separator: Option<&str>,
+    ) -> Self {
+        let options = UnnestOptions {
+            max_depth,
+            separator: separator.map(|s| s.into()),
+        };
+        self.unnest_with_options(cols, options)
+    }
+
+    /// Unnest the given `Struct` columns, and the `Struct` fields of those columns up to
+    /// [`UnnestOptions::max_depth`] levels deep.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with_options<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        self,
+        cols: I,
+        options: UnnestOptions,
+    ) -> Self {
         self.map_private(DslFunction::FunctionNode(FunctionNode::Unnest {
             columns: cols.into_iter().map(|s| Arc::from(s.as_ref())).collect(),
+            options,
         }))
     }
 
+    /// Unnest every `Struct` column one level deep, into a column per field named
+    /// `{column}{separator}{field}`. This flattens aggregations that return a `Struct`, e.g. of
+    /// [`LazyGroupBy::agg_with_names`], into wide columns.
+    #[cfg(feature = "dtype-struct")]
+    pub fn flatten_agg_struct(mut self, separator: &str) -> PolarsResult<LazyFrame> {
+        let schema = self.schema()?;
+        let structs = schema
+            .iter()
+            .filter(|(_, dtype)| matches!(dtype, DataType::Struct(_)))
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>();
+        if structs.is_empty() {
+            return Ok(self);
+        }
+        let options = UnnestOptions {
+            max_depth: Some(1),
+            separator: Some(separator.into()),
+        };
+        Ok(self.unnest_with_options(structs, options))
+    }
+
     #[cfg(feature = "merge_sorted")]
     pub fn merge_sorted(self, other: LazyFrame, key: &str) -> PolarsResult<LazyFrame> {
         // The two DataFrames are temporary concatenated
@@ -1750,6 +2113,48 @@ impl LazyFrame {
     }
 }
 
+/// The name of the outermost aggregation of `expr` for [`LazyGroupBy::agg_with_names`], e.g.
+/// `sum` for `col("a").sum()`, or `None` if it is not an aggregation or has an explicit name.
+fn aggregation_name(expr: &Expr) -> Option<String> {
+    let name = match expr {
+        Expr::Agg(agg) => match agg {
+            AggExpr::Min {
+                propagate_nans: true,
+                ..
+            } => "nan_min",
+            AggExpr::Min { .. } => "min",
+            AggExpr::Max {
+                propagate_nans: true,
+                ..
+            } => "nan_max",
+            AggExpr::Max { .. } => "max",
+            AggExpr::Median(_) => "median",
+            AggExpr::NUnique(_) => "n_unique",
+            AggExpr::First(_) => "first",
+            AggExpr::Last(_) => "last",
+            AggExpr::Mean(_) => "mean",
+            AggExpr::Implode(_) => "implode",
+            AggExpr::Count(_, _) => "count",
+            AggExpr::Quantile { .. } => "quantile",
+            AggExpr::Sum(_) => "sum",
+            AggExpr::AggGroups(_) => "agg_groups",
+            AggExpr::Std(_, _) => "std",
+            AggExpr::Var(_, _) => "var",
+        },
+        // The name of a function is its name in the plan, e.g. `dt.year`, keep the last part.
+        Expr::Function { function, .. } => {
+            let function = function.to_string();
+            return Some(match function.rsplit_once('.') {
+                Some((_, name)) => name.to_string(),
+                None => function,
+            });
+        },
+        Expr::Cast { expr, .. } => return aggregation_name(expr),
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
 /// Utility struct for lazy group_by operation.
 #[derive(Clone)]
 pub struct LazyGroupBy {
@@ -1816,6 +2221,46 @@ impl LazyGroupBy {
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 
+    /// Group by and aggregate, naming the aggregations with a `template`. In the template,
+    /// `{col}` is replaced by the name of the aggregated column and `{agg}` by the name of the
+    /// aggregation, e.g. `{col}_{agg}` names `col("rain").sum()` `rain_sum`.
+    ///
+    /// The names are applied per column after the expansion of wildcards, so
+    /// `col("*").exclude(["date"]).mean()` names every column. Aggregations with an explicit
+    /// alias and expressions that are not an aggregation keep their name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///       df.lazy()
+    ///        .group_by_stable([col("date")])
+    ///        .agg_with_names(
+    ///            [col("rain").min(), col("rain").sum(), col("rain").mean()],
+    ///            "{col}_{agg}",
+    ///        )
+    /// }
+    /// ```
+    pub fn agg_with_names<E: AsRef<[Expr]>>(self, aggs: E, template: &str) -> LazyFrame {
+        let aggs = aggs
+            .as_ref()
+            .iter()
+            .map(|expr| match aggregation_name(expr) {
+                Some(agg) => {
+                    let template = template.replace("{agg}", &agg);
+                    expr.clone()
+                        .name()
+                        .map(move |name| Ok(template.replace("{col}", name)))
+                },
+                None => expr.clone(),
+            })
+            .collect::<Vec<_>>();
+        self.agg(aggs)
+    }
+
     /// Return first n rows of each group
     pub fn head(self, n: Option<usize>) -> LazyFrame {
         let keys = self
@@ -1840,6 +2285,19 @@ impl LazyGroupBy {
             .explode([col("*").exclude(&keys)])
     }
 
+    /// Aggregate the rows of every group into a `List` of `Struct`s of the columns that are not
+    /// keys, in column `name`. The rows of a group are gathered once for all columns.
+    #[cfg(feature = "dtype-struct")]
+    pub fn agg_groups_struct(self, name: &str) -> LazyFrame {
+        let keys = self
+            .keys
+            .iter()
+            .filter_map(|expr| expr_output_name(expr).ok())
+            .collect::<Vec<_>>();
+
+        self.agg([as_struct(vec![col("*").exclude(&keys)]).alias(name)])
+    }
+
     /// Apply a function over the groups as a new DataFrame.
     ///
     /// **It is not recommended that you use this as materializing the DataFrame is very
@@ -1883,6 +2341,7 @@ pub struct JoinBuilder {
     validation: JoinValidation,
     coalesce: JoinCoalesce,
     join_nulls: bool,
+    duplicate_keys: Option<DuplicateKeyPolicy>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1899,6 +2358,7 @@ impl JoinBuilder {
             suffix: None,
             validation: Default::default(),
             coalesce: Default::default(),
+            duplicate_keys: None,
         }
     }
 
@@ -1976,10 +2436,22 @@ impl JoinBuilder {
         self
     }
 
+    /// What to do with keys that occur several times in the right table, see
+    /// [`DuplicateKeyPolicy`]. By default they are joined, which multiplies the matching rows of
+    /// the left table.
+    pub fn on_duplicate_keys(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = Some(policy);
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
-        let other = self.other.expect("with not set");
+        let mut other = self.other.expect("with not set");
+        let mut right_on = self.right_on;
+        if let Some(policy) = self.duplicate_keys {
+            (other, right_on) = policy.apply(other, right_on);
+        }
 
         // if any of the nodes reads from files we must activate this this plan as well.
         opt_state.file_caching |= other.opt_state.file_caching;
@@ -1999,7 +2471,7 @@ impl JoinBuilder {
             .join(
                 other.logical_plan,
                 self.left_on,
-                self.right_on,
+                right_on,
                 JoinOptions {
                     allow_parallel: self.allow_parallel,
                     force_parallel: self.force_parallel,
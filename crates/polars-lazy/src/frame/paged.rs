@@ -0,0 +1,164 @@
+//! Page through the result of a query while it runs, see [`LazyFrame::collect_paged`].
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, sync_channel, Receiver};
+
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+
+use super::*;
+
+/// The query that produces the chunks of a [`PagedFrames`].
+struct PagedQuery {
+    chunks: Receiver<DataFrame>,
+    result: Receiver<PolarsResult<DataFrame>>,
+    token: Arc<AtomicBool>,
+}
+
+/// Whether the rows of `plan` come out of a sort, of which the order is kept by the operations
+/// that follow it.
+fn ends_with_sort(plan: &DslPlan) -> bool {
+    match plan {
+        DslPlan::Sort { .. } => true,
+        DslPlan::Select { input, .. }
+        | DslPlan::HStack { input, .. }
+        | DslPlan::Filter { input, .. }
+        | DslPlan::Slice { input, .. }
+        | DslPlan::Cache { input, .. }
+        | DslPlan::MapFunction { input, .. } => ends_with_sort(input),
+        DslPlan::IR { dsl, .. } => ends_with_sort(dsl),
+        _ => false,
+    }
+}
+
+impl LazyFrame {
+    /// Run the query once in the background and page through its result, in pages of
+    /// `page_size` rows. Only the last page may be shorter.
+    ///
+    /// The query runs on the streaming engine, which sends its chunks to the pages over a
+    /// bounded buffer: the query waits while the buffer is full, so a page that isn't consumed
+    /// holds up the query rather than memory. The pages are in the order in which the chunks
+    /// arrive, which isn't the order of the rows of [`collect`](LazyFrame::collect). If the query
+    /// ends with a sort, the sorted result is materialized and the pages are in the sorted
+    /// order, as stable as the sort.
+    ///
+    /// Dropping the iterator cancels the query.
+    pub fn collect_paged(mut self, page_size: usize) -> PolarsResult<PagedFrames> {
+        polars_ensure!(page_size > 0, InvalidOperation: "`page_size` must be positive");
+
+        if ends_with_sort(&self.logical_plan) {
+            // The sort holds the whole result anyway, so the pages are slices of it.
+            let df = self.with_streaming(true).collect()?;
+            let pending_rows = df.height();
+            return Ok(PagedFrames {
+                page_size,
+                pending: vec![df],
+                pending_rows,
+                query: None,
+            });
+        }
+
+        let (chunk_tx, chunks) = sync_channel(POOL.current_num_threads());
+        self.opt_state.streaming = true;
+        self.logical_plan = DslPlan::Sink {
+            input: Arc::new(self.logical_plan),
+            payload: SinkType::Channel(ChunkSender(Arc::new(chunk_tx))),
+        };
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+
+        let (result_tx, result) = channel();
+        let token = state.cancel_token();
+        POOL.spawn_fifo(move || {
+            let out = execute_interruptible(physical_plan.as_mut(), &mut state);
+            // The plan holds the sender of the chunks, dropping it ends the pages.
+            drop(physical_plan);
+            let _ = result_tx.send(out);
+        });
+
+        Ok(PagedFrames {
+            page_size,
+            pending: vec![],
+            pending_rows: 0,
+            query: Some(PagedQuery {
+                chunks,
+                result,
+                token,
+            }),
+        })
+    }
+}
+
+/// An iterator over the pages of the result of a query, see [`LazyFrame::collect_paged`].
+#[must_use]
+pub struct PagedFrames {
+    page_size: usize,
+    /// The chunks that were received but not yielded yet.
+    pending: Vec<DataFrame>,
+    pending_rows: usize,
+    /// `None` once all chunks are received.
+    query: Option<PagedQuery>,
+}
+
+impl PagedFrames {
+    /// Receive chunks until a page is pending or the query is done.
+    fn fill(&mut self) -> PolarsResult<()> {
+        while self.pending_rows < self.page_size {
+            let Some(query) = &self.query else {
+                return Ok(());
+            };
+            match query.chunks.recv() {
+                Ok(df) => {
+                    self.pending_rows += df.height();
+                    self.pending.push(df);
+                },
+                // All senders are dropped, so the query is done.
+                Err(_) => {
+                    let result = query.result.recv();
+                    self.query = None;
+                    match result {
+                        Ok(result) => {
+                            result?;
+                        },
+                        Err(_) => polars_bail!(ComputeError: "the paged query panicked"),
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Take the next page off the pending chunks.
+    fn take_page(&mut self) -> DataFrame {
+        // Concatenate without rechunking, the pages are zero copy slices of the chunks.
+        let df = accumulate_dataframes_vertical_unchecked(std::mem::take(&mut self.pending));
+        if df.height() <= self.page_size {
+            self.pending_rows = 0;
+            return df;
+        }
+        let page = df.slice(0, self.page_size);
+        let rest = df.slice(self.page_size as i64, df.height() - self.page_size);
+        self.pending_rows = rest.height();
+        self.pending.push(rest);
+        page
+    }
+}
+
+impl Iterator for PagedFrames {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(err) = self.fill() {
+            self.pending.clear();
+            self.pending_rows = 0;
+            return Some(Err(err));
+        }
+        (self.pending_rows > 0).then(|| Ok(self.take_page()))
+    }
+}
+
+impl Drop for PagedFrames {
+    fn drop(&mut self) {
+        if let Some(query) = &self.query {
+            query.token.store(true, Ordering::Relaxed);
+        }
+    }
+}
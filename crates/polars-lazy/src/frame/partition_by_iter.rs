@@ -0,0 +1,127 @@
+//! Split a frame into the partitions of its keys without materializing all of them, see
+//! [`LazyFrame::partition_by_iter`].
+use std::collections::VecDeque;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+const DEFAULT_GROUPS_PER_PASS: usize = 256;
+
+/// An iterator over the partitions of a [`LazyFrame`] by its key columns, in the sorted order of
+/// the keys. See [`LazyFrame::partition_by_iter`].
+#[must_use]
+pub struct PartitionByIter {
+    lf: LazyFrame,
+    by: Vec<String>,
+    include_key: bool,
+    groups_per_pass: usize,
+    /// The distinct keys in sorted order, collected once the first partition is requested.
+    keys: Option<DataFrame>,
+    /// The row in `keys` of the first group of the next pass.
+    offset: usize,
+    /// The partitions of the last pass that were not yielded yet.
+    partitions: VecDeque<DataFrame>,
+    finished: bool,
+}
+
+impl PartitionByIter {
+    /// The number of groups of which the partitions are collected in a single pass over the
+    /// frame, 256 by default. More groups per pass need fewer passes, but more memory.
+    pub fn with_groups_per_pass(mut self, groups_per_pass: usize) -> Self {
+        self.groups_per_pass = groups_per_pass.max(1);
+        self
+    }
+
+    fn key_exprs(&self) -> Vec<Expr> {
+        self.by.iter().map(|name| col(name)).collect()
+    }
+
+    /// Collect the partitions of the next groups, returns `false` if there are no groups left.
+    fn next_pass(&mut self) -> PolarsResult<bool> {
+        if self.keys.is_none() {
+            let keys = self
+                .lf
+                .clone()
+                .select(self.key_exprs())
+                .unique(None, UniqueKeepStrategy::Any)
+                .with_streaming(true)
+                .collect()?
+                .sort(self.by.clone(), Default::default())?;
+            self.keys = Some(keys);
+        }
+        let keys = self.keys.as_ref().unwrap();
+        if self.offset >= keys.height() {
+            return Ok(false);
+        }
+        let pass_keys = keys.slice(self.offset as i64, self.groups_per_pass);
+        self.offset += pass_keys.height();
+
+        // The keys of the pass are the small side of the join, so the frame is streamed through
+        // it and only the rows of the pass are kept.
+        let on = self.key_exprs();
+        let mut args = JoinArgs::new(JoinType::Inner);
+        args.join_nulls = true;
+        let df = self
+            .lf
+            .clone()
+            .join(pass_keys.lazy(), &on, &on, args)
+            .with_streaming(true)
+            .collect()?
+            .sort(
+                self.by.clone(),
+                SortMultipleOptions::default().with_maintain_order(true),
+            )?;
+        self.partitions
+            .extend(df.partition_by_stable(self.by.clone(), self.include_key)?);
+        Ok(true)
+    }
+}
+
+impl Iterator for PartitionByIter {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(df) = self.partitions.pop_front() {
+                return Some(Ok(df));
+            }
+            if self.finished {
+                return None;
+            }
+            match self.next_pass() {
+                Ok(true) => {},
+                Ok(false) => self.finished = true,
+                Err(err) => {
+                    self.finished = true;
+                    return Some(Err(err));
+                },
+            }
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Split into a DataFrame per group of the columns `by`, one group after the other.
+    ///
+    /// Unlike [`DataFrame::partition_by`], the partitions are not materialized at once: the
+    /// distinct keys are collected with the streaming engine and the frame is streamed once per
+    /// [`with_groups_per_pass`](PartitionByIter::with_groups_per_pass) groups, of which only the
+    /// partitions are kept in memory. The partitions are yielded in the sorted order of their
+    /// keys, the order of the rows in a partition is not maintained.
+    ///
+    /// The frame is evaluated once per pass, so an expensive query should be cached to disk
+    /// first.
+    pub fn partition_by_iter(self, by: impl IntoVec<String>, include_key: bool) -> PartitionByIter {
+        PartitionByIter {
+            lf: self,
+            by: by.into_vec(),
+            include_key,
+            groups_per_pass: DEFAULT_GROUPS_PER_PASS,
+            keys: None,
+            offset: 0,
+            partitions: VecDeque::new(),
+            finished: false,
+        }
+    }
+}
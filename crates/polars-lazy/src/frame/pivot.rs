@@ -8,7 +8,7 @@
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
-use polars_ops::pivot::PivotAgg;
+use polars_ops::pivot::{PivotAgg, PivotOptions};
 
 use crate::physical_plan::exotic::{prepare_eval_expr, prepare_expression_for_context};
 use crate::prelude::*;
@@ -81,3 +81,40 @@ where
     });
     polars_ops::pivot::pivot_stable(df, on, index, values, sort_columns, agg_expr, separator)
 }
+
+/// Pivot with several aggregations at once, see [`polars_ops::pivot::pivot_multiple`]. The
+/// aggregations are named by their output name, which must be distinct if there are several.
+pub fn pivot_multiple<I0, I1, I2, S0, S1, S2>(
+    df: &DataFrame,
+    on: I0,
+    index: Option<I1>,
+    values: Option<I2>,
+    agg_exprs: Vec<Expr>,
+    options: &PivotOptions,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    I2: IntoIterator<Item = S2>,
+    S0: AsRef<str>,
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    let n_aggs = agg_exprs.len();
+    let aggs = agg_exprs
+        .into_iter()
+        .map(|agg_expr| {
+            let name = expr_output_name(&agg_expr)
+                .map(|name| name.to_string())
+                .unwrap_or_default();
+            polars_ensure!(
+                n_aggs == 1 || !name.is_empty(),
+                InvalidOperation: "the aggregations of a pivot with several aggregations must be named, use `alias`"
+            );
+            // make sure that the root column is replaced
+            let expr = prepare_eval_expr(agg_expr);
+            Ok((name, PivotAgg::Expr(Arc::new(PivotExpr(expr)))))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    polars_ops::pivot::pivot_multiple(df, on, index, values, aggs, options)
+}
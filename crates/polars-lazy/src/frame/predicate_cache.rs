@@ -0,0 +1,167 @@
+//! A DataFrame that caches the masks of the predicates it is filtered with, see
+//! [`PredicateCache`].
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// The number of cache hits and misses of a [`PredicateCache`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PredicateCacheStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+#[derive(Default)]
+struct MaskState {
+    /// The mask of every cached predicate and the tick at which it was last used.
+    masks: PlHashMap<Expr, (BooleanChunked, u64)>,
+    tick: u64,
+    stats: PredicateCacheStats,
+}
+
+/// An in-memory DataFrame that caches the boolean masks of the predicates it is filtered with.
+///
+/// A predicate is split into the conditions that are combined with `&`, and the mask of every
+/// condition is cached by its expression. Queries with overlapping predicates, like those of a
+/// dashboard that filters on several inputs, only evaluate the conditions they don't share. The
+/// masks are dropped when the DataFrame is mutated through [`df_mut`](Self::df_mut).
+pub struct PredicateCache {
+    df: DataFrame,
+    max_masks: Option<usize>,
+    state: Mutex<MaskState>,
+}
+
+/// Split `predicate` into the conditions that are combined with `&`.
+fn split_conjunction(predicate: Expr, out: &mut Vec<Expr>) {
+    match predicate {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            split_conjunction(Arc::unwrap_or_clone(left), out);
+            split_conjunction(Arc::unwrap_or_clone(right), out);
+        },
+        predicate => out.push(predicate),
+    }
+}
+
+impl PredicateCache {
+    pub fn new(df: DataFrame) -> Self {
+        Self {
+            df,
+            max_masks: None,
+            state: Default::default(),
+        }
+    }
+
+    /// Keep at most `max_masks` masks, the least recently used masks are dropped first.
+    pub fn with_max_masks(mut self, max_masks: usize) -> Self {
+        self.max_masks = Some(max_masks);
+        self
+    }
+
+    pub fn df(&self) -> &DataFrame {
+        &self.df
+    }
+
+    /// Mutable access to the DataFrame. This drops the cached masks.
+    pub fn df_mut(&mut self) -> &mut DataFrame {
+        self.clear();
+        &mut self.df
+    }
+
+    /// Drop the cached masks.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().masks.clear()
+    }
+
+    pub fn stats(&self) -> PredicateCacheStats {
+        self.state.lock().unwrap().stats
+    }
+
+    /// Evaluate a single condition. Null values are `false`, like in a filter.
+    fn evaluate(&self, condition: &Expr) -> PolarsResult<BooleanChunked> {
+        let out = self
+            .df
+            .clone()
+            .lazy()
+            .select([condition.clone().alias("mask")])
+            .collect()?;
+        let mask = out.column("mask")?;
+        polars_ensure!(
+            mask.dtype() == &DataType::Boolean,
+            ComputeError: "filter predicate must be of type `Boolean`, got `{}`", mask.dtype()
+        );
+        let mask = if mask.len() == 1 && self.df.height() != 1 {
+            mask.new_from_index(0, self.df.height())
+        } else {
+            mask.clone()
+        };
+        Ok(mask.bool()?.fill_null_with_values(false)?.rechunk())
+    }
+
+    /// The mask of `predicate`, of which the masks of the conditions are cached.
+    pub fn mask(&self, predicate: Expr) -> PolarsResult<BooleanChunked> {
+        let mut conditions = vec![];
+        split_conjunction(predicate, &mut conditions);
+
+        let mut out: Option<BooleanChunked> = None;
+        for condition in conditions {
+            let cached = {
+                let mut state = self.state.lock().unwrap();
+                state.tick += 1;
+                let tick = state.tick;
+                let cached = state.masks.get_mut(&condition).map(|(mask, last_use)| {
+                    *last_use = tick;
+                    mask.clone()
+                });
+                if cached.is_some() {
+                    state.stats.hits += 1;
+                } else {
+                    state.stats.misses += 1;
+                }
+                cached
+            };
+            // The lock isn't held while evaluating, concurrent misses may evaluate twice.
+            let mask = match cached {
+                Some(mask) => mask,
+                None => {
+                    let mask = self.evaluate(&condition)?;
+                    self.insert(condition, mask.clone());
+                    mask
+                },
+            };
+            out = Some(match out {
+                Some(out) => &out & &mask,
+                None => mask,
+            });
+        }
+        Ok(out.unwrap())
+    }
+
+    fn insert(&self, condition: Expr, mask: BooleanChunked) {
+        let mut state = self.state.lock().unwrap();
+        let tick = state.tick;
+        state.masks.insert(condition, (mask, tick));
+        if let Some(max_masks) = self.max_masks {
+            while state.masks.len() > max_masks {
+                let lru = state
+                    .masks
+                    .iter()
+                    .min_by_key(|(_, (_, last_use))| *last_use)
+                    .map(|(expr, _)| expr.clone())
+                    .unwrap();
+                state.masks.remove(&lru);
+            }
+        }
+    }
+
+    /// Filter the DataFrame by `predicate`, using and filling the cache of masks.
+    pub fn filter(&self, predicate: Expr) -> PolarsResult<DataFrame> {
+        let mask = self.mask(predicate)?;
+        self.df.filter(&mask)
+    }
+}
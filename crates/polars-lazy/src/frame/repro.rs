@@ -0,0 +1,224 @@
+//! Save a query together with its inputs into a single file, so that it can be attached to a bug
+//! report and replayed with [`LazyFrame::load_repro`].
+use std::collections::BTreeMap;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_utils::{create_file, open_file};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+const REPRO_FORMAT_VERSION: u32 = 1;
+
+/// Options that control how much of the inputs of a query is stored by
+/// [`LazyFrame::save_repro_with_options`].
+#[derive(Copy, Clone, Debug)]
+pub struct ReproOptions {
+    /// Inputs of at most this many bytes are stored in full.
+    pub full_data_threshold: usize,
+    /// Larger inputs are replaced by their first `sample_rows` rows.
+    pub sample_rows: IdxSize,
+}
+
+impl Default for ReproOptions {
+    fn default() -> Self {
+        ReproOptions {
+            full_data_threshold: 10 * 1024 * 1024,
+            sample_rows: 1000,
+        }
+    }
+}
+
+/// Describes one input of a query that was stored in a [`ReproBundle`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReproInput {
+    /// Where the input originally came from, e.g. the scanned paths.
+    pub source: String,
+    /// The number of rows that was stored.
+    pub height: usize,
+    /// Whether only the first rows of the input were stored.
+    pub sampled: bool,
+}
+
+/// A query with its inputs replaced by in-memory frames, together with the optimization flags
+/// and the `POLARS_*` environment variables it was run with.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReproBundle {
+    /// Version of the bundle format.
+    pub format_version: u32,
+    /// Version of polars that wrote the bundle.
+    pub polars_version: String,
+    pub plan: DslPlan,
+    pub opt_state: OptState,
+    /// The `POLARS_*` environment variables that were set. These are not applied when the bundle
+    /// is loaded.
+    pub env: BTreeMap<String, String>,
+    /// The inputs of the query, in the order they were encountered in the plan.
+    pub inputs: Vec<ReproInput>,
+}
+
+impl ReproBundle {
+    /// Read a bundle written by [`LazyFrame::save_repro`].
+    pub fn read<P: AsRef<Path>>(path: P) -> PolarsResult<Self> {
+        let file = open_file(path.as_ref())?;
+        let bundle: ReproBundle =
+            serde_json::from_reader(BufReader::new(file)).map_err(to_compute_err)?;
+        polars_ensure!(
+            bundle.format_version <= REPRO_FORMAT_VERSION,
+            ComputeError: "repro bundle has format version {}, but at most {} is supported",
+            bundle.format_version, REPRO_FORMAT_VERSION
+        );
+        Ok(bundle)
+    }
+
+    /// Write the bundle to `path`.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> PolarsResult<()> {
+        let file = create_file(path.as_ref())?;
+        serde_json::to_writer(BufWriter::new(file), self).map_err(to_compute_err)
+    }
+
+    /// The query of the bundle with the optimization flags it was saved with.
+    pub fn lazy_frame(&self) -> LazyFrame {
+        LazyFrame::from(self.plan.clone()).with_optimizations(self.opt_state)
+    }
+}
+
+impl LazyFrame {
+    /// Save the query, its optimization flags and its inputs into a single file that can be loaded
+    /// with [`LazyFrame::load_repro`].
+    ///
+    /// Inputs are stored in full if they are small, and sampled otherwise. See [`ReproOptions`].
+    pub fn save_repro<P: AsRef<Path>>(&self, path: P) -> PolarsResult<()> {
+        self.save_repro_with_options(path, ReproOptions::default())
+    }
+
+    /// Save the query into a single file, see [`LazyFrame::save_repro`].
+    pub fn save_repro_with_options<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: ReproOptions,
+    ) -> PolarsResult<()> {
+        self.to_repro_bundle(options)?.write(path)
+    }
+
+    /// Capture the query and its inputs into a [`ReproBundle`].
+    pub fn to_repro_bundle(&self, options: ReproOptions) -> PolarsResult<ReproBundle> {
+        let mut inputs = vec![];
+        let plan = capture_inputs(&self.logical_plan, &options, &mut inputs)?;
+        let env = std::env::vars()
+            .filter(|(key, _)| key.starts_with("POLARS_"))
+            .collect();
+
+        Ok(ReproBundle {
+            format_version: REPRO_FORMAT_VERSION,
+            polars_version: env!("CARGO_PKG_VERSION").to_string(),
+            plan,
+            opt_state: self.opt_state,
+            env,
+            inputs,
+        })
+    }
+
+    /// Load a query that was saved with [`LazyFrame::save_repro`].
+    pub fn load_repro<P: AsRef<Path>>(path: P) -> PolarsResult<LazyFrame> {
+        Ok(ReproBundle::read(path)?.lazy_frame())
+    }
+}
+
+fn in_memory(df: DataFrame) -> DslPlan {
+    DslBuilder::from_existing_df(df).build()
+}
+
+/// Replace every scan in the plan by an in-memory frame that holds (a sample of) its data.
+fn capture_inputs(
+    plan: &DslPlan,
+    options: &ReproOptions,
+    inputs: &mut Vec<ReproInput>,
+) -> PolarsResult<DslPlan> {
+    let mut capture = |input: &DslPlan| capture_inputs(input, options, inputs);
+    let mut plan = plan.clone();
+    match &mut plan {
+        DslPlan::Scan { paths, .. } => {
+            let source = paths
+                .iter()
+                .map(|p| p.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(", ");
+            // Only local files have a known size, remote files are always sampled.
+            let size = paths.iter().try_fold(0u64, |acc, p| {
+                std::fs::metadata(p).ok().map(|m| acc + m.len())
+            });
+            let sampled = size.map_or(true, |size| size > options.full_data_threshold as u64);
+
+            let lf = LazyFrame::from(plan.clone());
+            let df = if sampled {
+                lf.limit(options.sample_rows).collect()?
+            } else {
+                lf.collect()?
+            };
+            inputs.push(ReproInput {
+                source,
+                height: df.height(),
+                sampled,
+            });
+            return Ok(in_memory(df));
+        },
+        #[cfg(feature = "python")]
+        DslPlan::PythonScan { .. } => {
+            let df = LazyFrame::from(plan.clone())
+                .limit(options.sample_rows)
+                .collect()?;
+            inputs.push(ReproInput {
+                source: "python scan".to_string(),
+                height: df.height(),
+                sampled: true,
+            });
+            return Ok(in_memory(df));
+        },
+        DslPlan::DataFrameScan { df, .. } => {
+            let sampled = df.estimated_size() > options.full_data_threshold;
+            if sampled {
+                *df = Arc::new(df.head(Some(options.sample_rows as usize)));
+            }
+            inputs.push(ReproInput {
+                source: "in-memory DataFrame".to_string(),
+                height: df.height(),
+                sampled,
+            });
+        },
+        DslPlan::Filter { input, .. }
+        | DslPlan::Cache { input, .. }
+        | DslPlan::Select { input, .. }
+        | DslPlan::GroupBy { input, .. }
+        | DslPlan::HStack { input, .. }
+        | DslPlan::Distinct { input, .. }
+        | DslPlan::Sort { input, .. }
+        | DslPlan::Slice { input, .. }
+        | DslPlan::MapFunction { input, .. }
+        | DslPlan::Sink { input, .. } => *input = Arc::new(capture(input)?),
+        DslPlan::Join {
+            input_left,
+            input_right,
+            ..
+        } => {
+            *input_left = Arc::new(capture(input_left)?);
+            *input_right = Arc::new(capture(input_right)?);
+        },
+        DslPlan::Union { inputs, .. } | DslPlan::HConcat { inputs, .. } => {
+            for input in inputs.iter_mut() {
+                *input = capture(input)?;
+            }
+        },
+        DslPlan::ExtContext { input, contexts } => {
+            *input = Arc::new(capture(input)?);
+            for context in contexts.iter_mut() {
+                *context = capture(context)?;
+            }
+        },
+        DslPlan::IR { dsl, .. } => return capture(dsl),
+    }
+    Ok(plan)
+}
@@ -0,0 +1,136 @@
+//! Run many sinks at once and report the outcome of every sink, see [`sink_all`].
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use polars_core::prelude::*;
+use polars_core::utils::rayon::prelude::*;
+use polars_core::POOL;
+
+use crate::prelude::*;
+
+/// A query of which [`sink_all`] streams the result into the file `path`.
+#[derive(Clone)]
+pub struct SinkQuery {
+    lf: LazyFrame,
+    path: PathBuf,
+    file_type: FileType,
+}
+
+impl SinkQuery {
+    pub fn new(lf: LazyFrame, path: impl Into<PathBuf>, file_type: FileType) -> Self {
+        Self {
+            lf,
+            path: path.into(),
+            file_type,
+        }
+    }
+}
+
+/// Options for [`sink_all`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SinkAllOptions {
+    /// Keep running the other sinks if a sink fails. Otherwise the sinks that didn't start yet
+    /// are skipped once a sink fails. Sinks that already started always run to completion.
+    pub continue_on_error: bool,
+}
+
+/// The outcome of a sink of [`sink_all`].
+#[derive(Debug)]
+pub enum SinkOutcome {
+    Completed,
+    Failed(PolarsError),
+    /// The sink didn't run because another sink failed.
+    Skipped,
+}
+
+/// The outcome of every sink of [`sink_all`], in the order of the queries.
+#[derive(Debug)]
+pub struct SinkAllResult {
+    pub outcomes: Vec<(PathBuf, SinkOutcome)>,
+}
+
+impl SinkAllResult {
+    /// Whether all sinks completed.
+    pub fn is_ok(&self) -> bool {
+        self.outcomes
+            .iter()
+            .all(|(_, outcome)| matches!(outcome, SinkOutcome::Completed))
+    }
+
+    /// The errors of the sinks that failed, with their paths.
+    pub fn errors(&self) -> impl Iterator<Item = (&PathBuf, &PolarsError)> {
+        self.outcomes
+            .iter()
+            .filter_map(|(path, outcome)| match outcome {
+                SinkOutcome::Failed(err) => Some((path, err)),
+                _ => None,
+            })
+    }
+
+    /// Combine the outcomes into a single result, of which the error reports every sink that
+    /// failed.
+    pub fn into_result(self) -> PolarsResult<()> {
+        let n_sinks = self.outcomes.len();
+        let mut n_skipped = 0;
+        let mut failed = vec![];
+        for (path, outcome) in self.outcomes {
+            match outcome {
+                SinkOutcome::Completed => {},
+                SinkOutcome::Failed(err) => failed.push((path, err)),
+                SinkOutcome::Skipped => n_skipped += 1,
+            }
+        }
+        if failed.is_empty() {
+            return Ok(());
+        }
+
+        let mut msg = format!("{} of {} sinks failed", failed.len(), n_sinks);
+        if n_skipped > 0 {
+            write!(msg, " and {n_skipped} were skipped").unwrap();
+        }
+        if failed.len() == 1 {
+            let (path, err) = failed.pop().unwrap();
+            return Err(err.context(format!("{msg}: '{}'", path.display()).into()));
+        }
+        for (path, err) in failed {
+            write!(msg, "\n- '{}': {err}", path.display()).unwrap();
+        }
+        polars_bail!(ComputeError: "{}", msg)
+    }
+}
+
+/// Stream the results of many queries into files at the same time.
+///
+/// A failing sink doesn't abort the others: the outcome of every sink is reported, in the order
+/// of the queries. Use [`SinkAllResult::into_result`] to turn the outcomes into a single error.
+pub fn sink_all<I>(queries: I, options: SinkAllOptions) -> SinkAllResult
+where
+    I: IntoIterator<Item = SinkQuery>,
+{
+    let queries = queries.into_iter().collect::<Vec<_>>();
+    let failed = AtomicBool::new(false);
+    let outcomes = POOL.install(|| {
+        queries
+            .into_par_iter()
+            .map(|query| {
+                if !options.continue_on_error && failed.load(Ordering::Relaxed) {
+                    return (query.path, SinkOutcome::Skipped);
+                }
+                let payload = SinkType::File {
+                    path: Arc::new(query.path.clone()),
+                    file_type: query.file_type,
+                };
+                let outcome = match query.lf.sink(payload, "collect()") {
+                    Ok(()) => SinkOutcome::Completed,
+                    Err(err) => {
+                        failed.store(true, Ordering::Relaxed);
+                        SinkOutcome::Failed(err)
+                    },
+                };
+                (query.path, outcome)
+            })
+            .collect()
+    });
+    SinkAllResult { outcomes }
+}
@@ -0,0 +1,294 @@
+//! Build a [`LazyFrame`] from a declarative description of its sources, transformations and
+//! sink.
+//!
+//! Expressions, join types and writer options use the serde implementations of the DSL, so a
+//! pipeline can be changed by editing a JSON or YAML file rather than Rust code.
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// A query described as data: named sources, the source the query starts from, the steps
+/// applied to it and an optional sink.
+///
+/// # Example
+///
+/// ```yaml
+/// sources:
+///   trades:
+///     parquet:
+///       path: trades.parquet
+///   symbols:
+///     csv:
+///       path: symbols.csv
+/// input: trades
+/// steps:
+///   - filter: {BinaryExpr: {left: {Column: qty}, op: Gt, right: {Literal: {Int: 0}}}}
+///   - join:
+///       other: symbols
+///       left_on: [{Column: symbol}]
+///       right_on: [{Column: symbol}]
+///       how: Left
+/// sink:
+///   parquet:
+///     path: out.parquet
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PipelineSpec {
+    /// The sources of the pipeline by name.
+    pub sources: BTreeMap<String, SourceSpec>,
+    /// The name of the source the pipeline starts from.
+    pub input: String,
+    /// The steps applied to the input, in order.
+    #[serde(default)]
+    pub steps: Vec<StepSpec>,
+    /// Where the result is written. Without a sink, [`PipelineSpec::run`] returns the result.
+    #[serde(default)]
+    pub sink: Option<SinkSpec>,
+}
+
+/// A source of a [`PipelineSpec`].
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceSpec {
+    #[cfg(feature = "csv")]
+    Csv {
+        path: PathBuf,
+        #[serde(default = "default_has_header")]
+        has_header: bool,
+        #[serde(default = "default_separator")]
+        separator: char,
+    },
+    #[cfg(feature = "parquet")]
+    Parquet { path: PathBuf },
+    #[cfg(feature = "ipc")]
+    Ipc { path: PathBuf },
+    #[cfg(feature = "json")]
+    NdJson { path: PathBuf },
+    /// A serialized logical plan, e.g. the output of serializing [`LazyFrame::logical_plan`].
+    Plan(DslPlan),
+}
+
+#[cfg(feature = "csv")]
+fn default_has_header() -> bool {
+    true
+}
+
+#[cfg(feature = "csv")]
+fn default_separator() -> char {
+    ','
+}
+
+/// A step of a [`PipelineSpec`]. Every step maps to the [`LazyFrame`] method of the same name.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepSpec {
+    Select(Vec<Expr>),
+    WithColumns(Vec<Expr>),
+    Filter(Expr),
+    GroupBy {
+        by: Vec<Expr>,
+        aggs: Vec<Expr>,
+        #[serde(default)]
+        maintain_order: bool,
+    },
+    /// Join with the source named `other`.
+    Join {
+        other: String,
+        left_on: Vec<Expr>,
+        right_on: Vec<Expr>,
+        how: JoinType,
+        #[serde(default)]
+        suffix: Option<String>,
+    },
+    Sort {
+        by: Vec<Expr>,
+        #[serde(default)]
+        options: SortMultipleOptions,
+    },
+    Unique {
+        #[serde(default)]
+        subset: Option<Vec<String>>,
+        #[serde(default)]
+        keep: UniqueKeepStrategy,
+    },
+    Drop(Vec<String>),
+    Rename {
+        existing: Vec<String>,
+        new: Vec<String>,
+    },
+    Limit(IdxSize),
+}
+
+/// The sink of a [`PipelineSpec`]. The result is streamed into the file, so the query must
+/// support streaming.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SinkSpec {
+    #[cfg(feature = "parquet")]
+    Parquet {
+        path: PathBuf,
+        #[serde(default)]
+        options: ParquetWriteOptions,
+    },
+    #[cfg(feature = "ipc")]
+    Ipc {
+        path: PathBuf,
+        #[serde(default)]
+        options: IpcWriterOptions,
+    },
+    #[cfg(feature = "csv")]
+    Csv {
+        path: PathBuf,
+        #[serde(default)]
+        options: CsvWriterOptions,
+    },
+    #[cfg(feature = "json")]
+    Json {
+        path: PathBuf,
+        #[serde(default)]
+        options: JsonWriterOptions,
+    },
+}
+
+impl PipelineSpec {
+    /// Parse a pipeline from JSON.
+    pub fn from_json(json: &str) -> PolarsResult<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| polars_err!(ComputeError: "invalid pipeline spec: {}", e))
+    }
+
+    /// Parse a pipeline from YAML.
+    pub fn from_yaml(yaml: &str) -> PolarsResult<Self> {
+        serde_yaml::from_str(yaml)
+            .map_err(|e| polars_err!(ComputeError: "invalid pipeline spec: {}", e))
+    }
+
+    fn source(&self, name: &str) -> PolarsResult<LazyFrame> {
+        let source = self.sources.get(name).ok_or_else(
+            || polars_err!(ComputeError: "pipeline spec has no source named '{}'", name),
+        )?;
+        source.build()
+    }
+
+    /// Build the [`LazyFrame`] of the pipeline. The sink, if any, is not applied.
+    pub fn build(&self) -> PolarsResult<LazyFrame> {
+        self.steps
+            .iter()
+            .try_fold(self.source(&self.input)?, |lf, step| step.apply(lf, self))
+    }
+
+    /// Run the pipeline. If it has a sink the result is written to it and `None` is returned,
+    /// otherwise the result is collected.
+    pub fn run(&self) -> PolarsResult<Option<DataFrame>> {
+        let lf = self.build()?;
+        match &self.sink {
+            Some(sink) => sink.sink(lf).map(|_| None),
+            None => lf.collect().map(Some),
+        }
+    }
+}
+
+impl SourceSpec {
+    fn build(&self) -> PolarsResult<LazyFrame> {
+        match self {
+            #[cfg(feature = "csv")]
+            SourceSpec::Csv {
+                path,
+                has_header,
+                separator,
+            } => {
+                polars_ensure!(
+                    separator.is_ascii(),
+                    ComputeError: "csv separator must be a single-byte character, got '{}'", separator
+                );
+                LazyCsvReader::new(path)
+                    .with_has_header(*has_header)
+                    .with_separator(*separator as u8)
+                    .finish()
+            },
+            #[cfg(feature = "parquet")]
+            SourceSpec::Parquet { path } => LazyFrame::scan_parquet(path, Default::default()),
+            #[cfg(feature = "ipc")]
+            SourceSpec::Ipc { path } => LazyFrame::scan_ipc(path, Default::default()),
+            #[cfg(feature = "json")]
+            SourceSpec::NdJson { path } => LazyJsonLineReader::new(path).finish(),
+            SourceSpec::Plan(plan) => Ok(LazyFrame::from(plan.clone())),
+        }
+    }
+}
+
+impl StepSpec {
+    fn apply(&self, lf: LazyFrame, spec: &PipelineSpec) -> PolarsResult<LazyFrame> {
+        let lf = match self {
+            StepSpec::Select(exprs) => lf.select(exprs),
+            StepSpec::WithColumns(exprs) => lf.with_columns(exprs),
+            StepSpec::Filter(predicate) => lf.filter(predicate.clone()),
+            StepSpec::GroupBy {
+                by,
+                aggs,
+                maintain_order,
+            } => {
+                if *maintain_order {
+                    lf.group_by_stable(by).agg(aggs)
+                } else {
+                    lf.group_by(by).agg(aggs)
+                }
+            },
+            StepSpec::Join {
+                other,
+                left_on,
+                right_on,
+                how,
+                suffix,
+            } => {
+                let mut builder = lf
+                    .join_builder()
+                    .with(spec.source(other)?)
+                    .left_on(left_on)
+                    .right_on(right_on)
+                    .how(how.clone());
+                if let Some(suffix) = suffix {
+                    builder = builder.suffix(suffix);
+                }
+                builder.finish()
+            },
+            StepSpec::Sort { by, options } => lf.sort_by_exprs(by, options.clone()),
+            StepSpec::Unique { subset, keep } => lf.unique(subset.clone(), *keep),
+            StepSpec::Drop(columns) => lf.drop(columns),
+            StepSpec::Rename { existing, new } => {
+                polars_ensure!(
+                    existing.len() == new.len(),
+                    ComputeError: "`rename` step got {} existing and {} new names",
+                    existing.len(), new.len()
+                );
+                lf.rename(existing, new)
+            },
+            StepSpec::Limit(n) => lf.limit(*n),
+        };
+        Ok(lf)
+    }
+}
+
+impl SinkSpec {
+    fn sink(&self, lf: LazyFrame) -> PolarsResult<()> {
+        match self {
+            #[cfg(feature = "parquet")]
+            SinkSpec::Parquet { path, options } => lf.sink_parquet(path, *options),
+            #[cfg(feature = "ipc")]
+            SinkSpec::Ipc { path, options } => lf.sink_ipc(path, *options),
+            #[cfg(feature = "csv")]
+            SinkSpec::Csv { path, options } => lf.sink_csv(path, options.clone()),
+            #[cfg(feature = "json")]
+            SinkSpec::Json { path, options } => lf.sink_json(path, *options),
+            #[allow(unreachable_patterns)]
+            _ => {
+                let _ = lf;
+                polars_bail!(ComputeError: "no file format is enabled to sink a pipeline into")
+            },
+        }
+    }
+}
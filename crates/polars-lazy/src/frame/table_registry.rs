@@ -0,0 +1,257 @@
+//! A process-level registry of named tables, so that queries of a long-running session can refer
+//! to datasets by name, see [`LazyFrame::scan_table`].
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+static GLOBAL_TABLE_REGISTRY: Lazy<Arc<TableRegistry>> =
+    Lazy::new(|| Arc::new(TableRegistry::new(TableRegistryOptions::default())));
+
+/// Options of a [`TableRegistry`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TableRegistryOptions {
+    /// The maximum number of bytes of the registered DataFrames. If registering a DataFrame
+    /// exceeds this, the least recently used tables are evicted.
+    pub max_memory: Option<usize>,
+    /// The time to live of tables that are registered without an explicit time to live.
+    pub default_ttl: Option<Duration>,
+}
+
+/// Information about a registered table.
+#[derive(Clone, Debug)]
+pub struct TableInfo {
+    pub name: String,
+    /// The estimated size in bytes of a registered DataFrame, 0 for a LazyFrame.
+    pub estimated_size: usize,
+    /// Whether a DataFrame was registered, rather than a LazyFrame.
+    pub materialized: bool,
+    pub registered_at: Instant,
+    pub last_access: Instant,
+    pub expires_at: Option<Instant>,
+}
+
+struct TableEntry {
+    lf: LazyFrame,
+    info: TableInfo,
+}
+
+impl TableEntry {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.info
+            .expires_at
+            .map_or(false, |expires_at| expires_at <= now)
+    }
+}
+
+#[derive(Default)]
+struct RegistryState {
+    tables: PlHashMap<String, TableEntry>,
+    memory_usage: usize,
+}
+
+impl RegistryState {
+    fn remove(&mut self, name: &str) -> Option<TableEntry> {
+        let entry = self.tables.remove(name)?;
+        self.memory_usage -= entry.info.estimated_size;
+        Some(entry)
+    }
+
+    fn evict_expired(&mut self, now: Instant) -> usize {
+        let expired = self
+            .tables
+            .iter()
+            .filter(|(_, entry)| entry.is_expired(now))
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        for name in &expired {
+            self.remove(name);
+        }
+        expired.len()
+    }
+}
+
+/// Named DataFrames and LazyFrames that can be referenced by [`LazyFrame::scan_table`] and from
+/// SQL queries.
+///
+/// The data of a registered DataFrame is reference counted, so queries that are running while a
+/// table is unregistered or evicted keep working on the data they started with. Tables expire
+/// after their time to live, and the least recently used tables are evicted when the registered
+/// DataFrames exceed [`TableRegistryOptions::max_memory`].
+pub struct TableRegistry {
+    options: TableRegistryOptions,
+    state: RwLock<RegistryState>,
+}
+
+impl TableRegistry {
+    pub fn new(options: TableRegistryOptions) -> Self {
+        TableRegistry {
+            options,
+            state: Default::default(),
+        }
+    }
+
+    /// The registry of the process, which is used by [`LazyFrame::scan_table`].
+    pub fn global() -> Arc<TableRegistry> {
+        GLOBAL_TABLE_REGISTRY.clone()
+    }
+
+    /// Register a DataFrame under `name`, replacing an earlier table with that name.
+    pub fn register_df(&self, name: &str, df: DataFrame) -> PolarsResult<()> {
+        self.register_df_with_ttl(name, df, self.options.default_ttl)
+    }
+
+    /// Register a DataFrame under `name` that expires after `ttl`.
+    pub fn register_df_with_ttl(
+        &self,
+        name: &str,
+        df: DataFrame,
+        ttl: Option<Duration>,
+    ) -> PolarsResult<()> {
+        let estimated_size = df.estimated_size();
+        if let Some(max_memory) = self.options.max_memory {
+            polars_ensure!(
+                estimated_size <= max_memory,
+                ComputeError: "table '{}' of {} bytes exceeds the memory limit of the registry of {} bytes",
+                name, estimated_size, max_memory
+            );
+        }
+        self.insert(name, df.lazy(), estimated_size, true, ttl);
+        Ok(())
+    }
+
+    /// Register a LazyFrame under `name`, replacing an earlier table with that name. The query is
+    /// executed every time the table is used.
+    pub fn register(&self, name: &str, lf: LazyFrame) {
+        self.register_with_ttl(name, lf, self.options.default_ttl)
+    }
+
+    /// Register a LazyFrame under `name` that expires after `ttl`.
+    pub fn register_with_ttl(&self, name: &str, lf: LazyFrame, ttl: Option<Duration>) {
+        self.insert(name, lf, 0, false, ttl)
+    }
+
+    fn insert(
+        &self,
+        name: &str,
+        lf: LazyFrame,
+        estimated_size: usize,
+        materialized: bool,
+        ttl: Option<Duration>,
+    ) {
+        let now = Instant::now();
+        let mut state = self.state.write().unwrap();
+        state.remove(name);
+        state.evict_expired(now);
+        if let Some(max_memory) = self.options.max_memory {
+            while state.memory_usage + estimated_size > max_memory {
+                let lru = state
+                    .tables
+                    .iter()
+                    .filter(|(_, entry)| entry.info.materialized)
+                    .min_by_key(|(_, entry)| entry.info.last_access)
+                    .map(|(name, _)| name.clone())
+                    .unwrap();
+                state.remove(&lru);
+            }
+        }
+
+        state.memory_usage += estimated_size;
+        state.tables.insert(
+            name.to_string(),
+            TableEntry {
+                lf,
+                info: TableInfo {
+                    name: name.to_string(),
+                    estimated_size,
+                    materialized,
+                    registered_at: now,
+                    last_access: now,
+                    expires_at: ttl.map(|ttl| now + ttl),
+                },
+            },
+        );
+    }
+
+    /// Remove the table called `name`, returning whether it was registered.
+    pub fn unregister(&self, name: &str) -> bool {
+        self.state.write().unwrap().remove(name).is_some()
+    }
+
+    /// Get the table called `name`, if it is registered and not expired.
+    pub fn get(&self, name: &str) -> Option<LazyFrame> {
+        let now = Instant::now();
+        let mut state = self.state.write().unwrap();
+        match state.tables.get_mut(name) {
+            Some(entry) if !entry.is_expired(now) => {
+                entry.info.last_access = now;
+                Some(entry.lf.clone())
+            },
+            Some(_) => {
+                state.remove(name);
+                None
+            },
+            None => None,
+        }
+    }
+
+    /// Whether a table called `name` is registered and not expired.
+    pub fn contains(&self, name: &str) -> bool {
+        let state = self.state.read().unwrap();
+        state
+            .tables
+            .get(name)
+            .map_or(false, |entry| !entry.is_expired(Instant::now()))
+    }
+
+    /// Information about the table called `name`, if it is registered and not expired.
+    pub fn info(&self, name: &str) -> Option<TableInfo> {
+        let state = self.state.read().unwrap();
+        state
+            .tables
+            .get(name)
+            .filter(|entry| !entry.is_expired(Instant::now()))
+            .map(|entry| entry.info.clone())
+    }
+
+    /// The names of all tables that are not expired, in sorted order.
+    pub fn names(&self) -> Vec<String> {
+        let now = Instant::now();
+        let state = self.state.read().unwrap();
+        let mut names = state
+            .tables
+            .iter()
+            .filter(|(_, entry)| !entry.is_expired(now))
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    /// The estimated number of bytes of the registered DataFrames.
+    pub fn memory_usage(&self) -> usize {
+        self.state.read().unwrap().memory_usage
+    }
+
+    /// Remove the tables whose time to live has passed, returning how many were removed.
+    pub fn evict_expired(&self) -> usize {
+        self.state.write().unwrap().evict_expired(Instant::now())
+    }
+
+    /// Remove all tables.
+    pub fn clear(&self) {
+        *self.state.write().unwrap() = Default::default();
+    }
+}
+
+impl LazyFrame {
+    /// Scan a table that was registered in the [`TableRegistry::global`] registry.
+    pub fn scan_table(name: &str) -> PolarsResult<LazyFrame> {
+        TableRegistry::global()
+            .get(name)
+            .ok_or_else(|| polars_err!(ComputeError: "table '{}' is not registered", name))
+    }
+}
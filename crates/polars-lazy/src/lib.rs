@@ -203,6 +203,8 @@ extern crate core;
 #[cfg(feature = "dot_diagram")]
 mod dot;
 pub mod dsl;
+#[cfg(feature = "custom_operators")]
+pub use polars_pipe::operators;
 pub mod frame;
 pub mod physical_plan;
 pub mod prelude;
@@ -25,9 +25,10 @@ pub(super) fn all_column(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> bool {
 }
 
 pub(super) fn streamable_join(args: &JoinArgs) -> bool {
-    let supported = match args.how {
+    match args.how {
+        // The validation of the generic joins is not implemented for cross joins.
         #[cfg(feature = "cross_join")]
-        JoinType::Cross => true,
+        JoinType::Cross => !args.validation.needs_checks(),
         JoinType::Left => true,
         JoinType::Inner => {
             // no-coalescing not yet supported in streaming
@@ -38,6 +39,28 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
         },
         JoinType::Full { .. } => true,
         _ => false,
-    };
-    supported && !args.validation.needs_checks()
+    }
+}
+
+/// Check if the input of a `merge_sorted` is a union of sources that can be merged while they
+/// are streamed.
+#[cfg(feature = "merge_sorted")]
+pub(super) fn is_merge_sorted_source(input: Node, lp_arena: &Arena<IR>) -> bool {
+    fn is_source(node: Node, lp_arena: &Arena<IR>) -> bool {
+        match lp_arena.get(node) {
+            IR::Scan { scan_type, .. } => scan_type.streamable(),
+            IR::DataFrameScan { .. } => true,
+            IR::MapFunction {
+                input,
+                function: FunctionNode::Rechunk,
+            } => is_source(*input, lp_arena),
+            _ => false,
+        }
+    }
+    match lp_arena.get(input) {
+        IR::Union { inputs, options } => {
+            options.slice.is_none() && inputs.iter().all(|node| is_source(*node, lp_arena))
+        },
+        _ => false,
+    }
 }
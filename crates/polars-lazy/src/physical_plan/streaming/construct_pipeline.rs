@@ -11,6 +11,8 @@ use polars_pipe::pipeline::{
     create_pipeline, execute_pipeline, get_dummy_operator, get_operator, CallBacks, PipeLine,
 };
 use polars_plan::prelude::expr_ir::ExprIR;
+use polars_utils::interrupt::current_cancel_token;
+use polars_utils::memory_budget::MemoryBudget;
 
 use crate::physical_plan::streaming::tree::{PipelineNode, Tree};
 use crate::prelude::*;
@@ -248,6 +250,14 @@ fn get_pipeline_node(
         function: FunctionNode::Pipeline {
             function: Arc::new(move |_df: DataFrame| {
                 let mut state = ExecutionState::new();
+                // The pipeline runs on the query thread, share the cancel token of the query so
+                // that the operators on the worker threads stop as well.
+                if let Some(token) = current_cancel_token() {
+                    state.set_cancel_token(token);
+                }
+                if let Some(budget) = MemoryBudget::current() {
+                    state.set_memory_budget(budget);
+                }
                 if state.verbose() {
                     eprintln!("RUN STREAMING PIPELINE");
                     eprintln!("{:?}", &pipelines)
@@ -217,6 +217,25 @@ pub(crate) fn insert_streaming_nodes(
                 state.streamable = true;
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
+            // The row index is added once the chunks are ordered, so it doesn't have to wait
+            // for the chunks before it.
+            MapFunction {
+                input,
+                function: FunctionNode::RowIndex { .. },
+            } => {
+                state.streamable = true;
+                state.operators_sinks.push(PipelineNode::Sink(root));
+                stack.push(StackFrame::new(*input, state, current_idx))
+            },
+            // Sorted sources are merged by a source that pulls from all of them.
+            #[cfg(feature = "merge_sorted")]
+            MapFunction {
+                input,
+                function: FunctionNode::MergeSorted { .. },
+            } if is_merge_sorted_source(*input, lp_arena) => {
+                state.sources.push(root);
+                pipeline_trees[current_idx].push(state);
+            },
             // Streamable functions will be converted
             lp @ MapFunction { input, function } => {
                 if function.is_streamable() {
@@ -292,6 +311,18 @@ pub(crate) fn insert_streaming_nodes(
                             input,
                             function: FunctionNode::Rechunk,
                         } => matches!(lp_arena.get(*input), Scan { .. }),
+                        // The scans are aligned batch by batch, their predicates can't be
+                        // applied after the union.
+                        MapFunction {
+                            input,
+                            function: FunctionNode::AlignByName { .. },
+                        } => matches!(
+                            lp_arena.get(*input),
+                            Scan {
+                                predicate: None,
+                                ..
+                            }
+                        ),
                         _ => false,
                     }) =>
             {
@@ -1,4 +1,8 @@
 pub(crate) use polars_expr::prelude::*;
+pub use polars_expr::state::{
+    clear_execution_hooks, register_execution_hooks, ExecutionHooks, OperatorEvent,
+    PlanOptimizedEvent, SinkCompleteEvent,
+};
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -7,15 +11,23 @@ pub use polars_io::ipc::IpcWriterOptions;
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(feature = "binary_encryption")]
+pub use polars_ops::prelude::{AeadAlgorithm, AeadKey};
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+#[cfg(feature = "rename_normalized")]
+pub use polars_ops::prelude::{NameCase, NameDedupe, NormalizeNamesOptions};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
+#[cfg(feature = "semantic_types")]
+pub use polars_ops::prelude::{
+    SemanticType, SemanticTypeDetector, SemanticTypeInference, SemanticTypeOptions, SemanticTypes,
+};
 pub use polars_plan::plans::{
-    AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
-    NULL,
+    AnonymousScan, AnonymousScanArgs, AnonymousScanBatches, AnonymousScanOptions, DslPlan, Literal,
+    LiteralValue, Null, NULL,
 };
-pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
+pub use polars_plan::prelude::{SchemaEvolution, UnionArgs};
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
@@ -0,0 +1,42 @@
+use polars_core::prelude::*;
+use polars_io::ipc::CompressedDataFrame;
+
+use crate::prelude::*;
+
+struct CompressedScan(Arc<CompressedDataFrame>);
+
+impl AnonymousScan for CompressedScan {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        self.0
+            .select(scan_opts.with_columns.as_deref(), scan_opts.n_rows)
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.0.schema().clone())
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Scan a [`CompressedDataFrame`]. Only the compressed columns that the query projects are
+    /// decompressed.
+    pub fn scan_compressed(df: Arc<CompressedDataFrame>) -> PolarsResult<Self> {
+        let args = ScanArgsAnonymous {
+            schema: Some(df.schema().clone()),
+            name: "COMPRESSED SCAN",
+            ..Default::default()
+        };
+        Self::anonymous_scan(Arc::new(CompressedScan(df)), args)
+    }
+}
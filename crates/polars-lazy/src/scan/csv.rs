@@ -6,7 +6,7 @@ use polars_io::csv::read::{
     infer_file_schema, CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
 };
 use polars_io::utils::get_reader_bytes;
-use polars_io::RowIndex;
+use polars_io::{DtypeOverrides, RowIndex};
 
 use crate::prelude::*;
 
@@ -18,6 +18,9 @@ pub struct LazyCsvReader {
     cache: bool,
     read_options: CsvReadOptions,
     cloud_options: Option<CloudOptions>,
+    schema_evolution: SchemaEvolution,
+    #[cfg(feature = "rename_normalized")]
+    normalize_names: Option<NormalizeNamesOptions>,
 }
 
 #[cfg(feature = "csv")]
@@ -39,6 +42,9 @@ impl LazyCsvReader {
             cache: true,
             read_options: Default::default(),
             cloud_options: Default::default(),
+            schema_evolution: Default::default(),
+            #[cfg(feature = "rename_normalized")]
+            normalize_names: None,
         }
     }
 
@@ -102,6 +108,22 @@ impl LazyCsvReader {
         self
     }
 
+    /// Overwrite the inferred dtypes and the number of rows to infer them from for the columns
+    /// of which the name matches a pattern, see [`DtypeOverrides`].
+    #[must_use]
+    pub fn with_dtype_overrides(mut self, dtype_overrides: Option<DtypeOverrides>) -> Self {
+        self.read_options.dtype_overrides = dtype_overrides.map(Arc::new);
+        self
+    }
+
+    /// Set how the schemas of the files are combined if they differ, see [`SchemaEvolution`].
+    /// The schema of every file is inferred on its own, unless a schema is given.
+    #[must_use]
+    pub fn with_schema_evolution(mut self, schema_evolution: SchemaEvolution) -> Self {
+        self.schema_evolution = schema_evolution;
+        self
+    }
+
     /// Set whether the CSV file has headers
     #[must_use]
     pub fn with_has_header(mut self, has_header: bool) -> Self {
@@ -197,6 +219,14 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_decimal_comma(decimal_comma))
     }
 
+    /// Normalize the column names after reading, see [`LazyFrame::rename_normalized`].
+    #[cfg(feature = "rename_normalized")]
+    #[must_use]
+    pub fn with_normalize_names(mut self, options: Option<NormalizeNamesOptions>) -> Self {
+        self.normalize_names = options;
+        self
+    }
+
     #[must_use]
     /// Expand path given via globbing rules.
     pub fn with_glob(mut self, toggle: bool) -> Self {
@@ -234,6 +264,7 @@ impl LazyCsvReader {
             self.read_options.has_header,
             // we set it to None and modify them after the schema is updated
             None,
+            self.read_options.dtype_overrides.as_deref(),
             skip_rows,
             self.read_options.skip_rows_after_header,
             parse_options.comment_prefix.as_ref(),
@@ -264,11 +295,20 @@ impl LazyFileListReader for LazyCsvReader {
         // `expand_paths` respects globs
         let paths = self.expand_paths(false)?.0;
 
-        let mut lf: LazyFrame =
-            DslBuilder::scan_csv(paths, self.read_options, self.cache, self.cloud_options)?
-                .build()
-                .into();
+        let mut lf: LazyFrame = DslBuilder::scan_csv(
+            paths,
+            self.read_options,
+            self.cache,
+            self.cloud_options,
+            self.schema_evolution,
+        )?
+        .build()
+        .into();
         lf.opt_state.file_caching = true;
+        #[cfg(feature = "rename_normalized")]
+        if let Some(options) = self.normalize_names {
+            lf = lf.rename_normalized(options)?;
+        }
         Ok(lf)
     }
 
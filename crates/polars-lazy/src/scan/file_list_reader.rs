@@ -1,5 +1,5 @@
 use std::collections::VecDeque;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use polars_core::config;
 use polars_core::error::to_compute_err;
@@ -17,6 +17,20 @@ pub(super) fn get_glob_start_idx(path: &[u8]) -> Option<usize> {
     memchr::memchr3(b'*', b'?', b'[', path)
 }
 
+/// Whether `path` is the statistics sidecar of a dataset, which is not a file of the dataset.
+fn is_statistics_sidecar(path: &Path) -> bool {
+    #[cfg(feature = "parquet")]
+    {
+        path.file_name()
+            .is_some_and(|name| name == polars_io::parquet::STATISTICS_SIDECAR)
+    }
+    #[cfg(not(feature = "parquet"))]
+    {
+        let _ = path;
+        false
+    }
+}
+
 /// Recursively traverses directories and expands globs if `glob` is `true`.
 /// Returns the expanded paths and the index at which to start parsing hive
 /// partitions from the path.
@@ -122,6 +136,7 @@ fn expand_paths(
                             .await
                             .map_err(to_compute_err)?;
 
+                        paths.retain(|path| !is_statistics_sidecar(path));
                         paths.sort_unstable();
                         (
                             format_path(
@@ -192,7 +207,7 @@ fn expand_paths(
                     for path in paths {
                         if path.is_dir() {
                             stack.push_back(path);
-                        } else {
+                        } else if !is_statistics_sidecar(&path) {
                             out_paths.push(path);
                         }
                     }
@@ -14,7 +14,12 @@ pub struct ScanArgsIpc {
     pub rechunk: bool,
     pub row_index: Option<RowIndex>,
     pub memory_map: bool,
+    /// Copy the data of memory mapped files into memory and release the mappings once the files
+    /// are read, so that long running processes don't retain a mapping per scanned file.
+    pub release_resources: bool,
     pub cloud_options: Option<CloudOptions>,
+    /// How the schemas of the files are combined if they differ.
+    pub schema_evolution: SchemaEvolution,
 }
 
 impl Default for ScanArgsIpc {
@@ -25,7 +30,9 @@ impl Default for ScanArgsIpc {
             rechunk: false,
             row_index: None,
             memory_map: true,
+            release_resources: false,
             cloud_options: Default::default(),
+            schema_evolution: Default::default(),
         }
     }
 }
@@ -52,6 +59,7 @@ impl LazyFileListReader for LazyIpcReader {
 
         let options = IpcScanOptions {
             memory_map: args.memory_map,
+            release_resources: args.release_resources,
         };
 
         let mut lf: LazyFrame = DslBuilder::scan_ipc(
@@ -62,6 +70,7 @@ impl LazyFileListReader for LazyIpcReader {
             args.row_index,
             args.rechunk,
             args.cloud_options,
+            args.schema_evolution,
         )?
         .build()
         .into();
@@ -2,9 +2,9 @@ use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
-use polars_io::RowIndex;
+use polars_io::{DtypeOverrides, RowIndex};
 use polars_plan::plans::{DslPlan, FileScan};
-use polars_plan::prelude::{FileScanOptions, NDJsonReadOptions};
+use polars_plan::prelude::{FileScanOptions, NDJsonReadOptions, SchemaEvolution};
 
 use crate::prelude::LazyFrame;
 use crate::scan::file_list_reader::LazyFileListReader;
@@ -16,10 +16,12 @@ pub struct LazyJsonLineReader {
     pub(crate) low_memory: bool,
     pub(crate) rechunk: bool,
     pub(crate) schema: Option<SchemaRef>,
+    pub(crate) dtype_overrides: Option<Arc<DtypeOverrides>>,
     pub(crate) row_index: Option<RowIndex>,
     pub(crate) infer_schema_length: Option<NonZeroUsize>,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
+    pub(crate) schema_evolution: SchemaEvolution,
 }
 
 impl LazyJsonLineReader {
@@ -34,10 +36,12 @@ impl LazyJsonLineReader {
             low_memory: false,
             rechunk: false,
             schema: None,
+            dtype_overrides: None,
             row_index: None,
             infer_schema_length: NonZeroUsize::new(100),
             ignore_errors: false,
             n_rows: None,
+            schema_evolution: Default::default(),
         }
     }
     /// Add a row index column.
@@ -76,6 +80,21 @@ impl LazyJsonLineReader {
         self
     }
 
+    /// Set the dtypes and inference lengths of the columns that match a pattern, see
+    /// [`DtypeOverrides`]. Ignored when the schema is specified explicitly.
+    #[must_use]
+    pub fn with_dtype_overrides(mut self, dtype_overrides: Option<DtypeOverrides>) -> Self {
+        self.dtype_overrides = dtype_overrides.map(Arc::new);
+        self
+    }
+
+    /// Set how the schemas of the files are combined if they differ, see [`SchemaEvolution`].
+    #[must_use]
+    pub fn with_schema_evolution(mut self, schema_evolution: SchemaEvolution) -> Self {
+        self.schema_evolution = schema_evolution;
+        self
+    }
+
     /// Reduce memory usage at the expense of performance
     #[must_use]
     pub fn low_memory(mut self, toggle: bool) -> Self {
@@ -106,6 +125,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             rechunk: self.rechunk,
             file_counter: 0,
             hive_options: Default::default(),
+            schema_evolution: self.schema_evolution,
         };
 
         let options = NDJsonReadOptions {
@@ -115,6 +135,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             low_memory: self.low_memory,
             ignore_errors: self.ignore_errors,
             schema: self.schema,
+            dtype_overrides: self.dtype_overrides,
         };
 
         let scan_type = FileScan::NDJson { options };
@@ -138,6 +159,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             rechunk: self.rechunk,
             file_counter: 0,
             hive_options: Default::default(),
+            schema_evolution: self.schema_evolution,
         };
 
         let options = NDJsonReadOptions {
@@ -147,6 +169,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             low_memory: self.low_memory,
             ignore_errors: self.ignore_errors,
             schema: self.schema,
+            dtype_overrides: self.dtype_overrides,
         };
 
         let scan_type = FileScan::NDJson { options };
@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
+use polars_io::parquet::DatasetStatistics;
 use polars_io::utils::is_cloud_url;
 use polars_io::{HiveOptions, RowIndex};
 
@@ -18,10 +19,20 @@ pub struct ScanArgsParquet {
     pub hive_options: HiveOptions,
     pub use_statistics: bool,
     pub low_memory: bool,
+    /// Store repeated string values only once, see [`DataFrame::deduplicate_strings`].
+    pub deduplicate_strings: bool,
     pub rechunk: bool,
     pub cache: bool,
     /// Expand path given via globbing rules.
     pub glob: bool,
+    /// How the schemas of the files are combined if they differ.
+    pub schema_evolution: SchemaEvolution,
+    /// Plan the scan of a local directory with the statistics sidecar in its root, if it has
+    /// one, see [`DatasetStatistics`]. The directory is not listed, files are pruned with their
+    /// statistics and counted with their number of rows.
+    ///
+    /// The sidecar must be up to date with the files of the directory.
+    pub use_statistics_sidecar: bool,
 }
 
 impl Default for ScanArgsParquet {
@@ -35,8 +46,11 @@ impl Default for ScanArgsParquet {
             use_statistics: true,
             rechunk: false,
             low_memory: false,
+            deduplicate_strings: false,
             cache: true,
             glob: true,
+            schema_evolution: Default::default(),
+            use_statistics_sidecar: false,
         }
     }
 }
@@ -54,13 +68,30 @@ impl LazyParquetReader {
             paths: Arc::new([]),
         }
     }
+
+    /// Read the statistics sidecar of the scanned directory, if it is used and there is one.
+    fn read_statistics_sidecar(&self) -> PolarsResult<Option<DatasetStatistics>> {
+        match &*self.paths {
+            [path] if self.args.use_statistics_sidecar && !is_cloud_url(path) && path.is_dir() => {
+                DatasetStatistics::read(path)
+            },
+            _ => Ok(None),
+        }
+    }
 }
 
 impl LazyFileListReader for LazyParquetReader {
     /// Get the final [LazyFrame].
     fn finish(mut self) -> PolarsResult<LazyFrame> {
-        let (paths, hive_start_idx) =
-            self.expand_paths(self.args.hive_options.enabled.unwrap_or(false))?;
+        let statistics = self.read_statistics_sidecar()?;
+        let (paths, hive_start_idx) = match &statistics {
+            // The sidecar lists the files, so the directory isn't listed.
+            Some(statistics) => (
+                statistics.paths().into(),
+                self.paths[0].to_str().unwrap().len(),
+            ),
+            None => self.expand_paths(self.args.hive_options.enabled.unwrap_or(false))?,
+        };
         self.args.hive_options.enabled =
             Some(self.args.hive_options.enabled.unwrap_or_else(|| {
                 self.paths.len() == 1
@@ -86,6 +117,9 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.cloud_options,
             self.args.use_statistics,
             self.args.hive_options,
+            self.args.deduplicate_strings,
+            self.args.schema_evolution,
+            statistics.map(Arc::new),
         )?
         .build()
         .into();
@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use polars_ops::prelude::ListNameSpaceImpl;
 use polars_utils::unitvec;
 
@@ -571,3 +573,112 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_agg_groups_struct() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("fruits")])
+        .agg_groups_struct("rows")
+        .collect()?;
+
+    assert_eq!(out.get_column_names(), &["fruits", "rows"]);
+    let rows = out.column("rows")?.explode()?;
+    let rows = rows.struct_()?;
+    assert_eq!(
+        rows.field_by_name("A")?.i32()?.to_vec(),
+        &[Some(1), Some(2), Some(5), Some(3), Some(4)]
+    );
+    assert_eq!(
+        rows.fields()
+            .iter()
+            .map(|s| s.name().to_string())
+            .collect::<Vec<_>>(),
+        &["A", "B", "cars"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_udaf() -> PolarsResult<()> {
+    struct SumOfSquares;
+    struct SumOfSquaresState(f64);
+
+    impl UserDefinedAggregate for SumOfSquares {
+        fn output_type(&self, _input: &Field) -> PolarsResult<DataType> {
+            Ok(DataType::Float64)
+        }
+
+        fn init(&self) -> Box<dyn AggregateState> {
+            Box::new(SumOfSquaresState(0.0))
+        }
+    }
+
+    impl AggregateState for SumOfSquaresState {
+        fn update(&mut self, values: &Series) -> PolarsResult<()> {
+            let values = values.cast(&DataType::Float64)?;
+            self.0 += values
+                .f64()?
+                .into_no_null_iter()
+                .map(|v| v * v)
+                .sum::<f64>();
+            Ok(())
+        }
+
+        fn merge(&mut self, other: &dyn AggregateState) -> PolarsResult<()> {
+            self.0 += other.as_any().downcast_ref::<Self>().unwrap().0;
+            Ok(())
+        }
+
+        fn finalize(&self) -> PolarsResult<AnyValue<'static>> {
+            Ok(AnyValue::Float64(self.0))
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    let n = 10_000;
+    let df = df![
+        "g" => (0..n).map(|i| i % 7).collect::<Vec<i32>>(),
+        "v" => (0..n).map(|i| (i % 10) as i64).collect::<Vec<_>>(),
+    ]?;
+    let udaf: Arc<dyn UserDefinedAggregate> = Arc::new(SumOfSquares);
+    let q = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([
+            col("v").udaf(udaf.clone()).alias("udaf"),
+            (col("v") * col("v"))
+                .sum()
+                .cast(DataType::Float64)
+                .alias("expected"),
+        ])
+        .sort(["g"], Default::default());
+
+    let out = q.clone().collect()?;
+    assert_eq!(
+        out.column("udaf")?,
+        &out.column("expected")?.clone().with_name("udaf")
+    );
+
+    #[cfg(feature = "streaming")]
+    {
+        let q = q.with_streaming(true);
+        assert!(optimization_checks::has_pipeline(q.clone()));
+        assert_eq!(q.collect()?, out);
+    }
+
+    let out = df![
+        "v" => [1, 2, 3],
+    ]?
+    .lazy()
+    .select([col("v").udaf(udaf)])
+    .collect()?;
+    assert_eq!(out.column("v")?.f64()?.get(0), Some(14.0));
+    Ok(())
+}
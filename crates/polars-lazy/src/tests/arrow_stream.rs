@@ -0,0 +1,12 @@
+use super::*;
+
+#[test]
+fn test_arrow_stream_roundtrip() -> PolarsResult<()> {
+    let lf = load_df().lazy().filter(col("a").gt(lit(2)));
+    let stream = lf.clone().into_arrow_stream(false)?;
+    let out = unsafe { LazyFrame::scan_arrow_stream(stream) }?
+        .select([col("b"), col("a")])
+        .collect()?;
+    assert!(out.equals(&lf.select([col("b"), col("a")]).collect()?));
+    Ok(())
+}
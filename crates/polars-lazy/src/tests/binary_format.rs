@@ -0,0 +1,66 @@
+use super::*;
+
+fn serialize_plan(lf: &LazyFrame) -> PolarsResult<Vec<u8>> {
+    let mut buf = vec![];
+    lf.serialize_binary(&mut buf)?;
+    Ok(buf)
+}
+
+#[test]
+fn test_binary_format_plan_roundtrip() -> PolarsResult<()> {
+    let lf = load_df()
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .group_by([col("b")])
+        .agg([col("c").sum()])
+        .sort(["b"], Default::default());
+    let buf = serialize_plan(&lf)?;
+
+    let header = read_binary_header(buf.as_slice())?.unwrap();
+    assert_eq!(header.format_version, BINARY_FORMAT_VERSION);
+    assert_eq!(header.polars_version, env!("CARGO_PKG_VERSION"));
+
+    let out = LazyFrame::deserialize_binary(buf.as_slice())?.collect()?;
+    assert!(out.equals(&lf.collect()?));
+    Ok(())
+}
+
+#[test]
+fn test_binary_format_dataframe_roundtrip() -> PolarsResult<()> {
+    let df = load_df();
+    let mut buf = vec![];
+    serialize_dataframe_binary(&df, &mut buf)?;
+    let out = deserialize_dataframe_binary(buf.as_slice())?;
+    assert!(out.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_binary_format_reads_legacy_data() -> PolarsResult<()> {
+    let lf = load_df().lazy().select([col("a") * lit(2)]);
+    let mut buf = vec![];
+    ciborium::into_writer(&lf.logical_plan, &mut buf).unwrap();
+    assert!(read_binary_header(buf.as_slice())?.is_none());
+    let out = LazyFrame::deserialize_binary(buf.as_slice())?.collect()?;
+    assert!(out.equals(&lf.collect()?));
+
+    let df = load_df();
+    let mut buf = vec![];
+    ciborium::into_writer(&df, &mut buf).unwrap();
+    assert!(deserialize_dataframe_binary(buf.as_slice())?.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_binary_format_rejects_unsupported_data() -> PolarsResult<()> {
+    let mut buf = serialize_plan(&load_df().lazy())?;
+
+    // A DataFrame can't be read from a plan.
+    assert!(deserialize_dataframe_binary(buf.as_slice()).is_err());
+
+    // The version directly follows the magic.
+    buf[8..10].copy_from_slice(&(BINARY_FORMAT_VERSION + 1).to_le_bytes());
+    let err = LazyFrame::deserialize_binary(buf.as_slice()).unwrap_err();
+    assert!(err.to_string().contains("upgrade polars"));
+    Ok(())
+}
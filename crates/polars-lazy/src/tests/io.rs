@@ -419,6 +419,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             row_index: None,
             memory_map: true,
             cloud_options: None,
+            ..Default::default()
         },
     )?
     .collect()?;
@@ -430,6 +431,71 @@ fn test_ipc_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_ipc_batch_statistics_pruning() -> PolarsResult<()> {
+    let mut df = df!["a" => [0i32, 1, 2], "b" => ["x", "y", "z"]]?;
+    df.vstack_mut(&df!["a" => [3i32, 4, 5], "b" => ["u", "v", "w"]]?)?;
+    df.vstack_mut(&df!["a" => [6i32, 7, 8], "b" => ["r", "s", "t"]]?)?;
+
+    let path = std::env::temp_dir().join("polars_test_ipc_batch_statistics.ipc");
+    let f = std::fs::File::create(&path)?;
+    IpcWriter::new(f)
+        .with_batch_statistics(true)
+        .finish(&mut df)?;
+
+    for memory_map in [false, true] {
+        let args = ScanArgsIpc {
+            memory_map,
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_ipc(&path, args.clone())?
+            .filter(col("a").gt_eq(lit(4)).and(col("a").lt(lit(7))))
+            .collect()?;
+        assert_eq!(out, df!["a" => [4i32, 5, 6], "b" => ["v", "w", "r"]]?);
+
+        // Row indices must count the skipped rows.
+        let out = LazyFrame::scan_ipc(&path, args)?
+            .with_row_index("index", None)
+            .filter(col("a").gt(lit(6)))
+            .collect()?;
+        assert_eq!(
+            out.column("index")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [7, 8]
+        );
+    }
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_ipc_release_resources() -> PolarsResult<()> {
+    use polars_io::mmap::try_create_file;
+
+    let mut df = df!["a" => [0i32, 1, 2], "b" => ["x", "y", "z"]]?;
+    let path = std::env::temp_dir().join("polars_test_ipc_release_resources.ipc");
+    IpcWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    // The data references the mapping, so the file can't be written to.
+    let out = LazyFrame::scan_ipc(&path, Default::default())?.collect()?;
+    assert_eq!(out, df);
+    assert!(try_create_file(&path).is_err());
+    drop(out);
+
+    let args = ScanArgsIpc {
+        release_resources: true,
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_ipc(&path, args)?.collect()?;
+    assert_eq!(out, df);
+    drop(try_create_file(&path)?);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
 fn slice_at_union(lp_arena: &Arena<IR>, lp: Node) -> bool {
     (&lp_arena).iter(lp).all(|(_, lp)| {
         if let IR::Union { options, .. } = lp {
@@ -474,6 +540,49 @@ fn test_csv_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_csv_schema_evolution() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_csv_schema_evolution");
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("0.csv"), "a,b\n1,x\n2,y\n")?;
+    std::fs::write(dir.join("1.csv"), "b,a,c\nz,2.5,1.5\n")?;
+    let glob = dir.join("*.csv");
+
+    let df = LazyCsvReader::new(&glob)
+        .with_schema_evolution(SchemaEvolution::Unify)
+        .finish()?
+        .collect()?;
+    let expected = df![
+        "a" => [1.0, 2.0, 2.5],
+        "b" => ["x", "y", "z"],
+        "c" => [None, None, Some(1.5)]
+    ]?;
+    assert_eq!(df, expected);
+
+    let schema = Schema::from_iter([
+        Field::new("c", DataType::Float32),
+        Field::new("b", DataType::String),
+    ]);
+    let df = LazyCsvReader::new(&glob)
+        .with_schema_evolution(SchemaEvolution::Cast(Arc::new(schema)))
+        .with_row_index(Some(RowIndex {
+            name: Arc::from("index"),
+            offset: 0,
+        }))
+        .with_n_rows(Some(2))
+        .finish()?
+        .collect()?;
+    let expected = df![
+        "index" => [0 as IdxSize, 1],
+        "c" => [None::<f32>, None],
+        "b" => ["x", "y"]
+    ]?;
+    assert_eq!(df, expected);
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "json")]
 fn test_ndjson_globbing() -> PolarsResult<()> {
@@ -701,6 +810,155 @@ fn scan_anonymous_fn_with_options() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "streaming")]
+fn scan_anonymous_streaming() -> PolarsResult<()> {
+    struct BatchedScan {}
+
+    impl AnonymousScan for BatchedScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn allows_projection_pushdown(&self) -> bool {
+            true
+        }
+
+        fn allows_streaming(&self) -> bool {
+            true
+        }
+
+        fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            let dfs = self
+                .scan_batches(scan_opts)?
+                .collect::<PolarsResult<Vec<_>>>()?;
+            polars_core::utils::accumulate_dataframes_vertical(dfs)
+        }
+
+        fn scan_batches(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<AnonymousScanBatches> {
+            let columns = scan_opts.with_columns.unwrap();
+            assert_eq!(columns.len(), 2);
+            let df = fruits_cars().select(columns.as_ref())?;
+            let batches = (0..5).map(move |i| Ok(df.slice(i, 1)));
+            Ok(Box::new(batches))
+        }
+    }
+
+    let args = ScanArgsAnonymous {
+        schema: Some(Arc::new(fruits_cars().schema())),
+        ..ScanArgsAnonymous::default()
+    };
+    let q = LazyFrame::anonymous_scan(Arc::new(BatchedScan {}), args)?
+        .filter(col("A").gt(lit(2)))
+        .select([col("A"), col("fruits")]);
+
+    let q_streaming = q.clone().with_streaming(true);
+    assert!(optimization_checks::is_pipeline(q_streaming.clone()));
+    let out = q_streaming.collect()?;
+    assert_eq!(out, q.collect()?);
+    assert_eq!(out.shape(), (3, 2));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "streaming"))]
+fn test_sink_parquet_checkpointed() -> PolarsResult<()> {
+    use polars_io::parquet::write::Checkpoint;
+
+    let dir = std::env::temp_dir().join("polars_test_sink_parquet_checkpointed");
+    let _ = std::fs::remove_dir_all(&dir);
+    let df = fruits_cars();
+    let q = df.clone().lazy().select([col("A"), col("cars")]);
+    let expected = q.clone().collect()?;
+
+    q.clone()
+        .sink_parquet_checkpointed(&dir, ParquetWriteOptions::default(), 2)?;
+    let checkpoint = Checkpoint::load(&dir)?.unwrap();
+    assert_eq!((checkpoint.parts, checkpoint.rows), (3, 5));
+    assert!(checkpoint.complete);
+
+    // Interrupt the query after the second part.
+    std::fs::remove_file(Checkpoint::part_path(&dir, 2))?;
+    Checkpoint {
+        parts: 2,
+        rows: 4,
+        complete: false,
+        ..checkpoint
+    }
+    .store(&dir)?;
+
+    q.clone()
+        .sink_parquet_checkpointed(&dir, ParquetWriteOptions::default(), 2)?;
+    let out = LazyFrame::scan_parquet(dir.join("*.parquet"), Default::default())?.collect()?;
+    assert_eq!(out, expected);
+
+    // Another query cannot resume the checkpoint.
+    let other = q.filter(col("A").gt(lit(1)));
+    assert!(other
+        .sink_parquet_checkpointed(&dir, ParquetWriteOptions::default(), 2)
+        .is_err());
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_resume_aggregate_state() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_resume_aggregate_state.parquet");
+    let _ = std::fs::remove_file(&path);
+    let df = fruits_cars();
+    let aggs = [
+        col("A").sum().alias("sum"),
+        col("B").mean().alias("mean"),
+        col("A").min().alias("min"),
+        col("B").last().alias("last"),
+        len(),
+    ];
+    let group_by = |df: DataFrame| df.lazy().group_by([col("fruits")]);
+
+    group_by(df.slice(0, 3)).sink_aggregate_state(&path, aggs.clone())?;
+    group_by(df.slice(3, 2))
+        .resume_from_state(&path, aggs.clone())?
+        .sink(&path)?;
+
+    let out = group_by(df.slice(0, 0))
+        .resume_from_state(&path, aggs.clone())?
+        .finish()
+        .sort(["fruits"], Default::default())
+        .collect()?;
+    let expected = group_by(df)
+        .agg(aggs.clone())
+        .sort(["fruits"], Default::default())
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    // The aggregations must match the stored state.
+    assert!(group_by(fruits_cars())
+        .resume_from_state(&path, [col("A").max()])
+        .is_err());
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn scan_compressed() -> PolarsResult<()> {
+    let df = fruits_cars();
+    let compressed = Arc::new(CompressedDataFrame::new(&df, None)?);
+    assert_eq!(compressed.decompress()?, df);
+
+    let out = LazyFrame::scan_compressed(compressed.clone())?
+        .select([col("fruits"), col("A")])
+        .limit(3)
+        .collect()?;
+    assert_eq!(out, df.select(["fruits", "A"])?.head(Some(3)));
+
+    let columns = ["B".to_string()];
+    assert!(CompressedDataFrame::new(&df, Some(&columns)).is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
@@ -725,3 +983,173 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_collect_all_share_scans() -> PolarsResult<()> {
+    let queries = || {
+        [
+            scan_foods_csv()
+                .filter(col("calories").gt(lit(100)))
+                .select([col("category")]),
+            scan_foods_csv().select([col("fats_g").sum()]),
+            scan_foods_csv().with_row_index("idx", None).limit(2),
+        ]
+    };
+    let expected = collect_all(queries())?;
+    for max_concurrency in [None, Some(1), Some(2)] {
+        let out = collect_all_with_options(
+            queries(),
+            CollectAllOptions {
+                share_scans: true,
+                max_concurrency,
+            },
+        )?;
+        assert_eq!(out.len(), expected.len());
+        for (out, expected) in out.iter().zip(&expected) {
+            assert!(out.equals(expected));
+        }
+    }
+
+    assert!(collect_all_with_options(
+        queries(),
+        CollectAllOptions {
+            share_scans: false,
+            max_concurrency: Some(0),
+        },
+    )
+    .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "streaming"))]
+fn test_sink_all() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_sink_all");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    let df = fruits_cars();
+    let ok_path = dir.join("ok.csv");
+    // The directory of this file doesn't exist, so its sink fails.
+    let failing_path = dir.join("missing").join("failing.csv");
+    let queries = || {
+        [
+            SinkQuery::new(
+                df.clone().lazy().select([col("A")]),
+                &failing_path,
+                FileType::Csv(Default::default()),
+            ),
+            SinkQuery::new(
+                df.clone().lazy(),
+                &ok_path,
+                FileType::Csv(Default::default()),
+            ),
+        ]
+    };
+
+    let out = sink_all(
+        queries(),
+        SinkAllOptions {
+            continue_on_error: true,
+        },
+    );
+    assert!(!out.is_ok());
+    assert!(matches!(out.outcomes[0], (_, SinkOutcome::Failed(_))));
+    assert!(matches!(out.outcomes[1], (_, SinkOutcome::Completed)));
+    assert_eq!(out.errors().next().unwrap().0, &failing_path);
+    let read = LazyCsvReader::new(&ok_path).finish()?.collect()?;
+    assert_eq!(read.shape(), df.shape());
+
+    let err = out.into_result().unwrap_err().to_string();
+    assert!(err.contains("1 of 2 sinks failed"));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_statistics_sidecar() -> PolarsResult<()> {
+    use polars_io::parquet::DatasetStatistics;
+
+    let root = std::env::temp_dir().join("polars_test_scan_parquet_statistics_sidecar");
+    let _ = std::fs::remove_dir_all(&root);
+    let mut files = vec![];
+    for (i, a) in [[1, 2, 3], [4, 5, 6], [7, 8, 9]].into_iter().enumerate() {
+        std::fs::create_dir_all(root.join(format!("part={i}")))?;
+        let path = format!("part={i}/data.parquet");
+        let mut df = df!("a" => a, "b" => ["x", "y", "z"])?;
+        ParquetWriter::new(std::fs::File::create(root.join(&path))?).finish(&mut df)?;
+        files.push(DatasetStatistics::file_statistics(&path, &df)?);
+    }
+    DatasetStatistics::new(&root, files)?.write()?;
+
+    let args = ScanArgsParquet {
+        use_statistics_sidecar: true,
+        ..Default::default()
+    };
+    let q = LazyFrame::scan_parquet(&root, args.clone())?.filter(col("a").gt(lit(5)));
+    // The statistics of the first file show that none of its rows match.
+    let IRPlan {
+        lp_top, lp_arena, ..
+    } = q.clone().to_alp_optimized()?;
+    assert!((&lp_arena).iter(lp_top).any(|(_, lp)| match lp {
+        IR::Scan { paths, .. } => paths.len() == 2,
+        _ => false,
+    }));
+
+    // Without the sidecar the directory is listed, which must not pick up the sidecar.
+    let expected = LazyFrame::scan_parquet(&root, Default::default())?
+        .filter(col("a").gt(lit(5)))
+        .collect()?;
+    let out = q.collect()?;
+    assert_eq!(out.height(), 4);
+    assert!(out.equals(&expected));
+
+    let count = LazyFrame::scan_parquet(&root, args)?
+        .select([len()])
+        .collect()?;
+    assert_eq!(count.column("len")?.idx()?.get(0), Some(9));
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_join_runtime_filter_parquet_scan() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_join_runtime_filter.parquet");
+    let a = (0..100).collect::<Vec<i64>>();
+    let mut df = df!("a" => &a, "b" => a.iter().map(|a| a * 2).collect::<Vec<_>>())?;
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(10))
+        .finish(&mut df)?;
+    let keys = df!("a" => [45i64, 42, 47, 200], "c" => ["x", "y", "z", "w"])?;
+
+    // The range of the keys of the right side skips the row groups below 40.
+    let out = LazyFrame::scan_parquet(&path, Default::default())?
+        .join(
+            keys.clone().lazy(),
+            [col("a")],
+            [col("a")],
+            JoinType::Inner.into(),
+        )
+        .sort(["a"], Default::default())
+        .collect()?;
+    let expected = df!(
+        "a" => [42i64, 45, 47],
+        "b" => [84i64, 90, 94],
+        "c" => ["y", "x", "z"]
+    )?;
+    assert!(out.equals(&expected));
+
+    // The runtime filter is combined with the predicate of the scan.
+    let out = LazyFrame::scan_parquet(&path, Default::default())?
+        .filter(col("b").gt(lit(85)))
+        .join(keys.lazy(), [col("a")], [col("a")], JoinType::Inner.into())
+        .sort(["a"], Default::default())
+        .collect()?;
+    assert!(out.equals(&expected.slice(1, 2)));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -51,6 +51,48 @@ fn test_duration() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-duration")]
+fn test_duration_arithmetic() -> PolarsResult<()> {
+    let df = df![
+        "d" => [Some(1500i64), None, Some(4000)],
+        "factor" => [Some(2.0), Some(1.0), None],
+    ]?
+    .lazy()
+    .with_column(col("d").cast(DataType::Duration(TimeUnit::Milliseconds)));
+
+    let out = df
+        .select([
+            col("d")
+                .dt()
+                .duration_mul(col("factor"), Some(TimeUnit::Microseconds), true)
+                .alias("mul"),
+            col("d").dt().duration_div(lit(2), None, true).alias("div"),
+            col("d")
+                .dt()
+                .duration_div(col("d").first(), None, true)
+                .alias("ratio"),
+        ])
+        .collect()?;
+
+    let mul = out.column("mul")?;
+    assert_eq!(mul.dtype(), &DataType::Duration(TimeUnit::Microseconds));
+    assert_eq!(
+        mul.get(0)?,
+        AnyValue::Duration(3_000_000, TimeUnit::Microseconds)
+    );
+    assert_eq!(mul.null_count(), 2);
+    let div = out.column("div")?;
+    assert_eq!(
+        div.get(2)?,
+        AnyValue::Duration(2000, TimeUnit::Milliseconds)
+    );
+    let ratio = out.column("ratio")?;
+    assert_eq!(ratio.dtype(), &DataType::Float64);
+    assert_eq!(ratio.get(2)?, AnyValue::Float64(4000.0 / 1500.0));
+    Ok(())
+}
+
 fn print_plans(lf: &LazyFrame) {
     println!("LOGICAL PLAN\n\n{}\n", lf.describe_plan().unwrap());
     println!(
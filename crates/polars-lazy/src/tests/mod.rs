@@ -1,14 +1,22 @@
 mod aggregations;
 mod arity;
+#[cfg(feature = "arrow_stream")]
+mod arrow_stream;
+#[cfg(feature = "binary_format")]
+mod binary_format;
 #[cfg(all(feature = "strings", feature = "cse"))]
 mod cse;
 #[cfg(feature = "parquet")]
 mod io;
 mod logical;
 mod optimization_checks;
+#[cfg(feature = "pipeline_spec")]
+mod pipeline_spec;
 mod predicate_queries;
 mod projection_queries;
 mod queries;
+#[cfg(feature = "repro")]
+mod repro;
 mod schema;
 #[cfg(feature = "streaming")]
 mod streaming;
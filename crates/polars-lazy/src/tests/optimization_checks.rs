@@ -58,6 +58,21 @@ pub(crate) fn predicate_at_all_scans(q: LazyFrame) -> bool {
     })
 }
 
+/// Whether every scan of the optimized plan has a predicate.
+pub(crate) fn predicate_at_each_scan(q: LazyFrame) -> bool {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+
+    (&lp_arena).iter(lp).all(|(_, lp)| {
+        use IR::*;
+        match lp {
+            DataFrameScan { filter, .. } => filter.is_some(),
+            Scan { predicate, .. } => predicate.is_some(),
+            _ => true,
+        }
+    })
+}
+
 #[cfg(feature = "streaming")]
 pub(crate) fn is_pipeline(q: LazyFrame) -> bool {
     let (mut expr_arena, mut lp_arena) = get_arenas();
@@ -691,3 +706,32 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_low_latency_small_query() -> PolarsResult<()> {
+    let df = df!("foo" => &[0.5, 1.7, 3.2],
+                 "bar" => &[4.1, 1.5, 9.2])?;
+
+    let lf = df
+        .lazy()
+        .with_columns([col("foo") * lit(2.0)])
+        .with_columns([col("bar") / lit(1.5)])
+        .with_low_latency(true);
+
+    let plan = lf.clone().to_alp_optimized()?;
+    let mut n_with_columns = 0;
+    for (_, lp) in (&plan.lp_arena).iter(plan.lp_top) {
+        if let IR::HStack { options, .. } = lp {
+            n_with_columns += 1;
+            assert!(!options.run_parallel);
+        }
+    }
+    // `cluster_with_columns` is skipped.
+    assert_eq!(n_with_columns, 2);
+
+    let out = lf.collect()?;
+    let expected = df!("foo" => &[1.0, 3.4, 6.4],
+                       "bar" => &[4.1 / 1.5, 1.5 / 1.5, 9.2 / 1.5])?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
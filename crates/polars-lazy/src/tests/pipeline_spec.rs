@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+
+use super::*;
+
+#[test]
+fn test_pipeline_spec_json_roundtrip() -> PolarsResult<()> {
+    let spec = PipelineSpec {
+        sources: BTreeMap::from([
+            (
+                "left".to_string(),
+                SourceSpec::Plan(load_df().lazy().logical_plan),
+            ),
+            (
+                "right".to_string(),
+                SourceSpec::Plan(df!["b" => ["a", "c"], "d" => [10, 20]]?.lazy().logical_plan),
+            ),
+        ]),
+        input: "left".to_string(),
+        steps: vec![
+            StepSpec::Filter(col("a").gt(lit(1))),
+            StepSpec::Join {
+                other: "right".to_string(),
+                left_on: vec![col("b")],
+                right_on: vec![col("b")],
+                how: JoinType::Inner,
+                suffix: None,
+            },
+            StepSpec::Sort {
+                by: vec![col("a")],
+                options: Default::default(),
+            },
+            StepSpec::Select(vec![col("a"), col("d")]),
+        ],
+        sink: None,
+    };
+    let json = serde_json::to_string(&spec).unwrap();
+    let out = PipelineSpec::from_json(&json)?.run()?.unwrap();
+
+    let expected = df!["a" => [2, 4, 5], "d" => [10, 20, 20]]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_pipeline_spec_yaml() -> PolarsResult<()> {
+    let yaml = format!(
+        "
+sources:
+  foods:
+    csv:
+      path: {FOODS_CSV}
+input: foods
+steps:
+  - select: [{{Column: category}}]
+  - unique: {{}}
+  - sort:
+      by: [{{Column: category}}]
+"
+    );
+    let out = PipelineSpec::from_yaml(&yaml)?.build()?.collect()?;
+
+    let expected = LazyCsvReader::new(FOODS_CSV)
+        .finish()?
+        .select([col("category")])
+        .unique(None, Default::default())
+        .sort(["category"], Default::default())
+        .collect()?;
+    assert!(out.equals(&expected));
+
+    let err = PipelineSpec::from_yaml("sources: {}\ninput: missing")?.build();
+    assert!(err.is_err());
+    Ok(())
+}
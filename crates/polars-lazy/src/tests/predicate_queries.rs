@@ -1,3 +1,5 @@
+use polars_ops::frame::JoinCoalesce;
+
 use super::*;
 
 #[test]
@@ -333,3 +335,50 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_push_join_key_predicates_across_renamed_keys() -> PolarsResult<()> {
+    let left = df! {
+        "a" => [1, 2, 3, 4],
+        "v" => ["w", "x", "y", "z"],
+    }?;
+    let right = df! {
+        "k" => [1, 2, 2, 3],
+        "w" => [10, 20, 21, 30],
+    }?;
+
+    // A predicate on the right key of an inner join filters the left key too.
+    let q = left
+        .clone()
+        .lazy()
+        .join(
+            right.clone().lazy(),
+            [col("a")],
+            [col("k")],
+            JoinArgs::new(JoinType::Inner).with_coalesce(JoinCoalesce::KeepColumns),
+        )
+        .filter(col("k").gt(lit(1)));
+    assert!(predicate_at_each_scan(q.clone()));
+    let out = q.sort(["w"], Default::default()).collect()?;
+    assert_eq!(out.column("a")?, &Series::new("a", [2, 2, 3]));
+
+    // A predicate on the left key of a semi or anti join filters the right key too.
+    #[cfg(feature = "semi_anti_join")]
+    for (how, expected) in [(JoinType::Semi, vec![2, 3]), (JoinType::Anti, vec![4])] {
+        let q = left
+            .clone()
+            .lazy()
+            .join(
+                right.clone().lazy(),
+                [col("a")],
+                [col("k")],
+                JoinArgs::new(how),
+            )
+            .filter(col("a").gt(lit(1)));
+        assert!(predicate_at_each_scan(q.clone()));
+        let out = q.sort(["a"], Default::default()).collect()?;
+        assert_eq!(out.column("a")?, &Series::new("a", expected));
+    }
+
+    Ok(())
+}
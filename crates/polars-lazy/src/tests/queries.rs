@@ -760,6 +760,92 @@ fn test_lazy_group_by() {
     assert_eq!(out.column("a").unwrap().f64().unwrap().get(0), Some(1.0));
 }
 
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_lazy_group_by_agg_with_names() -> PolarsResult<()> {
+    let df = df! {
+        "a" => &[1, 2, 3, 4, 5],
+        "b" => &[1.0, 2.0, 3.0, 4.0, 5.0],
+        "groups" => &["a", "a", "b", "c", "c"]
+    }?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("groups")])
+        .agg_with_names(
+            [
+                col("*").exclude(["groups"]).sum(),
+                col("a").max().alias("top"),
+                as_struct(vec![
+                    col("b").min().alias("min"),
+                    col("b").max().alias("max"),
+                ])
+                .alias("b_range"),
+            ],
+            "{col}_{agg}",
+        )
+        .flatten_agg_struct("_")?
+        .collect()?;
+
+    assert_eq!(
+        out.get_column_names(),
+        &[
+            "groups",
+            "a_sum",
+            "b_sum",
+            "top",
+            "b_range_min",
+            "b_range_max"
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("a_sum")?.i32()?),
+        &[Some(3), Some(3), Some(9)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b_range_max")?.f64()?),
+        &[Some(2.0), Some(3.0), Some(5.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "mode")]
+fn test_lazy_group_by_first_last_n_mode_k() -> PolarsResult<()> {
+    let df = df! {
+        "groups" => &["x", "x", "y", "x", "y", "x", "x"],
+        "a" => &[1, 2, 3, 4, 5, 6, 7],
+        "v" => &[1, 2, 1, 2, 3, 2, 1]
+    }?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("groups")])
+        .agg([
+            col("a").first_n(2).alias("first"),
+            col("a").last_n(2).alias("last"),
+            col("a")
+                .filter(col("a").gt(lit(1)))
+                .first_n(2)
+                .alias("first_filtered"),
+            col("v").mode_k(2).alias("mode"),
+        ])
+        .collect()?;
+
+    let get = |name: &str, i: usize| -> PolarsResult<Vec<Option<i32>>> {
+        let s = out.column(name)?.list()?.get_as_series(i).unwrap();
+        Ok(Vec::from(s.i32()?))
+    };
+    assert_eq!(get("first", 0)?, &[Some(1), Some(2)]);
+    assert_eq!(get("first", 1)?, &[Some(3), Some(5)]);
+    assert_eq!(get("last", 0)?, &[Some(6), Some(7)]);
+    assert_eq!(get("last", 1)?, &[Some(3), Some(5)]);
+    assert_eq!(get("first_filtered", 0)?, &[Some(2), Some(4)]);
+    assert_eq!(get("mode", 0)?, &[Some(2), Some(1)]);
+    assert_eq!(get("mode", 1)?, &[Some(1), Some(3)]);
+    Ok(())
+}
+
 #[test]
 fn test_lazy_tail() {
     let df = df! {
@@ -1921,3 +2007,458 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_table_registry() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+    ]?;
+    let size = df.estimated_size();
+    let registry = TableRegistry::new(TableRegistryOptions {
+        max_memory: Some(2 * size),
+        default_ttl: None,
+    });
+
+    registry.register_df("t1", df.clone())?;
+    registry.register_df("t2", df.clone())?;
+    registry.register("q", registry.get("t1").unwrap().select([col("a") * lit(2)]));
+    assert_eq!(registry.names(), &["q", "t1", "t2"]);
+    assert_eq!(registry.memory_usage(), 2 * size);
+
+    // `t2` is the least recently used DataFrame and is evicted.
+    registry.get("t1").unwrap();
+    registry.register_df("t3", df.clone())?;
+    assert_eq!(registry.names(), &["q", "t1", "t3"]);
+    assert_eq!(registry.memory_usage(), 2 * size);
+    assert!(registry.get("q").unwrap().collect()?.equals(&df![
+        "a" => [2, 4, 6],
+    ]?));
+
+    registry.register_df_with_ttl("tmp", df.clone(), Some(std::time::Duration::ZERO))?;
+    assert!(!registry.contains("tmp"));
+    assert!(registry.get("tmp").is_none());
+
+    assert!(registry.unregister("t1"));
+    assert!(!registry.unregister("t1"));
+    assert_eq!(registry.memory_usage(), size);
+
+    TableRegistry::global().register_df("test_table_registry", df.clone())?;
+    let out = LazyFrame::scan_table("test_table_registry")?.collect()?;
+    assert!(out.equals(&df));
+    assert!(TableRegistry::global().unregister("test_table_registry"));
+    assert!(LazyFrame::scan_table("test_table_registry").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_estimate_resources() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000i64).rev().collect::<Vec<_>>(),
+    ]?;
+    let lf = df.lazy().sort(["a"], Default::default());
+
+    let estimate = lf.clone().estimate_resources_with_budget(None)?;
+    let names = estimate
+        .operators
+        .iter()
+        .map(|op| (op.name, op.depth))
+        .collect::<Vec<_>>();
+    assert_eq!(names, &[("sort", 0), ("df", 1)]);
+    let (sort, scan) = (&estimate.operators[0], &estimate.operators[1]);
+    assert_eq!(scan.rows, 1000);
+    assert_eq!(scan.output_bytes, 8000);
+    assert_eq!(sort.rows, 1000);
+    // The sort holds its input, the sort indices and its output.
+    assert!(sort.peak_bytes > 2 * scan.output_bytes);
+    assert_eq!(estimate.peak_bytes, sort.peak_bytes);
+    assert_eq!(estimate.spill_bytes, 0);
+    assert!(estimate.complete);
+    assert!(estimate.fits_in_memory());
+
+    let estimate = lf.estimate_resources_with_budget(Some(1000))?;
+    assert!(!estimate.fits_in_memory());
+    assert!(estimate.operators[0].spill_bytes > 0);
+    assert_eq!(estimate.operators[1].spill_bytes, 0);
+    assert_eq!(estimate.to_df()?.shape(), (2, 5));
+    Ok(())
+}
+
+#[test]
+fn test_execution_hooks() -> PolarsResult<()> {
+    #[derive(Default)]
+    struct Recorder {
+        events: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl ExecutionHooks for Recorder {
+        fn on_plan_optimized(&self, event: &PlanOptimizedEvent) {
+            let mut events = self.events.lock().unwrap();
+            events.push(format!("plan: {}", event.plan));
+        }
+
+        fn on_operator_start(&self, event: &OperatorEvent) {
+            let mut events = self.events.lock().unwrap();
+            events.push(format!("start: {}", event.name));
+        }
+
+        fn on_operator_finish(&self, event: &OperatorEvent) {
+            assert!(event.elapsed.is_some());
+            let mut events = self.events.lock().unwrap();
+            events.push(format!("finish: {}", event.name));
+        }
+    }
+
+    let recorder = Arc::new(Recorder::default());
+    register_execution_hooks(recorder.clone());
+    let out = df![
+        "hooks_col" => [1, 2, 3],
+    ]?
+    .lazy()
+    .sort(["hooks_col"], Default::default())
+    .collect();
+    clear_execution_hooks();
+    assert_eq!(out?.height(), 3);
+
+    // Other tests may run queries concurrently, so only look at the events of this query.
+    let events = recorder.events.lock().unwrap();
+    let events = events
+        .iter()
+        .filter(|e| e.contains("hooks_col"))
+        .map(|e| e.split(':').next().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(events, ["plan", "start", "finish"]);
+    Ok(())
+}
+
+#[test]
+fn test_collect_cancelable() -> PolarsResult<()> {
+    let q = fruits_cars().lazy().sort(["A"], Default::default());
+
+    let query = q.clone().collect_cancelable()?;
+    assert!(!query.cancel_handle().is_cancelled());
+    assert_eq!(query.collect()?.height(), 5);
+
+    let query = q.collect_cancelable()?;
+    query.cancel_handle().cancel();
+    assert!(matches!(query.collect(), Err(PolarsError::Cancelled(_))));
+    Ok(())
+}
+
+#[test]
+fn test_predicate_cache() -> PolarsResult<()> {
+    let mut cache = PredicateCache::new(fruits_cars()).with_max_masks(2);
+    let apples = col("fruits").eq(lit("apple"));
+    let beetles = col("cars").eq(lit("beetle"));
+
+    let out = cache.filter(apples.clone().and(beetles.clone()))?;
+    assert_eq!(out.height(), 2);
+    let out = cache.filter(beetles.clone().and(col("A").gt(lit(1))))?;
+    assert_eq!(out.height(), 3);
+    assert_eq!(cache.stats(), PredicateCacheStats { hits: 1, misses: 3 });
+
+    // `apples` is the least recently used mask and was dropped.
+    cache.filter(apples.clone())?;
+    assert_eq!(cache.stats().misses, 4);
+
+    // Mutation drops the masks.
+    cache
+        .df_mut()
+        .with_column(Series::new("cars", ["audi"; 5]))?;
+    let out = cache.filter(beetles)?;
+    assert_eq!(out.height(), 0);
+    assert_eq!(cache.stats().misses, 5);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "is_in")]
+fn test_in_frame() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1i64), Some(2), None, Some(4), Some(5)],
+    ]?;
+    let other = df![
+        "v" => [Some(2i32), Some(4), None, Some(4)],
+    ]?
+    .lazy();
+
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("a").in_frame(other.clone(), "v"))
+        .collect()?;
+    assert_eq!(out, df!["a" => [2i64, 4]]?);
+
+    let out = df
+        .clone()
+        .lazy()
+        .filter(
+            col("a")
+                .in_frame(other.clone(), "v")
+                .or(col("a").eq(lit(5))),
+        )
+        .collect()?;
+    assert_eq!(out, df!["a" => [2i64, 4, 5]]?);
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_column(col("a").in_frame(other.clone(), "v").alias("found"))
+        .collect()?;
+    let expected = df![
+        "a" => [Some(1i64), Some(2), None, Some(4), Some(5)],
+        "found" => [Some(false), Some(true), None, Some(true), Some(false)],
+    ]?;
+    assert_eq!(out, expected);
+
+    let out = df
+        .lazy()
+        .select([col("a").in_frame(other, "v")])
+        .collect()?;
+    assert_eq!(
+        out,
+        df!["a" => [Some(false), Some(true), None, Some(true), Some(false)]]?
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "period_join")]
+fn test_join_period() -> PolarsResult<()> {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let facts = df![
+        "ts" => [date(2021, 1, 15), date(2020, 12, 31), date(2021, 4, 1), date(2021, 2, 10)],
+        "amount" => [1, 2, 3, 4]
+    ]?;
+    // The second version is valid from 2021-02-05 on.
+    let versions = df![
+        "valid_from" => [date(2021, 1, 1), date(2021, 2, 5)],
+        "valid_to" => [Some(date(2021, 2, 5)), None],
+        "version" => ["a", "b"]
+    ]?;
+
+    for granularity in [
+        PeriodGranularity::Day,
+        PeriodGranularity::Month,
+        PeriodGranularity::Quarter,
+        PeriodGranularity::Year,
+    ] {
+        let out = facts
+            .clone()
+            .lazy()
+            .join_period(
+                versions.clone().lazy(),
+                col("ts"),
+                col("valid_from"),
+                col("valid_to"),
+                granularity,
+                JoinType::Inner,
+            )?
+            .select([col("amount"), col("version")])
+            .sort(["amount"], Default::default())
+            .collect()?;
+        assert_eq!(
+            out,
+            df!["amount" => [1, 3, 4], "version" => ["a", "b", "b"]]?
+        );
+
+        let out = facts
+            .clone()
+            .lazy()
+            .join_period(
+                versions.clone().lazy(),
+                col("ts"),
+                col("valid_from"),
+                col("valid_to"),
+                granularity,
+                JoinType::Left,
+            )?
+            .select([col("amount"), col("version")])
+            .collect()?;
+        assert_eq!(
+            out,
+            df![
+                "amount" => [1, 2, 3, 4],
+                "version" => [Some("a"), None, Some("b"), Some("b")]
+            ]?
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_join_duplicate_keys() -> PolarsResult<()> {
+    let left = df!["k" => [1, 2, 3], "a" => [1, 2, 3]]?.lazy();
+    let right = df!["k" => [1, 1, 2], "b" => [10, 20, 30]]?.lazy();
+
+    let report = right.clone().duplicate_keys([col("k")], 1).collect()?;
+    assert_eq!(report.shape(), (1, 2));
+    assert_eq!(report.column("count")?.idx()?.get(0), Some(2));
+
+    let out = left
+        .clone()
+        .join_builder()
+        .with(right.clone())
+        .on([col("k")])
+        .how(JoinType::Left)
+        .on_duplicate_keys(DuplicateKeyPolicy::Aggregate(vec![col("b").sum()]))
+        .finish()
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(30), Some(30), None]
+    );
+
+    let out = left
+        .join_builder()
+        .with(right)
+        .on([col("k")])
+        .on_duplicate_keys(DuplicateKeyPolicy::Error)
+        .finish()
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "top_k")]
+fn test_group_by_top_k() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 2, 1, 1, 2, 1, 2],
+        "v" => [Some(3), Some(7), None, Some(5), Some(1), Some(4), None]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("v").top_k(lit(2)).alias("top"),
+            col("v").bottom_k(lit(2)).alias("bottom"),
+            col("v")
+                .sort(SortOptions::default().with_order_descending(true))
+                .head(Some(3))
+                .alias("head"),
+        ])
+        .collect()?;
+    let lists = |name: &str| -> PolarsResult<Vec<Vec<Option<i32>>>> {
+        Ok(out
+            .column(name)?
+            .list()?
+            .into_no_null_iter()
+            .map(|s| Vec::from(s.i32().unwrap()))
+            .collect())
+    };
+    assert_eq!(
+        lists("top")?,
+        [vec![Some(5), Some(4)], vec![Some(7), Some(1)]]
+    );
+    assert_eq!(
+        lists("bottom")?,
+        [vec![Some(3), Some(4)], vec![Some(1), Some(7)]]
+    );
+    // Sorting descending puts the nulls first by default.
+    assert_eq!(
+        lists("head")?,
+        [vec![None, Some(5), Some(4)], vec![None, Some(7), Some(1)]]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("v")
+            .sort(SortOptions::default().with_nulls_last(true))
+            .head(Some(1))
+            .over_with_options([col("g")], None, WindowMapping::Explode)])
+        .collect()?;
+    let mut heads = Vec::from(out.column("v")?.i32()?);
+    heads.sort();
+    assert_eq!(heads, &[Some(1), Some(3)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "semantic_types")]
+fn test_infer_semantic_types() -> PolarsResult<()> {
+    let df = df![
+        "contact" => [None, Some("a@example.com"), Some("b@example.org"), Some("n/a")],
+        "site" => ["https://pola.rs", "http://example.com/a", "ftp://example.org", "https://x.io"],
+        "amount" => ["$1,000", "$2.50", "bad", "€3"],
+        "id" => [1, 2, 3, 4],
+    ]?;
+    let options = SemanticTypeOptions {
+        min_match_fraction: 0.6,
+        validate: true,
+        ..Default::default()
+    };
+    let (lf, types) = df
+        .lazy()
+        .infer_semantic_types(SemanticTypeInference::new(options))?;
+    let inferred = types
+        .iter()
+        .map(|m| (m.column.as_str(), m.semantic_type.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        inferred,
+        [
+            ("contact", "email"),
+            ("site", "url"),
+            ("amount", "currency")
+        ]
+    );
+
+    let out = lf.collect()?;
+    let expected = df![
+        "contact" => [None, Some("a@example.com"), Some("b@example.org"), None],
+        "amount" => [Some(1000.0), Some(2.5), None, Some(3.0)],
+    ]?;
+    assert!(out.select(["contact", "amount"])?.equals_missing(&expected));
+    assert_eq!(out.column("site")?.null_count(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "binary_encryption")]
+fn test_encrypt_decrypt_aead() -> PolarsResult<()> {
+    let df = df![
+        "id" => ["a", "b", "c"],
+        "ssn" => [Some("123-45-6789"), None, Some("987-65-4321")],
+    ]?;
+    let key = AeadKey::new([42u8; 32]);
+    let algorithm = AeadAlgorithm::ChaCha20Poly1305;
+
+    let encrypted = df
+        .clone()
+        .lazy()
+        .with_column(
+            col("ssn")
+                .binary()
+                .encrypt_aead(key.clone(), algorithm, Some(col("id"))),
+        )
+        .collect()?;
+    assert_eq!(encrypted.column("ssn")?.dtype(), &DataType::Binary);
+    assert_eq!(encrypted.column("ssn")?.null_count(), 1);
+
+    let decrypted = encrypted
+        .clone()
+        .lazy()
+        .with_column(
+            col("ssn")
+                .binary()
+                .decrypt_aead(key.clone(), algorithm, Some(col("id")), true)
+                .cast(DataType::String),
+        )
+        .collect()?;
+    assert!(decrypted.equals_missing(&df));
+
+    // The values are bound to their rows by the associated data.
+    let out = encrypted
+        .lazy()
+        .with_column(col("ssn").binary().decrypt_aead(
+            key,
+            algorithm,
+            Some(col("id").reverse()),
+            true,
+        ))
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn test_repro_roundtrip() -> PolarsResult<()> {
+    let right = df!["b" => ["a", "c"], "d" => [10, 20]]?;
+    let lf = load_df()
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .join(right.lazy(), [col("b")], [col("b")], JoinType::Inner.into())
+        .sort(["a"], Default::default())
+        .with_predicate_pushdown(false);
+
+    let path = std::env::temp_dir().join("polars_test_repro_roundtrip.json");
+    lf.save_repro(&path)?;
+    let bundle = ReproBundle::read(&path)?;
+    assert_eq!(bundle.inputs.len(), 2);
+    assert!(bundle.inputs.iter().all(|input| !input.sampled));
+    assert!(!bundle.opt_state.predicate_pushdown);
+
+    let out = LazyFrame::load_repro(&path)?.collect()?;
+    std::fs::remove_file(&path)?;
+    assert!(out.equals(&lf.collect()?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_repro_samples_large_inputs() -> PolarsResult<()> {
+    let lf = scan_foods_csv().select([col("category"), col("calories")]);
+    let options = ReproOptions {
+        full_data_threshold: 0,
+        sample_rows: 3,
+    };
+    let bundle = lf.to_repro_bundle(options)?;
+    assert_eq!(bundle.inputs.len(), 1);
+    assert!(bundle.inputs[0].sampled);
+    assert_eq!(bundle.inputs[0].height, 3);
+
+    let out = bundle.lazy_frame().collect()?;
+    assert_eq!(out.shape(), (3, 2));
+    Ok(())
+}
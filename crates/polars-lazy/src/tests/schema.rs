@@ -40,3 +40,52 @@ fn test_schema_update_after_projection_pd() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_conform_to_schema() -> PolarsResult<()> {
+    let df = df![
+        "b" => [1i32, 2],
+        "extra" => ["x", "y"],
+        "a" => ["p", "q"],
+    ]?;
+    let target = Schema::from_iter([
+        Field::new("a", DataType::String),
+        Field::new("b", DataType::Int64),
+        Field::new("c", DataType::Float64),
+    ]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .conform_to(&target, ConformOptions::default())?
+        .collect()?;
+    assert_eq!(out.schema(), target);
+    assert_eq!(out.column("c")?.null_count(), 2);
+
+    let options = ConformOptions {
+        extra: ExtraColumns::Error,
+        ..Default::default()
+    };
+    assert!(df.clone().lazy().conform_to(&target, options).is_err());
+
+    let options = ConformOptions {
+        missing: MissingColumns::Error,
+        ..Default::default()
+    };
+    assert!(df.clone().lazy().conform_to(&target, options).is_err());
+
+    // Int32 -> UInt32 may lose values, so it's only allowed with strict casts.
+    let narrow = Schema::from_iter([Field::new("b", DataType::UInt32)]);
+    assert!(df
+        .clone()
+        .lazy()
+        .conform_to(&narrow, ConformOptions::default())
+        .is_err());
+    let options = ConformOptions {
+        cast: ConformCast::Strict,
+        ..Default::default()
+    };
+    let out = df.lazy().conform_to(&narrow, options)?.collect()?;
+    assert_eq!(out.column("b")?.dtype(), &DataType::UInt32);
+    Ok(())
+}
@@ -70,6 +70,25 @@ fn test_streaming_glob() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_streaming_multi_output_expressions() -> PolarsResult<()> {
+    let q = get_csv_file();
+
+    let q = q
+        .select([
+            as_struct(vec![col("category"), col("calories")]).alias("s"),
+            cols(["fats_g", "sugars_g"]) * lit(2),
+        ])
+        .select([
+            col("s").struct_().field_by_name("*"),
+            col("*").exclude(["s"]),
+        ]);
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_union_order() -> PolarsResult<()> {
     let q = get_csv_glob();
@@ -91,6 +110,24 @@ fn test_streaming_union_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_unpivot() -> PolarsResult<()> {
+    let q = get_csv_glob();
+    let args = UnpivotArgs {
+        on: vec!["calories".into(), "fats_g".into()],
+        index: vec!["category".into()],
+        streamable: true,
+        ..Default::default()
+    };
+    // The streaming engine doesn't maintain the order of the unpivoted rows.
+    let q = q
+        .unpivot(args)
+        .sort(["category", "variable", "value"], Default::default());
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_multiple_keys_aggregate() -> PolarsResult<()> {
     let q = get_csv_glob();
@@ -128,6 +165,24 @@ fn test_streaming_first_sum() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "mode")]
+fn test_streaming_first_last_n_mode_k() -> PolarsResult<()> {
+    let q = get_csv_glob();
+
+    let q = q
+        .group_by([col("category")])
+        .agg([
+            col("calories").first_n(3).alias("calories_first"),
+            col("calories").last_n(2).alias("calories_last"),
+            col("sugars_g").mode_k(2).alias("sugars_mode"),
+        ])
+        .sort(["category"], Default::default());
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_unique() -> PolarsResult<()> {
     let q = get_csv_file();
@@ -260,6 +315,97 @@ fn test_streaming_left_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "merge_sorted")]
+fn test_streaming_merge_sorted() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let left = df![
+        "a" => [0, 2, 4, 6, 8, 10],
+        "b" => ["a", "b", "c", "d", "e", "f"]
+    ]?
+    .lazy();
+    let right = df![
+        "a" => [1, 3, 5, 7, 11, 13, 15],
+        "b" => ["g", "h", "i", "j", "k", "l", "m"]
+    ]?
+    .lazy();
+
+    let q = left.merge_sorted(right, "a")?;
+    assert_streaming_with_default(q.clone(), true, false);
+
+    let out = q.with_streaming(true).collect()?;
+    assert_eq!(out.column("a")?.is_sorted_flag(), IsSorted::Ascending);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "diagonal_concat")]
+fn test_streaming_concat_by_name() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_streaming_concat_by_name");
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("0.csv"), "a,b\n1,x\n2,y\n")?;
+    std::fs::write(dir.join("1.csv"), "b,a,c\nz,2.5,1\n")?;
+
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Float64),
+        Field::new("b", DataType::String),
+        Field::new("d", DataType::Int64),
+    ]));
+    let inputs = ["0.csv", "1.csv"]
+        .iter()
+        .map(|file| LazyCsvReader::new(dir.join(file)).finish())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let q = concat_lf_by_name(inputs, schema, Default::default())?;
+    assert_streaming_with_default(q.clone(), true, false);
+
+    let out = q.with_streaming(true).collect()?;
+    let expected = df![
+        "a" => [1.0, 2.0, 2.5],
+        "b" => ["x", "y", "z"],
+        "d" => [None::<i64>, None, None]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_streaming_join_validation() -> PolarsResult<()> {
+    let unique = df!["a" => [0, 1, 2, 3], "b" => [0, 1, 2, 3]]?.lazy();
+    let duplicates = df!["a" => [1, 1, 2, 5, 6, 7], "c" => [0, 1, 2, 3, 4, 5]]?.lazy();
+
+    let join = |left: &LazyFrame, right: &LazyFrame, how: &JoinType, validation| {
+        left.clone()
+            .join_builder()
+            .with(right.clone())
+            .on([col("a")])
+            .how(how.clone())
+            .validate(validation)
+            .finish()
+            .with_streaming(true)
+    };
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Full] {
+        let q = join(&duplicates, &unique, &how, JoinValidation::ManyToOne);
+        assert!(optimization_checks::has_pipeline(q.clone()));
+        let expected = q.clone().with_streaming(false).collect()?;
+        assert_eq!(q.collect()?.shape(), expected.shape());
+
+        for validation in [JoinValidation::OneToMany, JoinValidation::OneToOne] {
+            assert!(join(&duplicates, &unique, &how, validation)
+                .collect()
+                .is_err());
+        }
+        for validation in [JoinValidation::ManyToOne, JoinValidation::OneToOne] {
+            assert!(join(&unique, &duplicates, &how, validation)
+                .collect()
+                .is_err());
+        }
+    }
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "cross_join")]
 fn test_streaming_slice() -> PolarsResult<()> {
@@ -428,3 +574,223 @@ fn test_streaming_full_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "custom_operators")]
+#[test]
+fn test_streaming_custom_operator() -> PolarsResult<()> {
+    use std::sync::Mutex;
+
+    use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+
+    // Keeps the first occurrence of every value; the seen values are shared between threads.
+    #[derive(Clone)]
+    struct Dedupe {
+        seen: Arc<Mutex<PlHashSet<i64>>>,
+    }
+
+    impl Operator for Dedupe {
+        fn execute(
+            &mut self,
+            _context: &PExecutionContext,
+            chunk: &DataChunk,
+        ) -> PolarsResult<OperatorResult> {
+            let mut seen = self.seen.lock().unwrap();
+            let mask: BooleanChunked = chunk
+                .data
+                .column("calories")?
+                .i64()?
+                .into_iter()
+                .map(|opt_v| opt_v.map(|v| seen.insert(v)))
+                .collect();
+            let df = chunk.data.filter(&mask)?;
+            Ok(OperatorResult::Finished(chunk.with_data(df)))
+        }
+
+        fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+            Box::new(self.clone())
+        }
+
+        fn fmt(&self) -> &str {
+            "dedupe"
+        }
+    }
+
+    let q = |streaming: bool| {
+        let dedupe = Dedupe {
+            seen: Default::default(),
+        };
+        get_csv_file()
+            .select([col("calories")])
+            .map_streaming(Box::new(dedupe), None, Some("dedupe"))
+            .with_streaming(streaming)
+    };
+    assert!(optimization_checks::is_pipeline(q(true)));
+
+    let expected = get_csv_file()
+        .select([col("calories")])
+        .unique(None, UniqueKeepStrategy::Any)
+        .sort(["calories"], Default::default())
+        .collect()?;
+    for streaming in [true, false] {
+        let out = q(streaming)
+            .sort(["calories"], Default::default())
+            .collect()?;
+        assert_eq!(out, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_streaming_row_index() -> PolarsResult<()> {
+    let q = get_csv_glob();
+    let q = q
+        .filter(col("calories").gt(lit(50)))
+        .with_row_index("index", Some(10))
+        .filter(col("sugars_g").gt(lit(2)))
+        .select([col("index"), col("category")]);
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+fn test_head_streaming() -> PolarsResult<()> {
+    let q = get_csv_glob().filter(col("calories").gt(lit(50)));
+    let total = q.clone().collect()?.height();
+
+    let out = q.clone().head_streaming(5)?;
+    assert_eq!(out.height(), 5);
+    assert_eq!(out.schema(), q.clone().collect()?.schema());
+
+    // Fewer rows than requested.
+    let out = q.head_streaming((total + 10) as IdxSize)?;
+    assert_eq!(out.height(), total);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_join_cancelable() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .join(
+            get_parquet_file(),
+            [col("category")],
+            [col("category")],
+            JoinType::Full.into(),
+        )
+        .with_streaming(true);
+
+    let query = q.collect_cancelable()?;
+    query.cancel_handle().cancel();
+    assert!(matches!(query.collect(), Err(PolarsError::Cancelled(_))));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_memory_limit() -> PolarsResult<()> {
+    let q = get_parquet_file()
+        .select([col("calories")])
+        .sort(["calories"], Default::default());
+    let expected = q.clone().collect()?;
+
+    let query = q
+        .with_streaming(true)
+        .collect_cancelable()?
+        .with_memory_limit(1);
+    let handle = query.cancel_handle();
+    assert_eq!(handle.memory_usage().unwrap().used, 0);
+    let out = query.collect()?;
+    assert!(out.equals(&expected));
+
+    // The sort sink reserved its chunks and released them once it was dropped.
+    let usage = handle.memory_usage().unwrap();
+    assert_eq!(usage.limit, 1);
+    assert!(usage.peak > 0);
+    assert_eq!(usage.used, 0);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_spill_limit() -> PolarsResult<()> {
+    use polars_core::config::Config;
+
+    // A memory limit of a byte makes the sort spill.
+    let q = get_parquet_file()
+        .select([col("calories")])
+        .sort(["calories"], Default::default())
+        .with_streaming(true);
+
+    let query = q.clone().collect_cancelable()?.with_memory_limit(1);
+    let handle = query.cancel_handle();
+    query.collect()?;
+    let usage = handle.spill_usage();
+    assert_eq!(usage.limit, None);
+    assert!(usage.bytes > 0);
+    assert_eq!(usage.operators.len(), 1);
+    assert_eq!(usage.operators[0].operator, "sort");
+
+    let _guard = Config::new().with_max_spill_bytes(1).scoped();
+    let query = q.collect_cancelable()?.with_memory_limit(1);
+    assert!(matches!(
+        query.collect(),
+        Err(PolarsError::SpillLimitExceeded(_))
+    ));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "partition_by")]
+fn test_partition_by_iter() -> PolarsResult<()> {
+    let df = df![
+        "k" => [Some(2), None, Some(1), Some(2), Some(1), Some(3)],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?;
+    // Two groups per pass need two passes over the frame.
+    let parts = df
+        .clone()
+        .lazy()
+        .partition_by_iter(["k"], true)
+        .with_groups_per_pass(2)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    // The null key sorts first.
+    assert_eq!(parts.len(), 4);
+    assert_eq!(parts[0].column("k")?.null_count(), 1);
+    let sums = parts
+        .iter()
+        .map(|part| part.column("v")?.sum::<i32>())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(sums, [2, 8, 5, 6]);
+
+    let parts = df
+        .lazy()
+        .partition_by_iter(["k"], false)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(parts.len(), 4);
+    assert_eq!(parts[1].get_column_names(), ["v"]);
+    Ok(())
+}
+
+#[test]
+fn test_collect_paged() -> PolarsResult<()> {
+    let q = get_csv_glob().select([col("category"), col("calories")]);
+    let expected = q.clone().collect()?;
+
+    let pages = q.collect_paged(7)?.collect::<PolarsResult<Vec<_>>>()?;
+    let (last, full) = pages.split_last().unwrap();
+    assert!(full.iter().all(|page| page.height() == 7));
+    assert!(last.height() > 0 && last.height() <= 7);
+    let heights = pages.iter().map(|page| page.height()).sum::<usize>();
+    assert_eq!(heights, expected.height());
+
+    // The pages of a sorted query are in the sorted order, the sort is on all columns so that
+    // the ties are equal rows.
+    let q = get_csv_glob()
+        .select([col("category"), col("calories")])
+        .sort(["calories", "category"], Default::default());
+    let expected = q.clone().collect()?;
+    let pages = q.collect_paged(5)?.collect::<PolarsResult<Vec<_>>>()?;
+    let out = polars_core::utils::accumulate_dataframes_vertical_unchecked(pages);
+    assert!(out.equals_missing(&expected));
+
+    assert!(get_csv_glob().collect_paged(0).is_err());
+    Ok(())
+}
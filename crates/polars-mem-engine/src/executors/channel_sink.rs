@@ -0,0 +1,25 @@
+use super::*;
+
+/// Sends the result of its input to a channel, for plans that the streaming engine can't run.
+pub struct ChannelSinkExec {
+    pub input: Box<dyn Executor>,
+    pub sender: ChunkSender,
+}
+
+impl Executor for ChannelSinkExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                eprintln!("run ChannelSinkExec")
+            }
+        }
+        let df = self.input.execute(state)?;
+        let out = df.clear();
+        // The receiver may have hung up, then nobody is interested in the result.
+        if df.height() > 0 {
+            let _ = self.sender.0.send(df);
+        }
+        Ok(out)
+    }
+}
@@ -65,19 +65,19 @@ fn run_partitions(
     df: &mut DataFrame,
     exec: &PartitionGroupByExec,
     state: &ExecutionState,
-    n_threads: usize,
+    n_partitions: usize,
     maintain_order: bool,
 ) -> PolarsResult<(Vec<DataFrame>, Vec<Vec<Series>>)> {
     // We do a partitioned group_by.
     // Meaning that we first do the group_by operation arbitrarily
     // split on several threads. Than the final result we apply the same group_by again.
-    let dfs = split_df(df, n_threads, true);
+    let dfs = split_df(df, n_partitions, true);
 
     let phys_aggs = &exec.phys_aggs;
     let keys = &exec.phys_keys;
 
     let mut keys = DataFrame::from_iter(compute_keys(keys, df, state)?);
-    let splitted_keys = split_df(&mut keys, n_threads, true);
+    let splitted_keys = split_df(&mut keys, n_partitions, true);
 
     POOL.install(|| {
         dfs.into_par_iter()
@@ -115,7 +115,15 @@ fn run_partitions(
     })
 }
 
-fn estimate_unique_count(keys: &[Series], mut sample_size: usize) -> PolarsResult<usize> {
+/// What a sample of the keys tells about the distribution of the keys.
+struct KeySample {
+    unique_estimate: usize,
+    /// The fraction of the sampled rows that belong to a heavy hitter, a key that makes up at
+    /// least 1% of the sample.
+    hot_fraction: f64,
+}
+
+fn sample_keys(keys: &[Series], mut sample_size: usize) -> PolarsResult<KeySample> {
     // https://stats.stackexchange.com/a/19090/147321
     // estimated unique size
     // u + ui / m (s - m)
@@ -135,8 +143,21 @@ fn estimate_unique_count(keys: &[Series], mut sample_size: usize) -> PolarsResul
         } else {
             groups.iter().filter(|g| g.len() == 1).count() as f64
         };
+        let unique_estimate =
+            (u + (ui / sample_size as f64) * (set_size - sample_size) as f64) as usize;
 
-        (u + (ui / sample_size as f64) * (set_size - sample_size) as f64) as usize
+        // The sample of multiple keys can be smaller than `sample_size`.
+        let sampled_rows = groups.iter().map(|g| g.len()).sum::<usize>();
+        let min_hot_len = std::cmp::max(2, sampled_rows / 100);
+        let hot_rows = groups
+            .iter()
+            .map(|g| g.len())
+            .filter(|len| *len >= min_hot_len)
+            .sum::<usize>();
+        KeySample {
+            unique_estimate,
+            hot_fraction: hot_rows as f64 / std::cmp::max(sampled_rows, 1) as f64,
+        }
     };
 
     if keys.len() == 1 {
@@ -165,6 +186,26 @@ const PARTITION_LIMIT: usize = 15;
 #[cfg(not(debug_assertions))]
 const PARTITION_LIMIT: usize = 1000;
 
+#[cfg(debug_assertions)]
+const FLUSH_SIZE: usize = 8;
+#[cfg(not(debug_assertions))]
+const FLUSH_SIZE: usize = 1 << 16;
+
+/// How the group_by is executed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum GroupByStrategy {
+    /// The default hash aggregation.
+    Default,
+    /// Every thread pre-aggregates a part of the data, and the partial aggregates are merged.
+    Partitioned,
+    /// The keys have a long tail of unique values, but a few keys make up a large part of the
+    /// rows. Every thread pre-aggregates morsels of at most `flush_size` rows, so that the hash
+    /// tables stay small while the heavy hitters are reduced to a row per morsel before they
+    /// are merged. Without this, the rows of the heavy hitters all end up in the same partition
+    /// of the default hash aggregation.
+    PreAggregated { flush_size: usize },
+}
+
 // Checks if we should run normal or default aggregation
 // by sampling data.
 fn can_run_partitioned(
@@ -172,7 +213,7 @@ fn can_run_partitioned(
     original_df: &DataFrame,
     state: &ExecutionState,
     from_partitioned_ds: bool,
-) -> PolarsResult<bool> {
+) -> PolarsResult<GroupByStrategy> {
     if !keys
         .iter()
         .take(1)
@@ -181,32 +222,32 @@ fn can_run_partitioned(
         if state.verbose() {
             eprintln!("FOUND SORTED KEY: running default HASH AGGREGATION")
         }
-        Ok(false)
+        Ok(GroupByStrategy::Default)
     } else if std::env::var("POLARS_NO_PARTITION").is_ok() {
         if state.verbose() {
             eprintln!("POLARS_NO_PARTITION set: running default HASH AGGREGATION")
         }
-        Ok(false)
+        Ok(GroupByStrategy::Default)
     } else if std::env::var("POLARS_FORCE_PARTITION").is_ok() {
         if state.verbose() {
             eprintln!("POLARS_FORCE_PARTITION set: running partitioned HASH AGGREGATION")
         }
-        Ok(true)
+        Ok(GroupByStrategy::Partitioned)
     } else if original_df.height() < PARTITION_LIMIT && !cfg!(test) {
         if state.verbose() {
             eprintln!("DATAFRAME < {PARTITION_LIMIT} rows: running default HASH AGGREGATION")
         }
-        Ok(false)
+        Ok(GroupByStrategy::Default)
     } else {
         // below this boundary we assume the partitioned group_by will be faster
         let unique_count_boundary = std::env::var("POLARS_PARTITION_UNIQUE_COUNT")
             .map(|s| s.parse::<usize>().unwrap())
             .unwrap_or(1000);
 
-        let (unique_estimate, sampled_method) = match (keys.len(), keys[0].dtype()) {
+        let (unique_estimate, hot_fraction, sampled_method) = match (keys.len(), keys[0].dtype()) {
             #[cfg(feature = "dtype-categorical")]
             (1, DataType::Categorical(Some(rev_map), _) | DataType::Enum(Some(rev_map), _)) => {
-                (rev_map.len(), "known")
+                (rev_map.len(), None, "known")
             },
             _ => {
                 // sqrt(N) is a good sample size as it remains low on large numbers
@@ -215,7 +256,12 @@ fn can_run_partitioned(
 
                 // we never sample less than 100 data points.
                 let sample_size = std::cmp::max(100, sample_size);
-                (estimate_unique_count(keys, sample_size)?, "estimated")
+                let sample = sample_keys(keys, sample_size)?;
+                (
+                    sample.unique_estimate,
+                    Some(sample.hot_fraction),
+                    "estimated",
+                )
             },
         };
         if state.verbose() {
@@ -228,20 +274,33 @@ fn can_run_partitioned(
                 if state.verbose() {
                     eprintln!("PARTITIONED DS");
                 }
-                Ok(true)
+                Ok(GroupByStrategy::Partitioned)
             } else {
                 if state.verbose() {
                     eprintln!("PARTITIONED DS: estimated cardinality: {estimated_cardinality} exceeded the boundary: 0.4, running default HASH AGGREGATION");
                 }
-                Ok(false)
+                Ok(GroupByStrategy::Default)
             }
         } else if unique_estimate > unique_count_boundary {
+            // above this fraction of rows belonging to heavy hitters we pre-aggregate
+            let skew_boundary = std::env::var("POLARS_PARTITION_SKEW_FRACTION")
+                .map(|s| s.parse::<f64>().unwrap())
+                .unwrap_or(0.1);
+            if let Some(hot_fraction) = hot_fraction.filter(|f| *f >= skew_boundary) {
+                let flush_size = std::env::var("POLARS_PARTITION_FLUSH_SIZE")
+                    .map(|s| s.parse::<usize>().unwrap())
+                    .unwrap_or(FLUSH_SIZE);
+                if state.verbose() {
+                    eprintln!("SKEWED KEYS: {hot_fraction} of the sampled rows belong to heavy hitters, running PRE-AGGREGATED HASH AGGREGATION with flush size: {flush_size}")
+                }
+                return Ok(GroupByStrategy::PreAggregated { flush_size });
+            }
             if state.verbose() {
                 eprintln!("estimated unique count: {unique_estimate} exceeded the boundary: {unique_count_boundary}, running default HASH AGGREGATION")
             }
-            Ok(false)
+            Ok(GroupByStrategy::Default)
         } else {
-            Ok(true)
+            Ok(GroupByStrategy::Partitioned)
         }
     }
 }
@@ -259,7 +318,9 @@ impl PartitionGroupByExec {
             // of groups.
             let keys = self.keys(&original_df, state)?;
 
-            if !can_run_partitioned(&keys, &original_df, state, self.from_partitioned_ds)? {
+            let strategy =
+                can_run_partitioned(&keys, &original_df, state, self.from_partitioned_ds)?;
+            if strategy == GroupByStrategy::Default {
                 return group_by_helper(
                     original_df,
                     keys,
@@ -277,12 +338,21 @@ impl PartitionGroupByExec {
 
             // Run the partitioned aggregations
             let n_threads = POOL.current_num_threads();
+            let n_partitions = match strategy {
+                // The morsels are spread over the threads by rayon, every morsel has its own hash
+                // table that is flushed to the merge phase once the morsel is aggregated.
+                GroupByStrategy::PreAggregated { flush_size } => std::cmp::max(
+                    n_threads,
+                    original_df.height().div_ceil(std::cmp::max(flush_size, 1)),
+                ),
+                _ => n_threads,
+            };
 
             run_partitions(
                 &mut original_df,
                 self,
                 state,
-                n_threads,
+                n_partitions,
                 self.maintain_order,
             )?
         };
@@ -72,9 +72,17 @@ impl GroupByRollingExec {
             time_key = time_key.slice(offset, len);
         }
 
+        // The window boundaries come after the keys and are already in the output order.
+        let (keys_part, bounds) = keys.split_at_mut(self.keys.len());
+        if let Some((offset, len)) = self.slice {
+            for bound in bounds.iter_mut() {
+                *bound = bound.slice(offset, len);
+            }
+        }
+
         // the ordering has changed due to the group_by
-        if !keys.is_empty() {
-            unsafe { update_keys(&mut keys, groups) }
+        if !keys_part.is_empty() {
+            unsafe { update_keys(keys_part, groups) }
         };
 
         let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state)?;
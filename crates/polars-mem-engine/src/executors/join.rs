@@ -9,6 +9,8 @@ pub struct JoinExec {
     right_on: Vec<Arc<dyn PhysicalExpr>>,
     parallel: bool,
     args: JoinArgs,
+    /// Set from the keys of the right side before the left side is executed.
+    runtime_filter: Option<RuntimeFilter>,
 }
 
 impl JoinExec {
@@ -28,8 +30,16 @@ impl JoinExec {
             right_on,
             parallel,
             args,
+            runtime_filter: None,
         }
     }
+
+    /// Push the range of the keys of the right side into the scan of the left side. The right
+    /// side is executed first, so the inputs don't run in parallel.
+    pub(crate) fn with_runtime_filter(mut self, runtime_filter: Option<RuntimeFilter>) -> Self {
+        self.runtime_filter = runtime_filter;
+        self
+    }
 }
 
 impl Executor for JoinExec {
@@ -47,7 +57,19 @@ impl Executor for JoinExec {
         let mut input_left = self.input_left.take().unwrap();
         let mut input_right = self.input_right.take().unwrap();
 
-        let (df_left, df_right) = if self.parallel {
+        let (df_left, df_right) = if let Some(runtime_filter) = &self.runtime_filter {
+            let df_right = input_right.execute(state)?;
+            let right_on_series = self
+                .right_on
+                .iter()
+                .map(|e| e.evaluate(&df_right, state))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            runtime_filter.set(&right_on_series)?;
+            if state.verbose() {
+                eprintln!("join: pushed a runtime filter of the right keys into the left scan");
+            }
+            (input_left.execute(state), Ok(df_right))
+        } else if self.parallel {
             let mut state_right = state.split();
             let mut state_left = state.split();
             state_right.branch_idx += 1;
@@ -1,4 +1,5 @@
 mod cache;
+mod channel_sink;
 mod executor;
 mod ext_context;
 mod filter;
@@ -13,6 +14,7 @@ mod projection_simple;
 mod projection_utils;
 #[cfg(feature = "python")]
 mod python_scan;
+mod runtime_filter;
 mod scan;
 mod slice;
 mod sort;
@@ -31,6 +33,7 @@ use projection_utils::*;
 use rayon::prelude::*;
 
 pub(super) use self::cache::*;
+pub(super) use self::channel_sink::*;
 pub(super) use self::ext_context::*;
 pub(super) use self::filter::*;
 pub(super) use self::group_by::*;
@@ -45,6 +48,7 @@ pub(super) use self::projection::*;
 pub(super) use self::projection_simple::*;
 #[cfg(feature = "python")]
 pub(super) use self::python_scan::*;
+pub(super) use self::runtime_filter::RuntimeFilter;
 pub(super) use self::scan::*;
 pub(super) use self::slice::*;
 pub(super) use self::sort::*;
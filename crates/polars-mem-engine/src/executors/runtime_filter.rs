@@ -0,0 +1,90 @@
+//! Sideways information passing from the build side of a join to the scan of its probe side.
+use std::sync::Mutex;
+
+use polars_expr::{create_physical_expr, ExpressionConversionState};
+use polars_io::predicates::use_min_max;
+
+use super::*;
+
+struct RuntimeFilterInner {
+    /// The position of every key in the join keys, with its column in the scan.
+    keys: Vec<(usize, Field)>,
+    /// The predicate that was pushed down into the scan at planning time.
+    predicate: Option<Expr>,
+    schema: Option<SchemaRef>,
+    expr_depth: u16,
+    filter: Mutex<Option<Arc<dyn PhysicalExpr>>>,
+}
+
+/// A predicate on the join keys of the probe side of a join, that is only known once the build
+/// side is executed.
+///
+/// The join sets the filter to the range of the keys of its build side, the scan of the probe
+/// side uses it instead of its own predicate to skip the row groups and rows that can't match.
+#[derive(Clone)]
+pub(crate) struct RuntimeFilter(Arc<RuntimeFilterInner>);
+
+#[cfg_attr(not(feature = "parquet"), allow(dead_code))]
+impl RuntimeFilter {
+    pub(crate) fn new(
+        keys: Vec<(usize, Field)>,
+        predicate: Option<Expr>,
+        schema: Option<SchemaRef>,
+        expr_depth: u16,
+    ) -> Self {
+        Self(Arc::new(RuntimeFilterInner {
+            keys,
+            predicate,
+            schema,
+            expr_depth,
+            filter: Mutex::new(None),
+        }))
+    }
+
+    /// Set the filter to the minimum and maximum of the join keys of the build side.
+    pub(crate) fn set(&self, build_keys: &[Series]) -> PolarsResult<()> {
+        let mut filter: Option<Expr> = None;
+        for (idx, field) in &self.0.keys {
+            let s = &build_keys[*idx];
+            // A key without values can't bound the probe side.
+            if s.dtype() != field.data_type()
+                || !use_min_max(s.dtype())
+                || s.null_count() == s.len()
+            {
+                continue;
+            }
+            let name = field.name();
+            let min = s.min_reduce()?.into_series(name);
+            let max = s.max_reduce()?.into_series(name);
+            let range = col(name).gt_eq(lit(min)).and(col(name).lt_eq(lit(max)));
+            filter = Some(match filter {
+                None => range,
+                Some(filter) => filter.and(range),
+            });
+        }
+        let Some(filter) = filter else {
+            return Ok(());
+        };
+        let filter = match &self.0.predicate {
+            None => filter,
+            Some(predicate) => predicate.clone().and(filter),
+        };
+
+        let mut expr_arena = Arena::with_capacity(16);
+        let filter = to_expr_ir(filter, &mut expr_arena);
+        let filter = create_physical_expr(
+            &filter,
+            Context::Default,
+            &expr_arena,
+            self.0.schema.as_ref(),
+            &mut ExpressionConversionState::new(true, self.0.expr_depth),
+        )?;
+        *self.0.filter.lock().unwrap() = Some(filter);
+        Ok(())
+    }
+
+    /// The predicate of the scan including the runtime filter, if the filter was set.
+    pub(crate) fn predicate(&self) -> Option<Arc<dyn PhysicalExpr>> {
+        self.0.filter.lock().unwrap().clone()
+    }
+}
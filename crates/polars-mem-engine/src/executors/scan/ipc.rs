@@ -77,6 +77,15 @@ impl IpcExec {
 
         let row_limit = n_rows.unwrap_or(IdxSize::MAX);
 
+        // Without a row limit or row index, the rows of a file don't depend on the other files,
+        // so the files can be filtered while they are read. This also lets the reader skip
+        // record batches based on their statistics.
+        let predicate_per_file = (self.file_options.n_rows.is_none()
+            && self.file_options.row_index.is_none())
+        .then(|| self.predicate.clone().map(phys_expr_to_io_expr))
+        .flatten();
+        let verbose = config::verbose();
+
         // Used to determine the next file to open. This guarantees the order.
         let path_index = AtomicUsize::new(0);
         let row_counter = RwLock::new(ConsecutiveCountState::new(self.paths.len()));
@@ -100,7 +109,7 @@ impl IpcExec {
                     None
                 };
 
-                let df = IpcReader::new(file)
+                let reader = IpcReader::new(file)
                     .with_n_rows(
                         // NOTE: If there is any file that by itself exceeds the
                         // row limit, passing the total row limit to each
@@ -114,7 +123,13 @@ impl IpcExec {
                     .with_row_index(self.file_options.row_index.clone())
                     .with_projection(projection.clone())
                     .memory_mapped(memory_mapped)
-                    .finish()?;
+                    .with_release_resources(self.options.release_resources);
+                let df = match &predicate_per_file {
+                    Some(predicate) => {
+                        reader.finish_with_scan_ops(Some(predicate.clone()), verbose)?
+                    },
+                    None => reader.finish()?,
+                };
 
                 row_counter
                     .write()
@@ -130,7 +145,9 @@ impl IpcExec {
             row_counter.into_inner().unwrap(),
             self.file_options.row_index.as_ref(),
             row_limit,
-            self.predicate.as_ref(),
+            self.predicate
+                .as_ref()
+                .filter(|_| predicate_per_file.is_none()),
         )
     }
 
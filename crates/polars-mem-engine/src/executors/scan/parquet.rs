@@ -23,6 +23,8 @@ pub struct ParquetExec {
     file_options: FileScanOptions,
     #[allow(dead_code)]
     metadata: Option<FileMetaDataRef>,
+    /// Replaces the predicate once it is set by the join of which this scan is the probe side.
+    runtime_filter: Option<RuntimeFilter>,
 }
 
 impl ParquetExec {
@@ -46,9 +48,15 @@ impl ParquetExec {
             cloud_options,
             file_options,
             metadata,
+            runtime_filter: None,
         }
     }
 
+    pub(crate) fn with_runtime_filter(mut self, runtime_filter: Option<RuntimeFilter>) -> Self {
+        self.runtime_filter = runtime_filter;
+        self
+    }
+
     fn read_par(&mut self) -> PolarsResult<Vec<DataFrame>> {
         let parallel = match self.options.parallel {
             ParallelStrategy::Auto if self.paths.len() > POOL.current_num_threads() => {
@@ -316,6 +324,10 @@ impl ParquetExec {
     }
 
     fn read(&mut self) -> PolarsResult<DataFrame> {
+        if let Some(predicate) = self.runtime_filter.as_ref().and_then(|f| f.predicate()) {
+            self.predicate = Some(predicate);
+        }
+
         // FIXME: The row index implementation is incorrect when a predicate is
         // applied. This code mitigates that by applying the predicate after the
         // collection of the entire dataframe if a row index is requested. This is
@@ -355,6 +367,9 @@ impl ParquetExec {
         if self.file_options.rechunk {
             out.as_single_chunk_par();
         }
+        if self.options.deduplicate_strings {
+            out.deduplicate_strings();
+        }
         Ok(out)
     }
 }
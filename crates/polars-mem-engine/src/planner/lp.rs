@@ -128,16 +128,83 @@ fn partitionable_gb(
 
 struct ConversionState {
     expr_depth: u16,
+    /// The runtime filters of the parquet scans on the probe side of a join, by their nodes.
+    #[cfg(feature = "parquet")]
+    runtime_filters: std::cell::RefCell<PlHashMap<Node, executors::RuntimeFilter>>,
 }
 
 impl ConversionState {
     fn new() -> PolarsResult<Self> {
         Ok(ConversionState {
             expr_depth: get_expr_depth_limit()?,
+            #[cfg(feature = "parquet")]
+            runtime_filters: Default::default(),
         })
     }
 }
 
+/// Find the parquet scan on the left side of an inner join of which the rows can be filtered by
+/// the range of the keys of the right side, with the filter of the scan.
+///
+/// Only plain key columns of a scan that is reached through filters and simple projections are
+/// filtered, the right side is the build side.
+#[cfg(feature = "parquet")]
+fn runtime_filter_scan(
+    input_left: Node,
+    left_on: &[ExprIR],
+    args: &JoinArgs,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    expr_depth: u16,
+) -> Option<(Node, executors::RuntimeFilter)> {
+    if !matches!(args.how, JoinType::Inner)
+        || args.join_nulls
+        || std::env::var("POLARS_NO_RUNTIME_FILTER").is_ok()
+    {
+        return None;
+    }
+    let mut node = input_left;
+    loop {
+        match lp_arena.get(node) {
+            IR::SimpleProjection { input, .. } | IR::Filter { input, .. } => node = *input,
+            IR::Scan {
+                file_info,
+                output_schema,
+                scan_type: FileScan::Parquet { .. },
+                predicate,
+                file_options,
+                ..
+            } if file_options.n_rows.is_none() && file_options.row_index.is_none() => {
+                let keys = left_on
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, e)| match expr_arena.get(e.node()) {
+                        AExpr::Column(name) => {
+                            let field = file_info.schema.get_field(name)?;
+                            Some((i, field))
+                        },
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                if keys.is_empty() {
+                    return None;
+                }
+                let predicate = predicate
+                    .as_ref()
+                    .map(|predicate| node_to_expr(predicate.node(), expr_arena));
+                let filter = executors::RuntimeFilter::new(
+                    keys,
+                    predicate,
+                    output_schema.clone(),
+                    expr_depth,
+                );
+                return Some((node, filter));
+            },
+            _ => return None,
+        }
+    }
+}
+
 pub fn create_physical_plan(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -159,10 +226,23 @@ fn create_physical_plan_impl(
     match logical_plan {
         #[cfg(feature = "python")]
         PythonScan { options, .. } => Ok(Box::new(executors::PythonScanExec { options })),
-        Sink { payload, .. } => match payload {
+        Sink { input, payload } => match payload {
             SinkType::Memory => {
                 polars_bail!(InvalidOperation: "memory sink not supported in the standard engine")
             },
+            // The standard engine produces the rows in order, so this is a regular slice.
+            SinkType::LimitEarly { len } => {
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
+                Ok(Box::new(executors::SliceExec {
+                    input,
+                    offset: 0,
+                    len: len as IdxSize,
+                }))
+            },
+            SinkType::Channel(sender) => {
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
+                Ok(Box::new(executors::ChannelSinkExec { input, sender }))
+            },
             SinkType::File { file_type, .. } => {
                 polars_bail!(InvalidOperation:
                     "sink_{file_type:?} not yet supported in standard engine. Use 'collect().write_parquet()'"
@@ -172,6 +252,10 @@ fn create_physical_plan_impl(
             SinkType::Cloud { .. } => {
                 polars_bail!(InvalidOperation: "cloud sink not supported in standard engine.")
             },
+            #[cfg(feature = "parquet")]
+            SinkType::Checkpointed { .. } => {
+                polars_bail!(InvalidOperation: "checkpointed sink not supported in standard engine.")
+            },
         },
         Union { inputs, options } => {
             let inputs = inputs
@@ -239,6 +323,8 @@ fn create_physical_plan_impl(
             predicate,
             mut file_options,
         } => {
+            #[cfg(feature = "parquet")]
+            let runtime_filter = state.runtime_filters.borrow_mut().remove(&root);
             file_options.n_rows = _set_n_rows_for_scan(file_options.n_rows);
             let mut state = ExpressionConversionState::new(true, state.expr_depth);
             let predicate = predicate
@@ -281,16 +367,20 @@ fn create_physical_plan_impl(
                     options,
                     cloud_options,
                     metadata,
-                } => Ok(Box::new(executors::ParquetExec::new(
-                    paths,
-                    file_info,
-                    hive_parts,
-                    predicate,
-                    options,
-                    cloud_options,
-                    file_options,
-                    metadata,
-                ))),
+                    ..
+                } => Ok(Box::new(
+                    executors::ParquetExec::new(
+                        paths,
+                        file_info,
+                        hive_parts,
+                        predicate,
+                        options,
+                        cloud_options,
+                        file_options,
+                        metadata,
+                    )
+                    .with_runtime_filter(runtime_filter),
+                )),
                 #[cfg(feature = "json")]
                 FileScan::NDJson { options } => Ok(Box::new(executors::JsonExec::new(
                     paths,
@@ -321,7 +411,7 @@ fn create_physical_plan_impl(
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
             let mut state = ExpressionConversionState::new(
-                POOL.current_num_threads() > expr.len(),
+                options.run_parallel && POOL.current_num_threads() > expr.len(),
                 state.expr_depth,
             );
 
@@ -529,7 +619,29 @@ fn create_physical_plan_impl(
             options,
             ..
         } => {
-            let parallel = if options.force_parallel {
+            #[cfg(feature = "parquet")]
+            let runtime_filter = runtime_filter_scan(
+                input_left,
+                &left_on,
+                &options.args,
+                lp_arena,
+                expr_arena,
+                state.expr_depth,
+            )
+            .map(|(scan, filter)| {
+                state
+                    .runtime_filters
+                    .borrow_mut()
+                    .insert(scan, filter.clone());
+                filter
+            });
+            #[cfg(not(feature = "parquet"))]
+            let runtime_filter = None;
+
+            let parallel = if runtime_filter.is_some() {
+                // The right side is executed first to set the filter of the left side.
+                false
+            } else if options.force_parallel {
                 true
             } else if options.allow_parallel {
                 // check if two DataFrames come from a separate source.
@@ -561,14 +673,17 @@ fn create_physical_plan_impl(
                 &mut ExpressionConversionState::new(true, state.expr_depth),
             )?;
             let options = Arc::try_unwrap(options).unwrap_or_else(|options| (*options).clone());
-            Ok(Box::new(executors::JoinExec::new(
-                input_left,
-                input_right,
-                left_on,
-                right_on,
-                parallel,
-                options.args,
-            )))
+            Ok(Box::new(
+                executors::JoinExec::new(
+                    input_left,
+                    input_right,
+                    left_on,
+                    right_on,
+                    parallel,
+                    options.args,
+                )
+                .with_runtime_filter(runtime_filter),
+            ))
         },
         HStack {
             input,
@@ -582,7 +697,7 @@ fn create_physical_plan_impl(
             let streamable = all_streamable(&exprs, expr_arena, Context::Default);
 
             let mut state = ExpressionConversionState::new(
-                POOL.current_num_threads() > exprs.len(),
+                options.run_parallel && POOL.current_num_threads() > exprs.len(),
                 state.expr_depth,
             );
 
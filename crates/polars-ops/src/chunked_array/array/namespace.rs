@@ -3,7 +3,7 @@ use super::*;
 #[cfg(feature = "array_count")]
 use crate::chunked_array::array::count::array_count_matches;
 use crate::chunked_array::array::count::count_boolean_bits;
-use crate::chunked_array::array::sum_mean::sum_with_nulls;
+use crate::chunked_array::array::sum_mean::{mean_with_nulls, sum_with_nulls};
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
@@ -51,6 +51,26 @@ pub trait ArrayNameSpace: AsArray {
         }
     }
 
+    fn array_mean(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+
+        if has_inner_nulls(ca) {
+            return mean_with_nulls(ca);
+        };
+
+        match ca.inner_dtype() {
+            dt if dt.is_numeric() => {
+                let out_dtype = match dt {
+                    DataType::Float32 => DataType::Float32,
+                    _ => DataType::Float64,
+                };
+                let sum = sum_array_numerical(ca, dt).cast(&out_dtype)?;
+                Ok(&sum / ca.width() as f64)
+            },
+            _ => mean_with_nulls(ca),
+        }
+    }
+
     fn array_median(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::median_with_nulls(ca)
@@ -118,3 +118,20 @@ pub(super) fn sum_with_nulls(ca: &ArrayChunked, inner_dtype: &DataType) -> Polar
     out.rename(ca.name());
     Ok(out)
 }
+
+pub(super) fn mean_with_nulls(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let mut out = match ca.inner_dtype() {
+        DataType::Float32 => {
+            let out: Float32Chunked =
+                ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().mean().map(|v| v as f32)));
+            out.into_series()
+        },
+        _ => {
+            let out: Float64Chunked =
+                ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().mean()));
+            out.into_series()
+        },
+    };
+    out.rename(ca.name());
+    Ok(out)
+}
@@ -0,0 +1,229 @@
+//! Authenticated encryption of the values of binary columns.
+//!
+//! Every value is encrypted with its own random nonce, which is stored in front of the
+//! ciphertext: an encrypted value is `nonce (12 bytes) | ciphertext | tag (16 bytes)`. The
+//! associated data is authenticated but not encrypted, so binding a value to e.g. the key of its
+//! row prevents that it is moved to another row unnoticed.
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::repeat;
+
+use polars_core::prelude::*;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The AEAD algorithm of [`encrypt_aead`](super::BinaryNameSpaceImpl::encrypt_aead).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AeadAlgorithm {
+    /// AES-256 in Galois/Counter mode, needs a key of 32 bytes.
+    #[default]
+    Aes256Gcm,
+    /// ChaCha20-Poly1305, needs a key of 32 bytes.
+    ChaCha20Poly1305,
+}
+
+impl Display for AeadAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            AeadAlgorithm::Aes256Gcm => "AES-256-GCM",
+            AeadAlgorithm::ChaCha20Poly1305 => "ChaCha20-Poly1305",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl AeadAlgorithm {
+    fn key(self, key: &AeadKey) -> PolarsResult<LessSafeKey> {
+        let algorithm = match self {
+            AeadAlgorithm::Aes256Gcm => &ring::aead::AES_256_GCM,
+            AeadAlgorithm::ChaCha20Poly1305 => &ring::aead::CHACHA20_POLY1305,
+        };
+        let key = UnboundKey::new(algorithm, key.as_bytes()).map_err(|_| {
+            polars_err!(
+                InvalidOperation: "{} needs a key of {} bytes, got {} bytes",
+                self, algorithm.key_len(), key.as_bytes().len()
+            )
+        })?;
+        Ok(LessSafeKey::new(key))
+    }
+}
+
+/// The secret key of an AEAD algorithm.
+///
+/// The key is redacted from the debug output and refuses to be serialized, so it doesn't end up
+/// in printed or serialized query plans.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AeadKey(Arc<[u8]>);
+
+impl AeadKey {
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self(key.into().into())
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Debug for AeadKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AeadKey(<redacted>)")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for AeadKey {
+    fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom(
+            "cannot serialize an AEAD key, pass it to the deserialized plan instead",
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for AeadKey {
+    fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+        Err(serde::de::Error::custom("cannot deserialize an AEAD key"))
+    }
+}
+
+/// The associated data of every row: empty without `aad`, broadcast if `aad` has a single value.
+/// A null is authenticated as empty associated data.
+fn aad_iter<'a>(
+    aad: Option<&'a BinaryChunked>,
+    len: usize,
+) -> PolarsResult<Box<dyn Iterator<Item = &'a [u8]> + 'a>> {
+    Ok(match aad {
+        None => Box::new(repeat(&[][..])),
+        Some(aad) if aad.len() == 1 => Box::new(repeat(aad.get(0).unwrap_or_default())),
+        Some(aad) => {
+            polars_ensure!(
+                aad.len() == len,
+                ShapeMismatch: "the associated data has length {}, the values have length {}",
+                aad.len(), len
+            );
+            Box::new(aad.into_iter().map(|aad| aad.unwrap_or_default()))
+        },
+    })
+}
+
+fn seal(key: &LessSafeKey, rng: &SystemRandom, aad: &[u8], value: &[u8]) -> PolarsResult<Vec<u8>> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut nonce)
+        .map_err(|_| polars_err!(ComputeError: "could not generate a nonce"))?;
+    let tag_len = key.algorithm().tag_len();
+    let mut out = Vec::with_capacity(NONCE_LEN + value.len() + tag_len);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(value);
+    let tag = key
+        .seal_in_place_separate_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(aad),
+            &mut out[NONCE_LEN..],
+        )
+        .map_err(|_| polars_err!(ComputeError: "could not encrypt a value"))?;
+    out.extend_from_slice(tag.as_ref());
+    Ok(out)
+}
+
+fn open(key: &LessSafeKey, aad: &[u8], value: &[u8]) -> Option<Vec<u8>> {
+    if value.len() < NONCE_LEN + key.algorithm().tag_len() {
+        return None;
+    }
+    let (nonce, ciphertext) = value.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).ok()?;
+    let mut out = ciphertext.to_vec();
+    let len = key
+        .open_in_place(nonce, Aad::from(aad), &mut out)
+        .ok()?
+        .len();
+    out.truncate(len);
+    Some(out)
+}
+
+pub(super) fn encrypt_aead(
+    ca: &BinaryChunked,
+    key: &AeadKey,
+    algorithm: AeadAlgorithm,
+    aad: Option<&BinaryChunked>,
+) -> PolarsResult<BinaryChunked> {
+    let key = algorithm.key(key)?;
+    let rng = SystemRandom::new();
+    let out: BinaryChunked = ca
+        .into_iter()
+        .zip(aad_iter(aad, ca.len())?)
+        .map(|(value, aad)| value.map(|value| seal(&key, &rng, aad, value)).transpose())
+        .collect::<PolarsResult<_>>()?;
+    Ok(out.with_name(ca.name()))
+}
+
+pub(super) fn decrypt_aead(
+    ca: &BinaryChunked,
+    key: &AeadKey,
+    algorithm: AeadAlgorithm,
+    aad: Option<&BinaryChunked>,
+    strict: bool,
+) -> PolarsResult<BinaryChunked> {
+    let key = algorithm.key(key)?;
+    let out: BinaryChunked = ca
+        .into_iter()
+        .zip(aad_iter(aad, ca.len())?)
+        .map(|(value, aad)| match value {
+            None => Ok(None),
+            Some(value) => match open(&key, aad, value) {
+                Some(value) => Ok(Some(value)),
+                None if strict => polars_bail!(
+                    ComputeError:
+                    "could not decrypt a value, the key, the associated data or the value is wrong; \
+                    try setting `strict=false` to ignore"
+                ),
+                None => Ok(None),
+            },
+        })
+        .collect::<PolarsResult<_>>()?;
+    Ok(out.with_name(ca.name()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_aead_round_trip() -> PolarsResult<()> {
+        let key = AeadKey::new([7u8; 32]);
+        let values = BinaryChunked::from_slice_options(
+            "secret",
+            &[Some(b"alice".as_slice()), None, Some(b"".as_slice())],
+        );
+        let aad = BinaryChunked::from_slice("id", &[b"1".as_slice(), b"2", b"3"]);
+
+        for algorithm in [AeadAlgorithm::Aes256Gcm, AeadAlgorithm::ChaCha20Poly1305] {
+            let encrypted = encrypt_aead(&values, &key, algorithm, Some(&aad))?;
+            assert_eq!(encrypted.null_count(), 1);
+            assert_eq!(encrypted.get(2).unwrap().len(), NONCE_LEN + 16);
+            let decrypted = decrypt_aead(&encrypted, &key, algorithm, Some(&aad), true)?;
+            assert!(decrypted
+                .into_series()
+                .equals_missing(&values.clone().into_series()));
+
+            // The nonce differs per value, so equal values encrypt differently.
+            let again = encrypt_aead(&values, &key, algorithm, Some(&aad))?;
+            assert_ne!(again.get(0), encrypted.get(0));
+
+            // A value moved to another row doesn't authenticate.
+            let wrong_aad = BinaryChunked::from_slice("id", &[b"2".as_slice()]);
+            assert!(decrypt_aead(&encrypted, &key, algorithm, Some(&wrong_aad), true).is_err());
+            let out = decrypt_aead(&encrypted, &key, algorithm, Some(&wrong_aad), false)?;
+            assert_eq!(out.null_count(), 3);
+
+            let other_key = AeadKey::new([8u8; 32]);
+            assert!(decrypt_aead(&encrypted, &other_key, algorithm, Some(&aad), true).is_err());
+        }
+
+        assert!(encrypt_aead(&values, &AeadKey::new([0u8; 16]), Default::default(), None).is_err());
+        assert_eq!(format!("{key:?}"), "AeadKey(<redacted>)");
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
+#[cfg(feature = "binary_encryption")]
+mod aead;
 mod namespace;
 
+#[cfg(feature = "binary_encryption")]
+pub use aead::{AeadAlgorithm, AeadKey};
 pub use namespace::*;
 use polars_core::prelude::*;
 
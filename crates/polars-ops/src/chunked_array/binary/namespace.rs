@@ -124,6 +124,33 @@ pub trait BinaryNameSpaceImpl: AsBinary {
                 .unwrap()
         }
     }
+
+    /// Encrypt the values with an AEAD algorithm, with a random nonce per value that is stored in
+    /// front of the ciphertext. `aad` is the associated data of every value, or of all values if
+    /// it has a single value.
+    #[cfg(feature = "binary_encryption")]
+    fn encrypt_aead(
+        &self,
+        key: &AeadKey,
+        algorithm: AeadAlgorithm,
+        aad: Option<&BinaryChunked>,
+    ) -> PolarsResult<BinaryChunked> {
+        super::aead::encrypt_aead(self.as_binary(), key, algorithm, aad)
+    }
+
+    /// Decrypt values encrypted by [`encrypt_aead`](Self::encrypt_aead) with the same key,
+    /// algorithm and associated data. Values that don't authenticate raise an error if `strict`,
+    /// and are null otherwise.
+    #[cfg(feature = "binary_encryption")]
+    fn decrypt_aead(
+        &self,
+        key: &AeadKey,
+        algorithm: AeadAlgorithm,
+        aad: Option<&BinaryChunked>,
+        strict: bool,
+    ) -> PolarsResult<BinaryChunked> {
+        super::aead::decrypt_aead(self.as_binary(), key, algorithm, aad, strict)
+    }
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
@@ -5,6 +5,74 @@ use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::float::IsFloat;
 use polars_utils::total_ord::ToTotalOrd;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How the bins of a histogram are chosen if no explicit `bins` are given.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HistBinStrategy {
+    /// `bin_count` bins of equal width, or bins of unit width if `bin_count` isn't given.
+    #[default]
+    Uniform,
+    /// Bins of width `2 * IQR / n^(1/3)`, starting at the minimum.
+    FreedmanDiaconis,
+    /// `bin_count` bins of equal width in log space spanning the positive values, or one bin per
+    /// decade if `bin_count` isn't given. Values that aren't positive fall in the `(-inf, 0]` bin.
+    Log,
+}
+
+/// The upper edges of the bins chosen by the Freedman–Diaconis rule.
+fn freedman_diaconis_breaks(ca: &Float64Chunked) -> PolarsResult<Vec<f64>> {
+    let n = ca.len() - ca.null_count();
+    let (Some(min), Some(max)) = (ChunkAgg::min(ca), ChunkAgg::max(ca)) else {
+        return Ok(vec![]);
+    };
+    let q1 = ca.quantile(0.25, QuantileInterpolOptions::Linear)?.unwrap();
+    let q3 = ca.quantile(0.75, QuantileInterpolOptions::Linear)?.unwrap();
+    let width = 2.0 * (q3 - q1) / (n as f64).cbrt();
+    let bin_count = if width > 0.0 {
+        ((max - min) / width).ceil().max(1.0) as usize
+    } else {
+        1
+    };
+    Ok(equal_width_breaks(min, max, bin_count))
+}
+
+/// The upper edges of `bin_count` bins of equal width in log10 space.
+fn log_breaks(ca: &Float64Chunked, bin_count: Option<usize>) -> PolarsResult<Vec<f64>> {
+    polars_ensure!(
+        bin_count != Some(0),
+        InvalidOperation: "'bin_count' must be positive for log bins"
+    );
+    let positive = ca.filter(&ca.gt(0.0))?;
+    let (Some(min), Some(max)) = (ChunkAgg::min(&positive), ChunkAgg::max(&positive)) else {
+        return Ok(vec![0.0]);
+    };
+    let (lower, upper) = (min.log10(), max.log10());
+    let bin_count = bin_count.unwrap_or_else(|| ((upper - lower).ceil() as usize).max(1));
+
+    // The values that aren't positive are counted in the first bin, so it ends at zero.
+    let mut breaks = vec![0.0];
+    breaks.extend(
+        equal_width_breaks(lower, upper, bin_count)
+            .into_iter()
+            .map(|edge| 10f64.powf(edge)),
+    );
+    // Rounding in `powf` must not push the maximum out of the last bin.
+    *breaks.last_mut().unwrap() = max;
+    Ok(breaks)
+}
+
+/// The upper edges of `bin_count` bins of equal width, where the first bin includes `min`.
+fn equal_width_breaks(min: f64, max: f64, bin_count: usize) -> Vec<f64> {
+    let width = (max - min) / bin_count as f64;
+    let mut breaks = (1..=bin_count)
+        .map(|i| min + i as f64 * width)
+        .collect::<Vec<_>>();
+    *breaks.last_mut().unwrap() = max;
+    breaks
+}
 
 fn compute_hist<T>(
     ca: &ChunkedArray<T>,
@@ -155,6 +223,7 @@ pub fn hist_series(
     s: &Series,
     bin_count: Option<usize>,
     bins: Option<Series>,
+    strategy: HistBinStrategy,
     include_category: bool,
     include_breakpoint: bool,
 ) -> PolarsResult<Series> {
@@ -163,6 +232,10 @@ pub fn hist_series(
     let owned_bins;
     if let Some(bins) = bins {
         polars_ensure!(bins.null_count() == 0, InvalidOperation: "nulls not supported in 'bins' argument");
+        polars_ensure!(
+            strategy == HistBinStrategy::Uniform,
+            InvalidOperation: "'bins' can't be combined with the {:?} bin strategy", strategy
+        );
         let bins = bins.cast(&DataType::Float64)?;
         let bins_s = bins.rechunk();
         owned_bins = bins_s;
@@ -172,6 +245,17 @@ pub fn hist_series(
     };
     polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "'hist' is only supported for numeric data");
 
+    let computed_bins;
+    if strategy != HistBinStrategy::Uniform {
+        let values = s.cast(&DataType::Float64)?;
+        let values = values.f64().unwrap();
+        computed_bins = match strategy {
+            HistBinStrategy::FreedmanDiaconis => freedman_diaconis_breaks(values)?,
+            _ => log_breaks(values, bin_count)?,
+        };
+        bins_arg = Some(computed_bins.as_slice());
+    }
+
     let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
          let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
          compute_hist(ca, bin_count, bins_arg, include_category, include_breakpoint)
@@ -0,0 +1,232 @@
+use std::fmt::{Display, Formatter};
+
+use arrow::array::{Array, ListArray};
+use arrow::compute::utils::combine_validities_and;
+use arrow::offset::OffsetsBuffer;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::series::{max_horizontal, min_horizontal};
+
+/// Binary operation applied to the elements at the same position of two lists.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ListBinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    TrueDiv,
+    FloorDiv,
+    Rem,
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    Min,
+    Max,
+}
+
+impl ListBinaryOp {
+    pub fn is_comparison(&self) -> bool {
+        use ListBinaryOp::*;
+        matches!(self, Eq | NotEq | Lt | LtEq | Gt | GtEq)
+    }
+}
+
+impl Display for ListBinaryOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use ListBinaryOp::*;
+        let s = match self {
+            Add => "add",
+            Sub => "sub",
+            Mul => "mul",
+            Div => "div",
+            TrueDiv => "truediv",
+            FloorDiv => "floordiv",
+            Rem => "mod",
+            Eq => "eq",
+            NotEq => "ne",
+            Lt => "lt",
+            LtEq => "le",
+            Gt => "gt",
+            GtEq => "ge",
+            Min => "min",
+            Max => "max",
+        };
+        write!(f, "{s}")
+    }
+}
+
+fn apply_flat(lhs: &Series, rhs: &Series, op: ListBinaryOp) -> PolarsResult<Series> {
+    use ListBinaryOp::*;
+    match op {
+        Add => lhs + rhs,
+        Sub => lhs - rhs,
+        Mul => lhs * rhs,
+        Div => lhs / rhs,
+        TrueDiv => match lhs.dtype() {
+            DataType::Float32 | DataType::Float64 => lhs / rhs,
+            _ => &lhs.cast(&DataType::Float64)? / &rhs.cast(&DataType::Float64)?,
+        },
+        FloorDiv => {
+            #[cfg(feature = "round_series")]
+            {
+                crate::series::floor_div_series(lhs, rhs)
+            }
+            #[cfg(not(feature = "round_series"))]
+            {
+                polars_bail!(opq = floor_div, lhs.dtype())
+            }
+        },
+        Rem => lhs % rhs,
+        Eq => ChunkCompare::equal(lhs, rhs).map(|ca| ca.into_series()),
+        NotEq => ChunkCompare::not_equal(lhs, rhs).map(|ca| ca.into_series()),
+        Lt => ChunkCompare::lt(lhs, rhs).map(|ca| ca.into_series()),
+        LtEq => ChunkCompare::lt_eq(lhs, rhs).map(|ca| ca.into_series()),
+        Gt => ChunkCompare::gt(lhs, rhs).map(|ca| ca.into_series()),
+        GtEq => ChunkCompare::gt_eq(lhs, rhs).map(|ca| ca.into_series()),
+        Min => min_horizontal(&[lhs.clone(), rhs.clone()]).map(|s| s.unwrap()),
+        Max => max_horizontal(&[lhs.clone(), rhs.clone()]).map(|s| s.unwrap()),
+    }
+}
+
+/// Apply `op` to the elements at the same position of the lists in `lhs` and `rhs`.
+///
+/// Lists in the same row must have equal lengths. A row is null if it is null in either
+/// input. If one of the inputs has a single row, it is broadcast to the other.
+pub fn list_elementwise(
+    lhs: &ListChunked,
+    rhs: &ListChunked,
+    op: ListBinaryOp,
+) -> PolarsResult<ListChunked> {
+    let len = match (lhs.len(), rhs.len()) {
+        (1, len) | (len, 1) => len,
+        (l, r) => {
+            polars_ensure!(l == r, ShapeMismatch: "cannot apply '{}' to list columns of lengths {} and {}", op, l, r);
+            l
+        },
+    };
+    let name = lhs.name();
+    if len == 0 {
+        let values = apply_flat(
+            &Series::new_empty("", &lhs.inner_dtype()),
+            &Series::new_empty("", &rhs.inner_dtype()),
+            op,
+        )?;
+        return Ok(ListChunked::full_null_with_dtype(name, 0, values.dtype()));
+    }
+
+    let broadcast = |ca: &ListChunked| {
+        if ca.len() == len {
+            ca.rechunk()
+        } else {
+            ca.new_from_index(0, len).rechunk()
+        }
+    };
+    let lhs = broadcast(lhs);
+    let rhs = broadcast(rhs);
+    let lhs_arr = lhs.downcast_iter().next().unwrap();
+    let rhs_arr = rhs.downcast_iter().next().unwrap();
+    let lhs_offsets = lhs_arr.offsets().as_slice();
+    let rhs_offsets = rhs_arr.offsets().as_slice();
+    let validity = combine_validities_and(lhs_arr.validity(), rhs_arr.validity());
+    let is_valid = |i: usize| validity.as_ref().map_or(true, |v| v.get_bit(i));
+
+    let (values, offsets) = if lhs_offsets == rhs_offsets {
+        // The elements are already aligned, so we can operate on the inner values directly.
+        let values = apply_flat(&lhs.get_inner(), &rhs.get_inner(), op)?;
+        (values, lhs_arr.offsets().clone())
+    } else {
+        let mut lhs_idx = Vec::with_capacity(lhs.get_inner().len());
+        let mut rhs_idx = Vec::with_capacity(lhs_idx.capacity());
+        let mut offsets = Vec::with_capacity(len + 1);
+        offsets.push(0i64);
+        let mut offset = 0i64;
+        for i in 0..len {
+            if is_valid(i) {
+                let (l_start, l_end) = (lhs_offsets[i], lhs_offsets[i + 1]);
+                let (r_start, r_end) = (rhs_offsets[i], rhs_offsets[i + 1]);
+                polars_ensure!(
+                    l_end - l_start == r_end - r_start,
+                    ShapeMismatch: "cannot apply '{}' to lists of different lengths in row {}: {} and {}",
+                    op, i, l_end - l_start, r_end - r_start
+                );
+                lhs_idx.extend(l_start as IdxSize..l_end as IdxSize);
+                rhs_idx.extend(r_start as IdxSize..r_end as IdxSize);
+                offset += l_end - l_start;
+            }
+            offsets.push(offset);
+        }
+        let lhs_values = lhs.get_inner().take_slice(&lhs_idx)?;
+        let rhs_values = rhs.get_inner().take_slice(&rhs_idx)?;
+        let values = apply_flat(&lhs_values, &rhs_values, op)?;
+        // SAFETY: the offsets are monotonically increasing.
+        let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+        (values, offsets)
+    };
+
+    let values = values.rechunk();
+    let values_arr = values.chunks()[0].clone();
+    let arrow_dtype = ListArray::<i64>::default_datatype(values_arr.data_type().clone());
+    let arr = ListArray::new(arrow_dtype, offsets, values_arr, validity);
+    let dtype = DataType::List(Box::new(values.dtype().clone()));
+    // SAFETY: the inner array is the physical representation of `values`.
+    Ok(unsafe { ListChunked::from_chunks_and_dtype(name, vec![arr.boxed()], dtype) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn list(values: &[Option<Vec<f64>>]) -> ListChunked {
+        let s = values
+            .iter()
+            .map(|v| v.as_ref().map(|v| Series::new("", v)))
+            .collect::<Vec<_>>();
+        Series::new("a", s).list().unwrap().clone()
+    }
+
+    #[test]
+    fn test_list_elementwise() -> PolarsResult<()> {
+        let a = list(&[Some(vec![1.0, 2.0]), None, Some(vec![3.0]), Some(vec![])]);
+        let b = list(&[
+            Some(vec![10.0, 1.0]),
+            Some(vec![1.0]),
+            Some(vec![4.0]),
+            Some(vec![]),
+        ]);
+
+        let out = list_elementwise(&a, &b, ListBinaryOp::Add)?;
+        let expected = list(&[Some(vec![11.0, 3.0]), None, Some(vec![7.0]), Some(vec![])]);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let out = list_elementwise(&a, &b, ListBinaryOp::Max)?;
+        let expected = list(&[Some(vec![10.0, 2.0]), None, Some(vec![4.0]), Some(vec![])]);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let out = list_elementwise(&a, &b, ListBinaryOp::Gt)?;
+        assert_eq!(out.inner_dtype(), DataType::Boolean);
+        assert_eq!(out.get_inner().bool()?.sum(), Some(1));
+
+        // Broadcast a single row.
+        let c = list(&[Some(vec![1.0, 1.0])]);
+        let out = list_elementwise(&a.slice(0, 1), &c, ListBinaryOp::Sub)?;
+        let expected = list(&[Some(vec![0.0, 1.0])]);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_elementwise_length_mismatch() {
+        let a = list(&[Some(vec![1.0, 2.0]), Some(vec![1.0])]);
+        let b = list(&[Some(vec![1.0]), Some(vec![1.0, 2.0])]);
+        assert!(list_elementwise(&a, &b, ListBinaryOp::Add).is_err());
+
+        let c = list(&[Some(vec![1.0]), Some(vec![1.0]), Some(vec![1.0])]);
+        assert!(list_elementwise(&a, &c, ListBinaryOp::Add).is_err());
+    }
+}
@@ -4,6 +4,8 @@ use polars_core::prelude::*;
 mod any_all;
 mod count;
 mod dispersion;
+#[cfg(feature = "list_elementwise")]
+mod elementwise;
 #[cfg(feature = "hash")]
 pub(crate) mod hash;
 mod min_max;
@@ -18,6 +20,8 @@ mod to_struct;
 pub use count::*;
 #[cfg(not(feature = "list_count"))]
 use count::*;
+#[cfg(feature = "list_elementwise")]
+pub use elementwise::*;
 pub use namespace::*;
 #[cfg(feature = "list_sets")]
 pub use sets::*;
@@ -81,11 +81,35 @@ pub fn mode(s: &Series) -> PolarsResult<Series> {
     unsafe { out.cast_unchecked(s.dtype()) }
 }
 
+/// The `k` most frequent values of `s`, ignoring nulls, from the most to the least frequent.
+/// Values that are equally frequent are in the order of their first occurrence.
+pub fn mode_k(s: &Series, k: usize) -> PolarsResult<Series> {
+    let s = s.drop_nulls();
+    if s.is_empty() || k == 0 {
+        return Ok(s.clear());
+    }
+    let s_phys = s.to_physical_repr();
+    let groups = s_phys.group_tuples(false, false)?;
+    let mut counts = groups
+        .iter()
+        .map(|g| (g.first(), g.len()))
+        .collect::<Vec<_>>();
+    counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let idx = counts
+        .into_iter()
+        .take(k)
+        .map(|(first, _)| first)
+        .collect::<Vec<_>>();
+
+    // SAFETY: the first indices of the groups are in bounds.
+    Ok(unsafe { s.take_unchecked_from_slice(&idx) })
+}
+
 #[cfg(test)]
 mod test {
     use polars_core::prelude::*;
 
-    use super::{mode, mode_primitive};
+    use super::{mode, mode_k, mode_primitive};
 
     #[test]
     fn mode_test() {
@@ -124,4 +148,32 @@ mod test {
         assert_eq!(result.str_value(0).unwrap(), "test2");
         assert_eq!(result.len(), 1);
     }
+
+    #[test]
+    fn mode_k_test() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[
+                Some(3),
+                Some(1),
+                None,
+                Some(1),
+                Some(2),
+                Some(3),
+                None,
+                Some(2),
+                Some(1),
+            ],
+        );
+        let result = mode_k(&s, 2)?;
+        assert_eq!(Vec::from(result.i32()?), &[Some(1), Some(3)]);
+        let result = mode_k(&s, 5)?;
+        assert_eq!(Vec::from(result.i32()?), &[Some(1), Some(3), Some(2)]);
+        assert!(mode_k(&s, 0)?.is_empty());
+
+        let s = Series::new("a", &["b", "a", "a", "b", "c"]);
+        let result = mode_k(&s, 1)?;
+        assert_eq!(Vec::from(result.str()?), &[Some("b")]);
+        Ok(())
+    }
 }
@@ -14,12 +14,16 @@ mod namespace;
 mod pad;
 #[cfg(feature = "string_reverse")]
 mod reverse;
+#[cfg(feature = "string_similarity")]
+mod similarity;
 #[cfg(feature = "strings")]
 mod split;
 #[cfg(feature = "strings")]
 mod strip;
 #[cfg(feature = "strings")]
 mod substring;
+#[cfg(feature = "string_tokenize")]
+mod tokenize;
 
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
@@ -33,10 +37,14 @@ pub use json_path::*;
 #[cfg(feature = "strings")]
 pub use namespace::*;
 use polars_core::prelude::*;
+#[cfg(feature = "string_similarity")]
+pub use similarity::*;
 #[cfg(feature = "strings")]
 pub use split::*;
 #[cfg(feature = "strings")]
 pub use strip::*;
+#[cfg(feature = "string_tokenize")]
+pub use tokenize::*;
 
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
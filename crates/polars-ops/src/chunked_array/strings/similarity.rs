@@ -0,0 +1,215 @@
+//! Edit distances and similarities between the strings of two columns.
+//!
+//! All functions work on `char`s and broadcast if one of the sides has length 1. A null on
+//! either side gives a null.
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+
+use super::*;
+
+/// Reusable buffers, so that we don't allocate for every pair of strings.
+#[derive(Default)]
+struct Scratch {
+    a: Vec<char>,
+    b: Vec<char>,
+    row: Vec<usize>,
+    a_matched: Vec<bool>,
+    b_matched: Vec<bool>,
+}
+
+impl Scratch {
+    fn load(&mut self, a: &str, b: &str) -> (&[char], &[char]) {
+        self.a.clear();
+        self.a.extend(a.chars());
+        self.b.clear();
+        self.b.extend(b.chars());
+        (&self.a, &self.b)
+    }
+}
+
+fn levenshtein_impl<T: PartialEq>(a: &[T], b: &[T], row: &mut Vec<usize>) -> usize {
+    // Iterate over the longest string, so that the row is as short as possible.
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    row.clear();
+    row.extend(0..=b.len());
+    for (i, ca) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diag + (ca != cb) as usize;
+            diag = row[j + 1];
+            row[j + 1] = substitution.min(diag + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+fn levenshtein_str(a: &str, b: &str, scratch: &mut Scratch) -> (usize, usize) {
+    if a.is_ascii() && b.is_ascii() {
+        let max_len = a.len().max(b.len());
+        return (
+            levenshtein_impl(a.as_bytes(), b.as_bytes(), &mut scratch.row),
+            max_len,
+        );
+    }
+    let mut row = std::mem::take(&mut scratch.row);
+    let (a, b) = scratch.load(a, b);
+    let out = (levenshtein_impl(a, b, &mut row), a.len().max(b.len()));
+    scratch.row = row;
+    out
+}
+
+fn hamming_str(a: &str, b: &str) -> Option<(usize, usize)> {
+    let mut len = 0;
+    let mut distance = 0;
+    let mut b_chars = b.chars();
+    for ca in a.chars() {
+        distance += (ca != b_chars.next()?) as usize;
+        len += 1;
+    }
+    b_chars.next().is_none().then_some((distance, len))
+}
+
+fn jaro_winkler_str(a: &str, b: &str, scratch: &mut Scratch) -> f64 {
+    let mut a_matched = std::mem::take(&mut scratch.a_matched);
+    let mut b_matched = std::mem::take(&mut scratch.b_matched);
+    let (a, b) = scratch.load(a, b);
+
+    let jaro = if a.is_empty() && b.is_empty() {
+        1.0
+    } else if a.is_empty() || b.is_empty() {
+        0.0
+    } else {
+        let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+        a_matched.clear();
+        a_matched.resize(a.len(), false);
+        b_matched.clear();
+        b_matched.resize(b.len(), false);
+
+        let mut matches = 0usize;
+        for (i, ca) in a.iter().enumerate() {
+            let start = i.saturating_sub(window);
+            let end = (i + window + 1).min(b.len());
+            for j in start..end {
+                if !b_matched[j] && b[j] == *ca {
+                    a_matched[i] = true;
+                    b_matched[j] = true;
+                    matches += 1;
+                    break;
+                }
+            }
+        }
+
+        if matches == 0 {
+            0.0
+        } else {
+            // Matched characters that are out of order.
+            let b_in_order = b
+                .iter()
+                .zip(b_matched.iter())
+                .filter_map(|(c, m)| m.then_some(c));
+            let a_in_order = a
+                .iter()
+                .zip(a_matched.iter())
+                .filter_map(|(c, m)| m.then_some(c));
+            let transpositions = a_in_order.zip(b_in_order).filter(|(a, b)| a != b).count() / 2;
+
+            let m = matches as f64;
+            (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+        }
+    };
+
+    // Boost strings with a common prefix of up to 4 characters.
+    let prefix = a
+        .iter()
+        .zip(b.iter())
+        .take(4)
+        .take_while(|(a, b)| a == b)
+        .count();
+    scratch.a_matched = a_matched;
+    scratch.b_matched = b_matched;
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn normalize(distance: usize, len: usize) -> f64 {
+    if len == 0 {
+        1.0
+    } else {
+        1.0 - distance as f64 / len as f64
+    }
+}
+
+/// The Levenshtein distance, the minimal number of single character insertions, deletions and
+/// substitutions to turn one string into the other.
+pub fn levenshtein(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    let mut scratch = Scratch::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(levenshtein_str(a?, b?, &mut scratch).0 as u32)
+    })
+}
+
+/// The Levenshtein distance normalized to a similarity between 0.0 and 1.0, where 1.0 means the
+/// strings are equal.
+pub fn levenshtein_normalized(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let mut scratch = Scratch::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (distance, len) = levenshtein_str(a?, b?, &mut scratch);
+        Some(normalize(distance, len))
+    })
+}
+
+/// The Hamming distance, the number of positions at which the characters differ.
+///
+/// Strings of different lengths have no Hamming distance and give a null.
+pub fn hamming(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        hamming_str(a?, b?).map(|(distance, _)| distance as u32)
+    })
+}
+
+/// The Hamming distance normalized to a similarity between 0.0 and 1.0, where 1.0 means the
+/// strings are equal.
+pub fn hamming_normalized(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        hamming_str(a?, b?).map(|(distance, len)| normalize(distance, len))
+    })
+}
+
+/// The Jaro-Winkler similarity between 0.0 and 1.0, where 1.0 means the strings are equal.
+pub fn jaro_winkler(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let mut scratch = Scratch::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(jaro_winkler_str(a?, b?, &mut scratch))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        let mut scratch = Scratch::default();
+        assert_eq!(levenshtein_str("kitten", "sitting", &mut scratch), (3, 7));
+        assert_eq!(levenshtein_str("", "abc", &mut scratch), (3, 3));
+        assert_eq!(levenshtein_str("straße", "strasse", &mut scratch), (2, 7));
+        assert_eq!(levenshtein_str("abc", "abc", &mut scratch), (0, 3));
+    }
+
+    #[test]
+    fn test_hamming() {
+        assert_eq!(hamming_str("karolin", "kathrin"), Some((3, 7)));
+        assert_eq!(hamming_str("ab", "abc"), None);
+        assert_eq!(hamming_str("abc", "ab"), None);
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let mut scratch = Scratch::default();
+        let jw = jaro_winkler_str("martha", "marhta", &mut scratch);
+        assert!((jw - 0.961111).abs() < 1e-6);
+        let jw = jaro_winkler_str("dixon", "dicksonx", &mut scratch);
+        assert!((jw - 0.813333).abs() < 1e-6);
+        assert_eq!(jaro_winkler_str("", "", &mut scratch), 1.0);
+        assert_eq!(jaro_winkler_str("abc", "xyz", &mut scratch), 0.0);
+    }
+}
@@ -0,0 +1,146 @@
+//! Splitting strings into tokens and character n-grams.
+use arrow::array::ValueSize;
+use regex::Regex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::*;
+
+/// How [`tokenize`] splits a string into tokens.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Tokenizer {
+    /// Split on runs of Unicode whitespace.
+    Whitespace,
+    /// Unicode words as given by the word boundaries of UAX #29, without punctuation.
+    Words,
+    /// Every non-empty match of a regex pattern.
+    Regex(String),
+}
+
+/// Split every string into a list of tokens.
+pub fn tokenize(ca: &StringChunked, tokenizer: &Tokenizer) -> PolarsResult<ListChunked> {
+    let mut builder = ListStringChunkedBuilder::new(ca.name(), ca.len(), ca.get_values_size());
+    match tokenizer {
+        Tokenizer::Whitespace => ca.for_each(|opt_s| match opt_s {
+            Some(s) => builder.append_values_iter(s.split_whitespace()),
+            None => builder.append_null(),
+        }),
+        Tokenizer::Words => ca.for_each(|opt_s| match opt_s {
+            Some(s) => builder.append_values_iter(s.unicode_words()),
+            None => builder.append_null(),
+        }),
+        Tokenizer::Regex(pat) => {
+            let reg = Regex::new(pat)?;
+            ca.for_each(|opt_s| match opt_s {
+                Some(s) => builder.append_values_iter(
+                    reg.find_iter(s)
+                        .map(|m| m.as_str())
+                        .filter(|token| !token.is_empty()),
+                ),
+                None => builder.append_null(),
+            })
+        },
+    }
+    Ok(builder.finish())
+}
+
+/// The character n-grams of every string, i.e. all substrings of `n` consecutive characters.
+///
+/// Strings with less than `n` characters give an empty list.
+pub fn ngrams(ca: &StringChunked, n: usize) -> PolarsResult<ListChunked> {
+    polars_ensure!(n > 0, InvalidOperation: "`n` must be positive in 'ngrams', got 0");
+    let mut builder = ListStringChunkedBuilder::new(ca.name(), ca.len(), ca.get_values_size() * n);
+    // Byte offsets of the character boundaries of the current string.
+    let mut boundaries = Vec::new();
+    ca.for_each(|opt_s| match opt_s {
+        Some(s) => {
+            boundaries.clear();
+            boundaries.extend(s.char_indices().map(|(i, _)| i));
+            boundaries.push(s.len());
+            builder.append_values_iter(boundaries.windows(n + 1).map(|w| &s[w[0]..w[n]]))
+        },
+        None => builder.append_null(),
+    });
+    Ok(builder.finish())
+}
+
+/// Remove accents and other combining marks, e.g. `"Crème brûlée"` becomes `"Creme brulee"`.
+pub fn strip_accents(ca: &StringChunked) -> StringChunked {
+    ca.apply_to_buffer(|s, buf| {
+        if s.is_ascii() {
+            buf.push_str(s)
+        } else {
+            buf.extend(s.nfd().filter(|c| !is_combining_mark(*c)))
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_lists(ca: &ListChunked) -> Vec<Option<Vec<Option<String>>>> {
+        ca.into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    s.str()
+                        .unwrap()
+                        .into_iter()
+                        .map(|opt| opt.map(|v| v.to_string()))
+                        .collect()
+                })
+            })
+            .collect()
+    }
+
+    fn list(values: &[&str]) -> Option<Vec<Option<String>>> {
+        Some(values.iter().map(|v| Some(v.to_string())).collect())
+    }
+
+    #[test]
+    fn test_tokenize() -> PolarsResult<()> {
+        let ca = StringChunked::new("a", &[Some(" Hello,  wörld! It's 3.5 "), None]);
+
+        let out = tokenize(&ca, &Tokenizer::Whitespace)?;
+        assert_eq!(
+            to_lists(&out),
+            [list(&["Hello,", "wörld!", "It's", "3.5"]), None]
+        );
+
+        let out = tokenize(&ca, &Tokenizer::Words)?;
+        assert_eq!(
+            to_lists(&out),
+            [list(&["Hello", "wörld", "It's", "3.5"]), None]
+        );
+
+        let out = tokenize(&ca, &Tokenizer::Regex(r"\w*".into()))?;
+        assert_eq!(
+            to_lists(&out),
+            [list(&["Hello", "wörld", "It", "s", "3", "5"]), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ngrams() -> PolarsResult<()> {
+        let ca = StringChunked::new("a", &[Some("naïve"), Some("ab"), Some(""), None]);
+        let out = ngrams(&ca, 3)?;
+        assert_eq!(
+            to_lists(&out),
+            [list(&["naï", "aïv", "ïve"]), list(&[]), list(&[]), None]
+        );
+        assert!(ngrams(&ca, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_strip_accents() {
+        let ca = StringChunked::new("a", &[Some("Crème brûlée"), Some("plain"), None]);
+        let out = strip_accents(&ca);
+        assert_eq!(Vec::from(&out), [Some("Creme brulee"), Some("plain"), None]);
+    }
+}
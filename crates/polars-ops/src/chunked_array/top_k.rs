@@ -1,10 +1,15 @@
+use std::cmp::Ordering;
+
 use arrow::array::{BinaryViewArray, BooleanArray, PrimitiveArray, StaticArray, View};
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_core::chunked_array::ops::sort::arg_bottom_k::_arg_bottom_k;
 use polars_core::downcast_as_macro_arg_physical;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
+use polars_core::POOL;
+use polars_utils::idx_vec::IdxVec;
 use polars_utils::total_ord::TotalOrd;
+use rayon::prelude::*;
 
 fn first_n_valid_mask(num_valid: usize, out_len: usize) -> Option<Bitmap> {
     if num_valid < out_len {
@@ -283,6 +288,7 @@ fn top_k_by_impl(
         nulls_last: vec![true; by.len()],
         multithreaded,
         maintain_order: false,
+        limit: None,
     };
 
     let idx = _arg_bottom_k(k, by, &mut sort_options)?;
@@ -290,3 +296,142 @@ fn top_k_by_impl(
     let result = unsafe { src.take_unchecked(&idx.into_inner()) };
     Ok(result)
 }
+
+/// A max heap of at most `k` row indices, which keeps the `k` rows that come first in the order
+/// of `cmp`.
+struct BoundedHeap<F> {
+    heap: Vec<IdxSize>,
+    k: usize,
+    cmp: F,
+}
+
+impl<F: Fn(IdxSize, IdxSize) -> Ordering> BoundedHeap<F> {
+    fn new(k: usize, cmp: F) -> Self {
+        Self {
+            heap: Vec::with_capacity(k),
+            k,
+            cmp,
+        }
+    }
+
+    fn push(&mut self, idx: IdxSize) {
+        if self.heap.len() < self.k {
+            self.heap.push(idx);
+            self.sift_up(self.heap.len() - 1);
+        } else if self.k > 0 && (self.cmp)(idx, self.heap[0]) == Ordering::Less {
+            self.heap[0] = idx;
+            self.sift_down(0);
+        }
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if (self.cmp)(self.heap[i], self.heap[parent]) != Ordering::Greater {
+                break;
+            }
+            self.heap.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let mut largest = i;
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < self.heap.len()
+                    && (self.cmp)(self.heap[child], self.heap[largest]) == Ordering::Greater
+                {
+                    largest = child;
+                }
+            }
+            if largest == i {
+                break;
+            }
+            self.heap.swap(i, largest);
+            i = largest;
+        }
+    }
+
+    fn into_sorted(self) -> IdxVec {
+        let Self { mut heap, cmp, .. } = self;
+        heap.sort_unstable_by(|&a, &b| cmp(a, b));
+        heap.into_iter().collect()
+    }
+}
+
+fn arg_top_k_groups_impl<T>(
+    ca: &ChunkedArray<T>,
+    groups: &GroupsProxy,
+    k: usize,
+    options: SortOptions,
+) -> GroupsProxy
+where
+    T: PolarsDataType,
+    for<'a> T::Physical<'a>: TotalOrd,
+{
+    let cmp = |a: IdxSize, b: IdxSize| {
+        // SAFETY: group indices are always in bounds.
+        let (va, vb) = unsafe { (ca.get_unchecked(a as usize), ca.get_unchecked(b as usize)) };
+        let ord = match (va, vb) {
+            (Some(va), Some(vb)) if options.descending => vb.tot_cmp(&va),
+            (Some(va), Some(vb)) => va.tot_cmp(&vb),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) if options.nulls_last => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) if options.nulls_last => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+        };
+        // Equal values keep their order, like a stable sort.
+        ord.then(a.cmp(&b))
+    };
+    let top_k = |first: IdxSize, idx: &mut dyn Iterator<Item = IdxSize>| {
+        let mut heap = BoundedHeap::new(k, cmp);
+        idx.for_each(|i| heap.push(i));
+        let idx = heap.into_sorted();
+        (idx.first().copied().unwrap_or(first), idx)
+    };
+
+    let groups = POOL.install(|| match groups {
+        GroupsProxy::Idx(groups) => groups
+            .par_iter()
+            .map(|(first, idx)| top_k(first, &mut idx.iter().copied()))
+            .collect(),
+        GroupsProxy::Slice { groups, .. } => groups
+            .par_iter()
+            .map(|&[first, len]| top_k(first, &mut (first..first + len)))
+            .collect(),
+    });
+    GroupsProxy::Idx(groups)
+}
+
+/// The groups of the first `k` rows of every group of `s` in the order of `options`, in that
+/// order, like sorting every group and taking its head.
+///
+/// Only `k` rows of a group are kept in a bounded heap at a time, so a group of `n` rows takes
+/// `O(n log k)` time and `O(k)` memory. Returns `None` for dtypes that don't have a total order
+/// on their physical representation.
+pub fn arg_top_k_groups(
+    s: &Series,
+    groups: &GroupsProxy,
+    k: usize,
+    options: SortOptions,
+) -> Option<GroupsProxy> {
+    let s = s.rechunk();
+    let out = match s.dtype() {
+        DataType::Boolean => arg_top_k_groups_impl(s.bool().unwrap(), groups, k, options),
+        DataType::String => arg_top_k_groups_impl(s.str().unwrap(), groups, k, options),
+        DataType::Binary => arg_top_k_groups_impl(s.binary().unwrap(), groups, k, options),
+        dt if dt.is_numeric() || dt.is_temporal() => {
+            let s = s.to_physical_repr();
+            macro_rules! dispatch {
+                ($ca:expr) => {{
+                    arg_top_k_groups_impl($ca, groups, k, options)
+                }};
+            }
+            downcast_as_macro_arg_physical!(&s, dispatch)
+        },
+        _ => return None,
+    };
+    Some(out)
+}
@@ -0,0 +1,179 @@
+use polars_core::prelude::*;
+
+use super::{factorize, label, nan_to_null};
+
+/// How [`crosstab`] normalizes the counts, like the `normalize` argument of `pandas.crosstab`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CrosstabNormalize {
+    /// Divide the counts by the total count.
+    All,
+    /// Divide the counts of a row by the count of the row.
+    Index,
+    /// Divide the counts of a column by the count of the column.
+    Columns,
+}
+
+/// The options of [`crosstab`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CrosstabOptions {
+    /// Add a row and a column with the totals.
+    pub margins: bool,
+    /// The label of the row and the name of the column with the totals.
+    pub margins_name: String,
+    pub normalize: Option<CrosstabNormalize>,
+}
+
+impl Default for CrosstabOptions {
+    fn default() -> Self {
+        Self {
+            margins: false,
+            margins_name: "All".to_string(),
+            normalize: None,
+        }
+    }
+}
+
+/// Count the rows per pair of values of `index` and `columns`, like `pandas.crosstab`.
+///
+/// The result has a row per value of `index` and a column per value of `columns`, both in
+/// sorted order. The values of `index` are in the first column, named after `index`, and the
+/// other columns are named after the values of `columns`. The counts are `Int64`, or `Float64` if
+/// they are normalized.
+///
+/// A row with NaN or null in either column is not counted, so a value that only occurs with a
+/// missing value has no row or column. With [`margins`](CrosstabOptions::margins), the first
+/// column is cast to `String` for the label of the totals. Like pandas, the totals are normalized
+/// along with the counts: rows only get a row of the column totals, columns only get a column of
+/// the row totals.
+pub fn crosstab(
+    index: &Series,
+    columns: &Series,
+    options: &CrosstabOptions,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        index.len() == columns.len(),
+        ShapeMismatch: "cannot cross tabulate columns of length {} and {}",
+        index.len(), columns.len()
+    );
+    let index = nan_to_null(index)?;
+    let columns = nan_to_null(columns)?;
+    let observed = &index.is_not_null() & &columns.is_not_null();
+    let (row_codes, row_values) = factorize(&index.filter(&observed)?)?;
+    let (col_codes, col_values) = factorize(&columns.filter(&observed)?)?;
+
+    let n_rows = row_values.len();
+    let n_cols = col_values.len();
+    let mut counts = vec![0i64; n_rows * n_cols];
+    for (r, c) in row_codes.into_iter().zip(col_codes) {
+        // All values are observed, so there are no missing codes.
+        counts[r.unwrap() as usize * n_cols + c.unwrap() as usize] += 1;
+    }
+    let row_totals = (0..n_rows)
+        .map(|r| counts[r * n_cols..(r + 1) * n_cols].iter().sum::<i64>())
+        .collect::<Vec<_>>();
+    let col_totals = (0..n_cols)
+        .map(|c| (0..n_rows).map(|r| counts[r * n_cols + c]).sum::<i64>())
+        .collect::<Vec<_>>();
+    let total = row_totals.iter().sum::<i64>();
+
+    let (totals_row, totals_col) = match options.normalize {
+        _ if !options.margins => (false, false),
+        Some(CrosstabNormalize::Index) => (true, false),
+        Some(CrosstabNormalize::Columns) => (false, true),
+        _ => (true, true),
+    };
+
+    let mut index_col = row_values;
+    if totals_row {
+        index_col = index_col.cast(&DataType::String)?;
+        let margin = Series::new(index_col.name(), [options.margins_name.as_str()]);
+        index_col.append(&margin)?;
+    }
+    let mut out = vec![index_col];
+
+    // The count of every row of a column, including the totals.
+    let column = |c: Option<usize>| -> Vec<i64> {
+        let mut values = (0..n_rows)
+            .map(|r| match c {
+                Some(c) => counts[r * n_cols + c],
+                None => row_totals[r],
+            })
+            .collect::<Vec<_>>();
+        if totals_row {
+            values.push(c.map_or(total, |c| col_totals[c]));
+        }
+        values
+    };
+    let names = (0..n_cols)
+        .map(|c| Ok((Some(c), label(col_values.get(c)?))))
+        .chain(totals_col.then(|| Ok((None, options.margins_name.clone()))))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    for (c, name) in names {
+        let values = column(c);
+        let s = match options.normalize {
+            None => Series::new(&name, values),
+            Some(normalize) => {
+                let values = values.iter().enumerate().map(|(r, v)| {
+                    // The totals are divided by the total, whatever is normalized.
+                    let den = match normalize {
+                        _ if r == n_rows || c.is_none() => total,
+                        CrosstabNormalize::All => total,
+                        CrosstabNormalize::Index => row_totals[r],
+                        CrosstabNormalize::Columns => c.map_or(total, |c| col_totals[c]),
+                    };
+                    *v as f64 / den as f64
+                });
+                Series::new(&name, values.collect::<Vec<_>>())
+            },
+        };
+        out.push(s);
+    }
+    DataFrame::new(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_crosstab() -> PolarsResult<()> {
+        let a = Series::new("a", &[Some("x"), Some("y"), Some("x"), None, Some("z")]);
+        let b = Series::new(
+            "b",
+            &[Some(1.0), Some(2.0), Some(1.0), Some(2.0), Some(f64::NAN)],
+        );
+
+        // "z" only occurs with NaN, so it has no row.
+        let out = crosstab(&a, &b, &Default::default())?;
+        assert_eq!(out.get_column_names(), ["a", "1.0", "2.0"]);
+        assert_eq!(Vec::from(out.column("1.0")?.i64()?), [Some(2), Some(0)]);
+
+        let options = CrosstabOptions {
+            margins: true,
+            ..Default::default()
+        };
+        let out = crosstab(&a, &b, &options)?;
+        assert_eq!(out.get_column_names(), ["a", "1.0", "2.0", "All"]);
+        assert_eq!(
+            Vec::from(out.column("a")?.str()?),
+            [Some("x"), Some("y"), Some("All")]
+        );
+        assert_eq!(
+            Vec::from(out.column("All")?.i64()?),
+            [Some(2), Some(1), Some(3)]
+        );
+
+        let options = CrosstabOptions {
+            margins: true,
+            normalize: Some(CrosstabNormalize::Index),
+            ..Default::default()
+        };
+        let out = crosstab(&a, &b, &options)?;
+        assert_eq!(out.get_column_names(), ["a", "1.0", "2.0"]);
+        assert_eq!(
+            Vec::from(out.column("2.0")?.f64()?),
+            [Some(0.0), Some(1.0), Some(1.0 / 3.0)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,293 @@
+use polars_core::prelude::*;
+
+use super::nan_to_null;
+
+/// The bins of [`cut`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum CutBins {
+    /// This many bins of equal width over the range of the values. The range is widened by
+    /// 0.1% on the open side, so that the bins include the minimum and the maximum.
+    Count(usize),
+    /// The edges of the bins in increasing order.
+    Edges(Vec<f64>),
+}
+
+/// The labels of the bins of [`cut`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CutLabels {
+    /// The intervals of the bins, like `(0.0, 1.5]`.
+    #[default]
+    Intervals,
+    /// A unique label per bin.
+    Custom(Vec<String>),
+    /// The `Int64` index of the bin instead of a categorical, like `labels=False`.
+    Codes,
+}
+
+/// The options of [`cut`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CutOptions {
+    /// Whether the bins are closed on the right, `(a, b]`, else on the left, `[a, b)`.
+    pub right: bool,
+    /// Whether the first bin includes its left edge if the bins are closed on the right.
+    pub include_lowest: bool,
+    pub labels: CutLabels,
+    /// The number of significant decimals of the edges in the interval labels.
+    pub precision: usize,
+    /// Drop repeated edges instead of raising an error, like `duplicates="drop"`.
+    pub drop_duplicate_edges: bool,
+}
+
+impl Default for CutOptions {
+    fn default() -> Self {
+        Self {
+            right: true,
+            include_lowest: false,
+            labels: CutLabels::Intervals,
+            precision: 3,
+            drop_duplicate_edges: false,
+        }
+    }
+}
+
+/// The edges of `n` bins of equal width over the range of `values`.
+fn equal_width_edges(values: &Float64Chunked, n: usize, right: bool) -> PolarsResult<Vec<f64>> {
+    polars_ensure!(n > 0, InvalidOperation: "`bins` should be a positive integer");
+    let mut range: Option<(f64, f64)> = None;
+    for v in values.into_iter().flatten() {
+        polars_ensure!(
+            v.is_finite(),
+            InvalidOperation: "cannot specify integer `bins` when input data contains infinity"
+        );
+        range = Some(range.map_or((v, v), |(min, max)| (min.min(v), max.max(v))));
+    }
+    let Some((mut min, mut max)) = range else {
+        polars_bail!(InvalidOperation: "cannot cut a column without values into integer `bins`")
+    };
+
+    if min == max {
+        let adj = if min == 0.0 { 0.001 } else { 0.001 * min.abs() };
+        min -= adj;
+        max += adj;
+    }
+    let width = (max - min) / n as f64;
+    let mut edges = (0..=n)
+        .map(|i| if i == n { max } else { min + width * i as f64 })
+        .collect::<Vec<_>>();
+    if range.map_or(false, |(min, max)| min != max) {
+        let adj = (max - min) * 0.001;
+        if right {
+            edges[0] -= adj;
+        } else {
+            edges[n] += adj;
+        }
+    }
+    Ok(edges)
+}
+
+/// Round `x` to `precision` significant decimals.
+fn round_frac(x: f64, precision: usize) -> f64 {
+    if !x.is_finite() || x == 0.0 {
+        return x;
+    }
+    let (whole, frac) = (x.trunc(), x.fract());
+    let digits = if whole == 0.0 {
+        -(frac.abs().log10().floor() as i32) - 1 + precision as i32
+    } else {
+        precision as i32
+    };
+    let scale = 10f64.powi(digits);
+    (x * scale).round() / scale
+}
+
+/// The interval labels of the bins, with the lowest precision from `precision` up at which the
+/// edges are distinct.
+fn interval_labels(edges: &[f64], options: &CutOptions) -> Vec<String> {
+    let precision = (options.precision..20)
+        .find(|&p| {
+            let rounded = edges.iter().map(|e| round_frac(*e, p)).collect::<Vec<_>>();
+            rounded.windows(2).all(|w| w[0] != w[1])
+        })
+        .unwrap_or(options.precision);
+    let mut breaks = edges
+        .iter()
+        .map(|e| round_frac(*e, precision))
+        .collect::<Vec<_>>();
+    if let (true, Some(first)) = (options.right && options.include_lowest, breaks.first_mut()) {
+        *first -= 10f64.powi(-(precision as i32));
+    }
+    breaks
+        .windows(2)
+        .map(|w| {
+            if options.right {
+                format!("({:?}, {:?}]", w[0], w[1])
+            } else {
+                format!("[{:?}, {:?})", w[0], w[1])
+            }
+        })
+        .collect()
+}
+
+/// Bin the values of `s`, like `pandas.cut`.
+///
+/// Unlike the native `cut`, the outer bins are not open ended: a value outside the
+/// edges is null, as are NaN and null. The first bin only includes its left edge with
+/// [`include_lowest`](CutOptions::include_lowest), which widens its label by one unit of the
+/// precision like pandas. The result is a categorical with the labels of all bins in order,
+/// unless the labels are [codes](CutLabels::Codes).
+pub fn cut(s: &Series, bins: CutBins, options: &CutOptions) -> PolarsResult<Series> {
+    let values = nan_to_null(&s.cast(&DataType::Float64)?)?;
+    let values = values.f64()?;
+
+    let mut edges = match bins {
+        CutBins::Count(n) => equal_width_edges(values, n, options.right)?,
+        CutBins::Edges(edges) => {
+            polars_ensure!(!edges.iter().any(|e| e.is_nan()), InvalidOperation: "bins cannot be NaN");
+            polars_ensure!(
+                edges.windows(2).all(|w| w[0] <= w[1]),
+                InvalidOperation: "bins must increase monotonically"
+            );
+            edges
+        },
+    };
+    if options.drop_duplicate_edges {
+        edges.dedup();
+    }
+    polars_ensure!(
+        edges.windows(2).all(|w| w[0] != w[1]),
+        Duplicate: "bin edges must be unique: {:?}; set `drop_duplicate_edges` to drop them",
+        edges
+    );
+    let n_bins = edges.len().saturating_sub(1);
+
+    let first = edges.first().copied();
+    let codes = values.into_iter().map(|v| {
+        let v = v?;
+        if options.right && options.include_lowest && Some(v) == first {
+            return Some(0);
+        }
+        let pp = if options.right {
+            edges.partition_point(|e| *e < v)
+        } else {
+            edges.partition_point(|e| *e <= v)
+        };
+        pp.checked_sub(1).filter(|bin| *bin < n_bins)
+    });
+
+    let labels = match &options.labels {
+        CutLabels::Codes => {
+            let out: Int64Chunked = codes.map(|c| c.map(|c| c as i64)).collect();
+            return Ok(out.with_name(s.name()).into_series());
+        },
+        CutLabels::Custom(labels) => {
+            polars_ensure!(
+                labels.len() == n_bins,
+                ShapeMismatch: "bin labels must be one fewer than the number of bin edges"
+            );
+            let mut sorted = labels.iter().collect::<Vec<_>>();
+            sorted.sort();
+            polars_ensure!(
+                sorted.windows(2).all(|w| w[0] != w[1]),
+                Duplicate: "bin labels must be unique"
+            );
+            labels.clone()
+        },
+        CutLabels::Intervals => interval_labels(&edges, options),
+    };
+
+    // The labels are registered first, so the categories are in the order of the bins.
+    let mut builder = CategoricalChunkedBuilder::new(s.name(), s.len(), Default::default());
+    for label in &labels {
+        builder.register_value(label);
+    }
+    let out = builder.drain_iter_and_finish(codes.map(|c| c.map(|c| labels[c].as_str())));
+    Ok(out.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn categories(s: &Series) -> PolarsResult<Vec<Option<String>>> {
+        Ok(s.cast(&DataType::String)?
+            .str()?
+            .into_iter()
+            .map(|v| v.map(str::to_string))
+            .collect())
+    }
+
+    #[test]
+    fn test_cut_edges() -> PolarsResult<()> {
+        let s = Series::new("x", &[0.0, 0.5, 1.0, 2.0, 3.0, f64::NAN]);
+        let bins = CutBins::Edges(vec![0.0, 1.0, 2.0]);
+
+        let out = cut(&s, bins.clone(), &Default::default())?;
+        assert_eq!(out.name(), "x");
+        let expected = [
+            None,
+            Some("(0.0, 1.0]"),
+            Some("(0.0, 1.0]"),
+            Some("(1.0, 2.0]"),
+        ];
+        let expected = expected
+            .iter()
+            .map(|v| v.map(str::to_string))
+            .chain([None, None])
+            .collect::<Vec<_>>();
+        assert_eq!(categories(&out)?, expected);
+
+        let options = CutOptions {
+            include_lowest: true,
+            ..Default::default()
+        };
+        let out = cut(&s, bins.clone(), &options)?;
+        assert_eq!(categories(&out)?[0].as_deref(), Some("(-0.001, 1.0]"));
+
+        let options = CutOptions {
+            right: false,
+            labels: CutLabels::Codes,
+            ..Default::default()
+        };
+        let out = cut(&s, bins.clone(), &options)?;
+        assert_eq!(
+            Vec::from(out.i64()?),
+            [Some(0), Some(0), Some(1), None, None, None]
+        );
+
+        assert!(cut(&s, CutBins::Edges(vec![0.0, 1.0, 1.0]), &Default::default()).is_err());
+        let options = CutOptions {
+            drop_duplicate_edges: true,
+            ..Default::default()
+        };
+        assert!(cut(&s, CutBins::Edges(vec![0.0, 1.0, 1.0]), &options).is_ok());
+        assert!(cut(&s, CutBins::Edges(vec![1.0, 0.0]), &Default::default()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cut_count() -> PolarsResult<()> {
+        let s = Series::new("x", &[1, 2, 3, 4]);
+        let out = cut(&s, CutBins::Count(3), &Default::default())?;
+        assert_eq!(
+            categories(&out)?,
+            [
+                Some("(0.997, 2.0]".to_string()),
+                Some("(0.997, 2.0]".to_string()),
+                Some("(2.0, 3.0]".to_string()),
+                Some("(3.0, 4.0]".to_string()),
+            ]
+        );
+
+        let options = CutOptions {
+            labels: CutLabels::Custom(vec!["low".into(), "high".into()]),
+            ..Default::default()
+        };
+        let out = cut(&s, CutBins::Count(2), &options)?;
+        assert_eq!(
+            categories(&out)?,
+            ["low", "low", "high", "high"].map(|v| Some(v.to_string()))
+        );
+        assert!(cut(&s, CutBins::Count(0), &Default::default()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use polars_core::prelude::*;
+
+use super::{factorize, label, nan_to_null, NAN_LABEL};
+
+/// The options of [`get_dummies`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetDummiesOptions {
+    /// The columns to encode, by default the String, Categorical and Enum columns.
+    pub columns: Option<Vec<String>>,
+    /// The separator of the column name and the value in the names of the dummy columns.
+    pub prefix_sep: String,
+    /// Add a dummy column `{column}{prefix_sep}nan` for the missing values. Without it, a row
+    /// with a missing value is zero in all dummy columns of its column.
+    pub dummy_na: bool,
+    /// Leave out the dummy column of the first category.
+    pub drop_first: bool,
+    /// The dtype of the dummy columns, `Boolean` like pandas 2.
+    pub dtype: DataType,
+}
+
+impl Default for GetDummiesOptions {
+    fn default() -> Self {
+        Self {
+            columns: None,
+            prefix_sep: "_".to_string(),
+            dummy_na: false,
+            drop_first: false,
+            dtype: DataType::Boolean,
+        }
+    }
+}
+
+/// Encode the categories of columns as dummy columns, like `pandas.get_dummies`.
+///
+/// Unlike [`to_dummies`](crate::frame::DataFrameOps::to_dummies), the columns that are not
+/// encoded come first, followed by the dummy columns of every encoded column in the sorted order
+/// of its categories. NaN and null are missing values, which get no dummy column unless
+/// [`dummy_na`](GetDummiesOptions::dummy_na) is set, and which come after the categories if they
+/// do.
+pub fn get_dummies(df: &DataFrame, options: &GetDummiesOptions) -> PolarsResult<DataFrame> {
+    let encode = match &options.columns {
+        Some(columns) => {
+            for name in columns {
+                df.column(name)?;
+            }
+            columns.clone()
+        },
+        None => df
+            .get_columns()
+            .iter()
+            .filter(|s| {
+                matches!(
+                    s.dtype(),
+                    DataType::String | DataType::Categorical(_, _) | DataType::Enum(_, _)
+                )
+            })
+            .map(|s| s.name().to_string())
+            .collect(),
+    };
+
+    let mut out = df
+        .get_columns()
+        .iter()
+        .filter(|s| !encode.iter().any(|name| name == s.name()))
+        .cloned()
+        .collect::<Vec<_>>();
+    for name in &encode {
+        let s = df.column(name)?;
+        let (codes, uniques) = factorize(&nan_to_null(s)?)?;
+
+        let mut dummies = (0..uniques.len())
+            .map(|i| {
+                let name = format!("{name}{}{}", options.prefix_sep, label(uniques.get(i)?));
+                let code = Some(i as IdxSize);
+                let ca: BooleanChunked = codes.iter().map(|c| *c == code).collect();
+                Ok(ca.with_name(&name).into_series())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if options.dummy_na {
+            let name = format!("{name}{}{NAN_LABEL}", options.prefix_sep);
+            let ca: BooleanChunked = codes.iter().map(|c| c.is_none()).collect();
+            dummies.push(ca.with_name(&name).into_series());
+        }
+
+        let dummies = dummies.into_iter().skip(options.drop_first as usize);
+        for dummy in dummies {
+            out.push(dummy.cast(&options.dtype)?);
+        }
+    }
+    DataFrame::new(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_dummies() -> PolarsResult<()> {
+        let df = df![
+            "id" => [1, 2, 3, 4],
+            "color" => [Some("red"), None, Some("blue"), Some("red")],
+            "size" => [Some(2.0), Some(f64::NAN), Some(1.0), None],
+        ]?;
+
+        let out = get_dummies(&df, &Default::default())?;
+        assert_eq!(
+            out.get_column_names(),
+            ["id", "size", "color_blue", "color_red"]
+        );
+        assert_eq!(out.column("color_blue")?.dtype(), &DataType::Boolean);
+        assert_eq!(
+            Vec::from(out.column("color_red")?.bool()?),
+            [Some(true), Some(false), Some(false), Some(true)]
+        );
+
+        let options = GetDummiesOptions {
+            columns: Some(vec!["size".into(), "color".into()]),
+            dummy_na: true,
+            drop_first: true,
+            dtype: DataType::Int32,
+            ..Default::default()
+        };
+        let out = get_dummies(&df, &options)?;
+        assert_eq!(
+            out.get_column_names(),
+            ["id", "size_2.0", "size_nan", "color_red", "color_nan"]
+        );
+        assert_eq!(
+            Vec::from(out.column("size_nan")?.i32()?),
+            [Some(0), Some(1), Some(0), Some(1)]
+        );
+
+        let options = GetDummiesOptions {
+            columns: Some(vec!["missing".into()]),
+            ..Default::default()
+        };
+        assert!(get_dummies(&df, &options).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+//! Functions that behave like their pandas namesakes, to ease porting pandas code.
+//!
+//! They follow pandas where it differs from the native polars operations: NaN is missing like
+//! null, categories are in sorted order and the results have the dtypes and labels that pandas
+//! gives them.
+mod crosstab;
+mod cut;
+mod get_dummies;
+
+pub use crosstab::{crosstab, CrosstabNormalize, CrosstabOptions};
+pub use cut::{cut, CutBins, CutLabels, CutOptions};
+pub use get_dummies::{get_dummies, GetDummiesOptions};
+use polars_core::prelude::*;
+
+/// The label of a missing value, like pandas names the NaN category.
+const NAN_LABEL: &str = "nan";
+
+/// Replace the NaNs of a float column with nulls, as pandas treats both as missing.
+fn nan_to_null(s: &Series) -> PolarsResult<Series> {
+    let out = match s.dtype() {
+        DataType::Float32 => s
+            .f32()?
+            .into_iter()
+            .map(|v| v.filter(|v| !v.is_nan()))
+            .collect::<Float32Chunked>()
+            .into_series(),
+        DataType::Float64 => s
+            .f64()?
+            .into_iter()
+            .map(|v| v.filter(|v| !v.is_nan()))
+            .collect::<Float64Chunked>()
+            .into_series(),
+        _ => return Ok(s.clone()),
+    };
+    Ok(out.with_name(s.name()))
+}
+
+/// The label of a value in a column name, strings without quotes.
+fn label(av: AnyValue) -> String {
+    match av.get_str() {
+        Some(s) => s.to_string(),
+        None => format!("{av}"),
+    }
+}
+
+/// Encode the values of `s` as the indices of their distinct values in sorted order, like
+/// `pandas.factorize(sort=True)`. Returns the codes, `None` for a null, and the sorted distinct
+/// values without null.
+fn factorize(s: &Series) -> PolarsResult<(Vec<Option<IdxSize>>, Series)> {
+    let groups = s.group_tuples(true, false)?;
+    // SAFETY: the groups are in bounds.
+    let firsts = unsafe { s.agg_first(&groups) };
+    let order = firsts.arg_sort(SortOptions::default().with_nulls_last(true));
+    let mut rank = vec![0 as IdxSize; order.len()];
+    for (r, g) in order.into_no_null_iter().enumerate() {
+        rank[g as usize] = r as IdxSize;
+    }
+
+    // The nulls are a group, which sorts last.
+    let has_null = s.null_count() > 0;
+    let n_uniques = order.len() - has_null as usize;
+    let mut codes = vec![None; s.len()];
+    for (g, group) in groups.iter().enumerate() {
+        let code = Some(rank[g]).filter(|r| (*r as usize) < n_uniques);
+        match group {
+            GroupsIndicator::Idx((_, idx)) => {
+                for &i in idx.iter() {
+                    codes[i as usize] = code;
+                }
+            },
+            GroupsIndicator::Slice([offset, len]) => {
+                for i in offset..offset + len {
+                    codes[i as usize] = code;
+                }
+            },
+        }
+    }
+    let uniques = firsts.take(&order)?.slice(0, n_uniques);
+    Ok((codes, uniques))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_factorize() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some("b"), None, Some("a"), Some("b")]);
+        let (codes, uniques) = factorize(&s)?;
+        assert_eq!(codes, [Some(1), None, Some(0), Some(1)]);
+        assert!(uniques.equals(&Series::new("a", &["a", "b"])));
+
+        let s = nan_to_null(&Series::new("f", &[2.5, f64::NAN, 1.0]))?;
+        assert_eq!(s.null_count(), 1);
+        let (codes, uniques) = factorize(&s)?;
+        assert_eq!(codes, [Some(1), None, Some(0)]);
+        assert_eq!(label(uniques.get(0)?), "1.0");
+        Ok(())
+    }
+}
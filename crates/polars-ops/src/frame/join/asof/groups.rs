@@ -4,7 +4,7 @@ use ahash::RandomState;
 use hashbrown::HashMap;
 use num_traits::Zero;
 use polars_core::hashing::{
-    IdxHash, _df_rows_to_hashes_threaded_vertical, populate_multiple_key_hashmap,
+    _df_rows_to_hashes_threaded_vertical, populate_multiple_key_hashmap, IdxHash,
     _HASHMAP_INIT_SIZE,
 };
 use polars_core::prelude::*;
@@ -125,6 +125,7 @@ fn asof_in_group<'a, T, A, F>(
     right_grp_idxs: &[IdxSize],
     group_states: &mut PlHashMap<IdxSize, A>,
     filter: F,
+    params: AsofSearchParams,
 ) -> Option<IdxSize>
 where
     T: PolarsDataType,
@@ -134,7 +135,7 @@ where
     // We use the index of the first element in a group as an identifier to
     // associate with the group state.
     let id = right_grp_idxs.first()?;
-    let grp_state = group_states.entry(*id).or_default();
+    let grp_state = group_states.entry(*id).or_insert_with(|| A::new(params));
 
     unsafe {
         let r_grp_idx = grp_state.next(
@@ -161,6 +162,7 @@ fn asof_join_by_numeric<T, S, A, F>(
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
     filter: F,
+    params: AsofSearchParams,
 ) -> PolarsResult<IdxArr>
 where
     T: PolarsDataType,
@@ -228,6 +230,7 @@ where
                     right_grp_idxs.as_slice(),
                     &mut group_states,
                     &filter,
+                    params,
                 );
                 results.push(materialize_nullable(id));
             }
@@ -244,6 +247,7 @@ fn asof_join_by_binary<T, A, F>(
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
     filter: F,
+    params: AsofSearchParams,
 ) -> IdxArr
 where
     T: PolarsDataType,
@@ -293,6 +297,7 @@ where
                     right_grp_idxs.as_slice(),
                     &mut group_states,
                     &filter,
+                    params,
                 );
 
                 results.push(materialize_nullable(id));
@@ -309,6 +314,7 @@ fn asof_join_by_multiple<T, A, F>(
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
     filter: F,
+    params: AsofSearchParams,
 ) -> IdxArr
 where
     T: PolarsDataType,
@@ -373,6 +379,7 @@ where
                         &right_grp_idxs[..],
                         &mut group_states,
                         &filter,
+                        params,
                     );
 
                     results.push(materialize_nullable(id));
@@ -391,6 +398,7 @@ fn dispatch_join_by_type<T, A, F>(
     left_by: &mut DataFrame,
     right_by: &mut DataFrame,
     filter: F,
+    params: AsofSearchParams,
 ) -> PolarsResult<IdxArr>
 where
     T: PolarsDataType,
@@ -409,19 +417,23 @@ where
             DataType::String => {
                 let left_by = &left_by_s.str().unwrap().as_binary();
                 let right_by = right_by_s.str().unwrap().as_binary();
-                asof_join_by_binary::<T, A, F>(left_by, &right_by, left_asof, right_asof, filter)
+                asof_join_by_binary::<T, A, F>(
+                    left_by, &right_by, left_asof, right_asof, filter, params,
+                )
             },
             DataType::Binary => {
                 let left_by = &left_by_s.binary().unwrap();
                 let right_by = right_by_s.binary().unwrap();
-                asof_join_by_binary::<T, A, F>(left_by, right_by, left_asof, right_asof, filter)
+                asof_join_by_binary::<T, A, F>(
+                    left_by, right_by, left_asof, right_asof, filter, params,
+                )
             },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(left_by_s.dtype(), |$T| {
                     let left_by: &ChunkedArray<$T> = left_by_s.as_ref().as_ref().as_ref();
                     let right_by: &ChunkedArray<$T> = right_by_s.as_ref().as_ref().as_ref();
                     asof_join_by_numeric::<T, $T, A, F>(
-                        left_by, right_by, left_asof, right_asof, filter,
+                        left_by, right_by, left_asof, right_asof, filter, params,
                     )?
                 })
             },
@@ -437,12 +449,12 @@ where
                 match (left_by, right_by) {
                     (B::Small(left_by), B::Small(right_by)) => {
                         asof_join_by_numeric::<T, UInt32Type, A, F>(
-                            &left_by, &right_by, left_asof, right_asof, filter,
+                            &left_by, &right_by, left_asof, right_asof, filter, params,
                         )?
                     },
                     (B::Large(left_by), B::Large(right_by)) => {
                         asof_join_by_numeric::<T, UInt64Type, A, F>(
-                            &left_by, &right_by, left_asof, right_asof, filter,
+                            &left_by, &right_by, left_asof, right_asof, filter, params,
                         )?
                     },
                     // We have already asserted that the datatypes are the same.
@@ -458,7 +470,7 @@ where
             #[cfg(feature = "dtype-categorical")]
             _check_categorical_src(lhs.dtype(), rhs.dtype())?;
         }
-        asof_join_by_multiple::<T, A, F>(left_by, right_by, left_asof, right_asof, filter)
+        asof_join_by_multiple::<T, A, F>(left_by, right_by, left_asof, right_asof, filter, params)
     };
     Ok(out)
 }
@@ -470,6 +482,7 @@ fn dispatch_join_strategy<T: PolarsDataType>(
     left_by: &mut DataFrame,
     right_by: &mut DataFrame,
     strategy: AsofStrategy,
+    params: AsofSearchParams,
 ) -> PolarsResult<IdxArr>
 where
     for<'a> T::Physical<'a>: PartialOrd,
@@ -479,10 +492,10 @@ where
     let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
     match strategy {
         AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-            left_asof, right_asof, left_by, right_by, filter,
+            left_asof, right_asof, left_by, right_by, filter, params,
         ),
         AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-            left_asof, right_asof, left_by, right_by, filter,
+            left_asof, right_asof, left_by, right_by, filter, params,
         ),
         AsofStrategy::Nearest => unimplemented!(),
     }
@@ -496,6 +509,7 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
     right_by: &mut DataFrame,
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
+    params: AsofSearchParams,
 ) -> PolarsResult<IdxArr> {
     let right_ca = left_asof.unpack_series_matching_type(right_asof)?;
 
@@ -505,26 +519,26 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
         let filter = |a: T::Native, b: T::Native| a.abs_diff(b) <= abs_tolerance;
         match strategy {
             AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, params,
             ),
             AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, params,
             ),
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, params,
             ),
         }
     } else {
         let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
         match strategy {
             AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, params,
             ),
             AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, params,
             ),
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, params,
             ),
         }
     }
@@ -538,39 +552,56 @@ fn dispatch_join_type(
     right_by: &mut DataFrame,
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
+    params: AsofSearchParams,
 ) -> PolarsResult<IdxArr> {
     match left_asof.dtype() {
         DataType::Int64 => {
             let ca = left_asof.i64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, params,
+            )
         },
         DataType::Int32 => {
             let ca = left_asof.i32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, params,
+            )
         },
         DataType::UInt64 => {
             let ca = left_asof.u64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, params,
+            )
         },
         DataType::UInt32 => {
             let ca = left_asof.u32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, params,
+            )
         },
         DataType::Float32 => {
             let ca = left_asof.f32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, params,
+            )
         },
         DataType::Float64 => {
             let ca = left_asof.f64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, params,
+            )
         },
         DataType::Boolean => {
             let ca = left_asof.bool().unwrap();
-            dispatch_join_strategy::<BooleanType>(ca, right_asof, left_by, right_by, strategy)
+            dispatch_join_strategy::<BooleanType>(
+                ca, right_asof, left_by, right_by, strategy, params,
+            )
         },
         DataType::Binary => {
             let ca = left_asof.binary().unwrap();
-            dispatch_join_strategy::<BinaryType>(ca, right_asof, left_by, right_by, strategy)
+            dispatch_join_strategy::<BinaryType>(
+                ca, right_asof, left_by, right_by, strategy, params,
+            )
         },
         DataType::String => {
             let ca = left_asof.str().unwrap();
@@ -581,13 +612,22 @@ fn dispatch_join_type(
                 left_by,
                 right_by,
                 strategy,
+                params,
             )
         },
         _ => {
             let left_asof = left_asof.cast(&DataType::Int32).unwrap();
             let right_asof = right_asof.cast(&DataType::Int32).unwrap();
             let ca = left_asof.i32().unwrap();
-            dispatch_join_strategy_numeric(ca, &right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                &right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                params,
+            )
         },
     }
 }
@@ -607,7 +647,13 @@ pub trait AsofJoinBy: IntoDf {
         suffix: Option<&str>,
         slice: Option<(i64, usize)>,
         coalesce: bool,
+        params: AsofSearchParams,
+        tolerance_column: Option<&str>,
     ) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            !params.all_matches,
+            nyi = "asof join with `all_matches` in combination with `by` columns"
+        );
         let (self_sliced_slot, other_sliced_slot, left_slice_s, right_slice_s); // Keeps temporaries alive.
         let (self_df, other_df, left_key, right_key);
         if let Some((offset, len)) = slice {
@@ -660,6 +706,7 @@ pub trait AsofJoinBy: IntoDf {
             &mut right_by,
             strategy,
             tolerance,
+            params,
         )?;
 
         let mut drop_these = right_by.get_column_names();
@@ -675,11 +722,16 @@ pub trait AsofJoinBy: IntoDf {
             .collect();
         let proj_other_df = unsafe { DataFrame::new_no_checks(cols) };
 
+        let mut take_idx = IdxCa::with_chunk("", right_join_tuples);
+        if let Some(name) = tolerance_column {
+            let tolerance = self_df.column(name)?;
+            take_idx = apply_tolerance_column(take_idx, &left_asof, &right_asof, tolerance)?;
+        }
+
         let left = self_df.clone();
 
         // SAFETY: join tuples are in bounds.
-        let right_df =
-            unsafe { proj_other_df.take_unchecked(&IdxCa::with_chunk("", right_join_tuples)) };
+        let right_df = unsafe { proj_other_df.take_unchecked(&take_idx) };
 
         _finish_join(left, right_df, suffix)
     }
@@ -709,7 +761,18 @@ pub trait AsofJoinBy: IntoDf {
         let left_key = self_df.column(left_on)?;
         let right_key = other.column(right_on)?;
         self_df._join_asof_by(
-            other, left_key, right_key, left_by, right_by, strategy, tolerance, None, None, true,
+            other,
+            left_key,
+            right_key,
+            left_by,
+            right_by,
+            strategy,
+            tolerance,
+            None,
+            None,
+            true,
+            AsofSearchParams::default(),
+            None,
         )
     }
 }
@@ -15,7 +15,30 @@ use super::{_finish_join, build_tables, prepare_bytes};
 use crate::frame::IntoDf;
 use crate::series::SeriesMethods;
 
-trait AsofJoinState<T>: Default {
+/// Parameters that control which right row an asof search selects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AsofSearchParams {
+    /// Whether right keys equal to the left key may be matched.
+    pub allow_eq: bool,
+    /// Which of two equidistant right keys a nearest search selects.
+    pub tie_break: AsofTieBreak,
+    /// Whether all right rows with a key equal to the matched key are joined.
+    pub all_matches: bool,
+}
+
+impl Default for AsofSearchParams {
+    fn default() -> Self {
+        AsofSearchParams {
+            allow_eq: true,
+            tie_break: AsofTieBreak::default(),
+            all_matches: false,
+        }
+    }
+}
+
+trait AsofJoinState<T>: Sized {
+    fn new(params: AsofSearchParams) -> Self;
+
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
         left_val: &T,
@@ -24,12 +47,19 @@ trait AsofJoinState<T>: Default {
     ) -> Option<IdxSize>;
 }
 
-#[derive(Default)]
 struct AsofJoinForwardState {
     scan_offset: IdxSize,
+    allow_eq: bool,
 }
 
 impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
+    fn new(params: AsofSearchParams) -> Self {
+        AsofJoinForwardState {
+            scan_offset: 0,
+            allow_eq: params.allow_eq,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -39,7 +69,7 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
     ) -> Option<IdxSize> {
         while (self.scan_offset) < n_right {
             if let Some(right_val) = right(self.scan_offset) {
-                if right_val >= *left_val {
+                if right_val > *left_val || (self.allow_eq && right_val == *left_val) {
                     return Some(self.scan_offset);
                 }
             }
@@ -49,14 +79,22 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
     }
 }
 
-#[derive(Default)]
 struct AsofJoinBackwardState {
-    // best_bound is the greatest right index <= left_val.
+    // best_bound is the greatest right index <= left_val (< left_val if !allow_eq).
     best_bound: Option<IdxSize>,
     scan_offset: IdxSize,
+    allow_eq: bool,
 }
 
 impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
+    fn new(params: AsofSearchParams) -> Self {
+        AsofJoinBackwardState {
+            best_bound: None,
+            scan_offset: 0,
+            allow_eq: params.allow_eq,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -66,7 +104,7 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
     ) -> Option<IdxSize> {
         while self.scan_offset < n_right {
             if let Some(right_val) = right(self.scan_offset) {
-                if right_val <= *left_val {
+                if right_val < *left_val || (self.allow_eq && right_val == *left_val) {
                     self.best_bound = Some(self.scan_offset);
                 } else {
                     break;
@@ -78,14 +116,86 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
     }
 }
 
-#[derive(Default)]
 struct AsofJoinNearestState {
-    // best_bound is the nearest value to left_val, with ties broken towards the last element.
+    // best_bound is the nearest value to left_val, with ties broken towards the last element
+    // (or towards the smaller key if tie_break is Backward).
     best_bound: Option<IdxSize>,
     scan_offset: IdxSize,
+    allow_eq: bool,
+    tie_break: AsofTieBreak,
+}
+
+impl AsofJoinNearestState {
+    /// Nearest search that never matches right keys equal to the left key.
+    ///
+    /// Only right keys strictly smaller than `left_val` are consumed, the keys equal to
+    /// `left_val` are skipped without consuming them, as they may still be the nearest
+    /// match for a later (greater) left key.
+    #[inline]
+    fn next_exclusive<T: NumericNative, F: FnMut(IdxSize) -> Option<T>>(
+        &mut self,
+        left_val: &T,
+        mut right: F,
+        n_right: IdxSize,
+    ) -> Option<IdxSize> {
+        while self.scan_offset < n_right {
+            if let Some(right_val) = right(self.scan_offset) {
+                if right_val < *left_val {
+                    self.best_bound = Some(self.scan_offset);
+                } else {
+                    break;
+                }
+            }
+            self.scan_offset += 1;
+        }
+
+        // Find the last element of the first run of right keys greater than left_val.
+        let mut upper: Option<(IdxSize, T)> = None;
+        let mut lookahead = self.scan_offset;
+        while lookahead < n_right {
+            if let Some(right_val) = right(lookahead) {
+                match upper {
+                    Some((_, upper_val)) if right_val == upper_val => {
+                        upper = Some((lookahead, upper_val))
+                    },
+                    Some(_) => break,
+                    None if right_val > *left_val => upper = Some((lookahead, right_val)),
+                    None => {},
+                }
+            }
+            lookahead += 1;
+        }
+
+        match (self.best_bound, upper) {
+            (Some(lower_idx), Some((upper_idx, upper_val))) => {
+                // SAFETY: best_bound always points to a non-null value.
+                let lower_val = unsafe { right(lower_idx).unwrap_unchecked() };
+                let upper_diff = left_val.abs_diff(upper_val);
+                let lower_diff = left_val.abs_diff(lower_val);
+                if upper_diff < lower_diff
+                    || (upper_diff == lower_diff && self.tie_break == AsofTieBreak::Forward)
+                {
+                    Some(upper_idx)
+                } else {
+                    Some(lower_idx)
+                }
+            },
+            (None, Some((upper_idx, _))) => Some(upper_idx),
+            (lower, None) => lower,
+        }
+    }
 }
 
 impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
+    fn new(params: AsofSearchParams) -> Self {
+        AsofJoinNearestState {
+            best_bound: None,
+            scan_offset: 0,
+            allow_eq: params.allow_eq,
+            tie_break: params.tie_break,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -93,6 +203,10 @@ impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
         mut right: F,
         n_right: IdxSize,
     ) -> Option<IdxSize> {
+        if !self.allow_eq {
+            return self.next_exclusive(left_val, right, n_right);
+        }
+
         // Skipping ahead to the first value greater than left_val. This is
         // cheaper than computing differences.
         while self.scan_offset < n_right {
@@ -107,7 +221,10 @@ impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
                         let best_diff = left_val.abs_diff(best_right_val);
                         let scan_diff = left_val.abs_diff(scan_right_val);
 
-                        scan_diff <= best_diff
+                        match self.tie_break {
+                            AsofTieBreak::Forward => scan_diff <= best_diff,
+                            AsofTieBreak::Backward => scan_diff < best_diff,
+                        }
                     } else {
                         true
                     };
@@ -142,7 +259,7 @@ impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AsOfOptions {
     pub strategy: AsofStrategy,
@@ -155,6 +272,110 @@ pub struct AsOfOptions {
     pub tolerance_str: Option<SmartString>,
     pub left_by: Option<Vec<SmartString>>,
     pub right_by: Option<Vec<SmartString>>,
+    /// Whether right keys equal to the left key may be matched. If `false`, a
+    /// backward search is strictly less-than and a forward search strictly greater-than.
+    pub allow_exact_matches: bool,
+    /// Which of two equidistant right keys a nearest search selects.
+    pub tie_break: AsofTieBreak,
+    /// Join all right rows whose key is equal to the matched key instead of only one.
+    /// A left row may then produce multiple output rows.
+    pub all_matches: bool,
+    /// Name of a column in the left frame holding a per-row tolerance in the same unit
+    /// as the asof column. Rows where the tolerance is null are not restricted.
+    pub tolerance_column: Option<SmartString>,
+}
+
+impl Default for AsOfOptions {
+    fn default() -> Self {
+        AsOfOptions {
+            strategy: AsofStrategy::default(),
+            tolerance: None,
+            tolerance_str: None,
+            left_by: None,
+            right_by: None,
+            allow_exact_matches: true,
+            tie_break: AsofTieBreak::default(),
+            all_matches: false,
+            tolerance_column: None,
+        }
+    }
+}
+
+impl AsOfOptions {
+    pub(crate) fn search_params(&self) -> AsofSearchParams {
+        AsofSearchParams {
+            allow_eq: self.allow_exact_matches,
+            tie_break: self.tie_break,
+            all_matches: self.all_matches,
+        }
+    }
+}
+
+/// Restrict the matches in `take_idx` to those within the per-row `tolerance` of the
+/// left key. A null tolerance doesn't restrict the match.
+fn apply_tolerance_column(
+    take_idx: IdxCa,
+    left_key: &Series,
+    right_key: &Series,
+    tolerance: &Series,
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        left_key.len() == tolerance.len(),
+        ShapeMismatch: "asof tolerance column must have the same length as the left frame"
+    );
+    let dtype = if left_key.dtype().is_float() {
+        DataType::Float64
+    } else {
+        polars_ensure!(
+            left_key.dtype().is_numeric(),
+            InvalidOperation: "asof join with tolerance is only supported on numeric/temporal keys"
+        );
+        DataType::Int64
+    };
+    let left = left_key.cast(&dtype)?;
+    // SAFETY: join tuples are in bounds.
+    let right = unsafe { right_key.take_unchecked(&take_idx) }.cast(&dtype)?;
+    let tolerance = tolerance.to_physical_repr().cast(&dtype)?;
+
+    let within = (&left - &right)?.lt_eq(&tolerance)? & (&right - &left)?.lt_eq(&tolerance)?;
+    let keep = within | tolerance.is_null();
+    take_idx.zip_with(&keep, &IdxCa::full_null(take_idx.name(), take_idx.len()))
+}
+
+/// Expand `take_idx` such that every left row is joined with all right rows whose key
+/// is equal to the matched key. Returns the left and right join indices.
+fn expand_all_matches(take_idx: &IdxCa, right_key: &Series) -> (IdxCa, IdxCa) {
+    let right_key = right_key.rechunk();
+    let eq = right_key.into_total_eq_inner();
+    let n_right = right_key.len() as IdxSize;
+
+    let mut left_idx = Vec::with_capacity(take_idx.len());
+    let mut right_idx = Vec::with_capacity(take_idx.len());
+    for (i, opt_r) in take_idx.iter().enumerate() {
+        let Some(r) = opt_r else {
+            left_idx.push(i as IdxSize);
+            right_idx.push(None);
+            continue;
+        };
+        // The right keys are sorted, so equal keys are contiguous.
+        // SAFETY: all indices are in bounds.
+        let mut start = r;
+        while start > 0 && unsafe { eq.eq_element_unchecked(start as usize - 1, r as usize) } {
+            start -= 1;
+        }
+        let mut end = r + 1;
+        while end < n_right && unsafe { eq.eq_element_unchecked(end as usize, r as usize) } {
+            end += 1;
+        }
+        for j in start..end {
+            left_idx.push(i as IdxSize);
+            right_idx.push(Some(j));
+        }
+    }
+    (
+        IdxCa::from_vec("", left_idx),
+        IdxCa::from_slice_options("", &right_idx),
+    )
 }
 
 fn check_asof_columns(
@@ -202,6 +423,17 @@ pub enum AsofStrategy {
     Nearest,
 }
 
+/// How a nearest asof search resolves two right keys that are equally far from the left key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AsofTieBreak {
+    /// Select the greater right key.
+    #[default]
+    Forward,
+    /// Select the smaller right key.
+    Backward,
+}
+
 pub trait AsofJoin: IntoDf {
     #[doc(hidden)]
     #[allow(clippy::too_many_arguments)]
@@ -215,6 +447,8 @@ pub trait AsofJoin: IntoDf {
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
         coalesce: bool,
+        params: AsofSearchParams,
+        tolerance_column: Option<&str>,
     ) -> PolarsResult<DataFrame> {
         let self_df = self.to_df();
 
@@ -225,49 +459,54 @@ pub trait AsofJoin: IntoDf {
         let mut take_idx = match left_key.dtype() {
             DataType::Int64 => {
                 let ca = left_key.i64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, params)
             },
             DataType::Int32 => {
                 let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, params)
             },
             DataType::UInt64 => {
                 let ca = left_key.u64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, params)
             },
             DataType::UInt32 => {
                 let ca = left_key.u32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, params)
             },
             DataType::Float32 => {
                 let ca = left_key.f32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, params)
             },
             DataType::Float64 => {
                 let ca = left_key.f64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, params)
             },
             DataType::Boolean => {
                 let ca = left_key.bool().unwrap();
-                join_asof::<BooleanType>(ca, &right_key, strategy)
+                join_asof::<BooleanType>(ca, &right_key, strategy, params)
             },
             DataType::Binary => {
                 let ca = left_key.binary().unwrap();
-                join_asof::<BinaryType>(ca, &right_key, strategy)
+                join_asof::<BinaryType>(ca, &right_key, strategy, params)
             },
             DataType::String => {
                 let ca = left_key.str().unwrap();
                 let right_binary = right_key.cast(&DataType::Binary).unwrap();
-                join_asof::<BinaryType>(&ca.as_binary(), &right_binary, strategy)
+                join_asof::<BinaryType>(&ca.as_binary(), &right_binary, strategy, params)
             },
             _ => {
                 let left_key = left_key.cast(&DataType::Int32).unwrap();
                 let right_key = right_key.cast(&DataType::Int32).unwrap();
                 let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, params)
             },
         }?;
 
+        if let Some(name) = tolerance_column {
+            let tolerance = self_df.column(name)?;
+            take_idx = apply_tolerance_column(take_idx, &left_key, &right_key, tolerance)?;
+        }
+
         // Drop right join column.
         let other = if coalesce && left_key.name() == right_key.name() {
             Cow::Owned(other.drop(right_key.name())?)
@@ -276,6 +515,12 @@ pub trait AsofJoin: IntoDf {
         };
 
         let mut left = self_df.clone();
+        if params.all_matches {
+            let (left_idx, right_idx) = expand_all_matches(&take_idx, &right_key);
+            // SAFETY: join tuples are in bounds.
+            left = unsafe { left.take_unchecked(&left_idx) };
+            take_idx = right_idx;
+        }
         if let Some((offset, len)) = slice {
             left = left.slice(offset, len);
             take_idx = take_idx.slice(offset, len);
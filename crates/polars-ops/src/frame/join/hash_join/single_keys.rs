@@ -1,5 +1,6 @@
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
 use polars_utils::idx_vec::IdxVec;
+use polars_utils::interrupt::InterruptCheck;
 use polars_utils::nulls::IsNull;
 use polars_utils::sync::SyncPtr;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
@@ -47,12 +48,14 @@ where
         return vec![hm];
     }
 
+    let interrupt = InterruptCheck::current();
     POOL.install(|| {
         // Compute the number of elements in each partition for each portion.
         let per_thread_partition_sizes: Vec<Vec<usize>> = keys
             .par_iter()
             .with_max_len(1)
             .map(|key_portion| {
+                interrupt.try_raise();
                 let mut partition_sizes = vec![0; n_partitions];
                 for key in key_portion.clone() {
                     let key = key.to_total_ord();
@@ -100,6 +103,7 @@ where
             .with_max_len(1)
             .enumerate()
             .for_each(|(t, key_portion)| {
+                interrupt.try_raise();
                 let mut partition_offsets =
                     per_thread_partition_offsets[t * n_partitions..(t + 1) * n_partitions].to_vec();
                 for (i, key) in key_portion.into_iter().enumerate() {
@@ -124,6 +128,7 @@ where
             .into_par_iter()
             .with_max_len(1)
             .map(|p| {
+                interrupt.try_raise();
                 // Resizing the hash map is very, very expensive. That's why we
                 // adopt a hybrid strategy: we assume an initially small hash
                 // map, which would satisfy a highly skewed relation. If this
@@ -52,6 +52,18 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     num_group_join_left(lhs, rhs, validate, join_nulls)
                 })
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128()?;
+                num_group_join_left(lhs, rhs, validate, join_nulls)
+            },
+            #[cfg(feature = "dtype-u128")]
+            T::UInt128 => {
+                let lhs = lhs.u128().unwrap();
+                let rhs = rhs.u128()?;
+                num_group_join_left(lhs, rhs, validate, join_nulls)
+            },
             _ => {
                 let lhs = s_self.bit_repr();
                 let rhs = other.bit_repr();
@@ -130,6 +142,18 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     num_group_join_anti_semi(lhs, rhs, anti, join_nulls)
                 })
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128()?;
+                num_group_join_anti_semi(lhs, rhs, anti, join_nulls)
+            },
+            #[cfg(feature = "dtype-u128")]
+            T::UInt128 => {
+                let lhs = lhs.u128().unwrap();
+                let rhs = rhs.u128()?;
+                num_group_join_anti_semi(lhs, rhs, anti, join_nulls)
+            },
             _ => {
                 let lhs = s_self.bit_repr();
                 let rhs = other.bit_repr();
@@ -207,6 +231,18 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     group_join_inner::<$T>(lhs, rhs, validate, join_nulls)
                 })
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128()?;
+                group_join_inner(lhs, rhs, validate, join_nulls)
+            },
+            #[cfg(feature = "dtype-u128")]
+            T::UInt128 => {
+                let lhs = lhs.u128().unwrap();
+                let rhs = rhs.u128()?;
+                group_join_inner(lhs, rhs, validate, join_nulls)
+            },
             _ => {
                 let lhs = s_self.bit_repr();
                 let rhs = other.bit_repr();
@@ -277,6 +313,18 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     hash_join_outer(lhs, rhs, validate, join_nulls)
                 })
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128()?;
+                hash_join_outer(lhs, rhs, validate, join_nulls)
+            },
+            #[cfg(feature = "dtype-u128")]
+            T::UInt128 => {
+                let lhs = lhs.u128().unwrap();
+                let rhs = rhs.u128()?;
+                hash_join_outer(lhs, rhs, validate, join_nulls)
+            },
             _ => {
                 let (Some(lhs), Some(rhs)) = (s_self.bit_repr(), other.bit_repr()) else {
                     polars_bail!(nyi = "Hash Join Outer between {lhs_dtype} and {rhs_dtype}");
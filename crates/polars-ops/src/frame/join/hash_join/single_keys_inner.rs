@@ -1,6 +1,7 @@
 use polars_core::utils::flatten;
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
 use polars_utils::idx_vec::IdxVec;
+use polars_utils::interrupt::InterruptCheck;
 use polars_utils::iter::EnumerateIdxTrait;
 use polars_utils::nulls::IsNull;
 use polars_utils::sync::SyncPtr;
@@ -69,11 +70,13 @@ where
     let offsets = probe_to_offsets(&probe);
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
+    let interrupt = InterruptCheck::current();
     let out = POOL.install(|| {
         let tuples = probe
             .into_par_iter()
             .zip(offsets)
             .map(|(probe, offset)| {
+                interrupt.try_raise();
                 let probe = probe.into_iter();
                 // local reference
                 let hash_tbls = &hash_tbls;
@@ -1,5 +1,6 @@
 use polars_core::utils::flatten::flatten_par;
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
+use polars_utils::interrupt::InterruptCheck;
 use polars_utils::nulls::IsNull;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 
@@ -137,6 +138,7 @@ where
     let offsets = probe_to_offsets(&probe);
 
     // next we probe the other relation
+    let interrupt = InterruptCheck::current();
     let result: Vec<LeftJoinIds> = POOL.install(move || {
         probe
             .into_par_iter()
@@ -144,6 +146,7 @@ where
             // probes_hashes: Vec<u64> processed by this thread
             // offset: offset index
             .map(move |(probe, offset)| {
+                interrupt.try_raise();
                 // local reference
                 let hash_tbls = &hash_tbls;
 
@@ -1,4 +1,5 @@
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
+use polars_utils::interrupt::InterruptCheck;
 use polars_utils::nulls::IsNull;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 
@@ -58,12 +59,14 @@ where
 
     // next we probe the other relation
     // This is not wrapped in POOL.install because it is not being iterated here
+    let interrupt = InterruptCheck::current();
     probe
         .into_par_iter()
         .zip(offsets)
         // probes_hashes: Vec<u64> processed by this thread
         // offset: offset index
         .flat_map(move |(probe, offset)| {
+            interrupt.try_raise();
             // local reference
             let hash_sets = &hash_sets;
             let probe_iter = probe.into_iter();
@@ -8,6 +8,8 @@ mod general;
 mod hash_join;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+#[cfg(feature = "point_in_time_join")]
+mod point_in_time;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -17,7 +19,7 @@ use ahash::RandomState;
 pub use args::*;
 use arrow::trusted_len::TrustedLen;
 #[cfg(feature = "asof_join")]
-pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy};
+pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofSearchParams, AsofStrategy, AsofTieBreak};
 #[cfg(feature = "dtype-categorical")]
 pub(crate) use checks::*;
 pub use cross_join::CrossJoin;
@@ -30,6 +32,8 @@ pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
+#[cfg(feature = "point_in_time_join")]
+pub use point_in_time::PointInTimeJoin;
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
 #[allow(unused_imports)]
 use polars_core::prelude::sort::arg_sort_multiple::{
@@ -229,32 +233,39 @@ pub trait DataFrameJoinOps: IntoDf {
                     args.join_nulls,
                 ),
                 #[cfg(feature = "asof_join")]
-                JoinType::AsOf(options) => match (options.left_by, options.right_by) {
-                    (Some(left_by), Some(right_by)) => left_df._join_asof_by(
-                        other,
-                        s_left,
-                        s_right,
-                        left_by,
-                        right_by,
-                        options.strategy,
-                        options.tolerance,
-                        args.suffix.as_deref(),
-                        args.slice,
-                        should_coalesce,
-                    ),
-                    (None, None) => left_df._join_asof(
-                        other,
-                        s_left,
-                        s_right,
-                        options.strategy,
-                        options.tolerance,
-                        args.suffix,
-                        args.slice,
-                        should_coalesce,
-                    ),
-                    _ => {
-                        panic!("expected by arguments on both sides")
-                    },
+                JoinType::AsOf(options) => {
+                    let params = options.search_params();
+                    match (options.left_by, options.right_by) {
+                        (Some(left_by), Some(right_by)) => left_df._join_asof_by(
+                            other,
+                            s_left,
+                            s_right,
+                            left_by,
+                            right_by,
+                            options.strategy,
+                            options.tolerance,
+                            args.suffix.as_deref(),
+                            args.slice,
+                            should_coalesce,
+                            params,
+                            options.tolerance_column.as_deref(),
+                        ),
+                        (None, None) => left_df._join_asof(
+                            other,
+                            s_left,
+                            s_right,
+                            options.strategy,
+                            options.tolerance,
+                            args.suffix,
+                            args.slice,
+                            should_coalesce,
+                            params,
+                            options.tolerance_column.as_deref(),
+                        ),
+                        _ => {
+                            panic!("expected by arguments on both sides")
+                        },
+                    }
                 },
                 JoinType::Cross => {
                     unreachable!()
@@ -0,0 +1,264 @@
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca_unordered;
+use polars_core::prelude::*;
+
+#[cfg(feature = "dtype-categorical")]
+use super::_check_categorical_src;
+use super::_finish_join;
+use crate::frame::IntoDf;
+
+/// The physical values of a point in time column, or of a bound of a validity range.
+fn time_values(s: &Series, dtype: &DataType) -> PolarsResult<Int64Chunked> {
+    polars_ensure!(
+        s.dtype() == dtype,
+        SchemaMismatch: "datatypes of the point in time join must match, got {} and {} for column '{}'",
+        dtype, s.dtype(), s.name()
+    );
+    polars_ensure!(
+        dtype.to_physical().is_integer(),
+        InvalidOperation: "point in time join is only supported on temporal and integer columns, got {}",
+        dtype
+    );
+    let s = s.to_physical_repr().cast(&DataType::Int64)?;
+    Ok(s.i64()?.clone())
+}
+
+/// The rows of `by` of which a key is null, if any.
+fn null_keys(by: &DataFrame) -> Option<BooleanChunked> {
+    by.get_columns()
+        .iter()
+        .filter(|s| s.null_count() > 0)
+        .map(|s| s.is_null())
+        .reduce(|acc, is_null| &acc | &is_null)
+}
+
+/// Group the right rows without null keys by their key. The rows of every group are sorted by
+/// the start of their validity range and their ranges must not overlap.
+fn validity_groups(
+    keys: Option<&BinaryOffsetChunked>,
+    null_keys: Option<&BooleanChunked>,
+    from: &[i64],
+    to: &[i64],
+) -> PolarsResult<PlHashMap<&[u8], Vec<IdxSize>>> {
+    let mut groups: PlHashMap<&[u8], Vec<IdxSize>> = PlHashMap::new();
+    for row in 0..from.len() {
+        if null_keys.is_some_and(|null_keys| null_keys.get(row) == Some(true)) {
+            continue;
+        }
+        let key = keys.map_or(&[] as &[u8], |keys| keys.get(row).unwrap());
+        groups.entry(key).or_default().push(row as IdxSize);
+    }
+
+    for rows in groups.values_mut() {
+        rows.sort_unstable_by_key(|&row| from[row as usize]);
+        for &row in rows.iter() {
+            let row = row as usize;
+            polars_ensure!(
+                from[row] <= to[row],
+                ComputeError: "the validity range of row {} of the right frame ends before it starts",
+                row
+            );
+        }
+        for window in rows.windows(2) {
+            let (prev, next) = (window[0] as usize, window[1] as usize);
+            polars_ensure!(
+                to[prev] <= from[next],
+                ComputeError: "the validity ranges of rows {} and {} of the right frame overlap",
+                prev, next
+            );
+        }
+    }
+    Ok(groups)
+}
+
+pub trait PointInTimeJoin: IntoDf {
+    /// Join every row of this frame to the row of `other` with the same `left_by` and `right_by`
+    /// keys of which the validity range `[valid_from, valid_to)` contains `ts`, like facts to the
+    /// versions of a slowly changing dimension (type 2).
+    ///
+    /// A null `valid_from` or `valid_to` is an open bound. The ranges of a key must not overlap,
+    /// which is checked, so that every row matches at most one row of `other`. The ranges of
+    /// every key are sorted once and every row is matched with a binary search.
+    ///
+    /// Only [`JoinType::Inner`] and [`JoinType::Left`] are supported. Null keys and null points
+    /// in time never match. The `right_by` columns are not in the output.
+    #[allow(clippy::too_many_arguments)]
+    fn join_point_in_time<I, S>(
+        &self,
+        other: &DataFrame,
+        ts: &str,
+        valid_from: &str,
+        valid_to: &str,
+        left_by: I,
+        right_by: I,
+        how: JoinType,
+        suffix: Option<&str>,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        polars_ensure!(
+            matches!(how, JoinType::Inner | JoinType::Left),
+            InvalidOperation: "point in time join only supports inner and left joins, got {}", how
+        );
+        let self_df = self.to_df();
+
+        let ts = self_df.column(ts)?;
+        let dtype = ts.dtype();
+        let ts = time_values(ts, dtype)?;
+        let from = time_values(other.column(valid_from)?, dtype)?;
+        let to = time_values(other.column(valid_to)?, dtype)?;
+        let from = from
+            .iter()
+            .map(|v| v.unwrap_or(i64::MIN))
+            .collect::<Vec<_>>();
+        let to = to.iter().map(|v| v.unwrap_or(i64::MAX)).collect::<Vec<_>>();
+
+        let mut left_by = self_df.select(left_by)?;
+        let mut right_by = other.select(right_by)?;
+        polars_ensure!(
+            left_by.width() == right_by.width(),
+            InvalidOperation: "the number of `left_by` and `right_by` columns must match"
+        );
+        unsafe {
+            for (l, r) in left_by
+                .get_columns_mut()
+                .iter_mut()
+                .zip(right_by.get_columns_mut().iter_mut())
+            {
+                #[cfg(feature = "dtype-categorical")]
+                _check_categorical_src(l.dtype(), r.dtype())?;
+                *l = l.to_physical_repr().into_owned();
+                *r = r.to_physical_repr().strict_cast(l.dtype())?;
+            }
+        }
+
+        let (left_keys, right_keys) = if left_by.width() == 0 {
+            (None, None)
+        } else {
+            (
+                Some(_get_rows_encoded_ca_unordered("", left_by.get_columns())?),
+                Some(_get_rows_encoded_ca_unordered("", right_by.get_columns())?),
+            )
+        };
+        let left_null_keys = null_keys(&left_by);
+        let right_null_keys = null_keys(&right_by);
+
+        let groups = validity_groups(right_keys.as_ref(), right_null_keys.as_ref(), &from, &to)?;
+
+        let take_idx = ts
+            .iter()
+            .enumerate()
+            .map(|(row, ts)| {
+                let ts = ts?;
+                if left_null_keys
+                    .as_ref()
+                    .is_some_and(|null_keys| null_keys.get(row) == Some(true))
+                {
+                    return None;
+                }
+                let key = left_keys
+                    .as_ref()
+                    .map_or(&[] as &[u8], |keys| keys.get(row).unwrap());
+                let rows = groups.get(key)?;
+                let pos = rows.partition_point(|&r| from[r as usize] <= ts);
+                let r = *rows.get(pos.checked_sub(1)?)?;
+                (ts < to[r as usize]).then_some(r)
+            })
+            .collect::<IdxCa>();
+
+        let drop_these = right_by.get_column_names();
+        let cols = other
+            .get_columns()
+            .iter()
+            .filter(|s| !drop_these.contains(&s.name()))
+            .cloned()
+            .collect();
+        let proj_other_df = unsafe { DataFrame::new_no_checks(cols) };
+
+        let (left, take_idx) = if how == JoinType::Inner {
+            let matched = take_idx.is_not_null();
+            (self_df.filter(&matched)?, take_idx.filter(&matched)?)
+        } else {
+            (self_df.clone(), take_idx)
+        };
+
+        // SAFETY: join tuples are in bounds.
+        let right_df = unsafe { proj_other_df.take_unchecked(&take_idx) };
+
+        _finish_join(left, right_df, suffix)
+    }
+}
+
+impl PointInTimeJoin for DataFrame {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_join_point_in_time() -> PolarsResult<()> {
+        let facts = df![
+            "customer" => [Some("a"), Some("a"), Some("b"), Some("b"), None],
+            "ts" => [5, 15, 5, 30, 5],
+            "amount" => [1, 2, 3, 4, 5]
+        ]?;
+        let versions = df![
+            "customer" => ["a", "a", "b"],
+            "valid_from" => [Some(10), None, Some(0)],
+            "valid_to" => [None, Some(10), Some(20)],
+            "segment" => ["gold", "silver", "bronze"]
+        ]?;
+
+        let out = facts.join_point_in_time(
+            &versions,
+            "ts",
+            "valid_from",
+            "valid_to",
+            ["customer"],
+            ["customer"],
+            JoinType::Left,
+            None,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("segment")?.str()?),
+            &[Some("silver"), Some("gold"), Some("bronze"), None, None]
+        );
+        assert_eq!(out.width(), 6);
+
+        let out = facts.join_point_in_time(
+            &versions,
+            "ts",
+            "valid_from",
+            "valid_to",
+            ["customer"],
+            ["customer"],
+            JoinType::Inner,
+            None,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("amount")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+
+        // The validity ranges of a key must not overlap.
+        let overlapping = df![
+            "customer" => ["a", "a"],
+            "valid_from" => [0, 5],
+            "valid_to" => [10, 20]
+        ]?;
+        assert!(facts
+            .join_point_in_time(
+                &overlapping,
+                "ts",
+                "valid_from",
+                "valid_to",
+                ["customer"],
+                ["customer"],
+                JoinType::Left,
+                None,
+            )
+            .is_err());
+        Ok(())
+    }
+}
@@ -1,15 +1,31 @@
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "rename_normalized")]
+mod rename_normalized;
+#[cfg(feature = "semantic_types")]
+mod semantic_types;
+mod shrink_dtype;
+#[cfg(feature = "approx_unique")]
+mod sketch;
+#[cfg(feature = "upsert")]
+mod upsert;
 
 pub use join::*;
-#[cfg(feature = "to_dummies")]
 use polars_core::export::rayon::prelude::*;
 use polars_core::prelude::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::utils::accumulate_dataframes_horizontal;
-#[cfg(feature = "to_dummies")]
 use polars_core::POOL;
+#[cfg(feature = "rename_normalized")]
+pub use rename_normalized::*;
+#[cfg(feature = "semantic_types")]
+pub use semantic_types::*;
+pub use shrink_dtype::*;
+#[cfg(feature = "approx_unique")]
+pub use sketch::*;
+#[cfg(feature = "upsert")]
+pub use upsert::{UpsertFn, UpsertResolve, UpsertStrategy};
 
 #[allow(unused_imports)]
 use crate::prelude::*;
@@ -77,6 +93,131 @@ pub trait DataFrameOps: IntoDf {
         self._to_dummies(None, separator, drop_first)
     }
 
+    /// Rename the columns to normalized names, see [`normalize_names`].
+    #[cfg(feature = "rename_normalized")]
+    fn rename_normalized(&self, options: &NormalizeNamesOptions) -> PolarsResult<DataFrame> {
+        let mut df = self.to_df().clone();
+        let names = normalize_names(&df.get_column_names(), options)?;
+        df.set_column_names(&names)?;
+        Ok(df)
+    }
+
+    /// Infer the semantic types of the String columns, see [`SemanticTypeInference`]. The
+    /// columns are validated if the options of the inference ask for it.
+    #[cfg(feature = "semantic_types")]
+    fn infer_semantic_types(
+        &self,
+        inference: &SemanticTypeInference,
+    ) -> PolarsResult<(DataFrame, SemanticTypes)> {
+        let df = self.to_df();
+        let types = inference.infer(df)?;
+        let df = if inference.options().validate {
+            inference.validate(df, &types)?
+        } else {
+            df.clone()
+        };
+        Ok((df, types))
+    }
+
+    /// Shrink the dtypes of all columns in one pass over the columns, see [`ShrinkOptions`].
+    /// Numeric columns are shrunk to the smallest dtype that can hold their values.
+    fn shrink_dtype(&self, options: &ShrinkOptions) -> PolarsResult<DataFrame> {
+        let df = self.to_df();
+        let columns = POOL.install(|| {
+            df.get_columns()
+                .par_iter()
+                .map(|s| shrink_column(s.clone(), options))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        // SAFETY: the names and lengths of the columns are unchanged.
+        Ok(unsafe { DataFrame::new_no_checks(columns) })
+    }
+
+    /// Sketch the `columns` in one parallel pass over their values, see [`ColumnSketch`].
+    ///
+    /// The cardinality is estimated with HyperLogLog, and the heavy hitters are found with a
+    /// Misra-Gries summary of `k` counters: every value that occurs more than `height / (k + 1)`
+    /// times is returned.
+    #[cfg(feature = "approx_unique")]
+    fn sketch_columns<S: AsRef<str> + Sync>(
+        &self,
+        columns: &[S],
+        k: usize,
+    ) -> PolarsResult<Vec<ColumnSketch>> {
+        let df = self.to_df();
+        let columns = columns
+            .iter()
+            .map(|name| df.column(name.as_ref()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        POOL.install(|| {
+            columns
+                .into_par_iter()
+                .map(|s| sketch::sketch_column(s, k))
+                .collect()
+        })
+    }
+
+    /// Estimate the number of unique values of the `columns`, with HyperLogLog.
+    ///
+    /// Returns a row per column, with the columns `column` and `estimated_cardinality`.
+    #[cfg(feature = "approx_unique")]
+    fn estimated_cardinality<S: AsRef<str> + Sync>(
+        &self,
+        columns: &[S],
+    ) -> PolarsResult<DataFrame> {
+        let sketches = self.sketch_columns(columns, 0)?;
+        let names = sketches.iter().map(|s| s.name.as_str()).collect::<Vec<_>>();
+        let counts = sketches
+            .iter()
+            .map(|s| s.estimated_cardinality as IdxSize)
+            .collect::<Vec<_>>();
+        DataFrame::new(vec![
+            Series::new("column", names),
+            Series::new("estimated_cardinality", counts),
+        ])
+    }
+
+    /// Update the rows that match a row of `other` on the `on` columns and append the rows of
+    /// `other` that match no row, in one join. The columns of the rows that match are resolved
+    /// per column with `strategy`.
+    ///
+    /// Every key may occur only once in `other`, null keys never match. The columns of `other`
+    /// must be columns of this frame, columns that `other` doesn't have are kept and are null in
+    /// the appended rows.
+    #[cfg(feature = "upsert")]
+    fn upsert(
+        &self,
+        other: &DataFrame,
+        on: &[&str],
+        strategy: &UpsertStrategy,
+    ) -> PolarsResult<DataFrame> {
+        upsert::upsert(self.to_df(), other, on, strategy)
+    }
+
+    /// Find the indices where the rows of `keys` should be inserted into this frame to maintain
+    /// its order, where this frame is sorted by the columns of `keys` in sequence. `descending`
+    /// and `nulls_last` are the options the frame was sorted with.
+    ///
+    /// This looks up rows with a binary search instead of a join. With
+    /// [`SearchSortedSide::Right`], the index minus one is the last row that is not greater than
+    /// the key, which assigns the keys to the intervals that start at the rows of this frame.
+    #[cfg(feature = "search_sorted")]
+    fn search_sorted_rows(
+        &self,
+        keys: &DataFrame,
+        side: SearchSortedSide,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    ) -> PolarsResult<IdxCa> {
+        let df = self.to_df();
+        let by = keys
+            .get_columns()
+            .iter()
+            .map(|s| df.column(s.name()).cloned())
+            .collect::<PolarsResult<Vec<_>>>()?;
+        search_sorted_multiple(&by, keys.get_columns(), side, descending, nulls_last)
+    }
+
     #[cfg(feature = "to_dummies")]
     fn columns_to_dummies(
         &self,
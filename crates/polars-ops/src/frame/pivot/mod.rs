@@ -23,6 +23,41 @@ pub enum PivotAgg {
     Expr(Arc<dyn PhysicalAggExpr + Send + Sync>),
 }
 
+/// The order of the parts of the generated column names of a pivot. The name of the values column
+/// is only part of the names if there are several values columns, the same goes for the name of
+/// the aggregation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PivotNameOrder {
+    /// `{value}{separator}{aggregation}{separator}{on}`
+    #[default]
+    ValuesFirst,
+    /// `{on}{separator}{value}{separator}{aggregation}`
+    OnFirst,
+}
+
+/// Options of [`pivot_multiple`].
+#[derive(Clone, Debug, Default)]
+pub struct PivotOptions {
+    /// Sort the grouped keys so that the output order is predictable.
+    pub maintain_order: bool,
+    /// Sort the transposed columns by name, rather than by order of discovery.
+    pub sort_columns: bool,
+    /// Sort the transposed columns by their aggregate over all rows, rather than by name or by
+    /// order of discovery. The columns of every values column and aggregation are sorted apart.
+    pub sort_columns_by_value: bool,
+    /// Sort the transposed columns in descending order.
+    pub descending: bool,
+    /// The separator of the parts of the generated column names, `_` if `None`.
+    pub separator: Option<String>,
+    pub name_order: PivotNameOrder,
+    /// Add the margins: the aggregates over all transposed columns as a column after them, and
+    /// the aggregates over all rows as a last row. The margin row has `margins_name` in the
+    /// index columns of type String and nulls in the others.
+    pub margins: bool,
+    /// The name of the margins, `All` if `None`.
+    pub margins_name: Option<String>,
+}
+
 fn restore_logical_type(s: &Series, logical_type: &DataType) -> Series {
     // restore logical type
     match (logical_type, s.dtype()) {
@@ -104,15 +139,18 @@ where
         .map(|s| s.as_ref().to_string())
         .collect::<Vec<_>>();
     let (index, values) = assign_remaining_columns(pivot_df, &on, index, values)?;
+    let options = PivotOptions {
+        sort_columns,
+        separator: separator.map(String::from),
+        ..Default::default()
+    };
     pivot_impl(
         pivot_df,
         &on,
         &index,
         &values,
-        agg_fn,
-        sort_columns,
-        false,
-        separator,
+        &[(String::new(), agg_fn)],
+        &options,
     )
 }
 
@@ -143,18 +181,67 @@ where
         .map(|s| s.as_ref().to_string())
         .collect::<Vec<_>>();
     let (index, values) = assign_remaining_columns(pivot_df, &on, index, values)?;
+    let options = PivotOptions {
+        maintain_order: true,
+        sort_columns,
+        separator: separator.map(String::from),
+        ..Default::default()
+    };
     pivot_impl(
         pivot_df,
         &on,
         &index,
         &values,
-        agg_fn,
-        sort_columns,
-        true,
-        separator,
+        &[(String::new(), agg_fn)],
+        &options,
     )
 }
 
+/// Do a pivot operation with several aggregations at once. Every aggregation is applied to every
+/// values column, the aggregations are named by the first element of their tuple. If `aggs` is
+/// empty, no aggregation takes place and every group must have at most one element.
+///
+/// # Note
+/// Polars'/arrow memory is not ideal for transposing operations like pivots.
+/// If you have a relatively large table, consider using a group_by over a pivot.
+pub fn pivot_multiple<I0, I1, I2, S0, S1, S2>(
+    pivot_df: &DataFrame,
+    on: I0,
+    index: Option<I1>,
+    values: Option<I2>,
+    aggs: Vec<(String, PivotAgg)>,
+    options: &PivotOptions,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    I2: IntoIterator<Item = S2>,
+    S0: AsRef<str>,
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    let on = on
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>();
+    let (index, values) = assign_remaining_columns(pivot_df, &on, index, values)?;
+    let aggs = if aggs.is_empty() {
+        vec![(String::new(), None)]
+    } else {
+        let mut names = PlHashSet::with_capacity(aggs.len());
+        for (name, _) in &aggs {
+            polars_ensure!(
+                aggs.len() == 1 || names.insert(name.as_str()),
+                Duplicate: "the aggregations of a pivot must have distinct names, found '{}' twice", name
+            );
+        }
+        aggs.into_iter()
+            .map(|(name, agg_fn)| (name, Some(agg_fn)))
+            .collect()
+    };
+    pivot_impl(pivot_df, &on, &index, &values, &aggs, options)
+}
+
 /// Ensure both `index` and `values` are populated with `Vec<String>`.
 ///
 /// - If `index` is None, assign columns not in `on` and `values` to it.
@@ -214,16 +301,19 @@ fn pivot_impl(
     // these columns will be used for a nested group_by
     // the rows of this nested group_by will be pivoted as header column values
     values: &[String],
-    // aggregation function
-    agg_fn: Option<PivotAgg>,
-    sort_columns: bool,
-    stable: bool,
-    // used as separator/delimiter in generated column names.
-    separator: Option<&str>,
+    // named aggregation functions
+    aggs: &[(String, Option<PivotAgg>)],
+    options: &PivotOptions,
 ) -> PolarsResult<DataFrame> {
     polars_ensure!(!index.is_empty(), ComputeError: "index cannot be zero length");
     polars_ensure!(!on.is_empty(), ComputeError: "`on` cannot be zero length");
-    if !stable {
+    polars_ensure!(
+        !(options.margins || options.sort_columns_by_value)
+            || aggs.iter().all(|(_, agg_fn)| agg_fn.is_some()),
+        InvalidOperation: "the margins of a pivot and sorting its columns by value require an \
+        aggregation function"
+    );
+    if !options.maintain_order {
         println!("unstable pivot not yet supported, using stable pivot");
     };
     if on.len() > 1 {
@@ -238,39 +328,93 @@ fn pivot_impl(
         let columns_struct = StructChunked::new(&column, fields).unwrap().into_series();
         let mut binding = pivot_df.clone();
         let pivot_df = unsafe { binding.with_column_unchecked(columns_struct) };
-        pivot_impl_single_column(
-            pivot_df,
-            index,
-            &column,
-            values,
-            agg_fn,
-            sort_columns,
-            separator,
-        )
+        pivot_impl_single_column(pivot_df, index, &column, values, aggs, options)
     } else {
         pivot_impl_single_column(
             pivot_df,
             index,
             unsafe { on.get_unchecked(0) },
             values,
-            agg_fn,
-            sort_columns,
-            separator,
+            aggs,
+            options,
         )
     }
 }
 
+/// Aggregate the values of every group with `agg_fn`, or take the single value of every group if
+/// there is no aggregation function.
+///
+/// # Safety
+/// The groups must be in bounds of `value_col`.
+unsafe fn aggregate(
+    value_col: &Series,
+    value_col_name: &str,
+    agg_fn: &Option<PivotAgg>,
+    groups: &GroupsProxy,
+) -> PolarsResult<Series> {
+    use PivotAgg::*;
+    let out = match agg_fn {
+        None => match value_col.len() > groups.len() {
+            true => {
+                polars_bail!(ComputeError: "found multiple elements in the same group, please specify an aggregation function")
+            },
+            false => value_col.agg_first(groups),
+        },
+        Some(agg_fn) => match agg_fn {
+            Sum => value_col.agg_sum(groups),
+            Min => value_col.agg_min(groups),
+            Max => value_col.agg_max(groups),
+            Last => value_col.agg_last(groups),
+            First => value_col.agg_first(groups),
+            Mean => value_col.agg_mean(groups),
+            Median => value_col.agg_median(groups),
+            Count => groups.group_count().into_series(),
+            Expr(ref expr) => {
+                let name = expr.root_name()?;
+                let mut value_col = value_col.clone();
+                value_col.rename(name);
+                let tmp_df = value_col.into_frame();
+                let mut aggregated = expr.evaluate(&tmp_df, groups)?;
+                aggregated.rename(value_col_name);
+                aggregated
+            },
+        },
+    };
+    Ok(out)
+}
+
+/// Merge the groups of the index and column of a pivot into the groups of the output rows or
+/// columns they are positioned at.
+fn merge_groups(groups: &GroupsProxy, locations: &[IdxSize], n: usize) -> GroupsProxy {
+    let mut all = vec![IdxVec::new(); n];
+    for (group, location) in groups.iter().zip(locations) {
+        let merged = &mut all[*location as usize];
+        match group {
+            GroupsIndicator::Idx((_, idx)) => merged.extend(idx.iter().copied()),
+            GroupsIndicator::Slice([first, len]) => merged.extend(first..first + len),
+        }
+    }
+    // Keep the order of the rows, it matters for aggregations like `first`.
+    for merged in all.iter_mut() {
+        merged.as_mut_slice().sort_unstable();
+    }
+    let first = all.iter().map(|idx| idx[0]).collect();
+    GroupsProxy::Idx(GroupsIdx::new(first, all, true))
+}
+
 fn pivot_impl_single_column(
     pivot_df: &DataFrame,
     index: &[String],
     column: &str,
     values: &[String],
-    agg_fn: Option<PivotAgg>,
-    sort_columns: bool,
-    separator: Option<&str>,
+    aggs: &[(String, Option<PivotAgg>)],
+    options: &PivotOptions,
 ) -> PolarsResult<DataFrame> {
-    let sep = separator.unwrap_or("_");
+    let sep = options.separator.as_deref().unwrap_or("_");
+    let margins_name = options.margins_name.as_deref().unwrap_or("All");
     let mut final_cols = vec![];
+    // the values of the margin row, in the order of the final columns
+    let mut margin_row = vec![];
     let mut count = 0;
     let out: PolarsResult<()> = POOL.install(|| {
         let mut group_by = index.to_vec();
@@ -285,96 +429,166 @@ fn pivot_impl_single_column(
         let (col_locations, column_agg) = col?;
         let (row_locations, n_rows, mut row_index) = row?;
 
+        let headers = column_agg.unique_stable()?.cast(&DataType::String)?;
+        let headers = headers.str().unwrap();
+        let n_cols = headers.len();
+
+        // The rows and columns of the output, and the whole frame for the grand total.
+        let (row_groups, col_groups, all_rows) = if options.margins || options.sort_columns_by_value
+        {
+            let all_rows = GroupsProxy::Slice {
+                groups: vec![[0, pivot_df.height() as IdxSize]],
+                rolling: false,
+            };
+            (
+                merge_groups(&groups, &row_locations, n_rows),
+                merge_groups(&groups, &col_locations, n_cols),
+                all_rows,
+            )
+        } else {
+            Default::default()
+        };
+
         for value_col_name in values {
             let value_col = pivot_df.column(value_col_name)?;
 
-            use PivotAgg::*;
-            let value_agg = unsafe {
-                match &agg_fn {
-                    None => match value_col.len() > groups.len() {
-                        true => polars_bail!(ComputeError: "found multiple elements in the same group, please specify an aggregation function"),
-                        false => value_col.agg_first(&groups),
-                    }
-                    Some(agg_fn) => match agg_fn {
-                        Sum => value_col.agg_sum(&groups),
-                        Min => value_col.agg_min(&groups),
-                        Max => value_col.agg_max(&groups),
-                        Last => value_col.agg_last(&groups),
-                        First => value_col.agg_first(&groups),
-                        Mean => value_col.agg_mean(&groups),
-                        Median => value_col.agg_median(&groups),
-                        Count => groups.group_count().into_series(),
-                        Expr(ref expr) => {
-                            let name = expr.root_name()?;
-                            let mut value_col = value_col.clone();
-                            value_col.rename(name);
-                            let tmp_df = value_col.into_frame();
-                            let mut aggregated = expr.evaluate(&tmp_df, &groups)?;
-                            aggregated.rename(value_col_name);
-                            aggregated
-                        }
-                    },
+            for (agg_name, agg_fn) in aggs {
+                let value_agg = unsafe { aggregate(value_col, value_col_name, agg_fn, &groups)? };
+
+                let mut name_parts = vec![];
+                if values.len() > 1 {
+                    name_parts.push(value_col_name.as_str());
                 }
-            };
+                if aggs.len() > 1 {
+                    name_parts.push(agg_name.as_str());
+                }
+                let prefix = name_parts.join(sep);
+                let column_name = |v: &str| match (name_parts.is_empty(), options.name_order) {
+                    (true, _) => v.to_string(),
+                    (false, PivotNameOrder::ValuesFirst) => format!("{prefix}{sep}{v}"),
+                    (false, PivotNameOrder::OnFirst) => format!("{v}{sep}{prefix}"),
+                };
+                let headers = if name_parts.is_empty() {
+                    headers.clone()
+                } else {
+                    headers.apply_values(|v| Cow::from(column_name(v)))
+                };
 
-            let headers = column_agg.unique_stable()?.cast(&DataType::String)?;
-            let mut headers = headers.str().unwrap().clone();
-            if values.len() > 1 {
-                headers = headers.apply_values(|v| Cow::from(format!("{value_col_name}{sep}{v}")))
-            }
+                let value_agg_phys = value_agg.to_physical_repr();
+                let logical_type = value_agg.dtype();
+
+                debug_assert_eq!(row_locations.len(), col_locations.len());
+                debug_assert_eq!(value_agg_phys.len(), row_locations.len());
+
+                let cols = if value_agg_phys.dtype().is_numeric() {
+                    macro_rules! dispatch {
+                        ($ca:expr) => {{
+                            positioning::position_aggregates_numeric(
+                                n_rows,
+                                n_cols,
+                                &row_locations,
+                                &col_locations,
+                                $ca,
+                                logical_type,
+                                &headers,
+                            )
+                        }};
+                    }
+                    downcast_as_macro_arg_physical!(value_agg_phys, dispatch)
+                } else {
+                    positioning::position_aggregates(
+                        n_rows,
+                        n_cols,
+                        &row_locations,
+                        &col_locations,
+                        &value_agg_phys,
+                        logical_type,
+                        &headers,
+                    )
+                };
 
-            let n_cols = headers.len();
-            let value_agg_phys = value_agg.to_physical_repr();
-            let logical_type = value_agg.dtype();
+                // the aggregate of every column over all rows
+                let column_totals = if options.margins || options.sort_columns_by_value {
+                    Some(unsafe { aggregate(value_col, value_col_name, agg_fn, &col_groups)? })
+                } else {
+                    None
+                };
 
-            debug_assert_eq!(row_locations.len(), col_locations.len());
-            debug_assert_eq!(value_agg_phys.len(), row_locations.len());
+                let mut order = (0..cols.len()).collect::<Vec<_>>();
+                if let (true, Some(totals)) = (options.sort_columns_by_value, &column_totals) {
+                    let sort_options = SortOptions {
+                        descending: options.descending,
+                        nulls_last: true,
+                        maintain_order: true,
+                        ..Default::default()
+                    };
+                    order = totals
+                        .arg_sort(sort_options)
+                        .into_no_null_iter()
+                        .map(|idx| idx as usize)
+                        .collect();
+                } else if options.sort_columns {
+                    order.sort_unstable_by(|&a, &b| cols[a].name().cmp(cols[b].name()));
+                    if options.descending {
+                        order.reverse();
+                    }
+                }
+                let mut cols = order.iter().map(|&i| cols[i].clone()).collect::<Vec<_>>();
 
-            let mut cols = if value_agg_phys.dtype().is_numeric() {
-                macro_rules! dispatch {
-                    ($ca:expr) => {{
-                        positioning::position_aggregates_numeric(
-                            n_rows,
-                            n_cols,
-                            &row_locations,
-                            &col_locations,
-                            $ca,
-                            logical_type,
-                            &headers,
-                        )
-                    }};
+                if options.margins {
+                    let totals = column_totals.unwrap();
+                    for (&i, col) in order.iter().zip(&cols) {
+                        margin_row.push(totals.slice(i as i64, 1).cast(col.dtype())?);
+                    }
+
+                    let name = column_name(margins_name);
+                    let mut row_totals =
+                        unsafe { aggregate(value_col, value_col_name, agg_fn, &row_groups)? };
+                    row_totals.rename(&name);
+                    let mut grand_total =
+                        unsafe { aggregate(value_col, value_col_name, agg_fn, &all_rows)? };
+                    grand_total.rename(&name);
+                    cols.push(row_totals);
+                    margin_row.push(grand_total);
                 }
-                downcast_as_macro_arg_physical!(value_agg_phys, dispatch)
-            } else {
-                positioning::position_aggregates(
-                    n_rows,
-                    n_cols,
-                    &row_locations,
-                    &col_locations,
-                    &value_agg_phys,
-                    logical_type,
-                    &headers,
-                )
-            };
 
-            if sort_columns {
-                cols.sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
+                let cols = if count == 0 {
+                    let mut final_cols = row_index.take().unwrap();
+                    if options.margins {
+                        let index_margins = final_cols
+                            .iter()
+                            .map(|s| match s.dtype() {
+                                DataType::String => Series::new(s.name(), &[margins_name]),
+                                dtype => Series::full_null(s.name(), 1, dtype),
+                            })
+                            .collect::<Vec<_>>();
+                        margin_row.splice(0..0, index_margins);
+                    }
+                    final_cols.extend(cols);
+                    final_cols
+                } else {
+                    cols
+                };
+                count += 1;
+                final_cols.extend_from_slice(&cols);
             }
-
-            let cols = if count == 0 {
-                let mut final_cols = row_index.take().unwrap();
-                final_cols.extend(cols);
-                final_cols
-            } else {
-                cols
-            };
-            count += 1;
-            final_cols.extend_from_slice(&cols);
         }
         Ok(())
     });
     out?;
 
     // SAFETY: length has already been checked.
-    unsafe { DataFrame::new_no_length_checks(final_cols) }
+    let mut out = unsafe { DataFrame::new_no_length_checks(final_cols) }?;
+    if options.margins {
+        let margin_row = margin_row
+            .into_iter()
+            .zip(out.get_columns())
+            .map(|(mut s, col)| {
+                s.rename(col.name());
+                s
+            })
+            .collect::<Vec<_>>();
+        out.vstack_mut(&DataFrame::new(margin_row)?)?;
+    }
+    Ok(out)
 }
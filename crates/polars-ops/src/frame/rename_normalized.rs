@@ -0,0 +1,213 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// The case of normalized column names.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NameCase {
+    /// `column_name`
+    #[default]
+    Snake,
+    /// `columnName`
+    Camel,
+    /// `ColumnName`
+    Pascal,
+    /// Keep the case of the words and join them with `_`.
+    Preserve,
+}
+
+/// How to resolve normalized column names that clash.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NameDedupe {
+    /// The first column keeps the name, the next ones get the lowest free suffix `_1`, `_2`, ...
+    #[default]
+    Suffix,
+    /// Raise an error.
+    Error,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NormalizeNamesOptions {
+    pub case: NameCase,
+    /// Strip accents and replace other non-ASCII characters by a word separator.
+    pub ascii_only: bool,
+    pub dedupe: NameDedupe,
+}
+
+impl Default for NormalizeNamesOptions {
+    fn default() -> Self {
+        Self {
+            case: NameCase::Snake,
+            ascii_only: true,
+            dedupe: NameDedupe::Suffix,
+        }
+    }
+}
+
+/// Split a name into words on non-alphanumeric characters and on case changes, so
+/// `"HTTPServer errorCount"` gives `["HTTP", "Server", "error", "Count"]`.
+fn split_words(name: &str, split_case: bool) -> Vec<String> {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut words = vec![];
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if split_case && !word.is_empty() && c.is_uppercase() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).map_or(false, |c| c.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn normalize_name(name: &str, options: &NormalizeNamesOptions) -> String {
+    let name = if options.ascii_only {
+        name.nfkd()
+            .filter(|c| !is_combining_mark(*c))
+            .map(|c| if c.is_ascii() { c } else { ' ' })
+            .collect::<String>()
+    } else {
+        name.to_string()
+    };
+    let words = split_words(&name, options.case != NameCase::Preserve);
+    let name = match options.case {
+        NameCase::Snake => words
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        NameCase::Camel => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                if i == 0 {
+                    w.to_lowercase()
+                } else {
+                    capitalize(w)
+                }
+            })
+            .collect(),
+        NameCase::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+        NameCase::Preserve => words.join("_"),
+    };
+    if name.is_empty() {
+        "column".to_string()
+    } else {
+        name
+    }
+}
+
+/// Normalize column names to the given case, optionally restricted to ASCII, and resolve the
+/// names that clash after normalization.
+pub fn normalize_names<S: AsRef<str>>(
+    names: &[S],
+    options: &NormalizeNamesOptions,
+) -> PolarsResult<Vec<String>> {
+    let normalized = names
+        .iter()
+        .map(|name| normalize_name(name.as_ref(), options))
+        .collect::<Vec<_>>();
+
+    // The first occurrence of every normalized name keeps it, so a suffixed duplicate can never
+    // take the name of a later column.
+    let mut taken = PlHashSet::with_capacity(normalized.len());
+    let keeps_name = normalized
+        .iter()
+        .map(|name| taken.insert(name.as_str()))
+        .collect::<Vec<_>>();
+    let mut taken = taken
+        .into_iter()
+        .map(String::from)
+        .collect::<PlHashSet<_>>();
+
+    let mut out = Vec::with_capacity(normalized.len());
+    for (i, (name, keeps_name)) in normalized.iter().zip(keeps_name).enumerate() {
+        if keeps_name {
+            out.push(name.clone());
+            continue;
+        }
+        if options.dedupe == NameDedupe::Error {
+            let first = normalized.iter().position(|n| n == name).unwrap();
+            polars_bail!(
+                Duplicate: "columns '{}' and '{}' both normalize to '{}'",
+                names[first].as_ref(), names[i].as_ref(), name
+            );
+        }
+        let deduped = (1..)
+            .map(|suffix| format!("{name}_{suffix}"))
+            .find(|candidate| !taken.contains(candidate))
+            .unwrap();
+        taken.insert(deduped.clone());
+        out.push(deduped);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize_names() -> PolarsResult<()> {
+        let names = [
+            "Customer ID",
+            "customerId",
+            "HTTPServer",
+            "Crème brûlée (€)",
+            "col1Total",
+            "  ",
+            "customer_id_1",
+        ];
+        let out = normalize_names(&names, &Default::default())?;
+        assert_eq!(
+            out,
+            [
+                "customer_id",
+                "customer_id_2",
+                "http_server",
+                "creme_brulee",
+                "col1_total",
+                "column",
+                "customer_id_1",
+            ]
+        );
+
+        let options = NormalizeNamesOptions {
+            case: NameCase::Camel,
+            ascii_only: false,
+            dedupe: NameDedupe::Error,
+        };
+        let out = normalize_names(&["user name", "Crème"], &options)?;
+        assert_eq!(out, ["userName", "crème"]);
+        assert!(normalize_names(&["a b", "A_B"], &options).is_err());
+        Ok(())
+    }
+}
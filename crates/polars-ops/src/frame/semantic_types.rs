@@ -0,0 +1,529 @@
+//! Infer the semantic types of String columns, like e-mail addresses or URLs, from a sample of
+//! their values. See [`SemanticTypeInference`].
+use arrow::datatypes::{ArrowSchema, Metadata};
+use polars_core::prelude::*;
+
+/// The key of the semantic type in the metadata of a column.
+pub const SEMANTIC_TYPE_KEY: &str = "polars:semantic_type";
+/// The key of the fraction of the sampled values that matched the semantic type in the metadata
+/// of a column.
+pub const SEMANTIC_TYPE_MATCH_FRACTION_KEY: &str = "polars:semantic_type_match_fraction";
+
+/// ISO 3166-1 alpha-2 country codes, sorted.
+const COUNTRY_CODES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+const CURRENCY_SYMBOLS: &[char] = &['$', '€', '£', '¥', '₹', '₩', '₽', '₺', '₫', '₪'];
+
+/// Detects a semantic type in the values of String columns.
+///
+/// Implement it to infer a semantic type that is not built in, and add it to the inference with
+/// [`SemanticTypeInference::with_detector`].
+pub trait SemanticTypeDetector: Send + Sync {
+    /// The name of the semantic type, as stored in the metadata of the columns.
+    fn name(&self) -> &str;
+
+    /// Whether `value` is a value of the semantic type.
+    fn matches(&self, value: &str) -> bool;
+
+    /// The data type of the columns of the semantic type once they are validated.
+    fn dtype(&self) -> DataType {
+        DataType::String
+    }
+
+    /// Convert a column of the semantic type to [`dtype`](Self::dtype), replacing the values
+    /// that don't match by null. The values are kept as they are by default.
+    fn validate(&self, ca: &StringChunked) -> PolarsResult<Series> {
+        let out: StringChunked = ca
+            .into_iter()
+            .map(|value| value.filter(|value| self.matches(value)))
+            .collect();
+        Ok(out.with_name(ca.name()).into_series())
+    }
+}
+
+/// The semantic types that are inferred by default.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SemanticType {
+    /// `name@example.com`
+    Email,
+    /// An absolute `http`, `https` or `ftp` URL.
+    Url,
+    /// A phone number with at least one separator or a leading `+`, like `+1 555-0100`.
+    Phone,
+    /// An ISO 3166-1 alpha-2 country code, like `NL`.
+    CountryCode,
+    /// An amount with a currency symbol or code, like `$1,234.50` or `12 EUR`. Validated as
+    /// Float64.
+    Currency,
+}
+
+impl SemanticType {
+    pub const ALL: [SemanticType; 5] = [
+        SemanticType::Email,
+        SemanticType::Url,
+        SemanticType::Currency,
+        SemanticType::Phone,
+        SemanticType::CountryCode,
+    ];
+}
+
+impl SemanticTypeDetector for SemanticType {
+    fn name(&self) -> &str {
+        match self {
+            SemanticType::Email => "email",
+            SemanticType::Url => "url",
+            SemanticType::Phone => "phone",
+            SemanticType::CountryCode => "country_code",
+            SemanticType::Currency => "currency",
+        }
+    }
+
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            SemanticType::Email => is_email(value),
+            SemanticType::Url => is_url(value),
+            SemanticType::Phone => is_phone(value),
+            SemanticType::CountryCode => COUNTRY_CODES.binary_search(&value).is_ok(),
+            SemanticType::Currency => parse_currency(value).is_some(),
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        match self {
+            SemanticType::Currency => DataType::Float64,
+            _ => DataType::String,
+        }
+    }
+
+    fn validate(&self, ca: &StringChunked) -> PolarsResult<Series> {
+        match self {
+            SemanticType::Currency => {
+                let out: Float64Chunked = ca
+                    .into_iter()
+                    .map(|value| value.and_then(parse_currency))
+                    .collect();
+                Ok(out.with_name(ca.name()).into_series())
+            },
+            _ => {
+                let out: StringChunked = ca
+                    .into_iter()
+                    .map(|value| value.filter(|value| self.matches(value)))
+                    .collect();
+                Ok(out.with_name(ca.name()).into_series())
+            },
+        }
+    }
+}
+
+fn is_domain(domain: &str) -> bool {
+    let mut labels = domain.split('.');
+    let tld = labels.next_back().unwrap_or_default();
+    labels.next().is_some()
+        && tld.len() >= 2
+        && tld.chars().all(|c| c.is_alphabetic())
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c))
+        && is_domain(domain)
+}
+
+fn is_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+    let scheme = scheme.to_ascii_lowercase();
+    if !matches!(scheme.as_str(), "http" | "https" | "ftp") || value.contains(char::is_whitespace) {
+        return false;
+    }
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let host = match host.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+        _ => host,
+    };
+    host == "localhost"
+        || is_domain(host)
+        || (host.split('.').count() == 4 && host.split('.').all(|part| part.parse::<u8>().is_ok()))
+}
+
+fn is_phone(value: &str) -> bool {
+    let digits = value.strip_prefix('+').unwrap_or(value);
+    let mut n_digits = 0;
+    for c in digits.chars() {
+        match c {
+            '0'..='9' => n_digits += 1,
+            ' ' | '-' | '.' | '(' | ')' => {},
+            _ => return false,
+        }
+    }
+    // Without a `+` or a separator the value is more likely an integer.
+    (7..=15).contains(&n_digits)
+        && (value.starts_with('+') || n_digits < value.len())
+        && value.chars().last().map_or(false, |c| c.is_ascii_digit())
+        && !is_date_like(value)
+}
+
+/// Whether `value` looks like a `yyyy-mm-dd` or `dd.mm.yyyy` date rather than a phone number.
+fn is_date_like(value: &str) -> bool {
+    let parts = value
+        .split(['-', '.', '/'])
+        .map(str::len)
+        .collect::<Vec<_>>();
+    matches!(parts.as_slice(), [4, 2, 2] | [2, 2, 4])
+}
+
+/// Parse an amount with a currency symbol or ISO 4217 code in front or after it, the digits may
+/// be grouped with `,`.
+fn parse_currency(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value.trim_start()),
+        None => (false, value),
+    };
+    let is_code = |code: &str| code.len() == 3 && code.chars().all(|c| c.is_ascii_uppercase());
+    let amount = if let Some(amount) = value.strip_prefix(CURRENCY_SYMBOLS) {
+        amount
+    } else if let Some(amount) = value.strip_suffix(CURRENCY_SYMBOLS) {
+        amount
+    } else if value.len() > 3 && value.is_char_boundary(3) && is_code(&value[..3]) {
+        &value[3..]
+    } else if value.len() > 3
+        && value.is_char_boundary(value.len() - 3)
+        && is_code(&value[value.len() - 3..])
+    {
+        &value[..value.len() - 3]
+    } else {
+        return None;
+    };
+    let amount = amount.trim();
+    let (int, frac) = amount.split_once('.').unwrap_or((amount, ""));
+    let groups = int.split(',').collect::<Vec<_>>();
+    let grouped = groups.len() == 1
+        || (!groups[0].is_empty()
+            && groups[0].len() <= 3
+            && groups[1..].iter().all(|g| g.len() == 3));
+    if int.is_empty()
+        || !grouped
+        || !int.chars().all(|c| c.is_ascii_digit() || c == ',')
+        || !frac.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let amount = format!("{}.{}", int.replace(',', ""), frac)
+        .trim_end_matches('.')
+        .parse::<f64>()
+        .ok()?;
+    Some(if negative { -amount } else { amount })
+}
+
+/// The semantic type inferred for a column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SemanticTypeMatch {
+    pub column: String,
+    /// The [name](SemanticTypeDetector::name) of the semantic type.
+    pub semantic_type: String,
+    /// The fraction of the sampled values that matched the semantic type.
+    pub match_fraction: f64,
+}
+
+/// The semantic types inferred for the columns of a frame, see [`SemanticTypeInference::infer`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SemanticTypes {
+    matches: Vec<SemanticTypeMatch>,
+}
+
+impl SemanticTypes {
+    /// The semantic type of `column`, if one was inferred.
+    pub fn get(&self, column: &str) -> Option<&SemanticTypeMatch> {
+        self.matches.iter().find(|m| m.column == column)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SemanticTypeMatch> {
+        self.matches.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    /// The semantic type of `column` as column metadata, under [`SEMANTIC_TYPE_KEY`] and
+    /// [`SEMANTIC_TYPE_MATCH_FRACTION_KEY`].
+    pub fn column_metadata(&self, column: &str) -> Option<Metadata> {
+        let m = self.get(column)?;
+        Some(Metadata::from([
+            (SEMANTIC_TYPE_KEY.to_string(), m.semantic_type.clone()),
+            (
+                SEMANTIC_TYPE_MATCH_FRACTION_KEY.to_string(),
+                m.match_fraction.to_string(),
+            ),
+        ]))
+    }
+
+    /// Convert `schema` to arrow, with the semantic types in the metadata of the fields.
+    pub fn to_arrow_schema(&self, schema: &Schema, pl_flavor: bool) -> ArrowSchema {
+        let mut arrow_schema = schema.to_arrow(pl_flavor);
+        for field in arrow_schema.fields.iter_mut() {
+            if let Some(metadata) = self.column_metadata(&field.name) {
+                field.metadata.extend(metadata);
+            }
+        }
+        arrow_schema
+    }
+}
+
+impl FromIterator<SemanticTypeMatch> for SemanticTypes {
+    fn from_iter<I: IntoIterator<Item = SemanticTypeMatch>>(iter: I) -> Self {
+        Self {
+            matches: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// The options of a [`SemanticTypeInference`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SemanticTypeOptions {
+    /// The number of non-null values that are sampled from the start of every column.
+    pub sample_size: usize,
+    /// The fraction of the sampled values that must match a semantic type to infer it.
+    pub min_match_fraction: f64,
+    /// Validate the columns of which the semantic type is inferred, see
+    /// [`SemanticTypeDetector::validate`].
+    pub validate: bool,
+}
+
+impl Default for SemanticTypeOptions {
+    fn default() -> Self {
+        Self {
+            sample_size: 1000,
+            min_match_fraction: 0.9,
+            validate: false,
+        }
+    }
+}
+
+/// Infers the semantic types of the String columns of a frame from a sample of their values.
+///
+/// The inference detects the [built-in semantic types](SemanticType) and the types of the
+/// detectors that are added to it. A column gets the type that matches the largest fraction of
+/// its sampled values, if that fraction is at least
+/// [`min_match_fraction`](SemanticTypeOptions::min_match_fraction). Ties go to the detector that
+/// was added first, the built-in types come before the added ones.
+#[derive(Clone)]
+pub struct SemanticTypeInference {
+    detectors: Vec<Arc<dyn SemanticTypeDetector>>,
+    options: SemanticTypeOptions,
+}
+
+impl Default for SemanticTypeInference {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl SemanticTypeInference {
+    pub fn new(options: SemanticTypeOptions) -> Self {
+        Self {
+            detectors: SemanticType::ALL
+                .into_iter()
+                .map(|t| Arc::new(t) as Arc<dyn SemanticTypeDetector>)
+                .collect(),
+            options,
+        }
+    }
+
+    /// Also infer the semantic type of `detector`.
+    pub fn with_detector(mut self, detector: Arc<dyn SemanticTypeDetector>) -> Self {
+        self.detectors.push(detector);
+        self
+    }
+
+    pub fn options(&self) -> &SemanticTypeOptions {
+        &self.options
+    }
+
+    fn detector(&self, semantic_type: &str) -> PolarsResult<&Arc<dyn SemanticTypeDetector>> {
+        self.detectors
+            .iter()
+            .find(|d| d.name() == semantic_type)
+            .ok_or_else(|| polars_err!(ComputeError: "unknown semantic type '{}'", semantic_type))
+    }
+
+    /// Infer the semantic type of a String column from its values, the first
+    /// [`sample_size`](SemanticTypeOptions::sample_size) non-null values are sampled.
+    pub fn infer_column(&self, s: &Series) -> PolarsResult<Option<SemanticTypeMatch>> {
+        if s.dtype() != &DataType::String {
+            return Ok(None);
+        }
+        let sample = s.drop_nulls().head(Some(self.options.sample_size));
+        let sample = sample.str()?;
+        if sample.is_empty() {
+            return Ok(None);
+        }
+        let mut best: Option<(&Arc<dyn SemanticTypeDetector>, usize)> = None;
+        for detector in &self.detectors {
+            let n_matches = sample
+                .into_no_null_iter()
+                .filter(|value| detector.matches(value))
+                .count();
+            if best.map_or(true, |(_, best)| n_matches > best) {
+                best = Some((detector, n_matches));
+            }
+        }
+        let (detector, n_matches) = best.unwrap();
+        let match_fraction = n_matches as f64 / sample.len() as f64;
+        if n_matches == 0 || match_fraction < self.options.min_match_fraction {
+            return Ok(None);
+        }
+        Ok(Some(SemanticTypeMatch {
+            column: s.name().to_string(),
+            semantic_type: detector.name().to_string(),
+            match_fraction,
+        }))
+    }
+
+    /// Infer the semantic types of the String columns of `df`.
+    pub fn infer(&self, df: &DataFrame) -> PolarsResult<SemanticTypes> {
+        df.get_columns()
+            .iter()
+            .map(|s| self.infer_column(s))
+            .filter_map(|m| m.transpose())
+            .collect()
+    }
+
+    /// Validate a column of `semantic_type`, see [`SemanticTypeDetector::validate`].
+    pub fn validate_column(&self, s: &Series, semantic_type: &str) -> PolarsResult<Series> {
+        self.detector(semantic_type)?.validate(s.str()?)
+    }
+
+    /// The data type of a validated column of `semantic_type`.
+    pub fn validated_dtype(&self, semantic_type: &str) -> PolarsResult<DataType> {
+        Ok(self.detector(semantic_type)?.dtype())
+    }
+
+    /// Validate the columns of `df` of which the semantic type was inferred.
+    pub fn validate(&self, df: &DataFrame, types: &SemanticTypes) -> PolarsResult<DataFrame> {
+        let mut df = df.clone();
+        for m in types.iter() {
+            let s = self.validate_column(df.column(&m.column)?, &m.semantic_type)?;
+            df.with_column(s)?;
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_semantic_type_matchers() {
+        assert!(is_email("jane.doe+tag@example.co.uk"));
+        assert!(!is_email("jane@localhost"));
+        assert!(!is_email("@example.com"));
+        assert!(is_url("https://pola.rs/docs?q=1#top"));
+        assert!(is_url("http://user@127.0.0.1:8080/"));
+        assert!(!is_url("pola.rs"));
+        assert!(is_phone("+31 20 123 4567"));
+        assert!(is_phone("(555) 010-0100"));
+        assert!(!is_phone("5550100100"));
+        assert!(!is_phone("2024-01-01"));
+        assert_eq!(parse_currency("$1,234.50"), Some(1234.5));
+        assert_eq!(parse_currency("- €12"), Some(-12.0));
+        assert_eq!(parse_currency("12.5 EUR"), Some(12.5));
+        assert_eq!(parse_currency("$1,23"), None);
+        assert_eq!(parse_currency("12.5"), None);
+        assert!(SemanticType::CountryCode.matches("NL"));
+        assert!(!SemanticType::CountryCode.matches("XX"));
+    }
+
+    struct Ticker;
+
+    impl SemanticTypeDetector for Ticker {
+        fn name(&self) -> &str {
+            "ticker"
+        }
+
+        fn matches(&self, value: &str) -> bool {
+            value.starts_with('^')
+        }
+    }
+
+    #[test]
+    fn test_infer_semantic_types() -> PolarsResult<()> {
+        let df = df![
+            "email" => [Some("a@example.com"), None, Some("b@example.org"), Some("nope")],
+            "price" => ["$1.50", "$2", "€3.25", "$4"],
+            "country" => ["NL", "US", "DE", "FR"],
+            "ticker" => ["^AEX", "^GSPC", "^DJI", "^N225"],
+            "name" => ["a", "b", "c", "d"],
+            "n" => [1, 2, 3, 4],
+        ]?;
+        let options = SemanticTypeOptions {
+            min_match_fraction: 0.6,
+            ..Default::default()
+        };
+        let inference = SemanticTypeInference::new(options).with_detector(Arc::new(Ticker));
+        let types = inference.infer(&df)?;
+        let names = types
+            .iter()
+            .map(|m| (m.column.as_str(), m.semantic_type.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                ("email", "email"),
+                ("price", "currency"),
+                ("country", "country_code"),
+                ("ticker", "ticker"),
+            ]
+        );
+        let email = types.get("email").unwrap();
+        assert!((email.match_fraction - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(
+            types.column_metadata("price").unwrap()[SEMANTIC_TYPE_KEY],
+            "currency"
+        );
+
+        let out = inference.validate(&df, &types)?;
+        let expected = df![
+            "email" => [Some("a@example.com"), None, Some("b@example.org"), None],
+            "price" => [1.5, 2.0, 3.25, 4.0],
+        ]?;
+        assert!(out.select(["email", "price"])?.equals_missing(&expected));
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::series::shrink_type;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShrinkOptions {
+    /// Cast timezone-naive `Datetime` columns of which all values are at midnight to `Date`.
+    pub shrink_dates: bool,
+    /// Cast `String` columns of which the ratio of unique values to the length is at most this
+    /// threshold to `Categorical`.
+    pub categorical_threshold: Option<f64>,
+    /// Cast these `String` columns to an `Enum` of the sorted unique values instead of to
+    /// `Categorical`.
+    pub use_enum: bool,
+}
+
+#[cfg(feature = "dtype-datetime")]
+fn is_date(s: &Series, tu: TimeUnit) -> PolarsResult<bool> {
+    let units_per_day = match tu {
+        TimeUnit::Nanoseconds => 86_400_000_000_000,
+        TimeUnit::Microseconds => 86_400_000_000,
+        TimeUnit::Milliseconds => 86_400_000,
+    };
+    let ca = s.datetime()?;
+    Ok(ca.into_iter().flatten().all(|v| v % units_per_day == 0))
+}
+
+#[cfg(feature = "dtype-categorical")]
+fn shrink_string(s: Series, threshold: f64, use_enum: bool) -> PolarsResult<Series> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&threshold),
+        InvalidOperation: "`categorical_threshold` must be between 0 and 1, got {}", threshold
+    );
+    let ca = s.str()?;
+    if ca.is_empty() {
+        return Ok(s);
+    }
+    let uniques = ca.unique()?.drop_nulls();
+    if uniques.len() as f64 > threshold * ca.len() as f64 {
+        return Ok(s);
+    }
+    if use_enum {
+        let categories = uniques.sort(false).rechunk().downcast_into_array();
+        s.cast(&create_enum_data_type(categories))
+    } else {
+        s.cast(&DataType::Categorical(None, Default::default()))
+    }
+}
+
+/// Shrink the dtype of a single column, see [`ShrinkOptions`].
+pub fn shrink_column(s: Series, options: &ShrinkOptions) -> PolarsResult<Series> {
+    match s.dtype() {
+        dt if dt.is_numeric() => shrink_type(s),
+        #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
+        DataType::Datetime(tu, None) if options.shrink_dates => {
+            if is_date(&s, *tu)? {
+                s.cast(&DataType::Date)
+            } else {
+                Ok(s)
+            }
+        },
+        #[cfg(feature = "dtype-categorical")]
+        DataType::String => match options.categorical_threshold {
+            Some(threshold) => shrink_string(s, threshold, options.use_enum),
+            None => Ok(s),
+        },
+        _ => Ok(s),
+    }
+}
@@ -0,0 +1,90 @@
+use polars_core::export::rayon::prelude::*;
+use polars_core::prelude::*;
+use polars_core::POOL;
+
+use crate::series::{approx_n_unique_hashes, ApproxNUniqueState, MisraGries};
+
+/// Sketches of a column, computed in one pass over its values.
+#[derive(Clone, Debug)]
+pub struct ColumnSketch {
+    pub name: String,
+    /// The number of unique values, estimated with HyperLogLog. Null counts as a value.
+    pub estimated_cardinality: usize,
+    /// The values that may occur more than `len / (k + 1)` times, by descending count.
+    pub heavy_hitters: Series,
+    /// The lower bounds of the counts of the heavy hitters.
+    pub counts: IdxCa,
+}
+
+/// Sketch `s` with a [`MisraGries`] summary of `k` counters. The values are split in a range per
+/// thread, and the sketches of the ranges are merged.
+pub(super) fn sketch_column(s: &Series, k: usize) -> PolarsResult<ColumnSketch> {
+    let mut hashes = Vec::with_capacity(s.len());
+    approx_n_unique_hashes(s, &mut hashes)?;
+
+    let chunk_size = std::cmp::max(hashes.len().div_ceil(POOL.current_num_threads()), 1);
+    let init = || (ApproxNUniqueState::default(), MisraGries::new(k));
+    let (state, summary) = POOL.install(|| {
+        hashes
+            .par_chunks(chunk_size)
+            .enumerate()
+            .map(|(i, hashes)| {
+                let offset = i * chunk_size;
+                let (mut state, mut summary) = init();
+                for (j, &hash) in hashes.iter().enumerate() {
+                    state.add_hash(hash);
+                    if k > 0 {
+                        summary.add_hash(hash, (offset + j) as IdxSize);
+                    }
+                }
+                (state, summary)
+            })
+            .reduce(init, |mut a, b| {
+                a.0.merge(&b.0);
+                a.1.merge(&b.1);
+                a
+            })
+    });
+
+    let (rows, counts): (Vec<_>, Vec<_>) = summary.heavy_hitters().into_iter().unzip();
+    let heavy_hitters = s.take(&IdxCa::from_vec("", rows))?;
+    Ok(ColumnSketch {
+        name: s.name().to_string(),
+        estimated_cardinality: state.count(),
+        heavy_hitters,
+        counts: IdxCa::from_vec("count", counts),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::frame::DataFrameOps;
+
+    #[test]
+    fn test_sketch_columns() -> PolarsResult<()> {
+        let a = (0..10_000)
+            .map(|i| if i % 2 == 0 { 7 } else { i })
+            .collect::<Vec<i32>>();
+        let df = df![
+            "a" => a,
+            "b" => (0..10_000).map(|i| i % 10).collect::<Vec<i32>>(),
+        ]?;
+
+        let sketches = df.sketch_columns(&["a", "b"], 4)?;
+        let a = &sketches[0];
+        let expected = 5001.0;
+        assert!((a.estimated_cardinality as f64 - expected).abs() / expected < 0.05);
+        assert_eq!(a.heavy_hitters.i32()?.get(0), Some(7));
+        assert!(a.counts.get(0).unwrap() >= 5000 - 10_000 / 5);
+        assert_eq!(sketches[1].estimated_cardinality, 10);
+
+        let out = df.estimated_cardinality(&["b"])?;
+        assert_eq!(out.shape(), (1, 2));
+        assert_eq!(
+            out.column("estimated_cardinality")?.get(0)?,
+            AnyValue::from(10 as IdxSize)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use polars_core::prelude::*;
+
+use super::join::{DataFrameJoinOps, JoinArgs, JoinType, JoinValidation};
+
+const SELF_ROW: &str = "__POLARS_UPSERT_SELF";
+const OTHER_ROW: &str = "__POLARS_UPSERT_OTHER";
+
+/// A function of a column of both frames of an upsert, see [`UpsertResolve::Custom`].
+pub type UpsertFn = Arc<dyn Fn(&Series, &Series) -> PolarsResult<Series> + Send + Sync>;
+
+/// How the value of a column of a row that is in both frames of an upsert is resolved.
+#[derive(Clone, Default)]
+pub enum UpsertResolve {
+    /// The value of the other frame, also if it is null.
+    #[default]
+    TakeOther,
+    /// The value of this frame.
+    TakeSelf,
+    /// The value of the other frame, unless it is null.
+    Coalesce,
+    /// Called with the column of this frame and the column of the other frame gathered to the
+    /// rows of this frame, which is null where a row doesn't match. The result is only used for
+    /// the rows that match.
+    Custom(UpsertFn),
+}
+
+/// How the columns of the rows that are in both frames of
+/// [`upsert`](super::DataFrameOps::upsert) are resolved.
+#[derive(Clone, Default)]
+pub struct UpsertStrategy {
+    /// The resolution of the columns that are not given in `columns`.
+    pub default: UpsertResolve,
+    pub columns: PlHashMap<String, UpsertResolve>,
+}
+
+impl UpsertStrategy {
+    pub fn new(default: UpsertResolve) -> Self {
+        Self {
+            default,
+            columns: Default::default(),
+        }
+    }
+
+    /// Resolve the column `name` with `resolve` instead of the default.
+    pub fn with_column(mut self, name: &str, resolve: UpsertResolve) -> Self {
+        self.columns.insert(name.to_string(), resolve);
+        self
+    }
+
+    fn resolve(&self, name: &str) -> &UpsertResolve {
+        self.columns.get(name).unwrap_or(&self.default)
+    }
+}
+
+/// The row of `other` that every row of `df` matches on the `on` columns, if any. A key may
+/// occur at most once in `other`, null keys never match.
+fn match_rows(df: &DataFrame, other: &DataFrame, on: &[&str]) -> PolarsResult<IdxCa> {
+    let keys = df
+        .select(on.iter().copied())?
+        .with_row_index(SELF_ROW, None)?;
+    let other_keys = other
+        .select(on.iter().copied())?
+        .with_row_index(OTHER_ROW, None)?;
+    let args = JoinArgs {
+        validation: JoinValidation::ManyToOne,
+        ..JoinArgs::new(JoinType::Left)
+    };
+    let joined = keys.join(&other_keys, on.iter(), on.iter(), args)?;
+
+    let mut rows = vec![None; df.height()];
+    for (row, other_row) in joined
+        .column(SELF_ROW)?
+        .idx()?
+        .into_no_null_iter()
+        .zip(joined.column(OTHER_ROW)?.idx()?)
+    {
+        rows[row as usize] = other_row;
+    }
+    Ok(IdxCa::from_iter_options("", rows.into_iter()))
+}
+
+/// Update the rows of `df` that match a row of `other` on the `on` columns and append the rows of
+/// `other` that don't match.
+pub(super) fn upsert(
+    df: &DataFrame,
+    other: &DataFrame,
+    on: &[&str],
+    strategy: &UpsertStrategy,
+) -> PolarsResult<DataFrame> {
+    let schema = df.schema();
+    for name in other.get_column_names() {
+        polars_ensure!(
+            schema.contains(name),
+            SchemaMismatch: "column '{}' of the other frame is not in the frame that is upserted into", name
+        );
+    }
+    // Cast to the dtypes of `df`, so that the keys can be joined and the values combined.
+    let other = DataFrame::new(
+        other
+            .get_columns()
+            .iter()
+            .map(|s| s.cast(schema.get(s.name()).unwrap()))
+            .collect::<PolarsResult<Vec<_>>>()?,
+    )?;
+
+    let rows = match_rows(df, &other, on)?;
+    let matches = rows.is_not_null();
+
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let Ok(other_s) = other.column(s.name()) else {
+                return Ok(s.clone());
+            };
+            if on.contains(&s.name()) {
+                return Ok(s.clone());
+            }
+            // SAFETY: the rows are in bounds of `other`.
+            let other_s = unsafe { other_s.take_unchecked(&rows) };
+            let out = match strategy.resolve(s.name()) {
+                UpsertResolve::TakeOther => other_s.zip_with(&matches, s)?,
+                UpsertResolve::TakeSelf => s.clone(),
+                UpsertResolve::Coalesce => other_s.zip_with(&other_s.is_not_null(), s)?,
+                UpsertResolve::Custom(f) => {
+                    let out = f(s, &other_s)?;
+                    polars_ensure!(
+                        out.len() == s.len(),
+                        ShapeMismatch: "upsert function of column '{}' returned {} values, expected {}",
+                        s.name(), out.len(), s.len()
+                    );
+                    out.cast(s.dtype())?.zip_with(&matches, s)?
+                },
+            };
+            Ok(out.with_name(s.name()))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut out = DataFrame::new(columns)?;
+
+    // Append the rows of `other` that no row matched.
+    let mut matched = vec![false; other.height()];
+    for row in (&rows).into_iter().flatten() {
+        matched[row as usize] = true;
+    }
+    let unmatched = BooleanChunked::from_iter_values("", matched.into_iter().map(|m| !m));
+    let new_rows = other.filter(&unmatched)?;
+    let new_columns = df
+        .get_columns()
+        .iter()
+        .map(|s| match new_rows.column(s.name()) {
+            Ok(new_s) => new_s.clone(),
+            Err(_) => Series::full_null(s.name(), new_rows.height(), s.dtype()),
+        })
+        .collect::<Vec<_>>();
+    out.vstack_mut(&DataFrame::new(new_columns)?)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::frame::DataFrameOps;
+
+    #[test]
+    fn test_upsert() -> PolarsResult<()> {
+        let df = df![
+            "id" => [1, 2, 3],
+            "a" => [Some(1), Some(2), Some(3)],
+            "b" => ["x", "y", "z"],
+            "c" => [10, 20, 30],
+            "d" => [1.0, 2.0, 3.0]
+        ]?;
+        let other = df![
+            "id" => [Some(3), Some(4), Some(1), None],
+            "a" => [None, Some(4), Some(10), Some(5)],
+            "b" => [Some("zz"), Some("w"), None, Some("n")],
+            "c" => [300, 400, 100, 500]
+        ]?;
+
+        let add: UpsertFn = Arc::new(|s: &Series, other: &Series| s + other);
+        let strategy = UpsertStrategy::new(UpsertResolve::TakeOther)
+            .with_column("b", UpsertResolve::Coalesce)
+            .with_column("c", UpsertResolve::Custom(add));
+        let out = df.upsert(&other, &["id"], &strategy)?;
+
+        let expected = df![
+            "id" => [Some(1), Some(2), Some(3), Some(4), None],
+            "a" => [Some(10), Some(2), None, Some(4), Some(5)],
+            "b" => ["x", "y", "zz", "w", "n"],
+            "c" => [110, 20, 330, 400, 500],
+            "d" => [Some(1.0), Some(2.0), Some(3.0), None, None]
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let strategy = UpsertStrategy::new(UpsertResolve::TakeSelf);
+        let out = df.upsert(&other, &["id"], &strategy)?;
+        assert_eq!(out.column("a")?.i32()?.get(2), Some(3));
+
+        // A key may occur only once in the other frame.
+        let duplicates = df!["id" => [1, 1], "a" => [1, 2]]?;
+        assert!(df.upsert(&duplicates, &["id"], &strategy).is_err());
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@
 extern crate core;
 
 pub mod chunked_array;
+#[cfg(feature = "pandas_compat")]
+pub mod compat;
 #[cfg(feature = "pivot")]
 pub use frame::pivot;
 pub mod frame;
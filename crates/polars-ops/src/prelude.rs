@@ -5,5 +5,14 @@ pub use crate::chunked_array::*;
 #[cfg(feature = "merge_sorted")]
 pub use crate::frame::_merge_sorted_dfs;
 pub use crate::frame::join::*;
-pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
+#[cfg(feature = "approx_unique")]
+pub use crate::frame::ColumnSketch;
+#[cfg(feature = "rename_normalized")]
+pub use crate::frame::{normalize_names, NameCase, NameDedupe, NormalizeNamesOptions};
+pub use crate::frame::{shrink_column, DataFrameJoinOps, DataFrameOps, ShrinkOptions};
+#[cfg(feature = "semantic_types")]
+pub use crate::frame::{
+    SemanticType, SemanticTypeDetector, SemanticTypeInference, SemanticTypeMatch,
+    SemanticTypeOptions, SemanticTypes,
+};
 pub use crate::series::*;
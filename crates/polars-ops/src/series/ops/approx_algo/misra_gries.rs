@@ -0,0 +1,80 @@
+//! # Misra-Gries
+//!
+//! `misra_gries` contains a mergeable implementation of the Misra-Gries summary, which finds
+//! the heavy hitters of a stream: every value that occurs more than `n / (k + 1)` times in a
+//! stream of `n` values is kept by a summary of `k` counters.
+//!
+//! The summary works on the hashes of the values, the first row of every value is kept so that
+//! the value can be taken from the column afterwards.
+
+use polars_core::prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct MisraGries {
+    k: usize,
+    /// The count and the first row of every hash that is tracked.
+    counters: PlHashMap<u64, (IdxSize, IdxSize)>,
+}
+
+impl MisraGries {
+    /// Creates an empty summary with `k` counters.
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            counters: PlHashMap::with_capacity(k),
+        }
+    }
+
+    /// Adds the value with `hash`, that is found at `row`.
+    pub fn add_hash(&mut self, hash: u64, row: IdxSize) {
+        if let Some((count, _)) = self.counters.get_mut(&hash) {
+            *count += 1;
+        } else if self.counters.len() < self.k {
+            self.counters.insert(hash, (1, row));
+        } else {
+            // Every value is decremented once for every value that is dropped, so the total
+            // work is linear in the number of values.
+            self.counters.retain(|_, (count, _)| {
+                *count -= 1;
+                *count > 0
+            });
+        }
+    }
+
+    /// Merge the other summary into this one.
+    ///
+    /// The counts are added and the `k + 1`-th largest count is subtracted from all of them,
+    /// which keeps the error bound of the summary of both streams.
+    pub fn merge(&mut self, other: &Self) {
+        for (&hash, &(count, row)) in &other.counters {
+            self.counters
+                .entry(hash)
+                .and_modify(|(c, r)| {
+                    *c += count;
+                    *r = std::cmp::min(*r, row);
+                })
+                .or_insert((count, row));
+        }
+        if self.counters.len() > self.k {
+            let mut counts = self.counters.values().map(|c| c.0).collect::<Vec<_>>();
+            counts.sort_unstable_by(|a, b| b.cmp(a));
+            let cutoff = counts[self.k];
+            self.counters.retain(|_, (count, _)| {
+                *count = count.saturating_sub(cutoff);
+                *count > 0
+            });
+        }
+    }
+
+    /// The first row and the lower bound of the count of every heavy hitter, by descending
+    /// count. The count is at most `n / (k + 1)` lower than the actual count.
+    pub fn heavy_hitters(&self) -> Vec<(IdxSize, IdxSize)> {
+        let mut out = self
+            .counters
+            .values()
+            .map(|&(count, row)| (row, count))
+            .collect::<Vec<_>>();
+        out.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        out
+    }
+}
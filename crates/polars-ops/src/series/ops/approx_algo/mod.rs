@@ -1,5 +1,9 @@
 #[cfg(feature = "approx_unique")]
 mod hyperloglogplus;
+#[cfg(feature = "approx_unique")]
+mod misra_gries;
 
 #[cfg(feature = "approx_unique")]
 pub use hyperloglogplus::*;
+#[cfg(feature = "approx_unique")]
+pub use misra_gries::*;
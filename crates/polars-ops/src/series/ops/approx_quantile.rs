@@ -0,0 +1,373 @@
+use std::f64::consts::PI;
+
+use polars_core::prelude::*;
+use polars_core::POOL;
+
+/// Default accuracy of [`approx_quantile`].
+pub const DEFAULT_APPROX_QUANTILE_ACCURACY: f64 = 0.01;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Mergeable sketch to approximate quantiles, using the merging t-digest of Dunning.
+///
+/// Values are summarized by a bounded number of weighted centroids, which are small near
+/// the tails of the distribution and larger near the median. Digests built over different
+/// parts of the data can be [merged](TDigest::merge) and [serialized](TDigest::to_bytes), so
+/// partial results can be combined across threads, groups or files.
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    unmerged: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Create an empty digest that approximates the rank of a quantile up to `accuracy`.
+    pub fn new(accuracy: f64) -> Self {
+        Self::with_compression(1.0 / accuracy)
+    }
+
+    fn with_compression(compression: f64) -> Self {
+        Self {
+            compression,
+            centroids: vec![],
+            unmerged: vec![],
+            count: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Number of values that were added.
+    pub fn count(&self) -> f64 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0.0
+    }
+
+    /// Add a value. `NaN` values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.add_centroid(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    fn add_centroid(&mut self, centroid: Centroid) {
+        self.unmerged.push(centroid);
+        self.count += centroid.weight;
+        if self.unmerged.len() >= self.buffer_size() {
+            self.compress();
+        }
+    }
+
+    fn buffer_size(&self) -> usize {
+        (self.compression as usize * 5).max(64)
+    }
+
+    /// Merge the other digest into this one.
+    pub fn merge(&mut self, other: &Self) {
+        if other.is_empty() {
+            return;
+        }
+        for &centroid in other.centroids.iter().chain(&other.unmerged) {
+            self.add_centroid(centroid);
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Largest quantile the centroid starting at quantile `q0` may reach, following the
+    /// `k_1` scale function `k(q) = compression / 2π * asin(2q - 1)`.
+    fn quantile_limit(&self, q0: f64) -> f64 {
+        let k = self.compression / (2.0 * PI) * (2.0 * q0 - 1.0).asin() + 1.0;
+        if k >= self.compression / 4.0 {
+            1.0
+        } else {
+            ((k * 2.0 * PI / self.compression).sin() + 1.0) / 2.0
+        }
+    }
+
+    fn compress(&mut self) {
+        if self.unmerged.is_empty() {
+            return;
+        }
+        let mut centroids = std::mem::take(&mut self.unmerged);
+        centroids.append(&mut self.centroids);
+        centroids.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let mut out = Vec::with_capacity(centroids.len().min(self.compression as usize * 2));
+        let mut iter = centroids.into_iter();
+        let mut current = iter.next().unwrap();
+        let mut weight_so_far = 0.0;
+        let mut limit = self.quantile_limit(0.0);
+        for centroid in iter {
+            let q = (weight_so_far + current.weight + centroid.weight) / self.count;
+            if q <= limit {
+                current.weight += centroid.weight;
+                current.mean += (centroid.mean - current.mean) * centroid.weight / current.weight;
+            } else {
+                weight_so_far += current.weight;
+                out.push(current);
+                limit = self.quantile_limit(weight_so_far / self.count);
+                current = centroid;
+            }
+        }
+        out.push(current);
+        self.centroids = out;
+    }
+
+    /// Estimate the value at `quantile`, which must be between 0.0 and 1.0.
+    ///
+    /// Returns `None` if the digest is empty.
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        self.compress();
+        let centroids = &self.centroids;
+
+        // Every centroid is assumed to be centered at the middle of its cumulative weight,
+        // values in between are interpolated.
+        let rank = quantile * self.count;
+        let first = centroids[0];
+        if rank < first.weight / 2.0 {
+            let frac = rank / (first.weight / 2.0);
+            return Some(self.min + (first.mean - self.min) * frac);
+        }
+        let mut cum = 0.0;
+        for window in centroids.windows(2) {
+            let (left, right) = (window[0], window[1]);
+            let left_center = cum + left.weight / 2.0;
+            let right_center = cum + left.weight + right.weight / 2.0;
+            if rank <= right_center {
+                let frac = (rank - left_center) / (right_center - left_center);
+                return Some(left.mean + (right.mean - left.mean) * frac);
+            }
+            cum += left.weight;
+        }
+        let last = centroids[centroids.len() - 1];
+        let frac = (rank - (self.count - last.weight / 2.0)) / (last.weight / 2.0);
+        Some(last.mean + (self.max - last.mean) * frac.min(1.0))
+    }
+
+    /// Serialize the digest, see [`TDigest::from_bytes`].
+    pub fn to_bytes(&mut self) -> Vec<u8> {
+        self.compress();
+        let mut out = Vec::with_capacity(3 * 8 + self.centroids.len() * 16);
+        for v in [self.compression, self.min, self.max] {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        for c in &self.centroids {
+            out.extend_from_slice(&c.mean.to_le_bytes());
+            out.extend_from_slice(&c.weight.to_le_bytes());
+        }
+        out
+    }
+
+    /// Deserialize a digest created by [`TDigest::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() >= 24 && (bytes.len() - 24) % 16 == 0,
+            ComputeError: "invalid t-digest of {} bytes", bytes.len()
+        );
+        let mut values = bytes
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()));
+        let compression = values.next().unwrap();
+        polars_ensure!(
+            compression >= 1.0,
+            ComputeError: "invalid t-digest compression: {}", compression
+        );
+        let mut out = Self::with_compression(compression);
+        out.min = values.next().unwrap();
+        out.max = values.next().unwrap();
+        while let (Some(mean), Some(weight)) = (values.next(), values.next()) {
+            out.centroids.push(Centroid { mean, weight });
+            out.count += weight;
+        }
+        Ok(out)
+    }
+}
+
+fn check_quantile(quantile: f64) -> PolarsResult<()> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0, got {}", quantile
+    );
+    Ok(())
+}
+
+fn check_accuracy(accuracy: f64) -> PolarsResult<()> {
+    polars_ensure!(
+        accuracy > 0.0 && accuracy < 1.0,
+        ComputeError: "`accuracy` should be between 0.0 and 1.0 (exclusive), got {}", accuracy
+    );
+    Ok(())
+}
+
+fn to_float(s: &Series) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "`approx_quantile` operation not supported for dtype `{}`", s.dtype()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    Ok(s.f64().unwrap().clone())
+}
+
+fn digest_ca(ca: &Float64Chunked, accuracy: f64) -> TDigest {
+    let mut digest = TDigest::new(accuracy);
+    ca.into_iter().flatten().for_each(|v| digest.add(v));
+    digest
+}
+
+/// Approximate the value at `quantile` with a [`TDigest`], so the column doesn't have to be
+/// sorted. The rank of the returned value is off by roughly `accuracy` at most, and much
+/// less towards the tails.
+pub fn approx_quantile(s: &Series, quantile: f64, accuracy: f64) -> PolarsResult<Series> {
+    check_quantile(quantile)?;
+    check_accuracy(accuracy)?;
+    let ca = to_float(s)?;
+    let value = digest_ca(&ca, accuracy).quantile(quantile);
+    Ok(Series::new(s.name(), &[value]))
+}
+
+/// Approximate the value at `quantile` per group, see [`approx_quantile`].
+pub fn approx_quantile_groups(
+    s: &Series,
+    groups: &GroupsProxy,
+    quantile: f64,
+    accuracy: f64,
+) -> PolarsResult<Float64Chunked> {
+    check_quantile(quantile)?;
+    check_accuracy(accuracy)?;
+    let ca = to_float(s)?.rechunk();
+
+    let estimate = |group: GroupsIndicator| {
+        let mut digest = TDigest::new(accuracy);
+        match group {
+            GroupsIndicator::Idx((_, idx)) => {
+                for &i in idx.iter() {
+                    // SAFETY: group indices are in bounds.
+                    if let Some(v) = unsafe { ca.get_unchecked(i as usize) } {
+                        digest.add(v)
+                    }
+                }
+            },
+            GroupsIndicator::Slice([first, len]) => {
+                let ca = ca.slice(first as i64, len as usize);
+                ca.into_iter().flatten().for_each(|v| digest.add(v));
+            },
+        }
+        digest.quantile(quantile)
+    };
+    let out: Float64Chunked = POOL.install(|| groups.par_iter().map(estimate).collect());
+    Ok(out.with_name(s.name()))
+}
+
+/// Summarize the column in a serialized [`TDigest`], which can be combined with other
+/// sketches with [`tdigest_merge`] and queried with [`tdigest_quantile`].
+pub fn tdigest(s: &Series, accuracy: f64) -> PolarsResult<Series> {
+    check_accuracy(accuracy)?;
+    let ca = to_float(s)?;
+    let bytes = digest_ca(&ca, accuracy).to_bytes();
+    Ok(BinaryChunked::from_slice(s.name(), &[bytes.as_slice()]).into_series())
+}
+
+fn merge_digests(s: &Series) -> PolarsResult<Option<TDigest>> {
+    polars_ensure!(
+        s.dtype() == &DataType::Binary,
+        InvalidOperation: "expected serialized t-digests of dtype `binary`, got `{}`", s.dtype()
+    );
+    let mut out: Option<TDigest> = None;
+    for bytes in s.binary().unwrap().into_iter().flatten() {
+        let digest = TDigest::from_bytes(bytes)?;
+        match &mut out {
+            Some(out) => out.merge(&digest),
+            None => out = Some(digest),
+        }
+    }
+    Ok(out)
+}
+
+/// Merge a column of serialized [`TDigest`]s into a single one. Null values are ignored.
+pub fn tdigest_merge(s: &Series) -> PolarsResult<Series> {
+    let bytes = merge_digests(s)?.map(|mut digest| digest.to_bytes());
+    Ok(BinaryChunked::from_slice_options(s.name(), &[bytes.as_deref()]).into_series())
+}
+
+/// Merge a column of serialized [`TDigest`]s and approximate the value at `quantile`.
+pub fn tdigest_quantile(s: &Series, quantile: f64) -> PolarsResult<Series> {
+    check_quantile(quantile)?;
+    let value = merge_digests(s)?.and_then(|mut digest| digest.quantile(quantile));
+    Ok(Series::new(s.name(), &[value]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tdigest_quantile() {
+        let mut digest = TDigest::new(DEFAULT_APPROX_QUANTILE_ACCURACY);
+        assert_eq!(digest.quantile(0.5), None);
+        for i in 0..100_000 {
+            digest.add(i as f64);
+        }
+        assert!(digest.centroids.len() + digest.unmerged.len() < 1000);
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some(99_999.0));
+        for q in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let est = digest.quantile(q).unwrap();
+            assert!(
+                (est - q * 100_000.0).abs() < 500.0,
+                "q: {q}, estimate: {est}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_tdigest_merge_roundtrip() -> PolarsResult<()> {
+        let mut a = TDigest::new(0.01);
+        let mut b = TDigest::new(0.01);
+        for i in 0..50_000 {
+            a.add(i as f64);
+            b.add((i + 50_000) as f64);
+        }
+        let mut b = TDigest::from_bytes(&b.to_bytes())?;
+        assert_eq!(b.count(), 50_000.0);
+        a.merge(&b);
+        let est = a.quantile(0.5).unwrap();
+        assert!((est - 50_000.0).abs() < 500.0);
+        assert_eq!(b.quantile(0.0), Some(50_000.0));
+
+        assert!(TDigest::from_bytes(&[0; 10]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_approx_quantile_groups() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1.0), None, Some(3.0), Some(10.0), Some(20.0)]);
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 3], [3, 2], [1, 1]],
+            rolling: false,
+        };
+        let out = approx_quantile_groups(&s, &groups, 1.0, 0.01)?;
+        assert_eq!(Vec::from(&out), &[Some(3.0), Some(20.0), None]);
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use std::hash::Hash;
 
+use polars_core::export::ahash::RandomState;
 use polars_core::prelude::*;
-use polars_core::with_match_physical_integer_polars_type;
+use polars_core::utils::NoNull;
+use polars_core::{with_match_physical_integer_polars_type, POOL};
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 
 #[cfg(feature = "approx_unique")]
@@ -76,3 +78,160 @@ fn dispatcher(s: &Series) -> PolarsResult<Series> {
 pub fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
     dispatcher(s)
 }
+
+/// Fixed seed for [`approx_n_unique_hashes`], so that the hashes of different batches and
+/// threads can be merged into the same [`ApproxNUniqueState`].
+const HASH_SEED: RandomState = RandomState::with_seeds(
+    0x5b7e1c0a9d3f2e41_u64,
+    0x13c8f0d6a7b29e55_u64,
+    0xe2a4c6f8091b3d5f_u64,
+    0x7f61d3b5a2c4e806_u64,
+);
+
+/// Number of distinct hashes kept exactly before switching to HyperLogLog registers.
+const SPARSE_THRESHOLD: usize = 1024;
+
+/// Mergeable state to approximate the number of unique values.
+///
+/// Small states keep the distinct hashes, which is exact and cheap. Once more than
+/// [`SPARSE_THRESHOLD`] distinct hashes are seen, the state switches to a [`HyperLogLog`]
+/// sketch with a fixed memory footprint.
+#[derive(Clone, Debug, Default)]
+pub enum ApproxNUniqueState {
+    #[default]
+    Empty,
+    Sparse(PlHashSet<u64>),
+    Dense(Box<HyperLogLog<u64>>),
+}
+
+impl ApproxNUniqueState {
+    /// Add a hash produced by [`approx_n_unique_hashes`].
+    pub fn add_hash(&mut self, hash: u64) {
+        match self {
+            Self::Empty => {
+                let mut set = PlHashSet::new();
+                set.insert(hash);
+                *self = Self::Sparse(set);
+            },
+            Self::Sparse(set) => {
+                set.insert(hash);
+                if set.len() > SPARSE_THRESHOLD {
+                    self.densify();
+                }
+            },
+            Self::Dense(hll) => hll.add(&hash),
+        }
+    }
+
+    fn densify(&mut self) {
+        if let Self::Sparse(set) = self {
+            let mut hll = Box::<HyperLogLog<u64>>::default();
+            hll.extend(set.iter());
+            *self = Self::Dense(hll);
+        }
+    }
+
+    /// Merge the other state into this one.
+    pub fn merge(&mut self, other: &Self) {
+        match (&mut *self, other) {
+            (_, Self::Empty) => {},
+            (Self::Empty, other) => *self = other.clone(),
+            (Self::Dense(hll), Self::Dense(other)) => hll.merge(other),
+            (Self::Dense(hll), Self::Sparse(other)) => hll.extend(other.iter()),
+            (Self::Sparse(_), Self::Sparse(other)) => {
+                for &hash in other {
+                    self.add_hash(hash)
+                }
+            },
+            (Self::Sparse(set), Self::Dense(other)) => {
+                let mut hll = other.clone();
+                hll.extend(set.iter());
+                *self = Self::Dense(hll);
+            },
+        }
+    }
+
+    /// Estimate the number of unique values that were added.
+    pub fn count(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::Sparse(set) => set.len(),
+            Self::Dense(hll) => hll.count(),
+        }
+    }
+}
+
+/// Hash the values of `s` into `buf` so that they can be added to an
+/// [`ApproxNUniqueState`]. Null values are hashed as well and count as a unique value.
+pub fn approx_n_unique_hashes(s: &Series, buf: &mut Vec<u64>) -> PolarsResult<()> {
+    let s = s.to_physical_repr();
+    s.vec_hash(HASH_SEED, buf)
+}
+
+/// Approx count unique values per group.
+///
+/// Every group is estimated with an [`ApproxNUniqueState`], so no per-group hash set of
+/// all values has to be built.
+pub fn approx_n_unique_groups(s: &Series, groups: &GroupsProxy) -> PolarsResult<IdxCa> {
+    let mut hashes = Vec::with_capacity(s.len());
+    approx_n_unique_hashes(s, &mut hashes)?;
+
+    let count = |group: GroupsIndicator| {
+        let mut state = ApproxNUniqueState::default();
+        match group {
+            GroupsIndicator::Idx((_, idx)) => {
+                for &i in idx.iter() {
+                    state.add_hash(hashes[i as usize]);
+                }
+            },
+            GroupsIndicator::Slice([first, len]) => {
+                for &hash in &hashes[first as usize..(first + len) as usize] {
+                    state.add_hash(hash);
+                }
+            },
+        }
+        state.count() as IdxSize
+    };
+    let out: NoNull<IdxCa> = POOL.install(|| groups.par_iter().map(count).collect());
+    Ok(out.into_inner().with_name(s.name()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_approx_n_unique_state_merge() -> PolarsResult<()> {
+        let mut hashes = vec![];
+        let s = Series::new("a", (0..5000).map(|i| i % 2500).collect::<Vec<i32>>());
+        approx_n_unique_hashes(&s, &mut hashes)?;
+
+        let mut small = ApproxNUniqueState::default();
+        hashes[..10].iter().for_each(|&h| small.add_hash(h));
+        assert_eq!(small.count(), 10);
+
+        let (left, right) = hashes.split_at(1500);
+        let mut a = ApproxNUniqueState::default();
+        let mut b = ApproxNUniqueState::default();
+        left.iter().for_each(|&h| a.add_hash(h));
+        right.iter().for_each(|&h| b.add_hash(h));
+        assert!(matches!(a, ApproxNUniqueState::Dense(_)));
+        a.merge(&b);
+        a.merge(&small);
+        let count = a.count() as f64;
+        assert!((count - 2500.0).abs() / 2500.0 < 0.05);
+        Ok(())
+    }
+
+    #[test]
+    fn test_approx_n_unique_groups() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1), Some(1), None, Some(2), Some(3), Some(3)]);
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 3], [3, 3]],
+            rolling: false,
+        };
+        let out = approx_n_unique_groups(&s, &groups)?;
+        assert_eq!(Vec::from(&out), &[Some(2), Some(2)]);
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
 use argminmax::ArgMinMax;
-use arrow::array::Array;
+use arrow::array::{Array, PrimitiveArray};
 use arrow::legacy::bit_util::*;
 use polars_core::chunked_array::ops::float_sorted_arg_max::{
     float_arg_max_sorted_ascending, float_arg_max_sorted_descending,
 };
 use polars_core::series::IsSorted;
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_core::{with_match_physical_numeric_polars_type, POOL};
+use polars_utils::total_ord::TotalOrd;
+use rayon::prelude::*;
 
 use super::*;
 
@@ -112,6 +114,81 @@ impl ArgAgg for Series {
     }
 }
 
+/// Get the index of the minimal (`max == false`) or maximal value of every group, or `None` if a
+/// group has no valid values. Ties resolve to the first occurrence, like [`ArgAgg`].
+///
+/// This is a single pass over the groups, so it doesn't need to sort or materialize them.
+pub fn arg_min_max_groups(s: &Series, groups: &GroupsProxy, max: bool) -> IdxCa {
+    let out: IdxCa = if s.dtype().is_numeric() || s.dtype().is_temporal() {
+        let s = s.to_physical_repr().rechunk();
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            let arr = ca.downcast_iter().next().unwrap();
+            POOL.install(|| {
+                groups
+                    .par_iter()
+                    .map(|group| arg_min_max_primitive_group(arr, group, max))
+                    .collect()
+            })
+        })
+    } else {
+        POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|group| match group {
+                    GroupsIndicator::Idx((_, idx)) => {
+                        // SAFETY: group indices are in bounds.
+                        let group = unsafe { s.take_slice_unchecked(idx) };
+                        let i = if max {
+                            group.arg_max()
+                        } else {
+                            group.arg_min()
+                        }?;
+                        Some(idx[i])
+                    },
+                    GroupsIndicator::Slice([first, len]) => {
+                        let group = s.slice(first as i64, len as usize);
+                        let i = if max {
+                            group.arg_max()
+                        } else {
+                            group.arg_min()
+                        }?;
+                        Some(first + i as IdxSize)
+                    },
+                })
+                .collect()
+        })
+    };
+    out.with_name(s.name())
+}
+
+fn arg_min_max_primitive_group<T: NumericNative>(
+    arr: &PrimitiveArray<T>,
+    group: GroupsIndicator,
+    max: bool,
+) -> Option<IdxSize> {
+    let mut best: Option<(IdxSize, T)> = None;
+    let mut visit = |i: IdxSize| {
+        if !arr.is_valid(i as usize) {
+            return;
+        }
+        let v = arr.value(i as usize);
+        let better = match best {
+            None => true,
+            Some((_, b)) if max => v.tot_gt(&b),
+            Some((_, b)) => v.tot_lt(&b),
+        };
+        if better {
+            best = Some((i, v));
+        }
+    };
+    match group {
+        GroupsIndicator::Idx((_, idx)) => idx.iter().for_each(|&i| visit(i)),
+        GroupsIndicator::Slice([first, len]) => (first..first + len).for_each(visit),
+    }
+    best.map(|(i, _)| i)
+}
+
 fn arg_max_numeric_dispatch<T>(ca: &ChunkedArray<T>) -> Option<usize>
 where
     T: PolarsNumericType,
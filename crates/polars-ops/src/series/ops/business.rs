@@ -265,6 +265,53 @@ pub fn add_business_days(
     Ok(out.into_date().into_series())
 }
 
+/// Check whether the given dates are business days.
+///
+/// # Arguments
+/// - `dates`: Series holding dates or datetimes.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+pub fn is_business_day(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+
+    let dates = match dates.dtype() {
+        DataType::Date => dates.clone(),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, None) => dates.cast(&DataType::Date)?,
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(_)) => replace_time_zone(
+            dates.datetime().unwrap(),
+            None,
+            &StringChunked::from_iter(std::iter::once("raise")),
+            NonExistent::Raise,
+        )?
+        .cast(&DataType::Date)?,
+        _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", dates.dtype()),
+    };
+
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let out: BooleanChunked = dates.date()?.apply_values_generic(|date| {
+        // SAFETY: week_mask is length 7, get_day_of_week result is between 0 and 6
+        unsafe { *week_mask.get_unchecked(get_day_of_week(date)) }
+        &&holidays.binary_search(&date).is_err()
+    });
+    Ok(out.into_series())
+}
+
+/// Convert a Series of holidays to the number of days since the UNIX epoch, as expected by the
+/// business day functions. The Series is cast to `Date` and null values are ignored.
+pub fn holidays_from_series(holidays: &Series) -> PolarsResult<Vec<i32>> {
+    let holidays = holidays.cast(&DataType::Date)?;
+    Ok(holidays.date()?.into_iter().flatten().collect())
+}
+
 /// Ported from:
 /// https://github.com/numpy/numpy/blob/e59c074842e3f73483afa5ddef031e856b9fd313/numpy/_core/src/multiarray/datetime_busday.c#L265-L353
 fn add_business_days_impl(
@@ -392,3 +439,35 @@ fn decrement_day_of_week(x: usize) -> usize {
         x - 1
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_business_day() -> PolarsResult<()> {
+        // 2024-01-01 is a Monday, 2024-01-06 a Saturday.
+        let dates = Int32Chunked::new("dates", &[Some(19723), Some(19724), Some(19728), None])
+            .into_date()
+            .into_series();
+        let holidays = Int32Chunked::new("holidays", &[19724])
+            .into_date()
+            .into_series();
+        let holidays = holidays_from_series(&holidays)?;
+
+        let week_mask = [true, true, true, true, true, false, false];
+        let out = is_business_day(&dates, week_mask, &holidays)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[Some(true), Some(false), Some(false), None]
+        );
+
+        let week_mask = [true, true, true, true, true, true, false];
+        let out = is_business_day(&dates, week_mask, &[])?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[Some(true), Some(true), Some(true), None]
+        );
+        Ok(())
+    }
+}
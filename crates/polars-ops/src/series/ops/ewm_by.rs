@@ -211,3 +211,177 @@ where
         value
     }
 }
+
+/// Exponentially-weighted variance of `s`, with the weights decaying by half every
+/// `half_life` of `times`.
+///
+/// If `bias` is `false` the estimate is corrected for the effective number of
+/// observations. The state is updated online so that the result is stable for long and
+/// irregularly sampled series.
+pub fn ewm_var_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    bias: bool,
+    times_is_sorted: bool,
+) -> PolarsResult<Series> {
+    fn func<T>(
+        values: &ChunkedArray<T>,
+        times: &Int64Chunked,
+        half_life: i64,
+        bias: bool,
+        times_is_sorted: bool,
+    ) -> PolarsResult<Series>
+    where
+        T: PolarsFloatType,
+        T::Native: Float + Zero + One,
+        ChunkedArray<T>: IntoSeries,
+    {
+        if times_is_sorted {
+            Ok(ewm_var_by_impl(values, times, half_life, bias, None).into_series())
+        } else {
+            let sorting_indices = times.arg_sort(Default::default());
+            let sorted_values = unsafe { values.take_unchecked(&sorting_indices) };
+            let sorted_times = unsafe { times.take_unchecked(&sorting_indices) };
+            let sorting_indices = sorting_indices
+                .cont_slice()
+                .expect("`arg_sort` should have returned a single chunk");
+            Ok(ewm_var_by_impl(
+                &sorted_values,
+                &sorted_times,
+                half_life,
+                bias,
+                Some(sorting_indices),
+            )
+            .into_series())
+        }
+    }
+
+    match (s.dtype(), times.dtype()) {
+        (DataType::Float64, DataType::Int64) => func(
+            s.f64().unwrap(),
+            times.i64().unwrap(),
+            half_life,
+            bias,
+            times_is_sorted,
+        ),
+        (DataType::Float32, DataType::Int64) => func(
+            s.f32().unwrap(),
+            times.i64().unwrap(),
+            half_life,
+            bias,
+            times_is_sorted,
+        ),
+        #[cfg(feature = "dtype-datetime")]
+        (_, DataType::Datetime(time_unit, _)) => {
+            let half_life = adjust_half_life_to_time_unit(half_life, time_unit);
+            ewm_var_by(
+                s,
+                &times.cast(&DataType::Int64)?,
+                half_life,
+                bias,
+                times_is_sorted,
+            )
+        },
+        #[cfg(feature = "dtype-date")]
+        (_, DataType::Date) => ewm_var_by(
+            s,
+            &times.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+            half_life,
+            bias,
+            times_is_sorted,
+        ),
+        (_, DataType::UInt64 | DataType::UInt32 | DataType::Int32) => ewm_var_by(
+            s,
+            &times.cast(&DataType::Int64)?,
+            half_life,
+            bias,
+            times_is_sorted,
+        ),
+        (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => ewm_var_by(
+            &s.cast(&DataType::Float64)?,
+            times,
+            half_life,
+            bias,
+            times_is_sorted,
+        ),
+        _ => {
+            polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
+                Int64, Int32, UInt64, UInt32, and `by` to be Date, Datetime, Int64, Int32, \
+                UInt64, or UInt32")
+        },
+    }
+}
+
+/// Exponentially-weighted standard deviation, see [`ewm_var_by`].
+pub fn ewm_std_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    bias: bool,
+    times_is_sorted: bool,
+) -> PolarsResult<Series> {
+    let var = ewm_var_by(s, times, half_life, bias, times_is_sorted)?;
+    match var.dtype() {
+        DataType::Float32 => Ok(var.f32().unwrap().apply_values(|v| v.sqrt()).into_series()),
+        _ => Ok(var.f64().unwrap().apply_values(|v| v.sqrt()).into_series()),
+    }
+}
+
+/// `values` and `times` must be sorted by `times`. If they were sorted on behalf of the
+/// user, `sorting_indices` maps every row back to its original position.
+fn ewm_var_by_impl<T>(
+    values: &ChunkedArray<T>,
+    times: &Int64Chunked,
+    half_life: i64,
+    bias: bool,
+    sorting_indices: Option<&[IdxSize]>,
+) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float + Zero + One,
+{
+    let half = T::Native::from_f64(0.5).unwrap();
+    let half_life = T::Native::from_i64(half_life).unwrap();
+
+    let mut out: Vec<Option<T::Native>> = vec![None; times.len()];
+    // `(prev_time, mean, var, sum of the squared normalized weights)`
+    let mut state: Option<(i64, T::Native, T::Native, T::Native)> = None;
+    for (idx, (value, time)) in values.iter().zip(times.iter()).enumerate() {
+        let (Some(value), Some(time)) = (value, time) else {
+            continue;
+        };
+        let result = match state {
+            None => {
+                state = Some((time, value, T::Native::zero(), T::Native::one()));
+                Some(T::Native::zero())
+            },
+            Some((prev_time, mean, var, sum_wt2)) => {
+                // equivalent to: alpha = 1 - exp(-delta_time*ln(2) / half_life)
+                let one_minus_alpha =
+                    half.powf(T::Native::from_i64(time - prev_time).unwrap() / half_life);
+                let alpha = T::Native::one() - one_minus_alpha;
+
+                let diff = value - mean;
+                let increment = alpha * diff;
+                let mean = mean + increment;
+                let var = one_minus_alpha * (var + diff * increment);
+                let sum_wt2 = one_minus_alpha * one_minus_alpha * sum_wt2 + alpha * alpha;
+                state = Some((time, mean, var, sum_wt2));
+
+                if bias {
+                    Some(var)
+                } else {
+                    let denominator = T::Native::one() - sum_wt2;
+                    (denominator > T::Native::zero()).then(|| var / denominator)
+                }
+            },
+        };
+        let out_idx = match sorting_indices {
+            Some(sorting_indices) => sorting_indices[idx] as usize,
+            None => idx,
+        };
+        out[out_idx] = result;
+    }
+    ChunkedArray::from_iter_options(values.name(), out.into_iter())
+}
@@ -0,0 +1,123 @@
+use polars_core::prelude::*;
+
+fn to_float64(s: &Series) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "expected a numeric input, got {}", s.dtype()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    Ok(s.f64()?.clone())
+}
+
+/// Compute the per-period returns of a price series.
+///
+/// If `log` is `true` the log returns `ln(p_t / p_{t-1})` are computed, otherwise the
+/// simple returns `p_t / p_{t-1} - 1`. The first element is always null. Unlike
+/// `pct_change`, nulls are not forward filled: a return is null whenever the current
+/// or the previous price is null.
+pub fn pct_returns(s: &Series, log: bool) -> PolarsResult<Series> {
+    let ca = to_float64(s)?;
+
+    let mut prev: Option<f64> = None;
+    let out: Float64Chunked = ca
+        .iter()
+        .map(|current| {
+            let out = match (prev, current) {
+                (Some(prev), Some(current)) => {
+                    let ratio = current / prev;
+                    Some(if log { ratio.ln() } else { ratio - 1.0 })
+                },
+                _ => None,
+            };
+            prev = current;
+            out
+        })
+        .collect_trusted();
+    Ok(out.with_name(s.name()).into_series())
+}
+
+/// Compute the maximum drawdown of a price series.
+///
+/// The drawdown at every point is `p_t / max(p_0..=p_t) - 1`, so the result is a value
+/// in `[-1, 0]`. Nulls are skipped. Returns null if there are no valid values.
+pub fn max_drawdown(s: &Series) -> PolarsResult<Series> {
+    let ca = to_float64(s)?;
+
+    let mut peak = f64::NEG_INFINITY;
+    let mut out: Option<f64> = None;
+    for v in ca.iter().flatten() {
+        peak = peak.max(v);
+        let drawdown = v / peak - 1.0;
+        out = Some(match out {
+            Some(out) => out.min(drawdown),
+            None => drawdown,
+        });
+    }
+    Ok(Float64Chunked::from_slice_options(s.name(), &[out]).into_series())
+}
+
+/// Compute the annualized Sharpe ratio of a series of per-period returns.
+///
+/// `risk_free_rate` is the risk free rate per period and `periods` the number of periods
+/// per year used to annualize the ratio. Nulls are skipped. Returns null if there are
+/// fewer than two valid values or if the excess returns have no variance.
+pub fn sharpe(s: &Series, risk_free_rate: f64, periods: f64) -> PolarsResult<Series> {
+    let ca = to_float64(s)?;
+
+    let excess = ca.apply_values(|v| v - risk_free_rate);
+    let out = match (excess.mean(), excess.std(1)) {
+        (Some(mean), Some(std)) if std > 0.0 => Some(mean / std * periods.sqrt()),
+        _ => None,
+    };
+    Ok(Float64Chunked::from_slice_options(s.name(), &[out]).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pct_returns() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[Some(100.0), Some(110.0), None, Some(121.0), Some(60.5)],
+        );
+
+        let out = pct_returns(&s, false)?;
+        let out = out.f64()?;
+        assert_eq!(out.get(0), None);
+        assert!((out.get(1).unwrap() - 0.1).abs() < 1e-12);
+        assert_eq!(out.get(2), None);
+        assert_eq!(out.get(3), None);
+        assert!((out.get(4).unwrap() + 0.5).abs() < 1e-12);
+
+        let out = pct_returns(&s, true)?;
+        assert!((out.f64()?.get(1).unwrap() - 1.1f64.ln()).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_drawdown() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[Some(100.0), Some(120.0), None, Some(90.0), Some(130.0)],
+        );
+        let out = max_drawdown(&s)?;
+        assert!((out.f64()?.get(0).unwrap() + 0.25).abs() < 1e-12);
+
+        let s = Series::new("a", &[None::<f64>, None]);
+        assert_eq!(max_drawdown(&s)?.f64()?.get(0), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sharpe() -> PolarsResult<()> {
+        let s = Series::new("a", &[0.01, 0.02, 0.03]);
+        let out = sharpe(&s, 0.0, 4.0)?;
+        assert!((out.f64()?.get(0).unwrap() - 4.0).abs() < 1e-12);
+
+        let s = Series::new("a", &[0.01, 0.01]);
+        assert_eq!(sharpe(&s, 0.0, 252.0)?.f64()?.get(0), None);
+        Ok(())
+    }
+}
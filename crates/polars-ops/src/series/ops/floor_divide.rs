@@ -1,6 +1,8 @@
 use polars_compute::arithmetic::ArithmeticKernel;
 use polars_core::chunked_array::ops::arity::apply_binary_kernel_broadcast;
 use polars_core::prelude::*;
+#[cfg(feature = "dtype-array")]
+use polars_core::series::arithmetic::_array_arithmetic;
 #[cfg(feature = "dtype-struct")]
 use polars_core::series::arithmetic::_struct_arithmetic;
 use polars_core::with_match_physical_numeric_polars_type;
@@ -24,6 +26,10 @@ pub fn floor_div_series(a: &Series, b: &Series) -> PolarsResult<Series> {
         (DataType::Struct(_), DataType::Struct(_)) => {
             return _struct_arithmetic(a, b, floor_div_series);
         },
+        #[cfg(feature = "dtype-array")]
+        (DataType::Array(..), _) | (_, DataType::Array(..)) => {
+            return _array_arithmetic(a, b, floor_div_series);
+        },
         _ => {},
     }
 
@@ -0,0 +1,144 @@
+use polars_core::prelude::*;
+
+use super::InterpolationMethod;
+
+/// Collect the non-null values of `y` with their position in `x`, sorted by position.
+/// Of multiple values at the same position only the first is kept.
+fn knots(y: &Float64Chunked, x: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let mut knots = y
+        .iter()
+        .zip(x)
+        .filter_map(|(y, x)| Some((*x, y?)).filter(|(x, _)| !x.is_nan()))
+        .collect::<Vec<_>>();
+    // Stable sort, so that `dedup_by` keeps the first value.
+    knots.sort_by(|a, b| a.0.total_cmp(&b.0));
+    knots.dedup_by(|a, b| a.0 == b.0);
+    knots.into_iter().unzip()
+}
+
+/// Slopes at the knots of a natural cubic spline (zero second derivative at both ends).
+fn spline_slopes(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    if n < 3 {
+        let slope = if n == 2 {
+            (ys[1] - ys[0]) / (xs[1] - xs[0])
+        } else {
+            0.0
+        };
+        return vec![slope; n];
+    }
+    let h = xs.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+    let delta = (0..n - 1)
+        .map(|i| (ys[i + 1] - ys[i]) / h[i])
+        .collect::<Vec<_>>();
+
+    // Solve the tridiagonal system for the second derivatives `m` with the Thomas algorithm.
+    let mut diag = vec![0.0; n];
+    let mut rhs = vec![0.0; n];
+    for i in 1..n - 1 {
+        diag[i] = 2.0 * (h[i - 1] + h[i]);
+        rhs[i] = 6.0 * (delta[i] - delta[i - 1]);
+        if i > 1 {
+            let w = h[i - 1] / diag[i - 1];
+            diag[i] -= w * h[i - 1];
+            rhs[i] -= w * rhs[i - 1];
+        }
+    }
+    let mut m = vec![0.0; n];
+    for i in (1..n - 1).rev() {
+        m[i] = (rhs[i] - h[i] * m[i + 1]) / diag[i];
+    }
+
+    let mut slopes = (0..n - 1)
+        .map(|i| delta[i] - h[i] * (2.0 * m[i] + m[i + 1]) / 6.0)
+        .collect::<Vec<_>>();
+    slopes.push(delta[n - 2] + h[n - 2] * (m[n - 2] + 2.0 * m[n - 1]) / 6.0);
+    slopes
+}
+
+/// Slopes at the knots of a monotonicity preserving cubic (Fritsch-Carlson, as in scipy's
+/// `PchipInterpolator`).
+fn pchip_slopes(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    if n < 3 {
+        return spline_slopes(xs, ys);
+    }
+    let h = xs.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+    let delta = (0..n - 1)
+        .map(|i| (ys[i + 1] - ys[i]) / h[i])
+        .collect::<Vec<_>>();
+
+    let mut slopes = vec![0.0; n];
+    for i in 1..n - 1 {
+        // Local extrema and flat segments get a zero slope, otherwise take the weighted
+        // harmonic mean of the neighbouring secants.
+        if delta[i - 1] * delta[i] > 0.0 {
+            let w1 = 2.0 * h[i] + h[i - 1];
+            let w2 = h[i] + 2.0 * h[i - 1];
+            slopes[i] = (w1 + w2) / (w1 / delta[i - 1] + w2 / delta[i]);
+        }
+    }
+
+    let end_slope = |h0: f64, h1: f64, d0: f64, d1: f64| {
+        let slope = ((2.0 * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+        if slope.signum() != d0.signum() {
+            0.0
+        } else if d0.signum() != d1.signum() && slope.abs() > 3.0 * d0.abs() {
+            3.0 * d0
+        } else {
+            slope
+        }
+    };
+    slopes[0] = end_slope(h[0], h[1], delta[0], delta[1]);
+    slopes[n - 1] = end_slope(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+    slopes
+}
+
+/// Evaluate the cubic Hermite interpolant through `(xs, ys)` with the given slopes at `x`.
+fn hermite(xs: &[f64], ys: &[f64], slopes: &[f64], x: f64) -> f64 {
+    if xs.len() == 1 {
+        return ys[0];
+    }
+    let k = xs
+        .partition_point(|v| *v <= x)
+        .saturating_sub(1)
+        .min(xs.len() - 2);
+    let h = xs[k + 1] - xs[k];
+    let t = (x - xs[k]) / h;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (2.0 * t3 - 3.0 * t2 + 1.0) * ys[k]
+        + (t3 - 2.0 * t2 + t) * h * slopes[k]
+        + (3.0 * t2 - 2.0 * t3) * ys[k + 1]
+        + (t3 - t2) * h * slopes[k + 1]
+}
+
+/// Fill the null values of `y` by evaluating a piecewise cubic through all non-null values,
+/// placed at the positions given by `x`.
+///
+/// Like the other interpolation methods, nulls outside of the range of the known values are
+/// not extrapolated.
+pub(super) fn interpolate_cubic(
+    y: &Float64Chunked,
+    x: &[f64],
+    method: InterpolationMethod,
+) -> Float64Chunked {
+    if !y.has_validity() || y.null_count() == y.len() {
+        return y.clone();
+    }
+    let (xs, ys) = knots(y, x);
+    if xs.is_empty() {
+        return y.clone();
+    }
+    let slopes = match method {
+        InterpolationMethod::Pchip => pchip_slopes(&xs, &ys),
+        _ => spline_slopes(&xs, &ys),
+    };
+    let (low, high) = (xs[0], xs[xs.len() - 1]);
+
+    let out = y.iter().zip(x).map(|(opt_v, x)| match opt_v {
+        None if low <= *x && *x <= high => Some(hermite(&xs, &ys, &slopes, *x)),
+        opt_v => opt_v,
+    });
+    Float64Chunked::from_iter_options(y.name(), out)
+}
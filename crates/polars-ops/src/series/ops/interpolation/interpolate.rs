@@ -5,10 +5,8 @@ use arrow::bitmap::MutableBitmap;
 use polars_core::downcast_as_macro_arg_physical;
 use polars_core::export::num::{NumCast, Zero};
 use polars_core::prelude::*;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 
-use super::{linear_itp, nearest_itp};
+use super::{interpolate_cubic, linear_itp, nearest_itp, InterpolationMethod};
 
 fn near_interp<T>(low: T, high: T, steps: IdxSize, steps_n: T, out: &mut Vec<T>)
 where
@@ -191,17 +189,29 @@ where
     interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum InterpolationMethod {
-    Linear,
-    Nearest,
+fn interpolate_cubic_rows(s: &Series, method: InterpolationMethod) -> Series {
+    let logical = s.dtype();
+    if !logical.is_numeric() && !logical.is_temporal() {
+        return s.clone();
+    }
+    let physical = s.to_physical_repr();
+    let y = physical.cast(&DataType::Float64).unwrap();
+    let x = (0..s.len()).map(|i| i as f64).collect::<Vec<_>>();
+    let out = interpolate_cubic(y.f64().unwrap(), &x, method).into_series();
+    match logical {
+        DataType::Float32 => out.cast(&DataType::Float32).unwrap(),
+        dt if dt.is_temporal() => out.cast(physical.dtype()).unwrap().cast(logical).unwrap(),
+        _ => out,
+    }
 }
 
 pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
     match method {
         InterpolationMethod::Linear => interpolate_linear(s),
         InterpolationMethod::Nearest => interpolate_nearest(s),
+        InterpolationMethod::CubicSpline | InterpolationMethod::Pchip => {
+            interpolate_cubic_rows(s, method)
+        },
     }
 }
 
@@ -251,6 +261,49 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_interpolate_cubic() {
+        let ca = Int32Chunked::new("", &[Some(0), None, Some(8), None, Some(64), None]);
+        let s = ca.into_series();
+
+        let out = interpolate(&s, InterpolationMethod::CubicSpline);
+        let out = out.f64().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[
+                Some(0.0),
+                Some(-0.5),
+                Some(8.0),
+                Some(31.5),
+                Some(64.0),
+                None
+            ]
+        );
+
+        let out = interpolate(&s, InterpolationMethod::Pchip);
+        let out = out.f64().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[
+                Some(0.0),
+                Some(2.25),
+                Some(8.0),
+                Some(27.75),
+                Some(64.0),
+                None
+            ]
+        );
+
+        // Values on a line are reproduced exactly.
+        let ca = Float64Chunked::new("", &[Some(1.0), None, None, Some(4.0), Some(5.0)]);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::CubicSpline);
+        let out = out.f64().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0)]
+        );
+    }
+
     #[test]
     fn test_interpolate_decreasing_unsigned() {
         let ca = UInt32Chunked::new("", &[Some(4), None, None, Some(1)]);
@@ -7,7 +7,7 @@ use polars_core::export::num::{NumCast, Zero};
 use polars_core::prelude::*;
 use polars_utils::slice::SliceAble;
 
-use super::linear_itp;
+use super::{interpolate_cubic, linear_itp, InterpolationMethod};
 
 /// # Safety
 /// - `x` must be non-empty.
@@ -263,9 +263,7 @@ where
     }
 }
 
-pub fn interpolate_by(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResult<Series> {
-    polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
-
+fn interpolate_linear_by(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResult<Series> {
     fn func<T, F>(
         ca: &ChunkedArray<T>,
         by: &ChunkedArray<F>,
@@ -315,13 +313,15 @@ pub fn interpolate_by(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResu
             func(s.f32().unwrap(), by.u32().unwrap(), by_is_sorted)
         },
         #[cfg(feature = "dtype-date")]
-        (_, DataType::Date) => interpolate_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted),
+        (_, DataType::Date) => {
+            interpolate_linear_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted)
+        },
         #[cfg(feature = "dtype-datetime")]
         (_, DataType::Datetime(_, _)) => {
-            interpolate_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
+            interpolate_linear_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
         },
         (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
-            interpolate_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
+            interpolate_linear_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
         },
         _ => {
             polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
@@ -330,3 +330,136 @@ pub fn interpolate_by(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResu
         },
     }
 }
+
+/// Positions of the rows as given by the `by` column.
+fn by_positions(by: &Series) -> PolarsResult<Vec<f64>> {
+    polars_ensure!(by.null_count() == 0, InvalidOperation: "null values in `by` column are not yet supported in 'interpolate_by' expression");
+    polars_ensure!(
+        by.dtype().to_physical().is_numeric(),
+        InvalidOperation: "expected `by` to be numeric, Date or Datetime, got {}", by.dtype()
+    );
+    let by = by.to_physical_repr().cast(&DataType::Float64)?;
+    Ok(by.f64().unwrap().into_no_null_iter().collect())
+}
+
+/// Take every null from the non-null row with the nearest `by` value; ties go to the row
+/// with the larger `by` value.
+fn interpolate_nearest_by(s: &Series, by: &Series) -> PolarsResult<Series> {
+    if !s.has_validity() || s.null_count() == s.len() {
+        return Ok(s.clone());
+    }
+    let x = by_positions(by)?;
+    let validity = s.is_not_null();
+
+    let mut knots = validity
+        .into_no_null_iter()
+        .zip(&x)
+        .enumerate()
+        .filter_map(|(idx, (valid, x))| (valid && !x.is_nan()).then_some((*x, idx as IdxSize)))
+        .collect::<Vec<_>>();
+    if knots.is_empty() {
+        return Ok(s.clone());
+    }
+    knots.sort_by(|a, b| a.0.total_cmp(&b.0));
+    knots.dedup_by(|a, b| a.0 == b.0);
+    let (low, high) = (knots[0].0, knots[knots.len() - 1].0);
+
+    let idx = validity
+        .into_no_null_iter()
+        .zip(&x)
+        .enumerate()
+        .map(|(idx, (valid, x))| {
+            if valid {
+                return Some(idx as IdxSize);
+            }
+            if !(low <= *x && *x <= high) {
+                return None;
+            }
+            // `k >= 1` as `x` is not smaller than the first knot.
+            let k = knots.partition_point(|(v, _)| *v <= *x);
+            if k == knots.len() {
+                return Some(knots[k - 1].1);
+            }
+            let (x_low, idx_low) = knots[k - 1];
+            let (x_high, idx_high) = knots[k];
+            if *x - x_low < x_high - *x {
+                Some(idx_low)
+            } else {
+                Some(idx_high)
+            }
+        });
+    let idx = IdxCa::from_iter_options(s.name(), idx);
+    s.take(&idx)
+}
+
+fn interpolate_cubic_by(
+    s: &Series,
+    by: &Series,
+    method: InterpolationMethod,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "expected series to be numeric for '{:?}' interpolation, got {}", method, s.dtype()
+    );
+    let x = by_positions(by)?;
+    let y = s.cast(&DataType::Float64)?;
+    let out = interpolate_cubic(y.f64().unwrap(), &x, method).into_series();
+    match s.dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
+
+pub fn interpolate_by(
+    s: &Series,
+    by: &Series,
+    by_is_sorted: bool,
+    method: InterpolationMethod,
+) -> PolarsResult<Series> {
+    polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
+
+    match method {
+        InterpolationMethod::Linear => interpolate_linear_by(s, by, by_is_sorted),
+        InterpolationMethod::Nearest => interpolate_nearest_by(s, by),
+        InterpolationMethod::CubicSpline | InterpolationMethod::Pchip => {
+            interpolate_cubic_by(s, by, method)
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interpolate_by_methods() {
+        let s = Series::new("a", &[Some(1i64), None, None, Some(10), None]);
+        let by = Series::new("by", &[0i64, 1, 4, 5, 6]);
+
+        let out = interpolate_by(&s, &by, true, InterpolationMethod::Nearest).unwrap();
+        assert_eq!(out.dtype(), &DataType::Int64);
+        let out = out.i64().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[Some(1), Some(1), Some(10), Some(10), None]
+        );
+
+        // Two known values: all methods reduce to a straight line.
+        let out = interpolate_by(&s, &by, true, InterpolationMethod::Pchip).unwrap();
+        let out = out.f64().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[Some(1.0), Some(2.8), Some(8.2), Some(10.0), None]
+        );
+
+        // Unsorted `by`.
+        let s = Series::new("a", &[None, Some(8.0f64), Some(0.0), None, Some(64.0)]);
+        let by = Series::new("by", &[3i32, 2, 0, 1, 4]);
+        let out = interpolate_by(&s, &by, false, InterpolationMethod::CubicSpline).unwrap();
+        let out = out.f64().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[Some(31.5), Some(8.0), Some(0.0), Some(-0.5), Some(64.0)]
+        );
+    }
+}
@@ -1,9 +1,27 @@
 use std::ops::{Add, Div, Mul, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+mod cubic;
 #[cfg(feature = "interpolate")]
 pub mod interpolate;
 #[cfg(feature = "interpolate_by")]
 pub mod interpolate_by;
 
+use cubic::interpolate_cubic;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InterpolationMethod {
+    Linear,
+    Nearest,
+    /// Natural cubic spline through all non-null values.
+    CubicSpline,
+    /// Piecewise cubic Hermite interpolation that preserves monotonicity (PCHIP).
+    Pchip,
+}
+
 fn linear_itp<T>(low: T, step: T, slope: T) -> T
 where
     T: Sub<Output = T> + Mul<Output = T> + Add<Output = T> + Div<Output = T>,
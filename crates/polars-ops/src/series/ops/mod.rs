@@ -2,6 +2,8 @@
 mod abs;
 #[cfg(feature = "approx_unique")]
 mod approx_algo;
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
 #[cfg(feature = "approx_unique")]
 mod approx_unique;
 mod arg_min_max;
@@ -18,6 +20,8 @@ mod diff;
 mod ewm;
 #[cfg(feature = "ewma_by")]
 mod ewm_by;
+#[cfg(feature = "finance")]
+mod finance;
 #[cfg(feature = "round_series")]
 mod floor_divide;
 #[cfg(feature = "fused")]
@@ -41,6 +45,7 @@ mod is_unique;
 mod log;
 #[cfg(feature = "moment")]
 mod moment;
+mod n_unique;
 mod negate;
 #[cfg(feature = "pct_change")]
 mod pct_change;
@@ -58,19 +63,23 @@ mod rolling;
 mod round;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+mod shrink_type;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
 mod unique;
 mod various;
+mod weighted;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_algo::*;
+#[cfg(feature = "approx_quantile")]
+pub use approx_quantile::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_unique::*;
-pub use arg_min_max::ArgAgg;
+pub use arg_min_max::{arg_min_max_groups, ArgAgg};
 #[cfg(feature = "business")]
 pub use business::*;
 pub use clip::*;
@@ -84,6 +93,8 @@ pub use diff::*;
 pub use ewm::*;
 #[cfg(feature = "ewma_by")]
 pub use ewm_by::*;
+#[cfg(feature = "finance")]
+pub use finance::*;
 #[cfg(feature = "round_series")]
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
@@ -111,6 +122,7 @@ pub use is_unique::*;
 pub use log::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
+pub use n_unique::*;
 pub use negate::*;
 #[cfg(feature = "pct_change")]
 pub use pct_change::*;
@@ -130,11 +142,13 @@ pub use rolling::*;
 pub use round::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+pub use shrink_type::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
 pub use unique::*;
 pub use various::*;
+pub use weighted::*;
 mod not;
 pub use not::*;
 
@@ -0,0 +1,151 @@
+use arrow::array::{Array, BooleanArray};
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca_unordered;
+use polars_core::prelude::*;
+use polars_core::utils::NoNull;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+/// Row encode `columns`, so that every distinct combination of values maps to distinct bytes.
+/// Nulls are encoded as a value, so they count as distinct as in [`SeriesTrait::n_unique`].
+pub fn n_unique_rows_encoded(columns: &[Series]) -> PolarsResult<BinaryOffsetChunked> {
+    polars_ensure!(!columns.is_empty(), ComputeError: "`n_unique` needs at least one column");
+    let len = columns[0].len();
+    polars_ensure!(
+        columns.iter().all(|s| s.len() == len),
+        ShapeMismatch: "all columns of a multi-column `n_unique` must have the same length"
+    );
+    _get_rows_encoded_ca_unordered(columns[0].name(), columns)
+}
+
+fn mask_array(mask: Option<&BooleanChunked>) -> Option<BooleanArray> {
+    mask.map(|mask| {
+        let mask = mask.rechunk();
+        mask.downcast_iter().next().unwrap().clone()
+    })
+}
+
+#[inline]
+fn is_selected(mask: Option<&BooleanArray>, i: usize) -> bool {
+    // A null predicate doesn't select the row, like in `filter`.
+    mask.map_or(true, |mask| mask.is_valid(i) && mask.value(i))
+}
+
+/// Count the distinct combinations of values of `columns`, only considering the rows where
+/// `mask` is true.
+pub fn n_unique_rows(columns: &[Series], mask: Option<&BooleanChunked>) -> PolarsResult<IdxSize> {
+    if columns.len() == 1 && mask.is_none() {
+        return columns[0].n_unique().map(|n| n as IdxSize);
+    }
+    let rows = match mask {
+        Some(mask) => {
+            let mask = mask.fill_null_with_values(false)?;
+            let columns = columns
+                .iter()
+                .map(|s| s.filter(&mask))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            n_unique_rows_encoded(&columns)?
+        },
+        None => n_unique_rows_encoded(columns)?,
+    };
+    rows.into_series().n_unique().map(|n| n as IdxSize)
+}
+
+/// Count the distinct combinations of values of `columns` in every group, only considering the
+/// rows where `mask` is true.
+///
+/// The rows are hashed once for all groups, instead of materializing every group.
+pub fn n_unique_rows_groups(
+    columns: &[Series],
+    mask: Option<&BooleanChunked>,
+    groups: &GroupsProxy,
+) -> PolarsResult<IdxCa> {
+    let rows = n_unique_rows_encoded(columns)?;
+    polars_ensure!(
+        mask.map_or(true, |mask| mask.len() == rows.len()),
+        ShapeMismatch: "the predicate must have the same length as the columns"
+    );
+
+    // Give every distinct row a dense id, so that the groups only have to hash integers.
+    let mut ids = PlHashMap::with_capacity(rows.len() / 2);
+    let row_ids = rows
+        .into_no_null_iter()
+        .map(|row| {
+            let n = ids.len() as IdxSize;
+            *ids.entry(row).or_insert(n)
+        })
+        .collect::<Vec<_>>();
+    let mask = mask_array(mask);
+    let mask = mask.as_ref();
+
+    let count = |rows: &mut dyn Iterator<Item = usize>| {
+        let mut seen = PlHashSet::new();
+        for i in rows {
+            if is_selected(mask, i) {
+                seen.insert(row_ids[i]);
+            }
+        }
+        seen.len() as IdxSize
+    };
+    let out: NoNull<IdxCa> = POOL.install(|| {
+        groups
+            .par_iter()
+            .map(|group| match group {
+                GroupsIndicator::Idx((_, idx)) => count(&mut idx.iter().map(|i| *i as usize)),
+                GroupsIndicator::Slice([first, len]) => {
+                    count(&mut (first as usize..(first + len) as usize))
+                },
+            })
+            .collect()
+    });
+    Ok(out.into_inner().with_name(columns[0].name()))
+}
+
+/// Count the non-null values of `s` where `predicate` is true.
+pub fn count_if(s: &Series, predicate: &BooleanChunked) -> PolarsResult<IdxSize> {
+    Ok(count_if_mask(s, predicate)?.sum().unwrap_or(0))
+}
+
+/// The rows that are counted by [`count_if`].
+pub fn count_if_mask(s: &Series, predicate: &BooleanChunked) -> PolarsResult<BooleanChunked> {
+    polars_ensure!(
+        s.len() == predicate.len(),
+        ShapeMismatch: "`count_if` expected a predicate of length {}, got {}", s.len(), predicate.len()
+    );
+    let mask = predicate.fill_null_with_values(false)?;
+    Ok((&mask & &s.is_not_null()).with_name(s.name()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_n_unique_rows_groups() {
+        let a = Series::new("a", &[Some(1), Some(1), Some(2), None, None, Some(1)]);
+        let b = Series::new("b", &["x", "x", "x", "y", "y", "z"]);
+        let mask = BooleanChunked::new(
+            "",
+            &[
+                Some(true),
+                Some(true),
+                None,
+                Some(true),
+                Some(true),
+                Some(false),
+            ],
+        );
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 3], [3, 3]],
+            rolling: false,
+        };
+
+        let out = n_unique_rows_groups(&[a.clone(), b.clone()], None, &groups).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(2), Some(2)]);
+        let out = n_unique_rows_groups(&[a.clone(), b.clone()], Some(&mask), &groups).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(1), Some(1)]);
+
+        assert_eq!(n_unique_rows(&[a.clone(), b.clone()], None).unwrap(), 4);
+        assert_eq!(n_unique_rows(&[a.clone(), b], Some(&mask)).unwrap(), 2);
+        assert_eq!(count_if(&a, &mask).unwrap(), 2);
+    }
+}
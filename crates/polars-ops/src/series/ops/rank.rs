@@ -182,10 +182,74 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
     }
 }
 
+fn percent_rank(s: &Series, descending: bool) -> Series {
+    let n = s.len() - s.null_count();
+    if n == 0 {
+        return Float64Chunked::full_null(s.name(), s.len()).into_series();
+    }
+    let ranks = rank(s, RankMethod::Min, descending, None)
+        .cast(&DataType::Float64)
+        .unwrap();
+    // A single value has a percent rank of 0.
+    let denom = n.saturating_sub(1).max(1) as f64;
+    ((ranks.f64().unwrap() - 1.0) / denom).into_series()
+}
+
+fn cume_dist(s: &Series, descending: bool) -> Series {
+    let n = s.len() - s.null_count();
+    if n == 0 {
+        return Float64Chunked::full_null(s.name(), s.len()).into_series();
+    }
+    let ranks = rank(s, RankMethod::Max, descending, None)
+        .cast(&DataType::Float64)
+        .unwrap();
+    (ranks.f64().unwrap() / n as f64).into_series()
+}
+
+fn ntile(s: &Series, buckets: IdxSize, descending: bool) -> PolarsResult<Series> {
+    polars_ensure!(buckets > 0, ComputeError: "`ntile` needs at least one bucket");
+    let n = (s.len() - s.null_count()) as IdxSize;
+    if n == 0 {
+        return Ok(IdxCa::full_null(s.name(), s.len()).into_series());
+    }
+    // Like SQL, the first `n % buckets` buckets get one row more than the others.
+    let size = n / buckets;
+    let remainder = n % buckets;
+    let large = remainder * (size + 1);
+    let ranks = rank(s, RankMethod::Ordinal, descending, None);
+    let out: IdxCa = ranks.idx().unwrap().apply_values(|r| {
+        let i = r - 1;
+        if i < large {
+            i / (size + 1) + 1
+        } else {
+            remainder + (i - large) / size + 1
+        }
+    });
+    Ok(out.into_series())
+}
+
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
         rank(self.as_series(), options.method, options.descending, seed)
     }
+
+    /// The relative rank of each value, `(rank - 1) / (count - 1)`, where tied values get the
+    /// lowest rank of their group.
+    fn percent_rank(&self, descending: bool) -> Series {
+        percent_rank(self.as_series(), descending)
+    }
+
+    /// The cumulative distribution of each value, i.e. the fraction of values that are ordered
+    /// before or tied with it.
+    fn cume_dist(&self, descending: bool) -> Series {
+        cume_dist(self.as_series(), descending)
+    }
+
+    /// Divide the ordered values into `buckets` groups that are as equal in size as possible and
+    /// assign each value the (1-based) number of its group.
+    fn ntile(&self, buckets: IdxSize, descending: bool) -> PolarsResult<Series> {
+        ntile(self.as_series(), buckets, descending)
+    }
 }
 
 impl SeriesRank for Series {}
@@ -299,6 +363,38 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_distribution_ranks() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1), Some(2), None, Some(2), Some(4)]);
+
+        let out = percent_rank(&s, false)
+            .f64()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(0.0), Some(1.0 / 3.0), None, Some(1.0 / 3.0), Some(1.0)]
+        );
+
+        let out = cume_dist(&s, false).f64()?.into_iter().collect::<Vec<_>>();
+        assert_eq!(out, &[Some(0.25), Some(0.75), None, Some(0.75), Some(1.0)]);
+
+        let s = Series::new("a", &[6, 1, 5, 2, 4, 3, 7]);
+        let out = ntile(&s, 3, false)?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(out, &[3, 1, 2, 1, 2, 1, 3]);
+        let out = ntile(&s, 10, true)?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(out, &[2, 7, 3, 6, 4, 5, 1]);
+        assert!(ntile(&s, 0, false).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_rank_all_null() -> PolarsResult<()> {
         let s = UInt32Chunked::new("", &[None, None, None]).into_series();
@@ -1,4 +1,7 @@
+use arrow::array::BinaryArray;
 use polars_core::chunked_array::ops::search_sorted::{binary_search_ca, SearchSortedSide};
+use polars_core::prelude::sort::_broadcast_bools;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
 
@@ -48,6 +51,128 @@ pub fn search_sorted(
             });
             Ok(IdxCa::new_vec(s.name(), idx))
         },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => {
+            let search_values = search_values.struct_()?;
+            let mut out = search_sorted_multiple(
+                s.struct_().unwrap().fields(),
+                search_values.fields(),
+                side,
+                vec![descending],
+                vec![false],
+            )?;
+            out.rename(s.name());
+            Ok(out)
+        },
         _ => polars_bail!(opq = search_sorted, original_dtype),
     }
 }
+
+/// Find the indices where the rows of `search_values` should be inserted into the rows of `by` to
+/// maintain their order, where `by` is sorted by its columns in sequence, like
+/// [`DataFrame::sort`] sorts by multiple columns.
+///
+/// `descending` and `nulls_last` give the order of every column and are broadcast if they have a
+/// single value. The `search_values` are cast to the dtypes of `by`.
+pub fn search_sorted_multiple(
+    by: &[Series],
+    search_values: &[Series],
+    side: SearchSortedSide,
+    mut descending: Vec<bool>,
+    mut nulls_last: Vec<bool>,
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(!by.is_empty(), ComputeError: "search_sorted needs at least one sorted column");
+    polars_ensure!(
+        by.len() == search_values.len(),
+        ShapeMismatch: "search_sorted got {} sorted columns, but {} columns of search values",
+        by.len(), search_values.len()
+    );
+    _broadcast_bools(by.len(), &mut descending);
+    _broadcast_bools(by.len(), &mut nulls_last);
+    polars_ensure!(
+        descending.len() == by.len() && nulls_last.len() == by.len(),
+        ComputeError: "the length of `descending` and `nulls_last` must match the number of sorted columns"
+    );
+    let search_values = by
+        .iter()
+        .zip(search_values)
+        .map(|(s, v)| v.strict_cast(s.dtype()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    // Row encoding preserves the composite ordering, so the rows can be compared as bytes.
+    let rows = _get_rows_encoded_ca("", by, &descending, &nulls_last)?;
+    let rows = rows.downcast_iter().next().unwrap();
+    let values = _get_rows_encoded_ca("", &search_values, &descending, &nulls_last)?;
+
+    let name = by[0].name();
+    let idx = values
+        .downcast_iter()
+        .next()
+        .unwrap()
+        .values_iter()
+        .map(|value| search_rows(rows, value, side))
+        .collect::<Vec<_>>();
+    Ok(IdxCa::new_vec(name, idx))
+}
+
+fn search_rows(rows: &BinaryArray<i64>, value: &[u8], side: SearchSortedSide) -> IdxSize {
+    let (mut lo, mut hi) = (0, rows.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        // SAFETY: mid is in bounds.
+        let row = unsafe { rows.value_unchecked(mid) };
+        let before = match side {
+            SearchSortedSide::Right => row <= value,
+            SearchSortedSide::Any | SearchSortedSide::Left => row < value,
+        };
+        if before {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo as IdxSize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search_sorted_multiple() -> PolarsResult<()> {
+        let by = [
+            Series::new("a", [1, 1, 1, 2, 2]),
+            Series::new("b", [Some(3), Some(2), Some(1), Some(5), None]),
+        ];
+        let values = [
+            Series::new("a", [1, 1, 2, 2, 3]),
+            Series::new("b", [Some(2), Some(4), None, Some(4), Some(0)]),
+        ];
+
+        let out = search_sorted_multiple(
+            &by,
+            &values,
+            SearchSortedSide::Left,
+            vec![false, true],
+            vec![true],
+        )?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(0), Some(4), Some(4), Some(5)]
+        );
+
+        let out = search_sorted_multiple(
+            &by,
+            &values,
+            SearchSortedSide::Right,
+            vec![false, true],
+            vec![true],
+        )?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(2), Some(0), Some(5), Some(4), Some(5)]
+        );
+        Ok(())
+    }
+}
@@ -47,8 +47,11 @@ pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
                 };
                 Ok(ca.into_series())
             },
-            dt => {
-                polars_bail!(opq = unique_counts, dt)
+            _ => {
+                // Other types are grouped, the groups are ordered by first occurrence.
+                let groups = s.group_tuples(false, true)?;
+                let out: NoNull<IdxCa> = groups.iter().map(|g| g.len() as IdxSize).collect();
+                Ok(out.into_inner().with_name(s.name()).into_series())
             },
         }
     }
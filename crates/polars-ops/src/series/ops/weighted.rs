@@ -0,0 +1,191 @@
+use arrow::array::{Array, PrimitiveArray};
+use polars_core::prelude::*;
+use polars_core::POOL;
+use polars_utils::total_ord::TotalOrd;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WeightedAggregation {
+    Mean,
+    /// The variance with the weights taken as frequencies, so the divisor is `sum(w) - ddof`.
+    Var {
+        ddof: u8,
+    },
+    /// The smallest value for which the cumulative weight is at least the given fraction of the
+    /// total weight.
+    Quantile(f64),
+}
+
+impl WeightedAggregation {
+    fn name(&self) -> &'static str {
+        match self {
+            WeightedAggregation::Mean => "weighted_mean",
+            WeightedAggregation::Var { .. } => "weighted_var",
+            WeightedAggregation::Quantile(_) => "weighted_quantile",
+        }
+    }
+}
+
+fn prepare(
+    s: &Series,
+    weights: &Series,
+    agg: WeightedAggregation,
+) -> PolarsResult<(PrimitiveArray<f64>, PrimitiveArray<f64>)> {
+    let name = agg.name();
+    polars_ensure!(
+        s.len() == weights.len(),
+        ShapeMismatch: "`{}` expected weights of length {}, got {}", name, s.len(), weights.len()
+    );
+    polars_ensure!(
+        s.dtype().is_numeric() && weights.dtype().is_numeric(),
+        InvalidOperation: "`{}` operation not supported for dtypes `{}` and `{}`",
+        name, s.dtype(), weights.dtype()
+    );
+    if let WeightedAggregation::Quantile(quantile) = agg {
+        polars_ensure!(
+            (0.0..=1.0).contains(&quantile),
+            ComputeError: "`weighted_quantile` expected a quantile between 0 and 1, got {}", quantile
+        );
+    }
+    let to_array = |s: &Series| -> PolarsResult<PrimitiveArray<f64>> {
+        let s = s.cast(&DataType::Float64)?.rechunk();
+        Ok(s.f64().unwrap().downcast_iter().next().unwrap().clone())
+    };
+    Ok((to_array(s)?, to_array(weights)?))
+}
+
+/// Aggregate the rows `idx` of `values`. Rows where the value or the weight is null are skipped.
+fn weighted_agg_rows(
+    values: &PrimitiveArray<f64>,
+    weights: &PrimitiveArray<f64>,
+    idx: &mut dyn Iterator<Item = usize>,
+    agg: WeightedAggregation,
+) -> Option<f64> {
+    let pairs = idx
+        .filter(|&i| values.is_valid(i) && weights.is_valid(i))
+        .map(|i| (values.value(i), weights.value(i)));
+
+    match agg {
+        WeightedAggregation::Mean => {
+            let (mut sum_w, mut sum_wx) = (0.0, 0.0);
+            for (x, w) in pairs {
+                sum_w += w;
+                sum_wx += w * x;
+            }
+            (sum_w != 0.0).then(|| sum_wx / sum_w)
+        },
+        WeightedAggregation::Var { ddof } => {
+            // West's weighted incremental algorithm, which doesn't suffer from cancellation like
+            // `sum(w * x^2) - sum(w * x)^2 / sum(w)` does.
+            let (mut sum_w, mut mean, mut m2) = (0.0, 0.0, 0.0);
+            for (x, w) in pairs.filter(|(_, w)| *w != 0.0) {
+                sum_w += w;
+                let delta = x - mean;
+                mean += delta * w / sum_w;
+                m2 += w * delta * (x - mean);
+            }
+            let denom = sum_w - ddof as f64;
+            (denom > 0.0).then(|| m2.max(0.0) / denom)
+        },
+        WeightedAggregation::Quantile(quantile) => {
+            let mut pairs = pairs.filter(|(_, w)| *w > 0.0).collect::<Vec<_>>();
+            pairs.sort_unstable_by(|a, b| a.0.tot_cmp(&b.0));
+            let target = quantile * pairs.iter().map(|(_, w)| w).sum::<f64>();
+            let mut cum_w = 0.0;
+            pairs
+                .iter()
+                .find(|(_, w)| {
+                    cum_w += w;
+                    cum_w >= target
+                })
+                .or(pairs.last())
+                .map(|(x, _)| *x)
+        },
+    }
+}
+
+fn finish(out: Float64Chunked, s: &Series) -> PolarsResult<Series> {
+    let out = out.with_name(s.name()).into_series();
+    match s.dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
+
+/// Compute a weighted aggregation of `s`, skipping the rows where the value or the weight is
+/// null. The result is null if there is no weight.
+pub fn weighted_agg(
+    s: &Series,
+    weights: &Series,
+    agg: WeightedAggregation,
+) -> PolarsResult<Series> {
+    let (values, weights) = prepare(s, weights, agg)?;
+    let out = weighted_agg_rows(&values, &weights, &mut (0..values.len()), agg);
+    finish(Float64Chunked::from_slice_options("", &[out]), s)
+}
+
+/// Compute a weighted aggregation of every group of `s` in a single pass, without materializing
+/// the groups.
+pub fn weighted_agg_groups(
+    s: &Series,
+    weights: &Series,
+    groups: &GroupsProxy,
+    agg: WeightedAggregation,
+) -> PolarsResult<Series> {
+    let (values, weights) = prepare(s, weights, agg)?;
+    let out: Float64Chunked = POOL.install(|| {
+        groups
+            .par_iter()
+            .map(|group| match group {
+                GroupsIndicator::Idx((_, idx)) => {
+                    weighted_agg_rows(&values, &weights, &mut idx.iter().map(|i| *i as usize), agg)
+                },
+                GroupsIndicator::Slice([first, len]) => weighted_agg_rows(
+                    &values,
+                    &weights,
+                    &mut (first as usize..(first + len) as usize),
+                    agg,
+                ),
+            })
+            .collect()
+    });
+    finish(out, s)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_agg_groups() {
+        let s = Series::new(
+            "a",
+            &[Some(1.0), Some(2.0), None, Some(4.0), Some(3.0), Some(5.0)],
+        );
+        let w = Series::new(
+            "w",
+            &[Some(1.0), Some(3.0), Some(1.0), Some(2.0), None, Some(2.0)],
+        );
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 3], [3, 3]],
+            rolling: false,
+        };
+
+        let out = weighted_agg_groups(&s, &w, &groups, WeightedAggregation::Mean).unwrap();
+        assert_eq!(Vec::from(out.f64().unwrap()), &[Some(1.75), Some(4.5)]);
+
+        let agg = WeightedAggregation::Var { ddof: 0 };
+        let out = weighted_agg_groups(&s, &w, &groups, agg).unwrap();
+        assert_eq!(Vec::from(out.f64().unwrap()), &[Some(0.1875), Some(0.25)]);
+
+        let agg = WeightedAggregation::Quantile(0.5);
+        let out = weighted_agg_groups(&s, &w, &groups, agg).unwrap();
+        assert_eq!(Vec::from(out.f64().unwrap()), &[Some(2.0), Some(4.0)]);
+
+        let out = weighted_agg(&s, &w, WeightedAggregation::Mean).unwrap();
+        assert_eq!(out.f64().unwrap().get(0), Some(25.0 / 8.0));
+    }
+}
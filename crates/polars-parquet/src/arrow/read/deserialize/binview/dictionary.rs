@@ -9,7 +9,9 @@ use super::super::dictionary::*;
 use super::super::utils::MaybeNext;
 use super::super::PagesIter;
 use crate::arrow::read::deserialize::nested_utils::{InitNested, NestedState};
-use crate::parquet::page::DictPage;
+use crate::parquet::metadata::ColumnChunkMetaData;
+use crate::parquet::page::{DictPage, Page};
+use crate::parquet::read::{decompress, PageReader};
 use crate::read::deserialize::binary::utils::BinaryIter;
 
 /// An iterator adapter over [`PagesIter`] assumed to be encoded as parquet's dictionary-encoded binary representation
@@ -163,3 +165,59 @@ where
         }
     }
 }
+
+/// Whether all data pages of the column chunk are dictionary encoded, i.e. whether the
+/// values of the dictionary page are all the values that can occur in the column chunk.
+fn is_fully_dictionary_encoded(column: &ColumnChunkMetaData) -> bool {
+    use parquet_format_safe::{Encoding, PageType};
+
+    let is_dictionary = |encoding: Encoding| {
+        encoding == Encoding::PLAIN_DICTIONARY || encoding == Encoding::RLE_DICTIONARY
+    };
+
+    match &column.metadata().encoding_stats {
+        Some(stats) => stats
+            .iter()
+            .filter(|s| s.page_type == PageType::DATA_PAGE || s.page_type == PageType::DATA_PAGE_V2)
+            .all(|s| is_dictionary(s.encoding)),
+        // Without page statistics we can't know which encoding the dictionary page has, so
+        // we only trust column chunks without a plain encoding.
+        None => column.column_encoding().iter().all(|&encoding| {
+            is_dictionary(encoding) || encoding == Encoding::RLE || encoding == Encoding::BIT_PACKED
+        }),
+    }
+}
+
+/// Reads the values of the dictionary page of a string or binary column chunk without
+/// decoding its data pages.
+///
+/// Returns `None` if the column chunk is not fully dictionary encoded, in which case it may
+/// contain values that are not part of the dictionary.
+pub fn read_dictionary_values(
+    column: &ColumnChunkMetaData,
+    chunk: &[u8],
+    data_type: ArrowDataType,
+) -> PolarsResult<Option<Box<dyn Array>>> {
+    if !matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Utf8View | PhysicalType::BinaryView
+    ) || !is_fully_dictionary_encoded(column)
+    {
+        return Ok(None);
+    }
+
+    let mut pages = PageReader::new(
+        std::io::Cursor::new(chunk),
+        column,
+        std::sync::Arc::new(|_, _| true),
+        vec![],
+        usize::MAX,
+    );
+    let Some(page) = pages.next().transpose()? else {
+        return Ok(None);
+    };
+    let Page::Dict(dict) = decompress(page, &mut vec![])? else {
+        return Ok(None);
+    };
+    Ok(Some(read_dict(data_type, &dict)))
+}
@@ -3,5 +3,6 @@ mod dictionary;
 mod nested;
 
 pub(crate) use basic::BinaryViewArrayIter;
+pub use dictionary::read_dictionary_values;
 pub(crate) use dictionary::{DictIter, NestedDictIter};
 pub(crate) use nested::NestedIter;
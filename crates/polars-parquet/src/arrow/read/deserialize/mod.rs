@@ -17,6 +17,7 @@ use arrow::datatypes::{ArrowDataType, Field, IntervalUnit};
 use arrow::offset::Offsets;
 use simple::page_iter_to_arrays;
 
+pub use self::binview::read_dictionary_values;
 pub use self::nested_utils::{init_nested, InitNested, NestedArrayIter, NestedState};
 pub use self::struct_::StructIterator;
 use super::*;
@@ -1,7 +1,7 @@
 use arrow::array::{Array, DictionaryKey, MutablePrimitiveArray, PrimitiveArray};
 use arrow::datatypes::{ArrowDataType, IntervalUnit, TimeUnit};
 use arrow::match_integer_type;
-use arrow::types::{days_ms, i256, NativeType};
+use arrow::types::{days_ms, f16, i256, NativeType};
 use ethnum::I256;
 use polars_error::{polars_bail, PolarsResult};
 
@@ -177,6 +177,81 @@ pub fn page_iter_to_arrays<'a, I: PagesIter + 'a>(
 
             Box::new(arrays) as _
         },
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
+            let n = 2;
+            let pages = fixed_size_binary::Iter::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                num_rows,
+                chunk_size,
+            );
+
+            let pages = pages.map(move |maybe_array| {
+                let array = maybe_array?;
+                let values = array
+                    .values()
+                    .chunks_exact(n)
+                    .map(|value: &[u8]| f16::from_le_bytes(value.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+                let validity = array.validity().cloned();
+
+                PrimitiveArray::<f16>::try_new(data_type.clone(), values.into(), validity)
+            });
+
+            let arrays = pages.map(|x| x.map(|x| x.boxed()));
+
+            Box::new(arrays) as _
+        },
+        (PhysicalType::FixedLenByteArray(16), Int128) => {
+            let n = 16;
+            let pages = fixed_size_binary::Iter::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                num_rows,
+                chunk_size,
+            );
+
+            let pages = pages.map(move |maybe_array| {
+                let array = maybe_array?;
+                let values = array
+                    .values()
+                    .chunks_exact(n)
+                    .map(|value: &[u8]| i128::from_be_bytes(value.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+                let validity = array.validity().cloned();
+
+                PrimitiveArray::<i128>::try_new(data_type.clone(), values.into(), validity)
+            });
+
+            let arrays = pages.map(|x| x.map(|x| x.boxed()));
+
+            Box::new(arrays) as _
+        },
+        (PhysicalType::FixedLenByteArray(16), UInt128) => {
+            let n = 16;
+            let pages = fixed_size_binary::Iter::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                num_rows,
+                chunk_size,
+            );
+
+            let pages = pages.map(move |maybe_array| {
+                let array = maybe_array?;
+                let values = array
+                    .values()
+                    .chunks_exact(n)
+                    .map(|value: &[u8]| u128::from_be_bytes(value.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+                let validity = array.validity().cloned();
+
+                PrimitiveArray::<u128>::try_new(data_type.clone(), values.into(), validity)
+            });
+
+            let arrays = pages.map(|x| x.map(|x| x.boxed()));
+
+            Box::new(arrays) as _
+        },
         (PhysicalType::Int32, Decimal(_, _)) => dyn_iter(iden(primitive::IntegerIter::new(
             pages,
             data_type,
@@ -1,5 +1,5 @@
 use arrow::array::*;
-use arrow::types::{days_ms, i256};
+use arrow::types::{days_ms, f16, i256, NativeType};
 use ethnum::I256;
 use polars_error::PolarsResult;
 
@@ -28,6 +28,27 @@ pub(super) fn push_i128(
     Ok(())
 }
 
+pub(super) fn push_u128(
+    from: Option<&FixedLenStatistics>,
+    min: &mut dyn MutableArray,
+    max: &mut dyn MutableArray,
+) -> PolarsResult<()> {
+    let min = min
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<u128>>()
+        .unwrap();
+    let max = max
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<u128>>()
+        .unwrap();
+
+    let convert = |x: &[u8]| u128::from_be_bytes(x.try_into().unwrap());
+    min.push(from.and_then(|s| s.min_value.as_deref().map(convert)));
+    max.push(from.and_then(|s| s.max_value.as_deref().map(convert)));
+
+    Ok(())
+}
+
 pub(super) fn push_i256_with_i128(
     from: Option<&FixedLenStatistics>,
     n: usize,
@@ -97,6 +118,27 @@ pub(super) fn push(
     Ok(())
 }
 
+pub(super) fn push_f16(
+    from: Option<&FixedLenStatistics>,
+    min: &mut dyn MutableArray,
+    max: &mut dyn MutableArray,
+) -> PolarsResult<()> {
+    let min = min
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<f16>>()
+        .unwrap();
+    let max = max
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<f16>>()
+        .unwrap();
+
+    let convert = |x: &[u8]| f16::from_le_bytes(x.try_into().unwrap());
+    min.push(from.and_then(|s| s.min_value.as_deref().map(convert)));
+    max.push(from.and_then(|s| s.max_value.as_deref().map(convert)));
+
+    Ok(())
+}
+
 fn convert_year_month(value: &[u8]) -> i32 {
     i32::from_le_bytes(value[..4].try_into().unwrap())
 }
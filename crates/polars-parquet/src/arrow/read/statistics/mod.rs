@@ -489,8 +489,11 @@ fn push(
                 })
             }
         },
+        Float16 => fixlen::push_f16(rmap!(from, expect_as_fixedlen), min, max),
         Float32 => primitive::push::<f32, f32, _>(rmap!(from, expect_as_float), min, max, Ok),
         Float64 => primitive::push::<f64, f64, _>(rmap!(from, expect_as_double), min, max, Ok),
+        Int128 => fixlen::push_i128(rmap!(from, expect_as_fixedlen), 16, min, max),
+        UInt128 => fixlen::push_u128(rmap!(from, expect_as_fixedlen), min, max),
         Decimal(_, _) => match physical_type {
             PPT::Int32 => primitive::push(rmap!(from, expect_as_int32), min, max, |x: i32| {
                 Ok(x as i128)
@@ -29,7 +29,7 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{days_ms, i256, NativeType};
+use arrow::types::{days_ms, f16, i256, NativeType};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 pub use utils::write_def_levels;
@@ -167,6 +167,23 @@ fn decimal_length_from_precision(precision: usize) -> usize {
     (((10.0_f64.powi(precision as i32) + 1.0).log2() + 1.0) / 8.0).ceil() as usize
 }
 
+/// Stores the big-endian bytes of each value, as parquet has no 128-bit integer type.
+fn primitive_to_fixed_size_binary<T: NativeType>(
+    array: &PrimitiveArray<T>,
+) -> FixedSizeBinaryArray {
+    let size = std::mem::size_of::<T>();
+    let mut values = Vec::<u8>::with_capacity(size * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(x.to_be_bytes().as_ref()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(size),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
 /// Creates a parquet [`SchemaDescriptor`] from a [`ArrowSchema`].
 pub fn to_parquet_schema(schema: &ArrowSchema) -> PolarsResult<SchemaDescriptor> {
     let parquet_types = schema
@@ -553,6 +570,41 @@ pub fn array_to_page_simple(
 
             fixed_len_bytes::array_to_page(array, options, type_, statistics)
         },
+        ArrowDataType::Float16 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            let mut values = Vec::<u8>::with_capacity(2 * array.len());
+            array
+                .values()
+                .iter()
+                .for_each(|x| values.extend_from_slice(&x.to_le_bytes()));
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(2),
+                values.into(),
+                array.validity().cloned(),
+            );
+            fixed_len_bytes::array_to_page(&array, options, type_, None)
+        },
+        ArrowDataType::Int128 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            let array = primitive_to_fixed_size_binary(array);
+            // The byte order of two's complement values is not their numeric order, so these
+            // columns are written without min/max statistics.
+            fixed_len_bytes::array_to_page(&array, options, type_, None)
+        },
+        ArrowDataType::UInt128 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<u128>>()
+                .unwrap();
+            let array = primitive_to_fixed_size_binary(array);
+            fixed_len_bytes::array_to_page(&array, options, type_, None)
+        },
         ArrowDataType::Decimal256(precision, _) => {
             let precision = *precision;
             let array = array
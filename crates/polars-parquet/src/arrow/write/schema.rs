@@ -129,6 +129,17 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             None,
             None,
         )?),
+        // Half floats are stored as 2 little-endian bytes, the layout of parquet's FLOAT16
+        // logical type. That logical type isn't available here, so the arrow schema stored in
+        // the file metadata restores the dtype on read.
+        ArrowDataType::Float16 => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(2),
+            repetition,
+            None,
+            None,
+            None,
+        )?),
         ArrowDataType::Float32 => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::Float,
@@ -314,6 +325,16 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             None,
             None,
         )?),
+        // Parquet has no 128-bit integer type; the arrow schema stored in the file metadata
+        // restores the dtype on read.
+        ArrowDataType::Int128 | ArrowDataType::UInt128 => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(16),
+            repetition,
+            None,
+            None,
+            None,
+        )?),
         ArrowDataType::Decimal(precision, scale) => {
             let precision = *precision;
             let scale = *scale;
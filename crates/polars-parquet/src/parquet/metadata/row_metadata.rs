@@ -1,4 +1,4 @@
-use parquet_format_safe::RowGroup;
+use parquet_format_safe::{RowGroup, SortingColumn as ThriftSortingColumn};
 #[cfg(feature = "serde_types")]
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +7,16 @@ use super::schema_descriptor::SchemaDescriptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::write::ColumnOffsetsMetadata;
 
+/// A column by which the rows of a row group are sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct SortingColumn {
+    /// The index of the leaf column in the row group.
+    pub column_idx: usize,
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
 /// Metadata for a row group.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
@@ -14,6 +24,7 @@ pub struct RowGroupMetaData {
     columns: Vec<ColumnChunkMetaData>,
     num_rows: usize,
     total_byte_size: usize,
+    sorting_columns: Vec<SortingColumn>,
 }
 
 impl RowGroupMetaData {
@@ -27,6 +38,7 @@ impl RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns: vec![],
         }
     }
 
@@ -45,6 +57,13 @@ impl RowGroupMetaData {
         self.total_byte_size
     }
 
+    /// The columns by which the rows are sorted, in order of precedence.
+    ///
+    /// Only the first column is sorted over the whole row group, the others break ties.
+    pub fn sorting_columns(&self) -> &[SortingColumn] {
+        &self.sorting_columns
+    }
+
     /// Total size of all compressed column data in this row group.
     pub fn compressed_size(&self) -> usize {
         self.columns
@@ -71,11 +90,26 @@ impl RowGroupMetaData {
                 ColumnChunkMetaData::try_from_thrift(descriptor.clone(), column_chunk)
             })
             .collect::<ParquetResult<Vec<_>>>()?;
+        let sorting_columns = rg
+            .sorting_columns
+            .unwrap_or_default()
+            .into_iter()
+            // Ignore invalid column indices, the sort order is only a hint.
+            .filter_map(|sc| {
+                let column_idx = usize::try_from(sc.column_idx).ok()?;
+                (column_idx < columns.len()).then_some(SortingColumn {
+                    column_idx,
+                    descending: sc.descending,
+                    nulls_first: sc.nulls_first,
+                })
+            })
+            .collect();
 
         Ok(RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns,
         })
     }
 
@@ -90,11 +124,21 @@ impl RowGroupMetaData {
             .next()
             .unwrap_or(None);
         let total_compressed_size = Some(self.compressed_size() as i64);
+        let sorting_columns = (!self.sorting_columns.is_empty()).then(|| {
+            self.sorting_columns
+                .iter()
+                .map(|sc| ThriftSortingColumn {
+                    column_idx: sc.column_idx as i32,
+                    descending: sc.descending,
+                    nulls_first: sc.nulls_first,
+                })
+                .collect()
+        });
         RowGroup {
             columns: self.columns.into_iter().map(|v| v.into_thrift()).collect(),
             total_byte_size: self.total_byte_size as i64,
             num_rows: self.num_rows as i64,
-            sorting_columns: None,
+            sorting_columns,
             file_offset,
             total_compressed_size,
             ordinal: None,
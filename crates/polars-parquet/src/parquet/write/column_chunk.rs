@@ -5,8 +5,11 @@ use futures::AsyncWrite;
 use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
 #[cfg(feature = "async")]
 use parquet_format_safe::thrift::protocol::TCompactOutputStreamProtocol;
-use parquet_format_safe::{ColumnChunk, ColumnMetaData, Type};
-use polars_utils::aliases::PlHashSet;
+use parquet_format_safe::{
+    ColumnChunk, ColumnMetaData, Encoding as ParquetEncoding, PageEncodingStats,
+    PageType as ParquetPageType, Type,
+};
+use polars_utils::aliases::{PlHashMap, PlHashSet};
 
 #[cfg(feature = "async")]
 use super::page::write_page_async;
@@ -170,6 +173,36 @@ fn build_column_chunk(
     // Sort the encodings to have deterministic metadata
     encodings.sort();
 
+    // Count the pages per page type and encoding, so that readers know whether all data pages
+    // are dictionary encoded.
+    let mut encoding_stats = PlHashMap::<(ParquetPageType, ParquetEncoding), i32>::default();
+    for spec in specs {
+        let encoding = match spec.header.type_.try_into().unwrap() {
+            PageType::DataPage => spec.header.data_page_header.as_ref().unwrap().encoding,
+            PageType::DataPageV2 => spec.header.data_page_header_v2.as_ref().unwrap().encoding,
+            PageType::DictionaryPage => {
+                spec.header
+                    .dictionary_page_header
+                    .as_ref()
+                    .unwrap()
+                    .encoding
+            },
+        };
+        *encoding_stats
+            .entry((spec.header.type_, encoding))
+            .or_default() += 1;
+    }
+    let mut encoding_stats = encoding_stats
+        .into_iter()
+        .map(|((page_type, encoding), count)| PageEncodingStats {
+            page_type,
+            encoding,
+            count,
+        })
+        .collect::<Vec<_>>();
+    // Sort the encoding stats to have deterministic metadata
+    encoding_stats.sort_by_key(|stats| (stats.page_type, stats.encoding));
+
     let statistics = specs.iter().map(|x| &x.statistics).collect::<Vec<_>>();
     let statistics = reduce(&statistics)?;
     let statistics = statistics.map(|x| x.serialize());
@@ -189,7 +222,7 @@ fn build_column_chunk(
         index_page_offset: None,
         dictionary_page_offset: None,
         statistics,
-        encoding_stats: None,
+        encoding_stats: Some(encoding_stats),
         bloom_filter_offset: None,
     };
 
@@ -4,6 +4,7 @@ mod pass;
 mod placeholder;
 mod projection;
 mod reproject;
+mod unpivot;
 
 pub(crate) use filter::*;
 pub(crate) use function::*;
@@ -11,3 +12,4 @@ pub(crate) use pass::Pass;
 pub(crate) use placeholder::PlaceHolder;
 pub(crate) use projection::*;
 pub(crate) use reproject::*;
+pub(crate) use unpivot::*;
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
+use polars_core::prelude::{Field, Series};
 use polars_core::schema::SchemaRef;
 use polars_plan::prelude::ProjectionOptions;
 use smartstring::alias::String as SmartString;
@@ -45,9 +46,23 @@ impl Operator for SimpleProjectionOperator {
     }
 }
 
+/// Conform the output of an expression to the field the plan declared for it.
+///
+/// The expressions are evaluated per chunk, so an expression can output another dtype for a chunk
+/// than the plan declared, e.g. `Null` for the field of a struct that is null in the whole chunk.
+/// The sinks combine the chunks of an operator, so they must all have the declared dtypes.
+fn conform_to_field(s: &mut Series, field: &Field) {
+    let dtype = field.data_type();
+    if s.dtype() != dtype && dtype.is_known() && s.null_count() == s.len() {
+        *s = Series::full_null(s.name(), s.len(), dtype);
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct ProjectionOperator {
     pub(crate) exprs: Vec<Arc<dyn PhysicalPipedExpr>>,
+    /// The declared output field of every expression.
+    pub(crate) fields: Arc<[Field]>,
     pub(crate) options: ProjectionOptions,
 }
 
@@ -86,6 +101,9 @@ impl Operator for ProjectionOperator {
                 }
             }
         }
+        for (s, field) in projected.iter_mut().zip(self.fields.iter()) {
+            conform_to_field(s, field);
+        }
 
         let chunk = chunk.with_data(unsafe { DataFrame::new_no_checks(projected) });
         Ok(OperatorResult::Finished(chunk))
@@ -101,6 +119,8 @@ impl Operator for ProjectionOperator {
 #[derive(Clone)]
 pub(crate) struct HstackOperator {
     pub(crate) exprs: Vec<Arc<dyn PhysicalPipedExpr>>,
+    /// The declared output field of every expression.
+    pub(crate) fields: Arc<[Field]>,
     pub(crate) input_schema: SchemaRef,
     pub(crate) options: ProjectionOptions,
 }
@@ -113,11 +133,14 @@ impl Operator for HstackOperator {
     ) -> PolarsResult<OperatorResult> {
         // add temporary cse column to the chunk
         let width = chunk.data.width();
-        let projected = self
+        let mut projected = self
             .exprs
             .iter()
             .map(|e| e.evaluate(chunk, &context.execution_state))
             .collect::<PolarsResult<Vec<_>>>()?;
+        for (s, field) in projected.iter_mut().zip(self.fields.iter()) {
+            conform_to_field(s, field);
+        }
 
         let columns = chunk.data.get_columns()[..width].to_vec();
         let mut df = unsafe { DataFrame::new_no_checks(columns) };
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use smartstring::alias::String as SmartString;
+
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+
+/// Unpivots every chunk to long format. The columns and the supertype of the values are resolved
+/// once from the input schema. A chunk is emitted once per unpivoted column, so the output
+/// chunks are as long as the input chunks, instead of `on.len()` times as long.
+#[derive(Clone)]
+pub(crate) struct UnpivotOperator {
+    index: Arc<[usize]>,
+    on: Arc<[(usize, SmartString)]>,
+    dtype: DataType,
+    variable_name: SmartString,
+    value_name: SmartString,
+    // The position in `on` of the next column to emit for the current chunk.
+    offset: usize,
+}
+
+impl UnpivotOperator {
+    pub(crate) fn new(args: &UnpivotArgs, input_schema: &Schema) -> PolarsResult<Self> {
+        let index = args
+            .index
+            .iter()
+            .map(|name| input_schema.try_index_of(name))
+            .collect::<PolarsResult<Arc<[_]>>>()?;
+        let on = if args.on.is_empty() {
+            input_schema
+                .iter_names()
+                .filter(|name| !args.index.contains(name))
+                .cloned()
+                .collect::<Vec<_>>()
+        } else {
+            args.on.clone()
+        };
+
+        let mut dtype = DataType::Null;
+        let on = on
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let (pos, _, dt) = input_schema.try_get_full(&name)?;
+                dtype = if i == 0 {
+                    dt.clone()
+                } else {
+                    try_get_supertype(&dtype, dt)?
+                };
+                Ok((pos, name))
+            })
+            .collect::<PolarsResult<Arc<[_]>>>()?;
+
+        Ok(Self {
+            index,
+            on,
+            dtype,
+            variable_name: args
+                .variable_name
+                .clone()
+                .unwrap_or_else(|| "variable".into()),
+            value_name: args.value_name.clone().unwrap_or_else(|| "value".into()),
+            offset: 0,
+        })
+    }
+}
+
+impl Operator for UnpivotOperator {
+    fn execute(
+        &mut self,
+        _context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let columns = chunk.data.get_columns();
+        let height = chunk.data.height();
+        let mut out = self
+            .index
+            .iter()
+            .map(|&i| columns[i].clone())
+            .collect::<Vec<_>>();
+
+        let Some((pos, name)) = self.on.get(self.offset) else {
+            // There are no columns to unpivot.
+            out.iter_mut().for_each(|s| *s = s.clear());
+            out.push(Series::new_empty(&self.variable_name, &DataType::String));
+            out.push(Series::new_empty(&self.value_name, &DataType::Null));
+            // SAFETY: all columns are empty.
+            let df = unsafe { DataFrame::new_no_checks(out) };
+            return Ok(OperatorResult::Finished(chunk.with_data(df)));
+        };
+        let variable = StringChunked::full(&self.variable_name, name, height).into_series();
+        let value = columns[*pos].cast(&self.dtype).map_err(|_| {
+            polars_err!(
+                InvalidOperation: "'unpivot' not supported for dtype: {}", columns[*pos].dtype()
+            )
+        })?;
+        out.push(variable);
+        out.push(value.with_name(&self.value_name));
+        // SAFETY: all columns have the height of the chunk and the names are checked by the
+        // schema of the plan.
+        let df = unsafe { DataFrame::new_no_checks(out) };
+
+        self.offset += 1;
+        if self.offset == self.on.len() {
+            self.offset = 0;
+            Ok(OperatorResult::Finished(chunk.with_data(df)))
+        } else {
+            Ok(OperatorResult::HaveMoreOutPut(chunk.with_data(df)))
+        }
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn fmt(&self) -> &str {
+        "unpivot"
+    }
+}
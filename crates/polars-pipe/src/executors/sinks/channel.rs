@@ -0,0 +1,58 @@
+use std::any::Any;
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_plan::prelude::ChunkSender;
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+/// Sends the chunks to a bounded channel as they arrive, in the order they arrive.
+///
+/// A full channel blocks the thread that sinks, so the query produces no faster than the
+/// receiver consumes. Once the receiver is dropped the pipeline finishes.
+#[derive(Clone)]
+pub struct ChannelSink {
+    sender: ChunkSender,
+    schema: SchemaRef,
+}
+
+impl ChannelSink {
+    pub fn new(sender: ChunkSender, schema: SchemaRef) -> Self {
+        Self { sender, schema }
+    }
+}
+
+impl Sink for ChannelSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if chunk.data.height() == 0 {
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        match self.sender.0.send(chunk.data) {
+            Ok(()) => Ok(SinkResult::CanHaveMoreInput),
+            Err(_) => Ok(SinkResult::Finished),
+        }
+    }
+
+    fn combine(&mut self, _other: &mut dyn Sink) {
+        // no-op
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        Ok(FinalizedSink::Finished(DataFrame::empty_with_schema(
+            &self.schema,
+        )))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "channel_sink"
+    }
+}
@@ -0,0 +1,73 @@
+use std::any::Any;
+
+use num_traits::NumCast;
+use polars_core::datatypes::{AnyValue, DataType};
+use polars_core::prelude::{Series, IDX_DTYPE};
+use polars_ops::prelude::ApproxNUniqueState;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Aggregates the hashes produced by `approx_n_unique_hashes` into a mergeable sketch.
+pub(crate) struct ApproxNUniqueAgg {
+    state: ApproxNUniqueState,
+}
+
+impl ApproxNUniqueAgg {
+    pub(crate) fn new() -> Self {
+        ApproxNUniqueAgg {
+            state: ApproxNUniqueState::default(),
+        }
+    }
+}
+
+impl AggregateFn for ApproxNUniqueAgg {
+    fn has_physical_agg(&self) -> bool {
+        true
+    }
+
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        if let AnyValue::UInt64(hash) = item {
+            self.state.add_hash(hash)
+        }
+    }
+
+    fn pre_agg_primitive<T: NumCast>(&mut self, _chunk_idx: IdxSize, item: Option<T>) {
+        if let Some(hash) = item.and_then(|v| v.to_u64()) {
+            self.state.add_hash(hash)
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let hashes = values.slice(offset as i64, length as usize);
+        let hashes = unsafe { hashes.u64().unwrap_unchecked_release() };
+        for hash in hashes.into_no_null_iter() {
+            self.state.add_hash(hash)
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        IDX_DTYPE
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.state.merge(&other.state);
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        AnyValue::from(self.state.count() as IdxSize)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
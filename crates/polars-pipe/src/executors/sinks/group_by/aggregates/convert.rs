@@ -3,23 +3,38 @@ use std::sync::Arc;
 use polars_core::datatypes::Field;
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{DataType, SchemaRef, Series, IDX_DTYPE};
+#[cfg(feature = "dtype-struct")]
+use polars_core::prelude::StructChunked;
+use polars_core::prelude::{
+    BinaryOffsetChunked, ChunkFillNullValue, DataType, IntoSeries, SchemaRef, Series,
+    UInt64Chunked, IDX_DTYPE,
+};
 use polars_core::schema::Schema;
 use polars_expr::state::ExecutionState;
 use polars_io::predicates::PhysicalIoExpr;
-use polars_plan::dsl::Expr;
+use polars_plan::dsl::{Expr, FunctionExpr, UdafFunction, UserDefinedAggregate};
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::{ArenaExprIter, Context};
 use polars_plan::prelude::{AExpr, IRAggExpr};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::IdxSize;
 
+#[cfg(feature = "approx_unique")]
+use crate::executors::sinks::group_by::aggregates::approx_n_unique::ApproxNUniqueAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
+use crate::executors::sinks::group_by::aggregates::first_last_n::FirstLastNAgg;
+use crate::executors::sinks::group_by::aggregates::implode::ImplodeAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::{new_max, new_min};
+#[cfg(feature = "dtype-struct")]
+use crate::executors::sinks::group_by::aggregates::min_max_by::MinMaxByAgg;
+#[cfg(feature = "mode")]
+use crate::executors::sinks::group_by::aggregates::mode_k::ModeKAgg;
+use crate::executors::sinks::group_by::aggregates::n_unique_rows::NUniqueRowsAgg;
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+use crate::executors::sinks::group_by::aggregates::udaf::UdafAgg;
 use crate::executors::sinks::group_by::aggregates::{AggregateFunction, SumAgg};
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::DataChunk;
@@ -47,11 +62,254 @@ impl PhysicalPipedExpr for Len {
     }
 }
 
+/// Hashes the output of `input`, so that it can be aggregated by [`ApproxNUniqueAgg`].
+#[cfg(feature = "approx_unique")]
+struct ApproxNUniqueHashes {
+    input: Arc<dyn PhysicalPipedExpr>,
+}
+
+#[cfg(feature = "approx_unique")]
+impl PhysicalIoExpr for ApproxNUniqueHashes {
+    fn evaluate_io(&self, _df: &DataFrame) -> PolarsResult<Series> {
+        unimplemented!()
+    }
+}
+
+#[cfg(feature = "approx_unique")]
+impl PhysicalPipedExpr for ApproxNUniqueHashes {
+    fn evaluate(&self, chunk: &DataChunk, lazy_state: &ExecutionState) -> PolarsResult<Series> {
+        let s = self.input.evaluate(chunk, lazy_state)?;
+        let mut hashes = Vec::with_capacity(s.len());
+        polars_ops::prelude::approx_n_unique_hashes(&s, &mut hashes)?;
+        Ok(UInt64Chunked::from_vec(s.name(), hashes).into_series())
+    }
+
+    fn field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let field = self.input.field(input_schema)?;
+        Ok(Field::new(field.name(), DataType::UInt64))
+    }
+
+    fn expression(&self) -> Expr {
+        self.input.expression()
+    }
+}
+
+/// Packs the physical `value` and `by` columns into a struct, so that they can be aggregated
+/// together by [`MinMaxByAgg`].
+#[cfg(feature = "dtype-struct")]
+struct MinMaxByInput {
+    value: Arc<dyn PhysicalPipedExpr>,
+    by: Arc<dyn PhysicalPipedExpr>,
+}
+
+#[cfg(feature = "dtype-struct")]
+impl PhysicalIoExpr for MinMaxByInput {
+    fn evaluate_io(&self, _df: &DataFrame) -> PolarsResult<Series> {
+        unimplemented!()
+    }
+}
+
+#[cfg(feature = "dtype-struct")]
+impl PhysicalPipedExpr for MinMaxByInput {
+    fn evaluate(&self, chunk: &DataChunk, lazy_state: &ExecutionState) -> PolarsResult<Series> {
+        let value = self.value.evaluate(chunk, lazy_state)?;
+        let by = self.by.evaluate(chunk, lazy_state)?;
+        let fields = [
+            value.to_physical_repr().into_owned().with_name("value"),
+            by.to_physical_repr().into_owned().with_name("by"),
+        ];
+        Ok(StructChunked::new(value.name(), &fields)?.into_series())
+    }
+
+    fn field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let value = self.value.field(input_schema)?;
+        let by = self.by.field(input_schema)?;
+        let dtype = DataType::Struct(vec![
+            Field::new("value", value.dtype.to_physical()),
+            Field::new("by", by.dtype.to_physical()),
+        ]);
+        Ok(Field::new(value.name(), dtype))
+    }
+
+    fn expression(&self) -> Expr {
+        self.value.expression()
+    }
+}
+
+/// Row encodes the inputs, so that [`NUniqueRowsAgg`] can count the distinct rows. Rows that
+/// don't match the `predicate` are set to null.
+struct NUniqueRowsInput {
+    inputs: Vec<Arc<dyn PhysicalPipedExpr>>,
+    predicate: Option<Arc<dyn PhysicalPipedExpr>>,
+}
+
+impl PhysicalIoExpr for NUniqueRowsInput {
+    fn evaluate_io(&self, _df: &DataFrame) -> PolarsResult<Series> {
+        unimplemented!()
+    }
+}
+
+impl PhysicalPipedExpr for NUniqueRowsInput {
+    fn evaluate(&self, chunk: &DataChunk, lazy_state: &ExecutionState) -> PolarsResult<Series> {
+        let columns = self
+            .inputs
+            .iter()
+            .map(|e| e.evaluate(chunk, lazy_state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let rows = polars_ops::prelude::n_unique_rows_encoded(&columns)?;
+        let Some(predicate) = &self.predicate else {
+            return Ok(rows.into_series());
+        };
+        let mask = predicate.evaluate(chunk, lazy_state)?;
+        let mask = mask.bool()?.fill_null_with_values(false)?.rechunk();
+        let validity = mask.downcast_iter().next().unwrap().values().clone();
+        let arr = rows.downcast_iter().next().unwrap().clone();
+        Ok(
+            BinaryOffsetChunked::with_chunk(rows.name(), arr.with_validity(Some(validity)))
+                .into_series(),
+        )
+    }
+
+    fn field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let field = self.inputs[0].field(input_schema)?;
+        Ok(Field::new(field.name(), DataType::BinaryOffset))
+    }
+
+    fn expression(&self) -> Expr {
+        self.inputs[0].expression()
+    }
+}
+
+/// Evaluates the rows counted by `count_if` as `0` or `1`, so that they can be summed.
+struct CountIfInput {
+    input: Arc<dyn PhysicalPipedExpr>,
+    predicate: Arc<dyn PhysicalPipedExpr>,
+}
+
+impl PhysicalIoExpr for CountIfInput {
+    fn evaluate_io(&self, _df: &DataFrame) -> PolarsResult<Series> {
+        unimplemented!()
+    }
+}
+
+impl PhysicalPipedExpr for CountIfInput {
+    fn evaluate(&self, chunk: &DataChunk, lazy_state: &ExecutionState) -> PolarsResult<Series> {
+        let s = self.input.evaluate(chunk, lazy_state)?;
+        let predicate = self.predicate.evaluate(chunk, lazy_state)?;
+        polars_ops::prelude::count_if_mask(&s, predicate.bool()?)?
+            .into_series()
+            .cast(&IDX_DTYPE)
+    }
+
+    fn field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let field = self.input.field(input_schema)?;
+        Ok(Field::new(field.name(), IDX_DTYPE))
+    }
+
+    fn expression(&self) -> Expr {
+        self.input.expression()
+    }
+}
+
+fn is_n_unique_rows_or_count_if(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::NUniqueRows { .. } | FunctionExpr::CountIf,
+            ..
+        }
+    )
+}
+
+#[cfg(feature = "dtype-struct")]
+fn is_min_max_by(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::MinMaxBy { .. },
+            ..
+        }
+    )
+}
+
+#[cfg(not(feature = "dtype-struct"))]
+fn is_min_max_by(_ae: &AExpr) -> bool {
+    false
+}
+
+fn is_first_last_n(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::FirstLastN { .. },
+            ..
+        }
+    )
+}
+
+#[cfg(feature = "mode")]
+fn is_mode_k(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::ModeK { .. },
+            ..
+        }
+    )
+}
+
+#[cfg(not(feature = "mode"))]
+fn is_mode_k(_ae: &AExpr) -> bool {
+    false
+}
+
+/// The aggregate of a single input expression created by `Expr::udaf`.
+fn get_udaf(ae: &AExpr) -> Option<Arc<dyn UserDefinedAggregate>> {
+    match ae {
+        AExpr::AnonymousFunction {
+            input,
+            function,
+            options,
+            ..
+        } if input.len() == 1 => UdafFunction::get_aggregate(function.as_ref(), options),
+        _ => None,
+    }
+}
+
+fn is_udaf(ae: &AExpr) -> bool {
+    get_udaf(ae).is_some()
+}
+
+#[cfg(feature = "approx_unique")]
+fn is_approx_n_unique(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::ApproxNUnique,
+            ..
+        }
+    )
+}
+
+#[cfg(not(feature = "approx_unique"))]
+fn is_approx_n_unique(_ae: &AExpr) -> bool {
+    false
+}
+
 pub fn can_convert_to_hash_agg(
     mut node: Node,
     expr_arena: &Arena<AExpr>,
     input_schema: &Schema,
 ) -> bool {
+    // A column that isn't aggregated is collected in a list per group.
+    let inner = match expr_arena.get(node) {
+        AExpr::Alias(input, _) => *input,
+        _ => node,
+    };
+    if matches!(expr_arena.get(inner), AExpr::Column(_)) {
+        return true;
+    }
+
     let mut can_run_partitioned = true;
     if expr_arena
         .iter(node)
@@ -65,13 +323,27 @@ pub fn can_convert_to_hash_agg(
                 | AExpr::BinaryExpr { .. }
                 | AExpr::Ternary { .. }
                 | AExpr::Alias(_, _) => {},
+                ae if is_approx_n_unique(ae)
+                    || is_min_max_by(ae)
+                    || is_n_unique_rows_or_count_if(ae)
+                    || is_first_last_n(ae)
+                    || is_mode_k(ae)
+                    || is_udaf(ae) => {},
                 _ => {
                     can_run_partitioned = false;
                 },
             }
             ae
         })
-        .filter(|ae| matches!(ae, AExpr::Agg(_) | AExpr::Len))
+        .filter(|ae| {
+            matches!(ae, AExpr::Agg(_) | AExpr::Len)
+                || is_approx_n_unique(ae)
+                || is_min_max_by(ae)
+                || is_n_unique_rows_or_count_if(ae)
+                || is_first_last_n(ae)
+                || is_mode_k(ae)
+                || is_udaf(ae)
+        })
         .count()
         == 1
         && can_run_partitioned
@@ -82,6 +354,28 @@ pub fn can_convert_to_hash_agg(
         }
         match expr_arena.get(node) {
             AExpr::Len => true,
+            ae if is_approx_n_unique(ae)
+                || is_n_unique_rows_or_count_if(ae)
+                || is_first_last_n(ae)
+                || is_udaf(ae) =>
+            {
+                true
+            },
+            // The values are counted by equality, which doesn't group the NaNs of floats.
+            ae @ AExpr::Function { input, .. } if is_mode_k(ae) => expr_arena
+                .get(input[0].node())
+                .to_field(input_schema, Context::Default, expr_arena)
+                .map(|field| !field.dtype.to_physical().is_float())
+                .unwrap_or(false),
+            // The `by` column is compared per row, so it must have a simple physical type.
+            ae @ AExpr::Function { input, .. } if is_min_max_by(ae) => expr_arena
+                .get(input[1].node())
+                .to_field(input_schema, Context::Default, expr_arena)
+                .map(|field| {
+                    let dtype = field.dtype.to_physical();
+                    dtype.is_numeric() || dtype == DataType::String
+                })
+                .unwrap_or(false),
             ae @ AExpr::Agg(agg_fn) => {
                 matches!(
                     agg_fn,
@@ -90,6 +384,7 @@ pub fn can_convert_to_hash_agg(
                         | IRAggExpr::Last(_)
                         | IRAggExpr::Mean(_)
                         | IRAggExpr::Count(_, false)
+                        | IRAggExpr::NUnique(_)
                 ) || (matches!(
                     agg_fn,
                     IRAggExpr::Max {
@@ -134,11 +429,134 @@ where
 {
     match expr_arena.get(node) {
         AExpr::Alias(input, _) => convert_to_hash_agg(*input, expr_arena, schema, to_physical),
+        AExpr::Column(_) => {
+            let phys_expr = to_physical(
+                &ExprIR::from_node(node, expr_arena),
+                expr_arena,
+                Some(schema),
+            )
+            .unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            (
+                logical_dtype.clone(),
+                phys_expr,
+                AggregateFunction::Implode(ImplodeAgg::new(logical_dtype.to_physical())),
+            )
+        },
         AExpr::Len => (
             IDX_DTYPE,
             Arc::new(Len {}),
             AggregateFunction::Len(CountAgg::new()),
         ),
+        #[cfg(feature = "approx_unique")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ApproxNUnique,
+            ..
+        } => {
+            let phys_expr = to_physical(&input[0], expr_arena, Some(schema)).unwrap();
+            (
+                DataType::UInt64,
+                Arc::new(ApproxNUniqueHashes { input: phys_expr }),
+                AggregateFunction::ApproxNUnique(ApproxNUniqueAgg::new()),
+            )
+        },
+        #[cfg(feature = "dtype-struct")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::MinMaxBy { max },
+            ..
+        } => {
+            let value = to_physical(&input[0], expr_arena, Some(schema)).unwrap();
+            let by = to_physical(&input[1], expr_arena, Some(schema)).unwrap();
+            let value_dtype = value.field(schema).unwrap().dtype.to_physical();
+            let phys_expr = Arc::new(MinMaxByInput { value, by });
+            let input_dtype = phys_expr.field(schema).unwrap().dtype;
+            (
+                input_dtype,
+                phys_expr,
+                AggregateFunction::MinMaxBy(MinMaxByAgg::new(value_dtype, *max)),
+            )
+        },
+        AExpr::Function {
+            input,
+            function: FunctionExpr::FirstLastN { n, last },
+            ..
+        } => {
+            let phys_expr = to_physical(&input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            (
+                logical_dtype.clone(),
+                phys_expr,
+                AggregateFunction::FirstLastN(FirstLastNAgg::new(
+                    logical_dtype.to_physical(),
+                    *n,
+                    *last,
+                )),
+            )
+        },
+        #[cfg(feature = "mode")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ModeK { k },
+            ..
+        } => {
+            let phys_expr = to_physical(&input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            (
+                logical_dtype.clone(),
+                phys_expr,
+                AggregateFunction::ModeK(ModeKAgg::new(logical_dtype.to_physical(), *k)),
+            )
+        },
+        AExpr::Function {
+            input,
+            function: FunctionExpr::NUniqueRows { filtered },
+            ..
+        } => {
+            let mut inputs = input
+                .iter()
+                .map(|e| to_physical(e, expr_arena, Some(schema)).unwrap())
+                .collect::<Vec<_>>();
+            let predicate = if *filtered { inputs.pop() } else { None };
+            (
+                DataType::BinaryOffset,
+                Arc::new(NUniqueRowsInput { inputs, predicate }),
+                AggregateFunction::NUniqueRows(NUniqueRowsAgg::new()),
+            )
+        },
+        AExpr::Function {
+            input,
+            function: FunctionExpr::CountIf,
+            ..
+        } => {
+            let input_expr = to_physical(&input[0], expr_arena, Some(schema)).unwrap();
+            let predicate = to_physical(&input[1], expr_arena, Some(schema)).unwrap();
+            let agg_fn = if std::mem::size_of::<IdxSize>() == 4 {
+                AggregateFunction::SumU32(SumAgg::<u32>::new())
+            } else {
+                AggregateFunction::SumU64(SumAgg::<u64>::new())
+            };
+            (
+                IDX_DTYPE,
+                Arc::new(CountIfInput {
+                    input: input_expr,
+                    predicate,
+                }),
+                agg_fn,
+            )
+        },
+        ae @ AExpr::AnonymousFunction { input, .. } if is_udaf(ae) => {
+            let udaf = get_udaf(ae).unwrap();
+            let phys_expr = to_physical(&input[0], expr_arena, Some(schema)).unwrap();
+            let field = phys_expr.field(schema).unwrap();
+            let output_dtype = udaf.output_type(&field).unwrap();
+            (
+                field.dtype.clone(),
+                phys_expr,
+                AggregateFunction::Udaf(UdafAgg::new(udaf, field.dtype, output_dtype)),
+            )
+        },
         AExpr::Agg(agg) => match agg {
             IRAggExpr::Min { input, .. } => {
                 let phys_expr = to_physical(
@@ -292,6 +710,24 @@ where
                     AggregateFunction::Last(LastAgg::new(logical_dtype.to_physical())),
                 )
             },
+            IRAggExpr::NUnique(input) => {
+                let phys_expr = to_physical(
+                    &ExprIR::from_node(*input, expr_arena),
+                    expr_arena,
+                    Some(schema),
+                )
+                .unwrap();
+                // The values are row encoded, so that values of any dtype, including nulls, can
+                // be counted.
+                (
+                    DataType::BinaryOffset,
+                    Arc::new(NUniqueRowsInput {
+                        inputs: vec![phys_expr],
+                        predicate: None,
+                    }),
+                    AggregateFunction::NUniqueRows(NUniqueRowsAgg::new()),
+                )
+            },
             IRAggExpr::Count(input, _) => {
                 let phys_expr = to_physical(
                     &ExprIR::from_node(*input, expr_arena),
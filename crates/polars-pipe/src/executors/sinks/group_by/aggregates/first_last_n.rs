@@ -0,0 +1,108 @@
+use std::any::Any;
+
+use polars_core::datatypes::DataType;
+use polars_core::prelude::{AnyValue, Series};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use crate::executors::sinks::group_by::aggregates::AggregateFn;
+use crate::operators::IdxSize;
+
+/// Collects the first or the `last` `n` values of a group in a list.
+///
+/// Like `ImplodeAgg`, the values are tagged with the index of the chunk they came from. At most
+/// `2 * n` values are buffered: a full buffer is ordered by chunk and truncated to the `n` values
+/// that can still be in the result.
+pub(crate) struct FirstLastNAgg {
+    values: Vec<(IdxSize, AnyValue<'static>)>,
+    n: usize,
+    last: bool,
+    pub(crate) dtype: DataType,
+}
+
+impl FirstLastNAgg {
+    pub(crate) fn new(dtype: DataType, n: usize, last: bool) -> Self {
+        Self {
+            values: vec![],
+            n,
+            last,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.dtype.clone(), self.n, self.last)
+    }
+
+    fn push(&mut self, chunk_idx: IdxSize, av: AnyValue) {
+        if self.n == 0 {
+            return;
+        }
+        self.values.push((chunk_idx, av.into_static().unwrap()));
+        if self.values.len() >= 2 * self.n {
+            self.compact();
+        }
+    }
+
+    /// Order the values by chunk and keep the `n` values that can still be in the result.
+    fn compact(&mut self) {
+        // Stable, so the values of a chunk keep their order.
+        self.values.sort_by_key(|(chunk_idx, _)| *chunk_idx);
+        if self.last {
+            let len = self.values.len();
+            self.values.drain(..len.saturating_sub(self.n));
+        } else {
+            self.values.truncate(self.n);
+        }
+    }
+}
+
+impl AggregateFn for FirstLastNAgg {
+    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.push(chunk_idx, item);
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        // Only the first or last `n` values of the slice can be in the result.
+        let n = length.min(self.n as IdxSize);
+        let start = if self.last {
+            offset + length - n
+        } else {
+            offset
+        };
+        for i in start..start + n {
+            let av = unsafe { values.get_unchecked(i as usize) };
+            self.push(chunk_idx, av);
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::List(Box::new(self.dtype.clone()))
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.values.extend(other.values.iter().cloned());
+        self.compact();
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        self.compact();
+        let values = std::mem::take(&mut self.values)
+            .into_iter()
+            .map(|(_, av)| av)
+            .collect::<Vec<_>>();
+        let s = Series::from_any_values_and_dtype("", &values, &self.dtype, false).unwrap();
+        AnyValue::List(s)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,68 @@
+use std::any::Any;
+
+use polars_core::datatypes::DataType;
+use polars_core::prelude::{AnyValue, Series};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use crate::executors::sinks::group_by::aggregates::AggregateFn;
+use crate::operators::IdxSize;
+
+/// Collects the values of a group in a list.
+///
+/// The values are tagged with the index of the chunk they came from, so that the partial states
+/// of different threads or spilled partitions can be combined in the order of the input.
+pub(crate) struct ImplodeAgg {
+    values: Vec<(IdxSize, AnyValue<'static>)>,
+    pub(crate) dtype: DataType,
+}
+
+impl ImplodeAgg {
+    pub(crate) fn new(dtype: DataType) -> Self {
+        Self {
+            values: vec![],
+            dtype,
+        }
+    }
+}
+
+impl AggregateFn for ImplodeAgg {
+    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.values.push((chunk_idx, item.into_static().unwrap()));
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        self.values.extend((offset..offset + length).map(|i| {
+            let av = unsafe { values.get_unchecked(i as usize) };
+            (chunk_idx, av.into_static().unwrap())
+        }));
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::List(Box::new(self.dtype.clone()))
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.values.extend(other.values.iter().cloned());
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let mut values = std::mem::take(&mut self.values);
+        // Stable, so the values of a chunk keep their order.
+        values.sort_by_key(|(chunk_idx, _)| *chunk_idx);
+        let values = values.into_iter().map(|(_, av)| av).collect::<Vec<_>>();
+        let s = Series::from_any_values_and_dtype("", &values, &self.dtype, false).unwrap();
+        AnyValue::List(s)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
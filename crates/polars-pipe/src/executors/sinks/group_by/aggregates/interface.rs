@@ -5,12 +5,21 @@ use num_traits::NumCast;
 use polars_core::datatypes::DataType;
 use polars_core::prelude::{AnyValue, Series};
 
+use crate::executors::sinks::group_by::aggregates::approx_n_unique::ApproxNUniqueAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
+use crate::executors::sinks::group_by::aggregates::first_last_n::FirstLastNAgg;
+use crate::executors::sinks::group_by::aggregates::implode::ImplodeAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::MinMaxAgg;
+#[cfg(feature = "dtype-struct")]
+use crate::executors::sinks::group_by::aggregates::min_max_by::MinMaxByAgg;
+#[cfg(feature = "mode")]
+use crate::executors::sinks::group_by::aggregates::mode_k::ModeKAgg;
+use crate::executors::sinks::group_by::aggregates::n_unique_rows::NUniqueRowsAgg;
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+use crate::executors::sinks::group_by::aggregates::udaf::UdafAgg;
 use crate::executors::sinks::group_by::aggregates::SumAgg;
 use crate::operators::IdxSize;
 
@@ -43,6 +52,7 @@ pub(crate) trait AggregateFn: Send + Sync {
 // We dispatch via an enum
 // as that saves an indirection
 #[enum_dispatch]
+#[cfg_attr(not(feature = "approx_unique"), allow(dead_code))]
 pub(crate) enum AggregateFunction {
     First(FirstAgg),
     Last(LastAgg),
@@ -67,6 +77,15 @@ pub(crate) enum AggregateFunction {
     MinMaxI16(MinMaxAgg<i16, fn(i16, i16) -> i16>),
     MinMaxI32(MinMaxAgg<i32, fn(i32, i32) -> i32>),
     MinMaxI64(MinMaxAgg<i64, fn(i64, i64) -> i64>),
+    ApproxNUnique(ApproxNUniqueAgg),
+    #[cfg(feature = "dtype-struct")]
+    MinMaxBy(MinMaxByAgg),
+    NUniqueRows(NUniqueRowsAgg),
+    Implode(ImplodeAgg),
+    FirstLastN(FirstLastNAgg),
+    #[cfg(feature = "mode")]
+    ModeK(ModeKAgg),
+    Udaf(UdafAgg),
 }
 
 impl AggregateFunction {
@@ -96,6 +115,15 @@ impl AggregateFunction {
             MinMaxI16(inner) => MinMaxI16(inner.split()),
             MinMaxI32(inner) => MinMaxI32(inner.split()),
             MinMaxI64(inner) => MinMaxI64(inner.split()),
+            ApproxNUnique(_) => ApproxNUnique(ApproxNUniqueAgg::new()),
+            #[cfg(feature = "dtype-struct")]
+            MinMaxBy(inner) => MinMaxBy(inner.split()),
+            NUniqueRows(_) => NUniqueRows(NUniqueRowsAgg::new()),
+            Implode(agg) => Implode(ImplodeAgg::new(agg.dtype.clone())),
+            FirstLastN(agg) => FirstLastN(agg.split()),
+            #[cfg(feature = "mode")]
+            ModeK(agg) => ModeK(agg.split()),
+            Udaf(agg) => Udaf(agg.split()),
         }
     }
 }
@@ -0,0 +1,114 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use polars_core::datatypes::DataType;
+use polars_core::prelude::{AnyValue, Series};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use crate::executors::sinks::group_by::aggregates::AggregateFn;
+use crate::operators::IdxSize;
+
+/// Keeps the value in the row with the minimal or maximal `by` value.
+///
+/// The input is a struct of `(value, by)` pairs, see `MinMaxByInput`. Ties resolve to the row
+/// that came first.
+pub(crate) struct MinMaxByAgg {
+    chunk_idx: IdxSize,
+    by: Option<AnyValue<'static>>,
+    value: AnyValue<'static>,
+    pub(crate) dtype: DataType,
+    max: bool,
+}
+
+impl MinMaxByAgg {
+    pub(crate) fn new(dtype: DataType, max: bool) -> Self {
+        Self {
+            chunk_idx: IdxSize::MAX,
+            by: None,
+            value: AnyValue::Null,
+            dtype,
+            max,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.dtype.clone(), self.max)
+    }
+
+    fn update(&mut self, chunk_idx: IdxSize, value: AnyValue, by: AnyValue) {
+        if by.is_null() {
+            return;
+        }
+        let better = match &self.by {
+            None => true,
+            Some(current) => match by.partial_cmp(current) {
+                Some(Ordering::Greater) => self.max,
+                Some(Ordering::Less) => !self.max,
+                Some(Ordering::Equal) => chunk_idx < self.chunk_idx,
+                None => false,
+            },
+        };
+        if better {
+            self.chunk_idx = chunk_idx;
+            self.by = Some(by.into_static().unwrap());
+            self.value = value.into_static().unwrap();
+        }
+    }
+
+    fn update_struct(&mut self, chunk_idx: IdxSize, pair: AnyValue) {
+        match pair {
+            AnyValue::StructOwned(payload) => {
+                let (mut avs, _) = *payload;
+                let by = avs.pop().unwrap();
+                let value = avs.pop().unwrap();
+                self.update(chunk_idx, value, by)
+            },
+            pair @ AnyValue::Struct(..) => {
+                let mut avs = pair._iter_struct_av();
+                let value = avs.next().unwrap();
+                let by = avs.next().unwrap();
+                self.update(chunk_idx, value, by)
+            },
+            _ => {},
+        }
+    }
+}
+
+impl AggregateFn for MinMaxByAgg {
+    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.update_struct(chunk_idx, item)
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        for i in offset..offset + length {
+            let pair = unsafe { values.get_unchecked(i as usize) };
+            self.update_struct(chunk_idx, pair)
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        self.dtype.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        if let Some(by) = &other.by {
+            self.update(other.chunk_idx, other.value.clone(), by.clone());
+        }
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        std::mem::replace(&mut self.value, AnyValue::Null)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,12 +1,21 @@
+mod approx_n_unique;
 mod convert;
 mod count;
 mod first;
+mod first_last_n;
+mod implode;
 mod interface;
 mod last;
 mod mean;
 mod min_max;
+#[cfg(feature = "dtype-struct")]
+mod min_max_by;
+#[cfg(feature = "mode")]
+mod mode_k;
+mod n_unique_rows;
 mod null;
 mod sum;
+mod udaf;
 
 pub use convert::*;
 pub(crate) use interface::{AggregateFn, AggregateFunction};
@@ -0,0 +1,105 @@
+use std::any::Any;
+
+use polars_core::datatypes::DataType;
+use polars_core::prelude::{AnyValue, Series};
+use polars_utils::aliases::PlHashMap;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use crate::executors::sinks::group_by::aggregates::AggregateFn;
+use crate::operators::IdxSize;
+
+/// Counts the values of a group to find the `k` most frequent ones, ignoring nulls.
+///
+/// Every distinct value keeps its count and its first occurrence: the index of its chunk and its
+/// order within this state. The chunks of a state are disjoint from those of other states, so
+/// equally frequent values are ordered by their first occurrence after the states are combined.
+pub(crate) struct ModeKAgg {
+    counts: PlHashMap<AnyValue<'static>, (IdxSize, (IdxSize, IdxSize))>,
+    seq: IdxSize,
+    k: usize,
+    pub(crate) dtype: DataType,
+}
+
+impl ModeKAgg {
+    pub(crate) fn new(dtype: DataType, k: usize) -> Self {
+        Self {
+            counts: PlHashMap::default(),
+            seq: 0,
+            k,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.dtype.clone(), self.k)
+    }
+
+    fn insert(&mut self, chunk_idx: IdxSize, av: AnyValue) {
+        if av.is_null() {
+            return;
+        }
+        let first = (chunk_idx, self.seq);
+        self.seq += 1;
+        self.counts
+            .entry(av.into_static().unwrap())
+            .and_modify(|(count, _)| *count += 1)
+            .or_insert((1, first));
+    }
+}
+
+impl AggregateFn for ModeKAgg {
+    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.insert(chunk_idx, item);
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        for i in offset..offset + length {
+            let av = unsafe { values.get_unchecked(i as usize) };
+            self.insert(chunk_idx, av);
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::List(Box::new(self.dtype.clone()))
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        for (av, &(count, first)) in other.counts.iter() {
+            self.counts
+                .entry(av.clone())
+                .and_modify(|(c, f)| {
+                    *c += count;
+                    *f = (*f).min(first);
+                })
+                .or_insert((count, first));
+        }
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let mut counts = std::mem::take(&mut self.counts)
+            .into_iter()
+            .collect::<Vec<_>>();
+        counts.sort_unstable_by(|(_, (count_a, first_a)), (_, (count_b, first_b))| {
+            count_b.cmp(count_a).then(first_a.cmp(first_b))
+        });
+        let values = counts
+            .into_iter()
+            .take(self.k)
+            .map(|(av, _)| av)
+            .collect::<Vec<_>>();
+        let s = Series::from_any_values_and_dtype("", &values, &self.dtype, false).unwrap();
+        AnyValue::List(s)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
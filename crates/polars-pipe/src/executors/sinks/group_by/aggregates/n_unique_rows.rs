@@ -0,0 +1,72 @@
+use std::any::Any;
+
+use polars_core::datatypes::{AnyValue, DataType};
+use polars_core::prelude::{Series, IDX_DTYPE};
+use polars_utils::aliases::PlHashSet;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Counts the distinct row encoded values produced by `NUniqueRowsInput`.
+///
+/// Null values are rows that didn't match the predicate, so they are skipped.
+pub(crate) struct NUniqueRowsAgg {
+    seen: PlHashSet<Vec<u8>>,
+}
+
+impl NUniqueRowsAgg {
+    pub(crate) fn new() -> Self {
+        NUniqueRowsAgg {
+            seen: PlHashSet::default(),
+        }
+    }
+
+    fn insert(&mut self, row: &[u8]) {
+        if !self.seen.contains(row) {
+            self.seen.insert(row.to_vec());
+        }
+    }
+}
+
+impl AggregateFn for NUniqueRowsAgg {
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        if let AnyValue::Binary(row) = item {
+            self.insert(row)
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let rows = values.slice(offset as i64, length as usize);
+        let rows = unsafe { rows.binary_offset().unwrap_unchecked_release() };
+        for row in rows.into_iter().flatten() {
+            self.insert(row)
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        IDX_DTYPE
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        for row in &other.seen {
+            self.insert(row)
+        }
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        AnyValue::from(self.seen.len() as IdxSize)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,125 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::datatypes::DataType;
+use polars_core::prelude::{AnyValue, Series};
+use polars_plan::dsl::{AggregateState, UserDefinedAggregate};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use crate::executors::sinks::group_by::aggregates::AggregateFn;
+use crate::operators::IdxSize;
+
+/// The number of values that are buffered before they are added to the state.
+const BUFFER_SIZE: usize = 128;
+
+/// Runs a [`UserDefinedAggregate`].
+///
+/// The generic group by passes the values one by one, those are buffered so that the state is
+/// updated in batches.
+pub(crate) struct UdafAgg {
+    udaf: Arc<dyn UserDefinedAggregate>,
+    state: Box<dyn AggregateState>,
+    buffer: Vec<AnyValue<'static>>,
+    /// The logical dtype of the input.
+    input_dtype: DataType,
+    /// The logical dtype of the output.
+    output_dtype: DataType,
+}
+
+impl UdafAgg {
+    pub(crate) fn new(
+        udaf: Arc<dyn UserDefinedAggregate>,
+        input_dtype: DataType,
+        output_dtype: DataType,
+    ) -> Self {
+        Self {
+            state: udaf.init(),
+            udaf,
+            buffer: vec![],
+            input_dtype,
+            output_dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(
+            self.udaf.clone(),
+            self.input_dtype.clone(),
+            self.output_dtype.clone(),
+        )
+    }
+
+    /// Update the state with `values`, which have the physical dtype of the input.
+    fn update(&mut self, values: &Series) {
+        let values = unsafe { values.cast_unchecked(&self.input_dtype).unwrap() };
+        self.state
+            .update(&values)
+            .expect("user-defined aggregate failed to update");
+    }
+
+    fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let values = Series::from_any_values_and_dtype(
+            "",
+            &self.buffer,
+            &self.input_dtype.to_physical(),
+            true,
+        )
+        .unwrap();
+        self.buffer.clear();
+        self.update(&values)
+    }
+}
+
+impl AggregateFn for UdafAgg {
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.buffer.push(item.into_static().unwrap());
+        if self.buffer.len() >= BUFFER_SIZE {
+            self.flush()
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        self.flush();
+        self.update(&values.slice(offset as i64, length as usize))
+    }
+
+    fn dtype(&self) -> DataType {
+        self.output_dtype.to_physical()
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.state
+            .merge(other.state.as_ref())
+            .expect("user-defined aggregate failed to merge");
+        self.buffer.extend(other.buffer.iter().cloned());
+        if self.buffer.len() >= BUFFER_SIZE {
+            self.flush()
+        }
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        self.flush();
+        let value = self
+            .state
+            .finalize()
+            .expect("user-defined aggregate failed to finalize");
+        // The group by collects the physical values.
+        let s = Series::from_any_values_and_dtype("", &[value], &self.output_dtype, true).unwrap();
+        s.to_physical_repr().get(0).unwrap().into_static().unwrap()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
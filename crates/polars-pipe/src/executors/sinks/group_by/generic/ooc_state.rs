@@ -55,6 +55,7 @@ impl OocState {
             eprintln!("OOC group_by started");
         }
         self.ooc = true;
+        self.mem_track.record_spill();
 
         // start IO thread
         let mut iot = self.io_thread.lock().unwrap();
@@ -64,6 +65,16 @@ impl OocState {
         Ok(())
     }
 
+    /// Reserve the memory of `chunk` against the budget of the query, if it has one. The size of
+    /// the input is an upper bound of what the tables grow by, so a budget spills early rather
+    /// than late.
+    pub(super) fn reserve_chunk(&self, context: &PExecutionContext, chunk: &DataChunk) {
+        if context.execution_state.memory_budget().is_some() {
+            self.mem_track.bind_budget(context);
+            self.mem_track.fetch_add(chunk.data.estimated_size());
+        }
+    }
+
     pub(super) fn check_memory_usage(
         &mut self,
         spill_schema: &dyn Fn() -> Option<Schema>,
@@ -91,9 +102,16 @@ impl OocState {
         }
     }
 
-    pub(super) fn dump(&self, partition_no: usize, df: DataFrame) {
+    pub(super) fn dump(
+        &self,
+        context: &PExecutionContext,
+        partition_no: usize,
+        df: DataFrame,
+    ) -> PolarsResult<()> {
         let iot = self.io_thread.lock().unwrap();
         let iot = iot.as_ref().unwrap();
-        iot.dump_partition(partition_no as IdxSize, df)
+        iot.check_spill_limit(context)?;
+        iot.dump_partition(partition_no as IdxSize, df);
+        Ok(())
     }
 }
@@ -62,6 +62,7 @@ impl Sink for GenericGroupby2 {
         if chunk.is_empty() {
             return Ok(SinkResult::CanHaveMoreInput);
         }
+        self.ooc_state.reserve_chunk(context, &chunk);
         // load data and hashes
         unsafe {
             // SAFETY: we don't hold mutable refs
@@ -103,7 +104,7 @@ impl Sink for GenericGroupby2 {
             SpillAction::EarlyMerge => self.global_table.early_merge(),
             SpillAction::Dump => {
                 if let Some((partition_no, spill)) = self.global_table.get_ooc_dump() {
-                    self.ooc_state.dump(partition_no, spill)
+                    self.ooc_state.dump(context, partition_no, spill)?
                 } else {
                     // do nothing
                 }
@@ -164,6 +165,7 @@ impl Sink for GenericGroupby2 {
             // create an ooc source
             else {
                 Ok(FinalizedSink::Source(Box::new(GroupBySource::new(
+                    context,
                     &self.ooc_state.io_thread,
                     self.slice,
                     self.global_table.clone(),
@@ -18,6 +18,7 @@ pub(super) struct GroupBySource {
 
 impl GroupBySource {
     pub(super) fn new(
+        context: &PExecutionContext,
         io_thread: &IOThreadRef,
         slice: Option<(i64, usize)>,
         global_table: Arc<GlobalTable>,
@@ -30,6 +31,10 @@ impl GroupBySource {
         }
 
         block_thread_until_io_thread_done(&io_thread);
+        io_thread.check_spill_limit(context)?;
+        if context.verbose {
+            eprintln!("OOC group_by spilled {} bytes", io_thread.spilled_bytes());
+        }
         Ok(Self {
             _io_thread: io_thread,
             slice,
@@ -1,7 +1,8 @@
 use std::fs;
 use std::fs::File;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::OnceLock;
 use std::time::{Duration, SystemTime};
 
 use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
@@ -9,8 +10,11 @@ use polars_core::error::ErrString;
 use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
 use polars_io::prelude::*;
+use polars_utils::spill::SpillTracker;
+use polars_utils::sys::process_is_running;
 
 use crate::executors::sinks::get_base_temp_dir;
+use crate::operators::PExecutionContext;
 use crate::pipeline::morsels_per_sink;
 
 pub(in crate::executors::sinks) type DfIter =
@@ -28,18 +32,26 @@ pub(crate) struct IOThread {
     pub(in crate::executors::sinks) total: Arc<AtomicUsize>,
     pub(in crate::executors::sinks) thread_local_count: Arc<AtomicUsize>,
     schema: SchemaRef,
+    operation_name: &'static str,
+    // bytes written to the spill directory
+    spilled: Arc<AtomicUsize>,
+    // the spills of the query this operation is accounted to
+    spill_tracker: OnceLock<SpillTracker>,
 }
 
+/// Whether the spill directories of all operations were cleaned by this process.
+static CLEANED_ALL_SPILL_DIRS: AtomicBool = AtomicBool::new(false);
+
 fn get_lockfile_path(dir: &Path) -> PathBuf {
     let mut lockfile_path = dir.to_path_buf();
     lockfile_path.push(".lock");
     lockfile_path
 }
 
-fn get_spill_dir(operation_name: &'static str) -> PolarsResult<PathBuf> {
+fn get_spill_dir(operation_name: &'static str, base_dir: &str) -> PolarsResult<PathBuf> {
     let id = uuid::Uuid::new_v4();
 
-    let mut dir = std::path::PathBuf::from(get_base_temp_dir());
+    let mut dir = std::path::PathBuf::from(base_dir);
     dir.push(&format!("polars/{operation_name}/{id}"));
 
     if !dir.exists() {
@@ -58,6 +70,12 @@ fn get_spill_dir(operation_name: &'static str) -> PolarsResult<PathBuf> {
     Ok(dir)
 }
 
+fn add_file_size(spilled: &AtomicUsize, path: &Path) {
+    if let Ok(md) = std::fs::metadata(path) {
+        spilled.fetch_add(md.len() as usize, Ordering::Relaxed);
+    }
+}
+
 fn clean_after_delay(time: Option<SystemTime>, secs: u64, path: &Path) {
     if let Some(time) = time {
         let modified_since = SystemTime::now().duration_since(time).unwrap().as_secs();
@@ -71,44 +89,73 @@ fn clean_after_delay(time: Option<SystemTime>, secs: u64, path: &Path) {
     }
 }
 
-/// Starts a new thread that will clean up operations of directories that don't
-/// have a lockfile (opened with 'w' permissions).
-fn gc_thread(operation_name: &'static str, rx: Receiver<PathBuf>) {
+/// Whether the lockfile was written by a process that is not running anymore, i.e. a run that
+/// crashed.
+fn is_orphaned(lockfile_path: &Path) -> bool {
+    match std::fs::read_to_string(lockfile_path).map(|pid| pid.trim().parse::<u32>()) {
+        Ok(Ok(pid)) => pid != std::process::id() && !process_is_running(pid),
+        _ => false,
+    }
+}
+
+/// Clean up the spill directories of an operation that don't have a lockfile (opened with 'w'
+/// permissions), or of which the lockfile is orphaned.
+fn clean_spill_dirs(dir: &Path) {
+    // if the directory does not exist, there is nothing to clean
+    let rd = match std::fs::read_dir(dir) {
+        Ok(rd) => rd,
+        _ => panic!("cannot find {:?}", dir),
+    };
+
+    for entry in rd {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            let lockfile_path = get_lockfile_path(&path);
+
+            if is_orphaned(&lockfile_path) {
+                // This can be fallible if another process removes this.
+                let _ = std::fs::remove_dir_all(&path);
+            } else if let Ok(lockfile) = File::open(lockfile_path) {
+                // lockfile can be read
+                if let Ok(md) = lockfile.metadata() {
+                    let time = md.modified().ok();
+                    // The lockfile can still exist if a process was canceled
+                    // so we also check the modified date
+                    // we don't expect queries that run a month.
+                    clean_after_delay(time, SECONDS_IN_DAY as u64 * 30, &path);
+                }
+            } else {
+                // If path already removed, we simply continue.
+                if let Ok(md) = path.metadata() {
+                    let time = md.modified().ok();
+                    // Wait 15 seconds to ensure we don't remove before lockfile is created
+                    // in a `collect_all` contention case
+                    clean_after_delay(time, 15, &path);
+                }
+            }
+        }
+    }
+}
+
+/// Starts a new thread that will clean up the spill directories of the operation, and then the
+/// paths it receives. The first thread of a process cleans up the spill directories of all
+/// operations, which removes the spills of runs that crashed.
+fn gc_thread(operation_name: &'static str, base_dir: String, rx: Receiver<PathBuf>) {
     let _ = std::thread::spawn(move || {
         // First clean all existing
-        let mut dir = std::path::PathBuf::from(get_base_temp_dir());
-        dir.push(&format!("polars/{operation_name}"));
-
-        // if the directory does not exist, there is nothing to clean
-        let rd = match std::fs::read_dir(&dir) {
-            Ok(rd) => rd,
-            _ => panic!("cannot find {:?}", dir),
-        };
-
-        for entry in rd {
-            let path = entry.unwrap().path();
-            if path.is_dir() {
-                let lockfile_path = get_lockfile_path(&path);
-
-                if let Ok(lockfile) = File::open(lockfile_path) {
-                    // lockfile can be read
-                    if let Ok(md) = lockfile.metadata() {
-                        let time = md.modified().ok();
-                        // The lockfile can still exist if a process was canceled
-                        // so we also check the modified date
-                        // we don't expect queries that run a month.
-                        clean_after_delay(time, SECONDS_IN_DAY as u64 * 30, &path);
-                    }
-                } else {
-                    // If path already removed, we simply continue.
-                    if let Ok(md) = path.metadata() {
-                        let time = md.modified().ok();
-                        // Wait 15 seconds to ensure we don't remove before lockfile is created
-                        // in a `collect_all` contention case
-                        clean_after_delay(time, 15, &path);
+        let mut dir = std::path::PathBuf::from(base_dir);
+        dir.push("polars");
+
+        if !CLEANED_ALL_SPILL_DIRS.swap(true, Ordering::Relaxed) {
+            if let Ok(rd) = std::fs::read_dir(&dir) {
+                for entry in rd.flatten() {
+                    if entry.path().is_dir() {
+                        clean_spill_dirs(&entry.path());
                     }
                 }
             }
+        } else {
+            clean_spill_dirs(&dir.join(operation_name));
         }
 
         // Clean on receive
@@ -131,7 +178,8 @@ impl IOThread {
         // Will be used as subdirectory name in `~/.base_dir/polars/`
         operation_name: &'static str,
     ) -> PolarsResult<Self> {
-        let dir = get_spill_dir(operation_name)?;
+        let base_dir = get_base_temp_dir();
+        let dir = get_spill_dir(operation_name, &base_dir)?;
 
         // make sure we create lockfile before we GC
         let lockfile_path = get_lockfile_path(&dir);
@@ -140,7 +188,7 @@ impl IOThread {
         let (cleanup_tx, rx) = unbounded::<PathBuf>();
         // start a thread that will clean up old dumps.
         // TODO: if we will have more ooc in the future  we will have a dedicated GC thread
-        gc_thread(operation_name, rx);
+        gc_thread(operation_name, base_dir, rx);
 
         // we need some pushback otherwise we still could go OOM.
         let (tx, rx) = bounded::<Payload>(morsels_per_sink() * 2);
@@ -149,8 +197,11 @@ impl IOThread {
         let total: Arc<AtomicUsize> = Default::default();
         let thread_local_count: Arc<AtomicUsize> = Default::default();
 
+        let spilled: Arc<AtomicUsize> = Default::default();
+
         let dir2 = dir.clone();
         let total2 = total.clone();
+        let spilled2 = spilled.clone();
         let lockfile2 = lockfile.clone();
         let schema2 = schema.clone();
         std::thread::spawn(move || {
@@ -176,18 +227,19 @@ impl IOThread {
                         let _ = std::fs::create_dir(&path);
                         path.push(format!("{count}.ipc"));
 
-                        let file = File::create(path).unwrap();
+                        let file = File::create(&path).unwrap();
                         let writer = IpcWriter::new(file).with_pl_flavor(true);
                         let mut writer = writer.batched(&schema).unwrap();
                         writer.write_batch(&df).unwrap();
                         writer.finish().unwrap();
+                        add_file_size(&spilled2, &path);
                         count += 1;
                     }
                 } else {
                     let mut path = dir2.clone();
                     path.push(format!("{count}_0_pass.ipc"));
 
-                    let file = File::create(path).unwrap();
+                    let file = File::create(&path).unwrap();
                     let writer = IpcWriter::new(file).with_pl_flavor(true);
                     let mut writer = writer.batched(&schema).unwrap();
 
@@ -196,6 +248,7 @@ impl IOThread {
                         writer.write_batch(&df).unwrap();
                     }
                     writer.finish().unwrap();
+                    add_file_size(&spilled2, &path);
 
                     count += 1;
                 }
@@ -212,9 +265,31 @@ impl IOThread {
             _lockfile: lockfile,
             thread_local_count,
             schema,
+            operation_name,
+            spilled,
+            spill_tracker: OnceLock::new(),
         })
     }
 
+    /// Account the spills of this operation to the query of `context`, and error if the query
+    /// spilled more than its limit. This must be called before every spill that can be refused.
+    pub(in crate::executors::sinks) fn check_spill_limit(
+        &self,
+        context: &PExecutionContext,
+    ) -> PolarsResult<()> {
+        let tracker = self.spill_tracker.get_or_init(|| {
+            let tracker = context.execution_state.spill_tracker().clone();
+            tracker.register(self.operation_name, self.spilled.clone());
+            tracker
+        });
+        tracker.check_limit()
+    }
+
+    /// The bytes this operation wrote to disk.
+    pub(in crate::executors::sinks) fn spilled_bytes(&self) -> usize {
+        self.spilled.load(Ordering::Relaxed)
+    }
+
     pub(in crate::executors::sinks) fn dump_chunk(&self, mut df: DataFrame) {
         // if IO thread is blocked
         // we write locally on this thread
@@ -226,9 +301,10 @@ impl IOThread {
             // duplicates
             path.push(format!("_{count}_full.ipc"));
 
-            let file = File::create(path).unwrap();
+            let file = File::create(&path).unwrap();
             let mut writer = IpcWriter::new(file).with_pl_flavor(true);
             writer.finish(&mut df).unwrap();
+            add_file_size(&self.spilled, &path);
         } else {
             let iter = Box::new(std::iter::once(df));
             self.dump_iter(None, iter)
@@ -259,11 +335,12 @@ impl IOThread {
         // thread local name we start with an underscore to ensure we don't get
         // duplicates
         path.push(format!("_{count}.ipc"));
-        let file = File::create(path).unwrap();
+        let file = File::create(&path).unwrap();
         let writer = IpcWriter::new(file).with_pl_flavor(true);
         let mut writer = writer.batched(&self.schema).unwrap();
         writer.write_batch(&df).unwrap();
         writer.finish().unwrap();
+        add_file_size(&self.spilled, &path);
     }
 
     pub(in crate::executors::sinks) fn dump_iter(&self, partition: Option<IdxCa>, iter: DfIter) {
@@ -295,7 +372,8 @@ struct LockFile {
 
 impl LockFile {
     fn new(path: PathBuf) -> PolarsResult<Self> {
-        match File::create(&path) {
+        // The id of the process tells whether the spills are orphaned.
+        match std::fs::write(&path, std::process::id().to_string()) {
             Ok(_) => Ok(Self { path }),
             Err(e) => {
                 polars_bail!(ComputeError: "could not create lockfile: {e}")
@@ -0,0 +1,65 @@
+//! Join keys are compared by their physical representation. The physicals of a local categorical
+//! index into a rev-map that can differ per chunk, so they are translated to ids that are shared
+//! by all chunks of both sides of the join. Only the categories of a rev-map are looked up, the
+//! keys themselves are never decoded to strings.
+#[cfg(feature = "dtype-categorical")]
+use std::sync::Mutex;
+
+#[cfg(feature = "dtype-categorical")]
+use arrow::array::Utf8ViewArray;
+use polars_core::prelude::*;
+
+#[cfg(feature = "dtype-categorical")]
+struct State {
+    categories: CategoricalChunkedBuilder,
+    // The ids of the categories of every rev-map seen so far, indexed by physical and keyed by the
+    // hash of the rev-map. Chunks of the same source generally share their rev-map.
+    ids: PlHashMap<u128, Arc<[u32]>>,
+}
+
+#[derive(Default)]
+pub(super) struct CategoryIds {
+    #[cfg(feature = "dtype-categorical")]
+    state: Mutex<Option<State>>,
+}
+
+impl CategoryIds {
+    #[cfg(feature = "dtype-categorical")]
+    fn get(&self, categories: &Utf8ViewArray, hash: u128) -> Arc<[u32]> {
+        let mut state = self.state.lock().unwrap();
+        let state = state.get_or_insert_with(|| State {
+            categories: CategoricalChunkedBuilder::new("", 0, Default::default()),
+            ids: Default::default(),
+        });
+        let State {
+            categories: ids_of,
+            ids,
+        } = state;
+        ids.entry(hash)
+            .or_insert_with(|| {
+                categories
+                    .values_iter()
+                    .map(|s| ids_of.register_value(s).0)
+                    .collect()
+            })
+            .clone()
+    }
+
+    /// The physical representation of the join key `s`. Equal keys get equal physicals in all
+    /// chunks that are translated by the same [`CategoryIds`].
+    pub(super) fn to_physical(&self, s: &Series) -> Series {
+        #[cfg(feature = "dtype-categorical")]
+        if let DataType::Categorical(Some(rev_map), _) = s.dtype() {
+            if let RevMapping::Local(categories, hash) = rev_map.as_ref() {
+                let ids = self.get(categories, *hash);
+                let ca = s.categorical().unwrap();
+                // Null slots may hold any value, so they can be out of bounds.
+                return ca
+                    .physical()
+                    .apply_values(|v| ids.get(v as usize).copied().unwrap_or_default())
+                    .into_series();
+            }
+        }
+        s.to_physical_repr().into_owned()
+    }
+}
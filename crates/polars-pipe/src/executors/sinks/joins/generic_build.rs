@@ -13,6 +13,7 @@ use smartstring::alias::String as SmartString;
 
 use super::*;
 use crate::executors::operators::PlaceHolder;
+use crate::executors::sinks::joins::categorical::CategoryIds;
 use crate::executors::sinks::joins::generic_probe_inner_left::GenericJoinProbe;
 use crate::executors::sinks::joins::generic_probe_outer::GenericFullOuterJoinProbe;
 use crate::executors::sinks::utils::{hash_rows, load_vec};
@@ -43,6 +44,8 @@ pub struct GenericBuild<K: ExtraPayload> {
     // the columns that will be joined on
     join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
     join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    // shared by all threads of both sides of the join
+    category_ids: Arc<CategoryIds>,
 
     // amortize allocations
     join_columns: Vec<ArrayRef>,
@@ -83,6 +86,7 @@ impl<K: ExtraPayload> GenericBuild<K> {
             swapped,
             join_columns_left,
             join_columns_right,
+            category_ids: Default::default(),
             join_columns: vec![],
             materialized_join_cols: vec![],
             hash_tables,
@@ -140,7 +144,12 @@ impl<K: ExtraPayload> GenericBuild<K> {
         debug_assert!(self.join_columns.is_empty());
         for phys_e in self.join_columns_left.iter() {
             let s = phys_e.evaluate(chunk, &context.execution_state)?;
-            let arr = s.to_physical_repr().rechunk().array_ref(0).clone();
+            let arr = self
+                .category_ids
+                .to_physical(&s)
+                .rechunk()
+                .array_ref(0)
+                .clone();
             self.join_columns.push(arr);
         }
         let rows_encoded = polars_row::convert_columns_no_order(&self.join_columns).into_array();
@@ -290,6 +299,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
             self.placeholder.clone(),
         );
         new.hb = self.hb.clone();
+        new.category_ids = self.category_ids.clone();
         Box::new(new)
     }
 
@@ -306,6 +316,14 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         // Reallocate to Arc<[]> to get rid of double indirection as this is accessed on every
         // hashtable cmp.
         let materialized_join_cols = Arc::from(std::mem::take(&mut self.materialized_join_cols));
+
+        let validation = self.join_args.validation;
+        let (build_unique, probe_unique) = unique_sides(validation, self.swapped);
+        if build_unique {
+            validate_build(&self.hash_tables, validation)?;
+        }
+        let probe_keys = probe_unique.then(|| Arc::new(ProbeKeys::new(validation)));
+
         let suffix = self.suffix.clone();
         let hb = self.hb.clone();
         let hash_tables = Arc::new(PartitionedHashMap::new(std::mem::take(
@@ -328,11 +346,13 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                     hash_tables,
                     join_columns_left,
                     join_columns_right,
+                    self.category_ids.clone(),
                     self.swapped,
                     hashes,
                     context,
                     self.join_args.clone(),
                     self.join_nulls,
+                    probe_keys,
                 );
                 self.placeholder.replace(Box::new(probe_operator));
                 Ok(FinalizedSink::Operator)
@@ -346,12 +366,15 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                     hb,
                     hash_tables,
                     join_columns_left,
+                    self.category_ids.clone(),
                     self.swapped,
                     hashes,
                     self.join_nulls,
                     coalesce,
                     self.key_names_left.clone(),
                     self.key_names_right.clone(),
+                    probe_keys,
+                    context,
                 );
                 self.placeholder.replace(Box::new(probe_operator));
                 Ok(FinalizedSink::Operator)
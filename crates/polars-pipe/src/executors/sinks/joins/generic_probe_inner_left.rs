@@ -7,12 +7,16 @@ use polars_core::series::IsSorted;
 use polars_ops::chunked_array::DfTake;
 use polars_ops::frame::join::_finish_join;
 use polars_ops::prelude::{JoinArgs, JoinType};
+use polars_utils::interrupt::InterruptCheck;
 use polars_utils::nulls::IsNull;
 use smartstring::alias::String as SmartString;
 
+use crate::executors::sinks::joins::categorical::CategoryIds;
 use crate::executors::sinks::joins::generic_build::*;
 use crate::executors::sinks::joins::row_values::RowValues;
-use crate::executors::sinks::joins::{ExtraPayload, PartitionedMap, ToRow};
+use crate::executors::sinks::joins::{
+    ExtraPayload, PartitionedMap, ProbeKeys, ToRow, INTERRUPT_CHECK_INTERVAL,
+};
 use crate::executors::sinks::utils::hash_rows;
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
@@ -51,6 +55,9 @@ pub struct GenericJoinProbe<K: ExtraPayload> {
     args: JoinArgs,
     join_nulls: bool,
     row_values: RowValues,
+    interrupt: InterruptCheck,
+    /// The keys probed so far, if they must be unique.
+    probe_keys: Option<Arc<ProbeKeys>>,
 }
 
 impl<K: ExtraPayload> GenericJoinProbe<K> {
@@ -63,12 +70,14 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
         hash_tables: Arc<PartitionedMap<K>>,
         join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
         join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        category_ids: Arc<CategoryIds>,
         swapped_or_left: bool,
         // Re-use the hashes allocation of the build side.
         amortized_hashes: Vec<u64>,
         context: &PExecutionContext,
         args: JoinArgs,
         join_nulls: bool,
+        probe_keys: Option<Arc<ProbeKeys>>,
     ) -> Self {
         if swapped_or_left && args.should_coalesce() {
             let tmp = DataChunk {
@@ -103,7 +112,9 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
             output_names: None,
             args,
             join_nulls,
-            row_values: RowValues::new(join_columns_right, !swapped_or_left),
+            row_values: RowValues::new(join_columns_right, category_ids, !swapped_or_left),
+            interrupt: InterruptCheck::from_token(context.execution_state.cancel_token()),
+            probe_keys,
         }
     }
 
@@ -137,7 +148,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
         })
     }
 
-    fn match_left<'b, I, T>(&mut self, iter: I)
+    fn match_left<'b, I, T>(&mut self, iter: I) -> PolarsResult<()>
     where
         I: Iterator<Item = (usize, (&'b u64, T))> + 'b,
         T: IsNull
@@ -146,6 +157,9 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
             + ToRow,
     {
         for (i, (h, row)) in iter {
+            if i % INTERRUPT_CHECK_INTERVAL == 0 {
+                self.interrupt.check()?;
+            }
             let df_idx_left = i as IdxSize;
 
             let entry = if row.is_null() {
@@ -173,6 +187,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
                 },
             }
         }
+        Ok(())
     }
 
     fn execute_left(
@@ -191,13 +206,16 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
             .row_values
             .get_values(context, chunk, self.join_nulls)?;
         hash_rows(&rows, &mut hashes, &self.hb);
+        if let Some(probe_keys) = &self.probe_keys {
+            probe_keys.insert(&rows, &hashes)?;
+        }
 
         if self.join_nulls || rows.null_count() == 0 {
             let iter = hashes.iter().zip(rows.values_iter()).enumerate();
-            self.match_left(iter);
+            self.match_left(iter)?;
         } else {
             let iter = hashes.iter().zip(rows.iter()).enumerate();
-            self.match_left(iter);
+            self.match_left(iter)?;
         }
         self.hashes = hashes;
         let right_df = self.df_a.as_ref();
@@ -220,11 +238,14 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
         Ok(OperatorResult::Finished(chunk.with_data(out)))
     }
 
-    fn match_inner<'b, I>(&mut self, iter: I)
+    fn match_inner<'b, I>(&mut self, iter: I) -> PolarsResult<()>
     where
         I: Iterator<Item = (usize, (&'b u64, &'b [u8]))> + 'b,
     {
         for (i, (h, row)) in iter {
+            if i % INTERRUPT_CHECK_INTERVAL == 0 {
+                self.interrupt.check()?;
+            }
             let df_idx_right = i as IdxSize;
 
             let entry = self
@@ -242,6 +263,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
                     .extend(std::iter::repeat(df_idx_right).take(indexes_left.len()));
             }
         }
+        Ok(())
     }
 
     fn execute_inner(
@@ -256,17 +278,20 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
             .row_values
             .get_values(context, chunk, self.join_nulls)?;
         hash_rows(&rows, &mut hashes, &self.hb);
+        if let Some(probe_keys) = &self.probe_keys {
+            probe_keys.insert(&rows, &hashes)?;
+        }
 
         if self.join_nulls || rows.null_count() == 0 {
             let iter = hashes.iter().zip(rows.values_iter()).enumerate();
-            self.match_inner(iter);
+            self.match_inner(iter)?;
         } else {
             let iter = hashes
                 .iter()
                 .zip(rows.iter())
                 .enumerate()
                 .filter_map(|(i, (h, row))| row.map(|row| (i, (h, row))));
-            self.match_inner(iter);
+            self.match_inner(iter)?;
         }
         self.hashes = hashes;
 
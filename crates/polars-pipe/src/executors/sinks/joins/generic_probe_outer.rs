@@ -7,11 +7,13 @@ use polars_core::series::IsSorted;
 use polars_ops::chunked_array::DfTake;
 use polars_ops::frame::join::_finish_join;
 use polars_ops::prelude::_coalesce_full_join;
+use polars_utils::interrupt::InterruptCheck;
 use smartstring::alias::String as SmartString;
 
+use crate::executors::sinks::joins::categorical::CategoryIds;
 use crate::executors::sinks::joins::generic_build::*;
 use crate::executors::sinks::joins::row_values::RowValues;
-use crate::executors::sinks::joins::PartitionedMap;
+use crate::executors::sinks::joins::{PartitionedMap, ProbeKeys, INTERRUPT_CHECK_INTERVAL};
 use crate::executors::sinks::utils::hash_rows;
 use crate::executors::sinks::ExtraPayload;
 use crate::expressions::PhysicalPipedExpr;
@@ -56,6 +58,9 @@ pub struct GenericFullOuterJoinProbe<K: ExtraPayload> {
     row_values: RowValues,
     key_names_left: Arc<[SmartString]>,
     key_names_right: Arc<[SmartString]>,
+    interrupt: InterruptCheck,
+    /// The keys probed so far, if they must be unique.
+    probe_keys: Option<Arc<ProbeKeys>>,
 }
 
 impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
@@ -67,6 +72,7 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
         hb: RandomState,
         hash_tables: Arc<PartitionedMap<K>>,
         join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        category_ids: Arc<CategoryIds>,
         swapped: bool,
         // Re-use the hashes allocation of the build side.
         amortized_hashes: Vec<u64>,
@@ -74,6 +80,8 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
         coalesce: bool,
         key_names_left: Arc<[SmartString]>,
         key_names_right: Arc<[SmartString]>,
+        probe_keys: Option<Arc<ProbeKeys>>,
+        context: &PExecutionContext,
     ) -> Self {
         GenericFullOuterJoinProbe {
             df_a: Arc::new(df_a),
@@ -90,9 +98,11 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
             join_nulls,
             coalesce,
             thread_no: 0,
-            row_values: RowValues::new(join_columns_right, false),
+            row_values: RowValues::new(join_columns_right, category_ids, false),
             key_names_left,
             key_names_right,
+            interrupt: InterruptCheck::from_token(context.execution_state.cancel_token()),
+            probe_keys,
         }
     }
 
@@ -170,11 +180,14 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
         }
     }
 
-    fn match_outer<'b, I>(&mut self, iter: I)
+    fn match_outer<'b, I>(&mut self, iter: I) -> PolarsResult<()>
     where
         I: Iterator<Item = (usize, (&'b u64, &'b [u8]))> + 'b,
     {
         for (i, (h, row)) in iter {
+            if i % INTERRUPT_CHECK_INTERVAL == 0 {
+                self.interrupt.check()?;
+            }
             let df_idx_right = i as IdxSize;
 
             let entry = self
@@ -197,6 +210,7 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
                 self.join_tuples_b.push_value(df_idx_right);
             }
         }
+        Ok(())
     }
 
     fn execute_outer(
@@ -216,17 +230,20 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
             .row_values
             .get_values(context, chunk, self.join_nulls)?;
         hash_rows(&rows, &mut hashes, &self.hb);
+        if let Some(probe_keys) = &self.probe_keys {
+            probe_keys.insert(&rows, &hashes)?;
+        }
 
         if self.join_nulls || rows.null_count() == 0 {
             let iter = hashes.iter().zip(rows.values_iter()).enumerate();
-            self.match_outer(iter);
+            self.match_outer(iter)?;
         } else {
             let iter = hashes
                 .iter()
                 .zip(rows.iter())
                 .enumerate()
                 .filter_map(|(i, (h, row))| row.map(|row| (i, (h, row))));
-            self.match_outer(iter);
+            self.match_outer(iter)?;
         }
         self.hashes = hashes;
 
@@ -252,8 +269,10 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
         let n = ht.len();
         self.join_tuples_a.clear();
 
-        ht.iter().enumerate().for_each(|(i, ht)| {
+        for (i, ht) in ht.iter().enumerate() {
             if i % n == self.thread_no {
+                // The flush visits the whole build table, check between the partitions.
+                self.interrupt.check()?;
                 ht.iter().for_each(|(_k, (idx_left, tracker))| {
                     let found_match = tracker.get_tracker().load(Ordering::Relaxed);
 
@@ -262,7 +281,7 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
                     }
                 })
             }
-        });
+        }
 
         let left_df = unsafe {
             self.df_a
@@ -1,3 +1,4 @@
+mod categorical;
 #[cfg(feature = "cross_join")]
 mod cross;
 mod generic_build;
@@ -6,18 +7,27 @@ mod generic_probe_outer;
 mod row_values;
 
 use std::hash::{BuildHasherDefault, Hash, Hasher};
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use arrow::array::BinaryArray;
 
 #[cfg(feature = "cross_join")]
 pub(crate) use cross::*;
 pub(crate) use generic_build::GenericBuild;
 use polars_core::hashing::IdHasher;
-use polars_core::prelude::IdxSize;
-use polars_ops::prelude::JoinType;
+use polars_core::prelude::*;
+use polars_core::utils::_set_partition_size;
+use polars_ops::prelude::{JoinType, JoinValidation};
+use polars_utils::hashing::hash_to_partition;
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::index::ChunkId;
 use polars_utils::partitioned::PartitionedHashMap;
 
+/// The number of probed rows between checks whether the query is cancelled. A single row can
+/// match many rows, so the probe of a chunk isn't bounded by the size of the chunk.
+const INTERRUPT_CHECK_INTERVAL: usize = 1 << 12;
+
 trait ToRow {
     fn get_row(&self) -> &[u8];
 }
@@ -99,3 +109,72 @@ impl ExtraPayload for Tracker {
 
 type PartitionedMap<V> =
     PartitionedHashMap<Key, (UnitVec<ChunkId>, V), BuildHasherDefault<IdHasher>>;
+
+/// Whether the keys of the build side and of the probe side of a join must be unique to fulfil
+/// `validation`. The build side holds the right table if the join order is swapped.
+fn unique_sides(validation: JoinValidation, swapped: bool) -> (bool, bool) {
+    use JoinValidation::*;
+    let left = matches!(validation, OneToMany | OneToOne);
+    let right = matches!(validation, ManyToOne | OneToOne);
+    if swapped {
+        (right, left)
+    } else {
+        (left, right)
+    }
+}
+
+/// Check that every key of the build side is unique.
+fn validate_build<K>(
+    hash_tables: &PartitionedMap<K>,
+    validation: JoinValidation,
+) -> PolarsResult<()> {
+    let valid = hash_tables
+        .inner()
+        .iter()
+        .all(|ht| ht.values().all(|(idx, _)| idx.len() == 1));
+    polars_ensure!(valid, ComputeError: "the join keys did not fulfil {} validation", validation);
+    Ok(())
+}
+
+/// The keys that all threads of the probe side of a join have seen, to check that every key of
+/// the probe side is unique. Like the in-memory join, null keys are equal to each other.
+pub(super) struct ProbeKeys {
+    validation: JoinValidation,
+    partitions: Vec<Mutex<PlHashSet<Box<[u8]>>>>,
+    seen_null: AtomicBool,
+}
+
+impl ProbeKeys {
+    fn new(validation: JoinValidation) -> Self {
+        let partitions = (0.._set_partition_size())
+            .map(|_| Default::default())
+            .collect();
+        Self {
+            validation,
+            partitions,
+            seen_null: AtomicBool::new(false),
+        }
+    }
+
+    /// Add the keys of a chunk, raises if one of them was seen before.
+    fn insert(&self, rows: &BinaryArray<i64>, hashes: &[u64]) -> PolarsResult<()> {
+        let n_partitions = self.partitions.len();
+        for (row, h) in rows.iter().zip(hashes) {
+            let is_new = match row {
+                Some(row) => {
+                    let partition = hash_to_partition(*h, n_partitions);
+                    self.partitions[partition]
+                        .lock()
+                        .unwrap()
+                        .insert(row.into())
+                },
+                None => !self.seen_null.swap(true, Ordering::Relaxed),
+            };
+            polars_ensure!(
+                is_new,
+                ComputeError: "the join keys did not fulfil {} validation", self.validation
+            );
+        }
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use arrow::compute::utils::combine_validities_and_many;
 use polars_core::error::PolarsResult;
 use polars_row::RowsEncoded;
 
+use super::categorical::CategoryIds;
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{DataChunk, PExecutionContext};
 
@@ -12,6 +13,7 @@ use crate::operators::{DataChunk, PExecutionContext};
 pub(super) struct RowValues {
     current_rows: RowsEncoded,
     join_column_eval: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    category_ids: Arc<CategoryIds>,
     join_columns_material: Vec<ArrayRef>,
     // Location of join columns.
     // These column locations need to be dropped from the rhs
@@ -22,11 +24,13 @@ pub(super) struct RowValues {
 impl RowValues {
     pub(super) fn new(
         join_column_eval: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        category_ids: Arc<CategoryIds>,
         det_join_idx: bool,
     ) -> Self {
         Self {
             current_rows: Default::default(),
             join_column_eval,
+            category_ids,
             join_column_idx: None,
             join_columns_material: vec![],
             det_join_idx,
@@ -50,7 +54,7 @@ impl RowValues {
 
         for phys_e in self.join_column_eval.iter() {
             let s = phys_e.evaluate(chunk, &context.execution_state)?;
-            let mut s = s.to_physical_repr().rechunk();
+            let mut s = self.category_ids.to_physical(&s).rechunk();
             if chunk.data.is_empty() {
                 s = s.clear()
             };
@@ -0,0 +1,81 @@
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+
+use crate::operators::{
+    chunks_to_df_unchecked, DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult,
+};
+
+/// Keeps the first `len` rows that arrive and finishes the pipeline as soon as it has them.
+///
+/// Contrary to the [`SliceSink`](super::SliceSink) the rows are kept in the order they arrive,
+/// so it doesn't have to wait for the earlier chunks of the input.
+#[derive(Clone)]
+pub struct LimitEarlySink {
+    len: usize,
+    /// The chunks that arrived, shared by all threads, and their total height.
+    chunks: Arc<Mutex<(Vec<DataChunk>, usize)>>,
+    schema: SchemaRef,
+}
+
+impl LimitEarlySink {
+    pub fn new(len: usize, schema: SchemaRef) -> Self {
+        Self {
+            len,
+            chunks: Default::default(),
+            schema,
+        }
+    }
+}
+
+impl Sink for LimitEarlySink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        let height = chunk.data.height();
+        if height == 0 {
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        let mut guard = self.chunks.lock().unwrap();
+        let (chunks, current_len) = &mut *guard;
+        // Other threads may still push chunks after the limit was reached.
+        if *current_len < self.len {
+            chunks.push(chunk);
+            *current_len += height;
+        }
+
+        if *current_len >= self.len {
+            Ok(SinkResult::Finished)
+        } else {
+            Ok(SinkResult::CanHaveMoreInput)
+        }
+    }
+
+    fn combine(&mut self, _other: &mut dyn Sink) {
+        // no-op
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let (chunks, _) = std::mem::take(&mut *self.chunks.lock().unwrap());
+        if chunks.is_empty() {
+            return Ok(FinalizedSink::Finished(DataFrame::empty_with_schema(
+                &self.schema,
+            )));
+        }
+        let df = chunks_to_df_unchecked(chunks);
+        Ok(FinalizedSink::Finished(df.slice(0, self.len)))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "limit_early_sink"
+    }
+}
@@ -1,17 +1,35 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
+use polars_utils::memory_budget::MemoryBudget;
 use polars_utils::sys::MEMINFO;
 
+use crate::operators::PExecutionContext;
 use crate::pipeline::FORCE_OOC;
 
 const TO_MB: usize = 2 << 19;
 
+/// The memory used by a node, which is released from the budget of the query once all the
+/// threads of the node are dropped.
+#[derive(Default)]
+struct NodeUsage {
+    used: AtomicUsize,
+    budget: OnceLock<MemoryBudget>,
+}
+
+impl Drop for NodeUsage {
+    fn drop(&mut self) {
+        if let Some(budget) = self.budget.get() {
+            budget.release(*self.used.get_mut())
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(super) struct MemTracker {
     // available memory at the start of this node
     available_mem: Arc<AtomicUsize>,
-    used_by_node: Arc<AtomicUsize>,
+    used_by_node: Arc<NodeUsage>,
     fetch_count: Arc<AtomicUsize>,
     thread_count: usize,
     available_at_start: usize,
@@ -40,6 +58,20 @@ impl MemTracker {
         out
     }
 
+    /// Account the memory of this node to the budget of the query, if it has one. This must be
+    /// called before memory is added with [`fetch_add`](Self::fetch_add).
+    pub(super) fn bind_budget(&self, context: &PExecutionContext) {
+        if self.used_by_node.budget.get().is_none() {
+            if let Some(budget) = context.execution_state.memory_budget() {
+                let _ = self.used_by_node.budget.set(budget.clone());
+            }
+        }
+    }
+
+    fn budget(&self) -> Option<&MemoryBudget> {
+        self.used_by_node.budget.get()
+    }
+
     /// This shouldn't be called often as this is expensive.
     pub fn refresh_memory(&self) {
         self.available_mem
@@ -54,25 +86,47 @@ impl MemTracker {
         if fetch_count % (self.refresh_interval * self.thread_count) == 0 {
             self.refresh_memory()
         }
-        self.available_mem.load(Ordering::Relaxed)
+        self.available_within_budget()
     }
 
     pub(super) fn get_available_latest(&self) -> usize {
         self.refresh_memory();
         self.fetch_count.store(0, Ordering::Relaxed);
-        self.available_mem.load(Ordering::Relaxed)
+        self.available_within_budget()
+    }
+
+    fn available_within_budget(&self) -> usize {
+        let available = self.available_mem.load(Ordering::Relaxed);
+        match self.budget() {
+            Some(budget) => std::cmp::min(available, budget.available()),
+            None => available,
+        }
     }
 
     pub(super) fn free_memory_fraction_since_start(&self) -> f64 {
         // We divide first to reduce the precision loss in floats.
         // We also add 1.0 to available_at_start to prevent division by zero.
-        let available_at_start = (self.available_at_start / TO_MB) as f64 + 1.0;
+        let available_at_start = match self.budget() {
+            Some(budget) => std::cmp::min(self.available_at_start, budget.limit()),
+            None => self.available_at_start,
+        };
+        let available_at_start = (available_at_start / TO_MB) as f64 + 1.0;
         let available = (self.get_available() / TO_MB) as f64;
         available / available_at_start
     }
 
     /// Increment the used memory and return the previous value.
     pub(super) fn fetch_add(&self, add: usize) -> usize {
-        self.used_by_node.fetch_add(add, Ordering::Relaxed)
+        if let Some(budget) = self.budget() {
+            budget.reserve(add)
+        }
+        self.used_by_node.used.fetch_add(add, Ordering::Relaxed)
+    }
+
+    /// Record that this node started spilling to disk.
+    pub(super) fn record_spill(&self) {
+        if let Some(budget) = self.budget() {
+            budget.record_spill()
+        }
     }
 }
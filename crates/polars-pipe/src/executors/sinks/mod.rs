@@ -1,17 +1,20 @@
+mod channel;
 pub(crate) mod group_by;
 mod io;
 mod joins;
+mod limit_early;
 mod memory;
 mod ordered;
 mod output;
 mod reproject;
+mod row_index;
 mod slice;
 mod sort;
 mod utils;
 
-use std::sync::OnceLock;
-
+pub(crate) use channel::*;
 pub(crate) use joins::*;
+pub(crate) use limit_early::*;
 pub(crate) use ordered::*;
 #[cfg(any(
     feature = "parquet",
@@ -21,6 +24,7 @@ pub(crate) use ordered::*;
 ))]
 pub(crate) use output::*;
 pub(crate) use reproject::*;
+pub(crate) use row_index::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
 
@@ -28,16 +32,12 @@ pub(crate) use sort::*;
 // Overallocation seems a lot more expensive than resizing so we start reasonable small.
 const HASHMAP_INIT_SIZE: usize = 64;
 
-pub(crate) static POLARS_TEMP_DIR: OnceLock<String> = OnceLock::new();
-
-pub(crate) fn get_base_temp_dir() -> &'static str {
-    POLARS_TEMP_DIR.get_or_init(|| {
-        let tmp = std::env::var("POLARS_TEMP_DIR")
-            .unwrap_or_else(|_| std::env::temp_dir().to_string_lossy().into_owned());
+pub(crate) fn get_base_temp_dir() -> String {
+    let tmp = polars_core::config::get_var(polars_core::config::TEMP_DIR)
+        .unwrap_or_else(|| std::env::temp_dir().to_string_lossy().into_owned());
 
-        if polars_core::config::verbose() {
-            eprintln!("Temporary directory path in use: {}", &tmp);
-        }
-        tmp
-    })
+    if polars_core::config::verbose() {
+        eprintln!("Temporary directory path in use: {}", &tmp);
+    }
+    tmp
 }
@@ -0,0 +1,240 @@
+use std::any::Any;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::thread::JoinHandle;
+
+use crossbeam_channel::{bounded, Receiver, Sender};
+use polars_core::prelude::*;
+use polars_io::parquet::write::{BatchedWriter, Checkpoint, ParquetWriteOptions, ParquetWriter};
+use polars_plan::prelude::CheckpointOptions;
+
+use crate::operators::{
+    DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult, StreamingVstacker,
+};
+use crate::pipeline::morsels_per_sink;
+
+/// Writes the rows to parts in a directory and stores a [`Checkpoint`] after every part.
+struct PartWriter {
+    dir: PathBuf,
+    options: ParquetWriteOptions,
+    schema: Schema,
+    rows_per_part: usize,
+    checkpoint: Checkpoint,
+    /// The number of rows that were already written by a previous run, but are produced again.
+    skip_rows: usize,
+    /// The writer of the part that is being written and the number of rows in it.
+    part: Option<(BatchedWriter<File>, usize)>,
+}
+
+impl PartWriter {
+    /// The part is written to a temporary file, it is only renamed once it is complete.
+    fn tmp_path(&self) -> PathBuf {
+        self.dir.join("part.tmp")
+    }
+
+    fn write(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        if self.skip_rows > 0 {
+            let skip = std::cmp::min(self.skip_rows, df.height());
+            self.skip_rows -= skip;
+            df = df.slice(skip as i64, usize::MAX);
+        }
+
+        while df.height() > 0 {
+            if self.part.is_none() {
+                let writer = ParquetWriter::new(File::create(self.tmp_path())?)
+                    .with_compression(self.options.compression)
+                    .with_data_page_size(self.options.data_pagesize_limit)
+                    .with_statistics(self.options.statistics)
+                    .with_row_group_size(self.options.row_group_size)
+                    // This is important! Otherwise we will deadlock
+                    // See: #7074
+                    .set_parallel(false)
+                    .batched(&self.schema)?;
+                self.part = Some((writer, 0));
+            }
+            let (writer, rows) = self.part.as_mut().unwrap();
+
+            let len = std::cmp::min(self.rows_per_part - *rows, df.height());
+            writer.write_batch(&df.slice(0, len))?;
+            *rows += len;
+            df = df.slice(len as i64, usize::MAX);
+
+            if *rows == self.rows_per_part {
+                self.finish_part()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish_part(&mut self) -> PolarsResult<()> {
+        if let Some((writer, rows)) = self.part.take() {
+            writer.finish()?;
+            // Close the file before it is renamed.
+            drop(writer);
+            std::fs::rename(
+                self.tmp_path(),
+                Checkpoint::part_path(&self.dir, self.checkpoint.parts),
+            )?;
+            self.checkpoint.parts += 1;
+            self.checkpoint.rows += rows;
+            self.checkpoint.store(&self.dir)?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> PolarsResult<()> {
+        self.finish_part()?;
+        self.checkpoint.complete = true;
+        self.checkpoint.store(&self.dir)
+    }
+}
+
+fn init_part_writer_thread(
+    receiver: Receiver<Option<DataChunk>>,
+    mut writer: PartWriter,
+    // this is used to determine when a batch of chunks should be written to disk
+    // all chunks per push should be collected to determine in which order they should
+    // be written
+    morsels_per_sink: usize,
+) -> JoinHandle<PolarsResult<()>> {
+    std::thread::spawn(move || {
+        let mut chunks = Vec::with_capacity(morsels_per_sink);
+        let mut vstacker = StreamingVstacker::default();
+
+        while let Ok(chunk) = receiver.recv() {
+            // `last_write` indicates if all chunks are processed, e.g. this is the last write.
+            let last_write = if let Some(chunk) = chunk {
+                chunks.push(chunk);
+                false
+            } else {
+                true
+            };
+
+            if chunks.len() == morsels_per_sink || last_write {
+                // The parts must be written in the order of the output, otherwise a resumed run
+                // cannot know which rows were written.
+                chunks.sort_by_key(|chunk: &DataChunk| chunk.chunk_index);
+
+                for chunk in chunks.drain(0..) {
+                    for df in vstacker.add(chunk.data) {
+                        writer.write(df)?;
+                    }
+                }
+
+                if last_write {
+                    if let Some(df) = vstacker.finish() {
+                        writer.write(df)?;
+                    }
+                    return writer.finish();
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Writes the output to parts of `rows_per_part` rows in a directory, so that an interrupted
+/// query can continue from the last complete part. See [`Checkpoint`].
+#[derive(Clone)]
+pub struct CheckpointedParquetSink {
+    sender: Sender<Option<DataChunk>>,
+    io_thread_handle: Arc<Option<JoinHandle<PolarsResult<()>>>>,
+}
+
+impl CheckpointedParquetSink {
+    pub fn new(
+        dir: &Path,
+        options: ParquetWriteOptions,
+        checkpoint_options: &CheckpointOptions,
+        schema: &Schema,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            checkpoint_options.rows_per_part > 0,
+            InvalidOperation: "the number of rows per part must be positive"
+        );
+        let checkpoint = match Checkpoint::load(dir)? {
+            Some(checkpoint) => {
+                polars_ensure!(
+                    checkpoint.fingerprint == checkpoint_options.fingerprint,
+                    ComputeError: "the checkpoint in '{}' was written by a different query",
+                    dir.display()
+                );
+                checkpoint
+            },
+            None => Checkpoint {
+                fingerprint: checkpoint_options.fingerprint,
+                ..Default::default()
+            },
+        };
+
+        let writer = PartWriter {
+            dir: dir.to_path_buf(),
+            options,
+            schema: schema.clone(),
+            rows_per_part: checkpoint_options.rows_per_part,
+            checkpoint,
+            skip_rows: checkpoint_options.skip_rows,
+            part: None,
+        };
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_part_writer_thread(
+            receiver,
+            writer,
+            morsels_per_sink,
+        )));
+
+        Ok(Self {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
+
+impl Sink for CheckpointedParquetSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        // don't add empty dataframes
+        if chunk.data.height() > 0 {
+            // The writer thread only hangs up after an error, which `finalize` returns.
+            if self.sender.send(Some(chunk)).is_err() {
+                return Ok(SinkResult::Finished);
+            }
+        };
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, _other: &mut dyn Sink) {
+        // already synchronized
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // `None` indicates that we can flush all remaining chunks.
+        let _ = self.sender.send(None);
+
+        // wait until all parts are written
+        Arc::get_mut(&mut self.io_thread_handle)
+            .unwrap()
+            .take()
+            .unwrap()
+            .join()
+            .unwrap()?;
+
+        // return a dummy dataframe;
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "checkpointed_parquet_sink"
+    }
+}
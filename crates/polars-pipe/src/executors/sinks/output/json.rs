@@ -13,7 +13,7 @@ impl SinkWriter for BatchedWriter<std::fs::File> {
     }
 
     fn _finish(&mut self) -> PolarsResult<()> {
-        Ok(())
+        self.finish()
     }
 }
 
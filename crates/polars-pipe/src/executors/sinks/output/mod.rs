@@ -1,3 +1,5 @@
+#[cfg(feature = "parquet")]
+mod checkpoint;
 #[cfg(feature = "csv")]
 mod csv;
 #[cfg(any(
@@ -14,6 +16,8 @@ mod json;
 #[cfg(feature = "parquet")]
 mod parquet;
 
+#[cfg(feature = "parquet")]
+pub use checkpoint::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(feature = "ipc")]
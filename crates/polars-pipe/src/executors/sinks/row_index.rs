@@ -0,0 +1,98 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::*;
+use polars_core::utils::rayon::prelude::*;
+use polars_core::POOL;
+
+use crate::operators::{
+    chunks_to_df_unchecked, DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult,
+};
+
+// Adds a row index without synchronizing the threads. Every thread only collects its chunks, the
+// offset of every chunk is known once all chunks are ordered in `finalize`.
+#[derive(Clone)]
+pub struct RowIndexSink {
+    chunks: Vec<DataChunk>,
+    name: Arc<str>,
+    offset: IdxSize,
+    schema: SchemaRef,
+}
+
+impl RowIndexSink {
+    pub fn new(name: Arc<str>, offset: Option<IdxSize>, schema: SchemaRef) -> Self {
+        RowIndexSink {
+            chunks: vec![],
+            name,
+            offset: offset.unwrap_or(0),
+            schema,
+        }
+    }
+}
+
+impl Sink for RowIndexSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        // don't add empty dataframes
+        if chunk.data.height() > 0 || self.chunks.is_empty() {
+            self.chunks.push(chunk);
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<RowIndexSink>().unwrap();
+        self.chunks.append(&mut other.chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            chunks: vec![],
+            name: self.name.clone(),
+            offset: self.offset,
+            schema: self.schema.clone(),
+        })
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let mut chunks = std::mem::take(&mut self.chunks);
+        if chunks.is_empty() {
+            let df = DataFrame::empty_with_schema(&self.schema);
+            return Ok(FinalizedSink::Finished(
+                df.with_row_index(&self.name, None)?,
+            ));
+        }
+        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+
+        // The offset of a chunk is the number of rows in the chunks before it.
+        let mut offset = self.offset;
+        let offsets = chunks
+            .iter()
+            .map(|chunk| {
+                let chunk_offset = offset;
+                offset += chunk.data.height() as IdxSize;
+                chunk_offset
+            })
+            .collect::<Vec<_>>();
+        let chunks = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .zip(offsets)
+                .map(|(chunk, offset)| {
+                    let data = chunk.data.with_row_index(&self.name, Some(offset))?;
+                    Ok(chunk.with_data(data))
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        Ok(FinalizedSink::Finished(chunks_to_df_unchecked(chunks)))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "row_index_sink"
+    }
+}
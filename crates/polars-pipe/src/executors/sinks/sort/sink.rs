@@ -81,6 +81,7 @@ impl SortSink {
         }
         self.ooc_start = Some(Instant::now());
         self.ooc = true;
+        self.mem_track.record_spill();
 
         // start IO thread
         let mut iot = self.io_thread.write().unwrap();
@@ -142,10 +143,15 @@ impl SortSink {
 }
 
 impl Sink for SortSink {
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.mem_track.bind_budget(context);
         self.store_chunk(chunk)?;
 
         if self.ooc {
+            {
+                let iot = self.io_thread.read().unwrap();
+                iot.as_ref().unwrap().check_spill_limit(context)?;
+            }
             self.dump(false)?;
         }
         Ok(SinkResult::CanHaveMoreInput)
@@ -198,8 +204,10 @@ impl Sink for SortSink {
                 eprintln!("finished sinking into OOC sort in {:?}", instant.elapsed());
             }
             block_thread_until_io_thread_done(&io_thread);
+            io_thread.check_spill_limit(context)?;
             if context.verbose {
                 eprintln!("full file dump of OOC sort took {:?}", instant.elapsed());
+                eprintln!("OOC sort spilled {} bytes", io_thread.spilled_bytes());
             }
 
             sort_ooc(
@@ -0,0 +1,35 @@
+use polars_core::prelude::*;
+use polars_plan::prelude::align_by_name;
+
+use crate::operators::{PExecutionContext, Source, SourceResult};
+
+/// A source of which the columns of every batch are aligned by name to a fixed schema, so that
+/// sources with slightly different schemas can be unioned.
+pub(crate) struct AlignByNameSource {
+    schema: SchemaRef,
+    source: Box<dyn Source>,
+}
+
+impl AlignByNameSource {
+    pub(crate) fn new(schema: SchemaRef, source: Box<dyn Source>) -> Self {
+        AlignByNameSource { schema, source }
+    }
+}
+
+impl Source for AlignByNameSource {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        Ok(match self.source.get_batches(context)? {
+            SourceResult::Finished => SourceResult::Finished,
+            SourceResult::GotMoreData(chunks) => SourceResult::GotMoreData(
+                chunks
+                    .iter()
+                    .map(|chunk| Ok(chunk.with_data(align_by_name(&chunk.data, &self.schema)?)))
+                    .collect::<PolarsResult<Vec<_>>>()?,
+            ),
+        })
+    }
+
+    fn fmt(&self) -> &str {
+        "align-by-name"
+    }
+}
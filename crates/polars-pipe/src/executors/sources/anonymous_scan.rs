@@ -0,0 +1,56 @@
+use std::sync::Mutex;
+
+use polars_core::error::PolarsResult;
+use polars_core::POOL;
+use polars_plan::prelude::{AnonymousScan, AnonymousScanArgs, AnonymousScanBatches};
+use polars_utils::IdxSize;
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+pub(crate) struct AnonymousScanSource {
+    // Only accessed through `get_mut`, this makes the source `Sync`.
+    batches: Mutex<AnonymousScanBatches>,
+    n_threads: usize,
+}
+
+impl AnonymousScanSource {
+    pub(crate) fn new(
+        function: &dyn AnonymousScan,
+        scan_opts: AnonymousScanArgs,
+    ) -> PolarsResult<Self> {
+        let batches = function.scan_batches(scan_opts)?;
+        Ok(Self {
+            batches: Mutex::new(batches),
+            n_threads: POOL.current_num_threads(),
+        })
+    }
+}
+
+impl Source for AnonymousScanSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let batches = self.batches.get_mut().unwrap();
+        let dfs = batches
+            .filter(|df| !matches!(df, Ok(df) if df.height() == 0))
+            .take(self.n_threads)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if dfs.is_empty() {
+            return Ok(SourceResult::Finished);
+        }
+
+        let idx_offset = get_source_index(dfs.len() as u32);
+        let chunks = dfs
+            .into_iter()
+            .enumerate()
+            .map(|(i, data)| DataChunk {
+                chunk_index: (idx_offset + i as u32) as IdxSize,
+                data,
+            })
+            .collect();
+        Ok(SourceResult::GotMoreData(chunks))
+    }
+
+    fn fmt(&self) -> &str {
+        "anonymous_scan"
+    }
+}
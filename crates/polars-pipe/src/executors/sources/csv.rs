@@ -7,6 +7,7 @@ use polars_io::utils::is_cloud_url;
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::prelude::FileScanOptions;
 use polars_utils::iter::EnumerateIdxTrait;
+use polars_utils::memory_budget::MemoryBudget;
 
 use super::*;
 use crate::pipeline::determine_chunk_size;
@@ -27,6 +28,8 @@ pub(crate) struct CsvSource {
     // state for multi-file reads
     current_path_idx: usize,
     n_rows_read: usize,
+    // memory budget of the query, the chunk size of the next file shrinks if it is tight
+    memory_budget: Option<MemoryBudget>,
     // Used to check schema in a way that throws the same error messages as the default engine.
     // TODO: Refactor the checking code so that we can just use the schema to do this.
     schema_check_df: DataFrame,
@@ -83,7 +86,11 @@ impl CsvSource {
         });
         // inversely scale the chunk size by the number of threads so that we reduce memory pressure
         // in streaming
-        let chunk_size = determine_chunk_size(n_cols, POOL.current_num_threads())?;
+        let chunk_size = determine_chunk_size(
+            n_cols,
+            POOL.current_num_threads(),
+            self.memory_budget.as_ref(),
+        )?;
 
         if self.verbose {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
@@ -145,13 +152,15 @@ impl CsvSource {
             verbose,
             current_path_idx: 0,
             n_rows_read: 0,
+            memory_budget: None,
             schema_check_df: Default::default(),
         })
     }
 }
 
 impl Source for CsvSource {
-    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        self.memory_budget = context.execution_state.memory_budget().cloned();
         loop {
             let first_read_from_file = self.reader.is_none();
 
@@ -0,0 +1,107 @@
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::prelude::*;
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+/// Merges sources of which the `key` column is sorted ascending into a single source of which
+/// `key` is sorted.
+///
+/// The sources can't produce keys smaller than the last key they produced, so the buffered rows
+/// up to the smallest last key of all buffers are merged and the other rows stay buffered. The
+/// source with that smallest last key merges its whole buffer, which guarantees progress and
+/// bounds the buffer of every source to a single batch.
+pub struct MergeSortedSource {
+    sources: Vec<Box<dyn Source>>,
+    key: Arc<str>,
+    /// The rows of every source that are not merged yet, `None` once the source is finished.
+    buffers: Vec<Option<DataFrame>>,
+}
+
+impl MergeSortedSource {
+    pub(crate) fn new(sources: Vec<Box<dyn Source>>, key: Arc<str>) -> Self {
+        let buffers = sources.iter().map(|_| Some(DataFrame::empty())).collect();
+        Self {
+            sources,
+            key,
+            buffers,
+        }
+    }
+
+    /// Fill the empty buffers of the sources that are not finished.
+    fn fill_buffers(&mut self, context: &PExecutionContext) -> PolarsResult<()> {
+        for (source, buffer) in self.sources.iter_mut().zip(self.buffers.iter_mut()) {
+            loop {
+                match buffer {
+                    Some(df) if df.height() == 0 => {},
+                    _ => break,
+                }
+                match source.get_batches(context)? {
+                    SourceResult::Finished => *buffer = None,
+                    SourceResult::GotMoreData(mut chunks) => {
+                        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+                        *buffer = Some(accumulate_dataframes_vertical_unchecked(
+                            chunks.into_iter().map(|chunk| chunk.data),
+                        ));
+                    },
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Source for MergeSortedSource {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        self.fill_buffers(context)?;
+        let mut last_keys = self.buffers.iter().flatten().map(|df| {
+            let key = df.column(&self.key)?;
+            PolarsResult::Ok(key.tail(Some(1)))
+        });
+        let Some(bound) = last_keys.next() else {
+            return Ok(SourceResult::Finished);
+        };
+        let mut bound = bound?;
+        for last_key in last_keys {
+            bound.append(&last_key?)?;
+        }
+        let bound = bound.sort(Default::default())?.head(Some(1));
+
+        let mut out: Option<DataFrame> = None;
+        for buffer in self.buffers.iter_mut().flatten() {
+            let key = buffer.column(&self.key)?;
+            let len = search_sorted(key, &bound, SearchSortedSide::Right, false)?
+                .get(0)
+                .unwrap() as usize;
+            let merged = buffer.slice(0, len);
+            *buffer = buffer.slice(len as i64, buffer.height());
+
+            out = Some(match out {
+                None => merged,
+                Some(out) => {
+                    let left_key = out.column(&self.key)?;
+                    let right_key = merged.column(&self.key)?;
+                    _merge_sorted_dfs(&out, &merged, left_key, right_key, true)?
+                },
+            });
+        }
+        let mut out = out.unwrap();
+        out.apply(&self.key, |s| {
+            let mut s = s.clone();
+            s.set_sorted_flag(IsSorted::Ascending);
+            s
+        })?;
+
+        let chunk_index = get_source_index(1) as IdxSize;
+        Ok(SourceResult::GotMoreData(vec![DataChunk::new(
+            chunk_index,
+            out,
+        )]))
+    }
+
+    fn fmt(&self) -> &str {
+        "merge_sorted"
+    }
+}
@@ -1,7 +1,11 @@
+mod align_by_name;
+mod anonymous_scan;
 #[cfg(feature = "csv")]
 mod csv;
 mod frame;
 mod ipc_one_shot;
+#[cfg(feature = "merge_sorted")]
+mod merge_sorted;
 #[cfg(feature = "parquet")]
 mod parquet;
 mod reproject;
@@ -9,10 +13,14 @@ mod union;
 
 use std::sync::atomic::{AtomicU32, Ordering};
 
+pub(crate) use align_by_name::*;
+pub(crate) use anonymous_scan::*;
 #[cfg(feature = "csv")]
 pub(crate) use csv::CsvSource;
 pub(crate) use frame::*;
 pub(crate) use ipc_one_shot::*;
+#[cfg(feature = "merge_sorted")]
+pub(crate) use merge_sorted::*;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::*;
 pub(crate) use reproject::*;
@@ -22,6 +22,7 @@ use polars_plan::plans::FileInfo;
 use polars_plan::prelude::hive::HivePartitions;
 use polars_plan::prelude::FileScanOptions;
 use polars_utils::iter::EnumerateIdxTrait;
+use polars_utils::memory_budget::MemoryBudget;
 use polars_utils::IdxSize;
 
 use crate::executors::sources::get_source_index;
@@ -45,6 +46,8 @@ pub struct ParquetSource {
     run_async: bool,
     prefetch_size: usize,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    // memory budget of the query, the chunk size of the next file shrinks if it is tight
+    memory_budget: Option<MemoryBudget>,
 }
 
 impl ParquetSource {
@@ -93,7 +96,7 @@ impl ParquetSource {
         );
 
         let n_cols = projection.as_ref().map(|v| v.len()).unwrap_or(schema.len());
-        let chunk_size = determine_chunk_size(n_cols, self.n_threads)?;
+        let chunk_size = determine_chunk_size(n_cols, self.n_threads, self.memory_budget.as_ref())?;
 
         if self.verbose {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
@@ -222,6 +225,7 @@ impl ParquetSource {
             run_async,
             prefetch_size,
             predicate,
+            memory_budget: None,
         };
         // Already start downloading when we deal with cloud urls.
         if run_async {
@@ -274,7 +278,8 @@ impl ParquetSource {
 }
 
 impl Source for ParquetSource {
-    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        self.memory_budget = context.execution_state.memory_budget().cloned();
         self.prefetch_files()?;
 
         let Some(mut reader) = self.batched_readers.pop_front() else {
@@ -293,18 +298,21 @@ impl Source for ParquetSource {
 
                 // reset the reader
                 self.init_next_reader()?;
-                return self.get_batches(_context);
+                return self.get_batches(context);
             },
             Some(batches) => {
                 let idx_offset = get_source_index(0);
                 let out = batches
                     .into_iter()
                     .enumerate_u32()
-                    .map(|(i, data)| {
+                    .map(|(i, mut data)| {
                         // Keep the row limit updated so the next reader will have a correct limit.
                         if let Some(n_rows) = &mut self.file_options.n_rows {
                             *n_rows = n_rows.saturating_sub(data.height())
                         }
+                        if self.options.deduplicate_strings {
+                            data.deduplicate_strings();
+                        }
 
                         DataChunk {
                             chunk_index: (idx_offset + i) as IdxSize,
@@ -2,6 +2,9 @@ use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 
 use super::*;
 
+/// A morsel of data flowing through a pipeline.
+///
+/// All columns of `data` consist of a single chunk.
 #[derive(Clone, Debug)]
 pub struct DataChunk {
     pub chunk_index: IdxSize,
@@ -9,7 +12,7 @@ pub struct DataChunk {
 }
 
 impl DataChunk {
-    pub(crate) fn new(chunk_index: IdxSize, data: DataFrame) -> Self {
+    pub fn new(chunk_index: IdxSize, data: DataFrame) -> Self {
         // Check the invariant that all columns have a single chunk.
         #[cfg(debug_assertions)]
         {
@@ -19,10 +22,11 @@ impl DataChunk {
         }
         Self { chunk_index, data }
     }
-    pub(crate) fn with_data(&self, data: DataFrame) -> Self {
+    /// Create a chunk with the same index that holds `data`.
+    pub fn with_data(&self, data: DataFrame) -> Self {
         Self::new(self.chunk_index, data)
     }
-    pub(crate) fn is_empty(&self) -> bool {
+    pub fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
 }
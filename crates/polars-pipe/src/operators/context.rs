@@ -1,9 +1,13 @@
+use polars_core::config::Config;
 use polars_expr::state::ExecutionState;
 
+/// State shared by the nodes of a running pipeline.
 pub struct PExecutionContext {
     // injected upstream in polars-lazy
     pub(crate) execution_state: ExecutionState,
     pub(crate) verbose: bool,
+    // configuration of the thread that started the pipeline, activated on the worker threads
+    pub(crate) config: Config,
 }
 
 impl PExecutionContext {
@@ -11,6 +15,23 @@ impl PExecutionContext {
         PExecutionContext {
             execution_state: state,
             verbose,
+            config: Config::current(),
         }
     }
+
+    /// Whether the pipeline should print diagnostic output.
+    pub fn verbose(&self) -> bool {
+        self.verbose
+    }
+
+    /// The number of the `n_morsels` morsels that are pushed at the same time. Once more than
+    /// half of the memory budget of the query is reserved, fewer morsels are pushed at the same
+    /// time, down to a single one when the budget is used up.
+    pub(crate) fn max_concurrent_morsels(&self, n_morsels: usize) -> usize {
+        let Some(budget) = self.execution_state.memory_budget() else {
+            return n_morsels;
+        };
+        let headroom = ((1.0 - budget.pressure()) * 2.0).clamp(0.0, 1.0);
+        std::cmp::max((n_morsels as f64 * headroom) as usize, 1)
+    }
 }
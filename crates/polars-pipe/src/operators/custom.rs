@@ -0,0 +1,70 @@
+use std::any::Any;
+
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_expr::state::ExecutionState;
+use polars_plan::prelude::{DataFrameUdf, StreamingUdf};
+
+use super::*;
+
+/// Plan node that runs a user-defined [`Operator`] in the streaming engine.
+///
+/// Add it to a query with `map_streaming`. If the query runs on the in-memory engine, the
+/// operator processes the whole `DataFrame` as a single chunk.
+pub struct OperatorNode {
+    operator: Box<dyn Operator>,
+}
+
+impl OperatorNode {
+    pub fn new(operator: Box<dyn Operator>) -> Self {
+        Self { operator }
+    }
+
+    /// Create the operator instance for thread `thread_no`.
+    pub(crate) fn split(&self, thread_no: usize) -> Box<dyn Operator> {
+        self.operator.split(thread_no)
+    }
+}
+
+impl DataFrameUdf for OperatorNode {
+    fn call_udf(&self, mut df: DataFrame) -> PolarsResult<DataFrame> {
+        let context = PExecutionContext::new(ExecutionState::new(), false);
+        let mut operator = self.split(0);
+        df.as_single_chunk_par();
+        let chunk = DataChunk::new(0, df);
+
+        let mut out = vec![];
+        loop {
+            match operator.execute(&context, &chunk)? {
+                OperatorResult::HaveMoreOutPut(chunk) => out.push(chunk.data),
+                OperatorResult::Finished(chunk) => {
+                    out.push(chunk.data);
+                    break;
+                },
+                OperatorResult::NeedsNewData => break,
+            }
+        }
+        if operator.must_flush() {
+            loop {
+                match operator.flush()? {
+                    OperatorResult::HaveMoreOutPut(chunk) => out.push(chunk.data),
+                    OperatorResult::Finished(chunk) => {
+                        out.push(chunk.data);
+                        break;
+                    },
+                    OperatorResult::NeedsNewData => break,
+                }
+            }
+        }
+        polars_ensure!(
+            !out.is_empty(),
+            ComputeError: "operator '{}' did not produce any output", operator.fmt()
+        );
+        Ok(accumulate_dataframes_vertical_unchecked(out))
+    }
+}
+
+impl StreamingUdf for OperatorNode {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
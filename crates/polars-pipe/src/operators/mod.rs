@@ -1,12 +1,22 @@
 pub mod chunks;
 mod context;
+#[cfg(feature = "custom_operators")]
+mod custom;
 mod operator;
 mod sink;
 mod source;
 
 pub(crate) use chunks::*;
 pub use context::*;
+#[cfg(feature = "custom_operators")]
+pub use custom::*;
+#[cfg(not(feature = "custom_operators"))]
 pub(crate) use operator::*;
+#[cfg(feature = "custom_operators")]
+pub use operator::*;
 pub(crate) use polars_core::prelude::*;
 pub use sink::*;
+#[cfg(not(feature = "custom_operators"))]
 pub(crate) use source::*;
+#[cfg(feature = "custom_operators")]
+pub use source::*;
@@ -1,5 +1,6 @@
 use super::*;
 
+/// The result of pushing a chunk through an [`Operator`].
 pub enum OperatorResult {
     /// needs to be called again with new chunk.
     /// Or in case of `flush` needs to be called again.
@@ -10,22 +11,32 @@ pub enum OperatorResult {
     Finished(DataChunk),
 }
 
+/// A streaming node that transforms chunks and passes them on to the next node.
+///
+/// Every thread gets its own instance, created with [`Operator::split`]. Operators that
+/// buffer data between chunks must return `true` from [`Operator::must_flush`], so that
+/// [`Operator::flush`] is called once the source is exhausted.
 pub trait Operator: Send + Sync {
+    /// Process a chunk of data.
     fn execute(
         &mut self,
         context: &PExecutionContext,
         chunk: &DataChunk,
     ) -> PolarsResult<OperatorResult>;
 
+    /// Emit the data that is still buffered after the last chunk was processed.
     fn flush(&mut self) -> PolarsResult<OperatorResult> {
         unimplemented!()
     }
 
+    /// Whether [`Operator::flush`] must be called after the last chunk.
     fn must_flush(&self) -> bool {
         false
     }
 
+    /// Create a new instance of this operator for thread `thread_no`.
     fn split(&self, thread_no: usize) -> Box<dyn Operator>;
 
+    /// Name of the operator, used in verbose output.
     fn fmt(&self) -> &str;
 }
@@ -5,12 +5,14 @@ use polars_utils::arena::Node;
 
 use super::*;
 
+/// The result of pushing a chunk into a [`Sink`].
 #[derive(Debug)]
 pub enum SinkResult {
     Finished,
     CanHaveMoreInput,
 }
 
+/// The output of a [`Sink`] once all of its input has been consumed.
 pub enum FinalizedSink {
     Finished(DataFrame),
     Operator,
@@ -28,17 +30,28 @@ impl Debug for FinalizedSink {
     }
 }
 
+/// A streaming node that consumes all chunks of a pipeline, e.g. to aggregate or write them.
+///
+/// Every thread gets its own instance, created with [`Sink::split`]. Once the source is
+/// exhausted the thread-local instances are merged with [`Sink::combine`] and the result
+/// is produced by [`Sink::finalize`].
 pub trait Sink: Send + Sync {
+    /// Consume a chunk of data.
     fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult>;
 
+    /// Merge the state of another thread-local instance into this one.
     fn combine(&mut self, other: &mut dyn Sink);
 
+    /// Create a new instance of this sink for thread `thread_no`.
     fn split(&self, thread_no: usize) -> Box<dyn Sink>;
 
+    /// Produce the output once all chunks have been consumed.
     fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink>;
 
+    /// Used to downcast `other` in [`Sink::combine`].
     fn as_any(&mut self) -> &mut dyn Any;
 
+    /// Name of the sink, used in verbose output.
     fn fmt(&self) -> &str;
 
     fn is_join_build(&self) -> bool {
@@ -1,12 +1,16 @@
 use super::*;
 
+/// The result of polling a [`Source`].
 pub enum SourceResult {
     Finished,
     GotMoreData(Vec<DataChunk>),
 }
 
+/// A streaming node that produces the chunks of a pipeline.
 pub trait Source: Send + Sync {
+    /// Produce the next batches, or [`SourceResult::Finished`] if there is no data left.
     fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult>;
 
+    /// Name of the source, used in verbose output.
     fn fmt(&self) -> &str;
 }
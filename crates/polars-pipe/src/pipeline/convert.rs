@@ -37,6 +37,24 @@ where
         .collect()
 }
 
+/// The fields the plan declares for the outputs of `exprs`. The dtype of an output that can't be
+/// resolved is unknown, and isn't enforced.
+fn exprs_to_fields(
+    exprs: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+    input_schema: &Schema,
+) -> Arc<[Field]> {
+    exprs
+        .iter()
+        .map(|e| {
+            e.field(input_schema, Context::Default, expr_arena)
+                .unwrap_or_else(|_| {
+                    Field::new(e.output_name(), DataType::Unknown(Default::default()))
+                })
+        })
+        .collect()
+}
+
 #[allow(unused_variables)]
 fn get_source<F>(
     source: IR,
@@ -115,6 +133,7 @@ where
                     options: parquet_options,
                     cloud_options,
                     metadata,
+                    ..
                 } => {
                     let predicate = predicate
                         .as_ref()
@@ -133,6 +152,9 @@ where
                                 fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
                                     self.p.as_stats_evaluator()
                                 }
+                                fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+                                    self.p.live_variables()
+                                }
                             }
 
                             PolarsResult::Ok(Arc::new(Wrap { p }) as Arc<dyn PhysicalIoExpr>)
@@ -151,6 +173,21 @@ where
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
+                FileScan::Anonymous { function, .. } => {
+                    let scan_opts = AnonymousScanArgs {
+                        n_rows: file_options.n_rows,
+                        with_columns: file_options.with_columns,
+                        schema: file_info.schema,
+                        output_schema,
+                        // The operator added above still applies the predicate to the batches.
+                        predicate: predicate
+                            .filter(|_| function.allows_predicate_pushdown())
+                            .map(|predicate| predicate.to_expr(expr_arena)),
+                    };
+                    let src = sources::AnonymousScanSource::new(function.as_ref(), scan_opts)?;
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
+                #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
         },
@@ -176,6 +213,14 @@ where
                 SinkType::Memory => {
                     Box::new(OrderedSink::new(input_schema.into_owned())) as Box<dyn SinkTrait>
                 },
+                SinkType::LimitEarly { len } => {
+                    Box::new(LimitEarlySink::new(*len, input_schema.into_owned()))
+                        as Box<dyn SinkTrait>
+                },
+                SinkType::Channel(sender) => {
+                    Box::new(ChannelSink::new(sender.clone(), input_schema.into_owned()))
+                        as Box<dyn SinkTrait>
+                },
                 #[allow(unused_variables)]
                 SinkType::File {
                     path, file_type, ..
@@ -236,6 +281,17 @@ where
                         other_file_type => todo!("Cloud-sinking of the file type {other_file_type:?} is not (yet) supported."),
                     }
                 },
+                #[cfg(feature = "parquet")]
+                SinkType::Checkpointed {
+                    path,
+                    options,
+                    checkpoint,
+                } => Box::new(CheckpointedParquetSink::new(
+                    path.as_ref().as_path(),
+                    *options,
+                    checkpoint,
+                    input_schema.as_ref(),
+                )?) as Box<dyn SinkTrait>,
             }
         },
         Join {
@@ -337,6 +393,14 @@ where
             let slice = SliceSink::new(*offset as u64, *len as usize, input_schema.into_owned());
             Box::new(slice) as Box<dyn SinkTrait>
         },
+        MapFunction {
+            input,
+            function: FunctionNode::RowIndex { name, offset, .. },
+        } => {
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            let sink = RowIndexSink::new(name.clone(), *offset, input_schema.into_owned());
+            Box::new(sink) as Box<dyn SinkTrait>
+        },
         Reduce {
             input: _,
             exprs: _,
@@ -566,6 +630,7 @@ where
 {
     Ok(operators::HstackOperator {
         exprs: exprs_to_physical(exprs, expr_arena, &to_physical, Some(&input_schema))?,
+        fields: exprs_to_fields(exprs, expr_arena, &input_schema),
         input_schema,
         options,
     })
@@ -597,6 +662,7 @@ where
             let input_schema = lp_arena.get(*input).schema(lp_arena);
             let op = operators::ProjectionOperator {
                 exprs: exprs_to_physical(expr, expr_arena, &to_physical, Some(&input_schema))?,
+                fields: exprs_to_fields(expr, expr_arena, &input_schema),
                 options: *options,
             };
             Box::new(op) as Box<dyn Operator>
@@ -625,6 +691,30 @@ where
             let op = operators::FilterOperator { predicate };
             Box::new(op) as Box<dyn Operator>
         },
+        #[cfg(feature = "custom_operators")]
+        MapFunction {
+            function:
+                FunctionNode::OpaqueStreaming {
+                    function, fmt_str, ..
+                },
+            ..
+        } => {
+            let op = function
+                .as_any()
+                .downcast_ref::<crate::operators::OperatorNode>()
+                .ok_or_else(
+                    || polars_err!(ComputeError: "'{}' is not a streaming operator", fmt_str),
+                )?;
+            op.split(0)
+        },
+        MapFunction {
+            input,
+            function: FunctionNode::Unpivot { args, .. },
+        } => {
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            let op = operators::UnpivotOperator::new(args, &input_schema)?;
+            Box::new(op) as Box<dyn Operator>
+        },
         MapFunction { function, .. } => {
             let op = operators::FunctionOperator::new(function.clone());
             Box::new(op) as Box<dyn Operator>
@@ -687,6 +777,25 @@ where
                     .enumerate()
                     .map(|(i, node)| {
                         let lp = lp_arena.get(*node);
+                        if let MapFunction {
+                            input,
+                            function: FunctionNode::AlignByName { schema },
+                        } = lp
+                        {
+                            // These scans don't have a predicate.
+                            let source = get_source(
+                                lp_arena.get(*input).clone(),
+                                &mut operator_objects,
+                                expr_arena,
+                                &to_physical,
+                                false,
+                                verbose && i == 0,
+                            )?;
+                            return Ok(Box::new(sources::AlignByNameSource::new(
+                                schema.clone(),
+                                source,
+                            )) as Box<dyn Source>);
+                        }
                         // only push predicate of first source
                         get_source(
                             lp.clone(),
@@ -700,6 +809,46 @@ where
                     .collect::<PolarsResult<Vec<_>>>()?;
                 Box::new(sources::UnionSource::new(sources)) as Box<dyn Source>
             },
+            #[cfg(feature = "merge_sorted")]
+            MapFunction {
+                input,
+                function: FunctionNode::MergeSorted { column },
+            } => {
+                let Union { inputs, .. } = lp_arena.get(*input) else {
+                    unreachable!()
+                };
+                let sources = inputs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, node)| {
+                        let mut lp = lp_arena.get(*node);
+                        if let MapFunction {
+                            input,
+                            function: FunctionNode::Rechunk,
+                        } = lp
+                        {
+                            lp = lp_arena.get(*input);
+                        }
+                        // The predicates of the inputs are equal and can be applied after the
+                        // merge, so only push the predicate of the first input.
+                        let mut input_operators = vec![];
+                        get_source(
+                            lp.clone(),
+                            if i == 0 {
+                                &mut operator_objects
+                            } else {
+                                &mut input_operators
+                            },
+                            expr_arena,
+                            &to_physical,
+                            true,
+                            verbose && i == 0,
+                        )
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Box::new(sources::MergeSortedSource::new(sources, column.clone()))
+                    as Box<dyn Source>
+            },
             lp => {
                 panic!("source {lp:?} not (yet) supported")
             },
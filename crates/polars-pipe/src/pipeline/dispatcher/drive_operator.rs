@@ -32,36 +32,52 @@ pub(super) fn par_process_chunks(
     // we spawn the jobs. They don't have to finish in any specific order,
     // this makes it more lightweight than `par_iter`
 
-    // borrow as ref and move into the closure
-    POOL.scope(|s| {
-        for ((chunk, sink), operator_pipe) in chunks
+    // Every worker pushes chunks until all chunks are pushed. There is a worker per chunk, unless
+    // the memory budget of the query limits the chunks that are pushed at the same time.
+    let n_workers = ec.max_concurrent_morsels(chunks.len());
+    let jobs = Mutex::new(
+        chunks
             .into_iter()
             .zip(sink.iter_mut())
-            .zip(operators.iter_mut())
-        {
+            .zip(operators.iter_mut()),
+    );
+
+    // borrow as ref and move into the closure
+    POOL.scope(|s| {
+        for _ in 0..n_workers {
             let sink_results = sink_results.clone();
-            // Truncate the operators that should run into the current sink.
-            let operator_pipe = &mut operator_pipe[operator_start..operator_end];
+            let jobs = &jobs;
 
             s.spawn(move |_| {
-                let out = if operator_pipe.is_empty() {
-                    sink.sink(ec, chunk)
-                } else {
-                    push_operators_single_thread(chunk, ec, operator_pipe, sink, must_flush)
-                };
-
-                match out {
-                    Ok(SinkResult::Finished) | Err(_) => {
-                        let mut lock = sink_results.lock().unwrap();
-                        *lock = Some(out)
-                    },
-                    _ => {},
+                let _config = ec.config.clone().scoped();
+                loop {
+                    let next = jobs.lock().unwrap().next();
+                    let Some(((chunk, sink), operator_pipe)) = next else {
+                        break;
+                    };
+                    // Truncate the operators that should run into the current sink.
+                    let operator_pipe = &mut operator_pipe[operator_start..operator_end];
+
+                    let out = if operator_pipe.is_empty() {
+                        sink.sink(ec, chunk)
+                    } else {
+                        push_operators_single_thread(chunk, ec, operator_pipe, sink, must_flush)
+                    };
+
+                    match out {
+                        Ok(SinkResult::Finished) | Err(_) => {
+                            let mut lock = sink_results.lock().unwrap();
+                            *lock = Some(out)
+                        },
+                        _ => {},
+                    }
                 }
             })
         }
         // already get batches on the thread pool
         // if one job is finished earlier we can already start that work
         s.spawn(|_| {
+            let _config = ec.config.clone().scoped();
             let out = src.get_batches(ec);
             unsafe {
                 let ptr = next_batches_ptr.get();
@@ -157,6 +173,7 @@ pub(super) fn par_flush(
             let operator_pipe = &mut operator_pipe[operator_start..operator_end];
 
             s.spawn(move |_| {
+                let _config = ec.config.clone().scoped();
                 flush_operators(ec, operator_pipe, sink).unwrap();
             })
         }
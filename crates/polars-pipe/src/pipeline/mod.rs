@@ -9,6 +9,7 @@ pub use dispatcher::{execute_pipeline, PipeLine};
 use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_utils::cell::SyncUnsafeCell;
+use polars_utils::memory_budget::MemoryBudget;
 
 pub use crate::executors::sinks::group_by::aggregates::can_convert_to_hash_agg;
 use crate::operators::{Operator, Sink};
@@ -24,17 +25,33 @@ pub(crate) const PARTITION_SIZE: usize = 64;
 // env vars
 pub(crate) static FORCE_OOC: &str = "POLARS_FORCE_OOC";
 
+// The chunks of all threads may take up to 1/n of the memory left in the budget of a query.
+const BUDGET_CHUNK_FRACTION: usize = 16;
+const MIN_BUDGET_CHUNK_SIZE: usize = 256;
+
 /// ideal chunk size we strive to have
 /// scale the chunk size depending on the number of
 /// columns. With 10 columns we use a chunk size of 40_000
-pub(crate) fn determine_chunk_size(n_cols: usize, n_threads: usize) -> PolarsResult<usize> {
-    if let Ok(val) = std::env::var("POLARS_STREAMING_CHUNK_SIZE") {
+/// If the query has a memory budget, the chunks shrink as the budget gets tight.
+pub(crate) fn determine_chunk_size(
+    n_cols: usize,
+    n_threads: usize,
+    budget: Option<&MemoryBudget>,
+) -> PolarsResult<usize> {
+    if let Some(val) = polars_core::config::get_var(polars_core::config::STREAMING_CHUNK_SIZE) {
         val.parse().map_err(
             |_| polars_err!(ComputeError: "could not parse 'POLARS_STREAMING_CHUNK_SIZE' env var"),
         )
     } else {
         let thread_factor = std::cmp::max(12 / n_threads, 1);
-        Ok(std::cmp::max(50_000 / n_cols.max(1) * thread_factor, 1000))
+        let chunk_size = std::cmp::max(50_000 / n_cols.max(1) * thread_factor, 1000);
+        let Some(budget) = budget else {
+            return Ok(chunk_size);
+        };
+        // We don't know the row size up front, assume 8 bytes per value.
+        let bytes_per_chunk_row = n_cols.max(1) * 8 * n_threads.max(1);
+        let max_chunk_size = budget.available() / BUDGET_CHUNK_FRACTION / bytes_per_chunk_row;
+        Ok(chunk_size.min(max_chunk_size).max(MIN_BUDGET_CHUNK_SIZE))
     }
 }
 
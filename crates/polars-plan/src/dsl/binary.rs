@@ -58,4 +58,45 @@ impl BinaryNameSpace {
         self.0
             .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
+
+    /// Encrypt the values with an AEAD algorithm. Every value gets a random nonce that is stored
+    /// in front of its ciphertext, so the output is Binary.
+    ///
+    /// `aad` is the associated data that is authenticated along with the values, e.g. the key of
+    /// the row, or a single value for all rows. String values and associated data are taken as
+    /// their UTF-8 bytes.
+    ///
+    /// The key is not part of a serialized plan, a plan with this expression can't be
+    /// serialized.
+    #[cfg(feature = "binary_encryption")]
+    pub fn encrypt_aead(self, key: AeadKey, algorithm: AeadAlgorithm, aad: Option<Expr>) -> Expr {
+        let function = FunctionExpr::BinaryExpr(BinaryFunction::EncryptAead { key, algorithm });
+        match aad {
+            Some(aad) => self.0.map_many_private(function, &[aad], false, true),
+            None => self.0.map_private(function),
+        }
+    }
+
+    /// Decrypt values encrypted by [`encrypt_aead`](Self::encrypt_aead) with the same key,
+    /// algorithm and associated data. Values that don't authenticate raise an error if `strict`,
+    /// and are null otherwise. The output is Binary, cast it to String to get the decrypted
+    /// strings.
+    #[cfg(feature = "binary_encryption")]
+    pub fn decrypt_aead(
+        self,
+        key: AeadKey,
+        algorithm: AeadAlgorithm,
+        aad: Option<Expr>,
+        strict: bool,
+    ) -> Expr {
+        let function = FunctionExpr::BinaryExpr(BinaryFunction::DecryptAead {
+            key,
+            algorithm,
+            strict,
+        });
+        match aad {
+            Some(aad) => self.0.map_many_private(function, &[aad], false, true),
+            None => self.0.map_private(function),
+        }
+    }
 }
@@ -8,4 +8,22 @@ impl CategoricalNameSpace {
         self.0
             .apply_private(CategoricalFunction::GetCategories.into())
     }
+
+    /// Append categories to the categories, without changing the values. Categories that are
+    /// present already are ignored.
+    pub fn add_categories(self, categories: Vec<String>) -> Expr {
+        self.0
+            .map_private(CategoricalFunction::AddCategories(categories).into())
+    }
+
+    /// Remove the categories that don't occur in the values.
+    pub fn remove_unused(self) -> Expr {
+        self.0.map_private(CategoricalFunction::RemoveUnused.into())
+    }
+
+    /// Change the order of the categories. `categories` must contain every category exactly once.
+    pub fn reorder(self, categories: Vec<String>) -> Expr {
+        self.0
+            .map_private(CategoricalFunction::Reorder(categories).into())
+    }
 }
@@ -25,6 +25,33 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Count the number of business days until `end`, excluding `end`.
+    #[cfg(feature = "business")]
+    pub fn business_day_count(self, end: Expr, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::BusinessDayCount {
+                week_mask,
+                holidays,
+            }),
+            &[end],
+            false,
+            false,
+        )
+    }
+
+    /// Check whether the dates are business days.
+    ///
+    /// Holidays given as a `Date` [`Series`] can be converted with
+    /// [`holidays_from_series`](polars_ops::prelude::holidays_from_series).
+    #[cfg(feature = "business")]
+    pub fn is_business_day(self, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::IsBusinessDay {
+                week_mask,
+                holidays,
+            }))
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(self, format: &str) -> Expr {
@@ -204,8 +231,20 @@ impl DateLikeNameSpace {
 
     /// Truncate the Datetime/Date range into buckets.
     pub fn truncate(self, every: Expr) -> Expr {
+        self.truncate_with_dst_strategy(every, Ambiguous::Earliest, NonExistent::Raise)
+    }
+
+    /// Truncate the Datetime/Date range into buckets, localizing results which are
+    /// ambiguous or non-existent in the time zone of the data according to
+    /// `ambiguous` and `non_existent`.
+    pub fn truncate_with_dst_strategy(
+        self,
+        every: Expr,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::Truncate),
+            FunctionExpr::TemporalExpr(TemporalFunction::Truncate(ambiguous, non_existent)),
             &[every],
             false,
             false,
@@ -331,4 +370,34 @@ impl DateLikeNameSpace {
             TemporalFunction::TotalNanoseconds,
         ))
     }
+
+    /// Multiply a Duration by an integer or float `factor`, in `time_unit`, the time unit of the
+    /// Duration by default. A float result is rounded to the nearest `time_unit`.
+    ///
+    /// Nulls are preserved. A result that overflows is an error if `strict`, else a null.
+    #[cfg(feature = "dtype-duration")]
+    pub fn duration_mul(self, factor: Expr, time_unit: Option<TimeUnit>, strict: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::DurationMul { time_unit, strict }),
+            &[factor],
+            false,
+            false,
+        )
+    }
+
+    /// Divide a Duration by a `divisor`. Divided by a Duration, the result is their `Float64`
+    /// ratio, divided by an integer or float, the result is a Duration in `time_unit`, the time
+    /// unit of the Duration by default.
+    ///
+    /// Nulls are preserved and a division by zero is null. A result that overflows is an error if
+    /// `strict`, else a null.
+    #[cfg(feature = "dtype-duration")]
+    pub fn duration_div(self, divisor: Expr, time_unit: Option<TimeUnit>, strict: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::DurationDiv { time_unit, strict }),
+            &[divisor],
+            false,
+            false,
+        )
+    }
 }
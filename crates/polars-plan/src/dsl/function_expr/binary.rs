@@ -20,6 +20,17 @@ pub enum BinaryFunction {
     Base64Decode(bool),
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
+    #[cfg(feature = "binary_encryption")]
+    EncryptAead {
+        key: AeadKey,
+        algorithm: AeadAlgorithm,
+    },
+    #[cfg(feature = "binary_encryption")]
+    DecryptAead {
+        key: AeadKey,
+        algorithm: AeadAlgorithm,
+        strict: bool,
+    },
 }
 
 impl BinaryFunction {
@@ -32,6 +43,8 @@ impl BinaryFunction {
             HexDecode(_) | Base64Decode(_) => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
             HexEncode | Base64Encode => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "binary_encryption")]
+            EncryptAead { .. } | DecryptAead { .. } => mapper.with_dtype(DataType::Binary),
         }
     }
 }
@@ -51,6 +64,10 @@ impl Display for BinaryFunction {
             Base64Decode(_) => "base64_decode",
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
+            #[cfg(feature = "binary_encryption")]
+            EncryptAead { .. } => "encrypt_aead",
+            #[cfg(feature = "binary_encryption")]
+            DecryptAead { .. } => "decrypt_aead",
         };
         write!(f, "bin.{s}")
     }
@@ -77,6 +94,14 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Base64Decode(strict) => map!(base64_decode, strict),
             #[cfg(feature = "binary_encoding")]
             Base64Encode => map!(base64_encode),
+            #[cfg(feature = "binary_encryption")]
+            EncryptAead { key, algorithm } => map_as_slice!(encrypt_aead, &key, algorithm),
+            #[cfg(feature = "binary_encryption")]
+            DecryptAead {
+                key,
+                algorithm,
+                strict,
+            } => map_as_slice!(decrypt_aead, &key, algorithm, strict),
         }
     }
 }
@@ -131,6 +156,41 @@ pub(super) fn base64_encode(s: &Series) -> PolarsResult<Series> {
     Ok(ca.base64_encode())
 }
 
+/// The values and the associated data of an AEAD function as binary, String columns are
+/// encrypted as their UTF-8 bytes.
+#[cfg(feature = "binary_encryption")]
+fn aead_inputs(s: &[Series]) -> PolarsResult<(BinaryChunked, Option<BinaryChunked>)> {
+    let as_binary = |s: &Series| match s.dtype() {
+        DataType::String => Ok(s.str()?.as_binary()),
+        _ => s.binary().cloned(),
+    };
+    let aad = s.get(1).map(as_binary).transpose()?;
+    Ok((as_binary(&s[0])?, aad))
+}
+
+#[cfg(feature = "binary_encryption")]
+pub(super) fn encrypt_aead(
+    s: &[Series],
+    key: &AeadKey,
+    algorithm: AeadAlgorithm,
+) -> PolarsResult<Series> {
+    let (ca, aad) = aead_inputs(s)?;
+    ca.encrypt_aead(key, algorithm, aad.as_ref())
+        .map(|ok| ok.into_series())
+}
+
+#[cfg(feature = "binary_encryption")]
+pub(super) fn decrypt_aead(
+    s: &[Series],
+    key: &AeadKey,
+    algorithm: AeadAlgorithm,
+    strict: bool,
+) -> PolarsResult<Series> {
+    let (ca, aad) = aead_inputs(s)?;
+    ca.decrypt_aead(key, algorithm, aad.as_ref(), strict)
+        .map(|ok| ok.into_series())
+}
+
 impl From<BinaryFunction> for FunctionExpr {
     fn from(b: BinaryFunction) -> Self {
         FunctionExpr::BinaryExpr(b)
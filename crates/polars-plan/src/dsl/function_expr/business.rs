@@ -23,6 +23,11 @@ pub enum BusinessFunction {
         holidays: Vec<i32>,
         roll: Roll,
     },
+    #[cfg(feature = "business")]
+    IsBusinessDay {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
 }
 
 impl Display for BusinessFunction {
@@ -33,6 +38,8 @@ impl Display for BusinessFunction {
             &BusinessDayCount { .. } => "business_day_count",
             #[cfg(feature = "business")]
             &AddBusinessDay { .. } => "add_business_days",
+            #[cfg(feature = "business")]
+            &IsBusinessDay { .. } => "is_business_day",
         };
         write!(f, "{s}")
     }
@@ -56,6 +63,13 @@ impl From<BusinessFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             } => {
                 map_as_slice!(add_business_days, week_mask, &holidays, roll)
             },
+            #[cfg(feature = "business")]
+            IsBusinessDay {
+                week_mask,
+                holidays,
+            } => {
+                map_as_slice!(is_business_day, week_mask, &holidays)
+            },
         }
     }
 }
@@ -82,3 +96,12 @@ pub(super) fn add_business_days(
     let n = &s[1];
     polars_ops::prelude::add_business_days(start, n, week_mask, holidays, roll)
 }
+
+#[cfg(feature = "business")]
+pub(super) fn is_business_day(
+    s: &[Series],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    polars_ops::prelude::is_business_day(&s[0], week_mask, holidays)
+}
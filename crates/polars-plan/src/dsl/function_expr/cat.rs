@@ -5,6 +5,9 @@ use crate::map;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum CategoricalFunction {
     GetCategories,
+    AddCategories(Vec<String>),
+    RemoveUnused,
+    Reorder(Vec<String>),
 }
 
 impl CategoricalFunction {
@@ -12,6 +15,19 @@ impl CategoricalFunction {
         use CategoricalFunction::*;
         match self {
             GetCategories => mapper.with_dtype(DataType::String),
+            AddCategories(categories) => mapper
+                .try_map_dtype(|dt| map_rev_map(dt, |rev_map| rev_map.add_categories(categories))),
+            RemoveUnused => mapper.try_map_dtype(|dt| match dt {
+                DataType::Enum(_, ordering) => Ok(DataType::Enum(None, *ordering)),
+                dt => Ok(dt.clone()),
+            }),
+            Reorder(categories) => mapper.try_map_dtype(|dt| {
+                map_rev_map(dt, |rev_map| {
+                    rev_map
+                        .reorder_categories(categories)
+                        .map(|(rev_map, _)| rev_map)
+                })
+            }),
         }
     }
 }
@@ -21,6 +37,9 @@ impl Display for CategoricalFunction {
         use CategoricalFunction::*;
         let s = match self {
             GetCategories => "get_categories",
+            AddCategories(_) => "add_categories",
+            RemoveUnused => "remove_unused",
+            Reorder(_) => "reorder",
         };
         write!(f, "cat.{s}")
     }
@@ -31,6 +50,9 @@ impl From<CategoricalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
         use CategoricalFunction::*;
         match func {
             GetCategories => map!(get_categories),
+            AddCategories(categories) => map!(add_categories, &categories),
+            RemoveUnused => map!(remove_unused),
+            Reorder(categories) => map!(reorder, &categories),
         }
     }
 }
@@ -48,3 +70,33 @@ fn get_categories(s: &Series) -> PolarsResult<Series> {
     let arr = rev_map.get_categories().clone().boxed();
     Series::try_from((ca.name(), arr))
 }
+
+/// The dtype with the categories of an Enum changed by `func`. The categories of a Categorical
+/// depend on the data, so it is returned as is.
+fn map_rev_map(
+    dtype: &DataType,
+    func: impl FnOnce(&RevMapping) -> PolarsResult<RevMapping>,
+) -> PolarsResult<DataType> {
+    match dtype {
+        DataType::Enum(Some(rev_map), ordering) => {
+            Ok(DataType::Enum(Some(Arc::new(func(rev_map)?)), *ordering))
+        },
+        DataType::Enum(None, _) | DataType::Categorical(_, _) => Ok(dtype.clone()),
+        dt => polars_bail!(opq = cat, got = dt, expected = "categorical/enum"),
+    }
+}
+
+fn add_categories(s: &Series, categories: &[String]) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    Ok(ca.add_categories(categories)?.into_series())
+}
+
+fn remove_unused(s: &Series) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    Ok(ca.remove_unused_categories()?.into_series())
+}
+
+fn reorder(s: &Series, categories: &[String]) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    Ok(ca.reorder_categories(categories)?.into_series())
+}
@@ -51,7 +51,7 @@ pub enum TemporalFunction {
     #[cfg(feature = "timezones")]
     ConvertTimeZone(TimeZone),
     TimeStamp(TimeUnit),
-    Truncate,
+    Truncate(Ambiguous, NonExistent),
     #[cfg(feature = "offset_by")]
     OffsetBy,
     #[cfg(feature = "month_start")]
@@ -70,6 +70,16 @@ pub enum TemporalFunction {
         time_unit: TimeUnit,
         time_zone: Option<TimeZone>,
     },
+    #[cfg(feature = "dtype-duration")]
+    DurationMul {
+        time_unit: Option<TimeUnit>,
+        strict: bool,
+    },
+    #[cfg(feature = "dtype-duration")]
+    DurationDiv {
+        time_unit: Option<TimeUnit>,
+        strict: bool,
+    },
 }
 
 impl TemporalFunction {
@@ -106,7 +116,7 @@ impl TemporalFunction {
                 DataType::Datetime(tu, _) => Ok(DataType::Datetime(*tu, None)),
                 dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
             }),
-            Truncate => mapper.with_same_dtype(),
+            Truncate(_, _) => mapper.with_same_dtype(),
             #[cfg(feature = "offset_by")]
             OffsetBy => mapper.with_same_dtype(),
             #[cfg(feature = "month_start")]
@@ -134,6 +144,18 @@ impl TemporalFunction {
                     polars_bail!(ComputeError: "expected Date or Datetime, got {}", dtype)
                 },
             }),
+            #[cfg(feature = "dtype-duration")]
+            DurationMul { time_unit, .. } => mapper.try_map_dtype(|dt| match dt {
+                DataType::Duration(tu) => Ok(DataType::Duration(time_unit.unwrap_or(*tu))),
+                dtype => polars_bail!(ComputeError: "expected Duration, got {}", dtype),
+            }),
+            #[cfg(feature = "dtype-duration")]
+            DurationDiv { time_unit, .. } => mapper.try_map_dtypes(|dts| match dts {
+                [DataType::Duration(_), DataType::Duration(_)] => Ok(DataType::Float64),
+                [DataType::Duration(tu), _] => Ok(DataType::Duration(time_unit.unwrap_or(*tu))),
+                [dtype, ..] => polars_bail!(ComputeError: "expected Duration, got {}", dtype),
+                [] => unreachable!(),
+            }),
         }
     }
 }
@@ -176,7 +198,7 @@ impl Display for TemporalFunction {
             CastTimeUnit(_) => "cast_time_unit",
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
-            Truncate => "truncate",
+            Truncate(_, _) => "truncate",
             #[cfg(feature = "offset_by")]
             OffsetBy => "offset_by",
             #[cfg(feature = "month_start")]
@@ -192,6 +214,10 @@ impl Display for TemporalFunction {
             ReplaceTimeZone(_, _) => "replace_time_zone",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
             Combine(_) => "combine",
+            #[cfg(feature = "dtype-duration")]
+            DurationMul { .. } => "duration_mul",
+            #[cfg(feature = "dtype-duration")]
+            DurationDiv { .. } => "duration_div",
         };
         write!(f, "dt.{s}")
     }
@@ -383,7 +409,11 @@ pub(super) fn cast_time_unit(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn truncate(s: &[Series]) -> PolarsResult<Series> {
+pub(super) fn truncate(
+    s: &[Series],
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
+) -> PolarsResult<Series> {
     let time_series = &s[0];
     let every = s[1].str()?;
 
@@ -392,7 +422,12 @@ pub(super) fn truncate(s: &[Series]) -> PolarsResult<Series> {
             #[cfg(feature = "timezones")]
             Some(tz) => time_series
                 .datetime()?
-                .truncate(tz.parse::<Tz>().ok().as_ref(), every)?
+                .truncate_with_dst_strategy(
+                    tz.parse::<Tz>().ok().as_ref(),
+                    every,
+                    ambiguous,
+                    non_existent,
+                )?
                 .into_series(),
             _ => time_series.datetime()?.truncate(None, every)?.into_series(),
         },
@@ -9,6 +9,26 @@ pub(super) fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
     polars_ops::prelude::approx_n_unique(s)
 }
 
+#[cfg(feature = "approx_quantile")]
+pub(super) fn approx_quantile(s: &Series, quantile: f64, accuracy: f64) -> PolarsResult<Series> {
+    polars_ops::prelude::approx_quantile(s, quantile, accuracy)
+}
+
+#[cfg(feature = "approx_quantile")]
+pub(super) fn tdigest(s: &Series, accuracy: f64) -> PolarsResult<Series> {
+    polars_ops::prelude::tdigest(s, accuracy)
+}
+
+#[cfg(feature = "approx_quantile")]
+pub(super) fn tdigest_merge(s: &Series) -> PolarsResult<Series> {
+    polars_ops::prelude::tdigest_merge(s)
+}
+
+#[cfg(feature = "approx_quantile")]
+pub(super) fn tdigest_quantile(s: &Series, quantile: f64) -> PolarsResult<Series> {
+    polars_ops::prelude::tdigest_quantile(s, quantile)
+}
+
 #[cfg(feature = "diff")]
 pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
     polars_ops::prelude::diff(s, n, null_behavior)
@@ -19,16 +39,31 @@ pub(super) fn pct_change(s: &[Series]) -> PolarsResult<Series> {
     polars_ops::prelude::pct_change(&s[0], &s[1])
 }
 
+#[cfg(feature = "finance")]
+pub(super) fn pct_returns(s: &Series, log: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::pct_returns(s, log)
+}
+
+#[cfg(feature = "finance")]
+pub(super) fn max_drawdown(s: &Series) -> PolarsResult<Series> {
+    polars_ops::prelude::max_drawdown(s)
+}
+
+#[cfg(feature = "finance")]
+pub(super) fn sharpe(s: &Series, risk_free_rate: f64, periods: f64) -> PolarsResult<Series> {
+    polars_ops::prelude::sharpe(s, risk_free_rate, periods)
+}
+
 #[cfg(feature = "interpolate")]
 pub(super) fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResult<Series> {
     Ok(polars_ops::prelude::interpolate(s, method))
 }
 
 #[cfg(feature = "interpolate_by")]
-pub(super) fn interpolate_by(s: &[Series]) -> PolarsResult<Series> {
+pub(super) fn interpolate_by(s: &[Series], method: InterpolationMethod) -> PolarsResult<Series> {
     let by = &s[1];
     let by_is_sorted = by.is_sorted(Default::default())?;
-    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
+    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted, method)
 }
 
 pub(super) fn to_physical(s: &Series) -> PolarsResult<Series> {
@@ -134,15 +169,79 @@ pub(super) fn arg_unique(s: &Series) -> PolarsResult<Series> {
     s.arg_unique().map(|ok| ok.into_series())
 }
 
+pub(super) fn min_max_by(s: &[Series], max: bool) -> PolarsResult<Series> {
+    let (values, by) = (&s[0], &s[1]);
+    polars_ensure!(
+        values.len() == by.len(),
+        ShapeMismatch: "`{}_by` expected a `by` column of length {}, got {}",
+        if max { "max" } else { "min" }, values.len(), by.len()
+    );
+    let idx = if max { by.arg_max() } else { by.arg_min() };
+    Ok(match idx {
+        Some(idx) => values.slice(idx as i64, 1),
+        None => Series::full_null(values.name(), 1, values.dtype()),
+    })
+}
+
+pub(super) fn first_last_n(s: &Series, n: usize, last: bool) -> PolarsResult<Series> {
+    let out = if last {
+        s.tail(Some(n))
+    } else {
+        s.head(Some(n))
+    };
+    Ok(out.implode()?.into_series())
+}
+
+#[cfg(feature = "mode")]
+pub(super) fn mode_k(s: &Series, k: usize) -> PolarsResult<Series> {
+    Ok(mode::mode_k(s, k)?.implode()?.into_series())
+}
+
+pub(super) fn n_unique_rows(s: &[Series], filtered: bool) -> PolarsResult<Series> {
+    let (columns, predicate) = if filtered {
+        let (predicate, columns) = s.split_last().unwrap();
+        (columns, Some(predicate.bool()?))
+    } else {
+        (s, None)
+    };
+    let n = polars_ops::prelude::n_unique_rows(columns, predicate)?;
+    Ok(Series::new(s[0].name(), &[n]))
+}
+
+pub(super) fn count_if(s: &[Series]) -> PolarsResult<Series> {
+    let n = polars_ops::prelude::count_if(&s[0], s[1].bool()?)?;
+    Ok(Series::new(s[0].name(), &[n]))
+}
+
+pub(super) fn weighted_agg(s: &[Series], agg: WeightedAggregation) -> PolarsResult<Series> {
+    polars_ops::prelude::weighted_agg(&s[0], &s[1], agg)
+}
+
 #[cfg(feature = "rank")]
 pub(super) fn rank(s: &Series, options: RankOptions, seed: Option<u64>) -> PolarsResult<Series> {
     Ok(s.rank(options, seed))
 }
 
+#[cfg(feature = "rank")]
+pub(super) fn percent_rank(s: &Series, descending: bool) -> PolarsResult<Series> {
+    Ok(s.percent_rank(descending))
+}
+
+#[cfg(feature = "rank")]
+pub(super) fn cume_dist(s: &Series, descending: bool) -> PolarsResult<Series> {
+    Ok(s.cume_dist(descending))
+}
+
+#[cfg(feature = "rank")]
+pub(super) fn ntile(s: &Series, buckets: IdxSize, descending: bool) -> PolarsResult<Series> {
+    s.ntile(buckets, descending)
+}
+
 #[cfg(feature = "hist")]
 pub(super) fn hist(
     s: &[Series],
     bin_count: Option<usize>,
+    strategy: HistBinStrategy,
     include_category: bool,
     include_breakpoint: bool,
 ) -> PolarsResult<Series> {
@@ -152,7 +251,14 @@ pub(super) fn hist(
         None
     };
     let s = &s[0];
-    hist_series(s, bin_count, bins, include_category, include_breakpoint)
+    hist_series(
+        s,
+        bin_count,
+        bins,
+        strategy,
+        include_category,
+        include_breakpoint,
+    )
 }
 
 #[cfg(feature = "replace")]
@@ -49,6 +49,8 @@ pub enum ListFunction {
     NUnique,
     #[cfg(feature = "list_sets")]
     SetOperation(SetOperation),
+    #[cfg(feature = "list_elementwise")]
+    ElementWise(ListBinaryOp),
     #[cfg(feature = "list_any_all")]
     Any,
     #[cfg(feature = "list_any_all")]
@@ -95,6 +97,17 @@ impl ListFunction {
             Length => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_sets")]
             SetOperation(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "list_elementwise")]
+            ElementWise(op) => match op {
+                op if op.is_comparison() => {
+                    mapper.with_dtype(DataType::List(Box::new(DataType::Boolean)))
+                },
+                ListBinaryOp::TrueDiv => mapper.map_dtype(|dt| match dt.leaf_dtype() {
+                    DataType::Float32 => dt.clone(),
+                    _ => dt.cast_leaf(DataType::Float64),
+                }),
+                _ => mapper.map_to_list_supertype(),
+            },
             #[cfg(feature = "list_any_all")]
             Any => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "list_any_all")]
@@ -167,6 +180,8 @@ impl Display for ListFunction {
             NUnique => "n_unique",
             #[cfg(feature = "list_sets")]
             SetOperation(s) => return write!(f, "list.{s}"),
+            #[cfg(feature = "list_elementwise")]
+            ElementWise(op) => return write!(f, "list.elementwise_{op}"),
             #[cfg(feature = "list_any_all")]
             Any => "any",
             #[cfg(feature = "list_any_all")]
@@ -227,6 +242,8 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Unique(is_stable) => map!(unique, is_stable),
             #[cfg(feature = "list_sets")]
             SetOperation(s) => map_as_slice!(set_operation, s),
+            #[cfg(feature = "list_elementwise")]
+            ElementWise(op) => map_as_slice!(elementwise, op),
             #[cfg(feature = "list_any_all")]
             Any => map!(lst_any),
             #[cfg(feature = "list_any_all")]
@@ -590,6 +607,11 @@ pub(super) fn set_operation(s: &[Series], set_type: SetOperation) -> PolarsResul
     list_set_operation(s0.list()?, s1.list()?, set_type).map(|ca| ca.into_series())
 }
 
+#[cfg(feature = "list_elementwise")]
+pub(super) fn elementwise(s: &[Series], op: ListBinaryOp) -> PolarsResult<Series> {
+    list_elementwise(s[0].list()?, s[1].list()?, op).map(|ca| ca.into_series())
+}
+
 #[cfg(feature = "list_any_all")]
 pub(super) fn lst_any(s: &Series) -> PolarsResult<Series> {
     s.list()?.lst_any()
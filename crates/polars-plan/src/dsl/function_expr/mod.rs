@@ -34,6 +34,8 @@ mod list;
 #[cfg(feature = "log")]
 mod log;
 mod nan;
+#[cfg(feature = "polars-ml")]
+mod onnx;
 #[cfg(feature = "peaks")]
 mod peaks;
 #[cfg(feature = "ffi_plugin")]
@@ -78,6 +80,8 @@ pub(crate) use correlation::CorrelationMethod;
 #[cfg(feature = "fused")]
 pub(crate) use fused::FusedOperator;
 pub(super) use list::ListFunction;
+#[cfg(feature = "polars-ml")]
+pub use onnx::OnnxOptions;
 use polars_core::prelude::*;
 #[cfg(feature = "random")]
 pub(crate) use random::RandomMethod;
@@ -135,6 +139,7 @@ pub enum FunctionExpr {
     #[cfg(feature = "hist")]
     Hist {
         bin_count: Option<usize>,
+        strategy: HistBinStrategy,
         include_category: bool,
         include_breakpoint: bool,
     },
@@ -174,11 +179,47 @@ pub enum FunctionExpr {
     #[cfg(feature = "repeat_by")]
     RepeatBy,
     ArgUnique,
+    /// The value at the minimum or maximum of a second input.
+    MinMaxBy {
+        max: bool,
+    },
+    /// The first or the `last` `n` values, as a list.
+    FirstLastN {
+        n: usize,
+        last: bool,
+    },
+    /// The `k` most frequent values, as a list.
+    #[cfg(feature = "mode")]
+    ModeK {
+        k: usize,
+    },
+    /// The number of distinct rows of the inputs. If `filtered`, the last input is a predicate
+    /// that selects the rows to consider.
+    NUniqueRows {
+        filtered: bool,
+    },
+    /// The number of non-null values of the first input where the second input is true.
+    CountIf,
+    /// An aggregation of the first input, weighted by the second input.
+    WeightedAgg(WeightedAggregation),
     #[cfg(feature = "rank")]
     Rank {
         options: RankOptions,
         seed: Option<u64>,
     },
+    #[cfg(feature = "rank")]
+    PercentRank {
+        descending: bool,
+    },
+    #[cfg(feature = "rank")]
+    CumeDist {
+        descending: bool,
+    },
+    #[cfg(feature = "rank")]
+    Ntile {
+        buckets: IdxSize,
+        descending: bool,
+    },
     #[cfg(feature = "round_series")]
     Clip {
         has_min: bool,
@@ -226,16 +267,42 @@ pub enum FunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile {
+        quantile: f64,
+        accuracy: f64,
+    },
+    #[cfg(feature = "approx_quantile")]
+    TDigest {
+        accuracy: f64,
+    },
+    #[cfg(feature = "approx_quantile")]
+    TDigestMerge,
+    #[cfg(feature = "approx_quantile")]
+    TDigestQuantile {
+        quantile: f64,
+    },
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
     Diff(i64, NullBehavior),
     #[cfg(feature = "pct_change")]
     PctChange,
+    #[cfg(feature = "finance")]
+    PctReturns {
+        log: bool,
+    },
+    #[cfg(feature = "finance")]
+    MaxDrawdown,
+    #[cfg(feature = "finance")]
+    Sharpe {
+        risk_free_rate: f64,
+        periods: f64,
+    },
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
     #[cfg(feature = "interpolate_by")]
-    InterpolateBy,
+    InterpolateBy(InterpolationMethod),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -313,6 +380,9 @@ pub enum FunctionExpr {
         /// Pickle serialized keyword arguments.
         kwargs: Arc<[u8]>,
     },
+    #[cfg(feature = "polars-ml")]
+    /// Run an ONNX model over the inputs as feature columns.
+    OnnxPredict(OnnxOptions),
     BackwardFill {
         limit: FillNullLimit,
     },
@@ -331,6 +401,16 @@ pub enum FunctionExpr {
     EwmMeanBy {
         half_life: Duration,
     },
+    #[cfg(feature = "ewma_by")]
+    EwmStdBy {
+        half_life: Duration,
+        bias: bool,
+    },
+    #[cfg(feature = "ewma_by")]
+    EwmVarBy {
+        half_life: Duration,
+        bias: bool,
+    },
     #[cfg(feature = "ewma")]
     EwmStd {
         options: EWMOptions,
@@ -395,7 +475,7 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {},
+            InterpolateBy(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 lib,
@@ -406,8 +486,20 @@ impl Hash for FunctionExpr {
                 lib.hash(state);
                 symbol.hash(state);
             },
+            #[cfg(feature = "polars-ml")]
+            OnnxPredict(options) => options.hash(state),
             MaxHorizontal | MinHorizontal | SumHorizontal | MeanHorizontal | DropNans
-            | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill => {},
+            | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill | CountIf => {},
+            MinMaxBy { max } => max.hash(state),
+            FirstLastN { n, last } => (n, last).hash(state),
+            #[cfg(feature = "mode")]
+            ModeK { k } => k.hash(state),
+            NUniqueRows { filtered } => filtered.hash(state),
+            WeightedAgg(agg) => match agg {
+                WeightedAggregation::Mean => 0u8.hash(state),
+                WeightedAggregation::Var { ddof } => (1u8, ddof).hash(state),
+                WeightedAggregation::Quantile(quantile) => (2u8, quantile.to_bits()).hash(state),
+            },
             #[cfg(feature = "mode")]
             Mode => {},
             #[cfg(feature = "abs")]
@@ -445,6 +537,16 @@ impl Hash for FunctionExpr {
                 options.hash(state);
                 seed.hash(state);
             },
+            #[cfg(feature = "rank")]
+            PercentRank { descending } | CumeDist { descending } => descending.hash(state),
+            #[cfg(feature = "rank")]
+            Ntile {
+                buckets,
+                descending,
+            } => {
+                buckets.hash(state);
+                descending.hash(state);
+            },
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
                 has_min.hash(state);
@@ -478,10 +580,33 @@ impl Hash for FunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { quantile, accuracy } => {
+                quantile.to_bits().hash(state);
+                accuracy.to_bits().hash(state);
+            },
+            #[cfg(feature = "approx_quantile")]
+            TDigest { accuracy } => accuracy.to_bits().hash(state),
+            #[cfg(feature = "approx_quantile")]
+            TDigestMerge => {},
+            #[cfg(feature = "approx_quantile")]
+            TDigestQuantile { quantile } => quantile.to_bits().hash(state),
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
+            #[cfg(feature = "finance")]
+            PctReturns { log } => log.hash(state),
+            #[cfg(feature = "finance")]
+            MaxDrawdown => {},
+            #[cfg(feature = "finance")]
+            Sharpe {
+                risk_free_rate,
+                periods,
+            } => {
+                risk_free_rate.to_bits().hash(state);
+                periods.to_bits().hash(state);
+            },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => {
                 base.to_bits().hash(state);
@@ -554,6 +679,11 @@ impl Hash for FunctionExpr {
             EwmMean { options } => options.hash(state),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { half_life } => (half_life).hash(state),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { half_life, bias } | EwmVarBy { half_life, bias } => {
+                half_life.hash(state);
+                bias.hash(state);
+            },
             #[cfg(feature = "ewma")]
             EwmStd { options } => options.hash(state),
             #[cfg(feature = "ewma")]
@@ -561,10 +691,12 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "hist")]
             Hist {
                 bin_count,
+                strategy,
                 include_category,
                 include_breakpoint,
             } => {
                 bin_count.hash(state);
+                strategy.hash(state);
                 include_category.hash(state);
                 include_breakpoint.hash(state);
             },
@@ -639,8 +771,28 @@ impl Display for FunctionExpr {
             #[cfg(feature = "moment")]
             Kurtosis(..) => "kurtosis",
             ArgUnique => "arg_unique",
+            MinMaxBy { max: false } => "min_by",
+            MinMaxBy { max: true } => "max_by",
+            FirstLastN { last: false, .. } => "first_n",
+            FirstLastN { last: true, .. } => "last_n",
+            #[cfg(feature = "mode")]
+            ModeK { .. } => "mode_k",
+            NUniqueRows { filtered: false } => "n_unique_rows",
+            NUniqueRows { filtered: true } => "n_unique_if",
+            CountIf => "count_if",
+            WeightedAgg(agg) => match agg {
+                WeightedAggregation::Mean => "weighted_mean",
+                WeightedAggregation::Var { .. } => "weighted_var",
+                WeightedAggregation::Quantile(_) => "weighted_quantile",
+            },
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
+            #[cfg(feature = "rank")]
+            PercentRank { .. } => "percent_rank",
+            #[cfg(feature = "rank")]
+            CumeDist { .. } => "cume_dist",
+            #[cfg(feature = "rank")]
+            Ntile { .. } => "ntile",
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => match (has_min, has_max) {
                 (true, true) => "clip",
@@ -678,16 +830,30 @@ impl Display for FunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => "approx_quantile",
+            #[cfg(feature = "approx_quantile")]
+            TDigest { .. } => "tdigest",
+            #[cfg(feature = "approx_quantile")]
+            TDigestMerge => "tdigest_merge",
+            #[cfg(feature = "approx_quantile")]
+            TDigestQuantile { .. } => "tdigest_quantile",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
             Diff(_, _) => "diff",
             #[cfg(feature = "pct_change")]
             PctChange => "pct_change",
+            #[cfg(feature = "finance")]
+            PctReturns { .. } => "pct_returns",
+            #[cfg(feature = "finance")]
+            MaxDrawdown => "max_drawdown",
+            #[cfg(feature = "finance")]
+            Sharpe { .. } => "sharpe",
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => "interpolate_by",
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -739,6 +905,8 @@ impl Display for FunctionExpr {
             SetSortedFlag(_) => "set_sorted",
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
+            #[cfg(feature = "polars-ml")]
+            OnnxPredict(_) => "onnx_predict",
             BackwardFill { .. } => "backward_fill",
             ForwardFill { .. } => "forward_fill",
             MaxHorizontal => "max_horizontal",
@@ -749,6 +917,10 @@ impl Display for FunctionExpr {
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { .. } => "ewm_mean_by",
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { .. } => "ewm_std_by",
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { .. } => "ewm_var_by",
             #[cfg(feature = "ewma")]
             EwmStd { .. } => "ewm_std",
             #[cfg(feature = "ewma")]
@@ -947,17 +1119,21 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     },
                     VarBy(options) => map_as_slice!(rolling_by::rolling_var_by, options.clone()),
                     StdBy(options) => map_as_slice!(rolling_by::rolling_std_by, options.clone()),
+                    CovBy(options) => map_as_slice!(rolling_by::rolling_cov_by, options.clone()),
+                    CorrBy(options) => map_as_slice!(rolling_by::rolling_corr_by, options.clone()),
                 }
             },
             #[cfg(feature = "hist")]
             Hist {
                 bin_count,
+                strategy,
                 include_category,
                 include_breakpoint,
             } => {
                 map_as_slice!(
                     dispatch::hist,
                     bin_count,
+                    strategy,
                     include_category,
                     include_breakpoint
                 )
@@ -978,8 +1154,24 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "moment")]
             Kurtosis(fisher, bias) => map!(dispatch::kurtosis, fisher, bias),
             ArgUnique => map!(dispatch::arg_unique),
+            MinMaxBy { max } => map_as_slice!(dispatch::min_max_by, max),
+            FirstLastN { n, last } => map!(dispatch::first_last_n, n, last),
+            #[cfg(feature = "mode")]
+            ModeK { k } => map!(dispatch::mode_k, k),
+            NUniqueRows { filtered } => map_as_slice!(dispatch::n_unique_rows, filtered),
+            CountIf => map_as_slice!(dispatch::count_if),
+            WeightedAgg(agg) => map_as_slice!(dispatch::weighted_agg, agg),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
+            #[cfg(feature = "rank")]
+            PercentRank { descending } => map!(dispatch::percent_rank, descending),
+            #[cfg(feature = "rank")]
+            CumeDist { descending } => map!(dispatch::cume_dist, descending),
+            #[cfg(feature = "rank")]
+            Ntile {
+                buckets,
+                descending,
+            } => map!(dispatch::ntile, buckets, descending),
             #[cfg(feature = "dtype-struct")]
             AsStruct => {
                 map_as_slice!(coerce::as_struct)
@@ -1019,19 +1211,38 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Reverse => map!(dispatch::reverse),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => map!(dispatch::approx_n_unique),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { quantile, accuracy } => {
+                map!(dispatch::approx_quantile, quantile, accuracy)
+            },
+            #[cfg(feature = "approx_quantile")]
+            TDigest { accuracy } => map!(dispatch::tdigest, accuracy),
+            #[cfg(feature = "approx_quantile")]
+            TDigestMerge => map!(dispatch::tdigest_merge),
+            #[cfg(feature = "approx_quantile")]
+            TDigestQuantile { quantile } => map!(dispatch::tdigest_quantile, quantile),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
             Diff(n, null_behavior) => map!(dispatch::diff, n, null_behavior),
             #[cfg(feature = "pct_change")]
             PctChange => map_as_slice!(dispatch::pct_change),
+            #[cfg(feature = "finance")]
+            PctReturns { log } => map!(dispatch::pct_returns, log),
+            #[cfg(feature = "finance")]
+            MaxDrawdown => map!(dispatch::max_drawdown),
+            #[cfg(feature = "finance")]
+            Sharpe {
+                risk_free_rate,
+                periods,
+            } => map!(dispatch::sharpe, risk_free_rate, periods),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => {
                 map!(dispatch::interpolate, method)
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {
-                map_as_slice!(dispatch::interpolate_by)
+            InterpolateBy(method) => {
+                map_as_slice!(dispatch::interpolate_by, method)
             },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
@@ -1129,6 +1340,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     kwargs.as_ref()
                 )
             },
+            #[cfg(feature = "polars-ml")]
+            OnnxPredict(options) => map_as_slice!(onnx::onnx_predict, &options),
             BackwardFill { limit } => map!(dispatch::backward_fill, limit),
             ForwardFill { limit } => map!(dispatch::forward_fill, limit),
             MaxHorizontal => wrap!(dispatch::max_horizontal),
@@ -1139,6 +1352,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             EwmMean { options } => map!(ewm::ewm_mean, options),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { half_life } => map_as_slice!(ewm_by::ewm_mean_by, half_life),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { half_life, bias } => map_as_slice!(ewm_by::ewm_std_by, half_life, bias),
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { half_life, bias } => map_as_slice!(ewm_by::ewm_var_by, half_life, bias),
             #[cfg(feature = "ewma")]
             EwmStd { options } => map!(ewm::ewm_std, options),
             #[cfg(feature = "ewma")]
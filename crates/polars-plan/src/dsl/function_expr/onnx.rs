@@ -0,0 +1,165 @@
+use std::sync::RwLock;
+
+use ndarray::ArrayView2;
+use once_cell::sync::Lazy;
+use ort::{IntoTensorElementType, Session, Value};
+
+use super::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OnnxOptions {
+    /// Path to the `.onnx` model file.
+    pub model_path: Arc<str>,
+    /// Name of the model input the features are fed into. Defaults to the first input of the
+    /// model.
+    pub input_name: Option<Arc<str>>,
+    /// The model outputs to return and their data types. Every output must have one value per row.
+    pub outputs: Vec<Field>,
+    /// The number of rows that are passed to the model in a single run.
+    pub batch_size: usize,
+}
+
+impl OnnxOptions {
+    pub fn new(model_path: &str, outputs: Vec<Field>) -> Self {
+        Self {
+            model_path: model_path.into(),
+            input_name: None,
+            outputs,
+            batch_size: 8192,
+        }
+    }
+}
+
+static SESSIONS: Lazy<RwLock<PlHashMap<String, Arc<Session>>>> = Lazy::new(Default::default);
+
+fn to_compute_err(err: ort::Error) -> PolarsError {
+    polars_err!(ComputeError: "onnx runtime error: {}", err)
+}
+
+/// Load the model at `path`, or get it from the cache if it was loaded before.
+fn get_session(path: &str) -> PolarsResult<Arc<Session>> {
+    if let Some(session) = SESSIONS.read().unwrap().get(path) {
+        return Ok(session.clone());
+    }
+    let session = Session::builder()
+        .and_then(|builder| builder.commit_from_file(path))
+        .map_err(|e| polars_err!(ComputeError: "error loading onnx model '{}': {}", path, e))?;
+    let session = Arc::new(session);
+    SESSIONS
+        .write()
+        .unwrap()
+        .insert(path.to_string(), session.clone());
+    Ok(session)
+}
+
+fn extract_output<T>(value: &Value, field: &Field, n_rows: usize) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+    T::Native: IntoTensorElementType,
+{
+    let (shape, values) = value
+        .try_extract_raw_tensor::<T::Native>()
+        .map_err(to_compute_err)?;
+    polars_ensure!(
+        values.len() == n_rows,
+        ShapeMismatch: "onnx model output '{}' has shape {:?}, expected one value per row",
+        field.name(), shape
+    );
+    Ok(ChunkedArray::<T>::from_slice(field.name(), values).into_series())
+}
+
+/// Run the model over the feature columns in batches of `options.batch_size` rows and return
+/// the requested outputs as a struct.
+pub(super) fn onnx_predict(s: &[Series], options: &OnnxOptions) -> PolarsResult<Series> {
+    polars_ensure!(
+        !s.is_empty(),
+        ComputeError: "`onnx_predict` expected at least one feature column"
+    );
+    polars_ensure!(
+        options.batch_size > 0,
+        ComputeError: "`onnx_predict` expected a positive batch size"
+    );
+    let len = s[0].len();
+    let features = s
+        .iter()
+        .map(|s| {
+            polars_ensure!(
+                s.len() == len,
+                ShapeMismatch: "`onnx_predict` expected feature columns of equal length, got {} and {}",
+                len, s.len()
+            );
+            polars_ensure!(
+                s.null_count() == 0,
+                ComputeError: "`onnx_predict` got null values in feature column '{}'", s.name()
+            );
+            // This is free if the column already is a single chunk of `f32`.
+            Ok(s.cast(&DataType::Float32)?.rechunk())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let features = features
+        .iter()
+        .map(|s| s.f32().unwrap().cont_slice())
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let session = get_session(&options.model_path)?;
+    let input_name = match &options.input_name {
+        Some(name) => name.to_string(),
+        None => session
+            .inputs
+            .first()
+            .map(|input| input.name.clone())
+            .ok_or_else(|| polars_err!(ComputeError: "onnx model has no inputs"))?,
+    };
+
+    let mut columns: Vec<Option<Series>> = vec![None; options.outputs.len()];
+    let mut row_major = Vec::new();
+    for offset in (0..len).step_by(options.batch_size) {
+        let n_rows = options.batch_size.min(len - offset);
+        let input = if let [column] = features.as_slice() {
+            // A single column already has the layout of an `[n_rows, 1]` tensor, so the runtime
+            // reads it without a copy.
+            ArrayView2::from_shape((n_rows, 1), &column[offset..offset + n_rows])
+        } else {
+            row_major.clear();
+            row_major
+                .extend((offset..offset + n_rows).flat_map(|i| features.iter().map(move |c| c[i])));
+            ArrayView2::from_shape((n_rows, features.len()), &row_major)
+        }
+        .map_err(|e| polars_err!(ComputeError: "invalid onnx input shape: {}", e))?;
+
+        let inputs = ort::inputs![input_name.as_str() => input].map_err(to_compute_err)?;
+        let outputs = session.run(inputs).map_err(to_compute_err)?;
+        for (field, column) in options.outputs.iter().zip(columns.iter_mut()) {
+            let value = outputs.get(field.name().as_str()).ok_or_else(
+                || polars_err!(ColumnNotFound: "onnx model has no output '{}'", field.name()),
+            )?;
+            let batch = match field.data_type() {
+                DataType::Float32 => extract_output::<Float32Type>(value, field, n_rows),
+                DataType::Float64 => extract_output::<Float64Type>(value, field, n_rows),
+                DataType::Int32 => extract_output::<Int32Type>(value, field, n_rows),
+                DataType::Int64 => extract_output::<Int64Type>(value, field, n_rows),
+                dt => polars_bail!(
+                    InvalidOperation: "`onnx_predict` doesn't support output dtype {} of '{}'",
+                    dt, field.name()
+                ),
+            }?;
+            match column {
+                Some(column) => {
+                    column.append(&batch)?;
+                },
+                None => *column = Some(batch),
+            }
+        }
+    }
+
+    let columns = options
+        .outputs
+        .iter()
+        .zip(columns)
+        .map(|(field, column)| {
+            column.unwrap_or_else(|| Series::new_empty(field.name(), field.data_type()))
+        })
+        .collect::<Vec<_>>();
+    StructChunked::new(s[0].name(), &columns).map(|ca| ca.into_series())
+}
@@ -45,7 +45,10 @@ pub(super) fn sample_frac(
     let frac = frac_s.f64()?;
 
     match frac.get(0) {
-        Some(frac) => src.sample_frac(frac, with_replacement, shuffle, seed),
+        Some(frac) => match s.get(2) {
+            Some(weights) => src.sample_frac_weighted(frac, weights, with_replacement, seed),
+            None => src.sample_frac(frac, with_replacement, shuffle, seed),
+        },
         None => Ok(Series::new_empty(src.name(), src.dtype())),
     }
 }
@@ -68,7 +71,10 @@ pub(super) fn sample_n(
     let n = n_s.idx()?;
 
     match n.get(0) {
-        Some(n) => src.sample_n(n as usize, with_replacement, shuffle, seed),
+        Some(n) => match s.get(2) {
+            Some(weights) => src.sample_n_weighted(n as usize, weights, with_replacement, seed),
+            None => src.sample_n(n as usize, with_replacement, shuffle, seed),
+        },
         None => Ok(Series::new_empty(src.name(), src.dtype())),
     }
 }
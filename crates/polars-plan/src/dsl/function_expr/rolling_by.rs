@@ -12,6 +12,10 @@ pub enum RollingFunctionBy {
     QuantileBy(RollingOptionsDynamicWindow),
     VarBy(RollingOptionsDynamicWindow),
     StdBy(RollingOptionsDynamicWindow),
+    /// The inputs are `[x, y, by]`.
+    CovBy(RollingOptionsDynamicWindow),
+    /// The inputs are `[x, y, by]`.
+    CorrBy(RollingOptionsDynamicWindow),
 }
 
 impl Display for RollingFunctionBy {
@@ -26,6 +30,8 @@ impl Display for RollingFunctionBy {
             QuantileBy(_) => "rolling_quantile_by",
             VarBy(_) => "rolling_var_by",
             StdBy(_) => "rolling_std_by",
+            CovBy(_) => "rolling_cov_by",
+            CorrBy(_) => "rolling_corr_by",
         };
 
         write!(f, "{name}")
@@ -86,3 +92,17 @@ pub(super) fn rolling_std_by(
 ) -> PolarsResult<Series> {
     s[0].rolling_std_by(&s[1], options)
 }
+
+pub(super) fn rolling_cov_by(
+    s: &[Series],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Series> {
+    s[0].rolling_cov_by(&s[1], &s[2], options)
+}
+
+pub(super) fn rolling_corr_by(
+    s: &[Series],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Series> {
+    s[0].rolling_corr_by(&s[1], &s[2], options)
+}
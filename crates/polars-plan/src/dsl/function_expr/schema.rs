@@ -33,6 +33,7 @@ impl FunctionExpr {
             Business(func) => match func {
                 BusinessFunction::BusinessDayCount { .. } => mapper.with_dtype(DataType::Int32),
                 BusinessFunction::AddBusinessDay { .. } => mapper.with_same_dtype(),
+                BusinessFunction::IsBusinessDay { .. } => mapper.with_dtype(DataType::Boolean),
             },
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
@@ -74,6 +75,12 @@ impl FunctionExpr {
                 match rolling_func {
                     MinBy(_) | MaxBy(_) | SumBy(_) => mapper.with_same_dtype(),
                     MeanBy(_) | QuantileBy(_) | VarBy(_) | StdBy(_) => mapper.map_to_float_dtype(),
+                    CovBy(_) | CorrBy(_) => mapper.try_map_dtypes(|dtypes| {
+                        Ok(match (dtypes[0], dtypes[1]) {
+                            (DataType::Float32, DataType::Float32) => DataType::Float32,
+                            _ => DataType::Float64,
+                        })
+                    }),
                 }
             },
             ShiftAndFill => mapper.with_same_dtype(),
@@ -88,11 +95,21 @@ impl FunctionExpr {
             #[cfg(feature = "moment")]
             Kurtosis(..) => mapper.with_dtype(DataType::Float64),
             ArgUnique => mapper.with_dtype(IDX_DTYPE),
+            MinMaxBy { .. } => mapper.with_same_dtype(),
+            FirstLastN { .. } => mapper.map_dtype(|dt| DataType::List(Box::new(dt.clone()))),
+            #[cfg(feature = "mode")]
+            ModeK { .. } => mapper.map_dtype(|dt| DataType::List(Box::new(dt.clone()))),
+            NUniqueRows { .. } | CountIf => mapper.with_dtype(IDX_DTYPE),
+            WeightedAgg(_) => mapper.map_to_float_dtype(),
             #[cfg(feature = "rank")]
             Rank { options, .. } => mapper.with_dtype(match options.method {
                 RankMethod::Average => DataType::Float64,
                 _ => IDX_DTYPE,
             }),
+            #[cfg(feature = "rank")]
+            PercentRank { .. } | CumeDist { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "rank")]
+            Ntile { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "dtype-struct")]
             AsStruct => Ok(Field::new(
                 fields[0].name(),
@@ -134,6 +151,10 @@ impl FunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } | TDigestQuantile { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "approx_quantile")]
+            TDigest { .. } | TDigestMerge => mapper.with_dtype(DataType::Binary),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
@@ -175,13 +196,22 @@ impl FunctionExpr {
                 DataType::Float64 | DataType::Float32 => dt.clone(),
                 _ => DataType::Float64,
             }),
+            #[cfg(feature = "finance")]
+            PctReturns { .. } | MaxDrawdown | Sharpe { .. } => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => match method {
-                InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(),
+                InterpolationMethod::Linear
+                | InterpolationMethod::CubicSpline
+                | InterpolationMethod::Pchip => mapper.map_numeric_to_float_dtype(),
                 InterpolationMethod::Nearest => mapper.with_same_dtype(),
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => mapper.map_numeric_to_float_dtype(),
+            InterpolateBy(method) => match method {
+                InterpolationMethod::Linear
+                | InterpolationMethod::CubicSpline
+                | InterpolationMethod::Pchip => mapper.map_numeric_to_float_dtype(),
+                InterpolationMethod::Nearest => mapper.with_same_dtype(),
+            },
             ShrinkType => {
                 // we return the smallest type this can return
                 // this might not be correct once the actual data
@@ -293,6 +323,8 @@ impl FunctionExpr {
                 symbol,
                 kwargs,
             } => unsafe { plugin::plugin_field(fields, lib, symbol.as_ref(), kwargs) },
+            #[cfg(feature = "polars-ml")]
+            OnnxPredict(options) => mapper.with_dtype(DataType::Struct(options.outputs.clone())),
             BackwardFill { .. } => mapper.with_same_dtype(),
             ForwardFill { .. } => mapper.with_same_dtype(),
             MaxHorizontal => mapper.map_to_supertype(),
@@ -309,6 +341,8 @@ impl FunctionExpr {
             EwmMean { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { .. } | EwmVarBy { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
             EwmStd { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
@@ -9,7 +9,7 @@ use polars_core::utils::handle_casting_failures;
 #[cfg(feature = "dtype-struct")]
 use polars_utils::format_smartstring;
 #[cfg(feature = "regex")]
-use regex::{escape, Regex};
+use regex::{escape, NoExpand, Regex};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -73,6 +73,22 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein {
+        normalized: bool,
+    },
+    #[cfg(feature = "string_similarity")]
+    Hamming {
+        normalized: bool,
+    },
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_tokenize")]
+    Tokenize(Tokenizer),
+    #[cfg(feature = "string_tokenize")]
+    NGrams(usize),
+    #[cfg(feature = "string_tokenize")]
+    StripAccents,
     #[cfg(feature = "string_pad")]
     PadStart {
         length: usize,
@@ -112,6 +128,11 @@ pub enum StringFunction {
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
+    #[cfg(feature = "dtype-duration")]
+    ToDuration {
+        time_unit: TimeUnit,
+        strict: bool,
+    },
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -155,6 +176,22 @@ impl StringFunction {
             Replace { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { normalized } | Hamming { normalized } => {
+                if *normalized {
+                    mapper.with_dtype(DataType::Float64)
+                } else {
+                    mapper.with_dtype(DataType::UInt32)
+                }
+            },
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "string_tokenize")]
+            Tokenize(_) | NGrams(_) => {
+                mapper.with_dtype(DataType::List(Box::new(DataType::String)))
+            },
+            #[cfg(feature = "string_tokenize")]
+            StripAccents => mapper.with_same_dtype(),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
@@ -162,6 +199,8 @@ impl StringFunction {
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => mapper.with_dtype(DataType::Decimal(None, None)),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, .. } => mapper.with_dtype(DataType::Duration(*time_unit)),
             #[cfg(feature = "string_encoding")]
             HexEncode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -231,6 +270,18 @@ impl Display for StringFunction {
             Replace { .. } => "replace",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { .. } => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Hamming { .. } => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_tokenize")]
+            Tokenize(_) => "tokenize",
+            #[cfg(feature = "string_tokenize")]
+            NGrams(_) => "ngrams",
+            #[cfg(feature = "string_tokenize")]
+            StripAccents => "strip_accents",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -269,6 +320,8 @@ impl Display for StringFunction {
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => "to_decimal",
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { .. } => "to_duration",
             Uppercase => "uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
@@ -341,6 +394,18 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { normalized } => map_as_slice!(strings::levenshtein, normalized),
+            #[cfg(feature = "string_similarity")]
+            Hamming { normalized } => map_as_slice!(strings::hamming, normalized),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
+            #[cfg(feature = "string_tokenize")]
+            Tokenize(tokenizer) => map!(strings::tokenize, &tokenizer),
+            #[cfg(feature = "string_tokenize")]
+            NGrams(n) => map!(strings::ngrams, n),
+            #[cfg(feature = "string_tokenize")]
+            StripAccents => map!(strings::strip_accents),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -365,6 +430,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Base64Decode(strict) => map!(strings::base64_decode, strict),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(infer_len) => map!(strings::to_decimal, infer_len),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, strict } => map!(strings::to_duration, time_unit, strict),
             #[cfg(feature = "extract_jsonpath")]
             JsonDecode {
                 dtype,
@@ -530,6 +597,35 @@ pub(super) fn strip_suffix(s: &[Series]) -> PolarsResult<Series> {
     Ok(ca.strip_suffix(suffix).into_series())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Series], normalized: bool) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    if normalized {
+        Ok(polars_ops::chunked_array::strings::levenshtein_normalized(ca, other).into_series())
+    } else {
+        Ok(polars_ops::chunked_array::strings::levenshtein(ca, other).into_series())
+    }
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming(s: &[Series], normalized: bool) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    if normalized {
+        Ok(polars_ops::chunked_array::strings::hamming_normalized(ca, other).into_series())
+    } else {
+        Ok(polars_ops::chunked_array::strings::hamming(ca, other).into_series())
+    }
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(polars_ops::chunked_array::strings::jaro_winkler(ca, other).into_series())
+}
+
 pub(super) fn extract_all(args: &[Series]) -> PolarsResult<Series> {
     let s = &args[0];
     let pat = &args[1];
@@ -703,6 +799,17 @@ fn to_time(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
     Ok(out.into_series())
 }
 
+#[cfg(feature = "dtype-duration")]
+pub(super) fn to_duration(s: &Series, time_unit: TimeUnit, strict: bool) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    let out = ca.as_duration(time_unit)?.into_series();
+
+    if strict && ca.null_count() != out.null_count() {
+        handle_casting_failures(s, &out)?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "concat_str")]
 pub(super) fn join(s: &Series, delimiter: &str, ignore_nulls: bool) -> PolarsResult<Series> {
     let str_s = s.cast(&DataType::String)?;
@@ -761,6 +868,13 @@ fn is_literal_pat(pat: &str) -> bool {
     pat.chars().all(|c| !c.is_ascii_punctuation())
 }
 
+/// Whether the replacement values may reference capture groups, e.g. `$1` or `${name}`.
+#[cfg(feature = "regex")]
+fn has_group_refs(val: &StringChunked) -> bool {
+    val.downcast_iter()
+        .any(|arr| arr.values_iter().any(|v| v.contains('$')))
+}
+
 #[cfg(feature = "regex")]
 fn replace_n<'a>(
     ca: &'a StringChunked,
@@ -775,22 +889,21 @@ fn replace_n<'a>(
             let val = val.get(0).ok_or_else(
                 || polars_err!(ComputeError: "value cannot be 'null' in 'replace' expression"),
             )?;
-            let literal = literal || is_literal_pat(pat);
+            let literal = literal || (is_literal_pat(pat) && !val.contains('$'));
 
             match literal {
                 true => ca.replace_literal(pat, val, n),
                 false => {
-                    if n > 1 {
-                        polars_bail!(ComputeError: "regex replacement with 'n > 1' not yet supported")
+                    if n == 1 {
+                        ca.replace(pat, val)
+                    } else {
+                        let reg = Regex::new(pat)?;
+                        Ok(ca.apply_values(|s| reg.replacen(s, n, val)))
                     }
-                    ca.replace(pat, val)
                 },
             }
         },
         (1, len_val) => {
-            if n > 1 {
-                polars_bail!(ComputeError: "multivalue replacement with 'n > 1' not yet supported")
-            }
             let mut pat = get_pat(pat)?.to_string();
             polars_ensure!(
                 len_val == ca.len(),
@@ -798,7 +911,7 @@ fn replace_n<'a>(
                 "replacement value length ({}) does not match string column length ({})",
                 len_val, ca.len(),
             );
-            let literal = literal || is_literal_pat(&pat);
+            let literal = literal || (is_literal_pat(&pat) && !has_group_refs(val));
 
             if literal {
                 pat = escape(&pat)
@@ -808,10 +921,14 @@ fn replace_n<'a>(
             let lit = pat.chars().all(|c| !c.is_ascii_punctuation());
 
             let f = |s: &'a str, val: &'a str| {
-                if lit && (s.len() <= 32) {
-                    Cow::Owned(s.replacen(&pat, val, 1))
+                if literal {
+                    if lit && (s.len() <= 32) {
+                        Cow::Owned(s.replacen(&pat, val, n))
+                    } else {
+                        reg.replacen(s, n, NoExpand(val))
+                    }
                 } else {
-                    reg.replace(s, val)
+                    reg.replacen(s, n, val)
                 }
             };
             Ok(iter_and_replace(ca, val, f))
@@ -835,7 +952,7 @@ fn replace_all<'a>(
             let val = val.get(0).ok_or_else(
                 || polars_err!(ComputeError: "value cannot be 'null' in 'replace' expression"),
             )?;
-            let literal = literal || is_literal_pat(pat);
+            let literal = literal || (is_literal_pat(pat) && !val.contains('$'));
 
             match literal {
                 true => ca.replace_literal_all(pat, val),
@@ -850,7 +967,7 @@ fn replace_all<'a>(
                 "replacement value length ({}) does not match string column length ({})",
                 len_val, ca.len(),
             );
-            let literal = literal || is_literal_pat(&pat);
+            let literal = literal || (is_literal_pat(&pat) && !has_group_refs(val));
 
             if literal {
                 pat = escape(&pat)
@@ -858,7 +975,13 @@ fn replace_all<'a>(
 
             let reg = Regex::new(&pat)?;
 
-            let f = |s: &'a str, val: &'a str| reg.replace_all(s, val);
+            let f = |s: &'a str, val: &'a str| {
+                if literal {
+                    reg.replace_all(s, NoExpand(val))
+                } else {
+                    reg.replace_all(s, val)
+                }
+            };
             Ok(iter_and_replace(ca, val, f))
         },
         _ => polars_bail!(
@@ -880,6 +1003,8 @@ pub(super) fn replace(s: &[Series], literal: bool, n: i64) -> PolarsResult<Serie
 
     if all {
         replace_all(column, pat, val, literal)
+    } else if n == 0 {
+        Ok(column.clone())
     } else {
         replace_n(column, pat, val, literal, n as usize)
     }
@@ -892,6 +1017,24 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     Ok(ca.str_reverse().into_series())
 }
 
+#[cfg(feature = "string_tokenize")]
+pub(super) fn tokenize(s: &Series, tokenizer: &Tokenizer) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::tokenize(ca, tokenizer)?.into_series())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn ngrams(s: &Series, n: usize) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::ngrams(ca, n)?.into_series())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn strip_accents(s: &Series) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::strip_accents(ca).into_series())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(s: &[Series], strict: bool) -> PolarsResult<Series> {
     let ca = s[0].str()?;
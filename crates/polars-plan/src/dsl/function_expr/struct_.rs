@@ -14,6 +14,7 @@ pub enum StructFunction {
     #[cfg(feature = "json")]
     JsonEncode,
     WithFields,
+    MapFields,
     MultipleFields(Arc<[ColumnName]>),
 }
 
@@ -119,6 +120,25 @@ impl StructFunction {
                     polars_bail!(op = "with_fields", got = dt, expected = "Struct")
                 }
             },
+            MapFields => {
+                let args = mapper.args();
+                let struct_ = &args[0];
+
+                if let DataType::Struct(_) = struct_.data_type() {
+                    let dtype = DataType::Struct(
+                        args[1..]
+                            .iter()
+                            .map(|arg| Field::new(arg.name(), arg.data_type().clone()))
+                            .collect(),
+                    );
+                    let mut out = struct_.clone();
+                    out.coerce(dtype);
+                    Ok(out)
+                } else {
+                    let dt = struct_.data_type();
+                    polars_bail!(op = "map_fields", got = dt, expected = "Struct")
+                }
+            },
             MultipleFields(_) => panic!("should be expanded"),
         }
     }
@@ -136,6 +156,7 @@ impl Display for StructFunction {
             #[cfg(feature = "json")]
             JsonEncode => write!(f, "struct.to_json"),
             WithFields => write!(f, "with_fields"),
+            MapFields => write!(f, "map_fields"),
             MultipleFields(_) => write!(f, "multiple_fields"),
         }
     }
@@ -153,6 +174,7 @@ impl From<StructFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "json")]
             JsonEncode => map!(to_json),
             WithFields => map_as_slice!(with_fields),
+            MapFields => map_as_slice!(map_fields),
             MultipleFields(_) => unimplemented!(),
         }
     }
@@ -240,3 +262,12 @@ pub(super) fn with_fields(args: &[Series]) -> PolarsResult<Series> {
     let new_fields = fields.into_values().cloned().collect::<Vec<_>>();
     StructChunked::new(ca.name(), &new_fields).map(|ca| ca.into_series())
 }
+
+pub(super) fn map_fields(args: &[Series]) -> PolarsResult<Series> {
+    let ca = args[0].struct_()?;
+    polars_ensure!(
+        args.len() > 1,
+        InvalidOperation: "`map_fields` on a struct without fields"
+    );
+    StructChunked::new(ca.name(), &args[1..]).map(|ca| ca.into_series())
+}
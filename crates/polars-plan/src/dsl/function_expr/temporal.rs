@@ -39,8 +39,8 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ConvertTimeZone(tz) => map!(datetime::convert_time_zone, &tz),
             WithTimeUnit(tu) => map!(datetime::with_time_unit, tu),
             CastTimeUnit(tu) => map!(datetime::cast_time_unit, tu),
-            Truncate => {
-                map_as_slice!(datetime::truncate)
+            Truncate(ambiguous, non_existent) => {
+                map_as_slice!(datetime::truncate, ambiguous, non_existent)
             },
             #[cfg(feature = "offset_by")]
             OffsetBy => {
@@ -66,6 +66,14 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             } => {
                 map_as_slice!(temporal::datetime, &time_unit, time_zone.as_deref())
             },
+            #[cfg(feature = "dtype-duration")]
+            DurationMul { time_unit, strict } => {
+                map_as_slice!(temporal::duration_mul, time_unit, strict)
+            },
+            #[cfg(feature = "dtype-duration")]
+            DurationDiv { time_unit, strict } => {
+                map_as_slice!(temporal::duration_div, time_unit, strict)
+            },
         }
     }
 }
@@ -211,3 +219,21 @@ pub(super) fn combine(s: &[Series], tu: TimeUnit) -> PolarsResult<Series> {
         _ => result_naive,
     }
 }
+
+#[cfg(feature = "dtype-duration")]
+pub(super) fn duration_mul(
+    s: &[Series],
+    time_unit: Option<TimeUnit>,
+    strict: bool,
+) -> PolarsResult<Series> {
+    polars_time::duration_mul(&s[0], &s[1], time_unit, strict)
+}
+
+#[cfg(feature = "dtype-duration")]
+pub(super) fn duration_div(
+    s: &[Series],
+    time_unit: Option<TimeUnit>,
+    strict: bool,
+) -> PolarsResult<Series> {
+    polars_time::duration_div(&s[0], &s[1], time_unit, strict)
+}
@@ -0,0 +1,23 @@
+use super::*;
+
+/// Count the distinct combinations of values of `exprs`, optionally only considering the rows
+/// where `predicate` is true.
+///
+/// Unlike `as_struct(exprs).n_unique()`, this doesn't construct the struct column.
+pub fn n_unique_rows<E: AsRef<[Expr]>>(exprs: E, predicate: Option<Expr>) -> PolarsResult<Expr> {
+    let mut input = exprs.as_ref().to_vec();
+    polars_ensure!(!input.is_empty(), ComputeError: "`n_unique_rows` needs at least one expression");
+    let filtered = predicate.is_some();
+    input.extend(predicate);
+
+    Ok(Expr::Function {
+        input,
+        function: FunctionExpr::NUniqueRows { filtered },
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            input_wildcard_expansion: true,
+            returns_scalar: true,
+            ..Default::default()
+        },
+    })
+}
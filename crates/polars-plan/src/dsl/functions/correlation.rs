@@ -127,3 +127,28 @@ pub fn rolling_cov(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
 
     (mean_x_y - mean_x * mean_y) * (count_x_y.clone() / (count_x_y - lit(ddof)))
 }
+
+/// Compute the rolling covariance between two columns, with windows determined by `by`.
+///
+/// The delta degrees of freedom can be set by passing `RollingVarParams` as the `fn_params` of
+/// `options`.
+#[cfg(feature = "rolling_window_by")]
+pub fn rolling_cov_by(x: Expr, y: Expr, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+    x.apply_many_private(
+        FunctionExpr::RollingExprBy(RollingFunctionBy::CovBy(options)),
+        &[y, by],
+        false,
+        false,
+    )
+}
+
+/// Compute the rolling pearson correlation between two columns, with windows determined by `by`.
+#[cfg(feature = "rolling_window_by")]
+pub fn rolling_corr_by(x: Expr, y: Expr, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+    x.apply_many_private(
+        FunctionExpr::RollingExprBy(RollingFunctionBy::CorrBy(options)),
+        &[y, by],
+        false,
+        false,
+    )
+}
@@ -1,6 +1,7 @@
 //! # Functions
 //!
 //! Functions on expressions that might be useful.
+mod aggregation;
 mod arity;
 #[cfg(feature = "business")]
 mod business;
@@ -12,6 +13,8 @@ mod correlation;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
+#[cfg(feature = "polars-ml")]
+mod onnx;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
@@ -19,6 +22,7 @@ mod selectors;
 mod syntactic_sugar;
 mod temporal;
 
+pub use aggregation::*;
 pub use arity::*;
 #[cfg(all(feature = "business", feature = "dtype-date"))]
 pub use business::*;
@@ -30,6 +34,8 @@ pub use correlation::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
+#[cfg(feature = "polars-ml")]
+pub use onnx::*;
 #[cfg(feature = "dtype-struct")]
 use polars_core::utils::get_supertype;
 #[cfg(all(feature = "range", feature = "temporal"))]
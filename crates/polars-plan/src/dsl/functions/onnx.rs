@@ -0,0 +1,20 @@
+use super::*;
+
+/// Run an ONNX model over the feature columns `features` and return the model outputs selected in
+/// `options` as a struct.
+///
+/// The features are cast to `Float32` and passed to the model as an `[n_rows, n_features]`
+/// tensor, `options.batch_size` rows at a time. As the rows are independent, this can run in the
+/// streaming engine. Loaded models are cached by path.
+pub fn onnx_predict<E: AsRef<[Expr]>>(features: E, options: OnnxOptions) -> Expr {
+    Expr::Function {
+        input: features.as_ref().to_vec(),
+        function: FunctionExpr::OnnxPredict(options),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            input_wildcard_expansion: true,
+            returns_scalar: false,
+            ..Default::default()
+        },
+    }
+}
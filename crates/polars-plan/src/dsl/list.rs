@@ -374,6 +374,23 @@ impl ListNameSpace {
         }
     }
 
+    /// Apply `op` to the elements at the same position of both list arrays.
+    ///
+    /// The lists in every row must have the same length. Arithmetic operators between two
+    /// list columns, e.g. `col("a") + col("b")`, are applied this way as well.
+    #[cfg(feature = "list_elementwise")]
+    pub fn elementwise<E: Into<Expr>>(self, other: E, op: ListBinaryOp) -> Expr {
+        Expr::Function {
+            input: vec![self.0, other.into()],
+            function: FunctionExpr::ListExpr(ListFunction::ElementWise(op)),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ElementWise,
+                cast_to_supertypes: Some(Default::default()),
+                ..Default::default()
+            },
+        }
+    }
+
     /// Return the SET UNION between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
@@ -38,6 +38,7 @@ mod statistics;
 pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
+mod udaf;
 pub mod udf;
 
 use std::fmt::Debug;
@@ -66,6 +67,7 @@ use polars_core::utils::try_get_supertype;
 pub(crate) use selector::Selector;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
+pub use udaf::{AggregateState, UdafFunction, UserDefinedAggregate};
 pub use udf::UserDefinedFunction;
 
 use crate::constants::MAP_LIST_NAME;
@@ -321,6 +323,118 @@ impl Expr {
         )
     }
 
+    /// Get the value of `self` in the row where `by` is minimal, ignoring nulls in `by`.
+    ///
+    /// In the group_by context this runs in a single pass over the groups, instead of sorting
+    /// every group as `sort_by(by).first()` would.
+    pub fn min_by<E: Into<Expr>>(self, by: E) -> Self {
+        self.apply_many_private(
+            FunctionExpr::MinMaxBy { max: false },
+            &[by.into()],
+            true,
+            false,
+        )
+    }
+
+    /// Get the value of `self` in the row where `by` is maximal, ignoring nulls in `by`.
+    ///
+    /// This is the "latest record per key" aggregation: `col("value").max_by(col("ts"))`.
+    pub fn max_by<E: Into<Expr>>(self, by: E) -> Self {
+        self.apply_many_private(
+            FunctionExpr::MinMaxBy { max: true },
+            &[by.into()],
+            true,
+            false,
+        )
+    }
+
+    /// Get the first `n` values as a list, in the order they occur.
+    ///
+    /// In the group_by context this slices the groups, so nothing is sorted and the rest of a
+    /// group isn't gathered.
+    pub fn first_n(self, n: usize) -> Self {
+        self.apply_private(FunctionExpr::FirstLastN { n, last: false })
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Get the last `n` values as a list, in the order they occur.
+    pub fn last_n(self, n: usize) -> Self {
+        self.apply_private(FunctionExpr::FirstLastN { n, last: true })
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Get the `k` most frequent values as a list, ignoring nulls, from the most to the least
+    /// frequent. Values that are equally frequent are in the order of their first occurrence.
+    #[cfg(feature = "mode")]
+    pub fn mode_k(self, k: usize) -> Self {
+        self.apply_private(FunctionExpr::ModeK { k })
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Count the unique values where `predicate` is true.
+    ///
+    /// Equivalent to `self.filter(predicate).n_unique()`, but in the group_by context it doesn't
+    /// need to materialize the filtered groups.
+    pub fn n_unique_if<E: Into<Expr>>(self, predicate: E) -> Self {
+        self.apply_many_private(
+            FunctionExpr::NUniqueRows { filtered: true },
+            &[predicate.into()],
+            true,
+            false,
+        )
+    }
+
+    /// Count the non-null values where `predicate` is true.
+    ///
+    /// Equivalent to `self.filter(predicate).count()`, but in the group_by context it doesn't
+    /// need to materialize the filtered groups.
+    pub fn count_if<E: Into<Expr>>(self, predicate: E) -> Self {
+        self.apply_many_private(FunctionExpr::CountIf, &[predicate.into()], true, false)
+    }
+
+    /// Compute the mean weighted by `weights`, ignoring rows where the value or the weight is
+    /// null.
+    pub fn weighted_mean<E: Into<Expr>>(self, weights: E) -> Self {
+        self.apply_many_private(
+            FunctionExpr::WeightedAgg(WeightedAggregation::Mean),
+            &[weights.into()],
+            true,
+            false,
+        )
+    }
+
+    /// Compute the variance weighted by `weights`, ignoring rows where the value or the weight is
+    /// null. The weights are taken as frequencies, so the divisor is `sum(weights) - ddof`.
+    pub fn weighted_var<E: Into<Expr>>(self, weights: E, ddof: u8) -> Self {
+        self.apply_many_private(
+            FunctionExpr::WeightedAgg(WeightedAggregation::Var { ddof }),
+            &[weights.into()],
+            true,
+            false,
+        )
+    }
+
+    /// Compute the quantile weighted by `weights`, ignoring rows where the value or the weight is
+    /// null. This is the smallest value for which the cumulative weight is at least `quantile` of
+    /// the total weight.
+    pub fn weighted_quantile<E: Into<Expr>>(self, weights: E, quantile: f64) -> Self {
+        self.apply_many_private(
+            FunctionExpr::WeightedAgg(WeightedAggregation::Quantile(quantile)),
+            &[weights.into()],
+            true,
+            false,
+        )
+    }
+
     /// Get the index value that has the maximum value.
     pub fn arg_max(self) -> Self {
         let options = FunctionOptions {
@@ -359,6 +473,9 @@ impl Expr {
 
     #[cfg(feature = "search_sorted")]
     /// Find indices where elements should be inserted to maintain order.
+    ///
+    /// Struct columns are searched by their fields in sequence, so multiple sort keys can be
+    /// searched by wrapping them in a struct with `as_struct`.
     pub fn search_sorted<E: Into<Expr>>(self, element: E, side: SearchSortedSide) -> Expr {
         let element = element.into();
         Expr::Function {
@@ -641,6 +758,38 @@ impl Expr {
         }
     }
 
+    /// Apply a custom aggregation to every window of a `rolling` or `group_by_dynamic` context.
+    ///
+    /// The function receives a zero-copy slice of the window and must return a `Series`
+    /// with a single value, which may be of any data type. It is the responsibility of the
+    /// caller that the schema is correct by giving the correct output_type.
+    pub fn rolling_map_agg<F>(self, function: F, output_type: GetOutput) -> Self
+    where
+        F: Fn(Series) -> PolarsResult<Series> + 'static + Send + Sync,
+    {
+        let f = move |s: &mut [Series]| {
+            let out = function(std::mem::take(&mut s[0]))?;
+            polars_ensure!(
+                out.len() == 1,
+                ComputeError: "the function passed to `rolling_map_agg` must return a single value, got {}",
+                out.len()
+            );
+            Ok(Some(out))
+        };
+
+        Expr::AnonymousFunction {
+            input: vec![self],
+            function: SpecialEq::new(Arc::new(f)),
+            output_type,
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::GroupWise,
+                returns_scalar: true,
+                fmt_str: "rolling_map_agg",
+                ..Default::default()
+            },
+        }
+    }
+
     fn apply_private(self, function_expr: FunctionExpr) -> Self {
         Expr::Function {
             input: vec![self],
@@ -1061,6 +1210,25 @@ impl Expr {
         self.apply_private(BooleanFunction::IsDuplicated.into())
     }
 
+    /// Get a mask of the duplicated values that have to be dropped to keep a single
+    /// occurrence of every value according to `keep`.
+    ///
+    /// [`UniqueKeepStrategy::None`] marks all occurrences of duplicated values, like
+    /// [`Expr::is_duplicated`]. [`UniqueKeepStrategy::Any`] keeps the first occurrence.
+    #[allow(clippy::wrong_self_convention)]
+    #[cfg(all(
+        feature = "is_unique",
+        feature = "is_first_distinct",
+        feature = "is_last_distinct"
+    ))]
+    pub fn is_duplicated_keep(self, keep: UniqueKeepStrategy) -> Self {
+        match keep {
+            UniqueKeepStrategy::First | UniqueKeepStrategy::Any => self.is_first_distinct().not(),
+            UniqueKeepStrategy::Last => self.is_last_distinct().not(),
+            UniqueKeepStrategy::None => self.is_duplicated(),
+        }
+    }
+
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "is_between")]
     pub fn is_between<E: Into<Expr>>(self, lower: E, upper: E, closed: ClosedInterval) -> Self {
@@ -1089,6 +1257,51 @@ impl Expr {
             })
     }
 
+    /// Get the approximate value at the `quantile` using a t-digest sketch, without sorting
+    /// the data. The rank of the result is off by roughly `accuracy` at most, e.g. `0.01`.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: f64, accuracy: f64) -> Self {
+        self.apply_private(FunctionExpr::ApproxQuantile { quantile, accuracy })
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Summarize the values in a serialized t-digest sketch of dtype `Binary`.
+    ///
+    /// Sketches of different groups or files can be combined with [`Expr::tdigest_merge`]
+    /// and [`Expr::tdigest_quantile`].
+    #[cfg(feature = "approx_quantile")]
+    pub fn tdigest(self, accuracy: f64) -> Self {
+        self.apply_private(FunctionExpr::TDigest { accuracy })
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Merge a column of t-digest sketches created by [`Expr::tdigest`] into one sketch.
+    #[cfg(feature = "approx_quantile")]
+    pub fn tdigest_merge(self) -> Self {
+        self.apply_private(FunctionExpr::TDigestMerge)
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Merge a column of t-digest sketches created by [`Expr::tdigest`] and get the
+    /// approximate value at the `quantile`.
+    #[cfg(feature = "approx_quantile")]
+    pub fn tdigest_quantile(self, quantile: f64) -> Self {
+        self.apply_private(FunctionExpr::TDigestQuantile { quantile })
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
     /// "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1157,6 +1370,21 @@ impl Expr {
         }
     }
 
+    /// Check if the values of the left expression are in `column` of another frame.
+    ///
+    /// Unlike [`is_in`](Self::is_in), the other frame isn't collected into a hash set every
+    /// time the expression runs. In a filter this is a semi join with the other frame, elsewhere
+    /// a join with its distinct values. Like `is_in`, null values result in null.
+    #[allow(clippy::wrong_self_convention)]
+    #[cfg(feature = "is_in")]
+    pub fn in_frame<P: Into<DslPlan>>(self, other: P, column: &str) -> Self {
+        let other = Expr::SubPlan(
+            SpecialEq::new(Arc::new(other.into())),
+            vec![column.to_string()],
+        );
+        self.is_in(other)
+    }
+
     /// Sort this column by the ordering of another column evaluated from given expr.
     /// Can also be used in a group_by context to sort the groups.
     ///
@@ -1281,9 +1509,9 @@ impl Expr {
     }
 
     #[cfg(feature = "interpolate_by")]
-    /// Fill null values using interpolation.
-    pub fn interpolate_by(self, by: Expr) -> Expr {
-        self.apply_many_private(FunctionExpr::InterpolateBy, &[by], false, false)
+    /// Fill null values using interpolation, with the values of `by` as the position of each row.
+    pub fn interpolate_by(self, by: Expr, method: InterpolationMethod) -> Expr {
+        self.apply_many_private(FunctionExpr::InterpolateBy(method), &[by], false, false)
     }
 
     #[cfg(feature = "rolling_window")]
@@ -1507,6 +1735,29 @@ impl Expr {
         self.apply_private(FunctionExpr::Rank { options, seed })
     }
 
+    #[cfg(feature = "rank")]
+    /// Compute the relative rank `(rank - 1) / (count - 1)` of each value, where ties get the
+    /// lowest rank.
+    pub fn percent_rank(self, descending: bool) -> Expr {
+        self.apply_private(FunctionExpr::PercentRank { descending })
+    }
+
+    #[cfg(feature = "rank")]
+    /// Compute the fraction of values that are ordered before or tied with each value.
+    pub fn cume_dist(self, descending: bool) -> Expr {
+        self.apply_private(FunctionExpr::CumeDist { descending })
+    }
+
+    #[cfg(feature = "rank")]
+    /// Divide the ordered values into `buckets` groups of (nearly) equal size and return the
+    /// 1-based group number of each value.
+    pub fn ntile(self, buckets: IdxSize, descending: bool) -> Expr {
+        self.apply_private(FunctionExpr::Ntile {
+            buckets,
+            descending,
+        })
+    }
+
     #[cfg(feature = "replace")]
     /// Replace the given values with other values.
     pub fn replace<E: Into<Expr>>(self, old: E, new: E) -> Expr {
@@ -1654,6 +1905,44 @@ impl Expr {
         self.apply_many_private(FunctionExpr::PctChange, &[n], false, false)
     }
 
+    #[cfg(feature = "finance")]
+    /// Compute the per-period returns of a price series.
+    ///
+    /// If `log` is `true` the log returns are computed, otherwise the simple returns.
+    /// Nulls are not filled: a return is null if the current or previous price is null.
+    pub fn pct_returns(self, log: bool) -> Expr {
+        self.apply_private(FunctionExpr::PctReturns { log })
+    }
+
+    #[cfg(feature = "finance")]
+    /// Compute the maximum drawdown of a price series as a value in `[-1, 0]`.
+    ///
+    /// Nulls are skipped.
+    pub fn max_drawdown(self) -> Expr {
+        self.apply_private(FunctionExpr::MaxDrawdown)
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    #[cfg(feature = "finance")]
+    /// Compute the annualized Sharpe ratio of a series of per-period returns.
+    ///
+    /// `risk_free_rate` is the risk free rate per period and `periods` the number of
+    /// periods per year. Nulls are skipped; the result is null if there are fewer than
+    /// two valid values or the excess returns have zero variance.
+    pub fn sharpe(self, risk_free_rate: f64, periods: f64) -> Expr {
+        self.apply_private(FunctionExpr::Sharpe {
+            risk_free_rate,
+            periods,
+        })
+        .with_function_options(|mut options| {
+            options.returns_scalar = true;
+            options
+        })
+    }
+
     #[cfg(feature = "moment")]
     /// Compute the sample skewness of a data set.
     ///
@@ -1720,6 +2009,28 @@ impl Expr {
         )
     }
 
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving standard deviation by a time column.
+    pub fn ewm_std_by(self, times: Expr, half_life: Duration, bias: bool) -> Self {
+        self.apply_many_private(
+            FunctionExpr::EwmStdBy { half_life, bias },
+            &[times],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving variance by a time column.
+    pub fn ewm_var_by(self, times: Expr, half_life: Duration, bias: bool) -> Self {
+        self.apply_many_private(
+            FunctionExpr::EwmVarBy { half_life, bias },
+            &[times],
+            false,
+            false,
+        )
+    }
+
     #[cfg(feature = "ewma")]
     /// Calculate the exponentially-weighted moving standard deviation.
     pub fn ewm_std(self, options: EWMOptions) -> Self {
@@ -63,11 +63,14 @@ impl Expr {
     }
 
     /// Compute the histogram of a dataset.
+    ///
+    /// If no `bins` are given, the bins are chosen with `strategy`.
     #[cfg(feature = "hist")]
     pub fn hist(
         self,
         bins: Option<Expr>,
         bin_count: Option<usize>,
+        strategy: HistBinStrategy,
         include_category: bool,
         include_breakpoint: bool,
     ) -> Self {
@@ -80,6 +83,7 @@ impl Expr {
             input,
             function: FunctionExpr::Hist {
                 bin_count,
+                strategy,
                 include_category,
                 include_breakpoint,
             },
@@ -292,6 +292,14 @@ impl StringNameSpace {
         self.strptime(DataType::Time, options, lit("raise"))
     }
 
+    /// Convert a String column with ISO 8601 (e.g. `P1DT2H`) or humanized (e.g. `1d 2h 3m`)
+    /// durations into a Duration column.
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(self, time_unit: TimeUnit, strict: bool) -> Expr {
+        self.0
+            .map_private(StringFunction::ToDuration { time_unit, strict }.into())
+    }
+
     /// Convert a String column into a Decimal column.
     #[cfg(feature = "dtype-decimal")]
     pub fn to_decimal(self, infer_length: usize) -> Expr {
@@ -411,6 +419,64 @@ impl StringNameSpace {
         )
     }
 
+    #[cfg(feature = "string_similarity")]
+    /// Levenshtein distance to the strings in `other`, or the similarity between 0.0 and 1.0
+    /// if `normalized`.
+    pub fn levenshtein(self, other: Expr, normalized: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Levenshtein { normalized }),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Hamming distance to the strings in `other`, or the similarity between 0.0 and 1.0 if
+    /// `normalized`. Strings of different lengths give a null.
+    pub fn hamming(self, other: Expr, normalized: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Hamming { normalized }),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Jaro-Winkler similarity between 0.0 and 1.0 to the strings in `other`.
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::JaroWinkler),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "string_tokenize")]
+    /// Split each string into tokens. The resulting dtype is `List<String>`.
+    pub fn tokenize(self, tokenizer: Tokenizer) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Tokenize(
+                tokenizer,
+            )))
+    }
+
+    #[cfg(feature = "string_tokenize")]
+    /// The character n-grams of each string. The resulting dtype is `List<String>`.
+    pub fn ngrams(self, n: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::NGrams(n)))
+    }
+
+    #[cfg(feature = "string_tokenize")]
+    /// Remove accents and other combining marks from each string.
+    pub fn strip_accents(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::StripAccents))
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_many_private(
@@ -68,7 +68,24 @@ impl StructNameSpace {
             .map_private(FunctionExpr::StructExpr(StructFunction::JsonEncode))
     }
 
+    /// Add or overwrite fields of this struct.
+    ///
+    /// The fields of this struct can be referred to with `Expr::Field`. A wildcard field
+    /// expands to an expression per field.
     pub fn with_fields(self, fields: Vec<Expr>) -> Expr {
+        self.with_fields_impl(fields, StructFunction::WithFields)
+    }
+
+    /// Replace every field of this struct with the result of `function`.
+    ///
+    /// `function` receives an expression that refers to a field and is applied to all fields,
+    /// e.g. `map_fields(|f| f.round(2).name().prefix("rounded_"))`.
+    pub fn map_fields<F: FnOnce(Expr) -> Expr>(self, function: F) -> Expr {
+        let field = function(Expr::Field(Arc::from([ColumnName::from("*")])));
+        self.with_fields_impl(vec![field], StructFunction::MapFields)
+    }
+
+    fn with_fields_impl(self, fields: Vec<Expr>, function: StructFunction) -> Expr {
         fn materialize_field(this: &Expr, field: Expr) -> Expr {
             field.map_expr(|e| match e {
                 Expr::Field(names) => {
@@ -90,11 +107,12 @@ impl StructNameSpace {
         new_fields[0] = self.0;
         Expr::Function {
             input: new_fields,
-            function: FunctionExpr::StructExpr(StructFunction::WithFields),
+            function: FunctionExpr::StructExpr(function),
             options: FunctionOptions {
                 collect_groups: ApplyOptions::ElementWise,
                 pass_name_to_apply: true,
                 allow_group_aware: false,
+                input_wildcard_expansion: true,
                 ..Default::default()
             },
         }
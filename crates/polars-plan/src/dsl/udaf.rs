@@ -0,0 +1,98 @@
+//! User-defined aggregate functions (UDAF).
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+
+use super::{Expr, GetOutput, SeriesUdf, SpecialEq};
+use crate::prelude::{ApplyOptions, FunctionOptions};
+
+/// The running state of a [`UserDefinedAggregate`] for a single group.
+///
+/// A state is updated with the values of its group in batches. The streaming engine aggregates
+/// into a state per thread, and merges those states before finalizing them.
+pub trait AggregateState: Send + Sync {
+    /// Add `values` to the state.
+    fn update(&mut self, values: &Series) -> PolarsResult<()>;
+
+    /// Add the values that were added to `other`, a state of the same aggregate, to this state.
+    fn merge(&mut self, other: &dyn AggregateState) -> PolarsResult<()>;
+
+    /// The result of the aggregation, of the dtype returned by
+    /// [`UserDefinedAggregate::output_type`].
+    fn finalize(&self) -> PolarsResult<AnyValue<'static>>;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// An aggregation of which the state is defined by the user, see [`Expr::udaf`].
+pub trait UserDefinedAggregate: Send + Sync {
+    /// The dtype of the result, given the field of the input.
+    fn output_type(&self, input: &Field) -> PolarsResult<DataType>;
+
+    /// The state of a group without any values.
+    fn init(&self) -> Box<dyn AggregateState>;
+}
+
+/// The function of an expression created by [`Expr::udaf`].
+///
+/// Outside the streaming engine it aggregates every group in one go. The streaming engine
+/// drives the states of the [`UserDefinedAggregate`] itself.
+#[derive(Clone)]
+pub struct UdafFunction(pub Arc<dyn UserDefinedAggregate>);
+
+impl UdafFunction {
+    /// The aggregate of `function`, if it is the function of an expression created by
+    /// [`Expr::udaf`].
+    pub fn get_aggregate(
+        function: &dyn SeriesUdf,
+        options: &FunctionOptions,
+    ) -> Option<Arc<dyn UserDefinedAggregate>> {
+        // Other functions may not implement `as_any`.
+        if options.fmt_str != "udaf" {
+            return None;
+        }
+        function
+            .as_any()
+            .downcast_ref::<Self>()
+            .map(|udaf| udaf.0.clone())
+    }
+}
+
+impl SeriesUdf for UdafFunction {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn call_udf(&self, s: &mut [Series]) -> PolarsResult<Option<Series>> {
+        let s = &s[0];
+        let dtype = self.0.output_type(&s.field())?;
+        let mut state = self.0.init();
+        state.update(s)?;
+        let value = state.finalize()?;
+        Series::from_any_values_and_dtype(s.name(), &[value], &dtype, true).map(Some)
+    }
+}
+
+impl Expr {
+    /// Aggregate the values of every group with a [`UserDefinedAggregate`].
+    ///
+    /// Contrary to an [`apply`](Expr::apply) the groups are not collected, and the aggregation
+    /// can run in the streaming engine.
+    pub fn udaf(self, udaf: Arc<dyn UserDefinedAggregate>) -> Self {
+        let output = udaf.clone();
+        Expr::AnonymousFunction {
+            input: vec![self],
+            function: SpecialEq::new(Arc::new(UdafFunction(udaf))),
+            output_type: GetOutput::map_field(move |field| {
+                Ok(Field::new(field.name(), output.output_type(field)?))
+            }),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::GroupWise,
+                returns_scalar: true,
+                fmt_str: "udaf",
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -1,4 +1,9 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 /// State of the allowed optimizations
 pub struct OptState {
     /// Only read columns that are used later in the query.
@@ -32,6 +37,9 @@ pub struct OptState {
     /// Try to estimate the number of rows so that joins can determine which side to keep in memory.
     pub row_estimate: bool,
     pub new_streaming: bool,
+    /// Skip optimizations and parallelism that only pay off on large data if the sources of the
+    /// query are small. This cuts the fixed overhead of queries on tiny frames.
+    pub low_latency: bool,
 }
 
 impl Default for OptState {
@@ -54,6 +62,7 @@ impl Default for OptState {
             eager: false,
             row_estimate: true,
             new_streaming: false,
+            low_latency: false,
         }
     }
 }
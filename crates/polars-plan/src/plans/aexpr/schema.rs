@@ -150,6 +150,14 @@ impl AExpr {
                         let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Milliseconds, None)),
+                            #[cfg(feature = "dtype-array")]
+                            Array(ref inner, width) if inner.is_numeric() => {
+                                let inner = match **inner {
+                                    Float32 => Float32,
+                                    _ => Float64,
+                                };
+                                field.coerce(Array(Box::new(inner), width))
+                            },
                             _ => float_type(&mut field),
                         }
                         Ok(field)
@@ -159,6 +167,14 @@ impl AExpr {
                         let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Milliseconds, None)),
+                            #[cfg(feature = "dtype-array")]
+                            Array(ref inner, width) if inner.is_numeric() => {
+                                let inner = match **inner {
+                                    Float32 => Float32,
+                                    _ => Float64,
+                                };
+                                field.coerce(Array(Box::new(inner), width))
+                            },
                             _ => float_type(&mut field),
                         }
                         Ok(field)
@@ -171,13 +187,21 @@ impl AExpr {
                     Std(expr, _) => {
                         *nested = nested.saturating_sub(1);
                         let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
-                        float_type(&mut field);
+                        if field.dtype.is_decimal() {
+                            field.coerce(Float64)
+                        } else {
+                            float_type(&mut field)
+                        }
                         Ok(field)
                     },
                     Var(expr, _) => {
                         *nested = nested.saturating_sub(1);
                         let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
-                        float_type(&mut field);
+                        if field.dtype.is_decimal() {
+                            field.coerce(Float64)
+                        } else {
+                            float_type(&mut field)
+                        }
                         Ok(field)
                     },
                     NUnique(expr) => {
@@ -321,6 +345,10 @@ fn get_arithmetic_field(
                 (Struct(_), Struct(_)) => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-array")]
+                (Array(..), _) | (_, Array(..)) => {
+                    return get_array_arithmetic_field(left_field, &right_type);
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -350,6 +378,10 @@ fn get_arithmetic_field(
         Operator::Plus => {
             let right_type = right_ae.to_field_impl(schema, arena, nested)?.dtype;
             match (&left_field.dtype, &right_type) {
+                #[cfg(feature = "dtype-array")]
+                (Array(..), _) | (_, Array(..)) => {
+                    return get_array_arithmetic_field(left_field, &right_type);
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -380,6 +412,10 @@ fn get_arithmetic_field(
                 (Struct(_), Struct(_)) => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-array")]
+                (Array(..), _) | (_, Array(..)) => {
+                    return get_array_arithmetic_field(left_field, &right_type);
+                },
                 (Datetime(_, _), _)
                 | (_, Datetime(_, _))
                 | (Time, _)
@@ -428,10 +464,49 @@ fn get_arithmetic_field(
         },
     };
 
+    // Both sides are cast to the supertype, but the precision and scale of the result depend
+    // on the operation.
+    #[cfg(feature = "dtype-decimal")]
+    let super_type = match (op, super_type) {
+        (
+            Operator::Plus | Operator::Minus | Operator::Multiply | Operator::Divide,
+            Decimal(precision, Some(scale)),
+        ) => decimal_arithmetic_dtype(op, (precision, scale)),
+        (_, dt) => dt,
+    };
+
     left_field.coerce(super_type);
     Ok(left_field)
 }
 
+/// The dtype of `op` applied to two decimals of the given precision and scale.
+#[cfg(feature = "dtype-decimal")]
+fn decimal_arithmetic_dtype(op: Operator, dtype: (Option<usize>, usize)) -> DataType {
+    use polars_core::chunked_array::arithmetic::decimal;
+    let (precision, scale) = match op {
+        Operator::Multiply => decimal::mul_dtype(dtype, dtype),
+        Operator::Divide | Operator::TrueDivide => decimal::div_dtype(dtype, dtype),
+        _ => decimal::add_dtype(dtype, dtype),
+    };
+    DataType::Decimal(precision, Some(scale))
+}
+
+#[cfg(feature = "dtype-array")]
+fn get_array_arithmetic_field(mut left_field: Field, right_type: &DataType) -> PolarsResult<Field> {
+    let array_type = match left_field.data_type() {
+        dt @ DataType::Array(..) => dt,
+        _ => right_type,
+    };
+    let leaf_type = match try_get_supertype(left_field.dtype.leaf_dtype(), right_type.leaf_dtype())?
+    {
+        DataType::Unknown(kind) => kind.materialize().unwrap_or(DataType::Unknown(kind)),
+        dt => dt,
+    };
+    let out_type = array_type.cast_leaf(leaf_type);
+    left_field.coerce(out_type);
+    Ok(left_field)
+}
+
 fn get_truediv_field(
     left: Node,
     right: Node,
@@ -441,9 +516,27 @@ fn get_truediv_field(
 ) -> PolarsResult<Field> {
     let mut left_field = arena.get(left).to_field_impl(schema, arena, nested)?;
     use DataType::*;
+    #[cfg(feature = "dtype-array")]
+    if left_field.dtype.is_numeric() {
+        let right_type = arena.get(right).to_field_impl(schema, arena, nested)?.dtype;
+        if let Array(..) = right_type {
+            left_field.coerce(right_type.cast_leaf(Float64));
+            return Ok(left_field);
+        }
+    }
     let out_type = match left_field.data_type() {
         Float32 => Float32,
         dt if dt.is_numeric() => Float64,
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => {
+            let right_type = arena.get(right).to_field_impl(schema, arena, nested)?.dtype;
+            match try_get_supertype(left_field.data_type(), &right_type)? {
+                Decimal(precision, Some(scale)) => {
+                    decimal_arithmetic_dtype(Operator::TrueDivide, (precision, scale))
+                },
+                dt => dt,
+            }
+        },
         #[cfg(feature = "dtype-duration")]
         Duration(_) => match arena
             .get(right)
@@ -464,6 +557,9 @@ fn get_truediv_field(
         Time => polars_bail!(InvalidOperation: "division of 'Time' datatype is not allowed"),
         #[cfg(feature = "dtype-date")]
         Date => polars_bail!(InvalidOperation: "division of 'Date' datatype is not allowed"),
+        dt @ List(_) if dt.leaf_dtype().is_integer() => dt.cast_leaf(Float64),
+        #[cfg(feature = "dtype-array")]
+        dt @ Array(..) => dt.cast_leaf(Float64),
         // we don't know what to do here, best return the dtype
         dt => dt.clone(),
     };
@@ -14,6 +14,9 @@ pub struct AnonymousScanArgs {
     pub predicate: Option<Expr>,
 }
 
+/// The batches of an [`AnonymousScan`] that runs in the streaming engine.
+pub type AnonymousScanBatches = Box<dyn Iterator<Item = PolarsResult<DataFrame>> + Send>;
+
 pub trait AnonymousScan: Send + Sync {
     fn as_any(&self) -> &dyn Any;
     /// Creates a DataFrame from the supplied function & scan options.
@@ -42,6 +45,20 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// specify if the scan provider can produce its data in batches with
+    /// [`scan_batches`](AnonymousScan::scan_batches), so that it can run in the streaming engine
+    ///
+    /// Defaults to `false`
+    fn allows_streaming(&self) -> bool {
+        false
+    }
+    /// Creates an iterator over the batches of the scan, from the supplied scan options.
+    ///
+    /// Every batch must have the `output_schema` of the options if it is set, and the `schema`
+    /// otherwise. The same pushdowns are applied as for [`scan`](AnonymousScan::scan).
+    fn scan_batches(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<AnonymousScanBatches> {
+        polars_bail!(ComputeError: "anonymous scan does not support streaming");
+    }
 }
 
 impl<F> AnonymousScan for F
@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::fmt::{Debug, Formatter};
 
 use polars_core::prelude::*;
@@ -28,11 +29,25 @@ where
     }
 }
 
+/// A user-defined node that the streaming engine runs as a stateful operator.
+///
+/// The plan doesn't know the streaming engine's operator types, so the engine downcasts
+/// [`StreamingUdf::as_any`] to the node type it created. [`DataFrameUdf::call_udf`] is
+/// used when the query runs on the in-memory engine.
+pub trait StreamingUdf: DataFrameUdf {
+    fn as_any(&self) -> &dyn Any;
+}
+
 impl Debug for dyn DataFrameUdf {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "dyn DataFrameUdf")
     }
 }
+impl Debug for dyn StreamingUdf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "dyn StreamingUdf")
+    }
+}
 impl Debug for dyn DataFrameUdfMut {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "dyn DataFrameUdfMut")
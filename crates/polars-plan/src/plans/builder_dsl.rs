@@ -51,6 +51,7 @@ impl DslBuilder {
                 enabled: Some(false),
                 ..Default::default()
             },
+            schema_evolution: Default::default(),
         };
 
         Ok(DslPlan::Scan {
@@ -83,6 +84,9 @@ impl DslBuilder {
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
         hive_options: HiveOptions,
+        deduplicate_strings: bool,
+        schema_evolution: SchemaEvolution,
+        statistics: Option<Arc<polars_io::parquet::DatasetStatistics>>,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -94,6 +98,7 @@ impl DslBuilder {
             row_index,
             file_counter: Default::default(),
             hive_options,
+            schema_evolution,
         };
         Ok(DslPlan::Scan {
             paths,
@@ -106,15 +111,18 @@ impl DslBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    deduplicate_strings,
                 },
                 cloud_options,
                 metadata: None,
+                statistics,
             },
         }
         .into())
     }
 
     #[cfg(feature = "ipc")]
+    #[allow(clippy::too_many_arguments)]
     pub fn scan_ipc<P: Into<Arc<[std::path::PathBuf]>>>(
         paths: P,
         options: IpcScanOptions,
@@ -123,6 +131,7 @@ impl DslBuilder {
         row_index: Option<RowIndex>,
         rechunk: bool,
         cloud_options: Option<CloudOptions>,
+        schema_evolution: SchemaEvolution,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -142,6 +151,7 @@ impl DslBuilder {
                     enabled: Some(false),
                     ..Default::default()
                 },
+                schema_evolution,
             },
             predicate: None,
             scan_type: FileScan::Ipc {
@@ -160,6 +170,7 @@ impl DslBuilder {
         read_options: CsvReadOptions,
         cache: bool,
         cloud_options: Option<CloudOptions>,
+        schema_evolution: SchemaEvolution,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -178,6 +189,7 @@ impl DslBuilder {
                 enabled: Some(false),
                 ..Default::default()
             },
+            schema_evolution,
         };
         Ok(DslPlan::Scan {
             paths,
@@ -331,10 +343,10 @@ impl DslBuilder {
         .into()
     }
 
-    pub fn explode(self, columns: Vec<Expr>) -> Self {
+    pub fn explode(self, columns: Vec<Expr>, options: ExplodeOptions) -> Self {
         DslPlan::MapFunction {
             input: Arc::new(self.0),
-            function: DslFunction::Explode { columns },
+            function: DslFunction::Explode { columns, options },
         }
         .into()
     }
@@ -446,4 +458,21 @@ impl DslBuilder {
         }
         .into()
     }
+
+    pub fn map_streaming(
+        self,
+        function: Arc<dyn StreamingUdf>,
+        schema: Option<Arc<dyn UdfSchema>>,
+        name: &'static str,
+    ) -> Self {
+        DslPlan::MapFunction {
+            input: Arc::new(self.0),
+            function: DslFunction::FunctionNode(FunctionNode::OpaqueStreaming {
+                function,
+                schema,
+                fmt_str: name,
+            }),
+        }
+        .into()
+    }
 }
@@ -197,11 +197,12 @@ impl<'a> IRBuilder<'a> {
     }
 
     // call this if the schema needs to be updated
-    pub(crate) fn explode(self, columns: Arc<[Arc<str>]>) -> Self {
+    pub(crate) fn explode(self, columns: Arc<[Arc<str>]>, options: ExplodeOptions) -> Self {
         let lp = IR::MapFunction {
             input: self.root,
             function: FunctionNode::Explode {
                 columns,
+                options,
                 schema: Default::default(),
             },
         };
@@ -225,6 +226,10 @@ impl<'a> IRBuilder<'a> {
             if let Some(options) = options.rolling.as_ref() {
                 let name = &options.index_column;
                 let dtype = current_schema.get(name).unwrap();
+                if options.include_boundaries {
+                    schema.with_column("_lower_boundary".into(), dtype.clone());
+                    schema.with_column("_upper_boundary".into(), dtype.clone());
+                }
                 schema.with_column(name.clone(), dtype.clone());
             } else if let Some(options) = options.dynamic.as_ref() {
                 let name = &options.index_column;
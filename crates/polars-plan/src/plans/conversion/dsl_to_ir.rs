@@ -3,6 +3,9 @@ use either::Either;
 use expr_expansion::{is_regex_projection, rewrite_projections};
 use hive::{hive_partitions_from_paths, HivePartitions};
 
+#[cfg(feature = "is_in")]
+use super::in_frame::*;
+use super::schema_evolution::evolve_scan;
 use super::stack_opt::ConversionOptimizer;
 use super::*;
 
@@ -82,6 +85,27 @@ pub fn to_alp_impl(
     }
 
     let v = match lp {
+        DslPlan::Scan {
+            paths,
+            hive_parts,
+            predicate,
+            scan_type,
+            file_options,
+            ..
+        } if paths.len() > 1 && file_options.schema_evolution != SchemaEvolution::Strict => {
+            let lp = evolve_scan(
+                paths,
+                hive_parts,
+                predicate,
+                scan_type,
+                file_options,
+                expr_arena,
+                lp_arena,
+                convert,
+            )
+            .map_err(|e| e.context(failed_here!(scan)))?;
+            return to_alp_impl(lp, expr_arena, lp_arena, convert);
+        },
         DslPlan::Scan {
             file_info,
             hive_parts,
@@ -98,12 +122,22 @@ pub fn to_alp_impl(
                     FileScan::Parquet {
                         cloud_options,
                         metadata,
+                        statistics,
                         ..
                     } => {
-                        let (file_info, md) =
+                        let (mut file_info, md) =
                             scans::parquet_file_info(&paths, &file_options, cloud_options.as_ref())
                                 .map_err(|e| e.context(failed_here!(parquet scan)))?;
                         *metadata = md;
+                        // The statistics of the dataset know the number of rows of every file.
+                        if let Some(n_rows) = statistics.as_ref().and_then(|statistics| {
+                            paths
+                                .iter()
+                                .map(|path| statistics.num_rows(path))
+                                .sum::<Option<usize>>()
+                        }) {
+                            file_info.row_estimation = (Some(n_rows), n_rows);
+                        }
                         file_info
                     },
                     #[cfg(feature = "ipc")]
@@ -230,6 +264,12 @@ pub fn to_alp_impl(
                 options,
             }
         },
+        #[cfg(feature = "is_in")]
+        DslPlan::Filter { input, predicate } if has_in_frame(&predicate) => {
+            let lp = filter_in_frame(input, predicate, expr_arena, lp_arena, convert)
+                .map_err(|e| e.context(failed_here!(filter)))?;
+            return to_alp_impl(lp, expr_arena, lp_arena, convert);
+        },
         DslPlan::Filter { input, predicate } => {
             let mut input = to_alp_impl(owned(input), expr_arena, lp_arena, convert)
                 .map_err(|e| e.context(failed_input!(filter)))?;
@@ -294,6 +334,16 @@ pub fn to_alp_impl(
             output_schema,
             filter: selection.map(|expr| to_expr_ir(expr, expr_arena)),
         },
+        #[cfg(feature = "is_in")]
+        DslPlan::Select {
+            expr,
+            input,
+            options,
+        } if expr.iter().any(has_in_frame) => {
+            let lp = select_in_frame(input, expr, options, expr_arena, lp_arena, convert)
+                .map_err(|e| e.context(failed_here!(select)))?;
+            return to_alp_impl(lp, expr_arena, lp_arena, convert);
+        },
         DslPlan::Select {
             expr,
             input,
@@ -465,6 +515,16 @@ pub fn to_alp_impl(
             };
             return run_conversion(lp, lp_arena, expr_arena, convert, "join");
         },
+        #[cfg(feature = "is_in")]
+        DslPlan::HStack {
+            input,
+            exprs,
+            options,
+        } if exprs.iter().any(has_in_frame) => {
+            let lp = with_columns_in_frame(input, exprs, options, expr_arena, lp_arena, convert)
+                .map_err(|e| e.context(failed_here!(with_columns)))?;
+            return to_alp_impl(lp, expr_arena, lp_arena, convert);
+        },
         DslPlan::HStack {
             input,
             exprs,
@@ -658,7 +718,11 @@ pub fn to_alp_impl(
     Ok(lp_arena.add(v))
 }
 
-fn expand_filter(predicate: Expr, input: Node, lp_arena: &Arena<IR>) -> PolarsResult<Expr> {
+pub(super) fn expand_filter(
+    predicate: Expr,
+    input: Node,
+    lp_arena: &Arena<IR>,
+) -> PolarsResult<Expr> {
     let schema = lp_arena.get(input).schema(lp_arena);
     let predicate = if has_expr(&predicate, |e| match e {
         Expr::Column(name) => is_regex_projection(name),
@@ -774,6 +838,10 @@ fn resolve_group_by(
             let dtype = current_schema.try_get(name)?;
             keys.push(col(name));
             pop_keys = true;
+            if options.include_boundaries {
+                schema.with_column("_lower_boundary".into(), dtype.clone());
+                schema.with_column("_upper_boundary".into(), dtype.clone());
+            }
             schema.with_column(name.clone(), dtype.clone());
         } else if let Some(options) = _options.dynamic.as_ref() {
             let name = &options.index_column;
@@ -0,0 +1,287 @@
+//! Rewrites `is_in` against the column of another frame, see [`Expr::in_frame`], to joins.
+//!
+//! `is_in` collects the values it is checked against into a hash set every time it runs. If
+//! these values are the column of another, possibly large, frame, the rewrite lets a join hash
+//! them once instead:
+//! - a condition `key.is_in(other)` of a filter is a semi join with the other frame.
+//! - anywhere else in a filter, select or with_columns, the input is left joined with the
+//!   distinct values of the other frame, and `key.is_in(other)` checks if the joined value is
+//!   not null.
+//!
+//! Like `is_in`, a null key is never found but results in null.
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use polars_core::utils::try_get_supertype;
+
+use super::dsl_to_ir::expand_filter;
+use super::expr_expansion::rewrite_projections;
+use super::stack_opt::ConversionOptimizer;
+use super::*;
+
+static IN_FRAME_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// A name for the joined values of another frame that doesn't clash with the columns of the
+/// input.
+fn values_name() -> String {
+    format!(
+        "__POLARS_IN_FRAME_{}",
+        IN_FRAME_COUNT.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+pub(super) fn has_in_frame(expr: &Expr) -> bool {
+    has_expr(expr, |e| matches!(e, Expr::SubPlan(..)))
+}
+
+/// The key, the other frame and its column of `key.is_in(other)`.
+fn as_in_frame(expr: &Expr) -> Option<(&Expr, &Arc<DslPlan>, &str)> {
+    match expr {
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::IsIn),
+            ..
+        } => match input.as_slice() {
+            [key, Expr::SubPlan(other, names)] if names.len() == 1 && !has_in_frame(key) => {
+                Some((key, other, names[0].as_str()))
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+struct Ctx<'a> {
+    expr_arena: &'a mut Arena<AExpr>,
+    lp_arena: &'a mut Arena<IR>,
+    convert: &'a mut ConversionOptimizer,
+}
+
+impl Ctx<'_> {
+    /// Convert `lp` to IR, but keep it as DSL so it can be the input of the rewritten plan.
+    fn resolve(&mut self, lp: Arc<DslPlan>) -> PolarsResult<(DslPlan, SchemaRef, Node)> {
+        let node = to_alp_impl(
+            lp.as_ref().clone(),
+            self.expr_arena,
+            self.lp_arena,
+            self.convert,
+        )?;
+        let schema = self.lp_arena.get(node).schema(self.lp_arena).into_owned();
+        let lp = DslPlan::IR {
+            node: Some(node),
+            dsl: lp,
+            version: self.lp_arena.version(),
+        };
+        Ok((lp, schema, node))
+    }
+
+    /// The key to join on and the values of `column` of `other` as column `values_name`. Both
+    /// are cast to their supertype if their dtypes differ.
+    fn in_frame_values(
+        &mut self,
+        key: &Expr,
+        schema: &Schema,
+        other: &Arc<DslPlan>,
+        column: &str,
+        values_name: &str,
+    ) -> PolarsResult<(Expr, DslBuilder, DataType)> {
+        let key_dtype = key.to_field(schema, Context::Default)?.dtype;
+        let (other, other_schema, _) = self.resolve(other.clone())?;
+        let values_dtype = other_schema.try_get(column)?;
+
+        let mut key = key.clone();
+        let mut values = col(column);
+        let dtype = if &key_dtype == values_dtype {
+            key_dtype
+        } else {
+            let dtype = try_get_supertype(&key_dtype, values_dtype)?;
+            key = key.cast(dtype.clone());
+            values = values.cast(dtype.clone());
+            dtype
+        };
+        let values =
+            DslBuilder::from(other).project(vec![values.alias(values_name)], Default::default());
+        Ok((key, values, dtype))
+    }
+
+    /// Left join `lp` with the distinct values of the other frames that `exprs` check against
+    /// and replace these checks by the joined values being not null.
+    fn join_in_frame(
+        &mut self,
+        mut lp: DslPlan,
+        schema: &Schema,
+        exprs: Vec<Expr>,
+    ) -> PolarsResult<(DslPlan, Vec<Expr>)> {
+        let mut schema = schema.clone();
+        let exprs = exprs
+            .into_iter()
+            .map(|expr| {
+                let name = expr_output_name(&expr)?;
+                let expr = expr.try_map_expr(|e| {
+                    let Some((key, other, column)) = as_in_frame(&e) else {
+                        polars_ensure!(
+                            !matches!(e, Expr::SubPlan(..)),
+                            InvalidOperation: "the column of another frame can only be used in `is_in`"
+                        );
+                        return Ok(e);
+                    };
+                    let values_name = values_name();
+                    let (key, values, dtype) =
+                        self.in_frame_values(key, &schema, other, column, &values_name)?;
+                    let values = values
+                        .drop_nulls(None)
+                        .distinct(Default::default())
+                        .build();
+                    let options = JoinOptions {
+                        args: JoinArgs {
+                            coalesce: JoinCoalesce::KeepColumns,
+                            ..JoinArgs::new(JoinType::Left)
+                        },
+                        ..Default::default()
+                    };
+                    lp = DslBuilder::from(lp)
+                        .join(
+                            values,
+                            vec![key.clone()],
+                            vec![col(&values_name)],
+                            Arc::new(options),
+                        )
+                        .build();
+                    schema.with_column(values_name.as_str().into(), dtype);
+
+                    Ok(when(key.is_not_null())
+                        .then(col(&values_name).is_not_null())
+                        .otherwise(lit(NULL)))
+                })?;
+                Ok(match expr {
+                    Expr::Alias(..) => expr,
+                    expr => expr.alias(&name),
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok((lp, exprs))
+    }
+}
+
+fn split_conjunction(predicate: Expr, out: &mut Vec<Expr>) {
+    match predicate {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            split_conjunction(Arc::unwrap_or_clone(left), out);
+            split_conjunction(Arc::unwrap_or_clone(right), out);
+        },
+        predicate => out.push(predicate),
+    }
+}
+
+pub(super) fn filter_in_frame(
+    input: Arc<DslPlan>,
+    predicate: Expr,
+    expr_arena: &mut Arena<AExpr>,
+    lp_arena: &mut Arena<IR>,
+    convert: &mut ConversionOptimizer,
+) -> PolarsResult<DslPlan> {
+    let mut ctx = Ctx {
+        expr_arena,
+        lp_arena,
+        convert,
+    };
+    let (mut lp, schema, node) = ctx.resolve(input)?;
+    let predicate = expand_filter(predicate, node, ctx.lp_arena)?;
+
+    let mut conditions = vec![];
+    split_conjunction(predicate, &mut conditions);
+    let mut rest = vec![];
+    for condition in conditions {
+        #[cfg(feature = "semi_anti_join")]
+        if let Some((key, other, column)) = as_in_frame(&condition) {
+            let values_name = values_name();
+            let (key, values, _) =
+                ctx.in_frame_values(key, &schema, other, column, &values_name)?;
+            let options = JoinOptions {
+                args: JoinArgs::new(JoinType::Semi),
+                ..Default::default()
+            };
+            lp = DslBuilder::from(lp)
+                .join(
+                    values.build(),
+                    vec![key],
+                    vec![col(&values_name)],
+                    Arc::new(options),
+                )
+                .build();
+            continue;
+        }
+        rest.push(condition);
+    }
+
+    let Some(predicate) = rest.into_iter().reduce(|acc, condition| acc.and(condition)) else {
+        return Ok(lp);
+    };
+    if !has_in_frame(&predicate) {
+        return Ok(DslBuilder::from(lp).filter(predicate).build());
+    }
+    let (lp, mut predicate) = ctx.join_in_frame(lp, &schema, vec![predicate])?;
+    let columns = schema.iter_names().map(|name| col(name)).collect();
+    Ok(DslBuilder::from(lp)
+        .filter(predicate.pop().unwrap())
+        .project(columns, Default::default())
+        .build())
+}
+
+pub(super) fn select_in_frame(
+    input: Arc<DslPlan>,
+    exprs: Vec<Expr>,
+    options: ProjectionOptions,
+    expr_arena: &mut Arena<AExpr>,
+    lp_arena: &mut Arena<IR>,
+    convert: &mut ConversionOptimizer,
+) -> PolarsResult<DslPlan> {
+    let mut ctx = Ctx {
+        expr_arena,
+        lp_arena,
+        convert,
+    };
+    let (lp, schema, _) = ctx.resolve(input)?;
+    // Expand before joining, so that wildcards don't select the joined values.
+    let exprs = rewrite_projections(exprs, &schema, &[])?;
+    let (lp, exprs) = ctx.join_in_frame(lp, &schema, exprs)?;
+    Ok(DslBuilder::from(lp).project(exprs, options).build())
+}
+
+pub(super) fn with_columns_in_frame(
+    input: Arc<DslPlan>,
+    exprs: Vec<Expr>,
+    options: ProjectionOptions,
+    expr_arena: &mut Arena<AExpr>,
+    lp_arena: &mut Arena<IR>,
+    convert: &mut ConversionOptimizer,
+) -> PolarsResult<DslPlan> {
+    let mut ctx = Ctx {
+        expr_arena,
+        lp_arena,
+        convert,
+    };
+    let (lp, schema, _) = ctx.resolve(input)?;
+    let exprs = rewrite_projections(exprs, &schema, &[])?;
+
+    let mut columns = schema
+        .iter_names()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    for expr in &exprs {
+        let name = expr_output_name(expr)?;
+        if !schema.contains(&name) && !columns.iter().any(|c| c.as_str() == &*name) {
+            columns.push(name.to_string());
+        }
+    }
+
+    let (lp, exprs) = ctx.join_in_frame(lp, &schema, exprs)?;
+    let columns = columns.iter().map(|name| col(name)).collect();
+    Ok(DslBuilder::from(lp)
+        .with_columns(exprs, options)
+        .project(columns, Default::default())
+        .build())
+}
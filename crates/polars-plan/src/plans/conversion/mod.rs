@@ -2,9 +2,12 @@ mod convert_utils;
 mod dsl_to_ir;
 mod expr_expansion;
 mod expr_to_ir;
+#[cfg(feature = "is_in")]
+mod in_frame;
 mod ir_to_dsl;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 mod scans;
+mod schema_evolution;
 mod stack_opt;
 
 use std::borrow::Cow;
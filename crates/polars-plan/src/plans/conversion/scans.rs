@@ -295,8 +295,11 @@ pub(super) fn ndjson_file_info(
             )
         }
     } else {
-        let schema =
-            polars_io::ndjson::infer_schema(&mut reader, ndjson_options.infer_schema_length)?;
+        let schema = polars_io::ndjson::infer_schema_with_overrides(
+            &mut reader,
+            ndjson_options.infer_schema_length,
+            ndjson_options.dtype_overrides.as_deref(),
+        )?;
         prepare_schemas(schema, file_options.row_index.as_ref())
     };
 
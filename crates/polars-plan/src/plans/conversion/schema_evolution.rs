@@ -0,0 +1,122 @@
+//! Scans over several files of which the schema evolved over time, see [`SchemaEvolution`].
+//!
+//! Every file is scanned on its own and projected onto a common schema before the scans are
+//! concatenated. Columns are matched by name, columns that are missing from a file are filled
+//! with nulls and columns of which the dtype differs are cast.
+use std::path::PathBuf;
+
+use hive::HivePartitions;
+use polars_core::utils::try_get_supertype;
+
+use super::stack_opt::ConversionOptimizer;
+use super::*;
+
+/// The columns of all `schemas` in the order in which they first appear, with the supertype of
+/// their dtypes.
+fn unify_schemas<'a, I>(schemas: I) -> PolarsResult<Schema>
+where
+    I: IntoIterator<Item = &'a Schema>,
+{
+    let mut out = Schema::default();
+    for schema in schemas {
+        for (name, dtype) in schema.iter() {
+            match out.get(name) {
+                Some(current) if current == dtype => {},
+                Some(current) => {
+                    let supertype = try_get_supertype(current, dtype).map_err(|_| {
+                        polars_err!(
+                            SchemaMismatch: "column '{}' has dtype {} in one file and {} in another, which can't be unified",
+                            name, current, dtype
+                        )
+                    })?;
+                    out.with_column(name.clone(), supertype);
+                },
+                None => {
+                    out.with_column(name.clone(), dtype.clone());
+                },
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Rewrite a scan over several files to a union of the scans of the single files that are
+/// projected onto the schema given by `file_options.schema_evolution`.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn evolve_scan(
+    paths: Arc<[PathBuf]>,
+    hive_parts: Option<Arc<[HivePartitions]>>,
+    predicate: Option<Expr>,
+    scan_type: FileScan,
+    mut file_options: FileScanOptions,
+    expr_arena: &mut Arena<AExpr>,
+    lp_arena: &mut Arena<IR>,
+    convert: &mut ConversionOptimizer,
+) -> PolarsResult<DslPlan> {
+    let schema_evolution = std::mem::take(&mut file_options.schema_evolution);
+    // The row limit and row index apply to the concatenated files.
+    let n_rows = file_options.n_rows.take();
+    let row_index = file_options.row_index.take();
+    let rechunk = std::mem::take(&mut file_options.rechunk);
+
+    let mut files = Vec::with_capacity(paths.len());
+    for (i, path) in paths.iter().enumerate() {
+        let lp = DslPlan::Scan {
+            paths: Arc::new([path.clone()]),
+            file_info: None,
+            hive_parts: hive_parts
+                .as_ref()
+                .map(|hive_parts| Arc::from(&hive_parts[i..i + 1])),
+            predicate: predicate.clone(),
+            scan_type: scan_type.clone(),
+            file_options: file_options.clone(),
+        };
+        let node = to_alp_impl(lp.clone(), expr_arena, lp_arena, convert)
+            .map_err(|e| e.context(format!("error while reading {}", path.display()).into()))?;
+        let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+        let lp = DslPlan::IR {
+            node: Some(node),
+            dsl: Arc::new(lp),
+            version: lp_arena.version(),
+        };
+        files.push((lp, schema));
+    }
+
+    let schema = match schema_evolution {
+        SchemaEvolution::Strict => unreachable!(),
+        SchemaEvolution::Unify => Arc::new(unify_schemas(
+            files.iter().map(|(_, schema)| schema.as_ref()),
+        )?),
+        SchemaEvolution::Cast(schema) => schema,
+    };
+
+    let inputs = files
+        .into_iter()
+        .map(|(lp, file_schema)| {
+            let exprs = schema
+                .iter()
+                .map(|(name, dtype)| match file_schema.get(name) {
+                    Some(file_dtype) if file_dtype == dtype => col(name),
+                    Some(_) => col(name).strict_cast(dtype.clone()),
+                    None => lit(NULL).cast(dtype.clone()).alias(name),
+                })
+                .collect();
+            DslBuilder::from(lp)
+                .project(exprs, Default::default())
+                .build()
+        })
+        .collect();
+    let args = UnionArgs {
+        rechunk,
+        from_partitioned_ds: true,
+        ..Default::default()
+    };
+    let mut lp = DslBuilder::from(DslPlan::Union { inputs, args });
+    if let Some(n_rows) = n_rows {
+        lp = lp.slice(0, n_rows as IdxSize);
+    }
+    if let Some(row_index) = row_index {
+        lp = lp.row_index(&row_index.name, Some(row_index.offset));
+    }
+    Ok(lp.build())
+}
@@ -56,6 +56,28 @@ fn process_list_arithmetic(
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<Option<AExpr>> {
     match (&type_left, &type_right) {
+        (DataType::List(_), DataType::List(_)) => {
+            if type_left == type_right {
+                return Ok(None);
+            }
+            let Some(super_type) = get_supertype(&type_left, &type_right) else {
+                polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, type_left, type_right)
+            };
+            let mut cast = |node: Node, dtype: &DataType| {
+                if *dtype == super_type {
+                    node
+                } else {
+                    expr_arena.add(AExpr::Cast {
+                        expr: node,
+                        data_type: super_type.clone(),
+                        options: CastOptions::NonStrict,
+                    })
+                }
+            };
+            let left = cast(node_left, &type_left);
+            let right = cast(node_right, &type_right);
+            Ok(Some(AExpr::BinaryExpr { left, op, right }))
+        },
         (DataType::List(inner), _) => {
             if type_right != **inner {
                 let new_node_right = expr_arena.add(AExpr::Cast {
@@ -94,6 +116,42 @@ fn process_list_arithmetic(
     }
 }
 
+#[cfg(feature = "dtype-array")]
+// Cast both sides to the supertype of their leaf types, but keep the array shape.
+fn process_array_arithmetic(
+    type_left: DataType,
+    type_right: DataType,
+    node_left: Node,
+    node_right: Node,
+    op: Operator,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<AExpr>> {
+    let leaf_type = match get_supertype(type_left.leaf_dtype(), type_right.leaf_dtype()) {
+        Some(DataType::Unknown(kind)) => kind.materialize(),
+        leaf_type => leaf_type,
+    };
+    let Some(leaf_type) = leaf_type else {
+        polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, type_left, type_right)
+    };
+    if type_left.leaf_dtype() == &leaf_type && type_right.leaf_dtype() == &leaf_type {
+        return Ok(None);
+    }
+    let mut cast = |node: Node, dtype: &DataType| {
+        if dtype.leaf_dtype() == &leaf_type {
+            node
+        } else {
+            expr_arena.add(AExpr::Cast {
+                expr: node,
+                data_type: dtype.cast_leaf(leaf_type.clone()),
+                options: CastOptions::NonStrict,
+            })
+        }
+    };
+    let left = cast(node_left, &type_left);
+    let right = cast(node_right, &type_right);
+    Ok(Some(AExpr::BinaryExpr { left, op, right }))
+}
+
 #[cfg(feature = "dtype-struct")]
 // Ensure we don't cast to supertype
 // otherwise we will fill a struct with null fields
@@ -268,6 +326,12 @@ pub(super) fn process_binary(
                     type_left, type_right, node_left, node_right, op, expr_arena,
                 )
             },
+            #[cfg(feature = "dtype-array")]
+            (Array(..), _) | (_, Array(..)) => {
+                return process_array_arithmetic(
+                    type_left, type_right, node_left, node_right, op, expr_arena,
+                )
+            },
             (Datetime(_, _), _)
             | (_, Datetime(_, _))
             | (Date, _)
@@ -158,6 +158,21 @@ impl ExprIR {
         self.output_name_arc().as_ref()
     }
 
+    /// The field this expression outputs with the input `schema`. The multi-output expressions,
+    /// e.g. wildcards, selectors and `struct.field("*")`, are expanded into an `ExprIR` per
+    /// output, so every output has its own field.
+    pub fn field(
+        &self,
+        schema: &Schema,
+        ctxt: Context,
+        expr_arena: &Arena<AExpr>,
+    ) -> PolarsResult<Field> {
+        let field = expr_arena
+            .get(self.node)
+            .to_field(schema, ctxt, expr_arena)?;
+        Ok(Field::new(self.output_name(), field.dtype))
+    }
+
     pub fn to_expr(&self, expr_arena: &Arena<AExpr>) -> Expr {
         let out = node_to_expr(self.node, expr_arena);
 
@@ -1,4 +1,5 @@
 use std::hash::{Hash, Hasher};
+use std::path::Path;
 
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
@@ -8,6 +9,9 @@ use polars_io::ipc::IpcScanOptions;
 use polars_io::parquet::metadata::FileMetaDataRef;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::DatasetStatistics;
+use polars_io::predicates::BatchStats;
 
 use super::*;
 
@@ -25,6 +29,9 @@ pub enum FileScan {
         cloud_options: Option<polars_io::cloud::CloudOptions>,
         #[cfg_attr(feature = "serde", serde(skip))]
         metadata: Option<FileMetaDataRef>,
+        /// The statistics of the files of the dataset, read from its sidecar.
+        #[cfg_attr(feature = "serde", serde(skip))]
+        statistics: Option<Arc<DatasetStatistics>>,
     },
     #[cfg(feature = "ipc")]
     Ipc {
@@ -108,6 +115,7 @@ impl Hash for FileScan {
                 options,
                 cloud_options,
                 metadata: _,
+                statistics: _,
             } => {
                 options.hash(state);
                 cloud_options.hash(state);
@@ -143,6 +151,27 @@ impl FileScan {
         }
     }
 
+    /// Whether the scan has the statistics of its files, see [`Self::file_statistics`].
+    pub(crate) fn has_file_statistics(&self) -> bool {
+        match self {
+            #[cfg(feature = "parquet")]
+            Self::Parquet { statistics, .. } => statistics.is_some(),
+            _ => false,
+        }
+    }
+
+    /// The statistics of the file at `path` without reading the file, if the scan has them.
+    pub(crate) fn file_statistics(&self, _path: &Path) -> Option<BatchStats> {
+        match self {
+            #[cfg(feature = "parquet")]
+            Self::Parquet {
+                statistics: Some(statistics),
+                ..
+            } => statistics.batch_stats(_path),
+            _ => None,
+        }
+    }
+
     pub(crate) fn sort_projection(&self, _file_options: &FileScanOptions) -> bool {
         match self {
             #[cfg(feature = "csv")]
@@ -166,8 +195,7 @@ impl FileScan {
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
-            #[allow(unreachable_patterns)]
-            _ => false,
+            Self::Anonymous { function, .. } => function.allows_streaming(),
         }
     }
 }
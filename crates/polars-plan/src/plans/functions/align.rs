@@ -0,0 +1,19 @@
+use super::*;
+
+/// Align the columns of `df` by name to `schema`. The columns are cast to the dtype of the
+/// schema, missing columns are filled with nulls and columns that are not in the schema are
+/// dropped.
+pub fn align_by_name(df: &DataFrame, schema: &Schema) -> PolarsResult<DataFrame> {
+    let height = df.height();
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| match df.column(name) {
+            Ok(s) if s.dtype() == dtype => Ok(s.clone()),
+            Ok(s) => s.strict_cast(dtype),
+            Err(_) => Ok(Series::full_null(name, height, dtype)),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    // SAFETY: the names are unique as they are the names of a schema and all columns have the
+    // height of `df`.
+    Ok(unsafe { DataFrame::new_no_checks(columns) })
+}
@@ -57,8 +57,23 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
                 n_rows
             },
             #[cfg(feature = "parquet")]
-            FileScan::Parquet { cloud_options, .. } => {
-                count_rows_parquet(paths, cloud_options.as_ref())
+            FileScan::Parquet {
+                cloud_options,
+                statistics,
+                ..
+            } => {
+                // The number of rows of the files in the statistics of the dataset, if it has
+                // all of them.
+                let from_statistics = statistics.as_ref().and_then(|statistics| {
+                    paths
+                        .iter()
+                        .map(|path| statistics.num_rows(path))
+                        .sum::<Option<usize>>()
+                });
+                match from_statistics {
+                    Some(n_rows) => Ok(n_rows),
+                    None => count_rows_parquet(paths, cloud_options.as_ref()),
+                }
             },
             #[cfg(feature = "ipc")]
             FileScan::Ipc {
@@ -8,6 +8,8 @@ pub enum DslFunction {
     FunctionNode(FunctionNode),
     Explode {
         columns: Vec<Expr>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        options: ExplodeOptions,
     },
     Unpivot {
         args: UnpivotArgs,
@@ -59,7 +61,7 @@ pub enum StatsFunction {
 impl DslFunction {
     pub(crate) fn into_function_node(self, input_schema: &Schema) -> PolarsResult<FunctionNode> {
         let function = match self {
-            DslFunction::Explode { columns } => {
+            DslFunction::Explode { columns, options } => {
                 let columns = rewrite_projections(columns, input_schema, &[])?;
                 // columns to string
                 let columns = columns
@@ -76,6 +78,7 @@ impl DslFunction {
                     .collect::<PolarsResult<Arc<[Arc<str>]>>>()?;
                 FunctionNode::Explode {
                     columns,
+                    options,
                     schema: Default::default(),
                 }
             },
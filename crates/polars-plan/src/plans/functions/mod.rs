@@ -1,3 +1,4 @@
+mod align;
 mod count;
 mod dsl;
 #[cfg(feature = "merge_sorted")]
@@ -13,6 +14,7 @@ use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 use std::sync::Arc;
 
+pub use align::align_by_name;
 pub use dsl::*;
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
@@ -52,6 +54,15 @@ pub enum FunctionNode {
         #[cfg_attr(feature = "serde", serde(skip))]
         fmt_str: &'static str,
     },
+    /// User-defined operator for the streaming engine
+    #[cfg_attr(feature = "serde", serde(skip))]
+    OpaqueStreaming {
+        function: Arc<dyn StreamingUdf>,
+        schema: Option<Arc<dyn UdfSchema>>,
+        // used for formatting
+        #[cfg_attr(feature = "serde", serde(skip))]
+        fmt_str: &'static str,
+    },
     Count {
         paths: Arc<[PathBuf]>,
         scan_type: FileScan,
@@ -66,6 +77,8 @@ pub enum FunctionNode {
     },
     Unnest {
         columns: Arc<[Arc<str>]>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        options: UnnestOptions,
     },
     Rechunk,
     // The two DataFrames are temporary concatenated
@@ -87,6 +100,7 @@ pub enum FunctionNode {
     },
     Explode {
         columns: Arc<[Arc<str>]>,
+        options: ExplodeOptions,
         #[cfg_attr(feature = "serde", serde(skip))]
         schema: CachedSchema,
     },
@@ -102,6 +116,10 @@ pub enum FunctionNode {
         schema: CachedSchema,
         offset: Option<IdxSize>,
     },
+    /// Align the columns by name to a fixed schema, see [`align_by_name`].
+    AlignByName {
+        schema: SchemaRef,
+    },
 }
 
 impl Eq for FunctionNode {}
@@ -124,9 +142,21 @@ impl PartialEq for FunctionNode {
                     ..
                 },
             ) => existing_l == existing_r && new_l == new_r,
-            (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
+            (
+                Explode {
+                    columns: l,
+                    options: l_options,
+                    ..
+                },
+                Explode {
+                    columns: r,
+                    options: r_options,
+                    ..
+                },
+            ) => l == r && l_options == r_options,
             (Unpivot { args: l, .. }, Unpivot { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
+            (AlignByName { schema: l }, AlignByName { schema: r }) => l == r,
             #[cfg(feature = "merge_sorted")]
             (MergeSorted { column: l }, MergeSorted { column: r }) => l == r,
             _ => false,
@@ -141,6 +171,7 @@ impl Hash for FunctionNode {
             #[cfg(feature = "python")]
             FunctionNode::OpaquePython { .. } => {},
             FunctionNode::Opaque { fmt_str, .. } => fmt_str.hash(state),
+            FunctionNode::OpaqueStreaming { fmt_str, .. } => fmt_str.hash(state),
             FunctionNode::Count {
                 paths,
                 scan_type,
@@ -151,7 +182,10 @@ impl Hash for FunctionNode {
                 alias.hash(state);
             },
             FunctionNode::Pipeline { .. } => {},
-            FunctionNode::Unnest { columns } => columns.hash(state),
+            FunctionNode::Unnest { columns, options } => {
+                columns.hash(state);
+                options.hash(state);
+            },
             FunctionNode::Rechunk => {},
             #[cfg(feature = "merge_sorted")]
             FunctionNode::MergeSorted { column } => column.hash(state),
@@ -164,7 +198,14 @@ impl Hash for FunctionNode {
                 existing.hash(state);
                 new.hash(state);
             },
-            FunctionNode::Explode { columns, schema: _ } => columns.hash(state),
+            FunctionNode::Explode {
+                columns,
+                options,
+                schema: _,
+            } => {
+                columns.hash(state);
+                options.hash(state);
+            },
             FunctionNode::Unpivot { args, schema: _ } => args.hash(state),
             FunctionNode::RowIndex {
                 name,
@@ -174,6 +215,7 @@ impl Hash for FunctionNode {
                 name.hash(state);
                 offset.hash(state);
             },
+            FunctionNode::AlignByName { schema } => schema.hash(state),
         }
     }
 }
@@ -187,6 +229,8 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => false,
             Count { .. } | Unnest { .. } | Rename { .. } | Explode { .. } => true,
+            AlignByName { .. } => true,
+            OpaqueStreaming { .. } => true,
             Unpivot { args, .. } => args.streamable,
             Opaque { streamable, .. } => *streamable,
             #[cfg(feature = "python")]
@@ -215,7 +259,8 @@ impl FunctionNode {
             Rechunk | Unnest { .. } | Rename { .. } | Explode { .. } | Unpivot { .. } => true,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
-            RowIndex { .. } | Count { .. } => false,
+            // The filled columns don't exist in the input.
+            RowIndex { .. } | Count { .. } | OpaqueStreaming { .. } | AlignByName { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -235,6 +280,7 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             RowIndex { .. } => true,
+            OpaqueStreaming { .. } | AlignByName { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -242,7 +288,7 @@ impl FunctionNode {
     pub(crate) fn additional_projection_pd_columns(&self) -> Cow<[Arc<str>]> {
         use FunctionNode::*;
         match self {
-            Unnest { columns } => Cow::Borrowed(columns.as_ref()),
+            Unnest { columns, .. } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column, .. } => Cow::Owned(vec![column.clone()]),
@@ -254,6 +300,7 @@ impl FunctionNode {
         use FunctionNode::*;
         match self {
             Opaque { function, .. } => function.call_udf(df),
+            OpaqueStreaming { function, .. } => function.call_udf(df),
             #[cfg(feature = "python")]
             OpaquePython {
                 function,
@@ -270,10 +317,13 @@ impl FunctionNode {
             },
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column } => merge_sorted(&df, column.as_ref()),
-            Unnest { columns: _columns } => {
+            Unnest {
+                columns: _columns,
+                options: _options,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
-                    df.unnest(_columns.as_ref())
+                    df.unnest_with_options(_columns.as_ref(), _options)
                 }
                 #[cfg(not(feature = "dtype-struct"))]
                 {
@@ -294,12 +344,15 @@ impl FunctionNode {
                 }
             },
             Rename { existing, new, .. } => rename::rename_impl(df, existing, new),
-            Explode { columns, .. } => df.explode(columns.as_ref()),
+            Explode {
+                columns, options, ..
+            } => df.explode_with_options(columns.as_ref(), *options),
             Unpivot { args, .. } => {
                 let args = (**args).clone();
                 df.unpivot2(args)
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.as_ref(), *offset),
+            AlignByName { schema } => align_by_name(&df, schema),
         }
     }
 
@@ -327,12 +380,12 @@ impl Display for FunctionNode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use FunctionNode::*;
         match self {
-            Opaque { fmt_str, .. } => write!(f, "{fmt_str}"),
+            Opaque { fmt_str, .. } | OpaqueStreaming { fmt_str, .. } => write!(f, "{fmt_str}"),
             #[cfg(feature = "python")]
             OpaquePython { .. } => write!(f, "python dataframe udf"),
             Rechunk => write!(f, "RECHUNK"),
             Count { .. } => write!(f, "FAST COUNT(*)"),
-            Unnest { columns } => {
+            Unnest { columns, .. } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
                 fmt_column_delimited(f, columns, "[", "]")
@@ -355,6 +408,7 @@ impl Display for FunctionNode {
             Explode { .. } => write!(f, "EXPLODE"),
             Unpivot { .. } => write!(f, "UNPIVOT"),
             RowIndex { .. } => write!(f, "WITH ROW INDEX"),
+            AlignByName { .. } => write!(f, "ALIGN BY NAME"),
         }
     }
 }
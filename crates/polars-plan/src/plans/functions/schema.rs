@@ -25,7 +25,7 @@ impl FunctionNode {
     ) -> PolarsResult<Cow<'a, SchemaRef>> {
         use FunctionNode::*;
         match self {
-            Opaque { schema, .. } => match schema {
+            Opaque { schema, .. } | OpaqueStreaming { schema, .. } => match schema {
                 None => Ok(Cow::Borrowed(input_schema)),
                 Some(schema_fn) => {
                     let output_schema = schema_fn.get_schema(input_schema)?;
@@ -50,19 +50,24 @@ impl FunctionNode {
                 Ok(Cow::Owned(Arc::new(schema)))
             },
             Rechunk => Ok(Cow::Borrowed(input_schema)),
-            Unnest { columns: _columns } => {
+            Unnest {
+                columns: _columns,
+                options: _options,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
+                    polars_ensure!(
+                        _options.max_depth != Some(0),
+                        InvalidOperation: "'max_depth' of unnest must be positive"
+                    );
                     let mut new_schema = Schema::with_capacity(input_schema.len() * 2);
                     for (name, dtype) in input_schema.iter() {
                         if _columns.iter().any(|item| item.as_ref() == name.as_str()) {
                             match dtype {
-                                DataType::Struct(flds) => {
-                                    for fld in flds {
-                                        new_schema.with_column(
-                                            fld.name().clone(),
-                                            fld.data_type().clone(),
-                                        );
+                                DataType::Struct(_) => {
+                                    let field = Field::new(name, dtype.clone());
+                                    for fld in _options.unnest_field(&field) {
+                                        new_schema.with_column(fld.name, fld.dtype);
                                     }
                                 },
                                 DataType::Unknown(_) => {
@@ -97,8 +102,11 @@ impl FunctionNode {
             RowIndex { schema, name, .. } => {
                 Ok(Cow::Owned(row_index_schema(schema, input_schema, name)))
             },
-            Explode { schema, columns } => explode_schema(schema, input_schema, columns),
+            Explode {
+                schema, columns, ..
+            } => explode_schema(schema, input_schema, columns),
             Unpivot { schema, args } => unpivot_schema(args, schema, input_schema),
+            AlignByName { schema } => Ok(Cow::Owned(schema.clone())),
         }
     }
 }
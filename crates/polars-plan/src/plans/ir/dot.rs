@@ -314,9 +314,13 @@ impl<'a> IRDotDisplay<'a> {
                 write_label(f, id, |f| {
                     f.write_str(match payload {
                         SinkType::Memory => "SINK (MEMORY)",
+                        SinkType::LimitEarly { .. } => "SINK (LIMIT EARLY)",
+                        SinkType::Channel(_) => "SINK (CHANNEL)",
                         SinkType::File { .. } => "SINK (FILE)",
                         #[cfg(feature = "cloud")]
                         SinkType::Cloud { .. } => "SINK (CLOUD)",
+                        #[cfg(feature = "parquet")]
+                        SinkType::Checkpointed { .. } => "SINK (CHECKPOINTED)",
                     })
                 })?;
             },
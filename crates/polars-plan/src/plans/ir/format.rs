@@ -363,9 +363,13 @@ impl<'a> IRDisplay<'a> {
             Sink { input, payload, .. } => {
                 let name = match payload {
                     SinkType::Memory => "SINK (memory)",
+                    SinkType::LimitEarly { .. } => "SINK (limit early)",
+                    SinkType::Channel(_) => "SINK (channel)",
                     SinkType::File { .. } => "SINK (file)",
                     #[cfg(feature = "cloud")]
                     SinkType::Cloud { .. } => "SINK (cloud)",
+                    #[cfg(feature = "parquet")]
+                    SinkType::Checkpointed { .. } => "SINK (checkpointed)",
                 };
                 write!(f, "{:indent$}{name}", "")?;
                 self.with_root(*input)._format(f, sub_indent)
@@ -38,9 +38,13 @@ impl IR {
             ExtContext { .. } => "ext_context",
             Sink { payload, .. } => match payload {
                 SinkType::Memory => "sink (memory)",
+                SinkType::LimitEarly { .. } => "sink (limit early)",
+                SinkType::Channel(_) => "sink (channel)",
                 SinkType::File { .. } => "sink (file)",
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud { .. } => "sink (cloud)",
+                #[cfg(feature = "parquet")]
+                SinkType::Checkpointed { .. } => "sink (checkpointed)",
             },
             SimpleProjection { .. } => "simple_projection",
             Invalid => "invalid",
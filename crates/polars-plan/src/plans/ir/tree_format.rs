@@ -359,9 +359,13 @@ impl<'a> TreeFmtNode<'a> {
                             h,
                             match payload {
                                 SinkType::Memory => "SINK (memory)",
+                                SinkType::LimitEarly { .. } => "SINK (limit early)",
+                                SinkType::Channel(_) => "SINK (channel)",
                                 SinkType::File { .. } => "SINK (file)",
                                 #[cfg(feature = "cloud")]
                                 SinkType::Cloud { .. } => "SINK (cloud)",
+                                #[cfg(feature = "parquet")]
+                                SinkType::Checkpointed { .. } => "SINK (checkpointed)",
                             },
                         ),
                         vec![self.lp_node(None, *input)],
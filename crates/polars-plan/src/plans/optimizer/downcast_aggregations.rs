@@ -0,0 +1,96 @@
+use super::*;
+
+/// Sums of small integers are computed as `Int64`, which requires casting the whole column. If
+/// the number of rows is bounded such that the sum cannot overflow an `Int32`, the sum is
+/// computed as `Int32` and only the result is cast to `Int64`.
+pub(super) struct DowncastAggregations {}
+
+/// An upper bound of the number of rows of the output of `node`, if it can be proven from the
+/// sources.
+fn max_rows(mut node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<usize> {
+    loop {
+        match lp_arena.get(node) {
+            IR::DataFrameScan { df, .. } => return Some(df.height()),
+            IR::Scan {
+                paths,
+                file_info,
+                file_options,
+                scan_type,
+                ..
+            } => {
+                // The known number of rows of a scan of several files is that of the first file,
+                // unless the statistics of the dataset counted all files.
+                if paths.len() != 1 && !scan_type.has_file_statistics() {
+                    return None;
+                }
+                let rows = file_info.row_estimation.0?;
+                return Some(file_options.n_rows.map_or(rows, |n_rows| n_rows.min(rows)));
+            },
+            IR::Slice { len, .. } => return Some(*len as usize),
+            IR::Reduce { .. } => return Some(1),
+            // These never produce more rows than their input.
+            IR::Filter { input, .. }
+            | IR::Sort { input, .. }
+            | IR::SimpleProjection { input, .. }
+            | IR::Distinct { input, .. }
+            | IR::Cache { input, .. } => node = *input,
+            // The windows of a dynamic group by can overlap.
+            IR::GroupBy { input, options, .. } if !options.is_dynamic() => node = *input,
+            IR::Select { input, expr, .. }
+            | IR::HStack {
+                input, exprs: expr, ..
+            } if all_streamable(expr, expr_arena, Context::Default) => node = *input,
+            _ => return None,
+        }
+    }
+}
+
+impl OptimizationRule for DowncastAggregations {
+    fn optimize_expr(
+        &mut self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        lp_arena: &Arena<IR>,
+        lp_node: Node,
+    ) -> PolarsResult<Option<AExpr>> {
+        let AExpr::Agg(IRAggExpr::Sum(input)) = expr_arena.get(expr_node) else {
+            return Ok(None);
+        };
+        let input = *input;
+        let Some(lp_input) = lp_arena.get(lp_node).get_input() else {
+            return Ok(None);
+        };
+        let schema = lp_arena.get(lp_input).schema(lp_arena);
+        let Ok(dtype) = expr_arena
+            .get(input)
+            .get_type(&schema, Context::Default, expr_arena)
+        else {
+            return Ok(None);
+        };
+        let max_abs_value = match dtype {
+            DataType::Int8 => i8::MIN.unsigned_abs() as u64,
+            DataType::UInt8 => u8::MAX as u64,
+            DataType::Int16 => i16::MIN.unsigned_abs() as u64,
+            DataType::UInt16 => u16::MAX as u64,
+            _ => return Ok(None),
+        };
+        let Some(rows) = max_rows(lp_input, lp_arena, expr_arena) else {
+            return Ok(None);
+        };
+        if (rows as u64).saturating_mul(max_abs_value) > i32::MAX as u64 {
+            return Ok(None);
+        }
+
+        let input = expr_arena.add(AExpr::Cast {
+            expr: input,
+            data_type: DataType::Int32,
+            options: CastOptions::Strict,
+        });
+        let sum = expr_arena.add(AExpr::Agg(IRAggExpr::Sum(input)));
+        Ok(Some(AExpr::Cast {
+            expr: sum,
+            data_type: DataType::Int64,
+            options: CastOptions::Strict,
+        }))
+    }
+}
@@ -0,0 +1,68 @@
+use super::*;
+
+// Lower this at debug builds so that we hit this in the test suite.
+#[cfg(debug_assertions)]
+const LOW_LATENCY_ROW_LIMIT: usize = 128;
+#[cfg(not(debug_assertions))]
+const LOW_LATENCY_ROW_LIMIT: usize = 10_000;
+
+fn row_limit() -> usize {
+    std::env::var("POLARS_LOW_LATENCY_ROW_LIMIT")
+        .map(|s| s.parse::<usize>().unwrap())
+        .unwrap_or(LOW_LATENCY_ROW_LIMIT)
+}
+
+/// Whether the sources of the query have at most `POLARS_LOW_LATENCY_ROW_LIMIT` rows in total.
+/// Sources of which the number of rows is not known are never small.
+pub(super) fn is_small_query(root: Node, lp_arena: &Arena<IR>) -> bool {
+    let limit = row_limit();
+    let mut total_rows = 0usize;
+    for (_, lp) in lp_arena.iter(root) {
+        let rows = match lp {
+            IR::DataFrameScan { df, .. } => df.height(),
+            IR::Scan {
+                file_info,
+                file_options,
+                ..
+            } => {
+                let rows = match file_info.row_estimation {
+                    (Some(known), _) => known,
+                    (None, estimated) => estimated,
+                };
+                file_options.n_rows.map_or(rows, |n_rows| n_rows.min(rows))
+            },
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => return false,
+            _ => continue,
+        };
+        total_rows = total_rows.saturating_add(rows);
+        if total_rows > limit {
+            return false;
+        }
+    }
+    true
+}
+
+/// Run every node of the query on the calling thread. On small data, the cost of handing work to
+/// the thread pool exceeds the cost of the work.
+pub(super) fn disable_parallelism(root: Node, lp_arena: &mut Arena<IR>) {
+    let nodes = (&*lp_arena)
+        .iter(root)
+        .map(|(node, _)| node)
+        .collect::<Vec<_>>();
+    for node in nodes {
+        match lp_arena.get_mut(node) {
+            IR::Select { options, .. } | IR::HStack { options, .. } => {
+                options.run_parallel = false;
+            },
+            IR::Union { options, .. } => options.parallel = false,
+            IR::HConcat { options, .. } => options.parallel = false,
+            IR::Join { options, .. } => {
+                let options = Arc::make_mut(options);
+                options.allow_parallel = false;
+                options.force_parallel = false;
+            },
+            _ => {},
+        }
+    }
+}
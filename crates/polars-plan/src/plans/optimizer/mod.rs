@@ -11,10 +11,12 @@ mod collect_members;
 mod count_star;
 #[cfg(feature = "cse")]
 mod cse;
+mod downcast_aggregations;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
 mod join_utils;
+mod low_latency;
 mod predicate_pushdown;
 mod projection_pushdown;
 mod simplify_expr;
@@ -68,7 +70,7 @@ pub fn optimize(
     #[allow(dead_code)]
     let verbose = verbose();
     // get toggle values
-    let cluster_with_columns = opt_state.cluster_with_columns;
+    let mut cluster_with_columns = opt_state.cluster_with_columns;
     let predicate_pushdown = opt_state.predicate_pushdown;
     let projection_pushdown = opt_state.projection_pushdown;
     let type_coercion = opt_state.type_coercion;
@@ -80,10 +82,10 @@ pub fn optimize(
     // This keeps eager execution more snappy.
     let eager = opt_state.eager;
     #[cfg(feature = "cse")]
-    let comm_subplan_elim = opt_state.comm_subplan_elim && !eager;
+    let mut comm_subplan_elim = opt_state.comm_subplan_elim && !eager;
 
     #[cfg(feature = "cse")]
-    let comm_subexpr_elim = opt_state.comm_subexpr_elim;
+    let mut comm_subexpr_elim = opt_state.comm_subexpr_elim;
     #[cfg(not(feature = "cse"))]
     let comm_subexpr_elim = false;
 
@@ -105,6 +107,23 @@ pub fn optimize(
     #[cfg(debug_assertions)]
     let prev_schema = lp_arena.get(lp_top).schema(lp_arena).into_owned();
 
+    // Small queries skip the optimizations that only pay off on large data.
+    let low_latency =
+        opt_state.low_latency && !streaming && low_latency::is_small_query(lp_top, lp_arena);
+    if low_latency {
+        if verbose {
+            eprintln!(
+                "small query in low latency mode; skip cse, cluster_with_columns and parallelism"
+            )
+        }
+        cluster_with_columns = false;
+        #[cfg(feature = "cse")]
+        {
+            comm_subplan_elim = false;
+            comm_subexpr_elim = false;
+        }
+    }
+
     // Collect members for optimizations that need it.
     let mut members = MemberCollector::new();
     if !eager && (comm_subexpr_elim || projection_pushdown) {
@@ -191,6 +210,10 @@ pub fn optimize(
         rules.push(Box::new(FlattenUnionRule {}));
     }
 
+    if type_coercion {
+        rules.push(Box::new(downcast_aggregations::DowncastAggregations {}));
+    }
+
     lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
 
     if members.has_joins_or_unions && members.has_cache && _cse_plan_changed {
@@ -217,6 +240,10 @@ pub fn optimize(
         })?;
     }
 
+    if low_latency {
+        low_latency::disable_parallelism(lp_top, lp_arena);
+    }
+
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
     {
@@ -85,16 +85,46 @@ fn join_produces_null(how: &JoinType) -> LeftRight<bool> {
     }
 }
 
-fn all_pred_cols_in_left_on(
+/// Rewrite a predicate on key columns of one side of a join into the same predicate on the key
+/// columns of the other side, which are equal to them in the rows that match.
+///
+/// Returns `None` if the predicate has columns that are not plain key columns, or if a key has
+/// a different dtype on the other side.
+fn transfer_to_other_side(
     predicate: &ExprIR,
     expr_arena: &mut Arena<AExpr>,
-    left_on: &[ExprIR],
-) -> bool {
-    aexpr_to_leaf_names_iter(predicate.node(), expr_arena).all(|pred_column_name| {
-        left_on
-            .iter()
-            .any(|e| e.output_name() == pred_column_name.as_ref())
-    })
+    from_on: &[ExprIR],
+    to_on: &[ExprIR],
+    from_schema: &Schema,
+    to_schema: &Schema,
+) -> Option<ExprIR> {
+    let mut names = PlHashMap::with_capacity(from_on.len());
+    for (from, to) in from_on.iter().zip(to_on) {
+        if let (AExpr::Column(from), AExpr::Column(to)) =
+            (expr_arena.get(from.node()), expr_arena.get(to.node()))
+        {
+            if from_schema.get(from)? == to_schema.get(to)? {
+                names.entry(from.clone()).or_insert_with(|| to.clone());
+            }
+        }
+    }
+
+    let only_keys = aexpr_to_leaf_names_iter(predicate.node(), expr_arena)
+        .all(|name| names.contains_key(&name));
+    if !only_keys {
+        return None;
+    }
+    if names.iter().all(|(from, to)| from == to) {
+        return Some(predicate.clone());
+    }
+    // Rename all columns at once, a key can have the name of another key of the other side.
+    let expr = node_to_expr(predicate.node(), expr_arena).map_expr(|e| match e {
+        Expr::Column(name) => Expr::Column(names.get(&name).cloned().unwrap_or(name)),
+        e => e,
+    });
+    let mut predicate = predicate.clone();
+    predicate.set_node(to_aexpr(expr, expr_arena));
+    Some(predicate)
 }
 
 // Checks if a predicate refers to columns in both tables
@@ -198,18 +228,25 @@ pub(super) fn process_join(
         if !block_pushdown_left && check_input_node(predicate.node(), &schema_left, expr_arena) {
             insert_and_combine_predicate(&mut pushdown_left, &predicate, expr_arena);
             filter_left = true;
-            // If we push down to the left and all predicate columns are also
-            // join columns, we also push down right for inner, left or semi join
-            if all_pred_cols_in_left_on(&predicate, expr_arena, &left_on) {
-                filter_right = match &options.args.how {
-                    // TODO! if join_on right has a different name
-                    // we can set this to `true` IFF we rename the predicate
-                    JoinType::Inner | JoinType::Left => {
-                        check_input_node(predicate.node(), &schema_right, expr_arena)
-                    },
-                    #[cfg(feature = "semi_anti_join")]
-                    JoinType::Semi => check_input_node(predicate.node(), &schema_right, expr_arena),
-                    _ => false,
+            // If we push down to the left and all predicate columns are also join columns, the
+            // rows of the right table that match must satisfy the predicate on their keys too.
+            let transfer_right = match &options.args.how {
+                JoinType::Inner | JoinType::Left => true,
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi | JoinType::Anti => true,
+                _ => false,
+            };
+            if transfer_right {
+                if let Some(right_predicate) = transfer_to_other_side(
+                    &predicate,
+                    expr_arena,
+                    &left_on,
+                    &right_on,
+                    &schema_left,
+                    &schema_right,
+                ) {
+                    insert_and_combine_predicate(&mut pushdown_right, &right_predicate, expr_arena);
+                    filter_right = true;
                 }
             }
         // this is `else if` because if the predicate is in the left hand side
@@ -219,10 +256,22 @@ pub(super) fn process_join(
         } else if !block_pushdown_right
             && check_input_node(predicate.node(), &schema_right, expr_arena)
         {
-            filter_right = true
-        }
-        if filter_right {
             insert_and_combine_predicate(&mut pushdown_right, &predicate, expr_arena);
+            filter_right = true;
+            // The rows of an inner join match a right row that satisfies the predicate, so the
+            // predicate on their keys can filter the left table.
+            if matches!(&options.args.how, JoinType::Inner) && !block_pushdown_left {
+                if let Some(left_predicate) = transfer_to_other_side(
+                    &predicate,
+                    expr_arena,
+                    &right_on,
+                    &left_on,
+                    &schema_right,
+                    &schema_left,
+                ) {
+                    insert_and_combine_predicate(&mut pushdown_left, &left_predicate, expr_arena);
+                }
+            }
         }
 
         match (filter_left, filter_right, &options.args.how) {
@@ -351,30 +351,57 @@ impl<'a> PredicatePushDown<'a> {
                 };
                 let predicate = predicate_at_scan(acc_predicates, predicate.clone(), expr_arena);
 
-                if let (Some(hive_parts), Some(predicate)) = (&scan_hive_parts, &predicate) {
+                // Skip the files of which the hive partitions or the statistics show that the
+                // predicate can't match any row.
+                let can_prune = scan_hive_parts.is_some() || scan_type.has_file_statistics();
+                if let (true, Some(predicate)) = (can_prune, &predicate) {
                     if let Some(io_expr) = self.hive_partition_eval.unwrap()(predicate, expr_arena)
                     {
                         if let Some(stats_evaluator) = io_expr.as_stats_evaluator() {
                             let mut new_paths = Vec::with_capacity(paths.len());
                             let mut new_hive_parts = Vec::with_capacity(paths.len());
+                            let mut skipped_by_statistics = 0;
 
                             for i in 0..paths.len() {
                                 let path = &paths[i];
-                                let hive_parts = &hive_parts[i];
+                                if let Some(hive_parts) = &scan_hive_parts {
+                                    if !stats_evaluator
+                                        .should_read(hive_parts[i].get_statistics())?
+                                    {
+                                        continue;
+                                    }
+                                }
+                                if let Some(stats) = scan_type.file_statistics(path) {
+                                    if !stats_evaluator.should_read(&stats)? {
+                                        skipped_by_statistics += 1;
+                                        continue;
+                                    }
+                                }
 
-                                if stats_evaluator.should_read(hive_parts.get_statistics())? {
-                                    new_paths.push(path.clone());
-                                    new_hive_parts.push(hive_parts.clone());
+                                new_paths.push(path.clone());
+                                if let Some(hive_parts) = &scan_hive_parts {
+                                    new_hive_parts.push(hive_parts[i].clone());
                                 }
                             }
 
                             if paths.len() != new_paths.len() {
                                 if self.verbose {
-                                    eprintln!(
-                                        "hive partitioning: skipped {} files, first file : {}",
-                                        paths.len() - new_paths.len(),
-                                        paths[0].display()
-                                    )
+                                    let skipped_by_hive =
+                                        paths.len() - new_paths.len() - skipped_by_statistics;
+                                    if skipped_by_hive > 0 {
+                                        eprintln!(
+                                            "hive partitioning: skipped {} files, first file : {}",
+                                            skipped_by_hive,
+                                            paths[0].display()
+                                        )
+                                    }
+                                    if skipped_by_statistics > 0 {
+                                        eprintln!(
+                                            "file statistics: skipped {} files, first file : {}",
+                                            skipped_by_statistics,
+                                            paths[0].display()
+                                        )
+                                    }
                                 }
                                 scan_type.remove_metadata();
                             }
@@ -390,7 +417,9 @@ impl<'a> PredicatePushDown<'a> {
                                 });
                             } else {
                                 paths = Arc::from(new_paths);
-                                scan_hive_parts = Some(Arc::from(new_hive_parts));
+                                if scan_hive_parts.is_some() {
+                                    scan_hive_parts = Some(Arc::from(new_hive_parts));
+                                }
                             }
                         }
                     }
@@ -48,7 +48,9 @@ pub(super) fn process_functions(
             let lp = IR::MapFunction { input, function };
             Ok(lp)
         },
-        Explode { columns, .. } => {
+        Explode {
+            columns, options, ..
+        } => {
             columns.iter().for_each(|name| {
                 add_str_to_accumulated(name, &mut acc_projections, &mut projected_names, expr_arena)
             });
@@ -61,7 +63,7 @@ pub(super) fn process_functions(
                 expr_arena,
             )?;
             Ok(IRBuilder::new(input, expr_arena, lp_arena)
-                .explode(columns.clone())
+                .explode(columns.clone(), *options)
                 .build())
         },
         Unpivot { ref args, .. } => {
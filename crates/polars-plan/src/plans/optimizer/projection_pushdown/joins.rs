@@ -100,6 +100,20 @@ pub(super) fn process_asof_join(
                 );
             }
         }
+        // make sure that the per-row tolerance column is projected
+        if let Some(name) = &asof_options.tolerance_column {
+            let add = _projected_names.contains(name.as_str());
+
+            let node = expr_arena.add(AExpr::Column(ColumnName::from(name.as_str())));
+            add_keys_to_accumulated_state(
+                node,
+                &mut pushdown_left,
+                &mut local_projection,
+                &mut names_left,
+                expr_arena,
+                add,
+            );
+        }
 
         // The join on keys can lead that columns are already added, we don't want to create
         // duplicates so store the names.
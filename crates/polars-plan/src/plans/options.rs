@@ -1,6 +1,7 @@
 #[cfg(feature = "json")]
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::sync::mpsc::SyncSender;
 
 use polars_core::prelude::*;
 use polars_core::utils::SuperTypeOptions;
@@ -12,11 +13,11 @@ use polars_io::ipc::IpcWriterOptions;
 use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
-use polars_io::{HiveOptions, RowIndex};
+use polars_io::{DtypeOverrides, HiveOptions, RowIndex};
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(feature = "python")]
 use crate::prelude::python_udf::PythonFunction;
@@ -34,6 +35,24 @@ pub struct FileScanOptions {
     pub rechunk: bool,
     pub file_counter: FileCount,
     pub hive_options: HiveOptions,
+    pub schema_evolution: SchemaEvolution,
+}
+
+/// How the schemas of the files of a scan over several files are combined.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SchemaEvolution {
+    /// All files must have the same schema.
+    #[default]
+    Strict,
+    /// Columns are matched by name, in the order in which they first appear. Columns that are
+    /// missing from a file are filled with nulls and the dtypes of a column are widened to their
+    /// supertype, e.g. `Int32` and `Int64` to `Int64`.
+    Unify,
+    /// Every file is cast to the given schema. Columns that are missing from a file are filled
+    /// with nulls and columns that are not in the schema are dropped. Raises if a value can't be
+    /// cast.
+    Cast(SchemaRef),
 }
 
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
@@ -65,6 +84,20 @@ pub struct GroupbyOptions {
     pub slice: Option<(i64, usize)>,
 }
 
+impl GroupbyOptions {
+    /// Whether this is a dynamic group by, of which the windows can overlap.
+    pub(crate) fn is_dynamic(&self) -> bool {
+        #[cfg(feature = "dynamic_group_by")]
+        {
+            self.dynamic.is_some()
+        }
+        #[cfg(not(feature = "dynamic_group_by"))]
+        {
+            false
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DistinctOptions {
@@ -230,6 +263,14 @@ pub struct AnonymousScanOptions {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum SinkType {
     Memory,
+    /// Keep the first `len` rows that the streaming engine produces and stop the query once it
+    /// has them. The rows are kept in the order they arrive, not in the order of the input.
+    LimitEarly {
+        len: usize,
+    },
+    /// Send the chunks that the streaming engine produces to a bounded channel as they arrive.
+    /// The query stops once the receiver is dropped.
+    Channel(ChunkSender),
     File {
         path: Arc<PathBuf>,
         file_type: FileType,
@@ -240,6 +281,70 @@ pub enum SinkType {
         file_type: FileType,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
+    /// Write parquet parts to the directory `path` and checkpoint the progress, so that an
+    /// interrupted query can be resumed.
+    #[cfg(feature = "parquet")]
+    Checkpointed {
+        path: Arc<PathBuf>,
+        options: ParquetWriteOptions,
+        checkpoint: CheckpointOptions,
+    },
+}
+
+/// The sending half of the bounded channel of a [`SinkType::Channel`].
+///
+/// It compares and hashes by identity and refuses to be serialized, as it only has a meaning in
+/// the process that receives from it.
+#[derive(Clone)]
+pub struct ChunkSender(pub Arc<SyncSender<DataFrame>>);
+
+impl std::fmt::Debug for ChunkSender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ChunkSender")
+    }
+}
+
+impl PartialEq for ChunkSender {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ChunkSender {}
+
+impl std::hash::Hash for ChunkSender {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ChunkSender {
+    fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom(
+            "cannot serialize a plan that sends its result to a channel",
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ChunkSender {
+    fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+        Err(serde::de::Error::custom(
+            "cannot deserialize a plan that sends its result to a channel",
+        ))
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CheckpointOptions {
+    /// The number of rows that are written to a part before it is checkpointed.
+    pub rows_per_part: usize,
+    /// Identifies the query, a checkpoint of another query is not resumed.
+    pub fingerprint: u64,
+    /// The number of rows at the start of the output that were already written.
+    pub skip_rows: usize,
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -340,4 +445,5 @@ pub struct NDJsonReadOptions {
     pub low_memory: bool,
     pub ignore_errors: bool,
     pub schema: Option<SchemaRef>,
+    pub dtype_overrides: Option<Arc<DtypeOverrides>>,
 }
@@ -50,6 +50,7 @@ impl SelectModifiers {
 pub struct SQLContext {
     pub(crate) table_map: PlHashMap<String, LazyFrame>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
+    pub(crate) table_registry: Option<Arc<TableRegistry>>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
@@ -62,6 +63,7 @@ impl Default for SQLContext {
     fn default() -> Self {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
+            table_registry: None,
             table_map: Default::default(),
             cte_map: Default::default(),
             table_aliases: Default::default(),
@@ -170,6 +172,13 @@ impl SQLContext {
         self
     }
 
+    /// Resolve tables that are not registered in the SQLContext from a [`TableRegistry`], e.g.
+    /// [`TableRegistry::global`].
+    pub fn with_table_registry(mut self, table_registry: Arc<TableRegistry>) -> Self {
+        self.table_registry = Some(table_registry);
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -211,6 +220,13 @@ impl SQLContext {
     }
 
     pub(super) fn get_table_from_current_scope(&self, name: &str) -> Option<LazyFrame> {
+        let get_table = |name: &str| {
+            self.table_map.get(name).cloned().or_else(|| {
+                self.table_registry
+                    .as_ref()
+                    .and_then(|registry| registry.get(name))
+            })
+        };
         let table_name = self.table_map.get(name).cloned();
         table_name
             .or_else(|| self.cte_map.borrow().get(name).cloned())
@@ -218,8 +234,9 @@ impl SQLContext {
                 self.table_aliases
                     .borrow()
                     .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                    .and_then(|alias| get_table(alias))
             })
+            .or_else(|| get_table(name))
     }
 
     fn expr_or_ordinal(
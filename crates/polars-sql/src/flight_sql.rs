@@ -0,0 +1,453 @@
+//! Serve the tables of a [`SQLContext`] over [Arrow Flight SQL](https://arrow.apache.org/docs/format/FlightSql.html),
+//! so that BI tools and ADBC/JDBC drivers can query them.
+//!
+//! Supported are statement queries, which are planned with the [`SQLContext`] and streamed as
+//! Arrow IPC, and the catalog commands `GetCatalogs`, `GetDbSchemas`, `GetTables` and
+//! `GetTableTypes`. Prepared statements, transactions and updates are not supported.
+//!
+//! The server is stateless: the ticket of a statement holds its query, which is planned again
+//! when the results are fetched.
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use arrow::io::flight::{
+    default_ipc_fields, serialize_batch, serialize_schema, serialize_schema_to_info,
+    serialize_schema_to_result, WriteOptions,
+};
+use arrow_format::flight::data::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, Result as ActionResult, SchemaResult, Ticket,
+};
+use arrow_format::flight::service::flight_service_server::{FlightService, FlightServiceServer};
+use futures::Stream;
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_lazy::prelude::*;
+use prost::Message;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::SQLContext;
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/arrow.flight.protocol.sql.";
+/// The only catalog and schema that tables are listed in.
+const CATALOG: &str = "polars";
+const DB_SCHEMA: &str = "public";
+const TABLE_TYPE: &str = "TABLE";
+
+/// `google.protobuf.Any`, in which Flight SQL wraps its commands.
+#[derive(Clone, PartialEq, Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandStatementQuery {
+    #[prost(string, tag = "1")]
+    query: String,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    transaction_id: Option<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct TicketStatementQuery {
+    #[prost(bytes = "vec", tag = "1")]
+    statement_handle: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandGetCatalogs {}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandGetDbSchemas {
+    #[prost(string, optional, tag = "1")]
+    catalog: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    db_schema_filter_pattern: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandGetTables {
+    #[prost(string, optional, tag = "1")]
+    catalog: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    db_schema_filter_pattern: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    table_name_filter_pattern: Option<String>,
+    #[prost(string, repeated, tag = "4")]
+    table_types: Vec<String>,
+    #[prost(bool, tag = "5")]
+    include_schema: bool,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandGetTableTypes {}
+
+/// A Flight SQL command, decoded from the `Any` of a descriptor or ticket.
+enum Command {
+    StatementQuery(CommandStatementQuery),
+    TicketStatementQuery(TicketStatementQuery),
+    GetCatalogs(CommandGetCatalogs),
+    GetDbSchemas(CommandGetDbSchemas),
+    GetTables(CommandGetTables),
+    GetTableTypes(CommandGetTableTypes),
+}
+
+impl Command {
+    fn decode(bytes: &[u8]) -> Result<Self, Status> {
+        let any = Any::decode(bytes).map_err(|err| Status::invalid_argument(err.to_string()))?;
+        let name = any.type_url.strip_prefix(TYPE_URL_PREFIX).unwrap_or("");
+        let value = any.value.as_slice();
+        let decode_err = |err: prost::DecodeError| Status::invalid_argument(err.to_string());
+        Ok(match name {
+            "CommandStatementQuery" => {
+                Self::StatementQuery(Message::decode(value).map_err(decode_err)?)
+            },
+            "TicketStatementQuery" => {
+                Self::TicketStatementQuery(Message::decode(value).map_err(decode_err)?)
+            },
+            "CommandGetCatalogs" => Self::GetCatalogs(Message::decode(value).map_err(decode_err)?),
+            "CommandGetDbSchemas" => {
+                Self::GetDbSchemas(Message::decode(value).map_err(decode_err)?)
+            },
+            "CommandGetTables" => Self::GetTables(Message::decode(value).map_err(decode_err)?),
+            "CommandGetTableTypes" => {
+                Self::GetTableTypes(Message::decode(value).map_err(decode_err)?)
+            },
+            _ => {
+                return Err(Status::unimplemented(format!(
+                    "unsupported flight sql command '{}'",
+                    any.type_url
+                )))
+            },
+        })
+    }
+}
+
+fn encode_any<M: Message>(name: &str, message: &M) -> Vec<u8> {
+    Any {
+        type_url: format!("{TYPE_URL_PREFIX}{name}"),
+        value: message.encode_to_vec(),
+    }
+    .encode_to_vec()
+}
+
+fn to_status(err: PolarsError) -> Status {
+    match err {
+        PolarsError::SQLInterface(_)
+        | PolarsError::SQLSyntax(_)
+        | PolarsError::ColumnNotFound(_)
+        | PolarsError::SchemaFieldNotFound(_)
+        | PolarsError::InvalidOperation(_) => Status::invalid_argument(err.to_string()),
+        PolarsError::Cancelled(_) => Status::cancelled(err.to_string()),
+        PolarsError::SpillLimitExceeded(_) => Status::resource_exhausted(err.to_string()),
+        _ => Status::internal(err.to_string()),
+    }
+}
+
+/// Whether `value` matches the SQL `LIKE` `pattern`, in which `%` matches any sequence of
+/// characters and `_` any single character.
+fn matches_like(pattern: &str, value: &str) -> bool {
+    fn matches(pattern: &[char], value: &[char]) -> bool {
+        match pattern.split_first() {
+            None => value.is_empty(),
+            Some(('%', rest)) => (0..=value.len()).any(|i| matches(rest, &value[i..])),
+            Some(('_', rest)) => !value.is_empty() && matches(rest, &value[1..]),
+            Some((c, rest)) => value.first() == Some(c) && matches(rest, &value[1..]),
+        }
+    }
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+    matches(&pattern, &value)
+}
+
+fn matches_filter(pattern: &Option<String>, value: &str) -> bool {
+    pattern
+        .as_deref()
+        .map_or(true, |pattern| matches_like(pattern, value))
+}
+
+type FlightStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + 'static>>;
+
+/// The Flight SQL messages of `df`: its schema, followed by its record batches.
+fn to_flight_data(df: &DataFrame) -> PolarsResult<Vec<FlightData>> {
+    // Flight SQL clients expect the standard arrow types, not string views.
+    let schema = df.schema().to_arrow(false);
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions { compression: None };
+
+    let mut out = vec![serialize_schema(&schema, Some(&ipc_fields))];
+    for chunk in df.iter_chunks(false, true) {
+        let (dictionaries, batch) = serialize_batch(&chunk, &ipc_fields, &options)?;
+        out.extend(dictionaries);
+        out.push(batch);
+    }
+    Ok(out)
+}
+
+/// A Flight SQL service over the tables of a [`SQLContext`].
+///
+/// The server can be cloned to register tables while it is serving.
+#[derive(Clone)]
+pub struct FlightSqlServer {
+    ctx: Arc<Mutex<SQLContext>>,
+}
+
+impl FlightSqlServer {
+    /// Serve the tables registered in `ctx`.
+    pub fn new(ctx: SQLContext) -> Self {
+        Self {
+            ctx: Arc::new(Mutex::new(ctx)),
+        }
+    }
+
+    /// Register a [`LazyFrame`] as a table that can be queried.
+    pub fn register(&self, name: &str, lf: LazyFrame) {
+        self.ctx.lock().unwrap().register(name, lf)
+    }
+
+    /// Stop serving a table.
+    pub fn unregister(&self, name: &str) {
+        self.ctx.lock().unwrap().unregister(name)
+    }
+
+    /// The gRPC service, to be added to a [`tonic::transport::Server`].
+    pub fn into_service(self) -> FlightServiceServer<Self> {
+        FlightServiceServer::new(self)
+    }
+
+    /// Serve on `addr` until the server fails.
+    pub async fn serve(self, addr: SocketAddr) -> PolarsResult<()> {
+        tonic::transport::Server::builder()
+            .add_service(self.into_service())
+            .serve(addr)
+            .await
+            .map_err(to_compute_err)
+    }
+
+    fn plan(&self, query: &str) -> PolarsResult<LazyFrame> {
+        self.ctx.lock().unwrap().execute(query)
+    }
+
+    fn tables(&self) -> Vec<(String, LazyFrame)> {
+        let ctx = self.ctx.lock().unwrap();
+        ctx.get_tables()
+            .into_iter()
+            .map(|name| {
+                let lf = ctx.table_map[&name].clone();
+                (name, lf)
+            })
+            .collect()
+    }
+
+    /// The result of a catalog command, or `None` for a statement.
+    fn catalog_result(&self, command: &Command) -> PolarsResult<Option<DataFrame>> {
+        let df = match command {
+            Command::StatementQuery(_) | Command::TicketStatementQuery(_) => return Ok(None),
+            Command::GetCatalogs(_) => df!["catalog_name" => [CATALOG]]?,
+            Command::GetDbSchemas(cmd) => {
+                let show = cmd.catalog.as_deref().map_or(true, |c| c == CATALOG)
+                    && matches_filter(&cmd.db_schema_filter_pattern, DB_SCHEMA);
+                df![
+                    "catalog_name" => [CATALOG],
+                    "db_schema_name" => [DB_SCHEMA],
+                ]?
+                .head(Some(show as usize))
+            },
+            Command::GetTables(cmd) => {
+                let show = cmd.catalog.as_deref().map_or(true, |c| c == CATALOG)
+                    && matches_filter(&cmd.db_schema_filter_pattern, DB_SCHEMA)
+                    && (cmd.table_types.is_empty()
+                        || cmd.table_types.iter().any(|t| t == TABLE_TYPE));
+                let tables = self
+                    .tables()
+                    .into_iter()
+                    .filter(|(name, _)| {
+                        show && matches_filter(&cmd.table_name_filter_pattern, name)
+                    })
+                    .collect::<Vec<_>>();
+                let names = tables
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>();
+                let mut df = df![
+                    "catalog_name" => vec![CATALOG; names.len()],
+                    "db_schema_name" => vec![DB_SCHEMA; names.len()],
+                    "table_name" => names,
+                    "table_type" => vec![TABLE_TYPE; tables.len()],
+                ]?;
+                if cmd.include_schema {
+                    let schemas = tables
+                        .into_iter()
+                        .map(|(_, mut lf)| {
+                            let schema = lf.schema()?.to_arrow(false);
+                            serialize_schema_to_info(&schema, None)
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    df.with_column(BinaryChunked::from_slice("table_schema", &schemas))?;
+                }
+                df
+            },
+            Command::GetTableTypes(_) => df!["table_type" => [TABLE_TYPE]]?,
+        };
+        Ok(Some(df))
+    }
+
+    /// The schema of the result of `command`.
+    fn result_schema(&self, command: &Command) -> PolarsResult<ArrowSchema> {
+        let schema = match command {
+            Command::StatementQuery(cmd) => self.plan(&cmd.query)?.schema()?.as_ref().clone(),
+            Command::TicketStatementQuery(ticket) => {
+                let query = String::from_utf8_lossy(&ticket.statement_handle);
+                self.plan(&query)?.schema()?.as_ref().clone()
+            },
+            _ => self.catalog_result(command)?.unwrap().schema(),
+        };
+        Ok(schema.to_arrow(false))
+    }
+
+    /// The ticket with which the results of `command` are fetched.
+    fn ticket(command: &Command, descriptor_cmd: &[u8]) -> Vec<u8> {
+        match command {
+            Command::StatementQuery(cmd) => encode_any(
+                "TicketStatementQuery",
+                &TicketStatementQuery {
+                    statement_handle: cmd.query.clone().into_bytes(),
+                },
+            ),
+            _ => descriptor_cmd.to_vec(),
+        }
+    }
+
+    /// Compute the result of `command` on the blocking thread pool.
+    async fn execute(&self, command: Command) -> Result<DataFrame, Status> {
+        let server = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let query = match &command {
+                Command::StatementQuery(cmd) => cmd.query.clone(),
+                Command::TicketStatementQuery(ticket) => {
+                    String::from_utf8_lossy(&ticket.statement_handle).into_owned()
+                },
+                _ => return Ok(server.catalog_result(&command)?.unwrap()),
+            };
+            server.plan(&query)?.collect()
+        })
+        .await
+        .map_err(|err| Status::internal(err.to_string()))?
+        .map_err(to_status)
+    }
+}
+
+fn descriptor_command(descriptor: &FlightDescriptor) -> Result<Command, Status> {
+    // The descriptor type `CMD` is `2`.
+    if descriptor.r#type != 2 {
+        return Err(Status::invalid_argument(
+            "flight sql only supports command descriptors",
+        ));
+    }
+    Command::decode(&descriptor.cmd)
+}
+
+#[tonic::async_trait]
+impl FlightService for FlightSqlServer {
+    type HandshakeStream = FlightStream<HandshakeResponse>;
+    type ListFlightsStream = FlightStream<FlightInfo>;
+    type DoGetStream = FlightStream<FlightData>;
+    type DoPutStream = FlightStream<PutResult>;
+    type DoActionStream = FlightStream<ActionResult>;
+    type ListActionsStream = FlightStream<ActionType>;
+    type DoExchangeStream = FlightStream<FlightData>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        // No authentication, so there is nothing to negotiate.
+        let stream: Self::HandshakeStream =
+            Box::pin(futures::stream::iter([Ok(HandshakeResponse::default())]));
+        Ok(Response::new(stream))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("list_flights is not supported"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let descriptor = request.into_inner();
+        let command = descriptor_command(&descriptor)?;
+        let schema = self.result_schema(&command).map_err(to_status)?;
+        let ticket = Self::ticket(&command, &descriptor.cmd);
+        Ok(Response::new(FlightInfo {
+            schema: serialize_schema_to_info(&schema, None).map_err(to_status)?,
+            flight_descriptor: Some(descriptor),
+            endpoint: vec![FlightEndpoint {
+                ticket: Some(Ticket { ticket }),
+                ..Default::default()
+            }],
+            total_records: -1,
+            total_bytes: -1,
+            ..Default::default()
+        }))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        let command = descriptor_command(request.get_ref())?;
+        let schema = self.result_schema(&command).map_err(to_status)?;
+        Ok(Response::new(serialize_schema_to_result(&schema, None)))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let command = Command::decode(&request.get_ref().ticket)?;
+        let df = self.execute(command).await?;
+        let data = to_flight_data(&df).map_err(to_status)?;
+        let stream: Self::DoGetStream = Box::pin(futures::stream::iter(data.into_iter().map(Ok)));
+        Ok(Response::new(stream))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("updates are not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented(
+            "prepared statements and transactions are not supported",
+        ))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        let stream: Self::ListActionsStream = Box::pin(futures::stream::empty());
+        Ok(Response::new(stream))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("do_exchange is not supported"))
+    }
+}
@@ -2,6 +2,8 @@
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
 mod context;
+#[cfg(feature = "flight_sql")]
+pub mod flight_sql;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
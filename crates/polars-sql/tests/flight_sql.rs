@@ -0,0 +1,143 @@
+#![cfg(feature = "flight_sql")]
+use arrow::io::flight::{deserialize_batch, deserialize_schemas};
+use arrow_format::flight::data::{FlightData, FlightDescriptor};
+use arrow_format::flight::service::flight_service_server::FlightService;
+use futures::TryStreamExt;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::flight_sql::FlightSqlServer;
+use polars_sql::SQLContext;
+use prost::Message;
+use tonic::Request;
+
+#[derive(Clone, PartialEq, Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandStatementQuery {
+    #[prost(string, tag = "1")]
+    query: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandGetTables {
+    #[prost(string, optional, tag = "3")]
+    table_name_filter_pattern: Option<String>,
+}
+
+fn descriptor<M: Message>(name: &str, command: &M) -> FlightDescriptor {
+    let any = Any {
+        type_url: format!("type.googleapis.com/arrow.flight.protocol.sql.{name}"),
+        value: command.encode_to_vec(),
+    };
+    FlightDescriptor {
+        r#type: 2,
+        cmd: any.encode_to_vec(),
+        ..Default::default()
+    }
+}
+
+fn create_server() -> FlightSqlServer {
+    let df = df! {
+        "a" => [1, 2, 3, 4],
+        "b" => ["x", "y", "x", "z"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("tbl", df.lazy());
+    FlightSqlServer::new(ctx)
+}
+
+/// Fetch the results of `descriptor` as a client would: get the flight info, then its ticket.
+fn fetch(server: &FlightSqlServer, descriptor: FlightDescriptor) -> DataFrame {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let data: Vec<FlightData> = runtime.block_on(async {
+        let info = server
+            .get_flight_info(Request::new(descriptor))
+            .await
+            .unwrap()
+            .into_inner();
+        let ticket = info.endpoint[0].ticket.clone().unwrap();
+        server
+            .do_get(Request::new(ticket))
+            .await
+            .unwrap()
+            .into_inner()
+            .try_collect()
+            .await
+            .unwrap()
+    });
+
+    let (schema, ipc_schema) = deserialize_schemas(&data[0].data_header).unwrap();
+    let mut out = DataFrame::empty();
+    for batch in &data[1..] {
+        let chunk =
+            deserialize_batch(batch, &schema.fields, &ipc_schema, &Default::default()).unwrap();
+        let df = DataFrame::try_from((chunk, schema.fields.as_slice())).unwrap();
+        if out.width() == 0 {
+            out = df;
+        } else {
+            out.vstack_mut(&df).unwrap();
+        }
+    }
+    out
+}
+
+#[test]
+fn test_flight_sql_statement_query() {
+    let server = create_server();
+    let query = CommandStatementQuery {
+        query: "SELECT b, SUM(a) AS a FROM tbl GROUP BY b ORDER BY b".into(),
+    };
+    let out = fetch(&server, descriptor("CommandStatementQuery", &query));
+    let expected = df! {
+        "b" => ["x", "y", "z"],
+        "a" => [4, 2, 4],
+    }
+    .unwrap();
+    assert!(out.equals(&expected));
+}
+
+#[test]
+fn test_flight_sql_get_tables() {
+    let server = create_server();
+    server.register("other", df! {"c" => [1.0]}.unwrap().lazy());
+
+    let all = fetch(
+        &server,
+        descriptor("CommandGetTables", &CommandGetTables::default()),
+    );
+    // Tables are listed in sorted order.
+    assert!(all
+        .column("table_name")
+        .unwrap()
+        .equals(&Series::new("table_name", ["other", "tbl"])));
+
+    let filter = CommandGetTables {
+        table_name_filter_pattern: Some("tb%".into()),
+    };
+    let filtered = fetch(&server, descriptor("CommandGetTables", &filter));
+    assert_eq!(filtered.height(), 1);
+}
+
+#[test]
+fn test_flight_sql_invalid_query() {
+    let server = create_server();
+    let query = CommandStatementQuery {
+        query: "SELECT * FROM missing".into(),
+    };
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let status = runtime
+        .block_on(server.get_flight_info(Request::new(descriptor("CommandStatementQuery", &query))))
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
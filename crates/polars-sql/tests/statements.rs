@@ -594,3 +594,30 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     ctx.execute(sql).unwrap().collect().unwrap();
 }
+
+#[test]
+fn test_table_registry() {
+    let registry = Arc::new(TableRegistry::new(Default::default()));
+    registry
+        .register_df(
+            "registered",
+            df! {
+                "a" => [1i64, 2, 3],
+            }
+            .unwrap(),
+        )
+        .unwrap();
+    let mut ctx = create_ctx().with_table_registry(registry);
+    let sql = r#"
+    SELECT r.a, df.b
+    FROM registered AS r
+    INNER JOIN df ON r.a = df.b
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "a" => [1i64, 2, 3],
+        "b" => [1i64, 2, 3],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+}
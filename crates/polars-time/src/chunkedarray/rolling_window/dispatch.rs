@@ -144,6 +144,126 @@ where
     Series::try_from((ca.name(), out))
 }
 
+#[cfg(feature = "rolling_window_by")]
+fn rolling_cov_agg_by<T>(
+    x: &ChunkedArray<T>,
+    y: &ChunkedArray<T>,
+    by: &Series,
+    options: &RollingOptionsDynamicWindow,
+    corr: bool,
+) -> PolarsResult<Series>
+where
+    T: PolarsFloatType,
+    T::Native: polars_core::export::num::Float,
+{
+    if x.is_empty() {
+        return Ok(Series::new_empty(x.name(), x.dtype()));
+    }
+    polars_ensure!(x.len() == y.len(), InvalidOperation: "both columns in `rolling_cov_by` and `rolling_corr_by` must have the same length");
+    polars_ensure!(by.null_count() == 0 && x.null_count() == 0 && y.null_count() == 0, InvalidOperation: "'rolling_cov_by' and 'rolling_corr_by' not yet supported for series with null values");
+    polars_ensure!(x.len() == by.len(), InvalidOperation: "`by` column in `rolling_*_by` must be the same length as values column");
+    ensure_duration_matches_data_type(options.window_size, by.dtype(), "window_size")?;
+    polars_ensure!(!options.window_size.is_zero() && !options.window_size.negative, InvalidOperation: "`window_size` must be strictly positive");
+    let (by, tz) = match by.dtype() {
+        DataType::Datetime(tu, tz) => (by.cast(&DataType::Datetime(*tu, None))?, tz),
+        DataType::Date => (
+            by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+            &None,
+        ),
+        dt => polars_bail!(InvalidOperation:
+            "in `rolling_*_by` operation, `by` argument of dtype `{}` is not supported (expected `{}`)",
+            dt,
+            "date/datetime"),
+    };
+    let x = x.rechunk();
+    let y = y.rechunk();
+    let by = by.rechunk();
+    let by_is_sorted = by.is_sorted(SortOptions {
+        descending: false,
+        ..Default::default()
+    })?;
+    let by = by.datetime().unwrap();
+    let tu = by.time_unit();
+    let ddof = options.fn_params.as_ref().map_or(1, |params| {
+        params
+            .downcast_ref::<rolling::RollingVarParams>()
+            .unwrap()
+            .ddof
+    });
+
+    let func =
+        |x: &[T::Native], y: &[T::Native], time: &[i64], sorting_indices: Option<&[IdxSize]>| {
+            use super::rolling_kernels::covariance;
+            if corr {
+                covariance::rolling_corr(
+                    x,
+                    y,
+                    options.window_size,
+                    time,
+                    options.closed_window,
+                    options.min_periods,
+                    tu,
+                    tz.as_ref(),
+                    sorting_indices,
+                )
+            } else {
+                covariance::rolling_cov(
+                    x,
+                    y,
+                    options.window_size,
+                    time,
+                    options.closed_window,
+                    options.min_periods,
+                    tu,
+                    tz.as_ref(),
+                    ddof,
+                    sorting_indices,
+                )
+            }
+        };
+    let out: ArrayRef = if by_is_sorted {
+        func(
+            x.cont_slice().unwrap(),
+            y.cont_slice().unwrap(),
+            by.cont_slice().unwrap(),
+            None,
+        )?
+    } else {
+        let sorting_indices = by.arg_sort(Default::default());
+        let x = unsafe { x.take_unchecked(&sorting_indices) };
+        let y = unsafe { y.take_unchecked(&sorting_indices) };
+        let by = unsafe { by.take_unchecked(&sorting_indices) };
+        func(
+            x.cont_slice().unwrap(),
+            y.cont_slice().unwrap(),
+            by.cont_slice().unwrap(),
+            Some(sorting_indices.cont_slice().unwrap()),
+        )?
+    };
+    Series::try_from((x.name(), out))
+}
+
+#[cfg(feature = "rolling_window_by")]
+fn rolling_cov_by_impl(
+    x: &Series,
+    y: &Series,
+    by: &Series,
+    options: RollingOptionsDynamicWindow,
+    corr: bool,
+) -> PolarsResult<Series> {
+    let mut x = x.to_float()?;
+    let mut y = y.to_float()?;
+    if x.dtype() != y.dtype() {
+        x = x.cast(&DataType::Float64)?;
+        y = y.cast(&DataType::Float64)?;
+    }
+    with_match_physical_float_polars_type!(x.dtype(), |$T| {
+        let x: &ChunkedArray<$T> = x.as_ref().as_ref().as_ref();
+        let y: &ChunkedArray<$T> = y.as_ref().as_ref().as_ref();
+        rolling_cov_agg_by(x, y, by, &options, corr)
+    })
+}
+
 pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling mean to a Series based on another Series.
     #[cfg(feature = "rolling_window_by")]
@@ -206,6 +326,43 @@ pub trait SeriesOpsTime: AsSeries {
             s = s.to_float()?;
         }
 
+        #[cfg(feature = "dtype-decimal")]
+        if let DataType::Decimal(precision, Some(scale)) = s.dtype() {
+            polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+            if s.is_empty() {
+                return Ok(s.clone());
+            }
+            // The physical values share the scale, so their sums are the scaled decimal sums.
+            let ca = s.decimal()?.0.rechunk();
+            let arr = ca.downcast_iter().next().unwrap();
+            let out = match ca.null_count() {
+                0 => rolling::no_nulls::rolling_sum(
+                    arr.values().as_slice(),
+                    options.window_size,
+                    options.min_periods,
+                    options.center,
+                    None,
+                    options.fn_params,
+                )?,
+                _ => rolling::nulls::rolling_sum(
+                    arr,
+                    options.window_size,
+                    options.min_periods,
+                    options.center,
+                    None,
+                    options.fn_params,
+                ),
+            };
+            let out = out
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap()
+                .clone();
+            return Ok(Int128Chunked::with_chunk(s.name(), out)
+                .into_decimal_unchecked(*precision, *scale)
+                .into_series());
+        }
+
         with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
             rolling_agg(
@@ -434,6 +591,30 @@ pub trait SeriesOpsTime: AsSeries {
             s
         })
     }
+
+    /// Apply a rolling covariance with `other` to a Series based on another Series.
+    ///
+    /// The co-moments are updated incrementally, which is numerically stable.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_cov_by(
+        &self,
+        other: &Series,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        rolling_cov_by_impl(self.as_series(), other, by, options, false)
+    }
+
+    /// Apply a rolling pearson correlation with `other` to a Series based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_corr_by(
+        &self,
+        other: &Series,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        rolling_cov_by_impl(self.as_series(), other, by, options, true)
+    }
 }
 
 impl SeriesOpsTime for Series {}
@@ -0,0 +1,214 @@
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
+
+use super::*;
+
+/// The co-moments of the pairs in a window, updated with Welford's algorithm as pairs enter and
+/// leave the window.
+///
+/// Unlike computing `E[xy] - E[x]E[y]` from rolling sums, this doesn't lose precision when the
+/// values are large compared to their spread.
+struct CovWindow<'a, T> {
+    x: &'a [T],
+    y: &'a [T],
+    last_start: usize,
+    last_end: usize,
+    n: usize,
+    mean_x: T,
+    mean_y: T,
+    m2_x: T,
+    m2_y: T,
+    c_xy: T,
+}
+
+impl<'a, T: Float> CovWindow<'a, T> {
+    fn new(x: &'a [T], y: &'a [T]) -> Self {
+        Self {
+            x,
+            y,
+            last_start: 0,
+            last_end: 0,
+            n: 0,
+            mean_x: T::zero(),
+            mean_y: T::zero(),
+            m2_x: T::zero(),
+            m2_y: T::zero(),
+            c_xy: T::zero(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.n = 0;
+        self.mean_x = T::zero();
+        self.mean_y = T::zero();
+        self.m2_x = T::zero();
+        self.m2_y = T::zero();
+        self.c_xy = T::zero();
+    }
+
+    fn push(&mut self, x: T, y: T) {
+        self.n += 1;
+        let n: T = NumCast::from(self.n).unwrap();
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x = self.mean_x + dx / n;
+        self.mean_y = self.mean_y + dy / n;
+        self.c_xy = self.c_xy + dx * (y - self.mean_y);
+        self.m2_x = self.m2_x + dx * (x - self.mean_x);
+        self.m2_y = self.m2_y + dy * (y - self.mean_y);
+    }
+
+    fn pop(&mut self, x: T, y: T) {
+        if self.n <= 1 {
+            self.reset();
+            return;
+        }
+        self.n -= 1;
+        let n: T = NumCast::from(self.n).unwrap();
+        // The inverse of `push`: deviations from the means with and without the pair.
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x = self.mean_x - dx / n;
+        self.mean_y = self.mean_y - dy / n;
+        self.c_xy = self.c_xy - (x - self.mean_x) * dy;
+        self.m2_x = self.m2_x - (x - self.mean_x) * dx;
+        self.m2_y = self.m2_y - (y - self.mean_y) * dy;
+    }
+
+    /// Move the window to `start..end`.
+    ///
+    /// # Safety
+    /// `start` and `end` must be within the bounds of the values and must not be smaller than
+    /// in the previous call.
+    unsafe fn update(&mut self, start: usize, end: usize) {
+        if start >= self.last_end || self.n == 0 {
+            // No overlap with the previous window, so start from scratch, which also drops any
+            // accumulated rounding error.
+            self.reset();
+            for i in start..end {
+                self.push(*self.x.get_unchecked(i), *self.y.get_unchecked(i));
+            }
+        } else {
+            for i in self.last_start..start {
+                self.pop(*self.x.get_unchecked(i), *self.y.get_unchecked(i));
+            }
+            for i in self.last_end..end {
+                self.push(*self.x.get_unchecked(i), *self.y.get_unchecked(i));
+            }
+        }
+        self.last_start = start;
+        self.last_end = end;
+    }
+
+    fn cov(&self, ddof: u8) -> Option<T> {
+        let denom = self.n.checked_sub(ddof as usize).filter(|d| *d > 0)?;
+        let denom: T = NumCast::from(denom).unwrap();
+        Some(self.c_xy / denom)
+    }
+
+    fn corr(&self) -> Option<T> {
+        if self.n == 0 {
+            return None;
+        }
+        // The co-moments can get slightly negative due to rounding.
+        let var = self.m2_x.max(T::zero()) * self.m2_y.max(T::zero());
+        Some(self.c_xy / var.sqrt())
+    }
+}
+
+fn rolling_apply_cov_window<T, O>(
+    x: &[T],
+    y: &[T],
+    offsets: O,
+    min_periods: usize,
+    sorting_indices: Option<&[IdxSize]>,
+    finish: impl Fn(&CovWindow<T>) -> Option<T>,
+) -> PolarsResult<ArrayRef>
+where
+    O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
+    T: NativeType + Float,
+{
+    let mut window = CovWindow::new(x, y);
+    let out = offsets
+        .map(|result| {
+            result.map(|(start, len)| {
+                if len < (min_periods as IdxSize) {
+                    None
+                } else {
+                    // SAFETY: the offsets are in bounds and increasing, as `time` is sorted.
+                    unsafe { window.update(start as usize, (start + len) as usize) };
+                    finish(&window)
+                }
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let out = match sorting_indices {
+        None => out,
+        Some(sorting_indices) => {
+            let mut scattered = vec![None; out.len()];
+            for (v, idx) in out.into_iter().zip(sorting_indices) {
+                scattered[*idx as usize] = v;
+            }
+            scattered
+        },
+    };
+    Ok(Box::new(PrimitiveArray::from(out)))
+}
+
+/// The rolling covariance of `x` and `y` with windows determined by `time`.
+///
+/// If `time` isn't sorted, the values and `time` must have been sorted with `sorting_indices`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_cov<T>(
+    x: &[T],
+    y: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    ddof: u8,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    rolling_apply_cov_window(x, y, offset_iter, min_periods, sorting_indices, |w| {
+        w.cov(ddof)
+    })
+}
+
+/// The rolling pearson correlation of `x` and `y` with windows determined by `time`.
+///
+/// If `time` isn't sorted, the values and `time` must have been sorted with `sorting_indices`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_corr<T>(
+    x: &[T],
+    y: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    rolling_apply_cov_window(x, y, offset_iter, min_periods, sorting_indices, |w| {
+        w.corr()
+    })
+}
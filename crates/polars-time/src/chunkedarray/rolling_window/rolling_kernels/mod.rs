@@ -1,3 +1,4 @@
+pub(super) mod covariance;
 pub(super) mod no_nulls;
 use std::fmt::Debug;
 use std::ops::{AddAssign, Mul, SubAssign};
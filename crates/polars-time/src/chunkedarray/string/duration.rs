@@ -0,0 +1,173 @@
+use polars_core::prelude::TimeUnit;
+
+const NS_SECOND: i128 = 1_000_000_000;
+const NS_MINUTE: i128 = 60 * NS_SECOND;
+const NS_HOUR: i128 = 60 * NS_MINUTE;
+const NS_DAY: i128 = 24 * NS_HOUR;
+const NS_WEEK: i128 = 7 * NS_DAY;
+
+/// Parse an ISO 8601 duration (e.g. `P1DT2H3M4.5S`) or a humanized duration (e.g. `1d 2h 3m`)
+/// into an integer in the given [`TimeUnit`].
+///
+/// Years and months are not supported in ISO 8601 durations, as their length is not fixed.
+pub(super) fn parse_duration(s: &str, tu: TimeUnit) -> Option<i64> {
+    let s = s.trim();
+    let ns = if strip_sign(s).1.starts_with('P') {
+        parse_iso(s)?
+    } else {
+        parse_humanized(s)?
+    };
+    let v = match tu {
+        TimeUnit::Nanoseconds => ns,
+        TimeUnit::Microseconds => ns / 1_000,
+        TimeUnit::Milliseconds => ns / 1_000_000,
+    };
+    i64::try_from(v).ok()
+}
+
+fn strip_sign(s: &str) -> (bool, &str) {
+    match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    }
+}
+
+/// Split e.g. `1H30M` into `[("1", 'H'), ("30", 'M')]`.
+fn iso_components(s: &str) -> Option<Vec<(&str, char)>> {
+    let mut out = vec![];
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if c.is_ascii_alphabetic() {
+            if i == start {
+                return None;
+            }
+            out.push((&s[start..i], c));
+            start = i + 1;
+        }
+    }
+    (start == s.len()).then_some(out)
+}
+
+fn parse_integer(s: &str) -> Option<i128> {
+    if !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+fn parse_seconds(s: &str) -> Option<i128> {
+    let (whole, frac) = match s.find(['.', ',']) {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => (s, ""),
+    };
+    if frac.len() > 9 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let frac = if frac.is_empty() {
+        0
+    } else {
+        frac.parse::<i128>().ok()? * 10i128.pow(9 - frac.len() as u32)
+    };
+    parse_integer(whole)?
+        .checked_mul(NS_SECOND)?
+        .checked_add(frac)
+}
+
+fn parse_iso(s: &str) -> Option<i128> {
+    let (negative, s) = strip_sign(s);
+    let s = s.strip_prefix('P')?;
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+
+    let mut total = 0i128;
+    let mut n_components = 0;
+    for (value, unit) in iso_components(date)? {
+        let unit_ns = match unit {
+            'W' => NS_WEEK,
+            'D' => NS_DAY,
+            _ => return None,
+        };
+        total = total.checked_add(parse_integer(value)?.checked_mul(unit_ns)?)?;
+        n_components += 1;
+    }
+    if let Some(time) = time {
+        let components = iso_components(time)?;
+        if components.is_empty() {
+            return None;
+        }
+        for (value, unit) in components {
+            let ns = match unit {
+                'H' => parse_integer(value)?.checked_mul(NS_HOUR)?,
+                'M' => parse_integer(value)?.checked_mul(NS_MINUTE)?,
+                'S' => parse_seconds(value)?,
+                _ => return None,
+            };
+            total = total.checked_add(ns)?;
+            n_components += 1;
+        }
+    }
+    if n_components == 0 {
+        return None;
+    }
+    Some(if negative { -total } else { total })
+}
+
+fn humanized_unit_ns(unit: &str) -> Option<i128> {
+    Some(match unit {
+        "ns" => 1,
+        "us" | "\u{b5}s" | "\u{3bc}s" => 1_000,
+        "ms" => 1_000_000,
+        "s" => NS_SECOND,
+        "m" => NS_MINUTE,
+        "h" => NS_HOUR,
+        "d" => NS_DAY,
+        "w" => NS_WEEK,
+        _ => return None,
+    })
+}
+
+/// Parse the form that is used to display durations, e.g. `1d 2h 3m 4s 500ms` or `-1d -2h`.
+fn parse_humanized(s: &str) -> Option<i128> {
+    if s.is_empty() {
+        return None;
+    }
+    let mut total = 0i128;
+    let mut rest = s;
+    while !rest.is_empty() {
+        let (negative, r) = strip_sign(rest);
+        let digits_end = r.find(|c: char| !c.is_ascii_digit()).unwrap_or(r.len());
+        let value = parse_integer(&r[..digits_end]).filter(|_| digits_end > 0)?;
+        let r = &r[digits_end..];
+        let unit_end = r.find(|c: char| !c.is_alphabetic()).unwrap_or(r.len());
+        let value = value.checked_mul(humanized_unit_ns(&r[..unit_end])?)?;
+        total = total.checked_add(if negative { -value } else { value })?;
+        rest = r[unit_end..].trim_start();
+    }
+    Some(total)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        let tu = TimeUnit::Milliseconds;
+        assert_eq!(parse_duration("P1DT2H3M4.5S", tu), Some(93_784_500));
+        assert_eq!(parse_duration("1d 2h 3m 4s 500ms", tu), Some(93_784_500));
+        assert_eq!(parse_duration("-PT1M", tu), Some(-60_000));
+        assert_eq!(parse_duration("-1m -30s", tu), Some(-90_000));
+        assert_eq!(parse_duration("P1W", tu), Some(604_800_000));
+        assert_eq!(parse_duration("PT0S", tu), Some(0));
+        assert_eq!(parse_duration("1µs", TimeUnit::Nanoseconds), Some(1_000));
+
+        assert_eq!(parse_duration("P1M", tu), None);
+        assert_eq!(parse_duration("PT", tu), None);
+        assert_eq!(parse_duration("P", tu), None);
+        assert_eq!(parse_duration("1x", tu), None);
+        assert_eq!(parse_duration("", tu), None);
+    }
+}
@@ -5,6 +5,8 @@ use once_cell::sync::Lazy;
 use polars_core::prelude::*;
 use regex::Regex;
 
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+use super::iso8601;
 use super::patterns::{self, Pattern};
 #[cfg(feature = "dtype-date")]
 use crate::chunkedarray::date::naive_date_to_date;
@@ -143,6 +145,9 @@ pub trait StrpTimeParser<T> {
 #[cfg(feature = "dtype-datetime")]
 impl StrpTimeParser<i64> for DatetimeInfer<Int64Type> {
     fn parse_bytes(&mut self, val: &[u8], time_unit: Option<TimeUnit>) -> Option<i64> {
+        if let Some(parsed) = self.iso8601_transform.and_then(|transform| transform(val)) {
+            return Some(parsed);
+        }
         if self.fmt_len == 0 {
             self.fmt_len = strptime::fmt_len(self.latest_fmt.as_bytes())?;
         }
@@ -181,6 +186,9 @@ impl StrpTimeParser<i64> for DatetimeInfer<Int64Type> {
 #[cfg(feature = "dtype-date")]
 impl StrpTimeParser<i32> for DatetimeInfer<Int32Type> {
     fn parse_bytes(&mut self, val: &[u8], _time_unit: Option<TimeUnit>) -> Option<i32> {
+        if let Some(parsed) = self.iso8601_transform.and_then(|transform| transform(val)) {
+            return Some(parsed);
+        }
         if self.fmt_len == 0 {
             self.fmt_len = strptime::fmt_len(self.latest_fmt.as_bytes())?;
         }
@@ -216,6 +224,8 @@ pub struct DatetimeInfer<T: PolarsNumericType> {
     patterns: &'static [&'static str],
     latest_fmt: &'static str,
     transform: fn(&str, &str) -> Option<T::Native>,
+    /// The dedicated ISO 8601 parser of the pattern, which is tried before the formats.
+    iso8601_transform: Option<fn(&[u8]) -> Option<T::Native>>,
     transform_bytes: StrpTimeState,
     fmt_len: u16,
     pub logical_type: DataType,
@@ -241,6 +251,22 @@ impl TryFromWithUnit<Pattern> for DatetimeInfer<Int64Type> {
             (TimeUnit::Nanoseconds, Pattern::DatetimeYMDZ) => transform_tzaware_datetime_ns,
             (TimeUnit::Nanoseconds, _) => transform_datetime_ns,
         };
+        // The day first patterns are not ISO 8601.
+        let iso8601_transform: Option<fn(&[u8]) -> Option<i64>> = match (time_unit, value) {
+            (_, Pattern::DatetimeDMY | Pattern::DateDMY) => None,
+            (TimeUnit::Milliseconds, Pattern::DatetimeYMDZ) => {
+                Some(iso8601::transform_tzaware_datetime_ms)
+            },
+            (TimeUnit::Milliseconds, _) => Some(iso8601::transform_datetime_ms),
+            (TimeUnit::Microseconds, Pattern::DatetimeYMDZ) => {
+                Some(iso8601::transform_tzaware_datetime_us)
+            },
+            (TimeUnit::Microseconds, _) => Some(iso8601::transform_datetime_us),
+            (TimeUnit::Nanoseconds, Pattern::DatetimeYMDZ) => {
+                Some(iso8601::transform_tzaware_datetime_ns)
+            },
+            (TimeUnit::Nanoseconds, _) => Some(iso8601::transform_datetime_ns),
+        };
         let (pattern, patterns) = match value {
             Pattern::DatetimeDMY | Pattern::DateDMY => {
                 (Pattern::DatetimeDMY, patterns::DATETIME_D_M_Y)
@@ -256,6 +282,7 @@ impl TryFromWithUnit<Pattern> for DatetimeInfer<Int64Type> {
             patterns,
             latest_fmt: patterns[0],
             transform,
+            iso8601_transform,
             transform_bytes: StrpTimeState::default(),
             fmt_len: 0,
             logical_type: DataType::Datetime(time_unit, None),
@@ -274,6 +301,7 @@ impl TryFromWithUnit<Pattern> for DatetimeInfer<Int32Type> {
                 patterns: patterns::DATE_D_M_Y,
                 latest_fmt: patterns::DATE_D_M_Y[0],
                 transform: transform_date,
+                iso8601_transform: None,
                 transform_bytes: StrpTimeState::default(),
                 fmt_len: 0,
                 logical_type: DataType::Date,
@@ -283,6 +311,7 @@ impl TryFromWithUnit<Pattern> for DatetimeInfer<Int32Type> {
                 patterns: patterns::DATE_Y_M_D,
                 latest_fmt: patterns::DATE_Y_M_D[0],
                 transform: transform_date,
+                iso8601_transform: Some(iso8601::transform_date),
                 transform_bytes: StrpTimeState::default(),
                 fmt_len: 0,
                 logical_type: DataType::Date,
@@ -294,6 +323,12 @@ impl TryFromWithUnit<Pattern> for DatetimeInfer<Int32Type> {
 
 impl<T: PolarsNumericType> DatetimeInfer<T> {
     pub fn parse(&mut self, val: &str) -> Option<T::Native> {
+        if let Some(parsed) = self
+            .iso8601_transform
+            .and_then(|transform| transform(val.as_bytes()))
+        {
+            return Some(parsed);
+        }
         match (self.transform)(val, self.latest_fmt) {
             Some(parsed) => Some(parsed),
             // try other patterns
@@ -0,0 +1,305 @@
+//! A dedicated parser of ISO 8601 and RFC 3339 datetimes, like `2021-01-01T07:45:12.123+01:00`.
+//!
+//! Unlike the format based parsers, the fields are at fixed positions up to the seconds, so every
+//! field is parsed in a fixed number of steps, without a format to interpret. Values that are not
+//! ISO 8601 are left to the format based parsers.
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use polars_core::prelude::*;
+
+/// A datetime parsed by [`parse`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) struct Iso8601 {
+    /// The local datetime.
+    pub(super) datetime: NaiveDateTime,
+    /// The separator of the date and the time, `None` if there is only a date.
+    pub(super) separator: Option<u8>,
+    pub(super) has_seconds: bool,
+    /// The offset from UTC in seconds, if there is one.
+    pub(super) offset: Option<i32>,
+}
+
+impl Iso8601 {
+    /// The datetime in UTC, if there is an offset.
+    fn utc(&self) -> Option<NaiveDateTime> {
+        let offset = self.offset?;
+        self.datetime
+            .checked_sub_signed(Duration::seconds(offset as i64))
+    }
+}
+
+/// Parse the `N` ASCII digits at the start of `bytes`.
+///
+/// The digits are validated and accumulated without branching on them, so the loop is unrolled.
+#[inline(always)]
+fn digits<const N: usize>(bytes: &[u8]) -> Option<u32> {
+    let bytes: &[u8; N] = bytes.get(..N)?.try_into().ok()?;
+    let mut valid = true;
+    let mut out = 0u32;
+    for &b in bytes {
+        let digit = b.wrapping_sub(b'0');
+        valid &= digit < 10;
+        out = out.wrapping_mul(10).wrapping_add(digit as u32);
+    }
+    valid.then_some(out)
+}
+
+/// Parse `YYYY-MM-DD`.
+#[inline]
+pub(super) fn parse_date(val: &[u8]) -> Option<NaiveDate> {
+    if val.len() < 10 || val[4] != b'-' || val[7] != b'-' {
+        return None;
+    }
+    let year = digits::<4>(val)?;
+    let month = digits::<2>(&val[5..])?;
+    let day = digits::<2>(&val[8..])?;
+    NaiveDate::from_ymd_opt(year as i32, month, day)
+}
+
+/// Parse the offset from UTC at the end of a datetime: `Z`, `±HH`, `±HHMM` or `±HH:MM`.
+#[inline]
+fn parse_offset(val: &[u8]) -> Option<i32> {
+    let sign = match val.first()? {
+        b'Z' if val.len() == 1 => return Some(0),
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let hours = digits::<2>(&val[1..])?;
+    let minutes = match val.len() {
+        3 => 0,
+        5 => digits::<2>(&val[3..])?,
+        6 if val[3] == b':' => digits::<2>(&val[4..])?,
+        _ => return None,
+    };
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(sign * (hours * 3600 + minutes * 60) as i32)
+}
+
+/// Parse an ISO 8601 datetime: `YYYY-MM-DD`, optionally followed by `T` or a space and
+/// `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fffffffff`, optionally followed by an offset from UTC.
+/// Fractions of more than 9 digits are truncated to nanoseconds.
+pub(super) fn parse(val: &[u8]) -> Option<Iso8601> {
+    let date = parse_date(val)?;
+    if val.len() == 10 {
+        return Some(Iso8601 {
+            datetime: date.and_time(NaiveTime::MIN),
+            separator: None,
+            has_seconds: false,
+            offset: None,
+        });
+    }
+
+    let separator = val[10];
+    if !matches!(separator, b'T' | b' ') || val.len() < 16 || val[13] != b':' {
+        return None;
+    }
+    let hour = digits::<2>(&val[11..])?;
+    let min = digits::<2>(&val[14..])?;
+
+    let mut sec = 0;
+    let mut nano = 0;
+    let mut end = 16;
+    let has_seconds = val.get(16) == Some(&b':');
+    if has_seconds {
+        sec = digits::<2>(&val[17..])?;
+        end = 19;
+        if val.get(19) == Some(&b'.') {
+            let n_digits = val[20..].iter().take_while(|b| b.is_ascii_digit()).count();
+            if n_digits == 0 {
+                return None;
+            }
+            for (i, &b) in val[20..20 + n_digits.min(9)].iter().enumerate() {
+                nano += (b - b'0') as u32 * 10u32.pow(8 - i as u32);
+            }
+            end = 20 + n_digits;
+        }
+    }
+
+    let offset = if end == val.len() {
+        None
+    } else {
+        Some(parse_offset(&val[end..])?)
+    };
+    let time = NaiveTime::from_hms_nano_opt(hour, min, sec, nano)?;
+    Some(Iso8601 {
+        datetime: date.and_time(time),
+        separator: Some(separator),
+        has_seconds,
+        offset,
+    })
+}
+
+/// The ISO 8601 formats that are parsed by [`parse`] instead of by their format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum Iso8601Format {
+    /// `%Y-%m-%dT%H:%M:%S%.f` or `%Y-%m-%d %H:%M:%S%.f`.
+    Naive { separator: u8 },
+    /// `%+`, RFC 3339.
+    Rfc3339,
+}
+
+impl Iso8601Format {
+    pub(super) fn from_fmt(fmt: &str) -> Option<Self> {
+        match fmt {
+            "%Y-%m-%dT%H:%M:%S%.f" => Some(Iso8601Format::Naive { separator: b'T' }),
+            "%Y-%m-%d %H:%M:%S%.f" => Some(Iso8601Format::Naive { separator: b' ' }),
+            "%+" => Some(Iso8601Format::Rfc3339),
+            _ => None,
+        }
+    }
+
+    /// Parse `val` if it has this format, the datetime is in UTC for [`Iso8601Format::Rfc3339`].
+    #[inline]
+    pub(super) fn parse(self, val: &[u8]) -> Option<NaiveDateTime> {
+        let parsed = parse(val)?;
+        if !parsed.has_seconds {
+            return None;
+        }
+        match self {
+            Iso8601Format::Naive { separator } => (parsed.separator == Some(separator)
+                && parsed.offset.is_none())
+            .then_some(parsed.datetime),
+            Iso8601Format::Rfc3339 => {
+                if parsed.separator != Some(b'T') {
+                    return None;
+                }
+                parsed.utc()
+            },
+        }
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+pub(super) fn transform_date(val: &[u8]) -> Option<i32> {
+    if val.len() != 10 {
+        return None;
+    }
+    parse_date(val).map(crate::chunkedarray::date::naive_date_to_date)
+}
+
+/// Parse a datetime without an offset, as the patterns of `Pattern::DatetimeYMD` do.
+#[inline]
+fn naive(val: &[u8]) -> Option<NaiveDateTime> {
+    let parsed = parse(val)?;
+    parsed.offset.is_none().then_some(parsed.datetime)
+}
+
+/// Parse a datetime with an offset to UTC, as the patterns of `Pattern::DatetimeYMDZ` do.
+#[inline]
+fn utc(val: &[u8]) -> Option<NaiveDateTime> {
+    let parsed = parse(val)?;
+    if parsed.separator.is_none() {
+        return None;
+    }
+    parsed.utc()
+}
+
+#[cfg(feature = "dtype-datetime")]
+pub(super) fn transform_datetime_ns(val: &[u8]) -> Option<i64> {
+    naive(val).map(datetime_to_timestamp_ns)
+}
+
+#[cfg(feature = "dtype-datetime")]
+pub(super) fn transform_datetime_us(val: &[u8]) -> Option<i64> {
+    naive(val).map(datetime_to_timestamp_us)
+}
+
+#[cfg(feature = "dtype-datetime")]
+pub(super) fn transform_datetime_ms(val: &[u8]) -> Option<i64> {
+    naive(val).map(datetime_to_timestamp_ms)
+}
+
+#[cfg(feature = "dtype-datetime")]
+pub(super) fn transform_tzaware_datetime_ns(val: &[u8]) -> Option<i64> {
+    utc(val).map(datetime_to_timestamp_ns)
+}
+
+#[cfg(feature = "dtype-datetime")]
+pub(super) fn transform_tzaware_datetime_us(val: &[u8]) -> Option<i64> {
+    utc(val).map(datetime_to_timestamp_us)
+}
+
+#[cfg(feature = "dtype-datetime")]
+pub(super) fn transform_tzaware_datetime_ms(val: &[u8]) -> Option<i64> {
+    utc(val).map(datetime_to_timestamp_ms)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ndt(ymd: (i32, u32, u32), hms: (u32, u32, u32), nano: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2)
+            .unwrap()
+            .and_hms_nano_opt(hms.0, hms.1, hms.2, nano)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_iso8601() {
+        let cases = [
+            ("2021-01-01", Some((ndt((2021, 1, 1), (0, 0, 0), 0), None))),
+            (
+                "2021-01-01T07:45",
+                Some((ndt((2021, 1, 1), (7, 45, 0), 0), None)),
+            ),
+            (
+                "2021-01-01 07:45:12",
+                Some((ndt((2021, 1, 1), (7, 45, 12), 0), None)),
+            ),
+            (
+                "2019-04-18T02:45:55.5",
+                Some((ndt((2019, 4, 18), (2, 45, 55), 500_000_000), None)),
+            ),
+            (
+                "2019-04-18T02:45:55.1234567891",
+                Some((ndt((2019, 4, 18), (2, 45, 55), 123_456_789), None)),
+            ),
+            (
+                "2019-04-18T02:45:55Z",
+                Some((ndt((2019, 4, 18), (2, 45, 55), 0), Some(0))),
+            ),
+            (
+                "2019-04-18T02:45:55.123-05:30",
+                Some((ndt((2019, 4, 18), (2, 45, 55), 123_000_000), Some(-19800))),
+            ),
+            (
+                "2019-04-18T02:45+0100",
+                Some((ndt((2019, 4, 18), (2, 45, 0), 0), Some(3600))),
+            ),
+            ("2019-04-18T02:45:55.", None),
+            ("2019-04-18T02:45:55+01:0", None),
+            ("2019-02-30", None),
+            ("2019-04-18T25:00:00", None),
+            ("2019/04/18", None),
+            ("2019-4-18", None),
+            ("2019-04-18X02:45", None),
+        ];
+        for (val, expected) in cases {
+            let parsed = parse(val.as_bytes()).map(|p| (p.datetime, p.offset));
+            assert_eq!(parsed, expected, "{val}");
+        }
+    }
+
+    #[test]
+    fn test_iso8601_format() {
+        let rfc3339 = Iso8601Format::from_fmt("%+").unwrap();
+        assert_eq!(
+            rfc3339.parse(b"2019-04-18T02:45:55+01:00"),
+            Some(ndt((2019, 4, 18), (1, 45, 55), 0))
+        );
+        assert_eq!(rfc3339.parse(b"2019-04-18T02:45:55"), None);
+        assert_eq!(rfc3339.parse(b"2019-04-18 02:45:55Z"), None);
+
+        let naive = Iso8601Format::from_fmt("%Y-%m-%dT%H:%M:%S%.f").unwrap();
+        assert_eq!(
+            naive.parse(b"2019-04-18T02:45:55.25"),
+            Some(ndt((2019, 4, 18), (2, 45, 55), 250_000_000))
+        );
+        assert_eq!(naive.parse(b"2019-04-18T02:45"), None);
+        assert_eq!(naive.parse(b"2019-04-18T02:45:55Z"), None);
+        assert!(Iso8601Format::from_fmt("%Y-%m-%d").is_none());
+    }
+}
@@ -1,4 +1,9 @@
+#[cfg(feature = "dtype-duration")]
+mod duration;
 pub mod infer;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+#[cfg_attr(not(feature = "dtype-datetime"), allow(dead_code))]
+mod iso8601;
 use chrono::DateTime;
 mod patterns;
 mod strptime;
@@ -110,6 +115,16 @@ pub trait StringMethods: AsString {
         Ok(ca.with_name(string_ca.name()).into())
     }
 
+    #[cfg(feature = "dtype-duration")]
+    /// Parsing ISO 8601 (e.g. `P1DT2H`) or humanized (e.g. `1d 2h 3m`) duration strings and
+    /// return a [`DurationChunked`]
+    fn as_duration(&self, tu: TimeUnit) -> PolarsResult<DurationChunked> {
+        let string_ca = self.as_string();
+        let ca: Int64Chunked =
+            string_ca.apply_generic(|opt_s| duration::parse_duration(opt_s?, tu));
+        Ok(ca.with_name(string_ca.name()).into_duration(tu))
+    }
+
     #[cfg(feature = "dtype-date")]
     /// Parsing string values and return a [`DateChunked`]
     /// Different from `as_date` this function allows matches that not contain the whole string
@@ -274,6 +289,8 @@ pub trait StringMethods: AsString {
         };
         let fmt = strptime::compile_fmt(fmt)?;
         let use_cache = use_cache && string_ca.len() > 50;
+        // The ISO 8601 formats are parsed by the dedicated parser, the format is the fallback.
+        let iso8601_fmt = iso8601::Iso8601Format::from_fmt(&fmt);
 
         let func = match tu {
             TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
@@ -286,6 +303,9 @@ pub trait StringMethods: AsString {
             {
                 let mut convert = FastCachedFunc::new(
                     |s: &str| {
+                        if let Some(ndt) = iso8601_fmt.and_then(|f| f.parse(s.as_bytes())) {
+                            return Some(func(ndt));
+                        }
                         let dt = DateTime::parse_from_str(s, &fmt).ok()?;
                         Some(func(dt.naive_utc()))
                     },
@@ -326,7 +346,10 @@ pub trait StringMethods: AsString {
                 string_ca.apply_generic(|opt_s| convert.eval(opt_s?, use_cache))
             } else {
                 let mut convert = FastCachedFunc::new(
-                    |s| transform(s, &fmt),
+                    |s: &str| match iso8601_fmt.and_then(|f| f.parse(s.as_bytes())) {
+                        Some(ndt) => Some(func(ndt)),
+                        None => transform(s, &fmt),
+                    },
                     (string_ca.len() as f64).sqrt() as usize,
                 );
                 string_ca.apply_generic(|opt_s| convert.eval(opt_s?, use_cache))
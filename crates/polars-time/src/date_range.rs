@@ -101,6 +101,32 @@ pub(crate) fn datetime_range_i64(
     closed: ClosedWindow,
     tu: TimeUnit,
     tz: Option<&Tz>,
+) -> PolarsResult<Vec<i64>> {
+    datetime_range_i64_with_dst(
+        start,
+        end,
+        interval,
+        closed,
+        tu,
+        tz,
+        Ambiguous::Raise,
+        NonExistent::Raise,
+    )
+}
+
+/// vector of i64 representing temporal values, where values which are ambiguous or
+/// non-existent in `tz` are localized according to `ambiguous` and `non_existent`.
+/// Values which would be localized to null are skipped.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn datetime_range_i64_with_dst(
+    start: i64,
+    end: i64,
+    interval: Duration,
+    closed: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> PolarsResult<Vec<i64>> {
     if start > end {
         return Ok(Vec::new());
@@ -110,46 +136,31 @@ pub(crate) fn datetime_range_i64(
         ComputeError: "`interval` must be positive"
     );
 
-    let size: usize;
-    let offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
-
-    match tu {
-        TimeUnit::Nanoseconds => {
-            size = ((end - start) / interval.duration_ns() + 1) as usize;
-            offset_fn = Duration::add_ns;
-        },
-        TimeUnit::Microseconds => {
-            size = ((end - start) / interval.duration_us() + 1) as usize;
-            offset_fn = Duration::add_us;
-        },
-        TimeUnit::Milliseconds => {
-            size = ((end - start) / interval.duration_ms() + 1) as usize;
-            offset_fn = Duration::add_ms;
-        },
-    }
+    let size = match tu {
+        TimeUnit::Nanoseconds => ((end - start) / interval.duration_ns() + 1) as usize,
+        TimeUnit::Microseconds => ((end - start) / interval.duration_us() + 1) as usize,
+        TimeUnit::Milliseconds => ((end - start) / interval.duration_ms() + 1) as usize,
+    };
     let mut ts = Vec::with_capacity(size);
 
     let mut i = match closed {
         ClosedWindow::Both | ClosedWindow::Left => 0,
         ClosedWindow::Right | ClosedWindow::None => 1,
     };
-    let mut t = offset_fn(&(interval * i), start, tz)?;
-    i += 1;
-    match closed {
-        ClosedWindow::Both | ClosedWindow::Right => {
-            while t <= end {
-                ts.push(t);
-                t = offset_fn(&(interval * i), start, tz)?;
-                i += 1;
-            }
-        },
-        ClosedWindow::Left | ClosedWindow::None => {
-            while t < end {
-                ts.push(t);
-                t = offset_fn(&(interval * i), start, tz)?;
-                i += 1;
-            }
-        },
+    loop {
+        let t = (interval * i).add_with_dst(start, tu, tz, ambiguous, non_existent)?;
+        i += 1;
+        let Some(t) = t else {
+            continue;
+        };
+        let in_range = match closed {
+            ClosedWindow::Both | ClosedWindow::Right => t <= end,
+            ClosedWindow::Left | ClosedWindow::None => t < end,
+        };
+        if !in_range {
+            break;
+        }
+        ts.push(t);
     }
     debug_assert!(size >= ts.len());
     Ok(ts)
@@ -0,0 +1,228 @@
+//! Arithmetic of `Duration` columns with numeric and `Duration` columns, in an explicit time unit
+//! and checked for overflow.
+use polars_core::prelude::arity::broadcast_try_binary_elementwise;
+use polars_core::prelude::*;
+
+fn nanoseconds_per_unit(tu: TimeUnit) -> i128 {
+    match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+/// The result of an operation that overflowed: an error if `strict`, else a null.
+fn overflow(op: &str, strict: bool) -> PolarsResult<Option<i64>> {
+    polars_ensure!(
+        !strict,
+        ComputeError: "overflow in duration {}, the result doesn't fit in 64 bits; try a coarser \
+        `time_unit` or setting `strict=false` to get nulls", op
+    );
+    Ok(None)
+}
+
+/// Round a float number of time units to the nearest integer, `None` if it doesn't fit.
+fn f64_to_i64(value: f64) -> Option<i64> {
+    let value = value.round();
+    (value.is_finite() && value >= i64::MIN as f64 && value < i64::MAX as f64)
+        .then_some(value as i64)
+}
+
+fn ensure_lengths(lhs: &Series, rhs: &Series) -> PolarsResult<()> {
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        ShapeMismatch: "cannot combine a duration of length {} with a column of length {}",
+        lhs.len(), rhs.len()
+    );
+    Ok(())
+}
+
+fn integer_factor(s: &Series, strict: bool) -> PolarsResult<Series> {
+    if strict {
+        s.strict_cast(&DataType::Int64)
+    } else {
+        s.cast(&DataType::Int64)
+    }
+}
+
+/// Multiply the `duration` by the integer or float `factor`, in `time_unit`, the time unit of
+/// the `duration` by default.
+///
+/// A null in either column results in a null, and a column with a single value is broadcast. A
+/// float result is rounded to the nearest `time_unit`. A result that overflows is an error if
+/// `strict`, else a null.
+pub fn duration_mul(
+    duration: &Series,
+    factor: &Series,
+    time_unit: Option<TimeUnit>,
+    strict: bool,
+) -> PolarsResult<Series> {
+    let ca = duration.duration()?;
+    ensure_lengths(duration, factor)?;
+    let tu = time_unit.unwrap_or(ca.time_unit());
+    let num = nanoseconds_per_unit(ca.time_unit());
+    let den = nanoseconds_per_unit(tu);
+
+    let out: Int64Chunked = match factor.dtype() {
+        dt if dt.is_integer() => {
+            let factor = integer_factor(factor, strict)?;
+            broadcast_try_binary_elementwise(&ca.0, factor.i64()?, |v, f| match (v, f) {
+                (Some(v), Some(f)) => {
+                    let out = (v as i128)
+                        .checked_mul(f as i128)
+                        .and_then(|v| v.checked_mul(num))
+                        .and_then(|v| i64::try_from(v / den).ok());
+                    match out {
+                        Some(out) => Ok(Some(out)),
+                        None => overflow("multiplication", strict),
+                    }
+                },
+                _ => Ok(None),
+            })?
+        },
+        dt if dt.is_float() => {
+            let factor = factor.cast(&DataType::Float64)?;
+            let scale = num as f64 / den as f64;
+            broadcast_try_binary_elementwise(&ca.0, factor.f64()?, |v, f| match (v, f) {
+                (Some(v), Some(f)) => match f64_to_i64(v as f64 * f * scale) {
+                    Some(out) => Ok(Some(out)),
+                    None => overflow("multiplication", strict),
+                },
+                _ => Ok(None),
+            })?
+        },
+        dt => polars_bail!(opq = mul, duration.dtype(), dt),
+    };
+    Ok(out
+        .with_name(duration.name())
+        .into_duration(tu)
+        .into_series())
+}
+
+/// Divide the `duration` by the `divisor`.
+///
+/// Divided by a `Duration`, the result is the `Float64` ratio of the durations, whatever their
+/// time units. Divided by an integer or a float, the result is a `Duration` in `time_unit`, the
+/// time unit of the `duration` by default. An integer division truncates towards zero, a float
+/// division is rounded to the nearest `time_unit`.
+///
+/// A null in either column or a division by zero results in a null, and a column with a single
+/// value is broadcast. A result that overflows is an error if `strict`, else a null.
+pub fn duration_div(
+    duration: &Series,
+    divisor: &Series,
+    time_unit: Option<TimeUnit>,
+    strict: bool,
+) -> PolarsResult<Series> {
+    let ca = duration.duration()?;
+    ensure_lengths(duration, divisor)?;
+    let tu = time_unit.unwrap_or(ca.time_unit());
+    let num = nanoseconds_per_unit(ca.time_unit());
+    let den = nanoseconds_per_unit(tu);
+
+    let out: Int64Chunked = match divisor.dtype() {
+        DataType::Duration(divisor_tu) => {
+            let divisor = divisor.duration()?;
+            let divisor_num = nanoseconds_per_unit(*divisor_tu);
+            let out: Float64Chunked =
+                broadcast_try_binary_elementwise(&ca.0, &divisor.0, |v, d| match (v, d) {
+                    (Some(v), Some(d)) if d != 0 => Ok::<_, PolarsError>(Some(
+                        (v as f64 * num as f64) / (d as f64 * divisor_num as f64),
+                    )),
+                    _ => Ok(None),
+                })?;
+            return Ok(out.with_name(duration.name()).into_series());
+        },
+        dt if dt.is_integer() => {
+            let divisor = integer_factor(divisor, strict)?;
+            broadcast_try_binary_elementwise(&ca.0, divisor.i64()?, |v, d| match (v, d) {
+                (Some(v), Some(d)) if d != 0 => {
+                    let out = (v as i128)
+                        .checked_mul(num)
+                        .and_then(|v| i64::try_from(v / (den * d as i128)).ok());
+                    match out {
+                        Some(out) => Ok(Some(out)),
+                        None => overflow("division", strict),
+                    }
+                },
+                _ => Ok(None),
+            })?
+        },
+        dt if dt.is_float() => {
+            let divisor = divisor.cast(&DataType::Float64)?;
+            let scale = num as f64 / den as f64;
+            broadcast_try_binary_elementwise(&ca.0, divisor.f64()?, |v, d| match (v, d) {
+                (Some(v), Some(d)) if d != 0.0 => match f64_to_i64(v as f64 * scale / d) {
+                    Some(out) => Ok(Some(out)),
+                    None => overflow("division", strict),
+                },
+                _ => Ok(None),
+            })?
+        },
+        dt => polars_bail!(opq = div, duration.dtype(), dt),
+    };
+    Ok(out
+        .with_name(duration.name())
+        .into_duration(tu)
+        .into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn duration(values: &[Option<i64>], tu: TimeUnit) -> Series {
+        Int64Chunked::from_slice_options("d", values)
+            .into_duration(tu)
+            .into_series()
+    }
+
+    #[test]
+    fn test_duration_mul() -> PolarsResult<()> {
+        let d = duration(&[Some(1500), None, Some(-3)], TimeUnit::Milliseconds);
+
+        let factor = Series::new("f", &[Some(1.5), Some(2.0), None]);
+        let out = duration_mul(&d, &factor, None, true)?;
+        assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(Vec::from(&out.duration()?.0), &[Some(2250), None, None]);
+
+        let factor = Series::new("f", &[3i32]);
+        let out = duration_mul(&d, &factor, Some(TimeUnit::Microseconds), true)?;
+        assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Microseconds));
+        assert_eq!(
+            Vec::from(&out.duration()?.0),
+            &[Some(4_500_000), None, Some(-9000)]
+        );
+
+        let d = duration(&[Some(i64::MAX / 2), Some(1)], TimeUnit::Nanoseconds);
+        let factor = Series::new("f", &[3i64]);
+        assert!(duration_mul(&d, &factor, None, true).is_err());
+        let out = duration_mul(&d, &factor, None, false)?;
+        assert_eq!(Vec::from(&out.duration()?.0), &[None, Some(3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_duration_div() -> PolarsResult<()> {
+        let d = duration(&[Some(3000), None, Some(7)], TimeUnit::Milliseconds);
+
+        let divisor = duration(&[Some(1_500_000)], TimeUnit::Microseconds);
+        let out = duration_div(&d, &divisor, None, true)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(2.0), None, Some(7.0 / 1500.0)]
+        );
+
+        let divisor = Series::new("f", &[Some(2i64), Some(1), Some(0)]);
+        let out = duration_div(&d, &divisor, Some(TimeUnit::Microseconds), true)?;
+        assert_eq!(
+            Vec::from(&out.duration()?.0),
+            &[Some(1_500_000), None, None]
+        );
+
+        let divisor = Series::new("f", &[0.5f64]);
+        let out = duration_div(&d, &divisor, None, true)?;
+        assert_eq!(Vec::from(&out.duration()?.0), &[Some(6000), None, Some(14)]);
+        Ok(())
+    }
+}
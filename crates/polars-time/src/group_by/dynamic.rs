@@ -34,6 +34,12 @@ pub struct DynamicGroupOptions {
     pub include_boundaries: bool,
     pub closed_window: ClosedWindow,
     pub start_by: StartBy,
+    /// How to localize window boundaries which are ambiguous in the time zone
+    /// of the index column.
+    pub ambiguous: Ambiguous,
+    /// How to localize window boundaries which are non-existent in the time zone
+    /// of the index column.
+    pub non_existent: NonExistent,
 }
 
 impl Default for DynamicGroupOptions {
@@ -47,6 +53,8 @@ impl Default for DynamicGroupOptions {
             include_boundaries: false,
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
+            ambiguous: Ambiguous::Raise,
+            non_existent: NonExistent::Raise,
         }
     }
 }
@@ -58,8 +66,14 @@ pub struct RollingGroupOptions {
     pub index_column: SmartString,
     /// Window duration.
     pub period: Duration,
+    /// Offset of the start of the window relative to the index value.
     pub offset: Duration,
     pub closed_window: ClosedWindow,
+    /// Label a window by its start (`Left`), its end (`Right`) or the index value it was
+    /// created for (`DataPoint`).
+    pub label: Label,
+    /// Add the boundaries to the DataFrame.
+    pub include_boundaries: bool,
 }
 
 impl Default for RollingGroupOptions {
@@ -69,6 +83,8 @@ impl Default for RollingGroupOptions {
             period: Duration::new(1),
             offset: Duration::new(1),
             closed_window: ClosedWindow::Left,
+            label: Label::DataPoint,
+            include_boundaries: false,
         }
     }
 }
@@ -147,7 +163,7 @@ impl Wrap<&DataFrame> {
             UInt32 | UInt64 | Int32 => {
                 let time_type_dt = Datetime(TimeUnit::Nanoseconds, None);
                 let dt = time.cast(&Int64).unwrap().cast(&time_type_dt).unwrap();
-                let (out, by, gt) = self.impl_rolling(
+                let (out, mut by, gt) = self.impl_rolling(
                     dt,
                     group_by,
                     options,
@@ -156,12 +172,17 @@ impl Wrap<&DataFrame> {
                     &time_type_dt,
                 )?;
                 let out = out.cast(&Int64).unwrap().cast(time_type).unwrap();
+                for k in &mut by {
+                    if k.name() == UP_NAME || k.name() == LB_NAME {
+                        *k = k.cast(&Int64).unwrap().cast(time_type).unwrap()
+                    }
+                }
                 return Ok((out, by, gt));
             },
             Int64 => {
                 let time_type = Datetime(TimeUnit::Nanoseconds, None);
                 let dt = time.cast(&time_type).unwrap();
-                let (out, by, gt) = self.impl_rolling(
+                let (out, mut by, gt) = self.impl_rolling(
                     dt,
                     group_by,
                     options,
@@ -170,6 +191,11 @@ impl Wrap<&DataFrame> {
                     &time_type,
                 )?;
                 let out = out.cast(&Int64).unwrap();
+                for k in &mut by {
+                    if k.name() == UP_NAME || k.name() == LB_NAME {
+                        *k = k.cast(&Int64).unwrap()
+                    }
+                }
                 return Ok((out, by, gt));
             },
             dt => polars_bail!(
@@ -274,7 +300,8 @@ impl Wrap<&DataFrame> {
         // A requirement for the index so we can set this such that downstream code has this info.
         dt.set_sorted_flag(IsSorted::Ascending);
 
-        let w = Window::new(options.every, options.period, options.offset);
+        let w = Window::new(options.every, options.period, options.offset)
+            .with_dst_strategy(options.ambiguous, options.non_existent);
         let dt = dt.datetime().unwrap();
         let tz = dt.time_zone();
 
@@ -513,7 +540,7 @@ impl Wrap<&DataFrame> {
     fn impl_rolling(
         &self,
         dt: Series,
-        group_by: Vec<Series>,
+        mut group_by: Vec<Series>,
         options: &RollingGroupOptions,
         tu: TimeUnit,
         tz: Option<Tz>,
@@ -586,6 +613,9 @@ impl Wrap<&DataFrame> {
                             let dt = dt_local.slice(base_g[0] as i64, base_g[1] as usize);
                             let vals = dt.downcast_iter().next().unwrap();
                             let ts = vals.values().as_slice();
+                            if !matches!(dt.is_sorted_flag(), IsSorted::Ascending) {
+                                check_sortedness_slice(ts)?
+                            }
                             let sub_groups = group_by_values(
                                 options.period,
                                 options.offset,
@@ -607,12 +637,81 @@ impl Wrap<&DataFrame> {
             })
         }?;
 
+        if options.include_boundaries || options.label != Label::DataPoint {
+            let ca = dt.datetime().unwrap().rechunk();
+            let vals = ca.downcast_iter().next().unwrap();
+            let (lower, upper) = rolling_bounds(
+                vals.values().as_slice(),
+                options.period,
+                options.offset,
+                tu,
+                tz,
+            )?;
+            let sorted = group_by.is_empty();
+            let to_series = |name: &str, bound: Vec<i64>| {
+                let mut bound = Int64Chunked::new_vec(name, bound);
+                if sorted {
+                    bound.set_sorted_flag(IsSorted::Ascending);
+                }
+                bound
+                    .into_datetime(tu, None)
+                    .into_series()
+                    .cast(time_type)
+                    .unwrap()
+            };
+            let lower = to_series(LB_NAME, lower);
+            let upper = to_series(UP_NAME, upper);
+
+            match options.label {
+                Label::Left => dt = lower.clone().with_name(dt.name()),
+                Label::Right => dt = upper.clone().with_name(dt.name()),
+                Label::DataPoint => {},
+            }
+            if options.include_boundaries {
+                group_by.push(lower);
+                group_by.push(upper);
+            }
+        }
+
         let dt = dt.cast(time_type).unwrap();
 
         Ok((dt, group_by, groups))
     }
 }
 
+/// The lower and upper boundary of the rolling window of every index value, computed like the
+/// windows themselves in [`group_by_values`].
+fn rolling_bounds(
+    time: &[i64],
+    period: Duration,
+    offset: Duration,
+    tu: TimeUnit,
+    tz: Option<Tz>,
+) -> PolarsResult<(Vec<i64>, Vec<i64>)> {
+    let add = match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    };
+    // If the window ends at the index value it is not computed, as `t - 1mo + 1mo` doesn't
+    // always round-trip.
+    let ends_at_t = offset.negative && offset.duration_ns() == period.duration_ns();
+
+    let mut lower = Vec::with_capacity(time.len());
+    let mut upper = Vec::with_capacity(time.len());
+    for &t in time {
+        let start = add(&offset, t, tz.as_ref())?;
+        let stop = if ends_at_t {
+            t
+        } else {
+            add(&period, start, tz.as_ref())?
+        };
+        lower.push(start);
+        upper.push(stop);
+    }
+    Ok((lower, upper))
+}
+
 fn update_subgroups_slice(sub_groups: &[[IdxSize; 2]], base_g: [IdxSize; 2]) -> Vec<[IdxSize; 2]> {
     sub_groups
         .iter()
@@ -702,6 +801,7 @@ mod test {
                         period: Duration::parse("2d"),
                         offset: Duration::parse("-2d"),
                         closed_window: ClosedWindow::Right,
+                        ..Default::default()
                     },
                 )
                 .unwrap();
@@ -749,6 +849,7 @@ mod test {
                     period: Duration::parse("2d"),
                     offset: Duration::parse("-2d"),
                     closed_window: ClosedWindow::Right,
+                    ..Default::default()
                 },
             )
             .unwrap();
@@ -792,6 +893,60 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_rolling_closed_offset_duplicates() -> PolarsResult<()> {
+        let time = [0i64, 0, 1, 3, 3, 3, 4, 7, 7, 8];
+        let period = Duration::new(2);
+        for offset in [-4, -3, -2, -1, 0, 1, 2] {
+            let offset = Duration::new(offset);
+            for closed in [
+                ClosedWindow::Left,
+                ClosedWindow::Right,
+                ClosedWindow::Both,
+                ClosedWindow::None,
+            ] {
+                let groups =
+                    group_by_values(period, offset, &time, closed, TimeUnit::Nanoseconds, None)?;
+                let (lower, upper) =
+                    rolling_bounds(&time, period, offset, TimeUnit::Nanoseconds, None)?;
+                for (i, [first, len]) in groups.into_iter().enumerate() {
+                    let b = Bounds::new(lower[i], upper[i]);
+                    let members = (0..time.len())
+                        .filter(|j| b.is_member(time[*j], closed))
+                        .collect::<Vec<_>>();
+                    assert_eq!(len as usize, members.len(), "{offset:?} {closed:?} {i}");
+                    if let Some(expected_first) = members.first() {
+                        assert_eq!(first as usize, *expected_first, "{offset:?} {closed:?} {i}");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_label_and_boundaries() -> PolarsResult<()> {
+        let mut idx = Series::new("idx", [0i64, 1, 1, 4]);
+        idx.set_sorted_flag(IsSorted::Ascending);
+        let df = DataFrame::new(vec![idx])?;
+
+        let (time, keys, _) = df.rolling(
+            vec![],
+            &RollingGroupOptions {
+                index_column: "idx".into(),
+                period: Duration::new(2),
+                offset: Duration::new(-1),
+                closed_window: ClosedWindow::Both,
+                label: Label::Right,
+                include_boundaries: true,
+            },
+        )?;
+        assert_eq!(time, Series::new("idx", [1i64, 2, 2, 5]));
+        assert_eq!(keys[0], Series::new(LB_NAME, [-1i64, 0, 0, 3]));
+        assert_eq!(keys[1], Series::new(UP_NAME, [1i64, 2, 2, 5]));
+        Ok(())
+    }
+
     #[test]
     fn test_dynamic_group_by_window() -> PolarsResult<()> {
         let start = NaiveDate::from_ymd_opt(2021, 12, 16)
@@ -832,6 +987,7 @@ mod test {
                     include_boundaries: true,
                     closed_window: ClosedWindow::Both,
                     start_by: Default::default(),
+                    ..Default::default()
                 },
             )
             .unwrap();
@@ -952,6 +1108,7 @@ mod test {
                     include_boundaries: true,
                     closed_window: ClosedWindow::Both,
                     start_by: Default::default(),
+                    ..Default::default()
                 },
             )
             .unwrap();
@@ -8,14 +8,38 @@ use crate::prelude::*;
 
 pub trait PolarsTruncate {
     fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        self.truncate_with_dst_strategy(tz, every, Ambiguous::Earliest, NonExistent::Raise)
+    }
+
+    /// Truncate, localizing results which are ambiguous or non-existent in `tz`
+    /// according to `ambiguous` and `non_existent`.
+    ///
+    /// An ambiguous result stays on the same side of the DST fold as the original
+    /// datetime if that is itself ambiguous, `ambiguous` is only used otherwise.
+    fn truncate_with_dst_strategy(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
 impl PolarsTruncate for DatetimeChunked {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate_with_dst_strategy(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Self> {
         let time_zone = self.time_zone();
-        let offset = Duration::new(0);
+        let tu = self.time_unit();
 
         // Let's check if we can use a fastpath...
         if every.len() == 1 {
@@ -41,19 +65,22 @@ impl PolarsTruncate for DatetimeChunked {
                         })
                         .into_datetime(self.time_unit(), time_zone.clone()));
                 } else {
-                    let w = Window::new(every_parsed, every_parsed, offset);
-                    let out = match self.time_unit() {
-                        TimeUnit::Milliseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_ms(t, tz))
-                        },
-                        TimeUnit::Microseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_us(t, tz))
-                        },
-                        TimeUnit::Nanoseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_ns(t, tz))
-                        },
-                    };
-                    return Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()));
+                    let out: Int64Chunked = self
+                        .into_iter()
+                        .map(|opt_t| match opt_t {
+                            Some(t) => every_parsed.truncate_with_dst(
+                                t,
+                                tu,
+                                tz,
+                                ambiguous,
+                                non_existent,
+                            ),
+                            None => Ok(None),
+                        })
+                        .collect::<PolarsResult<_>>()?;
+                    return Ok(out
+                        .with_name(self.name())
+                        .into_datetime(self.time_unit(), self.time_zone().clone()));
                 }
             } else {
                 return Ok(Int64Chunked::full_null(self.name(), self.len())
@@ -64,12 +91,6 @@ impl PolarsTruncate for DatetimeChunked {
         // A sqrt(n) cache is not too small, not too large.
         let mut duration_cache = FastFixedCache::new((every.len() as f64).sqrt() as usize);
 
-        let func = match self.time_unit() {
-            TimeUnit::Nanoseconds => Window::truncate_ns,
-            TimeUnit::Microseconds => Window::truncate_us,
-            TimeUnit::Milliseconds => Window::truncate_ms,
-        };
-
         let out = broadcast_try_binary_elementwise(self, every, |opt_timestamp, opt_every| match (
             opt_timestamp,
             opt_every,
@@ -82,8 +103,7 @@ impl PolarsTruncate for DatetimeChunked {
                     polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
                 }
 
-                let w = Window::new(every, every, offset);
-                func(&w, timestamp, tz).map(Some)
+                every.truncate_with_dst(timestamp, tu, tz, ambiguous, non_existent)
             },
             _ => Ok(None),
         });
@@ -92,7 +112,13 @@ impl PolarsTruncate for DatetimeChunked {
 }
 
 impl PolarsTruncate for DateChunked {
-    fn truncate(&self, _tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate_with_dst_strategy(
+        &self,
+        _tz: Option<&Tz>,
+        every: &StringChunked,
+        _ambiguous: Ambiguous,
+        _non_existent: NonExistent,
+    ) -> PolarsResult<Self> {
         let offset = Duration::new(0);
         let out = match every.len() {
             1 => {
@@ -1,6 +1,7 @@
 #[cfg(feature = "timezones")]
 use polars_core::chunked_array::temporal::parse_time_zone;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_ops::prelude::*;
 use polars_ops::series::SeriesMethods;
 
@@ -85,6 +86,22 @@ pub trait PolarsUpsample {
         time_column: &str,
         every: Duration,
     ) -> PolarsResult<DataFrame>;
+
+    /// Upsample a [`DataFrame`] at a regular frequency, localizing points of the
+    /// upsampled range which are ambiguous or non-existent in the time zone of
+    /// `time_column` according to `ambiguous` and `non_existent`.
+    ///
+    /// Points which would be localized to null are left out of the upsampled range.
+    /// See [`upsample`][PolarsUpsample::upsample] for the other arguments.
+    fn upsample_with_dst_strategy<I: IntoVec<String>>(
+        &self,
+        by: I,
+        time_column: &str,
+        every: Duration,
+        stable: bool,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<DataFrame>;
 }
 
 impl PolarsUpsample for DataFrame {
@@ -97,7 +114,15 @@ impl PolarsUpsample for DataFrame {
         let by = by.into_vec();
         let time_type = self.column(time_column)?.dtype();
         ensure_duration_matches_data_type(every, time_type, "every")?;
-        upsample_impl(self, by, time_column, every, false)
+        upsample_impl(
+            self,
+            by,
+            time_column,
+            every,
+            false,
+            Ambiguous::Raise,
+            NonExistent::Raise,
+        )
     }
 
     fn upsample_stable<I: IntoVec<String>>(
@@ -109,7 +134,38 @@ impl PolarsUpsample for DataFrame {
         let by = by.into_vec();
         let time_type = self.column(time_column)?.dtype();
         ensure_duration_matches_data_type(every, time_type, "every")?;
-        upsample_impl(self, by, time_column, every, true)
+        upsample_impl(
+            self,
+            by,
+            time_column,
+            every,
+            true,
+            Ambiguous::Raise,
+            NonExistent::Raise,
+        )
+    }
+
+    fn upsample_with_dst_strategy<I: IntoVec<String>>(
+        &self,
+        by: I,
+        time_column: &str,
+        every: Duration,
+        stable: bool,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<DataFrame> {
+        let by = by.into_vec();
+        let time_type = self.column(time_column)?.dtype();
+        ensure_duration_matches_data_type(every, time_type, "every")?;
+        upsample_impl(
+            self,
+            by,
+            time_column,
+            every,
+            stable,
+            ambiguous,
+            non_existent,
+        )
     }
 }
 
@@ -119,6 +175,8 @@ fn upsample_impl(
     index_column: &str,
     every: Duration,
     stable: bool,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> PolarsResult<DataFrame> {
     let s = source.column(index_column)?;
     s.ensure_sorted_arg("upsample")?;
@@ -130,7 +188,15 @@ fn upsample_impl(
                 .unwrap()
         })
         .unwrap();
-        let mut out = upsample_impl(&df, by, index_column, every, stable)?;
+        let mut out = upsample_impl(
+            &df,
+            by,
+            index_column,
+            every,
+            stable,
+            ambiguous,
+            non_existent,
+        )?;
         out.apply(index_column, |s| s.cast(time_type).unwrap())
             .unwrap();
         Ok(out)
@@ -147,7 +213,15 @@ fn upsample_impl(
                 .unwrap()
         })
         .unwrap();
-        let mut out = upsample_impl(&df, by, index_column, every, stable)?;
+        let mut out = upsample_impl(
+            &df,
+            by,
+            index_column,
+            every,
+            stable,
+            ambiguous,
+            non_existent,
+        )?;
         out.apply(index_column, |s| s.cast(time_type).unwrap())
             .unwrap();
         Ok(out)
@@ -158,13 +232,21 @@ fn upsample_impl(
                 .unwrap()
         })
         .unwrap();
-        let mut out = upsample_impl(&df, by, index_column, every, stable)?;
+        let mut out = upsample_impl(
+            &df,
+            by,
+            index_column,
+            every,
+            stable,
+            ambiguous,
+            non_existent,
+        )?;
         out.apply(index_column, |s| s.cast(time_type).unwrap())
             .unwrap();
         Ok(out)
     } else if by.is_empty() {
         let index_column = source.column(index_column)?;
-        upsample_single_impl(source, index_column, every)
+        upsample_single_impl(source, index_column, every, ambiguous, non_existent)
     } else {
         let gb = if stable {
             source.group_by_stable(by)
@@ -174,7 +256,7 @@ fn upsample_impl(
         // don't parallelize this, this may SO on large data.
         gb?.apply(|df| {
             let index_column = df.column(index_column)?;
-            upsample_single_impl(&df, index_column, every)
+            upsample_single_impl(&df, index_column, every, ambiguous, non_existent)
         })
     }
 }
@@ -183,6 +265,8 @@ fn upsample_single_impl(
     source: &DataFrame,
     index_column: &Series,
     every: Duration,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> PolarsResult<DataFrame> {
     let index_col_name = index_column.name();
 
@@ -195,22 +279,27 @@ fn upsample_single_impl(
             let last = ca.iter().flatten().next_back();
             match (first, last) {
                 (Some(first), Some(last)) => {
-                    let tz = match tz {
+                    let parsed_tz = match tz {
                         #[cfg(feature = "timezones")]
                         Some(tz) => Some(parse_time_zone(tz)?),
                         _ => None,
                     };
-                    let range = datetime_range_impl(
+                    let mut range = Int64Chunked::new_vec(
                         index_col_name,
-                        first,
-                        last,
-                        every,
-                        ClosedWindow::Both,
-                        *tu,
-                        tz.as_ref(),
-                    )?
-                    .into_series()
-                    .into_frame();
+                        datetime_range_i64_with_dst(
+                            first,
+                            last,
+                            every,
+                            ClosedWindow::Both,
+                            *tu,
+                            parsed_tz.as_ref(),
+                            ambiguous,
+                            non_existent,
+                        )?,
+                    )
+                    .into_datetime(*tu, tz.clone());
+                    range.set_sorted_flag(IsSorted::Ascending);
+                    let range = range.into_series().into_frame();
                     range.join(
                         source,
                         &[index_col_name],
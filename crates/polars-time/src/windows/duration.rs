@@ -2,7 +2,6 @@ use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::ops::{Mul, Neg};
 
-#[cfg(feature = "timezones")]
 use arrow::legacy::kernels::{Ambiguous, NonExistent};
 use arrow::legacy::time_zone::Tz;
 use arrow::temporal_conversions::{
@@ -14,7 +13,7 @@ use polars_core::datatypes::DataType;
 use polars_core::export::arrow::temporal_conversions::MICROSECONDS;
 use polars_core::prelude::{
     datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us, polars_bail,
-    PolarsResult,
+    PolarsResult, TimeUnit,
 };
 use polars_error::polars_ensure;
 #[cfg(feature = "serde")]
@@ -531,6 +530,9 @@ impl Duration {
     ///   original datetime was 2022-11-06 01:30:00 CST, then this would
     ///   be 2022-11-06 07:30:00.
     /// * `result_dt_local` - result, without time zone.
+    /// * `ambiguous` - how to localize an ambiguous result if the original
+    ///   datetime is not itself in a DST fold (and so can't tell us which side to take).
+    /// * `non_existent` - how to localize a result which falls into a DST gap.
     #[cfg(feature = "timezones")]
     fn localize_result(
         &self,
@@ -538,10 +540,24 @@ impl Duration {
         original_dt_utc: NaiveDateTime,
         result_dt_local: NaiveDateTime,
         tz: &Tz,
-    ) -> PolarsResult<NaiveDateTime> {
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<NaiveDateTime>> {
         match localize_datetime_opt(result_dt_local, tz, Ambiguous::Raise) {
-            Some(dt) => Ok(dt.expect("we didn't use Ambiguous::Null")),
+            Some(dt) => Ok(Some(dt.expect("we didn't use Ambiguous::Null"))),
             None => {
+                // The result is either ambiguous or non-existent. In the latter case,
+                // `non_existent` decides what happens.
+                if try_localize_datetime(result_dt_local, tz, Ambiguous::Earliest, non_existent)?
+                    .is_none()
+                {
+                    return Ok(None);
+                }
+                if localize_datetime_opt(original_dt_local, tz, Ambiguous::Raise).is_some() {
+                    // The original datetime isn't in a fold, so it doesn't tell us
+                    // which side of the fold the result should be on.
+                    return try_localize_datetime(result_dt_local, tz, ambiguous, non_existent);
+                }
                 if try_localize_datetime(
                     original_dt_local,
                     tz,
@@ -551,44 +567,35 @@ impl Duration {
                 .expect("we didn't use Ambiguous::Null or NonExistent::Null")
                     == original_dt_utc
                 {
-                    Ok(try_localize_datetime(
+                    try_localize_datetime(
                         result_dt_local,
                         tz,
                         Ambiguous::Earliest,
                         NonExistent::Raise,
-                    )?
-                    .expect("we didn't use Ambiguous::Null or NonExistent::Null"))
-                } else if try_localize_datetime(
-                    original_dt_local,
-                    tz,
-                    Ambiguous::Latest,
-                    NonExistent::Raise,
-                )?
-                .expect("we didn't use Ambiguous::Null or NonExistent::Null")
-                    == original_dt_utc
-                {
-                    Ok(try_localize_datetime(
+                    )
+                } else {
+                    try_localize_datetime(
                         result_dt_local,
                         tz,
                         Ambiguous::Latest,
                         NonExistent::Raise,
-                    )?
-                    .expect("we didn't use Ambiguous::Null or NonExistent::Null"))
-                } else {
-                    unreachable!()
+                    )
                 }
             },
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn truncate_subweekly<G, J>(
         &self,
         t: i64,
         tz: Option<&Tz>,
+        _ambiguous: Ambiguous,
+        _non_existent: NonExistent,
         duration: i64,
         _timestamp_to_datetime: G,
         _datetime_to_timestamp: J,
-    ) -> PolarsResult<i64>
+    ) -> PolarsResult<Option<i64>>
     where
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
@@ -606,28 +613,37 @@ impl Duration {
                 }
                 let result_timestamp = t - remainder;
                 let result_dt_local = _timestamp_to_datetime(result_timestamp);
-                let result_dt_utc =
-                    self.localize_result(original_dt_local, original_dt_utc, result_dt_local, tz)?;
-                Ok(_datetime_to_timestamp(result_dt_utc))
+                let result_dt_utc = self.localize_result(
+                    original_dt_local,
+                    original_dt_utc,
+                    result_dt_local,
+                    tz,
+                    _ambiguous,
+                    _non_existent,
+                )?;
+                Ok(result_dt_utc.map(_datetime_to_timestamp))
             },
             _ => {
                 let mut remainder = t % duration;
                 if remainder < 0 {
                     remainder += duration
                 }
-                Ok(t - remainder)
+                Ok(Some(t - remainder))
             },
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn truncate_weekly<G, J>(
         &self,
         t: i64,
         tz: Option<&Tz>,
+        _ambiguous: Ambiguous,
+        _non_existent: NonExistent,
         _timestamp_to_datetime: G,
         _datetime_to_timestamp: J,
         daily_duration: i64,
-    ) -> PolarsResult<i64>
+    ) -> PolarsResult<Option<i64>>
     where
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
@@ -668,20 +684,25 @@ impl Duration {
                     _original_dt_utc.unwrap(),
                     result_dt_local,
                     tz,
+                    _ambiguous,
+                    _non_existent,
                 )?;
-                Ok(_datetime_to_timestamp(result_dt_utc))
+                Ok(result_dt_utc.map(_datetime_to_timestamp))
             },
-            _ => Ok(result_t_local),
+            _ => Ok(Some(result_t_local)),
         }
     }
+    #[allow(clippy::too_many_arguments)]
     fn truncate_monthly<G, J>(
         &self,
         t: i64,
         tz: Option<&Tz>,
+        _ambiguous: Ambiguous,
+        _non_existent: NonExistent,
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
         daily_duration: i64,
-    ) -> PolarsResult<i64>
+    ) -> PolarsResult<Option<i64>>
     where
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
@@ -750,11 +771,17 @@ impl Duration {
             // for UTC, use fastpath below (same as naive)
             Some(tz) if tz != &chrono_tz::UTC => {
                 let result_dt_local = timestamp_to_datetime(t - remainder_days * daily_duration);
-                let result_dt_utc =
-                    self.localize_result(original_dt_local, original_dt_utc, result_dt_local, tz)?;
-                Ok(datetime_to_timestamp(result_dt_utc))
+                let result_dt_utc = self.localize_result(
+                    original_dt_local,
+                    original_dt_utc,
+                    result_dt_local,
+                    tz,
+                    _ambiguous,
+                    _non_existent,
+                )?;
+                Ok(result_dt_utc.map(datetime_to_timestamp))
             },
-            _ => Ok(t - remainder_days * daily_duration),
+            _ => Ok(Some(t - remainder_days * daily_duration)),
         }
     }
 
@@ -767,6 +794,44 @@ impl Duration {
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
     ) -> PolarsResult<i64>
+    where
+        F: Fn(i64) -> i64,
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+    {
+        Ok(self
+            .try_truncate_impl(
+                t,
+                tz,
+                Ambiguous::Earliest,
+                NonExistent::Raise,
+                nsecs_to_unit,
+                timestamp_to_datetime,
+                datetime_to_timestamp,
+            )?
+            .expect("we didn't use Ambiguous::Null or NonExistent::Null"))
+    }
+
+    /// Truncate, resolving results which are ambiguous or non-existent in `tz`
+    /// according to `ambiguous` and `non_existent`.
+    ///
+    /// If the original datetime is in a DST fold, an ambiguous result stays on
+    /// the same side of the fold and `ambiguous` is not consulted.
+    ///
+    /// Note: this may only return `Ok(None)` if ambiguous is Ambiguous::Null
+    /// or if non_existent is NonExistent::Null.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    fn try_truncate_impl<F, G, J>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+        nsecs_to_unit: F,
+        timestamp_to_datetime: G,
+        datetime_to_timestamp: J,
+    ) -> PolarsResult<Option<i64>>
     where
         F: Fn(i64) -> i64,
         G: Fn(i64) -> NaiveDateTime,
@@ -780,6 +845,8 @@ impl Duration {
                 self.truncate_subweekly(
                     t,
                     tz,
+                    ambiguous,
+                    non_existent,
                     duration,
                     timestamp_to_datetime,
                     datetime_to_timestamp,
@@ -791,6 +858,8 @@ impl Duration {
                 self.truncate_subweekly(
                     t,
                     tz,
+                    ambiguous,
+                    non_existent,
                     duration,
                     timestamp_to_datetime,
                     datetime_to_timestamp,
@@ -802,6 +871,8 @@ impl Duration {
                 self.truncate_weekly(
                     t,
                     tz,
+                    ambiguous,
+                    non_existent,
                     timestamp_to_datetime,
                     datetime_to_timestamp,
                     duration,
@@ -813,6 +884,8 @@ impl Duration {
                 self.truncate_monthly(
                     t,
                     tz,
+                    ambiguous,
+                    non_existent,
                     timestamp_to_datetime,
                     datetime_to_timestamp,
                     duration,
@@ -860,14 +933,58 @@ impl Duration {
         )
     }
 
+    /// Truncate the given timestamp by the window boundary, resolving results which
+    /// are ambiguous or non-existent in `tz` with the given strategies.
+    pub fn truncate_with_dst(
+        &self,
+        t: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>> {
+        match tu {
+            TimeUnit::Nanoseconds => self.try_truncate_impl(
+                t,
+                tz,
+                ambiguous,
+                non_existent,
+                |nsecs| nsecs,
+                timestamp_ns_to_datetime,
+                datetime_to_timestamp_ns,
+            ),
+            TimeUnit::Microseconds => self.try_truncate_impl(
+                t,
+                tz,
+                ambiguous,
+                non_existent,
+                |nsecs| nsecs / 1000,
+                timestamp_us_to_datetime,
+                datetime_to_timestamp_us,
+            ),
+            TimeUnit::Milliseconds => self.try_truncate_impl(
+                t,
+                tz,
+                ambiguous,
+                non_existent,
+                |nsecs| nsecs / 1_000_000,
+                timestamp_ms_to_datetime,
+                datetime_to_timestamp_ms,
+            ),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn add_impl_month_week_or_day<F, G, J>(
         &self,
         t: i64,
         tz: Option<&Tz>,
+        _ambiguous: Ambiguous,
+        _non_existent: NonExistent,
         nsecs_to_unit: F,
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
-    ) -> PolarsResult<i64>
+    ) -> PolarsResult<Option<i64>>
     where
         F: Fn(i64) -> i64,
         G: Fn(i64) -> NaiveDateTime,
@@ -889,10 +1006,12 @@ impl Duration {
             new_t = match tz {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
-                Some(tz) if tz != &chrono_tz::UTC => datetime_to_timestamp(
-                    try_localize_datetime(dt, tz, Ambiguous::Raise, NonExistent::Raise)?
-                        .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
-                ),
+                Some(tz) if tz != &chrono_tz::UTC => {
+                    match try_localize_datetime(dt, tz, _ambiguous, _non_existent)? {
+                        Some(dt) => datetime_to_timestamp(dt),
+                        None => return Ok(None),
+                    }
+                },
                 _ => datetime_to_timestamp(dt),
             };
         }
@@ -906,15 +1025,15 @@ impl Duration {
                     new_t =
                         datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
                     new_t += if d.negative { -t_weeks } else { t_weeks };
-                    new_t = datetime_to_timestamp(
-                        try_localize_datetime(
-                            timestamp_to_datetime(new_t),
-                            tz,
-                            Ambiguous::Raise,
-                            NonExistent::Raise,
-                        )?
-                        .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
-                    );
+                    new_t = match try_localize_datetime(
+                        timestamp_to_datetime(new_t),
+                        tz,
+                        _ambiguous,
+                        _non_existent,
+                    )? {
+                        Some(dt) => datetime_to_timestamp(dt),
+                        None => return Ok(None),
+                    };
                 },
                 _ => new_t += if d.negative { -t_weeks } else { t_weeks },
             };
@@ -929,21 +1048,21 @@ impl Duration {
                     new_t =
                         datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
                     new_t += if d.negative { -t_days } else { t_days };
-                    new_t = datetime_to_timestamp(
-                        try_localize_datetime(
-                            timestamp_to_datetime(new_t),
-                            tz,
-                            Ambiguous::Raise,
-                            NonExistent::Raise,
-                        )?
-                        .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
-                    );
+                    new_t = match try_localize_datetime(
+                        timestamp_to_datetime(new_t),
+                        tz,
+                        _ambiguous,
+                        _non_existent,
+                    )? {
+                        Some(dt) => datetime_to_timestamp(dt),
+                        None => return Ok(None),
+                    };
                 },
                 _ => new_t += if d.negative { -t_days } else { t_days },
             };
         }
 
-        Ok(new_t)
+        Ok(Some(new_t))
     }
 
     pub fn add_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
@@ -951,12 +1070,15 @@ impl Duration {
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
+            Ambiguous::Raise,
+            NonExistent::Raise,
             |nsecs| nsecs,
             timestamp_ns_to_datetime,
             datetime_to_timestamp_ns,
-        );
+        )?
+        .expect("we didn't use Ambiguous::Null or NonExistent::Null");
         let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
-        Ok(new_t? + nsecs)
+        Ok(new_t + nsecs)
     }
 
     pub fn add_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
@@ -964,12 +1086,15 @@ impl Duration {
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
+            Ambiguous::Raise,
+            NonExistent::Raise,
             |nsecs| nsecs / 1000,
             timestamp_us_to_datetime,
             datetime_to_timestamp_us,
-        );
+        )?
+        .expect("we didn't use Ambiguous::Null or NonExistent::Null");
         let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
-        Ok(new_t? + nsecs / 1_000)
+        Ok(new_t + nsecs / 1_000)
     }
 
     pub fn add_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
@@ -977,12 +1102,65 @@ impl Duration {
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
+            Ambiguous::Raise,
+            NonExistent::Raise,
             |nsecs| nsecs / 1_000_000,
             timestamp_ms_to_datetime,
             datetime_to_timestamp_ms,
-        );
+        )?
+        .expect("we didn't use Ambiguous::Null or NonExistent::Null");
         let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
-        Ok(new_t? + nsecs / 1_000_000)
+        Ok(new_t + nsecs / 1_000_000)
+    }
+
+    /// Add this duration to the given timestamp, resolving results which are
+    /// ambiguous or non-existent in `tz` with the given strategies.
+    ///
+    /// Note: this may only return `Ok(None)` if ambiguous is Ambiguous::Null
+    /// or if non_existent is NonExistent::Null.
+    pub fn add_with_dst(
+        &self,
+        t: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>> {
+        let new_t = match tu {
+            TimeUnit::Nanoseconds => self.add_impl_month_week_or_day(
+                t,
+                tz,
+                ambiguous,
+                non_existent,
+                |nsecs| nsecs,
+                timestamp_ns_to_datetime,
+                datetime_to_timestamp_ns,
+            )?,
+            TimeUnit::Microseconds => self.add_impl_month_week_or_day(
+                t,
+                tz,
+                ambiguous,
+                non_existent,
+                |nsecs| nsecs / 1000,
+                timestamp_us_to_datetime,
+                datetime_to_timestamp_us,
+            )?,
+            TimeUnit::Milliseconds => self.add_impl_month_week_or_day(
+                t,
+                tz,
+                ambiguous,
+                non_existent,
+                |nsecs| nsecs / 1_000_000,
+                timestamp_ms_to_datetime,
+                datetime_to_timestamp_ms,
+            )?,
+        };
+        let nsecs = if self.negative { -self.nsecs } else { self.nsecs };
+        Ok(new_t.map(|new_t| match tu {
+            TimeUnit::Nanoseconds => new_t + nsecs,
+            TimeUnit::Microseconds => new_t + nsecs / 1_000,
+            TimeUnit::Milliseconds => new_t + nsecs / 1_000_000,
+        }))
     }
 }
 
@@ -24,17 +24,16 @@ use crate::prelude::*;
 pub(crate) fn ensure_t_in_or_in_front_of_window(
     mut every: Duration,
     t: i64,
-    offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
+    offset_fn: impl Fn(&Duration, i64) -> PolarsResult<i64>,
     period: Duration,
     mut start: i64,
     closed_window: ClosedWindow,
-    tz: Option<&Tz>,
 ) -> PolarsResult<Bounds> {
     every.negative = !every.negative;
-    let mut stop = offset_fn(&period, start, tz)?;
+    let mut stop = offset_fn(&period, start)?;
     while Bounds::new(start, stop).is_past(t, closed_window) {
-        start = offset_fn(&every, start, tz)?;
-        stop = offset_fn(&period, start, tz)?;
+        start = offset_fn(&every, start)?;
+        stop = offset_fn(&period, start)?;
     }
     Ok(Bounds::new_checked(start, stop))
 }
@@ -48,6 +47,10 @@ pub struct Window {
     every: Duration,
     period: Duration,
     pub offset: Duration,
+    // How to localize window boundaries which are ambiguous or
+    // non-existent in the time zone of the data.
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 }
 
 impl Window {
@@ -57,6 +60,63 @@ impl Window {
             every,
             period,
             offset,
+            ambiguous: Ambiguous::Raise,
+            non_existent: NonExistent::Raise,
+        }
+    }
+
+    /// Set how window boundaries which fall into a DST fold or gap are localized.
+    ///
+    /// With `Ambiguous::Null` or `NonExistent::Null`, a boundary which can't be
+    /// localized is skipped, i.e. the window is merged with the next one.
+    pub fn with_dst_strategy(mut self, ambiguous: Ambiguous, non_existent: NonExistent) -> Self {
+        self.ambiguous = ambiguous;
+        self.non_existent = non_existent;
+        self
+    }
+
+    /// Add `duration` to the window boundary `t`, localizing the result according
+    /// to the window's DST strategy. Boundaries which can't be localized are
+    /// skipped by adding further multiples of `duration`.
+    pub(crate) fn add_duration(
+        &self,
+        duration: &Duration,
+        t: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<i64> {
+        let mut n = 1;
+        loop {
+            if let Some(out) =
+                (*duration * n).add_with_dst(t, tu, tz, self.ambiguous, self.non_existent)?
+            {
+                return Ok(out);
+            }
+            n += 1;
+        }
+    }
+
+    /// Shift both ends of the window `bounds` by `every`.
+    fn next_bounds(&self, bounds: Bounds, tu: TimeUnit, tz: Option<&Tz>) -> PolarsResult<Bounds> {
+        let start = self
+            .every
+            .add_with_dst(bounds.start, tu, tz, self.ambiguous, self.non_existent)?;
+        let stop = self
+            .every
+            .add_with_dst(bounds.stop, tu, tz, self.ambiguous, self.non_existent)?;
+        match (start, stop) {
+            (Some(start), Some(stop)) => Ok(Bounds::new(start, stop)),
+            // A boundary was skipped, so `bounds` may have been merged with its
+            // neighbour. Compute the stop from the start instead.
+            (Some(start), None) => {
+                let stop = self.add_duration(&self.period, start, tu, tz)?;
+                Ok(Bounds::new(start, stop))
+            },
+            (None, _) => {
+                let start = self.add_duration(&self.every, bounds.start, tu, tz)?;
+                let stop = self.add_duration(&self.period, start, tu, tz)?;
+                Ok(Bounds::new(start, stop))
+            },
         }
     }
 
@@ -104,17 +164,7 @@ impl Window {
         closed_window: ClosedWindow,
         tz: Option<&Tz>,
     ) -> PolarsResult<Bounds> {
-        let start = self.truncate_ns(t, tz)?;
-        let start = self.offset.add_ns(start, tz)?;
-        ensure_t_in_or_in_front_of_window(
-            self.every,
-            t,
-            Duration::add_ns,
-            self.period,
-            start,
-            closed_window,
-            tz,
-        )
+        self.get_earliest_bounds(t, closed_window, TimeUnit::Nanoseconds, tz)
     }
 
     pub fn get_earliest_bounds_us(
@@ -123,17 +173,7 @@ impl Window {
         closed_window: ClosedWindow,
         tz: Option<&Tz>,
     ) -> PolarsResult<Bounds> {
-        let start = self.truncate_us(t, tz)?;
-        let start = self.offset.add_us(start, tz)?;
-        ensure_t_in_or_in_front_of_window(
-            self.every,
-            t,
-            Duration::add_us,
-            self.period,
-            start,
-            closed_window,
-            tz,
-        )
+        self.get_earliest_bounds(t, closed_window, TimeUnit::Microseconds, tz)
     }
 
     pub fn get_earliest_bounds_ms(
@@ -142,16 +182,38 @@ impl Window {
         closed_window: ClosedWindow,
         tz: Option<&Tz>,
     ) -> PolarsResult<Bounds> {
-        let start = self.truncate_ms(t, tz)?;
-        let start = self.offset.add_ms(start, tz)?;
+        self.get_earliest_bounds(t, closed_window, TimeUnit::Milliseconds, tz)
+    }
+
+    fn get_earliest_bounds(
+        &self,
+        t: i64,
+        closed_window: ClosedWindow,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<Bounds> {
+        let mut probe = t;
+        let start = loop {
+            if let Some(start) = self.every.truncate_with_dst(
+                probe,
+                tu,
+                tz,
+                Ambiguous::Earliest,
+                self.non_existent,
+            )? {
+                break start;
+            }
+            // The window containing `probe` would start in a DST gap, try the next one.
+            probe = self.add_duration(&self.every, probe, tu, tz)?;
+        };
+        let start = self.add_duration(&self.offset, start, tu, tz)?;
         ensure_t_in_or_in_front_of_window(
             self.every,
             t,
-            Duration::add_ms,
+            |duration, t| self.add_duration(duration, t, tu, tz),
             self.period,
             start,
             closed_window,
-            tz,
         )
     }
 
@@ -203,12 +265,7 @@ impl<'a> BoundsIter<'a> {
         let bi = match start_by {
             StartBy::DataPoint => {
                 let mut boundary = boundary;
-                let offset_fn = match tu {
-                    TimeUnit::Nanoseconds => Duration::add_ns,
-                    TimeUnit::Microseconds => Duration::add_us,
-                    TimeUnit::Milliseconds => Duration::add_ms,
-                };
-                boundary.stop = offset_fn(&window.period, boundary.start, tz)?;
+                boundary.stop = window.add_duration(&window.period, boundary.start, tu, tz)?;
                 boundary
             },
             StartBy::WindowBound => match tu {
@@ -224,28 +281,18 @@ impl<'a> BoundsIter<'a> {
             },
             _ => {
                 {
-                    #[allow(clippy::type_complexity)]
-                    let (from, to, offset_fn): (
-                        fn(i64) -> NaiveDateTime,
-                        fn(NaiveDateTime) -> i64,
-                        fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
-                    ) = match tu {
-                        TimeUnit::Nanoseconds => (
-                            timestamp_ns_to_datetime,
-                            datetime_to_timestamp_ns,
-                            Duration::add_ns,
-                        ),
-                        TimeUnit::Microseconds => (
-                            timestamp_us_to_datetime,
-                            datetime_to_timestamp_us,
-                            Duration::add_us,
-                        ),
-                        TimeUnit::Milliseconds => (
-                            timestamp_ms_to_datetime,
-                            datetime_to_timestamp_ms,
-                            Duration::add_ms,
-                        ),
-                    };
+                    let (from, to): (fn(i64) -> NaiveDateTime, fn(NaiveDateTime) -> i64) =
+                        match tu {
+                            TimeUnit::Nanoseconds => {
+                                (timestamp_ns_to_datetime, datetime_to_timestamp_ns)
+                            },
+                            TimeUnit::Microseconds => {
+                                (timestamp_us_to_datetime, datetime_to_timestamp_us)
+                            },
+                            TimeUnit::Milliseconds => {
+                                (timestamp_ms_to_datetime, datetime_to_timestamp_ms)
+                            },
+                        };
                     // find beginning of the week.
                     let dt = from(boundary.start);
                     match tz {
@@ -255,14 +302,16 @@ impl<'a> BoundsIter<'a> {
                             let dt = dt.beginning_of_week();
                             let dt = dt.naive_utc();
                             let start = to(dt);
+                            let offset_fn = |duration: &Duration, t: i64| {
+                                window.add_duration(duration, t, tu, Some(tz))
+                            };
                             // adjust start of the week based on given day of the week
                             let start = offset_fn(
                                 &Duration::parse(&format!("{}d", start_by.weekday().unwrap())),
                                 start,
-                                Some(tz),
                             )?;
                             // apply the 'offset'
-                            let start = offset_fn(&window.offset, start, Some(tz))?;
+                            let start = offset_fn(&window.offset, start)?;
                             // make sure the first datapoint has a chance to be included
                             // and compute the end of the window defined by the 'period'
                             ensure_t_in_or_in_front_of_window(
@@ -272,7 +321,6 @@ impl<'a> BoundsIter<'a> {
                                 window.period,
                                 start,
                                 closed_window,
-                                Some(tz),
                             )?
                         },
                         _ => {
@@ -281,15 +329,17 @@ impl<'a> BoundsIter<'a> {
                             let dt = dt.beginning_of_week();
                             let dt = dt.naive_utc();
                             let start = to(dt);
+                            let offset_fn = |duration: &Duration, t: i64| {
+                                window.add_duration(duration, t, tu, None)
+                            };
                             // adjust start of the week based on given day of the week
                             let start = offset_fn(
                                 &Duration::parse(&format!("{}d", start_by.weekday().unwrap())),
                                 start,
-                                None,
                             )
                             .unwrap();
                             // apply the 'offset'
-                            let start = offset_fn(&window.offset, start, None).unwrap();
+                            let start = offset_fn(&window.offset, start).unwrap();
                             // make sure the first datapoint has a chance to be included
                             // and compute the end of the window defined by the 'period'
                             ensure_t_in_or_in_front_of_window(
@@ -299,7 +349,6 @@ impl<'a> BoundsIter<'a> {
                                 window.period,
                                 start,
                                 closed_window,
-                                None,
                             )?
                         },
                     }
@@ -322,22 +371,9 @@ impl<'a> Iterator for BoundsIter<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         if self.bi.start < self.boundary.stop {
             let out = self.bi;
-            match self.tu {
-                // TODO: find some way to propagate error instead of unwrapping?
-                // Issue is that `next` needs to return `Option`.
-                TimeUnit::Nanoseconds => {
-                    self.bi.start = self.window.every.add_ns(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.every.add_ns(self.bi.stop, self.tz).unwrap();
-                },
-                TimeUnit::Microseconds => {
-                    self.bi.start = self.window.every.add_us(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.every.add_us(self.bi.stop, self.tz).unwrap();
-                },
-                TimeUnit::Milliseconds => {
-                    self.bi.start = self.window.every.add_ms(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.every.add_ms(self.bi.stop, self.tz).unwrap();
-                },
-            }
+            // TODO: find some way to propagate error instead of unwrapping?
+            // Issue is that `next` needs to return `Option`.
+            self.bi = self.window.next_bounds(out, self.tu, self.tz).unwrap();
             Some(out)
         } else {
             None
@@ -0,0 +1,178 @@
+//! Cooperative interruption of long running kernels.
+//!
+//! Heavy kernels call [`try_raise_interrupt`] in their chunk loops. If an interrupt is
+//! requested, either for all queries with [`request_interrupt`] (e.g. from a Ctrl-C handler)
+//! or through the cancel token of the query running on the current thread, the kernel unwinds
+//! with an [`Interrupted`] payload. [`catch_interrupt`] turns that back into a `Cancelled` error
+//! at the query boundary. Kernels that run outside of [`catch_interrupt`] are never interrupted.
+//!
+//! The cancel token is only known on the thread that runs the query. Kernels that spread their
+//! work over the thread pool capture an [`InterruptCheck`] first and check that in their jobs.
+use std::cell::RefCell;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use polars_error::{polars_bail, polars_err, PolarsResult};
+
+static INTERRUPT_REQUESTED: AtomicBool = AtomicBool::new(false);
+// Number of active `catch_interrupt` scopes over all threads.
+static N_CATCHERS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static CANCEL_TOKEN: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// Unwind payload of an interrupted kernel.
+#[derive(Debug)]
+pub struct Interrupted;
+
+/// Interrupt all running queries.
+///
+/// This is a no-op if no query is running, so it doesn't affect queries started later on.
+pub fn request_interrupt() {
+    if N_CATCHERS.load(Ordering::Relaxed) > 0 {
+        INTERRUPT_REQUESTED.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Whether the work on the current thread should be interrupted.
+#[inline]
+pub fn is_interrupted() -> bool {
+    INTERRUPT_REQUESTED.load(Ordering::Relaxed)
+        || CANCEL_TOKEN.with(|token| {
+            token
+                .borrow()
+                .as_ref()
+                .map_or(false, |token| token.load(Ordering::Relaxed))
+        })
+}
+
+/// The cancel token of the query running on the current thread, if any.
+pub fn current_cancel_token() -> Option<Arc<AtomicBool>> {
+    CANCEL_TOKEN.with(|token| token.borrow().clone())
+}
+
+/// Unwind with [`Interrupted`] if the work on the current thread should be interrupted.
+///
+/// Call this between chunks of work, it is cheap but not free.
+#[inline]
+pub fn try_raise_interrupt() {
+    if N_CATCHERS.load(Ordering::Relaxed) > 0 && is_interrupted() {
+        resume_unwind(Box::new(Interrupted))
+    }
+}
+
+/// Interrupt check of the query running on the current thread that can be moved to other
+/// threads.
+#[derive(Clone, Default)]
+pub struct InterruptCheck {
+    cancel_token: Option<Arc<AtomicBool>>,
+}
+
+impl InterruptCheck {
+    pub fn current() -> Self {
+        Self {
+            cancel_token: current_cancel_token(),
+        }
+    }
+
+    /// Check of the query with `cancel_token`, for work that doesn't run on the query thread,
+    /// like the operators of the streaming engine.
+    pub fn from_token(cancel_token: Arc<AtomicBool>) -> Self {
+        Self {
+            cancel_token: Some(cancel_token),
+        }
+    }
+
+    #[inline]
+    pub fn is_interrupted(&self) -> bool {
+        INTERRUPT_REQUESTED.load(Ordering::Relaxed)
+            || self
+                .cancel_token
+                .as_ref()
+                .map_or(false, |token| token.load(Ordering::Relaxed))
+    }
+
+    /// Unwind with [`Interrupted`] if the query should be interrupted.
+    #[inline]
+    pub fn try_raise(&self) {
+        if N_CATCHERS.load(Ordering::Relaxed) > 0 && self.is_interrupted() {
+            resume_unwind(Box::new(Interrupted))
+        }
+    }
+
+    /// Return a `Cancelled` error if the query should be interrupted. Use this instead of
+    /// [`try_raise`](Self::try_raise) where errors can be returned.
+    #[inline]
+    pub fn check(&self) -> PolarsResult<()> {
+        if self.is_interrupted() {
+            polars_bail!(Cancelled: "query interrupted")
+        }
+        Ok(())
+    }
+}
+
+/// Run `f`, turning an interruption of the kernels it runs into an error.
+///
+/// Setting `cancel_token` interrupts the kernels that run on the current thread.
+pub fn catch_interrupt<T, F>(cancel_token: Option<Arc<AtomicBool>>, f: F) -> PolarsResult<T>
+where
+    F: FnOnce() -> PolarsResult<T>,
+{
+    N_CATCHERS.fetch_add(1, Ordering::Relaxed);
+    let prev_token = CANCEL_TOKEN.with(|token| token.replace(cancel_token));
+    let out = catch_unwind(AssertUnwindSafe(f));
+    CANCEL_TOKEN.with(|token| token.replace(prev_token));
+    if N_CATCHERS.fetch_sub(1, Ordering::Relaxed) == 1 {
+        // The interrupt has been handled by all queries.
+        INTERRUPT_REQUESTED.store(false, Ordering::Relaxed);
+    }
+
+    match out {
+        Ok(out) => out,
+        Err(payload) if payload.is::<Interrupted>() => {
+            Err(polars_err!(Cancelled: "query interrupted"))
+        },
+        Err(payload) => resume_unwind(payload),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_error::PolarsError;
+
+    use super::*;
+
+    #[test]
+    fn test_catch_interrupt() {
+        // Outside of `catch_interrupt` nothing is interrupted.
+        try_raise_interrupt();
+
+        let token = Arc::new(AtomicBool::new(false));
+        let out = catch_interrupt(Some(token.clone()), || {
+            try_raise_interrupt();
+            token.store(true, Ordering::Relaxed);
+            try_raise_interrupt();
+            Ok(())
+        });
+        assert!(matches!(out, Err(PolarsError::Cancelled(_))));
+        assert!(!is_interrupted());
+
+        // The check is carried over to other threads.
+        let token = Arc::new(AtomicBool::new(true));
+        let out = catch_interrupt(Some(token), || {
+            let check = InterruptCheck::current();
+            let handle = std::thread::spawn(move || check.try_raise());
+            // Propagate the unwind like the thread pool does.
+            handle
+                .join()
+                .unwrap_or_else(|payload| resume_unwind(payload));
+            Ok(())
+        });
+        assert!(out.is_err());
+
+        let out = catch_interrupt(None, || Ok(1));
+        assert_eq!(out.unwrap(), 1);
+    }
+}
@@ -13,11 +13,14 @@ pub mod floor_divmod;
 pub mod functions;
 pub mod hashing;
 pub mod idx_vec;
+pub mod interrupt;
 pub mod mem;
+pub mod memory_budget;
 pub mod min_max;
 pub mod priority;
 pub mod slice;
 pub mod sort;
+pub mod spill;
 pub mod sync;
 #[cfg(feature = "sysinfo")]
 pub mod sys;
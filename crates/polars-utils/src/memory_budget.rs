@@ -0,0 +1,153 @@
+//! Memory limit of a query that is shared by its operators, see [`MemoryBudget`].
+//!
+//! Like the cancel token in [`interrupt`](crate::interrupt), the budget of the query running on
+//! the current thread is set with [`with_memory_budget`], so that operators created while the
+//! query runs can find it with [`MemoryBudget::current`].
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+thread_local! {
+    static MEMORY_BUDGET: RefCell<Option<MemoryBudget>> = const { RefCell::new(None) };
+}
+
+#[derive(Default)]
+struct Usage {
+    used: AtomicUsize,
+    peak: AtomicUsize,
+    spills: AtomicUsize,
+}
+
+/// A snapshot of the memory usage of a query.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The limit in bytes.
+    pub limit: usize,
+    /// The bytes that are reserved.
+    pub used: usize,
+    /// The largest number of bytes that were reserved at the same time.
+    pub peak: usize,
+    /// The number of operators that spilled to disk.
+    pub spills: usize,
+}
+
+/// A memory limit of a query.
+///
+/// Operators reserve the memory they hold on to against the budget. When the budget gets tight
+/// they spill to disk earlier, work on smaller morsels and process fewer morsels at the same
+/// time, instead of running out of memory. The limit is a target rather than a hard bound, as
+/// short lived allocations are not reserved.
+#[derive(Clone)]
+pub struct MemoryBudget {
+    limit: usize,
+    usage: Arc<Usage>,
+}
+
+impl MemoryBudget {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            usage: Default::default(),
+        }
+    }
+
+    /// The budget of the query running on the current thread, if any.
+    pub fn current() -> Option<Self> {
+        MEMORY_BUDGET.with(|budget| budget.borrow().clone())
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn used(&self) -> usize {
+        self.usage.used.load(Ordering::Relaxed)
+    }
+
+    /// The bytes that can still be reserved before the limit is reached.
+    pub fn available(&self) -> usize {
+        self.limit.saturating_sub(self.used())
+    }
+
+    /// The fraction of the limit that is reserved. This exceeds 1.0 if the limit is overrun.
+    pub fn pressure(&self) -> f64 {
+        self.used() as f64 / std::cmp::max(self.limit, 1) as f64
+    }
+
+    pub fn reserve(&self, bytes: usize) {
+        let used = self.usage.used.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.usage.peak.fetch_max(used, Ordering::Relaxed);
+    }
+
+    pub fn release(&self, bytes: usize) {
+        let _ = self
+            .usage
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_sub(bytes))
+            });
+    }
+
+    /// Record that an operator started spilling to disk.
+    pub fn record_spill(&self) {
+        self.usage.spills.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn usage(&self) -> MemoryUsage {
+        MemoryUsage {
+            limit: self.limit,
+            used: self.used(),
+            peak: self.usage.peak.load(Ordering::Relaxed),
+            spills: self.usage.spills.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Run `f` with `budget` as the budget of the query running on the current thread.
+pub fn with_memory_budget<T, F>(budget: Option<MemoryBudget>, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    struct Restore(Option<MemoryBudget>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            MEMORY_BUDGET.with(|budget| budget.replace(self.0.take()));
+        }
+    }
+
+    let _restore = Restore(MEMORY_BUDGET.with(|current| current.replace(budget)));
+    f()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_memory_budget() {
+        let budget = MemoryBudget::new(100);
+        budget.reserve(60);
+        budget.reserve(60);
+        budget.release(80);
+        budget.record_spill();
+        assert_eq!(
+            budget.usage(),
+            MemoryUsage {
+                limit: 100,
+                used: 40,
+                peak: 120,
+                spills: 1
+            }
+        );
+        assert_eq!(budget.available(), 60);
+        budget.release(80);
+        assert_eq!(budget.used(), 0);
+
+        assert!(MemoryBudget::current().is_none());
+        with_memory_budget(Some(budget.clone()), || {
+            assert_eq!(MemoryBudget::current().unwrap().limit(), 100);
+        });
+        assert!(MemoryBudget::current().is_none());
+    }
+}
@@ -0,0 +1,136 @@
+//! The bytes the operators of a query spill to disk, see [`SpillTracker`].
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use polars_error::{polars_ensure, PolarsResult};
+
+/// The bytes an operator spilled to disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OperatorSpill {
+    /// The name of the operator, e.g. `sort` or `group_by`.
+    pub operator: &'static str,
+    pub bytes: usize,
+}
+
+/// A snapshot of the bytes a query spilled to disk.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpillUsage {
+    /// The limit in bytes, if any.
+    pub limit: Option<usize>,
+    /// The bytes spilled by all operators.
+    pub bytes: usize,
+    /// Every operator that spilled, in the order they started spilling.
+    pub operators: Vec<OperatorSpill>,
+}
+
+/// Accounts the bytes that the operators of a query spill to disk, and limits them.
+///
+/// An operator registers the counter of the bytes it wrote once it starts spilling, and checks
+/// the limit before it spills more. The bytes are counted once they are written, so a query can
+/// overrun the limit by the spills that are in flight.
+#[derive(Clone, Default)]
+pub struct SpillTracker {
+    limit: Option<usize>,
+    operators: Arc<Mutex<Vec<(&'static str, Arc<AtomicUsize>)>>>,
+}
+
+impl SpillTracker {
+    pub fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            operators: Default::default(),
+        }
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Account the bytes that `operator` writes to `bytes` to the query.
+    pub fn register(&self, operator: &'static str, bytes: Arc<AtomicUsize>) {
+        self.operators.lock().unwrap().push((operator, bytes))
+    }
+
+    /// The bytes spilled by all operators.
+    pub fn bytes(&self) -> usize {
+        self.operators
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, bytes)| bytes.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    /// Error with `SpillLimitExceeded` if the operators spilled more than the limit.
+    pub fn check_limit(&self) -> PolarsResult<()> {
+        if let Some(limit) = self.limit {
+            let bytes = self.bytes();
+            polars_ensure!(
+                bytes <= limit,
+                SpillLimitExceeded: "the query spilled {} bytes to disk, its limit is {} bytes",
+                bytes, limit
+            );
+        }
+        Ok(())
+    }
+
+    pub fn usage(&self) -> SpillUsage {
+        let operators = self
+            .operators
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(operator, bytes)| OperatorSpill {
+                operator,
+                bytes: bytes.load(Ordering::Relaxed),
+            })
+            .collect::<Vec<_>>();
+        SpillUsage {
+            limit: self.limit,
+            bytes: operators.iter().map(|op| op.bytes).sum(),
+            operators,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_error::PolarsError;
+
+    use super::*;
+
+    #[test]
+    fn test_spill_tracker() {
+        let tracker = SpillTracker::new(Some(100));
+        let sort = Arc::new(AtomicUsize::new(40));
+        let group_by = Arc::new(AtomicUsize::new(0));
+        tracker.register("sort", sort.clone());
+        tracker.register("group_by", group_by.clone());
+        group_by.fetch_add(50, Ordering::Relaxed);
+        assert!(tracker.check_limit().is_ok());
+        assert_eq!(
+            tracker.usage(),
+            SpillUsage {
+                limit: Some(100),
+                bytes: 90,
+                operators: vec![
+                    OperatorSpill {
+                        operator: "sort",
+                        bytes: 40
+                    },
+                    OperatorSpill {
+                        operator: "group_by",
+                        bytes: 50
+                    },
+                ],
+            }
+        );
+
+        sort.fetch_add(20, Ordering::Relaxed);
+        assert!(matches!(
+            tracker.check_limit(),
+            Err(PolarsError::SpillLimitExceeded(_))
+        ));
+        assert!(SpillTracker::default().check_limit().is_ok());
+    }
+}
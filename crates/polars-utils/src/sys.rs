@@ -1,7 +1,7 @@
 use std::sync::Mutex;
 
 use once_cell::sync::Lazy;
-use sysinfo::System;
+use sysinfo::{Pid, System};
 
 /// Startup system is expensive, so we do it once
 pub struct MemInfo {
@@ -23,3 +23,12 @@ impl MemInfo {
 pub static MEMINFO: Lazy<MemInfo> = Lazy::new(|| MemInfo {
     sys: Mutex::new(System::new()),
 });
+
+/// Whether the process `pid` is running. This is assumed if it can't be determined on this
+/// platform.
+pub fn process_is_running(pid: u32) -> bool {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return true;
+    }
+    System::new().refresh_process(Pid::from_u32(pid))
+}
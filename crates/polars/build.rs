@@ -1,7 +1,44 @@
+use std::path::PathBuf;
+
+/// Write the features of this crate that are enabled to `$OUT_DIR/enabled_features.rs`, so that
+/// they can be reported at runtime.
+fn write_enabled_features() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    let manifest = std::fs::read_to_string("Cargo.toml").unwrap();
+
+    let mut in_features = false;
+    let mut enabled = vec![];
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_features = line == "[features]";
+            continue;
+        }
+        if !in_features || line.starts_with('#') {
+            continue;
+        }
+        let Some((name, _)) = line.split_once('=') else {
+            continue;
+        };
+        let name = name.trim();
+        let var = format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"));
+        if std::env::var_os(var).is_some() {
+            enabled.push(name.to_string());
+        }
+    }
+
+    let out = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("enabled_features.rs");
+    std::fs::write(
+        out,
+        format!("pub const ENABLED_FEATURES: &[&str] = &{enabled:?};\n"),
+    )
+    .unwrap();
+}
+
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
     let channel = version_check::Channel::read().unwrap();
     if channel.is_nightly() {
         println!("cargo:rustc-cfg=feature=\"nightly\"");
     }
+    write_enabled_features();
 }
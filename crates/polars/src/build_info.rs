@@ -0,0 +1,31 @@
+//! The features and data types that this build of Polars was compiled with.
+//!
+//! Useful to check what a trimmed down build, e.g. with the `minimal` feature, still includes.
+
+include!(concat!(env!("OUT_DIR"), "/enabled_features.rs"));
+
+/// The opt-in data types that are compiled in, e.g. `"date"` for `dtype-date`.
+///
+/// The data types that are always available, like the 32 and 64 bit numbers, `Boolean`,
+/// `String`, `Binary` and `List`, are not listed.
+pub fn enabled_dtypes() -> impl Iterator<Item = &'static str> {
+    ENABLED_FEATURES
+        .iter()
+        .filter_map(|feature| feature.strip_prefix("dtype-"))
+        .filter(|dtype| !matches!(*dtype, "full" | "slim"))
+}
+
+/// Whether the feature `name` is enabled.
+pub fn is_enabled(name: &str) -> bool {
+    ENABLED_FEATURES.contains(&name)
+}
+
+/// A human readable report of the enabled features and opt-in data types.
+pub fn report() -> String {
+    format!(
+        "polars {}\nfeatures: {}\nopt-in dtypes: {}",
+        crate::VERSION,
+        ENABLED_FEATURES.join(", "),
+        enabled_dtypes().collect::<Vec<_>>().join(", "),
+    )
+}
@@ -176,11 +176,15 @@
 //! ## Compile times and opt-in features
 //! The opt-in features are (not including dtype features):
 //!
+//! * `minimal` - CSV and the core expressions, without regex, temporal or cloud support. Use it
+//!               with `default-features = false` for small WASM and embedded builds. The
+//!               features and data types that a build includes are listed by [`build_info`].
 //! * `performant` - Longer compile times more fast paths.
 //! * `lazy` - Lazy API
 //!     - `regex` - Use regexes in [column selection]
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
 //! * `sql` - Pass SQL queries to polars.
+//!     - `flight_sql` - Serve the tables of a `SQLContext` over Arrow Flight SQL.
 //! * `streaming` - Be able to process datasets that are larger than RAM.
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
@@ -188,6 +192,9 @@
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_similarity` - `levenshtein`, `jaro_winkler`, `hamming`
+//!     - `string_tokenize` - `tokenize`, `ngrams`, `strip_accents`
+//! * `polars-ml` - Run [ONNX](https://onnx.ai/) models over feature columns with `onnx_predict`.
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -203,9 +210,16 @@
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
+//!     - `arrow_stream` - Import and export `DataFrame`s and query results through the Arrow C stream
+//!                        interface.
+//!     - `binary_format` - Versioned binary serialization of `LazyFrame` plans and `DataFrame`s.
+//!     - `pipeline_spec` - Build a `LazyFrame` from a JSON or YAML description of sources, steps and a sink.
+//!     - `repro` - Save a query with (samples of) its inputs to a single file that can be replayed.
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `range_reads` - Read Parquet and IPC files of which byte ranges are fetched on demand,
+//!                       e.g. by a JavaScript callback under wasm.
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!                      Supported compressions:
 //!                         * zip
@@ -223,12 +237,23 @@
 //!     - `rows` - Create [`DataFrame`] from rows and extract rows from [`DataFrame`]s.
 //!                And activates `pivot` and `transpose` operations
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
+//!     - `point_in_time_join` - Join rows to the validity ranges of the versions of a slowly
+//!                              changing dimension.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
 //!     - `row_hash` - Utility to hash [`DataFrame`] rows to [`UInt64Chunked`]
-//!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
+//!     - `diagonal_concat` - Concat diagonally thereby combining different schemas, or by name
+//!       to a fixed schema.
 //!     - `dataframe_arithmetic` - Arithmetic on ([`Dataframe`] and [`DataFrame`]s) and ([`DataFrame`] on [`Series`])
 //!     - `partition_by` - Split into multiple [`DataFrame`]s partitioned by groups.
+//!     - `rename_normalized` - Normalize column names to snake case (or another case) and ASCII,
+//!                             resolving the names that clash.
+//!     - `semantic_types` - Infer semantic types like e-mail addresses, URLs and currency amounts
+//!                          of String columns from a sample of their values.
+//!     - `upsert` - Update the rows of a [`DataFrame`] that match another [`DataFrame`] and append
+//!                  the others.
+//!     - `pandas_compat` - [`get_dummies`](compat::get_dummies), [`crosstab`](compat::crosstab) and
+//!                         [`cut`](compat::cut) that behave like their pandas namesakes.
 //! * [`Series`]/[`Expr`] operations:
 //!     - `is_in` - Check for membership in [`Series`].
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip).
@@ -257,6 +282,7 @@
 //!     - `product` - Compute the product of a [`Series`].
 //!     - `diff` - [`diff`] operation.
 //!     - `pct_change` - Compute change percentages.
+//!     - `finance` - Returns, max drawdown and Sharpe ratio aggregations.
 //!     - `unique_counts` - Count unique values in expressions.
 //!     - `log` - Logarithms for [`Series`].
 //!     - `list_to_struct` - Convert [`List`] to [`Struct`] dtypes.
@@ -273,6 +299,8 @@
 //!     - `extract_groups` - Extract multiple regex groups from strings.
 //!     - `cov` - Covariance and correlation functions.
 //!     - `find_many` - Find/replace multiple string patterns at once.
+//!     - `binary_encryption` - Encrypt and decrypt binary and string values with AES-256-GCM or
+//!                             ChaCha20-Poly1305.
 //! * [`DataFrame`] pretty printing
 //!     - `fmt` - Activate [`DataFrame`] formatting
 //!
@@ -365,6 +393,18 @@
 //!
 //! ## Config with ENV vars
 //!
+//! The settings below can also be set without touching the process environment with a [`Config`]
+//! that is active until its guard is dropped:
+//!
+//! ```rust
+//! use polars::Config;
+//!
+//! let _guard = Config::new()
+//!     .with_tbl_formatting("ASCII_MARKDOWN")
+//!     .with_streaming_chunk_size(10_000)
+//!     .scoped();
+//! ```
+//!
 //! * `POLARS_FMT_TABLE_FORMATTING` -> define styling of tables using any of the following options (default = UTF8_FULL_CONDENSED). These options are defined by comfy-table which provides examples for each at <https://github.com/Nukesor/comfy-table/blob/main/src/style/presets.rs>
 //!   * `ASCII_FULL`
 //!   * `ASCII_FULL_CONDENSED`
@@ -401,17 +441,30 @@
 //! * `POLARS_PARTITION_UNIQUE_COUNT` -> at which (estimated) key count a partitioned group_by should run.
 //!                                          defaults to `1000`, any higher cardinality will run default group_by.
 //! * `POLARS_FORCE_PARTITION` -> force partitioned group_by if the keys and aggregations allow it.
+//! * `POLARS_PARTITION_SKEW_FRACTION` -> the fraction of (sampled) rows belonging to a few frequent keys above which a
+//!                                      high cardinality group_by pre-aggregates anyway. Defaults to `0.1`.
+//! * `POLARS_PARTITION_FLUSH_SIZE` -> the number of rows after which the pre-aggregation of skewed keys is flushed.
+//! * `POLARS_NO_RADIX_GROUP_BY` -> polars may radix partition the keys of a group_by, based on their (estimated) cardinality.
+//!                                 Setting this env var will turn radix partitioning off.
+//! * `POLARS_FORCE_RADIX_GROUP_BY` -> force radix partitioning of the keys of a multithreaded group_by.
+//! * `POLARS_LOW_LATENCY_ROW_LIMIT` -> the number of rows below which a query in low latency mode skips optimizations
+//!                                     and parallelism that only pay off on large data. Defaults to `10000`.
 //! * `POLARS_ALLOW_EXTENSION` -> allows for [`ObjectChunked<T>`] to be used in arrow, opening up possibilities like using
 //!                               `T` in complex lazy expressions. However this does require `unsafe` code allow this.
 //! * `POLARS_NO_PARQUET_STATISTICS` -> if set, statistics in parquet files are ignored.
 //! * `POLARS_PANIC_ON_ERR` -> panic instead of returning an Error.
 //! * `POLARS_NO_CHUNKED_JOIN` -> force rechunk before joins.
+//! * `POLARS_STREAMING_CHUNK_SIZE` -> number of rows per chunk in the streaming engine.
+//! * `POLARS_TEMP_DIR` -> directory the streaming engine spills to.
+//! * `POLARS_FILE_CACHE_TTL` -> default time to live in seconds of files in the cloud file cache.
+//! * `POLARS_CLOUD_MAX_RETRIES` -> default number of retries of cloud requests.
 //!
 //! ## User guide
 //!
 //! If you want to read more, check the [user guide](https://docs.pola.rs/).
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![allow(ambiguous_glob_reexports)]
+pub mod build_info;
 pub mod docs;
 #[doc(hidden)]
 pub mod export;
@@ -419,18 +472,24 @@ pub mod prelude;
 #[cfg(feature = "sql")]
 pub mod sql;
 
+pub use polars_core::config::{Config, ConfigGuard};
 pub use polars_core::{
     apply_method_all_arrow_series, chunked_array, datatypes, df, error, frame, functions, series,
     testing,
 };
 #[cfg(feature = "dtype-categorical")]
 pub use polars_core::{enable_string_cache, using_string_cache};
+#[cfg(feature = "arrow_stream")]
+pub use polars_ffi::stream as arrow_stream;
 #[cfg(feature = "polars-io")]
 pub use polars_io as io;
 #[cfg(feature = "lazy")]
 pub use polars_lazy as lazy;
+#[cfg(feature = "pandas_compat")]
+pub use polars_ops::compat;
 #[cfg(feature = "temporal")]
 pub use polars_time as time;
+pub use polars_utils::interrupt;
 
 /// Polars crate version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -1,2 +1,4 @@
+#[cfg(feature = "flight_sql")]
+pub use polars_sql::flight_sql;
 pub use polars_sql::function_registry::*;
 pub use polars_sql::{keywords, sql_expr, SQLContext};
@@ -0,0 +1,12 @@
+use polars::build_info::*;
+
+#[test]
+fn test_build_info() {
+    assert!(is_enabled("csv"));
+    assert!(!is_enabled("does-not-exist"));
+    assert_eq!(
+        enabled_dtypes().any(|dtype| dtype == "categorical"),
+        cfg!(feature = "dtype-categorical")
+    );
+    assert!(report().contains("csv"));
+}
@@ -630,3 +630,31 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "dtype-i128", feature = "dtype-u128"))]
+fn test_join_and_group_by_128_bit_keys() -> PolarsResult<()> {
+    let big = u64::MAX as i128 + 1;
+    let left = df![
+        "id" => [big, -big, 7],
+        "uid" => [big as u128, 1, u128::MAX],
+        "a" => [1, 2, 3],
+    ]?;
+    let right = df![
+        "id" => [7, big],
+        "uid" => [u128::MAX, big as u128],
+        "b" => ["x", "y"],
+    ]?;
+
+    let out = left.join(&right, ["id"], ["id"], JoinArgs::new(JoinType::Inner))?;
+    assert_eq!(
+        out.column("a")?.sort(Default::default())?,
+        Series::new("a", &[1, 3])
+    );
+    let out = left.join(&right, ["uid"], ["uid"], JoinArgs::new(JoinType::Left))?;
+    assert_eq!(out.column("b")?.null_count(), 1);
+
+    let groups = df!["id" => [big, 1, big]]?.group_by(["id"])?.groups()?;
+    assert_eq!(groups.height(), 2);
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use polars::export::chrono::NaiveDate;
 use polars::prelude::*;
-use polars_ops::pivot::{pivot, pivot_stable, PivotAgg};
+use polars_ops::pivot::{
+    pivot, pivot_multiple, pivot_stable, PivotAgg, PivotNameOrder, PivotOptions,
+};
 
 #[test]
 #[cfg(feature = "dtype-date")]
@@ -270,3 +272,145 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_pivot_multiple_aggregations() -> PolarsResult<()> {
+    let df = df![
+        "index" => ["A", "A", "B", "B"],
+        "columns" => ["k", "k", "k", "l"],
+        "price" => [1.0, 3.0, 2.0, 4.0],
+        "qty" => [1, 2, 3, 4],
+    ]?;
+    let aggs = || {
+        vec![
+            ("sum".to_string(), PivotAgg::Sum),
+            ("mean".to_string(), PivotAgg::Mean),
+        ]
+    };
+    let mut options = PivotOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+
+    let out = pivot_multiple(
+        &df,
+        ["columns"],
+        Some(["index"]),
+        Some(["price"]),
+        aggs(),
+        &options,
+    )?;
+    let expected = df![
+        "index" => ["A", "B"],
+        "sum_k" => [Some(4.0), Some(2.0)],
+        "sum_l" => [None, Some(4.0)],
+        "mean_k" => [Some(2.0), Some(2.0)],
+        "mean_l" => [None, Some(4.0)],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    options.separator = Some(".".into());
+    options.name_order = PivotNameOrder::OnFirst;
+    let out = pivot_multiple(
+        &df,
+        ["columns"],
+        Some(["index"]),
+        Some(["price", "qty"]),
+        aggs(),
+        &options,
+    )?;
+    assert_eq!(
+        out.get_column_names(),
+        &[
+            "index",
+            "k.price.sum",
+            "l.price.sum",
+            "k.price.mean",
+            "l.price.mean",
+            "k.qty.sum",
+            "l.qty.sum",
+            "k.qty.mean",
+            "l.qty.mean",
+        ]
+    );
+
+    let duplicate = vec![
+        ("sum".to_string(), PivotAgg::Sum),
+        ("sum".to_string(), PivotAgg::Mean),
+    ];
+    assert!(pivot_multiple(
+        &df,
+        ["columns"],
+        Some(["index"]),
+        Some(["price"]),
+        duplicate,
+        &options,
+    )
+    .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_pivot_margins_and_sort_by_value() -> PolarsResult<()> {
+    let df = df![
+        "index" => ["A", "A", "B", "B"],
+        "columns" => ["k", "k", "k", "l"],
+        "price" => [1.0, 3.0, 2.0, 4.0],
+    ]?;
+    let aggs = || vec![("sum".to_string(), PivotAgg::Sum)];
+    let mut options = PivotOptions {
+        maintain_order: true,
+        margins: true,
+        ..Default::default()
+    };
+
+    let out = pivot_multiple(
+        &df,
+        ["columns"],
+        Some(["index"]),
+        Some(["price"]),
+        aggs(),
+        &options,
+    )?;
+    let expected = df![
+        "index" => ["A", "B", "All"],
+        "k" => [Some(4.0), Some(2.0), Some(6.0)],
+        "l" => [None, Some(4.0), Some(4.0)],
+        "All" => [4.0, 6.0, 10.0],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    options.margins = false;
+    options.sort_columns_by_value = true;
+    let out = pivot_multiple(
+        &df,
+        ["columns"],
+        Some(["index"]),
+        Some(["price"]),
+        aggs(),
+        &options,
+    )?;
+    assert_eq!(out.get_column_names(), &["index", "l", "k"]);
+    options.descending = true;
+    let out = pivot_multiple(
+        &df,
+        ["columns"],
+        Some(["index"]),
+        Some(["price"]),
+        aggs(),
+        &options,
+    )?;
+    assert_eq!(out.get_column_names(), &["index", "k", "l"]);
+
+    // Margins need an aggregation function.
+    let out = pivot_multiple(
+        &df,
+        ["columns"],
+        Some(["index"]),
+        Some(["price"]),
+        Vec::<(String, PivotAgg)>::new(),
+        &options,
+    );
+    assert!(out.is_err());
+    Ok(())
+}
@@ -35,3 +35,77 @@ fn test_construct_list_of_null_series() {
     assert_eq!(s.null_count(), 0);
     assert_eq!(s.field().name(), "a");
 }
+
+#[test]
+#[cfg(all(feature = "dtype-i128", feature = "dtype-decimal"))]
+fn test_int128_arithmetic_and_decimal_cast() -> PolarsResult<()> {
+    let big = i64::MAX as i128 * 4;
+    let a = Series::new("a", &[big, -big, 1]);
+    assert_eq!(a.dtype(), &DataType::Int128);
+
+    let b = Series::new("b", &[1i128, 2, 3]);
+    assert_eq!((&a + &b)?, Series::new("a", &[big + 1, -big + 2, 4]));
+    assert_eq!(
+        a.cast(&DataType::Int64)?.null_count(),
+        2,
+        "out-of-range values become null"
+    );
+
+    let dec = a.cast(&DataType::Decimal(Some(38), Some(2)))?;
+    assert_eq!(dec.cast(&DataType::Int128)?, a);
+    Ok(())
+}
+
+/// Strings that are compared case-insensitively and displayed in upper case.
+struct CaseInsensitive;
+
+impl ExtensionType for CaseInsensitive {
+    fn name(&self) -> &str {
+        "test.case_insensitive"
+    }
+
+    fn storage_dtype(&self) -> DataType {
+        DataType::String
+    }
+
+    fn format_value(&self, value: &AnyValue) -> Option<String> {
+        value.get_str().map(|s| s.to_uppercase())
+    }
+
+    fn sort_key(&self, storage: &Series) -> Option<Series> {
+        let key: StringChunked = storage
+            .str()
+            .unwrap()
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.to_lowercase()))
+            .collect();
+        Some(key.into_series())
+    }
+}
+
+#[test]
+fn test_extension_type() -> PolarsResult<()> {
+    let extension = register_extension_type(CaseInsensitive);
+    assert!(get_extension_type("test.case_insensitive").is_some());
+
+    let s = Series::new("a", ["b", "C", "a"]).with_extension(extension)?;
+    assert_eq!(s.str_value(1)?, "C");
+    assert_eq!(s.str_value(0)?, "B");
+
+    let sorted = s.sort(Default::default())?;
+    assert_eq!(sorted.extension().unwrap().name(), "test.case_insensitive");
+    assert_eq!(sorted.into_storage(), Series::new("a", ["a", "b", "C"]));
+
+    // The storage type must match.
+    assert!(Series::new("a", [1, 2])
+        .into_extension("test.case_insensitive")
+        .is_err());
+    // The values are validated.
+    assert!(Series::new("a", [&[1u8, 2][..]])
+        .into_extension("arrow.uuid")
+        .is_err());
+
+    assert!(unregister_extension_type("test.case_insensitive").is_some());
+    assert!(get_extension_type("test.case_insensitive").is_none());
+    Ok(())
+}
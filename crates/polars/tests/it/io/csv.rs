@@ -1,7 +1,7 @@
 use std::io::Cursor;
 use std::num::NonZeroUsize;
 
-use polars::io::RowIndex;
+use polars::io::{DtypeOverrides, RowIndex};
 use polars_core::utils::concat_df;
 
 use super::*;
@@ -39,6 +39,65 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+fn write_csv_many_rounds() {
+    // Many more rows than are serialized in one round of `n_threads * batch_size` rows.
+    let a = (0..10_000).collect::<Vec<i32>>();
+    let b = a.iter().map(|v| format!("s{v}")).collect::<Vec<_>>();
+    let mut df = df!["a" => &a, "b" => &b].unwrap();
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_batch_size(NonZeroUsize::new(7).unwrap())
+        .n_threads(3)
+        .finish(&mut df)
+        .expect("csv written");
+
+    let mut expected = "a,b\n".to_string();
+    for v in &a {
+        expected.push_str(&format!("{v},s{v}\n"));
+    }
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), expected);
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn write_nested() {
+    let l = Series::new(
+        "l",
+        [
+            Some(Series::new("", [1, 2])),
+            Some(Series::new("", [3])),
+            None,
+        ],
+    );
+    let mut df = DataFrame::new(vec![Series::new("id", [1, 2, 3]), l]).unwrap();
+
+    let write = |df: &mut DataFrame, nested: Option<NestedSerialization>| {
+        let mut buf: Vec<u8> = Vec::new();
+        let mut writer = CsvWriter::new(&mut buf);
+        if let Some(nested) = nested {
+            writer = writer.with_nested_serialization(nested);
+        }
+        writer.finish(df)?;
+        PolarsResult::Ok(String::from_utf8(buf).unwrap())
+    };
+
+    assert!(write(&mut df, None).is_err());
+    assert_eq!(
+        write(&mut df, Some(NestedSerialization::Json)).unwrap(),
+        "id,l\n1,\"[1,2]\"\n2,[3]\n3,\n"
+    );
+    assert_eq!(
+        write(&mut df, Some(NestedSerialization::Join(";".into()))).unwrap(),
+        "id,l\n1,1;2\n2,3\n3,\n"
+    );
+    assert_eq!(
+        write(&mut df, Some(NestedSerialization::Explode)).unwrap(),
+        "id,l\n1,1\n1,2\n2,3\n3,\n"
+    );
+}
+
 #[test]
 #[cfg(feature = "timezones")]
 fn write_dates() {
@@ -138,6 +197,19 @@ fn test_read_csv_file() {
     assert_eq!(df.shape(), (27, 4));
 }
 
+#[test]
+fn test_read_csv_from_non_seekable_reader() -> PolarsResult<()> {
+    // `&[u8]` can only be read, like stdin or a pipe.
+    let reader = read_to_bytes_reader(&b"a,b\n1,x\n2,y\n"[..])?;
+    let mut df = CsvReader::new(reader).finish()?;
+    assert_eq!(df.shape(), (2, 2));
+
+    let mut out = vec![];
+    CsvWriter::new(&mut out).finish(&mut df)?;
+    assert_eq!(out, b"a,b\n1,x\n2,y\n");
+    Ok(())
+}
+
 #[test]
 fn test_read_csv_filter() -> PolarsResult<()> {
     let df = CsvReadOptions::default()
@@ -1391,3 +1463,65 @@ fn test_read_io_reader() {
     let expected = CsvReader::new(file).finish().unwrap();
     assert!(df.equals(&expected))
 }
+
+#[test]
+fn test_csv_bad_rows() -> PolarsResult<()> {
+    let csv = "a,b\n1,x\n2,y\nnot a number,z\n4,\"multi\nline\"\n5,v,extra\n6,w\n";
+    let schema = Schema::from_iter([
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::String),
+    ]);
+    let (df, bad_rows) = CsvReadOptions::default()
+        .with_schema(Some(Arc::new(schema)))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish_with_bad_rows()?;
+
+    assert_eq!(
+        df.column("a")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1, 2, 4, 6]
+    );
+    assert_eq!(df.column("b")?.str()?.get(2), Some("multi\nline"));
+    assert_eq!(
+        bad_rows
+            .column("line")?
+            .u64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[4, 7]
+    );
+    assert_eq!(
+        bad_rows.column("raw")?.binary()?.get(0),
+        Some("not a number,z".as_bytes())
+    );
+    assert_eq!(bad_rows.column("error")?.null_count(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_csv_dtype_overrides() -> PolarsResult<()> {
+    let csv = "user_id,ts_start,x,y\n1,2,1,1\n2,3,2,2\n3,4,3,a\n";
+    let overrides = DtypeOverrides::new()
+        .with_dtype("^.*_id$", DataType::String)
+        .with_dtype("ts_*", DataType::Int32)
+        .with_infer_schema_length("y", None);
+    let df = CsvReadOptions::default()
+        .with_infer_schema_length(Some(2))
+        .with_dtype_overrides(Some(Arc::new(overrides)))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+
+    assert_eq!(
+        df.dtypes(),
+        &[
+            DataType::String,
+            DataType::Int32,
+            DataType::Int64,
+            DataType::String
+        ]
+    );
+    assert_eq!(df.column("y")?.str()?.get(2), Some("a"));
+    Ok(())
+}
@@ -46,6 +46,19 @@ fn write_and_read_ipc() {
     assert!(df.equals(&df_read));
 }
 
+#[test]
+fn test_read_ipc_from_non_seekable_reader() -> PolarsResult<()> {
+    let mut buf = vec![];
+    let mut df = create_df();
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+
+    // `&[u8]` can only be read, like stdin or a pipe.
+    let reader = read_to_bytes_reader(buf.as_slice())?;
+    let df_read = IpcReader::new(reader).finish()?;
+    assert!(df.equals(&df_read));
+    Ok(())
+}
+
 #[test]
 fn test_read_ipc_with_projection() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -151,3 +164,98 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+fn test_read_ipc_f16_as_f32() -> PolarsResult<()> {
+    use arrow::array::PrimitiveArray;
+    use arrow::datatypes::{ArrowSchema, Field};
+    use arrow::io::ipc::write::{FileWriter, WriteOptions};
+    use arrow::record_batch::RecordBatchT;
+    use arrow::types::f16;
+
+    let array = PrimitiveArray::from([Some(f16::from_f32(1.5)), None, Some(f16::from_f32(-2.0))]);
+    let schema = Arc::new(ArrowSchema::from(vec![Field::new(
+        "half",
+        array.data_type().clone(),
+        true,
+    )]));
+
+    let mut writer = FileWriter::try_new(
+        Cursor::new(vec![]),
+        schema,
+        None,
+        WriteOptions { compression: None },
+    )?;
+    writer.write(&RecordBatchT::try_new(vec![array.boxed()])?, None)?;
+    writer.finish()?;
+
+    let mut buf = writer.into_inner();
+    buf.set_position(0);
+    let df = IpcReader::new(buf).finish()?;
+
+    let expected = Series::new("half", [Some(1.5f32), None, Some(-2.0)]);
+    assert!(df.column("half")?.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_ipc_extension_types_roundtrip() -> PolarsResult<()> {
+    let uuid = Series::new(
+        "id",
+        [Some(&[0x12u8; 16][..]), None, Some(&[0xabu8; 16][..])],
+    )
+    .into_extension("arrow.uuid")?;
+    let json = Series::new("doc", [r#"{"a": 1}"#, "[]", "null"]).into_extension("arrow.json")?;
+    let mut df = DataFrame::new(vec![uuid, json])?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+    let df_read = IpcReader::new(buf).finish()?;
+
+    assert!(df.equals_missing(&df_read));
+    let extensions = df_read
+        .get_columns()
+        .iter()
+        .map(|s| s.extension().map(|e| e.name().to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        extensions,
+        [
+            Some("arrow.uuid".to_string()),
+            Some("arrow.json".to_string())
+        ]
+    );
+    assert_eq!(
+        df_read.column("id")?.str_value(0)?,
+        "12121212-1212-1212-1212-121212121212"
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_ipc_shared_memory_segment() -> PolarsResult<()> {
+    let name = format!("polars-test-{}", std::process::id());
+    let mut df = create_df();
+
+    let segment = SharedMemorySegment::create(&name, &mut df)?;
+    assert_eq!(segment.name(), name);
+    assert!(segment.size() > 0);
+    assert!(matches!(
+        SharedMemorySegment::create(&name, &mut df),
+        Err(PolarsError::Duplicate(_))
+    ));
+    assert!(SharedMemorySegment::create("a/b", &mut df).is_err());
+
+    let maps = polars::io::mmap::open_memory_maps();
+    let opened = SharedMemorySegment::open(&name)?;
+    assert!(opened.equals(&df));
+    // The opened frame references the mapping of the segment instead of a copy.
+    assert_eq!(polars::io::mmap::open_memory_maps(), maps + 1);
+
+    drop(segment);
+    assert!(SharedMemorySegment::open(&name).is_err());
+    assert!(opened.equals(&df));
+    Ok(())
+}
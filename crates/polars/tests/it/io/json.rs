@@ -1,6 +1,8 @@
 use std::io::Cursor;
 use std::num::NonZeroUsize;
 
+use polars::io::DtypeOverrides;
+
 use super::*;
 
 #[test]
@@ -179,3 +181,191 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_arrow_json_roundtrip() -> PolarsResult<()> {
+    let mut df = df![
+        "int" => [Some(1i64), None, Some(i64::MAX)],
+        "float" => [1.5f64, f64::NEG_INFINITY, f64::INFINITY],
+        "str" => [Some("a"), None, Some("ccc")],
+        "bool" => [Some(true), Some(false), None],
+    ]?;
+    let list = Series::new(
+        "list",
+        [Series::new("", [1i32, 2]), Series::new("", [3i32])],
+    );
+    let list = list.extend_constant(AnyValue::Null, 1)?;
+    df.with_column(list)?;
+
+    let mut buf = vec![];
+    ArrowJsonWriter::new(&mut buf).finish(&mut df)?;
+    let out = ArrowJsonReader::new(Cursor::new(buf)).finish()?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
+
+#[test]
+fn test_arrow_json_restores_chunk_layout() -> PolarsResult<()> {
+    let mut a = Series::new("a", [1i32, 2]);
+    a.append(&Series::new("a", [3i32]))?;
+    let mut b = Series::new("b", ["x"]);
+    b.append(&Series::new("b", ["y", "z"]))?;
+    let mut df = DataFrame::new(vec![a, b])?;
+
+    let mut buf = vec![];
+    ArrowJsonWriter::new(&mut buf)
+        .with_chunk_layout(true)
+        .finish(&mut df.clone())?;
+    let out = ArrowJsonReader::new(Cursor::new(buf.clone())).finish()?;
+    assert!(out.equals(&df));
+    for (left, right) in out.get_columns().iter().zip(df.get_columns()) {
+        assert!(left.chunk_lengths().eq(right.chunk_lengths()));
+    }
+
+    let out = ArrowJsonReader::new(Cursor::new(buf))
+        .set_rechunk(true)
+        .finish()?;
+    assert_eq!(out.n_chunks(), 1);
+    df.as_single_chunk();
+    assert!(out.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_write_json_orient() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(1i64), None],
+        "b" => ["x", "y"],
+    ]?;
+    let write = |df: &mut DataFrame, orient, null_policy| -> PolarsResult<String> {
+        let mut buf = vec![];
+        JsonWriter::new(&mut buf)
+            .with_json_format(JsonFormat::Json)
+            .with_orient(orient)
+            .with_null_policy(null_policy)
+            .finish(df)?;
+        Ok(String::from_utf8(buf).unwrap())
+    };
+
+    assert_eq!(
+        write(&mut df, JsonOrient::Records, JsonNullPolicy::Write)?,
+        r#"[{"a":1,"b":"x"},{"a":null,"b":"y"}]"#
+    );
+    assert_eq!(
+        write(&mut df, JsonOrient::Records, JsonNullPolicy::Skip)?,
+        r#"[{"a":1,"b":"x"},{"b":"y"}]"#
+    );
+    assert_eq!(
+        write(&mut df, JsonOrient::Columns, JsonNullPolicy::Skip)?,
+        r#"{"a":[1,null],"b":["x","y"]}"#
+    );
+    assert_eq!(
+        write(&mut df, JsonOrient::Values, JsonNullPolicy::Write)?,
+        r#"[[1,"x"],[null,"y"]]"#
+    );
+    assert_eq!(
+        write(&mut df.clear(), JsonOrient::Records, JsonNullPolicy::Write)?,
+        "[]"
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_write_json_date_format() -> PolarsResult<()> {
+    let mut df = df!["d" => [0i32, 1]]?;
+    df.apply("d", |s| s.cast(&DataType::Date).unwrap())?;
+
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_date_format(Some("%d/%m/%Y".into()))
+        .finish(&mut df)?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "{\"d\":\"01/01/1970\"}\n{\"d\":\"02/01/1970\"}\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_write_json_batched() -> PolarsResult<()> {
+    let df = df!["a" => [1i64, 2]]?;
+
+    let mut buf = vec![];
+    let mut writer = JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::Json)
+        .batched(&df.schema())?;
+    writer.write_batch(&df.clear())?;
+    writer.write_batch(&df)?;
+    writer.write_batch(&df.slice(0, 1))?;
+    writer.finish()?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        r#"[{"a":1},{"a":2},{"a":1}]"#
+    );
+
+    let mut buf = vec![];
+    let mut writer = JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::Json)
+        .batched(&df.schema())?;
+    writer.finish()?;
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), "[]");
+    Ok(())
+}
+
+#[test]
+fn test_ndjson_bad_rows() -> PolarsResult<()> {
+    let jsonlines =
+        "{\"a\":1,\"b\":\"x\"}\n{\"a\":\"two\",\"b\":\"y\"}\n{\"a\":3\n\n{\"a\":4,\"b\":null}\n";
+    let schema = Schema::from_iter([
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::String),
+    ]);
+    let (df, bad_rows) = JsonLineReader::new(Cursor::new(jsonlines))
+        .with_schema(Arc::new(schema))
+        .finish_with_bad_rows()?;
+
+    assert_eq!(
+        df.column("a")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1, 4]
+    );
+    assert_eq!(
+        bad_rows
+            .column("line")?
+            .u64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[2, 3]
+    );
+    assert_eq!(
+        bad_rows.column("raw")?.binary()?.get(1),
+        Some("{\"a\":3".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ndjson_dtype_overrides() -> PolarsResult<()> {
+    let jsonlines = r#"{"user_id":1, "a":1, "b":1}
+{"user_id":2, "a":2, "b":2}
+{"user_id":3, "a":3.5, "b":3.5}
+"#;
+    let overrides = DtypeOverrides::new()
+        .with_dtype("*_id", DataType::String)
+        .with_infer_schema_length("b", None);
+    let df = JsonLineReader::new(Cursor::new(jsonlines))
+        .infer_schema_len(NonZeroUsize::new(2))
+        .with_dtype_overrides(Some(Arc::new(overrides)))
+        .with_ignore_errors(true)
+        .finish()?;
+
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::String, DataType::Int64, DataType::Float64]
+    );
+    assert_eq!(df.column("b")?.f64()?.get(2), Some(3.5));
+    Ok(())
+}
@@ -13,6 +13,8 @@ mod avro;
 mod ipc;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
+#[cfg(all(feature = "range_reads", feature = "parquet", feature = "ipc"))]
+mod range_reader;
 
 use polars::prelude::*;
 
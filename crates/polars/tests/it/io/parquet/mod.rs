@@ -212,3 +212,19 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_parquet_extension_types_roundtrip() -> PolarsResult<()> {
+    let uuid = Series::new("id", [&[1u8; 16][..], &[2u8; 16][..]]).into_extension("arrow.uuid")?;
+    let mut df = DataFrame::new(vec![uuid])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+
+    assert!(df.equals(&read_df));
+    let s = read_df.column("id")?;
+    assert_eq!(s.extension().unwrap().name(), "arrow.uuid");
+    assert_eq!(s.dtype(), &DataType::Binary);
+    Ok(())
+}
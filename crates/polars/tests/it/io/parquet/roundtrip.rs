@@ -1,8 +1,9 @@
 use std::io::Cursor;
 
-use arrow::array::{ArrayRef, Utf8ViewArray};
+use arrow::array::{ArrayRef, PrimitiveArray, Utf8ViewArray};
 use arrow::datatypes::{ArrowSchema, Field};
 use arrow::record_batch::RecordBatchT;
+use arrow::types::f16;
 use polars_error::PolarsResult;
 use polars_parquet::arrow::write::{FileWriter, WriteOptions};
 use polars_parquet::read::read_metadata;
@@ -84,3 +85,20 @@ fn roundtrip_binview() -> PolarsResult<()> {
         vec![Encoding::Plain],
     )
 }
+
+#[test]
+fn roundtrip_f16() -> PolarsResult<()> {
+    let array = PrimitiveArray::from([
+        Some(f16::from_f32(1.5)),
+        None,
+        Some(f16::from_f32(-0.25)),
+        Some(f16::from_f32(65504.0)),
+    ]);
+
+    round_trip(
+        &array.boxed(),
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::Plain],
+    )
+}
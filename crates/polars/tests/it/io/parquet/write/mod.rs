@@ -321,6 +321,28 @@ fn test_parquet_datetime_round_trip() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "dtype-i128", feature = "dtype-u128"))]
+fn test_parquet_128_bit_integer_round_trip() -> PolarsResult<()> {
+    use std::io::{Cursor, Seek, SeekFrom};
+
+    let mut f = Cursor::new(vec![]);
+
+    let mut df = df![
+        "i128" => [Some(i128::MIN), Some(-1), None, Some(i128::MAX)],
+        "u128" => [Some(0u128), None, Some(u64::MAX as u128 + 1), Some(u128::MAX)]
+    ]?;
+
+    ParquetWriter::new(&mut f).finish(&mut df)?;
+
+    f.seek(SeekFrom::Start(0))?;
+
+    let read = ParquetReader::new(f).finish()?;
+    assert_eq!(read.dtypes(), [DataType::Int128, DataType::UInt128]);
+    assert!(read.equals_missing(&df));
+    Ok(())
+}
+
 #[test]
 fn test_read_parquet_with_projection() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
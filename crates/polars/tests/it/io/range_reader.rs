@@ -0,0 +1,81 @@
+use std::io::Cursor;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use polars::prelude::*;
+
+/// A source over `bytes` that counts the bytes it fetched.
+fn counting_source(bytes: Vec<u8>) -> (CallbackSource, Arc<AtomicUsize>) {
+    let fetched = Arc::new(AtomicUsize::new(0));
+    let counter = fetched.clone();
+    let source = CallbackSource::new(bytes.len() as u64, move |range| {
+        counter.fetch_add((range.end - range.start) as usize, Ordering::Relaxed);
+        Ok(bytes[range.start as usize..range.end as usize].to_vec())
+    });
+    (source, fetched)
+}
+
+fn wide_df() -> DataFrame {
+    let n = 10_000;
+    df![
+        "a" => (0..n).collect::<Vec<i64>>(),
+        "b" => (0..n).map(|i| format!("value {i}")).collect::<Vec<_>>(),
+        "c" => (0..n).map(|i| i as f64 * 0.5).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+}
+
+fn df_to_parquet(df: &mut DataFrame) -> PolarsResult<Vec<u8>> {
+    let mut buf = vec![];
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(1000))
+        .finish(df)?;
+    Ok(buf)
+}
+
+#[test]
+fn test_range_reader_parquet_fetches_projection() -> PolarsResult<()> {
+    let mut df = wide_df();
+    let buf = df_to_parquet(&mut df)?;
+    let size = buf.len();
+
+    let (source, fetched) = counting_source(buf);
+    let reader = RangeReader::new(source)?.with_block_size(1024);
+    let out = ParquetReader::new(reader)
+        .with_columns(Some(vec!["a".into()]))
+        .finish()?;
+    assert!(out.equals(&df.select(["a"])?));
+    assert!(fetched.load(Ordering::Relaxed) < size / 2);
+
+    let (source, fetched) = counting_source(df_to_parquet(&mut df)?);
+    let reader = RangeReader::new(source)?.with_block_size(1024);
+    let out = ParquetReader::new(reader)
+        .with_n_rows(Some(10))
+        .finish()?;
+    assert!(out.equals(&df.head(Some(10))));
+    assert!(fetched.load(Ordering::Relaxed) < size / 2);
+    Ok(())
+}
+
+#[test]
+fn test_range_reader_ipc() -> PolarsResult<()> {
+    let mut df = wide_df();
+    let mut buf = Cursor::new(vec![]);
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+
+    let (source, _) = counting_source(buf.into_inner());
+    let out = IpcReader::new(RangeReader::new(source)?)
+        .with_columns(Some(vec!["c".into(), "a".into()]))
+        .finish()?;
+    assert!(out.equals(&df.select(["c", "a"])?));
+    Ok(())
+}
+
+#[test]
+fn test_range_reader_rejects_short_fetch() -> PolarsResult<()> {
+    let source = CallbackSource::new(100, |_| Ok(vec![0; 10]));
+    assert!(ParquetReader::new(RangeReader::new(source)?)
+        .finish()
+        .is_err());
+    Ok(())
+}
@@ -61,3 +61,67 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_dynamic_rolling_map_agg() -> PolarsResult<()> {
+    let start = NaiveDate::from_ymd_opt(2022, 2, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2022, 2, 14)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let range = polars_time::date_range(
+        "dt",
+        start,
+        stop,
+        Duration::parse("1d"),
+        ClosedWindow::Left,
+        TimeUnit::Milliseconds,
+        None,
+    )?
+    .into_series();
+
+    let a = Int32Chunked::from_vec("a", (0..range.len() as i32).collect());
+    let df = df![
+        "dt" => range,
+        "a" => a
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("dt"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1w"),
+                period: Duration::parse("1w"),
+                offset: Duration::parse("0w"),
+                closed_window: ClosedWindow::Left,
+                label: Label::DataPoint,
+                start_by: StartBy::DataPoint,
+                ..Default::default()
+            },
+        )
+        .agg([col("a")
+            .rolling_map_agg(
+                |s| {
+                    let first = s.i32()?.first().unwrap();
+                    let last = s.i32()?.last().unwrap();
+                    Ok(Series::new(s.name(), &[format!("{first}-{last}")]))
+                },
+                GetOutput::from_type(DataType::String),
+            )
+            .alias("range")])
+        .collect()?;
+    let range = out.column("range")?;
+    assert_eq!(range.get(0)?, AnyValue::String("0-6"));
+    assert_eq!(range.get(1)?, AnyValue::String("7-12"));
+    Ok(())
+}
@@ -1,3 +1,4 @@
+mod build_info;
 mod core;
 mod io;
 mod joins;
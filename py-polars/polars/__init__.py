@@ -126,13 +126,16 @@ from polars.functions import (
     min,
     min_horizontal,
     n_unique,
+    n_unique_rows,
     nth,
     ones,
     quantile,
     reduce,
     repeat,
     rolling_corr,
+    rolling_corr_by,
     rolling_cov,
+    rolling_cov_by,
     select,
     set_random_seed,
     sql_expr,
@@ -340,11 +343,14 @@ __all__ = [
     "mean",
     "median",
     "n_unique",
+    "n_unique_rows",
     "nth",
     "quantile",
     "reduce",
     "rolling_corr",
+    "rolling_corr_by",
     "rolling_cov",
+    "rolling_cov_by",
     "select",
     "std",
     "struct",
@@ -131,7 +131,9 @@ if TYPE_CHECKING:
     from polars.interchange.dataframe import PolarsDataFrame
     from polars.ml.torch import PolarsDataset
     from polars.type_aliases import (
+        Ambiguous,
         AsofJoinStrategy,
+        AsofTieBreak,
         AvroCompression,
         ClosedInterval,
         ColumnFormatDict,
@@ -151,13 +153,18 @@ if TYPE_CHECKING:
         IpcCompression,
         JoinStrategy,
         JoinValidation,
+        JsonOrient,
         Label,
         MultiColSelector,
         MultiIndexSelector,
+        NameCase,
+        NameDedupe,
+        NonExistent,
         OneOrMoreDataTypes,
         Orientation,
         ParquetCompression,
         PivotAgg,
+        PivotNameOrder,
         PolarsDataType,
         RollingInterpolationMethod,
         RowTotalsDefinition,
@@ -2450,12 +2457,39 @@ class DataFrame:
         return serialize_polars_object(serializer, file, format)
 
     @overload
-    def write_json(self, file: None = ...) -> str: ...
+    def write_json(
+        self,
+        file: None = ...,
+        *,
+        orient: JsonOrient = ...,
+        skip_nulls: bool = ...,
+        datetime_format: str | None = ...,
+        date_format: str | None = ...,
+        time_format: str | None = ...,
+    ) -> str: ...
 
     @overload
-    def write_json(self, file: IOBase | str | Path) -> None: ...
+    def write_json(
+        self,
+        file: IOBase | str | Path,
+        *,
+        orient: JsonOrient = ...,
+        skip_nulls: bool = ...,
+        datetime_format: str | None = ...,
+        date_format: str | None = ...,
+        time_format: str | None = ...,
+    ) -> None: ...
 
-    def write_json(self, file: IOBase | str | Path | None = None) -> str | None:
+    def write_json(
+        self,
+        file: IOBase | str | Path | None = None,
+        *,
+        orient: JsonOrient = "records",
+        skip_nulls: bool = False,
+        datetime_format: str | None = None,
+        date_format: str | None = None,
+        time_format: str | None = None,
+    ) -> str | None:
         """
         Serialize to JSON representation.
 
@@ -2464,6 +2498,24 @@ class DataFrame:
         file
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
+        orient : {'records', 'columns', 'values'}
+            How the values are laid out.
+
+            - records: an array with an object per row, keyed by column name.
+            - columns: an object with an array of values per column.
+            - values: an array with an array of values per row.
+        skip_nulls
+            Leave out the keys of which the value is null. Only applies to the rows
+            written with `orient="records"`.
+        datetime_format
+            A format string for `Datetime` columns, with the specifiers defined by the
+            `chrono <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            Rust crate. If no format is specified, the values are written as e.g.
+            `"2020-01-01 12:00:00"`.
+        date_format
+            A format string for `Date` columns, see `datetime_format`.
+        time_format
+            A format string for `Time` columns, see `datetime_format`.
 
         See Also
         --------
@@ -2474,16 +2526,32 @@ class DataFrame:
         >>> df = pl.DataFrame(
         ...     {
         ...         "foo": [1, 2, 3],
-        ...         "bar": [6, 7, 8],
+        ...         "bar": [6, 7, None],
         ...     }
         ... )
         >>> df.write_json()
-        '[{"foo":1,"bar":6},{"foo":2,"bar":7},{"foo":3,"bar":8}]'
+        '[{"foo":1,"bar":6},{"foo":2,"bar":7},{"foo":3,"bar":null}]'
+        >>> df.write_json(skip_nulls=True)
+        '[{"foo":1,"bar":6},{"foo":2,"bar":7},{"foo":3}]'
+        >>> df.write_json(orient="columns")
+        '{"foo":[1,2,3],"bar":[6,7,null]}'
+        >>> df.write_json(orient="values")
+        '[[1,6],[2,7],[3,null]]'
         """
 
+        def write_json_to_file(file: Any) -> None:
+            self._df.write_json(
+                file,
+                orient,
+                skip_nulls,
+                datetime_format,
+                date_format,
+                time_format,
+            )
+
         def write_json_to_string() -> str:
             with BytesIO() as buf:
-                self._df.write_json(buf)
+                write_json_to_file(buf)
                 json_bytes = buf.getvalue()
             return json_bytes.decode("utf8")
 
@@ -2495,10 +2563,10 @@ class DataFrame:
             return None
         elif isinstance(file, (str, Path)):
             file = normalize_filepath(file)
-            self._df.write_json(file)
+            write_json_to_file(file)
             return None
         else:
-            self._df.write_json(file)
+            write_json_to_file(file)
             return None
 
     @overload
@@ -3299,6 +3367,7 @@ class DataFrame:
         *,
         compression: IpcCompression = "uncompressed",
         future: bool | None = None,
+        batch_statistics: bool = False,
     ) -> BytesIO: ...
 
     @overload
@@ -3308,6 +3377,7 @@ class DataFrame:
         *,
         compression: IpcCompression = "uncompressed",
         future: bool | None = None,
+        batch_statistics: bool = False,
     ) -> None: ...
 
     def write_ipc(
@@ -3316,6 +3386,7 @@ class DataFrame:
         *,
         compression: IpcCompression = "uncompressed",
         future: bool | None = None,
+        batch_statistics: bool = False,
     ) -> BytesIO | None:
         """
         Write to Arrow IPC binary stream or Feather file.
@@ -3336,6 +3407,10 @@ class DataFrame:
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
+        batch_statistics
+            Write the minimum, maximum and null count of the columns of every record
+            batch to the footer of the file. Scans with a filter use them to skip
+            record batches. Other Arrow implementations ignore them.
 
         Examples
         --------
@@ -3367,7 +3442,7 @@ class DataFrame:
         if future is None:
             future = True
 
-        self._df.write_ipc(file, compression, future)
+        self._df.write_ipc(file, compression, future, batch_statistics)
         return file if return_bytes else None  # type: ignore[return-value]
 
     @overload
@@ -4279,6 +4354,77 @@ class DataFrame:
         """
         return self.lazy().rename(mapping).collect(_eager=True)
 
+    def rename_normalized(
+        self,
+        case: NameCase = "snake",
+        *,
+        ascii_only: bool = True,
+        dedupe: NameDedupe = "suffix",
+    ) -> DataFrame:
+        """
+        Normalize the column names.
+
+        The names are split into words on non-alphanumeric characters and on case
+        changes, and the words are joined in the given case.
+
+        Parameters
+        ----------
+        case : {'snake', 'camel', 'pascal', 'preserve'}
+            Case of the normalized names.
+
+            - 'snake': `column_name`
+            - 'camel': `columnName`
+            - 'pascal': `ColumnName`
+            - 'preserve': keep the case of the words and join them with `_`.
+        ascii_only
+            Strip accents and replace other non-ASCII characters by a word separator.
+        dedupe : {'suffix', 'raise'}
+            How to resolve names that clash after normalization.
+
+            - 'suffix': the first column keeps the name, the next ones get the lowest
+              free suffix `_1`, `_2`, ...
+            - 'raise': raise an error.
+
+        See Also
+        --------
+        rename
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "Customer ID": [1, 2],
+        ...         "customerId": [3, 4],
+        ...         "Crème brûlée": ["a", "b"],
+        ...     }
+        ... )
+        >>> df.rename_normalized()
+        shape: (2, 3)
+        ┌─────────────┬───────────────┬──────────────┐
+        │ customer_id ┆ customer_id_1 ┆ creme_brulee │
+        │ ---         ┆ ---           ┆ ---          │
+        │ i64         ┆ i64           ┆ str          │
+        ╞═════════════╪═══════════════╪══════════════╡
+        │ 1           ┆ 3             ┆ a            │
+        │ 2           ┆ 4             ┆ b            │
+        └─────────────┴───────────────┴──────────────┘
+        >>> df.rename_normalized("camel")
+        shape: (2, 3)
+        ┌────────────┬──────────────┬─────────────┐
+        │ customerId ┆ customerId_1 ┆ cremeBrulee │
+        │ ---        ┆ ---          ┆ ---         │
+        │ i64        ┆ i64          ┆ str         │
+        ╞════════════╪══════════════╪═════════════╡
+        │ 1          ┆ 3            ┆ a           │
+        │ 2          ┆ 4            ┆ b           │
+        └────────────┴──────────────┴─────────────┘
+        """
+        return (
+            self.lazy()
+            .rename_normalized(case, ascii_only=ascii_only, dedupe=dedupe)
+            .collect(_eager=True)
+        )
+
     def insert_column(self, index: int, column: Series) -> DataFrame:
         """
         Insert a Series at a certain column index.
@@ -5890,6 +6036,8 @@ class DataFrame:
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        ambiguous: Ambiguous = "raise",
+        non_existent: NonExistent = "raise",
     ) -> DynamicGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -5960,6 +6108,20 @@ class DataFrame:
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        ambiguous
+            Determine how to deal with window boundaries which are ambiguous in the
+            time zone of `index_column`:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: skip the boundary, merging the window with the next one
+        non_existent
+            Determine how to deal with window boundaries which are non-existent in
+            the time zone of `index_column`:
+
+            - `'raise'` (default): raise
+            - `'null'`: skip the boundary, merging the window with the next one
 
         Returns
         -------
@@ -6192,6 +6354,8 @@ class DataFrame:
             closed=closed,
             group_by=group_by,
             start_by=start_by,
+            ambiguous=ambiguous,
+            non_existent=non_existent,
         )
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -6202,6 +6366,8 @@ class DataFrame:
         every: str | timedelta,
         group_by: str | Sequence[str] | None = None,
         maintain_order: bool = False,
+        ambiguous: Ambiguous = "raise",
+        non_existent: NonExistent = "raise",
     ) -> DataFrame:
         """
         Upsample a DataFrame at a regular frequency.
@@ -6241,6 +6407,20 @@ class DataFrame:
             First group by these columns and then upsample for every group.
         maintain_order
             Keep the ordering predictable. This is slower.
+        ambiguous
+            Determine how to deal with upsampled datetimes which are ambiguous in the
+            time zone of `time_column`:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: leave the datetime out of the upsampled range
+        non_existent
+            Determine how to deal with upsampled datetimes which are non-existent in
+            the time zone of `time_column`:
+
+            - `'raise'` (default): raise
+            - `'null'`: leave the datetime out of the upsampled range
 
         Returns
         -------
@@ -6291,7 +6471,9 @@ class DataFrame:
         every = parse_as_duration_string(every)
 
         return self._from_pydf(
-            self._df.upsample(group_by, time_column, every, maintain_order)
+            self._df.upsample(
+                group_by, time_column, every, maintain_order, ambiguous, non_existent
+            )
         )
 
     def join_asof(
@@ -6310,6 +6492,10 @@ class DataFrame:
         allow_parallel: bool = True,
         force_parallel: bool = False,
         coalesce: bool | None = None,
+        allow_exact_matches: bool = True,
+        tie_break: AsofTieBreak = "forward",
+        all_matches: bool = False,
+        tolerance_column: str | None = None,
     ) -> DataFrame:
         """
         Perform an asof join.
@@ -6395,6 +6581,24 @@ class DataFrame:
 
             Note that joining on any other expressions than `col`
             will turn off coalescing.
+        allow_exact_matches
+            Whether right rows with a key equal to the left key may be matched. If
+            False, a "backward" search selects the last row whose key is strictly less
+            than the left's key, and a "forward" search the first row whose key is
+            strictly greater. This prevents leaking same-timestamp information in
+            point-in-time joins.
+        tie_break : {'forward', 'backward'}
+            Which right row a "nearest" search selects if two right keys are equally
+            far from the left key: the greater ('forward') or the smaller ('backward')
+            one.
+        all_matches
+            Join all right rows whose key is equal to the matched key, instead of only
+            one. A left row may then produce multiple rows in the output. This is not
+            supported in combination with `by`.
+        tolerance_column
+            Name of a column in the left frame holding a per-row tolerance, in the same
+            unit as the `on` key. Matches further away than the tolerance are
+            discarded. A null tolerance does not restrict the match.
 
         Examples
         --------
@@ -6613,6 +6817,10 @@ class DataFrame:
                 allow_parallel=allow_parallel,
                 force_parallel=force_parallel,
                 coalesce=coalesce,
+                allow_exact_matches=allow_exact_matches,
+                tie_break=tie_break,
+                all_matches=all_matches,
+                tolerance_column=tolerance_column,
             )
             .collect(_eager=True)
         )
@@ -7620,6 +7828,7 @@ class DataFrame:
         self,
         columns: str | Expr | Sequence[str | Expr],
         *more_columns: str | Expr,
+        pad_with_nulls: bool = False,
     ) -> DataFrame:
         """
         Explode the dataframe to long format by exploding the given columns.
@@ -7631,6 +7840,10 @@ class DataFrame:
             columns being exploded must be of the `List` or `Array` data type.
         *more_columns
             Additional names of columns to explode, specified as positional arguments.
+        pad_with_nulls
+            Multiple columns are exploded in lockstep, so the lists of a row must have
+            the same number of elements in all columns. Set this to `True` to pad
+            the shorter lists of a row with nulls instead of raising an error.
 
         Returns
         -------
@@ -7673,7 +7886,11 @@ class DataFrame:
         │ c       ┆ 8       │
         └─────────┴─────────┘
         """
-        return self.lazy().explode(columns, *more_columns).collect(_eager=True)
+        return (
+            self.lazy()
+            .explode(columns, *more_columns, pad_with_nulls=pad_with_nulls)
+            .collect(_eager=True)
+        )
 
     @deprecate_renamed_parameter("columns", "on", version="1.0.0")
     def pivot(
@@ -7682,10 +7899,11 @@ class DataFrame:
         *,
         index: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
         values: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
-        aggregate_function: PivotAgg | Expr | None = None,
+        aggregate_function: PivotAgg | Expr | Sequence[PivotAgg | Expr] | None = None,
         maintain_order: bool = True,
         sort_columns: bool = False,
         separator: str = "_",
+        name_order: PivotNameOrder = "values_first",
     ) -> DataFrame:
         """
         Create a spreadsheet-style pivot table as a DataFrame.
@@ -7713,13 +7931,22 @@ class DataFrame:
             - A predefined aggregate function string, one of
               {'min', 'max', 'first', 'last', 'sum', 'mean', 'median', 'len'}
             - An expression to do the aggregation.
+            - A sequence of the above, to do several aggregations at once. Every
+              aggregation is applied to every `values` column. Expressions in a
+              sequence must be named with :meth:`Expr.alias`.
         maintain_order
             Sort the grouped keys so that the output order is predictable.
         sort_columns
             Sort the transposed columns by name. Default is by order of discovery.
         separator
             Used as separator/delimiter in generated column names in case of multiple
-            `values` columns.
+            `values` columns or aggregations.
+        name_order : {'values_first', 'on_first'}
+            The order of the parts of the generated column names in case of multiple
+            `values` columns or aggregations.
+
+            - 'values_first': `{value}{separator}{aggregation}{separator}{on}`
+            - 'on_first': `{on}{separator}{value}{separator}{aggregation}`
 
         Returns
         -------
@@ -7833,6 +8060,31 @@ class DataFrame:
         │ b    ┆ 0.964028 ┆ 0.999954 │
         └──────┴──────────┴──────────┘
 
+        Several aggregations can be done at once:
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "region": ["north", "north", "south", "south", "south"],
+        ...         "product": ["a", "b", "a", "a", "b"],
+        ...         "sales": [10, 20, 30, 50, 40],
+        ...     }
+        ... )
+        >>> df.pivot(
+        ...     "product",
+        ...     index="region",
+        ...     values="sales",
+        ...     aggregate_function=["sum", "mean"],
+        ... )
+        shape: (2, 5)
+        ┌────────┬───────┬───────┬────────┬────────┐
+        │ region ┆ sum_a ┆ sum_b ┆ mean_a ┆ mean_b │
+        │ ---    ┆ ---   ┆ ---   ┆ ---    ┆ ---    │
+        │ str    ┆ i64   ┆ i64   ┆ f64    ┆ f64    │
+        ╞════════╪═══════╪═══════╪════════╪════════╡
+        │ north  ┆ 10    ┆ 20    ┆ 10.0   ┆ 20.0   │
+        │ south  ┆ 80    ┆ 40    ┆ 40.0   ┆ 40.0   │
+        └────────┴───────┴───────┴────────┴────────┘
+
         Note that `pivot` is only available in eager mode. If you know the unique
         column values in advance, you can use :meth:`polars.LazyFrame.group_by` to
         get the same result as above in lazy mode:
@@ -7862,37 +8114,14 @@ class DataFrame:
         if index is not None:
             index = _expand_selectors(self, index)
 
-        if isinstance(aggregate_function, str):
-            if aggregate_function == "first":
-                aggregate_expr = F.element().first()._pyexpr
-            elif aggregate_function == "sum":
-                aggregate_expr = F.element().sum()._pyexpr
-            elif aggregate_function == "max":
-                aggregate_expr = F.element().max()._pyexpr
-            elif aggregate_function == "min":
-                aggregate_expr = F.element().min()._pyexpr
-            elif aggregate_function == "mean":
-                aggregate_expr = F.element().mean()._pyexpr
-            elif aggregate_function == "median":
-                aggregate_expr = F.element().median()._pyexpr
-            elif aggregate_function == "last":
-                aggregate_expr = F.element().last()._pyexpr
-            elif aggregate_function == "len":
-                aggregate_expr = F.len()._pyexpr
-            elif aggregate_function == "count":
-                issue_deprecation_warning(
-                    "`aggregate_function='count'` input for `pivot` is deprecated."
-                    " Please use `aggregate_function='len'`.",
-                    version="0.20.5",
-                )
-                aggregate_expr = F.len()._pyexpr
-            else:
-                msg = f"invalid input for `aggregate_function` argument: {aggregate_function!r}"
-                raise ValueError(msg)
-        elif aggregate_function is None:
-            aggregate_expr = None
+        if aggregate_function is None:
+            aggregate_exprs = []
+        elif isinstance(aggregate_function, (str, pl.Expr)):
+            aggregate_exprs = [_pivot_agg_expr(aggregate_function)._pyexpr]
         else:
-            aggregate_expr = aggregate_function._pyexpr
+            aggregate_exprs = [
+                _pivot_agg_expr(agg)._pyexpr for agg in aggregate_function
+            ]
 
         return self._from_pydf(
             self._df.pivot_expr(
@@ -7901,8 +8130,9 @@ class DataFrame:
                 values,
                 maintain_order,
                 sort_columns,
-                aggregate_expr,
+                aggregate_exprs,
                 separator,
+                name_order,
             )
         )
 
@@ -8370,10 +8600,19 @@ class DataFrame:
         """
         return self.lazy().shift(n, fill_value=fill_value).collect(_eager=True)
 
-    def is_duplicated(self) -> Series:
+    def is_duplicated(self, keep: Literal["first", "last", False] = False) -> Series:
         """
         Get a mask of all duplicated rows in this DataFrame.
 
+        Parameters
+        ----------
+        keep : {'first', 'last', False}
+            Which occurrences of a duplicated row not to mark.
+
+            * 'first' : Mark all occurrences except the first one.
+            * 'last' : Mark all occurrences except the last one.
+            * False : Mark all occurrences.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -8404,8 +8643,28 @@ class DataFrame:
         │ 1   ┆ x   │
         │ 1   ┆ x   │
         └─────┴─────┘
+
+        With `keep`, the mask marks the rows to drop to deduplicate the data.
+
+        >>> df.filter(~df.is_duplicated(keep="last"))
+        shape: (3, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 2   ┆ y   │
+        │ 3   ┆ z   │
+        │ 1   ┆ x   │
+        └─────┴─────┘
         """
-        return wrap_s(self._df.is_duplicated())
+        if keep is False:
+            return wrap_s(self._df.is_duplicated())
+        return (
+            self.select(F.struct(F.all()).is_duplicated(keep=keep))
+            .to_series()
+            .alias("")
+        )
 
     def is_unique(self) -> Series:
         """
@@ -9402,6 +9661,57 @@ class DataFrame:
             .collect(_eager=True)
         )
 
+    def arg_unique(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+        *,
+        keep: UniqueKeepStrategy = "any",
+        maintain_order: bool = False,
+    ) -> Series:
+        """
+        Get the indices of the rows that :meth:`unique` keeps.
+
+        With `maintain_order=True`, `df[df.arg_unique(...)]` equals
+        `df.unique(..., maintain_order=True)`. This can be used to audit which
+        duplicate rows are dropped.
+
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s), to consider when identifying
+            duplicate rows. If set to `None` (default), use all columns.
+        keep : {'first', 'last', 'any', 'none'}
+            Which of the duplicate rows to keep, see :meth:`unique`.
+        maintain_order
+            Return the indices in ascending order.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32` (:class:`UInt64` in the
+            `bigidx` build) named "index".
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "foo": [1, 2, 1, 1],
+        ...         "bar": ["a", "a", "a", "b"],
+        ...     }
+        ... )
+        >>> df.arg_unique(keep="last", maintain_order=True)
+        shape: (3,)
+        Series: 'index' [u32]
+        [
+            1
+            2
+            3
+        ]
+        """
+        if subset is not None:
+            subset = _expand_selectors(self, subset)
+        return wrap_s(self._df.arg_unique(maintain_order, subset, keep))
+
     def n_unique(self, subset: str | Expr | Sequence[str | Expr] | None = None) -> int:
         """
         Return the number of unique rows, or the number of unique row-subsets.
@@ -9610,6 +9920,62 @@ class DataFrame:
 
         return self._from_pydf(self._df.sample_n(n._s, with_replacement, shuffle, seed))
 
+    def sample_stratified(
+        self,
+        by: str | Sequence[str],
+        n: int | None = None,
+        *,
+        fraction: float | None = None,
+        seed: int | None = None,
+    ) -> DataFrame:
+        """
+        Sample the rows of every group of `by` separately.
+
+        Every group is represented in the sample, which makes it possible to build
+        balanced or stratified train/test splits. The sampled rows keep their order.
+
+        Parameters
+        ----------
+        by
+            Column(s) that define the groups (strata).
+        n
+            Number of rows to take from every group. Groups with fewer rows are taken
+            completely. Cannot be used with `fraction`. Defaults to 1 if `fraction` is
+            None.
+        fraction
+            Fraction of the rows of every group to take. Cannot be used with `n`.
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated for each sample operation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "label": ["a", "a", "a", "a", "b", "b"],
+        ...         "value": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> df.sample_stratified("label", n=1, seed=0)  # doctest: +IGNORE_RESULT
+        shape: (2, 2)
+        ┌───────┬───────┐
+        │ label ┆ value │
+        │ ---   ┆ ---   │
+        │ str   ┆ i64   │
+        ╞═══════╪═══════╡
+        │ a     ┆ 3     │
+        │ b     ┆ 5     │
+        └───────┴───────┘
+        """
+        if n is not None and fraction is not None:
+            msg = "cannot specify both `n` and `fraction`"
+            raise ValueError(msg)
+        if isinstance(by, str):
+            by = [by]
+        return self._from_pydf(
+            self._df.sample_stratified(list(by), n, fraction, seed)
+        )
+
     def fold(self, operation: Callable[[Series, Series], Series]) -> Series:
         """
         Apply a horizontal reduction on a DataFrame.
@@ -10267,6 +10633,58 @@ class DataFrame:
             df._df.shrink_to_fit()
             return df
 
+    def shrink_dtype(
+        self,
+        *,
+        shrink_dates: bool = False,
+        categorical_threshold: float | None = None,
+        use_enum: bool = False,
+    ) -> DataFrame:
+        """
+        Shrink the data types of all columns in one pass.
+
+        Numeric columns are cast to the smallest data type of the same kind that can
+        hold all values, see :meth:`Expr.shrink_dtype`.
+
+        Parameters
+        ----------
+        shrink_dates
+            Cast `Datetime` columns without a time zone of which all values are at
+            midnight to `Date`.
+        categorical_threshold
+            Cast `String` columns of which the number of unique values divided by the
+            length is at most this threshold to `Categorical`. Must be between 0 and 1.
+        use_enum
+            Cast these `String` columns to an `Enum` of the sorted unique values
+            instead of to `Categorical`.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 2, 3],
+        ...         "b": [1.0, 2.0, 3.0],
+        ...         "c": [datetime(2024, 1, 1), datetime(2024, 1, 2), None],
+        ...         "d": ["x", "y", "x"],
+        ...     }
+        ... )
+        >>> df.shrink_dtype(shrink_dates=True, categorical_threshold=0.7)
+        shape: (3, 4)
+        ┌─────┬─────┬────────────┬─────┐
+        │ a   ┆ b   ┆ c          ┆ d   │
+        │ --- ┆ --- ┆ ---        ┆ --- │
+        │ i8  ┆ f32 ┆ date       ┆ cat │
+        ╞═════╪═════╪════════════╪═════╡
+        │ 1   ┆ 1.0 ┆ 2024-01-01 ┆ x   │
+        │ 2   ┆ 2.0 ┆ 2024-01-02 ┆ y   │
+        │ 3   ┆ 3.0 ┆ null       ┆ x   │
+        └─────┴─────┴────────────┴─────┘
+        """
+        return self._from_pydf(
+            self._df.shrink_dtype(shrink_dates, categorical_threshold, use_enum)
+        )
+
     def gather_every(self, n: int, offset: int = 0) -> DataFrame:
         """
         Take every nth row in the DataFrame and return as a new DataFrame.
@@ -10482,7 +10900,63 @@ class DataFrame:
         └────────┴─────┴─────┴──────┴───────────┴───────┘
         """
         columns = _expand_selectors(self, columns, *more_columns)
-        return self._from_pydf(self._df.unnest(columns))
+        return self._from_pydf(self._df.unnest(columns, 1, None))
+
+    def unnest_recursive(
+        self,
+        columns: ColumnNameOrSelector | Collection[ColumnNameOrSelector],
+        *more_columns: ColumnNameOrSelector,
+        max_depth: int | None = None,
+        separator: str | None = ".",
+    ) -> DataFrame:
+        """
+        Decompose struct columns and the struct fields they contain into columns.
+
+        The new columns will be inserted into the DataFrame at the location of the
+        struct column.
+
+        Parameters
+        ----------
+        columns
+            Name of the struct column(s) that should be unnested.
+        *more_columns
+            Additional columns to unnest, specified as positional arguments.
+        max_depth
+            The number of levels of nested structs to unnest. If set to `None`
+            (default), all levels are unnested.
+        separator
+            The names of the new columns are the names of the struct columns and
+            fields they were nested in and their own name, joined by this separator.
+            If set to `None`, the new columns keep the names of the fields.
+
+        See Also
+        --------
+        unnest
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": [1, 2],
+        ...         "user": [
+        ...             {"name": "a", "address": {"city": "x", "zip": 1}},
+        ...             {"name": "b", "address": {"city": "y", "zip": 2}},
+        ...         ],
+        ...     }
+        ... )
+        >>> df.unnest_recursive("user")
+        shape: (2, 4)
+        ┌─────┬───────────┬───────────────────┬──────────────────┐
+        │ id  ┆ user.name ┆ user.address.city ┆ user.address.zip │
+        │ --- ┆ ---       ┆ ---               ┆ ---              │
+        │ i64 ┆ str       ┆ str               ┆ i64              │
+        ╞═════╪═══════════╪═══════════════════╪══════════════════╡
+        │ 1   ┆ a         ┆ x                 ┆ 1                │
+        │ 2   ┆ b         ┆ y                 ┆ 2                │
+        └─────┴───────────┴───────────────────┴──────────────────┘
+        """
+        columns = _expand_selectors(self, columns, *more_columns)
+        return self._from_pydf(self._df.unnest(columns, max_depth, separator))
 
     def corr(self, **kwargs: Any) -> DataFrame:
         """
@@ -10829,6 +11303,39 @@ class DataFrame:
         )
 
 
+def _pivot_agg_expr(aggregate_function: PivotAgg | Expr) -> Expr:
+    if not isinstance(aggregate_function, str):
+        return aggregate_function
+
+    if aggregate_function == "first":
+        expr = F.element().first()
+    elif aggregate_function == "sum":
+        expr = F.element().sum()
+    elif aggregate_function == "max":
+        expr = F.element().max()
+    elif aggregate_function == "min":
+        expr = F.element().min()
+    elif aggregate_function == "mean":
+        expr = F.element().mean()
+    elif aggregate_function == "median":
+        expr = F.element().median()
+    elif aggregate_function == "last":
+        expr = F.element().last()
+    elif aggregate_function == "len":
+        expr = F.len()
+    elif aggregate_function == "count":
+        issue_deprecation_warning(
+            "`aggregate_function='count'` input for `pivot` is deprecated."
+            " Please use `aggregate_function='len'`.",
+            version="0.20.5",
+        )
+        expr = F.len()
+    else:
+        msg = f"invalid input for `aggregate_function` argument: {aggregate_function!r}"
+        raise ValueError(msg)
+    return expr.alias(aggregate_function)
+
+
 def _prepare_other_arg(other: Any, length: int | None = None) -> Series:
     # if not a series create singleton series such that it will broadcast
     value = other
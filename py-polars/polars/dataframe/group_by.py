@@ -12,9 +12,11 @@ if TYPE_CHECKING:
 
     from polars import DataFrame
     from polars.type_aliases import (
+        Ambiguous,
         ClosedInterval,
         IntoExpr,
         Label,
+        NonExistent,
         RollingInterpolationMethod,
         SchemaDict,
         StartBy,
@@ -408,6 +410,46 @@ class GroupBy:
             .collect(no_optimization=True)
         )
 
+    def agg_groups_struct(self, name: str = "rows") -> DataFrame:
+        """
+        Aggregate the rows of every group into a list of structs.
+
+        The struct fields are the columns that are not group keys. The rows of a
+        group are gathered once for all columns, which makes this efficient to
+        pass the rows of every group to a function or to serialize them to JSON.
+
+        Parameters
+        ----------
+        name
+            Name of the resulting column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["one", "two", "one", "two"],
+        ...         "b": [1, 2, 3, 4],
+        ...         "c": ["x", "y", "z", "w"],
+        ...     }
+        ... )
+        >>> df.group_by("a", maintain_order=True).agg_groups_struct()
+        shape: (2, 2)
+        ┌─────┬────────────────────┐
+        │ a   ┆ rows               │
+        │ --- ┆ ---                │
+        │ str ┆ list[struct[2]]    │
+        ╞═════╪════════════════════╡
+        │ one ┆ [{1,"x"}, {3,"z"}] │
+        │ two ┆ [{2,"y"}, {4,"w"}] │
+        └─────┴────────────────────┘
+        """
+        return (
+            self.df.lazy()
+            .group_by(*self.by, **self.named_by, maintain_order=self.maintain_order)
+            .agg_groups_struct(name)
+            .collect(no_optimization=True)
+        )
+
     def all(self) -> DataFrame:
         """
         Aggregate the groups into Series.
@@ -908,6 +950,8 @@ class DynamicGroupBy:
         label: Label,
         group_by: IntoExpr | Iterable[IntoExpr] | None,
         start_by: StartBy,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
     ):
         every = parse_as_duration_string(every)
         period = parse_as_duration_string(period)
@@ -923,6 +967,8 @@ class DynamicGroupBy:
         self.closed = closed
         self.group_by = group_by
         self.start_by = start_by
+        self.ambiguous = ambiguous
+        self.non_existent = non_existent
 
     def __iter__(self) -> Self:
         temp_col = "__POLARS_GB_GROUP_INDICES"
@@ -938,6 +984,8 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                ambiguous=self.ambiguous,
+                non_existent=self.non_existent,
             )
             .agg(F.first().agg_groups().alias(temp_col))
             .collect(no_optimization=True)
@@ -989,6 +1037,8 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                ambiguous=self.ambiguous,
+                non_existent=self.non_existent,
             )
             .agg(*aggs, **named_aggs)
             .collect(no_optimization=True)
@@ -1034,6 +1084,8 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                ambiguous=self.ambiguous,
+                non_existent=self.non_existent,
             )
             .map_groups(function, schema)
             .collect(no_optimization=True)
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Iterable
 
 from polars._utils.wrap import wrap_expr
 
@@ -38,3 +38,71 @@ class ExprCatNameSpace:
         └──────┘
         """
         return wrap_expr(self._pyexpr.cat_get_categories())
+
+    def add_categories(self, categories: Iterable[str]) -> Expr:
+        """
+        Append categories to the categories of this data type.
+
+        The values are not changed. Categories that are present already are ignored.
+
+        Parameters
+        ----------
+        categories
+            The categories to append.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"size": ["S", "M", "S"]}, schema={"size": pl.Enum(["S", "M"])}
+        ... )
+        >>> df.select(pl.col("size").cat.add_categories(["L"])).schema["size"]
+        Enum(categories=['S', 'M', 'L'])
+        """
+        return wrap_expr(self._pyexpr.cat_add_categories(list(categories)))
+
+    def remove_unused(self) -> Expr:
+        """
+        Remove the categories that don't occur in the values.
+
+        The remaining categories keep their order.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"size": ["S", "L", "S"]}, schema={"size": pl.Enum(["S", "M", "L"])}
+        ... )
+        >>> df.select(pl.col("size").cat.remove_unused())["size"].dtype
+        Enum(categories=['S', 'L'])
+        """
+        return wrap_expr(self._pyexpr.cat_remove_unused())
+
+    def reorder(self, categories: Iterable[str]) -> Expr:
+        """
+        Change the order of the categories of this data type.
+
+        The values are not changed, but they are sorted in the new order of the
+        categories.
+
+        Parameters
+        ----------
+        categories
+            All categories, in their new order.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"size": ["S", "L", "M"]}, schema={"size": pl.Enum(["S", "M", "L"])}
+        ... )
+        >>> df.select(pl.col("size").cat.reorder(["L", "M", "S"]).sort())
+        shape: (3, 1)
+        ┌──────┐
+        │ size │
+        │ ---  │
+        │ enum │
+        ╞══════╡
+        │ L    │
+        │ M    │
+        │ S    │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.cat_reorder(list(categories)))
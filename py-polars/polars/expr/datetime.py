@@ -150,7 +150,63 @@ class ExprDateTimeNameSpace:
             )
         )
 
-    def truncate(self, every: str | dt.timedelta | Expr) -> Expr:
+    def is_business_day(
+        self,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+    ) -> Expr:
+        """
+        Determine whether each date is a business day.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week are business days. The default is Monday to Friday.
+            If only Monday to Thursday are business days, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays, which are not business days.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2020, 1, 3), date(2020, 1, 4), date(2020, 1, 6)]}
+        ... )
+        >>> df.with_columns(
+        ...     result=pl.col("date").dt.is_business_day(holidays=[date(2020, 1, 6)])
+        ... )
+        shape: (3, 2)
+        ┌────────────┬────────┐
+        │ date       ┆ result │
+        │ ---        ┆ ---    │
+        │ date       ┆ bool   │
+        ╞════════════╪════════╡
+        │ 2020-01-03 ┆ true   │
+        │ 2020-01-04 ┆ false  │
+        │ 2020-01-06 ┆ false  │
+        └────────────┴────────┘
+        """
+        unix_epoch = dt.date(1970, 1, 1)
+        return wrap_expr(
+            self._pyexpr.dt_is_business_day(
+                week_mask,
+                [(holiday - unix_epoch).days for holiday in holidays],
+            )
+        )
+
+    def truncate(
+        self,
+        every: str | dt.timedelta | Expr,
+        *,
+        ambiguous: Ambiguous = "earliest",
+        non_existent: NonExistent = "raise",
+    ) -> Expr:
         """
         Divide the date/datetime range into buckets.
 
@@ -165,6 +221,21 @@ class ExprDateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        ambiguous
+            Determine how to deal with results which are ambiguous in the time zone
+            of the data and can't be localised using the DST offset of the
+            original timestamp:
+
+            - `'earliest'` (default): use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'raise'`: raise
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with results which are non-existent in the time
+            zone of the data (e.g. midnight on a day where DST starts at midnight):
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Notes
         -----
@@ -274,7 +345,7 @@ class ExprDateTimeNameSpace:
             every = parse_as_duration_string(every)
 
         every = parse_into_expression(every, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_truncate(every))
+        return wrap_expr(self._pyexpr.dt_truncate(every, ambiguous, non_existent))
 
     @unstable()
     def round(self, every: str | dt.timedelta | IntoExprColumn) -> Expr:
@@ -446,7 +517,7 @@ class ExprDateTimeNameSpace:
 
     def to_string(self, format: str) -> Expr:
         """
-        Convert a Date/Time/Datetime column into a String column with the given format.
+        Convert a Date/Time/Datetime/Duration column into a String column with the given format.
 
         Similar to `cast(pl.String)`, but this method allows you to customize the
         formatting of the resulting string.
@@ -457,6 +528,9 @@ class ExprDateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
+            Duration columns accept `"iso"` for ISO 8601 durations (e.g. `"P1DT2H3M"`)
+            or `"humanized"` for the form that is used to display them
+            (e.g. `"1d 2h 3m"`).
 
         Examples
         --------
@@ -503,6 +577,20 @@ class ExprDateTimeNameSpace:
         │ 2020-04-01 00:00:00 ┆ Wednesday ┆ April      │
         │ 2020-05-01 00:00:00 ┆ Friday    ┆ May        │
         └─────────────────────┴───────────┴────────────┘
+
+        Durations can be formatted as ISO 8601 durations:
+
+        >>> from datetime import timedelta
+        >>> df = pl.DataFrame({"duration": [timedelta(days=1, hours=2, minutes=3)]})
+        >>> df.with_columns(iso=pl.col("duration").dt.to_string("iso"))
+        shape: (1, 2)
+        ┌──────────────┬──────────┐
+        │ duration     ┆ iso      │
+        │ ---          ┆ ---      │
+        │ duration[μs] ┆ str      │
+        ╞══════════════╪══════════╡
+        │ 1d 2h 3m     ┆ P1DT2H3M │
+        └──────────────┴──────────┘
         """
         return wrap_expr(self._pyexpr.dt_to_string(format))
 
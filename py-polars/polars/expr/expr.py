@@ -3,6 +3,7 @@ from __future__ import annotations
 import contextlib
 import math
 import operator
+import threading
 import warnings
 from datetime import timedelta
 from functools import reduce
@@ -16,6 +17,7 @@ from typing import (
     Collection,
     FrozenSet,
     Iterable,
+    Literal,
     Mapping,
     NoReturn,
     Sequence,
@@ -78,6 +80,7 @@ if TYPE_CHECKING:
     from polars.type_aliases import (
         ClosedInterval,
         FillNullStrategy,
+        HistBinStrategy,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
@@ -1160,6 +1163,53 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.count())
 
+    def count_if(self, predicate: IntoExpr) -> Expr:
+        """
+        Return the number of non-null elements in the column where `predicate` is true.
+
+        This gives the same result as `filter(predicate).count()`, but it doesn't
+        materialize the filtered groups. Rows where `predicate` is null are not counted.
+
+        Parameters
+        ----------
+        predicate
+            Boolean expression with the same length as the column.
+            Accepts expression input. Strings are parsed as column names.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        count
+        n_unique_if
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "a", "b", "b", "b"],
+        ...         "value": [1, None, 3, 0, 5],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(
+        ...     pl.col("value").count_if(pl.col("value") > 0)
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ key ┆ value │
+        │ --- ┆ ---   │
+        │ str ┆ u32   │
+        ╞═════╪═══════╡
+        │ "a" ┆ 1     │
+        │ "b" ┆ 2     │
+        └─────┴───────┘
+        """
+        predicate = parse_into_expression(predicate)
+        return self._from_pyexpr(self._pyexpr.count_if(predicate))
+
     def len(self) -> Expr:
         """
         Return the number of elements in the column.
@@ -3011,6 +3061,96 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.min())
 
+    def max_by(self, by: IntoExpr) -> Expr:
+        """
+        Get the value in the row where `by` is maximal.
+
+        This is the "latest record per key" aggregation, e.g.
+        `pl.col("value").max_by("timestamp")`.
+
+        Nulls in `by` are ignored. If `by` is tied, the value of the first of the
+        tied rows is taken.
+
+        Parameters
+        ----------
+        by
+            The column to look for the maximum in.
+            Accepts expression input. Strings are parsed as column names.
+
+        See Also
+        --------
+        max
+        arg_max
+        min_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "a", "b", "b", "b"],
+        ...         "ts": [3, 1, 2, 5, 4],
+        ...         "value": [10, 20, 30, 40, 50],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(pl.col("value").max_by("ts"))
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ key ┆ value │
+        │ --- ┆ ---   │
+        │ str ┆ i64   │
+        ╞═════╪═══════╡
+        │ "a" ┆ 10    │
+        │ "b" ┆ 40    │
+        └─────┴───────┘
+        """
+        by = parse_into_expression(by)
+        return self._from_pyexpr(self._pyexpr.max_by(by))
+
+    def min_by(self, by: IntoExpr) -> Expr:
+        """
+        Get the value in the row where `by` is minimal.
+
+        This gives the same result as `sort_by(by).first()` if `by` has no nulls or
+        ties, but it doesn't sort the groups.
+
+        Nulls in `by` are ignored. If `by` is tied, the value of the first of the
+        tied rows is taken.
+
+        Parameters
+        ----------
+        by
+            The column to look for the minimum in.
+            Accepts expression input. Strings are parsed as column names.
+
+        See Also
+        --------
+        min
+        arg_min
+        max_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "a", "b", "b", "b"],
+        ...         "ts": [3, 1, 2, 5, 4],
+        ...         "value": [10, 20, 30, 40, 50],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(pl.col("value").min_by("ts"))
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ key ┆ value │
+        │ --- ┆ ---   │
+        │ str ┆ i64   │
+        ╞═════╪═══════╡
+        │ "a" ┆ 20    │
+        │ "b" ┆ 30    │
+        └─────┴───────┘
+        """
+        by = parse_into_expression(by)
+        return self._from_pyexpr(self._pyexpr.min_by(by))
+
     def nan_max(self) -> Expr:
         """
         Get maximum value, but propagate/poison encountered NaN values.
@@ -3117,6 +3257,143 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.median())
 
+    def weighted_mean(self, weights: IntoExpr) -> Expr:
+        """
+        Get the mean value weighted by `weights`.
+
+        This gives the same result as `(value * weights).sum() / weights.sum()`,
+        but it doesn't materialize the groups. Rows where the value or the weight is
+        null are ignored. The result is null if the weights sum to zero.
+
+        Parameters
+        ----------
+        weights
+            Numeric expression with the same length as the column.
+            Accepts expression input. Strings are parsed as column names.
+
+        See Also
+        --------
+        weighted_var
+        weighted_quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "a", "b", "b", "b"],
+        ...         "value": [1, 2, 4, 3, 5],
+        ...         "weight": [1, 3, 2, 0, 2],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(
+        ...     pl.col("value").weighted_mean("weight")
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ key ┆ value │
+        │ --- ┆ ---   │
+        │ str ┆ f64   │
+        ╞═════╪═══════╡
+        │ "a" ┆ 1.75  │
+        │ "b" ┆ 4.5   │
+        └─────┴───────┘
+        """
+        weights = parse_into_expression(weights)
+        return self._from_pyexpr(self._pyexpr.weighted_mean(weights))
+
+    def weighted_var(self, weights: IntoExpr, ddof: int = 1) -> Expr:
+        """
+        Get the variance weighted by `weights`.
+
+        The weights are taken as frequencies, so the divisor is
+        `weights.sum() - ddof`. Rows where the value or the weight is null are
+        ignored.
+
+        Parameters
+        ----------
+        weights
+            Numeric expression with the same length as the column.
+            Accepts expression input. Strings are parsed as column names.
+        ddof
+            “Delta Degrees of Freedom”: the divisor used in the calculation is
+            `weights.sum() - ddof`.
+
+        See Also
+        --------
+        weighted_mean
+        weighted_quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "a", "b", "b", "b"],
+        ...         "value": [1, 2, 4, 3, 5],
+        ...         "weight": [1, 3, 2, 0, 2],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(
+        ...     pl.col("value").weighted_var("weight")
+        ... )
+        shape: (2, 2)
+        ┌─────┬──────────┐
+        │ key ┆ value    │
+        │ --- ┆ ---      │
+        │ str ┆ f64      │
+        ╞═════╪══════════╡
+        │ "a" ┆ 0.25     │
+        │ "b" ┆ 0.333333 │
+        └─────┴──────────┘
+        """
+        weights = parse_into_expression(weights)
+        return self._from_pyexpr(self._pyexpr.weighted_var(weights, ddof))
+
+    def weighted_quantile(self, weights: IntoExpr, quantile: float) -> Expr:
+        """
+        Get the quantile value weighted by `weights`.
+
+        This is the smallest value for which the cumulative weight is at least
+        `quantile` of the total weight. Rows where the value or the weight is null
+        are ignored.
+
+        Parameters
+        ----------
+        weights
+            Numeric expression with the same length as the column.
+            Accepts expression input. Strings are parsed as column names.
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        See Also
+        --------
+        weighted_mean
+        weighted_var
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "a", "b", "b", "b"],
+        ...         "value": [1, 2, 4, 3, 5],
+        ...         "weight": [1, 3, 2, 0, 2],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(
+        ...     pl.col("value").weighted_quantile("weight", 0.5)
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ key ┆ value │
+        │ --- ┆ ---   │
+        │ str ┆ f64   │
+        ╞═════╪═══════╡
+        │ "a" ┆ 2.0   │
+        │ "b" ┆ 4.0   │
+        └─────┴───────┘
+        """
+        weights = parse_into_expression(weights)
+        return self._from_pyexpr(self._pyexpr.weighted_quantile(weights, quantile))
+
     def product(self) -> Expr:
         """
         Compute the product of an expression.
@@ -3162,6 +3439,54 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.n_unique())
 
+    def n_unique_if(self, predicate: IntoExpr) -> Expr:
+        """
+        Count unique values in the rows where `predicate` is true.
+
+        This gives the same result as `filter(predicate).n_unique()`, but it doesn't
+        materialize the filtered groups. Rows where `predicate` is null are skipped.
+
+        Parameters
+        ----------
+        predicate
+            Boolean expression with the same length as the column.
+            Accepts expression input. Strings are parsed as column names.
+
+        Notes
+        -----
+        `null` is considered to be a unique value for the purposes of this operation.
+
+        See Also
+        --------
+        n_unique
+        count_if
+        polars.n_unique_rows
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "a", "b", "b", "b"],
+        ...         "user": ["x", "y", "x", "y", "x"],
+        ...         "active": [True, True, True, False, True],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(
+        ...     pl.col("user").n_unique_if("active")
+        ... )
+        shape: (2, 2)
+        ┌─────┬──────┐
+        │ key ┆ user │
+        │ --- ┆ ---  │
+        │ str ┆ u32  │
+        ╞═════╪══════╡
+        │ "a" ┆ 2    │
+        │ "b" ┆ 1    │
+        └─────┴──────┘
+        """
+        predicate = parse_into_expression(predicate)
+        return self._from_pyexpr(self._pyexpr.n_unique_if(predicate))
+
     def approx_n_unique(self) -> Expr:
         """
         Approximate count of unique values.
@@ -3697,10 +4022,22 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.is_last_distinct())
 
-    def is_duplicated(self) -> Expr:
+    def is_duplicated(self, keep: Literal["first", "last", False] = False) -> Expr:
         """
         Return a boolean mask indicating duplicated values.
 
+        Parameters
+        ----------
+        keep : {'first', 'last', False}
+            Which occurrences of a duplicated value not to mark.
+
+            * 'first' : Mark all occurrences except the first one.
+            * 'last' : Mark all occurrences except the last one.
+            * False : Mark all occurrences.
+
+            With 'first' or 'last' the mask marks the values to drop to deduplicate
+            the data, like the `keep` argument of :meth:`pandas.Series.duplicated`.
+
         Returns
         -------
         Expr
@@ -3708,20 +4045,25 @@ class Expr:
 
         Examples
         --------
-        >>> df = pl.DataFrame({"a": [1, 1, 2]})
-        >>> df.select(pl.col("a").is_duplicated())
-        shape: (3, 1)
-        ┌───────┐
-        │ a     │
-        │ ---   │
-        │ bool  │
-        ╞═══════╡
-        │ true  │
-        │ true  │
-        │ false │
-        └───────┘
-        """
-        return self._from_pyexpr(self._pyexpr.is_duplicated())
+        >>> df = pl.DataFrame({"a": [1, 1, 2, 1]})
+        >>> df.with_columns(
+        ...     first=pl.col("a").is_duplicated(keep="first"),
+        ...     last=pl.col("a").is_duplicated(keep="last"),
+        ...     all=pl.col("a").is_duplicated(),
+        ... )
+        shape: (4, 4)
+        ┌─────┬───────┬───────┬───────┐
+        │ a   ┆ first ┆ last  ┆ all   │
+        │ --- ┆ ---   ┆ ---   ┆ ---   │
+        │ i64 ┆ bool  ┆ bool  ┆ bool  │
+        ╞═════╪═══════╪═══════╪═══════╡
+        │ 1   ┆ false ┆ true  ┆ true  │
+        │ 1   ┆ true  ┆ true  ┆ true  │
+        │ 2   ┆ false ┆ false ┆ false │
+        │ 1   ┆ true  ┆ false ┆ true  │
+        └─────┴───────┴───────┴───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.is_duplicated(_parse_keep(keep)))
 
     def peak_max(self) -> Expr:
         """
@@ -3836,6 +4178,113 @@ class Expr:
         quantile = parse_into_expression(quantile)
         return self._from_pyexpr(self._pyexpr.quantile(quantile, interpolation))
 
+    def approx_quantile(self, quantile: float, accuracy: float = 0.01) -> Expr:
+        """
+        Get an approximate quantile value.
+
+        The values are summarized in a t-digest sketch, so the data doesn't have to be
+        sorted and groups don't have to be materialized. Use :meth:`tdigest` to keep
+        the sketches themselves, e.g. to combine results computed over several files.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        accuracy
+            The rank of the returned value is off by at most roughly this fraction of
+            the number of values, and much less towards the tails. Smaller values use
+            more memory.
+
+        See Also
+        --------
+        quantile
+        tdigest
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 2.5 │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.approx_quantile(quantile, accuracy))
+
+    def tdigest(self, accuracy: float = 0.01) -> Expr:
+        """
+        Summarize the values in a t-digest sketch.
+
+        The sketch is serialized as a Binary value. Sketches of different groups or
+        files can be combined with :meth:`tdigest_merge` and :meth:`tdigest_quantile`.
+
+        Parameters
+        ----------
+        accuracy
+            The rank of the quantiles estimated from the sketch is off by at most
+            roughly this fraction of the number of values.
+
+        See Also
+        --------
+        approx_quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"file": ["a", "a", "b", "b", "b"], "x": [1, 2, 3, 4, 5]})
+        >>> sketches = df.group_by("file").agg(pl.col("x").tdigest())
+        >>> sketches.select(pl.col("x").tdigest_quantile(0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ x   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 3.0 │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.tdigest(accuracy))
+
+    def tdigest_merge(self) -> Expr:
+        """
+        Merge t-digest sketches created by :meth:`tdigest` into a single sketch.
+
+        Null values are ignored.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"file": ["a", "a", "b", "b", "b"], "x": [1, 2, 3, 4, 5]})
+        >>> sketches = df.group_by("file").agg(pl.col("x").tdigest())
+        >>> merged = sketches.select(pl.col("x").tdigest_merge())
+        >>> merged.select(pl.col("x").tdigest_quantile(1.0)).item()
+        5.0
+        """
+        return self._from_pyexpr(self._pyexpr.tdigest_merge())
+
+    def tdigest_quantile(self, quantile: float) -> Expr:
+        """
+        Get an approximate quantile value from t-digest sketches.
+
+        All sketches created by :meth:`tdigest` are merged before the quantile is
+        estimated. Null values are ignored.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"file": ["a", "a", "b", "b", "b"], "x": [1, 2, 3, 4, 5]})
+        >>> sketches = df.group_by("file").agg(pl.col("x").tdigest())
+        >>> sketches.select(pl.col("x").tdigest_quantile(0.0)).item()
+        1.0
+        """
+        return self._from_pyexpr(self._pyexpr.tdigest_quantile(quantile))
+
     @unstable()
     def cut(
         self,
@@ -4369,40 +4818,147 @@ class Expr:
         │ 1   ┆ [4, 6]    │
         └─────┴───────────┘
 
-        Here's an example of a function that returns a scalar, where we want it
-        to stay as a scalar:
+        Here's an example of a function that returns a scalar, where we want it
+        to stay as a scalar:
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [0, 1, 0, 1],
+        ...         "b": [1, 2, 3, 4],
+        ...     }
+        ... )
+        >>> df.group_by("a").agg(
+        ...     pl.col("b").map_batches(lambda x: x.max(), returns_scalar=True)
+        ... )  # doctest: +IGNORE_RESULT
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 1   ┆ 4   │
+        │ 0   ┆ 3   │
+        └─────┴─────┘
+
+        """
+        if return_dtype is not None:
+            return_dtype = parse_into_dtype(return_dtype)
+
+        return self._from_pyexpr(
+            self._pyexpr.map_batches(
+                self._map_batches_wrapper(function, return_dtype),
+                return_dtype,
+                agg_list,
+                is_elementwise,
+                returns_scalar,
+            )
+        )
+
+    class _map_batches_with_state_wrapper:
+        def __init__(
+            self,
+            init: Callable[[], Any],
+            function: Callable[[Any, Series], Series | Any],
+        ):
+            self.init = init
+            self.function = function
+            self._local = threading.local()
+
+        def __call__(self, s: Series) -> Any:
+            try:
+                state = self._local.state
+            except AttributeError:
+                state = self._local.state = self.init()
+            return self.function(state, s)
+
+        # The thread-local state can't be pickled, and is recreated on demand anyway.
+        def __getstate__(self) -> tuple[Any, Any]:
+            return self.init, self.function
+
+        def __setstate__(self, state: tuple[Any, Any]) -> None:
+            self.__init__(*state)  # type: ignore[misc]
+
+    @unstable()
+    def map_batches_with_state(
+        self,
+        init: Callable[[], Any],
+        function: Callable[[Any, Series], Series | Any],
+        return_dtype: PolarsDataType | None = None,
+        *,
+        agg_list: bool = False,
+        is_elementwise: bool = False,
+        returns_scalar: bool = False,
+    ) -> Expr:
+        """
+        Apply a custom python function to a whole Series, with reusable state.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This works like :func:`map_batches`, but `function` is called with a state
+        object as its first argument. The state is created by calling `init` the first
+        time `function` runs on a thread, and is reused by all later calls on that
+        thread, across chunks, groups and partitions of the query. This makes it cheap
+        to use expensive objects, such as a loaded model or an HTTP client, that
+        shouldn't be shared between threads.
+
+        Parameters
+        ----------
+        init
+            Function without arguments that creates the state.
+        function
+            Function to apply, called with the state and the Series.
+        return_dtype
+            Dtype of the output Series.
+            If not set, the dtype will be inferred based on the first non-null value
+            that is returned by the function.
+        agg_list
+            Aggregate the values of the expression into a list before applying the
+            function. This parameter only works in a group-by context.
+        is_elementwise
+            If set to true this can run in the streaming engine, but may yield
+            incorrect results in group-by. Ensure you know what you are doing!
+        returns_scalar
+            If the function returns a scalar, keep the result as a scalar instead
+            of wrapping it in a list.
+
+        Notes
+        -----
+        `init` may be called once for every thread that runs the function, so the
+        state shouldn't be used to accumulate results across calls.
 
-        >>> df = pl.DataFrame(
-        ...     {
-        ...         "a": [0, 1, 0, 1],
-        ...         "b": [1, 2, 3, 4],
-        ...     }
-        ... )
-        >>> df.group_by("a").agg(
-        ...     pl.col("b").map_batches(lambda x: x.max(), returns_scalar=True)
-        ... )  # doctest: +IGNORE_RESULT
-        shape: (2, 2)
-        ┌─────┬─────┐
-        │ a   ┆ b   │
-        │ --- ┆ --- │
-        │ i64 ┆ i64 │
-        ╞═════╪═════╡
-        │ 1   ┆ 4   │
-        │ 0   ┆ 3   │
-        └─────┴─────┘
+        See Also
+        --------
+        map_batches
 
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3]})
+        >>> def load_model() -> dict[str, int]:
+        ...     return {"offset": 10}  # e.g. load a model or open a connection
+        >>> df.select(
+        ...     pl.col("a").map_batches_with_state(
+        ...         load_model, lambda model, s: s + model["offset"]
+        ...     )
+        ... )
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 11  │
+        │ 12  │
+        │ 13  │
+        └─────┘
         """
-        if return_dtype is not None:
-            return_dtype = parse_into_dtype(return_dtype)
-
-        return self._from_pyexpr(
-            self._pyexpr.map_batches(
-                self._map_batches_wrapper(function, return_dtype),
-                return_dtype,
-                agg_list,
-                is_elementwise,
-                returns_scalar,
-            )
+        return self.map_batches(
+            self._map_batches_with_state_wrapper(init, function),
+            return_dtype,
+            agg_list=agg_list,
+            is_elementwise=is_elementwise,
+            returns_scalar=returns_scalar,
         )
 
     def map_elements(
@@ -5995,9 +6551,20 @@ class Expr:
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
+        method : {'linear', 'nearest', 'cubic_spline', 'pchip'}
             Interpolation method.
 
+            - 'linear': straight line between the neighbouring non-null values.
+            - 'nearest': take the value of the nearest non-null value.
+            - 'cubic_spline': natural cubic spline through all non-null values.
+            - 'pchip': piecewise cubic that preserves monotonicity, so it does not
+              overshoot the neighbouring non-null values.
+
+        Notes
+        -----
+        Null values at the start and end are not extrapolated. The rows are assumed to
+        be evenly spaced; use :meth:`interpolate_by` for irregularly spaced data.
+
         Examples
         --------
         Fill null values using linear interpolation.
@@ -6066,14 +6633,21 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.interpolate(method))
 
-    def interpolate_by(self, by: IntoExpr) -> Expr:
+    def interpolate_by(
+        self, by: IntoExpr, method: InterpolationMethod = "linear"
+    ) -> Expr:
         """
         Fill null values using interpolation based on another column.
 
+        The values of `by` are used as the position of each row, e.g. the timestamps of
+        irregularly sampled measurements. `by` does not need to be sorted.
+
         Parameters
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'cubic_spline', 'pchip'}
+            Interpolation method; see :meth:`interpolate`.
 
         Examples
         --------
@@ -6097,9 +6671,37 @@ class Expr:
         │ null ┆ 7   ┆ 2.714286       │
         │ 3    ┆ 8   ┆ 3.0            │
         └──────┴─────┴────────────────┘
+
+        Smooth interpolation of irregularly sampled data. Unlike a cubic spline,
+        `pchip` does not overshoot where the data is flat.
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "t": [0, 1, 3, 4, 7, 8, 10],
+        ...         "a": [1.0, None, None, 4.0, 5.0, None, 5.0],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     spline=pl.col("a").interpolate_by("t", method="cubic_spline"),
+        ...     pchip=pl.col("a").interpolate_by("t", method="pchip"),
+        ... )
+        shape: (7, 4)
+        ┌─────┬──────┬──────────┬──────────┐
+        │ t   ┆ a    ┆ spline   ┆ pchip    │
+        │ --- ┆ ---  ┆ ---      ┆ ---      │
+        │ i64 ┆ f64  ┆ f64      ┆ f64      │
+        ╞═════╪══════╪══════════╪══════════╡
+        │ 0   ┆ 1.0  ┆ 1.0      ┆ 1.0      │
+        │ 1   ┆ null ┆ 1.84434  ┆ 1.939572 │
+        │ 3   ┆ null ┆ 3.382075 ┆ 3.461572 │
+        │ 4   ┆ 4.0  ┆ 4.0      ┆ 4.0      │
+        │ 7   ┆ 5.0  ┆ 5.0      ┆ 5.0      │
+        │ 8   ┆ null ┆ 5.071628 ┆ 5.0      │
+        │ 10  ┆ 5.0  ┆ 5.0      ┆ 5.0      │
+        └─────┴──────┴──────────┴──────────┘
         """
         by = parse_into_expression(by)
-        return self._from_pyexpr(self._pyexpr.interpolate_by(by))
+        return self._from_pyexpr(self._pyexpr.interpolate_by(by, method))
 
     @unstable()
     def rolling_min_by(
@@ -8396,6 +8998,122 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.rank(method, descending, seed))
 
+    def percent_rank(self, *, descending: bool = False) -> Expr:
+        """
+        Compute the relative rank of each value.
+
+        The relative rank is `(rank - 1) / (count - 1)`, where tied values get the
+        minimum of their ranks and `count` is the number of non-null values. The
+        result lies between 0 and 1; a single value has a relative rank of 0.
+
+        Parameters
+        ----------
+        descending
+            Rank in descending order.
+
+        See Also
+        --------
+        rank
+        cume_dist
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [3, 6, 1, 1, 6]})
+        >>> df.with_columns(pl.col("a").percent_rank().alias("percent_rank"))
+        shape: (5, 2)
+        ┌─────┬──────────────┐
+        │ a   ┆ percent_rank │
+        │ --- ┆ ---          │
+        │ i64 ┆ f64          │
+        ╞═════╪══════════════╡
+        │ 3   ┆ 0.5          │
+        │ 6   ┆ 0.75         │
+        │ 1   ┆ 0.0          │
+        │ 1   ┆ 0.0          │
+        │ 6   ┆ 0.75         │
+        └─────┴──────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.percent_rank(descending))
+
+    def cume_dist(self, *, descending: bool = False) -> Expr:
+        """
+        Compute the cumulative distribution of each value.
+
+        This is the fraction of non-null values that are ordered before, or tied
+        with, the value.
+
+        Parameters
+        ----------
+        descending
+            Order the values in descending order.
+
+        See Also
+        --------
+        rank
+        percent_rank
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [3, 6, 1, 1, 6]})
+        >>> df.with_columns(pl.col("a").cume_dist().alias("cume_dist"))
+        shape: (5, 2)
+        ┌─────┬───────────┐
+        │ a   ┆ cume_dist │
+        │ --- ┆ ---       │
+        │ i64 ┆ f64       │
+        ╞═════╪═══════════╡
+        │ 3   ┆ 0.6       │
+        │ 6   ┆ 1.0       │
+        │ 1   ┆ 0.4       │
+        │ 1   ┆ 0.4       │
+        │ 6   ┆ 1.0       │
+        └─────┴───────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.cume_dist(descending))
+
+    def ntile(self, n: int, *, descending: bool = False) -> Expr:
+        """
+        Divide the ordered values into `n` buckets and return the bucket of each value.
+
+        The buckets are numbered from 1 to `n` and differ in size by at most one
+        value, where the first buckets are the larger ones. Tied values are assigned
+        in the order in which they occur, so they may end up in different buckets.
+
+        Parameters
+        ----------
+        n
+            The number of buckets.
+        descending
+            Order the values in descending order.
+
+        See Also
+        --------
+        rank
+        qcut
+
+        Examples
+        --------
+        Use `ntile` with `over` to bucket the values within groups:
+
+        >>> df = pl.DataFrame(
+        ...     {"g": ["a", "a", "a", "b", "b"], "x": [10, 30, 20, 5, 7]}
+        ... )
+        >>> df.with_columns(pl.col("x").ntile(2).over("g").alias("ntile"))
+        shape: (5, 3)
+        ┌─────┬─────┬───────┐
+        │ g   ┆ x   ┆ ntile │
+        │ --- ┆ --- ┆ ---   │
+        │ str ┆ i64 ┆ u32   │
+        ╞═════╪═════╪═══════╡
+        │ "a" ┆ 10  ┆ 1     │
+        │ "a" ┆ 30  ┆ 2     │
+        │ "a" ┆ 20  ┆ 1     │
+        │ "b" ┆ 5   ┆ 1     │
+        │ "b" ┆ 7   ┆ 2     │
+        └─────┴─────┴───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.ntile(n, descending))
+
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Expr:
         """
         Calculate the first discrete difference between shifted items.
@@ -9173,8 +9891,8 @@ class Expr:
         │ i64 │
         ╞═════╡
         │ 2   │
-        │ 1   │
         │ 3   │
+        │ 1   │
         └─────┘
         """
         return self._from_pyexpr(self._pyexpr.shuffle(seed))
@@ -9187,6 +9905,7 @@ class Expr:
         with_replacement: bool = False,
         shuffle: bool = False,
         seed: int | None = None,
+        weights: IntoExpr | None = None,
     ) -> Expr:
         """
         Sample from this expression.
@@ -9205,6 +9924,10 @@ class Expr:
         seed
             Seed for the random number generator. If set to None (default), a
             random seed is generated for each sample operation.
+        weights
+            Sample every value with a probability proportional to its weight in this
+            column or expression. Values with a null or zero weight are never sampled.
+            The sampled values are always in random order.
 
         Examples
         --------
@@ -9217,25 +9940,29 @@ class Expr:
         │ i64 │
         ╞═════╡
         │ 3   │
-        │ 1   │
-        │ 1   │
+        │ 2   │
+        │ 2   │
         └─────┘
         """
         if n is not None and fraction is not None:
             msg = "cannot specify both `n` and `fraction`"
             raise ValueError(msg)
 
+        weights_pyexpr = None if weights is None else parse_into_expression(weights)
+
         if fraction is not None:
             fraction = parse_into_expression(fraction)
             return self._from_pyexpr(
-                self._pyexpr.sample_frac(fraction, with_replacement, shuffle, seed)
+                self._pyexpr.sample_frac(
+                    fraction, with_replacement, shuffle, seed, weights_pyexpr
+                )
             )
 
         if n is None:
             n = 1
         n = parse_into_expression(n)
         return self._from_pyexpr(
-            self._pyexpr.sample_n(n, with_replacement, shuffle, seed)
+            self._pyexpr.sample_n(n, with_replacement, shuffle, seed, weights_pyexpr)
         )
 
     def ewm_mean(
@@ -9416,6 +10143,147 @@ class Expr:
         half_life = parse_as_duration_string(half_life)
         return self._from_pyexpr(self._pyexpr.ewm_mean_by(by, half_life))
 
+    def ewm_std_by(
+        self,
+        by: str | IntoExpr,
+        *,
+        half_life: str | timedelta,
+        bias: bool = False,
+    ) -> Expr:
+        r"""
+        Calculate time-based exponentially weighted moving standard deviation.
+
+        The weights decay as in :meth:`ewm_mean_by`. The standard deviation is
+        updated online for every observation, so irregularly sampled series are
+        supported.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the standard deviation by. Should be ``DateTime``,
+            ``Date``, ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value.
+
+            Can be created either from a timedelta, or by using the same string
+            language as in :meth:`ewm_mean_by`.
+
+            Note that `half_life` is treated as a constant duration - calendar
+            durations such as months are not supported.
+        bias
+            When ``bias=False``, apply a correction to make the estimate
+            statistically unbiased.
+
+        Returns
+        -------
+        Expr
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df.with_columns(
+        ...     result=pl.col("values").ewm_std_by("times", half_life="4d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 0      ┆ 2020-01-01 ┆ 0.0      │
+        │ 1      ┆ 2020-01-03 ┆ 0.707107 │
+        │ 2      ┆ 2020-01-10 ┆ 1.214582 │
+        │ null   ┆ 2020-01-15 ┆ null     │
+        │ 4      ┆ 2020-01-17 ┆ 1.817498 │
+        └────────┴────────────┴──────────┘
+        """
+        by = parse_into_expression(by)
+        half_life = parse_as_duration_string(half_life)
+        return self._from_pyexpr(self._pyexpr.ewm_std_by(by, half_life, bias))
+
+    def ewm_var_by(
+        self,
+        by: str | IntoExpr,
+        *,
+        half_life: str | timedelta,
+        bias: bool = False,
+    ) -> Expr:
+        r"""
+        Calculate time-based exponentially weighted moving variance.
+
+        The weights decay as in :meth:`ewm_mean_by`. The variance is updated online
+        for every observation, so irregularly sampled series are supported.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the variance by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value.
+
+            Can be created either from a timedelta, or by using the same string
+            language as in :meth:`ewm_mean_by`.
+
+            Note that `half_life` is treated as a constant duration - calendar
+            durations such as months are not supported.
+        bias
+            When ``bias=False``, apply a correction to make the estimate
+            statistically unbiased.
+
+        Returns
+        -------
+        Expr
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df.with_columns(
+        ...     result=pl.col("values").ewm_var_by("times", half_life="4d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 0      ┆ 2020-01-01 ┆ 0.0      │
+        │ 1      ┆ 2020-01-03 ┆ 0.5      │
+        │ 2      ┆ 2020-01-10 ┆ 1.475209 │
+        │ null   ┆ 2020-01-15 ┆ null     │
+        │ 4      ┆ 2020-01-17 ┆ 3.303299 │
+        └────────┴────────────┴──────────┘
+        """
+        by = parse_into_expression(by)
+        half_life = parse_as_duration_string(half_life)
+        return self._from_pyexpr(self._pyexpr.ewm_var_by(by, half_life, bias))
+
     def ewm_std(
         self,
         *,
@@ -9962,6 +10830,7 @@ class Expr:
         bins: IntoExpr | None = None,
         *,
         bin_count: int | None = None,
+        strategy: HistBinStrategy = "uniform",
         include_category: bool = False,
         include_breakpoint: bool = False,
     ) -> Expr:
@@ -9980,6 +10849,15 @@ class Expr:
         bin_count
             If no bins provided, this will be used to determine
             the distance of the bins
+        strategy : {'uniform', 'freedman_diaconis', 'log'}
+            How to determine the bins if no bins are provided.
+
+            - 'uniform': `bin_count` bins of equal width.
+            - 'freedman_diaconis': bins of width `2 * IQR / n^(1/3)`, starting at the
+              minimum. `bin_count` is ignored.
+            - 'log': `bin_count` bins of equal width in log space, spanning the
+              positive values, or one bin per decade if `bin_count` is not given.
+              Values that are not positive are counted in the `(-inf, 0]` bin.
         include_breakpoint
             Include a column that indicates the upper breakpoint.
         include_category
@@ -10020,13 +10898,31 @@ class Expr:
         │ {3.0,"(2.0, 3.0]",2}  │
         │ {inf,"(3.0, inf]",2}  │
         └───────────────────────┘
+
+        Choose the bins with the Freedman–Diaconis rule.
+
+        >>> df.select(
+        ...     pl.col("a").hist(strategy="freedman_diaconis", include_breakpoint=True)
+        ... )
+        shape: (3, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ struct[2] │
+        ╞═══════════╡
+        │ {4.5,5}   │
+        │ {8.0,2}   │
+        │ {inf,0}   │
+        └───────────┘
         """
         if bins is not None:
             if isinstance(bins, list):
                 bins = pl.Series(bins)
             bins = parse_into_expression(bins)
         return self._from_pyexpr(
-            self._pyexpr.hist(bins, bin_count, include_category, include_breakpoint)
+            self._pyexpr.hist(
+                bins, bin_count, strategy, include_category, include_breakpoint
+            )
         )
 
     def replace(
@@ -10681,3 +11577,12 @@ def _prepare_rolling_by_window_args(window_size: timedelta | str) -> str:
     if isinstance(window_size, timedelta):
         window_size = parse_as_duration_string(window_size)
     return window_size
+
+
+def _parse_keep(keep: Literal["first", "last", False]) -> str:
+    if keep is False:
+        return "none"
+    elif keep in ("first", "last"):
+        return keep
+    msg = f"`keep` must be one of {{'first', 'last', False}}, got {keep!r}"
+    raise ValueError(msg)
@@ -15,6 +15,7 @@ if TYPE_CHECKING:
     from polars.type_aliases import (
         IntoExpr,
         IntoExprColumn,
+        ListElementwiseOperation,
         NullBehavior,
         ToStructStrategy,
     )
@@ -170,7 +171,7 @@ class ExprListNameSpace:
         │ ---       ┆ --- ┆ ---       │
         │ list[i64] ┆ i64 ┆ list[i64] │
         ╞═══════════╪═════╪═══════════╡
-        │ [1, 2, 3] ┆ 2   ┆ [2, 1]    │
+        │ [1, 2, 3] ┆ 2   ┆ [2, 3]    │
         │ [4, 5]    ┆ 1   ┆ [5]       │
         └───────────┴─────┴───────────┘
         """
@@ -1221,6 +1222,49 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_eval(expr._pyexpr, parallel))
 
+    def elementwise(
+        self, other: IntoExpr, operation: ListElementwiseOperation
+    ) -> Expr:
+        """
+        Apply an operation to the elements at the same position of two lists.
+
+        The lists in every row must have the same length. A row is null if it is null
+        in either input. Arithmetic operators between two list columns, e.g.
+        `pl.col("a") + pl.col("b")`, are applied element-wise as well.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the operation.
+        operation : {'add', 'sub', 'mul', 'truediv', 'floordiv', 'mod', 'eq', 'ne', 'lt', 'le', 'gt', 'ge', 'min', 'max'}
+            The operation to apply.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [[1, 5], [3, 4], None],
+        ...         "b": [[2, 2], [3, 1], [1, 1]],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     max=pl.col("a").list.elementwise("b", "max"),
+        ...     gt=pl.col("a").list.elementwise("b", "gt"),
+        ... )
+        shape: (3, 4)
+        ┌───────────┬───────────┬───────────┬───────────────┐
+        │ a         ┆ b         ┆ max       ┆ gt            │
+        │ ---       ┆ ---       ┆ ---       ┆ ---           │
+        │ list[i64] ┆ list[i64] ┆ list[i64] ┆ list[bool]    │
+        ╞═══════════╪═══════════╪═══════════╪═══════════════╡
+        │ [1, 5]    ┆ [2, 2]    ┆ [2, 5]    ┆ [false, true] │
+        │ [3, 4]    ┆ [3, 1]    ┆ [3, 4]    ┆ [false, true] │
+        │ null      ┆ [1, 1]    ┆ null      ┆ null          │
+        └───────────┴───────────┴───────────┴───────────────┘
+        """  # noqa: W505
+        other = parse_into_expression(other, str_as_lit=False)
+        return wrap_expr(self._pyexpr.list_elementwise(other, operation))
+
     def set_union(self, other: IntoExpr) -> Expr:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
@@ -25,6 +25,7 @@ if TYPE_CHECKING:
         PolarsDataType,
         PolarsTemporalType,
         TimeUnit,
+        TokenizeMethod,
         TransferEncoding,
     )
 
@@ -357,6 +358,48 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_to_decimal(inference_length))
 
+    def to_duration(
+        self,
+        time_unit: TimeUnit = "us",
+        *,
+        strict: bool = True,
+    ) -> Expr:
+        """
+        Convert a String column into a Duration column.
+
+        Both ISO 8601 durations (e.g. `"P1DT2H"`) and the humanized form that is used
+        to display durations (e.g. `"1d 2h 3m"`) are accepted. Years and months are not
+        supported, as their length is not fixed.
+
+        Parameters
+        ----------
+        time_unit : {'ns', 'us', 'ms'}
+            Time unit of the resulting Duration column.
+        strict
+            Raise an error if any conversion fails. If set to `False`, values that
+            could not be parsed are set to null.
+
+        See Also
+        --------
+        polars.Expr.dt.to_string : Format a Duration column as a String column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"duration": ["P1DT2H", "1d 2h 3m", "PT0.5S"]})
+        >>> df.with_columns(parsed=pl.col("duration").str.to_duration())
+        shape: (3, 2)
+        ┌──────────┬──────────────┐
+        │ duration ┆ parsed       │
+        │ ---      ┆ ---          │
+        │ str      ┆ duration[μs] │
+        ╞══════════╪══════════════╡
+        │ P1DT2H   ┆ 1d 2h        │
+        │ 1d 2h 3m ┆ 1d 2h 3m     │
+        │ PT0.5S   ┆ 500ms        │
+        └──────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_to_duration(time_unit, strict))
+
     def len_bytes(self) -> Expr:
         """
         Return the length of each string as the number of bytes.
@@ -1878,9 +1921,10 @@ class ExprStringNameSpace:
             A valid regular expression pattern, compatible with the `regex crate
             <https://docs.rs/regex/latest/regex/>`_.
         value
-            String that will replace the matched substring.
+            String that will replace the matched substring. Can refer to capture
+            groups of `pattern`, see the examples.
         literal
-            Treat `pattern` as a literal string.
+            Treat `pattern` and `value` as literal strings.
         n
             Number of matches to replace.
 
@@ -1914,7 +1958,9 @@ class ExprStringNameSpace:
 
         Capture groups are supported. Use `${1}` in the `value` string to refer to the
         first capture group in the `pattern`, `${2}` to refer to the second capture
-        group, and so on. You can also use named capture groups.
+        group, and so on. You can also use named capture groups. The short form `$1`
+        works too, as long as it isn't followed by a letter, digit or underscore.
+        If `value` is an expression, its strings can refer to capture groups as well.
 
         >>> df = pl.DataFrame({"word": ["hat", "hut"]})
         >>> df.with_columns(
@@ -1971,9 +2017,10 @@ class ExprStringNameSpace:
             A valid regular expression pattern, compatible with the `regex crate
             <https://docs.rs/regex/latest/regex/>`_.
         value
-            String that will replace the matched substring.
+            String that will replace the matched substring. Can refer to capture
+            groups of `pattern`, see the examples.
         literal
-            Treat `pattern` as a literal string.
+            Treat `pattern` and `value` as literal strings.
 
         See Also
         --------
@@ -2005,7 +2052,9 @@ class ExprStringNameSpace:
 
         Capture groups are supported. Use `${1}` in the `value` string to refer to the
         first capture group in the `pattern`, `${2}` to refer to the second capture
-        group, and so on. You can also use named capture groups.
+        group, and so on. You can also use named capture groups. The short form `$1`
+        works too, as long as it isn't followed by a letter, digit or underscore.
+        If `value` is an expression, its strings can refer to capture groups as well.
 
         >>> df = pl.DataFrame({"word": ["hat", "hut"]})
         >>> df.with_columns(
@@ -2514,6 +2563,272 @@ class ExprStringNameSpace:
             )
         )
 
+    def levenshtein(self, other: IntoExpr, *, normalized: bool = False) -> Expr:
+        """
+        Compute the Levenshtein distance to the strings in `other`.
+
+        The Levenshtein distance is the minimal number of single character
+        insertions, deletions and substitutions to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Strings are parsed as string literals, not
+            as column names.
+        normalized
+            Return a similarity between 0.0 and 1.0 instead of the distance, where
+            1.0 means the strings are equal. This is the distance divided by the
+            length of the longest string, subtracted from 1.0.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`, or :class:`Float64` if
+            `normalized` is set.
+
+        See Also
+        --------
+        hamming
+        jaro_winkler
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["kitten", "flaw", "dixon"], "b": ["sitting", "lawn", "dicksonx"]}
+        ... )
+        >>> df.with_columns(
+        ...     dist=pl.col("a").str.levenshtein(pl.col("b")),
+        ...     sim=pl.col("a").str.levenshtein(pl.col("b"), normalized=True),
+        ... )
+        shape: (3, 4)
+        ┌────────┬──────────┬──────┬──────────┐
+        │ a      ┆ b        ┆ dist ┆ sim      │
+        │ ---    ┆ ---      ┆ ---  ┆ ---      │
+        │ str    ┆ str      ┆ u32  ┆ f64      │
+        ╞════════╪══════════╪══════╪══════════╡
+        │ kitten ┆ sitting  ┆ 3    ┆ 0.571429 │
+        │ flaw   ┆ lawn     ┆ 2    ┆ 0.5      │
+        │ dixon  ┆ dicksonx ┆ 4    ┆ 0.5      │
+        └────────┴──────────┴──────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein(other, normalized))
+
+    def hamming(self, other: IntoExpr, *, normalized: bool = False) -> Expr:
+        """
+        Compute the Hamming distance to the strings in `other`.
+
+        The Hamming distance is the number of positions at which the characters
+        differ. It is only defined for strings of the same length, other pairs of
+        strings give a null.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Strings are parsed as string literals, not
+            as column names.
+        normalized
+            Return a similarity between 0.0 and 1.0 instead of the distance, where
+            1.0 means the strings are equal.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`, or :class:`Float64` if
+            `normalized` is set.
+
+        See Also
+        --------
+        levenshtein
+        jaro_winkler
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["karolin", "1011101", "abc"], "b": ["kathrin", "1001001", "abcd"]}
+        ... )
+        >>> df.with_columns(
+        ...     dist=pl.col("a").str.hamming(pl.col("b")),
+        ...     sim=pl.col("a").str.hamming(pl.col("b"), normalized=True),
+        ... )
+        shape: (3, 4)
+        ┌─────────┬─────────┬──────┬──────────┐
+        │ a       ┆ b       ┆ dist ┆ sim      │
+        │ ---     ┆ ---     ┆ ---  ┆ ---      │
+        │ str     ┆ str     ┆ u32  ┆ f64      │
+        ╞═════════╪═════════╪══════╪══════════╡
+        │ karolin ┆ kathrin ┆ 3    ┆ 0.571429 │
+        │ 1011101 ┆ 1001001 ┆ 2    ┆ 0.714286 │
+        │ abc     ┆ abcd    ┆ null ┆ null     │
+        └─────────┴─────────┴──────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming(other, normalized))
+
+    def jaro_winkler(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to the strings in `other`.
+
+        The similarity is between 0.0 and 1.0, where 1.0 means the strings are
+        equal. Strings that share a prefix are considered more similar.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Strings are parsed as string literals, not
+            as column names.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        See Also
+        --------
+        levenshtein
+        hamming
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["martha", "dixon", "abc"], "b": ["marhta", "dicksonx", "xyz"]}
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.jaro_winkler(pl.col("b")))
+        shape: (3, 3)
+        ┌────────┬──────────┬──────────┐
+        │ a      ┆ b        ┆ sim      │
+        │ ---    ┆ ---      ┆ ---      │
+        │ str    ┆ str      ┆ f64      │
+        ╞════════╪══════════╪══════════╡
+        │ martha ┆ marhta   ┆ 0.961111 │
+        │ dixon  ┆ dicksonx ┆ 0.813333 │
+        │ abc    ┆ xyz      ┆ 0.0      │
+        └────────┴──────────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other))
+
+    def tokenize(
+        self, method: TokenizeMethod = "whitespace", *, pattern: str | None = None
+    ) -> Expr:
+        r"""
+        Split the strings into a list of tokens.
+
+        Parameters
+        ----------
+        method : {'whitespace', 'words', 'regex'}
+            How to split the strings into tokens.
+
+            - 'whitespace': split on runs of whitespace.
+            - 'words': the words given by the Unicode word boundaries, without
+              punctuation and whitespace.
+            - 'regex': every non-empty match of `pattern`.
+        pattern
+            A valid regular expression pattern, compatible with the `regex crate
+            <https://docs.rs/regex/latest/regex/>`_. Only used, and required, if
+            `method` is 'regex'.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `List(String)`.
+
+        See Also
+        --------
+        split
+        extract_all
+        ngrams
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["Hello, world!", "It's 3.5 km", None]})
+        >>> df.with_columns(
+        ...     whitespace=pl.col("text").str.tokenize(),
+        ...     words=pl.col("text").str.tokenize("words"),
+        ... )
+        shape: (3, 3)
+        ┌───────────────┬───────────────────────┬───────────────────────┐
+        │ text          ┆ whitespace            ┆ words                 │
+        │ ---           ┆ ---                   ┆ ---                   │
+        │ str           ┆ list[str]             ┆ list[str]             │
+        ╞═══════════════╪═══════════════════════╪═══════════════════════╡
+        │ Hello, world! ┆ ["Hello,", "world!"]  ┆ ["Hello", "world"]    │
+        │ It's 3.5 km   ┆ ["It's", "3.5", "km"] ┆ ["It's", "3.5", "km"] │
+        │ null          ┆ null                  ┆ null                  │
+        └───────────────┴───────────────────────┴───────────────────────┘
+        """
+        if (method == "regex") != (pattern is not None):
+            msg = "a `pattern` must be given if and only if `method` is 'regex'"
+            raise ValueError(msg)
+        return wrap_expr(self._pyexpr.str_tokenize(method, pattern))
+
+    def ngrams(self, n: int) -> Expr:
+        """
+        Get the character n-grams of the strings.
+
+        The n-grams of a string are all its substrings of `n` consecutive
+        characters. Strings with less than `n` characters give an empty list.
+
+        Parameters
+        ----------
+        n
+            The number of characters of every n-gram, must be positive.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `List(String)`.
+
+        See Also
+        --------
+        tokenize
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": ["apple", "ab", None]})
+        >>> df.with_columns(ngrams=pl.col("s").str.ngrams(3))
+        shape: (3, 2)
+        ┌───────┬───────────────────────┐
+        │ s     ┆ ngrams                │
+        │ ---   ┆ ---                   │
+        │ str   ┆ list[str]             │
+        ╞═══════╪═══════════════════════╡
+        │ apple ┆ ["app", "ppl", "ple"] │
+        │ ab    ┆ []                    │
+        │ null  ┆ null                  │
+        └───────┴───────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_ngrams(n))
+
+    def strip_accents(self) -> Expr:
+        """
+        Remove accents and other combining marks from the strings.
+
+        The strings are decomposed into base characters and combining marks, after
+        which the combining marks are dropped.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": ["Crème brûlée", "naïve café", None]})
+        >>> df.with_columns(stripped=pl.col("s").str.strip_accents())
+        shape: (3, 2)
+        ┌──────────────┬──────────────┐
+        │ s            ┆ stripped     │
+        │ ---          ┆ ---          │
+        │ str          ┆ str          │
+        ╞══════════════╪══════════════╡
+        │ Crème brûlée ┆ Creme brulee │
+        │ naïve café   ┆ naive cafe   │
+        │ null         ┆ null         │
+        └──────────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_strip_accents())
+
     def join(self, delimiter: str = "", *, ignore_nulls: bool = True) -> Expr:
         """
         Vertically concatenate the string values in the column to a single string value.
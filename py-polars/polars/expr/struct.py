@@ -1,8 +1,9 @@
 from __future__ import annotations
 
 import os
-from typing import TYPE_CHECKING, Iterable, Sequence
+from typing import TYPE_CHECKING, Callable, Iterable, Sequence
 
+from polars import functions as F
 from polars._utils.parse import parse_into_list_of_expressions
 from polars._utils.wrap import wrap_expr
 
@@ -316,3 +317,38 @@ class ExprStructNameSpace:
         )
 
         return wrap_expr(self._pyexpr.struct_with_fields(pyexprs))
+
+    def map_fields(self, function: Callable[[Expr], Expr]) -> Expr:
+        """
+        Replace every field of this struct with the result of `function`.
+
+        This transforms all fields of a struct without unnesting it.
+
+        Parameters
+        ----------
+        function
+            Function that receives an expression referring to a field and returns
+            the new field. It is applied to every field of the struct.
+
+        See Also
+        --------
+        with_fields
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [{"x": 1.234, "y": 5.678}, {"x": 2.5, "y": 3.14}]})
+        >>> df.select(
+        ...     pl.col("a").struct.map_fields(lambda f: f.round(1).name.prefix("r_"))
+        ... ).unnest("a")
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ r_x ┆ r_y │
+        │ --- ┆ --- │
+        │ f64 ┆ f64 │
+        ╞═════╪═════╡
+        │ 1.2 ┆ 5.7 │
+        │ 2.5 ┆ 3.1 │
+        └─────┴─────┘
+        """
+        field = function(F.field("*"))
+        return wrap_expr(self._pyexpr.struct_map_fields(field._pyexpr))
@@ -56,11 +56,14 @@ from polars.functions.lazy import (
     mean,
     median,
     n_unique,
+    n_unique_rows,
     nth,
     quantile,
     reduce,
     rolling_corr,
+    rolling_corr_by,
     rolling_cov,
+    rolling_cov_by,
     select,
     sql_expr,
     std,
@@ -153,11 +156,14 @@ __all__ = [
     "mean_horizontal",
     "median",
     "n_unique",
+    "n_unique_rows",
     "nth",
     "quantile",
     "reduce",
     "rolling_corr",
+    "rolling_corr_by",
     "rolling_cov",
+    "rolling_cov_by",
     "select",
     "set_random_seed",
     "std",
@@ -6,6 +6,7 @@ from typing import TYPE_CHECKING, Any, Callable, Iterable, Sequence, overload
 import polars._reexport as pl
 import polars.functions as F
 from polars._utils.async_ import _AioDataFrameResult, _GeventDataFrameResult
+from polars._utils.convert import parse_as_duration_string
 from polars._utils.deprecation import deprecate_function, issue_deprecation_warning
 from polars._utils.parse import (
     parse_into_expression,
@@ -20,10 +21,12 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
     import polars.polars as plr
 
 if TYPE_CHECKING:
+    from datetime import timedelta
     from typing import Awaitable, Collection, Literal
 
     from polars import DataFrame, Expr, LazyFrame, Series
     from polars.type_aliases import (
+        ClosedInterval,
         CorrelationMethod,
         EpochTimeUnit,
         IntoExpr,
@@ -462,6 +465,63 @@ def n_unique(*columns: str) -> Expr:
     return F.col(*columns).n_unique()
 
 
+def n_unique_rows(*exprs: IntoExpr, predicate: IntoExpr | None = None) -> Expr:
+    """
+    Count the unique combinations of values of multiple columns.
+
+    This gives the same result as `pl.struct(exprs).n_unique()`, but it doesn't
+    construct the struct column.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to count the unique rows of.
+        Accepts expression input. Strings are parsed as column names.
+    predicate
+        Only count the rows where this boolean expression is true.
+        Rows where it is null are skipped.
+
+    Notes
+    -----
+    `null` is considered to be a unique value for the purposes of this operation.
+
+    See Also
+    --------
+    n_unique
+    Expr.n_unique_if
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [1, 1, 2, 1],
+    ...         "b": ["x", "x", "x", "y"],
+    ...     }
+    ... )
+    >>> df.select(pl.n_unique_rows("a", "b"))
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 3   │
+    └─────┘
+    >>> df.select(pl.n_unique_rows("a", "b", predicate=pl.col("a") == 1))
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 2   │
+    └─────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    pypredicate = None if predicate is None else parse_into_expression(predicate)
+    return wrap_expr(plr.n_unique_rows(pyexprs, pypredicate))
+
+
 def approx_n_unique(*columns: str) -> Expr:
     """
     Approximate count of unique values.
@@ -1519,6 +1579,7 @@ def arg_sort_by(
     nulls_last: bool | Sequence[bool] = False,
     multithreaded: bool = True,
     maintain_order: bool = False,
+    limit: int | None = None,
 ) -> Expr:
     """
     Return the row indices that would sort the column(s).
@@ -1534,11 +1595,15 @@ def arg_sort_by(
         Sort in descending order. When sorting by multiple columns, can be specified
         per column by passing a sequence of booleans.
     nulls_last
-        Place null values last.
+        Place null values last. When sorting by multiple columns, can be specified
+        per column by passing a sequence of booleans.
     multithreaded
         Sort using multiple threads.
     maintain_order
         Whether the order should be maintained if elements are equal.
+    limit
+        Only return the first `limit` indices. This only partially sorts the
+        data, which is faster than a full sort followed by a `head`.
 
     See Also
     --------
@@ -1585,6 +1650,19 @@ def arg_sort_by(
     │ 3   │
     └─────┘
 
+    Only compute the indices of the first rows.
+
+    >>> df.select(pl.arg_sort_by(["a", "b"], descending=True, limit=2))
+    shape: (2, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 2   │
+    │ 1   │
+    └─────┘
+
     Use gather to apply the arg sort to other columns.
 
     >>> df.select(pl.col("c").gather(pl.arg_sort_by("a")))
@@ -1604,7 +1682,9 @@ def arg_sort_by(
     descending = extend_bool(descending, len(exprs), "descending", "exprs")
     nulls_last = extend_bool(nulls_last, len(exprs), "nulls_last", "exprs")
     return wrap_expr(
-        plr.arg_sort_by(exprs, descending, nulls_last, multithreaded, maintain_order)
+        plr.arg_sort_by(
+            exprs, descending, nulls_last, multithreaded, maintain_order, limit
+        )
     )
 
 
@@ -1621,6 +1701,8 @@ def collect_all(
     comm_subexpr_elim: bool = True,
     cluster_with_columns: bool = True,
     streaming: bool = False,
+    share_scans: bool = False,
+    max_concurrency: int | None = None,
 ) -> list[DataFrame]:
     """
     Collect multiple LazyFrames at the same time.
@@ -1661,12 +1743,43 @@ def collect_all(
         .. note::
             Use :func:`explain` to see if Polars can process the query in streaming
             mode.
+    share_scans
+        Read the files that are scanned by more than one of the LazyFrames only once,
+        and share the result between them. The shared scan reads all columns that any
+        of the LazyFrames needs. Scans in streaming mode are not shared.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    max_concurrency
+        The maximum number of LazyFrames that are collected at the same time. By
+        default, all LazyFrames are collected at the same time.
 
     Returns
     -------
     list of DataFrames
         The collected DataFrames, returned in the same order as the input LazyFrames.
+
+    Examples
+    --------
+    >>> lf = pl.LazyFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    >>> a, b = pl.collect_all(
+    ...     [lf.select(pl.col("a").sum()), lf.select(pl.col("b").max())],
+    ...     max_concurrency=1,
+    ... )
+    >>> b
+    shape: (1, 1)
+    ┌─────┐
+    │ b   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 6   │
+    └─────┘
     """
+    if share_scans:
+        issue_unstable_warning("`share_scans` is considered unstable.")
+
     if no_optimization:
         predicate_pushdown = False
         projection_pushdown = False
@@ -1697,7 +1810,7 @@ def collect_all(
         )
         prepared.append(ldf)
 
-    out = plr.collect_all(prepared)
+    out = plr.collect_all(prepared, share_scans, max_concurrency)
 
     # wrap the pydataframes into dataframe
     result = [wrap_df(pydf) for pydf in out]
@@ -2167,6 +2280,213 @@ def rolling_corr(
     )
 
 
+@unstable()
+def rolling_cov_by(
+    a: IntoExpr,
+    b: IntoExpr,
+    by: IntoExpr,
+    window_size: timedelta | str,
+    *,
+    min_periods: int = 1,
+    closed: ClosedInterval = "right",
+    ddof: int = 1,
+) -> Expr:
+    """
+    Compute the rolling covariance between two columns, based on another column.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+    (the default) means the windows will be:
+
+        - (t_0 - window_size, t_0]
+        - (t_1 - window_size, t_1]
+        - ...
+        - (t_n - window_size, t_n]
+
+    The co-moments of each window are updated incrementally as rows enter and leave
+    it, which doesn't lose precision when the values are large compared to their
+    spread, unlike combining several rolling sums.
+
+    Parameters
+    ----------
+    a
+        Column name or Expression.
+    b
+        Column name or Expression.
+    by
+        This column must be of dtype Datetime or Date.
+    window_size
+        The length of the window. Can be a dynamic temporal
+        size indicated by a timedelta or the following string language:
+
+        - 1ns   (1 nanosecond)
+        - 1us   (1 microsecond)
+        - 1ms   (1 millisecond)
+        - 1s    (1 second)
+        - 1m    (1 minute)
+        - 1h    (1 hour)
+        - 1d    (1 calendar day)
+        - 1w    (1 calendar week)
+        - 1mo   (1 calendar month)
+        - 1q    (1 calendar quarter)
+        - 1y    (1 calendar year)
+
+        By "calendar day", we mean the corresponding time on the next day
+        (which may not be 24 hours, due to daylight savings). Similarly for
+        "calendar week", "calendar month", "calendar quarter", and
+        "calendar year".
+    min_periods
+        The number of values in the window that should be non-null before computing
+        a result.
+    closed : {'left', 'right', 'both', 'none'}
+        Define which sides of the temporal interval are closed (inclusive),
+        defaults to `'right'`.
+    ddof
+        "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
+
+    See Also
+    --------
+    rolling_corr_by
+    rolling_cov
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "date": pl.date_range(date(2024, 1, 1), date(2024, 1, 5), eager=True),
+    ...         "x": [1, 2, 3, 4, 5],
+    ...         "y": [1, 2, 3, 1, 5],
+    ...     }
+    ... )
+    >>> df.with_columns(cov=pl.rolling_cov_by("x", "y", "date", window_size="3d"))
+    shape: (5, 4)
+    ┌────────────┬─────┬─────┬──────┐
+    │ date       ┆ x   ┆ y   ┆ cov  │
+    │ ---        ┆ --- ┆ --- ┆ ---  │
+    │ date       ┆ i64 ┆ i64 ┆ f64  │
+    ╞════════════╪═════╪═════╪══════╡
+    │ 2024-01-01 ┆ 1   ┆ 1   ┆ null │
+    │ 2024-01-02 ┆ 2   ┆ 2   ┆ 0.5  │
+    │ 2024-01-03 ┆ 3   ┆ 3   ┆ 1.0  │
+    │ 2024-01-04 ┆ 4   ┆ 1   ┆ -0.5 │
+    │ 2024-01-05 ┆ 5   ┆ 5   ┆ 1.0  │
+    └────────────┴─────┴─────┴──────┘
+    """
+    window_size = parse_as_duration_string(window_size)
+    a = parse_into_expression(a)
+    b = parse_into_expression(b)
+    by = parse_into_expression(by)
+    return wrap_expr(
+        plr.rolling_cov_by(a, b, by, window_size, min_periods, closed, ddof)
+    )
+
+
+@unstable()
+def rolling_corr_by(
+    a: IntoExpr,
+    b: IntoExpr,
+    by: IntoExpr,
+    window_size: timedelta | str,
+    *,
+    min_periods: int = 1,
+    closed: ClosedInterval = "right",
+) -> Expr:
+    """
+    Compute the rolling correlation between two columns, based on another column.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+    (the default) means the windows will be:
+
+        - (t_0 - window_size, t_0]
+        - (t_1 - window_size, t_1]
+        - ...
+        - (t_n - window_size, t_n]
+
+    The co-moments of each window are updated incrementally as rows enter and leave
+    it, which doesn't lose precision when the values are large compared to their
+    spread, unlike combining several rolling sums.
+
+    Parameters
+    ----------
+    a
+        Column name or Expression.
+    b
+        Column name or Expression.
+    by
+        This column must be of dtype Datetime or Date.
+    window_size
+        The length of the window. Can be a dynamic temporal
+        size indicated by a timedelta or the following string language:
+
+        - 1ns   (1 nanosecond)
+        - 1us   (1 microsecond)
+        - 1ms   (1 millisecond)
+        - 1s    (1 second)
+        - 1m    (1 minute)
+        - 1h    (1 hour)
+        - 1d    (1 calendar day)
+        - 1w    (1 calendar week)
+        - 1mo   (1 calendar month)
+        - 1q    (1 calendar quarter)
+        - 1y    (1 calendar year)
+
+        By "calendar day", we mean the corresponding time on the next day
+        (which may not be 24 hours, due to daylight savings). Similarly for
+        "calendar week", "calendar month", "calendar quarter", and
+        "calendar year".
+    min_periods
+        The number of values in the window that should be non-null before computing
+        a result.
+    closed : {'left', 'right', 'both', 'none'}
+        Define which sides of the temporal interval are closed (inclusive),
+        defaults to `'right'`.
+
+    See Also
+    --------
+    rolling_cov_by
+    rolling_corr
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "date": pl.date_range(date(2024, 1, 1), date(2024, 1, 5), eager=True),
+    ...         "x": [1, 2, 3, 4, 5],
+    ...         "y": [1, 2, 3, 1, 5],
+    ...     }
+    ... )
+    >>> df.with_columns(
+    ...     corr=pl.rolling_corr_by("x", "y", "date", window_size="3d", min_periods=2)
+    ... )
+    shape: (5, 4)
+    ┌────────────┬─────┬─────┬──────┐
+    │ date       ┆ x   ┆ y   ┆ corr │
+    │ ---        ┆ --- ┆ --- ┆ ---  │
+    │ date       ┆ i64 ┆ i64 ┆ f64  │
+    ╞════════════╪═════╪═════╪══════╡
+    │ 2024-01-01 ┆ 1   ┆ 1   ┆ null │
+    │ 2024-01-02 ┆ 2   ┆ 2   ┆ 1.0  │
+    │ 2024-01-03 ┆ 3   ┆ 3   ┆ 1.0  │
+    │ 2024-01-04 ┆ 4   ┆ 1   ┆ -0.5 │
+    │ 2024-01-05 ┆ 5   ┆ 5   ┆ 0.5  │
+    └────────────┴─────┴─────┴──────┘
+    """
+    window_size = parse_as_duration_string(window_size)
+    a = parse_into_expression(a)
+    b = parse_into_expression(b)
+    by = parse_into_expression(by)
+    return wrap_expr(plr.rolling_corr_by(a, b, by, window_size, min_periods, closed))
+
+
 @overload
 def sql_expr(sql: str) -> Expr:  # type: ignore[overload-overlap]
     ...
@@ -932,6 +932,7 @@ def scan_csv(
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
+    normalize_column_names: bool = False,
     glob: bool = True,
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
@@ -1030,6 +1031,10 @@ def scan_csv(
         Truncate lines that are longer than the schema.
     decimal_comma
         Parse floats using a comma as the decimal separator instead of a period.
+    normalize_column_names
+        Normalize the column names to snake case with
+        :meth:`LazyFrame.rename_normalized`. Names in `schema_overrides` refer to the
+        column names before normalization.
     glob
         Expand path given via globbing rules.
     storage_options
@@ -1146,7 +1151,7 @@ def scan_csv(
             normalize_filepath(source, check_not_directory=False) for source in source
         ]
 
-    return _scan_csv_impl(
+    lf = _scan_csv_impl(
         source,
         has_header=has_header,
         separator=separator,
@@ -1178,6 +1183,9 @@ def scan_csv(
         storage_options=storage_options,
         file_cache_ttl=file_cache_ttl,
     )
+    if normalize_column_names:
+        lf = lf.rename_normalized()
+    return lf
 
 
 def _scan_csv_impl(
@@ -304,6 +304,7 @@ def scan_parquet(
     cache: bool = True,
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
+    deduplicate_strings: bool = False,
 ) -> LazyFrame:
     """
     Lazily read from a local or cloud-hosted parquet file (or files).
@@ -364,6 +365,10 @@ def scan_parquet(
         from environment variables.
     retries
         Number of retries if accessing a cloud instance fails.
+    deduplicate_strings
+        Store repeated values of String and Binary columns only once per chunk.
+        This reduces the memory of columns with many repeated values that should
+        not be Categorical, at the cost of some extra work while reading.
 
     See Also
     --------
@@ -414,6 +419,7 @@ def scan_parquet(
         try_parse_hive_dates=try_parse_hive_dates,
         retries=retries,
         glob=glob,
+        deduplicate_strings=deduplicate_strings,
     )
 
 
@@ -434,6 +440,7 @@ def _scan_parquet_impl(
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
     retries: int = 0,
+    deduplicate_strings: bool = False,
 ) -> LazyFrame:
     if isinstance(source, list):
         sources = source
@@ -463,5 +470,6 @@ def _scan_parquet_impl(
         try_parse_hive_dates=try_parse_hive_dates,
         retries=retries,
         glob=glob,
+        deduplicate_strings=deduplicate_strings,
     )
     return wrap_ldf(pylf)
@@ -96,7 +96,9 @@ if TYPE_CHECKING:
     from polars import DataFrame, DataType, Expr
     from polars.dependencies import numpy as np
     from polars.type_aliases import (
+        Ambiguous,
         AsofJoinStrategy,
+        AsofTieBreak,
         ClosedInterval,
         ColumnNameOrSelector,
         CsvQuoteStyle,
@@ -105,9 +107,15 @@ if TYPE_CHECKING:
         FrameInitTypes,
         IntoExpr,
         IntoExprColumn,
+        ConformCast,
+        ExtraColumns,
         JoinStrategy,
         JoinValidation,
         Label,
+        MissingColumns,
+        NameCase,
+        NameDedupe,
+        NonExistent,
         Orientation,
         PolarsDataType,
         RollingInterpolationMethod,
@@ -411,6 +419,36 @@ class LazyFrame:
 
         return cls._from_pyldf(deserializer(source))
 
+    @classmethod
+    @unstable()
+    def load_repro(cls, source: str | Path) -> LazyFrame:
+        """
+        Load a query that was saved with :func:`LazyFrame.save_repro`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The query is restored with the optimization flags it was saved with. The
+        `POLARS_*` environment variables that were recorded are not applied.
+
+        Parameters
+        ----------
+        source
+            Path to the bundle.
+
+        Warnings
+        --------
+        This function uses :mod:`pickle` if the logical plan contains Python UDFs,
+        and as such inherits the security implications. Loading can execute
+        arbitrary code, so it should only be attempted on trusted data.
+
+        See Also
+        --------
+        LazyFrame.save_repro
+        """
+        return cls._from_pyldf(PyLazyFrame.load_repro(normalize_filepath(source)))
+
     @property
     def columns(self) -> list[str]:
         """
@@ -719,6 +757,58 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return serialize_polars_object(serializer, file, format)
 
+    @unstable()
+    def save_repro(
+        self,
+        path: str | Path,
+        *,
+        sample_rows: int = 1000,
+        full_data_threshold: int = 10 * 1024 * 1024,
+    ) -> None:
+        """
+        Save the query together with its inputs into a single file.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The bundle contains the logical plan, the optimization flags, the `POLARS_*`
+        environment variables and the data of all inputs of the query, so that it
+        can be attached to a bug report and replayed with
+        :func:`LazyFrame.load_repro`.
+
+        Parameters
+        ----------
+        path
+            Path to which the bundle is written.
+        sample_rows
+            Inputs that are larger than `full_data_threshold` are replaced by their
+            first `sample_rows` rows.
+        full_data_threshold
+            Inputs of at most this many bytes are stored in full. Remote files are
+            always sampled.
+
+        Notes
+        -----
+        Sampled inputs change the result of the query, so check that the problem
+        still reproduces with the loaded bundle before sharing it.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3]}).select(pl.col("a").sum())
+        >>> lf.save_repro("repro.json")  # doctest: +SKIP
+        >>> pl.LazyFrame.load_repro("repro.json").collect()  # doctest: +SKIP
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 6   │
+        └─────┘
+        """
+        self._ldf.save_repro(normalize_filepath(path), sample_rows, full_data_threshold)
+
     def pipe(
         self,
         function: Callable[Concatenate[LazyFrame, P], T],
@@ -1755,6 +1845,57 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return df, timings
 
+    @unstable()
+    def estimate_resources(self, *, memory_budget: int | None = None) -> DataFrame:
+        """
+        Estimate the memory that the query needs, without running it.
+
+        The estimates are made from the optimized query plan and the metadata of the
+        scanned files. They are rough, and are meant to predict whether a query needs
+        the streaming engine or a bigger machine.
+
+        Returns a DataFrame with a row for every operator of the plan, indented by
+        its depth in the plan, with the following columns:
+
+        - `rows`: the estimated number of rows of the output of the operator.
+        - `output_bytes`: the estimated size of the output of the operator.
+        - `peak_bytes`: the estimated memory that is in use while the operator
+          runs, including its inputs. The peak memory of the query is the peak of
+          the first operator.
+        - `spill_bytes`: the estimated number of bytes that the streaming engine
+          spills to disk for the operator, because its state exceeds the memory
+          budget.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        memory_budget
+            The number of bytes of memory that the query may use. If set to `None`
+            (default), the memory that is currently available is used.
+
+        Notes
+        -----
+        Sources of which the number of rows is not known are estimated to be empty.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [3, 1, 2]}).sort("a")
+        >>> lf.estimate_resources(memory_budget=1024)
+        shape: (2, 5)
+        ┌──────────┬──────┬──────────────┬────────────┬─────────────┐
+        │ operator ┆ rows ┆ output_bytes ┆ peak_bytes ┆ spill_bytes │
+        │ ---      ┆ ---  ┆ ---          ┆ ---        ┆ ---         │
+        │ str      ┆ u64  ┆ u64          ┆ u64        ┆ u64         │
+        ╞══════════╪══════╪══════════════╪════════════╪═════════════╡
+        │ sort     ┆ 3    ┆ 24           ┆ 84         ┆ 0           │
+        │   df     ┆ 3    ┆ 24           ┆ 24         ┆ 0           │
+        └──────────┴──────┴──────────────┴────────────┴─────────────┘
+        """
+        return wrap_df(self._ldf.estimate_resources(memory_budget))
+
     @overload
     def collect(
         self,
@@ -1770,6 +1911,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         no_optimization: bool = False,
         streaming: bool = False,
         background: Literal[True],
+        low_latency: bool = False,
         _eager: bool = False,
     ) -> InProcessQuery: ...
 
@@ -1788,6 +1930,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         no_optimization: bool = False,
         streaming: bool = False,
         background: Literal[False] = False,
+        low_latency: bool = False,
         _eager: bool = False,
     ) -> DataFrame: ...
 
@@ -1805,6 +1948,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         no_optimization: bool = False,
         streaming: bool = False,
         background: bool = False,
+        low_latency: bool = False,
         _eager: bool = False,
         **_kwargs: Any,
     ) -> DataFrame | InProcessQuery:
@@ -1853,6 +1997,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             .. warning::
                 Background mode is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
+        low_latency
+            If the sources of the query are small, skip the optimizations and the
+            parallelism that only pay off on large data. This cuts the fixed overhead
+            of services that run many queries on tiny frames. The row limit can be
+            set with the `POLARS_LOW_LATENCY_ROW_LIMIT` environment variable.
 
         Returns
         -------
@@ -1929,6 +2078,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             _eager,
             new_streaming,
         )
+        if low_latency:
+            ldf = ldf.with_low_latency(True)
 
         if background:
             issue_unstable_warning("Background mode is considered unstable.")
@@ -2692,6 +2843,38 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return wrap_df(lf.fetch(n_rows))
 
+    @unstable()
+    def head_streaming(self, n: int = 5) -> DataFrame:
+        """
+        Evaluate the query in streaming mode and return the first `n` rows produced.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Execution stops as soon as `n` rows are available, so this can be used to
+        preview the result of an expensive query without waiting for it to complete.
+        If the query cannot run in streaming mode, this is equivalent to
+        `lf.head(n).collect()`.
+
+        Parameters
+        ----------
+        n
+            Number of rows to return.
+
+        Notes
+        -----
+        Contrary to :func:`head`, the rows are not guaranteed to be the first rows
+        of the result, but the first rows to be produced by the streaming engine.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": [5, 6, 7, 8]})
+        >>> lf.head_streaming(2).shape
+        (2, 2)
+        """
+        return wrap_df(self._ldf.head_streaming(n))
+
     def lazy(self) -> LazyFrame:
         """
         Return lazy representation, i.e. itself.
@@ -3311,6 +3494,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         offset: str | timedelta | None = None,
         closed: ClosedInterval = "right",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
+        label: Label = "datapoint",
+        include_boundaries: bool = False,
     ) -> LazyGroupBy:
         """
         Create rolling groups based on a temporal or integer column.
@@ -3376,6 +3561,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Define which sides of the temporal interval are closed (inclusive).
         group_by
             Also group by this column/these columns
+        label : {'datapoint', 'left', 'right'}
+            Define which label to use for the window:
+
+            - 'datapoint': the value of the index column the window was created for
+            - 'left': lower boundary of the window
+            - 'right': upper boundary of the window
+        include_boundaries
+            Add the lower and upper bound of the window to the "_lower_boundary" and
+            "_upper_boundary" columns.
 
         Returns
         -------
@@ -3435,7 +3629,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         period = parse_as_duration_string(period)
         offset = parse_as_duration_string(offset)
 
-        lgb = self._ldf.rolling(index_column, period, offset, closed, pyexprs_by)
+        lgb = self._ldf.rolling(
+            index_column,
+            period,
+            offset,
+            closed,
+            pyexprs_by,
+            label,
+            include_boundaries,
+        )
         return LazyGroupBy(lgb)
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -3451,6 +3653,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        ambiguous: Ambiguous = "raise",
+        non_existent: NonExistent = "raise",
     ) -> LazyGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -3521,6 +3725,20 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        ambiguous
+            Determine how to deal with window boundaries which are ambiguous in the
+            time zone of `index_column`:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: skip the boundary, merging the window with the next one
+        non_existent
+            Determine how to deal with window boundaries which are non-existent in
+            the time zone of `index_column`:
+
+            - `'raise'` (default): raise
+            - `'null'`: skip the boundary, merging the window with the next one
 
         Returns
         -------
@@ -3770,6 +3988,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             closed,
             pyexprs_by,
             start_by,
+            ambiguous,
+            non_existent,
         )
         return LazyGroupBy(lgb)
 
@@ -3789,6 +4009,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         allow_parallel: bool = True,
         force_parallel: bool = False,
         coalesce: bool | None = None,
+        allow_exact_matches: bool = True,
+        tie_break: AsofTieBreak = "forward",
+        all_matches: bool = False,
+        tolerance_column: str | None = None,
     ) -> LazyFrame:
         """
         Perform an asof join.
@@ -3874,6 +4098,24 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
             Note that joining on any other expressions than `col`
             will turn off coalescing.
+        allow_exact_matches
+            Whether right rows with a key equal to the left key may be matched. If
+            False, a "backward" search selects the last row whose key is strictly less
+            than the left's key, and a "forward" search the first row whose key is
+            strictly greater. This prevents leaking same-timestamp information in
+            point-in-time joins.
+        tie_break : {'forward', 'backward'}
+            Which right row a "nearest" search selects if two right keys are equally
+            far from the left key: the greater ('forward') or the smaller ('backward')
+            one.
+        all_matches
+            Join all right rows whose key is equal to the matched key, instead of only
+            one. A left row may then produce multiple rows in the output. This is not
+            supported in combination with `by`.
+        tolerance_column
+            Name of a column in the left frame holding a per-row tolerance, in the same
+            unit as the `on` key. Matches further away than the tolerance are
+            discarded. A null tolerance does not restrict the match.
 
 
         Examples
@@ -3965,6 +4207,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 tolerance_num,
                 tolerance_str,
                 coalesce=coalesce,
+                allow_exact_matches=allow_exact_matches,
+                tie_break=tie_break,
+                all_matches=all_matches,
+                tolerance_column=tolerance_column,
             )
         )
 
@@ -4565,6 +4811,138 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             new = list(mapping.values())
             return self._from_pyldf(self._ldf.rename(existing, new))
 
+    def rename_normalized(
+        self,
+        case: NameCase = "snake",
+        *,
+        ascii_only: bool = True,
+        dedupe: NameDedupe = "suffix",
+    ) -> LazyFrame:
+        """
+        Normalize the column names.
+
+        The names are split into words on non-alphanumeric characters and on case
+        changes, and the words are joined in the given case.
+
+        Parameters
+        ----------
+        case : {'snake', 'camel', 'pascal', 'preserve'}
+            Case of the normalized names.
+
+            - 'snake': `column_name`
+            - 'camel': `columnName`
+            - 'pascal': `ColumnName`
+            - 'preserve': keep the case of the words and join them with `_`.
+        ascii_only
+            Strip accents and replace other non-ASCII characters by a word separator.
+        dedupe : {'suffix', 'raise'}
+            How to resolve names that clash after normalization.
+
+            - 'suffix': the first column keeps the name, the next ones get the lowest
+              free suffix `_1`, `_2`, ...
+            - 'raise': raise an error.
+
+        See Also
+        --------
+        rename
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "Customer ID": [1, 2],
+        ...         "customerId": [3, 4],
+        ...         "Crème brûlée": ["a", "b"],
+        ...     }
+        ... )
+        >>> lf.rename_normalized().collect()
+        shape: (2, 3)
+        ┌─────────────┬───────────────┬──────────────┐
+        │ customer_id ┆ customer_id_1 ┆ creme_brulee │
+        │ ---         ┆ ---           ┆ ---          │
+        │ i64         ┆ i64           ┆ str          │
+        ╞═════════════╪═══════════════╪══════════════╡
+        │ 1           ┆ 3             ┆ a            │
+        │ 2           ┆ 4             ┆ b            │
+        └─────────────┴───────────────┴──────────────┘
+        >>> lf.rename_normalized("camel").collect()
+        shape: (2, 3)
+        ┌────────────┬──────────────┬─────────────┐
+        │ customerId ┆ customerId_1 ┆ cremeBrulee │
+        │ ---        ┆ ---          ┆ ---         │
+        │ i64        ┆ i64          ┆ str         │
+        ╞════════════╪══════════════╪═════════════╡
+        │ 1          ┆ 3            ┆ a           │
+        │ 2          ┆ 4            ┆ b           │
+        └────────────┴──────────────┴─────────────┘
+        """
+        return self._from_pyldf(
+            self._ldf.rename_normalized(case, ascii_only, dedupe)
+        )
+
+    def conform_to(
+        self,
+        schema: SchemaDict,
+        *,
+        missing: MissingColumns = "null",
+        extra: ExtraColumns = "drop",
+        cast: ConformCast = "safe",
+    ) -> LazyFrame:
+        """
+        Reorder, add, drop and cast columns to match the given schema.
+
+        This is done in a single projection, so the result can be appended to a table
+        with a fixed schema.
+
+        Parameters
+        ----------
+        schema
+            The target schema, mapping column names to data types.
+        missing : {'null', 'raise'}
+            What to do with columns of the schema that the frame doesn't have.
+
+            - 'null': add them filled with nulls.
+            - 'raise': raise an error.
+        extra : {'drop', 'raise'}
+            What to do with columns of the frame that are not in the schema.
+
+            - 'drop': drop them.
+            - 'raise': raise an error.
+        cast : {'safe', 'strict'}
+            Which casts are allowed to bring a column to its target data type.
+
+            - 'safe': only casts to a data type that can hold every value of the
+              original data type, e.g. `Int32` to `Int64`. Other casts raise an error
+              before the query is run.
+            - 'strict': any cast; the query raises if a value cannot be converted.
+
+        See Also
+        --------
+        cast
+        select
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {"name": ["a", "b"], "id": [1, 2], "tmp": [True, False]},
+        ...     schema_overrides={"id": pl.Int32},
+        ... )
+        >>> schema = {"id": pl.Int64, "name": pl.String, "score": pl.Float64}
+        >>> lf.conform_to(schema).collect()
+        shape: (2, 3)
+        ┌─────┬──────┬───────┐
+        │ id  ┆ name ┆ score │
+        │ --- ┆ ---  ┆ ---   │
+        │ i64 ┆ str  ┆ f64   │
+        ╞═════╪══════╪═══════╡
+        │ 1   ┆ a    ┆ null  │
+        │ 2   ┆ b    ┆ null  │
+        └─────┴──────┴───────┘
+        """
+        return self._from_pyldf(
+            self._ldf.conform_to(dict(schema), missing, extra, cast)
+        )
+
     def reverse(self) -> LazyFrame:
         """
         Reverse the DataFrame.
@@ -5535,6 +5913,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         columns: str | Expr | Sequence[str | Expr],
         *more_columns: str | Expr,
+        pad_with_nulls: bool = False,
     ) -> LazyFrame:
         """
         Explode the DataFrame to long format by exploding the given columns.
@@ -5546,6 +5925,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             columns being exploded must be of the `List` or `Array` data type.
         *more_columns
             Additional names of columns to explode, specified as positional arguments.
+        pad_with_nulls
+            Multiple columns are exploded in lockstep, so the lists of a row must have
+            the same number of elements in all columns. Set this to `True` to pad
+            the shorter lists of a row with nulls instead of raising an error.
 
         Examples
         --------
@@ -5575,7 +5958,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         columns = parse_into_list_of_expressions(
             *_expand_selectors(self, columns, *more_columns)
         )
-        return self._from_pyldf(self._ldf.explode(columns))
+        return self._from_pyldf(self._ldf.explode(columns, pad_with_nulls))
 
     def unique(
         self,
@@ -6014,7 +6397,63 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         └────────┴─────┴─────┴──────┴───────────┴───────┘
         """
         columns = _expand_selectors(self, columns, *more_columns)
-        return self._from_pyldf(self._ldf.unnest(columns))
+        return self._from_pyldf(self._ldf.unnest(columns, 1, None))
+
+    def unnest_recursive(
+        self,
+        columns: ColumnNameOrSelector | Collection[ColumnNameOrSelector],
+        *more_columns: ColumnNameOrSelector,
+        max_depth: int | None = None,
+        separator: str | None = ".",
+    ) -> LazyFrame:
+        """
+        Decompose struct columns and the struct fields they contain into columns.
+
+        The new columns will be inserted into the LazyFrame at the location of the
+        struct column.
+
+        Parameters
+        ----------
+        columns
+            Name of the struct column(s) that should be unnested.
+        *more_columns
+            Additional columns to unnest, specified as positional arguments.
+        max_depth
+            The number of levels of nested structs to unnest. If set to `None`
+            (default), all levels are unnested.
+        separator
+            The names of the new columns are the names of the struct columns and
+            fields they were nested in and their own name, joined by this separator.
+            If set to `None`, the new columns keep the names of the fields.
+
+        See Also
+        --------
+        unnest
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "id": [1, 2],
+        ...         "user": [
+        ...             {"name": "a", "address": {"city": "x", "zip": 1}},
+        ...             {"name": "b", "address": {"city": "y", "zip": 2}},
+        ...         ],
+        ...     }
+        ... )
+        >>> lf.unnest_recursive("user").collect()
+        shape: (2, 4)
+        ┌─────┬───────────┬───────────────────┬──────────────────┐
+        │ id  ┆ user.name ┆ user.address.city ┆ user.address.zip │
+        │ --- ┆ ---       ┆ ---               ┆ ---              │
+        │ i64 ┆ str       ┆ str               ┆ i64              │
+        ╞═════╪═══════════╪═══════════════════╪══════════════════╡
+        │ 1   ┆ a         ┆ x                 ┆ 1                │
+        │ 2   ┆ b         ┆ y                 ┆ 2                │
+        └─────┴───────────┴───────────────────┴──────────────────┘
+        """
+        columns = _expand_selectors(self, columns, *more_columns)
+        return self._from_pyldf(self._ldf.unnest(columns, max_depth, separator))
 
     def merge_sorted(self, other: LazyFrame, key: str) -> LazyFrame:
         """
@@ -308,6 +308,41 @@ class LazyGroupBy:
         """
         return wrap_ldf(self.lgb.tail(n))
 
+    def agg_groups_struct(self, name: str = "rows") -> LazyFrame:
+        """
+        Aggregate the rows of every group into a list of structs.
+
+        The struct fields are the columns that are not group keys. The rows of a
+        group are gathered once for all columns, which makes this efficient to
+        pass the rows of every group to a function or to serialize them to JSON.
+
+        Parameters
+        ----------
+        name
+            Name of the resulting column.
+
+        Examples
+        --------
+        >>> ldf = pl.DataFrame(
+        ...     {
+        ...         "a": ["one", "two", "one", "two"],
+        ...         "b": [1, 2, 3, 4],
+        ...         "c": ["x", "y", "z", "w"],
+        ...     }
+        ... ).lazy()
+        >>> ldf.group_by("a", maintain_order=True).agg_groups_struct().collect()
+        shape: (2, 2)
+        ┌─────┬────────────────────┐
+        │ a   ┆ rows               │
+        │ --- ┆ ---                │
+        │ str ┆ list[struct[2]]    │
+        ╞═════╪════════════════════╡
+        │ one ┆ [{1,"x"}, {3,"z"}] │
+        │ two ┆ [{2,"y"}, {4,"w"}] │
+        └─────┴────────────────────┘
+        """
+        return wrap_ldf(self.lgb.agg_groups_struct(name))
+
     def all(self) -> LazyFrame:
         """
         Aggregate the groups into Series.
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Iterable
 
 from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_s
@@ -37,6 +37,62 @@ class CatNameSpace:
         ]
         """
 
+    def add_categories(self, categories: Iterable[str]) -> Series:
+        """
+        Append categories to the categories of this data type.
+
+        The values are not changed. Categories that are present already are ignored.
+
+        Parameters
+        ----------
+        categories
+            The categories to append.
+
+        Examples
+        --------
+        >>> s = pl.Series(["S", "M", "S"], dtype=pl.Enum(["S", "M"]))
+        >>> s.cat.add_categories(["L"]).dtype
+        Enum(categories=['S', 'M', 'L'])
+        """
+
+    def remove_unused(self) -> Series:
+        """
+        Remove the categories that don't occur in the values.
+
+        The remaining categories keep their order.
+
+        Examples
+        --------
+        >>> s = pl.Series(["S", "L", "S"], dtype=pl.Enum(["S", "M", "L"]))
+        >>> s.cat.remove_unused().dtype
+        Enum(categories=['S', 'L'])
+        """
+
+    def reorder(self, categories: Iterable[str]) -> Series:
+        """
+        Change the order of the categories of this data type.
+
+        The values are not changed, but they are sorted in the new order of the
+        categories.
+
+        Parameters
+        ----------
+        categories
+            All categories, in their new order.
+
+        Examples
+        --------
+        >>> s = pl.Series(["S", "L", "M"], dtype=pl.Enum(["S", "M", "L"]))
+        >>> s.cat.reorder(["L", "M", "S"]).sort()
+        shape: (3,)
+        Series: '' [enum]
+        [
+            "L"
+            "M"
+            "S"
+        ]
+        """
+
     def is_local(self) -> bool:
         """
         Return whether or not the column is a local categorical.
@@ -128,6 +128,42 @@ class DateTimeNameSpace:
         ]
         """
 
+    def is_business_day(
+        self,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+    ) -> Series:
+        """
+        Determine whether each date is a business day.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week are business days. The default is Monday to Friday.
+            If only Monday to Thursday are business days, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays, which are not business days.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 1, 3), date(2020, 1, 4), date(2020, 1, 6)])
+        >>> s.dt.is_business_day(holidays=[date(2020, 1, 6)])
+        shape: (3,)
+        Series: '' [bool]
+        [
+                true
+                false
+                false
+        ]
+        """
+
     def min(self) -> dt.date | dt.datetime | dt.timedelta | None:
         """
         Return minimum as Python datetime.
@@ -208,7 +244,7 @@ class DateTimeNameSpace:
 
     def to_string(self, format: str) -> Series:
         """
-        Convert a Date/Time/Datetime column into a String column with the given format.
+        Convert a Date/Time/Datetime/Duration column into a String column with the given format.
 
         Similar to `cast(pl.String)`, but this method allows you to customize the
         formatting of the resulting string.
@@ -219,6 +255,9 @@ class DateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
+            Duration columns accept `"iso"` for ISO 8601 durations (e.g. `"P1DT2H3M"`)
+            or `"humanized"` for the form that is used to display them
+            (e.g. `"1d 2h 3m"`).
 
         Examples
         --------
@@ -1642,7 +1681,13 @@ class DateTimeNameSpace:
         ]
         """
 
-    def truncate(self, every: str | dt.timedelta | Expr) -> Series:
+    def truncate(
+        self,
+        every: str | dt.timedelta | Expr,
+        *,
+        ambiguous: Ambiguous = "earliest",
+        non_existent: NonExistent = "raise",
+    ) -> Series:
         """
         Divide the date/ datetime range into buckets.
 
@@ -1657,6 +1702,21 @@ class DateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        ambiguous
+            Determine how to deal with results which are ambiguous in the time zone
+            of the data and can't be localised using the DST offset of the
+            original timestamp:
+
+            - `'earliest'` (default): use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'raise'`: raise
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with results which are non-existent in the time
+            zone of the data (e.g. midnight on a day where DST starts at midnight):
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Notes
         -----
@@ -14,6 +14,7 @@ if TYPE_CHECKING:
     from polars.type_aliases import (
         IntoExpr,
         IntoExprColumn,
+        ListElementwiseOperation,
         NullBehavior,
         ToStructStrategy,
     )
@@ -160,7 +161,7 @@ class ListNameSpace:
         shape: (2,)
         Series: 'values' [list[i64]]
         [
-            [2, 1]
+            [2, 3]
             [5]
         ]
         """
@@ -953,6 +954,36 @@ class ListNameSpace:
         ]
         """
 
+    def elementwise(
+        self, other: Series, operation: ListElementwiseOperation
+    ) -> Series:
+        """
+        Apply an operation to the elements at the same position of two lists.
+
+        The lists in every row must have the same length. A row is null if it is null
+        in either input.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the operation.
+        operation : {'add', 'sub', 'mul', 'truediv', 'floordiv', 'mod', 'eq', 'ne', 'lt', 'le', 'gt', 'ge', 'min', 'max'}
+            The operation to apply.
+
+        Examples
+        --------
+        >>> a = pl.Series([[1, 5], [3, 4], None])
+        >>> b = pl.Series([[2, 2], [3, 1], [1, 1]])
+        >>> a.list.elementwise(b, "min")
+        shape: (3,)
+        Series: '' [list[i64]]
+        [
+                [1, 2]
+                [3, 1]
+                null
+        ]
+        """  # noqa: W505
+
     def set_union(self, other: Series) -> Series:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
@@ -127,6 +127,7 @@ if TYPE_CHECKING:
         ClosedInterval,
         ComparisonOperator,
         FillNullStrategy,
+        HistBinStrategy,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
@@ -954,6 +955,13 @@ class Series:
             else:
                 return self._from_pyseries(getattr(self._s, op_s)(_s))
 
+        if self.dtype == Array and isinstance(other, (int, float)):
+            _s = sequence_to_pyseries(self.name, [other])
+            if "rhs" in op_ffi:
+                return self._from_pyseries(getattr(_s, op_s)(self._s))
+            else:
+                return self._from_pyseries(getattr(self._s, op_s)(_s))
+
         if self.dtype.is_decimal() and isinstance(other, (PyDecimal, int)):
             if isinstance(other, int):
                 pyseries = sequence_to_pyseries(self.name, [other])
@@ -1022,6 +1030,9 @@ class Series:
             msg = "first cast to integer before dividing datelike dtypes"
             raise TypeError(msg)
 
+        if self.dtype == Array:
+            return self.to_frame().select_seq(F.col(self.name) / F.lit(other)).to_series()
+
         # this branch is exactly the floordiv function without rounding the floats
         if self.dtype.is_float() or self.dtype == Decimal:
             return self._arithmetic(other, "div", "div_<>")
@@ -2047,6 +2058,30 @@ class Series:
         """
         return self._s.quantile(quantile, interpolation)
 
+    def approx_quantile(self, quantile: float, accuracy: float = 0.01) -> float | None:
+        """
+        Get an approximate quantile value of this Series using a t-digest sketch.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        accuracy
+            The rank of the returned value is off by at most roughly this fraction of
+            the number of values, and much less towards the tails.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [0, 1, 2, 3, 4, 5])
+        >>> s.approx_quantile(0.5)
+        2.5
+        """
+        return (
+            self.to_frame()
+            .select(F.col(self.name).approx_quantile(quantile, accuracy))
+            .item()
+        )
+
     def to_dummies(
         self, *, separator: str = "_", drop_first: bool = False
     ) -> DataFrame:
@@ -2334,6 +2369,7 @@ class Series:
         bins: list[float] | None = None,
         *,
         bin_count: int | None = None,
+        strategy: HistBinStrategy = "uniform",
         include_category: bool = True,
         include_breakpoint: bool = True,
     ) -> DataFrame:
@@ -2352,6 +2388,15 @@ class Series:
         bin_count
             If no bins provided, this will be used to determine
             the distance of the bins
+        strategy : {'uniform', 'freedman_diaconis', 'log'}
+            How to determine the bins if no bins are provided.
+
+            - 'uniform': `bin_count` bins of equal width.
+            - 'freedman_diaconis': bins of width `2 * IQR / n^(1/3)`, starting at the
+              minimum. `bin_count` is ignored.
+            - 'log': `bin_count` bins of equal width in log space, spanning the
+              positive values, or one bin per decade if `bin_count` is not given.
+              Values that are not positive are counted in the `(-inf, 0]` bin.
         include_breakpoint
             Include a column that indicates the upper breakpoint.
         include_category
@@ -2377,6 +2422,19 @@ class Series:
         │ 6.75       ┆ (4.5, 6.75] ┆ 0     │
         │ inf        ┆ (6.75, inf] ┆ 2     │
         └────────────┴─────────────┴───────┘
+        >>> a.hist(bin_count=3, strategy="log")
+        shape: (5, 3)
+        ┌────────────┬─────────────┬───────┐
+        │ breakpoint ┆ category    ┆ count │
+        │ ---        ┆ ---         ┆ ---   │
+        │ f64        ┆ cat         ┆ u32   │
+        ╞════════════╪═════════════╪═══════╡
+        │ 0.0        ┆ (-inf, 0.0] ┆ 0     │
+        │ 2.0        ┆ (0.0, 2.0]  ┆ 3     │
+        │ 4.0        ┆ (2.0, 4.0]  ┆ 2     │
+        │ 8.0        ┆ (4.0, 8.0]  ┆ 2     │
+        │ inf        ┆ (8.0, inf]  ┆ 0     │
+        └────────────┴─────────────┴───────┘
         """
         out = (
             self.to_frame()
@@ -2384,6 +2442,7 @@ class Series:
                 F.col(self.name).hist(
                     bins=bins,
                     bin_count=bin_count,
+                    strategy=strategy,
                     include_category=include_category,
                     include_breakpoint=include_breakpoint,
                 )
@@ -3800,10 +3859,19 @@ class Series:
         ]
         """
 
-    def is_duplicated(self) -> Series:
+    def is_duplicated(self, keep: Literal["first", "last", False] = False) -> Series:
         """
         Get mask of all duplicated values.
 
+        Parameters
+        ----------
+        keep : {'first', 'last', False}
+            Which occurrences of a duplicated value not to mark.
+
+            * 'first' : Mark all occurrences except the first one.
+            * 'last' : Mark all occurrences except the last one.
+            * False : Mark all occurrences.
+
         Returns
         -------
         Series
@@ -3821,6 +3889,15 @@ class Series:
                 true
                 false
         ]
+        >>> s.is_duplicated(keep="first")
+        shape: (4,)
+        Series: 'a' [bool]
+        [
+                false
+                false
+                true
+                false
+        ]
         """
 
     def explode(self) -> Series:
@@ -6065,8 +6142,8 @@ class Series:
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
-            Interpolation method.
+        method : {'linear', 'nearest', 'cubic_spline', 'pchip'}
+            Interpolation method; see :meth:`Expr.interpolate`.
 
         Examples
         --------
@@ -6083,7 +6160,9 @@ class Series:
         ]
         """
 
-    def interpolate_by(self, by: IntoExpr) -> Series:
+    def interpolate_by(
+        self, by: IntoExpr, method: InterpolationMethod = "linear"
+    ) -> Series:
         """
         Fill null values using interpolation based on another column.
 
@@ -6091,6 +6170,8 @@ class Series:
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'cubic_spline', 'pchip'}
+            Interpolation method; see :meth:`Expr.interpolate`.
 
         Examples
         --------
@@ -6194,6 +6275,91 @@ class Series:
         ]
         """
 
+    def percent_rank(self, *, descending: bool = False) -> Series:
+        """
+        Compute the relative rank of each value.
+
+        The relative rank is `(rank - 1) / (count - 1)`, where tied values get the
+        minimum of their ranks and `count` is the number of non-null values. The
+        result lies between 0 and 1; a single value has a relative rank of 0.
+
+        Parameters
+        ----------
+        descending
+            Rank in descending order.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 6, 1, 1, 6])
+        >>> s.percent_rank()
+        shape: (5,)
+        Series: 'a' [f64]
+        [
+            0.5
+            0.75
+            0.0
+            0.0
+            0.75
+        ]
+        """
+
+    def cume_dist(self, *, descending: bool = False) -> Series:
+        """
+        Compute the cumulative distribution of each value.
+
+        This is the fraction of non-null values that are ordered before, or tied
+        with, the value.
+
+        Parameters
+        ----------
+        descending
+            Order the values in descending order.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 6, 1, 1, 6])
+        >>> s.cume_dist()
+        shape: (5,)
+        Series: 'a' [f64]
+        [
+            0.6
+            1.0
+            0.4
+            0.4
+            1.0
+        ]
+        """
+
+    def ntile(self, n: int, *, descending: bool = False) -> Series:
+        """
+        Divide the ordered values into `n` buckets and return the bucket of each value.
+
+        The buckets are numbered from 1 to `n` and differ in size by at most one
+        value, where the first buckets are the larger ones. Tied values are assigned
+        in the order in which they occur, so they may end up in different buckets.
+
+        Parameters
+        ----------
+        n
+            The number of buckets.
+        descending
+            Order the values in descending order.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 6, 1, 1, 6])
+        >>> s.ntile(2)
+        shape: (5,)
+        Series: 'a' [u32]
+        [
+            1
+            2
+            1
+            1
+            2
+        ]
+        """
+
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Series:
         """
         Calculate the first discrete difference between shifted items.
@@ -6786,8 +6952,8 @@ class Series:
         Series: 'a' [i64]
         [
                 2
-                1
                 3
+                1
         ]
         """
 
@@ -6954,6 +7120,131 @@ class Series:
         ]
         """
 
+    def ewm_std_by(
+        self,
+        by: IntoExpr,
+        *,
+        half_life: str | timedelta,
+        bias: bool = False,
+    ) -> Series:
+        r"""
+        Calculate time-based exponentially weighted moving standard deviation.
+
+        The weights decay as in :meth:`ewm_mean_by`. The standard deviation is
+        updated online for every observation, so irregularly sampled series are
+        supported.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the standard deviation by. Should be ``DateTime``,
+            ``Date``, ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value.
+
+            Can be created either from a timedelta, or by using the same string
+            language as in :meth:`ewm_mean_by`.
+
+            Note that `half_life` is treated as a constant duration - calendar
+            durations such as months are not supported.
+        bias
+            When ``bias=False``, apply a correction to make the estimate
+            statistically unbiased.
+
+        Returns
+        -------
+        Series
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df["values"].ewm_std_by(df["times"], half_life="4d")
+        shape: (5,)
+        Series: 'values' [f64]
+        [
+                0.0
+                0.707107
+                1.214582
+                null
+                1.817498
+        ]
+        """
+
+    def ewm_var_by(
+        self,
+        by: IntoExpr,
+        *,
+        half_life: str | timedelta,
+        bias: bool = False,
+    ) -> Series:
+        r"""
+        Calculate time-based exponentially weighted moving variance.
+
+        The weights decay as in :meth:`ewm_mean_by`. The variance is updated online
+        for every observation, so irregularly sampled series are supported.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the variance by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value.
+
+            Can be created either from a timedelta, or by using the same string
+            language as in :meth:`ewm_mean_by`.
+
+            Note that `half_life` is treated as a constant duration - calendar
+            durations such as months are not supported.
+        bias
+            When ``bias=False``, apply a correction to make the estimate
+            statistically unbiased.
+
+        Returns
+        -------
+        Series
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df["values"].ewm_var_by(df["times"], half_life="4d")
+        shape: (5,)
+        Series: 'values' [f64]
+        [
+                0.0
+                0.5
+                1.475209
+                null
+                3.303299
+        ]
+        """
+
     def ewm_std(
         self,
         *,
@@ -16,6 +16,7 @@ if TYPE_CHECKING:
         PolarsDataType,
         PolarsTemporalType,
         TimeUnit,
+        TokenizeMethod,
         TransferEncoding,
     )
 
@@ -298,6 +299,40 @@ class StringNameSpace:
         ]
         """
 
+    def to_duration(
+        self,
+        time_unit: TimeUnit = "us",
+        *,
+        strict: bool = True,
+    ) -> Series:
+        """
+        Convert a String column into a Duration column.
+
+        Both ISO 8601 durations (e.g. `"P1DT2H"`) and the humanized form that is used
+        to display durations (e.g. `"1d 2h 3m"`) are accepted. Years and months are not
+        supported, as their length is not fixed.
+
+        Parameters
+        ----------
+        time_unit : {'ns', 'us', 'ms'}
+            Time unit of the resulting Duration column.
+        strict
+            Raise an error if any conversion fails. If set to `False`, values that
+            could not be parsed are set to null.
+
+        Examples
+        --------
+        >>> s = pl.Series(["P1DT2H", "1d 2h 3m", "PT0.5S"])
+        >>> s.str.to_duration()
+        shape: (3,)
+        Series: '' [duration[μs]]
+        [
+            1d 2h
+            1d 2h 3m
+            500ms
+        ]
+        """
+
     def len_bytes(self) -> Series:
         """
         Return the length of each string as the number of bytes.
@@ -1092,9 +1127,10 @@ class StringNameSpace:
             A valid regular expression pattern, compatible with the `regex crate
             <https://docs.rs/regex/latest/regex/>`_.
         value
-            String that will replace the matched substring.
+            String that will replace the matched substring. Can refer to capture
+            groups of `pattern`, see the examples.
         literal
-            Treat `pattern` as a literal string.
+            Treat `pattern` and `value` as literal strings.
         n
             Number of matches to replace.
 
@@ -1125,7 +1161,8 @@ class StringNameSpace:
 
         Capture groups are supported. Use `${1}` in the `value` string to refer to the
         first capture group in the `pattern`, `${2}` to refer to the second capture
-        group, and so on. You can also use named capture groups.
+        group, and so on. You can also use named capture groups. The short form `$1`
+        works too, as long as it isn't followed by a letter, digit or underscore.
 
         >>> s = pl.Series(["hat", "hut"])
         >>> s.str.replace("h(.)t", "b${1}d")
@@ -1166,9 +1203,10 @@ class StringNameSpace:
             A valid regular expression pattern, compatible with the `regex crate
             <https://docs.rs/regex/latest/regex/>`_.
         value
-            String that will replace the matched substring.
+            String that will replace the matched substring. Can refer to capture
+            groups of `pattern`, see the examples.
         literal
-            Treat `pattern` as a literal string.
+            Treat `pattern` and `value` as literal strings.
 
         See Also
         --------
@@ -1197,7 +1235,8 @@ class StringNameSpace:
 
         Capture groups are supported. Use `${1}` in the `value` string to refer to the
         first capture group in the `pattern`, `${2}` to refer to the second capture
-        group, and so on. You can also use named capture groups.
+        group, and so on. You can also use named capture groups. The short form `$1`
+        works too, as long as it isn't followed by a letter, digit or underscore.
 
         >>> s = pl.Series(["hat", "hut"])
         >>> s.str.replace_all("h(.)t", "b${1}d")
@@ -1880,6 +1919,191 @@ class StringNameSpace:
         ]
         """
 
+    def levenshtein(self, other: IntoExpr, *, normalized: bool = False) -> Series:
+        """
+        Compute the Levenshtein distance to the strings in `other`.
+
+        The Levenshtein distance is the minimal number of single character
+        insertions, deletions and substitutions to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with.
+        normalized
+            Return a similarity between 0.0 and 1.0 instead of the distance, where
+            1.0 means the strings are equal. This is the distance divided by the
+            length of the longest string, subtracted from 1.0.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`, or :class:`Float64` if `normalized`
+            is set.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "sitting", "mitten"])
+        >>> s.str.levenshtein("kitten")
+        shape: (3,)
+        Series: '' [u32]
+        [
+                0
+                3
+                1
+        ]
+        """
+
+    def hamming(self, other: IntoExpr, *, normalized: bool = False) -> Series:
+        """
+        Compute the Hamming distance to the strings in `other`.
+
+        The Hamming distance is the number of positions at which the characters
+        differ. It is only defined for strings of the same length, other pairs of
+        strings give a null.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with.
+        normalized
+            Return a similarity between 0.0 and 1.0 instead of the distance, where
+            1.0 means the strings are equal.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`, or :class:`Float64` if `normalized`
+            is set.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "sitting", "mitten"])
+        >>> s.str.hamming("kitten")
+        shape: (3,)
+        Series: '' [u32]
+        [
+                0
+                null
+                1
+        ]
+        """
+
+    def jaro_winkler(self, other: IntoExpr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to the strings in `other`.
+
+        The similarity is between 0.0 and 1.0, where 1.0 means the strings are
+        equal. Strings that share a prefix are considered more similar.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "sitting", "mitten"])
+        >>> s.str.jaro_winkler("kitten")
+        shape: (3,)
+        Series: '' [f64]
+        [
+                1.0
+                0.746032
+                0.888889
+        ]
+        """
+
+    def tokenize(
+        self, method: TokenizeMethod = "whitespace", *, pattern: str | None = None
+    ) -> Series:
+        r"""
+        Split the strings into a list of tokens.
+
+        Parameters
+        ----------
+        method : {'whitespace', 'words', 'regex'}
+            How to split the strings into tokens.
+
+            - 'whitespace': split on runs of whitespace.
+            - 'words': the words given by the Unicode word boundaries, without
+              punctuation and whitespace.
+            - 'regex': every non-empty match of `pattern`.
+        pattern
+            A valid regular expression pattern, compatible with the `regex crate
+            <https://docs.rs/regex/latest/regex/>`_. Only used, and required, if
+            `method` is 'regex'.
+
+        Returns
+        -------
+        Series
+            Series of data type `List(String)`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["1 + 2 = 3", "no digits"])
+        >>> s.str.tokenize("regex", pattern=r"\d+")
+        shape: (2,)
+        Series: '' [list[str]]
+        [
+                ["1", "2", "3"]
+                []
+        ]
+        """
+
+    def ngrams(self, n: int) -> Series:
+        """
+        Get the character n-grams of the strings.
+
+        The n-grams of a string are all its substrings of `n` consecutive
+        characters. Strings with less than `n` characters give an empty list.
+
+        Parameters
+        ----------
+        n
+            The number of characters of every n-gram, must be positive.
+
+        Returns
+        -------
+        Series
+            Series of data type `List(String)`.
+
+        Examples
+        --------
+        >>> pl.Series(["word", "a"]).str.ngrams(2)
+        shape: (2,)
+        Series: '' [list[str]]
+        [
+                ["wo", "or", "rd"]
+                []
+        ]
+        """
+
+    def strip_accents(self) -> Series:
+        """
+        Remove accents and other combining marks from the strings.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> pl.Series(["Ångström", "façade"]).str.strip_accents()
+        shape: (2,)
+        Series: '' [str]
+        [
+                "Angstrom"
+                "facade"
+        ]
+        """
+
     def join(self, delimiter: str = "", *, ignore_nulls: bool = True) -> Series:
         """
         Vertically concatenate the string values in the column to a single string value.
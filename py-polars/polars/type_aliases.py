@@ -83,18 +83,26 @@ ColumnNameOrSelector: TypeAlias = Union[str, SelectorType]
 # User-facing string literal types
 # The following all have an equivalent Rust enum with the same name
 Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
+AsofTieBreak: TypeAlias = Literal["forward", "backward"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
+ConformCast: TypeAlias = Literal["safe", "strict"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
+JsonOrient: TypeAlias = Literal["records", "columns", "values"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+ExtraColumns: TypeAlias = Literal["drop", "raise"]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
 ]
 FloatFmt: TypeAlias = Literal["full", "mixed"]
+HistBinStrategy: TypeAlias = Literal["uniform", "freedman_diaconis", "log"]
 IndexOrder: TypeAlias = Literal["c", "fortran"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
+MissingColumns: TypeAlias = Literal["null", "raise"]
+NameCase: TypeAlias = Literal["snake", "camel", "pascal", "preserve"]
+NameDedupe: TypeAlias = Literal["suffix", "raise"]
 NonExistent: TypeAlias = Literal["raise", "null"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 ParallelStrategy: TypeAlias = Literal["auto", "columns", "row_groups", "none"]
@@ -104,6 +112,7 @@ ParquetCompression: TypeAlias = Literal[
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
+PivotNameOrder: TypeAlias = Literal["values_first", "on_first"]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
 SerializationFormat: TypeAlias = Literal["binary", "json"]
@@ -131,6 +140,7 @@ StartBy: TypeAlias = Literal[
     "sunday",
 ]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
+TokenizeMethod: TypeAlias = Literal["whitespace", "words", "regex"]
 UniqueKeepStrategy: TypeAlias = Literal["first", "last", "any", "none"]
 UnstackDirection: TypeAlias = Literal["vertical", "horizontal"]
 MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
@@ -138,7 +148,7 @@ MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 # The following have a Rust enum equivalent with a different name
 AsofJoinStrategy: TypeAlias = Literal["backward", "forward", "nearest"]  # AsofStrategy
 ClosedInterval: TypeAlias = Literal["left", "right", "both", "none"]  # ClosedWindow
-InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
+InterpolationMethod: TypeAlias = Literal["linear", "nearest", "cubic_spline", "pchip"]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "full", "semi", "anti", "cross", "outer"
 ]  # JoinType
@@ -148,6 +158,22 @@ RollingInterpolationMethod: TypeAlias = Literal[
 ToStructStrategy: TypeAlias = Literal[
     "first_non_null", "max_width"
 ]  # ListToStructWidthStrategy
+ListElementwiseOperation: TypeAlias = Literal[
+    "add",
+    "sub",
+    "mul",
+    "truediv",
+    "floordiv",
+    "mod",
+    "eq",
+    "ne",
+    "lt",
+    "le",
+    "gt",
+    "ge",
+    "min",
+    "max",
+]  # ListBinaryOp
 
 # The following have no equivalent on the Rust side
 ConcatMethod = Literal[
@@ -301,6 +301,9 @@ impl ToPyObject for Wrap<DataType> {
             DataType::BinaryOffset => {
                 unimplemented!()
             },
+            DataType::Int128 | DataType::UInt128 => {
+                unimplemented!("128-bit integers are not supported in Python")
+            },
         }
     }
 }
@@ -651,14 +654,48 @@ impl<'py> FromPyObject<'py> for Wrap<AsofStrategy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<AsofTieBreak> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "forward" => AsofTieBreak::Forward,
+            "backward" => AsofTieBreak::Backward,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "asof `tie_break` must be one of {{'forward', 'backward'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*(ob.extract::<PyBackedStr>()?) {
             "linear" => InterpolationMethod::Linear,
             "nearest" => InterpolationMethod::Nearest,
+            "cubic_spline" => InterpolationMethod::CubicSpline,
+            "pchip" => InterpolationMethod::Pchip,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "interpolation `method` must be one of {{'linear', 'nearest'}}, got {v}",
+                    "interpolation `method` must be one of {{'linear', 'nearest', 'cubic_spline', 'pchip'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "hist")]
+impl<'py> FromPyObject<'py> for Wrap<HistBinStrategy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "uniform" => HistBinStrategy::Uniform,
+            "freedman_diaconis" => HistBinStrategy::FreedmanDiaconis,
+            "log" => HistBinStrategy::Log,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "histogram `strategy` must be one of {{'uniform', 'freedman_diaconis', 'log'}}, got {v}",
                 )))
             },
         };
@@ -821,6 +858,23 @@ impl<'py> FromPyObject<'py> for Wrap<ListToStructWidthStrategy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<Ambiguous> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "earliest" => Ambiguous::Earliest,
+            "latest" => Ambiguous::Latest,
+            "null" => Ambiguous::Null,
+            "raise" => Ambiguous::Raise,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`ambiguous` must be one of {{'earliest', 'latest', 'null', 'raise'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<NonExistent> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -968,6 +1022,23 @@ impl<'py> FromPyObject<'py> for Wrap<TimeUnit> {
     }
 }
 
+#[cfg(feature = "pivot")]
+impl<'py> FromPyObject<'py> for Wrap<polars_ops::pivot::PivotNameOrder> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        use polars_ops::pivot::PivotNameOrder;
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "values_first" => PivotNameOrder::ValuesFirst,
+            "on_first" => PivotNameOrder::OnFirst,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`name_order` must be one of {{'values_first', 'on_first'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<UniqueKeepStrategy> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -985,6 +1056,83 @@ impl<'py> FromPyObject<'py> for Wrap<UniqueKeepStrategy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<NameCase> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "snake" => NameCase::Snake,
+            "camel" => NameCase::Camel,
+            "pascal" => NameCase::Pascal,
+            "preserve" => NameCase::Preserve,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`case` must be one of {{'snake', 'camel', 'pascal', 'preserve'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<NameDedupe> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "suffix" => NameDedupe::Suffix,
+            "raise" => NameDedupe::Error,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`dedupe` must be one of {{'suffix', 'raise'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<MissingColumns> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "null" => MissingColumns::Null,
+            "raise" => MissingColumns::Error,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`missing` must be one of {{'null', 'raise'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<ExtraColumns> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "drop" => ExtraColumns::Drop,
+            "raise" => ExtraColumns::Error,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`extra` must be one of {{'drop', 'raise'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<ConformCast> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "safe" => ConformCast::Safe,
+            "strict" => ConformCast::Strict,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`cast` must be one of {{'safe', 'strict'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "ipc")]
 impl<'py> FromPyObject<'py> for Wrap<IpcCompression> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -1086,6 +1234,23 @@ impl<'py> FromPyObject<'py> for Wrap<QuoteStyle> {
     }
 }
 
+#[cfg(feature = "json")]
+impl<'py> FromPyObject<'py> for Wrap<JsonOrient> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "records" => JsonOrient::Records,
+            "columns" => JsonOrient::Columns,
+            "values" => JsonOrient::Values,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`orient` must be one of {{'records', 'columns', 'values'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "cloud")]
 pub(crate) fn parse_cloud_options(uri: &str, kv: Vec<(String, String)>) -> PyResult<CloudOptions> {
     let out = CloudOptions::from_untyped_config(uri, kv).map_err(PyPolarsErr::from)?;
@@ -1110,6 +1275,34 @@ impl<'py> FromPyObject<'py> for Wrap<SetOperation> {
     }
 }
 
+#[cfg(feature = "list_elementwise")]
+impl<'py> FromPyObject<'py> for Wrap<ListBinaryOp> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "add" => ListBinaryOp::Add,
+            "sub" => ListBinaryOp::Sub,
+            "mul" => ListBinaryOp::Mul,
+            "truediv" => ListBinaryOp::TrueDiv,
+            "floordiv" => ListBinaryOp::FloorDiv,
+            "mod" => ListBinaryOp::Rem,
+            "eq" => ListBinaryOp::Eq,
+            "ne" => ListBinaryOp::NotEq,
+            "lt" => ListBinaryOp::Lt,
+            "le" => ListBinaryOp::LtEq,
+            "gt" => ListBinaryOp::Gt,
+            "ge" => ListBinaryOp::GtEq,
+            "min" => ListBinaryOp::Min,
+            "max" => ListBinaryOp::Max,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "list element-wise operation must be one of {{'add', 'sub', 'mul', 'truediv', 'floordiv', 'mod', 'eq', 'ne', 'lt', 'le', 'gt', 'ge', 'min', 'max'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 pub(crate) fn parse_fill_null_strategy(
     strategy: &str,
     limit: FillNullLimit,
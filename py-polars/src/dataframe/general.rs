@@ -2,7 +2,9 @@ use either::Either;
 use polars::prelude::*;
 use polars_core::frame::*;
 #[cfg(feature = "pivot")]
-use polars_lazy::frame::pivot::{pivot, pivot_stable};
+use polars_lazy::frame::pivot::pivot_multiple;
+#[cfg(feature = "pivot")]
+use polars_ops::pivot::{PivotNameOrder, PivotOptions};
 use pyo3::exceptions::PyIndexError;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
@@ -117,6 +119,25 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
+    #[pyo3(signature = (by, n, fraction, seed))]
+    pub fn sample_stratified(
+        &self,
+        py: Python,
+        by: Vec<String>,
+        n: Option<usize>,
+        fraction: Option<f64>,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        let size = match fraction {
+            Some(fraction) => StratumSize::Fraction(fraction),
+            None => StratumSize::N(n.unwrap_or(1)),
+        };
+        let df = py
+            .allow_threads(|| self.df.sample_stratified(by, size, seed))
+            .map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn rechunk(&self, py: Python) -> Self {
         let mut df = self.df.clone();
         py.allow_threads(|| df.as_single_chunk_par());
@@ -386,7 +407,8 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "pivot")]
-    #[pyo3(signature = (on, index, values, maintain_order, sort_columns, aggregate_expr, separator))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (on, index, values, maintain_order, sort_columns, aggregate_exprs, separator, name_order))]
     pub fn pivot_expr(
         &self,
         on: Vec<String>,
@@ -394,21 +416,20 @@ impl PyDataFrame {
         values: Option<Vec<String>>,
         maintain_order: bool,
         sort_columns: bool,
-        aggregate_expr: Option<PyExpr>,
-        separator: Option<&str>,
+        aggregate_exprs: Vec<PyExpr>,
+        separator: Option<String>,
+        name_order: Wrap<PivotNameOrder>,
     ) -> PyResult<Self> {
-        let fun = if maintain_order { pivot_stable } else { pivot };
-        let agg_expr = aggregate_expr.map(|expr| expr.inner);
-        let df = fun(
-            &self.df,
-            on,
-            index,
-            values,
+        let agg_exprs = aggregate_exprs.into_iter().map(|expr| expr.inner).collect();
+        let options = PivotOptions {
+            maintain_order,
             sort_columns,
-            agg_expr,
             separator,
-        )
-        .map_err(PyPolarsErr::from)?;
+            name_order: name_order.0,
+            ..Default::default()
+        };
+        let df = pivot_multiple(&self.df, on, index, values, agg_exprs, &options)
+            .map_err(PyPolarsErr::from)?;
         Ok(PyDataFrame::new(df))
     }
 
@@ -488,6 +509,42 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
+    #[pyo3(signature = (maintain_order, subset, keep))]
+    pub fn arg_unique(
+        &self,
+        py: Python,
+        maintain_order: bool,
+        subset: Option<Vec<String>>,
+        keep: Wrap<UniqueKeepStrategy>,
+    ) -> PyResult<PySeries> {
+        let idx = py
+            .allow_threads(|| {
+                self.df
+                    .unique_idx(maintain_order, subset.as_deref(), keep.0, None)
+            })
+            .map_err(PyPolarsErr::from)?;
+        Ok(idx.into_series().into())
+    }
+
+    #[pyo3(signature = (shrink_dates, categorical_threshold, use_enum))]
+    pub fn shrink_dtype(
+        &self,
+        py: Python,
+        shrink_dates: bool,
+        categorical_threshold: Option<f64>,
+        use_enum: bool,
+    ) -> PyResult<Self> {
+        let options = ShrinkOptions {
+            shrink_dates,
+            categorical_threshold,
+            use_enum,
+        };
+        let df = py
+            .allow_threads(|| self.df.shrink_dtype(&options))
+            .map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn null_count(&self) -> Self {
         let df = self.df.null_count();
         df.into()
@@ -560,14 +617,20 @@ impl PyDataFrame {
         index_column: &str,
         every: &str,
         stable: bool,
+        ambiguous: Wrap<Ambiguous>,
+        non_existent: Wrap<NonExistent>,
     ) -> PyResult<Self> {
-        let out = if stable {
-            self.df
-                .upsample_stable(by, index_column, Duration::parse(every))
-        } else {
-            self.df.upsample(by, index_column, Duration::parse(every))
-        };
-        let out = out.map_err(PyPolarsErr::from)?;
+        let out = self
+            .df
+            .upsample_with_dst_strategy(
+                by,
+                index_column,
+                Duration::parse(every),
+                stable,
+                ambiguous.0,
+                non_existent.0,
+            )
+            .map_err(PyPolarsErr::from)?;
         Ok(out.into())
     }
 
@@ -576,8 +639,17 @@ impl PyDataFrame {
         s.into_series().into()
     }
 
-    pub fn unnest(&self, columns: Vec<String>) -> PyResult<Self> {
-        let df = self.df.unnest(columns).map_err(PyPolarsErr::from)?;
+    #[pyo3(signature = (columns, max_depth, separator))]
+    pub fn unnest(
+        &self,
+        columns: Vec<String>,
+        max_depth: Option<usize>,
+        separator: Option<&str>,
+    ) -> PyResult<Self> {
+        let df = self
+            .df
+            .unnest_recursive(columns, max_depth, separator)
+            .map_err(PyPolarsErr::from)?;
         Ok(df.into())
     }
 
@@ -439,11 +439,29 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "json")]
-    pub fn write_json(&mut self, py_f: PyObject) -> PyResult<()> {
+    pub fn write_json(
+        &mut self,
+        py_f: PyObject,
+        orient: Wrap<JsonOrient>,
+        skip_nulls: bool,
+        datetime_format: Option<String>,
+        date_format: Option<String>,
+        time_format: Option<String>,
+    ) -> PyResult<()> {
         let file = BufWriter::new(get_file_like(py_f, true)?);
+        let null_policy = if skip_nulls {
+            JsonNullPolicy::Skip
+        } else {
+            JsonNullPolicy::Write
+        };
 
         JsonWriter::new(file)
             .with_json_format(JsonFormat::Json)
+            .with_orient(orient.0)
+            .with_null_policy(null_policy)
+            .with_datetime_format(datetime_format)
+            .with_date_format(date_format)
+            .with_time_format(time_format)
             .finish(&mut self.df)
             .map_err(PyPolarsErr::from)?;
         Ok(())
@@ -468,6 +486,7 @@ impl PyDataFrame {
         py_f: PyObject,
         compression: Wrap<Option<IpcCompression>>,
         future: bool,
+        batch_statistics: bool,
     ) -> PyResult<()> {
         if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
             let s: &str = s.as_ref();
@@ -477,6 +496,7 @@ impl PyDataFrame {
                 IpcWriter::new(f)
                     .with_compression(compression.0)
                     .with_pl_flavor(future)
+                    .with_batch_statistics(batch_statistics)
                     .finish(&mut self.df)
                     .map_err(PyPolarsErr::from)
             })?;
@@ -486,6 +506,7 @@ impl PyDataFrame {
             IpcWriter::new(&mut buf)
                 .with_compression(compression.0)
                 .with_pl_flavor(future)
+                .with_batch_statistics(batch_statistics)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)?;
         }
@@ -68,6 +68,9 @@ impl From<&DataType> for PyDataType {
             DataType::Null | DataType::Unknown(_) | DataType::BinaryOffset => {
                 panic!("null or unknown not expected here")
             },
+            DataType::Int128 | DataType::UInt128 => {
+                panic!("128-bit integers are not supported in Python")
+            },
         }
     }
 }
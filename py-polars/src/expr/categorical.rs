@@ -7,4 +7,16 @@ impl PyExpr {
     fn cat_get_categories(&self) -> Self {
         self.inner.clone().cat().get_categories().into()
     }
+
+    fn cat_add_categories(&self, categories: Vec<String>) -> Self {
+        self.inner.clone().cat().add_categories(categories).into()
+    }
+
+    fn cat_remove_unused(&self) -> Self {
+        self.inner.clone().cat().remove_unused().into()
+    }
+
+    fn cat_reorder(&self, categories: Vec<String>) -> Self {
+        self.inner.clone().cat().reorder(categories).into()
+    }
 }
@@ -20,6 +20,14 @@ impl PyExpr {
             .into()
     }
 
+    fn dt_is_business_day(&self, week_mask: [bool; 7], holidays: Vec<i32>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .is_business_day(week_mask, holidays)
+            .into()
+    }
+
     fn dt_to_string(&self, format: &str) -> Self {
         self.inner.clone().dt().to_string(format).into()
     }
@@ -69,8 +77,17 @@ impl PyExpr {
             .into()
     }
 
-    fn dt_truncate(&self, every: Self) -> Self {
-        self.inner.clone().dt().truncate(every.inner).into()
+    fn dt_truncate(
+        &self,
+        every: Self,
+        ambiguous: Wrap<Ambiguous>,
+        non_existent: Wrap<NonExistent>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .truncate_with_dst_strategy(every.inner, ambiguous.0, non_existent.0)
+            .into()
     }
 
     fn dt_month_start(&self) -> Self {
@@ -155,6 +155,21 @@ impl PyExpr {
             .quantile(quantile.inner, interpolation.0)
             .into()
     }
+    fn approx_quantile(&self, quantile: f64, accuracy: f64) -> Self {
+        self.inner
+            .clone()
+            .approx_quantile(quantile, accuracy)
+            .into()
+    }
+    fn tdigest(&self, accuracy: f64) -> Self {
+        self.inner.clone().tdigest(accuracy).into()
+    }
+    fn tdigest_merge(&self) -> Self {
+        self.inner.clone().tdigest_merge().into()
+    }
+    fn tdigest_quantile(&self, quantile: f64) -> Self {
+        self.inner.clone().tdigest_quantile(quantile).into()
+    }
 
     #[pyo3(signature = (breaks, labels, left_closed, include_breaks))]
     #[cfg(feature = "cutqcut")]
@@ -311,6 +326,37 @@ impl PyExpr {
         self.inner.clone().arg_max().into()
     }
 
+    fn min_by(&self, by: Self) -> Self {
+        self.inner.clone().min_by(by.inner).into()
+    }
+
+    fn max_by(&self, by: Self) -> Self {
+        self.inner.clone().max_by(by.inner).into()
+    }
+
+    fn count_if(&self, predicate: Self) -> Self {
+        self.inner.clone().count_if(predicate.inner).into()
+    }
+
+    fn n_unique_if(&self, predicate: Self) -> Self {
+        self.inner.clone().n_unique_if(predicate.inner).into()
+    }
+
+    fn weighted_mean(&self, weights: Self) -> Self {
+        self.inner.clone().weighted_mean(weights.inner).into()
+    }
+
+    fn weighted_var(&self, weights: Self, ddof: u8) -> Self {
+        self.inner.clone().weighted_var(weights.inner, ddof).into()
+    }
+
+    fn weighted_quantile(&self, weights: Self, quantile: f64) -> Self {
+        self.inner
+            .clone()
+            .weighted_quantile(weights.inner, quantile)
+            .into()
+    }
+
     fn arg_min(&self) -> Self {
         self.inner.clone().arg_min().into()
     }
@@ -348,6 +394,7 @@ impl PyExpr {
                     nulls_last,
                     multithreaded,
                     maintain_order,
+                    limit: None,
                 },
             )
             .into()
@@ -577,8 +624,8 @@ impl PyExpr {
         self.inner.clone().sign().into()
     }
 
-    fn is_duplicated(&self) -> Self {
-        self.inner.clone().is_duplicated().into()
+    fn is_duplicated(&self, keep: Wrap<UniqueKeepStrategy>) -> Self {
+        self.inner.clone().is_duplicated_keep(keep.0).into()
     }
 
     #[pyo3(signature = (partition_by, order_by, order_by_descending, order_by_nulls_last, mapping_strategy))]
@@ -625,6 +672,7 @@ impl PyExpr {
             period: Duration::parse(period),
             offset: Duration::parse(offset),
             closed_window: closed.0,
+            ..Default::default()
         };
 
         self.inner.clone().rolling(options).into()
@@ -734,8 +782,8 @@ impl PyExpr {
     fn interpolate(&self, method: Wrap<InterpolationMethod>) -> Self {
         self.inner.clone().interpolate(method.0).into()
     }
-    fn interpolate_by(&self, by: PyExpr) -> Self {
-        self.inner.clone().interpolate_by(by.inner).into()
+    fn interpolate_by(&self, by: PyExpr, method: Wrap<InterpolationMethod>) -> Self {
+        self.inner.clone().interpolate_by(by.inner, method.0).into()
     }
 
     fn lower_bound(&self) -> Self {
@@ -754,6 +802,18 @@ impl PyExpr {
         self.inner.clone().rank(options, seed).into()
     }
 
+    fn percent_rank(&self, descending: bool) -> Self {
+        self.inner.clone().percent_rank(descending).into()
+    }
+
+    fn cume_dist(&self, descending: bool) -> Self {
+        self.inner.clone().cume_dist(descending).into()
+    }
+
+    fn ntile(&self, buckets: IdxSize, descending: bool) -> Self {
+        self.inner.clone().ntile(buckets, descending).into()
+    }
+
     fn diff(&self, n: i64, null_behavior: Wrap<NullBehavior>) -> Self {
         self.inner.clone().diff(n, null_behavior.0).into()
     }
@@ -783,26 +843,40 @@ impl PyExpr {
         self.inner.clone().shuffle(seed).into()
     }
 
-    #[pyo3(signature = (n, with_replacement, shuffle, seed))]
-    fn sample_n(&self, n: Self, with_replacement: bool, shuffle: bool, seed: Option<u64>) -> Self {
-        self.inner
-            .clone()
-            .sample_n(n.inner, with_replacement, shuffle, seed)
-            .into()
+    #[pyo3(signature = (n, with_replacement, shuffle, seed, weights=None))]
+    fn sample_n(
+        &self,
+        n: Self,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+        weights: Option<Self>,
+    ) -> Self {
+        let expr = self.inner.clone();
+        match weights {
+            Some(weights) => expr.sample_n_weighted(n.inner, weights.inner, with_replacement, seed),
+            None => expr.sample_n(n.inner, with_replacement, shuffle, seed),
+        }
+        .into()
     }
 
-    #[pyo3(signature = (frac, with_replacement, shuffle, seed))]
+    #[pyo3(signature = (frac, with_replacement, shuffle, seed, weights=None))]
     fn sample_frac(
         &self,
         frac: Self,
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        weights: Option<Self>,
     ) -> Self {
-        self.inner
-            .clone()
-            .sample_frac(frac.inner, with_replacement, shuffle, seed)
-            .into()
+        let expr = self.inner.clone();
+        match weights {
+            Some(weights) => {
+                expr.sample_frac_weighted(frac.inner, weights.inner, with_replacement, seed)
+            },
+            None => expr.sample_frac(frac.inner, with_replacement, shuffle, seed),
+        }
+        .into()
     }
 
     fn ewm_mean(&self, alpha: f64, adjust: bool, min_periods: usize, ignore_nulls: bool) -> Self {
@@ -822,6 +896,20 @@ impl PyExpr {
             .ewm_mean_by(times.inner, half_life)
             .into()
     }
+    fn ewm_std_by(&self, times: PyExpr, half_life: &str, bias: bool) -> Self {
+        let half_life = Duration::parse(half_life);
+        self.inner
+            .clone()
+            .ewm_std_by(times.inner, half_life, bias)
+            .into()
+    }
+    fn ewm_var_by(&self, times: PyExpr, half_life: &str, bias: bool) -> Self {
+        let half_life = Duration::parse(half_life);
+        self.inner
+            .clone()
+            .ewm_var_by(times.inner, half_life, bias)
+            .into()
+    }
 
     fn ewm_std(
         &self,
@@ -921,18 +1009,25 @@ impl PyExpr {
     }
 
     #[cfg(feature = "hist")]
-    #[pyo3(signature = (bins, bin_count, include_category, include_breakpoint))]
+    #[pyo3(signature = (bins, bin_count, strategy, include_category, include_breakpoint))]
     fn hist(
         &self,
         bins: Option<PyExpr>,
         bin_count: Option<usize>,
+        strategy: Wrap<HistBinStrategy>,
         include_category: bool,
         include_breakpoint: bool,
     ) -> Self {
         let bins = bins.map(|e| e.inner);
         self.inner
             .clone()
-            .hist(bins, bin_count, include_category, include_breakpoint)
+            .hist(
+                bins,
+                bin_count,
+                strategy.0,
+                include_category,
+                include_breakpoint,
+            )
             .into()
     }
 }
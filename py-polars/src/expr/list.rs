@@ -242,6 +242,15 @@ impl PyExpr {
         }
     }
 
+    #[cfg(feature = "list_elementwise")]
+    fn list_elementwise(&self, other: PyExpr, operation: Wrap<ListBinaryOp>) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .elementwise(other.inner, operation.0)
+            .into()
+    }
+
     #[cfg(feature = "list_sets")]
     fn list_set_operation(&self, other: PyExpr, operation: Wrap<SetOperation>) -> Self {
         let e = self.inner.clone().list();
@@ -1,4 +1,5 @@
 use polars::prelude::*;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::conversion::Wrap;
@@ -153,6 +154,49 @@ impl PyExpr {
         self.inner.clone().str().reverse().into()
     }
 
+    fn str_levenshtein(&self, other: Self, normalized: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .levenshtein(other.inner, normalized)
+            .into()
+    }
+
+    fn str_hamming(&self, other: Self, normalized: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .hamming(other.inner, normalized)
+            .into()
+    }
+
+    fn str_jaro_winkler(&self, other: Self) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
+    #[pyo3(signature = (method, pattern))]
+    fn str_tokenize(&self, method: &str, pattern: Option<String>) -> PyResult<Self> {
+        let tokenizer = match (method, pattern) {
+            ("whitespace", None) => Tokenizer::Whitespace,
+            ("words", None) => Tokenizer::Words,
+            ("regex", Some(pattern)) => Tokenizer::Regex(pattern),
+            (method, pattern) => {
+                return Err(PyValueError::new_err(format!(
+                    "invalid tokenize `method` {method:?} with `pattern` {pattern:?}"
+                )))
+            },
+        };
+        Ok(self.inner.clone().str().tokenize(tokenizer).into())
+    }
+
+    fn str_ngrams(&self, n: usize) -> Self {
+        self.inner.clone().str().ngrams(n).into()
+    }
+
+    fn str_strip_accents(&self) -> Self {
+        self.inner.clone().str().strip_accents().into()
+    }
+
     fn str_pad_start(&self, length: usize, fill_char: char) -> Self {
         self.inner.clone().str().pad_start(length, fill_char).into()
     }
@@ -296,6 +340,14 @@ impl PyExpr {
         self.inner.clone().str().to_decimal(infer_len).into()
     }
 
+    fn str_to_duration(&self, time_unit: Wrap<TimeUnit>, strict: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .to_duration(time_unit.0, strict)
+            .into()
+    }
+
     #[cfg(feature = "find_many")]
     fn str_contains_any(&self, patterns: PyExpr, ascii_case_insensitive: bool) -> Self {
         self.inner
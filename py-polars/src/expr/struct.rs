@@ -29,4 +29,13 @@ impl PyExpr {
         let fields = fields.to_exprs();
         self.inner.clone().struct_().with_fields(fields).into()
     }
+
+    fn struct_map_fields(&self, field: PyExpr) -> Self {
+        // The function is applied to `pl.field("*")` on the Python side.
+        self.inner
+            .clone()
+            .struct_()
+            .map_fields(|_| field.inner)
+            .into()
+    }
 }
@@ -59,6 +59,43 @@ pub fn rolling_cov(
     .into()
 }
 
+#[pyfunction]
+pub fn rolling_corr_by(
+    x: PyExpr,
+    y: PyExpr,
+    by: PyExpr,
+    window_size: &str,
+    min_periods: usize,
+    closed: Wrap<ClosedWindow>,
+) -> PyExpr {
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::parse(window_size),
+        min_periods,
+        closed_window: closed.0,
+        fn_params: None,
+    };
+    dsl::rolling_corr_by(x.inner, y.inner, by.inner, options).into()
+}
+
+#[pyfunction]
+pub fn rolling_cov_by(
+    x: PyExpr,
+    y: PyExpr,
+    by: PyExpr,
+    window_size: &str,
+    min_periods: usize,
+    closed: Wrap<ClosedWindow>,
+    ddof: u8,
+) -> PyExpr {
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::parse(window_size),
+        min_periods,
+        closed_window: closed.0,
+        fn_params: Some(Arc::new(RollingVarParams { ddof }) as Arc<dyn std::any::Any + Send + Sync>),
+    };
+    dsl::rolling_cov_by(x.inner, y.inner, by.inner, options).into()
+}
+
 #[pyfunction]
 pub fn arg_sort_by(
     by: Vec<PyExpr>,
@@ -66,6 +103,7 @@ pub fn arg_sort_by(
     nulls_last: Vec<bool>,
     multithreaded: bool,
     maintain_order: bool,
+    limit: Option<IdxSize>,
 ) -> PyExpr {
     let by = by.into_iter().map(|e| e.inner).collect::<Vec<Expr>>();
     dsl::arg_sort_by(
@@ -75,6 +113,7 @@ pub fn arg_sort_by(
             nulls_last,
             multithreaded,
             maintain_order,
+            limit,
         },
     )
     .into()
@@ -113,22 +152,23 @@ pub fn col(name: &str) -> PyExpr {
 }
 
 #[pyfunction]
-pub fn collect_all(lfs: Vec<PyLazyFrame>, py: Python) -> PyResult<Vec<PyDataFrame>> {
-    use polars_core::utils::rayon::prelude::*;
-
+#[pyo3(signature = (lfs, share_scans=false, max_concurrency=None))]
+pub fn collect_all(
+    lfs: Vec<PyLazyFrame>,
+    share_scans: bool,
+    max_concurrency: Option<usize>,
+    py: Python,
+) -> PyResult<Vec<PyDataFrame>> {
+    let options = CollectAllOptions {
+        share_scans,
+        max_concurrency,
+    };
     let out = py.allow_threads(|| {
-        polars_core::POOL.install(|| {
-            lfs.par_iter()
-                .map(|lf| {
-                    let df = lf.ldf.clone().collect()?;
-                    Ok(PyDataFrame::new(df))
-                })
-                .collect::<polars_core::error::PolarsResult<Vec<_>>>()
-                .map_err(PyPolarsErr::from)
-        })
-    });
+        collect_all_with_options(lfs.into_iter().map(|lf| lf.ldf), options)
+            .map_err(PyPolarsErr::from)
+    })?;
 
-    Ok(out?)
+    Ok(out.into_iter().map(PyDataFrame::new).collect())
 }
 
 #[pyfunction]
@@ -216,6 +256,14 @@ pub fn cov(a: PyExpr, b: PyExpr, ddof: u8) -> PyExpr {
     dsl::cov(a.inner, b.inner, ddof).into()
 }
 
+#[pyfunction]
+#[pyo3(signature = (exprs, predicate=None))]
+pub fn n_unique_rows(exprs: Vec<PyExpr>, predicate: Option<PyExpr>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::n_unique_rows(exprs, predicate.map(|p| p.inner)).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
 #[pyfunction]
 #[cfg(feature = "trigonometry")]
 pub fn arctan2(y: PyExpr, x: PyExpr) -> PyExpr {
@@ -232,7 +232,7 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (path, paths, n_rows, cache, parallel, rechunk, row_index,
-        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, try_parse_hive_dates, retries, glob)
+        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, try_parse_hive_dates, retries, glob, deduplicate_strings)
     )]
     fn new_from_parquet(
         path: Option<PathBuf>,
@@ -250,6 +250,7 @@ impl PyLazyFrame {
         try_parse_hive_dates: bool,
         retries: usize,
         glob: bool,
+        deduplicate_strings: bool,
     ) -> PyResult<Self> {
         let parallel = parallel.0;
         let hive_schema = hive_schema.map(|s| Arc::new(s.0));
@@ -297,6 +298,9 @@ impl PyLazyFrame {
             use_statistics,
             hive_options,
             glob,
+            deduplicate_strings,
+            schema_evolution: Default::default(),
+            use_statistics_sidecar: false,
         };
 
         let lf = if path.is_some() {
@@ -363,8 +367,10 @@ impl PyLazyFrame {
             rechunk,
             row_index,
             memory_map,
+            release_resources: false,
             #[cfg(feature = "cloud")]
             cloud_options,
+            schema_evolution: Default::default(),
         };
 
         let lf = if let Some(path) = &path {
@@ -469,6 +475,11 @@ impl PyLazyFrame {
         ldf.into()
     }
 
+    fn with_low_latency(&self, toggle: bool) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_low_latency(toggle).into()
+    }
+
     fn sort(
         &self,
         by_column: &str,
@@ -485,6 +496,7 @@ impl PyLazyFrame {
                 nulls_last: vec![nulls_last],
                 multithreaded,
                 maintain_order,
+                limit: None,
             },
         )
         .into()
@@ -507,6 +519,7 @@ impl PyLazyFrame {
                 nulls_last,
                 maintain_order,
                 multithreaded,
+                limit: None,
             },
         )
         .into()
@@ -549,6 +562,24 @@ impl PyLazyFrame {
         Ok((df.into(), time_df.into()))
     }
 
+    #[pyo3(signature = (memory_budget))]
+    fn estimate_resources(
+        &self,
+        py: Python,
+        memory_budget: Option<usize>,
+    ) -> PyResult<PyDataFrame> {
+        let estimate = py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            match memory_budget {
+                Some(memory_budget) => ldf.estimate_resources_with_budget(Some(memory_budget)),
+                None => ldf.estimate_resources(),
+            }
+            .map_err(PyPolarsErr::from)
+        })?;
+        let df = estimate.to_df().map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     fn collect(&self, py: Python, lamdba_post_opt: Option<PyObject>) -> PyResult<PyDataFrame> {
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -703,6 +734,7 @@ impl PyLazyFrame {
             null: null_value,
             line_terminator,
             quote_style,
+            nested: Default::default(),
         };
 
         let options = CsvWriterOptions {
@@ -737,6 +769,13 @@ impl PyLazyFrame {
         Ok(())
     }
 
+    #[cfg(feature = "streaming")]
+    fn head_streaming(&self, py: Python, n: IdxSize) -> PyResult<PyDataFrame> {
+        let ldf = self.ldf.clone();
+        let df = py.allow_threads(|| ldf.head_streaming(n).map_err(PyPolarsErr::from))?;
+        Ok(df.into())
+    }
+
     fn fetch(&self, py: Python, n_rows: usize) -> PyResult<PyDataFrame> {
         let ldf = self.ldf.clone();
         let df = py.allow_threads(|| ldf.fetch(n_rows).map_err(PyPolarsErr::from))?;
@@ -779,6 +818,8 @@ impl PyLazyFrame {
         offset: &str,
         closed: Wrap<ClosedWindow>,
         by: Vec<PyExpr>,
+        label: Wrap<Label>,
+        include_boundaries: bool,
     ) -> PyLazyGroupBy {
         let closed_window = closed.0;
         let ldf = self.ldf.clone();
@@ -794,6 +835,8 @@ impl PyLazyFrame {
                 period: Duration::parse(period),
                 offset: Duration::parse(offset),
                 closed_window,
+                label: label.0,
+                include_boundaries,
             },
         );
 
@@ -811,6 +854,8 @@ impl PyLazyFrame {
         closed: Wrap<ClosedWindow>,
         group_by: Vec<PyExpr>,
         start_by: Wrap<StartBy>,
+        ambiguous: Wrap<Ambiguous>,
+        non_existent: Wrap<NonExistent>,
     ) -> PyLazyGroupBy {
         let closed_window = closed.0;
         let group_by = group_by
@@ -829,6 +874,8 @@ impl PyLazyFrame {
                 include_boundaries,
                 closed_window,
                 start_by: start_by.0,
+                ambiguous: ambiguous.0,
+                non_existent: non_existent.0,
                 ..Default::default()
             },
         );
@@ -842,7 +889,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(feature = "asof_join")]
-    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce))]
+    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce, allow_exact_matches=true, tie_break=Wrap(AsofTieBreak::Forward), all_matches=false, tolerance_column=None))]
     fn join_asof(
         &self,
         other: Self,
@@ -857,6 +904,10 @@ impl PyLazyFrame {
         tolerance: Option<Wrap<AnyValue<'_>>>,
         tolerance_str: Option<String>,
         coalesce: Option<bool>,
+        allow_exact_matches: bool,
+        tie_break: Wrap<AsofTieBreak>,
+        all_matches: bool,
+        tolerance_column: Option<String>,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -881,6 +932,10 @@ impl PyLazyFrame {
                 right_by: right_by.map(strings_to_smartstrings),
                 tolerance: tolerance.map(|t| t.0.into_static().unwrap()),
                 tolerance_str: tolerance_str.map(|s| s.into()),
+                allow_exact_matches,
+                tie_break: tie_break.0,
+                all_matches,
+                tolerance_column: tolerance_column.map(|s| s.into()),
             }))
             .suffix(suffix)
             .finish()
@@ -952,6 +1007,41 @@ impl PyLazyFrame {
         ldf.rename(existing, new).into()
     }
 
+    fn rename_normalized(
+        &self,
+        case: Wrap<NameCase>,
+        ascii_only: bool,
+        dedupe: Wrap<NameDedupe>,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let options = NormalizeNamesOptions {
+            case: case.0,
+            ascii_only,
+            dedupe: dedupe.0,
+        };
+        let ldf = ldf.rename_normalized(options).map_err(PyPolarsErr::from)?;
+        Ok(ldf.into())
+    }
+
+    fn conform_to(
+        &self,
+        schema: Wrap<Schema>,
+        missing: Wrap<MissingColumns>,
+        extra: Wrap<ExtraColumns>,
+        cast: Wrap<ConformCast>,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let options = ConformOptions {
+            missing: missing.0,
+            extra: extra.0,
+            cast: cast.0,
+        };
+        let ldf = ldf
+            .conform_to(&schema.0, options)
+            .map_err(PyPolarsErr::from)?;
+        Ok(ldf.into())
+    }
+
     fn reverse(&self) -> Self {
         let ldf = self.ldf.clone();
         ldf.reverse().into()
@@ -1019,10 +1109,11 @@ impl PyLazyFrame {
         out.into()
     }
 
-    fn explode(&self, column: Vec<PyExpr>) -> Self {
+    fn explode(&self, column: Vec<PyExpr>, pad_with_nulls: bool) -> Self {
         let ldf = self.ldf.clone();
         let column = column.to_exprs();
-        ldf.explode(column).into()
+        ldf.explode_with_options(column, ExplodeOptions { pad_with_nulls })
+            .into()
     }
 
     fn null_count(&self) -> Self {
@@ -1153,8 +1244,17 @@ impl PyLazyFrame {
         Ok(schema_dict.to_object(py))
     }
 
-    fn unnest(&self, columns: Vec<String>) -> Self {
-        self.ldf.clone().unnest(columns).into()
+    #[pyo3(signature = (columns, max_depth, separator))]
+    fn unnest(
+        &self,
+        columns: Vec<String>,
+        max_depth: Option<usize>,
+        separator: Option<&str>,
+    ) -> Self {
+        self.ldf
+            .clone()
+            .unnest_recursive(columns, max_depth, separator)
+            .into()
     }
 
     fn count(&self) -> Self {
@@ -1,4 +1,5 @@
 use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -85,4 +86,26 @@ impl PyLazyFrame {
             .map_err(|err| PyValueError::new_err(format!("{err:?}")))?;
         Ok(LazyFrame::from(lp).into())
     }
+
+    fn save_repro(
+        &self,
+        py: Python,
+        path: PathBuf,
+        sample_rows: IdxSize,
+        full_data_threshold: usize,
+    ) -> PyResult<()> {
+        let options = ReproOptions {
+            full_data_threshold,
+            sample_rows,
+        };
+        py.allow_threads(|| self.ldf.save_repro_with_options(path, options))
+            .map_err(PyPolarsErr::from)?;
+        Ok(())
+    }
+
+    #[staticmethod]
+    fn load_repro(path: PathBuf) -> PyResult<Self> {
+        let ldf = LazyFrame::load_repro(path).map_err(PyPolarsErr::from)?;
+        Ok(ldf.into())
+    }
 }
@@ -1,7 +1,7 @@
 use polars::datatypes::TimeUnit;
 use polars_core::prelude::{NonExistent, QuantileInterpolOptions};
 use polars_core::series::IsSorted;
-use polars_ops::prelude::ClosedInterval;
+use polars_ops::prelude::{ClosedInterval, Tokenizer};
 use polars_plan::dsl::function_expr::rolling::RollingFunction;
 use polars_plan::dsl::function_expr::rolling_by::RollingFunctionBy;
 use polars_plan::dsl::function_expr::trigonometry::TrigonometricFunction;
@@ -151,11 +151,18 @@ pub enum PyStringFunction {
     Strptime,
     Split,
     ToDecimal,
+    ToDuration,
     Titlecase,
     Uppercase,
     ZFill,
     ContainsMany,
     ReplaceMany,
+    Levenshtein,
+    Hamming,
+    JaroWinkler,
+    Tokenize,
+    NGrams,
+    StripAccents,
 }
 
 #[pymethods]
@@ -240,6 +247,8 @@ pub enum PyTemporalFunction {
     ReplaceTimeZone,
     Combine,
     DatetimeFunction,
+    DurationMul,
+    DurationDiv,
 }
 
 #[pymethods]
@@ -820,6 +829,29 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::Reverse => {
                         (PyStringFunction::Reverse.into_py(py),).to_object(py)
                     },
+                    StringFunction::Levenshtein { normalized } => {
+                        (PyStringFunction::Levenshtein.into_py(py), normalized).to_object(py)
+                    },
+                    StringFunction::Hamming { normalized } => {
+                        (PyStringFunction::Hamming.into_py(py), normalized).to_object(py)
+                    },
+                    StringFunction::JaroWinkler => {
+                        (PyStringFunction::JaroWinkler.into_py(py),).to_object(py)
+                    },
+                    StringFunction::Tokenize(tokenizer) => {
+                        let (method, pattern) = match tokenizer {
+                            Tokenizer::Whitespace => ("whitespace", None),
+                            Tokenizer::Words => ("words", None),
+                            Tokenizer::Regex(pattern) => ("regex", Some(pattern)),
+                        };
+                        (PyStringFunction::Tokenize.into_py(py), method, pattern).to_object(py)
+                    },
+                    StringFunction::NGrams(n) => {
+                        (PyStringFunction::NGrams.into_py(py), n).to_object(py)
+                    },
+                    StringFunction::StripAccents => {
+                        (PyStringFunction::StripAccents.into_py(py),).to_object(py)
+                    },
                     StringFunction::PadStart { length, fill_char } => {
                         (PyStringFunction::PadStart.into_py(py), length, fill_char).to_object(py)
                     },
@@ -874,6 +906,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::ToDecimal(_) => {
                         (PyStringFunction::ToDecimal.into_py(py),).to_object(py)
                     },
+                    StringFunction::ToDuration { time_unit, strict } => (
+                        PyStringFunction::ToDuration.into_py(py),
+                        Wrap(*time_unit).to_object(py),
+                        strict,
+                    )
+                        .to_object(py),
                     StringFunction::Titlecase => {
                         (PyStringFunction::Titlecase.into_py(py),).to_object(py)
                     },
@@ -955,7 +993,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     TemporalFunction::TimeStamp(time_unit) => {
                         (PyTemporalFunction::TimeStamp, Wrap(*time_unit)).into_py(py)
                     },
-                    TemporalFunction::Truncate => (PyTemporalFunction::Truncate).into_py(py),
+                    TemporalFunction::Truncate(_, _) => (PyTemporalFunction::Truncate).into_py(py),
                     TemporalFunction::OffsetBy => (PyTemporalFunction::OffsetBy,).into_py(py),
                     TemporalFunction::MonthStart => (PyTemporalFunction::MonthStart,).into_py(py),
                     TemporalFunction::MonthEnd => (PyTemporalFunction::MonthEnd,).into_py(py),
@@ -989,6 +1027,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                             .map_or_else(|| py.None(), |s| s.to_object(py)),
                     )
                         .into_py(py),
+                    TemporalFunction::DurationMul { time_unit, strict } => (
+                        PyTemporalFunction::DurationMul,
+                        time_unit.map(Wrap),
+                        *strict,
+                    )
+                        .into_py(py),
+                    TemporalFunction::DurationDiv { time_unit, strict } => (
+                        PyTemporalFunction::DurationDiv,
+                        time_unit.map(Wrap),
+                        *strict,
+                    )
+                        .into_py(py),
                 },
                 FunctionExpr::Boolean(boolfun) => match boolfun {
                     BooleanFunction::Any { ignore_nulls } => {
@@ -1108,6 +1158,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunctionBy::StdBy(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std by"))
                     },
+                    RollingFunctionBy::CovBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling cov by"))
+                    },
+                    RollingFunctionBy::CorrBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling corr by"))
+                    },
                 },
                 FunctionExpr::ShiftAndFill => {
                     return Err(PyNotImplementedError::new_err("shift and fill"))
@@ -1125,10 +1181,26 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 },
                 FunctionExpr::RepeatBy => ("repeat_by",).to_object(py),
                 FunctionExpr::ArgUnique => ("argunique",).to_object(py),
+                FunctionExpr::MinMaxBy { max } => ("min_max_by", max).to_object(py),
+                FunctionExpr::FirstLastN { n, last } => ("first_last_n", n, last).to_object(py),
+                FunctionExpr::ModeK { k } => ("mode_k", k).to_object(py),
+                FunctionExpr::NUniqueRows { filtered } => ("n_unique_rows", filtered).to_object(py),
+                FunctionExpr::CountIf => ("count_if",).to_object(py),
+                FunctionExpr::WeightedAgg(_) => {
+                    return Err(PyNotImplementedError::new_err("weighted aggregation"))
+                },
                 FunctionExpr::Rank {
                     options: _,
                     seed: _,
                 } => return Err(PyNotImplementedError::new_err("rank")),
+                FunctionExpr::PercentRank { descending } => {
+                    ("percent_rank", descending).to_object(py)
+                },
+                FunctionExpr::CumeDist { descending } => ("cume_dist", descending).to_object(py),
+                FunctionExpr::Ntile {
+                    buckets,
+                    descending,
+                } => ("ntile", buckets, descending).to_object(py),
                 FunctionExpr::Clip {
                     has_min: _,
                     has_max: _,
@@ -1151,6 +1223,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::ApproxNUnique => {
                     return Err(PyNotImplementedError::new_err("approx nunique"))
                 },
+                FunctionExpr::ApproxQuantile { .. }
+                | FunctionExpr::TDigest { .. }
+                | FunctionExpr::TDigestMerge
+                | FunctionExpr::TDigestQuantile { .. } => {
+                    return Err(PyNotImplementedError::new_err("approx quantile"))
+                },
                 FunctionExpr::Coalesce => ("coalesce",).to_object(py),
                 FunctionExpr::ShrinkType => {
                     return Err(PyNotImplementedError::new_err("shrink type"))
@@ -1162,7 +1240,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Interpolate(_) => {
                     return Err(PyNotImplementedError::new_err("interpolate"))
                 },
-                FunctionExpr::InterpolateBy => {
+                FunctionExpr::InterpolateBy(_) => {
                     return Err(PyNotImplementedError::new_err("interpolate_by"))
                 },
                 FunctionExpr::Entropy {
@@ -1261,6 +1339,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::EwmMeanBy { half_life: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm_mean_by"))
                 },
+                FunctionExpr::EwmStdBy { .. } => {
+                    return Err(PyNotImplementedError::new_err("ewm_std_by"))
+                },
+                FunctionExpr::EwmVarBy { .. } => {
+                    return Err(PyNotImplementedError::new_err("ewm_var_by"))
+                },
             },
             options: py.None(),
         }
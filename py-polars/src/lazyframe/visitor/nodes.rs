@@ -521,14 +521,25 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     streamable: _,
                     fmt_str: _,
                 } => return Err(PyNotImplementedError::new_err("opaque rust mapfunction")),
+                FunctionNode::OpaqueStreaming {
+                    function: _,
+                    schema: _,
+                    fmt_str: _,
+                } => {
+                    return Err(PyNotImplementedError::new_err(
+                        "opaque streaming mapfunction",
+                    ))
+                },
                 FunctionNode::Pipeline {
                     function: _,
                     schema: _,
                     original: _,
                 } => return Err(PyNotImplementedError::new_err("pipeline mapfunction")),
-                FunctionNode::Unnest { columns } => (
+                FunctionNode::Unnest { columns, options } => (
                     "unnest",
                     columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+                    options.max_depth,
+                    options.separator.as_ref().map(|s| s.to_string()),
                 )
                     .to_object(py),
                 FunctionNode::Rechunk => ("rechunk",).to_object(py),
@@ -547,9 +558,14 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     *swapping,
                 )
                     .to_object(py),
-                FunctionNode::Explode { columns, schema: _ } => (
+                FunctionNode::Explode {
+                    columns,
+                    options,
+                    schema: _,
+                } => (
                     "explode",
                     columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+                    options.pad_with_nulls,
                 )
                     .to_object(py),
                 FunctionNode::Unpivot { args, schema: _ } => (
@@ -574,6 +590,9 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     scan_type: _,
                     alias: _,
                 } => return Err(PyNotImplementedError::new_err("function count")),
+                FunctionNode::AlignByName { schema: _ } => {
+                    return Err(PyNotImplementedError::new_err("align by name"))
+                },
             },
         }
         .into_py(py),
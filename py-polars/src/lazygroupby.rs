@@ -34,6 +34,11 @@ impl PyLazyGroupBy {
         lgb.tail(Some(n)).into()
     }
 
+    fn agg_groups_struct(&mut self, name: &str) -> PyLazyFrame {
+        let lgb = self.lgb.clone().unwrap();
+        lgb.agg_groups_struct(name).into()
+    }
+
     fn map_groups(
         &mut self,
         lambda: PyObject,
@@ -203,6 +203,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::len)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cov)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::n_unique_rows))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cum_fold))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cum_reduce))
@@ -235,6 +237,10 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::rolling_cov))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rolling_corr_by))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rolling_cov_by))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::reduce)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::repeat)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::spearman_rank_corr))
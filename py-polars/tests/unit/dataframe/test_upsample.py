@@ -6,7 +6,7 @@ from typing import TYPE_CHECKING
 import pytest
 
 import polars as pl
-from polars.exceptions import InvalidOperationError
+from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -216,3 +216,18 @@ def test_upsample_index_invalid(
             every="1h",
             maintain_order=maintain_order,
         )
+
+
+def test_upsample_non_existent() -> None:
+    # Midnight doesn't exist in Havana on 2023-03-12.
+    df = pl.DataFrame(
+        {
+            "time": [datetime(2023, 3, 11), datetime(2023, 3, 13)],
+            "values": [1, 2],
+        }
+    ).with_columns(pl.col("time").dt.replace_time_zone("America/Havana").set_sorted())
+    with pytest.raises(ComputeError, match="non-existent"):
+        df.upsample(time_column="time", every="1d")
+
+    result = df.upsample(time_column="time", every="1d", non_existent="null")
+    assert_frame_equal(result, df)
@@ -256,7 +256,7 @@ def test_decimal_arithmetic() -> None:
         pl.Decimal(precision=None, scale=2),
         pl.Decimal(precision=None, scale=6),
         pl.Decimal(precision=None, scale=2),
-        pl.Decimal(precision=None, scale=14),
+        pl.Decimal(precision=34, scale=14),
     ]
 
     assert out.to_dict(as_series=False) == {
@@ -333,6 +333,77 @@ def test_decimal_aggregations() -> None:
     }
 
 
+def test_decimal_arithmetic_precision() -> None:
+    lf = pl.LazyFrame(
+        {"a": [D("1.25"), D("-3.50")], "b": [D("0.50"), D("2.00")]},
+        schema={"a": pl.Decimal(5, 2), "b": pl.Decimal(5, 2)},
+    ).select(
+        add=pl.col("a") + pl.col("b"),
+        mul=pl.col("a") * pl.col("b"),
+        div=pl.col("a") / pl.col("b"),
+    )
+    expected = pl.DataFrame(
+        {
+            "add": [D("1.75"), D("-1.50")],
+            "mul": [D("0.6250"), D("-7.0000")],
+            "div": [D("2.500000"), D("-1.750000")],
+        },
+        schema={
+            "add": pl.Decimal(6, 2),
+            "mul": pl.Decimal(10, 4),
+            "div": pl.Decimal(11, 6),
+        },
+    )
+    assert lf.collect_schema() == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+
+
+def test_decimal_mean_std() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2],
+            "a": [D("0.1"), D("10.1"), D("100.01"), D("9000.12")],
+        },
+        schema={"g": pl.Int64, "a": pl.Decimal(10, 2)},
+    )
+
+    # The mean stays a decimal, rounded half away from zero at the input scale.
+    out = df.group_by("g", maintain_order=True).agg(mean=pl.col("a").mean())
+    assert out.schema["mean"] == pl.Decimal(10, 2)
+    assert out["mean"].to_list() == [D("5.10"), D("4550.07")]
+    assert df.select(pl.col("a").mean()).item() == D("2277.58")
+
+    float_df = df.with_columns(pl.col("a").cast(pl.Float64))
+    for agg in (pl.col("a").std(), pl.col("a").var(ddof=0)):
+        assert_frame_equal(df.select(agg), float_df.select(agg))
+        assert_frame_equal(
+            df.group_by("g", maintain_order=True).agg(agg),
+            float_df.group_by("g", maintain_order=True).agg(agg),
+        )
+
+
+def test_decimal_rolling_sum() -> None:
+    s = pl.Series([D("1.10"), D("2.20"), None, D("3.30")], dtype=pl.Decimal(10, 2))
+    expected = pl.Series(
+        [D("1.10"), D("3.30"), D("2.20"), D("3.30")], dtype=pl.Decimal(10, 2)
+    )
+    assert_series_equal(s.rolling_sum(2, min_periods=1), expected)
+
+
+def test_decimal_compare_int_literal() -> None:
+    lf = pl.LazyFrame({"a": [D("1.50"), D("100.00")]}, schema={"a": pl.Decimal(5, 2)})
+    out = lf.select(
+        ge=pl.col("a") >= 100,
+        lt_big=pl.col("a") < 10**20,
+        eq_float=pl.col("a") == 1.5,
+    ).collect()
+    assert out.to_dict(as_series=False) == {
+        "ge": [False, True],
+        "lt_big": [True, True],
+        "eq_float": [True, False],
+    }
+
+
 def test_decimal_df_vertical_sum() -> None:
     df = pl.DataFrame({"a": [D("1.1"), D("2.2")]})
     expected = pl.DataFrame({"a": [D("3.3")]})
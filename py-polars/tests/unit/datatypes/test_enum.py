@@ -536,3 +536,49 @@ def test_integer_cast_to_enum_15738(dt: pl.DataType) -> None:
     assert s.to_list() == ["a", "b", "c"]
     expected_s = pl.Series(["a", "b", "c"], dtype=pl.Enum(["a", "b", "c"]))
     assert_series_equal(s, expected_s)
+
+
+def test_enum_add_categories() -> None:
+    s = pl.Series(["a", None, "b"], dtype=pl.Enum(["a", "b"]))
+    out = s.cat.add_categories(["c", "a"])
+    assert out.dtype == pl.Enum(["a", "b", "c"])
+    assert out.to_list() == ["a", None, "b"]
+
+    lf = s.to_frame("x").lazy().select(pl.col("x").cat.add_categories(["c"]))
+    assert lf.collect_schema()["x"] == pl.Enum(["a", "b", "c"])
+
+
+def test_enum_remove_unused() -> None:
+    s = pl.Series(["c", None, "a", "c"], dtype=pl.Enum(["a", "b", "c", "d"]))
+    out = s.cat.remove_unused()
+    assert out.dtype == pl.Enum(["a", "c"])
+    assert out.to_list() == ["c", None, "a", "c"]
+
+
+def test_enum_reorder() -> None:
+    s = pl.Series(["a", "c", None, "b"], dtype=pl.Enum(["a", "b", "c"]))
+    out = s.cat.reorder(["c", "b", "a"])
+    assert out.dtype == pl.Enum(["c", "b", "a"])
+    assert out.to_list() == ["a", "c", None, "b"]
+    assert out.sort().to_list() == [None, "c", "b", "a"]
+
+    with pytest.raises(InvalidOperationError, match="expects all 3 categories"):
+        s.cat.reorder(["c", "b"])
+    with pytest.raises(InvalidOperationError, match="unknown category 'd'"):
+        s.cat.reorder(["c", "b", "d"])
+    with pytest.raises(InvalidOperationError, match="duplicate category 'c'"):
+        s.cat.reorder(["c", "b", "c"])
+
+
+def test_enum_cast_to_extended_enum() -> None:
+    s = pl.Series(["a", "b", None], dtype=pl.Enum(["a", "b"]))
+    out = s.cast(pl.Enum(["a", "b", "c"]))
+    assert out.to_list() == ["a", "b", None]
+    assert out.to_physical().to_list() == [0, 1, None]
+
+    out = s.cast(pl.Enum(["c", "b", "a"]))
+    assert out.to_list() == ["a", "b", None]
+    assert out.to_physical().to_list() == [2, 1, None]
+
+    with pytest.raises(InvalidOperationError, match="present in the categories"):
+        s.cast(pl.Enum(["a", "c"]))
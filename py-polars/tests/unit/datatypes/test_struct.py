@@ -154,6 +154,45 @@ def test_struct_unnest_multiple() -> None:
     assert_frame_equal(result, df)
 
 
+def test_struct_unnest_recursive() -> None:
+    df = pl.DataFrame(
+        {
+            "id": [1, 2],
+            "user": [
+                {"name": "a", "address": {"city": "x", "zip": 1}},
+                {"name": "b", "address": {"city": "y", "zip": 2}},
+            ],
+        }
+    )
+
+    result = df.unnest_recursive("user")
+    expected = pl.DataFrame(
+        {
+            "id": [1, 2],
+            "user.name": ["a", "b"],
+            "user.address.city": ["x", "y"],
+            "user.address.zip": [1, 2],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.unnest_recursive("user", max_depth=1, separator="_")
+    assert result.columns == ["id", "user_name", "user_address"]
+    assert result.schema["user_address"] == pl.Struct(
+        {"city": pl.String, "zip": pl.Int64}
+    )
+
+    result = df.unnest_recursive("user", separator=None)
+    assert result.columns == ["id", "name", "city", "zip"]
+
+    lf = df.lazy().unnest_recursive("user").select("user.address.zip")
+    assert lf.collect_schema() == pl.Schema({"user.address.zip": pl.Int64})
+    assert_frame_equal(lf.collect(), expected.select("user.address.zip"))
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="max_depth"):
+        df.unnest_recursive("user", max_depth=0)
+
+
 def test_struct_function_expansion() -> None:
     df = pl.DataFrame(
         {"a": [1, 2, 3, 4], "b": ["one", "two", "three", "four"], "c": [9, 8, 7, 6]}
@@ -932,3 +971,39 @@ def test_struct_split_16536() -> None:
 
     df = pl.concat([df, df, df, df], rechunk=False)
     assert df.filter(pl.col("int") == 1).shape == (4, 3)
+
+
+def test_struct_map_fields() -> None:
+    df = pl.DataFrame(
+        {
+            "s": [{"a": 1.234, "b": 2.5, "c": "x"}, {"a": 3.0, "b": None, "c": "y"}],
+            "n": [10, 20],
+        }
+    )
+
+    out = df.select(
+        pl.col("s").struct.map_fields(lambda f: f.name.prefix("p_")),
+    )
+    assert out.schema["s"] == pl.Struct(
+        {"p_a": pl.Float64, "p_b": pl.Float64, "p_c": pl.String}
+    )
+
+    out = df.select(
+        pl.struct(pl.col("s").struct.field("a", "b"))
+        .struct.map_fields(lambda f: f.round(1) * pl.col("n"))
+        .alias("t"),
+    )
+    assert out["t"].to_list() == [{"a": 12.0, "b": 25.0}, {"a": 60.0, "b": None}]
+
+
+def test_struct_with_fields_wildcard() -> None:
+    df = pl.DataFrame({"s": [{"a": 1, "b": 2}, {"a": 3, "b": 4}]})
+    out = df.select(
+        pl.col("s").struct.with_fields(
+            (pl.field("*") * 10).name.suffix("_10"), c=pl.field("a") + 1
+        )
+    )
+    assert out["s"].to_list() == [
+        {"a": 1, "b": 2, "a_10": 10, "b_10": 20, "c": 2},
+        {"a": 3, "b": 4, "a_10": 30, "b_10": 40, "c": 4},
+    ]
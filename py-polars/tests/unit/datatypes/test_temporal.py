@@ -2042,6 +2042,30 @@ def test_truncate_non_existent_14957() -> None:
         ).dt.truncate("46m")
 
 
+def test_truncate_non_existent_strategy() -> None:
+    # Midnight doesn't exist in Havana on the day DST starts.
+    ser = pl.Series([datetime(2023, 3, 12, 12)]).dt.replace_time_zone("America/Havana")
+    with pytest.raises(ComputeError, match="non-existent"):
+        ser.dt.truncate("1d")
+    result = ser.dt.truncate("1d", non_existent="null")
+    assert result.dtype == ser.dtype
+    assert result.to_list() == [None]
+
+
+def test_truncate_ambiguous_strategy() -> None:
+    # Midnight is ambiguous in Havana on the day DST ends, but noon isn't, so
+    # the original timestamp can't tell us which side of the fold to take.
+    ser = pl.Series([datetime(2023, 11, 5, 12)]).dt.replace_time_zone("America/Havana")
+    earliest = ser.dt.truncate("1d")
+    latest = ser.dt.truncate("1d", ambiguous="latest")
+    assert earliest.dt.replace_time_zone(None).item() == datetime(2023, 11, 5)
+    assert latest.dt.replace_time_zone(None).item() == datetime(2023, 11, 5)
+    assert (latest - earliest).item() == timedelta(hours=1)
+    assert ser.dt.truncate("1d", ambiguous="null").to_list() == [None]
+    with pytest.raises(ComputeError, match="ambiguous"):
+        ser.dt.truncate("1d", ambiguous="raise")
+
+
 def test_round_ambiguous() -> None:
     t = (
         pl.datetime_range(
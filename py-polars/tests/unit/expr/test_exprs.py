@@ -383,6 +383,29 @@ def test_rank_string_null_11252() -> None:
     assert rank.to_list() == [None, 1.0, 3.0, None, 2.0]
 
 
+def test_distribution_ranks_over() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2, 2, 2], "x": [3, None, 1, 4, 4, 2, 8]})
+
+    out = df.select(
+        pl.col("x").percent_rank().over("g").alias("percent_rank"),
+        pl.col("x").cume_dist().over("g").alias("cume_dist"),
+        pl.col("x").ntile(2, descending=True).over("g").alias("ntile"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "percent_rank": [1.0, None, 0.0, 1 / 3, 1 / 3, 0.0, 1.0],
+        "cume_dist": [1.0, None, 0.5, 0.75, 0.75, 0.25, 1.0],
+        "ntile": [1, None, 2, 1, 2, 2, 1],
+    }
+    assert out.schema == {
+        "percent_rank": pl.Float64,
+        "cume_dist": pl.Float64,
+        "ntile": pl.get_index_type(),
+    }
+
+    with pytest.raises(pl.ComputeError, match="at least one bucket"):
+        pl.Series([1, 2]).ntile(0)
+
+
 def test_search_sorted() -> None:
     for seed in [1, 2, 3]:
         np.random.seed(seed)
@@ -352,3 +352,68 @@ def test_ipc_raise_on_writing_mmap(tmp_path: Path) -> None:
         ComputeError, match="cannot write to file: already memory mapped"
     ):
         df.write_ipc(p)
+
+
+@pytest.mark.parametrize("stream", [True, False])
+def test_ipc_read_run_end_encoded(stream: bool) -> None:
+    import pyarrow as pa
+    import pyarrow.ipc
+
+    ree_int = pa.RunEndEncodedArray.from_arrays(
+        pa.array([2, 3, 6], pa.int32()), pa.array([1, None, 3])
+    )
+    ree_str = pa.RunEndEncodedArray.from_arrays(
+        pa.array([1, 5, 6], pa.int16()), pa.array(["a", "b", None])
+    )
+    tbl = pa.table({"a": ree_int, "b": ree_str, "c": pa.array(range(6))})
+
+    f = io.BytesIO()
+    new_writer = pa.ipc.new_stream if stream else pa.ipc.new_file
+    with new_writer(f, tbl.schema) as writer:
+        writer.write_table(tbl)
+    f.seek(0)
+
+    expected = pl.DataFrame(
+        {
+            "a": [1, 1, None, 3, 3, 3],
+            "b": ["a", "b", "b", "b", "b", None],
+            "c": range(6),
+        }
+    )
+    assert_frame_equal(read_ipc(stream, f), expected)
+
+    f.seek(0)
+    assert_frame_equal(read_ipc(stream, f, columns=["c"]), expected.select("c"))
+
+    if not stream:
+        f.seek(0)
+        assert_frame_equal(pl.read_ipc(f, n_rows=2), expected.head(2))
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("memory_map", [False, True])
+def test_scan_ipc_batch_statistics(
+    tmp_path: Path, memory_map: bool, capfd: Any, monkeypatch: Any
+) -> None:
+    df = pl.concat(
+        [
+            pl.DataFrame({"a": [0, 1, 2], "b": ["x", None, "z"]}),
+            pl.DataFrame({"a": [3, 4, 5], "b": ["u", "v", "w"]}),
+            pl.DataFrame({"a": [6, 7, 8], "b": [None, "s", "t"]}),
+        ],
+        rechunk=False,
+    )
+    file_path = tmp_path / "stats.ipc"
+    df.write_ipc(file_path, batch_statistics=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    predicate = (pl.col("a") >= 4) & (pl.col("a") <= 6)
+    out = pl.scan_ipc(file_path, memory_map=memory_map).filter(predicate).collect()
+    assert_frame_equal(out, df.filter(predicate))
+    assert "skipping 1 of 3 record batches" in capfd.readouterr().err
+
+    # Without statistics every record batch is read.
+    df.write_ipc(file_path)
+    out = pl.scan_ipc(file_path).filter(pl.col("a") > 6).collect()
+    assert_frame_equal(out, df.filter(pl.col("a") > 6))
+    assert "record batches" not in capfd.readouterr().err
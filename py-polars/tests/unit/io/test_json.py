@@ -4,6 +4,7 @@ import io
 import json
 import typing
 from collections import OrderedDict
+from datetime import date, datetime, time
 from decimal import Decimal as D
 from io import BytesIO
 from typing import TYPE_CHECKING
@@ -62,6 +63,37 @@ def test_write_json_decimal() -> None:
     assert value == """[{"a":"1.00"},{"a":"2.00"},{"a":null}]"""
 
 
+def test_write_json_orient() -> None:
+    df = pl.DataFrame({"a": [1, None], "b": ["x", "y"]})
+    assert df.write_json(orient="records") == '[{"a":1,"b":"x"},{"a":null,"b":"y"}]'
+    assert df.write_json(skip_nulls=True) == '[{"a":1,"b":"x"},{"b":"y"}]'
+    assert df.write_json(orient="columns") == '{"a":[1,null],"b":["x","y"]}'
+    assert df.write_json(orient="values") == '[[1,"x"],[null,"y"]]'
+    assert df.clear().write_json(orient="values") == "[]"
+    assert df.clear().write_json(orient="columns") == '{"a":[],"b":[]}'
+
+    with pytest.raises(ValueError, match="`orient` must be one of"):
+        df.write_json(orient="index")  # type: ignore[arg-type]
+
+
+def test_write_json_temporal_format() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [date(2024, 1, 31)],
+            "datetime": [datetime(2024, 1, 31, 12, 30)],
+            "time": [time(12, 30)],
+        }
+    )
+    out = df.write_json(
+        date_format="%d/%m/%Y",
+        datetime_format="%Y-%m-%dT%H:%M",
+        time_format="%H.%M",
+    )
+    assert out == (
+        '[{"date":"31/01/2024","datetime":"2024-01-31T12:30","time":"12.30"}]'
+    )
+
+
 def test_json_infer_schema_length_11148() -> None:
     response = [{"col1": 1}] * 2 + [{"col1": 1, "col2": 2}] * 1
     result = pl.read_json(json.dumps(response).encode(), infer_schema_length=2)
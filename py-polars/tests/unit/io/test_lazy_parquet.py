@@ -279,6 +279,36 @@ def test_parquet_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> Non
     )
 
 
+@pytest.mark.write_disk()
+def test_parquet_dictionary_statistics(
+    monkeypatch: Any, capfd: Any, tmp_path: Path
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    # The min/max statistics of the first row group don't exclude "b", but its
+    # dictionary does.
+    df = pl.DataFrame({"s": ["a", "c"] * 25 + ["b"] * 50, "idx": range(100)})
+
+    file_path = tmp_path / "dictionary.parquet"
+    df.write_parquet(file_path, statistics=True, use_pyarrow=False, row_group_size=50)
+
+    for pred in [
+        pl.col("s") == "b",
+        pl.lit("b") == pl.col("s"),
+        pl.col("s").is_in(["b", "d"]),
+    ]:
+        result = pl.scan_parquet(file_path).filter(pred).collect()
+        assert_frame_equal(result, df.filter(pred))
+
+        captured = capfd.readouterr().err
+        assert (
+            "parquet file can be skipped, the statistics were sufficient"
+            " to apply the predicate." in captured
+        )
+
+
 @pytest.mark.write_disk()
 def test_categorical(tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
@@ -437,3 +467,25 @@ def test_scan_deadlock_rayon_spawn_from_async_15172(
     t.join(5)
 
     assert results[0].equals(df)
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("streaming", [False, True])
+def test_scan_parquet_deduplicate_strings(tmp_path: Path, streaming: bool) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    file_path = tmp_path / "dedup.parquet"
+    df = pl.DataFrame(
+        {
+            "s": [f"a fairly long string value {i % 3}" for i in range(1000)],
+            "b": [None, b"some long binary value!!"] * 500,
+            "i": range(1000),
+        }
+    )
+    df.write_parquet(file_path)
+
+    out = (
+        pl.scan_parquet(file_path, deduplicate_strings=True)
+        .filter(pl.col("i") > 10)
+        .collect(streaming=streaming)
+    )
+    assert_frame_equal(out, df.filter(pl.col("i") > 10), check_row_order=False)
@@ -0,0 +1,52 @@
+import pytest
+
+import polars as pl
+from polars.exceptions import (
+    ColumnNotFoundError,
+    InvalidOperationError,
+    SchemaError,
+)
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture()
+def lf() -> pl.LazyFrame:
+    return pl.LazyFrame(
+        {"b": [1, 300], "extra": ["x", "y"], "a": ["p", "q"]},
+        schema_overrides={"b": pl.Int32},
+    )
+
+
+def test_conform_to(lf: pl.LazyFrame) -> None:
+    schema = {"a": pl.String, "b": pl.Int64, "c": pl.Float64}
+    result = lf.conform_to(schema)
+
+    expected = pl.DataFrame(
+        {"a": ["p", "q"], "b": [1, 300], "c": [None, None]}, schema=schema
+    )
+    assert result.collect_schema() == pl.Schema(schema)
+    assert_frame_equal(result.collect(), expected)
+
+
+def test_conform_to_raise(lf: pl.LazyFrame) -> None:
+    with pytest.raises(SchemaError, match="extra"):
+        lf.conform_to({"a": pl.String, "b": pl.Int32}, extra="raise")
+    with pytest.raises(ColumnNotFoundError, match="'c'"):
+        lf.conform_to({"c": pl.Int64}, missing="raise")
+
+
+def test_conform_to_cast(lf: pl.LazyFrame) -> None:
+    with pytest.raises(SchemaError, match="cannot safely cast"):
+        lf.conform_to({"b": pl.UInt8})
+
+    with pytest.raises(InvalidOperationError):
+        lf.conform_to({"b": pl.UInt8}, cast="strict").collect()
+
+    result = lf.conform_to({"b": pl.UInt16}, cast="strict").collect()
+    assert result.to_dict(as_series=False) == {"b": [1, 300]}
+    assert result.schema == pl.Schema({"b": pl.UInt16})
+
+
+def test_conform_to_invalid_option(lf: pl.LazyFrame) -> None:
+    with pytest.raises(ValueError, match="`cast` must be one of"):
+        lf.conform_to({"a": pl.String}, cast="lossy")  # type: ignore[arg-type]
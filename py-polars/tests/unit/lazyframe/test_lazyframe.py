@@ -17,11 +17,14 @@ from polars.exceptions import (
     InvalidOperationError,
     PerformanceWarning,
     PolarsInefficientMapWarning,
+    UnstableWarning,
 )
 from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import FLOAT_DTYPES
 
 if TYPE_CHECKING:
+    from pathlib import Path
+
     from _pytest.capture import CaptureFixture
 
     from polars.type_aliases import PolarsDataType
@@ -906,6 +909,43 @@ def test_collect_all(df: pl.DataFrame, no_optimization: bool) -> None:
     assert cast(float, out[1].item()) == 12.0
 
 
+@pytest.mark.parametrize("max_concurrency", [None, 1, 2])
+def test_collect_all_share_scans(tmp_path: Path, max_concurrency: int | None) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 2, 3], "b": [4, 5, 6], "c": ["x", "y", "z"]}).write_parquet(
+        path
+    )
+    lfs = [
+        pl.scan_parquet(path).filter(pl.col("a") > 1).select("c"),
+        pl.scan_parquet(path).select(pl.col("b").sum()),
+        pl.scan_parquet(path).head(1),
+    ]
+    expected = pl.collect_all(lfs)
+    with pytest.warns(UnstableWarning):
+        out = pl.collect_all(lfs, share_scans=True, max_concurrency=max_concurrency)
+    for left, right in zip(out, expected):
+        assert_frame_equal(left, right)
+
+
+def test_collect_low_latency(monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    lf = (
+        pl.LazyFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+        .with_columns(c=pl.col("a") + pl.col("b"))
+        .with_columns(d=pl.col("a") * pl.col("b"))
+        .join(pl.LazyFrame({"a": [1, 3], "e": ["x", "y"]}), on="a")
+    )
+    expected = lf.collect()
+    capfd.readouterr()
+
+    assert_frame_equal(lf.collect(low_latency=True), expected)
+    assert "low latency mode" in capfd.readouterr().err
+
+    monkeypatch.setenv("POLARS_LOW_LATENCY_ROW_LIMIT", "2")
+    assert_frame_equal(lf.collect(low_latency=True), expected)
+    assert "low latency mode" not in capfd.readouterr().err
+
+
 def test_spearman_corr() -> None:
     ldf = pl.LazyFrame(
         {
@@ -1396,3 +1436,26 @@ def test_lf_properties() -> None:
         assert lf.dtypes == [pl.Int64, pl.Float64, pl.String]
     with pytest.warns(PerformanceWarning):
         assert lf.width == 3
+
+
+def test_estimate_resources(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": range(1000), "b": ["x"] * 1000}).write_parquet(path)
+
+    lf = pl.scan_parquet(path).group_by("b").agg(pl.col("a").sum())
+    out = lf.estimate_resources(memory_budget=1 << 30)
+    assert out.columns == [
+        "operator",
+        "rows",
+        "output_bytes",
+        "peak_bytes",
+        "spill_bytes",
+    ]
+    assert out["operator"].str.strip_chars().to_list() == ["aggregate", "Parquet"]
+    assert out["rows"].to_list() == [1000, 1000]
+    assert out["peak_bytes"][0] > out["output_bytes"][1] > 0
+    assert out["spill_bytes"].sum() == 0
+
+    out = lf.estimate_resources(memory_budget=16)
+    assert out["spill_bytes"][0] > 0
+    assert out["spill_bytes"][1] == 0
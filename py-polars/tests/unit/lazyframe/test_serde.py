@@ -107,3 +107,31 @@ def test_lf_serde_scan(tmp_path: Path) -> None:
     result = pl.LazyFrame.deserialize(io.BytesIO(ser))
     assert_frame_equal(result, lf)
     assert_frame_equal(result.collect(), df)
+
+
+def test_lf_save_load_repro(tmp_path: Path) -> None:
+    path = tmp_path / "repro.json"
+    other = pl.LazyFrame({"a": [1, 2], "c": ["x", "y"]})
+    lf = (
+        pl.LazyFrame({"a": [1, 2, 3], "b": [4.0, 5.0, 6.0]})
+        .join(other, on="a")
+        .with_columns(pl.col("b") * 2)
+    )
+    lf.save_repro(path)
+
+    result = pl.LazyFrame.load_repro(path)
+    assert_frame_equal(result.collect(), lf.collect())
+
+
+def test_lf_save_repro_samples_inputs(tmp_path: Path) -> None:
+    data_path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": range(100)}).write_parquet(data_path)
+    path = tmp_path / "repro.json"
+
+    lf = pl.scan_parquet(data_path).select(pl.col("a") + 1)
+    lf.save_repro(path, sample_rows=10, full_data_threshold=0)
+
+    # the bundle does not depend on the scanned file
+    data_path.unlink()
+    result = pl.LazyFrame.load_repro(path).collect()
+    assert_frame_equal(result, pl.DataFrame({"a": range(1, 11)}))
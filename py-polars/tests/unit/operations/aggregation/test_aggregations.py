@@ -7,8 +7,8 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import InvalidOperationError
-from polars.testing import assert_frame_equal
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
     import numpy.typing as npt
@@ -748,3 +748,124 @@ def test_sort_by_over_multiple_nulls_last() -> None:
         }
     )
     assert_frame_equal(out, expected)
+
+
+def test_min_max_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "c"],
+            "ts": [2, None, 2, 1, 3, None],
+            "value": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    mask = pl.col("value") > 1
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("value").max_by("ts").alias("max"),
+        pl.col("value").min_by("ts").alias("min"),
+        pl.col("value").filter(mask).max_by(pl.col("ts").filter(mask)).alias("filt"),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b", "c"],
+            "max": [1, 5, None],
+            "min": [1, 4, None],
+            "filt": [3, 5, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    assert df.select(pl.col("value").max_by("ts")).item() == 5
+    assert df.select(pl.col("value").min_by("ts")).item() == 4
+    out = df.select(pl.col("value").max_by("ts").over("g"))
+    assert out.to_series().to_list() == [1, 1, 1, 5, 5, None]
+
+
+def test_n_unique_rows_count_if() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "c"],
+            "x": [1, 1, 2, None, None, 3],
+            "y": ["p", "p", "p", "q", "q", "r"],
+            "flag": [True, True, None, True, False, False],
+        }
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.n_unique_rows("x", "y").alias("rows"),
+        pl.n_unique_rows("x", "y", predicate="flag").alias("rows_if"),
+        pl.col("x").count_if("flag").alias("count_if"),
+        pl.col("x").n_unique_if("flag").alias("n_unique_if"),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b", "c"],
+            "rows": [2, 1, 1],
+            "rows_if": [1, 1, 0],
+            "count_if": [2, 0, 0],
+            "n_unique_if": [1, 1, 0],
+        },
+        schema_overrides={
+            "rows": pl.UInt32,
+            "rows_if": pl.UInt32,
+            "count_if": pl.UInt32,
+            "n_unique_if": pl.UInt32,
+        },
+    )
+    assert_frame_equal(out, expected)
+
+    assert df.select(pl.n_unique_rows("x", "y")).item() == 4
+    assert df.select(pl.struct("x", "y").n_unique()).item() == 4
+    assert df.select(pl.n_unique_rows("x", "y", predicate="flag")).item() == 2
+    assert df.select(pl.col("x").count_if("flag")).item() == 2
+    assert df.select(pl.col("x").n_unique_if("flag")).item() == 2
+
+
+def test_weighted_aggregations() -> None:
+    df = pl.DataFrame(
+        {
+            "key": ["a", "a", "a", "b", "b", "b"],
+            "x": [1.0, 2.0, None, 4.0, 3.0, 5.0],
+            "w": [1, 3, 1, 2, None, 2],
+        }
+    )
+    out = df.group_by("key", maintain_order=True).agg(
+        pl.col("x").weighted_mean("w").alias("mean"),
+        pl.col("x").weighted_var("w", ddof=0).alias("var"),
+        pl.col("x").weighted_quantile("w", 0.5).alias("quantile"),
+    )
+    expected = pl.DataFrame(
+        {
+            "key": ["a", "b"],
+            "mean": [1.75, 4.5],
+            "var": [0.1875, 0.25],
+            "quantile": [2.0, 4.0],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    w = pl.when(pl.col("x").is_not_null()).then(pl.col("w"))
+    out = df.group_by("key", maintain_order=True).agg(
+        pl.col("x").weighted_mean("w"),
+        ((pl.col("x") * pl.col("w")).sum() / w.sum()).alias("expected"),
+    )
+    assert_series_equal(out["x"], out["expected"], check_names=False)
+
+    out = df.select(pl.col("x").weighted_mean("w").over("key"))
+    assert out["x"].to_list() == [1.75] * 3 + [4.5] * 3
+
+    assert df.select(pl.col("x").weighted_mean("w")).item() == 25.0 / 8.0
+    assert df.select(pl.col("x").weighted_var("w")).item() == pytest.approx(
+        df.select(pl.col("x").weighted_var("w", ddof=0)).item() * 8.0 / 7.0
+    )
+    assert df.select(pl.col("x").weighted_quantile("w", 1.0)).item() == 5.0
+
+    out = df.select(pl.col("x").cast(pl.Float32).weighted_mean("w"))
+    assert out.schema["x"] == pl.Float32
+
+    # the weights sum to zero
+    out = df.select(pl.col("x").weighted_mean(pl.col("w") * 0))
+    assert out.item() is None
+
+    with pytest.raises(ComputeError, match="quantile between 0 and 1"):
+        df.select(pl.col("x").weighted_quantile("w", 1.5))
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.col("key").weighted_mean("w"))
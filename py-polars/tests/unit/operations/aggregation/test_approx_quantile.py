@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal
+
+
+def test_approx_quantile() -> None:
+    s = pl.Series("a", range(100_000))
+    for q in [0.0, 0.01, 0.5, 0.99, 1.0]:
+        assert s.approx_quantile(q) == pytest.approx(q * 99_999, abs=500)
+
+    assert pl.Series("a", [None, 1.0, 2.0, 3.0]).approx_quantile(0.5) == 2.0
+    assert pl.Series("a", [], dtype=pl.Int64).approx_quantile(0.5) is None
+
+    with pytest.raises(ComputeError, match="quantile"):
+        s.approx_quantile(1.5)
+    with pytest.raises(ComputeError, match="accuracy"):
+        s.approx_quantile(0.5, accuracy=0.0)
+
+
+def test_approx_quantile_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [i % 3 for i in range(30_000)],
+            "x": range(30_000),
+        }
+    )
+    out = (
+        df.group_by("g")
+        .agg(
+            approx=pl.col("x").approx_quantile(0.5),
+            exact=pl.col("x").quantile(0.5, interpolation="linear"),
+        )
+        .sort("g")
+    )
+    for approx, exact in zip(out["approx"], out["exact"]):
+        assert approx == pytest.approx(exact, abs=300)
+
+    # Filtered groups go through the generic path.
+    out = df.group_by("g").agg(
+        pl.col("x").filter(pl.col("x") < 3).approx_quantile(1.0)
+    )
+    assert_frame_equal(
+        out.sort("g"),
+        pl.DataFrame({"g": [0, 1, 2], "x": [0.0, 1.0, 2.0]}),
+    )
+
+
+def test_tdigest_merge() -> None:
+    df = pl.DataFrame({"file": [i % 4 for i in range(40_000)], "x": range(40_000)})
+    sketches = df.group_by("file").agg(pl.col("x").tdigest())
+    assert sketches.schema["x"] == pl.Binary
+
+    estimate = sketches.select(pl.col("x").tdigest_quantile(0.9)).item()
+    assert estimate == pytest.approx(36_000, abs=400)
+
+    merged = sketches.select(pl.col("x").tdigest_merge())
+    assert merged.select(pl.col("x").tdigest_quantile(0.0)).item() == 0.0
+    assert merged.select(pl.col("x").tdigest_quantile(1.0)).item() == 39_999.0
+
+    # Nulls are ignored.
+    sketches = pl.concat(
+        [sketches, pl.DataFrame({"file": [4], "x": [None]})], how="vertical_relaxed"
+    )
+    assert sketches.select(pl.col("x").tdigest_quantile(0.9)).item() == estimate
+
+    with pytest.raises(ComputeError, match="t-digest"):
+        pl.select(pl.lit(b"abc").tdigest_quantile(0.5))
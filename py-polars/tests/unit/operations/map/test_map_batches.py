@@ -1,6 +1,9 @@
 from __future__ import annotations
 
+import pickle
+import threading
 from functools import reduce
+from typing import Any
 
 import numpy as np
 import pytest
@@ -117,3 +120,43 @@ def test_lazy_map_schema() -> None:
     assert df.lazy().map_batches(
         custom2, validate_output_schema=False
     ).collect().to_dict(as_series=False) == {"a": ["1", "2", "3"], "b": ["a", "b", "c"]}
+
+
+def _init_state() -> dict[str, Any]:
+    return {"thread": threading.get_ident(), "calls": 0}
+
+
+def _add_ten(state: dict[str, Any], s: pl.Series) -> pl.Series:
+    assert state["thread"] == threading.get_ident()
+    state["calls"] += 1
+    return s + 10
+
+
+def test_map_batches_with_state() -> None:
+    states: list[dict[str, Any]] = []
+
+    def init() -> dict[str, Any]:
+        state = _init_state()
+        states.append(state)
+        return state
+
+    df = pl.DataFrame({"g": [1, 1, 2, 2, 3], "a": [1, 2, 3, 4, 5]})
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").map_batches_with_state(init, _add_ten)
+    )
+    expected = pl.DataFrame({"g": [1, 2, 3], "a": [[11, 12], [13, 14], [15]]})
+    assert_frame_equal(result, expected)
+
+    # Every thread creates its state once, and reuses it for all its groups.
+    assert len({state["thread"] for state in states}) == len(states)
+    assert sum(state["calls"] for state in states) == 3
+
+    result = df.select(pl.col("a").map_batches_with_state(init, _add_ten))
+    assert result.to_series().to_list() == [11, 12, 13, 14, 15]
+
+    # The state isn't pickled, but recreated after unpickling.
+    f = pl.Expr._map_batches_with_state_wrapper(_init_state, _add_ten)
+    f(pl.Series([1]))
+    f = pickle.loads(pickle.dumps(f))
+    assert not hasattr(f._local, "state")
+    assert f(pl.Series([1])).to_list() == [11]
@@ -6,7 +6,7 @@ from typing import Any
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError
+from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 
@@ -449,3 +449,89 @@ def test_array_n_unique() -> None:
         {"n_unique": [2, 1, 1, None]}, schema={"n_unique": pl.UInt32}
     )
     assert_frame_equal(out, expected)
+
+
+def test_array_mean() -> None:
+    s = pl.Series("a", [[1, 2], [4, None], None], dtype=pl.Array(pl.Int64, 2))
+    assert s.arr.mean().to_list() == [1.5, 4.0, None]
+
+    s = pl.Series("a", [[1.0, 2.0], [4.0, 3.0]], dtype=pl.Array(pl.Float32, 2))
+    out = s.arr.mean()
+    assert out.dtype == pl.Float32
+    assert out.to_list() == [1.5, 3.5]
+
+    lf = pl.LazyFrame({"a": s})
+    assert lf.select(pl.col("a").arr.mean()).collect_schema() == {"a": pl.Float32}
+
+
+def test_array_arithmetic() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2], [3, 4], None],
+            "b": [[10, 20], [30, 40], [50, 60]],
+            "c": [1, 2, 3],
+        },
+        schema={
+            "a": pl.Array(pl.Int64, 2),
+            "b": pl.Array(pl.Int64, 2),
+            "c": pl.Int64,
+        },
+    )
+    out = df.lazy().select(
+        add=pl.col("a") + pl.col("b"),
+        sub=pl.col("b") - pl.col("c"),
+        mul=pl.col("c") * pl.col("a"),
+        lit=pl.col("a") * 2,
+        div=pl.col("a") / pl.col("c"),
+        float=pl.col("a") + 0.5,
+    )
+    expected = pl.DataFrame(
+        {
+            "add": [[11, 22], [33, 44], None],
+            "sub": [[9, 19], [28, 38], [47, 57]],
+            "mul": [[1, 2], [6, 8], None],
+            "lit": [[2, 4], [6, 8], None],
+            "div": [[1.0, 2.0], [1.5, 2.0], None],
+            "float": [[1.5, 2.5], [3.5, 4.5], None],
+        },
+        schema={
+            "add": pl.Array(pl.Int64, 2),
+            "sub": pl.Array(pl.Int64, 2),
+            "mul": pl.Array(pl.Int64, 2),
+            "lit": pl.Array(pl.Int64, 2),
+            "div": pl.Array(pl.Float64, 2),
+            "float": pl.Array(pl.Float64, 2),
+        },
+    )
+    assert out.collect_schema() == expected.schema
+    assert_frame_equal(out.collect(), expected)
+
+    # Broadcast a single array over all rows.
+    s = pl.Series([[1, 2]], dtype=pl.Array(pl.Int64, 2))
+    assert (df["b"] - s).to_list() == [[9, 18], [29, 38], [49, 58]]
+    assert (df["b"] * 2).to_list() == [[20, 40], [60, 80], [100, 120]]
+    assert (df["b"] / 10).to_list() == [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]
+
+    with pytest.raises(InvalidOperationError, match="same shape"):
+        df["a"] + pl.Series([[1, 2, 3]], dtype=pl.Array(pl.Int64, 3))
+
+
+def test_array_group_by_mean() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["x", "x", "y", "y", "z"],
+            "a": [[1.0, 2.0], [3.0, None], [5.0, 6.0], None, None],
+        },
+        schema={"g": pl.String, "a": pl.Array(pl.Float64, 2)},
+    )
+    out = df.lazy().group_by("g", maintain_order=True).agg(pl.col("a").mean())
+    assert out.collect_schema() == {"g": pl.String, "a": pl.Array(pl.Float64, 2)}
+    assert out.collect().to_dict(as_series=False) == {
+        "g": ["x", "y", "z"],
+        "a": [[2.0, 2.0], [5.0, 6.0], None],
+    }
+
+    df = df.with_columns(pl.col("a").cast(pl.Array(pl.Int32, 2)))
+    out = df.group_by("g", maintain_order=True).mean()
+    assert out["a"].dtype == pl.Array(pl.Float64, 2)
+    assert out["a"].to_list() == [[2.0, 2.0], [5.0, 6.0], None]
@@ -10,6 +10,7 @@ from polars.exceptions import (
     ComputeError,
     OutOfBoundsError,
     SchemaError,
+    ShapeError,
     StructFieldNotFoundError,
 )
 from polars.testing import assert_frame_equal, assert_series_equal
@@ -297,12 +298,12 @@ def test_list_drop_nulls() -> None:
 def test_list_sample() -> None:
     s = pl.Series("values", [[1, 2, 3, None], [None, None], [1, 2], None])
 
-    expected_sample_n = pl.Series("values", [[3, 1], [None], [2], None])
+    expected_sample_n = pl.Series("values", [[2, 3], [None], [2], None])
     assert_series_equal(
         s.list.sample(n=pl.Series([2, 1, 1, 1]), seed=1), expected_sample_n
     )
 
-    expected_sample_frac = pl.Series("values", [[3, 1], [None], [1, 2], None])
+    expected_sample_frac = pl.Series("values", [[2, 3], [None], [1, 2], None])
     assert_series_equal(
         s.list.sample(fraction=pl.Series([0.5, 0.5, 1.0, 0.3]), seed=1),
         expected_sample_frac,
@@ -320,7 +321,7 @@ def test_list_sample() -> None:
         sample_frac=pl.col("values").list.sample(fraction=pl.col("frac"), seed=1),
     )
     expected_df = pl.DataFrame(
-        {"sample_n": [[3, 1], [None], [3, 4]], "sample_frac": [[3, 1], [None], [3, 4]]}
+        {"sample_n": [[2, 3], [None], [3, 4]], "sample_frac": [[2, 3], [None], [3, 4]]}
     )
     assert_frame_equal(df, expected_df)
 
@@ -783,6 +784,72 @@ def test_list_arithmetic() -> None:
     assert_series_equal(s.list.min(), pl.Series("a", [1, 1]))
 
 
+
+def test_list_elementwise_arithmetic() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2.0], None, [3.0], [], [4.0, None]],
+            "b": [[10.0, 20.0], [1.0], [2.0], [], [1.0, 1.0]],
+        }
+    )
+    out = df.select(
+        add=pl.col("a") + pl.col("b"),
+        sub=pl.col("a") - pl.col("b"),
+        mul=pl.col("a") * pl.col("b"),
+        div=pl.col("a") / pl.col("b"),
+    )
+    expected = pl.DataFrame(
+        {
+            "add": [[11.0, 22.0], None, [5.0], [], [5.0, None]],
+            "sub": [[-9.0, -18.0], None, [1.0], [], [3.0, None]],
+            "mul": [[10.0, 40.0], None, [6.0], [], [4.0, None]],
+            "div": [[0.1, 0.1], None, [1.5], [], [4.0, None]],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    # Integer lists are cast to the supertype and true division returns floats.
+    df = pl.DataFrame({"a": [[1, 2], [3, 4]], "b": [[2.5, 2.0], [1.0, 8.0]]})
+    out = df.select(
+        add=pl.col("a") + pl.col("b"),
+        div=pl.col("a") / pl.col("a"),
+        floordiv=pl.col("a") // pl.lit(pl.Series([[2, 2]])),
+    )
+    assert out.schema == {
+        "add": pl.List(pl.Float64),
+        "div": pl.List(pl.Float64),
+        "floordiv": pl.List(pl.Int64),
+    }
+    assert out.to_dict(as_series=False) == {
+        "add": [[3.5, 4.0], [4.0, 12.0]],
+        "div": [[1.0, 1.0], [1.0, 1.0]],
+        "floordiv": [[0, 1], [1, 2]],
+    }
+
+
+def test_list_elementwise() -> None:
+    a = pl.Series("a", [[1, 5], [3, 4], None, [2]])
+    b = pl.Series("b", [[2, 2], [3, 1], [1, 1], None])
+    assert a.list.elementwise(b, "max").to_list() == [[2, 5], [3, 4], None, None]
+    assert a.list.elementwise(b, "min").to_list() == [[1, 2], [3, 1], None, None]
+    assert a.list.elementwise(b, "ge").to_list() == [
+        [False, True],
+        [True, True],
+        None,
+        None,
+    ]
+    assert a.list.elementwise(b, "eq").dtype == pl.List(pl.Boolean)
+
+    df = pl.DataFrame({"a": a, "b": b})
+    out = df.select(pl.col("a").list.elementwise(pl.col("b"), "lt"))
+    assert out["a"].to_list() == [[True, False], [False, False], None, None]
+
+    with pytest.raises(ShapeError, match="different lengths in row 0"):
+        pl.Series([[1, 2]]).list.elementwise(pl.Series([[1]]), "add")
+    with pytest.raises(ValueError, match="operation must be one of"):
+        a.list.elementwise(b, "pow")  # type: ignore[arg-type]
+
+
 def test_list_ordering() -> None:
     s = pl.Series("a", [[2, 1], [1, 3, 2]])
     assert_series_equal(s.list.sort(), pl.Series("a", [[1, 2], [1, 2, 3]]))
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+from datetime import timedelta
+
 import pytest
 
 import polars as pl
@@ -971,6 +973,45 @@ def test_replace_expressions() -> None:
     }
 
 
+def test_replace_capture_groups() -> None:
+    s = pl.Series(["12-34", "1-2 3-4 5-6", "x"])
+    assert s.str.replace(r"(\d+)-(\d+)", "$2/$1").to_list() == [
+        "34/12",
+        "2/1 3-4 5-6",
+        "x",
+    ]
+    assert s.str.replace(r"(\d+)-(\d+)", "$2/$1", n=2).to_list() == [
+        "34/12",
+        "2/1 4/3 5-6",
+        "x",
+    ]
+    assert s.str.replace_all(r"(?<a>\d+)-(?<b>\d+)", "${b}/${a}").to_list() == [
+        "34/12",
+        "2/1 4/3 6/5",
+        "x",
+    ]
+    assert s.str.replace("-", "$$", n=0).to_list() == s.to_list()
+
+    # A pattern without special characters can still refer to the whole match.
+    assert pl.Series(["abc"]).str.replace("b", "[$0]").to_list() == ["a[b]c"]
+    assert pl.Series(["abc"]).str.replace("b", "[$0]", literal=True).to_list() == [
+        "a[$0]c"
+    ]
+
+    # The replacement can be an expression that refers to the capture groups.
+    df = pl.DataFrame({"text": ["a1b22", "a3b4"], "value": ["[$1]", "<${1}>"]})
+    out = df.select(
+        first=pl.col("text").str.replace(r"(\d+)", pl.col("value")),
+        all=pl.col("text").str.replace_all(r"(\d+)", pl.col("value")),
+        literal=pl.col("text").str.replace_all("b", pl.col("value"), literal=True),
+    )
+    assert out.to_dict(as_series=False) == {
+        "first": ["a[1]b22", "a<3>b4"],
+        "all": ["a[1]b[22]", "a<3>b<4>"],
+        "literal": ["a1[$1]22", "a3<${1}>4"],
+    }
+
+
 def test_extract_all_count() -> None:
     df = pl.DataFrame({"foo": ["123 bla 45 asd", "xaz 678 910t", "boo", None]})
     assert (
@@ -1487,3 +1528,119 @@ def test_replace_lit_n_char_13385(
     res = s.str.replace("a", "b", literal=True)
     expected_s = pl.Series(expected_dat, dtype=pl.String)
     assert_series_equal(res, expected_s)
+
+
+def test_string_levenshtein() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "flaw", "", "straße", None, "abc"],
+            "b": ["sitting", "lawn", "abc", "strasse", "abc", None],
+        }
+    )
+    out = df.select(
+        dist=pl.col("a").str.levenshtein(pl.col("b")),
+        sim=pl.col("a").str.levenshtein(pl.col("b"), normalized=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "dist": pl.Series([3, 2, 3, 2, None, None], dtype=pl.UInt32),
+            "sim": [1 - 3 / 7, 0.5, 0.0, 1 - 2 / 7, None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    # Strings are literals and broadcast.
+    s = pl.Series("a", ["kitten", "mitten", None])
+    expected_s = pl.Series("a", [0, 1, None], dtype=pl.UInt32)
+    assert_series_equal(s.str.levenshtein("kitten"), expected_s)
+    assert_series_equal(
+        pl.select(pl.lit("kitten").str.levenshtein(s)).to_series(),
+        expected_s.alias("literal"),
+    )
+
+
+def test_string_hamming() -> None:
+    s = pl.Series("a", ["karolin", "1011101", "abc", "", None])
+    other = pl.Series("b", ["kathrin", "1001001", "abcd", "", "x"])
+    assert_series_equal(
+        s.str.hamming(other),
+        pl.Series("a", [3, 2, None, 0, None], dtype=pl.UInt32),
+    )
+    assert_series_equal(
+        s.str.hamming(other, normalized=True),
+        pl.Series("a", [1 - 3 / 7, 1 - 2 / 7, None, 1.0, None]),
+    )
+
+
+def test_string_jaro_winkler() -> None:
+    s = pl.Series("a", ["martha", "dixon", "abc", "", "same", None])
+    other = pl.Series("b", ["marhta", "dicksonx", "xyz", "", "same", "x"])
+    assert_series_equal(
+        s.str.jaro_winkler(other),
+        pl.Series("a", [0.961111, 0.813333, 0.0, 1.0, 1.0, None]),
+        atol=1e-6,
+    )
+
+
+def test_string_tokenize() -> None:
+    s = pl.Series("a", ["  Hello,\tworld!  ", "It's 3.5 km", "", None])
+    expected = pl.Series("a", [["Hello,", "world!"], ["It's", "3.5", "km"], [], None])
+    assert_series_equal(s.str.tokenize(), expected)
+
+    expected = pl.Series("a", [["Hello", "world"], ["It's", "3.5", "km"], [], None])
+    assert_series_equal(s.str.tokenize("words"), expected)
+
+    expected = pl.Series("a", [["Hello", "world"], ["It", "s", "km"], [], None])
+    assert_series_equal(s.str.tokenize("regex", pattern=r"[a-zA-Z]*"), expected)
+
+    df = pl.DataFrame({"a": s})
+    out = df.lazy().select(pl.col("a").str.tokenize("words"))
+    assert out.collect_schema() == {"a": pl.List(pl.String)}
+
+    with pytest.raises(ValueError, match="pattern"):
+        s.str.tokenize("regex")
+    with pytest.raises(ValueError, match="pattern"):
+        s.str.tokenize(pattern=r"\d+")
+    with pytest.raises(ComputeError):
+        s.str.tokenize("regex", pattern="(")
+
+
+def test_string_ngrams() -> None:
+    s = pl.Series("a", ["naïve", "ab", "", None])
+    assert_series_equal(
+        s.str.ngrams(2),
+        pl.Series("a", [["na", "aï", "ïv", "ve"], ["ab"], [], None]),
+    )
+    assert_series_equal(
+        s.str.ngrams(3),
+        pl.Series("a", [["naï", "aïv", "ïve"], [], [], None]),
+    )
+    with pytest.raises(InvalidOperationError):
+        s.str.ngrams(0)
+
+
+def test_string_strip_accents() -> None:
+    s = pl.Series("a", ["Crème brûlée", "Ångström", "plain", "", None])
+    expected = pl.Series("a", ["Creme brulee", "Angstrom", "plain", "", None])
+    assert_series_equal(s.str.strip_accents(), expected)
+
+
+def test_str_to_duration() -> None:
+    s = pl.Series(["P2W", "PT1H30M", "1h 30m", "-PT0,25S", "P1M", "nonsense", None])
+    result = s.str.to_duration("ms", strict=False)
+    expected = pl.Series(
+        [
+            timedelta(weeks=2),
+            timedelta(hours=1, minutes=30),
+            timedelta(hours=1, minutes=30),
+            -timedelta(milliseconds=250),
+            None,
+            None,
+            None,
+        ],
+        dtype=pl.Duration("ms"),
+    )
+    assert_series_equal(result, expected)
+
+    with pytest.raises(InvalidOperationError, match="conversion .* failed"):
+        s.str.to_duration()
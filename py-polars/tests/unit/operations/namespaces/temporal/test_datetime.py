@@ -40,6 +40,33 @@ def test_dt_to_string(series_of_int_dates: pl.Series) -> None:
     assert_series_equal(series_of_int_dates.dt.strftime("%F"), expected_str_dates)
 
 
+def test_dt_to_string_duration() -> None:
+    s = pl.Series(
+        "d",
+        [
+            timedelta(days=1, hours=2, minutes=3, seconds=4, milliseconds=500),
+            timedelta(0),
+            -timedelta(minutes=1, seconds=30),
+            None,
+        ],
+    )
+    iso = s.dt.to_string("iso")
+    assert_series_equal(
+        iso, pl.Series("d", ["P1DT2H3M4.5S", "PT0S", "-PT1M30S", None])
+    )
+    humanized = s.dt.to_string("humanized")
+    assert_series_equal(
+        humanized, pl.Series("d", ["1d 2h 3m 4s 500ms", "0µs", "-1m -30s", None])
+    )
+
+    # both formats parse back into the same durations
+    assert_series_equal(iso.str.to_duration(), s)
+    assert_series_equal(humanized.str.to_duration(), s)
+
+    with pytest.raises(InvalidOperationError, match="invalid duration format"):
+        s.dt.to_string("%H")
+
+
 @pytest.mark.parametrize(
     ("unit_attr", "expected"),
     [
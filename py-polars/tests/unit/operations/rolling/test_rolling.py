@@ -288,17 +288,17 @@ def test_rolling_extrema() -> None:
     # shuffled data triggers other kernels
     df = df.select([pl.all().shuffle(0)])
     assert df.select([pl.all().rolling_min(3)]).to_dict(as_series=False) == {
-        "col1": [None, None, 0, 0, 1, 2, 2],
-        "col2": [None, None, 0, 2, 1, 1, 1],
-        "col1_nulls": [None, None, None, None, None, 2, 2],
-        "col2_nulls": [None, None, None, None, None, 1, 1],
+        "col1": [None, None, 2, 4, 1, 0, 0],
+        "col2": [None, None, 0, 0, 0, 1, 3],
+        "col1_nulls": [None, None, 2, 4, None, None, None],
+        "col2_nulls": [None, None, 0, 0, None, None, None],
     }
 
     assert df.select([pl.all().rolling_max(3)]).to_dict(as_series=False) == {
-        "col1": [None, None, 6, 4, 5, 5, 5],
-        "col2": [None, None, 6, 6, 5, 4, 4],
-        "col1_nulls": [None, None, None, None, None, 5, 5],
-        "col2_nulls": [None, None, None, None, None, 4, 4],
+        "col1": [None, None, 6, 6, 6, 5, 3],
+        "col2": [None, None, 4, 2, 5, 6, 6],
+        "col1_nulls": [None, None, 6, 6, None, None, None],
+        "col2_nulls": [None, None, 4, 2, None, None, None],
     }
 
 
@@ -403,16 +403,16 @@ def test_rolling_group_by_extrema() -> None:
         .select(["col1_list", "col1_min", "col1_max"])
     ).to_dict(as_series=False) == {
         "col1_list": [
-            [3],
-            [3, 4],
-            [3, 4, 5],
-            [4, 5, 6],
-            [5, 6, 2],
-            [6, 2, 1],
-            [2, 1, 0],
+            [4],
+            [4, 1],
+            [4, 1, 2],
+            [1, 2, 6],
+            [2, 6, 5],
+            [6, 5, 0],
+            [5, 0, 3],
         ],
-        "col1_min": [3, 3, 3, 4, 2, 1, 0],
-        "col1_max": [3, 4, 5, 6, 6, 6, 2],
+        "col1_min": [4, 1, 1, 1, 2, 0, 0],
+        "col1_max": [4, 4, 4, 6, 6, 6, 5],
     }
 
 
@@ -589,6 +589,77 @@ def test_rolling_cov_corr() -> None:
     assert res["corr"][:2] == [None] * 2
 
 
+@pytest.mark.parametrize("offset", [0.0, 1e9])
+def test_rolling_cov_corr_by(offset: float) -> None:
+    df = pl.DataFrame(
+        {
+            "date": [date(2024, 1, d) for d in [1, 2, 3, 4, 5, 9, 10]],
+            "x": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 8.0],
+            "y": [1.0, 2.0, 3.0, 1.0, 5.0, 6.0, 7.0],
+        }
+    ).with_columns(pl.col("x", "y") + offset)
+
+    result = df.select(
+        pl.rolling_cov_by("x", "y", "date", window_size="3d").alias("cov"),
+        pl.rolling_cov_by("x", "y", "date", window_size="3d", ddof=0).alias("cov0"),
+        pl.rolling_corr_by("x", "y", "date", window_size="3d", min_periods=2).alias(
+            "corr"
+        ),
+    )
+    expected = pl.DataFrame(
+        {
+            "cov": [None, 0.5, 1.0, -0.5, 1.0, None, 1.0],
+            "cov0": [0.0, 0.25, 2 / 3, -1 / 3, 2 / 3, 0.0, 0.5],
+            "corr": [None, 1.0, 1.0, -0.5, 0.5, None, 1.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    # Unsorted `by`.
+    shuffled = df.with_row_index().sample(fraction=1.0, shuffle=True, seed=0)
+    result = shuffled.select(
+        "index",
+        pl.rolling_cov_by("x", "y", "date", window_size="3d").alias("cov"),
+        pl.rolling_cov_by("x", "y", "date", window_size="3d", ddof=0).alias("cov0"),
+        pl.rolling_corr_by("x", "y", "date", window_size="3d", min_periods=2).alias(
+            "corr"
+        ),
+    ).sort("index")
+    assert_frame_equal(result.drop("index"), expected)
+
+
+def test_rolling_cov_corr_by_dtypes() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [datetime(2024, 1, 1, h) for h in range(4)],
+            "x": [1, 2, 3, 4],
+            "y": [2.0, 4.0, 6.0, 8.0],
+        },
+        schema_overrides={"y": pl.Float32},
+    )
+    result = df.select(
+        pl.rolling_corr_by("x", "y", "date", window_size="2h", min_periods=2),
+        pl.rolling_cov_by(
+            pl.col("x").cast(pl.Float32), "y", "date", window_size=timedelta(hours=2)
+        ).alias("cov"),
+    )
+    expected = pl.DataFrame(
+        {"x": [None, 1.0, 1.0, 1.0], "cov": [None, 1.0, 1.0, 1.0]},
+        schema={"x": pl.Float64, "cov": pl.Float32},
+    )
+    assert_frame_equal(result, expected)
+    assert df.lazy().select(
+        pl.rolling_cov_by("y", "y", "date", window_size="2h")
+    ).collect_schema() == pl.Schema({"y": pl.Float32})
+
+    with pytest.raises(InvalidOperationError, match="null values"):
+        df.select(
+            pl.rolling_cov_by(
+                "x", pl.when(pl.col("x") > 1).then("y"), "date", window_size="2h"
+            ).alias("cov")
+        )
+
+
 @pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
 def test_rolling_empty_window_9406(time_unit: TimeUnit) -> None:
     datecol = pl.Series(
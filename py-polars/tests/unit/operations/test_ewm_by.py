@@ -246,3 +246,37 @@ def test_ewma_by_multiple_chunks() -> None:
     values = pl.Series([1, 2]).append(pl.Series([None], dtype=pl.Int64))
     result = values.ewm_mean_by(times, half_life="2i")
     assert_series_equal(result, expected)
+
+
+@pytest.mark.parametrize("sort", [True, False])
+def test_ewm_std_var_by(sort: bool) -> None:
+    df = pl.LazyFrame(
+        {
+            "values": [0.0, 1.0, 2.0, None, 4.0],
+            "times": [1, 3, 10, 15, 17],
+        }
+    )
+    if not sort:
+        df = df.reverse()
+    result = df.select(
+        var=pl.col("values").ewm_var_by("times", half_life="4i"),
+        var_biased=pl.col("values").ewm_var_by("times", half_life="4i", bias=True),
+        std=pl.col("values").ewm_std_by("times", half_life="4i"),
+    ).collect()
+    expected = pl.DataFrame(
+        {
+            "var": [0.0, 0.5, 1.475209, None, 3.303299],
+            "var_biased": [0.0, 0.207107, 0.670392, None, 1.512891],
+            "std": [0.0, 0.707107, 1.214582, None, 1.817498],
+        }
+    )
+    if not sort:
+        expected = expected.reverse()
+    assert_frame_equal(result, expected)
+
+
+def test_ewm_std_var_by_constant() -> None:
+    s = pl.Series([1e9 + 0.1] * 4)
+    times = pl.Series([1, 2, 4, 8])
+    result = s.ewm_var_by(times, half_life="1i")
+    assert_series_equal(result, pl.Series([0.0] * 4))
@@ -202,6 +202,31 @@ def test_explode_invalid_element_count() -> None:
         df.explode(["col1", "col2"])
 
 
+def test_explode_lockstep_pad_with_nulls() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2], [3], None, []],
+            "b": [["x", "y"], ["z", "w"], ["v"], []],
+            "c": [1, 2, 3, 4],
+        }
+    )
+    with pytest.raises(ShapeError, match=r"in row 1"):
+        df.explode("a", "b")
+
+    expected = pl.DataFrame(
+        {
+            "a": [1, 2, 3, None, None, None],
+            "b": ["x", "y", "z", "w", "v", None],
+            "c": [1, 1, 2, 2, 3, 4],
+        }
+    )
+    assert_frame_equal(df.explode("a", "b", pad_with_nulls=True), expected)
+    assert_frame_equal(
+        df.lazy().explode(["a", "b"], pad_with_nulls=True).collect(),
+        expected,
+    )
+
+
 def test_logical_explode() -> None:
     out = (
         pl.DataFrame(
@@ -776,6 +776,21 @@ def test_group_by_partitioned_ending_cast(monkeypatch: Any) -> None:
     assert_frame_equal(out, expected)
 
 
+def test_group_by_partitioned_skewed_keys(monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    monkeypatch.setenv("POLARS_PARTITION_UNIQUE_COUNT", "10")
+    # half of the rows belong to a single key, the other keys are unique
+    n = 1000
+    df = pl.DataFrame({"key": [0] * n + list(range(1, n + 1)), "value": [1] * 2 * n})
+
+    out = df.group_by("key").agg(pl.col("value").sum(), pl.len()).sort("key")
+    assert "SKEWED KEYS" in capfd.readouterr().err
+    assert out.height == n + 1
+    assert out.row(0) == (0, n, n)
+    assert out["value"][1:].to_list() == [1] * n
+    assert out["len"][1:].to_list() == [1] * n
+
+
 def test_group_by_series_partitioned(partition_limit: int) -> None:
     # test 15354
     df = pl.DataFrame([0, 0] * partition_limit)
@@ -1139,3 +1154,30 @@ def test_grouped_slice_literals() -> None:
         ),  # slices a list of 1 element, so remains the same element
         x2=pl.lit(pl.Series([1, 2])).slice(-1, 1),
     ).to_dict(as_series=False) == {"literal": [True], "x": [[1, 2]], "x2": [2]}
+
+
+def test_group_by_agg_groups_struct() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 1],
+            "a": [1, 2, 3, None],
+            "b": ["x", "y", "z", "w"],
+        }
+    )
+    out = df.group_by("g", maintain_order=True).agg_groups_struct("payload")
+    assert out.schema == {
+        "g": pl.Int64,
+        "payload": pl.List(pl.Struct({"a": pl.Int64, "b": pl.String})),
+    }
+    assert out.to_dict(as_series=False) == {
+        "g": [1, 2],
+        "payload": [
+            [{"a": 1, "b": "x"}, {"a": 3, "b": "z"}, {"a": None, "b": "w"}],
+            [{"a": 2, "b": "y"}],
+        ],
+    }
+    assert_frame_equal(
+        df.lazy().group_by("g").agg_groups_struct("payload").collect(),
+        out,
+        check_row_order=False,
+    )
@@ -790,6 +790,32 @@ def test_group_by_dynamic_monthly_crossing_dst() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_group_by_dynamic_non_existent_boundary() -> None:
+    # Midnight doesn't exist in Havana on 2023-03-12.
+    df = pl.DataFrame(
+        {
+            "time": pl.datetime_range(
+                datetime(2023, 3, 11, 12),
+                datetime(2023, 3, 13, 12),
+                "1h",
+                time_zone="America/Havana",
+                eager=True,
+            )
+        }
+    )
+    with pytest.raises(ComputeError, match="non-existent"):
+        df.group_by_dynamic("time", every="1d").agg(pl.len())
+
+    result = df.group_by_dynamic("time", every="1d", non_existent="null").agg(
+        pl.len()
+    )
+    assert result["time"].dt.replace_time_zone(None).to_list() == [
+        datetime(2023, 3, 11),
+        datetime(2023, 3, 13),
+    ]
+    assert result["len"].to_list() == [35, 13]
+
+
 def test_group_by_dynamic_2d_9333() -> None:
     df = pl.DataFrame({"ts": [datetime(2000, 1, 1, 3)], "values": [10.0]})
     df = df.with_columns(pl.col("ts").set_sorted())
@@ -11,7 +11,11 @@ from polars.dependencies import _ZONEINFO_AVAILABLE
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
-    from polars.type_aliases import PolarsDataType, PolarsTemporalType
+    from polars.type_aliases import (
+        InterpolationMethod,
+        PolarsDataType,
+        PolarsTemporalType,
+    )
 
 if sys.version_info >= (3, 9):
     from zoneinfo import ZoneInfo
@@ -157,3 +161,22 @@ def test_interpolate_temporal_nearest(
     assert result.collect_schema()["a"] == input_dtype
     expected = pl.DataFrame({"a": output}, schema={"a": input_dtype})
     assert_frame_equal(result.collect(), expected)
+
+
+@pytest.mark.parametrize(
+    ("method", "output"),
+    [
+        ("cubic_spline", [1.0, 2.28125, 3.0, 2.78125, 2.0, None]),
+        ("pchip", [1.0, 2.4375, 3.0, 2.8125, 2.0, None]),
+    ],
+)
+@pytest.mark.parametrize("input_dtype", [pl.Int64, pl.Float32, pl.Float64])
+def test_interpolate_cubic(
+    method: InterpolationMethod, output: list[Any], input_dtype: PolarsDataType
+) -> None:
+    df = pl.LazyFrame({"a": [1, None, 3, None, 2, None]}, schema={"a": input_dtype})
+    result = df.with_columns(pl.all().interpolate(method=method))
+    output_dtype = pl.Float32 if input_dtype == pl.Float32 else pl.Float64
+    assert result.collect_schema()["a"] == output_dtype
+    expected = pl.DataFrame({"a": output}, schema={"a": output_dtype})
+    assert_frame_equal(result.collect(), expected)
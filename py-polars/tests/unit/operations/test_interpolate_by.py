@@ -14,7 +14,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 from polars.testing.parametric import column, dataframes
 
 if TYPE_CHECKING:
-    from polars.type_aliases import PolarsDataType
+    from polars.type_aliases import InterpolationMethod, PolarsDataType
 
 
 @pytest.mark.parametrize(
@@ -205,3 +205,53 @@ def test_interpolate_by_invalid() -> None:
         match="null values in `by` column are not yet supported in 'interpolate_by'",
     ):
         s.interpolate_by(by)
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [
+        (
+            "cubic_spline",
+            [1.0, 1.914991, 4.702414, 5.0, 5.262566, 5.991308, 6.320661, 6.0],
+        ),
+        ("pchip", [1.0, 2.048987, 4.852934, 5.0, 5.098708, 5.445614, 5.766159, 6.0]),
+    ],
+)
+@pytest.mark.parametrize("times_dtype", [pl.Datetime("ms"), pl.Date, pl.Int64])
+def test_interpolate_by_cubic(
+    method: InterpolationMethod, expected: list[float], times_dtype: PolarsDataType
+) -> None:
+    df = pl.DataFrame(
+        {
+            "times": [1, 3, 10, 11, 12, 16, 21, 30],
+            "values": [1, None, None, 5, None, None, None, 6],
+        },
+        schema={"times": times_dtype, "values": pl.Int64},
+    )
+    result = df.select(pl.col("values").interpolate_by("times", method=method))
+    assert_frame_equal(result, pl.DataFrame({"values": expected}))
+
+    # Unsorted `by`.
+    result = (
+        df.reverse()
+        .select(pl.col("values").interpolate_by("times", method=method))
+        .reverse()
+    )
+    assert_frame_equal(result, pl.DataFrame({"values": expected}))
+
+
+def test_interpolate_by_nearest() -> None:
+    df = pl.DataFrame(
+        {
+            "times": [0, 1, 4, 5, 6, 9],
+            "values": ["a", None, None, "b", None, None],
+        }
+    )
+    result = df.select(pl.col("values").interpolate_by("times", method="nearest"))
+    expected = pl.DataFrame({"values": ["a", "a", "b", "b", None, None]})
+    assert_frame_equal(result, expected)
+
+    df = df.with_columns(pl.Series("values", [1, None, None, 3, None, None]))
+    result = df.select(pl.col("values").interpolate_by("times", method="nearest"))
+    expected = pl.DataFrame({"values": [1, 1, 3, 3, None, None]})
+    assert_frame_equal(result, expected)
@@ -1180,3 +1180,70 @@ def test_join_as_of_by_schema() -> None:
     b = pl.DataFrame({"a": [1], "b": [2], "d": [4]}).lazy()
     q = a.join_asof(b, on=pl.col("a").set_sorted(), by="b")
     assert q.collect_schema().names() == q.collect().columns
+
+
+@pytest.mark.parametrize(
+    ("strategy", "expected"),
+    [
+        ("backward", [None, "a", "b", "b", "d"]),
+        ("forward", ["b", "c", "d", "d", None]),
+        ("nearest", ["b", "c", "d", "d", "d"]),
+    ],
+)
+def test_join_asof_disallow_exact_matches(
+    strategy: str, expected: list[str | None]
+) -> None:
+    left = pl.DataFrame({"t": [1, 2, 3, 3, 5]})
+    right = pl.DataFrame({"t": [1, 2, 3, 4], "value": ["a", "b", "c", "d"]})
+
+    out = left.join_asof(
+        right,
+        on="t",
+        strategy=strategy,  # type: ignore[arg-type]
+        allow_exact_matches=False,
+    )
+    assert out["value"].to_list() == expected
+
+    out = left.with_columns(g=pl.lit(0)).join_asof(
+        right.with_columns(g=pl.lit(0)),
+        on="t",
+        by="g",
+        strategy=strategy,  # type: ignore[arg-type]
+        allow_exact_matches=False,
+    )
+    assert out["value"].to_list() == expected
+
+
+def test_join_asof_nearest_tie_break() -> None:
+    left = pl.DataFrame({"t": [2, 4]})
+    right = pl.DataFrame({"t": [1, 3, 5], "value": ["a", "b", "c"]})
+
+    out = left.join_asof(right, on="t", strategy="nearest")
+    assert out["value"].to_list() == ["b", "c"]
+
+    out = left.join_asof(right, on="t", strategy="nearest", tie_break="backward")
+    assert out["value"].to_list() == ["a", "b"]
+
+
+def test_join_asof_all_matches() -> None:
+    left = pl.DataFrame({"t": [1, 3, 6]})
+    right = pl.DataFrame({"t": [1, 2, 2, 5], "value": ["a", "b", "c", "d"]})
+
+    out = left.join_asof(right, on="t", all_matches=True)
+    expected = pl.DataFrame(
+        {"t": [1, 3, 3, 6], "value": ["a", "b", "c", "d"]},
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_join_asof_tolerance_column() -> None:
+    left = pl.DataFrame({"t": [10, 20, 30], "max_lookback": [1, 5, None]})
+    right = pl.DataFrame({"t": [8, 16, 21], "value": ["a", "b", "c"]})
+
+    out = (
+        left.lazy()
+        .join_asof(right.lazy(), on="t", tolerance_column="max_lookback")
+        .select("t", "value")
+        .collect()
+    )
+    assert out["value"].to_list() == [None, "b", "c"]
@@ -531,3 +531,60 @@ def test_pivot_invalid() -> None:
         match="`index` and `values` cannot both be None in `pivot` operation",
     ):
         pl.DataFrame({"a": [1, 2], "b": [2, 3], "c": [3, 4]}).pivot("a")
+
+
+def test_pivot_multiple_aggregations() -> None:
+    df = pl.DataFrame(
+        {
+            "region": ["north", "north", "south", "south", "south"],
+            "product": ["a", "b", "a", "a", "b"],
+            "sales": [10, 20, 30, 50, 40],
+            "units": [1, 2, 3, 4, 5],
+        }
+    )
+
+    result = df.pivot(
+        "product",
+        index="region",
+        values="sales",
+        aggregate_function=["sum", pl.element().max().alias("top")],
+    )
+    expected = pl.DataFrame(
+        {
+            "region": ["north", "south"],
+            "sum_a": [10, 80],
+            "sum_b": [20, 40],
+            "top_a": [10, 50],
+            "top_b": [20, 40],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.pivot(
+        "product",
+        index="region",
+        aggregate_function=["min", "len"],
+        separator="|",
+        name_order="on_first",
+    )
+    assert result.columns == [
+        "region",
+        "a|sales|min",
+        "b|sales|min",
+        "a|sales|len",
+        "b|sales|len",
+        "a|units|min",
+        "b|units|min",
+        "a|units|len",
+        "b|units|len",
+    ]
+    assert result.row(1, named=True)["a|units|len"] == 2
+
+    with pytest.raises(DuplicateError, match="distinct names"):
+        df.pivot("product", index="region", aggregate_function=["sum", "sum"])
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="alias"):
+        df.pivot(
+            "product",
+            index="region",
+            aggregate_function=[pl.element().sum(), pl.element().max()],
+        )
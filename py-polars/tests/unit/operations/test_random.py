@@ -152,7 +152,7 @@ def test_shuffle_expr() -> None:
 def test_shuffle_series() -> None:
     a = pl.Series("a", [1, 2, 3])
     out = a.shuffle(2)
-    expected = pl.Series("a", [2, 1, 3])
+    expected = pl.Series("a", [1, 3, 2])
     assert_series_equal(out, expected)
 
     out = pl.select(pl.lit(a).shuffle(2)).to_series()
@@ -167,3 +167,61 @@ def test_sample_16232() -> None:
     assert df.select(pl.col("b").list.sample(n=pl.col("a"), seed=0)).to_dict(
         as_series=False
     ) == {"b": [[], [], [1]]}
+
+
+def test_seeded_sample_independent_of_chunks() -> None:
+    df = pl.DataFrame({"a": range(100)})
+    chunked = pl.concat([df[:30], df[30:70], df[70:]], rechunk=False)
+    assert chunked.n_chunks() == 3
+
+    for seed in (0, 1, 42):
+        assert_frame_equal(
+            chunked.sample(fraction=1.0, shuffle=True, seed=seed),
+            df.sample(fraction=1.0, shuffle=True, seed=seed),
+        )
+        assert_series_equal(chunked["a"].shuffle(seed), df["a"].shuffle(seed))
+        # A seeded sample is a prefix of the seeded shuffle.
+        assert_series_equal(
+            df["a"].sample(10, shuffle=True, seed=seed),
+            df["a"].shuffle(seed).head(10),
+        )
+
+    for expr in (pl.col("a").shuffle(3), pl.col("a").sample(10, seed=3)):
+        q = chunked.lazy().select(expr)
+        assert_frame_equal(q.collect(streaming=True), df.select(expr))
+
+
+def test_sample_weighted_expr() -> None:
+    df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5], "w": [0, 1, None, 2, 0.0, 3]})
+
+    out = df.select(pl.col("a").sample(3, weights="w", seed=0))
+    assert sorted(out["a"].to_list()) == [1, 3, 5]
+
+    out = df.select(
+        pl.col("a").sample(fraction=2.0, with_replacement=True, weights="w", seed=0)
+    )
+    assert out.height == 12
+    assert set(out["a"].to_list()) <= {1, 3, 5}
+
+    with pytest.raises(ShapeError):
+        df.select(pl.col("a").sample(4, weights="w"))
+
+
+def test_sample_stratified() -> None:
+    df = pl.DataFrame(
+        {
+            "label": ["a"] * 8 + ["b"] * 4 + ["c"],
+            "value": range(13),
+        }
+    )
+
+    out = df.sample_stratified("label", 2, seed=1)
+    assert out["label"].to_list() == ["a", "a", "b", "b", "c"]
+    assert out["value"].is_sorted()
+    assert_frame_equal(out, df.sample_stratified(["label"], 2, seed=1))
+
+    out = df.sample_stratified("label", fraction=0.5, seed=1)
+    assert out["label"].value_counts(sort=True).rows() == [("a", 4), ("b", 2)]
+
+    with pytest.raises(ValueError, match="cannot specify both"):
+        df.sample_stratified("label", 1, fraction=0.5)
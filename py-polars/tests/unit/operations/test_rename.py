@@ -1,6 +1,15 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 
 def test_rename_invalidate_cache_15884() -> None:
     assert (
@@ -149,3 +158,45 @@ def test_rename_schema_order_6660() -> None:
 
     assert renamed.collect_schema() == renamed.collect().schema
     assert computed.collect_schema() == computed.collect().schema
+
+
+def test_rename_normalized() -> None:
+    df = pl.DataFrame(
+        {
+            "Customer ID": [1],
+            "customerId": [2],
+            "HTTPServer": [3],
+            "Crème brûlée (€)": [4],
+            "customer_id_1": [5],
+        }
+    )
+    assert df.rename_normalized().columns == [
+        "customer_id",
+        "customer_id_2",
+        "http_server",
+        "creme_brulee",
+        "customer_id_1",
+    ]
+    assert df.lazy().rename_normalized("pascal").collect().columns == [
+        "CustomerId",
+        "CustomerId_1",
+        "HttpServer",
+        "CremeBrulee",
+        "CustomerId1",
+    ]
+
+    df = pl.DataFrame({"user name": [1], "Crème": [2]})
+    assert df.rename_normalized("camel", ascii_only=False).columns == [
+        "userName",
+        "crème",
+    ]
+
+    with pytest.raises(pl.exceptions.DuplicateError):
+        pl.DataFrame({"a b": [1], "A_B": [2]}).rename_normalized(dedupe="raise")
+
+
+def test_scan_csv_normalize_column_names(tmp_path: Path) -> None:
+    path = tmp_path / "orders.csv"
+    path.write_text("Order ID,orderDate\n1,2024-01-01\n")
+    lf = pl.scan_csv(path, normalize_column_names=True)
+    assert lf.collect_schema().names() == ["order_id", "order_date"]
@@ -1,3 +1,7 @@
+from datetime import date, datetime
+
+import pytest
+
 import polars as pl
 
 
@@ -44,3 +48,63 @@ def test_shrink_dtype() -> None:
         "j": [None, None, None],
         "k": [None, None, None],
     }
+
+
+def test_shrink_dtype_frame() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 300],
+            "b": [datetime(2024, 1, 1), datetime(2024, 1, 2), None],
+            "c": [datetime(2024, 1, 1), datetime(2024, 1, 2, 12), None],
+            "d": ["x", "y", "x"],
+            "e": ["x", "y", "z"],
+        }
+    )
+
+    out = df.shrink_dtype()
+    assert out.dtypes == [
+        pl.Int16,
+        pl.Datetime("us"),
+        pl.Datetime("us"),
+        pl.String,
+        pl.String,
+    ]
+
+    out = df.shrink_dtype(shrink_dates=True, categorical_threshold=0.7)
+    assert out.dtypes == [
+        pl.Int16,
+        pl.Date,
+        pl.Datetime("us"),
+        pl.Categorical,
+        pl.String,
+    ]
+    assert out["b"].to_list() == [date(2024, 1, 1), date(2024, 1, 2), None]
+
+    out = df.shrink_dtype(categorical_threshold=0.7, use_enum=True)
+    assert out["d"].dtype == pl.Enum(["x", "y"])
+    assert out["d"].to_list() == ["x", "y", "x"]
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.shrink_dtype(categorical_threshold=1.5)
+
+
+def test_downcast_small_integer_sum() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2],
+            "a": pl.Series([100, 100, -100], dtype=pl.Int8),
+            "b": pl.Series([60_000, 60_000, 1], dtype=pl.UInt16),
+        }
+    )
+    out = (
+        df.lazy()
+        .group_by("g", maintain_order=True)
+        .agg(pl.col("a", "b").sum())
+        .collect()
+    )
+    assert out.schema == {"g": pl.Int64, "a": pl.Int64, "b": pl.Int64}
+    assert out.to_dict(as_series=False) == {
+        "g": [1, 2],
+        "a": [200, -100],
+        "b": [120_000, 1],
+    }
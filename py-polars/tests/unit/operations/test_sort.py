@@ -608,6 +608,45 @@ def test_arg_sort_by_descending() -> None:
         df.select(pl.arg_sort_by(["a", "b"], descending=[True]))
 
 
+@pytest.mark.parametrize("maintain_order", [False, True])
+@pytest.mark.parametrize("limit", [0, 1, 3, 7, 20])
+def test_arg_sort_by_limit(maintain_order: bool, limit: int) -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 2, 1, 2, 1, 2, 1, 2],
+            "a": [3, None, 1, 3, 1, None, 2, 3, 1, 2],
+            "b": [1.0, 2.0, None, 0.5, 1.0, None, 3.0, 0.25, 4.0, 1.0],
+        }
+    )
+
+    def arg_sort_by(limit: int | None = None) -> pl.Expr:
+        return pl.arg_sort_by(
+            "a",
+            "b",
+            descending=[True, False],
+            nulls_last=[True, False],
+            maintain_order=maintain_order,
+            limit=limit,
+        )
+
+    result = df.select(arg_sort_by(limit))
+    expected = df.select(arg_sort_by().head(limit))
+    assert result.height == min(limit, df.height)
+    assert_frame_equal(result, expected)
+
+    result = df.group_by("g", maintain_order=True).agg(arg_sort_by(limit))
+    expected = df.group_by("g", maintain_order=True).agg(arg_sort_by().head(limit))
+    assert_frame_equal(result, expected)
+
+
+def test_arg_sort_by_nulls_last_per_column() -> None:
+    df = pl.DataFrame({"a": [1, None, 1, None], "b": [None, 1, 2, None]})
+    result = df.select(
+        pl.arg_sort_by("a", "b", nulls_last=[True, False], maintain_order=True)
+    )
+    assert result.to_series().to_list() == [0, 2, 3, 1]
+
+
 def test_arg_sort_struct() -> None:
     df = pl.DataFrame(
         {
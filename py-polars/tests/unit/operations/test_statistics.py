@@ -49,6 +49,51 @@ def test_hist() -> None:
     ).to_series().to_list() == [0, 3, 4]
 
 
+def test_hist_strategies() -> None:
+    a = pl.Series("a", [1, 3, 8, 8, 2, 1, 3])
+
+    out = a.hist(strategy="freedman_diaconis")
+    assert out["breakpoint"].to_list() == [4.5, 8.0, float("inf")]
+    assert out["count"].to_list() == [5, 2, 0]
+
+    out = a.hist(bin_count=3, strategy="log")
+    assert out["breakpoint"].to_list() == [0.0, 2.0, 4.0, 8.0, float("inf")]
+    assert out["count"].to_list() == [0, 3, 2, 2, 0]
+
+    # one bin per decade, values that aren't positive go in the first bin
+    b = pl.Series("b", [-1.0, 0.0, 1.0, 20.0, 30.0, 100.0, None])
+    out = b.hist(strategy="log", include_category=False)
+    assert out["breakpoint"].to_list() == [0.0, 10.0, 100.0, float("inf")]
+    assert out["count"].to_list() == [2, 1, 3, 0]
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="bin strategy"):
+        a.hist(bins=[1, 2], strategy="log")
+    with pytest.raises(ValueError, match="strategy"):
+        a.hist(strategy="sturges")  # type: ignore[arg-type]
+
+
+def test_hist_strategy_per_group() -> None:
+    df = pl.DataFrame(
+        {"g": ["a", "a", "a", "b", "b", "b"], "x": [1, 10, 100, 2, 3, 4]}
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").hist(strategy="log", include_breakpoint=True)
+    )
+    assert out["x"].to_list() == [
+        [
+            {"breakpoint": 0.0, "count": 0},
+            {"breakpoint": 10.0, "count": 2},
+            {"breakpoint": 100.0, "count": 1},
+            {"breakpoint": float("inf"), "count": 0},
+        ],
+        [
+            {"breakpoint": 0.0, "count": 0},
+            {"breakpoint": 4.0, "count": 3},
+            {"breakpoint": float("inf"), "count": 0},
+        ],
+    ]
+
+
 @pytest.mark.parametrize("values", [[], [None]])
 def test_hist_empty_or_all_null(values: list[None]) -> None:
     ser = pl.Series(values, dtype=pl.Float64)
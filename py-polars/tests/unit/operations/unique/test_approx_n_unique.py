@@ -18,3 +18,25 @@ def test_lf_approx_n_unique_deprecated() -> None:
         result = df.approx_n_unique()
     expected = pl.LazyFrame({"a": [2], "b": [1]}).cast(pl.UInt32)
     assert_frame_equal(result, expected)
+
+
+def test_approx_n_unique_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3],
+            "a": ["w", "w", None, "x", "y", "z"],
+        }
+    )
+    result = df.group_by("g").agg(pl.col("a").approx_n_unique()).sort("g")
+    expected = pl.DataFrame(
+        {"g": [1, 2, 3], "a": [2, 2, 1]}, schema={"g": pl.Int64, "a": pl.UInt32}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_approx_n_unique_group_by_large() -> None:
+    n = 100_000
+    df = pl.DataFrame({"g": [0, 1] * (n // 2), "a": range(n)})
+    result = df.group_by("g").agg(pl.col("a").approx_n_unique())
+    for count in result["a"]:
+        assert abs(count - n // 2) / (n // 2) < 0.05
@@ -1,3 +1,5 @@
+import pytest
+
 import polars as pl
 from polars.testing import assert_series_equal
 
@@ -85,3 +87,51 @@ def test_is_duplicated_null() -> None:
     s = pl.Series([None, None, None])
     expected = pl.Series([True, True, True], dtype=pl.Boolean)
     assert_series_equal(s.is_duplicated(), expected)
+
+
+def test_is_duplicated_keep() -> None:
+    s = pl.Series("a", [1, 2, 1, None, 1, None])
+    assert s.is_duplicated(keep="first").to_list() == [
+        False,
+        False,
+        True,
+        False,
+        True,
+        True,
+    ]
+    assert s.is_duplicated(keep="last").to_list() == [
+        True,
+        False,
+        True,
+        True,
+        False,
+        False,
+    ]
+    assert_series_equal(s.is_duplicated(keep=False), s.is_duplicated())
+
+    # Dropping the marked rows deduplicates the data.
+    df = pl.DataFrame({"a": [1, 2, 1, 1], "b": ["x", "y", "x", "z"]})
+    assert df.filter(~df.is_duplicated(keep="first")).rows() == [
+        (1, "x"),
+        (2, "y"),
+        (1, "z"),
+    ]
+    assert df.filter(~df.is_duplicated(keep="last")).rows() == [
+        (2, "y"),
+        (1, "x"),
+        (1, "z"),
+    ]
+    assert_series_equal(
+        df.is_duplicated(keep=False), pl.Series([True, False, True, False])
+    )
+
+    out = df.group_by("b", maintain_order=True).agg(
+        pl.col("a").is_duplicated(keep="first")
+    )
+    assert out.to_dict(as_series=False) == {
+        "b": ["x", "y", "z"],
+        "a": [[False, True], [False], [False]],
+    }
+
+    with pytest.raises(ValueError, match="`keep` must be one of"):
+        s.is_duplicated(keep="any")  # type: ignore[arg-type]
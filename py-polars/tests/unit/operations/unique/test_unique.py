@@ -1,12 +1,16 @@
 from __future__ import annotations
 
 from datetime import date
+from typing import TYPE_CHECKING
 
 import pytest
 
 import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars.type_aliases import UniqueKeepStrategy
+
 
 def test_unique_predicate_pd() -> None:
     lf = pl.LazyFrame(
@@ -154,3 +158,37 @@ def test_unique_with_null() -> None:
         {"a": [1, 2, 3, 4], "b": ["a", "b", "c", "c"], "c": [None, None, None, None]}
     )
     assert_frame_equal(df.unique(maintain_order=True), expected_df)
+
+
+@pytest.mark.parametrize(
+    ("keep", "expected"),
+    [
+        ("first", pl.col("index") == pl.col("index").first().over("a", "b")),
+        ("last", pl.col("index") == pl.col("index").last().over("a", "b")),
+        ("none", pl.len().over("a", "b") == 1),
+    ],
+)
+def test_arg_unique_multiple_columns(
+    keep: UniqueKeepStrategy, expected: pl.Expr
+) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 1, 1, None, None, 3] * 1000,
+            "b": ["x", "x", "x", "y", None, None, "z"] * 1000,
+            "c": range(7000),
+        }
+    ).with_columns(pl.col("a") + pl.col("c") // 3500)
+
+    idx = df.arg_unique(["a", "b"], keep=keep, maintain_order=True)
+    assert idx.name == "index"
+    assert_series_equal(idx, df.with_row_index().filter(expected)["index"])
+    assert_frame_equal(
+        df[idx], df.unique(["a", "b"], keep=keep, maintain_order=True)
+    )
+
+
+def test_arg_unique_slice_and_empty() -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, 3], "b": [1, 1, 2, 3]})
+    assert df.arg_unique(keep="first", maintain_order=True).to_list() == [0, 2, 3]
+    assert df.arg_unique(keep="none", maintain_order=True).to_list() == [2, 3]
+    assert df.clear().arg_unique().to_list() == []
@@ -41,3 +41,23 @@ def test_unique_counts_null() -> None:
     s = pl.Series([None, None, None])
     expected = pl.Series([3], dtype=pl.UInt32)
     assert_series_equal(s.unique_counts(), expected)
+
+
+def test_unique_counts_nested_and_bool() -> None:
+    s = pl.Series("a", [True, False, True, None, True])
+    expected = pl.Series("a", [3, 1, 1], dtype=pl.UInt32)
+    assert_series_equal(s.unique_counts(), expected)
+
+    s = pl.Series("a", [[1], [2, 3], [1], [1]])
+    expected = pl.Series("a", [3, 1], dtype=pl.UInt32)
+    assert_series_equal(s.unique_counts(), expected)
+
+    s = pl.Series("a", [{"x": 1}, {"x": 2}, {"x": 2}])
+    expected = pl.Series("a", [1, 2], dtype=pl.UInt32)
+    assert_series_equal(s.unique_counts(), expected)
+
+
+def test_unique_counts_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2], "a": ["x", "y", "x", "z", "z"]})
+    out = df.group_by("g", maintain_order=True).agg(pl.col("a").unique_counts())
+    assert out.to_dict(as_series=False) == {"g": [1, 2], "a": [[2, 1], [2]]}
@@ -361,3 +361,52 @@ def test_streaming_with_hconcat(tmp_path: Path) -> None:
     )
 
     assert_frame_equal(result, expected)
+
+
+def test_streaming_with_row_index() -> None:
+    lf = (
+        pl.LazyFrame({"a": range(100_000)})
+        .filter(pl.col("a") % 3 != 0)
+        .with_row_index(offset=5)
+        .filter(pl.col("a") % 2 == 0)
+    )
+    # The whole query runs in the streaming engine.
+    assert lf.explain(streaming=True).startswith("STREAMING")
+    assert_frame_equal(lf.collect(streaming=True), lf.collect())
+
+
+def test_streaming_unpivot() -> None:
+    lf = pl.LazyFrame(
+        {
+            "id": range(50_000),
+            "a": range(50_000),
+            "b": [1.5] * 50_000,
+            "c": pl.Series([None] * 50_000, dtype=pl.Int8),
+        }
+    ).unpivot(index="id", variable_name="var", value_name="val")
+    assert lf.explain(streaming=True).startswith("STREAMING")
+
+    out = lf.collect(streaming=True)
+    assert out.schema == {"id": pl.Int64, "var": pl.String, "val": pl.Float64}
+    assert_frame_equal(
+        out.sort("id", "var"), lf.collect().sort("id", "var"), check_row_order=True
+    )
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        pl.LazyFrame({"a": [1]}).unpivot(on="b").collect(streaming=True)
+
+
+def test_head_streaming() -> None:
+    lf = pl.LazyFrame({"a": range(100_000), "b": [1.5] * 100_000}).filter(
+        pl.col("a") % 2 == 0
+    )
+    out = lf.head_streaming(10)
+    assert out.shape == (10, 2)
+    assert out.schema == {"a": pl.Int64, "b": pl.Float64}
+    assert (out["a"] % 2 == 0).all()
+
+    assert lf.head_streaming(100_000).height == 50_000
+
+    # Equivalent to a regular head if the query can't be streamed.
+    lf = pl.LazyFrame({"a": [3, 1, 2]}).with_columns(pl.col("a").cum_sum())
+    assert lf.head_streaming(2)["a"].to_list() == [3, 4]
@@ -519,3 +519,118 @@ def test_streaming_group_by_boolean_mean_15610(
     )
 
     assert_frame_equal(out, expect)
+
+
+def test_streaming_group_by_approx_n_unique() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 2, 1, 3] * 1000,
+            "a": list(range(3000)) * 2,
+        }
+    )
+    q = df.lazy().group_by("g").agg(pl.col("a").approx_n_unique()).sort("g")
+    assert "STREAMING" in q.explain(streaming=True)
+    result = q.collect(streaming=True)
+    expected = q.collect()
+    assert result["g"].to_list() == expected["g"].to_list()
+    assert result["a"].dtype == pl.UInt32
+    for streamed, in_memory in zip(result["a"], expected["a"]):
+        assert abs(streamed - in_memory) / in_memory < 0.05
+
+    # Small groups are counted exactly.
+    q = df.lazy().head(6).group_by("g").agg(pl.col("a").approx_n_unique()).sort("g")
+    assert q.collect(streaming=True)["a"].to_list() == [3, 2, 1]
+
+
+def test_streaming_group_by_min_max_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 2, 1, 3] * 1000,
+            "ts": list(range(6000)),
+            "value": [str(i) for i in range(6000)],
+        }
+    ).with_columns(pl.col("ts").cast(pl.Datetime("ms")))
+
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            pl.col("value").max_by("ts").alias("latest"),
+            pl.col("value").min_by("ts").alias("earliest"),
+        )
+        .sort("g")
+    )
+    assert "STREAMING" in q.explain(streaming=True)
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "latest": ["5998", "5997", "5999"],
+            "earliest": ["0", "1", "5"],
+        }
+    )
+    assert_frame_equal(q.collect(streaming=True), expected)
+    assert_frame_equal(q.collect(), expected)
+
+
+def test_streaming_group_by_n_unique_rows_count_if() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 2, 1, 3] * 1000,
+            "a": [i % 7 for i in range(6000)],
+            "b": [str(i % 3) if i % 11 else None for i in range(6000)],
+            "flag": [i % 5 != 0 if i % 13 else None for i in range(6000)],
+        }
+    )
+
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            pl.n_unique_rows("a", "b").alias("rows"),
+            pl.n_unique_rows("a", "b", predicate="flag").alias("rows_if"),
+            pl.col("b").count_if("flag").alias("count_if"),
+        )
+        .sort("g")
+    )
+    assert "STREAMING" in q.explain(streaming=True)
+    expected = (
+        df.group_by("g")
+        .agg(
+            pl.struct("a", "b").n_unique().alias("rows"),
+            pl.struct("a", "b").filter("flag").n_unique().alias("rows_if"),
+            pl.col("b").filter("flag").count().alias("count_if"),
+        )
+        .sort("g")
+    )
+    assert_frame_equal(q.collect(streaming=True), expected)
+    assert_frame_equal(q.collect(), expected)
+
+
+@pytest.mark.write_disk()
+def test_streaming_group_by_ooc_all_aggs(tmp_path: Path, monkeypatch: Any) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    df = pl.DataFrame(
+        {
+            "g": [i % 7 for i in range(3000)],
+            "s": [str(i % 5) if i % 11 else None for i in range(3000)],
+            "l": [[i, i % 3] for i in range(3000)],
+        }
+    )
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            pl.col("s").n_unique().alias("n_unique"),
+            pl.col("s").alias("list"),
+            pl.col("s").first().alias("first"),
+            pl.col("s").last().alias("last"),
+            pl.col("l").first().alias("l_first"),
+            pl.col("l").alias("l_list"),
+        )
+        .sort("g")
+    )
+    assert "STREAMING" in q.explain(streaming=True)
+    assert_frame_equal(q.collect(streaming=True), q.collect())
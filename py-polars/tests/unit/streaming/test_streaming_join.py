@@ -263,6 +263,27 @@ def test_non_coalescing_streaming_left_join() -> None:
     }
 
 
+@pytest.mark.parametrize("how", ["inner", "left"])
+def test_streaming_join_local_categorical_keys(how: JoinStrategy) -> None:
+    # The categories of both sides have different physicals.
+    lhs = pl.LazyFrame(
+        {"a": ["a", "b", "c"], "b": [1, 2, 3]}, schema_overrides={"a": pl.Categorical}
+    )
+    rhs = pl.LazyFrame(
+        {"a": ["c", "a", "d"], "c": [30, 10, 40]},
+        schema_overrides={"a": pl.Categorical},
+    )
+
+    q = lhs.join(rhs, on="a", how=how).sort("b")
+    assert q.explain(streaming=True).startswith("STREAMING")
+    out = q.collect(streaming=True).with_columns(pl.col("a").cast(pl.String))
+    expected = {
+        "inner": {"a": ["a", "c"], "b": [1, 3], "c": [10, 30]},
+        "left": {"a": ["a", "b", "c"], "b": [1, 2, 3], "c": [10, None, 30]},
+    }[how]
+    assert out.to_dict(as_series=False) == expected
+
+
 @pytest.mark.write_disk()
 def test_streaming_outer_join_partial_flush(tmp_path: Path) -> None:
     data = {